@@ -0,0 +1,65 @@
+//! Command for displaying the last persisted snapshot of key metrics.
+
+use clap::Parser;
+use reth_chainspec::EthChainSpec;
+use reth_cli::chainspec::ChainSpecParser;
+use reth_node_core::args::DatadirArgs;
+use reth_node_metrics::snapshot::MetricsSnapshot;
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// `reth debug last-metrics` command
+///
+/// Displays the last snapshot of key metrics that was persisted by the node, e.g. before a crash.
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    /// The chain this node is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = C::help_message(),
+        default_value = C::SUPPORTED_CHAINS[0],
+        value_parser = C::parser()
+    )]
+    chain: Arc<C::ChainSpec>,
+
+    #[command(flatten)]
+    datadir: DatadirArgs,
+
+    /// Print the snapshot as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec>> Command<C> {
+    /// Execute `debug last-metrics` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        let path = self.datadir.resolve_datadir(self.chain.chain()).metrics_snapshot();
+        let Some(snapshot) = MetricsSnapshot::load(&path)? else {
+            eyre::bail!("No metrics snapshot found at {}", path.display())
+        };
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&snapshot)?);
+            return Ok(())
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        println!(
+            "Metrics snapshot of reth {} taken {}s ago (timestamp {})",
+            snapshot.version,
+            now.saturating_sub(snapshot.timestamp),
+            snapshot.timestamp
+        );
+        println!();
+        for (series, value) in &snapshot.metrics {
+            println!("{series} {value}");
+        }
+
+        Ok(())
+    }
+}
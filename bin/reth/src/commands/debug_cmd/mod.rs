@@ -10,6 +10,7 @@ use reth_node_ethereum::EthEngineTypes;
 mod build_block;
 mod execution;
 mod in_memory_merkle;
+mod last_metrics;
 mod merkle;
 mod replay_engine;
 
@@ -33,6 +34,8 @@ pub enum Subcommands<C: ChainSpecParser> {
     BuildBlock(build_block::Command<C>),
     /// Debug engine API by replaying stored messages.
     ReplayEngine(replay_engine::Command<C>),
+    /// Display the last persisted snapshot of key metrics, e.g. before a crash.
+    LastMetrics(last_metrics::Command<C>),
 }
 
 impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
@@ -47,6 +50,7 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
             Subcommands::InMemoryMerkle(command) => command.execute::<N>(ctx).await,
             Subcommands::BuildBlock(command) => command.execute::<N>(ctx).await,
             Subcommands::ReplayEngine(command) => command.execute::<N>(ctx).await,
+            Subcommands::LastMetrics(command) => command.execute().await,
         }
     }
 }
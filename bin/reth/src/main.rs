@@ -8,7 +8,8 @@ use reth::cli::Cli;
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
 use reth_node_builder::{
     engine_tree_config::{
-        TreeConfig, DEFAULT_MEMORY_BLOCK_BUFFER_TARGET, DEFAULT_PERSISTENCE_THRESHOLD,
+        TreeConfig, DEFAULT_MAX_WARM_STATE_KEYS, DEFAULT_MEMORY_BLOCK_BUFFER_TARGET,
+        DEFAULT_PERSISTENCE_THRESHOLD,
     },
     EngineNodeLauncher,
 };
@@ -39,6 +40,11 @@ pub struct EngineArgs {
     /// Configure the target number of blocks to keep in memory.
     #[arg(long = "engine.memory-block-buffer-target", conflicts_with = "legacy", default_value_t = DEFAULT_MEMORY_BLOCK_BUFFER_TARGET)]
     pub memory_block_buffer_target: u64,
+
+    /// Configure the maximum number of recently changed account and storage keys that are
+    /// persisted on shutdown and pre-warmed on startup. Set to 0 to disable.
+    #[arg(long = "engine.warm-state-keys", conflicts_with = "legacy", default_value_t = DEFAULT_MAX_WARM_STATE_KEYS)]
+    pub max_warm_state_keys: u32,
 }
 
 impl Default for EngineArgs {
@@ -48,6 +54,7 @@ impl Default for EngineArgs {
             legacy: false,
             persistence_threshold: DEFAULT_PERSISTENCE_THRESHOLD,
             memory_block_buffer_target: DEFAULT_MEMORY_BLOCK_BUFFER_TARGET,
+            max_warm_state_keys: DEFAULT_MAX_WARM_STATE_KEYS,
         }
     }
 }
@@ -71,7 +78,8 @@ fn main() {
                 false => {
                     let engine_tree_config = TreeConfig::default()
                         .with_persistence_threshold(engine_args.persistence_threshold)
-                        .with_memory_block_buffer_target(engine_args.memory_block_buffer_target);
+                        .with_memory_block_buffer_target(engine_args.memory_block_buffer_target)
+                        .with_max_warm_state_keys(engine_args.max_warm_state_keys);
                     let handle = builder
                         .with_types_and_provider::<EthereumNode, BlockchainProvider2<_>>()
                         .with_components(EthereumNode::components())
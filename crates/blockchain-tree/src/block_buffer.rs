@@ -1,9 +1,15 @@
 use crate::metrics::BlockBufferMetrics;
 use alloy_primitives::{BlockHash, BlockNumber};
+use reth_memory_budget::{MemoryBudget, MemoryConsumer};
 use reth_network::cache::LruCache;
 use reth_primitives::SealedBlockWithSenders;
+use reth_primitives_traits::InMemorySize;
 use std::collections::{BTreeMap, HashMap, HashSet};
 
+/// The estimated in-memory size of a buffered block, used to register the capacity of the buffer
+/// with the memory budget.
+const ESTIMATED_BLOCK_SIZE: usize = 256 * 1024;
+
 /// Contains the tree of pending blocks that cannot be executed due to missing parent.
 /// It allows to store unconnected blocks for potential future inclusion.
 ///
@@ -14,7 +20,8 @@ use std::collections::{BTreeMap, HashMap, HashSet};
 /// * [`BlockBuffer::remove_old_blocks`] to remove old blocks that precede the finalized number.
 ///
 /// Note: Buffer is limited by number of blocks that it can contain and eviction of the block
-/// is done by last recently used block.
+/// is done by last recently used block. The limit is shrunk if the node is under memory pressure,
+/// see [`BlockBuffer::with_memory_budget`].
 #[derive(Debug)]
 pub struct BlockBuffer {
     /// All blocks in the buffer stored by their block hash.
@@ -31,6 +38,12 @@ pub struct BlockBuffer {
     ///
     /// Used as counter of amount of blocks inside buffer.
     pub(crate) lru: LruCache<BlockHash>,
+    /// The configured maximum number of blocks.
+    limit: u32,
+    /// The in-memory size of all buffered blocks.
+    size: usize,
+    /// The share of the node's memory budget.
+    memory: MemoryConsumer,
     /// Various metrics for the block buffer.
     pub(crate) metrics: BlockBufferMetrics,
 }
@@ -38,11 +51,20 @@ pub struct BlockBuffer {
 impl BlockBuffer {
     /// Create new buffer with max limit of blocks
     pub fn new(limit: u32) -> Self {
+        Self::with_memory_budget(limit, &MemoryBudget::unlimited())
+    }
+
+    /// Create new buffer with max limit of blocks that is registered with the given memory budget.
+    pub fn with_memory_budget(limit: u32, memory_budget: &MemoryBudget) -> Self {
         Self {
             blocks: Default::default(),
             parent_to_child: Default::default(),
             earliest_blocks: Default::default(),
             lru: LruCache::new(limit),
+            limit,
+            size: 0,
+            memory: memory_budget
+                .register("block_buffer", (limit as usize).saturating_mul(ESTIMATED_BLOCK_SIZE)),
             metrics: Default::default(),
         }
     }
@@ -72,7 +94,10 @@ impl BlockBuffer {
 
         self.parent_to_child.entry(block.parent_hash).or_default().insert(hash);
         self.earliest_blocks.entry(block.number).or_default().insert(hash);
-        self.blocks.insert(hash, block);
+        self.size += block.size();
+        if let Some(replaced) = self.blocks.insert(hash, block) {
+            self.size -= replaced.size();
+        }
 
         if let (_, Some(evicted_hash)) = self.lru.insert_and_get_evicted(hash) {
             // evict the block if limit is hit
@@ -81,7 +106,17 @@ impl BlockBuffer {
                 self.remove_from_parent(evicted_block.parent_hash, &evicted_hash);
             }
         }
-        self.metrics.blocks.set(self.blocks.len() as f64);
+
+        // evict the oldest blocks if the buffer exceeds its share of the memory budget
+        let limit = self.memory.scale(self.limit as usize);
+        while self.lru.len() > limit {
+            let Some(oldest) = self.lru.iter().last().copied() else { break };
+            if self.remove_block(&oldest).is_none() {
+                self.lru.remove(&oldest);
+            }
+        }
+
+        self.update_metrics();
     }
 
     /// Removes the given block from the buffer and also all the children of the block.
@@ -99,7 +134,7 @@ impl BlockBuffer {
             .into_iter()
             .chain(self.remove_children(vec![*parent_hash]))
             .collect();
-        self.metrics.blocks.set(self.blocks.len() as f64);
+        self.update_metrics();
         removed
     }
 
@@ -123,7 +158,13 @@ impl BlockBuffer {
         }
 
         self.remove_children(block_hashes_to_remove);
+        self.update_metrics();
+    }
+
+    /// Updates the metrics and reports the size of the buffer to the memory budget.
+    fn update_metrics(&self) {
         self.metrics.blocks.set(self.blocks.len() as f64);
+        self.memory.set_usage(self.size);
     }
 
     /// Remove block entry
@@ -154,6 +195,7 @@ impl BlockBuffer {
     /// been removed.
     fn remove_block(&mut self, hash: &BlockHash) -> Option<SealedBlockWithSenders> {
         let block = self.blocks.remove(hash)?;
+        self.size -= block.size();
         self.remove_from_earliest_blocks(block.number, hash);
         self.remove_from_parent(block.parent_hash, hash);
         self.lru.remove(hash);
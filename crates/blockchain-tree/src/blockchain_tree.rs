@@ -4,7 +4,7 @@ use crate::{
     externals::TreeNodeTypes,
     metrics::{MakeCanonicalAction, MakeCanonicalDurationsRecorder, TreeMetrics},
     state::{SidechainId, TreeState},
-    AppendableChain, BlockIndices, BlockchainTreeConfig, ExecutionData, TreeExternals,
+    AppendableChain, BlockBuffer, BlockIndices, BlockchainTreeConfig, ExecutionData, TreeExternals,
 };
 use alloy_eips::{BlockNumHash, ForkBlock};
 use alloy_primitives::{BlockHash, BlockNumber, B256, U256};
@@ -136,7 +136,10 @@ where
             state: TreeState::new(
                 last_finalized_block_number,
                 last_canonical_hashes,
-                config.max_unconnected_blocks(),
+                BlockBuffer::with_memory_budget(
+                    config.max_unconnected_blocks(),
+                    config.memory_budget(),
+                ),
             ),
             config,
             canon_state_notification_sender,
@@ -2402,7 +2405,8 @@ mod tests {
 
         // make tree
         let config = BlockchainTreeConfig::new(1, 2, 3, 2);
-        let mut tree = BlockchainTree::new(externals, config).expect("failed to create tree");
+        let mut tree =
+            BlockchainTree::new(externals, config.clone()).expect("failed to create tree");
 
         assert_eq!(
             tree.insert_block(block1.clone(), BlockValidationKind::Exhaustive).unwrap(),
@@ -2423,7 +2427,7 @@ mod tests {
 
         // restart
         let mut tree =
-            BlockchainTree::new(cloned_externals_1, config).expect("failed to create tree");
+            BlockchainTree::new(cloned_externals_1, config.clone()).expect("failed to create tree");
         assert_eq!(tree.block_indices().last_finalized_block(), 0);
 
         let mut block1a = block1;
@@ -1,7 +1,9 @@
 //! Blockchain tree configuration
 
+use reth_memory_budget::MemoryBudget;
+
 /// The configuration for the blockchain tree.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct BlockchainTreeConfig {
     /// Number of blocks after the last finalized block that we are storing.
     ///
@@ -19,6 +21,8 @@ pub struct BlockchainTreeConfig {
     /// be 256. It covers both number of blocks required for reorg, and number of blocks
     /// required for `BLOCKHASH` EVM opcode.
     num_of_additional_canonical_block_hashes: u64,
+    /// The memory budget the buffer of unconnected blocks registers with.
+    memory_budget: MemoryBudget,
 }
 
 impl Default for BlockchainTreeConfig {
@@ -33,6 +37,7 @@ impl Default for BlockchainTreeConfig {
             num_of_additional_canonical_block_hashes: 256,
             // max unconnected blocks.
             max_unconnected_blocks: 200,
+            memory_budget: Default::default(),
         }
     }
 }
@@ -54,9 +59,16 @@ impl BlockchainTreeConfig {
             max_reorg_depth,
             num_of_additional_canonical_block_hashes,
             max_unconnected_blocks,
+            memory_budget: Default::default(),
         }
     }
 
+    /// Sets the memory budget the buffer of unconnected blocks registers with.
+    pub fn with_memory_budget(mut self, memory_budget: MemoryBudget) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
+
     /// Return the maximum reorg depth.
     pub const fn max_reorg_depth(&self) -> u64 {
         self.max_reorg_depth
@@ -88,4 +100,9 @@ impl BlockchainTreeConfig {
     pub const fn max_unconnected_blocks(&self) -> u32 {
         self.max_unconnected_blocks
     }
+
+    /// Return the memory budget the buffer of unconnected blocks registers with.
+    pub const fn memory_budget(&self) -> &MemoryBudget {
+        &self.memory_budget
+    }
 }
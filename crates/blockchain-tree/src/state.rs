@@ -22,12 +22,12 @@ pub(crate) struct TreeState {
 }
 
 impl TreeState {
-    /// Initializes the tree state with the given last finalized block number and last canonical
-    /// hashes.
+    /// Initializes the tree state with the given last finalized block number, last canonical
+    /// hashes and buffer for unconnected blocks.
     pub(crate) fn new(
         last_finalized_block_number: BlockNumber,
         last_canonical_hashes: impl IntoIterator<Item = (BlockNumber, BlockHash)>,
-        buffer_limit: u32,
+        buffered_blocks: BlockBuffer,
     ) -> Self {
         Self {
             block_chain_id_generator: 0,
@@ -36,7 +36,7 @@ impl TreeState {
                 last_finalized_block_number,
                 BTreeMap::from_iter(last_canonical_hashes),
             ),
-            buffered_blocks: BlockBuffer::new(buffer_limit),
+            buffered_blocks,
         }
     }
 
@@ -149,7 +149,7 @@ mod tests {
         let tree_state = TreeState::new(
             last_finalized_block_number,
             last_canonical_hashes.clone(),
-            buffer_limit,
+            BlockBuffer::new(buffer_limit),
         );
 
         // Verify the tree state after initialization
@@ -166,7 +166,7 @@ mod tests {
     #[test]
     fn test_tree_state_next_id() {
         // Initialize the tree state
-        let mut tree_state = TreeState::new(0, vec![], 5);
+        let mut tree_state = TreeState::new(0, vec![], BlockBuffer::new(5));
 
         // Generate a few sidechain IDs
         let first_id = tree_state.next_id();
@@ -181,7 +181,7 @@ mod tests {
     #[test]
     fn test_tree_state_insert_chain() {
         // Initialize tree state
-        let mut tree_state = TreeState::new(0, vec![], 5);
+        let mut tree_state = TreeState::new(0, vec![], BlockBuffer::new(5));
 
         // Create a chain with two blocks
         let block: SealedBlockWithSenders = Default::default();
@@ -248,7 +248,7 @@ mod tests {
     #[test]
     fn test_block_by_hash_side_chain() {
         // Initialize a tree state with some dummy data
-        let mut tree_state = TreeState::new(0, vec![], 5);
+        let mut tree_state = TreeState::new(0, vec![], BlockBuffer::new(5));
 
         // Create two side-chain blocks with random hashes
         let block1_hash = B256::random();
@@ -290,7 +290,7 @@ mod tests {
     #[test]
     fn test_block_with_senders_by_hash() {
         // Initialize a tree state with some dummy data
-        let mut tree_state = TreeState::new(0, vec![], 5);
+        let mut tree_state = TreeState::new(0, vec![], BlockBuffer::new(5));
 
         // Create two side-chain blocks with random hashes
         let block1_hash = B256::random();
@@ -332,7 +332,7 @@ mod tests {
     #[test]
     fn test_get_buffered_block() {
         // Initialize a tree state with some dummy data
-        let mut tree_state = TreeState::new(0, vec![], 5);
+        let mut tree_state = TreeState::new(0, vec![], BlockBuffer::new(5));
 
         // Create a block with a random hash and add it to the buffer
         let block_hash = B256::random();
@@ -357,7 +357,7 @@ mod tests {
     #[test]
     fn test_lowest_buffered_ancestor() {
         // Initialize a tree state with some dummy data
-        let mut tree_state = TreeState::new(0, vec![], 5);
+        let mut tree_state = TreeState::new(0, vec![], BlockBuffer::new(5));
 
         // Create blocks with random hashes and set up parent-child relationships
         let ancestor_hash = B256::random();
@@ -390,7 +390,7 @@ mod tests {
     #[test]
     fn test_receipts_by_block_hash() {
         // Initialize a tree state with some dummy data
-        let mut tree_state = TreeState::new(0, vec![], 5);
+        let mut tree_state = TreeState::new(0, vec![], BlockBuffer::new(5));
 
         // Create a block with a random hash and receipts
         let block_hash = B256::random();
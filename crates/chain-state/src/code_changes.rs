@@ -0,0 +1,49 @@
+//! Code change stream.
+
+use crate::CanonStateNotificationStream;
+use reth_execution_types::CodeChange;
+use reth_primitives::NodePrimitives;
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use tokio_stream::Stream;
+
+/// A Stream of the code changes of the canonical chain.
+///
+/// Every item contains the code changes of a single
+/// [`CanonStateNotification`](crate::CanonStateNotification), see
+/// [`CanonStateNotification::code_changes`](crate::CanonStateNotification::code_changes).
+/// Notifications without code changes are skipped.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct CodeChangeStream<N: NodePrimitives = reth_primitives::EthPrimitives> {
+    #[pin]
+    st: CanonStateNotificationStream<N>,
+}
+
+impl<N: NodePrimitives> CodeChangeStream<N> {
+    /// Creates a new `CodeChangeStream`
+    pub const fn new(st: CanonStateNotificationStream<N>) -> Self {
+        Self { st }
+    }
+}
+
+impl<N: NodePrimitives> Stream for CodeChangeStream<N> {
+    type Item = Vec<(CodeChange, bool)>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match ready!(self.as_mut().project().st.poll_next(cx)) {
+                Some(notification) => {
+                    let changes = notification.code_changes();
+                    if changes.is_empty() {
+                        continue
+                    }
+                    return Poll::Ready(Some(changes))
+                }
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
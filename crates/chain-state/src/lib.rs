@@ -21,6 +21,9 @@ pub use notifications::{
     ForkChoiceSubscriptions,
 };
 
+mod code_changes;
+pub use code_changes::CodeChangeStream;
+
 mod memory_overlay;
 pub use memory_overlay::{MemoryOverlayStateProvider, MemoryOverlayStateProviderRef};
 
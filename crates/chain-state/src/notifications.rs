@@ -1,8 +1,9 @@
 //! Canonical chain state notification trait and types.
 
+use crate::CodeChangeStream;
 use alloy_eips::eip2718::Encodable2718;
 use derive_more::{Deref, DerefMut};
-use reth_execution_types::{BlockReceipts, Chain};
+use reth_execution_types::{BlockReceipts, Chain, CodeChange};
 use reth_primitives::{NodePrimitives, SealedBlockWithSenders, SealedHeader};
 use reth_storage_api::NodePrimitivesProvider;
 use std::{
@@ -38,6 +39,12 @@ pub trait CanonStateSubscriptions: NodePrimitivesProvider + Send + Sync {
             st: BroadcastStream::new(self.subscribe_to_canonical_state()),
         }
     }
+
+    /// Convenience method to get a stream of the code changes of the canonical chain, see
+    /// [`CanonStateNotification::code_changes`].
+    fn code_change_stream(&self) -> CodeChangeStream<Self::Primitives> {
+        CodeChangeStream::new(self.canonical_state_stream())
+    }
 }
 
 impl<T: CanonStateSubscriptions> CanonStateSubscriptions for &T {
@@ -151,6 +158,31 @@ impl<N: NodePrimitives> CanonStateNotification<N> {
         );
         receipts
     }
+
+    /// Get the code changes in the reverted and newly imported chain segments, see
+    /// [`CodeChange`].
+    ///
+    /// The boolean in the tuple (2nd element) denotes whether the code change was from the reverted
+    /// chain segment.
+    pub fn code_changes(&self) -> Vec<(CodeChange, bool)> {
+        let mut changes = Vec::new();
+
+        // get old code changes
+        if let Some(old) = self.reverted() {
+            changes.extend(
+                old.execution_outcome().code_changes().into_iter().map(|change| (change, true)),
+            );
+        }
+        // get new code changes
+        changes.extend(
+            self.committed()
+                .execution_outcome()
+                .code_changes()
+                .into_iter()
+                .map(|change| (change, false)),
+        );
+        changes
+    }
 }
 
 /// Wrapper around a broadcast receiver that receives fork choice notifications.
@@ -126,7 +126,9 @@ impl<C: ChainSpecParser> EnvironmentArgs<C> {
             self.chain.clone(),
             static_file_provider,
         )
-        .with_prune_modes(prune_modes.clone());
+        .with_prune_modes(prune_modes.clone())
+        .with_touch_epoch_length(config.stages.execution.touch_epoch_length)
+        .with_max_unindexed_history_blocks(config.stages.execution.max_unindexed_history_blocks);
 
         // Check for consistency between database and static files.
         if let Some(unwind_target) = factory
@@ -0,0 +1,95 @@
+use super::get::{maybe_json_value_parser, table_key, table_subkey};
+use clap::Parser;
+use reth_db::{RawDupSort, RawKey, TableViewer, Tables};
+use reth_db_api::{
+    cursor::{DbCursorRW, DbDupCursorRO},
+    database::Database,
+    table::{DupSort, Table},
+    transaction::{DbTx, DbTxMut},
+};
+use reth_node_builder::NodeTypesWithDB;
+use reth_provider::ProviderFactory;
+use tracing::{error, info};
+
+/// The arguments for the `reth db delete` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The table name
+    table: Tables,
+
+    /// The key to delete
+    #[arg(value_parser = maybe_json_value_parser)]
+    key: String,
+
+    /// The subkey to delete. If omitted, all duplicates of the key are deleted from `DupSort`
+    /// tables.
+    #[arg(value_parser = maybe_json_value_parser)]
+    subkey: Option<String>,
+
+    /// Confirms writing to the database directly, bypassing all consistency checks of the node
+    #[arg(long = "i-know-what-i-am-doing")]
+    pub(crate) i_know_what_i_am_doing: bool,
+}
+
+impl Command {
+    /// Execute `db delete` command
+    pub fn execute<N: NodeTypesWithDB>(
+        self,
+        provider_factory: ProviderFactory<N>,
+    ) -> eyre::Result<()> {
+        self.table.view(&DeleteViewer { db: provider_factory.db_ref(), args: &self })
+    }
+}
+
+struct DeleteViewer<'a, DB: Database> {
+    db: &'a DB,
+    args: &'a Command,
+}
+
+impl<DB: Database> DeleteViewer<'_, DB> {
+    fn report(&self, table: &str, deleted: bool) {
+        if deleted {
+            info!(target: "reth::cli", table, key = %self.args.key, subkey = ?self.args.subkey, "Deleted entry");
+        } else {
+            error!(target: "reth::cli", "No content for the given table key.");
+        }
+    }
+}
+
+impl<DB: Database> TableViewer<()> for DeleteViewer<'_, DB> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(), Self::Error> {
+        let key = table_key::<T>(&self.args.key)?;
+
+        let tx = self.db.tx_mut()?;
+        let deleted = tx.delete::<T>(key, None)?;
+        tx.commit()?;
+
+        self.report(T::NAME, deleted);
+        Ok(())
+    }
+
+    fn view_dupsort<T: DupSort>(&self) -> Result<(), Self::Error> {
+        let Some(subkey) = self.args.subkey.as_deref() else { return self.view::<T>() };
+        let key = table_key::<T>(&self.args.key)?;
+        let subkey = RawKey::new(table_subkey::<T>(Some(subkey))?);
+
+        let tx = self.db.tx_mut()?;
+        let mut cursor = tx.cursor_dup_write::<RawDupSort<T>>()?;
+        // the seek returns the first duplicate with a subkey greater or equal to the given one,
+        // values of `DupSort` tables are prefixed with their encoded subkey
+        let deleted = match cursor.seek_by_key_subkey(RawKey::new(key), subkey.clone())? {
+            Some(value) if value.raw_value().starts_with(subkey.raw_key()) => {
+                cursor.delete_current()?;
+                true
+            }
+            _ => false,
+        };
+        drop(cursor);
+        tx.commit()?;
+
+        self.report(T::NAME, deleted);
+        Ok(())
+    }
+}
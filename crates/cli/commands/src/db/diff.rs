@@ -1,18 +1,36 @@
+use crate::common::CliNodeTypes;
+use alloy_consensus::Header;
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{BlockNumber, Bloom, B256};
 use clap::Parser;
+use jsonrpsee::{
+    core::client::ClientT,
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
 use reth_db::{open_db_read_only, tables_to_generic, DatabaseEnv, Tables};
 use reth_db_api::{cursor::DbCursorRO, database::Database, table::Table, transaction::DbTx};
 use reth_db_common::DbTool;
-use reth_node_builder::{NodeTypesWithDBAdapter, NodeTypesWithEngine};
+use reth_node_builder::NodeTypesWithDBAdapter;
 use reth_node_core::{
     args::DatabaseArgs,
     dirs::{DataDirPath, PlatformPath},
 };
+use reth_primitives::{
+    proofs::{calculate_receipt_root_no_memo, calculate_transaction_root},
+    Receipt, TransactionSigned,
+};
+use reth_provider::{
+    providers::StaticFileProvider, BlockHashReader, HeaderProvider, ProviderFactory,
+    ReceiptProvider, TransactionsProvider,
+};
 use std::{
     collections::HashMap,
-    fmt::Debug,
+    fmt::{self, Debug},
     fs::{self, File},
     hash::Hash,
     io::Write,
+    ops::RangeInclusive,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -22,17 +40,40 @@ use tracing::{info, warn};
 /// The arguments for the `reth db diff` command
 pub struct Command {
     /// The path to the data dir for all reth files and subdirectories.
-    #[arg(long, verbatim_doc_comment)]
-    secondary_datadir: PlatformPath<DataDirPath>,
+    #[arg(long, verbatim_doc_comment, required_unless_present = "rpc_url")]
+    secondary_datadir: Option<PlatformPath<DataDirPath>>,
+
+    /// The URL of a remote node RPC to compare the block range against, instead of a second
+    /// datadir.
+    ///
+    /// Requires `--from` and `--to`.
+    #[arg(
+        long,
+        value_name = "URL",
+        verbatim_doc_comment,
+        conflicts_with = "secondary_datadir",
+        requires_all = ["from", "to"]
+    )]
+    rpc_url: Option<String>,
 
     /// Arguments for the second database
     #[command(flatten)]
     second_db: DatabaseArgs,
 
     /// The table name to diff. If not specified, all tables are diffed.
+    ///
+    /// If a block range is set, tables are only diffed if this is set.
     #[arg(long, verbatim_doc_comment)]
     table: Option<Tables>,
 
+    /// The first block of the range to compare block hashes and roots for.
+    #[arg(long, value_name = "BLOCK", verbatim_doc_comment, requires = "to")]
+    from: Option<BlockNumber>,
+
+    /// The last block of the range to compare block hashes and roots for, inclusive.
+    #[arg(long, value_name = "BLOCK", verbatim_doc_comment, requires = "from")]
+    to: Option<BlockNumber>,
+
     /// The output directory for the diff report.
     #[arg(long, verbatim_doc_comment)]
     output: PlatformPath<PathBuf>,
@@ -41,8 +82,14 @@ pub struct Command {
 impl Command {
     /// Execute the `db diff` command.
     ///
-    /// This first opens the `db/` folder from the secondary datadir, where the second database is
-    /// opened read-only.
+    /// If a block range is set, the hash, state root, transactions root and receipts root of every
+    /// canonical block in the range are compared between the primary database and either the
+    /// secondary datadir or the remote RPC. The transactions and receipts roots are additionally
+    /// recomputed from the stored data of each local database, so corrupted bodies or receipts
+    /// are reported even if the headers agree.
+    ///
+    /// For the table diff, this first opens the `db/` folder from the secondary datadir, where the
+    /// second database is opened read-only.
     ///
     /// The tool will then iterate through all key-value pairs for the primary and secondary
     /// databases. The value for each key will be compared with its corresponding value in the
@@ -52,17 +99,44 @@ impl Command {
     ///
     /// The discrepancies and extra elements, along with a brief summary of the diff results are
     /// then written to a file in the output directory.
-    pub fn execute<T: NodeTypesWithEngine>(
+    pub async fn execute<N: CliNodeTypes>(
         self,
-        tool: &DbTool<NodeTypesWithDBAdapter<T, Arc<DatabaseEnv>>>,
+        tool: &DbTool<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>,
     ) -> eyre::Result<()> {
         warn!("Make sure the node is not running when running `reth db diff`!");
+
+        let block_range = self.from.zip(self.to).map(|(from, to)| from..=to);
+        if let Some(range) = &block_range {
+            eyre::ensure!(!range.is_empty(), "Invalid block range {range:?}");
+        }
+
+        let Some(secondary_datadir) = &self.secondary_datadir else {
+            // `--rpc-url` conflicts with `--secondary-datadir` and requires a block range
+            let rpc_url = self.rpc_url.as_deref().expect("secondary datadir or rpc url is set");
+            let range = block_range.expect("block range is set with rpc url");
+            let client = HttpClientBuilder::default().build(rpc_url)?;
+            return diff_blocks(tool, &BlockSource::Rpc(client), range, &self.output).await
+        };
+
         // open second db
-        let second_db_path: PathBuf = self.secondary_datadir.join("db").into();
-        let second_db = open_db_read_only(&second_db_path, self.second_db.database_args())?;
+        let second_db_path: PathBuf = secondary_datadir.join("db").into();
+        let second_db =
+            Arc::new(open_db_read_only(&second_db_path, self.second_db.database_args())?);
+
+        if let Some(range) = block_range.clone() {
+            let second_static_files_path: PathBuf = secondary_datadir.join("static_files").into();
+            let second_factory = ProviderFactory::<NodeTypesWithDBAdapter<N, _>>::new(
+                second_db.clone(),
+                tool.chain(),
+                StaticFileProvider::read_only(second_static_files_path, false)?,
+            );
+            diff_blocks(tool, &BlockSource::Datadir(second_factory), range, &self.output).await?;
+        }
 
         let tables = match &self.table {
             Some(table) => std::slice::from_ref(table),
+            // Only diff tables that were asked for explicitly if a block range is set
+            None if block_range.is_some() => &[],
             None => Tables::ALL,
         };
 
@@ -87,6 +161,256 @@ impl Command {
     }
 }
 
+/// The source the blocks of the primary database are compared against.
+enum BlockSource<N: CliNodeTypes> {
+    /// A second datadir, opened read-only.
+    Datadir(ProviderFactory<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>),
+    /// A remote node RPC.
+    Rpc(HttpClient),
+}
+
+impl<N: CliNodeTypes> BlockSource<N> {
+    /// Name of the source used in the report.
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Datadir(_) => "secondary",
+            Self::Rpc(_) => "rpc",
+        }
+    }
+
+    /// Returns the [`BlockSummary`] of the canonical block with the given number, together with
+    /// the fields that don't match the data stored in the source, if it's a datadir.
+    async fn block(
+        &self,
+        number: BlockNumber,
+    ) -> eyre::Result<Option<(BlockSummary, Vec<&'static str>)>> {
+        match self {
+            Self::Datadir(factory) => read_block(&factory.provider()?, number),
+            Self::Rpc(client) => {
+                let block: Option<alloy_rpc_types_eth::Block> = client
+                    .request(
+                        "eth_getBlockByNumber",
+                        rpc_params![BlockNumberOrTag::Number(number), false],
+                    )
+                    .await?;
+                Ok(block.map(|block| {
+                    (BlockSummary::new(block.header.hash, &block.header.inner), Vec::new())
+                }))
+            }
+        }
+    }
+}
+
+/// Compares the blocks in the range between the primary database and the other source, then
+/// writes the result to `blocks.txt` in the output directory.
+async fn diff_blocks<N: CliNodeTypes>(
+    tool: &DbTool<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>,
+    source: &BlockSource<N>,
+    range: RangeInclusive<BlockNumber>,
+    output_dir: impl AsRef<Path>,
+) -> eyre::Result<()> {
+    info!(?range, source = source.name(), "Analyzing blocks...");
+
+    let provider = tool.provider_factory.provider()?;
+    let mut divergences = Vec::new();
+    for number in range.clone() {
+        let primary = read_block(&provider, number)?;
+        let other = source.block(number).await?;
+
+        if let Some((_, fields)) = primary.as_ref().filter(|(_, fields)| !fields.is_empty()) {
+            divergences.push(BlockDivergence::Inconsistent {
+                number,
+                source: "primary",
+                fields: fields.clone(),
+            });
+        }
+        if let Some((_, fields)) = other.as_ref().filter(|(_, fields)| !fields.is_empty()) {
+            divergences.push(BlockDivergence::Inconsistent {
+                number,
+                source: source.name(),
+                fields: fields.clone(),
+            });
+        }
+
+        match (primary, other) {
+            (Some((first, _)), Some((second, _))) => {
+                let fields = first.diff(&second);
+                if !fields.is_empty() {
+                    divergences.push(BlockDivergence::Fields { number, fields, first, second });
+                }
+            }
+            (Some(_), None) => {
+                divergences.push(BlockDivergence::Missing { number, source: source.name() })
+            }
+            (None, Some(_)) => {
+                divergences.push(BlockDivergence::Missing { number, source: "primary" })
+            }
+            (None, None) => {}
+        }
+    }
+    info!("Done analyzing blocks!");
+
+    fs::create_dir_all(output_dir.as_ref())?;
+    let file_name = output_dir.as_ref().join("blocks.txt");
+    let mut file = File::create(&file_name)?;
+
+    writeln!(file, "Block diff results for {range:?} against {}", source.name())?;
+    if let Some(first) = divergences.first() {
+        writeln!(file, "Found {} divergences", divergences.len())?;
+        writeln!(file, "First divergent block: {}", first.number())?;
+        info!(
+            divergences = divergences.len(),
+            first_divergent_block = first.number(),
+            "Found block divergences"
+        );
+    } else {
+        writeln!(file, "No divergences found")?;
+        info!("No block divergences found");
+    }
+
+    for divergence in &divergences {
+        writeln!(file, "{divergence}")?;
+    }
+
+    info!("Done writing block diff results to {}", file_name.display());
+    Ok(())
+}
+
+/// Reads the canonical block with the given number from the provider and recomputes its hash,
+/// transactions root and receipts root from the stored data.
+///
+/// Returns the [`BlockSummary`] of the stored header, together with the names of the fields that
+/// don't match the recomputed values.
+fn read_block<P>(
+    provider: &P,
+    number: BlockNumber,
+) -> eyre::Result<Option<(BlockSummary, Vec<&'static str>)>>
+where
+    P: BlockHashReader
+        + HeaderProvider<Header = Header>
+        + TransactionsProvider<Transaction = TransactionSigned>
+        + ReceiptProvider<Receipt = Receipt>,
+{
+    let (Some(hash), Some(header)) =
+        (provider.block_hash(number)?, provider.header_by_number(number)?)
+    else {
+        return Ok(None)
+    };
+
+    let mut mismatches = Vec::new();
+    if header.hash_slow() != hash {
+        mismatches.push("hash");
+    }
+    if let Some(transactions) = provider.transactions_by_block(number.into())? {
+        if calculate_transaction_root(&transactions) != header.transactions_root {
+            mismatches.push("transactions_root");
+        }
+
+        // Receipts may be pruned, in which case they can't be verified
+        if let Some(receipts) = provider
+            .receipts_by_block(number.into())?
+            .filter(|receipts| receipts.len() == transactions.len())
+        {
+            let receipts = receipts.iter().collect::<Vec<_>>();
+            if calculate_receipt_root_no_memo(&receipts) != header.receipts_root {
+                mismatches.push("receipts_root");
+            }
+        }
+    }
+
+    Ok(Some((BlockSummary::new(hash, &header), mismatches)))
+}
+
+/// The block data that is compared between two sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BlockSummary {
+    hash: B256,
+    parent_hash: B256,
+    state_root: B256,
+    transactions_root: B256,
+    receipts_root: B256,
+    logs_bloom: Bloom,
+    gas_used: u64,
+}
+
+impl BlockSummary {
+    /// Creates a summary of the block with the given hash and header.
+    const fn new(hash: B256, header: &Header) -> Self {
+        Self {
+            hash,
+            parent_hash: header.parent_hash,
+            state_root: header.state_root,
+            transactions_root: header.transactions_root,
+            receipts_root: header.receipts_root,
+            logs_bloom: header.logs_bloom,
+            gas_used: header.gas_used,
+        }
+    }
+
+    /// Returns the names of the fields that differ from the other summary.
+    fn diff(&self, other: &Self) -> Vec<&'static str> {
+        [
+            ("hash", self.hash != other.hash),
+            ("parent_hash", self.parent_hash != other.parent_hash),
+            ("state_root", self.state_root != other.state_root),
+            ("transactions_root", self.transactions_root != other.transactions_root),
+            ("receipts_root", self.receipts_root != other.receipts_root),
+            ("logs_bloom", self.logs_bloom != other.logs_bloom),
+            ("gas_used", self.gas_used != other.gas_used),
+        ]
+        .into_iter()
+        .filter_map(|(field, differs)| differs.then_some(field))
+        .collect()
+    }
+}
+
+/// A block that differs between the two sources, or whose stored data is inconsistent.
+#[derive(Debug)]
+enum BlockDivergence {
+    /// The block differs between the primary database and the other source
+    Fields {
+        number: BlockNumber,
+        fields: Vec<&'static str>,
+        first: BlockSummary,
+        second: BlockSummary,
+    },
+
+    /// The block is missing from the source
+    Missing { number: BlockNumber, source: &'static str },
+
+    /// The stored data of the source doesn't match the block header
+    Inconsistent { number: BlockNumber, source: &'static str, fields: Vec<&'static str> },
+}
+
+impl BlockDivergence {
+    /// Return the number of the divergent block
+    const fn number(&self) -> BlockNumber {
+        match self {
+            Self::Fields { number, .. } |
+            Self::Missing { number, .. } |
+            Self::Inconsistent { number, .. } => *number,
+        }
+    }
+}
+
+impl fmt::Display for BlockDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fields { number, fields, first, second } => write!(
+                f,
+                "Block {number}: {} differ, primary: {first:?}, other: {second:?}",
+                fields.join(", ")
+            ),
+            Self::Missing { number, source } => write!(f, "Block {number}: missing from {source}"),
+            Self::Inconsistent { number, source, fields } => write!(
+                f,
+                "Block {number}: stored {} of {source} don't match the header",
+                fields.join(", ")
+            ),
+        }
+    }
+}
+
 /// Find diffs for a table, then analyzing the result
 fn find_diffs<T: Table>(
     primary_tx: impl DbTx,
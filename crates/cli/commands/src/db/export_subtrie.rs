@@ -0,0 +1,82 @@
+use alloy_primitives::Address;
+use clap::Parser;
+use reth_db::tables;
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    transaction::DbTx,
+};
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockNumReader, DBProvider, HeaderProvider,
+    LatestStateProviderRef, ProviderError, ProviderFactory, StateProofProvider, StateProvider,
+};
+use reth_trie::{SubtrieExport, TrieInput};
+use std::{fs::File, io::BufWriter, path::PathBuf};
+use tracing::info;
+
+/// The arguments for the `reth db export-subtrie` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The first account to export.
+    #[arg(long, default_value_t = Address::ZERO)]
+    from: Address,
+
+    /// The maximum number of accounts to export.
+    #[arg(long, default_value_t = 1000)]
+    limit: usize,
+
+    /// The file to write the accounts and their proofs to.
+    #[arg(long, value_name = "FILE")]
+    output: PathBuf,
+}
+
+impl Command {
+    /// Execute `db export-subtrie` command
+    pub fn execute<N: ProviderNodeTypes>(
+        self,
+        provider_factory: ProviderFactory<N>,
+    ) -> eyre::Result<()> {
+        let provider = provider_factory.provider()?;
+        let block_number = provider.best_block_number()?;
+        let state_root = provider
+            .header_by_number(block_number)?
+            .ok_or(ProviderError::HeaderNotFound(block_number.into()))?
+            .state_root;
+        let state = LatestStateProviderRef::new(&provider);
+
+        let mut export = SubtrieExport { block_number, state_root, ..Default::default() };
+        let mut accounts = provider.tx_ref().cursor_read::<tables::PlainAccountState>()?;
+        let mut storage = provider.tx_ref().cursor_dup_read::<tables::PlainStorageState>()?;
+        for entry in accounts.walk(Some(self.from))?.take(self.limit) {
+            let (address, account) = entry?;
+
+            let slots = storage
+                .walk_dup(Some(address), None)?
+                .map(|entry| entry.map(|(_, entry)| entry.key))
+                .collect::<Result<Vec<_>, _>>()?;
+            export.accounts.push(state.proof(TrieInput::default(), address, &slots)?);
+
+            if let Some(code_hash) = account.bytecode_hash {
+                let bytecode = state
+                    .bytecode_by_hash(code_hash)?
+                    .ok_or_else(|| eyre::eyre!("bytecode {code_hash} of {address} not found"))?;
+                export.bytecodes.push(bytecode.original_bytes());
+            }
+        }
+
+        // don't hand out state that doesn't match the state root of this node
+        export.verify(state_root)?;
+
+        let file = BufWriter::new(File::create(&self.output)?);
+        serde_json::to_writer(file, &export)?;
+
+        info!(
+            target: "reth::cli",
+            block_number,
+            accounts = export.accounts.len(),
+            path = %self.output.display(),
+            "Exported subtrie"
+        );
+
+        Ok(())
+    }
+}
@@ -32,7 +32,8 @@ enum Subcommand {
         #[arg(value_parser = maybe_json_value_parser)]
         key: String,
 
-        /// The subkey to get content for
+        /// The subkey to get content for. If omitted, all duplicates of the key are returned for
+        /// `DupSort` tables.
         #[arg(value_parser = maybe_json_value_parser)]
         subkey: Option<String>,
 
@@ -133,7 +134,7 @@ pub(crate) fn table_key<T: Table>(key: &str) -> Result<T::Key, eyre::Error> {
 }
 
 /// Get an instance of subkey for given dupsort table
-fn table_subkey<T: DupSort>(subkey: Option<&str>) -> Result<T::SubKey, eyre::Error> {
+pub(crate) fn table_subkey<T: DupSort>(subkey: Option<&str>) -> Result<T::SubKey, eyre::Error> {
     serde_json::from_str(subkey.unwrap_or_default()).map_err(|e| eyre::eyre!(e))
 }
 
@@ -174,6 +175,17 @@ impl<N: ProviderNodeTypes> TableViewer<()> for GetValueViewer<'_, N> {
         // get a key for given table
         let key = table_key::<T>(&self.key)?;
 
+        // without a subkey, print all duplicates of the key
+        if self.subkey.is_none() {
+            let content = self.tool.get_dups::<T>(key)?;
+            if content.is_empty() {
+                error!(target: "reth::cli", "No content for the given table key.");
+            } else {
+                println!("{}", serde_json::to_string_pretty(&content)?);
+            }
+            return Ok(())
+        }
+
         // process dupsort table
         let subkey = table_subkey::<T>(self.subkey.as_deref())?;
 
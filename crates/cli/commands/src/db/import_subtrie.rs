@@ -0,0 +1,104 @@
+use alloy_primitives::keccak256;
+use clap::Parser;
+use reth_db::tables;
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW, DbDupCursorRW},
+    transaction::DbTxMut,
+};
+use reth_primitives::{Bytecode, StorageEntry};
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockNumReader, DBProvider, HeaderProvider, ProviderError,
+    ProviderFactory,
+};
+use reth_trie::SubtrieExport;
+use std::{fs::File, io::BufReader, path::PathBuf};
+use tracing::info;
+
+/// The arguments for the `reth db import-subtrie` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The file written by `reth db export-subtrie`.
+    #[arg(value_name = "FILE")]
+    input: PathBuf,
+}
+
+impl Command {
+    /// Execute `db import-subtrie` command
+    ///
+    /// The exported state is verified against the state root of the local head block, which has
+    /// to be the block the state was exported at. The plain and hashed state of the exported
+    /// accounts is replaced, the trie tables are left untouched.
+    pub fn execute<N: ProviderNodeTypes>(
+        self,
+        provider_factory: ProviderFactory<N>,
+    ) -> eyre::Result<()> {
+        let export: SubtrieExport =
+            serde_json::from_reader(BufReader::new(File::open(&self.input)?))?;
+
+        let provider_rw = provider_factory.provider_rw()?;
+        let block_number = provider_rw.best_block_number()?;
+        eyre::ensure!(
+            block_number == export.block_number,
+            "state was exported at block {}, but the local head is block {block_number}",
+            export.block_number
+        );
+        let state_root = provider_rw
+            .header_by_number(block_number)?
+            .ok_or(ProviderError::HeaderNotFound(block_number.into()))?
+            .state_root;
+        export.verify(state_root)?;
+
+        let tx = provider_rw.tx_ref();
+        let mut plain_accounts = tx.cursor_write::<tables::PlainAccountState>()?;
+        let mut hashed_accounts = tx.cursor_write::<tables::HashedAccounts>()?;
+        let mut plain_storage = tx.cursor_dup_write::<tables::PlainStorageState>()?;
+        let mut hashed_storage = tx.cursor_dup_write::<tables::HashedStorages>()?;
+        for account in &export.accounts {
+            let address = account.address;
+            let hashed_address = keccak256(address);
+
+            if plain_storage.seek_exact(address)?.is_some() {
+                plain_storage.delete_current_duplicates()?;
+            }
+            if hashed_storage.seek_exact(hashed_address)?.is_some() {
+                hashed_storage.delete_current_duplicates()?;
+            }
+
+            let Some(info) = account.info else {
+                if plain_accounts.seek_exact(address)?.is_some() {
+                    plain_accounts.delete_current()?;
+                }
+                if hashed_accounts.seek_exact(hashed_address)?.is_some() {
+                    hashed_accounts.delete_current()?;
+                }
+                continue
+            };
+            plain_accounts.upsert(address, info)?;
+            hashed_accounts.upsert(hashed_address, info)?;
+
+            for proof in account.storage_proofs.iter().filter(|proof| !proof.value.is_zero()) {
+                plain_storage
+                    .upsert(address, StorageEntry { key: proof.key, value: proof.value })?;
+                hashed_storage.upsert(
+                    hashed_address,
+                    StorageEntry { key: keccak256(proof.key), value: proof.value },
+                )?;
+            }
+        }
+
+        for bytecode in &export.bytecodes {
+            tx.put::<tables::Bytecodes>(keccak256(bytecode), Bytecode::new_raw(bytecode.clone()))?;
+        }
+
+        provider_rw.commit()?;
+
+        info!(
+            target: "reth::cli",
+            block_number,
+            accounts = export.accounts.len(),
+            "Imported subtrie"
+        );
+
+        Ok(())
+    }
+}
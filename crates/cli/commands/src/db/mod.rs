@@ -8,9 +8,16 @@ use std::io::{self, Write};
 
 mod checksum;
 mod clear;
+mod delete;
 mod diff;
+mod export_subtrie;
 mod get;
+mod import_subtrie;
 mod list;
+mod pool_snapshot;
+mod put;
+mod query;
+mod rebuild_log_index;
 mod stats;
 /// DB List TUI
 mod tui;
@@ -34,10 +41,17 @@ pub enum Subcommands {
     List(list::Command),
     /// Calculates the content checksum of a table
     Checksum(checksum::Command),
-    /// Create a diff between two database tables or two entire databases.
+    /// Create a diff between two database tables or two entire databases, or compare a block
+    /// range against another datadir or a remote RPC.
     Diff(diff::Command),
     /// Gets the content of a table for the given key
     Get(get::Command),
+    /// Writes a raw value for the given key to a table
+    Put(put::Command),
+    /// Deletes the entry of a table for the given key
+    Delete(delete::Command),
+    /// Runs a read-only SQL query over the block, transaction, receipt and log static files
+    Query(query::Command),
     /// Deletes all database entries
     Drop {
         /// Bypasses the interactive confirmation and drops the database directly
@@ -46,10 +60,18 @@ pub enum Subcommands {
     },
     /// Deletes all table entries
     Clear(clear::Command),
+    /// Rebuilds the log index used by `eth_getLogs`
+    RebuildLogIndex(rebuild_log_index::Command),
+    /// Exports accounts with their storage, bytecode and proofs against the head state root
+    ExportSubtrie(export_subtrie::Command),
+    /// Verifies and imports accounts exported by `export-subtrie` into the head state
+    ImportSubtrie(import_subtrie::Command),
     /// Lists current and local database versions
     Version,
     /// Returns the full database path
     Path,
+    /// Inspects a transaction pool snapshot
+    PoolSnapshot(pool_snapshot::Command),
 }
 
 /// `db_ro_exec` opens a database in read-only mode, and then execute with the provided command
@@ -65,6 +87,11 @@ macro_rules! db_ro_exec {
 impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
     /// Execute `db` command
     pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec>>(self) -> eyre::Result<()> {
+        // pool snapshots are inspected offline and don't require a datadir
+        if let Subcommands::PoolSnapshot(command) = self.command {
+            return command.execute()
+        }
+
         let data_dir = self.env.datadir.clone().resolve_datadir(self.env.chain.chain());
         let db_path = data_dir.db();
         let static_files_path = data_dir.static_files();
@@ -98,7 +125,7 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
             }
             Subcommands::Diff(command) => {
                 db_ro_exec!(self.env, tool, N, {
-                    command.execute(&tool)?;
+                    command.execute(&tool).await?;
                 });
             }
             Subcommands::Get(command) => {
@@ -106,6 +133,21 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                     command.execute(&tool)?;
                 });
             }
+            Subcommands::Put(command) => {
+                ensure_write_confirmed(command.i_know_what_i_am_doing)?;
+                let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
+                command.execute(provider_factory)?;
+            }
+            Subcommands::Delete(command) => {
+                ensure_write_confirmed(command.i_know_what_i_am_doing)?;
+                let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
+                command.execute(provider_factory)?;
+            }
+            Subcommands::Query(command) => {
+                db_ro_exec!(self.env, tool, N, {
+                    command.execute(&tool)?;
+                });
+            }
             Subcommands::Drop { force } => {
                 if !force {
                     // Ask for confirmation
@@ -130,6 +172,19 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                 let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
                 command.execute(provider_factory)?;
             }
+            Subcommands::RebuildLogIndex(command) => {
+                let Environment { provider_factory, config, .. } =
+                    self.env.init::<N>(AccessRights::RW)?;
+                command.execute(provider_factory, &config.stages)?;
+            }
+            Subcommands::ExportSubtrie(command) => {
+                let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+                command.execute(provider_factory)?;
+            }
+            Subcommands::ImportSubtrie(command) => {
+                let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
+                command.execute(provider_factory)?;
+            }
             Subcommands::Version => {
                 let local_db_version = match get_db_version(&db_path) {
                     Ok(version) => Some(version),
@@ -148,12 +203,23 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
             Subcommands::Path => {
                 println!("{}", db_path.display());
             }
+            Subcommands::PoolSnapshot(_) => unreachable!("pool snapshots are inspected above"),
         }
 
         Ok(())
     }
 }
 
+/// Ensures that a command writing to the database directly was confirmed with
+/// `--i-know-what-i-am-doing`.
+fn ensure_write_confirmed(confirmed: bool) -> eyre::Result<()> {
+    eyre::ensure!(
+        confirmed,
+        "Writing to the database directly can corrupt it. Pass --i-know-what-i-am-doing to proceed."
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,4 +238,69 @@ mod tests {
         .unwrap();
         assert_eq!(cmd.env.datadir.resolve_datadir(cmd.env.chain.chain).as_ref(), Path::new(&path));
     }
+
+    #[test]
+    fn parse_put_delete() {
+        let cmd = Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "put",
+            "CanonicalHeaders",
+            "1",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "--i-know-what-i-am-doing",
+        ])
+        .unwrap();
+        let Subcommands::Put(put) = cmd.command else { panic!("expected put command") };
+        assert!(put.i_know_what_i_am_doing);
+
+        let cmd = Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "delete",
+            "PlainStorageState",
+            "0x0000000000000000000000000000000000000000",
+        ])
+        .unwrap();
+        let Subcommands::Delete(delete) = cmd.command else { panic!("expected delete command") };
+        assert!(!delete.i_know_what_i_am_doing);
+    }
+
+    #[test]
+    fn parse_pool_snapshot() {
+        let cmd = Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "pool-snapshot",
+            "txpool.snapshot",
+            "--json",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.command, Subcommands::PoolSnapshot(_)));
+    }
+
+    #[test]
+    fn parse_diff_rpc_requires_block_range() {
+        assert!(Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "diff",
+            "--rpc-url",
+            "http://localhost:8545",
+            "--output",
+            "diff",
+        ])
+        .is_err());
+
+        let cmd = Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "diff",
+            "--rpc-url",
+            "http://localhost:8545",
+            "--from",
+            "100",
+            "--to",
+            "200",
+            "--output",
+            "diff",
+        ])
+        .unwrap();
+        assert!(matches!(cmd.command, Subcommands::Diff(_)));
+    }
 }
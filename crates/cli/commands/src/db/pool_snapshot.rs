@@ -0,0 +1,72 @@
+use clap::Parser;
+use comfy_table::{Cell, Row, Table as ComfyTable};
+use reth_transaction_pool::snapshot::PoolSnapshot;
+use serde_json::json;
+use std::path::PathBuf;
+
+/// The arguments for the `reth db pool-snapshot` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The path to the transaction pool snapshot
+    path: PathBuf,
+
+    /// Output the transactions as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+impl Command {
+    /// Execute `db pool-snapshot` command
+    pub fn execute(self) -> eyre::Result<()> {
+        // the transactions are not decoded so snapshots of any pool can be inspected
+        let snapshot = PoolSnapshot::load_raw(&self.path)?;
+
+        if self.json {
+            let transactions = snapshot
+                .transactions
+                .iter()
+                .map(|tx| {
+                    json!({
+                        "hash": tx.hash,
+                        "sender": tx.sender,
+                        "nonce": tx.nonce,
+                        "type": tx.tx_type,
+                        "origin": format!("{:?}", tx.origin),
+                        "pending": tx.pending,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let output = json!({
+                "blockNumber": snapshot.block_number,
+                "transactions": transactions,
+            });
+            println!("{}", serde_json::to_string_pretty(&output)?);
+            return Ok(())
+        }
+
+        println!(
+            "Snapshot at block {}: {} transactions ({} pending, {} queued)",
+            snapshot.block_number,
+            snapshot.transactions.len(),
+            snapshot.num_pending(),
+            snapshot.num_queued(),
+        );
+
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header(["Hash", "Sender", "Nonce", "Type", "Origin", "Pending"]);
+        for tx in &snapshot.transactions {
+            let mut row = Row::new();
+            row.add_cell(Cell::new(tx.hash))
+                .add_cell(Cell::new(tx.sender))
+                .add_cell(Cell::new(tx.nonce))
+                .add_cell(Cell::new(tx.tx_type))
+                .add_cell(Cell::new(format!("{:?}", tx.origin)))
+                .add_cell(Cell::new(tx.pending));
+            table.add_row(row);
+        }
+        println!("{table}");
+
+        Ok(())
+    }
+}
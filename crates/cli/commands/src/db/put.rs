@@ -0,0 +1,65 @@
+use super::get::{maybe_json_value_parser, table_key};
+use alloy_primitives::Bytes;
+use clap::Parser;
+use reth_db::{RawKey, RawTable, RawValue, TableViewer, Tables};
+use reth_db_api::{
+    database::Database,
+    table::{Decompress, Table},
+    transaction::{DbTx, DbTxMut},
+};
+use reth_node_builder::NodeTypesWithDB;
+use reth_provider::ProviderFactory;
+use tracing::info;
+
+/// The arguments for the `reth db put` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The table name
+    table: Tables,
+
+    /// The key to write the value for
+    #[arg(value_parser = maybe_json_value_parser)]
+    key: String,
+
+    /// The hex encoded raw value to write, as printed by `reth db get mdbx --raw`
+    ///
+    /// For `DupSort` tables the value is added as a new duplicate of the key.
+    value: Bytes,
+
+    /// Confirms writing to the database directly, bypassing all consistency checks of the node
+    #[arg(long = "i-know-what-i-am-doing")]
+    pub(crate) i_know_what_i_am_doing: bool,
+}
+
+impl Command {
+    /// Execute `db put` command
+    pub fn execute<N: NodeTypesWithDB>(
+        self,
+        provider_factory: ProviderFactory<N>,
+    ) -> eyre::Result<()> {
+        self.table.view(&PutViewer { db: provider_factory.db_ref(), args: &self })
+    }
+}
+
+struct PutViewer<'a, DB: Database> {
+    db: &'a DB,
+    args: &'a Command,
+}
+
+impl<DB: Database> TableViewer<()> for PutViewer<'_, DB> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<(), Self::Error> {
+        let key = table_key::<T>(&self.args.key)?;
+        // make sure the value can be decoded before writing it
+        let value = T::Value::decompress(&self.args.value)?;
+
+        let tx = self.db.tx_mut()?;
+        tx.put::<RawTable<T>>(RawKey::new(key), RawValue::from_vec(self.args.value.to_vec()))?;
+        tx.commit()?;
+
+        info!(target: "reth::cli", table = T::NAME, key = %self.args.key, "Wrote value");
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+//! `reth db query` command.
+//!
+//! Runs a read-only SQL query over the block, transaction, receipt and log static files, see
+//! [`sql`] for the supported dialect. The tables have the same schemas as the ones written by
+//! `reth export parquet`, rows are read in batches of blocks and only the blocks that can contain
+//! matching rows are scanned, based on the conditions on the block number column.
+
+use crate::export::parquet::{Batch, BatchTables, ExportTable};
+use alloy_primitives::hex;
+use clap::{Parser, ValueEnum};
+use reth_db_common::DbTool;
+use reth_exex_sinks::{ParquetRecord, ParquetValue};
+use reth_primitives::EthPrimitives;
+use reth_provider::{providers::ProviderNodeTypes, BlockNumReader};
+use std::io::{self, BufWriter, Write};
+use tracing::info;
+
+mod sql;
+
+use sql::{Projection, Query};
+
+/// The default number of blocks that are read at once.
+const DEFAULT_BLOCKS_PER_BATCH: u64 = 1_000;
+
+/// The format query results are written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Comma separated values with a header row.
+    #[default]
+    Csv,
+    /// One JSON object per row.
+    Json,
+}
+
+/// The arguments for the `reth db query` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The SQL query, e.g. `SELECT hash, gas_used FROM blocks WHERE number >= 1000 LIMIT 10`.
+    ///
+    /// The tables are `blocks`, `transactions`, `receipts` and `logs`.
+    sql: String,
+
+    /// The format to write the results in.
+    #[arg(long, value_enum, default_value_t)]
+    format: OutputFormat,
+
+    /// The number of blocks that are read at once.
+    #[arg(long, default_value_t = DEFAULT_BLOCKS_PER_BATCH, value_parser = clap::value_parser!(u64).range(1..))]
+    blocks_per_batch: u64,
+}
+
+impl Command {
+    /// Execute `db query` command
+    pub fn execute<N: ProviderNodeTypes<Primitives = EthPrimitives>>(
+        self,
+        tool: &DbTool<N>,
+    ) -> eyre::Result<()> {
+        let query = Query::parse(&self.sql)?;
+        let provider = tool.provider_factory.provider()?;
+
+        let mut output = Output::new(BufWriter::new(io::stdout().lock()), self.format, &query)?;
+        let Some(range) = query.block_range(0..=provider.best_block_number()?) else {
+            return output.finish()
+        };
+
+        info!(target: "reth::cli", table = ?query.table, ?range, "Scanning blocks");
+        let tables = BatchTables::only(query.table);
+        let mut start = *range.start();
+        'batches: while start <= *range.end() {
+            let end = start.saturating_add(self.blocks_per_batch - 1).min(*range.end());
+            let batch = Batch::read(&provider, start..=end, tables)?;
+            for row in rows(&batch, query.table) {
+                if !query.matches(&row) {
+                    continue
+                }
+                output.write_row(query.project(row))?;
+                if query.limit.is_some_and(|limit| output.rows >= limit) {
+                    break 'batches
+                }
+            }
+
+            if end == *range.end() {
+                break
+            }
+            start = end + 1;
+        }
+
+        output.finish()
+    }
+}
+
+/// Returns the values of the rows of the table in the batch.
+fn rows(batch: &Batch, table: ExportTable) -> Box<dyn Iterator<Item = Vec<ParquetValue>> + '_> {
+    match table {
+        ExportTable::Blocks => Box::new(batch.blocks.iter().map(ParquetRecord::values)),
+        ExportTable::Transactions => Box::new(batch.transactions.iter().map(ParquetRecord::values)),
+        ExportTable::Receipts => Box::new(batch.receipts.iter().map(ParquetRecord::values)),
+        ExportTable::Logs => Box::new(batch.logs.iter().map(ParquetRecord::values)),
+    }
+}
+
+/// Writes the results of a query.
+#[derive(Debug)]
+struct Output<W> {
+    writer: W,
+    format: OutputFormat,
+    columns: Vec<&'static str>,
+    /// Whether only the number of matching rows is written, on [`Output::finish`].
+    count_only: bool,
+    /// The number of matching rows so far.
+    rows: usize,
+}
+
+impl<W: Write> Output<W> {
+    /// Creates the output and writes the header row, if any.
+    fn new(mut writer: W, format: OutputFormat, query: &Query) -> eyre::Result<Self> {
+        let columns = query.column_names();
+        let count_only = query.projection == Projection::Count;
+        if format == OutputFormat::Csv {
+            writeln!(writer, "{}", columns.join(","))?;
+        }
+        Ok(Self { writer, format, columns, count_only, rows: 0 })
+    }
+
+    /// Writes a matching row.
+    fn write_row(&mut self, row: Vec<ParquetValue>) -> eyre::Result<()> {
+        self.rows += 1;
+        if self.count_only {
+            return Ok(())
+        }
+        self.write_values(row.into_iter().map(|value| match value {
+            ParquetValue::Null => serde_json::Value::Null,
+            ParquetValue::Boolean(value) => value.into(),
+            ParquetValue::UInt64(value) => value.into(),
+            ParquetValue::Bytes(value) => hex::encode_prefixed(value).into(),
+            ParquetValue::String(value) => value.into(),
+        }))
+    }
+
+    fn write_values(
+        &mut self,
+        values: impl Iterator<Item = serde_json::Value>,
+    ) -> eyre::Result<()> {
+        match self.format {
+            OutputFormat::Csv => {
+                let values = values
+                    .map(|value| match value {
+                        serde_json::Value::Null => String::new(),
+                        serde_json::Value::String(value) => value,
+                        value => value.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                writeln!(self.writer, "{}", values.join(","))?;
+            }
+            OutputFormat::Json => {
+                let object =
+                    self.columns.iter().map(|column| column.to_string()).zip(values).collect();
+                writeln!(self.writer, "{}", serde_json::Value::Object(object))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the number of matching rows, if counted, and flushes the output.
+    fn finish(mut self) -> eyre::Result<()> {
+        if self.count_only {
+            self.write_values(std::iter::once(self.rows.into()))?;
+        }
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_query_command() {
+        let cmd = Command::parse_from(["reth", "SELECT * FROM blocks", "--format", "json"]);
+        assert_eq!(cmd.sql, "SELECT * FROM blocks");
+        assert_eq!(cmd.format, OutputFormat::Json);
+        assert_eq!(cmd.blocks_per_batch, DEFAULT_BLOCKS_PER_BATCH);
+    }
+
+    #[test]
+    fn write_output() {
+        let row =
+            || vec![ParquetValue::UInt64(1), ParquetValue::Bytes(vec![0xab]), ParquetValue::Null];
+        let query = Query::parse("SELECT number, hash, base_fee_per_gas FROM blocks").unwrap();
+
+        let mut csv = Vec::new();
+        let mut output = Output::new(&mut csv, OutputFormat::Csv, &query).unwrap();
+        output.write_row(row()).unwrap();
+        output.finish().unwrap();
+        assert_eq!(String::from_utf8(csv).unwrap(), "number,hash,base_fee_per_gas\n1,0xab,\n");
+
+        let mut json = Vec::new();
+        let mut output = Output::new(&mut json, OutputFormat::Json, &query).unwrap();
+        output.write_row(row()).unwrap();
+        output.finish().unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "number": 1, "hash": "0xab", "base_fee_per_gas": null })
+        );
+
+        let query = Query::parse("SELECT COUNT(*) FROM logs").unwrap();
+        let mut count = Vec::new();
+        let mut output = Output::new(&mut count, OutputFormat::Csv, &query).unwrap();
+        output.write_row(row()).unwrap();
+        output.write_row(row()).unwrap();
+        output.finish().unwrap();
+        assert_eq!(String::from_utf8(count).unwrap(), "count\n2\n");
+    }
+}
@@ -0,0 +1,476 @@
+//! A minimal, read-only SQL dialect over the exported tables.
+//!
+//! Supported statements have the form:
+//!
+//! ```sql
+//! SELECT <* | COUNT(*) | column, ...> FROM <table>
+//!     [WHERE <condition> [AND <condition> ...]]
+//!     [LIMIT <n>]
+//! ```
+//!
+//! where a condition is either `column <op> literal`, with `op` one of `=`, `!=`, `<>`, `<`, `<=`,
+//! `>`, `>=`, or `column BETWEEN literal AND literal`. Literals are unsigned integers, `0x`
+//! prefixed hex bytes, single quoted strings, `TRUE` or `FALSE`.
+//!
+//! Decimal string columns, e.g. `value` of the `transactions` table, compare numerically to
+//! integer literals.
+
+use crate::export::parquet::ExportTable;
+use alloy_primitives::{hex, BlockNumber, U256};
+use clap::ValueEnum;
+use reth_exex_sinks::ParquetValue;
+use std::ops::RangeInclusive;
+
+/// A parsed `SELECT` statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Query {
+    /// The queried table.
+    pub(crate) table: ExportTable,
+    /// The selected columns.
+    pub(crate) projection: Projection,
+    /// The conditions every returned row satisfies.
+    pub(crate) conditions: Vec<Condition>,
+    /// The maximum number of returned rows.
+    pub(crate) limit: Option<usize>,
+}
+
+impl Query {
+    /// Parses a statement, see the [module docs](self) for the supported syntax.
+    pub(crate) fn parse(sql: &str) -> eyre::Result<Self> {
+        Parser { tokens: tokenize(sql)?, position: 0 }.query()
+    }
+
+    /// Returns the names of the selected columns.
+    pub(crate) fn column_names(&self) -> Vec<&'static str> {
+        let columns = self.table.columns();
+        match &self.projection {
+            Projection::All => columns.iter().map(|column| column.name).collect(),
+            Projection::Columns(indices) => indices.iter().map(|i| columns[*i].name).collect(),
+            Projection::Count => vec!["count"],
+        }
+    }
+
+    /// Returns `true` if the row satisfies all conditions.
+    pub(crate) fn matches(&self, row: &[ParquetValue]) -> bool {
+        self.conditions.iter().all(|condition| condition.matches(row))
+    }
+
+    /// Returns the selected values of a row that satisfies all conditions.
+    pub(crate) fn project(&self, mut row: Vec<ParquetValue>) -> Vec<ParquetValue> {
+        match &self.projection {
+            Projection::All | Projection::Count => row,
+            Projection::Columns(indices) => indices
+                .iter()
+                .map(|i| std::mem::replace(&mut row[*i], ParquetValue::Null))
+                .collect(),
+        }
+    }
+
+    /// Narrows the given block range to the blocks that can contain matching rows, based on the
+    /// conditions on the block number column.
+    ///
+    /// Returns `None` if no block can contain a matching row.
+    pub(crate) fn block_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Option<RangeInclusive<BlockNumber>> {
+        let block_number_column = self.table.block_number_column();
+        let (mut start, mut end) = range.into_inner();
+        for condition in &self.conditions {
+            if self.table.columns()[condition.column].name != block_number_column {
+                continue
+            }
+            let Literal::Number(value) = condition.value else { continue };
+            let value: u64 = value.try_into().unwrap_or(u64::MAX);
+            match condition.op {
+                Operator::Eq => {
+                    start = start.max(value);
+                    end = end.min(value);
+                }
+                Operator::Gt => start = start.max(value.checked_add(1)?),
+                Operator::Ge => start = start.max(value),
+                Operator::Lt => end = end.min(value.checked_sub(1)?),
+                Operator::Le => end = end.min(value),
+                Operator::Ne => {}
+            }
+        }
+        (start <= end).then_some(start..=end)
+    }
+}
+
+/// The selected columns of a [`Query`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Projection {
+    /// All columns of the table, `*`.
+    All,
+    /// The columns at the given indices, in the given order.
+    Columns(Vec<usize>),
+    /// The number of matching rows, `COUNT(*)`.
+    Count,
+}
+
+/// A `column <op> literal` condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Condition {
+    /// The index of the column.
+    pub(crate) column: usize,
+    /// The comparison operator.
+    pub(crate) op: Operator,
+    /// The value the column is compared to.
+    pub(crate) value: Literal,
+}
+
+impl Condition {
+    /// Returns `true` if the value of the column satisfies the condition.
+    ///
+    /// `NULL` values and values of a different type never satisfy a condition.
+    fn matches(&self, row: &[ParquetValue]) -> bool {
+        let ordering = match (&row[self.column], &self.value) {
+            (ParquetValue::UInt64(value), Literal::Number(literal)) => {
+                U256::from(*value).cmp(literal)
+            }
+            (ParquetValue::String(value), Literal::Number(literal)) => {
+                match U256::from_str_radix(value, 10) {
+                    Ok(value) => value.cmp(literal),
+                    Err(_) => return false,
+                }
+            }
+            (ParquetValue::String(value), Literal::String(literal)) => value.cmp(literal),
+            (ParquetValue::Bytes(value), Literal::Bytes(literal)) => value.cmp(literal),
+            (ParquetValue::Boolean(value), Literal::Boolean(literal)) => value.cmp(literal),
+            _ => return false,
+        };
+        match self.op {
+            Operator::Eq => ordering.is_eq(),
+            Operator::Ne => ordering.is_ne(),
+            Operator::Lt => ordering.is_lt(),
+            Operator::Le => ordering.is_le(),
+            Operator::Gt => ordering.is_gt(),
+            Operator::Ge => ordering.is_ge(),
+        }
+    }
+}
+
+/// A comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operator {
+    /// `=`
+    Eq,
+    /// `!=` or `<>`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+/// A literal value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Literal {
+    /// An unsigned integer.
+    Number(U256),
+    /// `0x` prefixed hex bytes.
+    Bytes(Vec<u8>),
+    /// A single quoted string.
+    String(String),
+    /// `TRUE` or `FALSE`.
+    Boolean(bool),
+}
+
+/// A token of a statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// A keyword or a name, as written.
+    Word(String),
+    /// A literal value.
+    Literal(Literal),
+    /// A comparison operator.
+    Operator(Operator),
+    /// `,`
+    Comma,
+    /// `*`
+    Star,
+    /// `(`
+    LeftParen,
+    /// `)`
+    RightParen,
+}
+
+/// Splits a statement into tokens.
+fn tokenize(sql: &str) -> eyre::Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            ',' => Token::Comma,
+            '*' => Token::Star,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            ';' if chars.peek().is_none() => continue,
+            '=' => Token::Operator(Operator::Eq),
+            '!' if chars.next_if(|(_, c)| *c == '=').is_some() => Token::Operator(Operator::Ne),
+            '<' if chars.next_if(|(_, c)| *c == '=').is_some() => Token::Operator(Operator::Le),
+            '<' if chars.next_if(|(_, c)| *c == '>').is_some() => Token::Operator(Operator::Ne),
+            '<' => Token::Operator(Operator::Lt),
+            '>' if chars.next_if(|(_, c)| *c == '=').is_some() => Token::Operator(Operator::Ge),
+            '>' => Token::Operator(Operator::Gt),
+            '\'' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        // `''` escapes a quote
+                        Some((_, '\'')) if chars.next_if(|(_, c)| *c == '\'').is_some() => {
+                            value.push('\'')
+                        }
+                        Some((_, '\'')) => break,
+                        Some((_, c)) => value.push(c),
+                        None => eyre::bail!("unterminated string starting at {start}"),
+                    }
+                }
+                Token::Literal(Literal::String(value))
+            }
+            c if c.is_ascii_alphanumeric() || c == '_' => {
+                let mut end = start + c.len_utf8();
+                while let Some((i, c)) =
+                    chars.next_if(|(_, c)| c.is_ascii_alphanumeric() || *c == '_')
+                {
+                    end = i + c.len_utf8();
+                }
+                let word = &sql[start..end];
+                if let Some(bytes) = word.strip_prefix("0x") {
+                    Token::Literal(Literal::Bytes(hex::decode(bytes)?))
+                } else if c.is_ascii_digit() {
+                    Token::Literal(Literal::Number(U256::from_str_radix(word, 10)?))
+                } else if word.eq_ignore_ascii_case("true") {
+                    Token::Literal(Literal::Boolean(true))
+                } else if word.eq_ignore_ascii_case("false") {
+                    Token::Literal(Literal::Boolean(false))
+                } else {
+                    Token::Word(word.to_string())
+                }
+            }
+            c => eyre::bail!("unexpected character '{c}' at {start}"),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// A recursive descent parser over the tokens of a statement.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    /// Parses a `SELECT` statement.
+    fn query(mut self) -> eyre::Result<Query> {
+        self.keyword("SELECT")?;
+        let projection = self.projection()?;
+        self.keyword("FROM")?;
+        let table = self.word()?;
+        let table = ExportTable::from_str(&table, true)
+            .map_err(|_| eyre::eyre!("unknown table {table}"))?;
+        let projection = match projection {
+            UnresolvedProjection::All => Projection::All,
+            UnresolvedProjection::Count => Projection::Count,
+            UnresolvedProjection::Columns(names) => Projection::Columns(
+                names.iter().map(|name| column_index(table, name)).collect::<Result<_, _>>()?,
+            ),
+        };
+
+        let mut conditions = Vec::new();
+        if self.next_keyword("WHERE") {
+            loop {
+                self.conditions(table, &mut conditions)?;
+                if !self.next_keyword("AND") {
+                    break
+                }
+            }
+        }
+
+        let mut limit = None;
+        if self.next_keyword("LIMIT") {
+            match self.next() {
+                Some(Token::Literal(Literal::Number(value))) => {
+                    limit = Some(value.try_into().unwrap_or(usize::MAX))
+                }
+                token => eyre::bail!("expected limit, found {token:?}"),
+            }
+        }
+
+        if let Some(token) = self.next() {
+            eyre::bail!("unexpected {token:?} at end of statement")
+        }
+
+        Ok(Query { table, projection, conditions, limit })
+    }
+
+    /// Parses the selected columns.
+    fn projection(&mut self) -> eyre::Result<UnresolvedProjection> {
+        if self.next_if(&Token::Star) {
+            return Ok(UnresolvedProjection::All)
+        }
+        if self.peek_keyword("COUNT") {
+            self.position += 1;
+            for token in [Token::LeftParen, Token::Star, Token::RightParen] {
+                if !self.next_if(&token) {
+                    eyre::bail!("only COUNT(*) is supported")
+                }
+            }
+            return Ok(UnresolvedProjection::Count)
+        }
+
+        let mut names = vec![self.word()?];
+        while self.next_if(&Token::Comma) {
+            names.push(self.word()?);
+        }
+        Ok(UnresolvedProjection::Columns(names))
+    }
+
+    /// Parses a condition, `BETWEEN` is pushed as two conditions.
+    fn conditions(
+        &mut self,
+        table: ExportTable,
+        conditions: &mut Vec<Condition>,
+    ) -> eyre::Result<()> {
+        let column = column_index(table, &self.word()?)?;
+        if self.next_keyword("BETWEEN") {
+            let low = self.literal()?;
+            self.keyword("AND")?;
+            let high = self.literal()?;
+            conditions.push(Condition { column, op: Operator::Ge, value: low });
+            conditions.push(Condition { column, op: Operator::Le, value: high });
+            return Ok(())
+        }
+        let op = match self.next() {
+            Some(Token::Operator(op)) => op,
+            token => eyre::bail!("expected comparison operator, found {token:?}"),
+        };
+        let value = self.literal()?;
+        conditions.push(Condition { column, op, value });
+        Ok(())
+    }
+
+    fn literal(&mut self) -> eyre::Result<Literal> {
+        match self.next() {
+            Some(Token::Literal(literal)) => Ok(literal),
+            token => eyre::bail!("expected literal, found {token:?}"),
+        }
+    }
+
+    fn word(&mut self) -> eyre::Result<String> {
+        match self.next() {
+            Some(Token::Word(word)) => Ok(word),
+            token => eyre::bail!("expected name, found {token:?}"),
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str) -> eyre::Result<()> {
+        if !self.next_keyword(keyword) {
+            eyre::bail!("expected {keyword}, found {:?}", self.tokens.get(self.position))
+        }
+        Ok(())
+    }
+
+    fn next_keyword(&mut self, keyword: &str) -> bool {
+        let found = self.peek_keyword(keyword);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.tokens.get(self.position), Some(Token::Word(word)) if word.eq_ignore_ascii_case(keyword))
+    }
+
+    fn next_if(&mut self, token: &Token) -> bool {
+        let found = self.tokens.get(self.position) == Some(token);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+}
+
+/// The selected columns before the table is known.
+enum UnresolvedProjection {
+    All,
+    Columns(Vec<String>),
+    Count,
+}
+
+/// Returns the index of the column in the table.
+fn column_index(table: ExportTable, name: &str) -> eyre::Result<usize> {
+    table
+        .columns()
+        .iter()
+        .position(|column| column.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| eyre::eyre!("unknown column {name} of table {table:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_query() {
+        let query = Query::parse(
+            "select hash, value from transactions \
+             where block_number between 10 and 20 and value >= 1000 and to = 0x0001 limit 5;",
+        )
+        .unwrap();
+        assert_eq!(query.table, ExportTable::Transactions);
+        assert_eq!(query.column_names(), vec!["hash", "value"]);
+        assert_eq!(query.conditions.len(), 4);
+        assert_eq!(query.conditions[3].value, Literal::Bytes(vec![0, 1]));
+        assert_eq!(query.limit, Some(5));
+        assert_eq!(query.block_range(0..=100), Some(10..=20));
+
+        let query = Query::parse("SELECT COUNT(*) FROM logs WHERE block_number > 5").unwrap();
+        assert_eq!(query.projection, Projection::Count);
+        assert_eq!(query.block_range(0..=100), Some(6..=100));
+        assert_eq!(query.block_range(0..=5), None);
+
+        assert!(Query::parse("SELECT * FROM accounts").is_err());
+        assert!(Query::parse("SELECT nonce FROM blocks").is_err());
+        assert!(Query::parse("SELECT * FROM blocks WHERE number = 1 OR number = 2").is_err());
+    }
+
+    #[test]
+    fn filter_rows() {
+        let query =
+            Query::parse("SELECT number FROM blocks WHERE miner = 0x01 AND base_fee_per_gas < 10")
+                .unwrap();
+        let mut row = vec![ParquetValue::Null; ExportTable::Blocks.columns().len()];
+        row[0] = ParquetValue::UInt64(7);
+        row[4] = ParquetValue::Bytes(vec![1]);
+        row[7] = ParquetValue::UInt64(9);
+        assert!(query.matches(&row));
+        assert_eq!(query.project(row.clone()), vec![ParquetValue::UInt64(7)]);
+
+        // `NULL` never satisfies a condition
+        row[7] = ParquetValue::Null;
+        assert!(!query.matches(&row));
+
+        // decimal strings compare numerically
+        let query = Query::parse("SELECT * FROM transactions WHERE value > 9").unwrap();
+        let mut row = vec![ParquetValue::Null; ExportTable::Transactions.columns().len()];
+        row[7] = ParquetValue::String("10".to_string());
+        assert!(query.matches(&row));
+        row[7] = ParquetValue::String("9".to_string());
+        assert!(!query.matches(&row));
+    }
+}
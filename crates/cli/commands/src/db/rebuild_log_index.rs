@@ -0,0 +1,65 @@
+use clap::Parser;
+use reth_config::config::StageConfig;
+use reth_provider::{
+    providers::ProviderNodeTypes, DatabaseProviderFactory, ProviderFactory, StageCheckpointReader,
+    StageCheckpointWriter,
+};
+use reth_stages::{stages::IndexLogsStage, ExecInput, ExecOutput, Stage, StageId};
+use tracing::{info, warn};
+
+/// The arguments for the `reth db rebuild-log-index` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// Number of blocks to index before committing.
+    ///
+    /// Defaults to the `commit_threshold` of the `index_logs` stage configuration.
+    #[arg(long)]
+    commit_threshold: Option<u64>,
+}
+
+impl Command {
+    /// Execute `db rebuild-log-index` command
+    pub fn execute<N: ProviderNodeTypes>(
+        self,
+        provider_factory: ProviderFactory<N>,
+        stages_config: &StageConfig,
+    ) -> eyre::Result<()> {
+        if !stages_config.index_logs.enabled {
+            warn!(target: "reth::cli", "The log index is disabled in the configuration and will be cleared on the next pipeline run, set `stages.index_logs.enabled` to keep it");
+        }
+
+        let mut index_logs = stages_config.index_logs;
+        index_logs.enabled = true;
+        if let Some(commit_threshold) = self.commit_threshold {
+            index_logs.commit_threshold = commit_threshold;
+        }
+        let mut stage = IndexLogsStage::new(index_logs, stages_config.etl.clone());
+
+        let target = provider_factory
+            .provider()?
+            .get_stage_checkpoint(StageId::Finish)?
+            .unwrap_or_default()
+            .block_number;
+
+        // Without an indexed block range in the checkpoint, the stage clears the index and
+        // rebuilds it from scratch.
+        let mut input = ExecInput { target: Some(target), checkpoint: None };
+        info!(target: "reth::cli", target, "Rebuilding log index");
+        loop {
+            let provider_rw = provider_factory.database_provider_rw()?;
+            let ExecOutput { checkpoint, done } = stage.execute(&provider_rw, input)?;
+            provider_rw.save_stage_checkpoint(StageId::IndexLogs, checkpoint)?;
+            provider_rw.commit()?;
+
+            info!(target: "reth::cli", block = checkpoint.block_number, target, "Indexed logs");
+            input.checkpoint = Some(checkpoint);
+
+            if done {
+                break
+            }
+        }
+        info!(target: "reth::cli", "Log index rebuilt");
+
+        Ok(())
+    }
+}
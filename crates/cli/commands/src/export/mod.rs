@@ -0,0 +1,31 @@
+//! `reth export` command.
+
+use crate::common::CliNodeTypes;
+use clap::{Parser, Subcommand};
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+
+pub mod parquet;
+
+/// `reth export` command
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(subcommand)]
+    command: Subcommands<C>,
+}
+
+/// `reth export` subcommands
+#[derive(Subcommand, Debug)]
+pub enum Subcommands<C: ChainSpecParser> {
+    /// Export blocks, transactions, receipts and logs into Parquet files.
+    Parquet(parquet::Command<C>),
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
+    /// Execute `export` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec>>(self) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Parquet(command) => command.execute::<N>().await,
+        }
+    }
+}
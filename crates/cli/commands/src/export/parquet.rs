@@ -0,0 +1,692 @@
+//! `reth export parquet` command.
+//!
+//! Exports a block range into one Parquet file per table, named `<table>_<from>_<to>.parquet`.
+//! Headers, transactions and receipts are streamed from static files in batches, every batch is
+//! written as a row group. The schemas of the tables are stable, new columns are only ever
+//! appended:
+//!
+//! - `blocks`: see [`BlockRow`]
+//! - `transactions`: see [`TransactionRow`]
+//! - `receipts`: see [`ReceiptRow`]
+//! - `logs`: see [`LogRow`]
+
+use crate::common::{AccessRights, CliNodeTypes, Environment, EnvironmentArgs};
+use alloy_consensus::Transaction;
+use alloy_primitives::{Address, BlockNumber, Log, TxHash, B256};
+use clap::{Parser, ValueEnum};
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_exex_sinks::{ParquetColumn, ParquetFileWriter, ParquetRecord, ParquetType, ParquetValue};
+use reth_primitives::{Receipt, SealedHeader, TransactionSigned};
+use reth_provider::{
+    BlockNumReader, BlockReader, HeaderProvider, ProviderError, ReceiptProvider,
+    TransactionsProvider,
+};
+use std::{
+    fs::File,
+    io::BufWriter,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+use tracing::info;
+
+/// The default number of blocks per row group.
+const DEFAULT_BLOCKS_PER_ROW_GROUP: u64 = 10_000;
+
+/// A table that can be exported, see the [module docs](self) for the schemas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportTable {
+    /// Block headers.
+    Blocks,
+    /// Transactions with their senders.
+    Transactions,
+    /// Transaction receipts.
+    Receipts,
+    /// Logs emitted by transactions.
+    Logs,
+}
+
+impl ExportTable {
+    /// Returns the columns of the table.
+    pub const fn columns(&self) -> &'static [ParquetColumn] {
+        match self {
+            Self::Blocks => BlockRow::COLUMNS,
+            Self::Transactions => TransactionRow::COLUMNS,
+            Self::Receipts => ReceiptRow::COLUMNS,
+            Self::Logs => LogRow::COLUMNS,
+        }
+    }
+
+    /// Returns the column that holds the block number of a row.
+    pub const fn block_number_column(&self) -> &'static str {
+        match self {
+            Self::Blocks => "number",
+            Self::Transactions | Self::Receipts | Self::Logs => "block_number",
+        }
+    }
+}
+
+/// `reth export parquet` command
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The tables to export.
+    #[arg(long, value_delimiter = ',', default_value = "blocks,transactions,receipts,logs")]
+    tables: Vec<ExportTable>,
+
+    /// The first block to export.
+    #[arg(long, default_value_t = 0)]
+    from: BlockNumber,
+
+    /// The last block to export. Defaults to the highest synced block.
+    #[arg(long)]
+    to: Option<BlockNumber>,
+
+    /// The directory to write the Parquet files to.
+    #[arg(long, value_name = "PATH", default_value = ".")]
+    output_dir: PathBuf,
+
+    /// The number of blocks that are written as one row group.
+    #[arg(long, default_value_t = DEFAULT_BLOCKS_PER_ROW_GROUP, value_parser = clap::value_parser!(u64).range(1..))]
+    blocks_per_row_group: u64,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
+    /// Execute `export parquet` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec>>(self) -> eyre::Result<()> {
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+        let provider = provider_factory.provider()?;
+
+        let from = self.from;
+        let to = match self.to {
+            Some(to) => to,
+            None => provider.best_block_number()?,
+        };
+        if from > to {
+            eyre::bail!("invalid block range {from}..={to}")
+        }
+
+        reth_fs_util::create_dir_all(&self.output_dir)?;
+        let mut writers = Writers::new(&self.tables, &self.output_dir, from..=to)?;
+
+        info!(target: "reth::cli", from, to, tables = ?self.tables, "Exporting blocks");
+        let mut start = from;
+        while start <= to {
+            let end = start.saturating_add(self.blocks_per_row_group - 1).min(to);
+            let batch = Batch::read(&provider, start..=end, writers.tables())?;
+            writers.write(&batch)?;
+            info!(target: "reth::cli", exported = end - from + 1, total = to - from + 1, "Exported blocks");
+
+            if end == to {
+                break
+            }
+            start = end + 1;
+        }
+
+        writers.finish()?;
+        info!(target: "reth::cli", output_dir = ?self.output_dir, "Export finished");
+
+        Ok(())
+    }
+}
+
+/// The Parquet file writers of the exported tables.
+#[derive(Debug, Default)]
+struct Writers {
+    blocks: Option<ParquetFileWriter<BufWriter<File>, BlockRow>>,
+    transactions: Option<ParquetFileWriter<BufWriter<File>, TransactionRow>>,
+    receipts: Option<ParquetFileWriter<BufWriter<File>, ReceiptRow>>,
+    logs: Option<ParquetFileWriter<BufWriter<File>, LogRow>>,
+}
+
+impl Writers {
+    /// Creates the files of the given tables in the output directory.
+    fn new(
+        tables: &[ExportTable],
+        output_dir: &Path,
+        range: RangeInclusive<BlockNumber>,
+    ) -> eyre::Result<Self> {
+        fn create<R: ParquetRecord>(
+            output_dir: &Path,
+            table: &str,
+            range: &RangeInclusive<BlockNumber>,
+        ) -> eyre::Result<ParquetFileWriter<BufWriter<File>, R>> {
+            let path =
+                output_dir.join(format!("{table}_{}_{}.parquet", range.start(), range.end()));
+            ParquetFileWriter::new(BufWriter::new(reth_fs_util::create_file(path)?))
+        }
+
+        let mut writers = Self::default();
+        for table in tables {
+            match table {
+                ExportTable::Blocks if writers.blocks.is_none() => {
+                    writers.blocks = Some(create(output_dir, "blocks", &range)?)
+                }
+                ExportTable::Transactions if writers.transactions.is_none() => {
+                    writers.transactions = Some(create(output_dir, "transactions", &range)?)
+                }
+                ExportTable::Receipts if writers.receipts.is_none() => {
+                    writers.receipts = Some(create(output_dir, "receipts", &range)?)
+                }
+                ExportTable::Logs if writers.logs.is_none() => {
+                    writers.logs = Some(create(output_dir, "logs", &range)?)
+                }
+                _ => {}
+            }
+        }
+        Ok(writers)
+    }
+
+    /// Returns the tables that need to be read.
+    const fn tables(&self) -> BatchTables {
+        BatchTables {
+            blocks: self.blocks.is_some(),
+            transactions: self.transactions.is_some(),
+            receipts: self.receipts.is_some(),
+            logs: self.logs.is_some(),
+        }
+    }
+
+    /// Writes the batch as a row group of every table.
+    fn write(&mut self, batch: &Batch) -> eyre::Result<()> {
+        if let Some(writer) = &mut self.blocks {
+            writer.write_records(&batch.blocks)?;
+        }
+        if let Some(writer) = &mut self.transactions {
+            writer.write_records(&batch.transactions)?;
+        }
+        if let Some(writer) = &mut self.receipts {
+            writer.write_records(&batch.receipts)?;
+        }
+        if let Some(writer) = &mut self.logs {
+            writer.write_records(&batch.logs)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the footers of all files.
+    fn finish(self) -> eyre::Result<()> {
+        if let Some(writer) = self.blocks {
+            writer.finish()?;
+        }
+        if let Some(writer) = self.transactions {
+            writer.finish()?;
+        }
+        if let Some(writer) = self.receipts {
+            writer.finish()?;
+        }
+        if let Some(writer) = self.logs {
+            writer.finish()?;
+        }
+        Ok(())
+    }
+}
+
+/// The tables that are read into a [`Batch`].
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct BatchTables {
+    blocks: bool,
+    transactions: bool,
+    receipts: bool,
+    logs: bool,
+}
+
+impl BatchTables {
+    /// Returns the selection of a single table.
+    pub(crate) const fn only(table: ExportTable) -> Self {
+        Self {
+            blocks: matches!(table, ExportTable::Blocks),
+            transactions: matches!(table, ExportTable::Transactions),
+            receipts: matches!(table, ExportTable::Receipts),
+            logs: matches!(table, ExportTable::Logs),
+        }
+    }
+
+    /// Returns `true` if transactions need to be read.
+    const fn needs_transactions(&self) -> bool {
+        self.transactions || self.needs_receipts()
+    }
+
+    /// Returns `true` if receipts need to be read.
+    const fn needs_receipts(&self) -> bool {
+        self.receipts || self.logs
+    }
+}
+
+/// The rows of a range of blocks.
+#[derive(Debug, Default)]
+pub(crate) struct Batch {
+    pub(crate) blocks: Vec<BlockRow>,
+    pub(crate) transactions: Vec<TransactionRow>,
+    pub(crate) receipts: Vec<ReceiptRow>,
+    pub(crate) logs: Vec<LogRow>,
+}
+
+impl Batch {
+    /// Reads the rows of the given tables.
+    pub(crate) fn read<P>(
+        provider: &P,
+        range: RangeInclusive<BlockNumber>,
+        tables: BatchTables,
+    ) -> eyre::Result<Self>
+    where
+        P: HeaderProvider<Header = reth_primitives::Header>
+            + BlockReader
+            + TransactionsProvider<Transaction = TransactionSigned>
+            + ReceiptProvider<Receipt = Receipt>,
+    {
+        let mut batch = Self::default();
+        for header in provider.sealed_headers_range(range)? {
+            let number = header.number;
+            let indices = provider
+                .block_body_indices(number)?
+                .ok_or(ProviderError::BlockBodyIndicesNotFound(number))?;
+            if tables.blocks {
+                batch.blocks.push(BlockRow::new(&header, indices.tx_count));
+            }
+            if !tables.needs_transactions() {
+                continue
+            }
+
+            let transactions = provider.transactions_by_tx_range(indices.tx_num_range())?;
+            if tables.transactions {
+                let mut senders = provider.senders_by_tx_range(indices.tx_num_range())?;
+                if senders.len() != transactions.len() {
+                    // senders are pruned, recover them from the signatures
+                    senders = TransactionSigned::recover_signers(&transactions, transactions.len())
+                        .ok_or(ProviderError::SenderRecoveryError)?;
+                }
+                batch.transactions.extend(transactions.iter().zip(senders).enumerate().map(
+                    |(index, (transaction, sender))| {
+                        TransactionRow::new(number, index as u64, transaction, sender)
+                    },
+                ));
+            }
+            if !tables.needs_receipts() {
+                continue
+            }
+
+            let receipts = provider.receipts_by_tx_range(indices.tx_num_range())?;
+            if receipts.len() != transactions.len() {
+                eyre::bail!("receipts of block {number} are pruned")
+            }
+            let mut gas_used = 0;
+            let mut log_index = 0;
+            for (index, (transaction, receipt)) in transactions.iter().zip(&receipts).enumerate() {
+                let index = index as u64;
+                let transaction_hash = transaction.hash();
+                if tables.receipts {
+                    batch.receipts.push(ReceiptRow::new(
+                        number,
+                        index,
+                        transaction_hash,
+                        receipt,
+                        receipt.cumulative_gas_used - gas_used,
+                    ));
+                }
+                gas_used = receipt.cumulative_gas_used;
+
+                for log in &receipt.logs {
+                    if tables.logs {
+                        batch.logs.push(LogRow::new(
+                            number,
+                            index,
+                            log_index,
+                            transaction_hash,
+                            log,
+                        ));
+                    }
+                    log_index += 1;
+                }
+            }
+        }
+        Ok(batch)
+    }
+}
+
+/// A row of the `blocks` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockRow {
+    /// Block number.
+    pub number: BlockNumber,
+    /// Block hash.
+    pub hash: B256,
+    /// Hash of the parent block.
+    pub parent_hash: B256,
+    /// Block timestamp.
+    pub timestamp: u64,
+    /// Beneficiary of the block.
+    pub miner: Address,
+    /// Gas used by all transactions of the block.
+    pub gas_used: u64,
+    /// Gas limit of the block.
+    pub gas_limit: u64,
+    /// Base fee per gas, only set since London.
+    pub base_fee_per_gas: Option<u64>,
+    /// Number of transactions in the block.
+    pub transaction_count: u64,
+    /// Extra data of the block.
+    pub extra_data: Vec<u8>,
+}
+
+impl BlockRow {
+    /// Creates the row of a block.
+    pub fn new(header: &SealedHeader, transaction_count: u64) -> Self {
+        Self {
+            number: header.number,
+            hash: header.hash(),
+            parent_hash: header.parent_hash,
+            timestamp: header.timestamp,
+            miner: header.beneficiary,
+            gas_used: header.gas_used,
+            gas_limit: header.gas_limit,
+            base_fee_per_gas: header.base_fee_per_gas,
+            transaction_count,
+            extra_data: header.extra_data.to_vec(),
+        }
+    }
+}
+
+impl ParquetRecord for BlockRow {
+    const COLUMNS: &'static [ParquetColumn] = &[
+        ParquetColumn::required("number", ParquetType::UInt64),
+        ParquetColumn::required("hash", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("parent_hash", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("timestamp", ParquetType::UInt64),
+        ParquetColumn::required("miner", ParquetType::FixedBytes(20)),
+        ParquetColumn::required("gas_used", ParquetType::UInt64),
+        ParquetColumn::required("gas_limit", ParquetType::UInt64),
+        ParquetColumn::optional("base_fee_per_gas", ParquetType::UInt64),
+        ParquetColumn::required("transaction_count", ParquetType::UInt64),
+        ParquetColumn::required("extra_data", ParquetType::Bytes),
+    ];
+
+    fn values(&self) -> Vec<ParquetValue> {
+        vec![
+            ParquetValue::UInt64(self.number),
+            ParquetValue::Bytes(self.hash.to_vec()),
+            ParquetValue::Bytes(self.parent_hash.to_vec()),
+            ParquetValue::UInt64(self.timestamp),
+            ParquetValue::Bytes(self.miner.to_vec()),
+            ParquetValue::UInt64(self.gas_used),
+            ParquetValue::UInt64(self.gas_limit),
+            self.base_fee_per_gas.map_or(ParquetValue::Null, ParquetValue::UInt64),
+            ParquetValue::UInt64(self.transaction_count),
+            ParquetValue::Bytes(self.extra_data.clone()),
+        ]
+    }
+}
+
+/// A row of the `transactions` table.
+///
+/// Amounts that don't fit into 64 bits are written as decimal strings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionRow {
+    /// Number of the block that contains the transaction.
+    pub block_number: BlockNumber,
+    /// Index of the transaction in the block.
+    pub transaction_index: u64,
+    /// Transaction hash.
+    pub hash: TxHash,
+    /// EIP-2718 transaction type.
+    pub transaction_type: u64,
+    /// Sender of the transaction.
+    pub from: Address,
+    /// Recipient of the transaction, `None` for contract creations.
+    pub to: Option<Address>,
+    /// Nonce of the sender.
+    pub nonce: u64,
+    /// Transferred value in wei.
+    pub value: String,
+    /// Gas limit of the transaction.
+    pub gas_limit: u64,
+    /// Gas price of legacy and EIP-2930 transactions.
+    pub gas_price: Option<String>,
+    /// Max fee per gas, equal to the gas price of legacy and EIP-2930 transactions.
+    pub max_fee_per_gas: String,
+    /// Max priority fee per gas of EIP-1559 transactions.
+    pub max_priority_fee_per_gas: Option<String>,
+    /// Input data of the transaction.
+    pub input: Vec<u8>,
+}
+
+impl TransactionRow {
+    /// Creates the row of a transaction.
+    pub fn new(
+        block_number: BlockNumber,
+        transaction_index: u64,
+        transaction: &TransactionSigned,
+        from: Address,
+    ) -> Self {
+        Self {
+            block_number,
+            transaction_index,
+            hash: transaction.hash(),
+            transaction_type: transaction.ty() as u64,
+            from,
+            to: transaction.to(),
+            nonce: transaction.nonce(),
+            value: transaction.value().to_string(),
+            gas_limit: transaction.gas_limit(),
+            gas_price: transaction.gas_price().map(|price| price.to_string()),
+            max_fee_per_gas: transaction.max_fee_per_gas().to_string(),
+            max_priority_fee_per_gas: transaction
+                .max_priority_fee_per_gas()
+                .map(|fee| fee.to_string()),
+            input: transaction.input().to_vec(),
+        }
+    }
+}
+
+impl ParquetRecord for TransactionRow {
+    const COLUMNS: &'static [ParquetColumn] = &[
+        ParquetColumn::required("block_number", ParquetType::UInt64),
+        ParquetColumn::required("transaction_index", ParquetType::UInt64),
+        ParquetColumn::required("hash", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("transaction_type", ParquetType::UInt64),
+        ParquetColumn::required("from", ParquetType::FixedBytes(20)),
+        ParquetColumn::optional("to", ParquetType::FixedBytes(20)),
+        ParquetColumn::required("nonce", ParquetType::UInt64),
+        ParquetColumn::required("value", ParquetType::String),
+        ParquetColumn::required("gas_limit", ParquetType::UInt64),
+        ParquetColumn::optional("gas_price", ParquetType::String),
+        ParquetColumn::required("max_fee_per_gas", ParquetType::String),
+        ParquetColumn::optional("max_priority_fee_per_gas", ParquetType::String),
+        ParquetColumn::required("input", ParquetType::Bytes),
+    ];
+
+    fn values(&self) -> Vec<ParquetValue> {
+        vec![
+            ParquetValue::UInt64(self.block_number),
+            ParquetValue::UInt64(self.transaction_index),
+            ParquetValue::Bytes(self.hash.to_vec()),
+            ParquetValue::UInt64(self.transaction_type),
+            ParquetValue::Bytes(self.from.to_vec()),
+            self.to.map_or(ParquetValue::Null, |to| ParquetValue::Bytes(to.to_vec())),
+            ParquetValue::UInt64(self.nonce),
+            ParquetValue::String(self.value.clone()),
+            ParquetValue::UInt64(self.gas_limit),
+            self.gas_price.clone().map_or(ParquetValue::Null, ParquetValue::String),
+            ParquetValue::String(self.max_fee_per_gas.clone()),
+            self.max_priority_fee_per_gas.clone().map_or(ParquetValue::Null, ParquetValue::String),
+            ParquetValue::Bytes(self.input.clone()),
+        ]
+    }
+}
+
+/// A row of the `receipts` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptRow {
+    /// Number of the block that contains the transaction.
+    pub block_number: BlockNumber,
+    /// Index of the transaction in the block.
+    pub transaction_index: u64,
+    /// Transaction hash.
+    pub transaction_hash: TxHash,
+    /// EIP-2718 transaction type.
+    pub transaction_type: u64,
+    /// Whether the transaction was executed successfully.
+    pub success: bool,
+    /// Gas used by the transaction.
+    pub gas_used: u64,
+    /// Gas used by the transaction and all previous transactions of the block.
+    pub cumulative_gas_used: u64,
+    /// Number of logs emitted by the transaction.
+    pub log_count: u64,
+}
+
+impl ReceiptRow {
+    /// Creates the row of a receipt.
+    pub fn new(
+        block_number: BlockNumber,
+        transaction_index: u64,
+        transaction_hash: TxHash,
+        receipt: &Receipt,
+        gas_used: u64,
+    ) -> Self {
+        Self {
+            block_number,
+            transaction_index,
+            transaction_hash,
+            transaction_type: u8::from(receipt.tx_type) as u64,
+            success: receipt.success,
+            gas_used,
+            cumulative_gas_used: receipt.cumulative_gas_used,
+            log_count: receipt.logs.len() as u64,
+        }
+    }
+}
+
+impl ParquetRecord for ReceiptRow {
+    const COLUMNS: &'static [ParquetColumn] = &[
+        ParquetColumn::required("block_number", ParquetType::UInt64),
+        ParquetColumn::required("transaction_index", ParquetType::UInt64),
+        ParquetColumn::required("transaction_hash", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("transaction_type", ParquetType::UInt64),
+        ParquetColumn::required("success", ParquetType::Boolean),
+        ParquetColumn::required("gas_used", ParquetType::UInt64),
+        ParquetColumn::required("cumulative_gas_used", ParquetType::UInt64),
+        ParquetColumn::required("log_count", ParquetType::UInt64),
+    ];
+
+    fn values(&self) -> Vec<ParquetValue> {
+        vec![
+            ParquetValue::UInt64(self.block_number),
+            ParquetValue::UInt64(self.transaction_index),
+            ParquetValue::Bytes(self.transaction_hash.to_vec()),
+            ParquetValue::UInt64(self.transaction_type),
+            ParquetValue::Boolean(self.success),
+            ParquetValue::UInt64(self.gas_used),
+            ParquetValue::UInt64(self.cumulative_gas_used),
+            ParquetValue::UInt64(self.log_count),
+        ]
+    }
+}
+
+/// A row of the `logs` table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRow {
+    /// Number of the block that contains the log.
+    pub block_number: BlockNumber,
+    /// Index of the transaction in the block.
+    pub transaction_index: u64,
+    /// Index of the log in the block.
+    pub log_index: u64,
+    /// Hash of the transaction that emitted the log.
+    pub transaction_hash: TxHash,
+    /// Address of the contract that emitted the log.
+    pub address: Address,
+    /// The topics of the log, at most four.
+    pub topics: Vec<B256>,
+    /// The data of the log.
+    pub data: Vec<u8>,
+}
+
+impl LogRow {
+    /// Creates the row of a log.
+    pub fn new(
+        block_number: BlockNumber,
+        transaction_index: u64,
+        log_index: u64,
+        transaction_hash: TxHash,
+        log: &Log,
+    ) -> Self {
+        Self {
+            block_number,
+            transaction_index,
+            log_index,
+            transaction_hash,
+            address: log.address,
+            topics: log.topics().to_vec(),
+            data: log.data.data.to_vec(),
+        }
+    }
+}
+
+impl ParquetRecord for LogRow {
+    const COLUMNS: &'static [ParquetColumn] = &[
+        ParquetColumn::required("block_number", ParquetType::UInt64),
+        ParquetColumn::required("transaction_index", ParquetType::UInt64),
+        ParquetColumn::required("log_index", ParquetType::UInt64),
+        ParquetColumn::required("transaction_hash", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("address", ParquetType::FixedBytes(20)),
+        ParquetColumn::optional("topic0", ParquetType::FixedBytes(32)),
+        ParquetColumn::optional("topic1", ParquetType::FixedBytes(32)),
+        ParquetColumn::optional("topic2", ParquetType::FixedBytes(32)),
+        ParquetColumn::optional("topic3", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("data", ParquetType::Bytes),
+    ];
+
+    fn values(&self) -> Vec<ParquetValue> {
+        let mut values = vec![
+            ParquetValue::UInt64(self.block_number),
+            ParquetValue::UInt64(self.transaction_index),
+            ParquetValue::UInt64(self.log_index),
+            ParquetValue::Bytes(self.transaction_hash.to_vec()),
+            ParquetValue::Bytes(self.address.to_vec()),
+        ];
+        values.extend((0..4).map(|index| {
+            self.topics
+                .get(index)
+                .map_or(ParquetValue::Null, |topic| ParquetValue::Bytes(topic.to_vec()))
+        }));
+        values.push(ParquetValue::Bytes(self.data.clone()));
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, LogData};
+    use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
+
+    #[test]
+    fn parse_export_parquet_command() {
+        let cmd: Command<EthereumChainSpecParser> =
+            Command::parse_from(["reth", "--tables", "blocks,logs", "--from", "10", "--to", "20"]);
+        assert_eq!(cmd.tables, vec![ExportTable::Blocks, ExportTable::Logs]);
+        assert_eq!((cmd.from, cmd.to), (10, Some(20)));
+
+        let cmd: Command<EthereumChainSpecParser> = Command::parse_from(["reth"]);
+        assert_eq!(cmd.tables.len(), 4);
+        assert_eq!(cmd.to, None);
+    }
+
+    #[test]
+    fn log_row_values() {
+        let log = Log {
+            address: Address::with_last_byte(1),
+            data: LogData::new_unchecked(vec![B256::with_last_byte(2)], Bytes::from_static(&[3])),
+        };
+        let row = LogRow::new(1, 2, 3, TxHash::with_last_byte(4), &log);
+
+        let values = row.values();
+        assert_eq!(values.len(), LogRow::COLUMNS.len());
+        assert_eq!(values[5], ParquetValue::Bytes(B256::with_last_byte(2).to_vec()));
+        assert_eq!(values[6..9], [ParquetValue::Null, ParquetValue::Null, ParquetValue::Null]);
+        assert_eq!(values[9], ParquetValue::Bytes(vec![3]));
+    }
+}
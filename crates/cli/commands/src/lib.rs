@@ -12,6 +12,7 @@ pub mod common;
 pub mod config_cmd;
 pub mod db;
 pub mod dump_genesis;
+pub mod export;
 pub mod import;
 pub mod init_cmd;
 pub mod init_state;
@@ -10,10 +10,10 @@ use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
 use reth_node_builder::{NodeBuilder, WithLaunchContext};
 use reth_node_core::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs,
+        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EventsArgs, NetworkArgs, PayloadBuilderArgs,
         PruningArgs, RpcServerArgs, TxPoolArgs,
     },
-    node_config::NodeConfig,
+    node_config::{NodeConfig, DEFAULT_METRICS_SNAPSHOT_INTERVAL_SECS},
     version,
 };
 use std::{ffi::OsString, fmt, future::Future, net::SocketAddr, path::PathBuf, sync::Arc};
@@ -48,6 +48,21 @@ pub struct NodeCommand<
     #[arg(long, value_name = "SOCKET", value_parser = parse_socket_address, help_heading = "Metrics")]
     pub metrics: Option<SocketAddr>,
 
+    /// The interval in seconds at which a snapshot of key metrics is persisted to the datadir, so
+    /// that it's available for post-crash analysis with `reth debug last-metrics`.
+    ///
+    /// Set to 0 to disable the snapshots.
+    #[arg(long = "metrics.snapshot-interval", value_name = "SECONDS", default_value_t = DEFAULT_METRICS_SNAPSHOT_INTERVAL_SECS, help_heading = "Metrics")]
+    pub metrics_snapshot_interval: u64,
+
+    /// The memory budget in MiB that is shared by the major caches of the node: the transaction
+    /// pool, the engine block buffer, the RPC state cache and the body downloader buffer.
+    ///
+    /// The limits of the caches are shrunk proportionally so that their combined size fits into
+    /// the budget. By default the caches are only bounded by their own limits.
+    #[arg(long = "memory.budget", value_name = "MiB")]
+    pub memory_budget: Option<u64>,
+
     /// Add a new instance of a node.
     ///
     /// Configures the ports of the node to avoid conflicts with the defaults.
@@ -107,6 +122,10 @@ pub struct NodeCommand<
     #[command(flatten)]
     pub pruning: PruningArgs,
 
+    /// All canonical chain events publisher related arguments with --events prefix
+    #[command(flatten)]
+    pub events: EventsArgs,
+
     /// Additional cli arguments
     #[command(flatten, next_help_heading = "Extension")]
     pub ext: Ext,
@@ -149,6 +168,8 @@ impl<
             config,
             chain,
             metrics,
+            metrics_snapshot_interval,
+            memory_budget,
             instance,
             with_unused_ports,
             network,
@@ -159,6 +180,7 @@ impl<
             db,
             dev,
             pruning,
+            events,
             ext,
         } = self;
 
@@ -168,6 +190,8 @@ impl<
             config,
             chain,
             metrics,
+            metrics_snapshot_interval,
+            memory_budget,
             instance,
             network,
             rpc,
@@ -177,6 +201,7 @@ impl<
             db,
             dev,
             pruning,
+            events,
         };
 
         let data_dir = node_config.datadir();
@@ -0,0 +1,97 @@
+//! DNS subcommand of P2P Debugging tool.
+
+use clap::Parser;
+use reth_cli_util::{get_secret_key, parse_duration_from_secs};
+use reth_dns_discovery::{tree::LinkEntry, DnsTree};
+use reth_network::{NetworkHandle, NetworkPrimitives};
+use secp256k1::SecretKey;
+use std::{
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Generates a signed [EIP-1459](https://eips.ethereum.org/EIPS/eip-1459) node list from the peers
+/// discovered by the node, which can be published as DNS TXT records.
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The domain the node list is published under.
+    #[arg(long, value_name = "DOMAIN")]
+    domain: String,
+
+    /// The sequence number of the node list.
+    ///
+    /// Defaults to the current unix timestamp, so that a republished list replaces the previous
+    /// one.
+    #[arg(long, value_name = "SEQ")]
+    seq: Option<u64>,
+
+    /// Links to other node lists to include, e.g. `enrtree://<key>@nodes.example.org`.
+    #[arg(long = "link", value_name = "ENRTREE")]
+    links: Vec<LinkEntry>,
+
+    /// File with additional ENRs to include, one per line.
+    #[arg(long, value_name = "FILE")]
+    enr_file: Option<PathBuf>,
+
+    /// Secret key used to sign the node list.
+    ///
+    /// Defaults to the p2p secret key of the node. A new key is generated if the file does not
+    /// exist.
+    #[arg(long, value_name = "PATH")]
+    signing_key: Option<PathBuf>,
+
+    /// How long to discover peers before generating the node list, in seconds.
+    ///
+    /// Only peers discovered over discv5 are included, see `--enable-discv5-discovery`.
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration_from_secs, default_value = "30")]
+    discovery_duration: Duration,
+
+    /// Writes the TXT records to the given file as JSON instead of printing them.
+    #[arg(long, value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+impl Command {
+    /// Execute `p2p dns-publish` command.
+    pub async fn execute<N: NetworkPrimitives>(
+        self,
+        network: &NetworkHandle<N>,
+        secret_key: SecretKey,
+    ) -> eyre::Result<()> {
+        let signing_key = match &self.signing_key {
+            Some(path) => get_secret_key(path)?,
+            None => secret_key,
+        };
+
+        println!("Discovering peers for {}s...", self.discovery_duration.as_secs());
+        tokio::time::sleep(self.discovery_duration).await;
+        let mut enrs = network.discv5_peer_enrs();
+        println!("Discovered {} peers", enrs.len());
+
+        if let Some(path) = &self.enr_file {
+            for enr in reth_fs_util::read_to_string(path)?.lines().map(str::trim) {
+                if enr.is_empty() {
+                    continue
+                }
+                enrs.push(enr.parse().map_err(|err| eyre::eyre!("invalid ENR {enr}: {err}"))?);
+            }
+        }
+
+        let seq = self.seq.unwrap_or_else(|| {
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+        });
+        let tree = DnsTree::new(seq, enrs, self.links, &signing_key);
+        let records = tree.txt_records(&self.domain);
+
+        match &self.output {
+            Some(path) => {
+                reth_fs_util::write_json_file(path, &records)?;
+                println!("Wrote {} TXT records to {}", records.len(), path.display());
+            }
+            None => println!("{}", serde_json::to_string_pretty(&records)?),
+        }
+        println!("Node list is available at {}", tree.link(self.domain));
+
+        Ok(())
+    }
+}
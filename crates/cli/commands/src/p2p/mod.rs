@@ -16,6 +16,7 @@ use reth_node_core::{
     utils::get_single_header,
 };
 
+mod dns;
 mod rlpx;
 
 /// `reth p2p` command
@@ -71,6 +72,8 @@ pub enum Subcommands {
     },
     // RLPx utilities
     Rlpx(rlpx::Command),
+    /// Generate a signed EIP-1459 DNS node list from the discovered peers
+    DnsPublish(dns::Command),
 }
 
 impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
@@ -158,6 +161,9 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
             Subcommands::Rlpx(command) => {
                 command.execute().await?;
             }
+            Subcommands::DnsPublish(command) => {
+                command.execute(&network, p2p_secret_key).await?;
+            }
         }
 
         Ok(())
@@ -151,6 +151,31 @@ impl<C: ChainSpecParser> Command<C> {
                 reset_stage_checkpoint(tx, StageId::TransactionLookup)?;
                 insert_genesis_header(&provider_rw, &self.env.chain)?;
             }
+            StageEnum::TxSelectors => {
+                tx.clear::<tables::TransactionSelectors>()?;
+                reset_prune_checkpoint(tx, PruneSegment::TransactionSelectors)?;
+
+                reset_stage_checkpoint(tx, StageId::IndexTransactionSelectors)?;
+            }
+            StageEnum::LogIndex => {
+                tx.clear::<tables::LogAddressIndex>()?;
+                tx.clear::<tables::LogTopicIndex>()?;
+
+                reset_stage_checkpoint(tx, StageId::IndexLogs)?;
+            }
+            StageEnum::WithdrawalHistory => {
+                tx.clear::<tables::AddressWithdrawals>()?;
+                tx.clear::<tables::ValidatorWithdrawals>()?;
+                reset_prune_checkpoint(tx, PruneSegment::WithdrawalHistory)?;
+
+                reset_stage_checkpoint(tx, StageId::IndexWithdrawalHistory)?;
+            }
+            StageEnum::AddressTransactions => {
+                tx.clear::<tables::AddressTransactions>()?;
+                reset_prune_checkpoint(tx, PruneSegment::AddressTransactions)?;
+
+                reset_stage_checkpoint(tx, StageId::IndexAddressTransactions)?;
+            }
         }
 
         tx.put::<tables::StageCheckpoints>(StageId::Finish.to_string(), Default::default())?;
@@ -10,7 +10,10 @@ use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_cli::chainspec::ChainSpecParser;
 use reth_cli_runner::CliContext;
 use reth_cli_util::get_secret_key;
-use reth_config::config::{HashingConfig, SenderRecoveryConfig, TransactionLookupConfig};
+use reth_config::config::{
+    HashingConfig, IndexAddressTransactionsConfig, IndexTransactionSelectorsConfig,
+    SenderRecoveryConfig, TransactionLookupConfig,
+};
 use reth_db_api::database_metrics::DatabaseMetrics;
 use reth_downloaders::{
     bodies::bodies::BodiesDownloaderBuilder,
@@ -40,8 +43,9 @@ use reth_provider::{
 use reth_stages::{
     stages::{
         AccountHashingStage, BodyStage, ExecutionStage, HeaderStage, IndexAccountHistoryStage,
-        IndexStorageHistoryStage, MerkleStage, SenderRecoveryStage, StorageHashingStage,
-        TransactionLookupStage,
+        IndexAddressTransactionsStage, IndexLogsStage, IndexStorageHistoryStage,
+        IndexTransactionSelectorsStage, IndexWithdrawalHistoryStage, MerkleStage,
+        SenderRecoveryStage, StorageHashingStage, TransactionLookupStage,
     },
     ExecInput, ExecOutput, ExecutionStageThresholds, Stage, StageError, StageExt, UnwindInput,
     UnwindOutput,
@@ -279,6 +283,22 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                     )),
                     None,
                 ),
+                StageEnum::TxSelectors => (
+                    Box::new(IndexTransactionSelectorsStage::new(
+                        IndexTransactionSelectorsConfig { chunk_size: batch_size },
+                        etl_config,
+                        prune_modes.transaction_selectors,
+                    )),
+                    None,
+                ),
+                StageEnum::AddressTransactions => (
+                    Box::new(IndexAddressTransactionsStage::new(
+                        IndexAddressTransactionsConfig { chunk_size: batch_size },
+                        etl_config,
+                        prune_modes.address_transactions,
+                    )),
+                    None,
+                ),
                 StageEnum::AccountHashing => (
                     Box::new(AccountHashingStage::new(
                         HashingConfig { clean_threshold: 1, commit_threshold: batch_size },
@@ -313,6 +333,17 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                     )),
                     None,
                 ),
+                StageEnum::LogIndex => {
+                    (Box::new(IndexLogsStage::new(config.stages.index_logs, etl_config)), None)
+                }
+                StageEnum::WithdrawalHistory => (
+                    Box::new(IndexWithdrawalHistoryStage::new(
+                        config.stages.index_withdrawal_history,
+                        etl_config,
+                        prune_modes.withdrawal_history,
+                    )),
+                    None,
+                ),
                 _ => return Ok(()),
             };
         if let Some(unwind_stage) = &unwind_stage {
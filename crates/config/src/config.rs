@@ -116,10 +116,18 @@ pub struct StageConfig {
     pub merkle: MerkleConfig,
     /// Transaction Lookup stage configuration.
     pub transaction_lookup: TransactionLookupConfig,
+    /// Index Transaction Selectors stage configuration.
+    pub index_transaction_selectors: IndexTransactionSelectorsConfig,
     /// Index Account History stage configuration.
     pub index_account_history: IndexHistoryConfig,
     /// Index Storage History stage configuration.
     pub index_storage_history: IndexHistoryConfig,
+    /// Index Logs stage configuration.
+    pub index_logs: IndexLogsConfig,
+    /// Index Withdrawal History stage configuration.
+    pub index_withdrawal_history: IndexHistoryConfig,
+    /// Index Address Transactions stage configuration.
+    pub index_address_transactions: IndexAddressTransactionsConfig,
     /// Common ETL related configuration.
     pub etl: EtlConfig,
 }
@@ -239,6 +247,18 @@ pub struct ExecutionConfig {
         deserialize_with = "deserialize_duration"
     )]
     pub max_duration: Option<Duration>,
+    /// The number of blocks per epoch for tracking the epochs in which accounts and storage slots
+    /// were last touched.
+    ///
+    /// Touch epochs are only tracked if this is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub touch_epoch_length: Option<u64>,
+    /// The maximum number of executed blocks whose changesets are not indexed in the history
+    /// tables yet, for which historical state is still served.
+    ///
+    /// Historical state lookups walk the changesets of these blocks, so more blocks make lookups
+    /// slower.
+    pub max_unindexed_history_blocks: u64,
 }
 
 impl Default for ExecutionConfig {
@@ -250,6 +270,8 @@ impl Default for ExecutionConfig {
             max_cumulative_gas: Some(30_000_000 * 50_000),
             // 10 minutes
             max_duration: Some(Duration::from_secs(10 * 60)),
+            touch_epoch_length: None,
+            max_unindexed_history_blocks: 10_000,
         }
     }
 }
@@ -325,6 +347,34 @@ impl Default for TransactionLookupConfig {
     }
 }
 
+/// Index Transaction Selectors stage configuration.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct IndexTransactionSelectorsConfig {
+    /// The maximum number of transactions to process before writing to disk.
+    pub chunk_size: u64,
+}
+
+impl Default for IndexTransactionSelectorsConfig {
+    fn default() -> Self {
+        Self { chunk_size: 5_000_000 }
+    }
+}
+
+/// Index Address Transactions stage configuration.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct IndexAddressTransactionsConfig {
+    /// The maximum number of transactions to process before writing to disk.
+    pub chunk_size: u64,
+}
+
+impl Default for IndexAddressTransactionsConfig {
+    fn default() -> Self {
+        Self { chunk_size: 5_000_000 }
+    }
+}
+
 /// Common ETL related configuration.
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
@@ -373,6 +423,24 @@ impl Default for IndexHistoryConfig {
     }
 }
 
+/// Index Logs stage configuration.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct IndexLogsConfig {
+    /// Whether the log index is maintained.
+    ///
+    /// The log index is used by `eth_getLogs` to skip blocks without matching logs.
+    pub enabled: bool,
+    /// The maximum number of blocks to process before committing progress to the database.
+    pub commit_threshold: u64,
+}
+
+impl Default for IndexLogsConfig {
+    fn default() -> Self {
+        Self { enabled: false, commit_threshold: 100_000 }
+    }
+}
+
 /// Pruning configuration.
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
@@ -382,11 +450,20 @@ pub struct PruneConfig {
     /// Pruning configuration for every part of the data that can be pruned.
     #[serde(alias = "parts")]
     pub segments: PruneModes,
+    /// Adaptive scheduling of the pruner runs based on the load of the node.
+    ///
+    /// If not set, the pruner runs every `block_interval` blocks regardless of the load.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttle: Option<PruneThrottleConfig>,
 }
 
 impl Default for PruneConfig {
     fn default() -> Self {
-        Self { block_interval: DEFAULT_BLOCK_INTERVAL, segments: PruneModes::none() }
+        Self {
+            block_interval: DEFAULT_BLOCK_INTERVAL,
+            segments: PruneModes::none(),
+            throttle: None,
+        }
     }
 }
 
@@ -402,14 +479,20 @@ impl PruneConfig {
         let Some(other) = other else { return };
         let Self {
             block_interval,
+            throttle,
             segments:
                 PruneModes {
                     sender_recovery,
                     transaction_lookup,
+                    transaction_hash_index,
+                    transaction_selectors,
+                    withdrawal_history,
+                    address_transactions,
                     receipts,
                     account_history,
                     storage_history,
                     receipts_log_filter,
+                    retained_addresses,
                 },
         } = other;
 
@@ -421,6 +504,12 @@ impl PruneConfig {
         // Merge the various segment prune modes
         self.segments.sender_recovery = self.segments.sender_recovery.or(sender_recovery);
         self.segments.transaction_lookup = self.segments.transaction_lookup.or(transaction_lookup);
+        self.segments.transaction_hash_index |= transaction_hash_index;
+        self.segments.transaction_selectors =
+            self.segments.transaction_selectors.or(transaction_selectors);
+        self.segments.withdrawal_history = self.segments.withdrawal_history.or(withdrawal_history);
+        self.segments.address_transactions =
+            self.segments.address_transactions.or(address_transactions);
         self.segments.receipts = self.segments.receipts.or(receipts);
         self.segments.account_history = self.segments.account_history.or(account_history);
         self.segments.storage_history = self.segments.storage_history.or(storage_history);
@@ -428,6 +517,40 @@ impl PruneConfig {
         if self.segments.receipts_log_filter.0.is_empty() && !receipts_log_filter.0.is_empty() {
             self.segments.receipts_log_filter = receipts_log_filter;
         }
+
+        if self.segments.retained_addresses.is_empty() {
+            self.segments.retained_addresses = retained_addresses;
+        }
+
+        self.throttle = self.throttle.or(throttle);
+    }
+}
+
+/// Adaptive pruner scheduling configuration.
+///
+/// Pruner runs are deferred while block processing latency or the number of in-flight RPC
+/// requests exceeds the thresholds, and the data left over by interrupted runs is pruned while the
+/// node is idle.
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct PruneThrottleConfig {
+    /// Block processing latency above which pruner runs are deferred.
+    #[serde(with = "humantime_serde")]
+    pub max_block_processing_latency: Duration,
+    /// Number of in-flight RPC requests above which pruner runs are deferred.
+    pub max_rpc_queue_depth: usize,
+    /// Maximum number of blocks a pruner run can be deferred for. Once reached, the pruner runs
+    /// regardless of the load, so that the data to prune doesn't grow unbounded.
+    pub max_deferred_blocks: u64,
+}
+
+impl Default for PruneThrottleConfig {
+    fn default() -> Self {
+        Self {
+            max_block_processing_latency: Duration::from_millis(500),
+            max_rpc_queue_depth: 128,
+            max_deferred_blocks: 300,
+        }
     }
 }
 
@@ -452,11 +575,16 @@ where
 #[cfg(test)]
 mod tests {
     use super::{Config, EXTENSION};
-    use crate::PruneConfig;
+    use crate::{PruneConfig, PruneThrottleConfig};
     use alloy_primitives::Address;
     use reth_network_peers::TrustedPeer;
     use reth_prune_types::{PruneMode, PruneModes, ReceiptsLogPruneConfig};
-    use std::{collections::BTreeMap, path::Path, str::FromStr, time::Duration};
+    use std::{
+        collections::{BTreeMap, BTreeSet},
+        path::Path,
+        str::FromStr,
+        time::Duration,
+    };
 
     fn with_tempdir(filename: &str, proc: fn(&std::path::Path)) {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -940,6 +1068,10 @@ receipts = 'full'
             segments: PruneModes {
                 sender_recovery: Some(PruneMode::Full),
                 transaction_lookup: None,
+                transaction_hash_index: false,
+                transaction_selectors: None,
+                withdrawal_history: None,
+                address_transactions: None,
                 receipts: Some(PruneMode::Distance(1000)),
                 account_history: None,
                 storage_history: Some(PruneMode::Before(5000)),
@@ -947,7 +1079,9 @@ receipts = 'full'
                     Address::random(),
                     PruneMode::Full,
                 )])),
+                retained_addresses: Default::default(),
             },
+            throttle: None,
         };
 
         let config2 = PruneConfig {
@@ -955,6 +1089,10 @@ receipts = 'full'
             segments: PruneModes {
                 sender_recovery: Some(PruneMode::Distance(500)),
                 transaction_lookup: Some(PruneMode::Full),
+                transaction_hash_index: true,
+                transaction_selectors: Some(PruneMode::Before(0)),
+                withdrawal_history: Some(PruneMode::Distance(1000)),
+                address_transactions: Some(PruneMode::Before(0)),
                 receipts: Some(PruneMode::Full),
                 account_history: Some(PruneMode::Distance(2000)),
                 storage_history: Some(PruneMode::Distance(3000)),
@@ -962,7 +1100,9 @@ receipts = 'full'
                     (Address::random(), PruneMode::Distance(1000)),
                     (Address::random(), PruneMode::Before(2000)),
                 ])),
+                retained_addresses: BTreeSet::from([Address::with_last_byte(1)]),
             },
+            throttle: Some(PruneThrottleConfig::default()),
         };
 
         let original_filter = config1.segments.receipts_log_filter.clone();
@@ -973,10 +1113,40 @@ receipts = 'full'
         assert_eq!(config1.block_interval, 10);
         assert_eq!(config1.segments.sender_recovery, Some(PruneMode::Full));
         assert_eq!(config1.segments.transaction_lookup, Some(PruneMode::Full));
+        assert!(config1.segments.transaction_hash_index);
+        assert_eq!(config1.segments.transaction_selectors, Some(PruneMode::Before(0)));
+        assert_eq!(config1.segments.withdrawal_history, Some(PruneMode::Distance(1000)));
+        assert_eq!(config1.segments.address_transactions, Some(PruneMode::Before(0)));
         assert_eq!(config1.segments.receipts, Some(PruneMode::Distance(1000)));
         assert_eq!(config1.segments.account_history, Some(PruneMode::Distance(2000)));
         assert_eq!(config1.segments.storage_history, Some(PruneMode::Before(5000)));
         assert_eq!(config1.segments.receipts_log_filter, original_filter);
+        assert_eq!(
+            config1.segments.retained_addresses,
+            BTreeSet::from([Address::with_last_byte(1)])
+        );
+        assert_eq!(config1.throttle, Some(PruneThrottleConfig::default()));
+    }
+
+    #[test]
+    fn test_prune_throttle_config() {
+        let s = r"#
+[prune]
+block_interval = 5
+
+[prune.throttle]
+max_block_processing_latency = '250ms'
+max_rpc_queue_depth = 64
+#";
+        let conf: Config = toml::from_str(s).unwrap();
+        assert_eq!(
+            conf.prune.unwrap().throttle,
+            Some(PruneThrottleConfig {
+                max_block_processing_latency: Duration::from_millis(250),
+                max_rpc_queue_depth: 64,
+                ..Default::default()
+            })
+        );
     }
 
     #[test]
@@ -6,8 +6,8 @@ use alloy_rpc_types_engine::{
 };
 use futures::TryFutureExt;
 use reth_engine_primitives::{
-    BeaconEngineMessage, BeaconOnNewPayloadError, EngineApiMessageVersion, EngineTypes,
-    OnForkChoiceUpdated,
+    BeaconEngineMessage, BeaconOnNewPayloadError, BlockAccessList, EngineApiMessageVersion,
+    EngineTypes, OnForkChoiceUpdated,
 };
 use reth_errors::RethResult;
 use reth_tokio_util::{EventSender, EventStream};
@@ -49,7 +49,33 @@ where
         sidecar: ExecutionPayloadSidecar,
     ) -> Result<PayloadStatus, BeaconOnNewPayloadError> {
         let (tx, rx) = oneshot::channel();
-        let _ = self.to_engine.send(BeaconEngineMessage::NewPayload { payload, sidecar, tx });
+        let _ = self.to_engine.send(BeaconEngineMessage::NewPayload {
+            payload,
+            sidecar,
+            block_access_list: None,
+            tx,
+        });
+        rx.await.map_err(|_| BeaconOnNewPayloadError::EngineUnavailable)?
+    }
+
+    /// Sends a new payload message with its EIP-7928 block access list to the beacon consensus
+    /// engine and waits for a response.
+    ///
+    /// The payload is invalid if the block access list doesn't match the block access list
+    /// generated by executing the payload.
+    pub async fn new_payload_with_block_access_list(
+        &self,
+        payload: ExecutionPayload,
+        sidecar: ExecutionPayloadSidecar,
+        block_access_list: BlockAccessList,
+    ) -> Result<PayloadStatus, BeaconOnNewPayloadError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_engine.send(BeaconEngineMessage::NewPayload {
+            payload,
+            sidecar,
+            block_access_list: Some(block_access_list),
+            tx,
+        });
         rx.await.map_err(|_| BeaconOnNewPayloadError::EngineUnavailable)?
     }
 
@@ -1873,7 +1873,8 @@ where
                         } => {
                             this.on_forkchoice_updated(state, payload_attrs, tx);
                         }
-                        BeaconEngineMessage::NewPayload { payload, sidecar, tx } => {
+                        // block access lists are only verified by the engine tree
+                        BeaconEngineMessage::NewPayload { payload, sidecar, tx, .. } => {
                             match this.on_new_payload(payload, sidecar) {
                                 Ok(Either::Right(block)) => {
                                     this.set_blockchain_tree_action(
@@ -238,6 +238,11 @@ pub enum ConsensusError {
     #[display("mismatched block requests hash: {_0}")]
     BodyRequestsHashDiff(GotExpectedBoxed<B256>),
 
+    /// Error when the hash of the block access list of a payload is different from the hash of
+    /// the block access list generated by executing the block.
+    #[display("mismatched block access list hash: {_0}")]
+    BlockAccessListHashDiff(GotExpectedBoxed<B256>),
+
     /// Error when a block with a specific hash and number is already known.
     #[display("block with [hash={hash}, number={number}] is already known")]
     BlockKnown {
@@ -0,0 +1,281 @@
+use alloy_eips::eip4844::{kzg_to_versioned_hash, Blob, BYTES_PER_BLOB};
+use alloy_primitives::{Bytes, B256};
+use alloy_rpc_types_beacon::sidecar::BeaconBlobBundle;
+use reqwest::{Client, StatusCode};
+use std::future::Future;
+
+/// Version of the blob encoding of batch data.
+pub const BLOB_ENCODING_VERSION: u8 = 0;
+
+/// Maximum length of the batch data that can be encoded into a single blob.
+pub const MAX_BLOB_DATA_LEN: usize = (4 * 31 + 3) * 1024 - 4;
+
+/// Number of field elements that are decoded together, yielding 127 bytes of data.
+const ROUNDS: usize = 1024;
+
+/// Seconds per slot of the beacon chain of Ethereum mainnet and its testnets.
+const DEFAULT_SECONDS_PER_SLOT: u64 = 12;
+
+/// Errors returned by a [`BlobSource`].
+#[derive(Debug, thiserror::Error)]
+pub enum BlobSourceError {
+    /// The request to the beacon node failed.
+    #[error(transparent)]
+    Http(#[from] reqwest::Error),
+    /// The block timestamp is before the genesis of the beacon chain.
+    #[error("timestamp {0} is before the beacon chain genesis")]
+    TimestampBeforeGenesis(u64),
+}
+
+/// A source of the blobs included in L1 blocks.
+pub trait BlobSource: Send + Sync {
+    /// Returns the blobs with the given versioned hashes that were included in the L1 block with
+    /// the given timestamp.
+    ///
+    /// Blobs that are not available are returned as `None`, in the order of the versioned hashes.
+    fn blobs(
+        &self,
+        timestamp: u64,
+        versioned_hashes: &[B256],
+    ) -> impl Future<Output = Result<Vec<Option<Blob>>, BlobSourceError>> + Send;
+}
+
+/// [`BlobSource`] that fetches blob sidecars from the API of a beacon node.
+///
+/// The beacon node only serves the sidecars of the retention period of the network, which is about
+/// 18 days, unless it's configured as an archive node.
+#[derive(Debug, Clone)]
+pub struct BeaconBlobSource {
+    http_client: Client,
+    base_url: String,
+    genesis_time: u64,
+    seconds_per_slot: u64,
+}
+
+impl BeaconBlobSource {
+    /// Creates a new blob source for the beacon node at the given URL, given the genesis time of
+    /// its beacon chain.
+    pub fn new(base_url: impl Into<String>, genesis_time: u64) -> Self {
+        Self {
+            http_client: Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            genesis_time,
+            seconds_per_slot: DEFAULT_SECONDS_PER_SLOT,
+        }
+    }
+
+    /// Sets the duration of a slot of the beacon chain.
+    pub const fn with_seconds_per_slot(mut self, seconds_per_slot: u64) -> Self {
+        self.seconds_per_slot = seconds_per_slot;
+        self
+    }
+
+    /// Returns the slot of the block with the given timestamp.
+    const fn slot(&self, timestamp: u64) -> Result<u64, BlobSourceError> {
+        if timestamp < self.genesis_time {
+            return Err(BlobSourceError::TimestampBeforeGenesis(timestamp))
+        }
+        Ok((timestamp - self.genesis_time) / self.seconds_per_slot)
+    }
+}
+
+impl BlobSource for BeaconBlobSource {
+    async fn blobs(
+        &self,
+        timestamp: u64,
+        versioned_hashes: &[B256],
+    ) -> Result<Vec<Option<Blob>>, BlobSourceError> {
+        let slot = self.slot(timestamp)?;
+        let response = self
+            .http_client
+            .get(format!("{}/eth/v1/beacon/blob_sidecars/{slot}", self.base_url))
+            .send()
+            .await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            // the slot is empty or its sidecars are pruned
+            return Ok(vec![None; versioned_hashes.len()])
+        }
+        let bundle: BeaconBlobBundle = response.error_for_status()?.json().await?;
+
+        let mut blobs = bundle
+            .data
+            .into_iter()
+            .map(|sidecar| (kzg_to_versioned_hash(sidecar.kzg_commitment.as_slice()), sidecar.blob))
+            .collect::<Vec<_>>();
+        Ok(versioned_hashes
+            .iter()
+            .map(|hash| {
+                let index = blobs.iter().position(|(versioned_hash, _)| versioned_hash == hash)?;
+                Some(*blobs.swap_remove(index).1)
+            })
+            .collect())
+    }
+}
+
+/// Errors that can occur when decoding the batch data of a blob.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum BlobDecodingError {
+    /// The encoding version is not supported.
+    #[error("unsupported blob encoding version {0}")]
+    UnsupportedVersion(u8),
+    /// The encoded data length exceeds [`MAX_BLOB_DATA_LEN`].
+    #[error("blob data length {0} exceeds the maximum length")]
+    TooLarge(usize),
+    /// A field element has one of its two high order bits set.
+    #[error("invalid field element {0}")]
+    InvalidFieldElement(usize),
+    /// The blob has non-zero bytes past the end of its data.
+    #[error("non-zero byte past the end of the data at position {0}")]
+    TrailingData(usize),
+}
+
+/// Decodes the batch data of a blob.
+///
+/// Each field element of a blob carries 31 bytes of data in its lower bytes and 6 bits in its
+/// high order byte, so that 4 field elements encode 127 bytes. The first field element starts
+/// with the encoding version and the data length as a big-endian 24-bit integer.
+pub fn decode_blob_data(blob: &Blob) -> Result<Bytes, BlobDecodingError> {
+    let blob = blob.as_slice();
+    if blob[1] != BLOB_ENCODING_VERSION {
+        return Err(BlobDecodingError::UnsupportedVersion(blob[1]))
+    }
+    let len = u32::from_be_bytes([0, blob[2], blob[3], blob[4]]) as usize;
+    if len > MAX_BLOB_DATA_LEN {
+        return Err(BlobDecodingError::TooLarge(len))
+    }
+
+    let mut output = vec![0u8; MAX_BLOB_DATA_LEN];
+    let mut encoded = [0u8; 4];
+
+    // the version and the length take the first 4 data bytes of the first field element
+    output[..27].copy_from_slice(&blob[5..32]);
+    encoded[0] = blob[0];
+    let mut output_pos = 28;
+    let mut blob_pos = 32;
+    for byte in encoded.iter_mut().skip(1) {
+        *byte = decode_field_element(blob, &mut blob_pos, &mut output, &mut output_pos)?;
+    }
+    output_pos = reassemble_bytes(encoded, &mut output, output_pos);
+
+    for _ in 1..ROUNDS {
+        if output_pos >= len {
+            break
+        }
+        for byte in &mut encoded {
+            *byte = decode_field_element(blob, &mut blob_pos, &mut output, &mut output_pos)?;
+        }
+        output_pos = reassemble_bytes(encoded, &mut output, output_pos);
+    }
+
+    if let Some(pos) = output[len..].iter().position(|byte| *byte != 0) {
+        return Err(BlobDecodingError::TrailingData(len + pos))
+    }
+    if let Some(pos) = blob[blob_pos..].iter().position(|byte| *byte != 0) {
+        return Err(BlobDecodingError::TrailingData(blob_pos + pos))
+    }
+
+    output.truncate(len);
+    Ok(output.into())
+}
+
+/// Copies the lower 31 bytes of the next field element to the output and returns its high order
+/// byte.
+fn decode_field_element(
+    blob: &[u8],
+    blob_pos: &mut usize,
+    output: &mut [u8],
+    output_pos: &mut usize,
+) -> Result<u8, BlobDecodingError> {
+    let byte = blob[*blob_pos];
+    if byte & 0b1100_0000 != 0 {
+        return Err(BlobDecodingError::InvalidFieldElement(*blob_pos / 32))
+    }
+    output[*output_pos..*output_pos + 31].copy_from_slice(&blob[*blob_pos + 1..*blob_pos + 32]);
+    *blob_pos += 32;
+    *output_pos += 32;
+    Ok(byte)
+}
+
+/// Reassembles the 3 bytes that are encoded in the high order bytes of 4 field elements, and
+/// writes them to the gaps that were left between the data of the field elements.
+///
+/// Returns the position of the next round in the output.
+fn reassemble_bytes(encoded: [u8; 4], output: &mut [u8], output_pos: usize) -> usize {
+    // a round yields 127 bytes, not 128
+    let output_pos = output_pos - 1;
+    let x = (encoded[0] & 0b0011_1111) | ((encoded[1] & 0b0011_0000) << 2);
+    let y = (encoded[1] & 0b0000_1111) | ((encoded[3] & 0b0000_1111) << 4);
+    let z = (encoded[2] & 0b0011_1111) | ((encoded[3] & 0b0011_0000) << 2);
+    output[output_pos - 32] = z;
+    output[output_pos - 32 * 2] = y;
+    output[output_pos - 32 * 3] = x;
+    output_pos
+}
+
+const _: () = assert!(ROUNDS * 4 * 32 == BYTES_PER_BLOB);
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Encodes the data into a blob, which is the inverse of [`decode_blob_data`].
+    pub(crate) fn encode_blob_data(data: &[u8]) -> Blob {
+        assert!(data.len() <= MAX_BLOB_DATA_LEN);
+
+        // prefix the data with the version and the length, and split it into rounds of 127 bytes
+        let mut input = vec![0, BLOB_ENCODING_VERSION];
+        input.extend_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+        input.extend_from_slice(data);
+
+        let mut blob = Blob::default();
+        for (round, chunk) in input[1..].chunks(127).enumerate() {
+            let mut chunk = chunk.to_vec();
+            chunk.resize(127, 0);
+            let fields = [&chunk[..31], &chunk[32..63], &chunk[64..95], &chunk[96..127]];
+            let (x, y, z) = (chunk[31], chunk[63], chunk[95]);
+            let high = [
+                x & 0b0011_1111,
+                (y & 0b0000_1111) | ((x & 0b1100_0000) >> 2),
+                z & 0b0011_1111,
+                ((z & 0b1100_0000) >> 2) | ((y & 0b1111_0000) >> 4),
+            ];
+            for (i, (field, high)) in fields.into_iter().zip(high).enumerate() {
+                let pos = (round * 4 + i) * 32;
+                blob[pos] = high;
+                blob[pos + 1..pos + 32].copy_from_slice(field);
+            }
+        }
+        blob
+    }
+
+    #[test]
+    fn blob_data_roundtrip() {
+        for len in [0, 1, 27, 28, 123, 124, 1000, MAX_BLOB_DATA_LEN] {
+            let data = (0..len).map(|i| (i * 7 + 3) as u8).collect::<Vec<_>>();
+            let blob = encode_blob_data(&data);
+            assert_eq!(decode_blob_data(&blob).unwrap(), data, "length {len}");
+        }
+    }
+
+    #[test]
+    fn reject_invalid_blobs() {
+        let mut blob = encode_blob_data(b"batch");
+        blob[1] = 1;
+        assert_eq!(decode_blob_data(&blob), Err(BlobDecodingError::UnsupportedVersion(1)));
+
+        let mut blob = encode_blob_data(b"batch");
+        blob[2..5].copy_from_slice(&[0xff; 3]);
+        assert_eq!(decode_blob_data(&blob), Err(BlobDecodingError::TooLarge(0xff_ffff)));
+
+        let mut blob = encode_blob_data(&[1; 200]);
+        blob[32 * 5] = 0b1000_0000;
+        assert_eq!(decode_blob_data(&blob), Err(BlobDecodingError::InvalidFieldElement(5)));
+
+        let mut blob = encode_blob_data(b"batch");
+        blob[BYTES_PER_BLOB - 1] = 1;
+        assert_eq!(
+            decode_blob_data(&blob),
+            Err(BlobDecodingError::TrailingData(BYTES_PER_BLOB - 1))
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use alloy_primitives::Bytes;
+use std::future::Future;
+
+/// A client of a data availability layer.
+///
+/// Batch data is addressed by a commitment of the DA layer, e.g. a transaction hash or a blob
+/// versioned hash on L1, or a height and share commitment on Celestia.
+pub trait DataAvailabilityClient: Send + Sync {
+    /// The commitment that addresses batch data on the DA layer.
+    type Commitment: Send + Sync;
+
+    /// The error returned if the DA layer can't be queried.
+    type Error: core::error::Error + Send + Sync + 'static;
+
+    /// Fetches the batch data for the given commitment.
+    ///
+    /// Returns `None` if the data is not available.
+    fn fetch_batch(
+        &self,
+        commitment: &Self::Commitment,
+    ) -> impl Future<Output = Result<Option<Bytes>, Self::Error>> + Send;
+}
@@ -0,0 +1,165 @@
+use crate::{
+    decode_blob_data, BlobDecodingError, BlobSource, BlobSourceError, DataAvailabilityClient,
+};
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_primitives::{BlockNumber, Bytes, TxHash, B256};
+use reth_storage_api::{HeaderProvider, TransactionsProvider};
+use reth_storage_errors::provider::ProviderError;
+
+/// Commitment of batch data posted to L1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum L1Commitment {
+    /// Batch data posted as the calldata of the transaction with this hash.
+    Calldata(TxHash),
+    /// Batch data posted as a blob of the given block.
+    Blob {
+        /// The L1 block that included the blob.
+        block_number: BlockNumber,
+        /// The versioned hash of the blob.
+        versioned_hash: B256,
+    },
+}
+
+/// Errors returned by the [`L1DataAvailabilityClient`].
+#[derive(Debug, thiserror::Error)]
+pub enum L1DataAvailabilityError {
+    /// Failed to read the transaction or the block.
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+    /// Failed to fetch the blob.
+    #[error(transparent)]
+    BlobSource(#[from] BlobSourceError),
+    /// Failed to decode the batch data of the blob.
+    #[error(transparent)]
+    BlobDecoding(#[from] BlobDecodingError),
+}
+
+/// [`DataAvailabilityClient`] that serves batch data posted to L1.
+///
+/// Calldata is read from the transactions of the chain. Blobs are fetched from a [`BlobSource`],
+/// e.g. a [`BeaconBlobSource`](crate::BeaconBlobSource), and their batch data is decoded.
+#[derive(Debug, Clone)]
+pub struct L1DataAvailabilityClient<Provider, B> {
+    provider: Provider,
+    blob_source: B,
+}
+
+impl<Provider, B> L1DataAvailabilityClient<Provider, B> {
+    /// Creates a new client that reads from the given provider and blob source.
+    pub const fn new(provider: Provider, blob_source: B) -> Self {
+        Self { provider, blob_source }
+    }
+}
+
+impl<Provider, B> DataAvailabilityClient for L1DataAvailabilityClient<Provider, B>
+where
+    Provider: TransactionsProvider + HeaderProvider,
+    B: BlobSource,
+{
+    type Commitment = L1Commitment;
+    type Error = L1DataAvailabilityError;
+
+    async fn fetch_batch(
+        &self,
+        commitment: &Self::Commitment,
+    ) -> Result<Option<Bytes>, Self::Error> {
+        match *commitment {
+            L1Commitment::Calldata(hash) => {
+                Ok(self.provider.transaction_by_hash(hash)?.map(|tx| tx.input().clone()))
+            }
+            L1Commitment::Blob { block_number, versioned_hash } => {
+                let Some(timestamp) =
+                    self.provider.header_by_number(block_number)?.map(|header| header.timestamp())
+                else {
+                    return Ok(None)
+                };
+                let blob = self.blob_source.blobs(timestamp, &[versioned_hash]).await?.pop();
+                Ok(blob.flatten().map(|blob| decode_blob_data(&blob)).transpose()?)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blob::tests::encode_blob_data;
+    use alloy_consensus::TxLegacy;
+    use alloy_eips::eip4844::Blob;
+    use alloy_primitives::{bytes, PrimitiveSignature as Signature};
+    use reth_primitives::{Block, BlockBody, Header, TransactionSigned};
+    use reth_provider::test_utils::MockEthProvider;
+    use std::collections::HashMap;
+
+    /// Blob source that serves the blobs of the blocks with the given timestamps.
+    #[derive(Debug, Default)]
+    struct TestBlobSource(HashMap<(u64, B256), Blob>);
+
+    impl BlobSource for TestBlobSource {
+        async fn blobs(
+            &self,
+            timestamp: u64,
+            versioned_hashes: &[B256],
+        ) -> Result<Vec<Option<Blob>>, BlobSourceError> {
+            Ok(versioned_hashes
+                .iter()
+                .map(|hash| self.0.get(&(timestamp, *hash)).copied())
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn fetch_l1_batches() {
+        let provider = MockEthProvider::default();
+        let input = bytes!("deadbeef");
+        let tx = TransactionSigned::new_unhashed(
+            TxLegacy { input: input.clone(), ..Default::default() }.into(),
+            Signature::test_signature(),
+        );
+        let tx_hash = tx.hash();
+        provider.add_block(
+            B256::random(),
+            Block {
+                header: Header { number: 1, timestamp: 12, ..Default::default() },
+                body: BlockBody { transactions: vec![tx], ..Default::default() },
+            },
+        );
+
+        let versioned_hash = B256::random();
+        let mut blob_source = TestBlobSource::default();
+        blob_source.0.insert((12, versioned_hash), encode_blob_data(&input));
+
+        let client = L1DataAvailabilityClient::new(provider, blob_source);
+
+        let calldata = client.fetch_batch(&L1Commitment::Calldata(tx_hash)).await.unwrap();
+        assert_eq!(calldata, Some(input.clone()));
+
+        let blob = client
+            .fetch_batch(&L1Commitment::Blob { block_number: 1, versioned_hash })
+            .await
+            .unwrap();
+        assert_eq!(blob, Some(input));
+
+        assert_eq!(
+            client.fetch_batch(&L1Commitment::Calldata(B256::random())).await.unwrap(),
+            None
+        );
+        assert_eq!(
+            client
+                .fetch_batch(&L1Commitment::Blob {
+                    block_number: 1,
+                    versioned_hash: B256::random()
+                })
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            client
+                .fetch_batch(&L1Commitment::Blob { block_number: 2, versioned_hash })
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Data availability layer client abstraction.
+//!
+//! Derivation-oriented components fetch batch data through the [`DataAvailabilityClient`] trait,
+//! which allows rollups to plug in alternative DA layers without changes to reth's core crates.
+//! The [`L1DataAvailabilityClient`] serves batch data posted to L1 as calldata or blobs, fetching
+//! blobs from a [`BlobSource`] such as the [`BeaconBlobSource`].
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod blob;
+mod client;
+mod l1;
+
+pub use blob::{
+    decode_blob_data, BeaconBlobSource, BlobDecodingError, BlobSource, BlobSourceError,
+    BLOB_ENCODING_VERSION, MAX_BLOB_DATA_LEN,
+};
+pub use client::DataAvailabilityClient;
+pub use l1::{L1Commitment, L1DataAvailabilityClient, L1DataAvailabilityError};
@@ -44,7 +44,11 @@ impl<T: PayloadTypes> PayloadTestContext<T> {
         &mut self,
         attrs: T::PayloadBuilderAttributes,
     ) -> eyre::Result<()> {
-        let first_event = self.payload_event_stream.next().await.unwrap()?;
+        let mut first_event = self.payload_event_stream.next().await.unwrap()?;
+        // skip the fee breakdown of the previously built payload
+        if let Events::BuiltPayloadFees(_) = first_event {
+            first_event = self.payload_event_stream.next().await.unwrap()?;
+        }
         if let Events::Attributes(attr) = first_event {
             assert_eq!(attrs.timestamp(), attr.timestamp());
         } else {
@@ -227,6 +227,7 @@ where
             sidecar: cancun_fields
                 .map(ExecutionPayloadSidecar::v3)
                 .unwrap_or_else(ExecutionPayloadSidecar::none),
+            block_access_list: None,
             tx,
         })?;
 
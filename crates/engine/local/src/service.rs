@@ -37,6 +37,7 @@ use reth_payload_primitives::{PayloadAttributesBuilder, PayloadTypes};
 use reth_provider::{providers::BlockchainProvider2, ChainSpecProvider, ProviderFactory};
 use reth_prune::PrunerWithFactory;
 use reth_stages_api::MetricEventsSender;
+use reth_tasks::TaskSpawner;
 use tokio::sync::mpsc::UnboundedSender;
 use tracing::error;
 
@@ -71,6 +72,7 @@ where
         pruner: PrunerWithFactory<ProviderFactory<N>>,
         payload_builder: PayloadBuilderHandle<N::Engine>,
         payload_validator: V,
+        task_spawner: Box<dyn TaskSpawner>,
         tree_config: TreeConfig,
         invalid_block_hook: Box<dyn InvalidBlockHook<N::Primitives>>,
         sync_metrics_tx: MetricEventsSender,
@@ -102,6 +104,7 @@ where
             tree_config,
             invalid_block_hook,
             engine_kind,
+            task_spawner,
         );
 
         let handler = EngineApiRequestHandler::new(to_tree_tx, from_tree);
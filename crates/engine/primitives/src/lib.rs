@@ -23,6 +23,8 @@ pub use message::{BeaconEngineMessage, OnForkChoiceUpdated};
 mod invalid_block_hook;
 pub use invalid_block_hook::InvalidBlockHook;
 
+pub use reth_execution_types::BlockAccessList;
+
 pub use reth_payload_primitives::{
     BuiltPayload, EngineApiMessageVersion, EngineObjectValidationError, PayloadOrAttributes,
     PayloadTypes,
@@ -41,7 +43,8 @@ pub trait EngineTypes:
         BuiltPayload: TryInto<Self::ExecutionPayloadEnvelopeV1>
                           + TryInto<Self::ExecutionPayloadEnvelopeV2>
                           + TryInto<Self::ExecutionPayloadEnvelopeV3>
-                          + TryInto<Self::ExecutionPayloadEnvelopeV4>,
+                          + TryInto<Self::ExecutionPayloadEnvelopeV4>
+                          + TryInto<Self::ExecutionPayloadEnvelopeV5>,
     > + DeserializeOwned
     + Serialize
     + 'static
@@ -78,6 +81,14 @@ pub trait EngineTypes:
         + Send
         + Sync
         + 'static;
+    /// Execution Payload V5 envelope type.
+    type ExecutionPayloadEnvelopeV5: DeserializeOwned
+        + Serialize
+        + Clone
+        + Unpin
+        + Send
+        + Sync
+        + 'static;
 }
 
 /// Type that validates the payloads processed by the engine.
@@ -129,7 +140,10 @@ pub trait EngineValidator<Types: EngineTypes>: Clone + Send + Sync + Unpin + 'st
         header: &<Self::Block as Block>::Header,
     ) -> Result<(), InvalidPayloadAttributesError> {
         if attr.timestamp() <= header.timestamp() {
-            return Err(InvalidPayloadAttributesError::InvalidTimestamp);
+            return Err(InvalidPayloadAttributesError::InvalidTimestamp {
+                timestamp: attr.timestamp(),
+                head_timestamp: header.timestamp(),
+            });
         }
         Ok(())
     }
@@ -1,4 +1,7 @@
-use crate::{BeaconOnNewPayloadError, EngineApiMessageVersion, EngineTypes, ForkchoiceStatus};
+use crate::{
+    BeaconOnNewPayloadError, BlockAccessList, EngineApiMessageVersion, EngineTypes,
+    ForkchoiceStatus,
+};
 use alloy_rpc_types_engine::{
     ExecutionPayload, ExecutionPayloadSidecar, ForkChoiceUpdateResult, ForkchoiceState,
     ForkchoiceUpdateError, ForkchoiceUpdated, PayloadId, PayloadStatus, PayloadStatusEnum,
@@ -146,6 +149,9 @@ pub enum BeaconEngineMessage<Engine: EngineTypes> {
         /// The execution payload sidecar with additional version-specific fields received by
         /// engine API.
         sidecar: ExecutionPayloadSidecar,
+        /// The EIP-7928 block access list received with the payload, which must match the block
+        /// access list generated by executing the payload.
+        block_access_list: Option<BlockAccessList>,
         /// The sender for returning payload status result.
         tx: oneshot::Sender<Result<PayloadStatus, BeaconOnNewPayloadError>>,
     },
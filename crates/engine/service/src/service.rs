@@ -72,7 +72,7 @@ where
         client: Client,
         incoming_requests: EngineMessageStream<N::Engine>,
         pipeline: Pipeline<N>,
-        pipeline_task_spawner: Box<dyn TaskSpawner>,
+        task_spawner: Box<dyn TaskSpawner>,
         provider: ProviderFactory<N>,
         blockchain_db: BlockchainProvider2<N>,
         pruner: PrunerWithFactory<ProviderFactory<N>>,
@@ -106,12 +106,13 @@ where
             tree_config,
             invalid_block_hook,
             engine_kind,
+            task_spawner.clone(),
         );
 
         let engine_handler = EngineApiRequestHandler::new(to_tree_tx, from_tree);
         let handler = EngineHandler::new(engine_handler, downloader, incoming_requests);
 
-        let backfill_sync = PipelineSync::new(pipeline, pipeline_task_spawner);
+        let backfill_sync = PipelineSync::new(pipeline, task_spawner);
 
         Self {
             orchestrator: ChainOrchestrator::new(handler, backfill_sync),
@@ -182,7 +183,7 @@ mod tests {
         let incoming_requests = UnboundedReceiverStream::new(rx);
 
         let pipeline = TestPipelineBuilder::new().build(chain_spec.clone());
-        let pipeline_task_spawner = Box::<TokioTaskExecutor>::default();
+        let task_spawner = Box::<TokioTaskExecutor>::default();
         let provider_factory = create_test_provider_factory_with_chain_spec(chain_spec.clone());
 
         let executor_factory = EthExecutorProvider::ethereum(chain_spec.clone());
@@ -202,7 +203,7 @@ mod tests {
             client,
             Box::pin(incoming_requests),
             pipeline,
-            pipeline_task_spawner,
+            task_spawner,
             provider_factory,
             blockchain_db,
             pruner,
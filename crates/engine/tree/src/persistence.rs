@@ -10,13 +10,17 @@ use reth_provider::{
 use reth_prune::{PrunerError, PrunerOutput, PrunerWithFactory};
 use reth_stages_api::{MetricEvent, MetricEventsSender};
 use std::{
-    sync::mpsc::{Receiver, SendError, Sender},
-    time::Instant,
+    sync::mpsc::{Receiver, RecvTimeoutError, SendError, Sender},
+    time::{Duration, Instant},
 };
 use thiserror::Error;
 use tokio::sync::oneshot;
 use tracing::{debug, error};
 
+/// How long the persistence service waits for a new action before it's considered idle and the
+/// pruner is given a chance to catch up.
+const IDLE_PRUNE_INTERVAL: Duration = Duration::from_secs(1);
+
 /// A helper trait with requirements for [`ProviderNodeTypes`] to be used within
 /// [`PersistenceService`].
 pub trait PersistenceNodeTypes: ProviderNodeTypes<Primitives = EthPrimitives> {}
@@ -79,11 +83,30 @@ where
     /// This is the main loop, that will listen to database events and perform the requested
     /// database actions
     pub fn run(mut self) -> Result<(), PersistenceError> {
-        // If the receiver errors then senders have disconnected, so the loop should then end.
-        while let Ok(action) = self.incoming.recv() {
+        // The highest block persisted to disk, used to resume the pruning while idle.
+        let mut last_persisted_block = None;
+
+        loop {
+            let action = match self.incoming.recv_timeout(IDLE_PRUNE_INTERVAL) {
+                Ok(action) => action,
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Some(block_number) = last_persisted_block {
+                        if self.pruner.is_pruning_needed(block_number) {
+                            // We log `PrunerOutput` inside the `Pruner`
+                            let _ = self.prune_before(block_number)?;
+                        }
+                    }
+                    continue
+                }
+                // If the receiver errors then senders have disconnected, so the loop should then
+                // end.
+                Err(RecvTimeoutError::Disconnected) => break,
+            };
+
             match action {
                 PersistenceAction::RemoveBlocksAbove(new_tip_num, sender) => {
                     let result = self.on_remove_blocks_above(new_tip_num)?;
+                    last_persisted_block = Some(new_tip_num);
                     // send new sync metrics based on removed blocks
                     let _ =
                         self.sync_metrics_tx.send(MetricEvent::SyncHeight { height: new_tip_num });
@@ -98,6 +121,8 @@ where
                     let _ = sender.send(result);
 
                     if let Some(block_number) = result_number {
+                        last_persisted_block = Some(block_number);
+
                         // send new sync metrics based on saved blocks
                         let _ = self
                             .sync_metrics_tx
@@ -0,0 +1,154 @@
+//! Cooperative abort of payload validation that was superseded by a forkchoice update.
+
+use alloy_primitives::{Address, B256, U256};
+use reth_blockchain_tree::error::InsertBlockErrorKindTwo;
+use reth_errors::DatabaseError;
+use reth_provider::ProviderError;
+use reth_revm::{
+    primitives::{AccountInfo, Bytecode},
+    Database,
+};
+
+/// The number of database reads between two checks whether the execution should be aborted.
+const ABORT_CHECK_INTERVAL: u64 = 64;
+
+/// The validation of a payload was aborted, because a forkchoice update superseded the payload
+/// while it was validated.
+#[derive(Debug, thiserror::Error)]
+#[error("validation of payload {0} aborted by a superseding forkchoice update")]
+pub(crate) struct PayloadValidationAborted(pub(crate) B256);
+
+impl PayloadValidationAborted {
+    /// Returns true if the given insert error is a [`PayloadValidationAborted`] error.
+    pub(crate) fn is_aborted(kind: &InsertBlockErrorKindTwo) -> bool {
+        matches!(kind, InsertBlockErrorKindTwo::Other(err) if err.is::<Self>())
+    }
+}
+
+/// A [`Database`] that fails all reads once `should_abort` returns true.
+///
+/// Failing reads make the EVM stop the execution of the block, so the execution is aborted
+/// cooperatively. `should_abort` is only invoked every [`ABORT_CHECK_INTERVAL`] reads, starting
+/// with the first read.
+#[derive(Debug)]
+pub(crate) struct AbortableDatabase<DB, F> {
+    db: DB,
+    should_abort: F,
+    reads: u64,
+    aborted: bool,
+}
+
+impl<DB, F: FnMut() -> bool> AbortableDatabase<DB, F> {
+    /// Creates a new [`AbortableDatabase`] that reads from `db`.
+    pub(crate) const fn new(db: DB, should_abort: F) -> Self {
+        Self { db, should_abort, reads: 0, aborted: false }
+    }
+
+    /// Returns an error if the execution was aborted.
+    fn ensure_not_aborted(&mut self) -> Result<(), ProviderError> {
+        if !self.aborted && self.reads % ABORT_CHECK_INTERVAL == 0 {
+            self.aborted = (self.should_abort)();
+        }
+        self.reads += 1;
+
+        if self.aborted {
+            return Err(ProviderError::Database(DatabaseError::Other(
+                "block execution aborted".to_string(),
+            )))
+        }
+        Ok(())
+    }
+}
+
+impl<DB, F> Database for AbortableDatabase<DB, F>
+where
+    DB: Database<Error = ProviderError>,
+    F: FnMut() -> bool,
+{
+    type Error = ProviderError;
+
+    fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        self.ensure_not_aborted()?;
+        self.db.basic(address)
+    }
+
+    fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.ensure_not_aborted()?;
+        self.db.code_by_hash(code_hash)
+    }
+
+    fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        self.ensure_not_aborted()?;
+        self.db.storage(address, index)
+    }
+
+    fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+        self.ensure_not_aborted()?;
+        self.db.block_hash(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_revm::db::EmptyDB;
+    use std::cell::Cell;
+
+    #[derive(Debug, Default)]
+    struct ProviderEmptyDB(EmptyDB);
+
+    impl Database for ProviderEmptyDB {
+        type Error = ProviderError;
+
+        fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+            Ok(self.0.basic(address).unwrap())
+        }
+
+        fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+            Ok(self.0.code_by_hash(code_hash).unwrap())
+        }
+
+        fn storage(&mut self, address: Address, index: U256) -> Result<U256, Self::Error> {
+            Ok(self.0.storage(address, index).unwrap())
+        }
+
+        fn block_hash(&mut self, number: u64) -> Result<B256, Self::Error> {
+            Ok(self.0.block_hash(number).unwrap())
+        }
+    }
+
+    #[test]
+    fn abort_reads() {
+        let checks = Cell::new(0);
+        let abort = Cell::new(false);
+        let mut db = AbortableDatabase::new(ProviderEmptyDB::default(), || {
+            checks.set(checks.get() + 1);
+            abort.get()
+        });
+
+        for _ in 0..ABORT_CHECK_INTERVAL {
+            assert!(db.basic(Address::ZERO).is_ok());
+        }
+        assert_eq!(checks.get(), 1);
+
+        abort.set(true);
+        // the abort is noticed on the next check
+        assert!(db.storage(Address::ZERO, U256::ZERO).is_err());
+        assert_eq!(checks.get(), 2);
+
+        // reads keep failing without further checks
+        abort.set(false);
+        assert!(db.block_hash(0).is_err());
+        assert_eq!(checks.get(), 2);
+    }
+
+    #[test]
+    fn detect_aborted_error() {
+        let aborted =
+            InsertBlockErrorKindTwo::Other(Box::new(PayloadValidationAborted(B256::ZERO)));
+        assert!(PayloadValidationAborted::is_aborted(&aborted));
+
+        let other = InsertBlockErrorKindTwo::Provider(ProviderError::SenderRecoveryError);
+        assert!(!PayloadValidationAborted::is_aborted(&other));
+    }
+}
@@ -1,5 +1,7 @@
 //! Engine tree configuration.
 
+use reth_memory_budget::MemoryBudget;
+
 /// Triggers persistence when the number of canonical blocks in memory exceeds this threshold.
 pub const DEFAULT_PERSISTENCE_THRESHOLD: u64 = 2;
 
@@ -11,6 +13,9 @@ const DEFAULT_MAX_INVALID_HEADER_CACHE_LENGTH: u32 = 256;
 
 const DEFAULT_MAX_EXECUTE_BLOCK_BATCH_SIZE: usize = 4;
 
+/// Default number of recently changed account and storage keys to pre-warm after a restart.
+pub const DEFAULT_MAX_WARM_STATE_KEYS: u32 = 100_000;
+
 /// The configuration of the engine tree.
 #[derive(Debug)]
 pub struct TreeConfig {
@@ -32,6 +37,13 @@ pub struct TreeConfig {
     /// This is used as a cutoff to prevent long-running sequential block execution when we receive
     /// a batch of downloaded blocks.
     max_execute_block_batch_size: usize,
+    /// Maximum number of recently changed account and storage keys that are persisted on shutdown
+    /// and pre-warmed on startup.
+    ///
+    /// Pre-warming is disabled if this is `0`.
+    max_warm_state_keys: u32,
+    /// The memory budget the buffer of pending blocks registers with.
+    memory_budget: MemoryBudget,
 }
 
 impl Default for TreeConfig {
@@ -42,18 +54,21 @@ impl Default for TreeConfig {
             block_buffer_limit: DEFAULT_BLOCK_BUFFER_LIMIT,
             max_invalid_header_cache_length: DEFAULT_MAX_INVALID_HEADER_CACHE_LENGTH,
             max_execute_block_batch_size: DEFAULT_MAX_EXECUTE_BLOCK_BATCH_SIZE,
+            max_warm_state_keys: DEFAULT_MAX_WARM_STATE_KEYS,
+            memory_budget: Default::default(),
         }
     }
 }
 
 impl TreeConfig {
     /// Create engine tree configuration.
-    pub const fn new(
+    pub fn new(
         persistence_threshold: u64,
         memory_block_buffer_target: u64,
         block_buffer_limit: u32,
         max_invalid_header_cache_length: u32,
         max_execute_block_batch_size: usize,
+        max_warm_state_keys: u32,
     ) -> Self {
         Self {
             persistence_threshold,
@@ -61,6 +76,8 @@ impl TreeConfig {
             block_buffer_limit,
             max_invalid_header_cache_length,
             max_execute_block_batch_size,
+            max_warm_state_keys,
+            memory_budget: Default::default(),
         }
     }
 
@@ -89,6 +106,16 @@ impl TreeConfig {
         self.max_execute_block_batch_size
     }
 
+    /// Return the maximum number of warm state keys.
+    pub const fn max_warm_state_keys(&self) -> u32 {
+        self.max_warm_state_keys
+    }
+
+    /// Return the memory budget the buffer of pending blocks registers with.
+    pub const fn memory_budget(&self) -> &MemoryBudget {
+        &self.memory_budget
+    }
+
     /// Setter for persistence threshold.
     pub const fn with_persistence_threshold(mut self, persistence_threshold: u64) -> Self {
         self.persistence_threshold = persistence_threshold;
@@ -127,4 +154,16 @@ impl TreeConfig {
         self.max_execute_block_batch_size = max_execute_block_batch_size;
         self
     }
+
+    /// Setter for maximum number of warm state keys.
+    pub const fn with_max_warm_state_keys(mut self, max_warm_state_keys: u32) -> Self {
+        self.max_warm_state_keys = max_warm_state_keys;
+        self
+    }
+
+    /// Setter for the memory budget the buffer of pending blocks registers with.
+    pub fn with_memory_budget(mut self, memory_budget: MemoryBudget) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
 }
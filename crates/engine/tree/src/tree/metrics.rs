@@ -43,6 +43,8 @@ pub(crate) struct EngineMetrics {
     pub(crate) failed_new_payload_response_deliveries: Counter,
     /// Tracks the how often we failed to deliver a forkchoice update response.
     pub(crate) failed_forkchoice_updated_response_deliveries: Counter,
+    /// The number of payload validations that were aborted by a superseding forkchoice update.
+    pub(crate) aborted_payload_validations: Counter,
     // TODO add latency metrics
 }
 
@@ -56,6 +58,12 @@ pub(crate) struct BlockValidationMetrics {
     pub(crate) state_root_histogram: Histogram,
     /// Latest state root duration
     pub(crate) state_root_duration: Gauge,
+    /// Histogram of the time waited for the background state root computation
+    pub(crate) state_root_wait_histogram: Histogram,
+    /// Total number of blocks executed while the state root of their parent was computed
+    pub(crate) executed_ahead_blocks: Counter,
+    /// Total number of blocks executed ahead whose output was discarded
+    pub(crate) discarded_executed_ahead_blocks: Counter,
 }
 
 impl BlockValidationMetrics {
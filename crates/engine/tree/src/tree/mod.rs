@@ -3,13 +3,17 @@ use crate::{
     chain::FromOrchestrator,
     engine::{DownloadRequest, EngineApiEvent, EngineApiKind, EngineApiRequest, FromEngine},
     persistence::PersistenceHandle,
-    tree::metrics::EngineApiMetrics,
+    tree::{
+        abort::{AbortableDatabase, PayloadValidationAborted},
+        metrics::EngineApiMetrics,
+        root::StateRootHandle,
+    },
 };
 use alloy_consensus::{BlockHeader, Header};
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{
     map::{HashMap, HashSet},
-    BlockNumber, B256, U256,
+    Address, BlockNumber, B256, U256,
 };
 use alloy_rpc_types_engine::{
     ExecutionPayload, ExecutionPayloadSidecar, ForkchoiceState, PayloadStatus, PayloadStatusEnum,
@@ -27,11 +31,12 @@ use reth_chain_state::{
 };
 use reth_consensus::{Consensus, FullConsensus, PostExecutionInput};
 use reth_engine_primitives::{
-    BeaconEngineMessage, BeaconOnNewPayloadError, EngineApiMessageVersion, EngineTypes,
-    EngineValidator, ForkchoiceStateTracker, OnForkChoiceUpdated,
+    BeaconEngineMessage, BeaconOnNewPayloadError, BlockAccessList, EngineApiMessageVersion,
+    EngineTypes, EngineValidator, ForkchoiceStateTracker, OnForkChoiceUpdated,
 };
 use reth_errors::{ConsensusError, ProviderResult};
-use reth_evm::execute::BlockExecutorProvider;
+use reth_evm::execute::{BlockExecutionOutput, BlockExecutorProvider};
+use reth_memory_budget::MemoryBudget;
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_payload_builder_primitives::PayloadBuilder;
 use reth_payload_primitives::PayloadBuilderAttributes;
@@ -46,17 +51,20 @@ use reth_provider::{
 };
 use reth_revm::database::StateProviderDatabase;
 use reth_stages_api::ControlFlow;
+use reth_tasks::TaskSpawner;
 use reth_trie::{updates::TrieUpdates, HashedPostState, TrieInput};
 use reth_trie_parallel::root::{ParallelStateRoot, ParallelStateRootError};
 use revm_primitives::EvmState;
 use std::{
+    cell::{Cell, RefCell},
     cmp::Ordering,
     collections::{btree_map, hash_map, BTreeMap, VecDeque},
     fmt::Debug,
     marker::PhantomData,
     ops::Bound,
     sync::{
-        mpsc::{Receiver, RecvError, RecvTimeoutError, Sender},
+        atomic::{self, AtomicBool},
+        mpsc::{self, Receiver, RecvError, RecvTimeoutError, Sender},
         Arc,
     },
     time::Instant,
@@ -67,6 +75,7 @@ use tokio::sync::{
 };
 use tracing::*;
 
+mod abort;
 pub mod config;
 mod invalid_block_hook;
 mod metrics;
@@ -393,6 +402,9 @@ pub struct EngineApiTreeState {
     forkchoice_state_tracker: ForkchoiceStateTracker,
     /// Buffer of detached blocks.
     buffer: BlockBuffer,
+    /// The block access lists that buffered blocks were received with, which are verified once
+    /// the blocks are connected.
+    buffered_block_access_lists: HashMap<B256, BlockAccessList>,
     /// Tracks the header of invalid payloads that were rejected by the engine because they're
     /// invalid.
     invalid_headers: InvalidHeaderCache,
@@ -403,14 +415,37 @@ impl EngineApiTreeState {
         block_buffer_limit: u32,
         max_invalid_header_cache_length: u32,
         canonical_block: BlockNumHash,
+        memory_budget: &MemoryBudget,
     ) -> Self {
         Self {
             invalid_headers: InvalidHeaderCache::new(max_invalid_header_cache_length),
-            buffer: BlockBuffer::new(block_buffer_limit),
+            buffer: BlockBuffer::with_memory_budget(block_buffer_limit, memory_budget),
+            buffered_block_access_lists: HashMap::default(),
             tree_state: TreeState::new(canonical_block),
             forkchoice_state_tracker: ForkchoiceStateTracker::default(),
         }
     }
+
+    /// Inserts the block into the buffer, together with the block access list it was received
+    /// with.
+    fn buffer_block(
+        &mut self,
+        block: SealedBlockWithSenders,
+        block_access_list: Option<&BlockAccessList>,
+    ) {
+        let hash = block.hash();
+        self.buffer.insert_block(block);
+        if let Some(block_access_list) = block_access_list {
+            self.buffered_block_access_lists.insert(hash, block_access_list.clone());
+        }
+        self.retain_buffered_block_access_lists();
+    }
+
+    /// Removes the block access lists of blocks that are no longer buffered.
+    fn retain_buffered_block_access_lists(&mut self) {
+        let buffer = &self.buffer;
+        self.buffered_block_access_lists.retain(|hash, _| buffer.block(hash).is_some());
+    }
 }
 
 /// The outcome of a tree operation.
@@ -489,6 +524,12 @@ where
     incoming_tx: Sender<FromEngine<EngineApiRequest<T>>>,
     /// Incoming engine API requests.
     incoming: Receiver<FromEngine<EngineApiRequest<T>>>,
+    /// Engine API requests that were received from `incoming` while a payload was validated and
+    /// that are handled before any new requests.
+    received: RefCell<VecDeque<FromEngine<EngineApiRequest<T>>>>,
+    /// The queued payload that is executed in the background while the state root of its parent
+    /// is computed.
+    execute_ahead: Option<ExecuteAheadTask>,
     /// Outgoing events that are emitted to the handler.
     outgoing: UnboundedSender<EngineApiEvent>,
     /// Channels to the persistence layer.
@@ -511,10 +552,35 @@ where
     invalid_block_hook: Box<dyn InvalidBlockHook<N>>,
     /// The engine API variant of this handler
     engine_kind: EngineApiKind,
+    /// Spawns the state root computations and the blocks executed ahead on the blocking pool.
+    task_spawner: Box<dyn TaskSpawner>,
     /// Captures the types the engine operates on
     _primtives: PhantomData<N>,
 }
 
+/// A block that is executed in the background on top of its parent, while the state root of the
+/// parent is computed.
+///
+/// Dropping the task aborts the execution.
+#[derive(Debug)]
+struct ExecuteAheadTask {
+    /// Hash of the executed block.
+    block_hash: B256,
+    /// Hash of the parent, which wasn't validated yet when the block was executed.
+    parent_hash: B256,
+    /// Set once the output of the execution can't be used anymore.
+    cancelled: Arc<AtomicBool>,
+    /// Receives the execution output, the sender is dropped if the execution failed or was
+    /// aborted.
+    rx: mpsc::Receiver<BlockExecutionOutput<reth_primitives::Receipt>>,
+}
+
+impl Drop for ExecuteAheadTask {
+    fn drop(&mut self) {
+        self.cancelled.store(true, atomic::Ordering::Relaxed);
+    }
+}
+
 impl<N, P: Debug, E: Debug, T: EngineTypes + Debug, V: Debug> std::fmt::Debug
     for EngineApiTreeHandler<N, P, E, T, V>
 where
@@ -537,6 +603,7 @@ where
             .field("metrics", &self.metrics)
             .field("invalid_block_hook", &format!("{:p}", self.invalid_block_hook))
             .field("engine_kind", &self.engine_kind)
+            .field("task_spawner", &self.task_spawner)
             .finish()
     }
 }
@@ -576,6 +643,7 @@ where
         payload_builder: PayloadBuilderHandle<T>,
         config: TreeConfig,
         engine_kind: EngineApiKind,
+        task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
         let (incoming_tx, incoming) = std::sync::mpsc::channel();
 
@@ -585,6 +653,8 @@ where
             consensus,
             payload_validator,
             incoming,
+            received: RefCell::default(),
+            execute_ahead: None,
             outgoing,
             persistence,
             persistence_state,
@@ -597,6 +667,7 @@ where
             incoming_tx,
             invalid_block_hook: Box::new(NoopInvalidBlockHook),
             engine_kind,
+            task_spawner,
             _primtives: Default::default(),
         }
     }
@@ -623,6 +694,7 @@ where
         config: TreeConfig,
         invalid_block_hook: Box<dyn InvalidBlockHook<N>>,
         kind: EngineApiKind,
+        task_spawner: Box<dyn TaskSpawner>,
     ) -> (Sender<FromEngine<EngineApiRequest<T>>>, UnboundedReceiver<EngineApiEvent>) {
         let best_block_number = provider.best_block_number().unwrap_or(0);
         let header = provider.sealed_header(best_block_number).ok().flatten().unwrap_or_default();
@@ -638,6 +710,7 @@ where
             config.block_buffer_limit(),
             config.max_invalid_header_cache_length(),
             header.num_hash(),
+            config.memory_budget(),
         );
 
         let mut task = Self::new(
@@ -653,6 +726,7 @@ where
             payload_builder,
             config,
             kind,
+            task_spawner,
         );
         task.set_invalid_block_hook(invalid_block_hook);
         let incoming = task.incoming_tx.clone();
@@ -746,6 +820,7 @@ where
         &mut self,
         payload: ExecutionPayload,
         sidecar: ExecutionPayloadSidecar,
+        block_access_list: Option<BlockAccessList>,
     ) -> Result<TreeOutcome<PayloadStatus>, InsertBlockFatalError> {
         trace!(target: "engine::tree", "invoked new payload");
         self.metrics.engine.new_payload_messages.increment(1);
@@ -813,7 +888,7 @@ where
         let status = if self.backfill_sync_state.is_idle() {
             let mut latest_valid_hash = None;
             let num_hash = block.num_hash();
-            match self.insert_block_without_senders(block) {
+            match self.insert_block_without_senders(block, block_access_list.as_ref()) {
                 Ok(status) => {
                     let status = match status {
                         InsertPayloadOk2::Inserted(BlockStatus2::Valid) => {
@@ -834,9 +909,15 @@ where
 
                     PayloadStatus::new(status, latest_valid_hash)
                 }
+                Err(error) if PayloadValidationAborted::is_aborted(error.kind()) => {
+                    // the block was superseded before its validation finished
+                    PayloadStatus::from_status(PayloadStatusEnum::Syncing)
+                }
                 Err(error) => self.on_insert_block_error(error)?,
             }
-        } else if let Err(error) = self.buffer_block_without_senders(block) {
+        } else if let Err(error) =
+            self.buffer_block_without_senders(block, block_access_list.as_ref())
+        {
             self.on_insert_block_error(error)?
         } else {
             PayloadStatus::from_status(PayloadStatusEnum::Syncing)
@@ -1134,6 +1215,10 @@ where
     fn try_recv_engine_message(
         &self,
     ) -> Result<Option<FromEngine<EngineApiRequest<T>>>, RecvError> {
+        if let Some(msg) = self.received.borrow_mut().pop_front() {
+            return Ok(Some(msg))
+        }
+
         if self.persistence_state.in_progress() {
             // try to receive the next request with a timeout to not block indefinitely
             match self.incoming.recv_timeout(std::time::Duration::from_millis(500)) {
@@ -1276,8 +1361,14 @@ where
                                     error!(target: "engine::tree", "Failed to send event: {err:?}");
                                 }
                             }
-                            BeaconEngineMessage::NewPayload { payload, sidecar, tx } => {
-                                let output = self.on_new_payload(payload, sidecar);
+                            BeaconEngineMessage::NewPayload {
+                                payload,
+                                sidecar,
+                                block_access_list,
+                                tx,
+                            } => {
+                                let output =
+                                    self.on_new_payload(payload, sidecar, block_access_list);
                                 if let Err(err) =
                                     tx.send(output.map(|o| o.outcome).map_err(|e| {
                                         BeaconOnNewPayloadError::Internal(Box::new(e))
@@ -1358,6 +1449,7 @@ where
 
         // remove all buffered blocks below the backfill height
         self.state.buffer.remove_old_blocks(backfill_height);
+        self.state.retain_buffered_block_access_lists();
         // we remove all entries because now we're synced to the backfill target and consider this
         // the canonical chain
         self.canonical_in_memory_state.clear_state();
@@ -1795,7 +1887,9 @@ where
         let block_count = blocks.len();
         for child in blocks {
             let child_num_hash = child.num_hash();
-            match self.insert_block(child) {
+            let block_access_list =
+                self.state.buffered_block_access_lists.remove(&child_num_hash.hash);
+            match self.insert_block_with_access_list(child, block_access_list.as_ref(), false) {
                 Ok(res) => {
                     debug!(target: "engine::tree", child =?child_num_hash, ?res, "connected buffered block");
                     if self.is_sync_target_head(child_num_hash.hash) &&
@@ -1824,19 +1918,26 @@ where
     fn buffer_block_without_senders(
         &mut self,
         block: SealedBlock,
+        block_access_list: Option<&BlockAccessList>,
     ) -> Result<(), InsertBlockErrorTwo> {
         match block.try_seal_with_senders() {
-            Ok(block) => self.buffer_block(block),
+            Ok(block) => self.buffer_block(block, block_access_list),
             Err(block) => Err(InsertBlockErrorTwo::sender_recovery_error(block)),
         }
     }
 
     /// Pre-validates the block and inserts it into the buffer.
-    fn buffer_block(&mut self, block: SealedBlockWithSenders) -> Result<(), InsertBlockErrorTwo> {
+    ///
+    /// The given block access list is verified once the block is connected.
+    fn buffer_block(
+        &mut self,
+        block: SealedBlockWithSenders,
+        block_access_list: Option<&BlockAccessList>,
+    ) -> Result<(), InsertBlockErrorTwo> {
         if let Err(err) = self.validate_block(&block) {
             return Err(InsertBlockErrorTwo::consensus_error(err, block.block))
         }
-        self.state.buffer.insert_block(block);
+        self.state.buffer_block(block, block_access_list);
         Ok(())
     }
 
@@ -2145,12 +2246,14 @@ where
         Ok(None)
     }
 
+    /// Inserts the block, see [`Self::insert_block_with_access_list`].
     fn insert_block_without_senders(
         &mut self,
         block: SealedBlock,
+        block_access_list: Option<&BlockAccessList>,
     ) -> Result<InsertPayloadOk2, InsertBlockErrorTwo> {
         match block.try_seal_with_senders() {
-            Ok(block) => self.insert_block(block),
+            Ok(block) => self.insert_block_with_access_list(block, block_access_list, true),
             Err(block) => Err(InsertBlockErrorTwo::sender_recovery_error(block)),
         }
     }
@@ -2159,13 +2262,33 @@ where
         &mut self,
         block: SealedBlockWithSenders,
     ) -> Result<InsertPayloadOk2, InsertBlockErrorTwo> {
-        self.insert_block_inner(block.clone())
+        self.insert_block_with_access_list(block, None, false)
+    }
+
+    /// Inserts the block, verifying the block access list of its execution against the given one.
+    ///
+    /// The access list is only verified if the block is executed, i.e. not if it is already known.
+    /// If the block is disconnected, the access list is buffered with the block and verified once
+    /// the block is connected.
+    ///
+    /// If `abortable` is set, the validation is aborted with [`PayloadValidationAborted`] once a
+    /// received forkchoice update supersedes the block, see
+    /// [`Self::receive_superseding_forkchoice`].
+    fn insert_block_with_access_list(
+        &mut self,
+        block: SealedBlockWithSenders,
+        block_access_list: Option<&BlockAccessList>,
+        abortable: bool,
+    ) -> Result<InsertPayloadOk2, InsertBlockErrorTwo> {
+        self.insert_block_inner(block.clone(), block_access_list, abortable)
             .map_err(|kind| InsertBlockErrorTwo::new(block.block, kind))
     }
 
     fn insert_block_inner(
         &mut self,
         block: SealedBlockWithSenders,
+        block_access_list: Option<&BlockAccessList>,
+        abortable: bool,
     ) -> Result<InsertPayloadOk2, InsertBlockErrorKindTwo> {
         debug!(target: "engine::tree", block=?block.num_hash(), parent = ?block.parent_hash, state_root = ?block.state_root, "Inserting new block into tree");
 
@@ -2190,7 +2313,7 @@ where
                 .map(|block| block.parent_num_hash())
                 .unwrap_or_else(|| block.parent_num_hash());
 
-            self.state.buffer.insert_block(block);
+            self.state.buffer_block(block, block_access_list);
 
             return Ok(InsertPayloadOk2::Inserted(BlockStatus2::Disconnected {
                 head: self.state.tree_state.current_canonical_head,
@@ -2209,26 +2332,64 @@ where
             return Err(e.into())
         }
 
-        trace!(target: "engine::tree", block=?block.num_hash(), "Executing block");
-        let executor = self.executor_provider.executor(StateProviderDatabase::new(&state_provider));
+        let block_hash = block.hash();
+        let parent_hash = block.parent_hash;
+        let executed_ahead = self.take_executed_ahead(block_hash, parent_hash);
+
+        // Checks whether a received forkchoice update superseded the block, at which point the
+        // validation of the block is useless and delays the new head.
+        let superseded = Cell::new(false);
+        let is_superseded = || {
+            if abortable &&
+                !superseded.get() &&
+                self.receive_superseding_forkchoice(block_hash, parent_hash)
+            {
+                superseded.set(true);
+            }
+            superseded.get()
+        };
+        let aborted = || {
+            debug!(target: "engine::tree", ?block_hash, "Aborting validation of superseded block");
+            self.metrics.engine.aborted_payload_validations.increment(1);
+            InsertBlockErrorKindTwo::Other(Box::new(PayloadValidationAborted(block_hash)))
+        };
+
+        if is_superseded() {
+            return Err(aborted())
+        }
 
         let block_number = block.number;
-        let block_hash = block.hash();
         let sealed_block = Arc::new(block.block.clone());
         let block = block.unseal();
 
-        let exec_time = Instant::now();
-
-        // TODO: create StateRootTask with the receiving end of a channel and
-        // pass the sending end of the channel to the state hook.
-        let noop_state_hook = |_state: &EvmState| {};
-        let output = self.metrics.executor.execute_metered(
-            executor,
-            (&block, U256::MAX).into(),
-            Box::new(noop_state_hook),
-        )?;
+        let output = if let Some(output) = executed_ahead {
+            debug!(target: "engine::tree", ?block_number, ?block_hash, "Using output of block executed ahead");
+            output
+        } else {
+            trace!(target: "engine::tree", ?block_number, ?block_hash, "Executing block");
+            let executor = self.executor_provider.executor(AbortableDatabase::new(
+                StateProviderDatabase::new(&state_provider),
+                &is_superseded,
+            ));
+
+            let exec_time = Instant::now();
+
+            // TODO: create StateRootTask with the receiving end of a channel and
+            // pass the sending end of the channel to the state hook.
+            let noop_state_hook = |_state: &EvmState| {};
+            let output = match self.metrics.executor.execute_metered(
+                executor,
+                (&block, U256::MAX).into(),
+                Box::new(noop_state_hook),
+            ) {
+                Ok(output) => output,
+                Err(_) if superseded.get() => return Err(aborted()),
+                Err(err) => return Err(err.into()),
+            };
 
-        trace!(target: "engine::tree", elapsed=?exec_time.elapsed(), ?block_number, "Executed block");
+            trace!(target: "engine::tree", elapsed=?exec_time.elapsed(), ?block_number, "Executed block");
+            output
+        };
 
         if let Err(err) = self.consensus.validate_block_post_execution(
             &block,
@@ -2244,6 +2405,31 @@ where
             return Err(err.into())
         }
 
+        if let Some(expected) = block_access_list {
+            let got = output
+                .block_access_list
+                .as_ref()
+                .map_or_else(|| BlockAccessList::default().hash(), BlockAccessList::hash);
+            if got != expected.hash() {
+                // call post-block hook
+                self.invalid_block_hook.on_invalid_block(
+                    &parent_block,
+                    &block.seal_slow(),
+                    &output,
+                    None,
+                );
+                return Err(ConsensusError::BlockAccessListHashDiff(
+                    GotExpected { got, expected: expected.hash() }.into(),
+                )
+                .into())
+            }
+        }
+
+        // the state root computation can't be aborted, so this is the last chance to skip it
+        if is_superseded() {
+            return Err(aborted())
+        }
+
         let hashed_state = self.provider.hashed_post_state(&output.state);
 
         trace!(target: "engine::tree", block=?sealed_block.num_hash(), "Calculating block state root");
@@ -2257,11 +2443,23 @@ where
         // finish parallel computation. It is important that nothing is being persisted as
         // we are computing in parallel, because we initialize a different database transaction
         // per thread and it might end up with a different view of the database.
+        //
+        // The state root is computed in the background, meanwhile the next queued payload is
+        // executed on top of this block.
         let persistence_in_progress = self.persistence_state.in_progress();
         if !persistence_in_progress {
             state_root_result = match self
-                .compute_state_root_parallel(block.parent_hash, &hashed_state)
-            {
+                .spawn_state_root_parallel(block.parent_hash, &hashed_state)
+                .and_then(|handle| {
+                    self.spawn_execute_ahead(&sealed_block, &block.senders, &output, &hashed_state);
+                    let wait_time = Instant::now();
+                    let result = handle.wait_for_result();
+                    self.metrics
+                        .block_validation
+                        .state_root_wait_histogram
+                        .record(wait_time.elapsed().as_secs_f64());
+                    result
+                }) {
                 Ok((state_root, trie_output)) => Some((state_root, trie_output)),
                 Err(ParallelStateRootError::Provider(ProviderError::ConsistentView(error))) => {
                     debug!(target: "engine", %error, "Parallel state root computation failed consistency check, falling back");
@@ -2279,6 +2477,9 @@ where
         };
 
         if state_root != block.state_root {
+            // the block is invalid, so a block executed on top of it is useless
+            self.execute_ahead = None;
+
             // call post-block hook
             self.invalid_block_hook.on_invalid_block(
                 &parent_block,
@@ -2326,19 +2527,51 @@ where
         Ok(InsertPayloadOk2::Inserted(BlockStatus2::Valid))
     }
 
-    /// Compute state root for the given hashed post state in parallel.
+    /// Receives all pending engine API requests and returns true if a received forkchoice update
+    /// supersedes the validation of the given block.
+    ///
+    /// A forkchoice update supersedes the block if it points to a known head that is neither the
+    /// current canonical head nor the block or its parent, i.e. if it moves the canonical chain to
+    /// a different branch. The received requests are queued and handled afterwards.
+    fn receive_superseding_forkchoice(&self, block_hash: B256, parent_hash: B256) -> bool {
+        let mut superseding = false;
+        let mut received = self.received.borrow_mut();
+        while let Ok(msg) = self.incoming.try_recv() {
+            if let FromEngine::Request(EngineApiRequest::Beacon(
+                BeaconEngineMessage::ForkchoiceUpdated { state, .. },
+            )) = &msg
+            {
+                let head = state.head_block_hash;
+                if head != block_hash &&
+                    head != parent_hash &&
+                    head != self.state.tree_state.canonical_block_hash() &&
+                    self.sealed_header_by_hash(head).is_ok_and(|header| header.is_some())
+                {
+                    superseding = true;
+                }
+            }
+            received.push_back(msg);
+        }
+        superseding
+    }
+
+    /// Spawns the state root computation for the given hashed post state in parallel on a
+    /// dedicated thread.
+    ///
+    /// The computation reads from a consistent view of the database, which fails the computation
+    /// if the database tip changes in the meantime.
     ///
     /// # Returns
     ///
-    /// Returns `Ok(_)` if computed successfully.
+    /// Returns `Ok(_)` with the handle to the result if spawned successfully.
     /// Returns `Err(_)` if error was encountered during computation.
     /// `Err(ProviderError::ConsistentView(_))` can be safely ignored and fallback computation
     /// should be used instead.
-    fn compute_state_root_parallel(
+    fn spawn_state_root_parallel(
         &self,
         parent_hash: B256,
         hashed_state: &HashedPostState,
-    ) -> Result<(B256, TrieUpdates), ParallelStateRootError> {
+    ) -> Result<StateRootHandle, ParallelStateRootError> {
         // TODO: when we switch to calculate state root using `StateRootTask` this
         // method can be still useful to calculate the required `TrieInput` to
         // create the task.
@@ -2365,7 +2598,122 @@ where
         // Extend with block we are validating root for.
         input.append_ref(hashed_state);
 
-        ParallelStateRoot::new(consistent_view, input).incremental_root_with_updates()
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.task_spawner.spawn_blocking(Box::pin(async move {
+            let result =
+                ParallelStateRoot::new(consistent_view, input).incremental_root_with_updates();
+            let _ = tx.send(result);
+        }));
+
+        Ok(StateRootHandle::new(rx))
+    }
+
+    /// Spawns the execution of the queued payload that extends the given block, if any, while the
+    /// state root of the block is computed in the background.
+    ///
+    /// The block is not validated yet, so the output is only used once the block was committed,
+    /// see [`Self::take_executed_ahead`]. A previously spawned execution is aborted.
+    fn spawn_execute_ahead(
+        &mut self,
+        sealed_block: &Arc<SealedBlock>,
+        senders: &[Address],
+        output: &BlockExecutionOutput<reth_primitives::Receipt>,
+        hashed_state: &HashedPostState,
+    ) {
+        let parent_hash = sealed_block.hash();
+        let Some(block) = self.queued_payload_with_parent(parent_hash) else { return };
+        let Ok(Some(historical)) = self.state_provider(sealed_block.parent_hash) else { return };
+
+        // overlay the execution output of the unvalidated parent
+        let parent = ExecutedBlock {
+            block: sealed_block.clone(),
+            senders: Arc::new(senders.to_vec()),
+            execution_output: Arc::new(ExecutionOutcome::from((
+                output.clone(),
+                sealed_block.number,
+            ))),
+            hashed_state: Arc::new(hashed_state.clone()),
+            trie: Arc::new(TrieUpdates::default()),
+        };
+        let state_provider = MemoryOverlayStateProvider::new(historical, vec![parent]);
+
+        let block_hash = block.hash();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let is_cancelled = cancelled.clone();
+        let executor_provider = self.executor_provider.clone();
+        let executor_metrics = self.metrics.executor.clone();
+        let executed_ahead_blocks = self.metrics.block_validation.executed_ahead_blocks.clone();
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.task_spawner.spawn_blocking(Box::pin(async move {
+            let executor = executor_provider.executor(AbortableDatabase::new(
+                StateProviderDatabase::new(state_provider),
+                || is_cancelled.load(atomic::Ordering::Relaxed),
+            ));
+
+            let noop_state_hook = |_state: &EvmState| {};
+            match executor_metrics.execute_metered(
+                executor,
+                (&block.unseal(), U256::MAX).into(),
+                Box::new(noop_state_hook),
+            ) {
+                Ok(output) => {
+                    debug!(target: "engine::tree", ?block_hash, ?parent_hash, "Executed block ahead");
+                    executed_ahead_blocks.increment(1);
+                    let _ = tx.send(output);
+                }
+                Err(error) => {
+                    debug!(target: "engine::tree", ?block_hash, %error, "Failed to execute block ahead");
+                }
+            }
+        }));
+
+        self.execute_ahead = Some(ExecuteAheadTask { block_hash, parent_hash, cancelled, rx });
+    }
+
+    /// Receives all pending engine API requests and returns the first queued payload that extends
+    /// the given parent, if it is well formed.
+    fn queued_payload_with_parent(&self, parent_hash: B256) -> Option<SealedBlockWithSenders> {
+        let mut received = self.received.borrow_mut();
+        while let Ok(msg) = self.incoming.try_recv() {
+            received.push_back(msg);
+        }
+
+        let (payload, sidecar) = received.iter().find_map(|msg| match msg {
+            FromEngine::Request(EngineApiRequest::Beacon(BeaconEngineMessage::NewPayload {
+                payload,
+                sidecar,
+                ..
+            })) if payload.parent_hash() == parent_hash => Some((payload.clone(), sidecar.clone())),
+            _ => None,
+        })?;
+        drop(received);
+
+        self.payload_validator
+            .ensure_well_formed_payload(payload, sidecar)
+            .ok()?
+            .try_seal_with_senders()
+            .ok()
+    }
+
+    /// Returns the output of the block if it was executed ahead on top of the given parent,
+    /// waiting for the execution to finish.
+    ///
+    /// The output is only valid if the parent was committed, i.e. its state root was verified
+    /// after the block was executed. Otherwise the execution is aborted and its output discarded.
+    fn take_executed_ahead(
+        &mut self,
+        block_hash: B256,
+        parent_hash: B256,
+    ) -> Option<BlockExecutionOutput<reth_primitives::Receipt>> {
+        let task = self.execute_ahead.take()?;
+        if task.block_hash != block_hash ||
+            task.parent_hash != parent_hash ||
+            self.state.tree_state.executed_block_by_hash(parent_hash).is_none()
+        {
+            self.metrics.block_validation.discarded_executed_ahead_blocks.increment(1);
+            return None
+        }
+        task.rx.recv().ok()
     }
 
     /// Handles an error that occurred while inserting a block.
@@ -2639,6 +2987,7 @@ mod tests {
     use reth_primitives::{BlockExt, EthPrimitives};
     use reth_provider::test_utils::MockEthProvider;
     use reth_rpc_types_compat::engine::{block_to_payload_v1, payload::block_to_payload_v3};
+    use reth_tasks::TokioTaskExecutor;
     use reth_trie::updates::TrieUpdates;
     use std::{
         str::FromStr,
@@ -2747,7 +3096,8 @@ mod tests {
 
             let header = chain_spec.genesis_header().clone();
             let header = SealedHeader::seal(header);
-            let engine_api_tree_state = EngineApiTreeState::new(10, 10, header.num_hash());
+            let engine_api_tree_state =
+                EngineApiTreeState::new(10, 10, header.num_hash(), &Default::default());
             let canonical_in_memory_state = CanonicalInMemoryState::with_head(header, None, None);
 
             let (to_payload_service, _payload_command_rx) = unbounded_channel();
@@ -2766,6 +3116,7 @@ mod tests {
                 payload_builder,
                 TreeConfig::default(),
                 EngineApiKind::Ethereum,
+                TokioTaskExecutor::default().boxed(),
             );
 
             let block_builder = TestBlockBuilder::default().with_chain_spec((*chain_spec).clone());
@@ -2916,6 +3267,7 @@ mod tests {
                         parent_beacon_block_root: block.parent_beacon_block_root.unwrap(),
                         versioned_hashes: vec![],
                     }),
+                    None,
                 )
                 .unwrap();
         }
@@ -3181,7 +3533,7 @@ mod tests {
 
         let outcome = test_harness
             .tree
-            .on_new_payload(payload.into(), ExecutionPayloadSidecar::none())
+            .on_new_payload(payload.into(), ExecutionPayloadSidecar::none(), None)
             .unwrap();
         assert!(outcome.outcome.is_syncing());
 
@@ -3190,6 +3542,41 @@ mod tests {
         assert_eq!(buffered.block, sealed);
     }
 
+    #[test]
+    fn test_disconnected_payload_with_block_access_list() {
+        let s = include_str!("../../test-data/holesky/2.rlp");
+        let data = Bytes::from_str(s).unwrap();
+        let block = Block::decode(&mut data.as_ref()).unwrap();
+        let sealed = block.seal_slow();
+        let num_hash = sealed.num_hash();
+        let payload = block_to_payload_v1(sealed);
+
+        let mut test_harness = TestHarness::new(HOLESKY.clone());
+
+        let block_access_list = BlockAccessList::default();
+        let outcome = test_harness
+            .tree
+            .on_new_payload(
+                payload.into(),
+                ExecutionPayloadSidecar::none(),
+                Some(block_access_list.clone()),
+            )
+            .unwrap();
+        assert!(outcome.outcome.is_syncing());
+
+        // ensure the block access list is buffered with the block, so that it's verified once the
+        // block is connected
+        assert!(test_harness.tree.state.buffer.block(&num_hash.hash).is_some());
+        assert_eq!(
+            test_harness.tree.state.buffered_block_access_lists.get(&num_hash.hash),
+            Some(&block_access_list)
+        );
+
+        test_harness.tree.state.buffer.remove_old_blocks(num_hash.number);
+        test_harness.tree.state.retain_buffered_block_access_lists();
+        assert!(test_harness.tree.state.buffered_block_access_lists.is_empty());
+    }
+
     #[test]
     fn test_disconnected_block() {
         let s = include_str!("../../test-data/holesky/2.rlp");
@@ -3199,7 +3586,7 @@ mod tests {
 
         let mut test_harness = TestHarness::new(HOLESKY.clone());
 
-        let outcome = test_harness.tree.insert_block_without_senders(sealed.clone()).unwrap();
+        let outcome = test_harness.tree.insert_block_without_senders(sealed.clone(), None).unwrap();
         assert_eq!(
             outcome,
             InsertPayloadOk2::Inserted(BlockStatus2::Disconnected {
@@ -3227,6 +3614,7 @@ mod tests {
                 BeaconEngineMessage::NewPayload {
                     payload: payload.clone().into(),
                     sidecar: ExecutionPayloadSidecar::none(),
+                    block_access_list: None,
                     tx,
                 }
                 .into(),
@@ -3700,6 +4088,133 @@ mod tests {
         test_harness.check_canon_head(fork_chain_last_hash);
     }
 
+    #[tokio::test]
+    async fn test_engine_tree_new_payload_aborted_by_superseding_fcu() {
+        let chain_spec = MAINNET.clone();
+        let mut test_harness = TestHarness::new(chain_spec.clone());
+
+        let main_chain: Vec<_> = test_harness.block_builder.get_executed_blocks(0..5).collect();
+        test_harness = test_harness.with_blocks(main_chain.clone());
+
+        // add a fork block to the tree
+        let fork_chain = test_harness.block_builder.create_fork(main_chain[2].block(), 1);
+        let fork_block_hash = fork_chain[0].hash();
+        test_harness.insert_block(fork_chain[0].clone()).unwrap();
+
+        // a forkchoice update to the fork block arrives before the next block is validated
+        let (tx, _rx) = oneshot::channel();
+        let fcu = FromEngine::Request(
+            BeaconEngineMessage::ForkchoiceUpdated {
+                state: test_harness.fcu_state(fork_block_hash),
+                payload_attrs: None,
+                tx,
+                version: EngineApiMessageVersion::default(),
+            }
+            .into(),
+        );
+        test_harness.to_tree_tx.send(fcu).unwrap();
+
+        let block = test_harness.block_builder.create_fork(main_chain[4].block(), 1).remove(0);
+        let outcome = test_harness
+            .tree
+            .on_new_payload(
+                block_to_payload_v3(block.block.clone()).into(),
+                ExecutionPayloadSidecar::v3(CancunPayloadFields {
+                    parent_beacon_block_root: block.parent_beacon_block_root.unwrap(),
+                    versioned_hashes: vec![],
+                }),
+                None,
+            )
+            .unwrap();
+
+        // the validation is aborted and the block is neither inserted nor marked invalid
+        assert!(outcome.outcome.is_syncing());
+        assert!(test_harness.tree.state.tree_state.block_by_hash(block.hash()).is_none());
+        assert!(test_harness.tree.state.invalid_headers.get(&block.hash()).is_none());
+
+        // the forkchoice update is handled next
+        let msg = test_harness.tree.try_recv_engine_message().unwrap().unwrap();
+        assert_matches!(
+            msg,
+            FromEngine::Request(EngineApiRequest::Beacon(
+                BeaconEngineMessage::ForkchoiceUpdated { state, .. }
+            )) if state.head_block_hash == fork_block_hash
+        );
+    }
+
+    #[tokio::test]
+    async fn test_take_executed_ahead_requires_committed_parent() {
+        let chain_spec = MAINNET.clone();
+        let mut test_harness = TestHarness::new(chain_spec);
+
+        let blocks: Vec<_> = test_harness.block_builder.get_executed_blocks(0..3).collect();
+        test_harness = test_harness.with_blocks(blocks.clone());
+        let committed = blocks.last().unwrap().block().hash();
+
+        let execute_ahead = |parent_hash| {
+            let (tx, rx) = mpsc::sync_channel(1);
+            tx.send(BlockExecutionOutput {
+                state: Default::default(),
+                receipts: vec![],
+                requests: Default::default(),
+                gas_used: 0,
+                block_access_list: None,
+            })
+            .unwrap();
+            let cancelled = Arc::new(AtomicBool::new(false));
+            let task = ExecuteAheadTask {
+                block_hash: B256::with_last_byte(1),
+                parent_hash,
+                cancelled: cancelled.clone(),
+                rx,
+            };
+            (task, cancelled)
+        };
+
+        // the parent was committed
+        let (task, _) = execute_ahead(committed);
+        test_harness.tree.execute_ahead = Some(task);
+        assert!(test_harness
+            .tree
+            .take_executed_ahead(B256::with_last_byte(1), committed)
+            .is_some());
+        assert!(test_harness.tree.execute_ahead.is_none());
+
+        // a different block is inserted, which aborts the execution
+        let (task, cancelled) = execute_ahead(committed);
+        test_harness.tree.execute_ahead = Some(task);
+        assert!(test_harness
+            .tree
+            .take_executed_ahead(B256::with_last_byte(2), committed)
+            .is_none());
+        assert!(test_harness.tree.execute_ahead.is_none());
+        assert!(cancelled.load(atomic::Ordering::Relaxed));
+
+        // the parent was not committed
+        let uncommitted = B256::with_last_byte(3);
+        let (task, cancelled) = execute_ahead(uncommitted);
+        test_harness.tree.execute_ahead = Some(task);
+        assert!(test_harness
+            .tree
+            .take_executed_ahead(B256::with_last_byte(1), uncommitted)
+            .is_none());
+        assert!(cancelled.load(atomic::Ordering::Relaxed));
+
+        // the execution failed or was aborted
+        let (tx, rx) = mpsc::sync_channel(1);
+        drop(tx);
+        test_harness.tree.execute_ahead = Some(ExecuteAheadTask {
+            block_hash: B256::with_last_byte(1),
+            parent_hash: committed,
+            cancelled: Default::default(),
+            rx,
+        });
+        assert!(test_harness
+            .tree
+            .take_executed_ahead(B256::with_last_byte(1), committed)
+            .is_none());
+    }
+
     #[tokio::test]
     async fn test_engine_tree_live_sync_transition_required_blocks_requested() {
         reth_tracing::init_test_tracing();
@@ -50,8 +50,15 @@ impl StateRootHandle {
     }
 
     /// Waits for the state root calculation to complete.
+    ///
+    /// Returns an error if the task was dropped without sending a result, e.g. because it
+    /// panicked.
     pub fn wait_for_result(self) -> StateRootResult {
-        self.rx.recv().expect("state root task was dropped without sending result")
+        self.rx.recv().unwrap_or_else(|_| {
+            Err(ParallelStateRootError::Other(
+                "state root task was dropped without sending result".to_string(),
+            ))
+        })
     }
 }
 
@@ -18,6 +18,8 @@ use skip_new_payload::EngineSkipNewPayload;
 pub mod reorg;
 use reorg::EngineReorg;
 
+pub mod warm_state;
+
 /// The collection of stream extensions for engine API message stream.
 pub trait EngineMessageStreamExt<Engine: EngineTypes>:
     Stream<Item = BeaconEngineMessage<Engine>>
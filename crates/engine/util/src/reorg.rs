@@ -151,7 +151,12 @@ where
             let next = ready!(this.stream.poll_next_unpin(cx));
             let item = match (next, &this.last_forkchoice_state) {
                 (
-                    Some(BeaconEngineMessage::NewPayload { payload, sidecar, tx }),
+                    Some(BeaconEngineMessage::NewPayload {
+                        payload,
+                        sidecar,
+                        block_access_list,
+                        tx,
+                    }),
                     Some(last_forkchoice_state),
                 ) if this.forkchoice_states_forwarded > this.frequency &&
                         // Only enter reorg state if new payload attaches to current head.
@@ -182,6 +187,7 @@ where
                             return Poll::Ready(Some(BeaconEngineMessage::NewPayload {
                                 payload,
                                 sidecar,
+                                block_access_list,
                                 tx,
                             }))
                         }
@@ -201,11 +207,12 @@ where
 
                     let queue = VecDeque::from([
                         // Current payload
-                        BeaconEngineMessage::NewPayload { payload, sidecar, tx },
+                        BeaconEngineMessage::NewPayload { payload, sidecar, block_access_list, tx },
                         // Reorg payload
                         BeaconEngineMessage::NewPayload {
                             payload: reorg_payload,
                             sidecar: reorg_sidecar,
+                            block_access_list: None,
                             tx: reorg_payload_tx,
                         },
                         // Reorg forkchoice state
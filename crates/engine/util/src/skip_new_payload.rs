@@ -40,7 +40,12 @@ where
         loop {
             let next = ready!(this.stream.poll_next_unpin(cx));
             let item = match next {
-                Some(BeaconEngineMessage::NewPayload { payload, sidecar, tx }) => {
+                Some(BeaconEngineMessage::NewPayload {
+                    payload,
+                    sidecar,
+                    block_access_list,
+                    tx,
+                }) => {
                     if this.skipped < this.threshold {
                         *this.skipped += 1;
                         tracing::warn!(
@@ -55,7 +60,12 @@ where
                         continue
                     }
                     *this.skipped = 0;
-                    Some(BeaconEngineMessage::NewPayload { payload, sidecar, tx })
+                    Some(BeaconEngineMessage::NewPayload {
+                        payload,
+                        sidecar,
+                        block_access_list,
+                        tx,
+                    })
                 }
                 next => next,
             };
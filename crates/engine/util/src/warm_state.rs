@@ -0,0 +1,236 @@
+//! Persists the keys of hot state across restarts to pre-warm the database on startup.
+//!
+//! After a restart all state and trie pages of the database are cold, so the first blocks and RPC
+//! requests are served from disk. The [`WarmStateTracker`] records the accounts and storage slots
+//! changed by recent canonical blocks, which are written to a file on shutdown. On startup the
+//! state of these keys is read once with [`warm_state`], which pulls the relevant state and trie
+//! pages into the page cache before they are needed.
+//!
+//! Only the keys are persisted, the values are always read from the current state.
+
+use alloy_primitives::{keccak256, map::HashMap, Address, B256};
+use futures::{Stream, StreamExt};
+use reth_fs_util as fs;
+use reth_primitives::NodePrimitives;
+use reth_provider::{CanonStateNotification, Chain, ProviderResult, StateProviderFactory};
+use reth_trie::TrieInput;
+use schnellru::{ByLength, LruMap};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashSet},
+    path::{Path, PathBuf},
+};
+use tracing::*;
+
+/// The keys of the state to pre-warm on startup.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WarmStateKeys {
+    /// The accounts to pre-warm.
+    pub accounts: Vec<Address>,
+    /// The storage slots to pre-warm, by account.
+    pub storage: BTreeMap<Address, Vec<B256>>,
+}
+
+impl WarmStateKeys {
+    /// Returns the total number of account and storage keys.
+    pub fn len(&self) -> usize {
+        self.accounts.len() + self.storage.values().map(Vec::len).sum::<usize>()
+    }
+
+    /// Returns `true` if there are no keys.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Loads the keys from the given file.
+    ///
+    /// Returns empty keys if the file does not exist.
+    pub fn load(path: &Path) -> eyre::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default())
+        }
+        Ok(fs::read_json_file(path)?)
+    }
+
+    /// Writes the keys to the given file.
+    pub fn save(&self, path: &Path) -> eyre::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::atomic_write_file(path, |file| serde_json::to_writer(file, self))?;
+        Ok(())
+    }
+}
+
+/// A key of the state tracked by the [`WarmStateTracker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum WarmStateKey {
+    Account(Address),
+    Storage(Address, B256),
+}
+
+/// Tracks the most recently changed account and storage keys of the canonical chain.
+#[derive(Debug)]
+pub struct WarmStateTracker {
+    keys: LruMap<WarmStateKey, ()>,
+}
+
+impl WarmStateTracker {
+    /// Creates a new tracker that keeps at most `max_keys` account and storage keys.
+    pub fn new(max_keys: u32) -> Self {
+        Self { keys: LruMap::new(ByLength::new(max_keys)) }
+    }
+
+    /// Tracks the given keys, e.g. the keys loaded on startup.
+    pub fn extend(&mut self, keys: &WarmStateKeys) {
+        for address in &keys.accounts {
+            self.keys.insert(WarmStateKey::Account(*address), ());
+        }
+        for (address, slots) in &keys.storage {
+            for slot in slots {
+                self.keys.insert(WarmStateKey::Storage(*address, *slot), ());
+            }
+        }
+    }
+
+    /// Tracks all accounts and storage slots changed by the blocks of the chain.
+    pub fn on_chain<N: NodePrimitives>(&mut self, chain: &Chain<N>) {
+        for (address, account) in chain.execution_outcome().bundle_accounts_iter() {
+            self.keys.insert(WarmStateKey::Account(address), ());
+            for slot in account.storage.keys() {
+                self.keys.insert(WarmStateKey::Storage(address, B256::from(*slot)), ());
+            }
+        }
+    }
+
+    /// Returns all tracked keys.
+    pub fn keys(&self) -> WarmStateKeys {
+        let mut keys = WarmStateKeys::default();
+        for (key, _) in self.keys.iter() {
+            match *key {
+                WarmStateKey::Account(address) => keys.accounts.push(address),
+                WarmStateKey::Storage(address, slot) => {
+                    keys.storage.entry(address).or_default().push(slot)
+                }
+            }
+        }
+        keys
+    }
+}
+
+/// Reads the latest state of all keys and computes a multiproof for them, so that the state and
+/// trie pages they touch are loaded from disk.
+pub fn warm_state<P: StateProviderFactory>(
+    provider: &P,
+    keys: &WarmStateKeys,
+) -> ProviderResult<()> {
+    let state = provider.latest()?;
+
+    let mut targets = HashMap::<B256, HashSet<B256>>::default();
+    for address in &keys.accounts {
+        state.basic_account(address)?;
+        targets.entry(keccak256(address)).or_default();
+    }
+    for (address, slots) in &keys.storage {
+        let hashed_slots = targets.entry(keccak256(address)).or_default();
+        for slot in slots {
+            state.storage(*address, *slot)?;
+            hashed_slots.insert(keccak256(slot));
+        }
+    }
+
+    state.multiproof(TrieInput::default(), targets)?;
+    Ok(())
+}
+
+/// Pre-warms the state of the keys in the given file, tracks the keys changed by new canonical
+/// blocks and writes them back to the file on shutdown.
+pub async fn warm_state_task<P, St, N>(
+    shutdown: reth_tasks::shutdown::GracefulShutdown,
+    provider: P,
+    canon_state_stream: St,
+    path: PathBuf,
+    max_keys: u32,
+) where
+    P: StateProviderFactory + Clone + 'static,
+    St: Stream<Item = CanonStateNotification<N>> + Send,
+    N: NodePrimitives,
+{
+    let path = path.as_path();
+    let mut tracker = WarmStateTracker::new(max_keys);
+
+    match WarmStateKeys::load(path) {
+        Ok(keys) if !keys.is_empty() => {
+            tracker.extend(&keys);
+            let provider = provider.clone();
+            let num_keys = keys.len();
+            let warm = tokio::task::spawn_blocking(move || warm_state(&provider, &keys));
+            match warm.await {
+                Ok(Ok(())) => info!(target: "engine::warm_state", num_keys, "Pre-warmed state"),
+                Ok(Err(err)) => {
+                    warn!(target: "engine::warm_state", %err, "Failed to pre-warm state")
+                }
+                Err(err) => warn!(target: "engine::warm_state", %err, "State pre-warming panicked"),
+            }
+        }
+        Ok(_) => {}
+        Err(err) => {
+            warn!(target: "engine::warm_state", %err, ?path, "Failed to load warm state keys")
+        }
+    }
+
+    let mut canon_state_stream = std::pin::pin!(canon_state_stream);
+    let mut shutdown = std::pin::pin!(shutdown);
+    let graceful_guard = loop {
+        tokio::select! {
+            guard = &mut shutdown => break guard,
+            notification = canon_state_stream.next() => {
+                let Some(notification) = notification else {
+                    break (&mut shutdown).await
+                };
+                tracker.on_chain(&notification.committed());
+            }
+        }
+    };
+
+    let keys = tracker.keys();
+    match keys.save(path) {
+        Ok(()) => {
+            debug!(target: "engine::warm_state", num_keys = keys.len(), ?path, "Saved warm state keys")
+        }
+        Err(err) => {
+            warn!(target: "engine::warm_state", %err, ?path, "Failed to save warm state keys")
+        }
+    }
+
+    drop(graceful_guard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_load_keys() {
+        let dir = std::env::temp_dir().join(format!("warm-state-{}", B256::random()));
+        let path = dir.join("warm-state-keys.json");
+        assert!(WarmStateKeys::load(&path).unwrap().is_empty());
+
+        let (account, other) = (Address::random(), Address::random());
+        let keys = WarmStateKeys {
+            accounts: vec![account, other],
+            storage: BTreeMap::from([(account, vec![B256::random(), B256::random()])]),
+        };
+        let mut tracker = WarmStateTracker::new(3);
+        tracker.extend(&keys);
+
+        // only the most recently tracked keys are kept
+        let tracked = tracker.keys();
+        assert_eq!(tracked.len(), 3);
+        assert_eq!(tracked.accounts, vec![other]);
+
+        tracked.save(&path).unwrap();
+        assert_eq!(WarmStateKeys::load(&path).unwrap(), tracked);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -51,6 +51,8 @@ hardfork!(
         Prague,
         /// Osaka: <https://eips.ethereum.org/EIPS/eip-7607>
         Osaka,
+        /// Amsterdam: <https://eips.ethereum.org/EIPS/eip-7773>
+        Amsterdam,
     }
 );
 
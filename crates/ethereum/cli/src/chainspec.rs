@@ -90,7 +90,8 @@ mod tests {
     "shanghaiTime": 0,
     "cancunTime": 0,
     "pragueTime": 0,
-    "osakaTime": 0
+    "osakaTime": 0,
+    "amsterdamTime": 0
   }
 }"#;
 
@@ -99,5 +100,6 @@ mod tests {
         assert!(spec.is_cancun_active_at_timestamp(0));
         assert!(spec.is_prague_active_at_timestamp(0));
         assert!(spec.is_osaka_active_at_timestamp(0));
+        assert!(spec.is_amsterdam_active_at_timestamp(0));
     }
 }
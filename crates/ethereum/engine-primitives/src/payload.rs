@@ -1,13 +1,14 @@
 //! Contains types required for building a payload.
 
 use alloy_eips::{eip4844::BlobTransactionSidecar, eip4895::Withdrawals, eip7685::Requests};
-use alloy_primitives::{Address, B256, U256};
+use alloy_primitives::{Address, Bytes, B256, U256};
 use alloy_rlp::Encodable;
 use alloy_rpc_types_engine::{
     ExecutionPayloadEnvelopeV2, ExecutionPayloadEnvelopeV3, ExecutionPayloadEnvelopeV4,
     ExecutionPayloadV1, PayloadAttributes, PayloadId,
 };
 use reth_chain_state::ExecutedBlock;
+use reth_execution_types::BlockAccessList;
 use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes};
 use reth_primitives::SealedBlock;
 use reth_rpc_types_compat::engine::payload::{
@@ -35,6 +36,8 @@ pub struct EthBuiltPayload {
     pub(crate) sidecars: Vec<BlobTransactionSidecar>,
     /// The requests of the payload
     pub(crate) requests: Option<Requests>,
+    /// The EIP-7928 block access list of the payload, if Amsterdam is active.
+    pub(crate) block_access_list: Option<BlockAccessList>,
 }
 
 // === impl BuiltPayload ===
@@ -50,7 +53,15 @@ impl EthBuiltPayload {
         executed_block: Option<ExecutedBlock>,
         requests: Option<Requests>,
     ) -> Self {
-        Self { id, block, executed_block, fees, sidecars: Vec::new(), requests }
+        Self {
+            id,
+            block,
+            executed_block,
+            fees,
+            sidecars: Vec::new(),
+            requests,
+            block_access_list: None,
+        }
     }
 
     /// Returns the identifier of the payload.
@@ -86,6 +97,17 @@ impl EthBuiltPayload {
         self.extend_sidecars(sidecars);
         self
     }
+
+    /// Returns the block access list of the payload.
+    pub const fn block_access_list(&self) -> Option<&BlockAccessList> {
+        self.block_access_list.as_ref()
+    }
+
+    /// Sets the block access list of the payload.
+    pub fn with_block_access_list(mut self, block_access_list: Option<BlockAccessList>) -> Self {
+        self.block_access_list = block_access_list;
+        self
+    }
 }
 
 impl BuiltPayload for EthBuiltPayload {
@@ -186,6 +208,26 @@ impl From<EthBuiltPayload> for ExecutionPayloadEnvelopeV4 {
     }
 }
 
+/// The response of `engine_getPayloadV5`: the V4 envelope with the RLP encoded
+/// [EIP-7928](https://eips.ethereum.org/EIPS/eip-7928) block access list of the payload.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionPayloadEnvelopeV5 {
+    /// The V4 envelope of the payload.
+    #[serde(flatten)]
+    pub envelope: ExecutionPayloadEnvelopeV4,
+    /// The RLP encoded block access list of the payload.
+    pub block_access_list: Bytes,
+}
+
+impl From<EthBuiltPayload> for ExecutionPayloadEnvelopeV5 {
+    fn from(value: EthBuiltPayload) -> Self {
+        let block_access_list =
+            alloy_rlp::encode(value.block_access_list.clone().unwrap_or_default()).into();
+        Self { envelope: value.into(), block_access_list }
+    }
+}
+
 /// Container type for all components required to build a payload.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct EthPayloadBuilderAttributes {
@@ -13,9 +13,9 @@ use reth_consensus::ConsensusError;
 use reth_ethereum_consensus::validate_block_post_execution;
 use reth_evm::{
     execute::{
-        balance_increment_state, BasicBlockExecutorProvider, BlockExecutionError,
-        BlockExecutionStrategy, BlockExecutionStrategyFactory, BlockValidationError, ExecuteOutput,
-        ProviderError,
+        balance_increment_state, BasicBlockExecutorProvider, BlockAccessList,
+        BlockAccessListBuilder, BlockExecutionError, BlockExecutionStrategy,
+        BlockExecutionStrategyFactory, BlockValidationError, ExecuteOutput, ProviderError,
     },
     state_change::post_block_balance_increments,
     system_calls::{OnStateHook, SystemCaller},
@@ -159,6 +159,14 @@ where
             (*self.chain_spec).is_spurious_dragon_active_at_block(block.header.number);
         self.state.set_state_clear_flag(state_clear_flag);
 
+        // Record the block access list of the block once Amsterdam is active, starting with the
+        // pre-execution system calls at index 0.
+        let block_access_list = self
+            .chain_spec
+            .is_amsterdam_active_at_timestamp(block.timestamp)
+            .then(BlockAccessListBuilder::default);
+        self.system_caller.with_block_access_list(block_access_list);
+
         let env = self.evm_env_for_block(&block.header, total_difficulty);
         let mut evm = self.evm_config.evm_with_env(&mut self.state, env);
 
@@ -177,7 +185,7 @@ where
 
         let mut cumulative_gas_used = 0;
         let mut receipts = Vec::with_capacity(block.body.transactions.len());
-        for (sender, transaction) in block.transactions_with_sender() {
+        for (index, (sender, transaction)) in block.transactions_with_sender().enumerate() {
             // The sum of the transaction’s gas limit, Tg, and the gas utilized in this block prior,
             // must be no greater than the block’s gasLimit.
             let block_available_gas = block.header.gas_limit - cumulative_gas_used;
@@ -204,6 +212,7 @@ where
                     error: Box::new(new_err),
                 }
             })?;
+            self.system_caller.set_block_access_index(index as u64 + 1);
             self.system_caller.on_state(&result_and_state.state);
             let ResultAndState { result, state } = result_and_state;
             evm.db_mut().commit(state);
@@ -235,6 +244,8 @@ where
         total_difficulty: U256,
         receipts: &[Receipt],
     ) -> Result<Requests, Self::Error> {
+        self.system_caller.set_block_access_index(block.body.transactions.len() as u64 + 1);
+
         let env = self.evm_env_for_block(&block.header, total_difficulty);
         let mut evm = self.evm_config.evm_with_env(&mut self.state, env);
 
@@ -295,6 +306,10 @@ where
         self.system_caller.with_state_hook(hook);
     }
 
+    fn take_block_access_list(&mut self) -> Option<BlockAccessList> {
+        self.system_caller.take_block_access_list().map(|builder| builder.build(&self.state))
+    }
+
     fn validate_block_post_execution(
         &self,
         block: &BlockWithSenders,
@@ -340,7 +355,10 @@ mod tests {
     use reth_evm::execute::{
         BasicBlockExecutorProvider, BatchExecutor, BlockExecutorProvider, Executor,
     };
-    use reth_execution_types::BlockExecutionOutput;
+    use reth_execution_types::{
+        block_access_list::{AccountChanges, BalanceChange},
+        BlockExecutionOutput,
+    };
     use reth_primitives::{
         public_key_to_address, Account, Block, BlockBody, BlockExt, Transaction,
     };
@@ -1298,4 +1316,71 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn block_access_list() {
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::from(&*MAINNET)
+                .shanghai_activated()
+                .with_fork(EthereumHardfork::Amsterdam, ForkCondition::Timestamp(1))
+                .build(),
+        );
+
+        let withdrawal_recipient = address!("1000000000000000000000000000000000000000");
+
+        let mut db = StateProviderTest::default();
+        db.insert_account(
+            withdrawal_recipient,
+            Account { balance: U256::from(100), nonce: 1, bytecode_hash: None },
+            None,
+            HashMap::default(),
+        );
+
+        let block = |timestamp| BlockWithSenders {
+            block: Block {
+                header: Header { timestamp, number: 1, ..Header::default() },
+                body: BlockBody {
+                    transactions: vec![],
+                    ommers: vec![],
+                    withdrawals: Some(
+                        vec![Withdrawal {
+                            index: 0,
+                            validator_index: 0,
+                            address: withdrawal_recipient,
+                            amount: 1,
+                        }]
+                        .into(),
+                    ),
+                },
+            },
+            senders: vec![],
+        };
+
+        let provider = executor_provider(chain_spec);
+
+        // no block access list is generated before Amsterdam
+        let output = provider
+            .executor(StateProviderDatabase::new(&db))
+            .execute((&block(0), U256::ZERO).into())
+            .expect("Block execution should succeed");
+        assert_eq!(output.block_access_list, None);
+
+        // the withdrawal is part of the post-execution changes, at the index after the last
+        // transaction
+        let output = provider
+            .executor(StateProviderDatabase::new(&db))
+            .execute((&block(1), U256::ZERO).into())
+            .expect("Block execution should succeed");
+        assert_eq!(
+            output.block_access_list,
+            Some(BlockAccessList(vec![AccountChanges {
+                address: withdrawal_recipient,
+                balance_changes: vec![BalanceChange {
+                    index: 1,
+                    balance: U256::from(100 + 1_000_000_000),
+                }],
+                ..Default::default()
+            }]))
+        );
+    }
 }
@@ -2,7 +2,9 @@
 
 use std::sync::Arc;
 
-use reth_basic_payload_builder::{BasicPayloadJobGenerator, BasicPayloadJobGeneratorConfig};
+use reth_basic_payload_builder::{
+    BasicPayloadJobGenerator, BasicPayloadJobGeneratorConfig, TransactionExclusions,
+};
 use reth_beacon_consensus::EthBeaconConsensus;
 use reth_chainspec::ChainSpec;
 use reth_ethereum_engine_primitives::{
@@ -26,12 +28,12 @@ use reth_node_builder::{
 };
 use reth_payload_builder::{PayloadBuilderHandle, PayloadBuilderService};
 use reth_primitives::{EthPrimitives, PooledTransactionsElement};
-use reth_provider::{CanonStateSubscriptions, EthStorage};
+use reth_provider::{CanonStateSubscriptions, EthStorage, HeaderProvider};
 use reth_rpc::EthApi;
-use reth_tracing::tracing::{debug, info};
+use reth_tracing::tracing::{debug, info, warn};
 use reth_transaction_pool::{
-    blobstore::DiskFileBlobStore, EthTransactionPool, PoolTransaction, TransactionPool,
-    TransactionValidationTaskExecutor,
+    blobstore::DiskFileBlobStore, EthTransactionPool, EvmAdmissionSimulator, PoolTransaction,
+    TransactionPool, TransactionValidationTaskExecutor,
 };
 use reth_trie_db::MerklePatriciaTrie;
 
@@ -172,21 +174,36 @@ where
     async fn build_pool(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Pool> {
         let data_dir = ctx.config().datadir();
         let pool_config = ctx.pool_config();
-        let blob_store = DiskFileBlobStore::open(data_dir.blobstore(), Default::default())?;
-        let validator = TransactionValidationTaskExecutor::eth_builder(ctx.chain_spec())
-            .with_head_timestamp(ctx.head().timestamp)
-            .kzg_settings(ctx.kzg_settings()?)
-            .with_local_transactions_config(pool_config.local_transactions_config.clone())
-            .with_additional_tasks(ctx.config().txpool.additional_validation_tasks)
-            .build_with_tasks(
-                ctx.provider().clone(),
-                ctx.task_executor().clone(),
-                blob_store.clone(),
-            );
+        let blob_store =
+            DiskFileBlobStore::open(data_dir.blobstore(), ctx.config().txpool.blob_store_config())?;
+        let mut validator_builder =
+            TransactionValidationTaskExecutor::eth_builder(ctx.chain_spec())
+                .with_head_timestamp(ctx.head().timestamp)
+                .kzg_settings(ctx.kzg_settings()?)
+                .with_local_transactions_config(pool_config.local_transactions_config.clone())
+                .with_additional_tasks(ctx.config().txpool.additional_validation_tasks);
+        if let Some(simulator_config) = ctx.config().txpool.admission_simulator_config() {
+            let mut simulator =
+                EvmAdmissionSimulator::new(EthEvmConfig::new(ctx.chain_spec()), simulator_config);
+            if let Some(header) = ctx.provider().sealed_header(ctx.head().number)? {
+                simulator = simulator.with_head(header, ctx.head().total_difficulty);
+            }
+            validator_builder = validator_builder.with_admission_simulator(Arc::new(simulator));
+            info!(target: "reth::cli", ?simulator_config, "Transaction admission simulation enabled");
+        }
+        let validator = validator_builder.build_with_tasks(
+            ctx.provider().clone(),
+            ctx.task_executor().clone(),
+            blob_store.clone(),
+        );
 
         let transaction_pool =
             reth_transaction_pool::Pool::eth_pool(validator, blob_store, pool_config);
         info!(target: "reth::cli", "Transaction pool initialized");
+
+        if let Err(err) = transaction_pool.replay_local_journal().await {
+            warn!(target: "reth::cli", %err, "Failed to replay local transaction journal");
+        }
         let transactions_path = data_dir.txpool_transactions();
 
         // spawn txpool maintenance task
@@ -208,6 +225,36 @@ where
                 },
             );
 
+            if let Some(snapshot_path) = ctx.config().txpool.snapshot.clone() {
+                ctx.task_executor().spawn_critical_with_graceful_shutdown_signal(
+                    "txpool snapshot task",
+                    |shutdown| {
+                        reth_transaction_pool::snapshot::pool_snapshot_task(
+                            shutdown,
+                            pool.clone(),
+                            snapshot_path,
+                        )
+                    },
+                );
+                debug!(target: "reth::cli", "Spawned txpool snapshot task");
+            }
+
+            if let Some(event_log_config) = ctx.config().txpool.event_log_config() {
+                ctx.task_executor().spawn(reth_transaction_pool::event_log::pool_event_log_task(
+                    pool.clone(),
+                    event_log_config,
+                ));
+                debug!(target: "reth::cli", "Spawned txpool event log task");
+            }
+
+            if let Some(analytics_config) = ctx.config().txpool.analytics_config() {
+                ctx.task_executor().spawn(reth_transaction_pool::analytics::pool_analytics_task(
+                    pool.clone(),
+                    analytics_config,
+                ));
+                debug!(target: "reth::cli", "Spawned txpool analytics task");
+            }
+
             // spawn the maintenance task
             ctx.task_executor().spawn_critical(
                 "txpool maintenance task",
@@ -252,14 +299,21 @@ impl EthereumPayloadBuilder {
             PayloadBuilderAttributes = EthPayloadBuilderAttributes,
         >,
     {
-        let payload_builder =
-            reth_ethereum_payload_builder::EthereumPayloadBuilder::new(evm_config);
         let conf = ctx.payload_builder_config();
 
+        let exclusions = match conf.exclusion_list() {
+            Some(path) => TransactionExclusions::load_and_watch(path, ctx.task_executor())?,
+            None => TransactionExclusions::default(),
+        };
+        let payload_builder =
+            reth_ethereum_payload_builder::EthereumPayloadBuilder::new(evm_config)
+                .with_exclusions(exclusions);
+
         let payload_job_config = BasicPayloadJobGeneratorConfig::default()
             .interval(conf.interval())
             .deadline(conf.deadline())
             .max_payload_tasks(conf.max_payload_tasks())
+            .prewarm_transactions(conf.prewarm_transactions())
             .extradata(conf.extradata_bytes());
 
         let payload_generator = BasicPayloadJobGenerator::with_builder(
@@ -17,12 +17,17 @@ use alloy_eips::{
 use alloy_primitives::U256;
 use reth_basic_payload_builder::{
     commit_withdrawals, is_better_payload, BuildArguments, BuildOutcome, PayloadBuilder,
-    PayloadConfig,
+    PayloadConfig, PayloadExclusions,
 };
 use reth_chain_state::ExecutedBlock;
 use reth_chainspec::ChainSpec;
 use reth_errors::RethError;
-use reth_evm::{system_calls::SystemCaller, ConfigureEvm, NextBlockEnvAttributes};
+use reth_evm::{
+    execute::{balance_increment_state, BlockAccessListBuilder},
+    state_change::post_block_withdrawals_balance_increments,
+    system_calls::SystemCaller,
+    ConfigureEvm, NextBlockEnvAttributes,
+};
 use reth_evm_ethereum::{eip6110::parse_deposits_from_receipts, EthEvmConfig};
 use reth_execution_types::ExecutionOutcome;
 use reth_payload_builder::{EthBuiltPayload, EthPayloadBuilderAttributes};
@@ -34,7 +39,7 @@ use reth_primitives::{
     TransactionSigned,
 };
 use reth_provider::{ChainSpecProvider, StateProviderFactory};
-use reth_revm::database::StateProviderDatabase;
+use reth_revm::{checkpoint::StateCheckpoint, database::StateProviderDatabase};
 use reth_transaction_pool::{
     error::InvalidPoolTransactionError, noop::NoopTransactionPool, BestTransactions,
     BestTransactionsAttributes, PoolTransaction, TransactionPool, ValidPoolTransaction,
@@ -56,19 +61,31 @@ type BestTransactionsIter<Pool> = Box<
 
 /// Ethereum payload builder
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct EthereumPayloadBuilder<EvmConfig = EthEvmConfig> {
+pub struct EthereumPayloadBuilder<EvmConfig = EthEvmConfig, Exclusions = ()> {
     /// The type responsible for creating the evm.
     evm_config: EvmConfig,
+    /// The transactions that are excluded from built payloads.
+    exclusions: Exclusions,
 }
 
 impl<EvmConfig> EthereumPayloadBuilder<EvmConfig> {
     /// `EthereumPayloadBuilder` constructor.
     pub const fn new(evm_config: EvmConfig) -> Self {
-        Self { evm_config }
+        Self { evm_config, exclusions: () }
     }
 }
 
-impl<EvmConfig> EthereumPayloadBuilder<EvmConfig>
+impl<EvmConfig, Exclusions> EthereumPayloadBuilder<EvmConfig, Exclusions> {
+    /// Configures the transactions that are excluded from built payloads.
+    pub fn with_exclusions<E: PayloadExclusions>(
+        self,
+        exclusions: E,
+    ) -> EthereumPayloadBuilder<EvmConfig, E> {
+        EthereumPayloadBuilder { evm_config: self.evm_config, exclusions }
+    }
+}
+
+impl<EvmConfig, Exclusions> EthereumPayloadBuilder<EvmConfig, Exclusions>
 where
     EvmConfig: ConfigureEvm<Header = Header>,
 {
@@ -89,9 +106,11 @@ where
 }
 
 // Default implementation of [PayloadBuilder] for unit type
-impl<EvmConfig, Pool, Client> PayloadBuilder<Pool, Client> for EthereumPayloadBuilder<EvmConfig>
+impl<EvmConfig, Exclusions, Pool, Client> PayloadBuilder<Pool, Client>
+    for EthereumPayloadBuilder<EvmConfig, Exclusions>
 where
     EvmConfig: ConfigureEvm<Header = Header, Transaction = TransactionSigned>,
+    Exclusions: PayloadExclusions,
     Client: StateProviderFactory + ChainSpecProvider<ChainSpec = ChainSpec>,
     Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>>,
 {
@@ -107,8 +126,18 @@ where
             .map_err(PayloadBuilderError::other)?;
 
         let pool = args.pool.clone();
+        let exclusions =
+            self.exclusions.exclusion_filter(&args.client, args.config.parent_header.hash())?;
         default_ethereum_payload(self.evm_config.clone(), args, cfg_env, block_env, |attributes| {
-            pool.best_transactions_with_attributes(attributes)
+            let best_txs = pool.best_transactions_with_attributes(attributes);
+            let Some(exclusions) = exclusions else { return best_txs };
+            Box::new(best_txs.filter_transactions(move |tx| {
+                let excluded = exclusions.is_excluded(&tx.transaction);
+                if excluded {
+                    trace!(target: "payload_builder", tx=?tx.hash(), "skipping excluded transaction");
+                }
+                !excluded
+            }))
         })
     }
 
@@ -187,6 +216,8 @@ where
     let block_number = initialized_block_env.number.to::<u64>();
 
     let mut system_caller = SystemCaller::new(evm_config.clone(), chain_spec.clone());
+    let is_amsterdam = chain_spec.is_amsterdam_active_at_timestamp(attributes.timestamp);
+    system_caller.with_block_access_list(is_amsterdam.then(BlockAccessListBuilder::default));
 
     // apply eip-4788 pre block contract call
     system_caller
@@ -225,6 +256,94 @@ where
     let mut evm = evm_config.evm_with_env(&mut db, env);
 
     let mut receipts = Vec::new();
+
+    // include the bundles targeting this block first, each bundle is executed atomically: either
+    // all of its transactions are included in order or none of them
+    for bundle in pool.bundles(block_number, attributes.timestamp) {
+        // check if the job was cancelled, if so we can exit early
+        if cancel.is_cancelled() {
+            return Ok(BuildOutcome::Cancelled)
+        }
+
+        let bundle_gas_limit =
+            bundle.bundle.transactions.iter().map(|tx| tx.gas_limit()).sum::<u64>();
+        if cumulative_gas_used + bundle_gas_limit > block_gas_limit {
+            trace!(target: "payload_builder", bundle=%bundle.hash, ?bundle_gas_limit, "skipping bundle because it would exceed the block gas limit");
+            continue
+        }
+
+        // snapshot the state so that the bundle can be rolled back if one of its transactions fails
+        let checkpoint = StateCheckpoint::new(evm.db());
+
+        let mut bundle_gas_used = cumulative_gas_used;
+        let mut bundle_fees = U256::ZERO;
+        let mut bundle_txs = Vec::with_capacity(bundle.bundle.transactions.len());
+        let mut bundle_states = Vec::with_capacity(bundle.bundle.transactions.len());
+        let mut bundle_receipts = Vec::with_capacity(bundle.bundle.transactions.len());
+        let mut failed = false;
+        for pool_tx in &bundle.bundle.transactions {
+            let tx = pool_tx.to_consensus();
+            *evm.tx_mut() = evm_config.tx_env(tx.as_signed(), tx.signer());
+
+            let ResultAndState { result, state } = match evm.transact() {
+                Ok(res) => res,
+                Err(EVMError::Transaction(err)) => {
+                    trace!(target: "payload_builder", bundle=%bundle.hash, %err, ?tx, "skipping bundle with invalid transaction");
+                    failed = true;
+                    break
+                }
+                Err(err) => {
+                    // this is an error that we should treat as fatal for this attempt
+                    return Err(PayloadBuilderError::EvmExecutionError(err))
+                }
+            };
+
+            if !result.is_success() && !bundle.bundle.can_revert(pool_tx.hash()) {
+                trace!(target: "payload_builder", bundle=%bundle.hash, ?tx, "skipping bundle with reverted transaction");
+                failed = true;
+                break
+            }
+
+            bundle_states.push(state.clone());
+            evm.db_mut().commit(state);
+
+            let gas_used = result.gas_used();
+            bundle_gas_used += gas_used;
+
+            #[allow(clippy::needless_update)] // side-effect of optimism fields
+            bundle_receipts.push(Some(Receipt {
+                tx_type: tx.tx_type(),
+                success: result.is_success(),
+                cumulative_gas_used: bundle_gas_used,
+                logs: result.into_logs().into_iter().map(Into::into).collect(),
+                ..Default::default()
+            }));
+
+            let miner_fee = tx
+                .effective_tip_per_gas(Some(base_fee))
+                .expect("fee is always valid; execution succeeded");
+            bundle_fees += U256::from(miner_fee) * U256::from(gas_used);
+
+            bundle_txs.push(tx);
+        }
+
+        if failed {
+            // roll back the transactions of the bundle that were already executed
+            checkpoint.revert(evm.db_mut());
+            continue
+        }
+
+        for (tx, state) in bundle_txs.into_iter().zip(bundle_states) {
+            system_caller.set_block_access_index(executed_txs.len() as u64 + 1);
+            system_caller.on_state(&state);
+            executed_senders.push(tx.signer());
+            executed_txs.push(tx.into_signed());
+        }
+        receipts.extend(bundle_receipts);
+        cumulative_gas_used = bundle_gas_used;
+        total_fees += bundle_fees;
+    }
+
     while let Some(pool_tx) = best_txs.next() {
         // ensure we still have capacity for this transaction
         if cumulative_gas_used + pool_tx.gas_limit() > block_gas_limit {
@@ -301,6 +420,8 @@ where
         };
 
         // commit changes
+        system_caller.set_block_access_index(executed_txs.len() as u64 + 1);
+        system_caller.on_state(&state);
         evm.db_mut().commit(state);
 
         // add to the total blob gas used if the transaction successfully executed
@@ -349,6 +470,9 @@ where
         return Ok(BuildOutcome::Aborted { fees: total_fees, cached_reads })
     }
 
+    // the post-execution changes are recorded at the index after the last transaction
+    system_caller.set_block_access_index(executed_txs.len() as u64 + 1);
+
     // calculate the requests and the requests root
     let requests = if chain_spec.is_prague_active_at_timestamp(attributes.timestamp) {
         let deposit_requests = parse_deposits_from_receipts(&chain_spec, receipts.iter().flatten())
@@ -396,6 +520,22 @@ where
     let withdrawals_root =
         commit_withdrawals(&mut db, &chain_spec, attributes.timestamp, &attributes.withdrawals)?;
 
+    let block_access_list = if is_amsterdam {
+        // record the balance changes of the withdrawals
+        let balance_increments = post_block_withdrawals_balance_increments(
+            &chain_spec,
+            attributes.timestamp,
+            &attributes.withdrawals,
+        );
+        let balance_state = balance_increment_state(&balance_increments, &mut db)
+            .map_err(|err| PayloadBuilderError::Internal(err.into()))?;
+        system_caller.on_state(&balance_state);
+
+        system_caller.take_block_access_list().map(|builder| builder.build(&db))
+    } else {
+        None
+    };
+
     // merge all transitions into bundle state, this would apply the withdrawal balance changes
     // and 4788 contract call
     db.merge_transitions(BundleRetention::Reverts);
@@ -501,7 +641,8 @@ where
     };
 
     let mut payload =
-        EthBuiltPayload::new(attributes.id, sealed_block, total_fees, Some(executed), requests);
+        EthBuiltPayload::new(attributes.id, sealed_block, total_fees, Some(executed), requests)
+            .with_block_access_list(block_access_list);
 
     // extend the payload with the blob sidecars from the executed txs
     payload.extend_sidecars(blob_sidecars.into_iter().map(Arc::unwrap_or_clone));
@@ -0,0 +1,312 @@
+//! [EIP-7928](https://eips.ethereum.org/EIPS/eip-7928) block-level access lists.
+//!
+//! A [`BlockAccessList`] records every account and storage slot accessed while executing a block,
+//! together with the post-values of all changes, keyed by the index of the block access that made
+//! them: `0` for the pre-execution system calls, `1..=n` for the `n` transactions of the block and
+//! `n + 1` for the post-execution changes.
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
+use alloy_rlp::{Encodable, RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
+use revm::{
+    db::State,
+    primitives::{AccountInfo, EvmState},
+};
+
+/// The block access list of a block, ordered by address.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, RlpEncodableWrapper, RlpDecodableWrapper)]
+pub struct BlockAccessList(pub Vec<AccountChanges>);
+
+impl BlockAccessList {
+    /// Returns the accessed accounts and their changes.
+    pub fn accounts(&self) -> &[AccountChanges] {
+        &self.0
+    }
+
+    /// Returns the keccak256 hash of the RLP encoded access list, which the block commits to.
+    pub fn hash(&self) -> B256 {
+        let mut out = Vec::with_capacity(self.length());
+        self.encode(&mut out);
+        keccak256(out)
+    }
+}
+
+/// All accesses and changes of a single account in a block.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+pub struct AccountChanges {
+    /// The address of the account.
+    pub address: Address,
+    /// The changes of all written storage slots, ordered by slot.
+    pub storage_changes: Vec<SlotChanges>,
+    /// All storage slots that were read but never written, ordered by slot.
+    pub storage_reads: Vec<B256>,
+    /// The changes of the balance, ordered by block access index.
+    pub balance_changes: Vec<BalanceChange>,
+    /// The changes of the nonce, ordered by block access index.
+    pub nonce_changes: Vec<NonceChange>,
+    /// The changes of the code, ordered by block access index.
+    pub code_changes: Vec<CodeChange>,
+}
+
+/// The changes of a single storage slot.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+pub struct SlotChanges {
+    /// The storage slot.
+    pub slot: B256,
+    /// The changes of the slot, ordered by block access index.
+    pub changes: Vec<StorageChange>,
+}
+
+/// The value of a storage slot after the block access with the given index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+pub struct StorageChange {
+    /// The block access index.
+    pub index: u64,
+    /// The new value of the slot.
+    pub value: U256,
+}
+
+/// The balance of an account after the block access with the given index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+pub struct BalanceChange {
+    /// The block access index.
+    pub index: u64,
+    /// The new balance.
+    pub balance: U256,
+}
+
+/// The nonce of an account after the block access with the given index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+pub struct NonceChange {
+    /// The block access index.
+    pub index: u64,
+    /// The new nonce.
+    pub nonce: u64,
+}
+
+/// The code of an account after the block access with the given index.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+pub struct CodeChange {
+    /// The block access index.
+    pub index: u64,
+    /// The new code.
+    pub code: Bytes,
+}
+
+/// Collects the state accessed while executing a block to build its [`BlockAccessList`].
+///
+/// The state of every block access is recorded with [`Self::record`] after setting the index of
+/// the access with [`Self::set_index`]. Changes are determined by comparing the recorded accounts
+/// with their previous values, which requires the [`State`] the block was executed with, see
+/// [`Self::build`].
+#[derive(Debug, Default)]
+pub struct BlockAccessListBuilder {
+    /// The index of the current block access.
+    index: u64,
+    /// The accesses of every account.
+    accounts: BTreeMap<Address, AccountAccesses>,
+}
+
+/// The recorded accesses of a single account.
+#[derive(Debug, Default)]
+struct AccountAccesses {
+    /// The account after every block access that touched it, `None` if it was destroyed.
+    infos: Vec<(u64, Option<AccountInfo>)>,
+    /// The values of every written slot after each block access that changed it.
+    storage_writes: BTreeMap<B256, Vec<(u64, U256)>>,
+    /// All slots that were read.
+    storage_reads: BTreeSet<B256>,
+}
+
+impl BlockAccessListBuilder {
+    /// Sets the index of the current block access.
+    pub fn set_index(&mut self, index: u64) {
+        self.index = index;
+    }
+
+    /// Records the state accessed by the current block access.
+    ///
+    /// This must be called with the state of every access before it is committed.
+    pub fn record(&mut self, state: &EvmState) {
+        let index = self.index;
+        for (address, account) in state {
+            let accesses = self.accounts.entry(*address).or_default();
+
+            if account.is_touched() {
+                let info = (!account.is_selfdestructed()).then(|| account.info.clone());
+                match accesses.infos.last_mut() {
+                    Some((last, last_info)) if *last == index => *last_info = info,
+                    _ => accesses.infos.push((index, info)),
+                }
+            }
+
+            for (slot, value) in &account.storage {
+                let slot = B256::from(*slot);
+                if value.is_changed() {
+                    let writes = accesses.storage_writes.entry(slot).or_default();
+                    match writes.last_mut() {
+                        Some((last, last_value)) if *last == index => {
+                            *last_value = value.present_value
+                        }
+                        _ => writes.push((index, value.present_value)),
+                    }
+                } else {
+                    accesses.storage_reads.insert(slot);
+                }
+            }
+        }
+    }
+
+    /// Builds the access list from the recorded accesses.
+    ///
+    /// The given state must be the state the block was executed with, before its transitions are
+    /// merged, so that the values of accounts before the block are known.
+    pub fn build<DB>(self, state: &State<DB>) -> BlockAccessList {
+        let accounts = self
+            .accounts
+            .into_iter()
+            .map(|(address, accesses)| {
+                let mut changes = AccountChanges { address, ..Default::default() };
+
+                let mut previous = pre_block_account_info(state, &address).unwrap_or_default();
+                for (index, info) in accesses.infos {
+                    let info = info.unwrap_or_default();
+                    if info.balance != previous.balance {
+                        changes
+                            .balance_changes
+                            .push(BalanceChange { index, balance: info.balance });
+                    }
+                    if info.nonce != previous.nonce {
+                        changes.nonce_changes.push(NonceChange { index, nonce: info.nonce });
+                    }
+                    if info.code_hash != previous.code_hash {
+                        let code = info
+                            .code
+                            .clone()
+                            .or_else(|| state.cache.contracts.get(&info.code_hash).cloned())
+                            .map(|code| code.original_bytes())
+                            .unwrap_or_default();
+                        changes.code_changes.push(CodeChange { index, code });
+                    }
+                    previous = info;
+                }
+
+                changes.storage_reads = accesses
+                    .storage_reads
+                    .into_iter()
+                    .filter(|slot| !accesses.storage_writes.contains_key(slot))
+                    .collect();
+                changes.storage_changes = accesses
+                    .storage_writes
+                    .into_iter()
+                    .map(|(slot, writes)| SlotChanges {
+                        slot,
+                        changes: writes
+                            .into_iter()
+                            .map(|(index, value)| StorageChange { index, value })
+                            .collect(),
+                    })
+                    .collect();
+
+                changes
+            })
+            .collect();
+
+        BlockAccessList(accounts)
+    }
+}
+
+/// Returns the account before the block from a [`State`] whose transitions have not been merged.
+fn pre_block_account_info<DB>(state: &State<DB>, address: &Address) -> Option<AccountInfo> {
+    if let Some(transition) =
+        state.transition_state.as_ref().and_then(|transitions| transitions.transitions.get(address))
+    {
+        return transition.previous_info.clone()
+    }
+    state.cache.accounts.get(address).and_then(|account| account.account_info())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rlp::Decodable;
+    use revm::{
+        db::EmptyDB,
+        primitives::{Account, AccountStatus, EvmStorageSlot},
+        DatabaseCommit,
+    };
+
+    fn account(balance: u64, storage: impl IntoIterator<Item = (u64, u64, u64)>) -> Account {
+        Account {
+            info: AccountInfo { balance: U256::from(balance), ..Default::default() },
+            storage: storage
+                .into_iter()
+                .map(|(slot, original, present)| {
+                    (
+                        U256::from(slot),
+                        EvmStorageSlot::new_changed(U256::from(original), U256::from(present)),
+                    )
+                })
+                .collect(),
+            status: AccountStatus::Touched,
+        }
+    }
+
+    #[test]
+    fn build_access_list() {
+        let mut state =
+            State::builder().with_database(EmptyDB::default()).with_bundle_update().build();
+        let mut builder = BlockAccessListBuilder::default();
+        let (alice, bob) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        state.load_cache_account(alice).unwrap();
+        state.load_cache_account(bob).unwrap();
+
+        // the first transaction funds alice and writes a slot
+        builder.set_index(1);
+        let changes = EvmState::from_iter([(alice, account(10, [(1, 0, 5)]))]);
+        builder.record(&changes);
+        state.commit(changes);
+
+        // the second transaction only reads a slot of alice and bob's empty account
+        builder.set_index(2);
+        let mut read = account(10, [(2, 0, 0)]);
+        read.status = AccountStatus::Loaded;
+        let changes = EvmState::from_iter([(alice, read), (bob, Account::default())]);
+        builder.record(&changes);
+        state.commit(changes);
+
+        // the post-execution changes only change the slot
+        builder.set_index(3);
+        let changes = EvmState::from_iter([(alice, account(10, [(1, 5, 6)]))]);
+        builder.record(&changes);
+        state.commit(changes);
+
+        let access_list = builder.build(&state);
+        assert_eq!(
+            access_list,
+            BlockAccessList(vec![
+                AccountChanges {
+                    address: alice,
+                    storage_changes: vec![SlotChanges {
+                        slot: B256::with_last_byte(1),
+                        changes: vec![
+                            StorageChange { index: 1, value: U256::from(5) },
+                            StorageChange { index: 3, value: U256::from(6) },
+                        ],
+                    }],
+                    storage_reads: vec![B256::with_last_byte(2)],
+                    balance_changes: vec![BalanceChange { index: 1, balance: U256::from(10) }],
+                    ..Default::default()
+                },
+                AccountChanges { address: bob, ..Default::default() },
+            ])
+        );
+
+        let encoded = alloy_rlp::encode(&access_list);
+        assert_eq!(BlockAccessList::decode(&mut encoded.as_slice()).unwrap(), access_list);
+        assert_eq!(access_list.hash(), keccak256(encoded));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::ExecutionOutcome;
+use alloc::vec::Vec;
+use alloy_primitives::{map::HashMap, Address, BlockNumber, B256};
+use revm::{
+    db::states::reverts::AccountInfoRevert,
+    primitives::{AccountInfo, Bytecode, KECCAK_EMPTY},
+};
+
+/// A change of the code of an account, see [`CodeChangeKind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CodeChange {
+    /// The block in which the code was changed.
+    pub block_number: BlockNumber,
+    /// The address of the account.
+    pub address: Address,
+    /// The kind of the code change.
+    pub kind: CodeChangeKind,
+}
+
+/// The kind of a [`CodeChange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CodeChangeKind {
+    /// A contract was deployed to the account.
+    Created {
+        /// The hash of the deployed code.
+        code_hash: B256,
+    },
+    /// The contract was removed from the account with `SELFDESTRUCT`.
+    SelfDestructed {
+        /// The hash of the removed code.
+        code_hash: B256,
+    },
+    /// The account was delegated to another address with an
+    /// [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702) authorization.
+    DelegationSet {
+        /// The address the account delegates to.
+        delegate: Address,
+    },
+    /// The [EIP-7702](https://eips.ethereum.org/EIPS/eip-7702) delegation of the account was
+    /// cleared.
+    DelegationCleared,
+}
+
+impl<T> ExecutionOutcome<T> {
+    /// Returns all code changes of the `ExecutionOutcome`, ordered by block number and address.
+    ///
+    /// Code changes are derived from the bundle reverts, so the bundle needs to retain the reverts
+    /// of every block to report their code changes.
+    pub fn code_changes(&self) -> Vec<CodeChange> {
+        // Walk the reverts backwards, starting from the final state of each account, so that the
+        // account info before and after every block is known.
+        let mut current = self
+            .bundle
+            .state
+            .iter()
+            .map(|(address, account)| (*address, account.info.clone()))
+            .collect::<HashMap<_, _>>();
+
+        let mut changes = Vec::new();
+        for (index, block_reverts) in self.bundle.reverts.iter().enumerate().rev() {
+            let block_number = self.first_block + index as u64;
+            for (address, revert) in block_reverts {
+                let after = current.get(address).cloned().flatten();
+                let before = match &revert.account {
+                    AccountInfoRevert::DoNothing => after.clone(),
+                    AccountInfoRevert::DeleteIt => None,
+                    AccountInfoRevert::RevertTo(info) => Some(info.clone()),
+                };
+
+                self.push_code_changes(
+                    &mut changes,
+                    block_number,
+                    *address,
+                    before.as_ref(),
+                    after.as_ref(),
+                    revert.wipe_storage,
+                );
+
+                current.insert(*address, before);
+            }
+        }
+
+        changes.sort_unstable();
+        changes
+    }
+
+    /// Compares the code of the account before and after the block and records the code changes.
+    fn push_code_changes(
+        &self,
+        changes: &mut Vec<CodeChange>,
+        block_number: BlockNumber,
+        address: Address,
+        before: Option<&AccountInfo>,
+        after: Option<&AccountInfo>,
+        destroyed: bool,
+    ) {
+        let code_hash = |info: Option<&AccountInfo>| {
+            info.map(|info| info.code_hash).filter(|code_hash| *code_hash != KECCAK_EMPTY)
+        };
+
+        let mut previous_code_hash = code_hash(before);
+        if destroyed {
+            if let Some(code_hash) = previous_code_hash.take() {
+                changes.push(CodeChange {
+                    block_number,
+                    address,
+                    kind: CodeChangeKind::SelfDestructed { code_hash },
+                });
+            }
+        }
+
+        let new_code_hash = code_hash(after);
+        if previous_code_hash == new_code_hash {
+            return
+        }
+
+        let kind = match new_code_hash {
+            Some(code_hash) => {
+                let code = after
+                    .and_then(|info| info.code.clone())
+                    .or_else(|| self.bundle.contracts.get(&code_hash).cloned());
+                match code {
+                    Some(Bytecode::Eip7702(code)) => {
+                        CodeChangeKind::DelegationSet { delegate: code.address() }
+                    }
+                    _ => CodeChangeKind::Created { code_hash },
+                }
+            }
+            // Since Cancun, the code of an account can only be removed without destroying the
+            // account by clearing its delegation.
+            None => CodeChangeKind::DelegationCleared,
+        };
+        changes.push(CodeChange { block_number, address, kind });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{bytes, U256};
+    use revm::{
+        db::{
+            states::reverts::{AccountRevert, Reverts},
+            AccountStatus, BundleAccount, BundleState,
+        },
+        primitives::Eip7702Bytecode,
+    };
+
+    fn account(code: Option<Bytecode>) -> AccountInfo {
+        let mut info = AccountInfo::from_balance(U256::from(1));
+        if let Some(code) = code {
+            info.code_hash = code.hash_slow();
+            info.code = Some(code);
+        }
+        info
+    }
+
+    fn bundle_account(info: Option<AccountInfo>) -> BundleAccount {
+        BundleAccount {
+            info,
+            original_info: None,
+            storage: Default::default(),
+            status: AccountStatus::Changed,
+        }
+    }
+
+    fn revert(account: AccountInfoRevert, wipe_storage: bool) -> AccountRevert {
+        AccountRevert {
+            account,
+            storage: Default::default(),
+            previous_status: AccountStatus::Loaded,
+            wipe_storage,
+        }
+    }
+
+    #[test]
+    fn code_changes() {
+        let created = Address::with_last_byte(1);
+        let destroyed = Address::with_last_byte(2);
+        let delegated = Address::with_last_byte(3);
+        let delegate = Address::with_last_byte(4);
+        let untouched = Address::with_last_byte(5);
+
+        let contract = Bytecode::new_raw(bytes!("6080604052"));
+        let delegation = Bytecode::Eip7702(Eip7702Bytecode::new(delegate));
+
+        let bundle = BundleState {
+            state: [
+                (created, bundle_account(Some(account(Some(contract.clone()))))),
+                (destroyed, bundle_account(None)),
+                (delegated, bundle_account(Some(account(None)))),
+                (untouched, bundle_account(Some(account(Some(contract.clone()))))),
+            ]
+            .into_iter()
+            .collect(),
+            reverts: Reverts::new(vec![
+                // Block 10: the contract is created and the account delegates.
+                vec![
+                    (created, revert(AccountInfoRevert::DeleteIt, false)),
+                    (delegated, revert(AccountInfoRevert::RevertTo(account(None)), false)),
+                    (
+                        untouched,
+                        revert(AccountInfoRevert::RevertTo(account(Some(contract.clone()))), false),
+                    ),
+                ],
+                // Block 11: the contract is destroyed and the delegation is cleared.
+                vec![
+                    (
+                        destroyed,
+                        revert(AccountInfoRevert::RevertTo(account(Some(contract.clone()))), true),
+                    ),
+                    (
+                        delegated,
+                        revert(AccountInfoRevert::RevertTo(account(Some(delegation))), false),
+                    ),
+                ],
+            ]),
+            ..Default::default()
+        };
+        let outcome = ExecutionOutcome::<()> { bundle, first_block: 10, ..Default::default() };
+
+        assert_eq!(
+            outcome.code_changes(),
+            vec![
+                CodeChange {
+                    block_number: 10,
+                    address: created,
+                    kind: CodeChangeKind::Created { code_hash: contract.hash_slow() },
+                },
+                CodeChange {
+                    block_number: 10,
+                    address: delegated,
+                    kind: CodeChangeKind::DelegationSet { delegate },
+                },
+                CodeChange {
+                    block_number: 11,
+                    address: destroyed,
+                    kind: CodeChangeKind::SelfDestructed { code_hash: contract.hash_slow() },
+                },
+                CodeChange {
+                    block_number: 11,
+                    address: delegated,
+                    kind: CodeChangeKind::DelegationCleared,
+                },
+            ]
+        );
+    }
+}
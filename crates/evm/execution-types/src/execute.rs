@@ -1,3 +1,4 @@
+use crate::BlockAccessList;
 use alloy_eips::eip7685::Requests;
 use alloy_primitives::U256;
 use revm::db::BundleState;
@@ -37,4 +38,6 @@ pub struct BlockExecutionOutput<T> {
     pub requests: Requests,
     /// The total gas used by the block.
     pub gas_used: u64,
+    /// The EIP-7928 block access list, if it was generated during execution.
+    pub block_access_list: Option<BlockAccessList>,
 }
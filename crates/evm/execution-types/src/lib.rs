@@ -19,6 +19,12 @@ pub use execute::*;
 mod execution_outcome;
 pub use execution_outcome::*;
 
+mod code_changes;
+pub use code_changes::*;
+
+pub mod block_access_list;
+pub use block_access_list::{BlockAccessList, BlockAccessListBuilder};
+
 /// Bincode-compatible serde implementations for commonly used types for (EVM) block execution.
 ///
 /// `bincode` crate doesn't work with optionally serializable serde fields, but some of the
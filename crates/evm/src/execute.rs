@@ -5,7 +5,10 @@ use alloy_consensus::BlockHeader;
 pub use reth_execution_errors::{
     BlockExecutionError, BlockValidationError, InternalBlockExecutionError,
 };
-pub use reth_execution_types::{BlockExecutionInput, BlockExecutionOutput, ExecutionOutcome};
+pub use reth_execution_types::{
+    BlockAccessList, BlockAccessListBuilder, BlockExecutionInput, BlockExecutionOutput,
+    ExecutionOutcome,
+};
 use reth_primitives_traits::Block as _;
 pub use reth_storage_errors::provider::ProviderError;
 
@@ -240,6 +243,14 @@ pub trait BlockExecutionStrategy {
     /// Sets a hook to be called after each state change during execution.
     fn with_state_hook(&mut self, _hook: Option<Box<dyn OnStateHook>>) {}
 
+    /// Returns the EIP-7928 block access list of the executed block, if one was generated.
+    ///
+    /// This is called after the post execution changes were applied and before
+    /// [`Self::finish`].
+    fn take_block_access_list(&mut self) -> Option<BlockAccessList> {
+        None
+    }
+
     /// Returns the final bundle state.
     fn finish(&mut self) -> BundleState {
         self.state_mut().merge_transitions(BundleRetention::Reverts);
@@ -364,9 +375,10 @@ where
             self.strategy.execute_transactions(block, total_difficulty)?;
         let requests =
             self.strategy.apply_post_execution_changes(block, total_difficulty, &receipts)?;
+        let block_access_list = self.strategy.take_block_access_list();
         let state = self.strategy.finish();
 
-        Ok(BlockExecutionOutput { state, receipts, requests, gas_used })
+        Ok(BlockExecutionOutput { state, receipts, requests, gas_used, block_access_list })
     }
 
     fn execute_with_state_closure<F>(
@@ -384,12 +396,13 @@ where
             self.strategy.execute_transactions(block, total_difficulty)?;
         let requests =
             self.strategy.apply_post_execution_changes(block, total_difficulty, &receipts)?;
+        let block_access_list = self.strategy.take_block_access_list();
 
         state(self.strategy.state_ref());
 
         let state = self.strategy.finish();
 
-        Ok(BlockExecutionOutput { state, receipts, requests, gas_used })
+        Ok(BlockExecutionOutput { state, receipts, requests, gas_used, block_access_list })
     }
 
     fn execute_with_state_hook<H>(
@@ -409,10 +422,11 @@ where
             self.strategy.execute_transactions(block, total_difficulty)?;
         let requests =
             self.strategy.apply_post_execution_changes(block, total_difficulty, &receipts)?;
+        let block_access_list = self.strategy.take_block_access_list();
 
         let state = self.strategy.finish();
 
-        Ok(BlockExecutionOutput { state, receipts, requests, gas_used })
+        Ok(BlockExecutionOutput { state, receipts, requests, gas_used, block_access_list })
     }
 }
 
@@ -177,6 +177,7 @@ mod tests {
                 receipts: vec![],
                 requests: Requests::default(),
                 gas_used: 0,
+                block_access_list: None,
             })
         }
         fn execute_with_state_closure<F>(
@@ -192,6 +193,7 @@ mod tests {
                 receipts: vec![],
                 requests: Requests::default(),
                 gas_used: 0,
+                block_access_list: None,
             })
         }
         fn execute_with_state_hook<F>(
@@ -210,6 +212,7 @@ mod tests {
                 receipts: vec![],
                 requests: Requests::default(),
                 gas_used: 0,
+                block_access_list: None,
             })
         }
     }
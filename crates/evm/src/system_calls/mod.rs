@@ -10,6 +10,7 @@ use alloy_primitives::Bytes;
 use core::fmt::Display;
 use reth_chainspec::EthereumHardforks;
 use reth_execution_errors::BlockExecutionError;
+use reth_execution_types::BlockAccessListBuilder;
 use revm::{Database, DatabaseCommit, Evm};
 use revm_primitives::{BlockEnv, CfgEnvWithHandlerCfg, EnvWithHandlerCfg, EvmState, B256};
 
@@ -51,13 +52,15 @@ pub struct SystemCaller<EvmConfig, Chainspec> {
     chain_spec: Arc<Chainspec>,
     /// Optional hook to be called after each state change.
     hook: Option<Box<dyn OnStateHook>>,
+    /// Optional builder that records the state changes for the EIP-7928 block access list.
+    block_access_list: Option<BlockAccessListBuilder>,
 }
 
 impl<EvmConfig, Chainspec> SystemCaller<EvmConfig, Chainspec> {
     /// Create a new system caller with the given EVM config, database, and chain spec, and creates
     /// the EVM with the given initialized config and block environment.
     pub const fn new(evm_config: EvmConfig, chain_spec: Arc<Chainspec>) -> Self {
-        Self { evm_config, chain_spec, hook: None }
+        Self { evm_config, chain_spec, hook: None, block_access_list: None }
     }
 
     /// Installs a custom hook to be called after each state change.
@@ -66,6 +69,28 @@ impl<EvmConfig, Chainspec> SystemCaller<EvmConfig, Chainspec> {
         self
     }
 
+    /// Installs a builder that records all state changes for the EIP-7928 block access list.
+    pub fn with_block_access_list(
+        &mut self,
+        block_access_list: Option<BlockAccessListBuilder>,
+    ) -> &mut Self {
+        self.block_access_list = block_access_list;
+        self
+    }
+
+    /// Sets the block access index of the following state changes, if a block access list is
+    /// recorded.
+    pub fn set_block_access_index(&mut self, index: u64) {
+        if let Some(block_access_list) = &mut self.block_access_list {
+            block_access_list.set_index(index);
+        }
+    }
+
+    /// Takes the installed block access list builder.
+    pub fn take_block_access_list(&mut self) -> Option<BlockAccessListBuilder> {
+        self.block_access_list.take()
+    }
+
     /// Convenience method to consume the type and drop borrowed fields
     pub fn finish(self) {}
 }
@@ -197,9 +222,7 @@ where
         )?;
 
         if let Some(res) = result_and_state {
-            if let Some(ref mut hook) = self.hook {
-                hook.on_state(&res.state);
-            }
+            self.on_state(&res.state);
             evm.context.evm.db.commit(res.state);
         }
 
@@ -252,9 +275,7 @@ where
         )?;
 
         if let Some(res) = result_and_state {
-            if let Some(ref mut hook) = self.hook {
-                hook.on_state(&res.state);
-            }
+            self.on_state(&res.state);
             evm.context.evm.db.commit(res.state);
         }
 
@@ -291,9 +312,7 @@ where
         let result_and_state =
             eip7002::transact_withdrawal_requests_contract_call(&self.evm_config.clone(), evm)?;
 
-        if let Some(ref mut hook) = self.hook {
-            hook.on_state(&result_and_state.state);
-        }
+        self.on_state(&result_and_state.state);
         evm.context.evm.db.commit(result_and_state.state);
 
         eip7002::post_commit(result_and_state.result)
@@ -329,18 +348,21 @@ where
         let result_and_state =
             eip7251::transact_consolidation_requests_contract_call(&self.evm_config.clone(), evm)?;
 
-        if let Some(ref mut hook) = self.hook {
-            hook.on_state(&result_and_state.state);
-        }
+        self.on_state(&result_and_state.state);
         evm.context.evm.db.commit(result_and_state.state);
 
         eip7251::post_commit(result_and_state.result)
     }
 
     /// Delegate to stored `OnStateHook`, noop if hook is `None`.
+    ///
+    /// The state is also recorded for the block access list, if one is recorded.
     pub fn on_state(&mut self, state: &EvmState) {
         if let Some(ref mut hook) = &mut self.hook {
             hook.on_state(state);
         }
+        if let Some(block_access_list) = &mut self.block_access_list {
+            block_access_list.record(state);
+        }
     }
 }
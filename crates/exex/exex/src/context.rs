@@ -1,4 +1,5 @@
 use crate::{ExExContextDyn, ExExEvent, ExExNotifications, ExExNotificationsStream};
+use alloy_primitives::BlockNumber;
 use reth_exex_types::ExExHead;
 use reth_node_api::{FullNodeComponents, NodePrimitives, NodeTypes};
 use reth_node_core::node_config::NodeConfig;
@@ -118,6 +119,12 @@ where
     pub fn set_notifications_with_head(&mut self, head: ExExHead) {
         self.notifications.set_with_head(head);
     }
+
+    /// Sets notifications stream to [`crate::ExExNotificationsFromBlock`], a stream of
+    /// notifications starting from the provided block number, inclusive.
+    pub fn set_notifications_from_block(&mut self, block_number: BlockNumber) {
+        self.notifications.set_subscribe_from(block_number);
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use crate::{
-    wal::Wal, ExExEvent, ExExNotification, ExExNotifications, FinishedExExHeight, WalHandle,
+    wal::Wal, ExExEvent, ExExManagerStatus, ExExNotification, ExExNotifications, ExExStatus,
+    FinishedExExHeight, SharedExExStatus, WalHandle,
 };
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockNumHash;
@@ -83,6 +84,8 @@ pub struct ExExHandle<N: NodePrimitives = EthPrimitives> {
     receiver: UnboundedReceiver<ExExEvent>,
     /// The ID of the next notification to send to this `ExEx`.
     next_notification_id: usize,
+    /// The head of the last notification that was sent to this `ExEx`.
+    head: Option<BlockNumHash>,
     /// The finished block of the `ExEx`.
     ///
     /// If this is `None`, the `ExEx` has not emitted a `FinishedHeight` event.
@@ -113,6 +116,7 @@ impl<N: NodePrimitives> ExExHandle<N> {
                 sender: PollSender::new(notification_tx),
                 receiver: event_rx,
                 next_notification_id: 0,
+                head: None,
                 finished_height: None,
             },
             event_tx,
@@ -146,6 +150,7 @@ impl<N: NodePrimitives> ExExHandle<N> {
                         );
 
                         self.next_notification_id = notification_id + 1;
+                        self.head = Some(new.tip().num_hash());
                         return Poll::Ready(Ok(()))
                     }
                 }
@@ -177,6 +182,7 @@ impl<N: NodePrimitives> ExExHandle<N> {
         match self.sender.send_item(notification.clone()) {
             Ok(()) => {
                 self.next_notification_id = notification_id + 1;
+                self.head = notification_head(notification).or(self.head);
                 self.metrics.notifications_sent_total.increment(1);
                 Poll::Ready(Ok(()))
             }
@@ -185,6 +191,21 @@ impl<N: NodePrimitives> ExExHandle<N> {
     }
 }
 
+/// Returns the head of the chain after the given notification, i.e. the tip of the committed
+/// chain, or the fork block of the reverted chain if nothing was committed.
+///
+/// Returns `None` if the chains of the notification are empty.
+fn notification_head<N: NodePrimitives>(
+    notification: &ExExNotification<N>,
+) -> Option<BlockNumHash> {
+    match notification.committed_chain() {
+        Some(chain) => chain.blocks().values().next_back().map(|block| block.num_hash()),
+        None => notification
+            .reverted_chain()
+            .and_then(|chain| (!chain.is_empty()).then(|| chain.fork_block())),
+    }
+}
+
 /// Metrics for the `ExEx` manager.
 #[derive(Metrics)]
 #[metrics(scope = "exex.manager")]
@@ -242,6 +263,10 @@ pub struct ExExManager<P, N: NodePrimitives> {
 
     /// The finished height of all `ExEx`'s.
     finished_height: watch::Sender<FinishedExExHeight>,
+    /// The head of the last notification that was received from the [`ExExManagerHandle`]s.
+    head: Option<BlockNumHash>,
+    /// The status of the manager and all `ExEx`'s, updated on every poll.
+    status: SharedExExStatus,
 
     /// Write-Ahead Log for the [`ExExNotification`]s.
     wal: Wal<N>,
@@ -283,6 +308,7 @@ where
         });
 
         let current_capacity = Arc::new(AtomicUsize::new(max_capacity));
+        let status = SharedExExStatus::default();
 
         let metrics = ExExManagerMetrics::default();
         metrics.max_capacity.set(max_capacity as f64);
@@ -303,6 +329,8 @@ where
 
             is_ready: is_ready_tx,
             finished_height: finished_height_tx,
+            head: None,
+            status: status.clone(),
 
             wal,
             finalized_header_stream,
@@ -314,6 +342,7 @@ where
                 is_ready: ReusableBoxFuture::new(make_wait_future(is_ready_rx)),
                 current_capacity,
                 finished_height: finished_height_rx,
+                status,
             },
             metrics,
         }
@@ -337,9 +366,33 @@ where
         let _ = self.is_ready.send(capacity > 0);
     }
 
+    /// Updates the shared status of the manager and all `ExEx`'s.
+    fn update_status(&self) {
+        let exexes = self
+            .exex_handles
+            .iter()
+            .map(|exex| ExExStatus {
+                id: exex.id.clone(),
+                head: exex.head,
+                finished_height: exex.finished_height,
+                pending_notifications: self.next_id.saturating_sub(exex.next_notification_id),
+            })
+            .collect();
+
+        self.status.set(ExExManagerStatus {
+            exexes,
+            head: self.head,
+            buffered_notifications: self.buffer.len(),
+            wal_blocks: self.wal.num_blocks(),
+            wal_size_bytes: self.wal.size_bytes(),
+            finished_height: *self.finished_height.borrow(),
+        });
+    }
+
     /// Pushes a new notification into the managers internal buffer, assigning the notification a
     /// unique ID.
     fn push_notification(&mut self, notification: ExExNotification<N>) {
+        self.head = notification_head(&notification).or(self.head);
         let next_id = self.next_id;
         self.buffer.push_back((next_id, notification));
         self.next_id += 1;
@@ -527,6 +580,8 @@ where
             let _ = this.finished_height.send(FinishedExExHeight::Height(finished_height));
         }
 
+        this.update_status();
+
         Poll::Pending
     }
 }
@@ -551,6 +606,8 @@ pub struct ExExManagerHandle<N: NodePrimitives = EthPrimitives> {
     current_capacity: Arc<AtomicUsize>,
     /// The finished height of all `ExEx`'s.
     finished_height: watch::Receiver<FinishedExExHeight>,
+    /// The status of the manager and all `ExEx`'s.
+    status: SharedExExStatus,
 }
 
 impl<N: NodePrimitives> ExExManagerHandle<N> {
@@ -571,6 +628,7 @@ impl<N: NodePrimitives> ExExManagerHandle<N> {
             is_ready: ReusableBoxFuture::new(make_wait_future(is_ready_rx)),
             current_capacity: Arc::new(AtomicUsize::new(0)),
             finished_height: finished_height_rx,
+            status: SharedExExStatus::default(),
         }
     }
 
@@ -621,6 +679,11 @@ impl<N: NodePrimitives> ExExManagerHandle<N> {
         self.finished_height.clone()
     }
 
+    /// The status of the manager and all `ExEx`'s, e.g. for monitoring.
+    pub fn status(&self) -> SharedExExStatus {
+        self.status.clone()
+    }
+
     /// Wait until the manager is ready for new notifications.
     pub async fn ready(&mut self) {
         poll_fn(|cx| self.poll_ready(cx)).await
@@ -651,6 +714,7 @@ impl<N: NodePrimitives> Clone for ExExManagerHandle<N> {
             is_ready: ReusableBoxFuture::new(make_wait_future(self.is_ready_receiver.clone())),
             current_capacity: self.current_capacity.clone(),
             finished_height: self.finished_height.clone(),
+            status: self.status.clone(),
         }
     }
 }
@@ -904,6 +968,15 @@ mod tests {
 
         // The finished height should be updated to the lower block height
         assert_eq!(finished_height, FinishedExExHeight::Height(42));
+
+        // Check that the status was updated
+        let status = pinned_manager.handle.status().get();
+        assert_eq!(status.exexes.len(), 1);
+        assert_eq!(status.exexes[0].id, "test_exex");
+        assert_eq!(status.exexes[0].finished_height, Some(block));
+        assert_eq!(status.exexes[0].pending_notifications, 0);
+        assert_eq!(status.finished_height, FinishedExExHeight::Height(42));
+        assert_eq!(status.wal_blocks, 0);
     }
 
     #[tokio::test]
@@ -1240,6 +1313,8 @@ mod tests {
 
         // Ensure the notification ID was incremented
         assert_eq!(exex_handle.next_notification_id, 23);
+        // The chains are empty, so the head is unknown
+        assert_eq!(exex_handle.head, None);
     }
 
     #[tokio::test]
@@ -1,5 +1,6 @@
 use crate::{BackfillJobFactory, ExExNotification, StreamBackfillJob, WalHandle};
 use alloy_consensus::BlockHeader;
+use alloy_primitives::BlockNumber;
 use futures::{Stream, StreamExt};
 use reth_chainspec::Head;
 use reth_evm::execute::BlockExecutorProvider;
@@ -9,7 +10,9 @@ use reth_primitives::EthPrimitives;
 use reth_provider::{BlockReader, Chain, HeaderProvider, StateProviderFactory};
 use reth_tracing::tracing::debug;
 use std::{
+    collections::VecDeque,
     fmt::Debug,
+    ops::RangeInclusive,
     pin::Pin,
     sync::Arc,
     task::{ready, Context, Poll},
@@ -18,7 +21,10 @@ use tokio::sync::mpsc::Receiver;
 
 /// A stream of [`ExExNotification`]s. The stream will emit notifications for all blocks. If the
 /// stream is configured with a head via [`ExExNotifications::set_with_head`] or
-/// [`ExExNotifications::with_head`], it will run backfill jobs to catch up to the node head.
+/// [`ExExNotifications::with_head`], it will run backfill jobs to catch up to the node head. If
+/// the stream is configured with a start block via [`ExExNotifications::set_subscribe_from`] or
+/// [`ExExNotifications::subscribe_from`], it will replay the WAL and run backfill jobs to catch up
+/// from that block to the node head.
 #[derive(Debug)]
 pub struct ExExNotifications<P, E>
 where
@@ -61,6 +67,20 @@ pub trait ExExNotificationsStream<N: NodePrimitives = EthPrimitives>:
     fn with_head(self, exex_head: ExExHead) -> Self
     where
         Self: Sized;
+
+    /// Sets [`ExExNotificationsStream`] to a stream of [`ExExNotification`]s starting from the
+    /// provided block number, inclusive.
+    ///
+    /// See the documentation of [`ExExNotificationsFromBlock`] for more details.
+    fn set_subscribe_from(&mut self, block_number: BlockNumber);
+
+    /// Returns a new [`ExExNotificationsStream`] starting from the provided block number,
+    /// inclusive.
+    ///
+    /// See the documentation of [`ExExNotificationsFromBlock`] for more details.
+    fn subscribe_from(self, block_number: BlockNumber) -> Self
+    where
+        Self: Sized;
 }
 
 #[derive(Debug)]
@@ -73,8 +93,11 @@ where
     /// A stream of [`ExExNotification`]s. The stream will only emit notifications for blocks that
     /// are committed or reverted after the given head.
     WithHead(ExExNotificationsWithHead<P, E>),
-    /// Internal state used when transitioning between [`ExExNotificationsInner::WithoutHead`] and
-    /// [`ExExNotificationsInner::WithHead`].
+    /// A stream of [`ExExNotification`]s. The stream will emit notifications for all canonical
+    /// blocks starting from the given block number, and then all new notifications.
+    FromBlock(ExExNotificationsFromBlock<P, E>),
+    /// Internal state used when transitioning between [`ExExNotificationsInner::WithoutHead`],
+    /// [`ExExNotificationsInner::WithHead`] and [`ExExNotificationsInner::FromBlock`].
     Invalid,
 }
 
@@ -121,6 +144,13 @@ where
                 notifications.notifications,
                 notifications.wal_handle,
             ),
+            ExExNotificationsInner::FromBlock(notifications) => ExExNotificationsWithoutHead::new(
+                notifications.node_head,
+                notifications.provider,
+                notifications.executor,
+                notifications.notifications,
+                notifications.wal_handle,
+            ),
             ExExNotificationsInner::Invalid => unreachable!(),
         });
     }
@@ -139,6 +169,40 @@ where
                 notifications.wal_handle,
                 exex_head,
             ),
+            ExExNotificationsInner::FromBlock(notifications) => ExExNotificationsWithHead::new(
+                notifications.node_head,
+                notifications.provider,
+                notifications.executor,
+                notifications.notifications,
+                notifications.wal_handle,
+                exex_head,
+            ),
+            ExExNotificationsInner::Invalid => unreachable!(),
+        });
+    }
+
+    fn set_subscribe_from(&mut self, block_number: BlockNumber) {
+        let current = std::mem::replace(&mut self.inner, ExExNotificationsInner::Invalid);
+        self.inner = ExExNotificationsInner::FromBlock(match current {
+            ExExNotificationsInner::WithoutHead(notifications) => {
+                notifications.subscribe_from(block_number)
+            }
+            ExExNotificationsInner::WithHead(notifications) => ExExNotificationsFromBlock::new(
+                notifications.node_head,
+                notifications.provider,
+                notifications.executor,
+                notifications.notifications,
+                notifications.wal_handle,
+                block_number,
+            ),
+            ExExNotificationsInner::FromBlock(notifications) => ExExNotificationsFromBlock::new(
+                notifications.node_head,
+                notifications.provider,
+                notifications.executor,
+                notifications.notifications,
+                notifications.wal_handle,
+                block_number,
+            ),
             ExExNotificationsInner::Invalid => unreachable!(),
         });
     }
@@ -152,6 +216,11 @@ where
         self.set_with_head(exex_head);
         self
     }
+
+    fn subscribe_from(mut self, block_number: BlockNumber) -> Self {
+        self.set_subscribe_from(block_number);
+        self
+    }
 }
 
 impl<P, E> Stream for ExExNotifications<P, E>
@@ -173,6 +242,7 @@ where
                 notifications.poll_next_unpin(cx).map(|result| result.map(Ok))
             }
             ExExNotificationsInner::WithHead(notifications) => notifications.poll_next_unpin(cx),
+            ExExNotificationsInner::FromBlock(notifications) => notifications.poll_next_unpin(cx),
             ExExNotificationsInner::Invalid => unreachable!(),
         }
     }
@@ -229,6 +299,18 @@ where
             head,
         )
     }
+
+    /// Subscribe to notifications starting from the given block number, inclusive.
+    fn subscribe_from(self, block_number: BlockNumber) -> ExExNotificationsFromBlock<P, E> {
+        ExExNotificationsFromBlock::new(
+            self.node_head,
+            self.provider,
+            self.executor,
+            self.notifications,
+            self.wal_handle,
+            block_number,
+        )
+    }
 }
 
 impl<P: Unpin, E> Stream for ExExNotificationsWithoutHead<P, E>
@@ -433,6 +515,188 @@ where
     }
 }
 
+/// A stream of [`ExExNotification`]s. The stream will emit notifications for all canonical blocks
+/// starting from the given block number, inclusive, and then all new notifications.
+///
+/// Blocks between the start block and the node head are caught up with committed chains that are
+/// still in the WAL, and with backfill jobs from the node database for all other blocks. Chains
+/// in the WAL are only replayed if all of their blocks are on the canonical chain.
+///
+/// Unlike [`ExExNotificationsWithHead`], the ExEx doesn't need to know the hash of its last
+/// processed block, so it's the ExEx's responsibility to only subscribe from a block after its last
+/// canonical block.
+#[derive(Debug)]
+pub struct ExExNotificationsFromBlock<P, E>
+where
+    E: BlockExecutorProvider,
+{
+    node_head: Head,
+    provider: P,
+    executor: E,
+    notifications: Receiver<ExExNotification<E::Primitives>>,
+    wal_handle: WalHandle<E::Primitives>,
+    from_block: BlockNumber,
+    /// The steps to catch up to the node head, planned on the first poll.
+    catch_up: Option<VecDeque<CatchUp<E::Primitives>>>,
+    /// The backfill job of the current catch up step.
+    backfill_job: Option<StreamBackfillJob<E, P, Chain<E::Primitives>>>,
+}
+
+/// A step of catching up from the start block of [`ExExNotificationsFromBlock`] to the node head.
+#[derive(Debug)]
+enum CatchUp<N: NodePrimitives> {
+    /// Replay a canonical committed chain from the WAL.
+    Wal(Arc<Chain<N>>),
+    /// Backfill the blocks from the node database.
+    Backfill(RangeInclusive<BlockNumber>),
+}
+
+impl<P, E> ExExNotificationsFromBlock<P, E>
+where
+    E: BlockExecutorProvider,
+{
+    /// Creates a new [`ExExNotificationsFromBlock`].
+    const fn new(
+        node_head: Head,
+        provider: P,
+        executor: E,
+        notifications: Receiver<ExExNotification<E::Primitives>>,
+        wal_handle: WalHandle<E::Primitives>,
+        from_block: BlockNumber,
+    ) -> Self {
+        Self {
+            node_head,
+            provider,
+            executor,
+            notifications,
+            wal_handle,
+            from_block,
+            catch_up: None,
+            backfill_job: None,
+        }
+    }
+}
+
+impl<P, E> ExExNotificationsFromBlock<P, E>
+where
+    P: BlockReader + HeaderProvider + StateProviderFactory + Clone + Unpin + 'static,
+    E: BlockExecutorProvider<Primitives: NodePrimitives<Block = P::Block>>
+        + Clone
+        + Unpin
+        + 'static,
+{
+    /// Plans the steps to catch up from the start block to the node head.
+    ///
+    /// Canonical committed chains in the WAL that lie fully within the range are replayed, and the
+    /// gaps between them are backfilled.
+    fn plan_catch_up(&self) -> eyre::Result<VecDeque<CatchUp<E::Primitives>>> {
+        if self.from_block > self.node_head.number + 1 {
+            return Err(eyre::eyre!(
+                "Start block {} is ahead of the node head {}",
+                self.from_block,
+                self.node_head.number
+            ))
+        }
+
+        let mut chains = Vec::new();
+        for notification in self.wal_handle.iter_notifications()? {
+            let Some(chain) = notification?.committed_chain() else { continue };
+            if chain.first().number() < self.from_block ||
+                chain.tip().number() > self.node_head.number
+            {
+                continue
+            }
+            if self.is_canonical(&chain)? {
+                chains.push(chain);
+            }
+        }
+        chains.sort_unstable_by_key(|chain| chain.first().number());
+
+        let mut catch_up = VecDeque::new();
+        let mut next_block = self.from_block;
+        for chain in chains {
+            let first_block = chain.first().number();
+            // Chains overlapping with an already replayed chain are skipped
+            if first_block < next_block {
+                continue
+            }
+            if first_block > next_block {
+                catch_up.push_back(CatchUp::Backfill(next_block..=first_block - 1));
+            }
+            next_block = chain.tip().number() + 1;
+            catch_up.push_back(CatchUp::Wal(chain));
+        }
+        if next_block <= self.node_head.number {
+            catch_up.push_back(CatchUp::Backfill(next_block..=self.node_head.number));
+        }
+
+        debug!(target: "exex::notifications", from_block = ?self.from_block, node_head = ?self.node_head.number, steps = ?catch_up.len(), "Planned catch up to the node head");
+        Ok(catch_up)
+    }
+
+    /// Returns `true` if all blocks of the chain are on the canonical chain.
+    fn is_canonical(&self, chain: &Chain<E::Primitives>) -> eyre::Result<bool> {
+        for block in chain.blocks_iter() {
+            if self.provider.block_hash(block.number())? != Some(block.hash()) {
+                return Ok(false)
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<P, E> Stream for ExExNotificationsFromBlock<P, E>
+where
+    P: BlockReader + HeaderProvider + StateProviderFactory + Clone + Unpin + 'static,
+    E: BlockExecutorProvider<Primitives: NodePrimitives<Block = P::Block>>
+        + Clone
+        + Unpin
+        + 'static,
+{
+    type Item = eyre::Result<ExExNotification<E::Primitives>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.catch_up.is_none() {
+            this.catch_up = Some(this.plan_catch_up()?);
+        }
+
+        loop {
+            if let Some(backfill_job) = &mut this.backfill_job {
+                debug!(target: "exex::notifications", "Polling backfill job");
+                if let Some(chain) = ready!(backfill_job.poll_next_unpin(cx)).transpose()? {
+                    debug!(target: "exex::notifications", range = ?chain.range(), "Backfill job returned a chain");
+                    return Poll::Ready(Some(Ok(ExExNotification::ChainCommitted {
+                        new: Arc::new(chain),
+                    })))
+                }
+
+                // Backfill job is done, remove it
+                this.backfill_job = None;
+            }
+
+            match this.catch_up.as_mut().and_then(VecDeque::pop_front) {
+                Some(CatchUp::Wal(chain)) => {
+                    debug!(target: "exex::notifications", range = ?chain.range(), "Replaying a chain from the WAL");
+                    return Poll::Ready(Some(Ok(ExExNotification::ChainCommitted { new: chain })))
+                }
+                Some(CatchUp::Backfill(range)) => {
+                    debug!(target: "exex::notifications", ?range, "Starting backfill");
+                    this.backfill_job = Some(
+                        BackfillJobFactory::new(this.executor.clone(), this.provider.clone())
+                            .backfill(range)
+                            .into_stream(),
+                    );
+                }
+                None => break,
+            }
+        }
+
+        Poll::Ready(ready!(this.notifications.poll_recv(cx)).map(Ok))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Wal;
@@ -768,4 +1032,118 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn exex_notifications_from_block() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let provider_factory = create_test_provider_factory();
+        let genesis_hash = init_genesis(&provider_factory)?;
+        let genesis_block = provider_factory
+            .block(genesis_hash.into())?
+            .ok_or_else(|| eyre::eyre!("genesis block not found"))?;
+
+        let provider = BlockchainProvider2::new(provider_factory.clone())?;
+
+        let first_block = random_block(
+            &mut rng,
+            genesis_block.number + 1,
+            BlockParams { parent: Some(genesis_hash), tx_count: Some(0), ..Default::default() },
+        )
+        .seal_with_senders::<reth_primitives::Block>()
+        .ok_or_eyre("failed to recover senders")?;
+        let node_head_block = random_block(
+            &mut rng,
+            first_block.number + 1,
+            BlockParams {
+                parent: Some(first_block.hash()),
+                tx_count: Some(0),
+                ..Default::default()
+            },
+        )
+        .seal_with_senders::<reth_primitives::Block>()
+        .ok_or_eyre("failed to recover senders")?;
+        let provider_rw = provider_factory.provider_rw()?;
+        provider_rw.insert_block(first_block.clone(), StorageLocation::Database)?;
+        provider_rw.insert_block(node_head_block.clone(), StorageLocation::Database)?;
+        provider_rw.commit()?;
+
+        let node_head = Head {
+            number: node_head_block.number,
+            hash: node_head_block.hash(),
+            ..Default::default()
+        };
+
+        // The node head block is still in the WAL, next to a block that was reorged out
+        let reorged_notification = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(
+                vec![random_block(
+                    &mut rng,
+                    node_head.number,
+                    BlockParams { parent: Some(first_block.hash()), ..Default::default() },
+                )
+                .seal_with_senders()
+                .ok_or_eyre("failed to recover senders")?],
+                Default::default(),
+                None,
+            )),
+        };
+        wal.commit(&reorged_notification)?;
+        let node_head_notification = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![node_head_block], Default::default(), None)),
+        };
+        wal.commit(&node_head_notification)?;
+
+        let new_notification = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(
+                vec![random_block(
+                    &mut rng,
+                    node_head.number + 1,
+                    BlockParams { parent: Some(node_head.hash), ..Default::default() },
+                )
+                .seal_with_senders()
+                .ok_or_eyre("failed to recover senders")?],
+                Default::default(),
+                None,
+            )),
+        };
+
+        let (notifications_tx, notifications_rx) = mpsc::channel(1);
+
+        notifications_tx.send(new_notification.clone()).await?;
+
+        let mut notifications = ExExNotificationsWithoutHead::new(
+            node_head,
+            provider,
+            EthExecutorProvider::mainnet(),
+            notifications_rx,
+            wal.handle(),
+        )
+        .subscribe_from(first_block.number);
+
+        // First notification is the backfill of the block that is not in the WAL anymore
+        assert_eq!(
+            notifications.next().await.transpose()?,
+            Some(ExExNotification::ChainCommitted {
+                new: Arc::new(
+                    BackfillJobFactory::new(
+                        notifications.executor.clone(),
+                        notifications.provider.clone()
+                    )
+                    .backfill(first_block.number..=first_block.number)
+                    .next()
+                    .ok_or_eyre("failed to backfill")??
+                )
+            })
+        );
+        // Second notification is the canonical node head block replayed from the WAL
+        assert_eq!(notifications.next().await.transpose()?, Some(node_head_notification));
+        // Third notification is the actual notification that we sent before
+        assert_eq!(notifications.next().await.transpose()?, Some(new_notification));
+
+        Ok(())
+    }
 }
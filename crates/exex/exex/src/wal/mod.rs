@@ -12,7 +12,7 @@ use metrics::Metrics;
 use std::{
     path::Path,
     sync::{
-        atomic::{AtomicU32, Ordering},
+        atomic::{AtomicU32, AtomicU64, Ordering},
         Arc,
     },
 };
@@ -76,6 +76,11 @@ where
     pub fn num_blocks(&self) -> usize {
         self.inner.block_cache().num_blocks()
     }
+
+    /// Returns the size of all notifications in the WAL in bytes.
+    pub fn size_bytes(&self) -> u64 {
+        self.inner.size_bytes.load(Ordering::Relaxed)
+    }
 }
 
 /// Inner type for the WAL.
@@ -86,6 +91,8 @@ struct WalInner<N: NodePrimitives> {
     storage: Storage<N>,
     /// WAL block cache. See [`cache::BlockCache`] docs for more details.
     block_cache: RwLock<BlockCache>,
+    /// The size of all notifications in the storage in bytes.
+    size_bytes: AtomicU64,
     metrics: Metrics,
 }
 
@@ -98,6 +105,7 @@ where
             next_file_id: AtomicU32::new(0),
             storage: Storage::new(directory)?,
             block_cache: RwLock::new(BlockCache::default()),
+            size_bytes: AtomicU64::new(0),
             metrics: Metrics::default(),
         };
         wal.fill_block_cache()?;
@@ -179,6 +187,11 @@ where
     }
 
     fn update_metrics(&self, block_cache: &BlockCache, size_delta: i64) {
+        if size_delta >= 0 {
+            self.size_bytes.fetch_add(size_delta as u64, Ordering::Relaxed);
+        } else {
+            self.size_bytes.fetch_sub(size_delta.unsigned_abs(), Ordering::Relaxed);
+        }
         self.metrics.size_bytes.increment(size_delta as f64);
         self.metrics.notifications_count.set(block_cache.notification_max_blocks.len() as f64);
         self.metrics.committed_blocks_count.set(block_cache.committed_blocks.len() as f64);
@@ -229,6 +242,13 @@ where
             .read_notification(file_id)
             .map(|entry| entry.map(|(notification, _)| notification))
     }
+
+    /// Returns an iterator over all notifications in the WAL.
+    pub fn iter_notifications(
+        &self,
+    ) -> eyre::Result<Box<dyn Iterator<Item = eyre::Result<ExExNotification<N>>> + '_>> {
+        self.wal.iter_notifications()
+    }
 }
 
 #[cfg(test)]
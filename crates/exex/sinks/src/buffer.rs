@@ -0,0 +1,90 @@
+use alloy_eips::BlockNumHash;
+use alloy_primitives::BlockNumber;
+use std::collections::BTreeMap;
+
+/// A buffer of records that have not been persisted yet, grouped by block.
+///
+/// Records of reverted blocks can be removed from the buffer with [`BlockBuffer::revert_from`],
+/// before they are ever written.
+#[derive(Debug)]
+pub struct BlockBuffer<R> {
+    blocks: BTreeMap<BlockNumber, (BlockNumHash, Vec<R>)>,
+}
+
+impl<R> BlockBuffer<R> {
+    /// Adds the records of a block to the buffer, replacing any records for the same block
+    /// number.
+    pub fn insert(&mut self, block: BlockNumHash, records: Vec<R>) {
+        self.blocks.insert(block.number, (block, records));
+    }
+
+    /// Removes the records of all blocks starting at the given block number.
+    ///
+    /// Returns the number of removed blocks.
+    pub fn revert_from(&mut self, block_number: BlockNumber) -> usize {
+        self.blocks.split_off(&block_number).len()
+    }
+
+    /// Removes and returns the records of all blocks up to and including the given block
+    /// number, along with the highest removed block.
+    pub fn drain_to(&mut self, block_number: BlockNumber) -> (Option<BlockNumHash>, Vec<R>) {
+        let rest = self.blocks.split_off(&(block_number.saturating_add(1)));
+        let drained = std::mem::replace(&mut self.blocks, rest);
+
+        let highest = drained.values().next_back().map(|(block, _)| *block);
+        let records = drained.into_values().flat_map(|(_, records)| records).collect();
+        (highest, records)
+    }
+
+    /// Removes and returns the records of all blocks, along with the highest removed block.
+    pub fn drain(&mut self) -> (Option<BlockNumHash>, Vec<R>) {
+        self.drain_to(BlockNumber::MAX)
+    }
+
+    /// Returns the highest buffered block.
+    pub fn tip(&self) -> Option<BlockNumHash> {
+        self.blocks.values().next_back().map(|(block, _)| *block)
+    }
+
+    /// Returns the number of buffered blocks.
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Returns `true` if no blocks are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+}
+
+impl<R> Default for BlockBuffer<R> {
+    fn default() -> Self {
+        Self { blocks: BTreeMap::new() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    const fn block(number: BlockNumber) -> BlockNumHash {
+        BlockNumHash::new(number, B256::with_last_byte(number as u8))
+    }
+
+    #[test]
+    fn revert_and_drain() {
+        let mut buffer = BlockBuffer::default();
+        for number in 1..=5 {
+            buffer.insert(block(number), vec![number]);
+        }
+
+        assert_eq!(buffer.revert_from(4), 2);
+        assert_eq!(buffer.tip(), Some(block(3)));
+
+        assert_eq!(buffer.drain_to(2), (Some(block(2)), vec![1, 2]));
+        assert_eq!(buffer.drain(), (Some(block(3)), vec![3]));
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.drain(), (None, vec![]));
+    }
+}
@@ -0,0 +1,792 @@
+use crate::{ChainMapper, EventTransport, Sink};
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{BlockHash, BlockNumber};
+use reth_execution_types::Chain;
+use reth_primitives_traits::NodePrimitives;
+use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+/// A Kafka producer.
+pub trait KafkaProducer: Send {
+    /// Sends a message with the given key and payload to the topic.
+    fn send(
+        &mut self,
+        topic: &str,
+        key: &[u8],
+        payload: Vec<u8>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send;
+}
+
+/// A message published by the [`KafkaSink`], encoded as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KafkaMessage<R> {
+    /// The block was committed to the canonical chain.
+    Commit {
+        /// Block number.
+        number: BlockNumber,
+        /// Block hash.
+        hash: BlockHash,
+        /// The records of the block.
+        records: Vec<R>,
+    },
+    /// The block was reverted, and all records of a previous [`KafkaMessage::Commit`] with the
+    /// same block hash are invalid.
+    Revert {
+        /// Block number.
+        number: BlockNumber,
+        /// Block hash.
+        hash: BlockHash,
+    },
+}
+
+impl<R> KafkaMessage<R> {
+    /// Returns the block number of the message.
+    pub const fn number(&self) -> BlockNumber {
+        match self {
+            Self::Commit { number, .. } | Self::Revert { number, .. } => *number,
+        }
+    }
+}
+
+/// A [`Sink`] that publishes a [`KafkaMessage`] for every committed and reverted block.
+///
+/// Messages are keyed by the big-endian block number, so all messages of the same height end up
+/// in the same partition and consumers observe a revert after the commit it invalidates. Commits
+/// that are reverted before they are published are dropped.
+#[derive(Debug)]
+pub struct KafkaSink<P, M, R> {
+    producer: P,
+    mapper: M,
+    topic: String,
+    batch_size: usize,
+    pending: Vec<KafkaMessage<R>>,
+    tip: Option<BlockNumHash>,
+}
+
+impl<P, M, R> KafkaSink<P, M, R> {
+    /// Creates a new sink that publishes to the given topic after every notification.
+    pub fn new(producer: P, mapper: M, topic: impl Into<String>) -> Self {
+        Self {
+            producer,
+            mapper,
+            topic: topic.into(),
+            batch_size: 1,
+            pending: Vec::new(),
+            tip: None,
+        }
+    }
+
+    /// Sets the minimum number of messages to buffer before publishing them.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Returns the producer of the sink.
+    pub const fn producer(&self) -> &P {
+        &self.producer
+    }
+}
+
+impl<N, P, M> Sink<N> for KafkaSink<P, M, M::Record>
+where
+    N: NodePrimitives,
+    P: KafkaProducer,
+    M: ChainMapper<N>,
+    M::Record: Serialize,
+{
+    async fn revert(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        for block in chain.blocks().values().rev() {
+            let hash = block.hash();
+            match self.pending.last() {
+                Some(KafkaMessage::Commit { hash: pending, .. }) if *pending == hash => {
+                    self.pending.pop();
+                }
+                _ => self
+                    .pending
+                    .push(KafkaMessage::Revert { number: block.header().number(), hash }),
+            }
+        }
+
+        let first = chain.first();
+        self.tip = first
+            .header()
+            .number()
+            .checked_sub(1)
+            .map(|number| BlockNumHash::new(number, first.header().parent_hash()));
+        Ok(())
+    }
+
+    async fn commit(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        for block in chain.blocks_iter() {
+            self.pending.push(KafkaMessage::Commit {
+                number: block.header().number(),
+                hash: block.hash(),
+                records: self.mapper.map_block(chain, block),
+            });
+        }
+
+        self.tip = Some(chain.tip().num_hash());
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> eyre::Result<Option<BlockNumHash>> {
+        if self.pending.is_empty() || self.pending.len() < self.batch_size {
+            return Ok(None)
+        }
+
+        for message in std::mem::take(&mut self.pending) {
+            let key = message.number().to_be_bytes();
+            let payload = serde_json::to_vec(&message)?;
+            self.producer.send(&self.topic, &key, payload).await?;
+        }
+
+        Ok(self.tip)
+    }
+}
+
+/// The maximum size of the keys and payloads of a single produce request, which keeps the
+/// requests below the default `message.max.bytes` of 1 MiB of the broker.
+const MAX_BATCH_SIZE: usize = 512 * 1024;
+
+/// How long the broker may wait for the replicas to acknowledge a produce request.
+const PRODUCE_TIMEOUT_MS: i32 = 30_000;
+
+/// How often a produce request is attempted before [`EventTransport::flush`] fails.
+const MAX_PRODUCE_ATTEMPTS: usize = 5;
+
+/// The delay before the first retry of a produce request, which doubles with every retry.
+const RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// An [`EventTransport`] that publishes events to a partition of a
+/// [Kafka](https://kafka.apache.org) topic.
+///
+/// All events are appended to the same partition, so consumers observe them in the order they were
+/// published. The leader of the partition is looked up through the metadata of the bootstrap
+/// brokers. Published events are buffered, and [`EventTransport::flush`] produces them in batches
+/// and waits until all in-sync replicas have acknowledged them.
+///
+/// If the connection fails or the leader moves to another broker, the leader is looked up again
+/// and the batch is retried. A batch that was appended before its acknowledgement was lost is
+/// appended again, which is in line with the at-least-once delivery of the
+/// [`ChainEventPublisher`](crate::ChainEventPublisher).
+#[derive(Debug)]
+pub struct KafkaTransport {
+    bootstrap: Vec<String>,
+    topic: String,
+    partition: i32,
+    /// The connection to the leader of the partition, `None` if it needs to be looked up.
+    leader: Option<TcpStream>,
+    correlation_id: i32,
+    pending: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
+impl KafkaTransport {
+    /// Connects to the leader of the partition, which is looked up through the given
+    /// comma-separated bootstrap brokers, e.g. `127.0.0.1:9092,127.0.0.1:9093`.
+    pub async fn connect(
+        bootstrap: &str,
+        topic: impl Into<String>,
+        partition: i32,
+    ) -> eyre::Result<Self> {
+        let bootstrap = bootstrap
+            .split(',')
+            .map(str::trim)
+            .filter(|broker| !broker.is_empty())
+            .map(String::from)
+            .collect();
+        let mut transport = Self {
+            bootstrap,
+            topic: topic.into(),
+            partition,
+            leader: None,
+            correlation_id: 0,
+            pending: Vec::new(),
+        };
+        transport.leader = Some(transport.connect_leader().await?);
+        Ok(transport)
+    }
+
+    /// Looks up the leader of the partition through the first bootstrap broker that responds, and
+    /// connects to it.
+    async fn connect_leader(&self) -> eyre::Result<TcpStream> {
+        let mut last_error = eyre::eyre!("no Kafka bootstrap brokers");
+        for broker in &self.bootstrap {
+            match self.lookup_leader(broker).await {
+                Ok(leader) => return Ok(TcpStream::connect(leader).await?),
+                Err(err) => last_error = err,
+            }
+        }
+        Err(last_error)
+    }
+
+    /// Returns the address of the leader of the partition from the metadata of the broker.
+    async fn lookup_leader(&self, broker: &str) -> eyre::Result<String> {
+        let mut stream = TcpStream::connect(broker).await?;
+        // the correlation id only needs to be unique per connection
+        let request = wire::metadata_request(0, &self.topic);
+        let response = round_trip(&mut stream, &request).await?;
+        wire::partition_leader(&response, 0, &self.topic, self.partition)
+    }
+
+    /// Produces the given number of pending records, retrying on connection errors and
+    /// retriable broker errors.
+    async fn produce(&mut self, records: usize) -> eyre::Result<()> {
+        let mut backoff = RETRY_BACKOFF;
+        let mut attempt = 1;
+        loop {
+            let result = self.try_produce(records).await;
+            let retriable = match &result {
+                Ok(0) => {
+                    self.pending.drain(..records);
+                    return Ok(())
+                }
+                Ok(error_code) => wire::is_retriable(*error_code),
+                Err(_) => true,
+            };
+            if !retriable || attempt == MAX_PRODUCE_ATTEMPTS {
+                return match result {
+                    Ok(error_code) => Err(eyre::eyre!(
+                        "Kafka broker rejected events with error code {error_code}"
+                    )),
+                    Err(err) => Err(err),
+                }
+            }
+
+            // the leader may have moved, so it's looked up again
+            self.leader = None;
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+            attempt += 1;
+        }
+    }
+
+    /// Sends a produce request with the given records to the leader and returns the error code of
+    /// the response.
+    async fn try_produce(&mut self, records: usize) -> eyre::Result<i16> {
+        let mut stream = match self.leader.take() {
+            Some(stream) => stream,
+            None => self.connect_leader().await?,
+        };
+
+        self.correlation_id = self.correlation_id.wrapping_add(1);
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as i64;
+        let request = wire::produce_request(
+            self.correlation_id,
+            &self.topic,
+            self.partition,
+            &self.pending[..records],
+            timestamp,
+        );
+        let response = round_trip(&mut stream, &request).await?;
+        let error_code = wire::produce_response_error(&response, self.correlation_id)?;
+
+        // the connection is only reused if the request completed
+        self.leader = Some(stream);
+        Ok(error_code)
+    }
+}
+
+/// Sends the size-prefixed request and returns the response without its size prefix.
+async fn round_trip(stream: &mut TcpStream, request: &[u8]) -> eyre::Result<Vec<u8>> {
+    stream.write_all(request).await?;
+    let size = stream.read_i32().await?;
+    let mut response = vec![0; usize::try_from(size)?];
+    stream.read_exact(&mut response).await?;
+    Ok(response)
+}
+
+impl EventTransport for KafkaTransport {
+    async fn publish(&mut self, key: &[u8], payload: Vec<u8>) -> eyre::Result<()> {
+        self.pending.push((key.to_vec(), payload));
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> eyre::Result<()> {
+        while !self.pending.is_empty() {
+            // a single record that exceeds the limit is still produced on its own
+            let mut size = 0;
+            let records = self
+                .pending
+                .iter()
+                .take_while(|(key, payload)| {
+                    size += key.len() + payload.len();
+                    size <= MAX_BATCH_SIZE
+                })
+                .count()
+                .max(1);
+            self.produce(records).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Minimal encoding of the Kafka wire protocol, see <https://kafka.apache.org/protocol>.
+mod wire {
+    /// The API key of produce requests.
+    const PRODUCE: i16 = 0;
+
+    /// The API key of metadata requests.
+    const METADATA: i16 = 3;
+
+    /// Version 4 is the oldest version that is supported by all current brokers.
+    const METADATA_VERSION: i16 = 4;
+
+    /// Version 3 is the first version that supports record batches.
+    const PRODUCE_VERSION: i16 = 3;
+
+    /// Wait for all in-sync replicas to acknowledge the records.
+    const ACKS_ALL: i16 = -1;
+
+    /// Encodes a produce request for the records of a single partition, including the size prefix.
+    pub(super) fn produce_request(
+        correlation_id: i32,
+        topic: &str,
+        partition: i32,
+        records: &[(Vec<u8>, Vec<u8>)],
+        timestamp: i64,
+    ) -> Vec<u8> {
+        let batch = record_batch(records, timestamp);
+
+        // the size is filled in after the request is encoded
+        let mut buf = vec![0; 4];
+        buf.extend_from_slice(&PRODUCE.to_be_bytes());
+        buf.extend_from_slice(&PRODUCE_VERSION.to_be_bytes());
+        buf.extend_from_slice(&correlation_id.to_be_bytes());
+        string(&mut buf, "reth");
+        // no transactional id
+        buf.extend_from_slice(&(-1i16).to_be_bytes());
+        buf.extend_from_slice(&ACKS_ALL.to_be_bytes());
+        buf.extend_from_slice(&super::PRODUCE_TIMEOUT_MS.to_be_bytes());
+        buf.extend_from_slice(&1i32.to_be_bytes());
+        string(&mut buf, topic);
+        buf.extend_from_slice(&1i32.to_be_bytes());
+        buf.extend_from_slice(&partition.to_be_bytes());
+        buf.extend_from_slice(&(batch.len() as i32).to_be_bytes());
+        buf.extend_from_slice(&batch);
+
+        let size = (buf.len() - 4) as i32;
+        buf[..4].copy_from_slice(&size.to_be_bytes());
+        buf
+    }
+
+    /// Encodes a metadata request for the topic, including the size prefix.
+    pub(super) fn metadata_request(correlation_id: i32, topic: &str) -> Vec<u8> {
+        let mut buf = vec![0; 4];
+        buf.extend_from_slice(&METADATA.to_be_bytes());
+        buf.extend_from_slice(&METADATA_VERSION.to_be_bytes());
+        buf.extend_from_slice(&correlation_id.to_be_bytes());
+        string(&mut buf, "reth");
+        buf.extend_from_slice(&1i32.to_be_bytes());
+        string(&mut buf, topic);
+        // don't create the topic if it doesn't exist
+        buf.push(0);
+
+        let size = (buf.len() - 4) as i32;
+        buf[..4].copy_from_slice(&size.to_be_bytes());
+        buf
+    }
+
+    /// Returns the `host:port` address of the leader of the partition from a metadata response,
+    /// without the size prefix.
+    pub(super) fn partition_leader(
+        mut response: &[u8],
+        correlation_id: i32,
+        topic: &str,
+        partition: i32,
+    ) -> eyre::Result<String> {
+        let response_id = i32::from_be_bytes(take(&mut response)?);
+        if response_id != correlation_id {
+            eyre::bail!("unexpected Kafka correlation id {response_id}, expected {correlation_id}")
+        }
+        // throttle time
+        take::<4>(&mut response)?;
+
+        let mut brokers = Vec::new();
+        for _ in 0..i32::from_be_bytes(take(&mut response)?) {
+            let node_id = i32::from_be_bytes(take(&mut response)?);
+            let host = read_string(&mut response)?.unwrap_or_default();
+            let port = i32::from_be_bytes(take(&mut response)?);
+            // rack
+            read_string(&mut response)?;
+            brokers.push((node_id, format!("{host}:{port}")));
+        }
+        // cluster id and controller id
+        read_string(&mut response)?;
+        take::<4>(&mut response)?;
+
+        for _ in 0..i32::from_be_bytes(take(&mut response)?) {
+            let topic_error = i16::from_be_bytes(take(&mut response)?);
+            let name = read_string(&mut response)?;
+            // is internal
+            take::<1>(&mut response)?;
+            for _ in 0..i32::from_be_bytes(take(&mut response)?) {
+                let partition_error = i16::from_be_bytes(take(&mut response)?);
+                let partition_index = i32::from_be_bytes(take(&mut response)?);
+                let leader = i32::from_be_bytes(take(&mut response)?);
+                // replicas and in-sync replicas
+                skip_i32_array(&mut response)?;
+                skip_i32_array(&mut response)?;
+
+                if name.as_deref() != Some(topic) || partition_index != partition {
+                    continue
+                }
+                if topic_error != 0 || partition_error != 0 {
+                    eyre::bail!(
+                        "Kafka metadata of partition {partition} has error code {}",
+                        if topic_error != 0 { topic_error } else { partition_error }
+                    )
+                }
+                return brokers
+                    .into_iter()
+                    .find(|(node_id, _)| *node_id == leader)
+                    .map(|(_, addr)| addr)
+                    .ok_or_else(|| eyre::eyre!("Kafka partition {partition} has no leader"))
+            }
+        }
+
+        eyre::bail!("Kafka partition {partition} of topic {topic} not found")
+    }
+
+    /// Returns true if a produce request that failed with the error code may succeed when it's
+    /// retried, possibly with another leader.
+    pub(super) const fn is_retriable(error_code: i16) -> bool {
+        matches!(
+            error_code,
+            // unknown topic or partition, leader not available, not leader or follower, request
+            // timed out, network exception, not enough replicas (after append)
+            3 | 5 | 6 | 7 | 13 | 19 | 20
+        )
+    }
+
+    /// Encodes an uncompressed record batch (magic value 2) of the given key and value pairs.
+    fn record_batch(records: &[(Vec<u8>, Vec<u8>)], timestamp: i64) -> Vec<u8> {
+        // all fields after the checksum, which are covered by it
+        let mut body = Vec::new();
+        // attributes
+        body.extend_from_slice(&0i16.to_be_bytes());
+        // last offset delta
+        body.extend_from_slice(&(records.len() as i32 - 1).to_be_bytes());
+        // first and max timestamp
+        body.extend_from_slice(&timestamp.to_be_bytes());
+        body.extend_from_slice(&timestamp.to_be_bytes());
+        // no producer id, producer epoch and base sequence, because the producer isn't idempotent
+        body.extend_from_slice(&(-1i64).to_be_bytes());
+        body.extend_from_slice(&(-1i16).to_be_bytes());
+        body.extend_from_slice(&(-1i32).to_be_bytes());
+        body.extend_from_slice(&(records.len() as i32).to_be_bytes());
+        for (offset_delta, (key, value)) in records.iter().enumerate() {
+            // attributes and timestamp delta
+            let mut record = vec![0, 0];
+            varint(&mut record, offset_delta as i64);
+            varint(&mut record, key.len() as i64);
+            record.extend_from_slice(key);
+            varint(&mut record, value.len() as i64);
+            record.extend_from_slice(value);
+            // no headers
+            varint(&mut record, 0);
+
+            varint(&mut body, record.len() as i64);
+            body.extend_from_slice(&record);
+        }
+
+        let mut batch = Vec::with_capacity(body.len() + 21);
+        // base offset, which is assigned by the broker
+        batch.extend_from_slice(&0i64.to_be_bytes());
+        // length of the partition leader epoch, magic value, checksum and body
+        batch.extend_from_slice(&(body.len() as i32 + 9).to_be_bytes());
+        // partition leader epoch
+        batch.extend_from_slice(&(-1i32).to_be_bytes());
+        batch.push(2);
+        batch.extend_from_slice(&crc32c(&body).to_be_bytes());
+        batch.extend_from_slice(&body);
+        batch
+    }
+
+    /// Returns the error code of the single partition of a produce response, without the size
+    /// prefix.
+    pub(super) fn produce_response_error(
+        mut response: &[u8],
+        correlation_id: i32,
+    ) -> eyre::Result<i16> {
+        let response_id = i32::from_be_bytes(take(&mut response)?);
+        if response_id != correlation_id {
+            eyre::bail!("unexpected Kafka correlation id {response_id}, expected {correlation_id}")
+        }
+
+        // number of topics, the topic name, number of partitions and the partition
+        take::<4>(&mut response)?;
+        let topic_len = i16::from_be_bytes(take(&mut response)?);
+        response = response
+            .get(usize::try_from(topic_len)?..)
+            .ok_or_else(|| eyre::eyre!("truncated Kafka response"))?;
+        take::<8>(&mut response)?;
+
+        Ok(i16::from_be_bytes(take(&mut response)?))
+    }
+
+    fn take<const N: usize>(buf: &mut &[u8]) -> eyre::Result<[u8; N]> {
+        let Some((value, rest)) = buf.split_first_chunk::<N>() else {
+            eyre::bail!("truncated Kafka response")
+        };
+        *buf = rest;
+        Ok(*value)
+    }
+
+    /// Reads a nullable string.
+    fn read_string(buf: &mut &[u8]) -> eyre::Result<Option<String>> {
+        let Ok(len) = usize::try_from(i16::from_be_bytes(take(buf)?)) else { return Ok(None) };
+        let Some((value, rest)) = buf.split_at_checked(len) else {
+            eyre::bail!("truncated Kafka response")
+        };
+        *buf = rest;
+        Ok(Some(String::from_utf8(value.to_vec())?))
+    }
+
+    fn skip_i32_array(buf: &mut &[u8]) -> eyre::Result<()> {
+        for _ in 0..i32::from_be_bytes(take(buf)?) {
+            take::<4>(buf)?;
+        }
+        Ok(())
+    }
+
+    fn string(buf: &mut Vec<u8>, value: &str) {
+        buf.extend_from_slice(&(value.len() as i16).to_be_bytes());
+        buf.extend_from_slice(value.as_bytes());
+    }
+
+    /// Encodes a zigzag varint.
+    fn varint(buf: &mut Vec<u8>, value: i64) {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+        while value >= 0x80 {
+            buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        buf.push(value as u8);
+    }
+
+    /// Computes the CRC-32C (Castagnoli) checksum of record batches.
+    pub(super) fn crc32c(data: &[u8]) -> u32 {
+        let mut crc = !0u32;
+        for byte in data {
+            crc ^= *byte as u32;
+            for _ in 0..8 {
+                crc = (crc >> 1) ^ (0x82F6_3B78 & (crc & 1).wrapping_neg());
+            }
+        }
+        !crc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{chain, flush},
+        BlockMapper, BlockRecord,
+    };
+    use std::net::SocketAddr;
+    use tokio::net::TcpListener;
+
+    #[derive(Debug, Default)]
+    struct MockProducer {
+        messages: Vec<KafkaMessage<BlockRecord>>,
+    }
+
+    impl KafkaProducer for MockProducer {
+        async fn send(&mut self, topic: &str, key: &[u8], payload: Vec<u8>) -> eyre::Result<()> {
+            assert_eq!(topic, "blocks");
+            let message: KafkaMessage<BlockRecord> = serde_json::from_slice(&payload)?;
+            assert_eq!(key, message.number().to_be_bytes());
+            self.messages.push(message);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn reorg() -> eyre::Result<()> {
+        let mut sink =
+            KafkaSink::new(MockProducer::default(), BlockMapper, "blocks").with_batch_size(2);
+
+        let (first, second, third) = (chain(1..=1, None), chain(2..=2, None), chain(3..=3, None));
+        sink.commit(&first).await?;
+        assert_eq!(flush(&mut sink).await?, None);
+        sink.commit(&second).await?;
+        assert_eq!(flush(&mut sink).await?, Some(second.tip().num_hash()));
+        sink.commit(&third).await?;
+        assert_eq!(flush(&mut sink).await?, None);
+
+        // block 3 is reverted before it is published, block 2 is reverted after
+        let old: Chain =
+            Chain::new([second.tip().clone(), third.tip().clone()], Default::default(), None);
+        let new = chain(2..=2, None);
+        sink.revert(&old).await?;
+        sink.commit(&new).await?;
+        assert_eq!(flush(&mut sink).await?, Some(new.tip().num_hash()));
+
+        assert_eq!(
+            sink.producer()
+                .messages
+                .iter()
+                .map(|message| match message {
+                    KafkaMessage::Commit { number, hash, .. } => (true, *number, *hash),
+                    KafkaMessage::Revert { number, hash } => (false, *number, *hash),
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                (true, 1, first.tip().hash()),
+                (true, 2, second.tip().hash()),
+                (false, 2, second.tip().hash()),
+                (true, 2, new.tip().hash()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn crc32c() {
+        assert_eq!(wire::crc32c(b"123456789"), 0xe306_9283);
+    }
+
+    /// Serves the given number of connections of a mock broker, and returns the produce requests.
+    ///
+    /// Metadata requests are answered with the leader that is returned by `leader`, and produce
+    /// requests with the error code that is returned by `produce`.
+    async fn broker(
+        listener: TcpListener,
+        connections: usize,
+        mut leader: impl FnMut() -> SocketAddr,
+        mut produce: impl FnMut() -> i16,
+    ) -> eyre::Result<Vec<Vec<u8>>> {
+        let mut requests = Vec::new();
+        for _ in 0..connections {
+            let (mut stream, _) = listener.accept().await?;
+            while let Ok(size) = stream.read_i32().await {
+                let mut request = vec![0; size as usize];
+                stream.read_exact(&mut request).await?;
+
+                let mut response = request[4..8].to_vec();
+                if request[..2] == 3i16.to_be_bytes() {
+                    // one broker, one topic with one partition that is led by the broker
+                    let leader = leader();
+                    let host = leader.ip().to_string();
+                    response.extend_from_slice(&0i32.to_be_bytes());
+                    response.extend_from_slice(&1i32.to_be_bytes());
+                    response.extend_from_slice(&7i32.to_be_bytes());
+                    response.extend_from_slice(&(host.len() as i16).to_be_bytes());
+                    response.extend_from_slice(host.as_bytes());
+                    response.extend_from_slice(&i32::from(leader.port()).to_be_bytes());
+                    response.extend_from_slice(&(-1i16).to_be_bytes());
+                    response.extend_from_slice(&(-1i16).to_be_bytes());
+                    response.extend_from_slice(&7i32.to_be_bytes());
+                    response.extend_from_slice(&1i32.to_be_bytes());
+                    response.extend_from_slice(&0i16.to_be_bytes());
+                    response.extend_from_slice(&6i16.to_be_bytes());
+                    response.extend_from_slice(b"blocks");
+                    response.push(0);
+                    response.extend_from_slice(&1i32.to_be_bytes());
+                    response.extend_from_slice(&0i16.to_be_bytes());
+                    response.extend_from_slice(&3i32.to_be_bytes());
+                    response.extend_from_slice(&7i32.to_be_bytes());
+                    response.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0, 7].repeat(2));
+                } else {
+                    // one topic with one partition
+                    response.extend_from_slice(&1i32.to_be_bytes());
+                    response.extend_from_slice(&6i16.to_be_bytes());
+                    response.extend_from_slice(b"blocks");
+                    response.extend_from_slice(&1i32.to_be_bytes());
+                    response.extend_from_slice(&3i32.to_be_bytes());
+                    response.extend_from_slice(&produce().to_be_bytes());
+                    response.extend_from_slice(&[0; 20]);
+                    requests.push(request);
+                }
+                stream.write_all(&(response.len() as i32).to_be_bytes()).await?;
+                stream.write_all(&response).await?;
+            }
+        }
+        Ok(requests)
+    }
+
+    #[tokio::test]
+    async fn produce_and_flush() -> eyre::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        // the metadata connection and the leader connection
+        let server = tokio::spawn(broker(listener, 2, move || addr, || 0));
+
+        let mut transport = KafkaTransport::connect(&addr.to_string(), "blocks", 3).await?;
+        transport.publish(&[1], b"hello".to_vec()).await?;
+        transport.flush().await?;
+        transport.flush().await?;
+        drop(transport);
+
+        let requests = server.await??;
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        // produce request version 3 with correlation id 1
+        assert_eq!(request[..8], [0, 0, 0, 3, 0, 0, 0, 1]);
+
+        // the batch ends with the record, and its checksum covers everything after it
+        let record = [24, 0, 0, 0, 2, 1, 10, b'h', b'e', b'l', b'l', b'o', 0];
+        assert!(request.ends_with(&record));
+        let body = &request[request.len() - record.len() - 40..];
+        let crc = &request[request.len() - record.len() - 44..][..4];
+        assert_eq!(crc, wire::crc32c(body).to_be_bytes());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn follow_leader() -> eyre::Result<()> {
+        let bootstrap = TcpListener::bind("127.0.0.1:0").await?;
+        let bootstrap_addr = bootstrap.local_addr()?;
+        let next = TcpListener::bind("127.0.0.1:0").await?;
+        let next_addr = next.local_addr()?;
+
+        // the bootstrap broker leads the partition until it answers with not leader or follower,
+        // and then points to the next leader
+        let mut lookups = 0;
+        let previous = tokio::spawn(broker(
+            bootstrap,
+            3,
+            move || {
+                lookups += 1;
+                if lookups == 1 {
+                    bootstrap_addr
+                } else {
+                    next_addr
+                }
+            },
+            || 6,
+        ));
+        let next = tokio::spawn(broker(next, 1, move || next_addr, || 0));
+
+        let mut transport =
+            KafkaTransport::connect(&bootstrap_addr.to_string(), "blocks", 3).await?;
+        transport.publish(&[1], b"hello".to_vec()).await?;
+        transport.flush().await?;
+        assert!(transport.pending.is_empty());
+        drop(transport);
+
+        // the rejected request is retried with the next leader
+        let (previous, next) = (previous.await??, next.await??);
+        assert_eq!(previous.len(), 1);
+        assert_eq!(next.len(), 1);
+        let record = [24, 0, 0, 0, 2, 1, 10, b'h', b'e', b'l', b'l', b'o', 0];
+        assert!(previous[0].ends_with(&record));
+        assert!(next[0].ends_with(&record));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,191 @@
+//! Reusable data sinks for `ExEx`es.
+//!
+//! Most `ExEx`es that export chain data to an external system share the same structure: map every
+//! committed block to a set of records, buffer them, write them out in batches, undo the writes of
+//! reverted blocks, and report the highest persisted block back to the node with
+//! [`ExExEvent::FinishedHeight`]. This crate provides these building blocks:
+//!
+//! - [`Sink`]: the interface that all sinks implement, driven by [`run_sink`].
+//! - [`ChainMapper`]: maps blocks into records, e.g. [`BlockMapper`].
+//! - [`PostgresSink`]: batched, reorg-safe upserts into a Postgres table.
+//! - [`ParquetSink`]: append-only row groups, written once blocks are deep enough to not be
+//!   reorged, e.g. into a Parquet file with the [`ParquetFileWriter`].
+//! - [`KafkaSink`]: publishes commit and revert messages for every block.
+//! - [`ChainEventPublisher`]: publishes [`ChainEvent`]s with at-least-once delivery and a resume
+//!   cursor, e.g. to NATS with the [`NatsTransport`] or to Kafka with the [`KafkaTransport`].
+//!
+//! The sinks are generic over thin client traits ([`PostgresClient`], [`RowGroupWriter`],
+//! [`KafkaProducer`] and [`EventTransport`]), so they can be used with any client library.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod buffer;
+pub use buffer::BlockBuffer;
+
+mod kafka;
+pub use kafka::{KafkaMessage, KafkaProducer, KafkaSink, KafkaTransport};
+
+mod nats;
+pub use nats::NatsTransport;
+
+mod parquet;
+pub use parquet::{
+    ParquetColumn, ParquetFileWriter, ParquetRecord, ParquetSink, ParquetType, ParquetValue,
+    RowGroupWriter,
+};
+
+mod postgres;
+pub use postgres::{PostgresClient, PostgresRecord, PostgresSink, SqlValue};
+
+mod publisher;
+pub use publisher::{
+    read_cursor, ChainEvent, ChainEventKind, ChainEventPublisher, EventDetail, EventEncoding,
+    EventTransport, ReceiptEvent,
+};
+
+mod record;
+pub use record::{BlockMapper, BlockRecord, ChainMapper};
+
+use alloy_eips::BlockNumHash;
+use futures_util::{Stream, TryStreamExt};
+use reth_execution_types::Chain;
+use reth_exex::ExExEvent;
+use reth_exex_types::ExExNotification;
+use reth_primitives_traits::NodePrimitives;
+use std::future::Future;
+use tokio::sync::mpsc::UnboundedSender;
+
+/// A destination for the data of the canonical chain.
+///
+/// For every notification, [`Sink::revert`] is called with the reverted chain and
+/// [`Sink::commit`] with the committed chain, if any. Afterwards, [`Sink::flush`] is called and the
+/// sink may persist its buffered data.
+pub trait Sink<N: NodePrimitives>: Send {
+    /// Reverts the data of all blocks of the given chain.
+    fn revert(&mut self, chain: &Chain<N>) -> impl Future<Output = eyre::Result<()>> + Send;
+
+    /// Adds the data of all blocks of the given chain.
+    fn commit(&mut self, chain: &Chain<N>) -> impl Future<Output = eyre::Result<()>> + Send;
+
+    /// Persists the buffered data, if the sink decides to do so.
+    ///
+    /// Returns the highest block that has been persisted, if it changed. All data up to this
+    /// block must survive a restart, because the node may prune the notifications for it.
+    fn flush(&mut self) -> impl Future<Output = eyre::Result<Option<BlockNumHash>>> + Send;
+}
+
+/// Drives the sink with the notifications of an `ExEx`, until the notification stream ends.
+///
+/// Sends an [`ExExEvent::FinishedHeight`] event every time the sink persisted new data. Heights
+/// below the last sent height, e.g. the parent of a reverted block, are not sent, because the node
+/// may already have pruned the notifications below the last sent height.
+pub async fn run_sink<N, S>(
+    mut notifications: impl Stream<Item = eyre::Result<ExExNotification<N>>> + Unpin,
+    events: UnboundedSender<ExExEvent>,
+    mut sink: S,
+) -> eyre::Result<()>
+where
+    N: NodePrimitives,
+    S: Sink<N>,
+{
+    let mut last_finished_height: Option<BlockNumHash> = None;
+    while let Some(notification) = notifications.try_next().await? {
+        if let Some(reverted) = notification.reverted_chain() {
+            sink.revert(&reverted).await?;
+        }
+        if let Some(committed) = notification.committed_chain() {
+            sink.commit(&committed).await?;
+        }
+        if let Some(finished_height) = sink.flush().await? {
+            if last_finished_height.is_none_or(|last| finished_height.number >= last.number) {
+                events.send(ExExEvent::FinishedHeight(finished_height))?;
+                last_finished_height = Some(finished_height);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use crate::Sink;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B256;
+    use reth_execution_types::Chain;
+    use reth_primitives::EthPrimitives;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+    use std::ops::RangeInclusive;
+
+    /// Returns a chain of random blocks.
+    pub(crate) fn chain(numbers: RangeInclusive<u64>, parent: Option<B256>) -> Chain {
+        let blocks = random_block_range(
+            &mut generators::rng(),
+            numbers,
+            BlockRangeParams { parent, tx_count: 0..2, ..Default::default() },
+        );
+        Chain::new(
+            blocks.into_iter().map(|block| block.seal_with_senders().unwrap()),
+            Default::default(),
+            None,
+        )
+    }
+
+    /// Flushes the sink, see [`Sink::flush`].
+    pub(crate) async fn flush<S: Sink<EthPrimitives>>(
+        sink: &mut S,
+    ) -> eyre::Result<Option<BlockNumHash>> {
+        sink.flush().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::chain, ChainEventPublisher, EventTransport};
+    use std::sync::Arc;
+
+    #[derive(Debug)]
+    struct NoopTransport;
+
+    impl EventTransport for NoopTransport {
+        async fn publish(&mut self, _key: &[u8], _payload: Vec<u8>) -> eyre::Result<()> {
+            Ok(())
+        }
+
+        async fn flush(&mut self) -> eyre::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn finished_height_never_decreases() -> eyre::Result<()> {
+        let old = chain(1..=2, None);
+        let reverted: Chain = Chain::new([old.tip().clone()], Default::default(), None);
+        let new = chain(2..=2, Some(old.tip().parent_hash));
+        let notifications = futures_util::stream::iter(
+            [
+                ExExNotification::ChainCommitted { new: Arc::new(old.clone()) },
+                ExExNotification::ChainReverted { old: Arc::new(reverted) },
+                ExExNotification::ChainCommitted { new: Arc::new(new.clone()) },
+            ]
+            .map(Ok),
+        );
+
+        let (events, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        run_sink(notifications, events, ChainEventPublisher::new(NoopTransport)).await?;
+
+        // the parent of the reverted block is not reported
+        assert_eq!(rx.try_recv()?, ExExEvent::FinishedHeight(old.tip().num_hash()));
+        assert_eq!(rx.try_recv()?, ExExEvent::FinishedHeight(new.tip().num_hash()));
+        assert!(rx.try_recv().is_err());
+
+        Ok(())
+    }
+}
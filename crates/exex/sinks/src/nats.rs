@@ -0,0 +1,249 @@
+use crate::EventTransport;
+use std::time::Duration;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpStream,
+    },
+};
+
+/// How often publishing the pending messages is attempted before [`EventTransport::flush`] fails.
+const MAX_FLUSH_ATTEMPTS: usize = 5;
+
+/// The delay before the first reconnect, which doubles with every further attempt.
+const RECONNECT_BACKOFF: Duration = Duration::from_millis(100);
+
+/// An [`EventTransport`] that publishes events to a subject of a [NATS](https://nats.io) server.
+///
+/// NATS messages have no key, so the key of the events is ignored. Published messages are buffered
+/// until [`EventTransport::flush`] sends them and waits until the server has processed them. If
+/// the connection fails, the transport reconnects and sends all messages that weren't
+/// acknowledged again, in line with the at-least-once delivery of the
+/// [`ChainEventPublisher`](crate::ChainEventPublisher).
+#[derive(Debug)]
+pub struct NatsTransport {
+    addr: String,
+    subject: String,
+    /// The connection to the server, `None` if it failed.
+    connection: Option<NatsConnection>,
+    /// The messages that were published since the last successful flush.
+    pending: Vec<Vec<u8>>,
+}
+
+impl NatsTransport {
+    /// Connects to the NATS server at the given address, e.g. `nats://127.0.0.1:4222`.
+    pub async fn connect(url: &str, subject: impl Into<String>) -> eyre::Result<Self> {
+        let addr = url.strip_prefix("nats://").unwrap_or(url).to_string();
+        let connection = NatsConnection::connect(&addr).await?;
+        Ok(Self {
+            addr,
+            subject: subject.into(),
+            connection: Some(connection),
+            pending: Vec::new(),
+        })
+    }
+
+    /// Sends the pending messages and waits until the server has processed them, reconnecting if
+    /// there's no connection.
+    async fn try_flush(&mut self) -> eyre::Result<()> {
+        let mut connection = match self.connection.take() {
+            Some(connection) => connection,
+            None => NatsConnection::connect(&self.addr).await?,
+        };
+        for payload in &self.pending {
+            connection.publish(&self.subject, payload).await?;
+        }
+        connection.ping().await?;
+
+        // the connection is only reused if the messages were acknowledged
+        self.connection = Some(connection);
+        Ok(())
+    }
+}
+
+impl EventTransport for NatsTransport {
+    async fn publish(&mut self, _key: &[u8], payload: Vec<u8>) -> eyre::Result<()> {
+        self.pending.push(payload);
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> eyre::Result<()> {
+        let mut backoff = RECONNECT_BACKOFF;
+        let mut attempt = 1;
+        loop {
+            match self.try_flush().await {
+                Ok(()) => {
+                    self.pending.clear();
+                    return Ok(())
+                }
+                Err(_) if attempt < MAX_FLUSH_ATTEMPTS => {
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+/// A connection to a NATS server.
+#[derive(Debug)]
+struct NatsConnection {
+    reader: BufReader<OwnedReadHalf>,
+    writer: BufWriter<OwnedWriteHalf>,
+}
+
+impl NatsConnection {
+    async fn connect(addr: &str) -> eyre::Result<Self> {
+        let (reader, writer) = TcpStream::connect(addr).await?.into_split();
+        let mut connection =
+            Self { reader: BufReader::new(reader), writer: BufWriter::new(writer) };
+
+        let info = connection.read_line().await?;
+        if !info.starts_with("INFO") {
+            eyre::bail!("unexpected NATS server greeting: {info}")
+        }
+        connection
+            .writer
+            .write_all(br#"CONNECT {"verbose":false,"pedantic":false,"name":"reth"}"#)
+            .await?;
+        connection.writer.write_all(b"\r\n").await?;
+        connection.ping().await?;
+
+        Ok(connection)
+    }
+
+    async fn read_line(&mut self) -> eyre::Result<String> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line).await? == 0 {
+            eyre::bail!("NATS connection closed")
+        }
+        Ok(line.trim_end().to_string())
+    }
+
+    async fn publish(&mut self, subject: &str, payload: &[u8]) -> eyre::Result<()> {
+        self.writer.write_all(format!("PUB {subject} {}\r\n", payload.len()).as_bytes()).await?;
+        self.writer.write_all(payload).await?;
+        self.writer.write_all(b"\r\n").await?;
+        Ok(())
+    }
+
+    /// Waits until the server has processed all previous messages.
+    async fn ping(&mut self) -> eyre::Result<()> {
+        // the server processes messages in order, so a pong acknowledges all previous messages
+        self.writer.write_all(b"PING\r\n").await?;
+        self.writer.flush().await?;
+
+        loop {
+            let line = self.read_line().await?;
+            match line.split_whitespace().next() {
+                Some("PONG") => return Ok(()),
+                Some("PING") => {
+                    self.writer.write_all(b"PONG\r\n").await?;
+                    self.writer.flush().await?;
+                }
+                Some("-ERR") => eyre::bail!("NATS server error: {line}"),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn publish_and_flush() -> eyre::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("nats://{}", listener.local_addr()?);
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await?;
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            writer.write_all(b"INFO {}\r\n").await?;
+
+            let mut received = Vec::new();
+            while let Some(line) = lines.next_line().await? {
+                if line == "PING" {
+                    writer.write_all(b"PONG\r\n").await?;
+                }
+                received.push(line);
+            }
+            eyre::Ok(received)
+        });
+
+        let mut transport = NatsTransport::connect(&url, "reth.chain").await?;
+        transport.publish(&[], b"hello".to_vec()).await?;
+        transport.flush().await?;
+        drop(transport);
+
+        assert_eq!(
+            server.await??,
+            vec![
+                r#"CONNECT {"verbose":false,"pedantic":false,"name":"reth"}"#,
+                "PING",
+                "PUB reth.chain 5",
+                "hello",
+                "PING",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reconnect() -> eyre::Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("nats://{}", listener.local_addr()?);
+
+        let server = tokio::spawn(async move {
+            // the first connection is closed after the handshake
+            let (stream, _) = listener.accept().await?;
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            writer.write_all(b"INFO {}\r\n").await?;
+            while lines.next_line().await?.is_some_and(|line| line != "PING") {}
+            writer.write_all(b"PONG\r\n").await?;
+            drop((lines, writer));
+
+            let (stream, _) = listener.accept().await?;
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            writer.write_all(b"INFO {}\r\n").await?;
+
+            let mut received = Vec::new();
+            while let Some(line) = lines.next_line().await? {
+                if line == "PING" {
+                    writer.write_all(b"PONG\r\n").await?;
+                }
+                received.push(line);
+            }
+            eyre::Ok(received)
+        });
+
+        let mut transport = NatsTransport::connect(&url, "reth.chain").await?;
+        transport.publish(&[], b"hello".to_vec()).await?;
+        transport.flush().await?;
+        assert!(transport.pending.is_empty());
+        drop(transport);
+
+        // the message is sent again over the new connection
+        assert_eq!(
+            server.await??,
+            vec![
+                r#"CONNECT {"verbose":false,"pedantic":false,"name":"reth"}"#,
+                "PING",
+                "PUB reth.chain 5",
+                "hello",
+                "PING",
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,393 @@
+use super::{
+    thrift::{write_uleb128, CompactWriter},
+    RowGroupWriter,
+};
+use crate::BlockRecord;
+use std::{io::Write, marker::PhantomData};
+
+/// Magic bytes at the start and the end of a Parquet file.
+const MAGIC: &[u8; 4] = b"PAR1";
+
+/// `Type` of the Parquet format.
+mod physical_type {
+    pub(super) const BOOLEAN: i32 = 0;
+    pub(super) const INT64: i32 = 2;
+    pub(super) const BYTE_ARRAY: i32 = 6;
+    pub(super) const FIXED_LEN_BYTE_ARRAY: i32 = 7;
+}
+
+/// `ConvertedType` of the Parquet format.
+mod converted_type {
+    pub(super) const UTF8: i32 = 0;
+    pub(super) const UINT_64: i32 = 14;
+}
+
+/// `Encoding` of the Parquet format.
+mod encoding {
+    pub(super) const PLAIN: i32 = 0;
+    pub(super) const RLE: i32 = 3;
+}
+
+/// The type of a column of a Parquet file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParquetType {
+    /// `BOOLEAN`
+    Boolean,
+    /// `INT64` annotated as unsigned 64-bit integer.
+    UInt64,
+    /// `FIXED_LEN_BYTE_ARRAY` of the given length, e.g. hashes and addresses.
+    FixedBytes(usize),
+    /// `BYTE_ARRAY`
+    Bytes,
+    /// `BYTE_ARRAY` annotated as UTF-8 string, e.g. decimal `U256` values.
+    String,
+}
+
+impl ParquetType {
+    const fn physical_type(&self) -> i32 {
+        match self {
+            Self::Boolean => physical_type::BOOLEAN,
+            Self::UInt64 => physical_type::INT64,
+            Self::FixedBytes(_) => physical_type::FIXED_LEN_BYTE_ARRAY,
+            Self::Bytes | Self::String => physical_type::BYTE_ARRAY,
+        }
+    }
+
+    const fn converted_type(&self) -> Option<i32> {
+        match self {
+            Self::UInt64 => Some(converted_type::UINT_64),
+            Self::String => Some(converted_type::UTF8),
+            _ => None,
+        }
+    }
+}
+
+/// A column of a Parquet file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParquetColumn {
+    /// The name of the column.
+    pub name: &'static str,
+    /// The type of the column.
+    pub ty: ParquetType,
+    /// Whether the column can contain [`ParquetValue::Null`].
+    pub optional: bool,
+}
+
+impl ParquetColumn {
+    /// Creates a column that always has a value.
+    pub const fn required(name: &'static str, ty: ParquetType) -> Self {
+        Self { name, ty, optional: false }
+    }
+
+    /// Creates a column that can contain [`ParquetValue::Null`].
+    pub const fn optional(name: &'static str, ty: ParquetType) -> Self {
+        Self { name, ty, optional: true }
+    }
+}
+
+/// A value of a [`ParquetColumn`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParquetValue {
+    /// No value, only allowed in optional columns.
+    Null,
+    /// [`ParquetType::Boolean`]
+    Boolean(bool),
+    /// [`ParquetType::UInt64`]
+    UInt64(u64),
+    /// [`ParquetType::FixedBytes`] or [`ParquetType::Bytes`]
+    Bytes(Vec<u8>),
+    /// [`ParquetType::String`]
+    String(String),
+}
+
+/// A record that can be written to a Parquet file.
+pub trait ParquetRecord: Send + Sync {
+    /// The columns of the file, in the order of [`ParquetRecord::values`].
+    const COLUMNS: &'static [ParquetColumn];
+
+    /// Returns the values of the record.
+    fn values(&self) -> Vec<ParquetValue>;
+}
+
+impl ParquetRecord for BlockRecord {
+    const COLUMNS: &'static [ParquetColumn] = &[
+        ParquetColumn::required("number", ParquetType::UInt64),
+        ParquetColumn::required("hash", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("parent_hash", ParquetType::FixedBytes(32)),
+        ParquetColumn::required("timestamp", ParquetType::UInt64),
+        ParquetColumn::required("gas_used", ParquetType::UInt64),
+        ParquetColumn::required("transaction_count", ParquetType::UInt64),
+    ];
+
+    fn values(&self) -> Vec<ParquetValue> {
+        vec![
+            ParquetValue::UInt64(self.number),
+            ParquetValue::Bytes(self.hash.to_vec()),
+            ParquetValue::Bytes(self.parent_hash.to_vec()),
+            ParquetValue::UInt64(self.timestamp),
+            ParquetValue::UInt64(self.gas_used),
+            ParquetValue::UInt64(self.transaction_count),
+        ]
+    }
+}
+
+/// Metadata of a written column chunk.
+#[derive(Debug)]
+struct ColumnChunkMeta {
+    offset: u64,
+    size: u64,
+    num_values: u64,
+}
+
+/// Metadata of a written row group.
+#[derive(Debug)]
+struct RowGroupMeta {
+    columns: Vec<ColumnChunkMeta>,
+    num_rows: u64,
+}
+
+/// Writes [`ParquetRecord`]s into an uncompressed Parquet file.
+///
+/// Every row group is written as a single plain-encoded data page per column. The file is only
+/// readable after [`ParquetFileWriter::finish`] wrote the footer.
+#[derive(Debug)]
+pub struct ParquetFileWriter<W, R> {
+    writer: W,
+    offset: u64,
+    row_groups: Vec<RowGroupMeta>,
+    _record: PhantomData<fn(R)>,
+}
+
+impl<W: Write, R: ParquetRecord> ParquetFileWriter<W, R> {
+    /// Creates a new writer and writes the header of the file.
+    pub fn new(mut writer: W) -> eyre::Result<Self> {
+        writer.write_all(MAGIC)?;
+        Ok(Self {
+            writer,
+            offset: MAGIC.len() as u64,
+            row_groups: Vec::new(),
+            _record: PhantomData,
+        })
+    }
+
+    /// Returns the number of rows written so far.
+    pub fn num_rows(&self) -> u64 {
+        self.row_groups.iter().map(|row_group| row_group.num_rows).sum()
+    }
+
+    /// Writes the records as a new row group. Empty row groups are skipped.
+    pub fn write_records(&mut self, records: &[R]) -> eyre::Result<()> {
+        if records.is_empty() {
+            return Ok(())
+        }
+
+        let rows = records.iter().map(ParquetRecord::values).collect::<Vec<_>>();
+        let mut columns = Vec::with_capacity(R::COLUMNS.len());
+        for (index, column) in R::COLUMNS.iter().enumerate() {
+            let page = encode_page(column, rows.iter().map(|row| &row[index]))?;
+            self.writer.write_all(&page)?;
+            columns.push(ColumnChunkMeta {
+                offset: self.offset,
+                size: page.len() as u64,
+                num_values: rows.len() as u64,
+            });
+            self.offset += page.len() as u64;
+        }
+
+        self.row_groups.push(RowGroupMeta { columns, num_rows: rows.len() as u64 });
+        Ok(())
+    }
+
+    /// Writes the footer of the file and returns the underlying writer.
+    pub fn finish(mut self) -> eyre::Result<W> {
+        let metadata = self.file_metadata();
+        self.writer.write_all(&metadata)?;
+        self.writer.write_all(&(metadata.len() as u32).to_le_bytes())?;
+        self.writer.write_all(MAGIC)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// Encodes the `FileMetaData` of the file.
+    fn file_metadata(&self) -> Vec<u8> {
+        CompactWriter::encode(|writer| {
+            writer.i32_field(1, 1);
+            // the schema is a flat list of the root element followed by the columns
+            let schema =
+                std::iter::once(None).chain(R::COLUMNS.iter().map(Some)).collect::<Vec<_>>();
+            writer.struct_list_field(2, &schema, |writer, column| match column {
+                Some(column) => {
+                    writer.i32_field(1, column.ty.physical_type());
+                    if let ParquetType::FixedBytes(len) = column.ty {
+                        writer.i32_field(2, len as i32);
+                    }
+                    // required or optional
+                    writer.i32_field(3, column.optional as i32);
+                    writer.binary_field(4, column.name.as_bytes());
+                    if let Some(converted_type) = column.ty.converted_type() {
+                        writer.i32_field(6, converted_type);
+                    }
+                }
+                None => {
+                    writer.binary_field(4, b"schema");
+                    writer.i32_field(5, R::COLUMNS.len() as i32);
+                }
+            });
+            writer.i64_field(3, self.num_rows() as i64);
+            writer.struct_list_field(4, &self.row_groups, |writer, row_group| {
+                writer.struct_list_field(
+                    1,
+                    &row_group.columns.iter().zip(R::COLUMNS).collect::<Vec<_>>(),
+                    |writer, (chunk, column)| {
+                        writer.i64_field(2, chunk.offset as i64);
+                        writer.struct_field(3, |writer| {
+                            writer.i32_field(1, column.ty.physical_type());
+                            writer.i32_list_field(2, &[encoding::PLAIN, encoding::RLE]);
+                            writer.binary_list_field(3, &[column.name.as_bytes()]);
+                            // uncompressed
+                            writer.i32_field(4, 0);
+                            writer.i64_field(5, chunk.num_values as i64);
+                            writer.i64_field(6, chunk.size as i64);
+                            writer.i64_field(7, chunk.size as i64);
+                            writer.i64_field(9, chunk.offset as i64);
+                        });
+                    },
+                );
+                writer.i64_field(
+                    2,
+                    row_group.columns.iter().map(|chunk| chunk.size).sum::<u64>() as i64,
+                );
+                writer.i64_field(3, row_group.num_rows as i64);
+            });
+            writer.binary_field(6, b"reth");
+        })
+    }
+}
+
+impl<W: Write + Send, R: ParquetRecord> RowGroupWriter<R> for ParquetFileWriter<W, R> {
+    async fn write_row_group(&mut self, records: Vec<R>) -> eyre::Result<()> {
+        self.write_records(&records)
+    }
+}
+
+/// Encodes the values of a column chunk into a data page, including its header.
+fn encode_page<'a>(
+    column: &ParquetColumn,
+    values: impl ExactSizeIterator<Item = &'a ParquetValue>,
+) -> eyre::Result<Vec<u8>> {
+    let num_values = values.len();
+    let mut definition_levels = Vec::with_capacity(num_values);
+    let mut booleans = Vec::new();
+    let mut data = Vec::new();
+
+    for value in values {
+        definition_levels.push(!matches!(value, ParquetValue::Null));
+        match (column.ty, value) {
+            (_, ParquetValue::Null) if column.optional => {}
+            (ParquetType::Boolean, ParquetValue::Boolean(value)) => booleans.push(*value),
+            (ParquetType::UInt64, ParquetValue::UInt64(value)) => {
+                data.extend_from_slice(&value.to_le_bytes())
+            }
+            (ParquetType::FixedBytes(len), ParquetValue::Bytes(value)) if value.len() == len => {
+                data.extend_from_slice(value)
+            }
+            (ParquetType::Bytes, ParquetValue::Bytes(value)) => {
+                data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                data.extend_from_slice(value);
+            }
+            (ParquetType::String, ParquetValue::String(value)) => {
+                data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+                data.extend_from_slice(value.as_bytes());
+            }
+            (ty, value) => {
+                eyre::bail!("invalid value {value:?} for column {} of type {ty:?}", column.name)
+            }
+        }
+    }
+
+    // booleans are bit-packed, least significant bit first
+    for chunk in booleans.chunks(8) {
+        data.push(chunk.iter().rev().fold(0, |byte, value| (byte << 1) | *value as u8));
+    }
+
+    let mut page = Vec::with_capacity(data.len() + 4);
+    if column.optional {
+        let levels = encode_definition_levels(&definition_levels);
+        page.extend_from_slice(&(levels.len() as u32).to_le_bytes());
+        page.extend_from_slice(&levels);
+    }
+    page.extend_from_slice(&data);
+
+    let mut encoded = CompactWriter::encode(|writer| {
+        // data page
+        writer.i32_field(1, 0);
+        writer.i32_field(2, page.len() as i32);
+        writer.i32_field(3, page.len() as i32);
+        writer.struct_field(5, |writer| {
+            writer.i32_field(1, num_values as i32);
+            writer.i32_field(2, encoding::PLAIN);
+            writer.i32_field(3, encoding::RLE);
+            writer.i32_field(4, encoding::RLE);
+        });
+    });
+    encoded.extend_from_slice(&page);
+    Ok(encoded)
+}
+
+/// Encodes the definition levels of an optional column with the RLE encoding, as runs of equal
+/// levels with a bit width of 1.
+fn encode_definition_levels(levels: &[bool]) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    for run in levels.chunk_by(|a, b| a == b) {
+        write_uleb128(&mut encoded, (run.len() as u64) << 1);
+        encoded.push(run[0] as u8);
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Record(Option<bool>);
+
+    impl ParquetRecord for Record {
+        const COLUMNS: &'static [ParquetColumn] =
+            &[ParquetColumn::optional("flag", ParquetType::Boolean)];
+
+        fn values(&self) -> Vec<ParquetValue> {
+            vec![self.0.map_or(ParquetValue::Null, ParquetValue::Boolean)]
+        }
+    }
+
+    #[test]
+    fn encode_definition_levels_as_runs() {
+        assert_eq!(
+            encode_definition_levels(&[true, true, false, true]),
+            vec![0x04, 0x01, 0x02, 0x00, 0x02, 0x01]
+        );
+    }
+
+    #[test]
+    fn write_file() -> eyre::Result<()> {
+        let mut writer = ParquetFileWriter::new(Vec::new())?;
+        writer.write_records(&[Record(Some(true)), Record(None), Record(Some(false))])?;
+        writer.write_records(&[])?;
+        assert_eq!(writer.num_rows(), 3);
+        let file = writer.finish()?;
+
+        assert_eq!(&file[..4], MAGIC);
+        assert_eq!(&file[file.len() - 4..], MAGIC);
+        let metadata_len =
+            u32::from_le_bytes(file[file.len() - 8..file.len() - 4].try_into()?) as usize;
+        // FileMetaData starts with the version
+        assert_eq!(&file[file.len() - 8 - metadata_len..][..2], &[0x15, 0x02]);
+
+        // the page data follows the header: definition levels and the two bit-packed values
+        let page = &file[4..file.len() - 8 - metadata_len];
+        assert!(page.ends_with(&[0x06, 0x00, 0x00, 0x00, 0x02, 0x01, 0x02, 0x00, 0x02, 0x01, 0x01]));
+
+        Ok(())
+    }
+}
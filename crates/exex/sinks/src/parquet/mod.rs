@@ -0,0 +1,137 @@
+mod file;
+pub use file::{ParquetColumn, ParquetFileWriter, ParquetRecord, ParquetType, ParquetValue};
+
+mod thrift;
+
+use crate::{BlockBuffer, ChainMapper, Sink};
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumHash;
+use reth_execution_types::Chain;
+use reth_primitives_traits::NodePrimitives;
+use std::future::Future;
+
+/// A writer of row groups, e.g. of a Parquet file.
+pub trait RowGroupWriter<R>: Send {
+    /// Appends a row group with the given records.
+    fn write_row_group(&mut self, records: Vec<R>)
+        -> impl Future<Output = eyre::Result<()>> + Send;
+}
+
+/// A [`Sink`] that appends records to a [`RowGroupWriter`].
+///
+/// Row groups can't be removed once written, so records are only written once their block is at
+/// least `finality_depth` blocks below the tip. Reorgs deeper than that can't be handled and
+/// return an error.
+#[derive(Debug)]
+pub struct ParquetSink<W, M, R> {
+    writer: W,
+    mapper: M,
+    finality_depth: u64,
+    buffer: BlockBuffer<R>,
+    written: Option<BlockNumHash>,
+}
+
+impl<W, M, R> ParquetSink<W, M, R> {
+    /// Creates a new sink that writes records once their block is `finality_depth` blocks deep.
+    pub fn new(writer: W, mapper: M, finality_depth: u64) -> Self {
+        Self { writer, mapper, finality_depth, buffer: BlockBuffer::default(), written: None }
+    }
+
+    /// Returns the writer of the sink.
+    pub const fn writer(&self) -> &W {
+        &self.writer
+    }
+}
+
+impl<N, W, M> Sink<N> for ParquetSink<W, M, M::Record>
+where
+    N: NodePrimitives,
+    W: RowGroupWriter<M::Record>,
+    M: ChainMapper<N>,
+{
+    async fn revert(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        let first = chain.first().header().number();
+        if let Some(written) = self.written.filter(|written| written.number >= first) {
+            eyre::bail!(
+                "cannot revert block {first}, records up to block {} are already written",
+                written.number
+            )
+        }
+
+        self.buffer.revert_from(first);
+        Ok(())
+    }
+
+    async fn commit(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        for block in chain.blocks_iter() {
+            let records = self.mapper.map_block(chain, block);
+            self.buffer.insert(block.num_hash(), records);
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> eyre::Result<Option<BlockNumHash>> {
+        let Some(final_block) =
+            self.buffer.tip().and_then(|tip| tip.number.checked_sub(self.finality_depth))
+        else {
+            return Ok(None)
+        };
+
+        let (highest, records) = self.buffer.drain_to(final_block);
+        if highest.is_some() {
+            self.writer.write_row_group(records).await?;
+            self.written = highest;
+        }
+        Ok(highest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{chain, flush},
+        BlockMapper, BlockRecord,
+    };
+
+    #[derive(Debug, Default)]
+    struct MockWriter {
+        row_groups: Vec<Vec<BlockRecord>>,
+    }
+
+    impl RowGroupWriter<BlockRecord> for MockWriter {
+        async fn write_row_group(&mut self, records: Vec<BlockRecord>) -> eyre::Result<()> {
+            self.row_groups.push(records);
+            Ok(())
+        }
+    }
+
+    fn numbers(row_group: &[BlockRecord]) -> Vec<u64> {
+        row_group.iter().map(|record| record.number).collect()
+    }
+
+    #[tokio::test]
+    async fn writes_final_blocks() -> eyre::Result<()> {
+        let mut sink = ParquetSink::new(MockWriter::default(), BlockMapper, 2);
+
+        let old = chain(1..=4, None);
+        sink.commit(&old).await?;
+        assert_eq!(flush(&mut sink).await?, Some(old.blocks()[&2].num_hash()));
+        assert_eq!(numbers(&sink.writer().row_groups[0]), vec![1, 2]);
+
+        // reorg of the blocks that are not final yet
+        let reorged = chain(3..=4, None);
+        let new = chain(3..=5, Some(old.blocks()[&2].hash()));
+        sink.revert(&reorged).await?;
+        sink.commit(&new).await?;
+        assert_eq!(flush(&mut sink).await?, Some(new.blocks()[&3].num_hash()));
+        assert_eq!(numbers(&sink.writer().row_groups[1]), vec![3]);
+        assert_eq!(sink.writer().row_groups[1][0].hash, new.blocks()[&3].hash());
+
+        // reorg of written blocks fails
+        assert!(sink.revert(&new).await.is_err());
+
+        Ok(())
+    }
+}
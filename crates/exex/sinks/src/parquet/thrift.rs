@@ -0,0 +1,168 @@
+//! Encoder for the Thrift compact protocol, used for the metadata of Parquet files.
+//!
+//! See <https://github.com/apache/thrift/blob/master/doc/specs/thrift-compact-protocol.md>.
+
+use std::mem;
+
+/// Field type of a 32-bit integer.
+pub(super) const I32: u8 = 5;
+/// Field type of a 64-bit integer.
+pub(super) const I64: u8 = 6;
+/// Field type of a binary or string.
+pub(super) const BINARY: u8 = 8;
+/// Field type of a list.
+pub(super) const LIST: u8 = 9;
+/// Field type of a struct.
+pub(super) const STRUCT: u8 = 12;
+
+/// Writes Thrift structs in the compact protocol.
+#[derive(Debug, Default)]
+pub(super) struct CompactWriter {
+    buf: Vec<u8>,
+    /// The id of the last field written in the current struct.
+    last_field_id: i16,
+}
+
+impl CompactWriter {
+    /// Encodes a struct whose fields are written by the closure.
+    pub(super) fn encode(f: impl FnOnce(&mut Self)) -> Vec<u8> {
+        let mut writer = Self::default();
+        writer.write_struct(f);
+        writer.buf
+    }
+
+    /// Writes a 32-bit integer field.
+    pub(super) fn i32_field(&mut self, id: i16, value: i32) {
+        self.field_header(I32, id);
+        self.write_i32(value);
+    }
+
+    /// Writes a 64-bit integer field.
+    pub(super) fn i64_field(&mut self, id: i16, value: i64) {
+        self.field_header(I64, id);
+        self.write_varint(zigzag(value));
+    }
+
+    /// Writes a binary or string field.
+    pub(super) fn binary_field(&mut self, id: i16, value: &[u8]) {
+        self.field_header(BINARY, id);
+        self.write_binary(value);
+    }
+
+    /// Writes a struct field whose fields are written by the closure.
+    pub(super) fn struct_field(&mut self, id: i16, f: impl FnOnce(&mut Self)) {
+        self.field_header(STRUCT, id);
+        self.write_struct(f);
+    }
+
+    /// Writes a list field of 32-bit integers.
+    pub(super) fn i32_list_field(&mut self, id: i16, values: &[i32]) {
+        self.list_header(id, I32, values.len());
+        for value in values {
+            self.write_i32(*value);
+        }
+    }
+
+    /// Writes a list field of strings.
+    pub(super) fn binary_list_field(&mut self, id: i16, values: &[&[u8]]) {
+        self.list_header(id, BINARY, values.len());
+        for value in values {
+            self.write_binary(value);
+        }
+    }
+
+    /// Writes a list field of structs, each written by the closure.
+    pub(super) fn struct_list_field<T>(
+        &mut self,
+        id: i16,
+        values: &[T],
+        mut f: impl FnMut(&mut Self, &T),
+    ) {
+        self.list_header(id, STRUCT, values.len());
+        for value in values {
+            self.write_struct(|writer| f(writer, value));
+        }
+    }
+
+    fn write_struct(&mut self, f: impl FnOnce(&mut Self)) {
+        let last_field_id = mem::take(&mut self.last_field_id);
+        f(self);
+        // stop field
+        self.buf.push(0);
+        self.last_field_id = last_field_id;
+    }
+
+    fn field_header(&mut self, ty: u8, id: i16) {
+        let delta = id - self.last_field_id;
+        if (1..=15).contains(&delta) {
+            self.buf.push(((delta as u8) << 4) | ty);
+        } else {
+            self.buf.push(ty);
+            self.write_varint(zigzag(id as i64));
+        }
+        self.last_field_id = id;
+    }
+
+    fn list_header(&mut self, id: i16, element_ty: u8, len: usize) {
+        self.field_header(LIST, id);
+        if len < 15 {
+            self.buf.push(((len as u8) << 4) | element_ty);
+        } else {
+            self.buf.push(0xf0 | element_ty);
+            self.write_varint(len as u64);
+        }
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write_varint(zigzag(value as i64));
+    }
+
+    fn write_binary(&mut self, value: &[u8]) {
+        self.write_varint(value.len() as u64);
+        self.buf.extend_from_slice(value);
+    }
+
+    fn write_varint(&mut self, value: u64) {
+        write_uleb128(&mut self.buf, value);
+    }
+}
+
+/// Writes an unsigned LEB128 varint.
+pub(super) fn write_uleb128(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+const fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_compact() {
+        let encoded = CompactWriter::encode(|writer| {
+            writer.i32_field(1, -1);
+            writer.binary_field(2, b"ab");
+            writer.struct_field(20, |writer| writer.i64_field(1, 300));
+            writer.i32_list_field(21, &[0, 3]);
+        });
+
+        assert_eq!(
+            encoded,
+            vec![
+                0x15, 0x01, // field 1: i32 -1
+                0x18, 0x02, b'a', b'b', // field 2: binary "ab"
+                0x0c, 0x28, // field 20: struct, long form
+                0x16, 0xd8, 0x04, 0x00, // field 1: i64 300, stop
+                0x19, 0x25, 0x00, 0x06, // field 21: list<i32> [0, 3]
+                0x00, // stop
+            ]
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::{BlockBuffer, BlockRecord, ChainMapper, Sink};
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumHash;
+use reth_execution_types::Chain;
+use reth_primitives_traits::NodePrimitives;
+use std::future::Future;
+
+/// The maximum number of parameters of a single Postgres statement.
+const MAX_STATEMENT_PARAMS: usize = u16::MAX as usize;
+
+/// A value bound to a parameter of a SQL statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SqlValue {
+    /// `NULL`
+    Null,
+    /// `BIGINT`
+    BigInt(i64),
+    /// `NUMERIC`, formatted as a decimal string.
+    Numeric(String),
+    /// `TEXT`
+    Text(String),
+    /// `BYTEA`
+    Bytea(Vec<u8>),
+}
+
+/// A record that can be written to a Postgres table.
+pub trait PostgresRecord: Send + Sync {
+    /// The columns of the table, in the order of [`PostgresRecord::values`].
+    const COLUMNS: &'static [&'static str];
+
+    /// The columns of the primary key of the table, used to upsert records.
+    const KEY: &'static [&'static str];
+
+    /// The column that holds the block number of the record, used to delete reverted records.
+    const BLOCK_NUMBER: &'static str;
+
+    /// Returns the values of the record.
+    fn values(&self) -> Vec<SqlValue>;
+}
+
+impl PostgresRecord for BlockRecord {
+    const COLUMNS: &'static [&'static str] =
+        &["number", "hash", "parent_hash", "timestamp", "gas_used", "transaction_count"];
+    const KEY: &'static [&'static str] = &["number"];
+    const BLOCK_NUMBER: &'static str = "number";
+
+    fn values(&self) -> Vec<SqlValue> {
+        vec![
+            SqlValue::BigInt(self.number as i64),
+            SqlValue::Bytea(self.hash.to_vec()),
+            SqlValue::Bytea(self.parent_hash.to_vec()),
+            SqlValue::BigInt(self.timestamp as i64),
+            SqlValue::BigInt(self.gas_used as i64),
+            SqlValue::BigInt(self.transaction_count as i64),
+        ]
+    }
+}
+
+/// A Postgres client, e.g. a connection or a connection pool.
+pub trait PostgresClient: Send {
+    /// Executes the statement with the given parameters, returning the number of modified rows.
+    fn execute(
+        &mut self,
+        statement: &str,
+        params: &[SqlValue],
+    ) -> impl Future<Output = eyre::Result<u64>> + Send;
+}
+
+/// A [`Sink`] that writes records into a Postgres table.
+///
+/// Records are buffered until at least `batch_size` blocks are pending, and then written with
+/// upserts, so replaying blocks after a restart is idempotent. Reverted records are dropped from
+/// the buffer and deleted from the table, including records that were written before a restart.
+#[derive(Debug)]
+pub struct PostgresSink<C, M, R> {
+    client: C,
+    mapper: M,
+    table: String,
+    batch_size: usize,
+    buffer: BlockBuffer<R>,
+}
+
+impl<C, M, R> PostgresSink<C, M, R> {
+    /// Creates a new sink that writes into the given table and flushes after every notification.
+    pub fn new(client: C, mapper: M, table: impl Into<String>) -> Self {
+        Self { client, mapper, table: table.into(), batch_size: 1, buffer: BlockBuffer::default() }
+    }
+
+    /// Sets the minimum number of blocks to buffer before writing them.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Returns the client of the sink.
+    pub const fn client(&self) -> &C {
+        &self.client
+    }
+}
+
+impl<C, M, R> PostgresSink<C, M, R>
+where
+    R: PostgresRecord,
+{
+    /// Returns the statement that upserts `rows` records.
+    fn upsert_statement(&self, rows: usize) -> String {
+        let columns = R::COLUMNS;
+        let values = (0..rows)
+            .map(|row| {
+                let params = (1..=columns.len())
+                    .map(|column| format!("${}", row * columns.len() + column))
+                    .collect::<Vec<_>>();
+                format!("({})", params.join(", "))
+            })
+            .collect::<Vec<_>>();
+        let updates = columns
+            .iter()
+            .filter(|column| !R::KEY.contains(column))
+            .map(|column| format!("{column} = EXCLUDED.{column}"))
+            .collect::<Vec<_>>();
+        let on_conflict = if updates.is_empty() {
+            "DO NOTHING".to_string()
+        } else {
+            format!("DO UPDATE SET {}", updates.join(", "))
+        };
+
+        format!(
+            "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) {}",
+            self.table,
+            columns.join(", "),
+            values.join(", "),
+            R::KEY.join(", "),
+            on_conflict
+        )
+    }
+}
+
+impl<N, C, M> Sink<N> for PostgresSink<C, M, M::Record>
+where
+    N: NodePrimitives,
+    C: PostgresClient,
+    M: ChainMapper<N>,
+    M::Record: PostgresRecord,
+{
+    async fn revert(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        let first = chain.first().header().number();
+        self.buffer.revert_from(first);
+
+        // the table may hold records of reverted blocks that were written before a restart, so
+        // they are always deleted
+        let statement =
+            format!("DELETE FROM {} WHERE {} >= $1", self.table, M::Record::BLOCK_NUMBER);
+        self.client.execute(&statement, &[SqlValue::BigInt(first as i64)]).await?;
+
+        Ok(())
+    }
+
+    async fn commit(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        for block in chain.blocks_iter() {
+            let records = self.mapper.map_block(chain, block);
+            self.buffer.insert(block.num_hash(), records);
+        }
+
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> eyre::Result<Option<BlockNumHash>> {
+        if self.buffer.len() < self.batch_size {
+            return Ok(None)
+        }
+
+        let (highest, records) = self.buffer.drain();
+        let rows_per_statement = (MAX_STATEMENT_PARAMS / M::Record::COLUMNS.len()).max(1);
+        for chunk in records.chunks(rows_per_statement) {
+            let statement = self.upsert_statement(chunk.len());
+            let params = chunk.iter().flat_map(PostgresRecord::values).collect::<Vec<_>>();
+            self.client.execute(&statement, &params).await?;
+        }
+
+        Ok(highest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{chain, flush},
+        BlockMapper,
+    };
+
+    #[derive(Debug, Default)]
+    struct MockClient {
+        statements: Vec<(String, Vec<SqlValue>)>,
+    }
+
+    impl PostgresClient for MockClient {
+        async fn execute(&mut self, statement: &str, params: &[SqlValue]) -> eyre::Result<u64> {
+            self.statements.push((statement.to_string(), params.to_vec()));
+            Ok(0)
+        }
+    }
+
+    #[tokio::test]
+    async fn batched_upserts() -> eyre::Result<()> {
+        let mut sink =
+            PostgresSink::new(MockClient::default(), BlockMapper, "blocks").with_batch_size(3);
+
+        let (first, second) = (chain(1..=2, None), chain(3..=3, None));
+        sink.commit(&first).await?;
+        assert_eq!(flush(&mut sink).await?, None);
+        sink.commit(&second).await?;
+        assert_eq!(flush(&mut sink).await?, Some(second.tip().num_hash()));
+
+        let [(statement, params)] = sink.client().statements.as_slice() else {
+            panic!("expected a single statement")
+        };
+        assert_eq!(
+            statement,
+            "INSERT INTO blocks (number, hash, parent_hash, timestamp, gas_used, \
+             transaction_count) VALUES ($1, $2, $3, $4, $5, $6), ($7, $8, $9, $10, $11, $12), \
+             ($13, $14, $15, $16, $17, $18) ON CONFLICT (number) DO UPDATE SET hash = \
+             EXCLUDED.hash, parent_hash = EXCLUDED.parent_hash, timestamp = EXCLUDED.timestamp, \
+             gas_used = EXCLUDED.gas_used, transaction_count = EXCLUDED.transaction_count"
+        );
+        assert_eq!(params.len(), 18);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reorg() -> eyre::Result<()> {
+        let mut sink = PostgresSink::new(MockClient::default(), BlockMapper, "blocks");
+
+        // persisted blocks are deleted
+        let old = chain(1..=2, None);
+        sink.commit(&old).await?;
+        flush(&mut sink).await?;
+        sink.revert(&old).await?;
+        assert_eq!(
+            sink.client().statements.last().unwrap(),
+            &("DELETE FROM blocks WHERE number >= $1".to_string(), vec![SqlValue::BigInt(1)])
+        );
+
+        // buffered blocks are dropped
+        let mut sink = sink.with_batch_size(2);
+        let (new, reorged) = (chain(1..=1, None), chain(2..=2, None));
+        sink.commit(&new).await?;
+        sink.commit(&reorged).await?;
+        sink.revert(&reorged).await?;
+        assert_eq!(flush(&mut sink).await?, None);
+
+        // blocks that were written before a restart are deleted as well
+        let mut sink = PostgresSink::new(MockClient::default(), BlockMapper, "blocks");
+        sink.revert(&chain(5..=6, None)).await?;
+        assert_eq!(
+            sink.client().statements,
+            vec![("DELETE FROM blocks WHERE number >= $1".to_string(), vec![SqlValue::BigInt(5)])]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,459 @@
+use crate::Sink;
+use alloy_consensus::{BlockHeader, TxReceipt};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{BlockHash, BlockNumber, Log, TxHash};
+use reth_execution_types::Chain;
+use reth_primitives::SealedBlockWithSenders;
+use reth_primitives_traits::{NodePrimitives, SignedTransaction};
+use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+};
+
+/// A transport that delivers published chain events, e.g. a Kafka producer or a NATS connection.
+pub trait EventTransport: Send {
+    /// Publishes an event with the given key.
+    ///
+    /// The event doesn't need to be delivered before [`EventTransport::flush`] is called.
+    fn publish(
+        &mut self,
+        key: &[u8],
+        payload: Vec<u8>,
+    ) -> impl Future<Output = eyre::Result<()>> + Send;
+
+    /// Waits until all published events have been acknowledged by the receiver.
+    fn flush(&mut self) -> impl Future<Output = eyre::Result<()>> + Send;
+}
+
+/// The level of detail of published [`ChainEvent`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EventDetail {
+    /// Only the block header fields.
+    #[default]
+    Headers,
+    /// Block header fields and transaction hashes.
+    Transactions,
+    /// Block header fields, transaction hashes and receipts.
+    Receipts,
+}
+
+/// The encoding of published [`ChainEvent`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EventEncoding {
+    /// JSON, see the serde implementation of [`ChainEvent`].
+    #[default]
+    Json,
+    /// Protobuf, see [`ChainEvent::encode_protobuf`].
+    Protobuf,
+}
+
+/// The kind of a [`ChainEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainEventKind {
+    /// The block was committed to the canonical chain.
+    Commit,
+    /// The block was reverted from the canonical chain.
+    Revert,
+}
+
+/// An event for a block that was committed to or reverted from the canonical chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainEvent {
+    /// Whether the block was committed or reverted.
+    pub kind: ChainEventKind,
+    /// Block number.
+    pub number: BlockNumber,
+    /// Block hash.
+    pub hash: BlockHash,
+    /// Hash of the parent block.
+    pub parent_hash: BlockHash,
+    /// Block timestamp.
+    pub timestamp: u64,
+    /// Gas used by all transactions of the block.
+    pub gas_used: u64,
+    /// Transaction hashes, if the detail is at least [`EventDetail::Transactions`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub transactions: Vec<TxHash>,
+    /// Receipts, if the detail is [`EventDetail::Receipts`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub receipts: Vec<ReceiptEvent>,
+}
+
+/// A receipt of a [`ChainEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptEvent {
+    /// Whether the transaction was successful.
+    pub success: bool,
+    /// Gas used by the transaction and all previous transactions of the block.
+    pub cumulative_gas_used: u64,
+    /// Logs emitted by the transaction.
+    pub logs: Vec<Log>,
+}
+
+impl ChainEvent {
+    /// Creates the event for a block of the chain with the given detail.
+    pub fn new<N>(
+        kind: ChainEventKind,
+        chain: &Chain<N>,
+        block: &SealedBlockWithSenders<N::Block>,
+        detail: EventDetail,
+    ) -> Self
+    where
+        N: NodePrimitives<SignedTx: SignedTransaction>,
+    {
+        let transactions = if detail >= EventDetail::Transactions {
+            block.transactions().iter().map(|tx| *tx.tx_hash()).collect()
+        } else {
+            Vec::new()
+        };
+        let receipts = if detail >= EventDetail::Receipts {
+            chain
+                .receipts_by_block_hash(block.hash())
+                .unwrap_or_default()
+                .into_iter()
+                .map(|receipt| ReceiptEvent {
+                    success: receipt.status(),
+                    cumulative_gas_used: receipt.cumulative_gas_used() as u64,
+                    logs: receipt.logs().to_vec(),
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        Self {
+            kind,
+            number: block.header().number(),
+            hash: block.hash(),
+            parent_hash: block.header().parent_hash(),
+            timestamp: block.header().timestamp(),
+            gas_used: block.header().gas_used(),
+            transactions,
+            receipts,
+        }
+    }
+
+    /// Encodes the event with the given encoding.
+    pub fn encode(&self, encoding: EventEncoding) -> eyre::Result<Vec<u8>> {
+        Ok(match encoding {
+            EventEncoding::Json => serde_json::to_vec(self)?,
+            EventEncoding::Protobuf => self.encode_protobuf(),
+        })
+    }
+
+    /// Encodes the event as protobuf, using the following schema:
+    ///
+    /// ```protobuf
+    /// message ChainEvent {
+    ///   enum Kind { COMMIT = 0; REVERT = 1; }
+    ///   Kind kind = 1;
+    ///   uint64 number = 2;
+    ///   bytes hash = 3;
+    ///   bytes parent_hash = 4;
+    ///   uint64 timestamp = 5;
+    ///   uint64 gas_used = 6;
+    ///   repeated bytes transactions = 7;
+    ///   repeated Receipt receipts = 8;
+    /// }
+    ///
+    /// message Receipt {
+    ///   bool success = 1;
+    ///   uint64 cumulative_gas_used = 2;
+    ///   repeated Log logs = 3;
+    /// }
+    ///
+    /// message Log {
+    ///   bytes address = 1;
+    ///   repeated bytes topics = 2;
+    ///   bytes data = 3;
+    /// }
+    /// ```
+    pub fn encode_protobuf(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        protobuf::uint64(&mut buf, 1, matches!(self.kind, ChainEventKind::Revert) as u64);
+        protobuf::uint64(&mut buf, 2, self.number);
+        protobuf::bytes(&mut buf, 3, self.hash.as_slice());
+        protobuf::bytes(&mut buf, 4, self.parent_hash.as_slice());
+        protobuf::uint64(&mut buf, 5, self.timestamp);
+        protobuf::uint64(&mut buf, 6, self.gas_used);
+        for tx in &self.transactions {
+            protobuf::element(&mut buf, 7, tx.as_slice());
+        }
+        for receipt in &self.receipts {
+            let mut receipt_buf = Vec::new();
+            protobuf::uint64(&mut receipt_buf, 1, receipt.success as u64);
+            protobuf::uint64(&mut receipt_buf, 2, receipt.cumulative_gas_used);
+            for log in &receipt.logs {
+                let mut log_buf = Vec::new();
+                protobuf::bytes(&mut log_buf, 1, log.address.as_slice());
+                for topic in log.topics() {
+                    protobuf::element(&mut log_buf, 2, topic.as_slice());
+                }
+                protobuf::bytes(&mut log_buf, 3, &log.data.data);
+                protobuf::element(&mut receipt_buf, 3, &log_buf);
+            }
+            protobuf::element(&mut buf, 8, &receipt_buf);
+        }
+        buf
+    }
+}
+
+/// Minimal protobuf encoding helpers. Default values are omitted, as in proto3.
+mod protobuf {
+    fn varint(buf: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        buf.push(value as u8);
+    }
+
+    pub(super) fn uint64(buf: &mut Vec<u8>, field: u64, value: u64) {
+        if value != 0 {
+            varint(buf, field << 3);
+            varint(buf, value);
+        }
+    }
+
+    pub(super) fn bytes(buf: &mut Vec<u8>, field: u64, value: &[u8]) {
+        if !value.is_empty() {
+            element(buf, field, value);
+        }
+    }
+
+    /// Encodes an element of a repeated field, which is never omitted.
+    pub(super) fn element(buf: &mut Vec<u8>, field: u64, value: &[u8]) {
+        varint(buf, (field << 3) | 2);
+        varint(buf, value.len() as u64);
+        buf.extend_from_slice(value);
+    }
+}
+
+/// The last block whose events have been acknowledged by the transport.
+#[derive(Debug, Serialize, Deserialize)]
+struct Cursor {
+    number: BlockNumber,
+    hash: BlockHash,
+}
+
+/// Reads the block of the resume cursor that is written by the [`ChainEventPublisher`].
+///
+/// Returns `None` if the cursor file doesn't exist yet.
+pub fn read_cursor(path: &Path) -> eyre::Result<Option<BlockNumHash>> {
+    match std::fs::read(path) {
+        Ok(cursor) => {
+            let Cursor { number, hash } = serde_json::from_slice(&cursor)?;
+            Ok(Some(BlockNumHash::new(number, hash)))
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// A [`Sink`] that publishes a [`ChainEvent`] for every committed and reverted block.
+///
+/// Events are keyed by the big-endian block number. Delivery is at-least-once: after the
+/// transport acknowledged all events of a notification, the tip is written to the resume cursor
+/// (see [`read_cursor`]). After a restart, all events after the cursor are published again.
+#[derive(Debug)]
+pub struct ChainEventPublisher<T> {
+    transport: T,
+    detail: EventDetail,
+    encoding: EventEncoding,
+    cursor: Option<PathBuf>,
+    tip: Option<BlockNumHash>,
+    unflushed: bool,
+}
+
+impl<T> ChainEventPublisher<T> {
+    /// Creates a new publisher that publishes [`EventDetail::Headers`] as JSON.
+    pub const fn new(transport: T) -> Self {
+        Self {
+            transport,
+            detail: EventDetail::Headers,
+            encoding: EventEncoding::Json,
+            cursor: None,
+            tip: None,
+            unflushed: false,
+        }
+    }
+
+    /// Sets the detail of the published events.
+    pub const fn with_detail(mut self, detail: EventDetail) -> Self {
+        self.detail = detail;
+        self
+    }
+
+    /// Sets the encoding of the published events.
+    pub const fn with_encoding(mut self, encoding: EventEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Sets the path of the resume cursor file.
+    pub fn with_cursor(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cursor = Some(path.into());
+        self
+    }
+
+    /// Returns the transport of the publisher.
+    pub const fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    async fn write_cursor(&self, tip: BlockNumHash) -> eyre::Result<()> {
+        let Some(path) = &self.cursor else { return Ok(()) };
+
+        // write to a temporary file first, so the cursor is never corrupted
+        let tmp = path.with_extension("tmp");
+        let cursor = serde_json::to_vec(&Cursor { number: tip.number, hash: tip.hash })?;
+        tokio::fs::write(&tmp, cursor).await?;
+        tokio::fs::rename(tmp, path).await?;
+        Ok(())
+    }
+}
+
+impl<T: EventTransport> ChainEventPublisher<T> {
+    async fn publish(&mut self, event: ChainEvent) -> eyre::Result<()> {
+        let payload = event.encode(self.encoding)?;
+        self.transport.publish(&event.number.to_be_bytes(), payload).await?;
+        self.unflushed = true;
+        Ok(())
+    }
+}
+
+impl<N, T> Sink<N> for ChainEventPublisher<T>
+where
+    N: NodePrimitives<SignedTx: SignedTransaction>,
+    T: EventTransport,
+{
+    async fn revert(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        for block in chain.blocks().values().rev() {
+            self.publish(ChainEvent::new(ChainEventKind::Revert, chain, block, self.detail))
+                .await?;
+        }
+
+        let first = chain.first();
+        self.tip = first
+            .header()
+            .number()
+            .checked_sub(1)
+            .map(|number| BlockNumHash::new(number, first.header().parent_hash()));
+        Ok(())
+    }
+
+    async fn commit(&mut self, chain: &Chain<N>) -> eyre::Result<()> {
+        for block in chain.blocks_iter() {
+            self.publish(ChainEvent::new(ChainEventKind::Commit, chain, block, self.detail))
+                .await?;
+        }
+
+        self.tip = Some(chain.tip().num_hash());
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> eyre::Result<Option<BlockNumHash>> {
+        if !self.unflushed {
+            return Ok(None)
+        }
+
+        self.transport.flush().await?;
+        self.unflushed = false;
+        if let Some(tip) = self.tip {
+            self.write_cursor(tip).await?;
+        }
+        Ok(self.tip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{chain, flush};
+
+    #[derive(Debug, Default)]
+    struct MockTransport {
+        pending: Vec<ChainEvent>,
+        acknowledged: Vec<ChainEvent>,
+    }
+
+    impl EventTransport for MockTransport {
+        async fn publish(&mut self, key: &[u8], payload: Vec<u8>) -> eyre::Result<()> {
+            let event: ChainEvent = serde_json::from_slice(&payload)?;
+            assert_eq!(key, event.number.to_be_bytes());
+            self.pending.push(event);
+            Ok(())
+        }
+
+        async fn flush(&mut self) -> eyre::Result<()> {
+            self.acknowledged.append(&mut self.pending);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn publishes_with_cursor() -> eyre::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cursor = dir.path().join("cursor.json");
+        assert_eq!(read_cursor(&cursor)?, None);
+
+        let mut publisher = ChainEventPublisher::new(MockTransport::default())
+            .with_detail(EventDetail::Transactions)
+            .with_cursor(&cursor);
+
+        let old = chain(1..=2, None);
+        publisher.commit(&old).await?;
+        assert_eq!(flush(&mut publisher).await?, Some(old.tip().num_hash()));
+        assert_eq!(read_cursor(&cursor)?, Some(old.tip().num_hash()));
+
+        let new = chain(2..=2, Some(old.tip().parent_hash));
+        let reverted: Chain = Chain::new([old.tip().clone()], Default::default(), None);
+        publisher.revert(&reverted).await?;
+        publisher.commit(&new).await?;
+        assert_eq!(flush(&mut publisher).await?, Some(new.tip().num_hash()));
+        assert_eq!(read_cursor(&cursor)?, Some(new.tip().num_hash()));
+        assert_eq!(flush(&mut publisher).await?, None);
+
+        let events = &publisher.transport().acknowledged;
+        assert_eq!(
+            events.iter().map(|event| (event.kind, event.number)).collect::<Vec<_>>(),
+            vec![
+                (ChainEventKind::Commit, 1),
+                (ChainEventKind::Commit, 2),
+                (ChainEventKind::Revert, 2),
+                (ChainEventKind::Commit, 2),
+            ]
+        );
+        assert_eq!(events[1].hash, old.tip().hash());
+        assert_eq!(events[3].hash, new.tip().hash());
+        assert_eq!(
+            events[3].transactions,
+            new.tip().transactions().iter().map(|tx| tx.hash()).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn protobuf_encoding() {
+        let event = ChainEvent {
+            kind: ChainEventKind::Revert,
+            number: 300,
+            hash: BlockHash::repeat_byte(1),
+            parent_hash: BlockHash::ZERO,
+            timestamp: 0,
+            gas_used: 1,
+            transactions: vec![],
+            receipts: vec![ReceiptEvent { success: true, cumulative_gas_used: 0, logs: vec![] }],
+        };
+
+        let mut expected = vec![0x08, 0x01, 0x10, 0xac, 0x02, 0x1a, 0x20];
+        expected.extend_from_slice(&[1; 32]);
+        expected.extend_from_slice(&[0x22, 0x20]);
+        expected.extend_from_slice(&[0; 32]);
+        expected.extend_from_slice(&[0x30, 0x01, 0x42, 0x02, 0x08, 0x01]);
+        assert_eq!(event.encode_protobuf(), expected);
+    }
+}
@@ -0,0 +1,62 @@
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{BlockHash, BlockNumber};
+use reth_execution_types::Chain;
+use reth_primitives::SealedBlockWithSenders;
+use reth_primitives_traits::NodePrimitives;
+use serde::{Deserialize, Serialize};
+
+/// Maps the blocks of a [`Chain`] into records that are written to a sink.
+pub trait ChainMapper<N: NodePrimitives>: Send + Sync {
+    /// The record type.
+    type Record: Send + Sync;
+
+    /// Maps a single block of the chain into records.
+    ///
+    /// The chain can be used to access the receipts and state changes of the block.
+    fn map_block(
+        &self,
+        chain: &Chain<N>,
+        block: &SealedBlockWithSenders<N::Block>,
+    ) -> Vec<Self::Record>;
+}
+
+/// A [`ChainMapper`] that maps every block into a single [`BlockRecord`].
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct BlockMapper;
+
+impl<N: NodePrimitives> ChainMapper<N> for BlockMapper {
+    type Record = BlockRecord;
+
+    fn map_block(
+        &self,
+        _chain: &Chain<N>,
+        block: &SealedBlockWithSenders<N::Block>,
+    ) -> Vec<Self::Record> {
+        vec![BlockRecord {
+            number: block.header().number(),
+            hash: block.hash(),
+            parent_hash: block.header().parent_hash(),
+            timestamp: block.header().timestamp(),
+            gas_used: block.header().gas_used(),
+            transaction_count: block.transactions().len() as u64,
+        }]
+    }
+}
+
+/// A summary of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockRecord {
+    /// Block number.
+    pub number: BlockNumber,
+    /// Block hash.
+    pub hash: BlockHash,
+    /// Hash of the parent block.
+    pub parent_hash: BlockHash,
+    /// Block timestamp.
+    pub timestamp: u64,
+    /// Gas used by all transactions of the block.
+    pub gas_used: u64,
+    /// Number of transactions in the block.
+    pub transaction_count: u64,
+}
@@ -11,10 +11,12 @@
 mod finished_height;
 mod head;
 mod notification;
+mod status;
 
 pub use finished_height::FinishedExExHeight;
 pub use head::ExExHead;
 pub use notification::ExExNotification;
+pub use status::{ExExManagerStatus, ExExStatus, SharedExExStatus};
 
 /// Bincode-compatible serde implementations for commonly used ExEx types.
 ///
@@ -0,0 +1,112 @@
+use crate::FinishedExExHeight;
+use alloy_eips::BlockNumHash;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// The status of a single `ExEx`, as tracked by the `ExEx` manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExExStatus {
+    /// The ID of the `ExEx`.
+    pub id: String,
+    /// The head of the last notification that was delivered to the `ExEx`.
+    ///
+    /// If this is `None`, no notification was delivered to the `ExEx` yet.
+    pub head: Option<BlockNumHash>,
+    /// The finished height of the `ExEx`.
+    ///
+    /// If this is `None`, the `ExEx` has not emitted a `FinishedHeight` event.
+    pub finished_height: Option<BlockNumHash>,
+    /// The number of buffered notifications that were not delivered to the `ExEx` yet.
+    pub pending_notifications: usize,
+}
+
+/// The status of the `ExEx` manager and all installed `ExEx`'s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExExManagerStatus {
+    /// The status of each installed `ExEx`.
+    pub exexes: Vec<ExExStatus>,
+    /// The head of the last notification that was received by the manager.
+    pub head: Option<BlockNumHash>,
+    /// The number of notifications in the internal buffer of the manager.
+    pub buffered_notifications: usize,
+    /// The number of blocks in the Write-Ahead Log.
+    pub wal_blocks: usize,
+    /// The size of the Write-Ahead Log in bytes.
+    pub wal_size_bytes: u64,
+    /// The lowest finished height among all `ExEx`'s.
+    pub finished_height: FinishedExExHeight,
+}
+
+impl Default for ExExManagerStatus {
+    fn default() -> Self {
+        Self {
+            exexes: Vec::new(),
+            head: None,
+            buffered_notifications: 0,
+            wal_blocks: 0,
+            wal_size_bytes: 0,
+            finished_height: FinishedExExHeight::NoExExs,
+        }
+    }
+}
+
+impl ExExManagerStatus {
+    /// Returns the number of blocks the given `ExEx` is behind the head of the manager, i.e. the
+    /// number of blocks that it has not finished yet.
+    ///
+    /// Returns `None` if the manager didn't receive a notification yet, or the `ExEx` has not
+    /// emitted a `FinishedHeight` event.
+    pub fn lag(&self, exex: &ExExStatus) -> Option<u64> {
+        let head = self.head?;
+        let finished_height = exex.finished_height?;
+        Some(head.number.saturating_sub(finished_height.number))
+    }
+}
+
+/// The latest [`ExExManagerStatus`], shared between the `ExEx` manager and its observers, e.g. the
+/// RPC.
+///
+/// The manager updates the status whenever it is polled.
+#[derive(Debug, Clone, Default)]
+pub struct SharedExExStatus {
+    inner: Arc<RwLock<ExExManagerStatus>>,
+}
+
+impl SharedExExStatus {
+    /// Returns the latest status.
+    pub fn get(&self) -> ExExManagerStatus {
+        self.inner.read().clone()
+    }
+
+    /// Replaces the status.
+    pub fn set(&self, status: ExExManagerStatus) {
+        *self.inner.write() = status;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    #[test]
+    fn exex_lag() {
+        let exex = ExExStatus {
+            id: "exex".to_string(),
+            head: Some(BlockNumHash::new(8, B256::with_last_byte(8))),
+            finished_height: Some(BlockNumHash::new(7, B256::with_last_byte(7))),
+            pending_notifications: 2,
+        };
+        let mut status = ExExManagerStatus { exexes: vec![exex.clone()], ..Default::default() };
+        assert_eq!(status.lag(&exex), None);
+
+        status.head = Some(BlockNumHash::new(10, B256::with_last_byte(10)));
+        assert_eq!(status.lag(&exex), Some(3));
+        assert_eq!(status.lag(&ExExStatus { finished_height: None, ..exex }), None);
+
+        let shared = SharedExExStatus::default();
+        assert_eq!(shared.get(), ExExManagerStatus::default());
+        shared.set(status.clone());
+        assert_eq!(shared.get(), status);
+    }
+}
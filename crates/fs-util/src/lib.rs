@@ -297,6 +297,15 @@ pub fn write_json_file<T: Serialize>(path: &Path, obj: &T) -> Result<()> {
     writer.flush().map_err(|e| FsPathError::write(e, path))
 }
 
+/// Writes the object as a JSON object, creating the parent directories of the file if they don't
+/// exist yet.
+pub fn write_json_file_all<T: Serialize>(path: &Path, obj: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write_json_file(path, obj)
+}
+
 /// Writes atomically to file.
 ///
 /// 1. Creates a temporary file with a `.tmp` extension in the same file directory.
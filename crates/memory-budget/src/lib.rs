@@ -0,0 +1,299 @@
+//! Node-wide memory budget shared by the major caches of the node.
+//!
+//! Caches like the transaction pool, the engine block buffer, the RPC state cache and the body
+//! downloader buffer are bounded by independent limits, which collectively can exceed the memory of
+//! the machine. If they share a limited [`MemoryBudget`], these caches register as
+//! [`MemoryConsumer`]s with their configured capacity, and their limits are shrunk proportionally
+//! so that their combined capacity fits into the budget. If the combined usage exceeds the budget
+//! regardless, the limits are shrunk further in proportion to the usage of each consumer.
+//!
+//! The budget is created once by the node and passed to the caches through their configs. With an
+//! [unlimited](MemoryBudget::unlimited) budget, which is the default, all consumers may use their
+//! full capacity.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod metrics;
+
+use crate::metrics::{MemoryBudgetMetrics, MemoryConsumerMetrics};
+use parking_lot::Mutex;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Weak,
+    },
+    time::Duration,
+};
+use tracing::debug;
+
+/// The default interval at which the limits of the consumers are recomputed, see
+/// [`MemoryBudget::run`].
+pub const DEFAULT_REBALANCE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// A memory budget that is shared by multiple [`MemoryConsumer`]s.
+///
+/// Clones share the same budget.
+#[derive(Debug, Clone)]
+pub struct MemoryBudget {
+    inner: Arc<MemoryBudgetInner>,
+}
+
+#[derive(Debug)]
+struct MemoryBudgetInner {
+    /// The maximum number of bytes of all consumers, `None` if unlimited.
+    max_bytes: Option<usize>,
+    /// All registered consumers, dropped consumers are removed on the next rebalance.
+    consumers: Mutex<Vec<Weak<ConsumerState>>>,
+    metrics: MemoryBudgetMetrics,
+}
+
+impl MemoryBudget {
+    /// Creates a new budget for the given number of bytes.
+    pub fn new(max_bytes: usize) -> Self {
+        let metrics = MemoryBudgetMetrics::default();
+        metrics.max_bytes.set(max_bytes as f64);
+        Self::with_max_bytes(Some(max_bytes), metrics)
+    }
+
+    /// Creates a budget without a limit, all consumers may use their full capacity.
+    pub fn unlimited() -> Self {
+        Self::with_max_bytes(None, MemoryBudgetMetrics::default())
+    }
+
+    fn with_max_bytes(max_bytes: Option<usize>, metrics: MemoryBudgetMetrics) -> Self {
+        Self {
+            inner: Arc::new(MemoryBudgetInner {
+                max_bytes,
+                consumers: Default::default(),
+                metrics,
+            }),
+        }
+    }
+
+    /// Returns the maximum number of bytes of all consumers, `None` if unlimited.
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.inner.max_bytes
+    }
+
+    /// Registers a new consumer with the given capacity in bytes.
+    ///
+    /// The capacity is the limit of the consumer without a budget, e.g. the configured maximum
+    /// size of a cache.
+    pub fn register(&self, name: &'static str, capacity: usize) -> MemoryConsumer {
+        let state = Arc::new(ConsumerState {
+            name,
+            capacity,
+            usage: AtomicUsize::new(0),
+            limit: AtomicUsize::new(capacity),
+            metrics: MemoryConsumerMetrics::new_with_labels(&[("consumer", name)]),
+        });
+        state.metrics.capacity_bytes.set(capacity as f64);
+        state.metrics.limit_bytes.set(capacity as f64);
+
+        // without a limit there's nothing to balance
+        if self.inner.max_bytes.is_some() {
+            self.inner.consumers.lock().push(Arc::downgrade(&state));
+            self.rebalance();
+        }
+
+        MemoryConsumer { state }
+    }
+
+    /// Returns the combined usage of all consumers in bytes.
+    pub fn usage(&self) -> usize {
+        self.inner
+            .consumers
+            .lock()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|consumer| consumer.usage.load(Ordering::Relaxed))
+            .fold(0, usize::saturating_add)
+    }
+
+    /// Recomputes the limits of all consumers from their capacity and current usage.
+    pub fn rebalance(&self) {
+        let Some(max_bytes) = self.inner.max_bytes else { return };
+
+        let mut consumers = self.inner.consumers.lock();
+        consumers.retain(|consumer| consumer.strong_count() > 0);
+        let consumers = consumers.iter().filter_map(Weak::upgrade).collect::<Vec<_>>();
+
+        let demands = consumers
+            .iter()
+            .map(|consumer| (consumer.capacity, consumer.usage.load(Ordering::Relaxed)))
+            .collect::<Vec<_>>();
+        let limits = compute_limits(max_bytes, &demands);
+
+        for (consumer, limit) in consumers.iter().zip(limits) {
+            consumer.set_limit(limit);
+        }
+
+        let usage = demands.iter().map(|(_, usage)| *usage).fold(0, usize::saturating_add);
+        self.inner.metrics.usage_bytes.set(usage as f64);
+        self.inner.metrics.consumers.set(consumers.len() as f64);
+        debug!(target: "memory_budget", max_bytes, usage, consumers = consumers.len(), "Rebalanced memory budget");
+    }
+
+    /// Rebalances the budget at the given interval, so that the limits follow the usage of the
+    /// consumers.
+    ///
+    /// This is an endless future that should be spawned.
+    pub async fn run(self, interval: Duration) {
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            self.rebalance();
+        }
+    }
+}
+
+impl Default for MemoryBudget {
+    fn default() -> Self {
+        Self::unlimited()
+    }
+}
+
+/// Computes the limits of consumers with the given `(capacity, usage)` for the budget.
+///
+/// The capacities are scaled down proportionally if they don't fit into the budget. If the
+/// combined usage exceeds the budget, the limits are additionally capped to the share of the
+/// budget proportional to the usage of each consumer.
+fn compute_limits(max_bytes: usize, demands: &[(usize, usize)]) -> Vec<usize> {
+    let capacity = demands.iter().map(|(capacity, _)| *capacity as u128).sum::<u128>();
+    let usage = demands.iter().map(|(_, usage)| *usage as u128).sum::<u128>();
+    let max_bytes = max_bytes as u128;
+
+    let share = |value: usize, total: u128| (value as u128 * max_bytes / total.max(1)) as usize;
+
+    demands
+        .iter()
+        .map(|(consumer_capacity, consumer_usage)| {
+            let mut limit = if capacity <= max_bytes {
+                *consumer_capacity
+            } else {
+                share(*consumer_capacity, capacity)
+            };
+            if usage > max_bytes {
+                limit = limit.min(share(*consumer_usage, usage));
+            }
+            limit
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct ConsumerState {
+    name: &'static str,
+    capacity: usize,
+    usage: AtomicUsize,
+    limit: AtomicUsize,
+    metrics: MemoryConsumerMetrics,
+}
+
+impl ConsumerState {
+    fn set_limit(&self, limit: usize) {
+        let previous = self.limit.swap(limit, Ordering::Relaxed);
+        if previous != limit {
+            debug!(target: "memory_budget", consumer = self.name, previous, limit, "Updated memory limit");
+            self.metrics.limit_bytes.set(limit as f64);
+        }
+    }
+}
+
+/// A cache that is bounded by a [`MemoryBudget`].
+///
+/// The consumer reports its usage with [`MemoryConsumer::set_usage`] and must keep its usage
+/// below [`MemoryConsumer::limit`], limits that aren't in bytes can be scaled with
+/// [`MemoryConsumer::scale`].
+#[derive(Debug, Clone)]
+pub struct MemoryConsumer {
+    state: Arc<ConsumerState>,
+}
+
+impl MemoryConsumer {
+    /// Returns the name of the consumer.
+    pub fn name(&self) -> &'static str {
+        self.state.name
+    }
+
+    /// Returns the capacity of the consumer in bytes, which is the limit without a budget.
+    pub fn capacity(&self) -> usize {
+        self.state.capacity
+    }
+
+    /// Returns the number of bytes the consumer may currently use, at most its capacity.
+    pub fn limit(&self) -> usize {
+        self.state.limit.load(Ordering::Relaxed)
+    }
+
+    /// Returns the last reported usage in bytes.
+    pub fn usage(&self) -> usize {
+        self.state.usage.load(Ordering::Relaxed)
+    }
+
+    /// Reports the current usage in bytes.
+    pub fn set_usage(&self, bytes: usize) {
+        self.state.usage.store(bytes, Ordering::Relaxed);
+        self.state.metrics.usage_bytes.set(bytes as f64);
+    }
+
+    /// Scales a limit of the consumer, e.g. a maximum number of entries, by the share of its
+    /// capacity that it may currently use.
+    ///
+    /// A non-zero limit is never scaled to zero.
+    pub fn scale(&self, value: usize) -> usize {
+        let (limit, capacity) = (self.limit(), self.capacity());
+        if limit >= capacity || value == 0 {
+            return value
+        }
+        ((value as u128 * limit as u128 / capacity as u128) as usize).max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limits_fit_into_budget() {
+        // capacities fit into the budget
+        assert_eq!(compute_limits(100, &[(30, 10), (50, 50)]), vec![30, 50]);
+
+        // capacities are scaled down proportionally
+        assert_eq!(compute_limits(100, &[(100, 10), (300, 50)]), vec![25, 75]);
+
+        // usage exceeds the budget, limits are capped by the share of the usage
+        assert_eq!(compute_limits(100, &[(100, 20), (300, 180)]), vec![10, 75]);
+    }
+
+    #[test]
+    fn register_consumers() {
+        let budget = MemoryBudget::new(100);
+        let pool = budget.register("pool", 100);
+        assert_eq!(pool.limit(), 100);
+        assert_eq!(pool.scale(10), 10);
+
+        let cache = budget.register("cache", 300);
+        assert_eq!(pool.limit(), 25);
+        assert_eq!(cache.limit(), 75);
+        assert_eq!(pool.scale(10), 2);
+        assert_eq!(pool.scale(1), 1);
+
+        cache.set_usage(50);
+        assert_eq!(budget.usage(), 50);
+
+        drop(cache);
+        budget.rebalance();
+        assert_eq!(pool.limit(), 100);
+        assert_eq!(budget.usage(), 0);
+
+        let unlimited = MemoryBudget::unlimited().register("pool", 100);
+        assert_eq!(unlimited.limit(), 100);
+    }
+}
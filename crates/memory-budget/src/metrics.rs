@@ -0,0 +1,25 @@
+use reth_metrics::{metrics::Gauge, Metrics};
+
+/// Metrics of the node-wide memory budget.
+#[derive(Metrics)]
+#[metrics(scope = "memory_budget")]
+pub(crate) struct MemoryBudgetMetrics {
+    /// The maximum number of bytes of all consumers
+    pub(crate) max_bytes: Gauge,
+    /// The combined usage of all consumers in bytes
+    pub(crate) usage_bytes: Gauge,
+    /// The number of registered consumers
+    pub(crate) consumers: Gauge,
+}
+
+/// Metrics of a single consumer of the memory budget.
+#[derive(Metrics)]
+#[metrics(scope = "memory_budget.consumer")]
+pub(crate) struct MemoryConsumerMetrics {
+    /// The capacity of the consumer in bytes
+    pub(crate) capacity_bytes: Gauge,
+    /// The current limit of the consumer in bytes
+    pub(crate) limit_bytes: Gauge,
+    /// The reported usage of the consumer in bytes
+    pub(crate) usage_bytes: Gauge,
+}
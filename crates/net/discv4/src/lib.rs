@@ -375,6 +375,14 @@ impl Discv4 {
         self.send_to_service(cmd);
     }
 
+    /// Sets the external ip address
+    ///
+    /// This will update our [`NodeRecord`]'s and [`Enr`]'s ip address.
+    pub fn set_external_ip_addr(&self, ip: IpAddr) {
+        let cmd = Discv4Command::SetExternalIp(ip);
+        self.send_to_service(cmd);
+    }
+
     /// Sets the pair in the EIP-868 [`Enr`] of the node.
     ///
     /// If the key already exists, this will update it.
@@ -1743,6 +1751,9 @@ impl Discv4Service {
                             let _ = self.local_eip_868_enr.set_tcp6(port, &self.secret_key);
                         }
                     }
+                    Discv4Command::SetExternalIp(ip) => {
+                        self.set_external_ip_addr(ip);
+                    }
 
                     Discv4Command::Terminated => {
                         // terminate the service
@@ -2024,6 +2035,7 @@ impl Default for ReceiveCache {
 enum Discv4Command {
     Add(NodeRecord),
     SetTcpPort(u16),
+    SetExternalIp(IpAddr),
     SetEIP868RLPPair { key: Vec<u8>, rlp: Bytes },
     Ban(PeerId, IpAddr),
     BanPeer(PeerId),
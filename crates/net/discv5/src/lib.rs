@@ -45,7 +45,7 @@ pub use config::{
 };
 pub use enr::enr_to_discv4_id;
 pub use error::Error;
-pub use filter::{FilterOutcome, MustNotIncludeKeys};
+pub use filter::{FilterOutcome, MustIncludeKey, MustNotIncludeKeys};
 pub use network_stack_id::NetworkStackId;
 
 use metrics::{DiscoveredPeersMetrics, Discv5Metrics};
@@ -145,6 +145,22 @@ impl Discv5 {
         self.discv5.ban_ip(ip, None);
     }
 
+    /// Sets the external ip address in the local [`Enr`](discv5::Enr), keeping the ports.
+    pub fn set_external_ip_addr(&self, ip: IpAddr) {
+        let enr = self.discv5.local_enr();
+        let (udp_port, tcp_port) = match ip {
+            IpAddr::V4(_) => (enr.udp4(), enr.tcp4()),
+            IpAddr::V6(_) => (enr.udp6(), enr.tcp6()),
+        };
+        if let Some(port) = udp_port {
+            self.discv5.update_local_enr_socket(SocketAddr::new(ip, port), false);
+        }
+        if let Some(port) = tcp_port {
+            self.discv5.update_local_enr_socket(SocketAddr::new(ip, port), true);
+        }
+        debug!(target: "net::discv5", ?ip, enr=?self.discv5.local_enr(), "Updated external ip");
+    }
+
     /// Returns the [`NodeRecord`] of the local node.
     ///
     /// This includes the currently tracked external IP address of the node.
@@ -377,6 +393,85 @@ impl Discv5 {
         Ok(fork_id)
     }
 
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+    // Topics
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Advertises the topic in the local [`Enr`](discv5::Enr), e.g. `b"opstack"` or a custom
+    /// rollup id, so that peers can discover the node by the capability.
+    ///
+    /// If the topic is already advertised, this will update its value.
+    ///
+    /// CAUTION: The value **must** be rlp encoded
+    pub fn advertise_topic(&self, topic: &'static [u8], rlp: Bytes) {
+        self.set_eip868_in_local_enr(topic.to_vec(), rlp)
+    }
+
+    /// Returns the peers in the routing table that advertise the given topic, i.e. that have a
+    /// kv-pair with the topic as key in their [`Enr`](discv5::Enr).
+    pub fn peers_with_topic(&self, topic: &'static [u8]) -> Vec<NodeRecord> {
+        let filter = MustIncludeKey::new(topic);
+        self.discv5
+            .table_entries_enr()
+            .iter()
+            .filter(|enr| filter.filter(enr).is_ok())
+            .filter_map(|enr| self.try_into_reachable_topic_peer(enr))
+            .collect()
+    }
+
+    /// Runs a lookup query for peers that advertise the given topic, see
+    /// [`Self::peers_with_topic`]. Returns at most `max_peers` peers.
+    pub async fn find_peers_with_topic(
+        &self,
+        topic: &'static [u8],
+        max_peers: usize,
+    ) -> Result<Vec<NodeRecord>, Error> {
+        let filter = MustIncludeKey::new(topic);
+        let target = discv5::enr::NodeId::random();
+
+        trace!(target: "net::discv5",
+            topic=%String::from_utf8_lossy(topic),
+            %target,
+            "starting topic lookup query"
+        );
+
+        let enrs = self
+            .discv5
+            .find_node_predicate(target, Box::new(move |enr| filter.filter(enr).is_ok()), max_peers)
+            .await
+            .map_err(Error::QueryFailed)?;
+
+        Ok(enrs.iter().filter_map(|enr| self.try_into_reachable_topic_peer(enr)).collect())
+    }
+
+    /// Returns the [`Enr`]s of all peers in the routing table that are identified by a secp256k1
+    /// key, e.g. to publish them in an [EIP-1459](https://eips.ethereum.org/EIPS/eip-1459) node list.
+    pub fn table_enrs(&self) -> Vec<Enr<SecretKey>> {
+        self.discv5
+            .table_entries_enr()
+            .into_iter()
+            .filter(|enr| enr_to_discv4_id(enr).is_some())
+            .map(|enr| EnrCombinedKeyWrapper(enr).into())
+            .collect()
+    }
+
+    /// Tries to convert an [`Enr`](discv5::Enr) that advertises a topic into a [`NodeRecord`],
+    /// using the discovery socket advertised in the [`Enr`](discv5::Enr) as udp socket.
+    fn try_into_reachable_topic_peer(&self, enr: &discv5::Enr) -> Option<NodeRecord> {
+        let socket = self.rlpx_ip_mode.get_contactable_addr(enr)?;
+        match self.try_into_reachable(enr, socket) {
+            Ok(node_record) => Some(node_record),
+            Err(err) => {
+                trace!(target: "net::discv5",
+                    %err,
+                    ?enr,
+                    "peer advertising topic is unreachable"
+                );
+                None
+            }
+        }
+    }
+
     ////////////////////////////////////////////////////////////////////////////////////////////////
     // Interface with sigp/discv5
     ////////////////////////////////////////////////////////////////////////////////////////////////
@@ -774,6 +869,42 @@ mod test {
         )
     }
 
+    #[test]
+    fn peers_with_topic() {
+        const TOPIC: &[u8] = b"rollup-901";
+
+        let discv5 = discv5_noop();
+
+        let build_enr = |udp_port: u16, topic: Option<&[u8]>| {
+            let key = CombinedKey::generate_secp256k1();
+            let mut builder = Enr::builder();
+            builder.ip4(Ipv4Addr::new(104, 28, 44, 25)).udp4(udp_port).tcp4(30303);
+            if let Some(topic) = topic {
+                builder.add_value_rlp(topic, alloy_rlp::encode(1u64).into());
+            }
+            builder.build(&key).unwrap()
+        };
+        let with_topic = build_enr(9000, Some(TOPIC));
+        let other_topic = build_enr(9001, Some(NetworkStackId::OPSTACK));
+        let without_topic = build_enr(9002, None);
+
+        for enr in [&with_topic, &other_topic, &without_topic] {
+            discv5.with_discv5(|discv5| discv5.add_enr(enr.clone())).unwrap();
+        }
+
+        let peers = discv5.peers_with_topic(TOPIC);
+
+        assert_eq!(
+            peers,
+            vec![NodeRecord {
+                address: Ipv4Addr::new(104, 28, 44, 25).into(),
+                udp_port: 9000,
+                tcp_port: 30303,
+                id: enr_to_discv4_id(&with_topic).unwrap(),
+            }]
+        );
+    }
+
     // Copied from sigp/discv5 with slight modification (U256 type)
     // <https://github.com/sigp/discv5/blob/master/src/kbucket/key.rs#L89-L101>
     #[allow(unreachable_pub)]
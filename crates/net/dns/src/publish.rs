@@ -0,0 +1,215 @@
+//! Generation of [EIP-1459](https://eips.ethereum.org/EIPS/eip-1459) node lists for publishing via
+//! DNS.
+//!
+//! A [`DnsTree`] is built from a set of [`Enr`]s and links to other trees and signed with the key
+//! of the tree operator. Its TXT records can then be published under a domain, which makes the tree
+//! available at the [`LinkEntry`] returned by [`DnsTree::link`].
+
+use crate::tree::{BranchEntry, DnsEntry, LinkEntry, NodeEntry, TreeRootEntry};
+use alloy_primitives::keccak256;
+use data_encoding::BASE32_NOPAD;
+use enr::{Enr, EnrKey};
+use secp256k1::{Message, PublicKey, SecretKey, SECP256K1};
+use std::collections::BTreeMap;
+
+/// The maximum number of children of a branch entry, so that the entry fits into a single TXT
+/// record.
+pub const MAX_BRANCH_CHILDREN: usize = 13;
+
+/// The number of bytes of the entry hash that are used as subdomain of the entry.
+const SUBDOMAIN_HASH_LEN: usize = 16;
+
+/// A signed node list that can be published as DNS TXT records.
+#[derive(Debug, Clone)]
+pub struct DnsTree {
+    /// The signed root entry.
+    root: TreeRootEntry,
+    /// All entries other than the root, by their subdomain.
+    entries: BTreeMap<String, DnsEntry<SecretKey>>,
+    /// The public key of the operator that signed the tree.
+    pubkey: PublicKey,
+}
+
+// === impl DnsTree ===
+
+impl DnsTree {
+    /// Builds a tree with the given sequence number that contains the given nodes and links to
+    /// other trees, and signs it with the given key.
+    ///
+    /// The nodes are ordered by their node id and duplicates are removed, so the same set of nodes
+    /// always results in the same tree.
+    pub fn new(
+        sequence_number: u64,
+        nodes: impl IntoIterator<Item = Enr<SecretKey>>,
+        links: impl IntoIterator<Item = LinkEntry<SecretKey>>,
+        key: &SecretKey,
+    ) -> Self {
+        let mut nodes = nodes.into_iter().collect::<Vec<_>>();
+        nodes.sort_by_key(|enr| enr.node_id());
+        nodes.dedup_by_key(|enr| enr.node_id());
+        let mut links = links.into_iter().collect::<Vec<_>>();
+        links.sort_by_cached_key(|link| link.to_string());
+        links.dedup();
+
+        let mut entries = BTreeMap::new();
+        let enr_root = build_subtree(
+            &mut entries,
+            nodes.into_iter().map(|enr| DnsEntry::Node(NodeEntry { enr })).collect(),
+        );
+        let link_root =
+            build_subtree(&mut entries, links.into_iter().map(DnsEntry::Link).collect());
+
+        let mut root = TreeRootEntry {
+            enr_root: insert_entry(&mut entries, enr_root),
+            link_root: insert_entry(&mut entries, link_root),
+            sequence_number,
+            signature: Default::default(),
+        };
+        root.signature = sign_root(&root, key).into();
+
+        Self { root, entries, pubkey: key.public() }
+    }
+
+    /// Returns the signed root entry of the tree.
+    pub const fn root(&self) -> &TreeRootEntry {
+        &self.root
+    }
+
+    /// Returns all entries of the tree other than the root, by their subdomain.
+    pub const fn entries(&self) -> &BTreeMap<String, DnsEntry<SecretKey>> {
+        &self.entries
+    }
+
+    /// Returns the link to the tree if it is published under the given domain.
+    pub fn link(&self, domain: impl Into<String>) -> LinkEntry<SecretKey> {
+        LinkEntry { domain: domain.into(), pubkey: self.pubkey }
+    }
+
+    /// Returns the TXT records of the tree if it is published under the given domain, by their
+    /// fully qualified name.
+    pub fn txt_records(&self, domain: &str) -> BTreeMap<String, String> {
+        let mut records = self
+            .entries
+            .iter()
+            .map(|(subdomain, entry)| (format!("{subdomain}.{domain}"), entry.to_string()))
+            .collect::<BTreeMap<_, _>>();
+        records.insert(domain.to_string(), self.root.to_string());
+        records
+    }
+}
+
+/// Builds the subtree of the given entries and returns its root, which is not yet inserted.
+///
+/// Entries are grouped into branches of at most [`MAX_BRANCH_CHILDREN`] children, which are again
+/// grouped until a single entry remains. An empty list results in an empty branch.
+fn build_subtree(
+    entries: &mut BTreeMap<String, DnsEntry<SecretKey>>,
+    mut children: Vec<DnsEntry<SecretKey>>,
+) -> DnsEntry<SecretKey> {
+    if children.len() == 1 {
+        return children.pop().expect("one child")
+    }
+    if children.len() <= MAX_BRANCH_CHILDREN {
+        let children = children.into_iter().map(|child| insert_entry(entries, child)).collect();
+        return DnsEntry::Branch(BranchEntry { children })
+    }
+
+    let mut subtrees = Vec::with_capacity(children.len().div_ceil(MAX_BRANCH_CHILDREN));
+    while !children.is_empty() {
+        let rest = children.split_off(children.len().min(MAX_BRANCH_CHILDREN));
+        subtrees.push(build_subtree(entries, std::mem::replace(&mut children, rest)));
+    }
+    build_subtree(entries, subtrees)
+}
+
+/// Inserts the entry under its subdomain and returns the subdomain.
+fn insert_entry(
+    entries: &mut BTreeMap<String, DnsEntry<SecretKey>>,
+    entry: DnsEntry<SecretKey>,
+) -> String {
+    let subdomain = subdomain(&entry);
+    entries.insert(subdomain.clone(), entry);
+    subdomain
+}
+
+/// Returns the subdomain of the entry, which is the base32 encoded truncated keccak256 hash of its
+/// text representation.
+fn subdomain(entry: &DnsEntry<SecretKey>) -> String {
+    let hash = keccak256(entry.to_string());
+    BASE32_NOPAD.encode(&hash[..SUBDOMAIN_HASH_LEN])
+}
+
+/// Signs the content of the root entry, returning the 65 byte recoverable signature.
+///
+/// Other implementations expect the recovery id to be included, unlike
+/// [`TreeRootEntry::sign`].
+fn sign_root(root: &TreeRootEntry, key: &SecretKey) -> Vec<u8> {
+    let msg = Message::from_digest(keccak256(root.content()).0);
+    let (recovery_id, signature) = SECP256K1.sign_ecdsa_recoverable(&msg, key).serialize_compact();
+
+    let mut sig = signature.to_vec();
+    sig.push(recovery_id.to_i32() as u8);
+    sig
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DnsDiscoveryEvent, DnsDiscoveryService, MapResolver};
+    use secp256k1::rand::thread_rng;
+    use std::{collections::HashSet, future::poll_fn, net::Ipv4Addr, sync::Arc};
+
+    fn random_enr() -> Enr<SecretKey> {
+        let secret_key = SecretKey::new(&mut thread_rng());
+        Enr::builder().ip4(Ipv4Addr::LOCALHOST).udp4(30303).tcp4(30303).build(&secret_key).unwrap()
+    }
+
+    #[test]
+    fn build_tree() {
+        let key = SecretKey::new(&mut thread_rng());
+        let nodes = (0..30).map(|_| random_enr()).collect::<Vec<_>>();
+        let tree = DnsTree::new(1, nodes.clone(), Vec::new(), &key);
+
+        assert!(tree.root().verify::<SecretKey>(&key.public()));
+        assert_eq!(tree.root().signature.len(), 65);
+        // 30 nodes in 3 branches, and a branch linking them, and the empty link branch
+        assert_eq!(tree.entries().len(), 30 + 3 + 1 + 1);
+
+        // the same nodes in a different order result in the same tree
+        let reversed = DnsTree::new(1, nodes.into_iter().rev(), Vec::new(), &key);
+        assert_eq!(reversed.root(), tree.root());
+
+        // every record can be parsed
+        for record in tree.txt_records("nodes.example.org").values() {
+            record.parse::<DnsEntry<SecretKey>>().unwrap();
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn resolve_published_tree() {
+        reth_tracing::init_test_tracing();
+
+        let key = SecretKey::new(&mut thread_rng());
+        let nodes = (0..20).map(|_| random_enr()).collect::<Vec<_>>();
+        let tree = DnsTree::new(1, nodes.clone(), Vec::new(), &key);
+
+        let link = tree.link("nodes.example.org");
+        let resolver = MapResolver::default();
+        for (name, record) in tree.txt_records(&link.domain) {
+            resolver.insert(name, record);
+        }
+
+        let mut service = DnsDiscoveryService::new(Arc::new(resolver), Default::default());
+        service.sync_tree_with_link(link);
+
+        let mut resolved = HashSet::new();
+        while resolved.len() < nodes.len() {
+            match poll_fn(|cx| service.poll(cx)).await {
+                DnsDiscoveryEvent::Enr(enr) => {
+                    resolved.insert(enr.node_id());
+                }
+            }
+        }
+        assert_eq!(resolved, nodes.iter().map(|enr| enr.node_id()).collect());
+    }
+}
@@ -127,7 +127,7 @@ impl TreeRootEntry {
     /// ```text
     /// e=<enr-root> l=<link-root> seq=<sequence-number> sig=<signature>
     /// ```
-    fn content(&self) -> String {
+    pub(crate) fn content(&self) -> String {
         format!(
             "{} e={} l={} seq={}",
             ROOT_V1_PREFIX, self.enr_root, self.link_root, self.sequence_number
@@ -210,8 +210,13 @@ impl BranchEntry {
             Ok(hash.to_string())
         }
 
+        let input = input.trim();
+        if input.is_empty() {
+            // an empty list, e.g. a tree without links
+            return Ok(Self { children: Vec::new() })
+        }
         let children =
-            input.trim().split(',').map(ensure_valid_hash).collect::<ParseEntryResult<Vec<_>>>()?;
+            input.split(',').map(ensure_valid_hash).collect::<ParseEntryResult<Vec<_>>>()?;
         Ok(Self { children })
     }
 }
@@ -6,6 +6,7 @@ use futures::Stream;
 use futures_util::StreamExt;
 use reth_config::BodiesConfig;
 use reth_consensus::Consensus;
+use reth_memory_budget::{MemoryBudget, MemoryConsumer};
 use reth_network_p2p::{
     bodies::{
         client::BodiesClient,
@@ -48,8 +49,9 @@ pub struct BodiesDownloader<B: BodiesClient, Provider> {
     stream_batch_size: usize,
     /// The allowed range for number of concurrent requests.
     concurrent_requests_range: RangeInclusive<usize>,
-    /// Maximum number of bytes of received blocks to buffer internally.
-    max_buffered_blocks_size_bytes: usize,
+    /// The share of the node's memory budget for buffered blocks, its capacity is the configured
+    /// maximum number of bytes of received blocks to buffer internally.
+    memory: MemoryConsumer,
     /// Current estimated size of buffered blocks in bytes.
     buffered_blocks_size_bytes: usize,
     /// The range of block numbers for body download.
@@ -79,7 +81,10 @@ where
         };
         // as the range is inclusive, we need to add 1 to the end.
         let items_left = (self.download_range.end() + 1).saturating_sub(start_at);
-        let limit = items_left.min(self.request_limit);
+        // request no more bodies than most peers serve at once
+        let request_limit =
+            self.in_progress_queue.response_limits().request_size(self.request_limit);
+        let limit = items_left.min(request_limit);
         self.query_headers(start_at..=*self.download_range.end(), limit)
     }
 
@@ -154,9 +159,10 @@ where
         max_requests.min(*self.concurrent_requests_range.end())
     }
 
-    /// Returns true if the size of buffered blocks is lower than the configured maximum
-    const fn has_buffer_capacity(&self) -> bool {
-        self.buffered_blocks_size_bytes < self.max_buffered_blocks_size_bytes
+    /// Returns true if the size of buffered blocks is lower than the configured maximum, as
+    /// limited by the memory budget.
+    fn has_buffer_capacity(&self) -> bool {
+        self.buffered_blocks_size_bytes < self.memory.limit()
     }
 
     // Check if the stream is terminated
@@ -183,6 +189,7 @@ where
         self.queued_bodies = Vec::new();
         self.buffered_responses = BinaryHeap::new();
         self.buffered_blocks_size_bytes = 0;
+        self.memory.set_usage(0);
 
         // reset metrics
         self.metrics.in_flight_requests.set(0.);
@@ -204,6 +211,7 @@ where
         let resp = self.buffered_responses.pop()?;
         self.metrics.buffered_responses.decrement(1.);
         self.buffered_blocks_size_bytes -= resp.size();
+        self.memory.set_usage(self.buffered_blocks_size_bytes);
         self.metrics.buffered_blocks.decrement(resp.len() as f64);
         self.metrics.buffered_blocks_size_bytes.set(self.buffered_blocks_size_bytes as f64);
         Some(resp)
@@ -222,6 +230,7 @@ where
         let response_len = response.len();
 
         self.buffered_blocks_size_bytes += size;
+        self.memory.set_usage(self.buffered_blocks_size_bytes);
         self.buffered_responses.push(response);
 
         self.metrics.buffered_blocks.increment(response_len as f64);
@@ -510,6 +519,8 @@ pub struct BodiesDownloaderBuilder {
     pub max_buffered_blocks_size_bytes: usize,
     /// The maximum number of requests to send concurrently.
     pub concurrent_requests_range: RangeInclusive<usize>,
+    /// The memory budget the buffer of received bodies registers with.
+    pub memory_budget: MemoryBudget,
 }
 
 impl BodiesDownloaderBuilder {
@@ -534,6 +545,7 @@ impl Default for BodiesDownloaderBuilder {
             stream_batch_size: 1_000,
             max_buffered_blocks_size_bytes: 2 * 1024 * 1024 * 1024, // ~2GB
             concurrent_requests_range: 5..=100,
+            memory_budget: Default::default(),
         }
     }
 }
@@ -569,6 +581,12 @@ impl BodiesDownloaderBuilder {
         self
     }
 
+    /// Set the memory budget the buffer of received bodies registers with.
+    pub fn with_memory_budget(mut self, memory_budget: MemoryBudget) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
+
     /// Consume self and return the concurrent downloader.
     pub fn build<B, Provider>(
         self,
@@ -585,16 +603,17 @@ impl BodiesDownloaderBuilder {
             stream_batch_size,
             concurrent_requests_range,
             max_buffered_blocks_size_bytes,
+            memory_budget,
         } = self;
         let metrics = BodyDownloaderMetrics::default();
-        let in_progress_queue = BodiesRequestQueue::new(metrics.clone());
+        let in_progress_queue = BodiesRequestQueue::new(metrics.clone(), Default::default());
         BodiesDownloader {
             client: Arc::new(client),
             consensus,
             provider,
             request_limit,
             stream_batch_size,
-            max_buffered_blocks_size_bytes,
+            memory: memory_budget.register("bodies_downloader", max_buffered_blocks_size_bytes),
             concurrent_requests_range,
             in_progress_queue,
             metrics,
@@ -1,5 +1,5 @@
 use super::request::BodiesRequestFuture;
-use crate::metrics::BodyDownloaderMetrics;
+use crate::{metrics::BodyDownloaderMetrics, response_limits::PeerResponseLimits};
 use alloy_primitives::BlockNumber;
 use futures::{stream::FuturesUnordered, Stream};
 use futures_util::StreamExt;
@@ -24,6 +24,8 @@ pub(crate) struct BodiesRequestQueue<B: BodiesClient> {
     inner: FuturesUnordered<BodiesRequestFuture<B>>,
     /// The downloader metrics.
     metrics: BodyDownloaderMetrics,
+    /// The learned response limits of peers.
+    response_limits: PeerResponseLimits,
     /// Last requested block number.
     pub(crate) last_requested_block_number: Option<BlockNumber>,
 }
@@ -33,8 +35,18 @@ where
     B: BodiesClient + 'static,
 {
     /// Create new instance of request queue.
-    pub(crate) fn new(metrics: BodyDownloaderMetrics) -> Self {
-        Self { metrics, inner: Default::default(), last_requested_block_number: None }
+    pub(crate) fn new(metrics: BodyDownloaderMetrics, response_limits: PeerResponseLimits) -> Self {
+        Self {
+            metrics,
+            response_limits,
+            inner: Default::default(),
+            last_requested_block_number: None,
+        }
+    }
+
+    /// Returns the learned response limits of peers.
+    pub(crate) const fn response_limits(&self) -> &PeerResponseLimits {
+        &self.response_limits
     }
 
     /// Returns `true` if the queue is empty.
@@ -71,7 +83,13 @@ where
             .or(self.last_requested_block_number);
         // Create request and push into the queue.
         self.inner.push(
-            BodiesRequestFuture::new(client, consensus, self.metrics.clone()).with_headers(request),
+            BodiesRequestFuture::new(
+                client,
+                consensus,
+                self.metrics.clone(),
+                self.response_limits.clone(),
+            )
+            .with_headers(request),
         )
     }
 }
@@ -1,4 +1,7 @@
-use crate::metrics::{BodyDownloaderMetrics, ResponseMetrics};
+use crate::{
+    metrics::{BodyDownloaderMetrics, ResponseMetrics},
+    response_limits::PeerResponseLimits,
+};
 use alloy_consensus::BlockHeader;
 use alloy_primitives::B256;
 use futures::{Future, FutureExt};
@@ -45,6 +48,8 @@ pub(crate) struct BodiesRequestFuture<B: BodiesClient> {
     /// Metrics for individual responses. This can be used to observe how the size (in bytes) of
     /// responses change while bodies are being downloaded.
     response_metrics: ResponseMetrics,
+    /// The learned response limits of peers, updated with each response.
+    response_limits: PeerResponseLimits,
     // Headers to download. The collection is shrunk as responses are buffered.
     pending_headers: VecDeque<SealedHeader>,
     /// Internal buffer for all blocks
@@ -63,12 +68,14 @@ where
         client: Arc<B>,
         consensus: Arc<dyn Consensus<alloy_consensus::Header, B::Body>>,
         metrics: BodyDownloaderMetrics,
+        response_limits: PeerResponseLimits,
     ) -> Self {
         Self {
             client,
             consensus,
             metrics,
             response_metrics: Default::default(),
+            response_limits,
             pending_headers: Default::default(),
             buffer: Default::default(),
             last_request_len: None,
@@ -144,6 +151,8 @@ where
             }))
         }
 
+        self.response_limits.on_response(peer_id, request_len as u64, response_len as u64);
+
         // Buffer block responses
         self.try_buffer_blocks(bodies)?;
 
@@ -277,6 +286,7 @@ mod tests {
             client.clone(),
             Arc::new(TestConsensus::default()),
             BodyDownloaderMetrics::default(),
+            PeerResponseLimits::default(),
         )
         .with_headers(headers.clone());
 
@@ -297,14 +307,18 @@ mod tests {
         let client = Arc::new(
             TestBodiesClient::default().with_bodies(bodies.clone()).with_max_batch_size(batch_size),
         );
+        let response_limits = PeerResponseLimits::default();
         let fut = BodiesRequestFuture::new(
             client.clone(),
             Arc::new(TestConsensus::default()),
             BodyDownloaderMetrics::default(),
+            response_limits.clone(),
         )
         .with_headers(headers.clone());
 
         assert_eq!(fut.await.unwrap(), zip_blocks(headers.iter(), &mut bodies));
+        // the truncated responses were recorded
+        assert!(response_limits.request_size(20) <= batch_size as u64 + 1);
         assert_eq!(
             client.times_requested(),
             // div_ceild
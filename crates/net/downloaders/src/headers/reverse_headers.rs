@@ -1,7 +1,7 @@
 //! A headers downloader that can handle multiple requests concurrently.
 
 use super::task::TaskDownloader;
-use crate::metrics::HeaderDownloaderMetrics;
+use crate::{metrics::HeaderDownloaderMetrics, response_limits::PeerResponseLimits};
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::{BlockNumber, B256};
@@ -83,6 +83,8 @@ pub struct ReverseHeadersDownloader<H: HeadersClient> {
     next_chain_tip_block_number: u64,
     /// The batch size per one request
     request_limit: u64,
+    /// The learned response limits of peers that cap the batch size per one request.
+    response_limits: PeerResponseLimits,
     /// Minimum amount of requests to handle concurrently.
     min_concurrent_requests: usize,
     /// Maximum amount of requests to handle concurrently.
@@ -177,7 +179,7 @@ where
                 let request = calc_next_request(
                     local_head,
                     self.next_request_block_number,
-                    self.request_limit,
+                    self.response_limits.request_size(self.request_limit),
                 );
                 // need to shift the tracked request block number based on the number of requested
                 // headers so follow-up requests will use that as start.
@@ -455,6 +457,8 @@ where
                     .into())
                 }
 
+                self.response_limits.on_response(peer_id, request.limit, headers.len() as u64);
+
                 if (headers.len() as u64) != request.limit {
                     return Err(HeadersResponseError {
                         peer_id: Some(peer_id),
@@ -535,8 +539,16 @@ where
         // Update error metric
         self.metrics.increment_errors(&error);
 
-        // Re-submit the request
-        self.submit_request(request, Priority::High);
+        // Re-submit the request, split into requests that are served in full if the peer
+        // truncated the response
+        if let DownloadError::HeadersResponseTooShort(_) = error {
+            let size = self.response_limits.request_size(request.limit);
+            for request in split_request(request, size) {
+                self.submit_request(request, Priority::High);
+            }
+        } else {
+            self.submit_request(request, Priority::High);
+        }
     }
 
     /// Attempts to validate the buffered responses
@@ -1188,6 +1200,7 @@ impl ReverseHeadersDownloaderBuilder {
             next_chain_tip_block_number: 0,
             lowest_validated_header: None,
             request_limit,
+            response_limits: Default::default(),
             min_concurrent_requests,
             max_concurrent_requests,
             stream_batch_size,
@@ -1220,6 +1233,23 @@ fn calc_next_request(
     HeadersRequest::falling(start.into(), limit)
 }
 
+/// Splits the falling request into consecutive falling requests of at most `size` headers.
+///
+/// Requests that don't start at a block number are returned as is.
+fn split_request(request: HeadersRequest, size: u64) -> Vec<HeadersRequest> {
+    let BlockHashOrNumber::Number(mut start) = request.start else { return vec![request] };
+    let size = size.max(1);
+    let mut remaining = request.limit;
+    let mut requests = Vec::with_capacity(remaining.div_ceil(size) as usize);
+    while remaining > 0 {
+        let limit = remaining.min(size);
+        requests.push(HeadersRequest::falling(start.into(), limit));
+        start = start.saturating_sub(limit);
+        remaining -= limit;
+    }
+    requests
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1384,6 +1414,46 @@ mod tests {
         assert_eq!(Some(downloader.next_request_block_number), downloader.local_block_number());
     }
 
+    /// Tests that requests are capped by the learned response limits
+    #[test]
+    fn test_next_request_adapts_to_response_limits() {
+        let client = Arc::new(TestHeadersClient::default());
+
+        let mut downloader = ReverseHeadersDownloaderBuilder::default()
+            .request_limit(100)
+            .build(Arc::clone(&client), Arc::new(TestConsensus::default()));
+        downloader.update_local_head(SealedHeader::default());
+        downloader.update_sync_target(SyncTarget::Tip(B256::random()));
+        downloader.next_request_block_number = 1000;
+
+        downloader.response_limits.on_response(PeerId::with_last_byte(1), 100, 40);
+        let request = downloader.next_request().unwrap();
+        assert_eq!(request.start, 1000u64.into());
+        assert_eq!(request.limit, 40);
+        assert_eq!(downloader.next_request_block_number, 960);
+    }
+
+    #[test]
+    fn test_split_request() {
+        let requests = split_request(HeadersRequest::falling(1000u64.into(), 100), 40)
+            .into_iter()
+            .map(|request| (request.start, request.limit))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            requests,
+            [
+                (BlockHashOrNumber::Number(1000), 40),
+                (BlockHashOrNumber::Number(960), 40),
+                (BlockHashOrNumber::Number(920), 20),
+            ]
+        );
+
+        let requests = split_request(HeadersRequest::falling(B256::ZERO.into(), 100), 40);
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].start, B256::ZERO.into());
+        assert_eq!(requests[0].limit, 100);
+    }
+
     #[test]
     fn test_resp_order() {
         let mut heap = BinaryHeap::new();
@@ -21,6 +21,9 @@ pub mod headers;
 /// Common downloader metrics.
 pub mod metrics;
 
+/// Soft response limits of peers that are learned to adapt request sizes.
+pub mod response_limits;
+
 /// Module managing file-based data retrieval and buffering.
 ///
 /// Contains [`FileClient`](file_client::FileClient) to read block data from files,
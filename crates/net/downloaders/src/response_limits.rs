@@ -0,0 +1,106 @@
+use parking_lot::Mutex;
+use reth_network_peers::PeerId;
+use schnellru::{ByLength, LruMap};
+use std::sync::Arc;
+
+/// The maximum number of peers to track response limits for.
+const MAX_TRACKED_PEERS: u32 = 1024;
+
+/// Soft response limits of peers, learned from the responses to download requests.
+///
+/// Peers truncate responses at their own byte caps, so a response with fewer items than requested
+/// reveals how many items the peer serves at once. A full response raises the learned limit of the
+/// peer slightly above the served number of items, so that the limit recovers if the peer serves
+/// more items again (e.g. smaller blocks).
+///
+/// Peers are selected by the network and not by the downloader, so the request size is derived
+/// from the limits of all tracked peers, see [`PeerResponseLimits::request_size`].
+///
+/// This type is cheap to clone and clones share the learned limits.
+#[derive(Debug, Clone)]
+pub struct PeerResponseLimits {
+    limits: Arc<Mutex<LruMap<PeerId, u64, ByLength>>>,
+}
+
+impl PeerResponseLimits {
+    /// Records the response of the peer to a request for `requested` items.
+    pub fn on_response(&self, peer_id: PeerId, requested: u64, received: u64) {
+        if received == 0 || received > requested {
+            // not a truncated response, but an invalid one
+            return
+        }
+
+        let mut limits = self.limits.lock();
+        if received < requested {
+            limits.insert(peer_id, received);
+        } else {
+            // probe for a higher limit
+            let probe = received.saturating_add(received.div_ceil(8));
+            let limit = limits.get(&peer_id).map_or(probe, |limit| (*limit).max(probe));
+            limits.insert(peer_id, limit);
+        }
+    }
+
+    /// Returns the number of items to request at once, at most `max`.
+    ///
+    /// This is the lower median of the learned limits, which is served in full by most of the
+    /// tracked peers. If no limits were learned yet, this returns `max`.
+    pub fn request_size(&self, max: u64) -> u64 {
+        let mut limits = self.limits.lock().iter().map(|(_, limit)| *limit).collect::<Vec<_>>();
+        if limits.is_empty() {
+            return max
+        }
+        limits.sort_unstable();
+        limits[(limits.len() - 1) / 2].clamp(1, max.max(1))
+    }
+}
+
+impl Default for PeerResponseLimits {
+    fn default() -> Self {
+        Self { limits: Arc::new(Mutex::new(LruMap::new(ByLength::new(MAX_TRACKED_PEERS)))) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn learn_truncated_responses() {
+        let limits = PeerResponseLimits::default();
+        assert_eq!(limits.request_size(1000), 1000);
+
+        limits.on_response(PeerId::with_last_byte(1), 1000, 400);
+        assert_eq!(limits.request_size(1000), 400);
+        assert_eq!(limits.request_size(100), 100);
+
+        // a single peer with a larger limit doesn't change the request size
+        limits.on_response(PeerId::with_last_byte(2), 1000, 800);
+        assert_eq!(limits.request_size(1000), 400);
+
+        limits.on_response(PeerId::with_last_byte(3), 1000, 1000);
+        assert_eq!(limits.request_size(1000), 800);
+    }
+
+    #[test]
+    fn ignore_invalid_responses() {
+        let limits = PeerResponseLimits::default();
+        limits.on_response(PeerId::with_last_byte(4), 1000, 0);
+        limits.on_response(PeerId::with_last_byte(5), 10, 20);
+        assert_eq!(limits.request_size(1000), 1000);
+    }
+
+    #[test]
+    fn raise_limit_on_full_responses() {
+        let limits = PeerResponseLimits::default();
+        let peer_id = PeerId::with_last_byte(1);
+        limits.on_response(peer_id, 1000, 400);
+        assert_eq!(limits.request_size(1000), 400);
+
+        limits.on_response(peer_id, 400, 400);
+        assert_eq!(limits.request_size(1000), 450);
+
+        limits.on_response(peer_id, 450, 420);
+        assert_eq!(limits.request_size(1000), 420);
+    }
+}
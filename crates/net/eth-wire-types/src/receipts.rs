@@ -26,9 +26,47 @@ pub struct Receipts(
     pub Vec<Vec<ReceiptWithBloom<Receipt>>>,
 );
 
+/// The `eth/69` response to [`GetReceipts`].
+///
+/// Unlike [`Receipts`], the receipts don't include the logs bloom, which can be recomputed from
+/// the logs by the receiving side, see also [EIP-7642](https://eips.ethereum.org/EIPS/eip-7642).
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodableWrapper, RlpDecodableWrapper, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
+pub struct Receipts69(
+    /// Each receipt list should correspond to a block hash in the request.
+    pub Vec<Vec<Receipt>>,
+);
+
+impl From<Receipts> for Receipts69 {
+    fn from(receipts: Receipts) -> Self {
+        Self(
+            receipts
+                .0
+                .into_iter()
+                .map(|receipts| receipts.into_iter().map(|receipt| receipt.receipt).collect())
+                .collect(),
+        )
+    }
+}
+
+/// Converts the `eth/69` receipts into [`Receipts`] by recomputing the logs bloom of every receipt.
+impl From<Receipts69> for Receipts {
+    fn from(receipts: Receipts69) -> Self {
+        Self(
+            receipts
+                .0
+                .into_iter()
+                .map(|receipts| receipts.into_iter().map(Receipt::with_bloom).collect())
+                .collect(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{message::RequestPair, GetReceipts, Receipts};
+    use crate::{message::RequestPair, GetReceipts, Receipts, Receipts69};
     use alloy_primitives::{hex, Log};
     use alloy_rlp::{Decodable, Encodable};
     use reth_primitives::{Receipt, ReceiptWithBloom, TxType};
@@ -49,6 +87,36 @@ mod tests {
         assert_eq!(receipts, decoded);
     }
 
+    #[test]
+    #[allow(clippy::needless_update)]
+    fn roundtrip_eth69_receipts() {
+        let receipt = Receipt {
+            tx_type: TxType::Eip1559,
+            success: true,
+            cumulative_gas_used: 0x1u64,
+            logs: vec![Log::new_unchecked(
+                hex!("0000000000000000000000000000000000000011").into(),
+                vec![
+                    hex!("000000000000000000000000000000000000000000000000000000000000dead").into()
+                ],
+                hex!("0100ff")[..].into(),
+            )],
+            ..Default::default()
+        };
+        let receipts = Receipts(vec![vec![receipt.clone().with_bloom()]]);
+
+        let receipts69 = Receipts69::from(receipts.clone());
+        assert_eq!(receipts69, Receipts69(vec![vec![receipt]]));
+
+        let mut out = vec![];
+        receipts69.encode(&mut out);
+        assert!(out.len() < alloy_rlp::encode(&receipts).len());
+
+        let decoded = Receipts69::decode(&mut out.as_slice()).unwrap();
+        assert_eq!(decoded, receipts69);
+        assert_eq!(Receipts::from(decoded), receipts);
+    }
+
     #[test]
     // Test vector from: https://eips.ethereum.org/EIPS/eip-2481
     fn encode_get_receipts() {
@@ -134,6 +134,105 @@ impl Default for Status {
     }
 }
 
+/// The status message used in the `eth/69` protocol handshake, as defined by
+/// [EIP-7642](https://eips.ethereum.org/EIPS/eip-7642).
+///
+/// Instead of the total difficulty and hash of the best block, which are no longer meaningful
+/// after the merge, it contains the range of blocks the peer can serve, see also
+/// [`BlockRangeUpdate`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
+pub struct StatusEth69 {
+    /// The current protocol version.
+    pub version: EthVersion,
+
+    /// The chain id, as introduced in
+    /// [EIP155](https://eips.ethereum.org/EIPS/eip-155#list-of-chain-ids).
+    pub chain: Chain,
+
+    /// The genesis hash of the peer's chain.
+    pub genesis: B256,
+
+    /// The fork identifier as defined by
+    /// [EIP-2124](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-2124.md).
+    pub forkid: ForkId,
+
+    /// The earliest block the peer can serve headers, bodies and receipts for.
+    pub earliest: u64,
+
+    /// The latest block the peer can serve.
+    pub latest: u64,
+
+    /// The hash of the latest block.
+    pub latest_hash: B256,
+}
+
+impl StatusEth69 {
+    /// Creates the `eth/69` status from the [`Status`] and the range of blocks the node can serve.
+    pub const fn new(status: Status, range: BlockRangeUpdate) -> Self {
+        Self {
+            version: status.version,
+            chain: status.chain,
+            genesis: status.genesis,
+            forkid: status.forkid,
+            earliest: range.earliest,
+            latest: range.latest,
+            latest_hash: range.latest_hash,
+        }
+    }
+
+    /// Returns the range of blocks the peer can serve.
+    pub const fn block_range(&self) -> BlockRangeUpdate {
+        BlockRangeUpdate {
+            earliest: self.earliest,
+            latest: self.latest,
+            latest_hash: self.latest_hash,
+        }
+    }
+}
+
+/// Converts the `eth/69` status into a [`Status`] with a zero total difficulty and the latest
+/// block hash.
+impl From<StatusEth69> for Status {
+    fn from(status: StatusEth69) -> Self {
+        let StatusEth69 { version, chain, genesis, forkid, latest_hash, .. } = status;
+        Self {
+            version,
+            chain,
+            total_difficulty: U256::ZERO,
+            blockhash: latest_hash,
+            genesis,
+            forkid,
+        }
+    }
+}
+
+/// Announces the range of blocks a peer can serve, introduced in `eth/69`.
+///
+/// Sent after the handshake and whenever the range changes, e.g. after history was pruned, so that
+/// peers don't request blocks the node no longer has.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[add_arbitrary_tests(rlp)]
+pub struct BlockRangeUpdate {
+    /// The earliest block the peer can serve headers, bodies and receipts for.
+    pub earliest: u64,
+    /// The latest block the peer can serve.
+    pub latest: u64,
+    /// The hash of the latest block.
+    pub latest_hash: B256,
+}
+
+impl BlockRangeUpdate {
+    /// Returns `true` if the block is within the announced range.
+    pub const fn contains(&self, block: u64) -> bool {
+        self.earliest <= block && block <= self.latest
+    }
+}
+
 /// Builder for [`Status`] messages.
 ///
 /// # Example
@@ -215,7 +314,7 @@ impl StatusBuilder {
 
 #[cfg(test)]
 mod tests {
-    use crate::{EthVersion, Status};
+    use crate::{BlockRangeUpdate, EthVersion, Status, StatusEth69};
     use alloy_consensus::constants::MAINNET_GENESIS_HASH;
     use alloy_genesis::Genesis;
     use alloy_primitives::{hex, B256, U256};
@@ -387,4 +486,37 @@ mod tests {
         assert_eq!(status.blockhash, head_hash);
         assert_eq!(status.genesis, genesis_hash);
     }
+
+    #[test]
+    fn encode_eth69_status_message() {
+        // [version, networkid, genesis, forkid, earliest, latest, latest hash]
+        let expected = hex!("f8514501a0d4e56740f876aef8c010b86a40d5f56745a118d0906a34e69aec8c0db1cb8fa3c684b715077d80808401312d00a0fefefefefefefefefefefefefefefefefefefefefefefefefefefefefefefefe");
+
+        let range = BlockRangeUpdate {
+            earliest: 0,
+            latest: 20_000_000,
+            latest_hash: B256::repeat_byte(0xfe),
+        };
+        let status = StatusEth69::new(
+            Status {
+                version: EthVersion::Eth69,
+                total_difficulty: U256::from(36206751599115524359527u128),
+                forkid: ForkId { hash: ForkHash([0xb7, 0x15, 0x07, 0x7d]), next: 0 },
+                ..Default::default()
+            },
+            range,
+        );
+
+        let mut rlp_status = vec![];
+        status.encode(&mut rlp_status);
+        assert_eq!(rlp_status, expected);
+
+        let decoded = StatusEth69::decode(&mut &rlp_status[..]).unwrap();
+        assert_eq!(decoded, status);
+        assert_eq!(decoded.block_range(), range);
+
+        let status_from_eth69 = Status::from(decoded);
+        assert_eq!(status_from_eth69.total_difficulty, U256::ZERO);
+        assert_eq!(status_from_eth69.blockhash, range.latest_hash);
+    }
 }
@@ -30,7 +30,7 @@ pub enum EthVersion {
 
 impl EthVersion {
     /// The latest known eth version
-    pub const LATEST: Self = Self::Eth68;
+    pub const LATEST: Self = Self::Eth69;
 
     /// Returns the total number of messages the protocol version supports.
     pub const fn total_messages(&self) -> u8 {
@@ -40,8 +40,9 @@ impl EthVersion {
                 // eth/67,68 are eth/66 minus GetNodeData and NodeData messages
                 13
             }
-            // eth69 is both eth67 and eth68 minus NewBlockHashes and NewBlock
-            Self::Eth69 => 11,
+            // eth69 is both eth67 and eth68 minus NewBlockHashes and NewBlock plus
+            // BlockRangeUpdate
+            Self::Eth69 => 12,
         }
     }
 
@@ -264,6 +265,6 @@ mod tests {
         assert_eq!(EthVersion::Eth66.total_messages(), 15);
         assert_eq!(EthVersion::Eth67.total_messages(), 13);
         assert_eq!(EthVersion::Eth68.total_messages(), 13);
-        assert_eq!(EthVersion::Eth69.total_messages(), 11);
+        assert_eq!(EthVersion::Eth69.total_messages(), 12);
     }
 }
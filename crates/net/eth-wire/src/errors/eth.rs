@@ -38,6 +38,14 @@ pub enum EthStreamError {
         /// The number of transaction sizes.
         sizes_len: usize,
     },
+    #[error("invalid block range: earliest={earliest} latest={latest}")]
+    /// Received a block range update whose earliest block is higher than its latest block.
+    InvalidBlockRange {
+        /// The earliest block of the range.
+        earliest: u64,
+        /// The latest block of the range.
+        latest: u64,
+    },
     /// Error when data is not received from peer for a prolonged period.
     #[error("never received data from remote peer")]
     StreamTimeout,
@@ -94,6 +102,17 @@ pub enum EthHandshakeError {
     #[error("mismatched chain in status message: {0}")]
     /// Mismatch in chain details in status messages.
     MismatchedChain(GotExpected<Chain>),
+    #[error("no block range to announce in the eth/69 status")]
+    /// The local block range is required for an `eth/69` status, but wasn't set.
+    MissingBlockRange,
+    #[error("invalid block range in status message: earliest {earliest}, latest {latest}")]
+    /// The earliest block of the announced block range is after the latest block.
+    InvalidBlockRange {
+        /// The earliest block the peer can serve.
+        earliest: u64,
+        /// The latest block the peer can serve.
+        latest: u64,
+    },
     #[error("total difficulty bitlen is too large: got {got}, maximum {maximum}")]
     /// Excessively large total difficulty bit lengths.
     TotalDifficultyBitLenTooLarge {
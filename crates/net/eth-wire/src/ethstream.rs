@@ -3,8 +3,8 @@ use crate::{
     errors::{EthHandshakeError, EthStreamError},
     message::{EthBroadcastMessage, ProtocolBroadcastMessage},
     p2pstream::HANDSHAKE_TIMEOUT,
-    CanDisconnect, DisconnectReason, EthMessage, EthNetworkPrimitives, EthVersion, ProtocolMessage,
-    Status,
+    BlockRangeUpdate, CanDisconnect, DisconnectReason, EthMessage, EthNetworkPrimitives,
+    EthVersion, ProtocolMessage, Status, StatusEth69,
 };
 use alloy_primitives::bytes::{Bytes, BytesMut};
 use alloy_rlp::Encodable;
@@ -36,12 +36,23 @@ pub(crate) const MAX_STATUS_SIZE: usize = 500 * 1024;
 pub struct UnauthedEthStream<S> {
     #[pin]
     inner: S,
+    /// The range of blocks the local node can serve, announced in the `eth/69` status.
+    block_range: Option<BlockRangeUpdate>,
 }
 
 impl<S> UnauthedEthStream<S> {
     /// Create a new `UnauthedEthStream` from a type `S` which implements `Stream` and `Sink`.
     pub const fn new(inner: S) -> Self {
-        Self { inner }
+        Self { inner, block_range: None }
+    }
+
+    /// Sets the range of blocks the local node can serve.
+    ///
+    /// This is required for the `eth/69` handshake, whose status announces the range instead of
+    /// the total difficulty.
+    pub const fn with_block_range(mut self, block_range: BlockRangeUpdate) -> Self {
+        self.block_range = Some(block_range);
+        self
     }
 
     /// Consumes the type and returns the wrapped stream
@@ -89,14 +100,20 @@ where
             "sending eth status to peer"
         );
 
+        // eth/69 peers expect a status message with the block range instead of the total
+        // difficulty
+        let our_status = if status.version.is_eth69() {
+            let Some(block_range) = self.block_range else {
+                return Err(EthHandshakeError::MissingBlockRange.into())
+            };
+            EthMessage::<N>::Status69(StatusEth69::new(status, block_range))
+        } else {
+            EthMessage::<N>::Status(status)
+        };
+
         // we need to encode and decode here on our own because we don't have an `EthStream` yet
         // The max length for a status with TTD is: <msg id = 1 byte> + <rlp(status) = 88 byte>
-        self.inner
-            .send(
-                alloy_rlp::encode(ProtocolMessage::<N>::from(EthMessage::<N>::Status(status)))
-                    .into(),
-            )
-            .await?;
+        self.inner.send(alloy_rlp::encode(ProtocolMessage::<N>::from(our_status)).into()).await?;
 
         let their_msg_res = self.inner.next().await;
 
@@ -125,69 +142,81 @@ where
 
         // The following checks should match the checks in go-ethereum:
         // https://github.com/ethereum/go-ethereum/blob/9244d5cd61f3ea5a7645fdf2a1a96d53421e412f/eth/protocols/eth/handshake.go#L87-L89
-        match msg.message {
-            EthMessage::Status(resp) => {
-                trace!(
-                    status=%resp,
-                    "validating incoming eth status from peer"
-                );
-                if status.genesis != resp.genesis {
-                    self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
-                    return Err(EthHandshakeError::MismatchedGenesis(
-                        GotExpected { expected: status.genesis, got: resp.genesis }.into(),
-                    )
-                    .into())
-                }
-
-                if status.version != resp.version {
-                    self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
-                    return Err(EthHandshakeError::MismatchedProtocolVersion(GotExpected {
-                        got: resp.version,
-                        expected: status.version,
-                    })
-                    .into())
-                }
-
-                if status.chain != resp.chain {
-                    self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
-                    return Err(EthHandshakeError::MismatchedChain(GotExpected {
-                        got: resp.chain,
-                        expected: status.chain,
-                    })
-                    .into())
-                }
-
-                // TD at mainnet block #7753254 is 76 bits. If it becomes 100 million times
-                // larger, it will still fit within 100 bits
-                if status.total_difficulty.bit_len() > 100 {
-                    self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
-                    return Err(EthHandshakeError::TotalDifficultyBitLenTooLarge {
-                        got: status.total_difficulty.bit_len(),
-                        maximum: 100,
-                    }
-                    .into())
-                }
-
-                if let Err(err) =
-                    fork_filter.validate(resp.forkid).map_err(EthHandshakeError::InvalidFork)
-                {
-                    self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
-                    return Err(err.into())
-                }
-
-                // now we can create the `EthStream` because the peer has successfully completed
-                // the handshake
-                let stream = EthStream::new(version, self.inner);
-
-                Ok((stream, resp))
-            }
+        let (resp, peer_block_range) = match msg.message {
+            EthMessage::Status(resp) => (resp, None),
+            // eth/69 peers announce the range of blocks they can serve instead of the total
+            // difficulty
+            EthMessage::Status69(resp) => (resp.into(), Some(resp.block_range())),
             _ => {
                 self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
-                Err(EthStreamError::EthHandshakeError(
+                return Err(EthStreamError::EthHandshakeError(
                     EthHandshakeError::NonStatusMessageInHandshake,
                 ))
             }
+        };
+
+        trace!(
+            status=%resp,
+            "validating incoming eth status from peer"
+        );
+        if status.genesis != resp.genesis {
+            self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+            return Err(EthHandshakeError::MismatchedGenesis(
+                GotExpected { expected: status.genesis, got: resp.genesis }.into(),
+            )
+            .into())
+        }
+
+        if status.version != resp.version {
+            self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+            return Err(EthHandshakeError::MismatchedProtocolVersion(GotExpected {
+                got: resp.version,
+                expected: status.version,
+            })
+            .into())
+        }
+
+        if status.chain != resp.chain {
+            self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+            return Err(EthHandshakeError::MismatchedChain(GotExpected {
+                got: resp.chain,
+                expected: status.chain,
+            })
+            .into())
+        }
+
+        // TD at mainnet block #7753254 is 76 bits. If it becomes 100 million times
+        // larger, it will still fit within 100 bits
+        if status.total_difficulty.bit_len() > 100 {
+            self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+            return Err(EthHandshakeError::TotalDifficultyBitLenTooLarge {
+                got: status.total_difficulty.bit_len(),
+                maximum: 100,
+            }
+            .into())
         }
+
+        if let Some(range) = peer_block_range.filter(|range| range.earliest > range.latest) {
+            self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+            return Err(EthHandshakeError::InvalidBlockRange {
+                earliest: range.earliest,
+                latest: range.latest,
+            }
+            .into())
+        }
+
+        if let Err(err) = fork_filter.validate(resp.forkid).map_err(EthHandshakeError::InvalidFork)
+        {
+            self.inner.disconnect(DisconnectReason::ProtocolBreach).await?;
+            return Err(err.into())
+        }
+
+        // now we can create the `EthStream` because the peer has successfully completed
+        // the handshake
+        let mut stream = EthStream::new(version, self.inner);
+        stream.peer_block_range = peer_block_range;
+
+        Ok((stream, resp))
     }
 }
 
@@ -198,6 +227,8 @@ where
 pub struct EthStream<S, N = EthNetworkPrimitives> {
     /// Negotiated eth version.
     version: EthVersion,
+    /// The range of blocks the peer announced in its `eth/69` status.
+    peer_block_range: Option<BlockRangeUpdate>,
     #[pin]
     inner: S,
 
@@ -209,7 +240,7 @@ impl<S, N> EthStream<S, N> {
     /// to manually handshake a peer.
     #[inline]
     pub const fn new(version: EthVersion, inner: S) -> Self {
-        Self { version, inner, _pd: std::marker::PhantomData }
+        Self { version, peer_block_range: None, inner, _pd: std::marker::PhantomData }
     }
 
     /// Returns the eth version.
@@ -218,6 +249,12 @@ impl<S, N> EthStream<S, N> {
         self.version
     }
 
+    /// Returns the range of blocks the peer announced in its `eth/69` status, if any.
+    #[inline]
+    pub const fn peer_block_range(&self) -> Option<BlockRangeUpdate> {
+        self.peer_block_range
+    }
+
     /// Returns the underlying stream.
     #[inline]
     pub const fn inner(&self) -> &S {
@@ -304,7 +341,7 @@ where
             }
         };
 
-        if matches!(msg.message, EthMessage::Status(_)) {
+        if matches!(msg.message, EthMessage::Status(_) | EthMessage::Status69(_)) {
             return Poll::Ready(Some(Err(EthStreamError::EthHandshakeError(
                 EthHandshakeError::StatusNotInHandshake,
             ))))
@@ -327,7 +364,7 @@ where
     }
 
     fn start_send(self: Pin<&mut Self>, item: EthMessage<N>) -> Result<(), Self::Error> {
-        if matches!(item, EthMessage::Status(_)) {
+        if matches!(item, EthMessage::Status(_) | EthMessage::Status69(_)) {
             // TODO: to disconnect here we would need to do something similar to P2PStream's
             // start_disconnect, which would ideally be a part of the CanDisconnect trait, or at
             // least similar.
@@ -376,8 +413,8 @@ mod tests {
         ethstream::RawCapabilityMessage,
         hello::DEFAULT_TCP_PORT,
         p2pstream::UnauthedP2PStream,
-        EthMessage, EthStream, EthVersion, HelloMessageWithProtocols, PassthroughCodec,
-        ProtocolVersion, Status,
+        BlockRangeUpdate, EthMessage, EthStream, EthVersion, HelloMessageWithProtocols,
+        PassthroughCodec, ProtocolVersion, Status,
     };
     use alloy_chains::NamedChain;
     use alloy_primitives::{bytes::Bytes, B256, U256};
@@ -441,6 +478,65 @@ mod tests {
         handle.await.unwrap();
     }
 
+    #[tokio::test]
+    async fn can_handshake_eth69() {
+        let genesis = B256::random();
+        let fork_filter = ForkFilter::new(Head::default(), genesis, 0, Vec::new());
+
+        let status = Status {
+            version: EthVersion::Eth69,
+            chain: NamedChain::Mainnet.into(),
+            total_difficulty: U256::from(100),
+            blockhash: B256::random(),
+            genesis,
+            forkid: fork_filter.current(),
+        };
+        let block_range =
+            BlockRangeUpdate { earliest: 10, latest: 100, latest_hash: status.blockhash };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let local_addr = listener.local_addr().unwrap();
+
+        let fork_filter_clone = fork_filter.clone();
+        let handle = tokio::spawn(async move {
+            let (incoming, _) = listener.accept().await.unwrap();
+            let stream = PassthroughCodec::default().framed(incoming);
+            let (stream, their_status) = UnauthedEthStream::new(stream)
+                .with_block_range(block_range)
+                .handshake::<EthNetworkPrimitives>(status, fork_filter_clone)
+                .await
+                .unwrap();
+
+            // the total difficulty isn't exchanged in eth/69
+            assert_eq!(their_status, Status { total_difficulty: U256::ZERO, ..status });
+            assert_eq!(stream.peer_block_range(), Some(block_range));
+        });
+
+        // the block range is required to send an eth/69 status
+        let unused = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let outgoing = TcpStream::connect(unused.local_addr().unwrap()).await.unwrap();
+        let sink = PassthroughCodec::default().framed(outgoing);
+        let err = UnauthedEthStream::new(sink)
+            .handshake::<EthNetworkPrimitives>(status, fork_filter.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            EthStreamError::EthHandshakeError(EthHandshakeError::MissingBlockRange)
+        ));
+
+        let outgoing = TcpStream::connect(local_addr).await.unwrap();
+        let sink = PassthroughCodec::default().framed(outgoing);
+        let (stream, _) = UnauthedEthStream::new(sink)
+            .with_block_range(block_range)
+            .handshake::<EthNetworkPrimitives>(status, fork_filter)
+            .await
+            .unwrap();
+        assert_eq!(stream.peer_block_range(), Some(block_range));
+
+        handle.await.unwrap();
+    }
+
     #[tokio::test]
     async fn pass_handshake_on_low_td_bitlen() {
         let genesis = B256::random();
@@ -498,7 +594,7 @@ mod tests {
         let status = Status {
             version: EthVersion::Eth67,
             chain: NamedChain::Mainnet.into(),
-            total_difficulty: U256::from(2).pow(U256::from(100)),
+            total_difficulty: U256::from(100),
             blockhash: B256::random(),
             genesis,
             // Pass the current fork id.
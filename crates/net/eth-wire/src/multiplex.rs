@@ -20,7 +20,8 @@ use crate::{
     capability::{SharedCapabilities, SharedCapability, UnsupportedCapabilityError},
     errors::{EthStreamError, P2PStreamError},
     p2pstream::DisconnectP2P,
-    CanDisconnect, Capability, DisconnectReason, EthStream, P2PStream, Status, UnauthedEthStream,
+    BlockRangeUpdate, CanDisconnect, Capability, DisconnectReason, EthStream, P2PStream, Status,
+    UnauthedEthStream,
 };
 use bytes::{Bytes, BytesMut};
 use futures::{Sink, SinkExt, Stream, StreamExt, TryStream, TryStreamExt};
@@ -205,9 +206,13 @@ impl<St> RlpxProtocolMultiplexer<St> {
 
     /// Converts this multiplexer into a [`RlpxSatelliteStream`] with eth protocol as the given
     /// primary protocol.
+    ///
+    /// The block range is required for the `eth/69` handshake, see
+    /// [`UnauthedEthStream::with_block_range`].
     pub async fn into_eth_satellite_stream<N: NetworkPrimitives>(
         self,
         status: Status,
+        block_range: Option<BlockRangeUpdate>,
         fork_filter: ForkFilter,
     ) -> Result<(RlpxSatelliteStream<St, EthStream<ProtocolProxy, N>>, Status), EthStreamError>
     where
@@ -217,7 +222,11 @@ impl<St> RlpxProtocolMultiplexer<St> {
         self.into_satellite_stream_with_tuple_handshake(
             &Capability::eth(eth_cap),
             move |proxy| async move {
-                UnauthedEthStream::new(proxy).handshake(status, fork_filter).await
+                let mut unauthed = UnauthedEthStream::new(proxy);
+                if let Some(block_range) = block_range {
+                    unauthed = unauthed.with_block_range(block_range);
+                }
+                unauthed.handshake(status, fork_filter).await
             },
         )
         .await
@@ -735,7 +744,11 @@ mod tests {
             let (conn, _) = UnauthedP2PStream::new(stream).handshake(server_hello).await.unwrap();
 
             let (mut st, _their_status) = RlpxProtocolMultiplexer::new(conn)
-                .into_eth_satellite_stream::<EthNetworkPrimitives>(other_status, other_fork_filter)
+                .into_eth_satellite_stream::<EthNetworkPrimitives>(
+                    other_status,
+                    None,
+                    other_fork_filter,
+                )
                 .await
                 .unwrap();
 
@@ -766,7 +779,7 @@ mod tests {
 
         let conn = connect_passthrough(local_addr, test_hello().0).await;
         let (mut st, _their_status) = RlpxProtocolMultiplexer::new(conn)
-            .into_eth_satellite_stream::<EthNetworkPrimitives>(status, fork_filter)
+            .into_eth_satellite_stream::<EthNetworkPrimitives>(status, None, fork_filter)
             .await
             .unwrap();
 
@@ -45,6 +45,11 @@ impl Protocol {
         Self::eth(EthVersion::Eth68)
     }
 
+    /// Returns the [`EthVersion::Eth69`] capability.
+    pub const fn eth_69() -> Self {
+        Self::eth(EthVersion::Eth69)
+    }
+
     /// Consumes the type and returns a tuple of the [Capability] and number of messages.
     #[inline]
     pub(crate) fn split(self) -> (Capability, u8) {
@@ -53,8 +58,11 @@ impl Protocol {
 
     /// The number of values needed to represent all message IDs of capability.
     pub fn messages(&self) -> u8 {
+        if self.cap.is_eth_v69() {
+            return EthMessageID::max(EthVersion::Eth69) + 1
+        }
         if self.cap.is_eth() {
-            return EthMessageID::max() + 1
+            return EthMessageID::max(EthVersion::Eth68) + 1
         }
         self.messages
     }
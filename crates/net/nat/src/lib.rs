@@ -1,4 +1,4 @@
-//! Helpers for resolving the external IP.
+//! Helpers for resolving the external IP and mapping ports on the gateway.
 //!
 //! ## Feature Flags
 //!
@@ -13,8 +13,13 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 pub mod net_if;
+pub mod port_mapping;
 
 pub use net_if::{NetInterfaceError, DEFAULT_NET_IF_NAME};
+pub use port_mapping::{
+    MappedPorts, PortMapper, PortMappingError, PortMappingInterval, PortMappingMethod,
+    PortProtocol, DEFAULT_PORT_MAPPING_LEASE,
+};
 
 use std::{
     fmt,
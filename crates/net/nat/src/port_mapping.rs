@@ -0,0 +1,382 @@
+//! Port mapping on the local gateway via `UPnP` and NAT-PMP.
+//!
+//! Nodes behind a router are not dialable unless the router forwards the listening ports. The
+//! [`PortMapper`] asks the gateway to forward the ports to the local node, first via `UPnP` and if
+//! that fails via NAT-PMP, and resolves the external address of the gateway. Mappings are leased,
+//! [`PortMappingInterval`] renews them before the lease expires.
+
+use std::{
+    fmt,
+    future::{poll_fn, Future},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use igd_next::{
+    aio::{tokio::Tokio, Gateway},
+    PortMappingProtocol, SearchOptions,
+};
+use natpmp::{NatpmpAsync, Protocol, Response};
+use tokio::net::UdpSocket;
+use tracing::{debug, trace};
+
+/// The default duration of a port mapping lease.
+pub const DEFAULT_PORT_MAPPING_LEASE: Duration = Duration::from_secs(20 * 60);
+
+/// How long to wait for a `UPnP` gateway to respond to the search.
+const UPNP_SEARCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The description of the port mappings, shown in the router's interface.
+const PORT_MAPPING_DESCRIPTION: &str = "reth";
+
+/// The transport protocol of a mapped port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PortProtocol {
+    /// TCP, e.g. the `RLPx` listener.
+    Tcp,
+    /// UDP, e.g. discovery.
+    Udp,
+}
+
+impl fmt::Display for PortProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp => f.write_str("tcp"),
+            Self::Udp => f.write_str("udp"),
+        }
+    }
+}
+
+/// The protocol a gateway mapped the ports with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PortMappingMethod {
+    /// Universal Plug and Play, Internet Gateway Device protocol.
+    Upnp,
+    /// NAT Port Mapping Protocol.
+    NatPmp,
+}
+
+impl fmt::Display for PortMappingMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Upnp => f.write_str("upnp"),
+            Self::NatPmp => f.write_str("nat-pmp"),
+        }
+    }
+}
+
+/// Error when mapping ports on the gateway.
+#[derive(Debug, thiserror::Error)]
+pub enum PortMappingError {
+    /// Neither a `UPnP` nor a NAT-PMP gateway mapped the ports.
+    #[error("no gateway mapped the ports, upnp: {upnp}, nat-pmp: {nat_pmp}")]
+    NoGateway {
+        /// Why mapping via `UPnP` failed.
+        upnp: String,
+        /// Why mapping via NAT-PMP failed.
+        nat_pmp: String,
+    },
+    /// Failed to map the ports via `UPnP`.
+    #[error("{0}")]
+    Upnp(String),
+    /// Failed to map the ports via NAT-PMP.
+    #[error("{0}")]
+    NatPmp(String),
+}
+
+/// A successful port mapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MappedPorts {
+    /// The protocol the ports were mapped with.
+    pub method: PortMappingMethod,
+    /// The external address of the gateway.
+    pub external_ip: IpAddr,
+}
+
+/// A gateway that mapped the ports.
+enum MappingGateway {
+    Upnp(Gateway<Tokio>),
+    NatPmp(NatpmpAsync<UdpSocket>),
+}
+
+/// Maps ports on the local gateway, the external ports are the same as the local ports.
+pub struct PortMapper {
+    ports: Vec<(PortProtocol, u16)>,
+    lease: Duration,
+    /// The gateway of the last successful mapping, reused when renewing the lease.
+    gateway: Option<MappingGateway>,
+}
+
+impl fmt::Debug for PortMapper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PortMapper")
+            .field("ports", &self.ports)
+            .field("lease", &self.lease)
+            .field(
+                "gateway",
+                &self.gateway.as_ref().map(|gateway| match gateway {
+                    MappingGateway::Upnp(_) => PortMappingMethod::Upnp,
+                    MappingGateway::NatPmp(_) => PortMappingMethod::NatPmp,
+                }),
+            )
+            .finish()
+    }
+}
+
+impl PortMapper {
+    /// Creates a new mapper for the given ports, leased for the given duration.
+    pub fn new(ports: impl IntoIterator<Item = (PortProtocol, u16)>, lease: Duration) -> Self {
+        Self { ports: ports.into_iter().collect(), lease, gateway: None }
+    }
+
+    /// Returns the ports to map.
+    pub fn ports(&self) -> &[(PortProtocol, u16)] {
+        &self.ports
+    }
+
+    /// Returns the duration of the lease.
+    pub const fn lease(&self) -> Duration {
+        self.lease
+    }
+
+    /// Maps the ports, or renews the lease of an existing mapping.
+    ///
+    /// If there is no mapping yet, or renewing it fails, this searches for a `UPnP` gateway first
+    /// and then for a NAT-PMP gateway.
+    pub async fn map_ports(&mut self) -> Result<MappedPorts, PortMappingError> {
+        if let Some(gateway) = &self.gateway {
+            match self.map_with(gateway).await {
+                Ok(mapped) => return Ok(mapped),
+                Err(err) => {
+                    debug!(target: "net::nat", %err, "Failed to renew port mapping, searching gateway");
+                    self.gateway = None;
+                }
+            }
+        }
+
+        let upnp = match self.search_upnp().await {
+            Ok(gateway) => match self.map_with(&gateway).await {
+                Ok(mapped) => {
+                    self.gateway = Some(gateway);
+                    return Ok(mapped)
+                }
+                Err(err) => err,
+            },
+            Err(err) => err,
+        };
+        trace!(target: "net::nat", %upnp, "Failed to map ports via UPnP");
+
+        let nat_pmp = match natpmp::new_tokio_natpmp().await {
+            Ok(client) => {
+                let gateway = MappingGateway::NatPmp(client);
+                match self.map_with(&gateway).await {
+                    Ok(mapped) => {
+                        self.gateway = Some(gateway);
+                        return Ok(mapped)
+                    }
+                    Err(err) => err,
+                }
+            }
+            Err(err) => PortMappingError::NatPmp(err.to_string()),
+        };
+        trace!(target: "net::nat", %nat_pmp, "Failed to map ports via NAT-PMP");
+
+        Err(PortMappingError::NoGateway { upnp: upnp.to_string(), nat_pmp: nat_pmp.to_string() })
+    }
+
+    async fn search_upnp(&self) -> Result<MappingGateway, PortMappingError> {
+        let options = SearchOptions { timeout: Some(UPNP_SEARCH_TIMEOUT), ..Default::default() };
+        igd_next::aio::tokio::search_gateway(options)
+            .await
+            .map(MappingGateway::Upnp)
+            .map_err(|err| PortMappingError::Upnp(err.to_string()))
+    }
+
+    async fn map_with(&self, gateway: &MappingGateway) -> Result<MappedPorts, PortMappingError> {
+        let lease = self.lease.as_secs().try_into().unwrap_or(u32::MAX);
+        match gateway {
+            MappingGateway::Upnp(gateway) => {
+                let local_ip = local_ip_towards(gateway.addr)
+                    .await
+                    .map_err(|err| PortMappingError::Upnp(err.to_string()))?;
+                for (protocol, port) in &self.ports {
+                    let protocol = match protocol {
+                        PortProtocol::Tcp => PortMappingProtocol::TCP,
+                        PortProtocol::Udp => PortMappingProtocol::UDP,
+                    };
+                    gateway
+                        .add_port(
+                            protocol,
+                            *port,
+                            SocketAddr::new(local_ip, *port),
+                            lease,
+                            PORT_MAPPING_DESCRIPTION,
+                        )
+                        .await
+                        .map_err(|err| PortMappingError::Upnp(err.to_string()))?;
+                }
+                let external_ip = gateway
+                    .get_external_ip()
+                    .await
+                    .map_err(|err| PortMappingError::Upnp(err.to_string()))?;
+                Ok(MappedPorts { method: PortMappingMethod::Upnp, external_ip })
+            }
+            MappingGateway::NatPmp(client) => {
+                let nat_pmp_err = |err: natpmp::Error| PortMappingError::NatPmp(err.to_string());
+                for (protocol, port) in &self.ports {
+                    let protocol = match protocol {
+                        PortProtocol::Tcp => Protocol::TCP,
+                        PortProtocol::Udp => Protocol::UDP,
+                    };
+                    client
+                        .send_port_mapping_request(protocol, *port, *port, lease)
+                        .await
+                        .map_err(nat_pmp_err)?;
+                    match client.read_response_or_retry().await.map_err(nat_pmp_err)? {
+                        Response::TCP(mapping) | Response::UDP(mapping) => {
+                            if mapping.public_port() != *port {
+                                debug!(target: "net::nat", port, public_port = mapping.public_port(), "Gateway mapped a different external port");
+                            }
+                        }
+                        Response::Gateway(_) => {
+                            return Err(PortMappingError::NatPmp("unexpected response".to_string()))
+                        }
+                    }
+                }
+
+                client.send_public_address_request().await.map_err(nat_pmp_err)?;
+                match client.read_response_or_retry().await.map_err(nat_pmp_err)? {
+                    Response::Gateway(response) => Ok(MappedPorts {
+                        method: PortMappingMethod::NatPmp,
+                        external_ip: IpAddr::V4(*response.public_address()),
+                    }),
+                    _ => Err(PortMappingError::NatPmp("unexpected response".to_string())),
+                }
+            }
+        }
+    }
+}
+
+/// Returns the local address that is used to reach the given address.
+async fn local_ip_towards(addr: SocketAddr) -> std::io::Result<IpAddr> {
+    let unspecified: IpAddr =
+        if addr.is_ipv4() { Ipv4Addr::UNSPECIFIED.into() } else { Ipv6Addr::UNSPECIFIED.into() };
+    let socket = UdpSocket::bind(SocketAddr::new(unspecified, 0)).await?;
+    // connecting a UDP socket sends nothing, it only selects the route
+    socket.connect(addr).await?;
+    Ok(socket.local_addr()?.ip())
+}
+
+/// Returns whether the external address of a gateway is reachable from the internet.
+///
+/// Gateways that are behind another NAT, e.g. carrier-grade NAT, report a private address, mapping
+/// ports on them does not make the node dialable.
+pub fn is_public_gateway_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            // 100.64.0.0/10 is reserved for carrier-grade NAT
+            let shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0b1100_0000) == 0b0100_0000;
+            !(ip.is_private() ||
+                ip.is_loopback() ||
+                ip.is_link_local() ||
+                ip.is_unspecified() ||
+                shared)
+        }
+        IpAddr::V6(ip) => !(ip.is_loopback() || ip.is_unspecified()),
+    }
+}
+
+/// Maps ports on the gateway and renews the mapping before the lease expires.
+///
+/// The first mapping is attempted immediately, then every half lease. Failed attempts are retried
+/// with the next renewal.
+#[must_use = "Does nothing unless polled"]
+pub struct PortMappingInterval {
+    /// The mapper, `None` while a mapping is in progress.
+    mapper: Option<PortMapper>,
+    #[allow(clippy::type_complexity)]
+    future: Option<
+        Pin<Box<dyn Future<Output = (PortMapper, Result<MappedPorts, PortMappingError>)> + Send>>,
+    >,
+    interval: tokio::time::Interval,
+}
+
+impl fmt::Debug for PortMappingInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PortMappingInterval")
+            .field("mapper", &self.mapper)
+            .field("future", &self.future.as_ref().map(drop))
+            .field("interval", &self.interval)
+            .finish()
+    }
+}
+
+impl PortMappingInterval {
+    /// Creates a new [`PortMappingInterval`] for the given mapper.
+    #[track_caller]
+    pub fn new(mapper: PortMapper) -> Self {
+        let interval = tokio::time::interval(mapper.lease() / 2);
+        Self { mapper: Some(mapper), future: None, interval }
+    }
+
+    /// Completes when the next mapping attempt has finished.
+    pub async fn tick(&mut self) -> Result<MappedPorts, PortMappingError> {
+        poll_fn(|cx| self.poll_tick(cx)).await
+    }
+
+    /// Polls for the next mapping attempt to finish.
+    pub fn poll_tick(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<MappedPorts, PortMappingError>> {
+        if self.interval.poll_tick(cx).is_ready() {
+            if let Some(mut mapper) = self.mapper.take() {
+                self.future = Some(Box::pin(async move {
+                    let res = mapper.map_ports().await;
+                    (mapper, res)
+                }));
+            }
+        }
+
+        if let Some(mut fut) = self.future.take() {
+            match fut.as_mut().poll(cx) {
+                Poll::Ready((mapper, res)) => {
+                    self.mapper = Some(mapper);
+                    return Poll::Ready(res)
+                }
+                Poll::Pending => self.future = Some(fut),
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    #[ignore]
+    async fn map_ports() {
+        reth_tracing::init_test_tracing();
+        let mut mapper = PortMapper::new(
+            [(PortProtocol::Tcp, 30303), (PortProtocol::Udp, 30303)],
+            DEFAULT_PORT_MAPPING_LEASE,
+        );
+        let mapped = mapper.map_ports().await;
+        dbg!(mapped);
+    }
+
+    #[test]
+    fn public_gateway_ip() {
+        assert!(is_public_gateway_ip("1.1.1.1".parse().unwrap()));
+        assert!(is_public_gateway_ip("100.128.0.1".parse().unwrap()));
+        assert!(!is_public_gateway_ip("192.168.1.1".parse().unwrap()));
+        assert!(!is_public_gateway_ip("10.0.0.1".parse().unwrap()));
+        assert!(!is_public_gateway_ip("100.64.0.1".parse().unwrap()));
+        assert!(!is_public_gateway_ip("0.0.0.0".parse().unwrap()));
+    }
+}
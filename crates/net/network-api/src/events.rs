@@ -9,7 +9,7 @@ use reth_eth_wire_types::{
 use reth_ethereum_forks::ForkId;
 use reth_network_p2p::error::{RequestError, RequestResult};
 use reth_network_peers::PeerId;
-use reth_network_types::PeerAddr;
+use reth_network_types::{PeerAddr, Reputation};
 use reth_tokio_util::EventStream;
 use std::{
     fmt,
@@ -88,6 +88,13 @@ pub enum PeerEvent {
     PeerAdded(PeerId),
     /// Event emitted when a new peer is removed
     PeerRemoved(PeerId),
+    /// Event emitted when the reputation of a peer changed
+    ReputationChanged {
+        /// The identifier of the peer.
+        peer_id: PeerId,
+        /// The new reputation of the peer.
+        reputation: Reputation,
+    },
 }
 
 /// (Non-exhaustive) Network events representing peer lifecycle events and session requests.
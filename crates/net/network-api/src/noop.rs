@@ -11,7 +11,11 @@ use reth_eth_wire_types::{DisconnectReason, ProtocolVersion};
 use reth_network_peers::NodeRecord;
 use reth_network_types::{PeerKind, Reputation, ReputationChangeKind};
 
-use crate::{NetworkError, NetworkInfo, NetworkStatus, PeerId, PeerInfo, Peers, PeersInfo};
+use crate::{
+    events::{PeerEvent, PeerEventStream},
+    NetworkError, NetworkInfo, NetworkPeersEvents, NetworkStatus, PeerId, PeerInfo, Peers,
+    PeersInfo,
+};
 
 /// A type that implements all network trait that does nothing.
 ///
@@ -117,3 +121,9 @@ impl Peers for NoopNetwork {
         Ok(None)
     }
 }
+
+impl NetworkPeersEvents for NoopNetwork {
+    fn peer_events(&self) -> PeerEventStream {
+        PeerEventStream::new(tokio_stream::pending::<PeerEvent>())
+    }
+}
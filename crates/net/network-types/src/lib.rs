@@ -27,6 +27,6 @@ pub use peers::{
     kind::PeerKind,
     reputation::{is_banned_reputation, ReputationChangeOutcome, DEFAULT_REPUTATION},
     state::PeerConnectionState,
-    ConnectionsConfig, Peer, PeersConfig,
+    ConnectionsConfig, Peer, PeersConfig, PersistedPeer,
 };
-pub use session::{SessionLimits, SessionsConfig};
+pub use session::{BandwidthLimits, CaptureConfig, ProxyConfig, SessionLimits, SessionsConfig};
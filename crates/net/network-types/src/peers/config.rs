@@ -3,7 +3,7 @@
 use std::{
     collections::HashSet,
     io::{self, ErrorKind},
-    path::Path,
+    path::{Path, PathBuf},
     time::Duration,
 };
 
@@ -24,6 +24,9 @@ pub const DEFAULT_MAX_COUNT_PEERS_INBOUND: u32 = 30;
 /// This restricts how many outbound dials can be performed concurrently.
 pub const DEFAULT_MAX_COUNT_CONCURRENT_OUTBOUND_DIALS: usize = 15;
 
+/// Number of persisted peers that are dialed first on startup.
+pub const DEFAULT_PERSISTED_PEERS_REDIALS: usize = 20;
+
 /// A temporary timeout for ips on incoming connection attempts.
 pub const INBOUND_IP_THROTTLE_DURATION: Duration = Duration::from_secs(30);
 
@@ -163,6 +166,15 @@ pub struct PeersConfig {
     /// This acts as an IP based rate limit.
     #[cfg_attr(feature = "serde", serde(default, with = "humantime_serde"))]
     pub incoming_ip_throttle_duration: Duration,
+    /// File to persist known-good peers to on shutdown and to load them from on startup.
+    ///
+    /// Unset by default, which disables the persistence. Requires the `serde` feature of the
+    /// network. See also [`PersistedPeer`](crate::PersistedPeer).
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub persist_path: Option<PathBuf>,
+    /// How many of the persisted peers with the highest dial priority are dialed first on
+    /// startup.
+    pub persisted_peers_redials: usize,
 }
 
 impl Default for PeersConfig {
@@ -180,6 +192,8 @@ impl Default for PeersConfig {
             basic_nodes: Default::default(),
             max_backoff_count: 5,
             incoming_ip_throttle_duration: INBOUND_IP_THROTTLE_DURATION,
+            persist_path: None,
+            persisted_peers_redials: DEFAULT_PERSISTED_PEERS_REDIALS,
         }
     }
 }
@@ -270,6 +284,19 @@ impl PeersConfig {
         self
     }
 
+    /// Configures the file to persist known-good peers to on shutdown and to load them from on
+    /// startup.
+    pub fn with_persist_path(mut self, persist_path: impl Into<PathBuf>) -> Self {
+        self.persist_path = Some(persist_path.into());
+        self
+    }
+
+    /// Configures how many of the persisted peers are dialed first on startup.
+    pub const fn with_persisted_peers_redials(mut self, persisted_peers_redials: usize) -> Self {
+        self.persisted_peers_redials = persisted_peers_redials;
+        self
+    }
+
     /// Returns the maximum number of peers, inbound and outbound.
     pub const fn max_peers(&self) -> usize {
         self.connection_info.max_outbound + self.connection_info.max_inbound
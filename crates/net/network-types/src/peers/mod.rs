@@ -1,12 +1,16 @@
 pub mod addr;
 pub mod config;
 pub mod kind;
+pub mod persist;
 pub mod reputation;
 pub mod state;
 
 pub use config::{ConnectionsConfig, PeersConfig};
+pub use persist::PersistedPeer;
 pub use reputation::{Reputation, ReputationChange, ReputationChangeKind, ReputationChangeWeights};
 
+use std::{sync::Arc, time::SystemTime};
+
 use reth_ethereum_forks::ForkId;
 use tracing::trace;
 
@@ -35,6 +39,10 @@ pub struct Peer {
     /// Counts number of times the peer was backed off due to a severe
     /// [`BackoffKind`](crate::BackoffKind).
     pub severe_backoff_counter: u8,
+    /// The client version the peer announced in its last session, if any.
+    pub client_version: Option<Arc<str>>,
+    /// When a session with the peer was last active, if any.
+    pub last_seen: Option<SystemTime>,
 }
 
 // === impl Peer ===
@@ -66,6 +74,8 @@ impl Peer {
             kind: Default::default(),
             backed_off: false,
             severe_backoff_counter: 0,
+            client_version: None,
+            last_seen: None,
         }
     }
 
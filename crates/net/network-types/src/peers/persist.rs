@@ -0,0 +1,100 @@
+//! Snapshot of known-good peers that is persisted across restarts.
+
+use std::{
+    cmp::Ordering,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reth_network_peers::NodeRecord;
+
+use crate::Peer;
+
+/// A known-good peer as it is persisted to disk on shutdown.
+///
+/// On startup, persisted peers are added to the peer set with their previous reputation and the
+/// ones with the highest [`PersistedPeer::dial_priority`] are dialed first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PersistedPeer {
+    /// Where to reach the peer.
+    pub record: NodeRecord,
+    /// The client version the peer announced in its last session.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    pub client_version: Option<String>,
+    /// Reputation of the peer.
+    pub reputation: i32,
+    /// When a session with the peer was last active, in seconds since the unix epoch.
+    pub last_seen: u64,
+}
+
+impl PersistedPeer {
+    /// Returns the persisted record for the given peer, if the peer is known-good.
+    ///
+    /// A peer is known-good if it is not banned and had an active session at some point, in
+    /// which case `last_seen` is set.
+    pub fn from_peer(record: NodeRecord, peer: &Peer) -> Option<Self> {
+        if peer.is_banned() {
+            return None
+        }
+        let last_seen = peer.last_seen?;
+        Some(Self {
+            record,
+            client_version: peer.client_version.as_deref().map(Into::into),
+            reputation: peer.reputation,
+            last_seen: last_seen.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs(),
+        })
+    }
+
+    /// Returns the time a session with the peer was last active.
+    pub fn last_seen(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.last_seen)
+    }
+
+    /// Compares the dial priority of two peers: peers with a higher reputation come first, ties
+    /// are broken by the most recently seen peer.
+    pub fn dial_priority(&self, other: &Self) -> Ordering {
+        other.reputation.cmp(&self.reputation).then_with(|| other.last_seen.cmp(&self.last_seen))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PeerAddr, DEFAULT_REPUTATION};
+    use reth_network_peers::PeerId;
+    use std::net::Ipv4Addr;
+
+    fn persisted(reputation: i32, last_seen: u64) -> PersistedPeer {
+        PersistedPeer {
+            record: NodeRecord::new((Ipv4Addr::LOCALHOST, 30303).into(), PeerId::repeat_byte(1)),
+            client_version: None,
+            reputation,
+            last_seen,
+        }
+    }
+
+    #[test]
+    fn only_seen_peers_are_persisted() {
+        let record = NodeRecord::new((Ipv4Addr::LOCALHOST, 30303).into(), PeerId::repeat_byte(1));
+        let mut peer = Peer::new(PeerAddr::from_tcp(record.tcp_addr()));
+        assert!(PersistedPeer::from_peer(record, &peer).is_none());
+
+        peer.last_seen = Some(UNIX_EPOCH + Duration::from_secs(100));
+        peer.client_version = Some("reth/v1.1.2".into());
+        let persisted = PersistedPeer::from_peer(record, &peer).unwrap();
+        assert_eq!(persisted.last_seen, 100);
+        assert_eq!(persisted.reputation, DEFAULT_REPUTATION);
+        assert_eq!(persisted.client_version.as_deref(), Some("reth/v1.1.2"));
+
+        peer.reputation = i32::MIN;
+        assert!(PersistedPeer::from_peer(record, &peer).is_none());
+    }
+
+    #[test]
+    fn sort_peers_by_dial_priority() {
+        let mut peers = vec![persisted(0, 10), persisted(10, 1), persisted(0, 20)];
+        peers.sort_by(PersistedPeer::dial_priority);
+        let order = peers.iter().map(|peer| (peer.reputation, peer.last_seen)).collect::<Vec<_>>();
+        assert_eq!(order, vec![(10, 1), (0, 20), (0, 10)]);
+    }
+}
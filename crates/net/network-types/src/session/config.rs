@@ -1,7 +1,7 @@
 //! Configuration types for peer sessions manager.
 
 use crate::peers::config::{DEFAULT_MAX_COUNT_PEERS_INBOUND, DEFAULT_MAX_COUNT_PEERS_OUTBOUND};
-use std::time::Duration;
+use std::{fmt, path::PathBuf, str::FromStr, time::Duration};
 
 /// Default request timeout for a single request.
 ///
@@ -16,6 +16,12 @@ pub const PENDING_SESSION_TIMEOUT: Duration = Duration::from_secs(20);
 /// This is the time a peer has to answer a response.
 pub const PROTOCOL_BREACH_REQUEST_TIMEOUT: Duration = Duration::from_secs(2 * 60);
 
+/// Default maximum size of a traffic capture file before it is rotated: 256MB
+pub const DEFAULT_CAPTURE_MAX_FILE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Default number of traffic capture files to keep, including the current one.
+pub const DEFAULT_CAPTURE_MAX_FILES: usize = 10;
+
 /// The default maximum number of peers.
 const DEFAULT_MAX_PEERS: usize =
     DEFAULT_MAX_COUNT_PEERS_OUTBOUND as usize + DEFAULT_MAX_COUNT_PEERS_INBOUND as usize;
@@ -50,6 +56,18 @@ pub struct SessionsConfig {
     pub protocol_breach_request_timeout: Duration,
     /// The timeout after which a pending session attempt is considered failed.
     pub pending_session_timeout: Duration,
+    /// Bandwidth limits to enforce.
+    ///
+    /// By default, no limits will be enforced.
+    pub bandwidth: BandwidthLimits,
+    /// Where to capture the messages of all sessions to.
+    ///
+    /// By default, no messages are captured.
+    pub capture: Option<CaptureConfig>,
+    /// The proxy outgoing connections are established through.
+    ///
+    /// By default, peers are dialed directly.
+    pub proxy: Option<ProxyConfig>,
 }
 
 impl Default for SessionsConfig {
@@ -69,6 +87,9 @@ impl Default for SessionsConfig {
             initial_internal_request_timeout: INITIAL_REQUEST_TIMEOUT,
             protocol_breach_request_timeout: PROTOCOL_BREACH_REQUEST_TIMEOUT,
             pending_session_timeout: PENDING_SESSION_TIMEOUT,
+            bandwidth: Default::default(),
+            capture: None,
+            proxy: None,
         }
     }
 }
@@ -100,6 +121,24 @@ impl SessionsConfig {
         }
         self
     }
+
+    /// Sets the bandwidth limits of the sessions.
+    pub const fn with_bandwidth_limits(mut self, bandwidth: BandwidthLimits) -> Self {
+        self.bandwidth = bandwidth;
+        self
+    }
+
+    /// Sets where to capture the messages of all sessions to.
+    pub fn with_capture(mut self, capture: CaptureConfig) -> Self {
+        self.capture = Some(capture);
+        self
+    }
+
+    /// Sets the proxy outgoing connections are established through.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
 }
 
 /// Limits for sessions.
@@ -144,10 +183,193 @@ impl SessionLimits {
     }
 }
 
+/// Bandwidth limits for sessions, in bytes per second.
+///
+/// The limits apply to the raw bytes of the connections, including the handshakes. Per-peer
+/// limits apply to each session individually, total limits to all sessions combined. Limits must
+/// be non-zero.
+///
+/// By default, no bandwidth limits will be enforced
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BandwidthLimits {
+    /// Maximum rate at which bytes are received from a single peer.
+    pub peer_ingress: Option<u64>,
+    /// Maximum rate at which bytes are sent to a single peer.
+    pub peer_egress: Option<u64>,
+    /// Maximum rate at which bytes are received from all peers.
+    pub total_ingress: Option<u64>,
+    /// Maximum rate at which bytes are sent to all peers.
+    pub total_egress: Option<u64>,
+}
+
+impl BandwidthLimits {
+    /// Sets the maximum rate at which bytes are received from a single peer.
+    pub const fn with_peer_ingress(mut self, bytes_per_sec: u64) -> Self {
+        self.peer_ingress = Some(bytes_per_sec);
+        self
+    }
+
+    /// Sets the maximum rate at which bytes are sent to a single peer.
+    pub const fn with_peer_egress(mut self, bytes_per_sec: u64) -> Self {
+        self.peer_egress = Some(bytes_per_sec);
+        self
+    }
+
+    /// Sets the maximum rate at which bytes are received from all peers.
+    pub const fn with_total_ingress(mut self, bytes_per_sec: u64) -> Self {
+        self.total_ingress = Some(bytes_per_sec);
+        self
+    }
+
+    /// Sets the maximum rate at which bytes are sent to all peers.
+    pub const fn with_total_egress(mut self, bytes_per_sec: u64) -> Self {
+        self.total_egress = Some(bytes_per_sec);
+        self
+    }
+}
+
+/// Configuration of the debug capture of the `RLPx` messages of all sessions.
+///
+/// Messages are appended to the file at `path`. Once it exceeds `max_file_size`, it is rotated to
+/// `path.1`, previously rotated files are shifted to `path.2` and so on, and files beyond
+/// `max_files` are removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CaptureConfig {
+    /// The path of the current capture file.
+    pub path: PathBuf,
+    /// The size in bytes after which the capture file is rotated.
+    pub max_file_size: u64,
+    /// The number of capture files to keep, including the current one.
+    pub max_files: usize,
+}
+
+impl CaptureConfig {
+    /// Creates a new capture config that writes to the given path, with default rotation limits.
+    pub const fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            max_file_size: DEFAULT_CAPTURE_MAX_FILE_SIZE,
+            max_files: DEFAULT_CAPTURE_MAX_FILES,
+        }
+    }
+
+    /// Sets the size in bytes after which the capture file is rotated.
+    pub const fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    /// Sets the number of capture files to keep, including the current one.
+    pub const fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+}
+
+/// Configuration of a SOCKS5 proxy that outgoing connections are established through.
+///
+/// Can be parsed from a URL of the form `socks5://[user:password@]host:port`.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProxyConfig {
+    /// The host name or IP address of the proxy.
+    pub host: String,
+    /// The port of the proxy.
+    pub port: u16,
+    /// The username and password to authenticate with, if any.
+    pub credentials: Option<(String, String)>,
+    /// Whether to dial peers directly if the connection through the proxy fails.
+    pub direct_fallback: bool,
+}
+
+impl ProxyConfig {
+    /// Creates a new proxy config for the proxy at the given host and port, without credentials
+    /// and fallback.
+    pub fn new(host: impl Into<String>, port: u16) -> Self {
+        Self { host: host.into(), port, credentials: None, direct_fallback: false }
+    }
+
+    /// Sets the username and password to authenticate with.
+    pub fn with_credentials(mut self, username: String, password: String) -> Self {
+        self.credentials = Some((username, password));
+        self
+    }
+
+    /// Sets whether to dial peers directly if the connection through the proxy fails.
+    pub const fn with_direct_fallback(mut self, direct_fallback: bool) -> Self {
+        self.direct_fallback = direct_fallback;
+        self
+    }
+}
+
+impl fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // the password is not included
+        f.debug_struct("ProxyConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("username", &self.credentials.as_ref().map(|(username, _)| username))
+            .field("direct_fallback", &self.direct_fallback)
+            .finish()
+    }
+}
+
+impl fmt::Display for ProxyConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "socks5://{}:{}", self.host, self.port)
+    }
+}
+
+impl FromStr for ProxyConfig {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s.strip_prefix("socks5://").ok_or_else(|| {
+            format!("unsupported proxy {s}, expected socks5://[user:password@]host:port")
+        })?;
+        let (credentials, address) = match rest.rsplit_once('@') {
+            Some((credentials, address)) => {
+                let (username, password) = credentials.split_once(':').ok_or_else(|| {
+                    "proxy credentials must be of the form user:password".to_string()
+                })?;
+                (Some((username.to_string(), password.to_string())), address)
+            }
+            None => (None, rest),
+        };
+        let (host, port) =
+            address.rsplit_once(':').ok_or_else(|| format!("missing port in proxy {s}"))?;
+        let port = port.parse().map_err(|err| format!("invalid proxy port {port}: {err}"))?;
+        // strip the brackets of IPv6 addresses
+        let host = host.strip_prefix('[').and_then(|host| host.strip_suffix(']')).unwrap_or(host);
+        if host.is_empty() {
+            return Err(format!("missing host in proxy {s}"))
+        }
+
+        Ok(Self { host: host.to_string(), port, credentials, direct_fallback: false })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn parse_proxy_config() {
+        let proxy: ProxyConfig = "socks5://127.0.0.1:9050".parse().unwrap();
+        assert_eq!(proxy, ProxyConfig::new("127.0.0.1", 9050));
+
+        let proxy: ProxyConfig = "socks5://user:secret@[::1]:1080".parse().unwrap();
+        assert_eq!(
+            proxy,
+            ProxyConfig::new("::1", 1080).with_credentials("user".into(), "secret".into())
+        );
+
+        assert!("http://127.0.0.1:8080".parse::<ProxyConfig>().is_err());
+        assert!("socks5://127.0.0.1".parse::<ProxyConfig>().is_err());
+    }
+
     #[test]
     fn scale_session_event_buffer() {
         let config = SessionsConfig::default().with_upscaled_event_buffer(10);
@@ -2,7 +2,8 @@
 
 use crate::{
     eth_requests::EthRequestHandler,
-    transactions::{TransactionsManager, TransactionsManagerConfig},
+    protocol::IntoRlpxSubProtocol,
+    transactions::{TransactionPropagationPolicy, TransactionsManager, TransactionsManagerConfig},
     NetworkHandle, NetworkManager,
 };
 use reth_eth_wire::{EthNetworkPrimitives, NetworkPrimitives};
@@ -62,10 +63,20 @@ impl<Tx, Eth, N: NetworkPrimitives> NetworkBuilder<Tx, Eth, N> {
         let (tx, rx) = mpsc::channel(ETH_REQUEST_CHANNEL_CAPACITY);
         network.set_eth_request_handler(tx);
         let peers = network.handle().peers_handle().clone();
-        let request_handler = EthRequestHandler::new(client, peers, rx);
+        let request_handler =
+            EthRequestHandler::new(client, peers, rx).with_network(network.handle().clone());
         NetworkBuilder { network, request_handler, transactions }
     }
 
+    /// Adds an additional `RLPx` sub-protocol that shares the sessions of the network.
+    ///
+    /// To register a sub-protocol after the network was launched, use
+    /// [`NetworkProtocols::add_rlpx_sub_protocol`](crate::NetworkProtocols::add_rlpx_sub_protocol).
+    pub fn add_rlpx_sub_protocol(mut self, protocol: impl IntoRlpxSubProtocol) -> Self {
+        self.network.add_rlpx_sub_protocol(protocol);
+        self
+    }
+
     /// Creates a new [`TransactionsManager`] and wires it to the network.
     pub fn transactions<Pool: TransactionPool>(
         self,
@@ -80,3 +91,16 @@ impl<Tx, Eth, N: NetworkPrimitives> NetworkBuilder<Tx, Eth, N> {
         NetworkBuilder { network, request_handler, transactions }
     }
 }
+
+impl<Pool: TransactionPool, Eth, N: NetworkPrimitives>
+    NetworkBuilder<TransactionsManager<Pool, N>, Eth, N>
+{
+    /// Sets the [`TransactionPropagationPolicy`] of the [`TransactionsManager`].
+    pub fn transaction_propagation_policy(
+        mut self,
+        policy: impl TransactionPropagationPolicy,
+    ) -> Self {
+        self.transactions.set_propagation_policy(policy);
+        self
+    }
+}
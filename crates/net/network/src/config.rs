@@ -83,6 +83,8 @@ pub struct NetworkConfig<C, N: NetworkPrimitives = EthNetworkPrimitives> {
     pub transactions_manager_config: TransactionsManagerConfig,
     /// The NAT resolver for external IP
     pub nat: Option<NatResolver>,
+    /// Whether to map the listening ports on the gateway via `UPnP` or NAT-PMP.
+    pub port_mapping: bool,
 }
 
 // === impl NetworkConfig ===
@@ -207,6 +209,8 @@ pub struct NetworkConfigBuilder<N: NetworkPrimitives = EthNetworkPrimitives> {
     transactions_manager_config: TransactionsManagerConfig,
     /// The NAT resolver for external IP
     nat: Option<NatResolver>,
+    /// Whether to map the listening ports on the gateway
+    port_mapping: bool,
 }
 
 // === impl NetworkConfigBuilder ===
@@ -239,6 +243,7 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             block_import: None,
             transactions_manager_config: Default::default(),
             nat: None,
+            port_mapping: false,
         }
     }
 
@@ -432,6 +437,14 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
     // Disable nat
     pub const fn disable_nat(mut self) -> Self {
         self.nat = None;
+        self.port_mapping = false;
+        self
+    }
+
+    /// Sets whether to map the listening and discovery ports on the gateway via `UPnP` or NAT-PMP,
+    /// announcing the external address of the gateway.
+    pub const fn port_mapping(mut self, enabled: bool) -> Self {
+        self.port_mapping = enabled;
         self
     }
 
@@ -540,6 +553,7 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             block_import,
             transactions_manager_config,
             nat,
+            port_mapping,
         } = self;
 
         discovery_v5_builder = discovery_v5_builder.map(|mut builder| {
@@ -607,6 +621,7 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             tx_gossip_disabled,
             transactions_manager_config,
             nat,
+            port_mapping,
         }
     }
 }
@@ -12,6 +12,10 @@ use reth_dns_discovery::{
     DnsDiscoveryConfig, DnsDiscoveryHandle, DnsDiscoveryService, DnsNodeRecordUpdate, DnsResolver,
 };
 use reth_ethereum_forks::{EnrForkIdEntry, ForkId};
+use reth_net_nat::{
+    port_mapping::is_public_gateway_ip, MappedPorts, PortMapper, PortMappingError,
+    PortMappingInterval, PortProtocol, DEFAULT_PORT_MAPPING_LEASE,
+};
 use reth_network_api::{DiscoveredEvent, DiscoveryEvent};
 use reth_network_peers::{NodeRecord, PeerId};
 use reth_network_types::PeerAddr;
@@ -25,7 +29,7 @@ use std::{
 };
 use tokio::{sync::mpsc, task::JoinHandle};
 use tokio_stream::{wrappers::ReceiverStream, Stream};
-use tracing::trace;
+use tracing::{debug, info, trace};
 
 /// Default max capacity for cache of discovered peers.
 ///
@@ -60,6 +64,8 @@ pub struct Discovery {
     dns_discovery_updates: Option<ReceiverStream<DnsNodeRecordUpdate>>,
     /// The handle to the spawned DNS discovery service
     _dns_disc_service: Option<JoinHandle<()>>,
+    /// Maps the listening ports on the gateway, if enabled.
+    port_mapping: Option<PortMappingInterval>,
     /// Events buffered until polled.
     queued_events: VecDeque<DiscoveryEvent>,
     /// List of listeners subscribed to discovery events.
@@ -134,9 +140,53 @@ impl Discovery {
             _dns_disc_service,
             _dns_discovery,
             dns_discovery_updates,
+            port_mapping: None,
         })
     }
 
+    /// Maps the `RLPx` and discovery ports on the gateway via `UPnP` or NAT-PMP and announces the
+    /// external address of the gateway in the local ENRs.
+    pub(crate) fn enable_port_mapping(&mut self, tcp_port: u16) {
+        let mut ports = vec![(PortProtocol::Tcp, tcp_port)];
+        if let Some(discv4) = &self.discv4 {
+            ports.push((PortProtocol::Udp, discv4.local_addr().port()));
+        }
+        if let Some(discv5) = &self.discv5 {
+            let enr = discv5.with_discv5(|discv5| discv5.local_enr());
+            ports.extend(enr.udp4().or(enr.udp6()).map(|port| (PortProtocol::Udp, port)));
+        }
+        ports.dedup();
+
+        let mapper = PortMapper::new(ports, DEFAULT_PORT_MAPPING_LEASE);
+        self.port_mapping = Some(PortMappingInterval::new(mapper));
+    }
+
+    /// Announces the external address of the gateway once the ports are mapped.
+    fn on_port_mapping(&mut self, res: Result<MappedPorts, PortMappingError>) {
+        let MappedPorts { method, external_ip } = match res {
+            Ok(mapped) => mapped,
+            Err(err) => {
+                debug!(target: "net::discovery", %err, "Failed to map ports");
+                return
+            }
+        };
+        if !is_public_gateway_ip(external_ip) {
+            debug!(target: "net::discovery", %method, %external_ip, "Gateway is behind another NAT, not announcing its address");
+            return
+        }
+
+        if self.local_enr.address != external_ip {
+            info!(target: "net::discovery", %method, %external_ip, "Mapped ports on gateway");
+            self.local_enr.address = external_ip;
+        }
+        if let Some(discv4) = &self.discv4 {
+            discv4.set_external_ip_addr(external_ip)
+        }
+        if let Some(discv5) = &self.discv5 {
+            discv5.set_external_ip_addr(external_ip)
+        }
+    }
+
     /// Registers a listener for receiving [`DiscoveryEvent`] updates.
     pub(crate) fn add_listener(&mut self, tx: mpsc::UnboundedSender<DiscoveryEvent>) {
         self.discovery_listeners.push(tx);
@@ -289,6 +339,13 @@ impl Discovery {
                 self.on_node_record_update(update.node_record, update.fork_id);
             }
 
+            // renew the port mapping
+            while let Some(Poll::Ready(res)) =
+                self.port_mapping.as_mut().map(|mapping| mapping.poll_tick(cx))
+            {
+                self.on_port_mapping(res);
+            }
+
             if self.queued_events.is_empty() {
                 return Poll::Pending
             }
@@ -330,6 +387,7 @@ impl Discovery {
             _dns_discovery: None,
             dns_discovery_updates: None,
             _dns_disc_service: None,
+            port_mapping: None,
             discovery_listeners: Default::default(),
         }
     }
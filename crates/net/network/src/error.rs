@@ -113,7 +113,9 @@ impl SessionError for EthStreamError {
                 P2PHandshakeError::HelloNotInHandshake |
                 P2PHandshakeError::NonHelloMessageInHandshake,
             )) => true,
-            Self::EthHandshakeError(err) => !matches!(err, EthHandshakeError::NoResponse),
+            Self::EthHandshakeError(err) => {
+                !matches!(err, EthHandshakeError::NoResponse | EthHandshakeError::MissingBlockRange)
+            }
             _ => false,
         }
     }
@@ -144,7 +146,9 @@ impl SessionError for EthStreamError {
                         P2PStreamError::MismatchedProtocolVersion { .. }
                 )
             }
-            Self::EthHandshakeError(err) => !matches!(err, EthHandshakeError::NoResponse),
+            Self::EthHandshakeError(err) => {
+                !matches!(err, EthHandshakeError::NoResponse | EthHandshakeError::MissingBlockRange)
+            }
             _ => false,
         }
     }
@@ -2,20 +2,23 @@
 
 use crate::{
     budget::DEFAULT_BUDGET_TRY_DRAIN_DOWNLOADERS, metered_poll_nested_stream_with_budget,
-    metrics::EthRequestHandlerMetrics,
+    metrics::EthRequestHandlerMetrics, NetworkHandle,
 };
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockHashOrNumber;
+use alloy_primitives::BlockNumber;
 use alloy_rlp::Encodable;
 use futures::StreamExt;
 use reth_eth_wire::{
-    BlockBodies, BlockHeaders, EthNetworkPrimitives, GetBlockBodies, GetBlockHeaders, GetNodeData,
-    GetReceipts, HeadersDirection, NetworkPrimitives, NodeData, Receipts,
+    BlockBodies, BlockHeaders, BlockRangeUpdate, EthNetworkPrimitives, GetBlockBodies,
+    GetBlockHeaders, GetNodeData, GetReceipts, HeadersDirection, NetworkPrimitives, NodeData,
+    Receipts,
 };
 use reth_network_api::test_utils::PeersHandle;
 use reth_network_p2p::error::RequestResult;
 use reth_network_peers::PeerId;
 use reth_primitives_traits::Block;
+use reth_prune_types::{PruneMode, PruneModes, MINIMUM_PRUNING_DISTANCE};
 use reth_storage_api::{BlockReader, HeaderProvider, ReceiptProvider};
 use std::{
     future::Future,
@@ -23,8 +26,12 @@ use std::{
     task::{Context, Poll},
     time::Duration,
 };
-use tokio::sync::{mpsc::Receiver, oneshot};
+use tokio::{
+    sync::{mpsc::Receiver, oneshot},
+    time::Interval,
+};
 use tokio_stream::wrappers::ReceiverStream;
+use tracing::trace;
 
 // Limits: <https://github.com/ethereum/go-ethereum/blob/b0d44338bbcefee044f1f635a84487cbbd8f0538/eth/protocols/eth/handler.go#L34-L56>
 
@@ -47,6 +54,11 @@ const MAX_BODIES_SERVE: usize = 1024;
 /// Maximum size of replies to data retrievals.
 const SOFT_RESPONSE_LIMIT: usize = 2 * 1024 * 1024;
 
+/// Interval at which the range of blocks the node can serve is announced to `eth/69` peers.
+///
+/// This corresponds to roughly 32 blocks on mainnet.
+const BLOCK_RANGE_UPDATE_INTERVAL: Duration = Duration::from_secs(384);
+
 /// Manages eth related requests on top of the p2p network.
 ///
 /// This can be spawned to another task and is supposed to be run as background service.
@@ -63,6 +75,16 @@ pub struct EthRequestHandler<C, N: NetworkPrimitives = EthNetworkPrimitives> {
     incoming_requests: ReceiverStream<IncomingEthRequest<N>>,
     /// Metrics for the eth request handler.
     metrics: EthRequestHandlerMetrics,
+    /// The pruning configuration of the node.
+    ///
+    /// Requests for data that was pruned locally are declined.
+    prune_modes: PruneModes,
+    /// Used to announce the range of blocks the node can serve to peers, if configured.
+    network: Option<NetworkHandle<N>>,
+    /// The range of blocks last announced to peers.
+    block_range: Option<BlockRangeUpdate>,
+    /// Interval at which the range of blocks is announced, created on first poll.
+    block_range_interval: Option<Interval>,
 }
 
 // === impl EthRequestHandler ===
@@ -74,6 +96,43 @@ impl<C, N: NetworkPrimitives> EthRequestHandler<C, N> {
             peers,
             incoming_requests: ReceiverStream::new(incoming),
             metrics: Default::default(),
+            prune_modes: PruneModes::none(),
+            network: None,
+            block_range: None,
+            block_range_interval: None,
+        }
+    }
+
+    /// Sets the pruning configuration of the node, so that requests for pruned data are declined
+    /// instead of being answered with incomplete data.
+    pub fn with_prune_modes(mut self, prune_modes: PruneModes) -> Self {
+        self.prune_modes = prune_modes;
+        self
+    }
+
+    /// Sets the network handle used to announce the range of blocks the node can serve.
+    pub fn with_network(mut self, network: NetworkHandle<N>) -> Self {
+        self.network = Some(network);
+        self
+    }
+
+    /// Returns the earliest block whose receipts weren't pruned, given the current tip.
+    ///
+    /// Blocks whose receipts are only partially pruned, because they are kept for
+    /// [`PruneModes::receipts_log_filter`] or [`PruneModes::retained_addresses`], can't be served
+    /// either and count as pruned.
+    fn earliest_receipts_block(&self, tip: BlockNumber) -> BlockNumber {
+        // The log filter prunes all other receipts as if they had a distance of
+        // `MINIMUM_PRUNING_DISTANCE`, unless it's overridden by the receipts mode.
+        let mode = self.prune_modes.receipts.or_else(|| {
+            (!self.prune_modes.receipts_log_filter.is_empty())
+                .then_some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE))
+        });
+        match mode {
+            None => 0,
+            Some(PruneMode::Full) => tip.saturating_add(1),
+            Some(PruneMode::Distance(distance)) => tip.saturating_sub(distance),
+            Some(PruneMode::Before(block)) => block,
         }
     }
 }
@@ -196,7 +255,26 @@ where
 
         let mut total_bytes = 0;
 
+        let earliest = self
+            .client
+            .best_block_number()
+            .map(|tip| self.earliest_receipts_block(tip))
+            .unwrap_or_default();
+
         for hash in request.0 {
+            // receipts of pruned blocks would be incomplete, only the receipts up to the first
+            // pruned block are served
+            if earliest > 0 &&
+                !self
+                    .client
+                    .block_number(hash)
+                    .unwrap_or_default()
+                    .is_some_and(|number| number >= earliest)
+            {
+                self.metrics.eth_receipts_requests_declined_total.increment(1);
+                break
+            }
+
             if let Some(receipts_by_block) =
                 self.client.receipts_by_block(BlockHashOrNumber::Hash(hash)).unwrap_or_default()
             {
@@ -218,6 +296,25 @@ where
 
         let _ = response.send(Ok(Receipts(receipts)));
     }
+
+    /// Returns the range of blocks the node can serve headers, bodies and receipts for.
+    fn servable_block_range(&self) -> Option<BlockRangeUpdate> {
+        let latest = self.client.best_block_number().ok()?;
+        let latest_hash = self.client.block_hash(latest).ok()??;
+        let earliest = self.earliest_receipts_block(latest).min(latest);
+        Some(BlockRangeUpdate { earliest, latest, latest_hash })
+    }
+
+    /// Announces the range of blocks the node can serve to all peers if it changed.
+    fn announce_block_range(&mut self) {
+        let Some(network) = &self.network else { return };
+        let Some(range) = self.servable_block_range() else { return };
+        if self.block_range != Some(range) {
+            trace!(target: "net::eth", ?range, "Announcing servable block range");
+            network.update_block_range(range);
+            self.block_range = Some(range);
+        }
+    }
 }
 
 /// An endless future.
@@ -235,6 +332,17 @@ where
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
 
+        if this.network.is_some() {
+            while this
+                .block_range_interval
+                .get_or_insert_with(|| tokio::time::interval(BLOCK_RANGE_UPDATE_INTERVAL))
+                .poll_tick(cx)
+                .is_ready()
+            {
+                this.announce_block_range();
+            }
+        }
+
         let mut acc = Duration::ZERO;
         let maybe_more_incoming_requests = metered_poll_nested_stream_with_budget!(
             acc,
@@ -7,7 +7,9 @@ pub use client::FetchClient;
 use crate::message::BlockRequest;
 use alloy_primitives::B256;
 use futures::StreamExt;
-use reth_eth_wire::{EthNetworkPrimitives, GetBlockBodies, GetBlockHeaders, NetworkPrimitives};
+use reth_eth_wire::{
+    EthNetworkPrimitives, GetBlockBodies, GetBlockHeaders, HeadersDirection, NetworkPrimitives,
+};
 use reth_network_api::test_utils::PeersHandle;
 use reth_network_p2p::{
     error::{EthResponseValidator, PeerRequestResult, RequestError, RequestResult},
@@ -15,8 +17,9 @@ use reth_network_p2p::{
     priority::Priority,
 };
 use reth_network_peers::PeerId;
-use reth_network_types::ReputationChangeKind;
+use reth_network_types::{peers::reputation::DEFAULT_REPUTATION, Reputation, ReputationChangeKind};
 use std::{
+    cmp::Reverse,
     collections::{HashMap, VecDeque},
     sync::{
         atomic::{AtomicU64, AtomicUsize, Ordering},
@@ -30,6 +33,19 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 type InflightHeadersRequest<H> = Request<HeadersRequest, PeerRequestResult<Vec<H>>>;
 type InflightBodiesRequest<B> = Request<Vec<B256>, PeerRequestResult<Vec<B>>>;
 
+/// Requests for more than this many headers or bodies are considered large and are preferably
+/// dispatched to peers with a proven track record.
+const LARGE_REQUEST_THRESHOLD: usize = 64;
+
+/// Number of successful responses after which a peer is no longer considered new.
+const PROVEN_PEER_MIN_RESPONSES: u64 = 3;
+
+/// Maximum number of new peers that are probed with small requests at the same time.
+///
+/// Once reached, small requests are dispatched like large requests, so that a burst of new peers
+/// can't take over all small requests.
+const MAX_CONCURRENT_PROBES: usize = 2;
+
 /// Manages data fetching operations.
 ///
 /// This type is hooked into the staged sync pipeline and delegates download request to available
@@ -89,10 +105,21 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
                 best_number,
                 timeout,
                 last_response_likely_bad: false,
+                earliest_block: 0,
+                reputation: DEFAULT_REPUTATION,
+                successful_responses: 0,
+                failed_responses: 0,
             },
         );
     }
 
+    /// Updates the tracked reputation of the peer with its score in the network's peers manager.
+    pub(crate) fn update_peer_reputation(&mut self, peer_id: &PeerId, reputation: Reputation) {
+        if let Some(peer) = self.peers.get_mut(peer_id) {
+            peer.reputation = reputation;
+        }
+    }
+
     /// Removes the peer from the peer list, after which it is no longer available for future
     /// requests.
     ///
@@ -123,6 +150,13 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         false
     }
 
+    /// Updates the earliest block the peer announced it can serve.
+    pub(crate) fn update_peer_earliest_block(&mut self, peer_id: &PeerId, earliest_block: u64) {
+        if let Some(peer) = self.peers.get_mut(peer_id) {
+            peer.earliest_block = earliest_block;
+        }
+    }
+
     /// Invoked when an active session is about to be disconnected.
     pub(crate) fn on_pending_disconnect(&mut self, peer_id: &PeerId) {
         if let Some(peer) = self.peers.get_mut(peer_id) {
@@ -130,24 +164,24 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         }
     }
 
-    /// Returns the _next_ idle peer that's ready to accept a request,
-    /// prioritizing those with the lowest timeout/latency and those that recently responded with
-    /// adequate data.
-    fn next_best_peer(&self) -> Option<PeerId> {
+    /// Returns the _next_ idle peer that's ready to accept a request of the given size.
+    ///
+    /// Peers that announced they can serve the requested blocks are always preferred, followed by
+    /// peers that recently responded with adequate data and that aren't penalized by the network.
+    /// Requests are then dispatched to proven peers with the highest success rate, except for
+    /// small requests that are used to probe new peers, up to [`MAX_CONCURRENT_PROBES`] at a time.
+    /// Remaining ties are broken by the lowest timeout/latency.
+    fn next_best_peer(&self, size: RequestSize, lowest_block: Option<u64>) -> Option<PeerId> {
+        let probe = size == RequestSize::Small &&
+            self.peers.values().filter(|peer| peer.is_probed()).count() < MAX_CONCURRENT_PROBES;
+
         let mut idle = self.peers.iter().filter(|(_, peer)| peer.state.is_idle());
 
         let mut best_peer = idle.next()?;
 
         for maybe_better in idle {
-            // replace best peer if our current best peer sent us a bad response last time
-            if best_peer.1.last_response_likely_bad && !maybe_better.1.last_response_likely_bad {
-                best_peer = maybe_better;
-                continue
-            }
-
-            // replace best peer if this peer has better rtt
-            if maybe_better.1.timeout() < best_peer.1.timeout() &&
-                !maybe_better.1.last_response_likely_bad
+            if maybe_better.1.selection_key(probe, lowest_block) >
+                best_peer.1.selection_key(probe, lowest_block)
             {
                 best_peer = maybe_better;
             }
@@ -163,7 +197,11 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
             return PollAction::NoRequests
         }
 
-        let Some(peer_id) = self.next_best_peer() else { return PollAction::NoPeersAvailable };
+        let request = self.queued_requests.front().expect("not empty");
+        let (size, lowest_block) = (request.size(), request.lowest_block());
+        let Some(peer_id) = self.next_best_peer(size, lowest_block) else {
+            return PollAction::NoPeersAvailable
+        };
 
         let request = self.queued_requests.pop_front().expect("not empty");
         let request = self.prepare_block_request(peer_id, request);
@@ -276,6 +314,7 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         if let Some(peer) = self.peers.get_mut(&peer_id) {
             // update the peer's response state
             peer.last_response_likely_bad = is_likely_bad_response;
+            peer.record_response(!is_error && !is_likely_bad_response);
 
             // If the peer is still ready to accept new requests, we try to send a followup
             // request immediately.
@@ -304,6 +343,7 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         if let Some(peer) = self.peers.get_mut(&peer_id) {
             // update the peer's response state
             peer.last_response_likely_bad = is_likely_bad_response;
+            peer.record_response(!is_likely_bad_response);
 
             if peer.state.on_request_finished() && !is_likely_bad_response {
                 return self.followup_request(peer_id)
@@ -347,12 +387,83 @@ struct Peer {
     /// downloaded), but we still want to avoid requesting from the same peer again if it has the
     /// lowest timeout.
     last_response_likely_bad: bool,
+    /// The earliest block the peer announced it can serve, `0` if it didn't announce a range.
+    earliest_block: u64,
+    /// The peer's reputation as tracked by the network's peers manager.
+    reputation: Reputation,
+    /// Number of requests the peer answered with adequate data.
+    successful_responses: u64,
+    /// Number of requests the peer failed to answer or answered with a likely bad response.
+    failed_responses: u64,
 }
 
 impl Peer {
     fn timeout(&self) -> u64 {
         self.timeout.load(Ordering::Relaxed)
     }
+
+    /// Records the outcome of a request sent to this peer.
+    fn record_response(&mut self, success: bool) {
+        if success {
+            self.successful_responses += 1;
+        } else {
+            self.failed_responses += 1;
+        }
+    }
+
+    /// Returns `true` if the peer answered enough requests to be considered reliable for large
+    /// requests.
+    const fn is_proven(&self) -> bool {
+        self.successful_responses >= PROVEN_PEER_MIN_RESPONSES
+    }
+
+    /// Returns `true` if the peer is new and currently handling a request.
+    const fn is_probed(&self) -> bool {
+        !self.is_proven() && self.state.is_busy()
+    }
+
+    /// Returns the share of successful responses in tenths, so that peers with a similar
+    /// success rate are ranked by latency instead.
+    const fn success_rate_decile(&self) -> u64 {
+        let total = self.successful_responses + self.failed_responses;
+        if total == 0 {
+            return 10
+        }
+        self.successful_responses * 10 / total
+    }
+
+    /// Returns the key by which peers are ranked for a request that starts at the given block,
+    /// higher is better.
+    ///
+    /// If `probe` is set, new peers are preferred.
+    fn selection_key(
+        &self,
+        probe: bool,
+        lowest_block: Option<u64>,
+    ) -> (bool, bool, bool, bool, u64, Reverse<u64>) {
+        let (prefer_proven, success_rate) = if probe {
+            (!self.is_proven(), 0)
+        } else {
+            (self.is_proven(), self.success_rate_decile())
+        };
+        (
+            lowest_block.is_none_or(|block| block >= self.earliest_block),
+            !self.last_response_likely_bad,
+            self.reputation >= DEFAULT_REPUTATION,
+            prefer_proven,
+            success_rate,
+            Reverse(self.timeout()),
+        )
+    }
+}
+
+/// Coarse size of a download request, used to select the peer to dispatch it to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RequestSize {
+    /// A request that's cheap to retry, suitable to probe peers.
+    Small,
+    /// A request for a large range of data.
+    Large,
 }
 
 /// Tracks the state of an individual peer
@@ -376,6 +487,11 @@ impl PeerState {
         matches!(self, Self::Idle)
     }
 
+    /// Returns true if the peer is currently handling a request.
+    const fn is_busy(&self) -> bool {
+        matches!(self, Self::GetBlockHeaders | Self::GetBlockBodies)
+    }
+
     /// Resets the state on a received response.
     ///
     /// If the state was already marked as `Closing` do nothing.
@@ -442,6 +558,35 @@ impl<N: NetworkPrimitives> DownloadRequest<N> {
     const fn is_normal_priority(&self) -> bool {
         self.get_priority().is_normal()
     }
+
+    /// Returns the lowest block number of the request, if known.
+    fn lowest_block(&self) -> Option<u64> {
+        match self {
+            Self::GetBlockHeaders { request, .. } => {
+                let start = request.start.as_number()?;
+                match request.direction {
+                    HeadersDirection::Rising => Some(start),
+                    HeadersDirection::Falling => {
+                        Some(start.saturating_sub(request.limit.saturating_sub(1)))
+                    }
+                }
+            }
+            Self::GetBlockBodies { .. } => None,
+        }
+    }
+
+    /// Returns the coarse size of the request.
+    fn size(&self) -> RequestSize {
+        let len = match self {
+            Self::GetBlockHeaders { request, .. } => request.limit as usize,
+            Self::GetBlockBodies { request, .. } => request.len(),
+        };
+        if len > LARGE_REQUEST_THRESHOLD {
+            RequestSize::Large
+        } else {
+            RequestSize::Small
+        }
+    }
 }
 
 /// An action the syncer can emit.
@@ -506,17 +651,17 @@ mod tests {
         fetcher.new_active_peer(peer1, B256::random(), 1, Arc::new(AtomicU64::new(1)));
         fetcher.new_active_peer(peer2, B256::random(), 2, Arc::new(AtomicU64::new(1)));
 
-        let first_peer = fetcher.next_best_peer().unwrap();
+        let first_peer = fetcher.next_best_peer(RequestSize::Small, None).unwrap();
         assert!(first_peer == peer1 || first_peer == peer2);
         // Pending disconnect for first_peer
         fetcher.on_pending_disconnect(&first_peer);
         // first_peer now isn't idle, so we should get other peer
-        let second_peer = fetcher.next_best_peer().unwrap();
+        let second_peer = fetcher.next_best_peer(RequestSize::Small, None).unwrap();
         assert!(first_peer == peer1 || first_peer == peer2);
         assert_ne!(first_peer, second_peer);
         // without idle peers, returns None
         fetcher.on_pending_disconnect(&second_peer);
-        assert_eq!(fetcher.next_best_peer(), None);
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), None);
     }
 
     #[tokio::test]
@@ -536,13 +681,86 @@ mod tests {
         fetcher.new_active_peer(peer3, B256::random(), 3, Arc::new(AtomicU64::new(50)));
 
         // Must always get peer1 (lowest timeout)
-        assert_eq!(fetcher.next_best_peer(), Some(peer1));
-        assert_eq!(fetcher.next_best_peer(), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(peer1));
         // peer2's timeout changes below peer1's
         peer2_timeout.store(10, Ordering::Relaxed);
         // Then we get peer 2 always (now lowest)
-        assert_eq!(fetcher.next_best_peer(), Some(peer2));
-        assert_eq!(fetcher.next_best_peer(), Some(peer2));
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(peer2));
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(peer2));
+    }
+
+    #[tokio::test]
+    async fn test_peer_selection_by_request_size() {
+        let manager = PeersManager::new(PeersConfig::default());
+        let mut fetcher =
+            StateFetcher::<EthNetworkPrimitives>::new(manager.handle(), Default::default());
+        let proven = B512::random();
+        let new = B512::random();
+
+        fetcher.new_active_peer(proven, B256::random(), 1, Arc::new(AtomicU64::new(100)));
+        fetcher.new_active_peer(new, B256::random(), 2, Arc::new(AtomicU64::new(10)));
+        for _ in 0..PROVEN_PEER_MIN_RESPONSES {
+            fetcher.peers.get_mut(&proven).unwrap().record_response(true);
+        }
+
+        // large ranges go to the proven peer, even though it's slower
+        assert_eq!(fetcher.next_best_peer(RequestSize::Large, None), Some(proven));
+        // small ranges are used to probe the new peer
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(new));
+
+        // unless enough new peers are probed already
+        for _ in 0..MAX_CONCURRENT_PROBES {
+            let probed = B512::random();
+            fetcher.new_active_peer(probed, B256::random(), 2, Arc::new(AtomicU64::new(1)));
+            fetcher.peers.get_mut(&probed).unwrap().state = PeerState::GetBlockHeaders;
+        }
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(proven));
+
+        // unreliable peers lose their preference for large ranges
+        let unreliable = B512::random();
+        fetcher.new_active_peer(unreliable, B256::random(), 3, Arc::new(AtomicU64::new(1)));
+        let peer = fetcher.peers.get_mut(&unreliable).unwrap();
+        for _ in 0..PROVEN_PEER_MIN_RESPONSES {
+            peer.record_response(true);
+            peer.record_response(false);
+        }
+        assert_eq!(fetcher.next_best_peer(RequestSize::Large, None), Some(proven));
+    }
+
+    #[tokio::test]
+    async fn test_peer_selection_by_reputation() {
+        let manager = PeersManager::new(PeersConfig::default());
+        let mut fetcher =
+            StateFetcher::<EthNetworkPrimitives>::new(manager.handle(), Default::default());
+        let peer1 = B512::random();
+        let peer2 = B512::random();
+
+        fetcher.new_active_peer(peer1, B256::random(), 1, Arc::new(AtomicU64::new(10)));
+        fetcher.new_active_peer(peer2, B256::random(), 2, Arc::new(AtomicU64::new(100)));
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(peer1));
+
+        // penalized peers are deprioritized regardless of their latency
+        fetcher.update_peer_reputation(&peer1, -1024);
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(peer2));
+    }
+
+    #[tokio::test]
+    async fn test_peer_selection_by_block_range() {
+        let manager = PeersManager::new(PeersConfig::default());
+        let mut fetcher =
+            StateFetcher::<EthNetworkPrimitives>::new(manager.handle(), Default::default());
+        let peer1 = B512::random();
+        let peer2 = B512::random();
+
+        fetcher.new_active_peer(peer1, B256::random(), 1, Arc::new(AtomicU64::new(10)));
+        fetcher.new_active_peer(peer2, B256::random(), 2, Arc::new(AtomicU64::new(100)));
+        fetcher.update_peer_earliest_block(&peer1, 1_000);
+
+        // peers that pruned the requested blocks are deprioritized regardless of their latency
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, Some(10)), Some(peer2));
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, Some(1_000)), Some(peer1));
+        assert_eq!(fetcher.next_best_peer(RequestSize::Small, None), Some(peer1));
     }
 
     #[tokio::test]
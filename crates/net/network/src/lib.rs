@@ -151,11 +151,14 @@ pub use reth_network_api::{
     NetworkEventListenerProvider, NetworkInfo, PeerRequest, PeerRequestSender, Peers, PeersInfo,
 };
 pub use reth_network_p2p::sync::{NetworkSyncUpdater, SyncState};
-pub use reth_network_types::{PeersConfig, SessionsConfig};
+pub use reth_network_types::{
+    BandwidthLimits, CaptureConfig, PeersConfig, ProxyConfig, SessionsConfig,
+};
 pub use session::{
-    ActiveSessionHandle, ActiveSessionMessage, Direction, EthRlpxConnection, PeerInfo,
-    PendingSessionEvent, PendingSessionHandle, PendingSessionHandshakeError, SessionCommand,
-    SessionEvent, SessionId, SessionManager,
+    read_capture_file, ActiveSessionHandle, ActiveSessionMessage, CaptureDirection,
+    CapturedMessage, Direction, EthRlpxConnection, PeerBandwidth, PeerInfo, PendingSessionEvent,
+    PendingSessionHandle, PendingSessionHandshakeError, SessionCommand, SessionEvent, SessionId,
+    SessionManager,
 };
 
 pub use builder::NetworkBuilder;
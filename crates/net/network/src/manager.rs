@@ -29,7 +29,7 @@ use crate::{
     peers::PeersManager,
     poll_nested_stream_with_budget,
     protocol::IntoRlpxSubProtocol,
-    session::SessionManager,
+    session::{SessionCapture, SessionManager},
     state::NetworkState,
     swarm::{Swarm, SwarmEvent},
     transactions::NetworkTransactionEvent,
@@ -192,6 +192,7 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             tx_gossip_disabled,
             transactions_manager_config: _,
             nat,
+            port_mapping,
         } = config;
 
         let peers_manager = PeersManager::new(peers_config);
@@ -219,7 +220,7 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             discv5.extend_unsigned_boot_nodes(resolved_boot_nodes)
         }
 
-        let discovery = Discovery::new(
+        let mut discovery = Discovery::new(
             listener_addr,
             discovery_v4_addr,
             secret_key,
@@ -228,6 +229,9 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             dns_discovery_config,
         )
         .await?;
+        if port_mapping {
+            discovery.enable_port_mapping(listener_addr.port());
+        }
         // need to retrieve the addr here since provided port could be `0`
         let local_peer_id = discovery.local_id();
         let discv4 = discovery.discv4();
@@ -235,7 +239,8 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
 
         let num_active_peers = Arc::new(AtomicUsize::new(0));
 
-        let sessions = SessionManager::new(
+        let capture = sessions_config.capture.clone();
+        let mut sessions = SessionManager::new(
             secret_key,
             sessions_config,
             executor,
@@ -244,6 +249,9 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             fork_filter,
             extra_protocols,
         );
+        if let Some(capture) = capture {
+            sessions.set_capture(SessionCapture::new(capture)?);
+        }
 
         let state = NetworkState::new(
             crate::state::BlockNumReader::new(client),
@@ -367,9 +375,22 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
     /// `persistent_peers_file`.
     pub fn write_peers_to_file(&self, persistent_peers_file: &Path) -> Result<(), FsPathError> {
         let known_peers = self.all_peers().collect::<Vec<_>>();
-        persistent_peers_file.parent().map(fs::create_dir_all).transpose()?;
-        reth_fs_util::write_json_file(persistent_peers_file, &known_peers)?;
-        Ok(())
+        fs::write_json_file_all(persistent_peers_file, &known_peers)
+    }
+
+    /// Writes the known-good peers to the configured
+    /// [`PeersConfig::persist_path`](reth_network_types::PeersConfig::persist_path), if any.
+    ///
+    /// This is invoked on graceful shutdown, see [`Self::run_until_graceful_shutdown`], the
+    /// persisted peers are loaded again by the [`PeersManager`](crate::peers::PeersManager) on
+    /// startup.
+    #[cfg(feature = "serde")]
+    pub fn persist_peers(&self) -> Result<(), FsPathError> {
+        let peers = self.swarm.state().peers();
+        let Some(persist_path) = peers.persist_path() else { return Ok(()) };
+        let persisted = peers.persisted_peers();
+        trace!(target: "net", file = %persist_path.display(), num_peers = persisted.len(), "Persisting peers");
+        fs::write_json_file_all(persist_path, &persisted)
     }
 
     /// Returns a new [`FetchClient`] that can be cloned and shared.
@@ -547,6 +568,9 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                     msg,
                 });
             }
+            PeerMessage::BlockRangeUpdate(range) => {
+                self.swarm.state_mut().on_block_range_update(peer_id, range);
+            }
             PeerMessage::SendTransactions(_) => {
                 unreachable!("Not emitted by session")
             }
@@ -617,6 +641,9 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             NetworkHandleMessage::GetReputationById(peer_id, tx) => {
                 let _ = tx.send(self.swarm.state_mut().peers().get_reputation(&peer_id));
             }
+            NetworkHandleMessage::GetPeerBandwidth(tx) => {
+                let _ = tx.send(self.swarm.sessions().peer_bandwidth());
+            }
             NetworkHandleMessage::FetchClient(tx) => {
                 let _ = tx.send(self.fetch_client());
             }
@@ -628,6 +655,16 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                     self.swarm.state_mut().update_fork_id(transition.current);
                 }
             }
+            NetworkHandleMessage::UpdateBlockRange(range) => {
+                self.swarm.sessions_mut().on_block_range_update(range);
+                let peers =
+                    self.swarm.sessions().active_sessions().keys().copied().collect::<Vec<_>>();
+                for peer_id in peers {
+                    self.swarm
+                        .sessions_mut()
+                        .send_message(&peer_id, PeerMessage::BlockRangeUpdate(range));
+                }
+            }
             NetworkHandleMessage::GetPeerInfos(tx) => {
                 let _ = tx.send(self.get_peer_infos());
             }
@@ -715,6 +752,11 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                     self.swarm.state_mut().peers_mut().on_active_outgoing_established(peer_id);
                 }
 
+                self.swarm
+                    .state_mut()
+                    .peers_mut()
+                    .on_session_established(&peer_id, client_version.clone());
+
                 self.update_active_connection_metrics();
 
                 let session_info = SessionInfo {
@@ -739,6 +781,13 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
                 self.event_sender.notify(NetworkEvent::Peer(PeerEvent::PeerRemoved(peer_id)));
                 self.metrics.tracked_peers.set(self.swarm.state().peers().num_known_peers() as f64);
             }
+            SwarmEvent::ReputationChanged { peer_id, reputation } => {
+                trace!(target: "net", ?peer_id, reputation, "Peer reputation changed");
+                self.event_sender.notify(NetworkEvent::Peer(PeerEvent::ReputationChanged {
+                    peer_id,
+                    reputation,
+                }));
+            }
             SwarmEvent::SessionClosed { peer_id, remote_addr, error } => {
                 let total_active = self.num_active_peers.fetch_sub(1, Ordering::Relaxed) - 1;
                 self.metrics.connected_peers.set(total_active as f64);
@@ -956,7 +1005,8 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
 
     /// Drives the [`NetworkManager`] future until a [`GracefulShutdown`] signal is received.
     ///
-    /// This invokes the given function `shutdown_hook` while holding the graceful shutdown guard.
+    /// This persists the known-good peers, see [`Self::persist_peers`], and invokes the given
+    /// function `shutdown_hook` while holding the graceful shutdown guard.
     pub async fn run_until_graceful_shutdown<F, R>(
         mut self,
         shutdown: GracefulShutdown,
@@ -974,6 +1024,10 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
         }
 
         self.perform_network_shutdown();
+        #[cfg(feature = "serde")]
+        if let Err(err) = self.persist_peers() {
+            warn!(target: "net", %err, "Failed to persist peers");
+        }
         let res = shutdown_hook(self);
         drop(graceful_guard);
         res
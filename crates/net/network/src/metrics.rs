@@ -89,6 +89,28 @@ pub struct SessionManagerMetrics {
     pub(crate) queued_outgoing_messages: Gauge,
 }
 
+/// Metrics for the outgoing connections through the configured proxy.
+#[derive(Metrics, Clone)]
+#[metrics(scope = "network.proxy")]
+pub struct ProxyMetrics {
+    /// Number of connections established through the proxy.
+    pub(crate) connections: Counter,
+    /// Number of failed attempts to connect through the proxy.
+    pub(crate) failures: Counter,
+    /// Number of failed attempts that fell back to dialing the peer directly.
+    pub(crate) direct_fallbacks: Counter,
+}
+
+/// Metrics for the bandwidth of all peer sessions.
+#[derive(Metrics, Clone)]
+#[metrics(scope = "network")]
+pub struct BandwidthMetrics {
+    /// Total number of bytes received from peers.
+    pub(crate) ingress_bytes: Counter,
+    /// Total number of bytes sent to peers.
+    pub(crate) egress_bytes: Counter,
+}
+
 /// Metrics for the [`TransactionsManager`](crate::transactions::TransactionsManager).
 #[derive(Metrics)]
 #[metrics(scope = "network")]
@@ -98,6 +120,13 @@ pub struct TransactionsManagerMetrics {
     pub(crate) propagated_transactions: Counter,
     /// Total number of reported bad transactions
     pub(crate) reported_bad_transactions: Counter,
+    /// Total number of transactions that were not propagated due to the gossip config.
+    pub(crate) suppressed_gossip_transactions: Counter,
+    /// Total number of transactions that were only announced as hashes due to the gossip config.
+    pub(crate) hashes_only_gossip_transactions: Counter,
+    /// Total number of transactions that were announced as hashes instead of in full, because
+    /// the gossip bandwidth of the peer was exhausted.
+    pub(crate) bandwidth_capped_gossip_transactions: Counter,
 
     /* -- Freq txns already marked as seen by peer -- */
     /// Total number of messages from a peer, announcing transactions that have already been
@@ -326,6 +355,10 @@ pub struct EthRequestHandlerMetrics {
     /// Number of `GetNodeData` requests received
     pub(crate) eth_node_data_requests_received_total: Counter,
 
+    /// Number of `GetReceipts` requests that were cut short because they requested pruned
+    /// receipts
+    pub(crate) eth_receipts_requests_declined_total: Counter,
+
     /// Duration in seconds of call to poll
     /// [`EthRequestHandler`](crate::eth_requests::EthRequestHandler).
     pub(crate) acc_duration_poll_eth_req_handler: Gauge,
@@ -1,15 +1,15 @@
 use crate::{
     config::NetworkMode, message::PeerMessage, protocol::RlpxSubProtocol,
-    swarm::NetworkConnectionState, transactions::TransactionsHandle, FetchClient,
+    swarm::NetworkConnectionState, transactions::TransactionsHandle, FetchClient, PeerBandwidth,
 };
-use alloy_primitives::B256;
+use alloy_primitives::{bytes::Bytes, B256};
 use enr::Enr;
 use futures::StreamExt;
 use parking_lot::Mutex;
 use reth_discv4::{Discv4, NatResolver};
 use reth_discv5::Discv5;
 use reth_eth_wire::{
-    DisconnectReason, EthNetworkPrimitives, NetworkPrimitives, NewBlock,
+    BlockRangeUpdate, DisconnectReason, EthNetworkPrimitives, NetworkPrimitives, NewBlock,
     NewPooledTransactionHashes, SharedTransactions,
 };
 use reth_ethereum_forks::Head;
@@ -120,6 +120,14 @@ impl<N: NetworkPrimitives> NetworkHandle<N> {
         self.send_message(NetworkHandleMessage::AnnounceBlock(block, hash))
     }
 
+    /// Announces the range of blocks the node can serve to all `eth/69` peers.
+    ///
+    /// Established sessions receive a `BlockRangeUpdate` message, new sessions advertise the range
+    /// in their `Status` message.
+    pub fn update_block_range(&self, range: BlockRangeUpdate) {
+        self.send_message(NetworkHandleMessage::UpdateBlockRange(range))
+    }
+
     /// Sends a [`PeerRequest`] to the given peer's session.
     pub fn send_request(&self, peer_id: PeerId, request: PeerRequest<N>) {
         self.send_message(NetworkHandleMessage::EthRequest { peer_id, request })
@@ -181,6 +189,14 @@ impl<N: NetworkPrimitives> NetworkHandle<N> {
         self.send_message(NetworkHandleMessage::SetNetworkState(network_conn));
     }
 
+    /// Returns the number of bytes received from and sent to each connected peer over the current
+    /// session, including the handshakes.
+    pub async fn peer_bandwidth(&self) -> Result<Vec<PeerBandwidth>, NetworkError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.manager().send(NetworkHandleMessage::GetPeerBandwidth(tx));
+        Ok(rx.await?)
+    }
+
     /// Whether tx gossip is disabled
     pub fn tx_gossip_disabled(&self) -> bool {
         self.inner.tx_gossip_disabled
@@ -190,6 +206,44 @@ impl<N: NetworkPrimitives> NetworkHandle<N> {
     pub fn secret_key(&self) -> &SecretKey {
         &self.inner.secret_key
     }
+
+    /// Advertises the topic in the local discv5 [`Enr`], e.g. `b"opstack"` or a custom rollup id,
+    /// so that peers can discover the node by the capability.
+    ///
+    /// This is a noop if discv5 is disabled.
+    ///
+    /// CAUTION: The value **must** be rlp encoded
+    pub fn advertise_topic(&self, topic: &'static [u8], rlp: Bytes) {
+        if let Some(discv5) = &self.inner.discv5 {
+            discv5.advertise_topic(topic, rlp)
+        }
+    }
+
+    /// Returns the peers discovered over discv5 that advertise the given topic.
+    ///
+    /// Returns an empty list if discv5 is disabled.
+    pub fn peers_with_topic(&self, topic: &'static [u8]) -> Vec<NodeRecord> {
+        self.inner.discv5.as_ref().map(|discv5| discv5.peers_with_topic(topic)).unwrap_or_default()
+    }
+
+    /// Runs a discv5 lookup query for at most `max_peers` peers that advertise the given topic.
+    ///
+    /// Returns an empty list if discv5 is disabled.
+    pub async fn find_peers_with_topic(
+        &self,
+        topic: &'static [u8],
+        max_peers: usize,
+    ) -> Result<Vec<NodeRecord>, reth_discv5::Error> {
+        let Some(discv5) = &self.inner.discv5 else { return Ok(Vec::new()) };
+        discv5.find_peers_with_topic(topic, max_peers).await
+    }
+
+    /// Returns the [`Enr`]s of the peers in the discv5 routing table.
+    ///
+    /// Returns an empty list if discv5 is disabled.
+    pub fn discv5_peer_enrs(&self) -> Vec<Enr<SecretKey>> {
+        self.inner.discv5.as_ref().map(|discv5| discv5.table_enrs()).unwrap_or_default()
+    }
 }
 
 // === API Implementations ===
@@ -515,6 +569,8 @@ pub(crate) enum NetworkHandleMessage<N: NetworkPrimitives = EthNetworkPrimitives
         /// The head status to apply.
         head: Head,
     },
+    /// Announces the range of blocks the node can serve.
+    UpdateBlockRange(BlockRangeUpdate),
     /// Retrieves the current status via a oneshot sender.
     GetStatus(oneshot::Sender<NetworkStatus>),
     /// Gets `PeerInfo` for the specified peer IDs.
@@ -527,6 +583,8 @@ pub(crate) enum NetworkHandleMessage<N: NetworkPrimitives = EthNetworkPrimitives
     GetPeerInfosByPeerKind(PeerKind, oneshot::Sender<Vec<PeerInfo>>),
     /// Gets the reputation for a specific peer via a oneshot sender.
     GetReputationById(PeerId, oneshot::Sender<Option<Reputation>>),
+    /// Gets the bandwidth of all active sessions via a oneshot sender.
+    GetPeerBandwidth(oneshot::Sender<Vec<PeerBandwidth>>),
     /// Retrieves the `TransactionsHandle` via a oneshot sender.
     GetTransactionsHandle(oneshot::Sender<Option<TransactionsHandle<N>>>),
     /// Initiates a graceful shutdown of the network via a oneshot sender.
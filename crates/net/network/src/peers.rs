@@ -8,6 +8,7 @@ use crate::{
 use futures::StreamExt;
 use reth_eth_wire::{errors::EthStreamError, DisconnectReason};
 use reth_ethereum_forks::ForkId;
+use reth_fs_util::FsPathError;
 use reth_net_banlist::BanList;
 use reth_network_api::test_utils::{PeerCommand, PeersHandle};
 use reth_network_peers::{NodeRecord, PeerId};
@@ -16,16 +17,18 @@ use reth_network_types::{
         config::PeerBackoffDurations,
         reputation::{DEFAULT_REPUTATION, MAX_TRUSTED_PEER_REPUTATION_CHANGE},
     },
-    ConnectionsConfig, Peer, PeerAddr, PeerConnectionState, PeerKind, PeersConfig,
-    ReputationChangeKind, ReputationChangeOutcome, ReputationChangeWeights,
+    ConnectionsConfig, Peer, PeerAddr, PeerConnectionState, PeerKind, PeersConfig, PersistedPeer,
+    Reputation, ReputationChangeKind, ReputationChangeOutcome, ReputationChangeWeights,
 };
 use std::{
     collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
     fmt::Display,
     io::{self},
     net::{IpAddr, SocketAddr},
+    path::{Path, PathBuf},
+    sync::Arc,
     task::{Context, Poll},
-    time::Duration,
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use thiserror::Error;
 use tokio::{
@@ -33,7 +36,7 @@ use tokio::{
     time::{Instant, Interval},
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use tracing::{trace, warn};
+use tracing::{debug, trace, warn};
 
 /// Maintains the state of _all_ the peers known to the network.
 ///
@@ -56,6 +59,9 @@ pub struct PeersManager {
     handle_rx: UnboundedReceiverStream<PeerCommand>,
     /// Buffered actions until the manager is polled.
     queued_actions: VecDeque<PeerAction>,
+    /// Reputation changes of tracked peers that have not been reported yet, see
+    /// [`Self::pop_reputation_change`].
+    reputation_changes: VecDeque<(PeerId, Reputation)>,
     /// Interval for triggering connections if there are free slots.
     refill_slots_interval: Interval,
     /// How to weigh reputation changes
@@ -84,6 +90,10 @@ pub struct PeersManager {
     net_connection_state: NetworkConnectionState,
     /// How long to temporarily ban ip on an incoming connection attempt.
     incoming_ip_throttle_duration: Duration,
+    /// File to persist known-good peers to, see [`PeersConfig::persist_path`].
+    persist_path: Option<PathBuf>,
+    /// Persisted peers with the highest dial priority that are dialed before any other peer.
+    priority_dials: VecDeque<PeerId>,
 }
 
 impl PeersManager {
@@ -101,6 +111,8 @@ impl PeersManager {
             basic_nodes,
             max_backoff_count,
             incoming_ip_throttle_duration,
+            persist_path,
+            persisted_peers_redials,
         } = config;
         let (manager_tx, handle_rx) = mpsc::unbounded_channel();
         let now = Instant::now();
@@ -131,12 +143,44 @@ impl PeersManager {
             });
         }
 
+        let mut priority_dials = VecDeque::with_capacity(persisted_peers_redials);
+        if let Some(path) = persist_path.as_deref() {
+            match load_persisted_peers(path) {
+                Ok(mut persisted) => {
+                    debug!(target: "net::peers", file = %path.display(), num_peers = persisted.len(), "Loaded persisted peers");
+                    persisted.sort_by(PersistedPeer::dial_priority);
+                    for persisted_peer in persisted {
+                        let last_seen = persisted_peer.last_seen();
+                        let PersistedPeer { record, client_version, reputation, .. } =
+                            persisted_peer;
+                        let NodeRecord { address, tcp_port, udp_port, id } = record;
+                        if ban_list.is_banned(&id, &address) {
+                            continue
+                        }
+                        let peer = peers.entry(id).or_insert_with(|| {
+                            Peer::new(PeerAddr::new_with_ports(address, tcp_port, Some(udp_port)))
+                        });
+                        peer.reputation = reputation;
+                        peer.client_version = client_version.map(Into::into);
+                        peer.last_seen = Some(last_seen);
+                        if !peer.is_banned() && priority_dials.len() < persisted_peers_redials {
+                            priority_dials.push_back(id);
+                        }
+                    }
+                }
+                Err(err) => {
+                    warn!(target: "net::peers", %err, "Failed to load persisted peers");
+                }
+            }
+        }
+
         Self {
             peers,
             trusted_peer_ids,
             manager_tx,
             handle_rx: UnboundedReceiverStream::new(handle_rx),
             queued_actions: Default::default(),
+            reputation_changes: Default::default(),
             reputation_weights,
             refill_slots_interval: tokio::time::interval(refill_slots_interval),
             release_interval: tokio::time::interval_at(now + unban_interval, unban_interval),
@@ -150,6 +194,8 @@ impl PeersManager {
             max_backoff_count,
             net_connection_state: NetworkConnectionState::default(),
             incoming_ip_throttle_duration,
+            persist_path,
+            priority_dials,
         }
     }
 
@@ -176,6 +222,38 @@ impl PeersManager {
         })
     }
 
+    /// Returns the file known-good peers are persisted to, if configured.
+    pub(crate) fn persist_path(&self) -> Option<&Path> {
+        self.persist_path.as_deref()
+    }
+
+    /// Returns all known-good peers, see [`PersistedPeer::from_peer`], ordered by their dial
+    /// priority.
+    ///
+    /// Peers with an active session are considered seen now.
+    pub(crate) fn persisted_peers(&self) -> Vec<PersistedPeer> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let mut persisted = self
+            .peers
+            .iter()
+            .filter_map(|(peer_id, peer)| {
+                let record = NodeRecord::new_with_ports(
+                    peer.addr.tcp().ip(),
+                    peer.addr.tcp().port(),
+                    peer.addr.udp().map(|addr| addr.port()),
+                    *peer_id,
+                );
+                let mut persisted = PersistedPeer::from_peer(record, peer)?;
+                if matches!(peer.state, PeerConnectionState::In | PeerConnectionState::Out) {
+                    persisted.last_seen = now;
+                }
+                Some(persisted)
+            })
+            .collect::<Vec<_>>();
+        persisted.sort_by(PersistedPeer::dial_priority);
+        persisted
+    }
+
     /// Returns the `NodeRecord` and `PeerKind` for the given peer id
     pub(crate) fn peer_by_id(&self, peer_id: PeerId) -> Option<(NodeRecord, PeerKind)> {
         self.peers.get(&peer_id).map(|v| {
@@ -445,8 +523,9 @@ impl PeersManager {
     /// trusted peer, it will also be less strict with the reputation slashing.
     pub(crate) fn apply_reputation_change(&mut self, peer_id: &PeerId, rep: ReputationChangeKind) {
         let outcome = if let Some(peer) = self.peers.get_mut(peer_id) {
+            let previous = peer.reputation;
             // First check if we should reset the reputation
-            if rep.is_reset() {
+            let outcome = if rep.is_reset() {
                 peer.reset_reputation()
             } else {
                 let mut reputation_change = self.reputation_weights.change(rep).as_i32();
@@ -469,7 +548,11 @@ impl PeersManager {
                     }
                 }
                 peer.apply_reputation(reputation_change)
+            };
+            if peer.reputation != previous {
+                self.reputation_changes.push_back((*peer_id, peer.reputation));
             }
+            outcome
         } else {
             return
         };
@@ -490,6 +573,12 @@ impl PeersManager {
         }
     }
 
+    /// Returns the next reputation change applied to a tracked peer, with the peer's new
+    /// reputation.
+    pub(crate) fn pop_reputation_change(&mut self) -> Option<(PeerId, Reputation)> {
+        self.reputation_changes.pop_front()
+    }
+
     /// Gracefully disconnected a pending _outgoing_ session
     pub(crate) fn on_outgoing_pending_session_gracefully_closed(&mut self, peer_id: &PeerId) {
         if let Some(peer) = self.peers.get_mut(peer_id) {
@@ -534,6 +623,16 @@ impl PeersManager {
         self.fill_outbound_slots();
     }
 
+    /// Called when a session with the peer was established, in either direction.
+    ///
+    /// Records the client version the peer announced and marks it as seen.
+    pub(crate) fn on_session_established(&mut self, peer_id: &PeerId, client_version: Arc<str>) {
+        if let Some(peer) = self.peers.get_mut(peer_id) {
+            peer.client_version = Some(client_version);
+            peer.last_seen = Some(SystemTime::now());
+        }
+    }
+
     /// Called when a _pending_ outbound connection is successful.
     pub(crate) fn on_active_outgoing_established(&mut self, peer_id: PeerId) {
         if let Some(peer) = self.peers.get_mut(&peer_id) {
@@ -694,8 +793,14 @@ impl PeersManager {
     }
 
     /// Marks the given peer as trusted.
+    ///
+    /// If the peer is already tracked, it is upgraded to a trusted peer, so that it is dialed even
+    /// if `trusted_nodes_only` is enabled.
     pub(crate) fn add_trusted_peer_id(&mut self, peer_id: PeerId) {
         self.trusted_peer_ids.insert(peer_id);
+        if let Some(peer) = self.peers.get_mut(&peer_id) {
+            peer.kind = PeerKind::Trusted;
+        }
     }
 
     /// Called for a newly discovered trusted peer.
@@ -818,16 +923,26 @@ impl PeersManager {
     }
 
     /// Removes the tracked node from the trusted set.
+    ///
+    /// If `trusted_nodes_only` is enabled, an active session to the peer is disconnected, since it
+    /// would not be accepted anymore.
     pub(crate) fn remove_peer_from_trusted_set(&mut self, peer_id: PeerId) {
-        let Entry::Occupied(mut entry) = self.peers.entry(peer_id) else { return };
-        if !entry.get().is_trusted() {
+        self.trusted_peer_ids.remove(&peer_id);
+
+        let Some(peer) = self.peers.get_mut(&peer_id) else { return };
+        if !peer.is_trusted() {
             return
         }
-
-        let peer = entry.get_mut();
         peer.kind = PeerKind::Basic;
 
-        self.trusted_peer_ids.remove(&peer_id);
+        if self.trusted_nodes_only && peer.state.is_connected() {
+            trace!(target: "net::peers", ?peer_id, "disconnecting peer removed from trusted set");
+            peer.state.disconnect();
+            self.queued_actions.push_back(PeerAction::Disconnect {
+                peer_id,
+                reason: Some(DisconnectReason::DisconnectRequested),
+            })
+        }
     }
 
     /// Returns the idle peer with the highest reputation.
@@ -838,14 +953,26 @@ impl PeersManager {
     /// If `trusted_nodes_only` is enabled, see [`PeersConfig`], then this will only consider
     /// `trusted` peers.
     ///
+    /// Persisted peers that were loaded on startup with the highest dial priority, see
+    /// [`PeersConfig::persisted_peers_redials`], are returned before any other peer.
+    ///
     /// Returns `None` if no peer is available.
     fn best_unconnected(&mut self) -> Option<(PeerId, &mut Peer)> {
-        let mut unconnected = self.peers.iter_mut().filter(|(_, peer)| {
+        let trusted_nodes_only = self.trusted_nodes_only;
+        let is_dialable = |peer: &Peer| {
             !peer.is_backed_off() &&
                 !peer.is_banned() &&
                 peer.state.is_unconnected() &&
-                (!self.trusted_nodes_only || peer.is_trusted())
-        });
+                (!trusted_nodes_only || peer.is_trusted())
+        };
+
+        while let Some(peer_id) = self.priority_dials.pop_front() {
+            if self.peers.get(&peer_id).is_some_and(is_dialable) {
+                return self.peers.get_mut(&peer_id).map(|peer| (peer_id, peer))
+            }
+        }
+
+        let mut unconnected = self.peers.iter_mut().filter(|(_, peer)| is_dialable(peer));
 
         // keep track of the best peer, if there's one
         let mut best_peer = unconnected.next()?;
@@ -986,6 +1113,21 @@ impl Default for PeersManager {
     }
 }
 
+/// Reads the persisted peers from the given file, if it exists.
+#[cfg(feature = "serde")]
+fn load_persisted_peers(path: &Path) -> Result<Vec<PersistedPeer>, FsPathError> {
+    if !path.exists() {
+        return Ok(Vec::new())
+    }
+    reth_fs_util::read_json_file(path)
+}
+
+/// Persisted peers can only be read with the `serde` feature.
+#[cfg(not(feature = "serde"))]
+fn load_persisted_peers(_path: &Path) -> Result<Vec<PersistedPeer>, FsPathError> {
+    Ok(Vec::new())
+}
+
 /// Tracks stats about connected nodes
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct ConnectionInfo {
@@ -1147,11 +1289,12 @@ mod tests {
     };
     use reth_net_banlist::BanList;
     use reth_network_api::Direction;
-    use reth_network_peers::{PeerId, TrustedPeer};
+    use reth_network_peers::{NodeRecord, PeerId, TrustedPeer};
     use reth_network_types::{
-        peers::reputation::DEFAULT_REPUTATION, BackoffKind, ReputationChangeKind,
+        peers::reputation::DEFAULT_REPUTATION, BackoffKind, PersistedPeer, ReputationChangeKind,
     };
     use std::{
+        collections::HashSet,
         future::{poll_fn, Future},
         io,
         net::{IpAddr, Ipv4Addr, SocketAddr},
@@ -1748,6 +1891,29 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_reputation_change_reported() {
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        let mut peers = PeersManager::default();
+
+        // changes of unknown peers are not reported
+        peers.apply_reputation_change(&peer, ReputationChangeKind::BadMessage);
+        assert_eq!(peers.pop_reputation_change(), None);
+
+        peers.add_peer(peer, PeerAddr::from_tcp(socket_addr), None);
+        peers.apply_reputation_change(&peer, ReputationChangeKind::BadMessage);
+        let reputation = peers.peers.get(&peer).unwrap().reputation;
+        assert!(reputation < DEFAULT_REPUTATION);
+        assert_eq!(peers.pop_reputation_change(), Some((peer, reputation)));
+
+        // resetting is reported, but only if the reputation changed
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Reset);
+        assert_eq!(peers.pop_reputation_change(), Some((peer, DEFAULT_REPUTATION)));
+        peers.apply_reputation_change(&peer, ReputationChangeKind::Reset);
+        assert_eq!(peers.pop_reputation_change(), None);
+    }
+
     #[tokio::test]
     async fn accept_incoming_trusted_unknown_peer_address() {
         let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 99)), 8008);
@@ -2294,6 +2460,44 @@ mod tests {
         assert!(!peers.peers.contains_key(&basic_peer));
     }
 
+    #[tokio::test]
+    async fn test_update_trusted_set_with_trusted_nodes_only() {
+        let config = PeersConfig::test().with_trusted_nodes_only(true);
+        let mut peers = PeersManager::new(config);
+
+        let peer = PeerId::random();
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 2)), 8008);
+        peers.add_peer(peer, PeerAddr::from_tcp(socket_addr), None);
+
+        match event!(peers) {
+            PeerAction::PeerAdded(peer_id) => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+
+        // the peer is only dialed once it is trusted
+        peers.add_trusted_peer_id(peer);
+        match event!(peers) {
+            PeerAction::Connect { peer_id, remote_addr } => {
+                assert_eq!(peer_id, peer);
+                assert_eq!(remote_addr, socket_addr);
+            }
+            _ => unreachable!(),
+        }
+        peers.on_active_outgoing_established(peer);
+
+        // and disconnected once it is no longer trusted
+        peers.remove_peer_from_trusted_set(peer);
+        assert!(!peers.trusted_peer_ids.contains(&peer));
+        match event!(peers) {
+            PeerAction::Disconnect { peer_id, .. } => {
+                assert_eq!(peer_id, peer);
+            }
+            _ => unreachable!(),
+        }
+    }
+
     #[tokio::test]
     async fn test_incoming_without_trusted_nodes_only() {
         let trusted_peer = PeerId::random();
@@ -2609,4 +2813,60 @@ mod tests {
         assert_eq!(record.tcp_addr(), socket_addr);
         assert_eq!(record.udp_addr(), socket_addr);
     }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn test_persisted_peers_are_redialed_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let persist_path = dir.path().join("peers.json");
+
+        let persisted = |ip: u8, reputation: i32, last_seen: u64| PersistedPeer {
+            record: NodeRecord::new(
+                SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, ip)), 8008),
+                PeerId::random(),
+            ),
+            client_version: Some("reth/v1.1.2".to_string()),
+            reputation,
+            last_seen,
+        };
+        let stale = persisted(1, -100, 5);
+        let best = persisted(2, 0, 10);
+        reth_fs_util::write_json_file(&persist_path, &vec![stale.clone(), best.clone()]).unwrap();
+
+        let basic = NodeRecord::new(
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 1, 3)), 8008),
+            PeerId::random(),
+        );
+        let config = PeersConfig::test()
+            .with_max_outbound(2)
+            .with_basic_nodes(HashSet::from([basic]))
+            .with_persist_path(&persist_path);
+        let mut peers = PeersManager::new(config);
+
+        let stale_peer = peers.peers.get(&stale.record.id).unwrap();
+        assert_eq!(stale_peer.reputation, -100);
+        assert_eq!(stale_peer.client_version.as_deref(), Some("reth/v1.1.2"));
+
+        // persisted peers are dialed by priority, before peers with a better reputation
+        for expected in [&best, &stale] {
+            match event!(peers) {
+                PeerAction::Connect { peer_id, remote_addr } => {
+                    assert_eq!(peer_id, expected.record.id);
+                    assert_eq!(remote_addr, expected.record.tcp_addr());
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        peers.on_active_outgoing_established(best.record.id);
+        peers.on_session_established(&best.record.id, "reth/v1.1.3".into());
+
+        // only peers that had a session are persisted, the connected peer is seen now
+        let snapshot = peers.persisted_peers();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].record, best.record);
+        assert_eq!(snapshot[0].client_version.as_deref(), Some("reth/v1.1.3"));
+        assert!(snapshot[0].last_seen > best.last_seen);
+        assert_eq!(snapshot[1], stale);
+    }
 }
@@ -3,17 +3,23 @@
 //! See also <https://github.com/ethereum/devp2p/blob/master/README.md>
 
 use alloy_primitives::bytes::BytesMut;
-use futures::Stream;
+use futures::{Stream, StreamExt};
+use parking_lot::Mutex;
 use reth_eth_wire::{
-    capability::SharedCapabilities, multiplex::ProtocolConnection, protocol::Protocol,
+    capability::SharedCapabilities, multiplex::ProtocolConnection, protocol::Protocol, Capability,
 };
 use reth_network_api::{Direction, PeerId};
 use std::{
+    collections::HashMap,
     fmt,
     net::SocketAddr,
     ops::{Deref, DerefMut},
     pin::Pin,
+    sync::Arc,
+    task::{ready, Context, Poll},
 };
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::UnboundedReceiverStream;
 
 /// A trait that allows to offer additional RLPx-based application-level protocols when establishing
 /// a peer-to-peer connection.
@@ -145,6 +151,198 @@ impl RlpxSubProtocols {
     }
 }
 
+/// Handles the messages of a [`MessageProtocol`].
+///
+/// All messages start with the message ID relative to the protocol, i.e. the first message of the
+/// protocol has ID `0`.
+pub trait MessageHandler: fmt::Debug + Send + Sync + 'static {
+    /// Invoked when a connection with a peer that supports the protocol was established.
+    fn on_connection(&self, _peer_id: PeerId, _direction: Direction) {}
+
+    /// Invoked for every message received from the peer.
+    ///
+    /// If a message is returned, it is sent back to the peer.
+    fn on_message(&self, peer_id: PeerId, message: BytesMut) -> Option<BytesMut>;
+
+    /// Invoked when the connection with the peer was closed.
+    fn on_disconnect(&self, _peer_id: PeerId) {}
+}
+
+/// The senders to all connected peers of a [`MessageProtocol`].
+type MessageProtocolPeers = Arc<Mutex<HashMap<PeerId, mpsc::UnboundedSender<BytesMut>>>>;
+
+/// An `RLPx` sub-protocol that is defined by its capability, number of messages and a
+/// [`MessageHandler`].
+///
+/// This takes care of the connection handling, messages can be sent to connected peers with the
+/// [`MessageProtocolHandle`].
+///
+/// The protocol can be registered before the network is launched, or at runtime with
+/// [`NetworkProtocols::add_rlpx_sub_protocol`](crate::NetworkProtocols::add_rlpx_sub_protocol).
+/// Capabilities are negotiated once during the `RLPx` handshake, so sessions that were established
+/// before the protocol was registered don't use it.
+#[derive(Debug)]
+pub struct MessageProtocol<H> {
+    protocol: Protocol,
+    handler: Arc<H>,
+    peers: MessageProtocolPeers,
+}
+
+impl<H: MessageHandler> MessageProtocol<H> {
+    /// Creates a new protocol with the given name, version and number of messages.
+    pub fn new(name: impl Into<String>, version: usize, messages: u8, handler: H) -> Self {
+        Self {
+            protocol: Protocol::new(Capability::new(name.into(), version), messages),
+            handler: Arc::new(handler),
+            peers: Default::default(),
+        }
+    }
+
+    /// Returns a handle to send messages to the connected peers.
+    pub fn handle(&self) -> MessageProtocolHandle {
+        MessageProtocolHandle { peers: self.peers.clone() }
+    }
+
+    fn connection_handler(&self) -> MessageConnectionHandler<H> {
+        MessageConnectionHandler {
+            protocol: self.protocol.clone(),
+            handler: self.handler.clone(),
+            peers: self.peers.clone(),
+        }
+    }
+}
+
+impl<H: MessageHandler> ProtocolHandler for MessageProtocol<H> {
+    type ConnectionHandler = MessageConnectionHandler<H>;
+
+    fn on_incoming(&self, _socket_addr: SocketAddr) -> Option<Self::ConnectionHandler> {
+        Some(self.connection_handler())
+    }
+
+    fn on_outgoing(
+        &self,
+        _socket_addr: SocketAddr,
+        _peer_id: PeerId,
+    ) -> Option<Self::ConnectionHandler> {
+        Some(self.connection_handler())
+    }
+}
+
+/// A handle to send messages to the peers that are connected with a [`MessageProtocol`].
+#[derive(Debug, Clone)]
+pub struct MessageProtocolHandle {
+    peers: MessageProtocolPeers,
+}
+
+impl MessageProtocolHandle {
+    /// Returns all peers that are connected with the protocol.
+    pub fn peers(&self) -> Vec<PeerId> {
+        self.peers.lock().keys().copied().collect()
+    }
+
+    /// Sends a message to the peer.
+    ///
+    /// Returns `false` if the peer is not connected with the protocol.
+    pub fn send(&self, peer_id: PeerId, message: BytesMut) -> bool {
+        self.peers.lock().get(&peer_id).is_some_and(|peer| peer.send(message).is_ok())
+    }
+
+    /// Sends a message to all connected peers.
+    pub fn broadcast(&self, message: BytesMut) {
+        for peer in self.peers.lock().values() {
+            let _ = peer.send(message.clone());
+        }
+    }
+}
+
+/// The [`ConnectionHandler`] of a [`MessageProtocol`].
+#[derive(Debug)]
+pub struct MessageConnectionHandler<H> {
+    protocol: Protocol,
+    handler: Arc<H>,
+    peers: MessageProtocolPeers,
+}
+
+impl<H: MessageHandler> ConnectionHandler for MessageConnectionHandler<H> {
+    type Connection = MessageConnection<H>;
+
+    fn protocol(&self) -> Protocol {
+        self.protocol.clone()
+    }
+
+    fn on_unsupported_by_peer(
+        self,
+        _supported: &SharedCapabilities,
+        _direction: Direction,
+        _peer_id: PeerId,
+    ) -> OnNotSupported {
+        OnNotSupported::KeepAlive
+    }
+
+    fn into_connection(
+        self,
+        direction: Direction,
+        peer_id: PeerId,
+        conn: ProtocolConnection,
+    ) -> Self::Connection {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.peers.lock().insert(peer_id, tx.clone());
+        self.handler.on_connection(peer_id, direction);
+        MessageConnection {
+            peer_id,
+            conn,
+            to_peer: tx,
+            from_handle: UnboundedReceiverStream::new(rx),
+            handler: self.handler,
+            peers: self.peers,
+        }
+    }
+}
+
+/// The connection of a [`MessageProtocol`] with a peer.
+#[derive(Debug)]
+pub struct MessageConnection<H: MessageHandler> {
+    peer_id: PeerId,
+    conn: ProtocolConnection,
+    to_peer: mpsc::UnboundedSender<BytesMut>,
+    from_handle: UnboundedReceiverStream<BytesMut>,
+    handler: Arc<H>,
+    peers: MessageProtocolPeers,
+}
+
+impl<H: MessageHandler> Stream for MessageConnection<H> {
+    type Item = BytesMut;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Poll::Ready(Some(msg)) = this.from_handle.poll_next_unpin(cx) {
+                return Poll::Ready(Some(msg))
+            }
+
+            let Some(msg) = ready!(this.conn.poll_next_unpin(cx)) else { return Poll::Ready(None) };
+
+            if let Some(response) = this.handler.on_message(this.peer_id, msg) {
+                return Poll::Ready(Some(response))
+            }
+        }
+    }
+}
+
+impl<H: MessageHandler> Drop for MessageConnection<H> {
+    fn drop(&mut self) {
+        {
+            let mut peers = self.peers.lock();
+            // the peer may have reconnected in the meantime
+            if peers.get(&self.peer_id).is_some_and(|peer| peer.same_channel(&self.to_peer)) {
+                peers.remove(&self.peer_id);
+            }
+        }
+        self.handler.on_disconnect(self.peer_id);
+    }
+}
+
 /// A set of additional RLPx-based sub-protocol connection handlers.
 #[derive(Default)]
 pub(crate) struct RlpxSubProtocolHandlers(Vec<Box<dyn DynConnectionHandler>>);
@@ -54,6 +54,10 @@ const SAMPLE_IMPACT: f64 = 0.1;
 /// Amount of RTTs before timeout
 const TIMEOUT_SCALING: u32 = 3;
 
+/// The maximum number of messages that are sent before a queued response to a sync request, see
+/// [`QueuedOutgoingMessages`].
+const MAX_CONSECUTIVE_PRIORITY_MESSAGES: usize = 16;
+
 /// The type that advances an established session by listening for incoming messages (from local
 /// node or read from connection) and emitting events back to the
 /// [`SessionManager`](super::SessionManager).
@@ -176,10 +180,14 @@ impl<N: NetworkPrimitives> ActiveSession<N> {
         }
 
         match msg {
-            message @ EthMessage::Status(_) => OnIncomingMessageOutcome::BadMessage {
-                error: EthStreamError::EthHandshakeError(EthHandshakeError::StatusNotInHandshake),
-                message,
-            },
+            message @ (EthMessage::Status(_) | EthMessage::Status69(_)) => {
+                OnIncomingMessageOutcome::BadMessage {
+                    error: EthStreamError::EthHandshakeError(
+                        EthHandshakeError::StatusNotInHandshake,
+                    ),
+                    message,
+                }
+            }
             EthMessage::NewBlockHashes(msg) => {
                 self.try_emit_broadcast(PeerMessage::NewBlockHashes(msg)).into()
             }
@@ -237,6 +245,25 @@ impl<N: NetworkPrimitives> ActiveSession<N> {
             EthMessage::Receipts(resp) => {
                 on_response!(resp, GetReceipts)
             }
+            EthMessage::Receipts69(resp) => {
+                // eth/69 receipts don't include the bloom, so it's recomputed here to keep the
+                // response compatible with all other versions
+                let resp =
+                    RequestPair { request_id: resp.request_id, message: resp.message.into() };
+                on_response!(resp, GetReceipts)
+            }
+            EthMessage::BlockRangeUpdate(msg) => {
+                if msg.earliest > msg.latest {
+                    return OnIncomingMessageOutcome::BadMessage {
+                        error: EthStreamError::InvalidBlockRange {
+                            earliest: msg.earliest,
+                            latest: msg.latest,
+                        },
+                        message: EthMessage::BlockRangeUpdate(msg),
+                    }
+                }
+                self.try_emit_broadcast(PeerMessage::BlockRangeUpdate(msg)).into()
+            }
         }
     }
 
@@ -257,10 +284,15 @@ impl<N: NetworkPrimitives> ActiveSession<N> {
     fn on_internal_peer_message(&mut self, msg: PeerMessage<N>) {
         match msg {
             PeerMessage::NewBlockHashes(msg) => {
-                self.queued_outgoing.push_back(EthMessage::NewBlockHashes(msg).into());
+                // block announcements were removed in eth/69
+                if !self.conn.version().is_eth69() {
+                    self.queued_outgoing.push_back(EthMessage::NewBlockHashes(msg).into());
+                }
             }
             PeerMessage::NewBlock(msg) => {
-                self.queued_outgoing.push_back(EthBroadcastMessage::NewBlock(msg.block).into());
+                if !self.conn.version().is_eth69() {
+                    self.queued_outgoing.push_back(EthBroadcastMessage::NewBlock(msg.block).into());
+                }
             }
             PeerMessage::PooledTransactions(msg) => {
                 if msg.is_valid_for_version(self.conn.version()) {
@@ -274,6 +306,11 @@ impl<N: NetworkPrimitives> ActiveSession<N> {
             PeerMessage::SendTransactions(msg) => {
                 self.queued_outgoing.push_back(EthBroadcastMessage::Transactions(msg).into());
             }
+            PeerMessage::BlockRangeUpdate(msg) => {
+                if self.conn.version().is_eth69() {
+                    self.queued_outgoing.push_back(EthMessage::BlockRangeUpdate(msg).into());
+                }
+            }
             PeerMessage::ReceivedTransaction(_) => {
                 unreachable!("Not emitted by network")
             }
@@ -295,6 +332,13 @@ impl<N: NetworkPrimitives> ActiveSession<N> {
     /// This will queue the response to be sent to the peer
     fn handle_outgoing_response(&mut self, id: u64, resp: PeerResponseResult<N>) {
         match resp.try_into_message(id) {
+            Ok(EthMessage::Receipts(receipts)) if self.conn.version().is_eth69() => {
+                let receipts = RequestPair {
+                    request_id: receipts.request_id,
+                    message: receipts.message.into(),
+                };
+                self.queued_outgoing.push_back(EthMessage::Receipts69(receipts).into());
+            }
             Ok(msg) => {
                 self.queued_outgoing.push_back(msg.into());
             }
@@ -745,6 +789,23 @@ pub(crate) enum OutgoingMessage<N: NetworkPrimitives> {
     Raw(RawCapabilityMessage),
 }
 
+impl<N: NetworkPrimitives> OutgoingMessage<N> {
+    /// Returns true if this is a response to a sync request, which is potentially large and less
+    /// time critical than block announcements, transactions and requests.
+    const fn is_bulk(&self) -> bool {
+        matches!(
+            self,
+            Self::Eth(
+                EthMessage::BlockHeaders(_) |
+                    EthMessage::BlockBodies(_) |
+                    EthMessage::NodeData(_) |
+                    EthMessage::Receipts(_) |
+                    EthMessage::Receipts69(_)
+            )
+        )
+    }
+}
+
 impl<N: NetworkPrimitives> From<EthMessage<N>> for OutgoingMessage<N> {
     fn from(value: EthMessage<N>) -> Self {
         Self::Eth(value)
@@ -769,39 +830,72 @@ fn calculate_new_timeout(current_timeout: Duration, estimated_rtt: Duration) ->
 }
 
 /// A helper struct that wraps the queue of outgoing messages and a metric to track their count
+///
+/// Responses to sync requests, see [`OutgoingMessage::is_bulk`], are queued separately and only
+/// sent if no other message is queued, so that block announcements and transactions are not
+/// delayed by bulk sync traffic if the connection is saturated. To not starve the remote's sync
+/// requests, a bulk message is sent after at most [`MAX_CONSECUTIVE_PRIORITY_MESSAGES`] other
+/// messages.
 pub(crate) struct QueuedOutgoingMessages<N: NetworkPrimitives> {
     messages: VecDeque<OutgoingMessage<N>>,
+    bulk: VecDeque<OutgoingMessage<N>>,
+    /// Number of messages popped in a row while a bulk message was queued.
+    consecutive_priority: usize,
     count: Gauge,
 }
 
 impl<N: NetworkPrimitives> QueuedOutgoingMessages<N> {
     pub(crate) const fn new(metric: Gauge) -> Self {
-        Self { messages: VecDeque::new(), count: metric }
+        Self {
+            messages: VecDeque::new(),
+            bulk: VecDeque::new(),
+            consecutive_priority: 0,
+            count: metric,
+        }
     }
 
     pub(crate) fn push_back(&mut self, message: OutgoingMessage<N>) {
-        self.messages.push_back(message);
+        if message.is_bulk() {
+            self.bulk.push_back(message);
+        } else {
+            self.messages.push_back(message);
+        }
         self.count.increment(1);
     }
 
     pub(crate) fn pop_front(&mut self) -> Option<OutgoingMessage<N>> {
-        self.messages.pop_front().inspect(|_| self.count.decrement(1))
+        let msg = if self.consecutive_priority < MAX_CONSECUTIVE_PRIORITY_MESSAGES {
+            self.messages.pop_front().or_else(|| self.bulk.pop_front())
+        } else {
+            self.bulk.pop_front().or_else(|| self.messages.pop_front())
+        }?;
+
+        if msg.is_bulk() || self.bulk.is_empty() {
+            self.consecutive_priority = 0;
+        } else {
+            self.consecutive_priority += 1;
+        }
+        self.count.decrement(1);
+        Some(msg)
     }
 
     pub(crate) fn shrink_to_fit(&mut self) {
         self.messages.shrink_to_fit();
+        self.bulk.shrink_to_fit();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::session::{handle::PendingSessionEvent, start_pending_incoming_session};
+    use crate::session::{
+        bandwidth::BandwidthMeter, handle::PendingSessionEvent, start_pending_incoming_session,
+    };
     use reth_chainspec::MAINNET;
     use reth_ecies::stream::ECIESStream;
     use reth_eth_wire::{
-        EthNetworkPrimitives, EthStream, GetBlockBodies, HelloMessageWithProtocols, P2PStream,
-        Status, StatusBuilder, UnauthedEthStream, UnauthedP2PStream,
+        BlockBodies, EthNetworkPrimitives, EthStream, GetBlockBodies, HelloMessageWithProtocols,
+        P2PStream, Status, StatusBuilder, UnauthedEthStream, UnauthedP2PStream,
     };
     use reth_network_peers::pk2id;
     use reth_network_types::session::config::PROTOCOL_BREACH_REQUEST_TIMEOUT;
@@ -847,7 +941,7 @@ mod tests {
             F: FnOnce(EthStream<P2PStream<ECIESStream<TcpStream>>, N>) -> O + Send + 'static,
             O: Future<Output = ()> + Send + Sync,
         {
-            let status = self.status;
+            let mut status = self.status;
             let fork_filter = self.fork_filter.clone();
             let local_peer_id = self.local_peer_id;
             let mut hello = self.hello.clone();
@@ -858,8 +952,10 @@ mod tests {
                 let sink = ECIESStream::connect(outgoing, key, local_peer_id).await.unwrap();
 
                 let (p2p_stream, _) = UnauthedP2PStream::new(sink).handshake(hello).await.unwrap();
+                status.set_eth_version(p2p_stream.shared_capabilities().eth_version().unwrap());
 
                 let (client_stream, _) = UnauthedEthStream::new(p2p_stream)
+                    .with_block_range(Default::default())
                     .handshake(status, fork_filter)
                     .await
                     .unwrap();
@@ -877,11 +973,14 @@ mod tests {
                 disconnect_rx,
                 session_id,
                 stream,
+                BandwidthMeter::default().session(),
+                None,
                 pending_sessions_tx,
                 remote_addr,
                 self.secret_key,
                 self.hello.clone(),
                 self.status,
+                Default::default(),
                 self.fork_filter.clone(),
                 Default::default(),
             ));
@@ -1188,4 +1287,25 @@ mod tests {
         assert!(calculate_new_timeout(timeout, rtt * 2) > timeout);
         assert!(calculate_new_timeout(timeout, rtt * 2) < timeout * 2);
     }
+
+    #[test]
+    fn test_queued_outgoing_priority() {
+        let mut queue = QueuedOutgoingMessages::<EthNetworkPrimitives>::new(Gauge::noop());
+        let bodies = || {
+            EthMessage::BlockBodies(RequestPair { request_id: 0, message: BlockBodies::default() })
+        };
+
+        queue.push_back(bodies().into());
+        for _ in 0..MAX_CONSECUTIVE_PRIORITY_MESSAGES + 1 {
+            queue.push_back(EthMessage::NewBlockHashes(Default::default()).into());
+        }
+
+        // announcements preempt the queued bodies response, but can't starve it
+        for _ in 0..MAX_CONSECUTIVE_PRIORITY_MESSAGES {
+            assert!(!queue.pop_front().unwrap().is_bulk());
+        }
+        assert!(queue.pop_front().unwrap().is_bulk());
+        assert!(!queue.pop_front().unwrap().is_bulk());
+        assert!(queue.pop_front().is_none());
+    }
 }
@@ -0,0 +1,312 @@
+//! Bandwidth accounting and rate limiting of peer sessions.
+
+use crate::metrics::BandwidthMetrics;
+use futures::ready;
+use parking_lot::Mutex;
+use reth_network_peers::PeerId;
+use reth_network_types::BandwidthLimits;
+use std::{
+    future::Future,
+    io,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    time::{Instant, Sleep},
+};
+
+/// The number of bytes a peer session has transferred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerBandwidth {
+    /// The identifier of the remote peer.
+    pub peer_id: PeerId,
+    /// The number of bytes received from the peer.
+    pub ingress_bytes: u64,
+    /// The number of bytes sent to the peer.
+    pub egress_bytes: u64,
+}
+
+/// Live byte counters of a session, shared between the session's stream and its handle.
+#[derive(Debug, Default)]
+pub(crate) struct SessionBandwidth {
+    ingress: AtomicU64,
+    egress: AtomicU64,
+}
+
+impl SessionBandwidth {
+    /// Returns the number of bytes received from the peer.
+    pub(crate) fn ingress_bytes(&self) -> u64 {
+        self.ingress.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes sent to the peer.
+    pub(crate) fn egress_bytes(&self) -> u64 {
+        self.egress.load(Ordering::Relaxed)
+    }
+}
+
+/// Creates the [`SessionMeter`]s of new sessions, enforcing the configured [`BandwidthLimits`].
+///
+/// The total limits are shared by all sessions created by the same meter.
+#[derive(Debug, Default)]
+pub(crate) struct BandwidthMeter {
+    limits: BandwidthLimits,
+    total_ingress: Option<Arc<Mutex<RateLimit>>>,
+    total_egress: Option<Arc<Mutex<RateLimit>>>,
+    metrics: BandwidthMetrics,
+}
+
+impl BandwidthMeter {
+    /// Creates a new meter with the given limits.
+    pub(crate) fn new(limits: BandwidthLimits) -> Self {
+        let shared =
+            |limit: Option<u64>| limit.map(|limit| Arc::new(Mutex::new(RateLimit::new(limit))));
+        Self {
+            limits,
+            total_ingress: shared(limits.total_ingress),
+            total_egress: shared(limits.total_egress),
+            metrics: Default::default(),
+        }
+    }
+
+    /// Returns the meter for a new session.
+    pub(crate) fn session(&self) -> SessionMeter {
+        SessionMeter {
+            bandwidth: Default::default(),
+            ingress: Throttle::new(self.limits.peer_ingress, self.total_ingress.clone()),
+            egress: Throttle::new(self.limits.peer_egress, self.total_egress.clone()),
+            metrics: self.metrics.clone(),
+        }
+    }
+}
+
+/// Meters the connection of a single session.
+#[derive(Debug)]
+pub(crate) struct SessionMeter {
+    bandwidth: Arc<SessionBandwidth>,
+    ingress: Throttle,
+    egress: Throttle,
+    metrics: BandwidthMetrics,
+}
+
+impl SessionMeter {
+    /// Returns the byte counters of the session.
+    pub(crate) fn bandwidth(&self) -> Arc<SessionBandwidth> {
+        Arc::clone(&self.bandwidth)
+    }
+
+    /// Wraps the connection of the session.
+    pub(crate) const fn wrap<S>(self, stream: S) -> MeteredStream<S> {
+        MeteredStream { inner: stream, meter: self }
+    }
+}
+
+/// A stream that counts the bytes read and written, and delays reads and writes while a
+/// bandwidth limit is exceeded.
+#[derive(Debug)]
+pub struct MeteredStream<S> {
+    inner: S,
+    meter: SessionMeter,
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for MeteredStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let meter = &mut this.meter;
+        ready!(meter.ingress.poll_ready(cx));
+
+        let filled = buf.filled().len();
+        ready!(Pin::new(&mut this.inner).poll_read(cx, buf))?;
+        let bytes = (buf.filled().len() - filled) as u64;
+
+        meter.bandwidth.ingress.fetch_add(bytes, Ordering::Relaxed);
+        meter.metrics.ingress_bytes.increment(bytes);
+        meter.ingress.consume(bytes);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for MeteredStream<S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let meter = &mut this.meter;
+        ready!(meter.egress.poll_ready(cx));
+
+        let written = ready!(Pin::new(&mut this.inner).poll_write(cx, buf))?;
+        let bytes = written as u64;
+
+        meter.bandwidth.egress.fetch_add(bytes, Ordering::Relaxed);
+        meter.metrics.egress_bytes.increment(bytes);
+        meter.egress.consume(bytes);
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+/// Enforces the per-peer and total limit of one direction of a session.
+#[derive(Debug)]
+struct Throttle {
+    peer: Option<RateLimit>,
+    total: Option<Arc<Mutex<RateLimit>>>,
+    /// Pending until a limit allows the transfer of bytes again.
+    delay: Option<Pin<Box<Sleep>>>,
+}
+
+impl Throttle {
+    fn new(peer: Option<u64>, total: Option<Arc<Mutex<RateLimit>>>) -> Self {
+        Self { peer: peer.map(RateLimit::new), total, delay: None }
+    }
+
+    /// Returns `Poll::Ready` if bytes can be transferred.
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        loop {
+            if let Some(delay) = &mut self.delay {
+                ready!(delay.as_mut().poll(cx));
+                self.delay = None;
+            }
+
+            let now = Instant::now();
+            let peer = self.peer.as_mut().and_then(|limit| limit.delay(now));
+            let total = self.total.as_ref().and_then(|limit| limit.lock().delay(now));
+            match peer.max(total) {
+                Some(delay) => self.delay = Some(Box::pin(tokio::time::sleep(delay))),
+                None => return Poll::Ready(()),
+            }
+        }
+    }
+
+    /// Records transferred bytes.
+    fn consume(&mut self, bytes: u64) {
+        if let Some(limit) = &mut self.peer {
+            limit.consume(bytes);
+        }
+        if let Some(limit) = &self.total {
+            limit.lock().consume(bytes);
+        }
+    }
+}
+
+/// A token bucket that limits the number of bytes per second.
+///
+/// The bucket holds at most one second worth of bytes. Transfers are allowed as long as bytes are
+/// available, even if they exceed the available bytes, the deficit then delays the next transfer.
+#[derive(Debug)]
+struct RateLimit {
+    bytes_per_sec: f64,
+    /// The number of available bytes, negative if more bytes were transferred than available.
+    available: f64,
+    last_refill: Instant,
+}
+
+impl RateLimit {
+    /// Creates a new bucket for the given rate.
+    ///
+    /// # Panics
+    ///
+    /// If the rate is zero, which would block all transfers.
+    fn new(bytes_per_sec: u64) -> Self {
+        assert!(bytes_per_sec > 0, "bandwidth limit must be at least one byte per second");
+        let bytes_per_sec = bytes_per_sec as f64;
+        Self { bytes_per_sec, available: bytes_per_sec, last_refill: Instant::now() }
+    }
+
+    /// Refills the bucket, returns the time until bytes are available, `None` if they are.
+    fn delay(&mut self, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.available = (self.available + elapsed * self.bytes_per_sec).min(self.bytes_per_sec);
+        self.last_refill = now;
+
+        (self.available <= 0.).then(|| {
+            // wait at least a millisecond, the resolution of the timer
+            Duration::from_secs_f64(-self.available / self.bytes_per_sec)
+                .max(Duration::from_millis(1))
+        })
+    }
+
+    fn consume(&mut self, bytes: u64) {
+        self.available -= bytes as f64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn rate_limit_refills() {
+        let mut limit = RateLimit::new(1000);
+        let now = limit.last_refill;
+        assert_eq!(limit.delay(now), None);
+
+        limit.consume(1500);
+        assert_eq!(limit.delay(now), Some(Duration::from_millis(500)));
+        assert_eq!(limit.delay(now + Duration::from_millis(250)), Some(Duration::from_millis(250)));
+        assert_eq!(limit.delay(now + Duration::from_millis(600)), None);
+
+        // the bucket is capped at one second worth of bytes
+        assert_eq!(limit.delay(now + Duration::from_secs(10)), None);
+        limit.consume(2000);
+        assert_eq!(limit.delay(now + Duration::from_secs(10)), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "bandwidth limit must be at least one byte per second")]
+    fn reject_zero_rate_limit() {
+        BandwidthMeter::new(BandwidthLimits::default().with_total_ingress(0));
+    }
+
+    #[tokio::test]
+    async fn counts_bytes() {
+        let meter = BandwidthMeter::default().session();
+        let bandwidth = meter.bandwidth();
+        let (local, mut remote) = tokio::io::duplex(64);
+        let mut stream = meter.wrap(local);
+
+        stream.write_all(b"hello").await.unwrap();
+        remote.write_all(b"hi").await.unwrap();
+        let mut buf = [0u8; 2];
+        stream.read_exact(&mut buf).await.unwrap();
+
+        assert_eq!(bandwidth.egress_bytes(), 5);
+        assert_eq!(bandwidth.ingress_bytes(), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn throttles_writes() {
+        let meter = BandwidthMeter::new(BandwidthLimits::default().with_peer_egress(100));
+        let (local, mut remote) = tokio::io::duplex(1024);
+        let mut stream = meter.session().wrap(local);
+
+        let start = Instant::now();
+        stream.write_all(&[0u8; 150]).await.unwrap();
+        // the first write exhausts the bucket, the next one waits for the deficit to refill
+        stream.write_all(&[0u8; 10]).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(500));
+
+        let mut buf = [0u8; 160];
+        remote.read_exact(&mut buf).await.unwrap();
+    }
+}
@@ -0,0 +1,469 @@
+//! Debug capture of the `RLPx` messages of peer sessions.
+//!
+//! Every message that is received from or sent to a peer is appended to a capture file as an RLP
+//! encoded [`CapturedMessage`], see [`read_capture_file`] for replaying them. The messages are
+//! written by a dedicated thread, so capturing doesn't block the sessions.
+
+use alloy_primitives::{
+    bytes::{self, BytesMut},
+    Bytes,
+};
+use alloy_rlp::{Decodable, Header, RlpDecodable, RlpEncodable};
+use futures::{Sink, Stream};
+use reth_network_peers::PeerId;
+use reth_network_types::CaptureConfig;
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::mpsc::{self, Receiver, SyncSender, TrySendError},
+    task::{ready, Context, Poll},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tracing::{debug, warn};
+
+/// The number of messages that can be queued for the capture writer, messages are dropped if the
+/// writer can't keep up.
+const CAPTURE_CHANNEL_CAPACITY: usize = 4096;
+
+/// The RLP encoded id of the `Hello` message, all messages after the `Hello` are snappy
+/// compressed.
+const HELLO_MESSAGE_ID: u8 = 0x80;
+
+/// The direction of a [`CapturedMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum CaptureDirection {
+    /// The message was received from the peer.
+    Inbound = 0,
+    /// The message was sent to the peer.
+    Outbound = 1,
+}
+
+/// A single `RLPx` message of a session, as it is stored in a capture file.
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+pub struct CapturedMessage {
+    /// The time the message was received or sent, in milliseconds since the unix epoch.
+    pub timestamp_ms: u64,
+    /// The remote peer of the session.
+    pub peer_id: PeerId,
+    /// Whether the message was received or sent, see [`CaptureDirection`].
+    pub direction: u8,
+    /// The id of the message.
+    pub message_id: u8,
+    /// The RLP encoded payload of the message.
+    ///
+    /// Messages that were snappy compressed on the wire are stored decompressed, unless they
+    /// failed to decompress.
+    pub payload: Bytes,
+}
+
+impl CapturedMessage {
+    /// Creates a new captured message from a raw `RLPx` message, which starts with the message
+    /// id.
+    fn new(raw: RawMessage, decoder: &mut snap::raw::Decoder) -> Self {
+        let RawMessage { timestamp_ms, peer_id, direction, compressed, message } = raw;
+        let mut payload = &message[..];
+        // the message id is a single byte for all known messages, keep malformed messages as is
+        let message_id = u8::decode(&mut payload).unwrap_or_else(|_| {
+            payload = &message[..];
+            u8::MAX
+        });
+        let payload = if compressed {
+            decoder.decompress_vec(payload).map(Bytes::from).unwrap_or_else(|err| {
+                debug!(target: "net::session", %err, ?peer_id, message_id, "failed to decompress captured message");
+                Bytes::copy_from_slice(payload)
+            })
+        } else {
+            Bytes::copy_from_slice(payload)
+        };
+        Self { timestamp_ms, peer_id, direction: direction as u8, message_id, payload }
+    }
+
+    /// Returns the direction of the message, `None` if unknown.
+    pub const fn direction(&self) -> Option<CaptureDirection> {
+        match self.direction {
+            0 => Some(CaptureDirection::Inbound),
+            1 => Some(CaptureDirection::Outbound),
+            _ => None,
+        }
+    }
+}
+
+/// Reads all messages of a capture file, in the order they were captured.
+pub fn read_capture_file(path: impl AsRef<Path>) -> io::Result<Vec<CapturedMessage>> {
+    let data = fs::read(path)?;
+    let mut buf = data.as_slice();
+    let mut messages = Vec::new();
+    while !buf.is_empty() {
+        // a partially written message at the end of the file is ignored
+        let mut remaining = buf;
+        match Header::decode(&mut remaining) {
+            Ok(header) if header.payload_length <= remaining.len() => {}
+            _ => break,
+        }
+        let message = CapturedMessage::decode(&mut buf)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        messages.push(message);
+    }
+    Ok(messages)
+}
+
+/// A message as it was received or sent, before it is decoded by the capture writer.
+#[derive(Debug)]
+struct RawMessage {
+    timestamp_ms: u64,
+    peer_id: PeerId,
+    direction: CaptureDirection,
+    /// Whether the payload is snappy compressed.
+    compressed: bool,
+    message: bytes::Bytes,
+}
+
+/// Commands for the capture writer.
+#[derive(Debug)]
+enum CaptureCommand {
+    /// Write a message to the capture file.
+    Message(RawMessage),
+    /// Flush the capture file and notify the sender.
+    #[cfg(test)]
+    Flush(mpsc::SyncSender<()>),
+}
+
+/// Sends the captured messages of all sessions to a writer thread, which writes them to a
+/// rotating capture file.
+#[derive(Debug, Clone)]
+pub(crate) struct SessionCapture {
+    to_writer: SyncSender<CaptureCommand>,
+}
+
+impl SessionCapture {
+    /// Opens the capture file of the given config, appending to it if it exists, and spawns the
+    /// writer thread.
+    ///
+    /// The writer thread exits once all [`SessionCapture`]s are dropped.
+    pub(crate) fn new(config: CaptureConfig) -> io::Result<Self> {
+        let file = CaptureFile::open(config)?;
+        let (to_writer, from_sessions) = mpsc::sync_channel(CAPTURE_CHANNEL_CAPACITY);
+        std::thread::Builder::new()
+            .name("session-capture".to_string())
+            .spawn(move || file.run(from_sessions))?;
+        Ok(Self { to_writer })
+    }
+
+    /// Wraps the stream of a session with the given peer.
+    pub(crate) const fn wrap<S>(
+        capture: Option<Self>,
+        peer_id: PeerId,
+        stream: S,
+    ) -> CapturedStream<S> {
+        CapturedStream {
+            inner: stream,
+            peer_id,
+            capture,
+            inbound_compressed: false,
+            outbound_compressed: false,
+        }
+    }
+
+    /// Queues the message for the writer, the message is dropped if the writer can't keep up.
+    fn capture(
+        &self,
+        peer_id: PeerId,
+        direction: CaptureDirection,
+        compressed: bool,
+        message: bytes::Bytes,
+    ) {
+        let timestamp_ms =
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        let message = RawMessage { timestamp_ms, peer_id, direction, compressed, message };
+        match self.to_writer.try_send(CaptureCommand::Message(message)) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                debug!(target: "net::session", ?peer_id, "capture writer is busy, dropping message");
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                debug!(target: "net::session", ?peer_id, "capture writer exited, dropping message");
+            }
+        }
+    }
+
+    /// Waits until all queued messages are written to the capture file.
+    #[cfg(test)]
+    fn flush(&self) {
+        let (tx, rx) = mpsc::sync_channel(1);
+        self.to_writer.send(CaptureCommand::Flush(tx)).unwrap();
+        rx.recv().unwrap();
+    }
+}
+
+/// The current capture file.
+#[derive(Debug)]
+struct CaptureFile {
+    config: CaptureConfig,
+    writer: BufWriter<File>,
+    size: u64,
+}
+
+impl CaptureFile {
+    fn open(config: CaptureConfig) -> io::Result<Self> {
+        if let Some(parent) = config.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&config.path)?;
+        let size = file.metadata()?.len();
+        Ok(Self { config, writer: BufWriter::new(file), size })
+    }
+
+    /// Writes the messages of all sessions until all senders are dropped.
+    ///
+    /// The file is flushed whenever all queued messages are written.
+    fn run(mut self, from_sessions: Receiver<CaptureCommand>) {
+        let mut decoder = snap::raw::Decoder::new();
+        while let Ok(command) = from_sessions.recv() {
+            self.on_command(command, &mut decoder);
+            while let Ok(command) = from_sessions.try_recv() {
+                self.on_command(command, &mut decoder);
+            }
+            if let Err(err) = self.writer.flush() {
+                warn!(target: "net::session", %err, "failed to flush capture file");
+            }
+        }
+    }
+
+    fn on_command(&mut self, command: CaptureCommand, decoder: &mut snap::raw::Decoder) {
+        match command {
+            CaptureCommand::Message(message) => {
+                let message = CapturedMessage::new(message, decoder);
+                if let Err(err) = self.write(&message) {
+                    warn!(target: "net::session", %err, "failed to capture message");
+                }
+            }
+            #[cfg(test)]
+            CaptureCommand::Flush(tx) => {
+                let _ = self.writer.flush();
+                let _ = tx.send(());
+            }
+        }
+    }
+
+    fn write(&mut self, message: &CapturedMessage) -> io::Result<()> {
+        if self.size >= self.config.max_file_size {
+            self.rotate()?;
+        }
+        let encoded = alloy_rlp::encode(message);
+        self.writer.write_all(&encoded)?;
+        self.size += encoded.len() as u64;
+        Ok(())
+    }
+
+    /// Moves the current file to `path.1`, shifting the rotated files and removing the oldest.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.writer.flush()?;
+        let path = &self.config.path;
+        let max_rotated = self.config.max_files.saturating_sub(1);
+        if max_rotated == 0 {
+            fs::remove_file(path)?;
+        } else {
+            let _ = fs::remove_file(rotated_path(path, max_rotated));
+            for n in (1..max_rotated).rev() {
+                let from = rotated_path(path, n);
+                if from.exists() {
+                    fs::rename(from, rotated_path(path, n + 1))?;
+                }
+            }
+            fs::rename(path, rotated_path(path, 1))?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        self.writer = BufWriter::new(file);
+        self.size = 0;
+        Ok(())
+    }
+}
+
+/// Returns the path of the `n`th rotated capture file.
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{n}"));
+    name.into()
+}
+
+/// A stream of raw `RLPx` messages that captures all messages it yields and sends.
+#[derive(Debug)]
+pub struct CapturedStream<S> {
+    inner: S,
+    peer_id: PeerId,
+    capture: Option<SessionCapture>,
+    /// Whether the `Hello` was received, after which all messages are snappy compressed.
+    inbound_compressed: bool,
+    /// Whether the `Hello` was sent, after which all messages are snappy compressed.
+    outbound_compressed: bool,
+}
+
+impl<S> CapturedStream<S> {
+    /// Returns the wrapped stream.
+    pub const fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S> Stream for CapturedStream<S>
+where
+    S: Stream<Item = io::Result<BytesMut>> + Unpin,
+{
+    type Item = io::Result<BytesMut>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let item = ready!(Pin::new(&mut this.inner).poll_next(cx));
+        if let (Some(capture), Some(Ok(message))) = (&this.capture, &item) {
+            capture.capture(
+                this.peer_id,
+                CaptureDirection::Inbound,
+                this.inbound_compressed,
+                bytes::Bytes::copy_from_slice(message),
+            );
+            this.inbound_compressed |= message.first() == Some(&HELLO_MESSAGE_ID);
+        }
+        Poll::Ready(item)
+    }
+}
+
+impl<S> Sink<bytes::Bytes> for CapturedStream<S>
+where
+    S: Sink<bytes::Bytes, Error = io::Error> + Unpin,
+{
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_ready(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: bytes::Bytes) -> Result<(), Self::Error> {
+        let this = self.get_mut();
+        if let Some(capture) = &this.capture {
+            capture.capture(
+                this.peer_id,
+                CaptureDirection::Outbound,
+                this.outbound_compressed,
+                item.clone(),
+            );
+            this.outbound_compressed |= item.first() == Some(&HELLO_MESSAGE_ID);
+        }
+        Pin::new(&mut this.inner).start_send(item)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{SinkExt, StreamExt};
+
+    #[tokio::test]
+    async fn captures_messages() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("capture");
+        let capture = SessionCapture::new(CaptureConfig::new(path.clone())).unwrap();
+        let peer_id = PeerId::random();
+
+        // a `Hello` followed by a snappy compressed `Ping`
+        let mut ping = vec![0x02];
+        ping.extend(snap::raw::Encoder::new().compress_vec(&[0xc0]).unwrap());
+        let inbound = futures::stream::iter([
+            Ok(BytesMut::from(&[0x80, 0xc1, 0x05][..])),
+            Ok(BytesMut::from(&ping[..])),
+        ]);
+        let sink = Vec::<bytes::Bytes>::new().sink_map_err(|_| io::Error::other("closed"));
+        let mut stream =
+            SessionCapture::wrap(Some(capture.clone()), peer_id, SplitStream(inbound, sink));
+
+        stream.next().await.unwrap().unwrap();
+        stream.next().await.unwrap().unwrap();
+        stream.send(bytes::Bytes::from_static(&[0x10, 0xc1, 0x80])).await.unwrap();
+        capture.flush();
+
+        let messages = read_capture_file(&path).unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[0].peer_id, peer_id);
+        assert_eq!(messages[0].direction(), Some(CaptureDirection::Inbound));
+        assert_eq!(messages[0].message_id, 0x00);
+        assert_eq!(messages[0].payload, Bytes::from_static(&[0xc1, 0x05]));
+        assert_eq!(messages[1].message_id, 0x02);
+        assert_eq!(messages[1].payload, Bytes::from_static(&[0xc0]));
+        assert_eq!(messages[2].direction(), Some(CaptureDirection::Outbound));
+        assert_eq!(messages[2].message_id, 0x10);
+        assert_eq!(messages[2].payload, Bytes::from_static(&[0xc1, 0x80]));
+    }
+
+    #[test]
+    fn rotates_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("capture");
+        let config = CaptureConfig::new(path.clone()).with_max_file_size(1).with_max_files(3);
+        let capture = SessionCapture::new(config).unwrap();
+        let peer_id = PeerId::random();
+
+        for id in 0..5u8 {
+            capture.capture(peer_id, CaptureDirection::Inbound, false, vec![id].into());
+        }
+        capture.flush();
+
+        // each message is written to its own file, only the latest three are kept
+        let ids = |path: &Path| {
+            read_capture_file(path).unwrap().iter().map(|m| m.message_id).collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&path), vec![4]);
+        assert_eq!(ids(&rotated_path(&path, 1)), vec![3]);
+        assert_eq!(ids(&rotated_path(&path, 2)), vec![2]);
+        assert!(!rotated_path(&path, 3).exists());
+    }
+
+    /// Combines a stream and a sink.
+    struct SplitStream<St, Si>(St, Si);
+
+    impl<St: Stream + Unpin, Si: Unpin> Stream for SplitStream<St, Si> {
+        type Item = St::Item;
+
+        fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+            self.0.poll_next_unpin(cx)
+        }
+    }
+
+    impl<St: Unpin, Si: Sink<bytes::Bytes> + Unpin> Sink<bytes::Bytes> for SplitStream<St, Si> {
+        type Error = Si::Error;
+
+        fn poll_ready(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            self.1.poll_ready_unpin(cx)
+        }
+
+        fn start_send(mut self: Pin<&mut Self>, item: bytes::Bytes) -> Result<(), Self::Error> {
+            self.1.start_send_unpin(item)
+        }
+
+        fn poll_flush(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            self.1.poll_flush_unpin(cx)
+        }
+
+        fn poll_close(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            self.1.poll_close_unpin(cx)
+        }
+    }
+}
@@ -1,5 +1,6 @@
 //! Connection types for a session
 
+use crate::session::{bandwidth::MeteredStream, capture::CapturedStream};
 use futures::{Sink, Stream};
 use reth_ecies::stream::ECIESStream;
 use reth_eth_wire::{
@@ -7,7 +8,8 @@ use reth_eth_wire::{
     errors::EthStreamError,
     message::EthBroadcastMessage,
     multiplex::{ProtocolProxy, RlpxSatelliteStream},
-    EthMessage, EthNetworkPrimitives, EthStream, EthVersion, NetworkPrimitives, P2PStream,
+    BlockRangeUpdate, EthMessage, EthNetworkPrimitives, EthStream, EthVersion, NetworkPrimitives,
+    P2PStream,
 };
 use std::{
     pin::Pin,
@@ -16,11 +18,14 @@ use std::{
 use tokio::net::TcpStream;
 
 /// The type of the underlying peer network connection.
-pub type EthPeerConnection<N> = EthStream<P2PStream<ECIESStream<TcpStream>>, N>;
+pub type EthPeerConnection<N> =
+    EthStream<P2PStream<CapturedStream<ECIESStream<MeteredStream<TcpStream>>>>, N>;
 
 /// Various connection types that at least support the ETH protocol.
-pub type EthSatelliteConnection<N = EthNetworkPrimitives> =
-    RlpxSatelliteStream<ECIESStream<TcpStream>, EthStream<ProtocolProxy, N>>;
+pub type EthSatelliteConnection<N = EthNetworkPrimitives> = RlpxSatelliteStream<
+    CapturedStream<ECIESStream<MeteredStream<TcpStream>>>,
+    EthStream<ProtocolProxy, N>,
+>;
 
 /// Connection types that support the ETH protocol.
 ///
@@ -47,9 +52,20 @@ impl<N: NetworkPrimitives> EthRlpxConnection<N> {
         }
     }
 
+    /// Returns the range of blocks the peer announced in its `eth/69` status, if any.
+    #[inline]
+    pub(crate) const fn peer_block_range(&self) -> Option<BlockRangeUpdate> {
+        match self {
+            Self::EthOnly(conn) => conn.peer_block_range(),
+            Self::Satellite(conn) => conn.primary().peer_block_range(),
+        }
+    }
+
     /// Consumes this type and returns the wrapped [`P2PStream`].
     #[inline]
-    pub(crate) fn into_inner(self) -> P2PStream<ECIESStream<TcpStream>> {
+    pub(crate) fn into_inner(
+        self,
+    ) -> P2PStream<CapturedStream<ECIESStream<MeteredStream<TcpStream>>>> {
         match self {
             Self::EthOnly(conn) => conn.into_inner(),
             Self::Satellite(conn) => conn.into_inner(),
@@ -58,7 +74,9 @@ impl<N: NetworkPrimitives> EthRlpxConnection<N> {
 
     /// Returns mutable access to the underlying stream.
     #[inline]
-    pub(crate) fn inner_mut(&mut self) -> &mut P2PStream<ECIESStream<TcpStream>> {
+    pub(crate) fn inner_mut(
+        &mut self,
+    ) -> &mut P2PStream<CapturedStream<ECIESStream<MeteredStream<TcpStream>>>> {
         match self {
             Self::EthOnly(conn) => conn.inner_mut(),
             Self::Satellite(conn) => conn.inner_mut(),
@@ -67,7 +85,9 @@ impl<N: NetworkPrimitives> EthRlpxConnection<N> {
 
     /// Returns  access to the underlying stream.
     #[inline]
-    pub(crate) const fn inner(&self) -> &P2PStream<ECIESStream<TcpStream>> {
+    pub(crate) const fn inner(
+        &self,
+    ) -> &P2PStream<CapturedStream<ECIESStream<MeteredStream<TcpStream>>>> {
         match self {
             Self::EthOnly(conn) => conn.inner(),
             Self::Satellite(conn) => conn.inner(),
@@ -2,7 +2,7 @@
 
 use crate::{
     message::PeerMessage,
-    session::{conn::EthRlpxConnection, Direction, SessionId},
+    session::{bandwidth::SessionBandwidth, conn::EthRlpxConnection, Direction, SessionId},
     PendingSessionHandshakeError,
 };
 use reth_ecies::ECIESError;
@@ -29,6 +29,8 @@ pub struct PendingSessionHandle {
     pub(crate) disconnect_tx: Option<oneshot::Sender<()>>,
     /// The direction of the session
     pub(crate) direction: Direction,
+    /// The bytes transferred by the session.
+    pub(crate) bandwidth: Arc<SessionBandwidth>,
 }
 
 // === impl PendingSessionHandle ===
@@ -75,6 +77,8 @@ pub struct ActiveSessionHandle<N: NetworkPrimitives> {
     pub(crate) local_addr: Option<SocketAddr>,
     /// The Status message the peer sent for the `eth` handshake
     pub(crate) status: Arc<Status>,
+    /// The bytes transferred by the session, including the handshakes.
+    pub(crate) bandwidth: Arc<SessionBandwidth>,
 }
 
 // === impl ActiveSessionHandle ===
@@ -1,16 +1,23 @@
 //! Support for handling peer sessions.
 
 mod active;
+mod bandwidth;
+mod capture;
 mod conn;
 mod counter;
 mod handle;
+mod proxy;
 
 use active::QueuedOutgoingMessages;
+pub use bandwidth::PeerBandwidth;
+pub(crate) use capture::SessionCapture;
+pub use capture::{read_capture_file, CaptureDirection, CapturedMessage};
 pub use conn::EthRlpxConnection;
 pub use handle::{
     ActiveSessionHandle, ActiveSessionMessage, PendingSessionEvent, PendingSessionHandle,
     SessionCommand,
 };
+use proxy::SessionProxy;
 
 pub use reth_network_api::{Direction, PeerInfo};
 
@@ -29,13 +36,15 @@ use crate::{
     protocol::{IntoRlpxSubProtocol, RlpxSubProtocolHandlers, RlpxSubProtocols},
     session::active::ActiveSession,
 };
+use bandwidth::{BandwidthMeter, MeteredStream, SessionMeter};
+use capture::CapturedStream;
 use counter::SessionCounter;
 use futures::{future::Either, io, FutureExt, StreamExt};
 use reth_ecies::{stream::ECIESStream, ECIESError};
 use reth_eth_wire::{
     capability::CapabilityMessage, errors::EthStreamError, multiplex::RlpxProtocolMultiplexer,
-    Capabilities, DisconnectReason, EthVersion, HelloMessageWithProtocols, NetworkPrimitives,
-    Status, UnauthedEthStream, UnauthedP2PStream,
+    BlockRangeUpdate, Capabilities, DisconnectReason, EthVersion, HelloMessageWithProtocols,
+    NetworkPrimitives, Status, UnauthedEthStream, UnauthedP2PStream,
 };
 use reth_ethereum_forks::{ForkFilter, ForkId, ForkTransition, Head};
 use reth_metrics::common::mpsc::MeteredPollSender;
@@ -78,6 +87,8 @@ pub struct SessionManager<N: NetworkPrimitives> {
     secret_key: SecretKey,
     /// The `Status` message to send to peers.
     status: Status,
+    /// The range of blocks the node can serve, sent to `eth/69` peers in the `Status` message.
+    block_range: BlockRangeUpdate,
     /// The `HelloMessage` message to send to peers.
     hello_message: HelloMessageWithProtocols,
     /// The [`ForkFilter`] used to validate the peer's `Status` message.
@@ -111,6 +122,12 @@ pub struct SessionManager<N: NetworkPrimitives> {
     extra_protocols: RlpxSubProtocols,
     /// Tracks the ongoing graceful disconnections attempts for incoming connections.
     disconnections_counter: DisconnectionsCounter,
+    /// Meters the bandwidth of all sessions.
+    bandwidth: BandwidthMeter,
+    /// Captures the messages of all sessions, if enabled.
+    capture: Option<SessionCapture>,
+    /// The proxy outgoing connections are established through, if configured.
+    proxy: Option<SessionProxy>,
     /// Metrics for the session manager.
     metrics: SessionManagerMetrics,
 }
@@ -132,6 +149,11 @@ impl<N: NetworkPrimitives> SessionManager<N> {
         let (pending_sessions_tx, pending_sessions_rx) = mpsc::channel(config.session_event_buffer);
         let (active_session_tx, active_session_rx) = mpsc::channel(config.session_event_buffer);
         let active_session_tx = PollSender::new(active_session_tx);
+        let block_range = BlockRangeUpdate {
+            earliest: 0,
+            latest: fork_filter.head().number,
+            latest_hash: status.blockhash,
+        };
 
         Self {
             next_id: 0,
@@ -141,6 +163,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
             pending_session_timeout: config.pending_session_timeout,
             secret_key,
             status,
+            block_range,
             hello_message,
             fork_filter,
             session_command_buffer: config.session_command_buffer,
@@ -153,6 +176,9 @@ impl<N: NetworkPrimitives> SessionManager<N> {
             active_session_rx: ReceiverStream::new(active_session_rx),
             extra_protocols,
             disconnections_counter: Default::default(),
+            bandwidth: BandwidthMeter::new(config.bandwidth),
+            capture: None,
+            proxy: config.proxy.map(SessionProxy::new),
             metrics: Default::default(),
         }
     }
@@ -190,6 +216,23 @@ impl<N: NetworkPrimitives> SessionManager<N> {
         self.hello_message.clone()
     }
 
+    /// Returns the number of bytes transferred by each active session.
+    pub fn peer_bandwidth(&self) -> Vec<PeerBandwidth> {
+        self.active_sessions
+            .iter()
+            .map(|(peer_id, session)| PeerBandwidth {
+                peer_id: *peer_id,
+                ingress_bytes: session.bandwidth.ingress_bytes(),
+                egress_bytes: session.bandwidth.egress_bytes(),
+            })
+            .collect()
+    }
+
+    /// Captures the messages of all new sessions.
+    pub(crate) fn set_capture(&mut self, capture: SessionCapture) {
+        self.capture = Some(capture);
+    }
+
     /// Adds an additional protocol handler to the `RLPx` sub-protocol list.
     pub(crate) fn add_rlpx_sub_protocol(&mut self, protocol: impl IntoRlpxSubProtocol) {
         self.extra_protocols.push(protocol)
@@ -217,11 +260,21 @@ impl<N: NetworkPrimitives> SessionManager<N> {
     pub(crate) fn on_status_update(&mut self, head: Head) -> Option<ForkTransition> {
         self.status.blockhash = head.hash;
         self.status.total_difficulty = head.total_difficulty;
+        self.block_range.latest = head.number;
+        self.block_range.latest_hash = head.hash;
         let transition = self.fork_filter.set_head(head);
         self.status.forkid = self.fork_filter.current();
         transition
     }
 
+    /// Invoked when the range of blocks the node can serve changed.
+    ///
+    /// New `eth/69` sessions announce this range in their `Status` message, established sessions
+    /// are notified with a separate `BlockRangeUpdate` message.
+    pub(crate) fn on_block_range_update(&mut self, range: BlockRangeUpdate) {
+        self.block_range = range;
+    }
+
     /// An incoming TCP connection was received. This starts the authentication process to turn this
     /// stream into an active peer session.
     ///
@@ -247,8 +300,12 @@ impl<N: NetworkPrimitives> SessionManager<N> {
         let secret_key = self.secret_key;
         let hello_message = self.hello_message.clone();
         let status = self.status;
+        let block_range = self.block_range;
         let fork_filter = self.fork_filter.clone();
         let extra_handlers = self.extra_protocols.on_incoming(remote_addr);
+        let meter = self.bandwidth.session();
+        let bandwidth = meter.bandwidth();
+        let capture = self.capture.clone();
         self.spawn(pending_session_with_timeout(
             self.pending_session_timeout,
             session_id,
@@ -259,11 +316,14 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                 disconnect_rx,
                 session_id,
                 stream,
+                meter,
+                capture,
                 pending_events,
                 remote_addr,
                 secret_key,
                 hello_message,
                 status,
+                block_range,
                 fork_filter,
                 extra_handlers,
             ),
@@ -272,6 +332,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
         let handle = PendingSessionHandle {
             disconnect_tx: Some(disconnect_tx),
             direction: Direction::Incoming,
+            bandwidth,
         };
         self.pending_sessions.insert(session_id, handle);
         self.counter.inc_pending_inbound();
@@ -289,7 +350,12 @@ impl<N: NetworkPrimitives> SessionManager<N> {
             let hello_message = self.hello_message.clone();
             let fork_filter = self.fork_filter.clone();
             let status = self.status;
+            let block_range = self.block_range;
             let extra_handlers = self.extra_protocols.on_outgoing(remote_addr, remote_peer_id);
+            let meter = self.bandwidth.session();
+            let bandwidth = meter.bandwidth();
+            let capture = self.capture.clone();
+            let proxy = self.proxy.clone();
             self.spawn(pending_session_with_timeout(
                 self.pending_session_timeout,
                 session_id,
@@ -302,9 +368,13 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     session_id,
                     remote_addr,
                     remote_peer_id,
+                    meter,
+                    capture,
+                    proxy,
                     secret_key,
                     hello_message,
                     status,
+                    block_range,
                     fork_filter,
                     extra_handlers,
                 ),
@@ -313,6 +383,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
             let handle = PendingSessionHandle {
                 disconnect_tx: Some(disconnect_tx),
                 direction: Direction::Outgoing(remote_peer_id),
+                bandwidth,
             };
             self.pending_sessions.insert(session_id, handle);
             self.counter.inc_pending_outbound();
@@ -476,7 +547,10 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                 client_id,
             } => {
                 // move from pending to established.
-                self.remove_pending_session(&session_id);
+                let bandwidth = self
+                    .remove_pending_session(&session_id)
+                    .map(|session| session.bandwidth)
+                    .unwrap_or_default();
 
                 // If there's already a session to the peer then we disconnect right away
                 if self.active_sessions.contains_key(&peer_id) {
@@ -514,6 +588,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
 
                 // negotiated version
                 let version = conn.version();
+                let block_range = conn.peer_block_range();
 
                 let session = ActiveSession {
                     next_id: 0,
@@ -554,6 +629,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     client_version: Arc::clone(&client_version),
                     remote_addr,
                     local_addr,
+                    bandwidth,
                 };
 
                 self.active_sessions.insert(peer_id, handle);
@@ -570,6 +646,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     version,
                     capabilities,
                     status,
+                    block_range,
                     messages,
                     direction,
                     timeout,
@@ -679,6 +756,8 @@ pub enum SessionEvent<N: NetworkPrimitives> {
         version: EthVersion,
         /// The Status message the peer sent during the `eth` handshake
         status: Arc<Status>,
+        /// The range of blocks the peer announced in its `eth/69` status
+        block_range: Option<BlockRangeUpdate>,
         /// The channel for sending messages to the peer with the session
         messages: PeerRequestSender<PeerRequest<N>>,
         /// The direction of the session, either `Inbound` or `Outgoing`
@@ -826,11 +905,14 @@ pub(crate) async fn start_pending_incoming_session<N: NetworkPrimitives>(
     disconnect_rx: oneshot::Receiver<()>,
     session_id: SessionId,
     stream: TcpStream,
+    meter: SessionMeter,
+    capture: Option<SessionCapture>,
     events: mpsc::Sender<PendingSessionEvent<N>>,
     remote_addr: SocketAddr,
     secret_key: SecretKey,
     hello: HelloMessageWithProtocols,
     status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
 ) {
@@ -838,12 +920,15 @@ pub(crate) async fn start_pending_incoming_session<N: NetworkPrimitives>(
         disconnect_rx,
         events,
         stream,
+        meter,
+        capture,
         session_id,
         remote_addr,
         secret_key,
         Direction::Incoming,
         hello,
         status,
+        block_range,
         fork_filter,
         extra_handlers,
     )
@@ -859,13 +944,21 @@ async fn start_pending_outbound_session<N: NetworkPrimitives>(
     session_id: SessionId,
     remote_addr: SocketAddr,
     remote_peer_id: PeerId,
+    meter: SessionMeter,
+    capture: Option<SessionCapture>,
+    proxy: Option<SessionProxy>,
     secret_key: SecretKey,
     hello: HelloMessageWithProtocols,
     status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
 ) {
-    let stream = match TcpStream::connect(remote_addr).await {
+    let stream = match proxy {
+        Some(proxy) => proxy.connect(remote_addr).await,
+        None => TcpStream::connect(remote_addr).await,
+    };
+    let stream = match stream {
         Ok(stream) => {
             if let Err(err) = stream.set_nodelay(true) {
                 tracing::warn!(target: "net::session", "set nodelay failed: {:?}", err);
@@ -888,12 +981,15 @@ async fn start_pending_outbound_session<N: NetworkPrimitives>(
         disconnect_rx,
         events,
         stream,
+        meter,
+        capture,
         session_id,
         remote_addr,
         secret_key,
         Direction::Outgoing(remote_peer_id),
         hello,
         status,
+        block_range,
         fork_filter,
         extra_handlers,
     )
@@ -901,22 +997,28 @@ async fn start_pending_outbound_session<N: NetworkPrimitives>(
 }
 
 /// Authenticates a session
+///
+/// All bytes of the connection are metered by the given [`SessionMeter`], and all messages are
+/// captured by the given [`SessionCapture`], if any.
 #[allow(clippy::too_many_arguments)]
 async fn authenticate<N: NetworkPrimitives>(
     disconnect_rx: oneshot::Receiver<()>,
     events: mpsc::Sender<PendingSessionEvent<N>>,
     stream: TcpStream,
+    meter: SessionMeter,
+    capture: Option<SessionCapture>,
     session_id: SessionId,
     remote_addr: SocketAddr,
     secret_key: SecretKey,
     direction: Direction,
     hello: HelloMessageWithProtocols,
     status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
 ) {
     let local_addr = stream.local_addr().ok();
-    let stream = match get_ecies_stream(stream, secret_key, direction).await {
+    let stream = match get_ecies_stream(meter.wrap(stream), secret_key, direction).await {
         Ok(stream) => stream,
         Err(error) => {
             let _ = events
@@ -931,7 +1033,8 @@ async fn authenticate<N: NetworkPrimitives>(
         }
     };
 
-    let unauthed = UnauthedP2PStream::new(stream);
+    let remote_id = stream.remote_id();
+    let unauthed = UnauthedP2PStream::new(SessionCapture::wrap(capture, remote_id, stream));
 
     let auth = authenticate_stream(
         unauthed,
@@ -941,6 +1044,7 @@ async fn authenticate<N: NetworkPrimitives>(
         direction,
         hello,
         status,
+        block_range,
         fork_filter,
         extra_handlers,
     )
@@ -986,13 +1090,14 @@ async fn get_ecies_stream<Io: AsyncRead + AsyncWrite + Unpin>(
 /// also negotiate the additional protocols.
 #[allow(clippy::too_many_arguments)]
 async fn authenticate_stream<N: NetworkPrimitives>(
-    stream: UnauthedP2PStream<ECIESStream<TcpStream>>,
+    stream: UnauthedP2PStream<CapturedStream<ECIESStream<MeteredStream<TcpStream>>>>,
     session_id: SessionId,
     remote_addr: SocketAddr,
     local_addr: Option<SocketAddr>,
     direction: Direction,
     mut hello: HelloMessageWithProtocols,
     mut status: Status,
+    block_range: BlockRangeUpdate,
     fork_filter: ForkFilter,
     mut extra_handlers: RlpxSubProtocolHandlers,
 ) -> PendingSessionEvent<N> {
@@ -1025,12 +1130,12 @@ async fn authenticate_stream<N: NetworkPrimitives>(
         }
     };
 
+    // Before trying status handshake, set up the version to negotiated shared version
+    status.set_eth_version(eth_version);
+
     let (conn, their_status) = if p2p_stream.shared_capabilities().len() == 1 {
         // if the hello handshake was successful we can try status handshake
-        //
-        // Before trying status handshake, set up the version to negotiated shared version
-        status.set_eth_version(eth_version);
-        let eth_unauthed = UnauthedEthStream::new(p2p_stream);
+        let eth_unauthed = UnauthedEthStream::new(p2p_stream).with_block_range(block_range);
         let (eth_stream, their_status) = match eth_unauthed.handshake(status, fork_filter).await {
             Ok(stream_res) => stream_res,
             Err(err) => {
@@ -1058,18 +1163,20 @@ async fn authenticate_stream<N: NetworkPrimitives>(
                 .ok();
         }
 
-        let (multiplex_stream, their_status) =
-            match multiplex_stream.into_eth_satellite_stream(status, fork_filter).await {
-                Ok((multiplex_stream, their_status)) => (multiplex_stream, their_status),
-                Err(err) => {
-                    return PendingSessionEvent::Disconnected {
-                        remote_addr,
-                        session_id,
-                        direction,
-                        error: Some(PendingSessionHandshakeError::Eth(err)),
-                    }
+        let (multiplex_stream, their_status) = match multiplex_stream
+            .into_eth_satellite_stream(status, Some(block_range), fork_filter)
+            .await
+        {
+            Ok((multiplex_stream, their_status)) => (multiplex_stream, their_status),
+            Err(err) => {
+                return PendingSessionEvent::Disconnected {
+                    remote_addr,
+                    session_id,
+                    direction,
+                    error: Some(PendingSessionHandshakeError::Eth(err)),
                 }
-            };
+            }
+        };
 
         (multiplex_stream.into(), their_status)
     };
@@ -0,0 +1,211 @@
+//! Outgoing connections through a SOCKS5 proxy, see [RFC 1928](https://www.rfc-editor.org/rfc/rfc1928).
+
+use crate::metrics::ProxyMetrics;
+use reth_network_types::ProxyConfig;
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+use tracing::{debug, trace};
+
+const SOCKS_VERSION: u8 = 0x05;
+const AUTH_VERSION: u8 = 0x01;
+const METHOD_NO_AUTH: u8 = 0x00;
+const METHOD_USERNAME_PASSWORD: u8 = 0x02;
+const CMD_CONNECT: u8 = 0x01;
+const ATYP_IPV4: u8 = 0x01;
+const ATYP_DOMAIN: u8 = 0x03;
+const ATYP_IPV6: u8 = 0x04;
+const REPLY_SUCCEEDED: u8 = 0x00;
+
+/// Establishes the outgoing connections of sessions through a SOCKS5 proxy.
+#[derive(Debug, Clone)]
+pub(crate) struct SessionProxy {
+    config: Arc<ProxyConfig>,
+    metrics: ProxyMetrics,
+}
+
+impl SessionProxy {
+    /// Creates a new proxy dialer with the given config.
+    pub(crate) fn new(config: ProxyConfig) -> Self {
+        Self { config: Arc::new(config), metrics: Default::default() }
+    }
+
+    /// Connects to the given address through the proxy.
+    ///
+    /// If this fails and the direct fallback is enabled, the address is dialed directly instead.
+    pub(crate) async fn connect(&self, remote_addr: SocketAddr) -> io::Result<TcpStream> {
+        match socks5_connect(&self.config, remote_addr).await {
+            Ok(stream) => {
+                self.metrics.connections.increment(1);
+                Ok(stream)
+            }
+            Err(err) => {
+                self.metrics.failures.increment(1);
+                debug!(target: "net::session", %err, proxy=%self.config, %remote_addr, "failed to connect through proxy");
+                if !self.config.direct_fallback {
+                    return Err(err)
+                }
+                self.metrics.direct_fallbacks.increment(1);
+                TcpStream::connect(remote_addr).await
+            }
+        }
+    }
+}
+
+/// Connects to the given address through the SOCKS5 proxy with the given config.
+async fn socks5_connect(proxy: &ProxyConfig, remote_addr: SocketAddr) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect((proxy.host.as_str(), proxy.port)).await?;
+
+    // negotiate the authentication method
+    let method =
+        if proxy.credentials.is_some() { METHOD_USERNAME_PASSWORD } else { METHOD_NO_AUTH };
+    stream.write_all(&[SOCKS_VERSION, 1, method]).await?;
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != SOCKS_VERSION {
+        return Err(invalid_data("unexpected SOCKS version"))
+    }
+    // the proxy replies with `0xff` if none of the offered methods is acceptable
+    if reply[1] != method {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "proxy rejected authentication method",
+        ))
+    }
+
+    if let Some((username, password)) = &proxy.credentials {
+        let (username, password) = (username.as_bytes(), password.as_bytes());
+        let (Ok(username_len), Ok(password_len)) =
+            (u8::try_from(username.len()), u8::try_from(password.len()))
+        else {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "proxy credentials too long"))
+        };
+        let mut request = Vec::with_capacity(3 + username.len() + password.len());
+        request.extend_from_slice(&[AUTH_VERSION, username_len]);
+        request.extend_from_slice(username);
+        request.push(password_len);
+        request.extend_from_slice(password);
+        stream.write_all(&request).await?;
+
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != REPLY_SUCCEEDED {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                "proxy authentication failed",
+            ))
+        }
+    }
+
+    // request the connection to the remote
+    let mut request = vec![SOCKS_VERSION, CMD_CONNECT, 0x00];
+    match remote_addr.ip() {
+        IpAddr::V4(ip) => {
+            request.push(ATYP_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        IpAddr::V6(ip) => {
+            request.push(ATYP_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+    }
+    request.extend_from_slice(&remote_addr.port().to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != SOCKS_VERSION {
+        return Err(invalid_data("unexpected SOCKS version"))
+    }
+    if reply[1] != REPLY_SUCCEEDED {
+        return Err(io::Error::new(
+            io::ErrorKind::ConnectionRefused,
+            format!("proxy failed to connect, reply code {}", reply[1]),
+        ))
+    }
+
+    // skip the bound address, which is of no use to us
+    let addr_len = match reply[3] {
+        ATYP_IPV4 => 4,
+        ATYP_IPV6 => 16,
+        ATYP_DOMAIN => stream.read_u8().await? as usize,
+        _ => return Err(invalid_data("unexpected address type")),
+    };
+    let mut bound = vec![0u8; addr_len + 2];
+    stream.read_exact(&mut bound).await?;
+
+    trace!(target: "net::session", proxy=%proxy, %remote_addr, "connected through proxy");
+    Ok(stream)
+}
+
+fn invalid_data(msg: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Serves a single SOCKS5 connect request that requires the given credentials and returns the
+    /// requested address.
+    async fn serve_socks5(listener: TcpListener, credentials: (&str, &str)) -> SocketAddr {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let mut greeting = [0u8; 3];
+        stream.read_exact(&mut greeting).await.unwrap();
+        assert_eq!(greeting, [SOCKS_VERSION, 1, METHOD_USERNAME_PASSWORD]);
+        stream.write_all(&[SOCKS_VERSION, METHOD_USERNAME_PASSWORD]).await.unwrap();
+
+        let mut auth = vec![0u8; 3 + credentials.0.len() + credentials.1.len()];
+        stream.read_exact(&mut auth).await.unwrap();
+        assert_eq!(&auth[2..2 + credentials.0.len()], credentials.0.as_bytes());
+        stream.write_all(&[AUTH_VERSION, REPLY_SUCCEEDED]).await.unwrap();
+
+        let mut request = [0u8; 10];
+        stream.read_exact(&mut request).await.unwrap();
+        assert_eq!(request[..4], [SOCKS_VERSION, CMD_CONNECT, 0x00, ATYP_IPV4]);
+        let ip = IpAddr::from([request[4], request[5], request[6], request[7]]);
+        let port = u16::from_be_bytes([request[8], request[9]]);
+        stream
+            .write_all(&[SOCKS_VERSION, REPLY_SUCCEEDED, 0x00, ATYP_IPV4, 0, 0, 0, 0, 0, 0])
+            .await
+            .unwrap();
+
+        SocketAddr::new(ip, port)
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn connect_through_proxy() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy_addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(serve_socks5(listener, ("user", "secret")));
+
+        let config = ProxyConfig::new(proxy_addr.ip().to_string(), proxy_addr.port())
+            .with_credentials("user".into(), "secret".into());
+        let remote_addr: SocketAddr = "10.0.0.1:30303".parse().unwrap();
+        SessionProxy::new(config).connect(remote_addr).await.unwrap();
+
+        assert_eq!(server.await.unwrap(), remote_addr);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn direct_fallback() {
+        // nothing is listening on the proxy port
+        let proxy_addr = TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+        let remote = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let remote_addr = remote.local_addr().unwrap();
+
+        let config = ProxyConfig::new(proxy_addr.ip().to_string(), proxy_addr.port());
+        assert!(SessionProxy::new(config.clone()).connect(remote_addr).await.is_err());
+
+        let proxy = SessionProxy::new(config.with_direct_fallback(true));
+        let stream = proxy.connect(remote_addr).await.unwrap();
+        assert_eq!(stream.peer_addr().unwrap(), remote_addr);
+    }
+}
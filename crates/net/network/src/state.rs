@@ -12,13 +12,13 @@ use alloy_consensus::BlockHeader;
 use alloy_primitives::B256;
 use rand::seq::SliceRandom;
 use reth_eth_wire::{
-    BlockHashNumber, Capabilities, DisconnectReason, EthNetworkPrimitives, NetworkPrimitives,
-    NewBlockHashes, Status,
+    BlockHashNumber, BlockRangeUpdate, Capabilities, DisconnectReason, EthNetworkPrimitives,
+    NetworkPrimitives, NewBlockHashes, Status,
 };
 use reth_ethereum_forks::ForkId;
 use reth_network_api::{DiscoveredEvent, DiscoveryEvent, PeerRequest, PeerRequestSender};
 use reth_network_peers::PeerId;
-use reth_network_types::{PeerAddr, PeerKind};
+use reth_network_types::{PeerAddr, PeerKind, Reputation};
 use reth_primitives_traits::Block;
 use std::{
     collections::{HashMap, VecDeque},
@@ -156,6 +156,9 @@ impl<N: NetworkPrimitives> NetworkState<N> {
         let block_number =
             self.client.block_number(status.blockhash).ok().flatten().unwrap_or_default();
         self.state_fetcher.new_active_peer(peer, status.blockhash, block_number, timeout);
+        if let Some(reputation) = self.peers_manager.get_reputation(&peer) {
+            self.state_fetcher.update_peer_reputation(&peer, reputation);
+        }
 
         self.active_peers.insert(
             peer,
@@ -278,6 +281,13 @@ impl<N: NetworkPrimitives> NetworkState<N> {
         }
     }
 
+    /// Invoked for a `BlockRangeUpdate` message, announcing the range of blocks the peer can
+    /// serve.
+    pub(crate) fn on_block_range_update(&mut self, peer_id: PeerId, range: BlockRangeUpdate) {
+        self.update_peer_block(&peer_id, range.latest_hash, range.latest);
+        self.state_fetcher.update_peer_earliest_block(&peer_id, range.earliest);
+    }
+
     /// Bans the [`IpAddr`] in the discovery service.
     pub(crate) fn ban_ip_discovery(&self, ip: IpAddr) {
         trace!(target: "net", ?ip, "Banning discovery");
@@ -415,6 +425,11 @@ impl<N: NetworkPrimitives> NetworkState<N> {
         if let Some(outcome) = outcome {
             self.on_block_response_outcome(outcome);
         }
+
+        // the response or timeout may have changed the peer's reputation
+        if let Some(reputation) = self.peers_manager.get_reputation(&peer) {
+            self.state_fetcher.update_peer_reputation(&peer, reputation);
+        }
     }
 
     /// Advances the state
@@ -488,6 +503,11 @@ impl<N: NetworkPrimitives> NetworkState<N> {
             while let Poll::Ready(action) = self.peers_manager.poll(cx) {
                 self.on_peer_action(action);
             }
+            while let Some((peer_id, reputation)) = self.peers_manager.pop_reputation_change() {
+                self.state_fetcher.update_peer_reputation(&peer_id, reputation);
+                self.queued_messages
+                    .push_back(StateAction::ReputationChanged { peer_id, reputation });
+            }
 
             // We need to poll again tn case we have received any responses because they may have
             // triggered follow-up requests.
@@ -552,6 +572,12 @@ pub(crate) enum StateAction<N: NetworkPrimitives> {
     PeerAdded(PeerId),
     /// A peer was dropped
     PeerRemoved(PeerId),
+    /// The reputation of a peer changed
+    ReputationChanged {
+        peer_id: PeerId,
+        /// The new reputation of the peer.
+        reputation: Reputation,
+    },
 }
 
 #[cfg(test)]
@@ -13,6 +13,7 @@ use reth_eth_wire::{
 };
 use reth_network_api::{PeerRequest, PeerRequestSender};
 use reth_network_peers::PeerId;
+use reth_network_types::Reputation;
 use std::{
     io,
     net::SocketAddr,
@@ -119,6 +120,7 @@ impl<N: NetworkPrimitives> Swarm<N> {
                 capabilities,
                 version,
                 status,
+                block_range,
                 messages,
                 direction,
                 timeout,
@@ -130,6 +132,9 @@ impl<N: NetworkPrimitives> Swarm<N> {
                     messages.clone(),
                     timeout,
                 );
+                if let Some(range) = block_range {
+                    self.state.on_block_range_update(peer_id, range);
+                }
                 Some(SwarmEvent::SessionEstablished {
                     peer_id,
                     remote_addr,
@@ -246,6 +251,9 @@ impl<N: NetworkPrimitives> Swarm<N> {
             }
             StateAction::PeerAdded(peer_id) => return Some(SwarmEvent::PeerAdded(peer_id)),
             StateAction::PeerRemoved(peer_id) => return Some(SwarmEvent::PeerRemoved(peer_id)),
+            StateAction::ReputationChanged { peer_id, reputation } => {
+                return Some(SwarmEvent::ReputationChanged { peer_id, reputation })
+            }
             StateAction::DiscoveredNode { peer_id, addr, fork_id } => {
                 // Don't try to connect to peer if node is shutting down
                 if self.is_shutting_down() {
@@ -406,6 +414,8 @@ pub(crate) enum SwarmEvent<N: NetworkPrimitives = EthNetworkPrimitives> {
     PeerAdded(PeerId),
     /// Admin rpc: peer removed
     PeerRemoved(PeerId),
+    /// The reputation of a peer changed
+    ReputationChanged { peer_id: PeerId, reputation: Reputation },
     /// Closed an incoming pending session during authentication.
     IncomingPendingSessionClosed {
         remote_addr: SocketAddr,
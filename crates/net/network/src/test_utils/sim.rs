@@ -0,0 +1,448 @@
+//! A deterministic simulation of a network of multiple nodes in a single process.
+//!
+//! All connections between the simulated nodes are routed through an in-process router that acts
+//! as the SOCKS5 proxy of every node, see [`ProxyConfig`]. This allows tests to control the
+//! latency of the links between nodes and to partition the network, without any external
+//! infrastructure.
+
+use crate::{
+    error::NetworkError,
+    test_utils::{NetworkEventStream, Peer, PeerConfig, PeerHandle, Testnet, TestnetHandle},
+};
+use alloy_consensus::Header;
+use alloy_primitives::{Address, U256};
+use parking_lot::Mutex;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use reth_chainspec::{ChainSpecProvider, Hardforks};
+use reth_ethereum_forks::Head;
+use reth_network_api::Peers;
+use reth_network_peers::PeerId;
+use reth_network_types::ProxyConfig;
+use reth_primitives::{BlockBody, SealedBlock, SealedHeader};
+use reth_storage_api::{BlockReader, HeaderProvider};
+use reth_transaction_pool::test_utils::TestPool;
+use secp256k1::SecretKey;
+use std::{collections::HashMap, fmt, io, net::Ipv4Addr, sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+    task::AbortHandle,
+    time::Instant,
+};
+use tracing::trace;
+
+/// The interval between the timestamps of blocks produced by [`SimulationHandle::produce_blocks`].
+const BLOCK_TIME: u64 = 12;
+
+/// A callback that is invoked for every block produced by a node of the [`Simulation`].
+type BlockSink = Arc<dyn Fn(usize, &SealedBlock) + Send + Sync>;
+
+/// A network of nodes whose connections are routed through an in-process router.
+///
+/// The secret keys of the nodes and the produced blocks are derived from the seed, so a
+/// simulation with the same seed and the same script results in the same peer ids and chains.
+pub struct Simulation<C> {
+    rng: StdRng,
+    testnet: Testnet<C, TestPool>,
+    router: SimRouter,
+    on_block: BlockSink,
+}
+
+// === impl Simulation ===
+
+impl<C> Simulation<C>
+where
+    C: BlockReader + HeaderProvider + Clone + ChainSpecProvider<ChainSpec: Hardforks> + 'static,
+{
+    /// Creates an empty simulation with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            testnet: Testnet::default(),
+            router: SimRouter::default(),
+            on_block: Arc::new(|_, _| {}),
+        }
+    }
+
+    /// Same as [`Self::try_add_node`] but panics on error.
+    pub async fn add_node(&mut self, client: C) -> usize {
+        self.try_add_node(client).await.unwrap()
+    }
+
+    /// Adds a node with the given client and returns its index.
+    pub async fn try_add_node(&mut self, client: C) -> Result<usize, NetworkError> {
+        let node = self.testnet.peers().len();
+        let proxy = self.router.add_ingress(node).await?;
+        let secret_key = SecretKey::new(&mut self.rng);
+        let config = PeerConfig::with_secret_key(client, secret_key).with_proxy(proxy);
+        self.testnet.add_peer_with_config(config).await?;
+        self.router.register(node, self.testnet.peers()[node].local_addr().port());
+        Ok(node)
+    }
+
+    /// Sets the callback that is invoked with the index of the node and the block for every block
+    /// produced by [`SimulationHandle::produce_blocks`].
+    ///
+    /// This is typically used to insert the block into the client of the node.
+    pub fn on_block<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize, &SealedBlock) + Send + Sync + 'static,
+    {
+        self.on_block = Arc::new(f);
+        self
+    }
+
+    /// Sets the latency of all links between nodes that have no specific latency, see
+    /// [`SimulationHandle::set_latency`].
+    pub fn with_default_latency(self, latency: Duration) -> Self {
+        self.router.state.lock().default_latency = latency;
+        self
+    }
+
+    /// Return a mutable slice of all nodes.
+    pub fn nodes_mut(&mut self) -> &mut [Peer<C>] {
+        self.testnet.peers_mut()
+    }
+
+    /// Apply a closure on each node
+    pub fn for_each_node_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Peer<C>),
+    {
+        self.testnet.for_each_mut(f)
+    }
+}
+
+impl<C> Simulation<C>
+where
+    C: BlockReader<
+            Block = reth_primitives::Block,
+            Receipt = reth_primitives::Receipt,
+            Header = reth_primitives::Header,
+        > + HeaderProvider
+        + Clone
+        + Unpin
+        + 'static,
+{
+    /// Spawns all nodes of the simulation to a separate task.
+    pub fn spawn(self) -> SimulationHandle<C> {
+        let Self { rng, testnet, router, on_block } = self;
+        let genesis = SealedHeader::seal(Header::default());
+        let heads = vec![genesis; testnet.peers().len()];
+        SimulationHandle { rng, testnet: testnet.spawn(), router, on_block, heads }
+    }
+}
+
+impl<C> fmt::Debug for Simulation<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Simulation").field("router", &self.router).finish_non_exhaustive()
+    }
+}
+
+/// A handle to a spawned [`Simulation`] that controls the links between the nodes and produces
+/// blocks.
+pub struct SimulationHandle<C> {
+    rng: StdRng,
+    testnet: TestnetHandle<C, TestPool>,
+    router: SimRouter,
+    on_block: BlockSink,
+    /// The head of the chain of each node.
+    heads: Vec<SealedHeader>,
+}
+
+// === impl SimulationHandle ===
+
+impl<C> SimulationHandle<C> {
+    /// Returns the [`PeerHandle`]s of all nodes.
+    pub fn nodes(&self) -> &[PeerHandle<TestPool>] {
+        self.testnet.peers()
+    }
+
+    /// Returns the [`PeerHandle`] of the node with the given index.
+    pub fn node(&self, node: usize) -> &PeerHandle<TestPool> {
+        &self.testnet.peers()[node]
+    }
+
+    /// Returns the [`PeerId`] of the node with the given index.
+    pub fn peer_id(&self, node: usize) -> PeerId {
+        *self.node(node).peer_id()
+    }
+
+    /// Connects the node `from` to the node `to` and returns once the session is established.
+    pub async fn connect(&self, from: usize, to: usize) {
+        let mut events = NetworkEventStream::new(self.node(from).event_listener());
+        let target = self.node(to);
+        self.node(from).network().add_peer(*target.peer_id(), target.local_addr());
+        while let Some(peer_id) = events.next_session_established().await {
+            if peer_id == *target.peer_id() {
+                return
+            }
+        }
+    }
+
+    /// Connects all nodes with each other and returns once all sessions are established.
+    pub async fn connect_all(&self) {
+        self.testnet.connect_peers().await
+    }
+
+    /// Sets the latency of the link between the two nodes, in both directions.
+    ///
+    /// This applies to data sent after the call, including data of already established sessions.
+    pub fn set_latency(&self, a: usize, b: usize, latency: Duration) {
+        self.router.state.lock().latencies.insert(link_key(a, b), latency);
+    }
+
+    /// Partitions the network into the given groups of nodes.
+    ///
+    /// Nodes in different groups can't connect to each other and all their existing connections
+    /// are cut. Nodes that are not in any group are isolated from all other nodes.
+    pub fn partition(&self, groups: &[&[usize]]) {
+        let mut state = self.router.state.lock();
+        state.groups = groups
+            .iter()
+            .enumerate()
+            .flat_map(|(group, nodes)| nodes.iter().map(move |node| (*node, group)))
+            .collect();
+        state.partitioned = true;
+        let links: Vec<_> = std::mem::take(&mut state.links)
+            .into_iter()
+            .filter(|link| {
+                let reachable = state.is_reachable(link.nodes.0, link.nodes.1);
+                if !reachable {
+                    trace!(target: "net::sim", nodes=?link.nodes, "cutting link");
+                    link.task.abort();
+                }
+                reachable
+            })
+            .collect();
+        state.links = links;
+    }
+
+    /// Removes the partition, so all nodes can connect to each other again.
+    pub fn heal(&self) {
+        let mut state = self.router.state.lock();
+        state.groups.clear();
+        state.partitioned = false;
+    }
+
+    /// Returns the head of the chain of the node with the given index.
+    pub fn head(&self, node: usize) -> &SealedHeader {
+        &self.heads[node]
+    }
+
+    /// Produces the given number of empty blocks on top of the head of the node and updates the
+    /// head the node announces to its peers.
+    ///
+    /// Every block is passed to the callback set with [`Simulation::on_block`].
+    pub fn produce_blocks(&mut self, node: usize, count: usize) -> Vec<SealedBlock> {
+        let mut blocks = Vec::with_capacity(count);
+        for _ in 0..count {
+            let parent = &self.heads[node];
+            let header = Header {
+                parent_hash: parent.hash(),
+                number: parent.number + 1,
+                timestamp: parent.timestamp + BLOCK_TIME,
+                beneficiary: Address::from(self.rng.gen::<[u8; 20]>()),
+                gas_limit: parent.gas_limit,
+                ..Default::default()
+            };
+            let block = SealedBlock::new(SealedHeader::seal(header), BlockBody::default());
+            (self.on_block)(node, &block);
+            self.heads[node] = block.header.clone();
+            blocks.push(block);
+        }
+
+        let head = &self.heads[node];
+        self.node(node).network().update_status(Head {
+            number: head.number,
+            hash: head.hash(),
+            difficulty: U256::ZERO,
+            total_difficulty: U256::ZERO,
+            timestamp: head.timestamp,
+        });
+        blocks
+    }
+
+    /// Terminates the simulation and returns the nodes.
+    pub async fn terminate(self) -> Testnet<C, TestPool> {
+        self.testnet.terminate().await
+    }
+}
+
+impl<C> fmt::Debug for SimulationHandle<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimulationHandle")
+            .field("router", &self.router)
+            .field("heads", &self.heads)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns the key of the link between the two nodes, which is the same in both directions.
+const fn link_key(a: usize, b: usize) -> (usize, usize) {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Routes the connections between the nodes of a [`Simulation`].
+///
+/// Every node dials its outgoing connections through its own SOCKS5 ingress of the router, so the
+/// router knows both ends of each connection.
+#[derive(Debug, Clone, Default)]
+struct SimRouter {
+    state: Arc<Mutex<RouterState>>,
+}
+
+/// The shared state of the [`SimRouter`].
+#[derive(Debug, Default)]
+struct RouterState {
+    /// The node listening on each port.
+    nodes: HashMap<u16, usize>,
+    /// The latency of links without a specific latency.
+    default_latency: Duration,
+    /// The latency of specific links.
+    latencies: HashMap<(usize, usize), Duration>,
+    /// Whether the network is partitioned.
+    partitioned: bool,
+    /// The group of each node while the network is partitioned.
+    groups: HashMap<usize, usize>,
+    /// All established links.
+    links: Vec<Link>,
+}
+
+impl RouterState {
+    /// Returns true if the two nodes can connect to each other.
+    fn is_reachable(&self, a: usize, b: usize) -> bool {
+        !self.partitioned ||
+            self.groups.get(&a).is_some_and(|group| self.groups.get(&b) == Some(group))
+    }
+
+    /// Returns the latency of the given link.
+    fn latency(&self, link: (usize, usize)) -> Duration {
+        self.latencies.get(&link).copied().unwrap_or(self.default_latency)
+    }
+}
+
+/// A connection between two nodes relayed by the [`SimRouter`].
+#[derive(Debug)]
+struct Link {
+    /// The nodes of the link, see [`link_key`].
+    nodes: (usize, usize),
+    /// The task forwarding the data of the connection.
+    task: AbortHandle,
+}
+
+impl SimRouter {
+    /// Starts the ingress for the node with the given index and returns the proxy config the node
+    /// dials through.
+    async fn add_ingress(&self, node: usize) -> Result<ProxyConfig, NetworkError> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let addr = listener.local_addr()?;
+        let router = self.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let router = router.clone();
+                tokio::spawn(async move {
+                    if let Err(err) = router.route(node, stream).await {
+                        trace!(target: "net::sim", %err, node, "failed to route connection");
+                    }
+                });
+            }
+        });
+        Ok(ProxyConfig::new(addr.ip().to_string(), addr.port()))
+    }
+
+    /// Registers the port the node with the given index listens on.
+    fn register(&self, node: usize, port: u16) {
+        self.state.lock().nodes.insert(port, node);
+    }
+
+    /// Serves the SOCKS5 connect request of the given node and relays the connection.
+    async fn route(&self, from: usize, mut stream: TcpStream) -> io::Result<()> {
+        // greeting, only the no authentication method is supported
+        let mut greeting = [0u8; 2];
+        stream.read_exact(&mut greeting).await?;
+        let mut methods = vec![0u8; greeting[1] as usize];
+        stream.read_exact(&mut methods).await?;
+        stream.write_all(&[0x05, 0x00]).await?;
+
+        // connect request
+        let mut request = [0u8; 4];
+        stream.read_exact(&mut request).await?;
+        let addr_len = match request[3] {
+            0x01 => 4,
+            0x04 => 16,
+            _ => return Err(io::Error::new(io::ErrorKind::InvalidData, "unexpected address type")),
+        };
+        let mut addr = vec![0u8; addr_len + 2];
+        stream.read_exact(&mut addr).await?;
+        let port = u16::from_be_bytes([addr[addr_len], addr[addr_len + 1]]);
+
+        let to = {
+            let state = self.state.lock();
+            state.nodes.get(&port).copied().filter(|to| state.is_reachable(from, *to))
+        };
+        let Some(to) = to else {
+            // connection not allowed by ruleset
+            stream.write_all(&[0x05, 0x02, 0x00, 0x01, 0, 0, 0, 0, 0, 0]).await?;
+            return Ok(())
+        };
+        let target = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).await?;
+        stream.write_all(&[0x05, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0]).await?;
+
+        let nodes = link_key(from, to);
+        let (stream_read, stream_write) = stream.into_split();
+        let (target_read, target_write) = target.into_split();
+        let forward = {
+            let router = self.clone();
+            async move {
+                tokio::join!(
+                    router.forward(nodes, stream_read, target_write),
+                    router.forward(nodes, target_read, stream_write)
+                );
+            }
+        };
+
+        let mut state = self.state.lock();
+        // the network could have been partitioned while connecting
+        if state.is_reachable(from, to) {
+            trace!(target: "net::sim", from, to, "established link");
+            let task = tokio::spawn(forward).abort_handle();
+            state.links.retain(|link| !link.task.is_finished());
+            state.links.push(Link { nodes, task });
+        }
+        Ok(())
+    }
+
+    /// Forwards all data from the reader to the writer, delayed by the latency of the link.
+    async fn forward<R, W>(&self, link: (usize, usize), mut reader: R, mut writer: W)
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let read = async move {
+            let mut buf = vec![0u8; 16 * 1024];
+            while let Ok(n @ 1..) = reader.read(&mut buf).await {
+                let deliver_at = Instant::now() + self.state.lock().latency(link);
+                if tx.send((deliver_at, buf[..n].to_vec())).is_err() {
+                    break
+                }
+            }
+        };
+        let write = async move {
+            while let Some((deliver_at, data)) = rx.recv().await {
+                tokio::time::sleep_until(deliver_at).await;
+                if writer.write_all(&data).await.is_err() {
+                    break
+                }
+            }
+            let _ = writer.shutdown().await;
+        };
+        tokio::join!(read, write);
+    }
+}
@@ -20,6 +20,7 @@ use reth_network_api::{
     NetworkEvent, NetworkEventListenerProvider, NetworkInfo, Peers,
 };
 use reth_network_peers::PeerId;
+use reth_network_types::ProxyConfig;
 use reth_primitives::{PooledTransactionsElement, TransactionSigned};
 use reth_storage_api::{
     noop::NoopProvider, BlockReader, BlockReaderIdExt, HeaderProvider, StateProviderFactory,
@@ -673,6 +674,12 @@ where
         Self { config, client, secret_key }
     }
 
+    /// Configures the network to dial outgoing connections through the given proxy.
+    pub fn with_proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.config.sessions_config = self.config.sessions_config.with_proxy(proxy);
+        self
+    }
+
     fn network_config_builder(secret_key: SecretKey) -> NetworkConfigBuilder {
         NetworkConfigBuilder::new(secret_key)
             .listener_addr(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)))
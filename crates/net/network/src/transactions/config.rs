@@ -1,4 +1,5 @@
 use super::{
+    constants::tx_manager::DEFAULT_MAX_COUNT_PROPAGATION_TRACES,
     DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
     DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
     SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE,
@@ -8,6 +9,7 @@ use crate::transactions::constants::tx_fetcher::{
     DEFAULT_MAX_COUNT_CONCURRENT_REQUESTS_PER_PEER,
 };
 use derive_more::Constructor;
+use std::{fmt, str::FromStr};
 
 /// Configuration for managing transactions within the network.
 #[derive(Debug, Clone)]
@@ -20,6 +22,20 @@ pub struct TransactionsManagerConfig {
     /// How new pending transactions are propagated.
     #[cfg_attr(feature = "serde", serde(default))]
     pub propagation_mode: TransactionPropagationMode,
+    /// Max number of local transactions to record a propagation trace for, see
+    /// [`TransactionsHandle::get_propagation_trace`](super::TransactionsHandle::get_propagation_trace).
+    ///
+    /// Tracing is disabled if this is `0`.
+    #[cfg_attr(feature = "serde", serde(default = "default_max_propagation_traces"))]
+    pub max_propagation_traces: u32,
+    /// Which transactions are gossiped and how much bandwidth the gossip may use per peer.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub gossip: TransactionGossipConfig,
+}
+
+#[cfg(feature = "serde")]
+const fn default_max_propagation_traces() -> u32 {
+    DEFAULT_MAX_COUNT_PROPAGATION_TRACES
 }
 
 impl Default for TransactionsManagerConfig {
@@ -28,12 +44,54 @@ impl Default for TransactionsManagerConfig {
             transaction_fetcher_config: TransactionFetcherConfig::default(),
             max_transactions_seen_by_peer_history: DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
             propagation_mode: TransactionPropagationMode::default(),
+            max_propagation_traces: DEFAULT_MAX_COUNT_PROPAGATION_TRACES,
+            gossip: TransactionGossipConfig::default(),
         }
     }
 }
 
+/// Rules for the gossip of new pending transactions by transaction class.
+///
+/// Suppressed transactions are not propagated at all. Deprioritized transactions are only
+/// announced as hashes, so peers only request them if they need them. Transactions requested by a
+/// peer with `GetPooledTransactions` are served regardless of these rules.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TransactionGossipConfig {
+    /// Transactions with a larger encoded size are not propagated.
+    pub max_propagated_size: Option<usize>,
+    /// Transaction types that are not propagated.
+    pub suppressed_types: Vec<u8>,
+    /// Transactions with a larger encoded size are only announced as hashes.
+    pub max_full_size: Option<usize>,
+    /// Transaction types that are only announced as hashes.
+    pub hashes_only_types: Vec<u8>,
+    /// Max number of bytes of full transactions that are sent to a single peer per second.
+    ///
+    /// Transactions that exceed the budget of the peer are announced as hashes.
+    pub max_full_bytes_per_peer: Option<usize>,
+}
+
+impl TransactionGossipConfig {
+    /// Returns `true` if transactions of the type and encoded size must not be propagated.
+    pub fn is_suppressed(&self, tx_type: u8, size: usize) -> bool {
+        self.suppressed_types.contains(&tx_type) ||
+            self.max_propagated_size.is_some_and(|max| size > max)
+    }
+
+    /// Returns `true` if transactions of the type and encoded size must only be announced as
+    /// hashes.
+    pub fn is_hashes_only(&self, tx_type: u8, size: usize) -> bool {
+        self.hashes_only_types.contains(&tx_type) ||
+            self.max_full_size.is_some_and(|max| size > max)
+    }
+}
+
 /// Determines how new pending transactions are propagated to other peers in full.
-#[derive(Debug, Clone, Default)]
+///
+/// All remaining peers are only sent the transaction hashes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TransactionPropagationMode {
     /// Send full transactions to sqrt of current peers.
@@ -43,6 +101,8 @@ pub enum TransactionPropagationMode {
     All,
     /// Send full transactions to a maximum number of peers
     Max(usize),
+    /// Send full transactions to the given percentage of current peers, capped at 100.
+    Percent(u8),
 }
 
 impl TransactionPropagationMode {
@@ -52,6 +112,49 @@ impl TransactionPropagationMode {
             Self::Sqrt => (peer_count as f64).sqrt().round() as usize,
             Self::All => peer_count,
             Self::Max(max) => peer_count.min(*max),
+            Self::Percent(percent) => (peer_count * (*percent).min(100) as usize).div_ceil(100),
+        }
+    }
+}
+
+impl fmt::Display for TransactionPropagationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sqrt => f.write_str("sqrt"),
+            Self::All => f.write_str("all"),
+            Self::Max(max) => write!(f, "{max}"),
+            Self::Percent(percent) => write!(f, "{percent}%"),
+        }
+    }
+}
+
+impl FromStr for TransactionPropagationMode {
+    type Err = String;
+
+    /// Parses `sqrt`, `all`, a percentage of peers (e.g. `25%`) or a max number of peers (e.g.
+    /// `8`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "sqrt" => Ok(Self::Sqrt),
+            "all" => Ok(Self::All),
+            s => {
+                if let Some(percent) = s.strip_suffix('%') {
+                    let percent = percent
+                        .trim()
+                        .parse::<u8>()
+                        .ok()
+                        .filter(|percent| *percent <= 100)
+                        .ok_or_else(|| format!("invalid percentage of peers: {s}"))?;
+                    Ok(Self::Percent(percent))
+                } else {
+                    s.parse::<usize>().map(Self::Max).map_err(|_| {
+                        format!(
+                            "invalid propagation mode: {s}, expected `sqrt`, `all`, a percentage \
+                             or a number of peers"
+                        )
+                    })
+                }
+            }
         }
     }
 }
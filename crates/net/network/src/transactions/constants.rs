@@ -36,6 +36,7 @@ pub const SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE: usize = 2 * 1024 *
 /// Constants used by [`TransactionsManager`](super::TransactionsManager).
 pub mod tx_manager {
     use super::SOFT_LIMIT_COUNT_HASHES_IN_NEW_POOLED_TRANSACTIONS_BROADCAST_MESSAGE;
+    use std::time::Duration;
 
     /// Default limit for number of transactions to keep track of for a single peer.
     ///
@@ -53,6 +54,24 @@ pub mod tx_manager {
     ///
     /// Default is 100 KiB, i.e. 3 200 transaction hashes.
     pub const DEFAULT_MAX_COUNT_BAD_IMPORTS: u32 = 100 * 1024 / 32;
+
+    /// Default limit for number of local transactions to keep a propagation trace for.
+    ///
+    /// Each trace holds at most one entry per connected peer.
+    pub const DEFAULT_MAX_COUNT_PROPAGATION_TRACES: u32 = 256;
+
+    /// Default limit for number of transactions to buffer for a single peer, whose announcement
+    /// was throttled by the
+    /// [`TransactionPropagationPolicy`](crate::transactions::TransactionPropagationPolicy).
+    ///
+    /// Default is the number of hashes in one full announcement, which is spec'd at 4096 hashes.
+    pub const DEFAULT_MAX_COUNT_THROTTLED_ANNOUNCEMENTS_BY_PEER: u32 =
+        SOFT_LIMIT_COUNT_HASHES_IN_NEW_POOLED_TRANSACTIONS_BROADCAST_MESSAGE as u32;
+
+    /// Default interval at which buffered throttled announcements are announced again, if the
+    /// [`TransactionPropagationPolicy`](crate::transactions::TransactionPropagationPolicy) allows
+    /// announcing to the peer.
+    pub const DEFAULT_THROTTLED_ANNOUNCEMENTS_INTERVAL: Duration = Duration::from_secs(1);
 }
 
 /// Constants used by [`TransactionFetcher`](super::TransactionFetcher).
@@ -6,16 +6,27 @@ pub mod config;
 pub mod constants;
 /// Component responsible for fetching transactions from [`NewPooledTransactionHashes`].
 pub mod fetcher;
+pub mod policy;
+pub mod trace;
 pub mod validation;
 
 pub use self::constants::{
     tx_fetcher::DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
     SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE,
 };
-pub use config::{TransactionFetcherConfig, TransactionPropagationMode, TransactionsManagerConfig};
+pub use config::{
+    TransactionFetcherConfig, TransactionGossipConfig, TransactionPropagationMode,
+    TransactionsManagerConfig,
+};
+pub use policy::{
+    ConfiguredPropagationPolicy, DefaultPropagationPolicy, PeerPropagation, PropagationCandidate,
+    PropagationFilter, TransactionPropagationPolicy,
+};
+pub use trace::{PropagationTrace, PropagationTraceEntry};
 pub use validation::*;
 
 pub(crate) use fetcher::{FetchEvent, TransactionFetcher};
+use trace::PropagationTracer;
 
 use self::constants::{tx_manager::*, DEFAULT_SOFT_LIMIT_BYTE_SIZE_TRANSACTIONS_BROADCAST_MESSAGE};
 use crate::{
@@ -67,7 +78,10 @@ use std::{
     task::{Context, Poll},
     time::{Duration, Instant},
 };
-use tokio::sync::{mpsc, oneshot, oneshot::error::RecvError};
+use tokio::{
+    sync::{mpsc, oneshot, oneshot::error::RecvError},
+    time::Interval,
+};
 use tokio_stream::wrappers::{ReceiverStream, UnboundedReceiverStream};
 use tracing::{debug, trace};
 
@@ -181,6 +195,20 @@ impl<N: NetworkPrimitives> TransactionsHandle<N> {
         Ok(res.into_values().next().unwrap_or_default())
     }
 
+    /// Requests the propagation trace of a local transaction, which records the peers the
+    /// transaction was sent to and when.
+    ///
+    /// Returns `None` if the transaction isn't local, tracing is disabled or the trace was
+    /// evicted, see [`TransactionsManagerConfig::max_propagation_traces`].
+    pub async fn get_propagation_trace(
+        &self,
+        hash: TxHash,
+    ) -> Result<Option<PropagationTrace>, RecvError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(TransactionsCommand::GetPropagationTrace { hash, tx });
+        rx.await
+    }
+
     /// Requests the transactions directly from the given peer.
     ///
     /// Returns `None` if the peer is not connected.
@@ -276,6 +304,13 @@ pub struct TransactionsManager<Pool, N: NetworkPrimitives = EthNetworkPrimitives
     transaction_events: UnboundedMeteredReceiver<NetworkTransactionEvent<N>>,
     /// How the `TransactionsManager` is configured.
     config: TransactionsManagerConfig,
+    /// Decides which transactions are propagated to which peers.
+    propagation_policy: Box<dyn TransactionPropagationPolicy>,
+    /// Interval at which announcements that were throttled by the
+    /// [`TransactionPropagationPolicy`] are announced again.
+    throttled_announcements_interval: Interval,
+    /// Records to which peers local transactions were propagated.
+    propagation_tracer: PropagationTracer,
     /// `TransactionsManager` metrics
     metrics: TransactionsManagerMetrics,
 }
@@ -326,16 +361,67 @@ impl<Pool: TransactionPool, N: NetworkPrimitives> TransactionsManager<Pool, N> {
                 from_network,
                 NETWORK_POOL_TRANSACTIONS_SCOPE,
             ),
+            throttled_announcements_interval: tokio::time::interval_at(
+                tokio::time::Instant::now() + DEFAULT_THROTTLED_ANNOUNCEMENTS_INTERVAL,
+                DEFAULT_THROTTLED_ANNOUNCEMENTS_INTERVAL,
+            ),
+            propagation_tracer: PropagationTracer::new(
+                transactions_manager_config.max_propagation_traces,
+            ),
             config: transactions_manager_config,
+            propagation_policy: Box::new(DefaultPropagationPolicy),
             metrics,
         }
     }
 
+    /// Sets the [`TransactionPropagationPolicy`] that controls the transaction gossip.
+    pub fn set_propagation_policy(&mut self, policy: impl TransactionPropagationPolicy) {
+        self.propagation_policy = Box::new(policy);
+    }
+
+    /// Configures the [`TransactionPropagationPolicy`] that controls the transaction gossip.
+    pub fn with_propagation_policy(mut self, policy: impl TransactionPropagationPolicy) -> Self {
+        self.set_propagation_policy(policy);
+        self
+    }
+
     /// Returns a new handle that can send commands to this type.
     pub fn handle(&self) -> TransactionsHandle<N> {
         TransactionsHandle { manager_tx: self.command_tx.clone() }
     }
 
+    /// Returns all transactions of the pool with the given hashes that may be propagated according
+    /// to the [`TransactionGossipConfig`] and the [`TransactionPropagationPolicy`].
+    ///
+    /// Local transactions are registered for propagation tracing.
+    fn propagatable_transactions(
+        &mut self,
+        hashes: Vec<TxHash>,
+    ) -> Vec<Arc<ValidPoolTransaction<Pool::Transaction>>> {
+        let mut txs = self.pool.get_all(hashes);
+        txs.retain(|tx| self.is_propagatable(tx));
+        for tx in txs.iter().filter(|tx| tx.origin.is_local()) {
+            self.propagation_tracer.track(*tx.hash());
+        }
+        txs
+    }
+
+    /// Returns `true` if the transaction may be propagated according to the
+    /// [`TransactionGossipConfig`] and the [`TransactionPropagationPolicy`].
+    fn is_propagatable(&self, tx: &ValidPoolTransaction<Pool::Transaction>) -> bool {
+        if self.config.gossip.is_suppressed(tx.transaction.tx_type(), tx.encoded_length()) {
+            self.metrics.suppressed_gossip_transactions.increment(1);
+            return false
+        }
+        self.propagation_policy.should_propagate(&PropagationCandidate::new(tx))
+    }
+
+    /// Records the propagated transactions and notifies the pool so events get fired.
+    fn on_propagated(&mut self, propagated: PropagatedTransactions) {
+        self.propagation_tracer.on_propagated(&propagated);
+        self.pool.on_propagated(propagated);
+    }
+
     /// Returns `true` if [`TransactionsManager`] has capacity to request pending hashes. Returns
     /// `false` if [`TransactionsManager`] is operating close to full capacity.
     fn has_capacity_for_fetching_pending_hashes(&self) -> bool {
@@ -730,13 +816,18 @@ where
     ) -> Option<PropagatedTransactions> {
         trace!(target: "net::tx", ?peer_id, "Propagating transactions to peer");
 
+        let to_propagate = self
+            .propagatable_transactions(txs)
+            .into_iter()
+            .map(|tx| self.propagate_transaction(tx))
+            .collect::<Vec<_>>();
+
         let peer = self.peers.get_mut(&peer_id)?;
         let mut propagated = PropagatedTransactions::default();
 
         // filter all transactions unknown to the peer
-        let mut full_transactions = FullTransactionsBuilder::new(peer.version);
-
-        let to_propagate = self.pool.get_all(txs).into_iter().map(PropagateTransaction::new);
+        let mut full_transactions = FullTransactionsBuilder::new(peer.version)
+            .with_byte_budget(peer.full_gossip_budget.as_mut().map(|budget| budget.available()));
 
         if propagation_mode.is_forced() {
             // skip cache check if forced
@@ -757,7 +848,10 @@ where
             return None
         }
 
-        let PropagateTransactions { pooled, full } = full_transactions.build();
+        let PropagateTransactions { pooled, full, full_size, over_budget } =
+            full_transactions.build();
+        peer.consume_full_gossip_budget(full_size);
+        self.metrics.bandwidth_capped_gossip_transactions.increment(over_budget as u64);
 
         // send hashes if any
         if let Some(new_pooled_hashes) = pooled {
@@ -803,18 +897,17 @@ where
         // This fetches a transactions from the pool, including the blob transactions, which are
         // only ever sent as hashes.
         let propagated = {
-            let Some(peer) = self.peers.get_mut(&peer_id) else {
-                // no such peer
-                return
-            };
-
             let to_propagate = self
-                .pool
-                .get_all(hashes)
+                .propagatable_transactions(hashes)
                 .into_iter()
                 .map(PropagateTransaction::new)
                 .collect::<Vec<_>>();
 
+            let Some(peer) = self.peers.get_mut(&peer_id) else {
+                // no such peer
+                return
+            };
+
             let mut propagated = PropagatedTransactions::default();
 
             // check if transaction is known to peer
@@ -854,7 +947,7 @@ where
         };
 
         // notify pool so events get fired
-        self.pool.on_propagated(propagated);
+        self.on_propagated(propagated);
     }
 
     /// Propagate the transactions to all connected peers either as full objects or hashes.
@@ -875,14 +968,26 @@ where
 
         // send full transactions to a set of the connected peers based on the configured mode
         let max_num_full = self.config.propagation_mode.full_peer_count(self.peers.len());
+        let mut with_throttled_announcements = Vec::new();
 
         // Note: Assuming ~random~ order due to random state of the peers map hasher
         for (peer_idx, (peer_id, peer)) in self.peers.iter_mut().enumerate() {
             // determine whether to send full tx objects or hashes.
-            let mut builder = if peer_idx > max_num_full {
-                PropagateTransactionsBuilder::pooled(peer.version)
+            let default = if peer_idx > max_num_full {
+                PeerPropagation::Hashes
             } else {
-                PropagateTransactionsBuilder::full(peer.version)
+                PeerPropagation::Full
+            };
+            let mut builder = match self.propagation_policy.peer_propagation(peer_id, default) {
+                PeerPropagation::Full => PropagateTransactionsBuilder::full(
+                    peer.version,
+                    peer.full_gossip_budget.as_mut().map(|budget| budget.available()),
+                ),
+                PeerPropagation::Hashes => PropagateTransactionsBuilder::pooled(peer.version),
+                PeerPropagation::Skip => {
+                    trace!(target: "net::tx", ?peer_id, "Skipping peer by propagation policy");
+                    continue
+                }
             };
 
             if propagation_mode.is_forced() {
@@ -905,7 +1010,26 @@ where
                 continue
             }
 
-            let PropagateTransactions { pooled, full } = builder.build();
+            if !self.propagation_policy.on_announcement(peer_id) {
+                trace!(target: "net::tx", ?peer_id, "Announcement to peer throttled by propagation policy");
+                // buffer the transactions, they're announced once the policy allows it again
+                peer.throttled_announcements.extend(
+                    to_propagate
+                        .iter()
+                        .map(|tx| *tx.tx_hash())
+                        .filter(|hash| !peer.seen_transactions.contains(hash)),
+                );
+                continue
+            }
+
+            if !peer.throttled_announcements.is_empty() {
+                // the announcement also covers the transactions of throttled announcements
+                with_throttled_announcements.push(*peer_id);
+            }
+
+            let PropagateTransactions { pooled, full, full_size, over_budget } = builder.build();
+            peer.consume_full_gossip_budget(full_size);
+            self.metrics.bandwidth_capped_gossip_transactions.increment(over_budget as u64);
 
             // send hashes if any
             if let Some(mut new_pooled_hashes) = pooled {
@@ -948,21 +1072,57 @@ where
         // Update propagated transactions metrics
         self.metrics.propagated_transactions.increment(propagated.0.len() as u64);
 
+        for peer_id in with_throttled_announcements {
+            self.propagate_throttled_announcements_to(peer_id);
+        }
+
         propagated
     }
 
+    /// Announces the transactions of throttled announcements to all peers the
+    /// [`TransactionPropagationPolicy`] allows to announce to again.
+    fn propagate_throttled_announcements(&mut self) {
+        if self.network.tx_gossip_disabled() {
+            return
+        }
+
+        let peers = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| !peer.throttled_announcements.is_empty())
+            .map(|(peer_id, _)| *peer_id)
+            .collect::<Vec<_>>();
+        for peer_id in peers {
+            if self.propagation_policy.on_announcement(&peer_id) {
+                self.propagate_throttled_announcements_to(peer_id);
+            }
+        }
+    }
+
+    /// Announces the hashes of the transactions of throttled announcements to the peer.
+    ///
+    /// Note: Transactions that were removed from the pool in the meantime are skipped.
+    fn propagate_throttled_announcements_to(&mut self, peer_id: PeerId) {
+        let Some(peer) = self.peers.get_mut(&peer_id) else { return };
+        let hashes = peer.take_throttled_announcements();
+        trace!(target: "net::tx", ?peer_id, num_txs=hashes.len(), "Propagating throttled announcements to peer");
+        self.propagate_hashes_to(hashes, peer_id, PropagationMode::Basic);
+    }
+
     /// Propagates the given transactions to the peers
     ///
     /// This fetches all transaction from the pool, including the 4844 blob transactions but
     /// __without__ their sidecar, because 4844 transactions are only ever announced as hashes.
     fn propagate_all(&mut self, hashes: Vec<TxHash>) {
-        let propagated = self.propagate_transactions(
-            self.pool.get_all(hashes).into_iter().map(PropagateTransaction::new).collect(),
-            PropagationMode::Basic,
-        );
+        let to_propagate = self
+            .propagatable_transactions(hashes)
+            .into_iter()
+            .map(|tx| self.propagate_transaction(tx))
+            .collect();
+        let propagated = self.propagate_transactions(to_propagate, PropagationMode::Basic);
 
         // notify pool so events get fired
-        self.pool.on_propagated(propagated);
+        self.on_propagated(propagated);
     }
 
     /// Request handler for an incoming request for transactions
@@ -1011,7 +1171,7 @@ where
                 if let Some(propagated) =
                     self.propagate_full_transactions_to_peer(txs, peer, PropagationMode::Forced)
                 {
-                    self.pool.on_propagated(propagated);
+                    self.on_propagated(propagated);
                 }
             }
             TransactionsCommand::PropagateTransactions(txs) => self.propagate_all(txs),
@@ -1031,6 +1191,9 @@ where
                 let sender = self.peers.get(&peer_id).map(|peer| peer.request_tx.clone());
                 peer_request_sender.send(sender).ok();
             }
+            TransactionsCommand::GetPropagationTrace { hash, tx } => {
+                tx.send(self.propagation_tracer.get(&hash)).ok();
+            }
         }
     }
 
@@ -1043,12 +1206,14 @@ where
         let SessionInfo { peer_id, client_version, version, .. } = info;
 
         // Insert a new peer into the peerset.
-        let peer = PeerMetadata::<N>::new(
+        let mut peer = PeerMetadata::<N>::new(
             messages,
             version,
             client_version,
             self.config.max_transactions_seen_by_peer_history,
         );
+        peer.full_gossip_budget =
+            self.config.gossip.max_full_bytes_per_peer.map(FullGossipBudget::new);
         let peer = match self.peers.entry(peer_id) {
             Entry::Occupied(mut entry) => {
                 entry.insert(peer);
@@ -1066,9 +1231,10 @@ where
         }
 
         // Get transactions to broadcast
-        let pooled_txs = self.pool.pooled_transactions_max(
+        let mut pooled_txs = self.pool.pooled_transactions_max(
             SOFT_LIMIT_COUNT_HASHES_IN_NEW_POOLED_TRANSACTIONS_BROADCAST_MESSAGE,
         );
+        pooled_txs.retain(|tx| self.is_propagatable(tx));
         if pooled_txs.is_empty() {
             trace!(target: "net::tx", ?peer_id, "No transactions in the pool to broadcast");
             return;
@@ -1093,6 +1259,7 @@ where
                 // remove the peer
                 self.peers.remove(&peer_id);
                 self.transaction_fetcher.remove_peer(&peer_id);
+                self.propagation_policy.on_session_closed(&peer_id);
             }
             NetworkEvent::ActivePeerSession { info, messages } => {
                 // process active peer session and broadcast available transaction from the pool
@@ -1199,6 +1366,7 @@ where
         if let Some(peer) = self.peers.get_mut(&peer_id) {
             // pre-size to avoid reallocations
             let mut new_txs = Vec::with_capacity(transactions.len());
+
             for tx in transactions {
                 // recover transaction
                 let tx = match tx.try_into_ecrecovered() {
@@ -1447,6 +1615,15 @@ where
             poll_durations.acc_pending_fetch
         );
 
+        // Announce throttled announcements to peers that can be announced to again.
+        let mut retry_throttled_announcements = false;
+        while this.throttled_announcements_interval.poll_tick(cx).is_ready() {
+            retry_throttled_announcements = true;
+        }
+        if retry_throttled_announcements {
+            this.propagate_throttled_announcements();
+        }
+
         // Advance commands (propagate/fetch/serve txns).
         let maybe_more_commands = metered_poll_nested_stream_with_budget!(
             poll_durations.acc_cmds,
@@ -1506,6 +1683,8 @@ impl PropagationMode {
 struct PropagateTransaction<T = TransactionSigned> {
     size: usize,
     transaction: Arc<T>,
+    /// Whether the transaction must only be announced as a hash.
+    hashes_only: bool,
 }
 
 impl<T: SignedTransaction> PropagateTransaction<T> {
@@ -1517,7 +1696,7 @@ impl<T: SignedTransaction> PropagateTransaction<T> {
         let size = tx.encoded_length();
         let transaction = tx.transaction.clone_into_consensus();
         let transaction = Arc::new(transaction.into_signed());
-        Self { size, transaction }
+        Self { size, transaction, hashes_only: false }
     }
 
     fn tx_hash(&self) -> &TxHash {
@@ -1539,9 +1718,10 @@ impl<T> PropagateTransactionsBuilder<T> {
         Self::Pooled(PooledTransactionsHashesBuilder::new(version))
     }
 
-    /// Create a builder that sends transactions in full and records transactions that don't fit.
-    fn full(version: EthVersion) -> Self {
-        Self::Full(FullTransactionsBuilder::new(version))
+    /// Create a builder that sends transactions in full and records transactions that don't fit
+    /// into the message or the byte budget.
+    fn full(version: EthVersion, byte_budget: Option<usize>) -> Self {
+        Self::Full(FullTransactionsBuilder::new(version).with_byte_budget(byte_budget))
     }
 
     /// Returns true if no transactions are recorded.
@@ -1555,9 +1735,12 @@ impl<T> PropagateTransactionsBuilder<T> {
     /// Consumes the type and returns the built messages that should be sent to the peer.
     fn build(self) -> PropagateTransactions<T> {
         match self {
-            Self::Pooled(pooled) => {
-                PropagateTransactions { pooled: Some(pooled.build()), full: None }
-            }
+            Self::Pooled(pooled) => PropagateTransactions {
+                pooled: Some(pooled.build()),
+                full: None,
+                full_size: 0,
+                over_budget: 0,
+            },
             Self::Full(full) => full.build(),
         }
     }
@@ -1586,6 +1769,11 @@ struct PropagateTransactions<T> {
     pooled: Option<NewPooledTransactionHashes>,
     /// The transactions to send in full.
     full: Option<Vec<Arc<T>>>,
+    /// The total size of the transactions to send in full.
+    full_size: usize,
+    /// The number of transactions that are announced as hashes because they exceed the byte
+    /// budget for full transactions.
+    over_budget: usize,
 }
 
 /// Helper type for constructing the full transaction message that enforces the
@@ -1600,6 +1788,10 @@ struct FullTransactionsBuilder<T> {
     transactions: Vec<Arc<T>>,
     /// Transactions that didn't fit into the broadcast message
     pooled: PooledTransactionsHashesBuilder,
+    /// The max total size of full transactions, see [`TransactionGossipConfig`].
+    byte_budget: Option<usize>,
+    /// The number of transactions that exceeded the byte budget.
+    over_budget: usize,
 }
 
 impl<T> FullTransactionsBuilder<T> {
@@ -1609,9 +1801,18 @@ impl<T> FullTransactionsBuilder<T> {
             total_size: 0,
             pooled: PooledTransactionsHashesBuilder::new(version),
             transactions: vec![],
+            byte_budget: None,
+            over_budget: 0,
         }
     }
 
+    /// Limits the total size of the full transactions, transactions that exceed it are
+    /// announced as hashes.
+    const fn with_byte_budget(mut self, byte_budget: Option<usize>) -> Self {
+        self.byte_budget = byte_budget;
+        self
+    }
+
     /// Returns whether or not any transactions are in the [`FullTransactionsBuilder`].
     fn is_empty(&self) -> bool {
         self.transactions.is_empty() && self.pooled.is_empty()
@@ -1621,7 +1822,12 @@ impl<T> FullTransactionsBuilder<T> {
     fn build(self) -> PropagateTransactions<T> {
         let pooled = Some(self.pooled.build()).filter(|pooled| !pooled.is_empty());
         let full = Some(self.transactions).filter(|full| !full.is_empty());
-        PropagateTransactions { pooled, full }
+        PropagateTransactions {
+            pooled,
+            full,
+            full_size: self.total_size,
+            over_budget: self.over_budget,
+        }
     }
 }
 
@@ -1651,12 +1857,20 @@ impl<T: SignedTransaction> FullTransactionsBuilder<T> {
         //  via `GetPooledTransactions`.
         //
         // From: <https://eips.ethereum.org/EIPS/eip-4844#networking>
-        if !transaction.transaction.tx_type().is_broadcastable_in_full() {
+        if !transaction.transaction.tx_type().is_broadcastable_in_full() || transaction.hashes_only
+        {
             self.pooled.push(transaction);
             return
         }
 
         let new_size = self.total_size + transaction.size;
+        if self.byte_budget.is_some_and(|budget| new_size > budget) {
+            // the peer received too many bytes of full transactions recently
+            self.over_budget += 1;
+            self.pooled.push(transaction);
+            return
+        }
+
         if new_size > DEFAULT_SOFT_LIMIT_BYTE_SIZE_TRANSACTIONS_BROADCAST_MESSAGE &&
             self.total_size > 0
         {
@@ -1768,6 +1982,11 @@ pub struct PeerMetadata<N: NetworkPrimitives = EthNetworkPrimitives> {
     version: EthVersion,
     /// The peer's client version.
     client_version: Arc<str>,
+    /// The budget for full transactions sent to the peer, if capped.
+    full_gossip_budget: Option<FullGossipBudget>,
+    /// Transactions whose announcement to the peer was throttled by the
+    /// [`TransactionPropagationPolicy`] and that are announced once the policy allows it.
+    throttled_announcements: LruCache<TxHash>,
 }
 
 impl<N: NetworkPrimitives> PeerMetadata<N> {
@@ -1783,8 +2002,60 @@ impl<N: NetworkPrimitives> PeerMetadata<N> {
             request_tx,
             version,
             client_version,
+            full_gossip_budget: None,
+            throttled_announcements: LruCache::new(
+                DEFAULT_MAX_COUNT_THROTTLED_ANNOUNCEMENTS_BY_PEER,
+            ),
         }
     }
+
+    /// Takes the transactions of throttled announcements to the peer.
+    fn take_throttled_announcements(&mut self) -> Vec<TxHash> {
+        let hashes = self.throttled_announcements.iter().copied().collect();
+        self.throttled_announcements =
+            LruCache::new(DEFAULT_MAX_COUNT_THROTTLED_ANNOUNCEMENTS_BY_PEER);
+        hashes
+    }
+
+    /// Consumes the bytes of full transactions sent to the peer from its budget.
+    fn consume_full_gossip_budget(&mut self, bytes: usize) {
+        if let Some(budget) = &mut self.full_gossip_budget {
+            budget.consume(bytes);
+        }
+    }
+}
+
+/// The budget of bytes of full transactions that can be sent to a peer, which refills at a fixed
+/// rate per second up to the rate.
+#[derive(Debug)]
+struct FullGossipBudget {
+    /// The number of bytes per second.
+    rate: usize,
+    /// The number of bytes that can be sent.
+    available: usize,
+    /// When the budget was last refilled.
+    refilled_at: Instant,
+}
+
+impl FullGossipBudget {
+    fn new(rate: usize) -> Self {
+        Self { rate, available: rate, refilled_at: Instant::now() }
+    }
+
+    /// Refills the budget and returns the number of bytes that can be sent.
+    fn available(&mut self) -> usize {
+        let now = Instant::now();
+        let refill = self.rate as f64 * now.duration_since(self.refilled_at).as_secs_f64();
+        if refill >= 1. {
+            self.available = self.available.saturating_add(refill as usize).min(self.rate);
+            self.refilled_at = now;
+        }
+        self.available
+    }
+
+    fn consume(&mut self, bytes: usize) {
+        self.available = self.available.saturating_sub(bytes);
+    }
 }
 
 /// Commands to send to the [`TransactionsManager`]
@@ -1810,6 +2081,8 @@ enum TransactionsCommand<N: NetworkPrimitives = EthNetworkPrimitives> {
         peer_id: PeerId,
         peer_request_sender: oneshot::Sender<Option<PeerRequestSender<PeerRequest<N>>>>,
     },
+    /// Requests the propagation trace of a local transaction.
+    GetPropagationTrace { hash: TxHash, tx: oneshot::Sender<Option<PropagationTrace>> },
 }
 
 /// All events related to transactions emitted by the network.
@@ -2384,7 +2657,7 @@ mod tests {
     #[test]
     fn test_transaction_builder_large() {
         let mut builder =
-            PropagateTransactionsBuilder::<TransactionSigned>::full(EthVersion::Eth68);
+            PropagateTransactionsBuilder::<TransactionSigned>::full(EthVersion::Eth68, None);
         assert!(builder.is_empty());
 
         let mut factory = MockTransactionFactory::default();
@@ -2413,7 +2686,7 @@ mod tests {
     #[test]
     fn test_transaction_builder_eip4844() {
         let mut builder =
-            PropagateTransactionsBuilder::<TransactionSigned>::full(EthVersion::Eth68);
+            PropagateTransactionsBuilder::<TransactionSigned>::full(EthVersion::Eth68, None);
         assert!(builder.is_empty());
 
         let mut factory = MockTransactionFactory::default();
@@ -2436,6 +2709,46 @@ mod tests {
         assert_eq!(txs.len(), 1);
     }
 
+    #[test]
+    fn test_transaction_builder_gossip_rules() {
+        let mut factory = MockTransactionFactory::default();
+        let mut tx = factory.create_eip1559();
+        tx.transaction.set_size(1_000);
+        let tx = PropagateTransaction::new(Arc::new(tx));
+        let hashes_only = PropagateTransaction {
+            hashes_only: true,
+            ..PropagateTransaction::new(Arc::new(factory.create_eip1559()))
+        };
+
+        // the second transaction exceeds the budget
+        let mut builder =
+            PropagateTransactionsBuilder::<TransactionSigned>::full(EthVersion::Eth68, Some(1_500));
+        builder.push(&tx);
+        builder.push(&tx);
+        builder.push(&hashes_only);
+
+        let txs = builder.build();
+        assert_eq!(txs.full.unwrap().len(), 1);
+        assert_eq!(txs.pooled.unwrap().len(), 2);
+        assert_eq!(txs.full_size, 1_000);
+        assert_eq!(txs.over_budget, 1);
+    }
+
+    #[test]
+    fn test_full_gossip_budget() {
+        let mut budget = FullGossipBudget::new(1_000);
+        assert_eq!(budget.available(), 1_000);
+
+        budget.consume(1_500);
+        assert_eq!(budget.available(), 0);
+
+        budget.refilled_at -= Duration::from_millis(500);
+        assert!((499..=1_000).contains(&budget.available()));
+
+        budget.refilled_at -= Duration::from_secs(10);
+        assert_eq!(budget.available(), 1_000);
+    }
+
     #[tokio::test]
     async fn test_propagate_full() {
         reth_tracing::init_test_tracing();
@@ -2486,4 +2799,128 @@ mod tests {
         let propagated = tx_manager.propagate_transactions(propagate, PropagationMode::Basic);
         assert!(propagated.0.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_propagate_with_policy() {
+        reth_tracing::init_test_tracing();
+
+        let (tx_manager, network) = new_tx_manager().await;
+        let peer_id = PeerId::random();
+        network.handle().update_sync_state(SyncState::Idle);
+
+        let mut factory = MockTransactionFactory::default();
+        let eip1559_tx = factory.create_eip1559();
+        let private_tx = factory.create_eip1559();
+        let policy = ConfiguredPropagationPolicy::default()
+            .with_filter(PropagationFilter::Sender(private_tx.sender()))
+            .with_peer(peer_id, PeerPropagation::Hashes)
+            .with_min_announcement_interval(Duration::from_secs(60));
+        let mut tx_manager = tx_manager.with_propagation_policy(policy);
+
+        // mock a peer
+        let (tx, _rx) = mpsc::channel::<PeerRequest>(1);
+        let session_info = SessionInfo {
+            peer_id,
+            remote_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+            client_version: Arc::from(""),
+            capabilities: Arc::new(vec![].into()),
+            status: Arc::new(Default::default()),
+            version: EthVersion::Eth68,
+        };
+        let messages: PeerRequestSender<PeerRequest> = PeerRequestSender::new(peer_id, tx);
+        tx_manager
+            .on_network_event(NetworkEvent::ActivePeerSession { info: session_info, messages });
+
+        // transactions of filtered senders are never propagated
+        let hashes = vec![*eip1559_tx.hash(), *private_tx.hash()];
+        tx_manager.pool.add_external_transaction(eip1559_tx.transaction.clone()).await.unwrap();
+        tx_manager.pool.add_external_transaction(private_tx.transaction.clone()).await.unwrap();
+        let propagate = tx_manager
+            .propagatable_transactions(hashes)
+            .into_iter()
+            .map(PropagateTransaction::new)
+            .collect::<Vec<_>>();
+        assert_eq!(propagate.len(), 1);
+        assert_eq!(propagate[0].tx_hash(), eip1559_tx.hash());
+
+        // the peer only receives hashes although it's eligible for full transactions
+        let propagated = tx_manager.propagate_transactions(propagate, PropagationMode::Basic);
+        let prop_txs = propagated.0.get(eip1559_tx.hash()).unwrap();
+        assert_eq!(prop_txs.len(), 1);
+        assert!(prop_txs[0].is_hash());
+
+        // the next announcement is throttled and the transaction is not marked as seen
+        let another_tx = Arc::new(factory.create_eip1559());
+        tx_manager.pool.add_external_transaction(another_tx.transaction.clone()).await.unwrap();
+        let throttled = PropagateTransaction::new(another_tx.clone());
+        let propagated = tx_manager.propagate_transactions(vec![throttled], PropagationMode::Basic);
+        assert!(propagated.0.is_empty());
+        let peer = tx_manager.peers.get(&peer_id).unwrap();
+        assert!(!peer.seen_transactions.contains(another_tx.hash()));
+        assert!(peer.throttled_announcements.contains(another_tx.hash()));
+
+        // the throttled transaction is not announced again while the peer is throttled
+        tx_manager.propagate_throttled_announcements();
+        let peer = tx_manager.peers.get(&peer_id).unwrap();
+        assert!(!peer.seen_transactions.contains(another_tx.hash()));
+
+        // once the peer can be announced to again, the throttled transaction is announced
+        tx_manager.propagation_policy.on_session_closed(&peer_id);
+        tx_manager.propagate_throttled_announcements();
+        let peer = tx_manager.peers.get(&peer_id).unwrap();
+        assert!(peer.seen_transactions.contains(another_tx.hash()));
+        assert!(peer.throttled_announcements.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_propagation_trace_local_tx() {
+        reth_tracing::init_test_tracing();
+
+        let (mut tx_manager, network) = new_tx_manager().await;
+        network.handle().update_sync_state(SyncState::Idle);
+        tx_manager.config.propagation_mode = TransactionPropagationMode::Percent(50);
+
+        // mock peers
+        for _ in 0..4 {
+            let peer_id = PeerId::random();
+            let (tx, _rx) = mpsc::channel::<PeerRequest>(1);
+            let session_info = SessionInfo {
+                peer_id,
+                remote_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                client_version: Arc::from(""),
+                capabilities: Arc::new(vec![].into()),
+                status: Arc::new(Default::default()),
+                version: EthVersion::Eth68,
+            };
+            let messages: PeerRequestSender<PeerRequest> = PeerRequestSender::new(peer_id, tx);
+            tx_manager
+                .on_network_event(NetworkEvent::ActivePeerSession { info: session_info, messages });
+        }
+
+        let mut factory = MockTransactionFactory::default();
+        let local_tx = factory.create_eip1559();
+        let external_tx = factory.create_eip1559();
+        tx_manager
+            .pool
+            .add_transaction(
+                reth_transaction_pool::TransactionOrigin::Local,
+                local_tx.transaction.clone(),
+            )
+            .await
+            .unwrap();
+        tx_manager.pool.add_external_transaction(external_tx.transaction.clone()).await.unwrap();
+
+        tx_manager.propagate_all(vec![*local_tx.hash(), *external_tx.hash()]);
+
+        // half of the peers, and like for all propagation modes one more peer, receive the
+        // transaction in full
+        let trace = tx_manager.propagation_tracer.get(local_tx.hash()).unwrap();
+        assert_eq!(trace.propagations.len(), 4);
+        assert_eq!(trace.full_count(), 3);
+        assert_eq!(trace.hash_count(), 1);
+        assert!(trace.propagations.iter().all(|entry| entry.timestamp >= trace.first_seen));
+
+        // only local transactions are traced
+        assert!(tx_manager.propagation_tracer.get(external_tx.hash()).is_none());
+    }
 }
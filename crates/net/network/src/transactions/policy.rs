@@ -0,0 +1,249 @@
+//! Policies that control how new pending transactions are propagated to peers.
+
+use alloy_primitives::{map::HashMap, Address, Selector, TxHash};
+use reth_network_peers::PeerId;
+use reth_transaction_pool::{PoolTransaction, ValidPoolTransaction};
+use std::{
+    collections::HashSet,
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// How new pending transactions are propagated to a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerPropagation {
+    /// Send the transactions in full.
+    ///
+    /// Note: EIP-4844 transactions are always announced as hashes.
+    Full,
+    /// Only announce the hashes of the transactions.
+    Hashes,
+    /// Don't propagate transactions to the peer.
+    Skip,
+}
+
+/// A transaction that is about to be propagated, see
+/// [`TransactionPropagationPolicy::should_propagate`].
+#[derive(Debug, Clone, Copy)]
+pub struct PropagationCandidate<'a> {
+    /// The hash of the transaction.
+    pub hash: &'a TxHash,
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The recipient of the transaction, `None` for contract creations.
+    pub to: Option<Address>,
+    /// The input data of the transaction.
+    pub input: &'a [u8],
+}
+
+impl<'a> PropagationCandidate<'a> {
+    /// Creates a new candidate from a pooled transaction.
+    pub fn new<T: PoolTransaction>(tx: &'a ValidPoolTransaction<T>) -> Self {
+        Self {
+            hash: tx.hash(),
+            sender: tx.sender(),
+            to: tx.transaction.to(),
+            input: tx.transaction.input(),
+        }
+    }
+
+    /// Returns the function selector of the call, if the input is long enough.
+    pub fn selector(&self) -> Option<Selector> {
+        self.input.get(..4).map(Selector::from_slice)
+    }
+}
+
+/// Hooks into the transaction gossip of the
+/// [`TransactionsManager`](crate::transactions::TransactionsManager).
+///
+/// The policy decides which transactions are propagated at all, which peers receive new pending
+/// transactions in full or as hashes, and how often transactions are announced to a peer.
+///
+/// The policy only affects the propagation of transactions, transactions requested by a peer
+/// with `GetPooledTransactions` are still served.
+pub trait TransactionPropagationPolicy: fmt::Debug + Send + Sync + Unpin + 'static {
+    /// Returns `false` if the transaction must not be propagated to any peer.
+    fn should_propagate(&self, tx: &PropagationCandidate<'_>) -> bool {
+        let _ = tx;
+        true
+    }
+
+    /// Returns how new pending transactions are propagated to the peer.
+    ///
+    /// `default` is the kind selected by the configured
+    /// [`TransactionPropagationMode`](crate::transactions::TransactionPropagationMode).
+    fn peer_propagation(&self, peer_id: &PeerId, default: PeerPropagation) -> PeerPropagation {
+        let _ = peer_id;
+        default
+    }
+
+    /// Invoked before new pending transactions are announced to the peer.
+    ///
+    /// Returning `false` throttles the announcement: nothing is sent to the peer and the
+    /// transactions are not marked as seen by the peer. Instead, their hashes are buffered and
+    /// announced with the next announcement to the peer, which is retried periodically.
+    fn on_announcement(&mut self, peer_id: &PeerId) -> bool {
+        let _ = peer_id;
+        true
+    }
+
+    /// Invoked when the session with the peer was closed.
+    fn on_session_closed(&mut self, peer_id: &PeerId) {
+        let _ = peer_id;
+    }
+}
+
+/// The default [`TransactionPropagationPolicy`] that propagates all transactions according to the
+/// configured [`TransactionPropagationMode`](crate::transactions::TransactionPropagationMode).
+#[derive(Debug, Clone, Copy, Default)]
+#[non_exhaustive]
+pub struct DefaultPropagationPolicy;
+
+impl TransactionPropagationPolicy for DefaultPropagationPolicy {}
+
+/// A pattern of transactions that are never propagated, see [`ConfiguredPropagationPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PropagationFilter {
+    /// Transactions sent by the address.
+    Sender(Address),
+    /// Transactions sent to the address.
+    Recipient(Address),
+    /// Calls of the function selector on any contract.
+    Selector(Selector),
+    /// Calls of the function selector on the contract.
+    Call(Address, Selector),
+}
+
+impl PropagationFilter {
+    /// Returns `true` if the transaction matches the pattern.
+    pub fn matches(&self, tx: &PropagationCandidate<'_>) -> bool {
+        match self {
+            Self::Sender(sender) => tx.sender == *sender,
+            Self::Recipient(to) => tx.to == Some(*to),
+            Self::Selector(selector) => tx.selector() == Some(*selector),
+            Self::Call(to, selector) => tx.to == Some(*to) && tx.selector() == Some(*selector),
+        }
+    }
+}
+
+/// A [`TransactionPropagationPolicy`] configured with static rules, e.g. for private orderflow
+/// setups.
+///
+/// - transactions matching any [`PropagationFilter`] are never propagated.
+/// - peers can be pinned to receive transactions in full, as hashes or not at all.
+/// - announcements to a peer can be limited to one per interval.
+#[derive(Debug, Clone, Default)]
+pub struct ConfiguredPropagationPolicy {
+    /// Patterns of transactions that are never propagated.
+    filters: HashSet<PropagationFilter>,
+    /// Peers with a fixed [`PeerPropagation`].
+    peers: HashMap<PeerId, PeerPropagation>,
+    /// Minimum interval between two announcements to the same peer.
+    min_announcement_interval: Option<Duration>,
+    /// The time of the last announcement to a peer.
+    last_announcements: HashMap<PeerId, Instant>,
+}
+
+impl ConfiguredPropagationPolicy {
+    /// Suppresses the propagation of transactions that match the pattern.
+    pub fn with_filter(mut self, filter: PropagationFilter) -> Self {
+        self.filters.insert(filter);
+        self
+    }
+
+    /// Always propagates transactions to the peer with the given [`PeerPropagation`].
+    pub fn with_peer(mut self, peer_id: PeerId, propagation: PeerPropagation) -> Self {
+        self.peers.insert(peer_id, propagation);
+        self
+    }
+
+    /// Announces transactions to every peer at most once per interval.
+    pub const fn with_min_announcement_interval(mut self, interval: Duration) -> Self {
+        self.min_announcement_interval = Some(interval);
+        self
+    }
+}
+
+impl TransactionPropagationPolicy for ConfiguredPropagationPolicy {
+    fn should_propagate(&self, tx: &PropagationCandidate<'_>) -> bool {
+        !self.filters.iter().any(|filter| filter.matches(tx))
+    }
+
+    fn peer_propagation(&self, peer_id: &PeerId, default: PeerPropagation) -> PeerPropagation {
+        self.peers.get(peer_id).copied().unwrap_or(default)
+    }
+
+    fn on_announcement(&mut self, peer_id: &PeerId) -> bool {
+        let Some(interval) = self.min_announcement_interval else { return true };
+        let now = Instant::now();
+        if self
+            .last_announcements
+            .get(peer_id)
+            .is_some_and(|last| now.saturating_duration_since(*last) < interval)
+        {
+            return false
+        }
+        self.last_announcements.insert(*peer_id, now);
+        true
+    }
+
+    fn on_session_closed(&mut self, peer_id: &PeerId) {
+        self.last_announcements.remove(peer_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, bytes, fixed_bytes};
+
+    #[test]
+    fn filters_match_candidates() {
+        let router = address!("7a250d5630b4cf539739df2c5dacb4c659f2488d");
+        let swap = fixed_bytes!("38ed1739");
+        let hash = TxHash::ZERO;
+        let input = bytes!("38ed17390000");
+        let tx = PropagationCandidate {
+            hash: &hash,
+            sender: Address::with_last_byte(1),
+            to: Some(router),
+            input: &input,
+        };
+
+        assert!(PropagationFilter::Sender(Address::with_last_byte(1)).matches(&tx));
+        assert!(PropagationFilter::Recipient(router).matches(&tx));
+        assert!(PropagationFilter::Selector(swap).matches(&tx));
+        assert!(PropagationFilter::Call(router, swap).matches(&tx));
+        assert!(!PropagationFilter::Call(Address::ZERO, swap).matches(&tx));
+
+        let creation = PropagationCandidate { to: None, input: &[0x38, 0xed], ..tx };
+        assert!(!PropagationFilter::Recipient(router).matches(&creation));
+        assert!(!PropagationFilter::Selector(swap).matches(&creation));
+
+        let policy = ConfiguredPropagationPolicy::default()
+            .with_filter(PropagationFilter::Call(router, swap));
+        assert!(!policy.should_propagate(&tx));
+        assert!(policy.should_propagate(&creation));
+    }
+
+    #[test]
+    fn throttles_announcements() {
+        let peer = PeerId::random();
+        let mut policy = ConfiguredPropagationPolicy::default()
+            .with_peer(peer, PeerPropagation::Hashes)
+            .with_min_announcement_interval(Duration::from_secs(60));
+
+        assert_eq!(policy.peer_propagation(&peer, PeerPropagation::Full), PeerPropagation::Hashes);
+        assert_eq!(
+            policy.peer_propagation(&PeerId::random(), PeerPropagation::Full),
+            PeerPropagation::Full
+        );
+
+        assert!(policy.on_announcement(&peer));
+        assert!(!policy.on_announcement(&peer));
+        assert!(policy.on_announcement(&PeerId::random()));
+
+        policy.on_session_closed(&peer);
+        assert!(policy.on_announcement(&peer));
+    }
+}
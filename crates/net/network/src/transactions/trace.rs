@@ -0,0 +1,131 @@
+//! Records to which peers local transactions were propagated and when.
+
+use crate::cache::LruMap;
+use alloy_primitives::TxHash;
+use reth_network_peers::PeerId;
+use reth_transaction_pool::{PropagateKind, PropagatedTransactions};
+use std::time::SystemTime;
+use tracing::trace;
+
+/// The propagation history of a single local transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropagationTrace {
+    /// When the transaction was first handed to the
+    /// [`TransactionsManager`](super::TransactionsManager) for propagation.
+    pub first_seen: SystemTime,
+    /// The peers the transaction was sent to, in order of propagation.
+    pub propagations: Vec<PropagationTraceEntry>,
+}
+
+impl PropagationTrace {
+    /// Returns the number of peers that received the transaction in full.
+    pub fn full_count(&self) -> usize {
+        self.propagations.iter().filter(|entry| entry.kind.is_full()).count()
+    }
+
+    /// Returns the number of peers that received the transaction hash only.
+    pub fn hash_count(&self) -> usize {
+        self.propagations.iter().filter(|entry| entry.kind.is_hash()).count()
+    }
+}
+
+/// A single propagation of a traced transaction to a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropagationTraceEntry {
+    /// To whom the transaction was sent and whether in full or as hash.
+    pub kind: PropagateKind,
+    /// When the transaction was sent to the peer.
+    pub timestamp: SystemTime,
+}
+
+impl PropagationTraceEntry {
+    /// Returns the peer the transaction was sent to.
+    pub const fn peer_id(&self) -> &PeerId {
+        self.kind.peer()
+    }
+}
+
+/// Bounded store of [`PropagationTrace`]s, keyed by transaction hash.
+///
+/// Only transactions registered via [`PropagationTracer::track`] are traced, which the
+/// [`TransactionsManager`](super::TransactionsManager) does for local transactions only.
+#[derive(Debug)]
+pub(crate) struct PropagationTracer {
+    traces: Option<LruMap<TxHash, PropagationTrace>>,
+}
+
+impl PropagationTracer {
+    /// Creates a new tracer that keeps at most `max_traces` traces. Tracing is disabled if
+    /// `max_traces` is `0`.
+    pub(crate) fn new(max_traces: u32) -> Self {
+        Self { traces: (max_traces > 0).then(|| LruMap::new(max_traces)) }
+    }
+
+    /// Starts tracing the given transaction, if it isn't traced already.
+    pub(crate) fn track(&mut self, hash: TxHash) {
+        let Some(traces) = self.traces.as_mut() else { return };
+        if traces.peek(&hash).is_none() {
+            traces.insert(
+                hash,
+                PropagationTrace { first_seen: SystemTime::now(), propagations: Vec::new() },
+            );
+        }
+    }
+
+    /// Records the propagations of all traced transactions.
+    ///
+    /// Only the first propagation to a peer is recorded.
+    pub(crate) fn on_propagated(&mut self, propagated: &PropagatedTransactions) {
+        let Some(traces) = self.traces.as_mut() else { return };
+        let now = SystemTime::now();
+        for (hash, kinds) in &propagated.0 {
+            let Some(trace) = traces.get(hash) else { continue };
+            for kind in kinds {
+                if trace.propagations.iter().any(|entry| entry.peer_id() == kind.peer()) {
+                    continue
+                }
+                trace!(target: "net::tx::propagation", %hash, ?kind, "Propagated local transaction");
+                trace.propagations.push(PropagationTraceEntry { kind: *kind, timestamp: now });
+            }
+        }
+    }
+
+    /// Returns the trace of the given transaction, if any.
+    pub(crate) fn get(&self, hash: &TxHash) -> Option<PropagationTrace> {
+        self.traces.as_ref()?.peek(hash).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_first_propagation_per_peer() {
+        let mut tracer = PropagationTracer::new(2);
+        let traced = TxHash::random();
+        let untraced = TxHash::random();
+        let (peer_a, peer_b) = (PeerId::random(), PeerId::random());
+        tracer.track(traced);
+
+        let mut propagated = PropagatedTransactions::default();
+        propagated.0.insert(traced, vec![PropagateKind::Full(peer_a), PropagateKind::Hash(peer_b)]);
+        propagated.0.insert(untraced, vec![PropagateKind::Full(peer_a)]);
+        tracer.on_propagated(&propagated);
+        tracer.on_propagated(&propagated);
+
+        let trace = tracer.get(&traced).unwrap();
+        assert_eq!(trace.propagations.len(), 2);
+        assert_eq!(trace.full_count(), 1);
+        assert_eq!(trace.hash_count(), 1);
+        assert!(tracer.get(&untraced).is_none());
+    }
+
+    #[test]
+    fn disabled_tracer_records_nothing() {
+        let mut tracer = PropagationTracer::new(0);
+        let hash = TxHash::random();
+        tracer.track(hash);
+        assert!(tracer.get(&hash).is_none());
+    }
+}
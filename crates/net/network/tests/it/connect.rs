@@ -61,7 +61,11 @@ async fn test_establish_connections() {
         let mut established = listener0.take(4);
         while let Some(ev) = established.next().await {
             match ev {
-                NetworkEvent::Peer(PeerEvent::SessionClosed { .. } | PeerEvent::PeerRemoved(_)) => {
+                NetworkEvent::Peer(
+                    PeerEvent::SessionClosed { .. } |
+                    PeerEvent::PeerRemoved(_) |
+                    PeerEvent::ReputationChanged { .. },
+                ) => {
                     panic!("unexpected event")
                 }
                 NetworkEvent::ActivePeerSession { info, .. } |
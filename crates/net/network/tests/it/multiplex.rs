@@ -13,10 +13,14 @@ use reth_eth_wire::{
     capability::SharedCapabilities, multiplex::ProtocolConnection, protocol::Protocol,
 };
 use reth_network::{
-    protocol::{ConnectionHandler, OnNotSupported, ProtocolHandler},
+    protocol::{
+        ConnectionHandler, IntoRlpxSubProtocol, MessageHandler, MessageProtocol, OnNotSupported,
+        ProtocolHandler,
+    },
     test_utils::Testnet,
+    NetworkProtocols,
 };
-use reth_network_api::{Direction, PeerId};
+use reth_network_api::{Direction, NetworkInfo, PeerId};
 use reth_provider::test_utils::MockEthProvider;
 use tokio::sync::{mpsc, oneshot};
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -325,3 +329,59 @@ async fn test_proto_multiplex() {
     let response = rx.await.unwrap();
     assert_eq!(response, "hello from peer1!");
 }
+
+/// A [`MessageHandler`] that echoes all messages and forwards them to a channel.
+#[derive(Debug)]
+struct EchoHandler {
+    received: mpsc::UnboundedSender<(PeerId, BytesMut)>,
+}
+
+impl MessageHandler for EchoHandler {
+    fn on_message(&self, peer_id: PeerId, message: BytesMut) -> Option<BytesMut> {
+        self.received.send((peer_id, message.clone())).unwrap();
+        // only echo requests (id 0) as responses (id 1)
+        (message[0] == 0).then(|| {
+            let mut response = message;
+            response[0] = 1;
+            response
+        })
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_message_protocol_registered_at_runtime() {
+    reth_tracing::init_test_tracing();
+    let provider = MockEthProvider::default();
+    let net = Testnet::create_with(2, provider.clone()).await;
+    let handle = net.spawn();
+
+    let (tx, mut from_peer0) = mpsc::unbounded_channel();
+    let protocol0 = MessageProtocol::new("echo", 1, 2, EchoHandler { received: tx });
+    let echo0 = protocol0.handle();
+    let (tx, mut from_peer1) = mpsc::unbounded_channel();
+    let protocol1 = MessageProtocol::new("echo", 1, 2, EchoHandler { received: tx });
+
+    // register the protocols after the networks were launched
+    let (peer0, peer1) = (handle.peers()[0].network(), handle.peers()[1].network());
+    peer0.add_rlpx_sub_protocol(protocol0.into_rlpx_sub_protocol());
+    peer1.add_rlpx_sub_protocol(protocol1.into_rlpx_sub_protocol());
+    // wait until the protocols are registered
+    peer0.network_status().await.unwrap();
+    peer1.network_status().await.unwrap();
+
+    handle.connect_peers().await;
+
+    let peer1_id = *handle.peers()[1].peer_id();
+    while !echo0.peers().contains(&peer1_id) {
+        tokio::task::yield_now().await;
+    }
+    assert!(echo0.send(peer1_id, BytesMut::from(&[0u8, 42][..])));
+
+    let (peer_id, request) = from_peer1.recv().await.unwrap();
+    assert_eq!(peer_id, *handle.peers()[0].peer_id());
+    assert_eq!(&request[..], &[0, 42]);
+
+    let (peer_id, response) = from_peer0.recv().await.unwrap();
+    assert_eq!(peer_id, peer1_id);
+    assert_eq!(&response[..], &[1, 42]);
+}
@@ -35,9 +35,10 @@ async fn test_session_established_with_highest_version() {
                 assert_eq!(handle1.peer_id(), &peer_id);
             }
             NetworkEvent::ActivePeerSession { info, .. } => {
-                let SessionInfo { peer_id, status, .. } = info;
+                let SessionInfo { peer_id, status, version, .. } = info;
                 assert_eq!(handle1.peer_id(), &peer_id);
-                assert_eq!(status.version, EthVersion::Eth68);
+                assert_eq!(version, EthVersion::LATEST);
+                assert_eq!(status.version, version);
             }
             ev => {
                 panic!("unexpected event {ev:?}")
@@ -86,3 +87,43 @@ async fn test_session_established_with_different_capability() {
 
     handle.terminate().await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_session_falls_back_to_eth68() {
+    reth_tracing::init_test_tracing();
+
+    let mut net = Testnet::create(1).await;
+
+    let p1 = PeerConfig::with_protocols(NoopProvider::default(), Some(EthVersion::Eth68.into()));
+    net.add_peer_with_config(p1).await.unwrap();
+
+    net.for_each(|peer| assert_eq!(0, peer.num_peers()));
+
+    let mut handles = net.handles();
+    let handle0 = handles.next().unwrap();
+    let handle1 = handles.next().unwrap();
+    drop(handles);
+
+    let handle = net.spawn();
+
+    let mut events = handle0.event_listener().take(2);
+    handle0.add_peer(*handle1.peer_id(), handle1.local_addr());
+
+    while let Some(event) = events.next().await {
+        match event {
+            NetworkEvent::Peer(PeerEvent::PeerAdded(peer_id)) => {
+                assert_eq!(handle1.peer_id(), &peer_id);
+            }
+            NetworkEvent::ActivePeerSession { info, .. } => {
+                let SessionInfo { peer_id, status, .. } = info;
+                assert_eq!(handle1.peer_id(), &peer_id);
+                assert_eq!(status.version, EthVersion::Eth68);
+            }
+            ev => {
+                panic!("unexpected event: {ev:?}")
+            }
+        }
+    }
+
+    handle.terminate().await;
+}
@@ -0,0 +1,120 @@
+//! Tests for the network simulation harness.
+
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+use reth_eth_wire::HeadersDirection;
+use reth_network::{
+    test_utils::{NetworkEventStream, Simulation},
+    BlockDownloaderProvider, NetworkEventListenerProvider,
+};
+use reth_network_api::{Peers, PeersInfo};
+use reth_network_p2p::headers::client::{HeadersClient, HeadersRequest};
+use reth_provider::test_utils::MockEthProvider;
+use reth_storage_api::noop::NoopProvider;
+use tokio::time::Instant;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_simulation_is_deterministic() {
+    reth_tracing::init_test_tracing();
+
+    async fn run(seed: u64) -> (Vec<String>, Vec<String>) {
+        let mut sim = Simulation::new(seed);
+        for _ in 0..2 {
+            sim.add_node(NoopProvider::default()).await;
+        }
+        let mut sim = sim.spawn();
+        let peer_ids = (0..2).map(|node| sim.peer_id(node).to_string()).collect();
+        let blocks =
+            sim.produce_blocks(0, 5).iter().map(|block| block.hash().to_string()).collect();
+        (peer_ids, blocks)
+    }
+
+    assert_eq!(run(1).await, run(1).await);
+    assert_ne!(run(1).await, run(2).await);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_simulation_fetch_headers_with_latency() {
+    reth_tracing::init_test_tracing();
+
+    let providers =
+        vec![Arc::new(MockEthProvider::default()), Arc::new(MockEthProvider::default())];
+    let mut sim = Simulation::new(1).on_block({
+        let providers = providers.clone();
+        move |node, block| providers[node].add_block(block.hash(), block.clone().unseal())
+    });
+    for provider in &providers {
+        sim.add_node(provider.clone()).await;
+    }
+    sim.for_each_node_mut(|node| node.install_request_handler());
+    let mut sim = sim.spawn();
+
+    sim.connect(0, 1).await;
+    let blocks = sim.produce_blocks(1, 10);
+    assert_eq!(sim.head(1).number, 10);
+
+    let latency = Duration::from_millis(100);
+    sim.set_latency(0, 1, latency);
+
+    let fetch = sim.node(0).network().fetch_client().await.unwrap();
+    let request = HeadersRequest {
+        start: blocks.last().unwrap().hash().into(),
+        limit: 10,
+        direction: HeadersDirection::Falling,
+    };
+    let start = Instant::now();
+    let headers = fetch.get_headers(request).await.unwrap().1;
+
+    // the request and the response are both delayed
+    assert!(start.elapsed() >= 2 * latency);
+    let expected =
+        blocks.iter().rev().map(|block| block.header.header().clone()).collect::<Vec<_>>();
+    assert_eq!(headers, expected);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_simulation_partition() {
+    reth_tracing::init_test_tracing();
+
+    let mut sim = Simulation::new(1);
+    for _ in 0..3 {
+        sim.add_node(NoopProvider::default()).await;
+    }
+    let sim = sim.spawn();
+    sim.connect_all().await;
+
+    let mut events0 = NetworkEventStream::new(sim.node(0).event_listener());
+    let mut events1 = NetworkEventStream::new(sim.node(1).event_listener());
+    sim.partition(&[&[0], &[1, 2]]);
+
+    // node 0 is cut off from all other nodes
+    let mut closed = HashSet::new();
+    for _ in 0..2 {
+        closed.insert(events0.next_session_closed().await.unwrap().0);
+    }
+    assert_eq!(closed, HashSet::from([sim.peer_id(1), sim.peer_id(2)]));
+
+    // while the nodes in the other group stay connected
+    assert_eq!(events1.next_session_closed().await.unwrap().0, sim.peer_id(0));
+    assert_eq!(sim.node(1).network().num_connected_peers(), 1);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_simulation_heal() {
+    reth_tracing::init_test_tracing();
+
+    let mut sim = Simulation::new(1);
+    for _ in 0..2 {
+        sim.add_node(NoopProvider::default()).await;
+    }
+    let sim = sim.spawn();
+    sim.partition(&[&[0], &[1]]);
+
+    // nodes in different groups can't connect
+    let connect = tokio::time::timeout(Duration::from_secs(1), sim.connect(0, 1)).await;
+    assert!(connect.is_err());
+
+    sim.heal();
+    sim.connect(1, 0).await;
+    assert_eq!(sim.node(0).network().num_connected_peers(), 1);
+}
@@ -136,7 +136,13 @@ async fn test_sending_invalid_transactions() {
         peer0.network().send_transactions(*peer1.peer_id(), vec![Arc::new(tx)]);
     }
 
-    // await disconnect for bad tx spam
+    // await disconnect for bad tx spam, which is preceded by reputation changes of the spammer
+    let mut peer1_events = peer1_events.filter(|ev| {
+        futures::future::ready(!matches!(
+            ev,
+            NetworkEvent::Peer(PeerEvent::ReputationChanged { .. })
+        ))
+    });
     if let Some(ev) = peer1_events.next().await {
         match ev {
             NetworkEvent::Peer(PeerEvent::SessionClosed { peer_id, .. }) => {
@@ -152,6 +158,7 @@ async fn test_sending_invalid_transactions() {
             NetworkEvent::Peer(PeerEvent::PeerRemoved(_)) => {
                 panic!("unexpected PeerRemoved event")
             }
+            NetworkEvent::Peer(PeerEvent::ReputationChanged { .. }) => unreachable!(),
         }
     }
 
@@ -5,11 +5,14 @@ use alloy_rpc_types_engine::JwtSecret;
 use reth_beacon_consensus::BeaconConsensusEngineHandle;
 use reth_consensus::FullConsensus;
 use reth_evm::execute::BlockExecutorProvider;
+use reth_exex_types::SharedExExStatus;
+use reth_memory_budget::MemoryBudget;
 use reth_network_api::FullNetwork;
 use reth_node_core::node_config::NodeConfig;
 use reth_node_types::{HeaderTy, NodeTypes, NodeTypesWithDB, NodeTypesWithEngine, TxTy};
 use reth_payload_builder_primitives::PayloadBuilder;
 use reth_provider::FullProvider;
+use reth_prune_types::{BlockPins, NodeLoad};
 use reth_tasks::TaskExecutor;
 use reth_transaction_pool::{PoolTransaction, TransactionPool};
 use std::{future::Future, marker::PhantomData};
@@ -101,6 +104,14 @@ pub struct AddOnsContext<'a, N: FullNodeComponents> {
         BeaconConsensusEngineHandle<<N::Types as NodeTypesWithEngine>::Engine>,
     /// JWT secret for the node.
     pub jwt_secret: JwtSecret,
+    /// Blocks pinned via RPC, which are protected from pruning.
+    pub block_pins: BlockPins,
+    /// Load of the node, used to throttle the pruner.
+    pub node_load: NodeLoad,
+    /// The status of the installed `ExEx`'s.
+    pub exex_status: SharedExExStatus,
+    /// The memory budget shared by the caches of the node.
+    pub memory_budget: MemoryBudget,
 }
 
 /// Customizable node add-on types.
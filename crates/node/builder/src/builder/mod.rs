@@ -18,6 +18,7 @@ use reth_db_api::{
     database_metrics::{DatabaseMetadata, DatabaseMetrics},
 };
 use reth_exex::ExExContext;
+use reth_memory_budget::MemoryBudget;
 use reth_network::{
     transactions::TransactionsManagerConfig, NetworkBuilder, NetworkConfig, NetworkConfigBuilder,
     NetworkHandle, NetworkManager,
@@ -36,6 +37,7 @@ use reth_provider::{
     providers::{BlockchainProvider, NodeTypesForProvider},
     BlockReader, ChainSpecProvider, FullProvider,
 };
+use reth_prune::PruneModes;
 use reth_tasks::TaskExecutor;
 use reth_transaction_pool::{PoolConfig, PoolTransaction, TransactionPool};
 use revm_primitives::EnvKzgSettings;
@@ -612,6 +614,11 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         &self.config_container.toml_config
     }
 
+    /// Returns the memory budget shared by the caches of the node.
+    pub const fn memory_budget(&self) -> &MemoryBudget {
+        &self.config_container.memory_budget
+    }
+
     /// Returns the executor of the node.
     ///
     /// This can be used to execute async tasks or functions during the setup.
@@ -629,9 +636,26 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
         self.config().dev.dev
     }
 
-    /// Returns the transaction pool config of the node.
+    /// Returns the transaction pool config of the node, registered with the memory budget of the
+    /// node.
     pub fn pool_config(&self) -> PoolConfig {
-        self.config().txpool.pool_config()
+        PoolConfig {
+            memory_budget: self.memory_budget().clone(),
+            ..self.config().txpool.pool_config()
+        }
+    }
+
+    /// Returns the configured [`PruneModes`], any configuration set in CLI takes precedence over
+    /// the one set in toml.
+    pub fn prune_modes(&self) -> PruneModes {
+        let prune_config = match self.config().prune_config() {
+            Some(mut prune_config) => {
+                prune_config.merge(self.reth_config().prune.clone());
+                Some(prune_config)
+            }
+            None => self.reth_config().prune.clone(),
+        };
+        prune_config.map(|config| config.segments).unwrap_or_default()
     }
 
     /// Loads `EnvKzgSettings::Default`.
@@ -696,6 +720,7 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
             .transactions(pool, tx_config)
             .request_handler(self.provider().clone())
             .split_with_handle();
+        let eth = eth.with_prune_modes(self.prune_modes());
 
         self.executor.spawn_critical("p2p txpool", txpool);
         self.executor.spawn_critical("p2p eth request handler", eth);
@@ -1,6 +1,6 @@
 //! Helper types that can be used by launchers.
 
-use std::{sync::Arc, thread::available_parallelism};
+use std::{sync::Arc, thread::available_parallelism, time::Duration};
 
 use crate::{
     components::{NodeComponents, NodeComponentsBuilder},
@@ -21,6 +21,7 @@ use reth_engine_tree::tree::{InvalidBlockHook, InvalidBlockHooks, NoopInvalidBlo
 use reth_evm::noop::NoopBlockExecutorProvider;
 use reth_fs_util as fs;
 use reth_invalid_block_hooks::InvalidBlockWitnessHook;
+use reth_memory_budget::{MemoryBudget, DEFAULT_REBALANCE_INTERVAL};
 use reth_network_p2p::headers::client::HeadersClient;
 use reth_node_api::{
     FullNodePrimitives, FullNodeTypes, NodePrimitives, NodeTypes, NodeTypesWithDB,
@@ -39,6 +40,7 @@ use reth_node_metrics::{
     hooks::Hooks,
     recorder::install_prometheus_recorder,
     server::{MetricServer, MetricServerConfig},
+    snapshot::MetricsSnapshotter,
     version::VersionInfo,
 };
 use reth_primitives::{Head, TransactionSigned};
@@ -92,7 +94,11 @@ impl LaunchContext {
         config: NodeConfig<ChainSpec>,
     ) -> eyre::Result<LaunchContextWith<WithConfigs<ChainSpec>>> {
         let toml_config = self.load_toml_config(&config)?;
-        Ok(self.with(WithConfigs { config, toml_config }))
+        let memory_budget = config
+            .memory_budget
+            .map(|budget_mib| MemoryBudget::new((budget_mib as usize).saturating_mul(1024 * 1024)))
+            .unwrap_or_default();
+        Ok(self.with(WithConfigs { config, toml_config, memory_budget }))
     }
 
     /// Loads the reth config with the configured `data_dir` and overrides settings according to the
@@ -285,11 +291,27 @@ impl<R, ChainSpec: EthChainSpec> LaunchContextWith<Attached<WithConfigs<ChainSpe
         self
     }
 
+    /// Spawns the task that rebalances the memory budget shared by the caches of the node, if a
+    /// budget is configured.
+    pub fn with_memory_budget_task(self) -> Self {
+        let budget = self.memory_budget().clone();
+        if let Some(max_bytes) = budget.max_bytes() {
+            info!(target: "reth::cli", budget_mib = max_bytes / (1024 * 1024), "Configured memory budget");
+            self.task_executor().spawn(budget.run(DEFAULT_REBALANCE_INTERVAL));
+        }
+        self
+    }
+
     /// Returns the container for all config types
     pub const fn configs(&self) -> &WithConfigs<ChainSpec> {
         self.attachment.left()
     }
 
+    /// Returns the memory budget shared by the caches of the node.
+    pub const fn memory_budget(&self) -> &MemoryBudget {
+        &self.left().memory_budget
+    }
+
     /// Returns the attached [`NodeConfig`].
     pub const fn node_config(&self) -> &NodeConfig<ChainSpec> {
         &self.left().config
@@ -396,6 +418,10 @@ where
             StaticFileProvider::read_write(self.data_dir().static_files())?,
         )
         .with_prune_modes(self.prune_modes())
+        .with_touch_epoch_length(self.toml_config().stages.execution.touch_epoch_length)
+        .with_max_unindexed_history_blocks(
+            self.toml_config().stages.execution.max_unindexed_history_blocks,
+        )
         .with_static_files_metrics();
 
         let has_receipt_pruning =
@@ -499,11 +525,39 @@ where
         Ok(self)
     }
 
-    /// Starts the prometheus endpoint.
+    /// Starts the prometheus endpoint and the persisted snapshots of key metrics.
     pub async fn start_prometheus_endpoint(&self) -> eyre::Result<()> {
         // ensure recorder runs upkeep periodically
         install_prometheus_recorder().spawn_upkeep();
 
+        let hooks = Hooks::builder()
+            .with_hook({
+                let db = self.database().clone();
+                move || db.report_metrics()
+            })
+            .with_hook({
+                let sfp = self.static_file_provider();
+                move || {
+                    if let Err(error) = sfp.report_metrics() {
+                        error!(%error, "Failed to report metrics for the static file provider");
+                    }
+                }
+            })
+            .build();
+
+        let snapshot_interval = self.node_config().metrics_snapshot_interval;
+        if snapshot_interval > 0 {
+            let path = self.data_dir().metrics_snapshot();
+            info!(target: "reth::cli", path = %path.display(), "Persisting metrics snapshots");
+            MetricsSnapshotter::new(
+                path,
+                Duration::from_secs(snapshot_interval),
+                CARGO_PKG_VERSION,
+                hooks.clone(),
+            )
+            .spawn(self.task_executor());
+        }
+
         let listen_addr = self.node_config().metrics;
         if let Some(addr) = listen_addr {
             info!(target: "reth::cli", "Starting metrics endpoint at {}", addr);
@@ -519,20 +573,7 @@ where
                 },
                 ChainSpecInfo { name: self.left().config.chain.chain().to_string() },
                 self.task_executor().clone(),
-                Hooks::builder()
-                    .with_hook({
-                        let db = self.database().clone();
-                        move || db.report_metrics()
-                    })
-                    .with_hook({
-                        let sfp = self.static_file_provider();
-                        move || {
-                            if let Err(error) = sfp.report_metrics() {
-                                error!(%error, "Failed to report metrics for the static file provider");
-                            }
-                        }
-                    })
-                    .build(),
+                hooks,
             );
 
             MetricServer::new(config).serve().await?;
@@ -1000,6 +1041,8 @@ pub struct WithConfigs<ChainSpec> {
     pub config: NodeConfig<ChainSpec>,
     /// The loaded reth.toml config.
     pub toml_config: reth_config::Config,
+    /// The memory budget shared by the caches of the node, see [`NodeConfig::memory_budget`].
+    pub memory_budget: MemoryBudget,
 }
 
 /// Helper container type to bundle the [`ProviderFactory`] and the metrics
@@ -1062,6 +1105,7 @@ mod tests {
                     transaction_lookup_full: false,
                     transaction_lookup_distance: None,
                     transaction_lookup_before: None,
+                    transaction_lookup_static_file_index: false,
                     receipts_full: false,
                     receipts_distance: None,
                     receipts_before: None,
@@ -1072,6 +1116,11 @@ mod tests {
                     storage_history_distance: None,
                     storage_history_before: None,
                     receipts_log_filter: vec![],
+                    retained_addresses: vec![],
+                    throttle: false,
+                    throttle_max_block_latency: None,
+                    throttle_max_rpc_queue_depth: None,
+                    throttle_max_deferred_blocks: None,
                 },
                 ..NodeConfig::test()
             };
@@ -3,7 +3,7 @@
 use futures::{future::Either, stream, stream_select, StreamExt};
 use reth_beacon_consensus::{
     hooks::{EngineHooks, StaticFileHook},
-    BeaconConsensusEngineHandle,
+    BeaconConsensusEngineEvent, BeaconConsensusEngineHandle,
 };
 use reth_chainspec::EthChainSpec;
 use reth_consensus_debug_client::{DebugConsensusClient, EtherscanBlockProvider};
@@ -14,7 +14,7 @@ use reth_engine_tree::{
     persistence::PersistenceNodeTypes,
     tree::TreeConfig,
 };
-use reth_engine_util::EngineMessageStreamExt;
+use reth_engine_util::{warm_state::warm_state_task, EngineMessageStreamExt};
 use reth_exex::ExExManagerHandle;
 use reth_network::{NetworkSyncUpdater, SyncState};
 use reth_network_api::BlockDownloaderProvider;
@@ -29,7 +29,11 @@ use reth_node_core::{
 };
 use reth_node_events::{cl::ConsensusLayerHealthEvents, node};
 use reth_primitives::{EthPrimitives, EthereumHardforks};
-use reth_provider::providers::{BlockchainProvider2, ProviderNodeTypes};
+use reth_provider::{
+    providers::{BlockchainProvider2, ProviderNodeTypes},
+    CanonStateSubscriptions,
+};
+use reth_prune::{BlockPins, NodeLoad};
 use reth_tasks::TaskExecutor;
 use reth_tokio_util::EventSender;
 use reth_tracing::tracing::{debug, error, info};
@@ -113,6 +117,8 @@ where
             .attach(database.clone())
             // ensure certain settings take effect
             .with_adjusted_configs()
+            // rebalance the memory budget that is shared by the caches
+            .with_memory_budget_task()
             // Create the provider factory
             .with_provider_factory().await?
             .inspect(|_| {
@@ -192,6 +198,7 @@ where
             static_file_producer,
             ctx.components().block_executor().clone(),
             pipeline_exex_handle,
+            ctx.memory_budget().clone(),
         )?;
 
         // The new engine writes directly to static files. This ensures that they're up to the tip.
@@ -199,7 +206,10 @@ where
 
         let pipeline_events = pipeline.events();
 
-        let mut pruner_builder = ctx.pruner_builder();
+        let block_pins = BlockPins::default();
+        let node_load = NodeLoad::default();
+        let mut pruner_builder =
+            ctx.pruner_builder().block_pins(block_pins.clone()).node_load(node_load.clone());
         if let Some(exex_manager_handle) = &exex_manager_handle {
             pruner_builder =
                 pruner_builder.finished_exex_height(exex_manager_handle.finished_height());
@@ -220,9 +230,36 @@ where
             config: ctx.node_config(),
             beacon_engine_handle: beacon_engine_handle.clone(),
             jwt_secret,
+            block_pins,
+            node_load: node_load.clone(),
+            exex_status: exex_manager_handle
+                .as_ref()
+                .map(ExExManagerHandle::status)
+                .unwrap_or_default(),
+            memory_budget: ctx.memory_budget().clone(),
         };
         let engine_payload_validator = add_ons.engine_validator(&add_ons_ctx).await?;
 
+        let max_warm_state_keys = engine_tree_config.max_warm_state_keys();
+        if max_warm_state_keys > 0 {
+            let provider = ctx.blockchain_db().clone();
+            let canon_state_stream = provider.canonical_state_stream();
+            let path = ctx.data_dir().warm_state_keys();
+            ctx.task_executor().spawn_critical_with_graceful_shutdown_signal(
+                "warm state task",
+                |shutdown| {
+                    warm_state_task(
+                        shutdown,
+                        provider,
+                        canon_state_stream,
+                        path,
+                        max_warm_state_keys,
+                    )
+                },
+            );
+        }
+
+        let engine_tree_config = engine_tree_config.with_memory_budget(ctx.memory_budget().clone());
         let mut engine_service = if ctx.is_dev() {
             let eth_service = LocalEngineService::new(
                 consensus.clone(),
@@ -232,6 +269,7 @@ where
                 pruner,
                 ctx.components().payload_builder().clone(),
                 engine_payload_validator,
+                Box::new(ctx.task_executor().clone()),
                 engine_tree_config,
                 ctx.invalid_block_hook()?,
                 ctx.sync_metrics_tx(),
@@ -392,6 +430,11 @@ where
                                     };
                                     network_handle.update_status(head_block);
                                 }
+                                if let BeaconConsensusEngineEvent::CanonicalBlockAdded(_, elapsed) |
+                                BeaconConsensusEngineEvent::ForkBlockAdded(_, elapsed) = &ev
+                                {
+                                    node_load.record_block_processing(*elapsed);
+                                }
                                 event_sender.notify(ev);
                             }
                         }
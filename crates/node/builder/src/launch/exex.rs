@@ -6,10 +6,18 @@ use futures::future;
 use reth_chain_state::ForkChoiceSubscriptions;
 use reth_chainspec::EthChainSpec;
 use reth_exex::{
-    ExExContext, ExExHandle, ExExManager, ExExManagerHandle, ExExNotificationSource, Wal,
+    ExExContext, ExExHandle, ExExHead, ExExManager, ExExManagerHandle, ExExNotificationSource, Wal,
     DEFAULT_EXEX_MANAGER_CAPACITY,
 };
+use reth_exex_sinks::{
+    read_cursor, run_sink, ChainEventPublisher, EventDetail, EventEncoding, KafkaTransport,
+    NatsTransport,
+};
 use reth_node_api::{FullNodeComponents, NodeTypes};
+use reth_node_core::{
+    args::{ChainEventDetail, ChainEventEncoding, EventsArgs},
+    node_config::NodeConfig,
+};
 use reth_primitives::{EthPrimitives, Head};
 use reth_provider::CanonStateSubscriptions;
 use reth_tracing::tracing::{debug, info};
@@ -43,7 +51,11 @@ impl<Node: FullNodeComponents<Types: NodeTypes<Primitives = EthPrimitives>> + Cl
     /// Spawns all extensions and returns the handle to the exex manager if any extensions are
     /// installed.
     pub async fn launch(self) -> eyre::Result<Option<ExExManagerHandle>> {
-        let Self { head, extensions, components, config_container } = self;
+        let Self { head, mut extensions, components, config_container } = self;
+
+        if let Some(publisher) = events_publisher(&config_container.config) {
+            extensions.push(("events-publisher".to_string(), publisher));
+        }
 
         if extensions.is_empty() {
             // nothing to launch
@@ -144,6 +156,77 @@ impl<Node: FullNodeComponents<Types: NodeTypes<Primitives = EthPrimitives>> + Cl
     }
 }
 
+/// The file in the data directory that stores the resume cursor of the events publisher.
+const EVENTS_PUBLISHER_CURSOR: &str = "events-publisher-cursor.json";
+
+/// The system that the events publisher publishes to.
+enum EventsTarget {
+    /// A NATS server with the given URL.
+    Nats(String),
+    /// A partition of the topic on the Kafka broker with the given address.
+    Kafka { broker: String, partition: i32 },
+}
+
+/// Returns the built-in `ExEx` that publishes canonical chain events, if it is configured.
+fn events_publisher<Node>(
+    config: &NodeConfig<<Node::Types as NodeTypes>::ChainSpec>,
+) -> Option<Box<dyn BoxedLaunchExEx<Node>>>
+where
+    Node: FullNodeComponents<Types: NodeTypes<Primitives = EthPrimitives>>,
+{
+    let EventsArgs { nats_url, kafka_broker, kafka_partition, subject, detail, encoding } =
+        config.events.clone();
+    let target = match (nats_url, kafka_broker) {
+        (Some(url), _) => EventsTarget::Nats(url),
+        (None, Some(broker)) => EventsTarget::Kafka { broker, partition: kafka_partition },
+        (None, None) => return None,
+    };
+    let cursor = config.datadir().data_dir().join(EVENTS_PUBLISHER_CURSOR);
+    let detail = match detail {
+        ChainEventDetail::Headers => EventDetail::Headers,
+        ChainEventDetail::Transactions => EventDetail::Transactions,
+        ChainEventDetail::Receipts => EventDetail::Receipts,
+    };
+    let encoding = match encoding {
+        ChainEventEncoding::Json => EventEncoding::Json,
+        ChainEventEncoding::Protobuf => EventEncoding::Protobuf,
+    };
+
+    Some(Box::new(move |mut ctx: ExExContext<Node>| async move {
+        // resume after the last block that was acknowledged by the server
+        if let Some(block) = read_cursor(&cursor)? {
+            ctx.set_notifications_with_head(ExExHead { block });
+        }
+
+        Ok(match target {
+            EventsTarget::Nats(url) => {
+                info!(target: "reth::cli", %url, %subject, "Publishing canonical chain events");
+                let transport = NatsTransport::connect(&url, subject).await?;
+                let publisher = ChainEventPublisher::new(transport)
+                    .with_detail(detail)
+                    .with_encoding(encoding)
+                    .with_cursor(cursor);
+                future::Either::Left(run_sink(ctx.notifications, ctx.events, publisher))
+            }
+            EventsTarget::Kafka { broker, partition } => {
+                info!(
+                    target: "reth::cli",
+                    %broker,
+                    topic = %subject,
+                    partition,
+                    "Publishing canonical chain events"
+                );
+                let transport = KafkaTransport::connect(&broker, subject, partition).await?;
+                let publisher = ChainEventPublisher::new(transport)
+                    .with_detail(detail)
+                    .with_encoding(encoding)
+                    .with_cursor(cursor);
+                future::Either::Right(run_sink(ctx.notifications, ctx.events, publisher))
+            }
+        })
+    }))
+}
+
 impl<Node: FullNodeComponents> Debug for ExExLauncher<Node> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ExExLauncher")
@@ -14,7 +14,7 @@ use std::{future::Future, sync::Arc};
 use futures::{future::Either, stream, stream_select, StreamExt};
 use reth_beacon_consensus::{
     hooks::{EngineHooks, PruneHook, StaticFileHook},
-    BeaconConsensusEngine,
+    BeaconConsensusEngine, BeaconConsensusEngineEvent,
 };
 use reth_blockchain_tree::{
     externals::TreeNodeTypes, noop::NoopBlockchainTree, BlockchainTree, BlockchainTreeConfig,
@@ -32,6 +32,7 @@ use reth_node_core::{
 };
 use reth_node_events::{cl::ConsensusLayerHealthEvents, node};
 use reth_provider::providers::{BlockchainProvider, ProviderNodeTypes};
+use reth_prune::{BlockPins, NodeLoad};
 use reth_rpc::eth::RpcNodeCore;
 use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::{debug, info};
@@ -145,6 +146,8 @@ where
             .attach(database.clone())
             // ensure certain settings take effect
             .with_adjusted_configs()
+            // rebalance the memory budget that is shared by the caches
+            .with_memory_budget_task()
             // Create the provider factory
             .with_provider_factory().await?
             .inspect(|_| {
@@ -173,6 +176,7 @@ where
             consensus.clone(),
             ctx.components().block_executor().clone(),
         );
+        let tree_config = tree_config.with_memory_budget(ctx.memory_budget().clone());
         let tree = BlockchainTree::new(tree_externals, tree_config)?
             .with_sync_metrics_tx(ctx.sync_metrics_tx())
             // Note: This is required because we need to ensure that both the components and the
@@ -245,6 +249,7 @@ where
                 static_file_producer,
                 ctx.components().block_executor().clone(),
                 pipeline_exex_handle,
+                ctx.memory_budget().clone(),
             )?;
 
             (pipeline, network_client.clone())
@@ -254,7 +259,10 @@ where
 
         let initial_target = ctx.node_config().debug.tip;
 
-        let mut pruner_builder = ctx.pruner_builder();
+        let block_pins = BlockPins::default();
+        let node_load = NodeLoad::default();
+        let mut pruner_builder =
+            ctx.pruner_builder().block_pins(block_pins.clone()).node_load(node_load.clone());
         if let Some(exex_manager_handle) = &exex_manager_handle {
             pruner_builder =
                 pruner_builder.finished_exex_height(exex_manager_handle.finished_height());
@@ -282,6 +290,19 @@ where
         )?;
         info!(target: "reth::cli", "Consensus engine initialized");
 
+        // record the block processing latency for the pruner throttle
+        let mut engine_events = beacon_engine_handle.event_listener();
+        let engine_node_load = node_load.clone();
+        ctx.task_executor().spawn(async move {
+            while let Some(event) = engine_events.next().await {
+                if let BeaconConsensusEngineEvent::CanonicalBlockAdded(_, elapsed) |
+                BeaconConsensusEngineEvent::ForkBlockAdded(_, elapsed) = event
+                {
+                    engine_node_load.record_block_processing(elapsed);
+                }
+            }
+        });
+
         let events = stream_select!(
             pipeline_events.map(Into::into),
             if ctx.node_config().debug.tip.is_none() && !ctx.is_dev() {
@@ -312,6 +333,13 @@ where
             config: ctx.node_config(),
             beacon_engine_handle,
             jwt_secret,
+            block_pins,
+            node_load,
+            exex_status: exex_manager_handle
+                .as_ref()
+                .map(ExExManagerHandle::status)
+                .unwrap_or_default(),
+            memory_budget: ctx.memory_budget().clone(),
         };
 
         let RpcHandle { rpc_server_handles, rpc_registry } =
@@ -5,10 +5,14 @@ use std::{
     future::Future,
     marker::PhantomData,
     ops::{Deref, DerefMut},
+    sync::Arc,
+    time::Duration,
 };
 
-use alloy_rpc_types::engine::ClientVersionV1;
-use futures::TryFutureExt;
+use alloy_rpc_types::{engine::ClientVersionV1, BlockNumHash};
+use futures::{StreamExt, TryFutureExt};
+use jsonrpsee::server::RpcServiceBuilder;
+use reth_chainspec::EthChainSpec;
 use reth_node_api::{
     AddOnsContext, EngineValidator, FullNodeComponents, NodeAddOns, NodePrimitives, NodeTypes,
     NodeTypesWithEngine,
@@ -19,18 +23,32 @@ use reth_node_core::{
 };
 use reth_payload_builder::PayloadStore;
 use reth_primitives::EthPrimitives;
-use reth_provider::providers::ProviderNodeTypes;
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockNumReader, CanonStateSubscriptions, DBProvider,
+    DatabaseProviderFactory,
+};
 use reth_rpc::{
     eth::{EthApiTypes, FullEthApiServer},
-    EthApi,
+    BlockPinApi, ConfigurableTracer, EthApi, ExExApi, NativeTracers,
+};
+use reth_rpc_api::{
+    eth::helpers::AddDevSigners, BlockPinApiServer, ExExApiServer, RpcUsageApiServer,
 };
-use reth_rpc_api::eth::helpers::AddDevSigners;
 use reth_rpc_builder::{
     auth::{AuthRpcModule, AuthServerHandle},
     config::RethRpcServerConfig,
-    RpcModuleBuilder, RpcRegistryInner, RpcServerHandle, TransportRpcModules,
+    constants,
+    fast_path::{FastPathCache, FastPathLayer},
+    finality::FinalityGuardLayer,
+    load::NodeLoadLayer,
+    pinned::PinnedBlockLayer,
+    snapshot::BatchSnapshotLayer,
+    trace_scheduler::TraceSchedulerLayer,
+    RethRpcModule, RpcModuleBuilder, RpcRegistryInner, RpcServerHandle, TransportRpcModules,
+};
+use reth_rpc_engine_api::{
+    capabilities::EngineCapabilities, EngineApi, ProposerApi, ProposerApiServer,
 };
-use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
 use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::{debug, info};
 
@@ -339,6 +357,8 @@ pub struct RpcAddOns<Node: FullNodeComponents, EthApi: EthApiTypes, EV> {
     eth_api_builder: Box<dyn FnOnce(&EthApiBuilderCtx<Node>) -> EthApi + Send + Sync>,
     /// Engine validator
     engine_validator_builder: EV,
+    /// Native tracers that can be selected in the `debug` tracing methods.
+    tracers: NativeTracers,
     _pd: PhantomData<(Node, EthApi)>,
 }
 
@@ -350,6 +370,7 @@ impl<Node: FullNodeComponents, EthApi: EthApiTypes, EV: Debug> Debug
             .field("hooks", &self.hooks)
             .field("eth_api_builder", &"...")
             .field("engine_validator_builder", &self.engine_validator_builder)
+            .field("tracers", &self.tracers)
             .finish()
     }
 }
@@ -364,10 +385,18 @@ impl<Node: FullNodeComponents, EthApi: EthApiTypes, EV> RpcAddOns<Node, EthApi,
             hooks: RpcHooks::default(),
             eth_api_builder: Box::new(eth_api_builder),
             engine_validator_builder,
+            tracers: NativeTracers::default(),
             _pd: PhantomData,
         }
     }
 
+    /// Registers a native tracer that is selected in the `debug` tracing methods if the `tracer`
+    /// field of the tracing options is set to the given name.
+    pub fn with_tracer(mut self, name: impl Into<String>, tracer: impl ConfigurableTracer) -> Self {
+        self.tracers.register(name, tracer);
+        self
+    }
+
     /// Sets the hook that is run once the rpc server is started.
     pub fn on_rpc_started<F>(mut self, hook: F) -> Self
     where
@@ -428,10 +457,19 @@ where
     where
         F: FnOnce(&mut TransportRpcModules, &mut AuthRpcModule) -> eyre::Result<()>,
     {
-        let Self { eth_api_builder, engine_validator_builder, hooks, _pd: _ } = self;
+        let Self { eth_api_builder, engine_validator_builder, hooks, tracers, _pd: _ } = self;
 
         let engine_validator = engine_validator_builder.build(&ctx).await?;
-        let AddOnsContext { node, config, beacon_engine_handle, jwt_secret } = ctx;
+        let AddOnsContext {
+            node,
+            config,
+            beacon_engine_handle,
+            jwt_secret,
+            block_pins,
+            node_load,
+            exex_status,
+            memory_budget,
+        } = ctx;
 
         let client = ClientVersionV1 {
             code: CLIENT_CODE,
@@ -454,8 +492,20 @@ where
         info!(target: "reth::cli", "Engine API handler initialized");
 
         let auth_config = config.rpc.auth_server_config(jwt_secret)?;
-        let module_config = config.rpc.transport_rpc_module_config();
+        let mut module_config = config.rpc.transport_rpc_module_config();
+        // transactions of an address can only be searched if the node maintains the index
+        let address_index = node
+            .provider()
+            .database_provider_ro()?
+            .prune_modes_ref()
+            .has_address_transactions_index();
+        if let Some(module_config) = module_config.config_mut() {
+            module_config.ots_mut().address_index = address_index;
+        }
         debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
+        let serves_eth = (module_config.http().is_none() ||
+            module_config.contains_http(&RethRpcModule::Eth)) &&
+            (module_config.ws().is_none() || module_config.contains_ws(&RethRpcModule::Eth));
 
         let (mut modules, mut auth_module, registry) = RpcModuleBuilder::default()
             .with_provider(node.provider().clone())
@@ -466,8 +516,58 @@ where
             .with_evm_config(node.evm_config().clone())
             .with_block_executor(node.block_executor().clone())
             .with_consensus(node.consensus().clone())
+            .with_native_tracers(tracers)
+            .with_memory_budget(memory_budget)
             .build_with_auth_server(module_config, engine_api, eth_api_builder);
 
+        auth_module
+            .merge_auth_methods(ProposerApi::new(node.payload_builder().clone()).into_rpc())?;
+        modules.merge_if_module_configured(
+            RethRpcModule::Reth,
+            BlockPinApi::new(node.provider().clone(), block_pins.clone()).into_rpc(),
+        )?;
+        modules.merge_if_module_configured(
+            RethRpcModule::Reth,
+            ExExApi::new(exex_status).into_rpc(),
+        )?;
+        let accounting = config.rpc.rpc_accounting()?;
+        let rate_limits = config.rpc.rpc_rate_limits()?;
+        if let (Some(rate_limits), Some(path)) = (&rate_limits, &config.rpc.rpc_rate_limits) {
+            node.task_executor().spawn(rate_limits.clone().reload_on_change(
+                path.clone(),
+                Duration::from_secs(constants::DEFAULT_RATE_LIMITS_RELOAD_INTERVAL_SECS),
+            ));
+        }
+        if let Some(accounting) = &accounting {
+            modules.merge_if_module_configured(
+                RethRpcModule::Admin,
+                RpcUsageApiServer::into_rpc(accounting.clone()),
+            )?;
+        }
+
+        // if enabled, trivial eth calls are served from a cache that tracks the canonical head, if
+        // all http and ws servers serve the eth namespace, and calls are neither accounted nor rate
+        // limited so that they can't bypass the quotas of a tenant
+        let fast_path =
+            config.rpc.rpc_fast_path && serves_eth && accounting.is_none() && rate_limits.is_none();
+        let fast_path = if fast_path {
+            let chain_info = node.provider().chain_info()?;
+            let cache = Arc::new(FastPathCache::new(
+                config.chain.chain().id(),
+                BlockNumHash::new(chain_info.best_number, chain_info.best_hash),
+            ));
+            let mut canon_state_stream = node.provider().canonical_state_stream();
+            let head_cache = cache.clone();
+            node.task_executor().spawn(async move {
+                while let Some(notification) = canon_state_stream.next().await {
+                    head_cache.set_best_block(notification.tip().num_hash());
+                }
+            });
+            Some(FastPathLayer::new(cache, config.rpc.rpc_max_response_size_bytes() as usize))
+        } else {
+            None
+        };
+
         // in dev mode we generate 20 random dev-signer accounts
         if config.dev.dev {
             registry.eth_api().with_dev_accounts();
@@ -487,7 +587,32 @@ where
         ext(ctx.modules, ctx.auth_module)?;
         extend_rpc_modules.extend_rpc_modules(ctx)?;
 
-        let server_config = config.rpc.rpc_server_config();
+        // tracks in-flight requests for the pruner, serves trivial eth calls, resolves the
+        // `pinned` block tag, pins the `latest` block of batches, rejects stale `safe` and
+        // `finalized` block tags and schedules tracing calls, this is not applied to the IPC server
+        let server_config = config
+            .rpc
+            .rpc_server_config()
+            .set_rpc_middleware(
+                RpcServiceBuilder::new()
+                    .layer(NodeLoadLayer::new(node_load))
+                    .option_layer(fast_path)
+                    .layer(PinnedBlockLayer::new(block_pins))
+                    .option_layer(
+                        config
+                            .rpc
+                            .rpc_batch_snapshot
+                            .then(|| BatchSnapshotLayer::new(node.provider().clone())),
+                    )
+                    .option_layer(config.rpc.rpc_max_finality_staleness.map(|max_staleness| {
+                        FinalityGuardLayer::new(node.provider().clone(), max_staleness)
+                    }))
+                    .option_layer(config.rpc.trace_scheduler_config().map(|trace_scheduler| {
+                        TraceSchedulerLayer::new(node.provider().clone(), trace_scheduler)
+                    })),
+            )
+            .with_accounting(accounting)
+            .with_rate_limits(rate_limits);
         let cloned_modules = modules.clone();
         let launch_rpc = server_config.start(&cloned_modules).map_ok(|handle| {
             if let Some(path) = handle.ipc_endpoint() {
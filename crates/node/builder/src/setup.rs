@@ -11,6 +11,7 @@ use reth_downloaders::{
 };
 use reth_evm::execute::BlockExecutorProvider;
 use reth_exex::ExExManagerHandle;
+use reth_memory_budget::MemoryBudget;
 use reth_network_p2p::{
     bodies::downloader::BodyDownloader, headers::downloader::HeaderDownloader, BlockClient,
 };
@@ -36,6 +37,7 @@ pub fn build_networked_pipeline<N, Client, Executor>(
     static_file_producer: StaticFileProducer<ProviderFactory<N>>,
     executor: Executor,
     exex_manager_handle: ExExManagerHandle<N::Primitives>,
+    memory_budget: MemoryBudget,
 ) -> eyre::Result<Pipeline<N>>
 where
     N: ProviderNodeTypes,
@@ -49,6 +51,7 @@ where
         .into_task_with(task_executor);
 
     let body_downloader = BodiesDownloaderBuilder::new(config.bodies)
+        .with_memory_budget(memory_budget)
         .build(client, Arc::clone(&consensus), provider_factory.clone())
         .into_task_with(task_executor);
 
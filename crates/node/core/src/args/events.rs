@@ -0,0 +1,140 @@
+//! clap [Args](clap::Args) for publishing canonical chain events
+
+use clap::{Args, ValueEnum};
+
+/// Default subject or topic that canonical chain events are published to.
+pub const DEFAULT_EVENTS_SUBJECT: &str = "reth.chain";
+
+/// Parameters for publishing canonical chain events
+#[derive(Debug, Clone, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Events")]
+pub struct EventsArgs {
+    /// URL of the NATS server to publish canonical chain events to, e.g.
+    /// `nats://127.0.0.1:4222`.
+    ///
+    /// If set, the node publishes an event for every committed and reverted block, resuming from
+    /// the last acknowledged block after a restart.
+    #[arg(long = "events.nats-url", value_name = "URL", conflicts_with = "kafka_broker")]
+    pub nats_url: Option<String>,
+
+    /// Comma-separated addresses of the Kafka brokers to publish canonical chain events to, e.g.
+    /// `127.0.0.1:9092,127.0.0.1:9093`.
+    ///
+    /// The events are produced to the leader of the partition, which is looked up through these
+    /// brokers. If set, the node publishes an event for every committed and reverted block,
+    /// resuming from the last acknowledged block after a restart.
+    #[arg(long = "events.kafka-broker", value_name = "ADDR")]
+    pub kafka_broker: Option<String>,
+
+    /// Partition of the Kafka topic to publish canonical chain events to.
+    ///
+    /// All events are published to this partition, so consumers receive them in order.
+    #[arg(long = "events.kafka-partition", value_name = "PARTITION", default_value_t = 0)]
+    pub kafka_partition: i32,
+
+    /// Subject (NATS) or topic (Kafka) to publish canonical chain events to.
+    #[arg(long = "events.subject", value_name = "SUBJECT", default_value = DEFAULT_EVENTS_SUBJECT)]
+    pub subject: String,
+
+    /// Detail of the published events.
+    #[arg(long = "events.detail", value_enum, default_value_t = ChainEventDetail::Headers)]
+    pub detail: ChainEventDetail,
+
+    /// Encoding of the published events.
+    #[arg(long = "events.encoding", value_enum, default_value_t = ChainEventEncoding::Json)]
+    pub encoding: ChainEventEncoding,
+}
+
+impl Default for EventsArgs {
+    fn default() -> Self {
+        Self {
+            nats_url: None,
+            kafka_broker: None,
+            kafka_partition: 0,
+            subject: DEFAULT_EVENTS_SUBJECT.to_string(),
+            detail: ChainEventDetail::Headers,
+            encoding: ChainEventEncoding::Json,
+        }
+    }
+}
+
+/// The detail of published canonical chain events.
+#[derive(Debug, Copy, Clone, ValueEnum, Eq, PartialEq)]
+pub enum ChainEventDetail {
+    /// Block header fields
+    Headers,
+    /// Block header fields and transaction hashes
+    Transactions,
+    /// Block header fields, transaction hashes and receipts
+    Receipts,
+}
+
+/// The encoding of published canonical chain events.
+#[derive(Debug, Copy, Clone, ValueEnum, Eq, PartialEq)]
+pub enum ChainEventEncoding {
+    /// JSON
+    Json,
+    /// Protobuf
+    Protobuf,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_events_args() {
+        let args = CommandParser::<EventsArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, EventsArgs::default());
+
+        let args = CommandParser::<EventsArgs>::parse_from([
+            "reth",
+            "--events.nats-url",
+            "nats://127.0.0.1:4222",
+            "--events.detail",
+            "receipts",
+            "--events.encoding",
+            "protobuf",
+        ])
+        .args;
+        assert_eq!(
+            args,
+            EventsArgs {
+                nats_url: Some("nats://127.0.0.1:4222".to_string()),
+                kafka_broker: None,
+                kafka_partition: 0,
+                subject: DEFAULT_EVENTS_SUBJECT.to_string(),
+                detail: ChainEventDetail::Receipts,
+                encoding: ChainEventEncoding::Protobuf,
+            }
+        );
+
+        let args = CommandParser::<EventsArgs>::parse_from([
+            "reth",
+            "--events.kafka-broker",
+            "127.0.0.1:9092",
+            "--events.kafka-partition",
+            "2",
+        ])
+        .args;
+        assert_eq!(args.kafka_broker, Some("127.0.0.1:9092".to_string()));
+        assert_eq!(args.kafka_partition, 2);
+
+        assert!(CommandParser::<EventsArgs>::try_parse_from([
+            "reth",
+            "--events.nats-url",
+            "nats://127.0.0.1:4222",
+            "--events.kafka-broker",
+            "127.0.0.1:9092",
+        ])
+        .is_err());
+    }
+}
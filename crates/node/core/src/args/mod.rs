@@ -6,7 +6,7 @@ pub use network::{DiscoveryArgs, NetworkArgs};
 
 /// RpcServerArg struct for configuring the RPC
 mod rpc_server;
-pub use rpc_server::RpcServerArgs;
+pub use rpc_server::{IpcEndpointArgs, IpcSocketOwner, MethodResponseSizeArgs, RpcServerArgs};
 
 /// `RpcStateCacheArgs` struct for configuring RPC state cache
 mod rpc_state_cache;
@@ -52,6 +52,10 @@ pub use pruning::PruningArgs;
 mod datadir_args;
 pub use datadir_args::DatadirArgs;
 
+/// EventsArgs for configuring the canonical chain events publisher
+mod events;
+pub use events::{ChainEventDetail, ChainEventEncoding, EventsArgs, DEFAULT_EVENTS_SUBJECT};
+
 /// BenchmarkArgs struct for configuring the benchmark to run
 mod benchmark_args;
 pub use benchmark_args::BenchmarkArgs;
@@ -6,7 +6,7 @@ use std::{
     path::PathBuf,
 };
 
-use clap::Args;
+use clap::{builder::RangedU64ValueParser, Args};
 use reth_chainspec::EthChainSpec;
 use reth_config::Config;
 use reth_discv4::{NodeRecord, DEFAULT_DISCOVERY_ADDR, DEFAULT_DISCOVERY_PORT};
@@ -23,14 +23,17 @@ use reth_network::{
                 DEFAULT_MAX_COUNT_CONCURRENT_REQUESTS_PER_PEER,
             },
             tx_manager::{
-                DEFAULT_MAX_COUNT_PENDING_POOL_IMPORTS, DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
+                DEFAULT_MAX_COUNT_PENDING_POOL_IMPORTS, DEFAULT_MAX_COUNT_PROPAGATION_TRACES,
+                DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
             },
         },
-        TransactionFetcherConfig, TransactionsManagerConfig,
+        TransactionFetcherConfig, TransactionGossipConfig, TransactionPropagationMode,
+        TransactionsManagerConfig,
         DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
         SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE,
     },
-    HelloMessageWithProtocols, NetworkConfigBuilder, SessionsConfig,
+    BandwidthLimits, CaptureConfig, HelloMessageWithProtocols, NetworkConfigBuilder, ProxyConfig,
+    SessionsConfig,
 };
 use reth_network_peers::{mainnet_nodes, TrustedPeer};
 use secp256k1::SecretKey;
@@ -91,6 +94,11 @@ pub struct NetworkArgs {
     #[arg(long, default_value = "any")]
     pub nat: NatResolver,
 
+    /// Map the listening and discovery ports on the router via `UPnP` or NAT-PMP and announce the
+    /// router's external address, so that the node is dialable behind a home router.
+    #[arg(long = "nat.port-mapping")]
+    pub nat_port_mapping: bool,
+
     /// Network listening address
     #[arg(long = "addr", value_name = "ADDR", default_value_t = DEFAULT_DISCOVERY_ADDR)]
     pub addr: IpAddr,
@@ -149,6 +157,74 @@ pub struct NetworkArgs {
     #[arg(long = "max-tx-pending-fetch", value_name = "COUNT", default_value_t = DEFAULT_MAX_CAPACITY_CACHE_PENDING_FETCH, verbatim_doc_comment)]
     pub max_capacity_cache_txns_pending_fetch: u32,
 
+    /// How many peers new pending transactions are broadcast to in full. All other peers are only
+    /// sent the transaction hashes.
+    ///
+    /// Accepts `sqrt` (square root of connected peers), `all`, a percentage of connected peers
+    /// (e.g. `25%`) or a max number of peers (e.g. `8`).
+    #[arg(long = "tx-propagation-mode", value_name = "MODE", default_value_t = TransactionPropagationMode::Sqrt, verbatim_doc_comment)]
+    pub tx_propagation_mode: TransactionPropagationMode,
+
+    /// Max number of local transactions to keep a propagation trace for, recording which peers a
+    /// transaction was sent to and when. Tracing is disabled if set to 0.
+    #[arg(long = "max-tx-propagation-traces", value_name = "COUNT", default_value_t = DEFAULT_MAX_COUNT_PROPAGATION_TRACES, verbatim_doc_comment)]
+    pub max_tx_propagation_traces: u32,
+
+    /// Don't propagate transactions with a larger encoded size, in bytes.
+    #[arg(long = "tx-gossip.max-size", value_name = "BYTES")]
+    pub tx_gossip_max_size: Option<usize>,
+
+    /// Don't propagate transactions of the given types, e.g. `3` for blob transactions.
+    #[arg(long = "tx-gossip.suppress-types", value_name = "TYPES", value_delimiter = ',')]
+    pub tx_gossip_suppress_types: Vec<u8>,
+
+    /// Only announce the hashes of transactions with a larger encoded size, in bytes.
+    #[arg(long = "tx-gossip.hashes-only-size", value_name = "BYTES")]
+    pub tx_gossip_hashes_only_size: Option<usize>,
+
+    /// Only announce the hashes of transactions of the given types.
+    #[arg(long = "tx-gossip.hashes-only-types", value_name = "TYPES", value_delimiter = ',')]
+    pub tx_gossip_hashes_only_types: Vec<u8>,
+
+    /// Maximum rate at which full transactions are sent to a single peer, in bytes per second.
+    /// Transactions that exceed the rate are announced as hashes.
+    #[arg(long = "tx-gossip.max-peer-rate", value_name = "BYTES_PER_SEC")]
+    pub tx_gossip_max_peer_rate: Option<usize>,
+
+    /// Maximum rate at which bytes are received from all peers, in bytes per second.
+    #[arg(long = "max-ingress-rate", value_name = "BYTES_PER_SEC", value_parser = RangedU64ValueParser::<u64>::new().range(1..))]
+    pub max_ingress_rate: Option<u64>,
+
+    /// Maximum rate at which bytes are sent to all peers, in bytes per second.
+    #[arg(long = "max-egress-rate", value_name = "BYTES_PER_SEC", value_parser = RangedU64ValueParser::<u64>::new().range(1..))]
+    pub max_egress_rate: Option<u64>,
+
+    /// Maximum rate at which bytes are received from a single peer, in bytes per second.
+    #[arg(long = "max-ingress-rate-peer", value_name = "BYTES_PER_SEC", value_parser = RangedU64ValueParser::<u64>::new().range(1..))]
+    pub max_ingress_rate_per_peer: Option<u64>,
+
+    /// Maximum rate at which bytes are sent to a single peer, in bytes per second.
+    #[arg(long = "max-egress-rate-peer", value_name = "BYTES_PER_SEC", value_parser = RangedU64ValueParser::<u64>::new().range(1..))]
+    pub max_egress_rate_per_peer: Option<u64>,
+
+    /// Captures all `RLPx` messages sent to and received from peers to the given file.
+    ///
+    /// This is a debug mode to reproduce protocol issues offline. The file is rotated once it
+    /// exceeds 256MB, keeping the 10 most recent files.
+    #[arg(long = "network.capture", value_name = "PATH")]
+    pub capture: Option<PathBuf>,
+
+    /// SOCKS5 proxy to establish outgoing peer connections through, e.g. for Tor.
+    ///
+    /// Format: `socks5://[user:password@]host:port`. Only TCP connections are proxied, discovery
+    /// should be disabled if the node's address must not be revealed.
+    #[arg(long = "network.proxy", value_name = "URL")]
+    pub proxy: Option<ProxyConfig>,
+
+    /// Dial peers directly if the connection through the proxy fails.
+    #[arg(long = "network.proxy-fallback", requires = "proxy")]
+    pub proxy_fallback: bool,
+
     /// Name of network interface used to communicate with peers.
     ///
     /// If flag is set, but no value is passed, the default interface for docker `eth0` is tried.
@@ -178,6 +254,30 @@ impl NetworkArgs {
         self.addr
     }
 
+    /// Returns the configured bandwidth limits of the peer sessions.
+    pub const fn bandwidth_limits(&self) -> BandwidthLimits {
+        BandwidthLimits {
+            peer_ingress: self.max_ingress_rate_per_peer,
+            peer_egress: self.max_egress_rate_per_peer,
+            total_ingress: self.max_ingress_rate,
+            total_egress: self.max_egress_rate,
+        }
+    }
+
+    /// Returns the configuration of the peer sessions for the given maximum number of peers.
+    pub fn sessions_config(&self, max_peers: usize) -> SessionsConfig {
+        let mut config = SessionsConfig::default()
+            .with_upscaled_event_buffer(max_peers)
+            .with_bandwidth_limits(self.bandwidth_limits());
+        if let Some(path) = &self.capture {
+            config = config.with_capture(CaptureConfig::new(path.clone()));
+        }
+        if let Some(proxy) = &self.proxy {
+            config = config.with_proxy(proxy.clone().with_direct_fallback(self.proxy_fallback));
+        }
+        config
+    }
+
     /// Returns the resolved bootnodes if any are provided.
     pub fn resolved_bootnodes(&self) -> Option<Vec<NodeRecord>> {
         self.bootnodes.clone().map(|bootnodes| {
@@ -226,7 +326,15 @@ impl NetworkArgs {
                 self.max_capacity_cache_txns_pending_fetch,
             ),
             max_transactions_seen_by_peer_history: self.max_seen_tx_history,
-            propagation_mode: Default::default(),
+            propagation_mode: self.tx_propagation_mode,
+            max_propagation_traces: self.max_tx_propagation_traces,
+            gossip: TransactionGossipConfig {
+                max_propagated_size: self.tx_gossip_max_size,
+                suppressed_types: self.tx_gossip_suppress_types.clone(),
+                max_full_size: self.tx_gossip_hashes_only_size,
+                hashes_only_types: self.tx_gossip_hashes_only_types.clone(),
+                max_full_bytes_per_peer: self.tx_gossip_max_peer_rate,
+            },
         };
 
         // Configure basic network stack
@@ -235,9 +343,8 @@ impl NetworkArgs {
                 self.persistent_peers_file(peers_file).as_deref(),
             ))
             .external_ip_resolver(self.nat)
-            .sessions_config(
-                SessionsConfig::default().with_upscaled_event_buffer(peers_config.max_peers()),
-            )
+            .port_mapping(self.nat_port_mapping)
+            .sessions_config(self.sessions_config(peers_config.max_peers()))
             .peer_config(peers_config)
             .boot_nodes(chain_bootnodes.clone())
             .transactions_manager_config(transactions_manager_config)
@@ -319,6 +426,7 @@ impl Default for NetworkArgs {
             p2p_secret_key: None,
             no_persist_peers: false,
             nat: NatResolver::Any,
+            nat_port_mapping: false,
             addr: DEFAULT_DISCOVERY_ADDR,
             port: DEFAULT_DISCOVERY_PORT,
             max_outbound_peers: None,
@@ -331,6 +439,20 @@ impl Default for NetworkArgs {
             max_pending_pool_imports: DEFAULT_MAX_COUNT_PENDING_POOL_IMPORTS,
             max_seen_tx_history: DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
             max_capacity_cache_txns_pending_fetch: DEFAULT_MAX_CAPACITY_CACHE_PENDING_FETCH,
+            tx_propagation_mode: TransactionPropagationMode::Sqrt,
+            max_tx_propagation_traces: DEFAULT_MAX_COUNT_PROPAGATION_TRACES,
+            tx_gossip_max_size: None,
+            tx_gossip_suppress_types: Vec::new(),
+            tx_gossip_hashes_only_size: None,
+            tx_gossip_hashes_only_types: Vec::new(),
+            tx_gossip_max_peer_rate: None,
+            max_ingress_rate: None,
+            max_egress_rate: None,
+            max_ingress_rate_per_peer: None,
+            max_egress_rate_per_peer: None,
+            capture: None,
+            proxy: None,
+            proxy_fallback: false,
             net_if: None,
         }
     }
@@ -591,6 +713,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_tx_propagation_mode_args() {
+        let tests = [
+            ("sqrt", TransactionPropagationMode::Sqrt),
+            ("all", TransactionPropagationMode::All),
+            ("25%", TransactionPropagationMode::Percent(25)),
+            ("8", TransactionPropagationMode::Max(8)),
+        ];
+
+        for (mode, expected) in tests {
+            let args =
+                CommandParser::<NetworkArgs>::parse_from(["reth", "--tx-propagation-mode", mode])
+                    .args;
+            assert_eq!(args.tx_propagation_mode, expected);
+        }
+
+        assert!(CommandParser::<NetworkArgs>::try_parse_from([
+            "reth",
+            "--tx-propagation-mode",
+            "101%"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn parse_bandwidth_limits() {
+        let args =
+            CommandParser::<NetworkArgs>::parse_from(["reth", "--max-egress-rate-peer", "1024"])
+                .args;
+        assert_eq!(args.bandwidth_limits().peer_egress, Some(1024));
+
+        assert!(CommandParser::<NetworkArgs>::try_parse_from(["reth", "--max-ingress-rate", "0"])
+            .is_err());
+    }
+
     #[cfg(not(feature = "optimism"))]
     #[test]
     fn network_args_default_sanity_test() {
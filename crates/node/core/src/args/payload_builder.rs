@@ -6,7 +6,12 @@ use clap::{
     Arg, Args, Command,
 };
 use reth_cli_util::{parse_duration_from_secs, parse_duration_from_secs_or_ms};
-use std::{borrow::Cow, ffi::OsStr, time::Duration};
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 /// Parameters for configuring the Payload Builder
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
@@ -35,6 +40,23 @@ pub struct PayloadBuilderArgs {
     /// Maximum number of tasks to spawn for building a payload.
     #[arg(long = "builder.max-tasks", default_value = "3", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
     pub max_payload_tasks: usize,
+
+    /// Number of best pool transactions whose accessed state is read in the background after each
+    /// new block.
+    ///
+    /// The senders, recipients and access lists of these transactions are cached for the next
+    /// payload, which also speeds up the execution of the next block if it includes them.
+    /// Disabled if 0.
+    #[arg(long = "builder.prewarm-transactions", default_value = "0", value_name = "COUNT")]
+    pub prewarm_transactions: usize,
+
+    /// Path to a JSON file with addresses and contract code hashes whose transactions are
+    /// excluded from locally built blocks.
+    ///
+    /// The file has the format `{"addresses": [..], "codeHashes": [..]}` and is reloaded when it
+    /// is modified. Excluded transactions are still accepted and propagated.
+    #[arg(long = "builder.exclusion-list", value_name = "PATH")]
+    pub exclusion_list: Option<PathBuf>,
 }
 
 impl Default for PayloadBuilderArgs {
@@ -45,6 +67,8 @@ impl Default for PayloadBuilderArgs {
             interval: Duration::from_secs(1),
             deadline: SLOT_DURATION,
             max_payload_tasks: 3,
+            prewarm_transactions: 0,
+            exclusion_list: None,
         }
     }
 }
@@ -69,6 +93,14 @@ impl PayloadBuilderConfig for PayloadBuilderArgs {
     fn max_payload_tasks(&self) -> usize {
         self.max_payload_tasks
     }
+
+    fn prewarm_transactions(&self) -> usize {
+        self.prewarm_transactions
+    }
+
+    fn exclusion_list(&self) -> Option<&Path> {
+        self.exclusion_list.as_deref()
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -3,10 +3,11 @@
 use crate::args::error::ReceiptsLogError;
 use alloy_primitives::{Address, BlockNumber};
 use clap::{builder::RangedU64ValueParser, Args};
+use humantime::parse_duration;
 use reth_chainspec::EthChainSpec;
 use reth_config::config::PruneConfig;
 use reth_prune_types::{PruneMode, PruneModes, ReceiptsLogPruneConfig, MINIMUM_PRUNING_DISTANCE};
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, time::Duration};
 
 /// Parameters for pruning and full node
 #[derive(Debug, Clone, Args, PartialEq, Eq, Default)]
@@ -45,6 +46,12 @@ pub struct PruningArgs {
     /// is not pruned.
     #[arg(long = "prune.transactionlookup.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["transaction_lookup_full", "transaction_lookup_distance"])]
     pub transaction_lookup_before: Option<BlockNumber>,
+    /// Index finalized transaction static files by transaction hash, so that transaction lookups
+    /// can be pruned for their blocks while transactions remain queryable by hash.
+    ///
+    /// Transaction lookups are only pruned for blocks whose static files are indexed.
+    #[arg(long = "prune.transactionlookup.static-file-index")]
+    pub transaction_lookup_static_file_index: bool,
 
     // Receipts
     /// Prunes all receipt data.
@@ -82,12 +89,84 @@ pub struct PruningArgs {
     #[arg(long = "prune.storagehistory.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["storage_history_full", "storage_history_distance"])]
     pub storage_history_before: Option<BlockNumber>,
 
+    // Optional indices
+    /// Maintains the transaction selector index used by `reth_getTransactionsBySelector`.
+    ///
+    /// The whole chain is indexed unless limited with `--prune.txselectors.distance` or
+    /// `--prune.txselectors.before`.
+    #[arg(long = "index.txselectors")]
+    pub transaction_selectors_index: bool,
+    /// Keep the transaction selector index for the last N + 1 blocks only. Implies
+    /// `--index.txselectors`.
+    #[arg(long = "prune.txselectors.distance", value_name = "BLOCKS", conflicts_with_all = &["transaction_selectors_before"])]
+    pub transaction_selectors_distance: Option<u64>,
+    /// Keep the transaction selector index from the specified block number on only. Implies
+    /// `--index.txselectors`.
+    #[arg(long = "prune.txselectors.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["transaction_selectors_distance"])]
+    pub transaction_selectors_before: Option<BlockNumber>,
+
+    /// Maintains the withdrawal history index used by `reth_getWithdrawalsByAddress` and
+    /// `reth_getWithdrawalsByValidator`.
+    ///
+    /// The whole chain is indexed unless limited with `--prune.withdrawals.distance` or
+    /// `--prune.withdrawals.before`.
+    #[arg(long = "index.withdrawals")]
+    pub withdrawal_history_index: bool,
+    /// Keep the withdrawal history index for the last N + 1 blocks only. Implies
+    /// `--index.withdrawals`.
+    #[arg(long = "prune.withdrawals.distance", value_name = "BLOCKS", conflicts_with_all = &["withdrawal_history_before"])]
+    pub withdrawal_history_distance: Option<u64>,
+    /// Keep the withdrawal history index from the specified block number on only. Implies
+    /// `--index.withdrawals`.
+    #[arg(long = "prune.withdrawals.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["withdrawal_history_distance"])]
+    pub withdrawal_history_before: Option<BlockNumber>,
+
+    /// Maintains the transaction-by-address index used by `ots_searchTransactionsBefore` and
+    /// `ots_searchTransactionsAfter`.
+    ///
+    /// The whole chain is indexed unless limited with `--prune.addresstxs.distance` or
+    /// `--prune.addresstxs.before`.
+    #[arg(long = "index.addresstxs")]
+    pub address_transactions_index: bool,
+    /// Keep the transaction-by-address index for the last N + 1 blocks only. Implies
+    /// `--index.addresstxs`.
+    #[arg(long = "prune.addresstxs.distance", value_name = "BLOCKS", conflicts_with_all = &["address_transactions_before"])]
+    pub address_transactions_distance: Option<u64>,
+    /// Keep the transaction-by-address index from the specified block number on only. Implies
+    /// `--index.addresstxs`.
+    #[arg(long = "prune.addresstxs.before", value_name = "BLOCK_NUMBER", conflicts_with_all = &["address_transactions_distance"])]
+    pub address_transactions_before: Option<BlockNumber>,
+
     // Receipts Log Filter
     /// Configure receipts log filter. Format:
     /// <`address`>:<`prune_mode`>[,<`address`>:<`prune_mode`>...] Where <`prune_mode`> can be
     /// 'full', 'distance:<`blocks`>', or 'before:<`block_number`>'
     #[arg(long = "prune.receiptslogfilter", value_name = "FILTER_CONFIG", value_delimiter = ',', value_parser = parse_receipts_log_filter)]
     pub receipts_log_filter: Vec<String>,
+
+    // Retained addresses
+    /// Addresses whose receipts, transaction lookups and changesets are never pruned, regardless
+    /// of the other prune settings.
+    #[arg(long = "prune.retain", value_name = "ADDRESS", value_delimiter = ',')]
+    pub retained_addresses: Vec<Address>,
+
+    // Throttle
+    /// Defer pruner runs while the node is under load and catch up while it's idle.
+    ///
+    /// Implied by any of the other `--prune.throttle.*` flags.
+    #[arg(long = "prune.throttle")]
+    pub throttle: bool,
+    /// Block processing latency above which pruner runs are deferred.
+    ///
+    /// Parses strings using [`humantime::parse_duration`], e.g. `500ms`.
+    #[arg(long = "prune.throttle.max-block-latency", value_name = "DURATION", value_parser = parse_duration)]
+    pub throttle_max_block_latency: Option<Duration>,
+    /// Number of in-flight RPC requests above which pruner runs are deferred.
+    #[arg(long = "prune.throttle.max-rpc-queue-depth", value_name = "REQUESTS")]
+    pub throttle_max_rpc_queue_depth: Option<usize>,
+    /// Maximum number of blocks a pruner run can be deferred for because of the load.
+    #[arg(long = "prune.throttle.max-deferred-blocks", value_name = "BLOCKS")]
+    pub throttle_max_deferred_blocks: Option<u64>,
 }
 
 impl PruningArgs {
@@ -103,6 +182,10 @@ impl PruningArgs {
                 segments: PruneModes {
                     sender_recovery: Some(PruneMode::Full),
                     transaction_lookup: None,
+                    transaction_hash_index: false,
+                    transaction_selectors: None,
+                    withdrawal_history: None,
+                    address_transactions: None,
                     // prune all receipts if chain doesn't have deposit contract specified in chain
                     // spec
                     receipts: chain_spec
@@ -118,7 +201,9 @@ impl PruningArgs {
                             .into_iter()
                             .collect(),
                     ),
+                    retained_addresses: Default::default(),
                 },
+                throttle: config.throttle,
             }
         }
 
@@ -132,6 +217,9 @@ impl PruningArgs {
         if let Some(mode) = self.transaction_lookup_prune_mode() {
             config.segments.transaction_lookup = Some(mode);
         }
+        if self.transaction_lookup_static_file_index {
+            config.segments.transaction_hash_index = true;
+        }
         if let Some(mode) = self.receipts_prune_mode() {
             config.segments.receipts = Some(mode);
         }
@@ -141,6 +229,45 @@ impl PruningArgs {
         if let Some(mode) = self.storage_history_prune_mode() {
             config.segments.storage_history = Some(mode);
         }
+        // The optional indices are enabled explicitly, also on top of `--full`
+        if let Some(mode) = index_prune_mode(
+            self.transaction_selectors_index,
+            self.transaction_selectors_distance,
+            self.transaction_selectors_before,
+        ) {
+            config.segments.transaction_selectors = Some(mode);
+        }
+        if let Some(mode) = index_prune_mode(
+            self.withdrawal_history_index,
+            self.withdrawal_history_distance,
+            self.withdrawal_history_before,
+        ) {
+            config.segments.withdrawal_history = Some(mode);
+        }
+        if let Some(mode) = index_prune_mode(
+            self.address_transactions_index,
+            self.address_transactions_distance,
+            self.address_transactions_before,
+        ) {
+            config.segments.address_transactions = Some(mode);
+        }
+        config.segments.retained_addresses.extend(self.retained_addresses.iter().copied());
+        if self.throttle ||
+            self.throttle_max_block_latency.is_some() ||
+            self.throttle_max_rpc_queue_depth.is_some() ||
+            self.throttle_max_deferred_blocks.is_some()
+        {
+            let throttle = config.throttle.get_or_insert_with(Default::default);
+            if let Some(latency) = self.throttle_max_block_latency {
+                throttle.max_block_processing_latency = latency;
+            }
+            if let Some(depth) = self.throttle_max_rpc_queue_depth {
+                throttle.max_rpc_queue_depth = depth;
+            }
+            if let Some(blocks) = self.throttle_max_deferred_blocks {
+                throttle.max_deferred_blocks = blocks;
+            }
+        }
 
         Some(config)
     }
@@ -205,6 +332,23 @@ impl PruningArgs {
     }
 }
 
+/// Returns the prune mode of an optional index, if it's enabled by any of its flags.
+const fn index_prune_mode(
+    enabled: bool,
+    distance: Option<u64>,
+    before: Option<BlockNumber>,
+) -> Option<PruneMode> {
+    if let Some(distance) = distance {
+        Some(PruneMode::Distance(distance))
+    } else if let Some(block_number) = before {
+        Some(PruneMode::Before(block_number))
+    } else if enabled {
+        Some(PruneMode::Before(0))
+    } else {
+        None
+    }
+}
+
 pub(crate) fn parse_receipts_log_filter(
     value: &str,
 ) -> Result<ReceiptsLogPruneConfig, ReceiptsLogError> {
@@ -252,6 +396,7 @@ pub(crate) fn parse_receipts_log_filter(
 mod tests {
     use super::*;
     use clap::Parser;
+    use reth_config::PruneThrottleConfig;
 
     /// A helper type to parse Args more easily
     #[derive(Parser)]
@@ -267,6 +412,83 @@ mod tests {
         assert_eq!(args, default_args);
     }
 
+    #[test]
+    fn test_parse_retained_addresses() {
+        let args = CommandParser::<PruningArgs>::parse_from([
+            "reth",
+            "--full",
+            "--prune.retain",
+            "0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002",
+        ])
+        .args;
+        let config = args.prune_config(reth_chainspec::MAINNET.as_ref()).unwrap();
+        assert!(config.segments.is_retained(&Address::with_last_byte(1)));
+        assert!(config.segments.is_retained(&Address::with_last_byte(2)));
+        assert!(!config.segments.is_retained(&Address::with_last_byte(3)));
+    }
+
+    #[test]
+    fn test_parse_transaction_lookup_static_file_index() {
+        let args = CommandParser::<PruningArgs>::parse_from([
+            "reth",
+            "--prune.transactionlookup.full",
+            "--prune.transactionlookup.static-file-index",
+        ])
+        .args;
+        let config = args.prune_config(reth_chainspec::MAINNET.as_ref()).unwrap();
+        assert_eq!(config.segments.transaction_lookup, Some(PruneMode::Full));
+        assert!(config.segments.transaction_hash_index);
+    }
+
+    #[test]
+    fn test_parse_optional_indices() {
+        let args = CommandParser::<PruningArgs>::parse_from(["reth", "--full"]).args;
+        let config = args.prune_config(reth_chainspec::MAINNET.as_ref()).unwrap();
+        assert!(!config.segments.has_transaction_selectors_index());
+        assert!(!config.segments.has_withdrawal_history_index());
+        assert!(!config.segments.has_address_transactions_index());
+
+        let args = CommandParser::<PruningArgs>::parse_from([
+            "reth",
+            "--full",
+            "--index.txselectors",
+            "--prune.withdrawals.distance",
+            "100000",
+            "--index.addresstxs",
+            "--prune.addresstxs.before",
+            "1000",
+        ])
+        .args;
+        let config = args.prune_config(reth_chainspec::MAINNET.as_ref()).unwrap();
+        assert_eq!(config.segments.transaction_selectors, Some(PruneMode::Before(0)));
+        assert_eq!(config.segments.withdrawal_history, Some(PruneMode::Distance(100000)));
+        assert_eq!(config.segments.address_transactions, Some(PruneMode::Before(1000)));
+        assert!(config.segments.has_transaction_selectors_index());
+        assert!(config.segments.has_withdrawal_history_index());
+        assert!(config.segments.has_address_transactions_index());
+    }
+
+    #[test]
+    fn test_parse_throttle() {
+        let args = CommandParser::<PruningArgs>::parse_from(["reth"]).args;
+        let config = args.prune_config(reth_chainspec::MAINNET.as_ref()).unwrap();
+        assert_eq!(config.throttle, None);
+
+        let args = CommandParser::<PruningArgs>::parse_from([
+            "reth",
+            "--prune.throttle.max-block-latency",
+            "250ms",
+        ])
+        .args;
+        let config = args.prune_config(reth_chainspec::MAINNET.as_ref()).unwrap();
+        let throttle = config.throttle.unwrap();
+        assert_eq!(throttle.max_block_processing_latency, Duration::from_millis(250));
+        assert_eq!(
+            throttle.max_rpc_queue_depth,
+            PruneThrottleConfig::default().max_rpc_queue_depth
+        );
+    }
+
     #[test]
     fn test_parse_receipts_log_filter() {
         let filter1 = "0x0000000000000000000000000000000000000001:full";
@@ -1,10 +1,12 @@
 //! clap [Args](clap::Args) for RPC related arguments.
 
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     ffi::OsStr,
     net::{IpAddr, Ipv4Addr},
     path::PathBuf,
+    str::FromStr,
+    time::Duration,
 };
 
 use alloy_primitives::Address;
@@ -14,6 +16,7 @@ use clap::{
     Arg, Args, Command,
 };
 use rand::Rng;
+use reth_cli_util::parse_duration_from_secs;
 use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
 
 use crate::args::{
@@ -87,6 +90,22 @@ pub struct RpcServerArgs {
     #[arg(long, default_value_t = constants::DEFAULT_IPC_ENDPOINT.to_string())]
     pub ipcpath: String,
 
+    /// Permissions of the IPC socket files in octal notation, e.g. `600` to only allow the owner
+    /// to connect. Unix only.
+    #[arg(long = "ipc.permissions", value_name = "MODE", value_parser = parse_socket_permissions)]
+    pub ipc_permissions: Option<u32>,
+
+    /// Owner of the IPC socket files as `UID`, `UID:GID` or `:GID`. Unix only.
+    #[arg(long = "ipc.owner", value_name = "UID:GID")]
+    pub ipc_owner: Option<IpcSocketOwner>,
+
+    /// Additional IPC endpoint as `PATH=MODULES[@MODE]` that only serves the given modules, e.g.
+    /// `/tmp/admin.ipc=admin,debug@600`. Can be set multiple times.
+    ///
+    /// The optional `MODE` overrides `--ipc.permissions` for the endpoint.
+    #[arg(long = "ipc.endpoint", value_name = "PATH=MODULES[@MODE]")]
+    pub ipc_endpoints: Vec<IpcEndpointArgs>,
+
     /// Auth server address to listen on
     #[arg(long = "authrpc.addr", default_value_t = IpAddr::V4(Ipv4Addr::LOCALHOST))]
     pub auth_addr: IpAddr,
@@ -128,6 +147,14 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-response-size", alias = "rpc-max-response-size", visible_alias = "rpc.returndata.limit", default_value_t = RPC_DEFAULT_MAX_RESPONSE_SIZE_MB.into())]
     pub rpc_max_response_size: MaxU32,
 
+    /// Maximum RPC response payload size of a method in megabytes as `METHOD=MB`, e.g.
+    /// `eth_getLogs=20`. Can be set multiple times.
+    ///
+    /// HTTP and WS results are only serialized up to the limit, and larger responses are replaced
+    /// with an error that asks to narrow the query.
+    #[arg(long = "rpc.max-method-response-size", value_name = "METHOD=MB")]
+    pub rpc_max_method_response_sizes: Vec<MethodResponseSizeArgs>,
+
     /// Set the maximum concurrent subscriptions per connection.
     #[arg(long = "rpc.max-subscriptions-per-connection", alias = "rpc-max-subscriptions-per-connection", default_value_t = RPC_DEFAULT_MAX_SUBS_PER_CONN.into())]
     pub rpc_max_subscriptions_per_connection: MaxU32,
@@ -153,6 +180,18 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-logs-per-response", alias = "rpc-max-logs-per-response", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64))]
     pub rpc_max_logs_per_response: ZeroAsNoneU64,
 
+    /// The number of seconds after which a filter that has not been polled is uninstalled.
+    #[arg(long = "rpc.filter-ttl", value_name = "SECONDS", default_value_t = constants::DEFAULT_STALE_FILTER_TTL_SECS)]
+    pub rpc_filter_ttl: u64,
+
+    /// Maximum block range of a `trace_filter` request. (0 = no limit)
+    #[arg(long = "rpc.max-trace-filter-blocks", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_TRACE_FILTER_BLOCKS))]
+    pub rpc_max_trace_filter_blocks: ZeroAsNoneU64,
+
+    /// Maximum number of transactions a `trace_filter` request may execute. (0 = no limit)
+    #[arg(long = "rpc.max-trace-filter-transactions", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_TRACE_FILTER_TRANSACTIONS))]
+    pub rpc_max_trace_filter_transactions: ZeroAsNoneU64,
+
     /// Maximum gas limit for `eth_call` and call tracing RPC methods.
     #[arg(
         long = "rpc.gascap",
@@ -185,11 +224,143 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.proof-permits", alias = "rpc-proof-permits", value_name = "COUNT", default_value_t = constants::DEFAULT_PROOF_PERMITS)]
     pub rpc_proof_permits: usize,
 
+    /// Enables `eth_sendRawTransactionSync`, which waits up to this many seconds for the
+    /// inclusion of the submitted transaction.
+    #[arg(long = "rpc.send-raw-transaction-sync-timeout", value_name = "SECONDS", value_parser = parse_duration_from_secs)]
+    pub rpc_send_raw_transaction_sync_timeout: Option<Duration>,
+
     /// Path to file containing disallowed addresses, json-encoded list of strings. Block
     /// validation API will reject blocks containing transactions from these addresses.
     #[arg(long = "builder.disallow", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<HashSet<Address>>)]
     pub builder_disallow: Option<HashSet<Address>>,
 
+    /// Path to a directory with JSON ABIs or Foundry/Hardhat build artifacts. The events of these
+    /// ABIs are used to decode logs returned by `reth_getDecodedLogs`.
+    #[arg(long = "rpc.abi-dir", value_name = "PATH")]
+    pub rpc_abi_dir: Option<PathBuf>,
+
+    /// Look up events that are not in the ABI directory on Sourcify and 4byte when decoding logs.
+    #[arg(long = "rpc.abi-lookup")]
+    pub rpc_abi_lookup: bool,
+
+    /// Maintain hourly and daily rollups of the canonical chain that are served by the `stats`
+    /// namespace.
+    #[arg(long = "rpc.stats")]
+    pub rpc_stats: bool,
+
+    /// The number of days the chain statistics of the `stats` namespace are retained.
+    #[arg(long = "rpc.stats-retention-days", value_name = "DAYS", default_value_t = constants::DEFAULT_STATS_RETENTION_DAYS)]
+    pub rpc_stats_retention_days: u64,
+
+    /// Record the execution fingerprints of canonical blocks to this file, served by the
+    /// `fingerprint` namespace.
+    ///
+    /// The fingerprint of a block consists of its state root, receipts root, transaction count,
+    /// gas used and a hash of the state changes, so that nodes can be compared cheaply.
+    #[arg(long = "rpc.fingerprints", value_name = "PATH")]
+    pub rpc_fingerprints: Option<PathBuf>,
+
+    /// The maximum size of the block fingerprints file in megabytes before it's rotated.
+    #[arg(long = "rpc.fingerprints-max-file-size", value_name = "MB", default_value_t = constants::DEFAULT_FINGERPRINTS_MAX_FILE_SIZE_MB)]
+    pub rpc_fingerprints_max_file_size: u64,
+
+    /// Schedule `trace` and `debug` calls by their estimated cost, so that a few expensive calls
+    /// can't starve all other tracing calls.
+    ///
+    /// The cost is estimated with the gas used and the number of transactions of the traced
+    /// block. Light, medium and heavy calls are queued separately with their own concurrency
+    /// limit, and rejected with a retry-after if their queue is full.
+    #[arg(long = "rpc.trace-scheduler")]
+    pub rpc_trace_scheduler: bool,
+
+    /// The maximum number of concurrent heavy tracing calls, if `--rpc.trace-scheduler` is set.
+    #[arg(long = "rpc.trace-heavy-concurrency", value_name = "COUNT", default_value_t = constants::DEFAULT_TRACE_HEAVY_CONCURRENCY, value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
+    pub rpc_trace_heavy_concurrency: usize,
+
+    /// The maximum number of tracing calls per cost class that wait for execution, if
+    /// `--rpc.trace-scheduler` is set.
+    #[arg(long = "rpc.trace-max-queued", value_name = "COUNT", default_value_t = constants::DEFAULT_TRACE_MAX_QUEUED)]
+    pub rpc_trace_max_queued: usize,
+
+    /// Reject calls that use the `safe` or `finalized` block tag if no fork choice update was
+    /// received from the consensus layer within this many seconds.
+    ///
+    /// Otherwise, these calls are served from the last known safe and finalized blocks while the
+    /// consensus layer is unavailable.
+    #[arg(long = "rpc.max-finality-staleness", value_name = "SECONDS", value_parser = parse_duration_from_secs)]
+    pub rpc_max_finality_staleness: Option<Duration>,
+
+    /// Read the `latest` block of all calls of an HTTP request from the same block, so that the
+    /// results of a batch are consistent even if a new block is committed while it's served.
+    ///
+    /// Explicit `latest` block parameters are pinned, as well as the omitted block parameter of
+    /// state methods like `eth_call` and `eth_getBalance`. The block parameter of these
+    /// methods is pinned to the block hash, so that they are consistent even if the block is
+    /// reorged.
+    #[arg(long = "rpc.batch-snapshot")]
+    pub rpc_batch_snapshot: bool,
+
+    /// Serve `eth_chainId`, `eth_blockNumber` and `eth_gasPrice` from a cache that tracks the
+    /// canonical head, without calling the method handlers.
+    ///
+    /// This only applies if all HTTP and WS servers serve the `eth` namespace, and if calls are
+    /// neither accounted nor rate limited.
+    #[arg(long = "rpc.fast-path")]
+    pub rpc_fast_path: bool,
+
+    /// Path to a JSON file that maps API keys to their quota in compute units per window, e.g.
+    /// `{"<key>": 1000000}`.
+    ///
+    /// If set, HTTP and WS calls are charged to the API key of the request, and calls without a
+    /// known API key or that exceed the quota are rejected. The usage is exported by
+    /// `admin_rpcUsage`.
+    #[arg(long = "rpc.api-keys", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<HashMap<String, u64>>)]
+    pub rpc_api_keys: Option<HashMap<String, u64>>,
+
+    /// The header that carries the API key.
+    #[arg(long = "rpc.api-key-header", value_name = "NAME", default_value = constants::DEFAULT_API_KEY_HEADER)]
+    pub rpc_api_key_header: String,
+
+    /// The number of seconds in which the quota of an API key can be used.
+    #[arg(long = "rpc.api-key-quota-window", value_name = "SECONDS", default_value_t = constants::DEFAULT_API_KEY_QUOTA_WINDOW_SECS)]
+    pub rpc_api_key_quota_window: u64,
+
+    /// Path to a TOML file with token bucket rate limits of the calls of each client and method.
+    ///
+    /// Clients are identified by their API key, if `--rpc.api-keys` is set, or the IP address in
+    /// `--rpc.client-ip-header`. All other clients share one bucket. HTTP and WS calls that exceed
+    /// the limits are rejected with a retry-after. The file is reloaded when it changes.
+    #[arg(long = "rpc.rate-limits", value_name = "PATH")]
+    pub rpc_rate_limits: Option<PathBuf>,
+
+    /// The header that carries the IP address of the client for the rate limits, e.g.
+    /// `x-forwarded-for`.
+    ///
+    /// Only set this if the node is behind a trusted reverse proxy that sets the header, otherwise
+    /// clients can make up their IP address.
+    #[arg(long = "rpc.client-ip-header", value_name = "NAME")]
+    pub rpc_client_ip_header: Option<String>,
+
+    /// The number of seconds a resumable `eth_subscribe` subscription is kept after its client
+    /// disconnected, so that it can be resumed with `eth_resubscribe`.
+    ///
+    /// Subscriptions are not resumable if this is 0.
+    #[arg(long = "rpc.subscription-resume-window", value_name = "SECONDS", default_value_t = constants::DEFAULT_SUBSCRIPTION_RESUME_WINDOW_SECS)]
+    pub rpc_subscription_resume_window: u64,
+
+    /// The number of buffered events of resumable `newHeads` subscriptions, 0 to disable.
+    #[arg(long = "rpc.subscription-resume-new-heads", value_name = "EVENTS", default_value_t = constants::DEFAULT_SUBSCRIPTION_RESUME_NEW_HEADS)]
+    pub rpc_subscription_resume_new_heads: usize,
+
+    /// The number of buffered events of resumable `logs` subscriptions, 0 to disable.
+    #[arg(long = "rpc.subscription-resume-logs", value_name = "EVENTS", default_value_t = constants::DEFAULT_SUBSCRIPTION_RESUME_LOGS)]
+    pub rpc_subscription_resume_logs: usize,
+
+    /// The number of buffered events of resumable `newPendingTransactions` subscriptions, 0 to
+    /// disable.
+    #[arg(long = "rpc.subscription-resume-pending-transactions", value_name = "EVENTS", default_value_t = constants::DEFAULT_SUBSCRIPTION_RESUME_PENDING_TRANSACTIONS)]
+    pub rpc_subscription_resume_pending_transactions: usize,
+
     /// State cache configuration.
     #[command(flatten)]
     pub rpc_state_cache: RpcStateCacheArgs,
@@ -312,6 +483,9 @@ impl Default for RpcServerArgs {
             ws_api: None,
             ipcdisable: false,
             ipcpath: constants::DEFAULT_IPC_ENDPOINT.to_string(),
+            ipc_permissions: None,
+            ipc_owner: None,
+            ipc_endpoints: Vec::new(),
             auth_addr: Ipv4Addr::LOCALHOST.into(),
             auth_port: constants::DEFAULT_AUTH_PORT,
             auth_jwtsecret: None,
@@ -320,20 +494,136 @@ impl Default for RpcServerArgs {
             rpc_jwtsecret: None,
             rpc_max_request_size: RPC_DEFAULT_MAX_REQUEST_SIZE_MB.into(),
             rpc_max_response_size: RPC_DEFAULT_MAX_RESPONSE_SIZE_MB.into(),
+            rpc_max_method_response_sizes: Vec::new(),
             rpc_max_subscriptions_per_connection: RPC_DEFAULT_MAX_SUBS_PER_CONN.into(),
             rpc_max_connections: RPC_DEFAULT_MAX_CONNECTIONS.into(),
             rpc_max_tracing_requests: constants::default_max_tracing_requests(),
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
+            rpc_filter_ttl: constants::DEFAULT_STALE_FILTER_TTL_SECS,
+            rpc_max_trace_filter_blocks: constants::DEFAULT_MAX_TRACE_FILTER_BLOCKS.into(),
+            rpc_max_trace_filter_transactions: constants::DEFAULT_MAX_TRACE_FILTER_TRANSACTIONS
+                .into(),
             rpc_gas_cap: constants::gas_oracle::RPC_DEFAULT_GAS_CAP,
             rpc_max_simulate_blocks: constants::DEFAULT_MAX_SIMULATE_BLOCKS,
             rpc_eth_proof_window: constants::DEFAULT_ETH_PROOF_WINDOW,
             gas_price_oracle: GasPriceOracleArgs::default(),
             rpc_state_cache: RpcStateCacheArgs::default(),
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
+            rpc_send_raw_transaction_sync_timeout: None,
             builder_disallow: Default::default(),
+            rpc_abi_dir: None,
+            rpc_abi_lookup: false,
+            rpc_stats: false,
+            rpc_stats_retention_days: constants::DEFAULT_STATS_RETENTION_DAYS,
+            rpc_fingerprints: None,
+            rpc_fingerprints_max_file_size: constants::DEFAULT_FINGERPRINTS_MAX_FILE_SIZE_MB,
+            rpc_trace_scheduler: false,
+            rpc_trace_heavy_concurrency: constants::DEFAULT_TRACE_HEAVY_CONCURRENCY,
+            rpc_trace_max_queued: constants::DEFAULT_TRACE_MAX_QUEUED,
+            rpc_max_finality_staleness: None,
+            rpc_batch_snapshot: false,
+            rpc_fast_path: false,
+            rpc_api_keys: None,
+            rpc_api_key_header: constants::DEFAULT_API_KEY_HEADER.to_string(),
+            rpc_api_key_quota_window: constants::DEFAULT_API_KEY_QUOTA_WINDOW_SECS,
+            rpc_rate_limits: None,
+            rpc_client_ip_header: None,
+            rpc_subscription_resume_window: constants::DEFAULT_SUBSCRIPTION_RESUME_WINDOW_SECS,
+            rpc_subscription_resume_new_heads: constants::DEFAULT_SUBSCRIPTION_RESUME_NEW_HEADS,
+            rpc_subscription_resume_logs: constants::DEFAULT_SUBSCRIPTION_RESUME_LOGS,
+            rpc_subscription_resume_pending_transactions:
+                constants::DEFAULT_SUBSCRIPTION_RESUME_PENDING_TRANSACTIONS,
+        }
+    }
+}
+
+/// Owner and group of the IPC socket files, see `--ipc.owner`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpcSocketOwner {
+    /// The user id, `None` keeps the current owner.
+    pub uid: Option<u32>,
+    /// The group id, `None` keeps the current group.
+    pub gid: Option<u32>,
+}
+
+impl FromStr for IpcSocketOwner {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |id: &str| {
+            (!id.is_empty())
+                .then(|| id.parse::<u32>().map_err(|err| format!("invalid id {id}: {err}")))
+                .transpose()
+        };
+        let (uid, gid) = s.split_once(':').unwrap_or((s, ""));
+        let owner = Self { uid: parse(uid)?, gid: parse(gid)? };
+        if owner.uid.is_none() && owner.gid.is_none() {
+            return Err("expected UID, UID:GID or :GID".to_string())
+        }
+        Ok(owner)
+    }
+}
+
+/// An additional IPC endpoint, see `--ipc.endpoint`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpcEndpointArgs {
+    /// Path of the IPC socket/pipe.
+    pub path: String,
+    /// The modules served by the endpoint.
+    pub modules: RpcModuleSelection,
+    /// Permissions of the socket file, overrides `--ipc.permissions`.
+    pub permissions: Option<u32>,
+}
+
+impl FromStr for IpcEndpointArgs {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (path, modules) =
+            s.rsplit_once('=').ok_or_else(|| "expected PATH=MODULES[@MODE]".to_string())?;
+        if path.is_empty() {
+            return Err("missing IPC endpoint path".to_string())
         }
+        let (modules, permissions) = match modules.split_once('@') {
+            Some((modules, mode)) => (modules, Some(parse_socket_permissions(mode)?)),
+            None => (modules, None),
+        };
+        let modules = modules.parse::<RpcModuleSelection>().map_err(|err| err.to_string())?;
+        Ok(Self { path: path.to_string(), modules, permissions })
+    }
+}
+
+/// The maximum response size of a method, see `--rpc.max-method-response-size`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodResponseSizeArgs {
+    /// The name of the method, e.g. `eth_getLogs`.
+    pub method: String,
+    /// The maximum response size in megabytes.
+    pub size_mb: u32,
+}
+
+impl FromStr for MethodResponseSizeArgs {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (method, size) = s.split_once('=').ok_or_else(|| "expected METHOD=MB".to_string())?;
+        if method.is_empty() {
+            return Err("missing method name".to_string())
+        }
+        let size_mb = size.parse().map_err(|err| format!("invalid size {size}: {err}"))?;
+        Ok(Self { method: method.to_string(), size_mb })
+    }
+}
+
+/// Parses unix file permissions in octal notation, with an optional `0o` prefix.
+fn parse_socket_permissions(s: &str) -> Result<u32, String> {
+    let mode = u32::from_str_radix(s.trim_start_matches("0o"), 8)
+        .map_err(|err| format!("invalid permissions {s}: {err}"))?;
+    if mode > 0o777 {
+        return Err(format!("invalid permissions {s}: expected at most 777"))
     }
+    Ok(mode)
 }
 
 /// clap value parser for [`RpcModuleSelection`].
@@ -412,6 +702,101 @@ mod tests {
         assert_eq!(apis, expected);
     }
 
+    #[test]
+    fn test_rpc_server_send_raw_transaction_sync_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.send-raw-transaction-sync-timeout",
+            "30",
+        ])
+        .args;
+        assert_eq!(args.rpc_send_raw_transaction_sync_timeout, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_rpc_server_trace_filter_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.max-trace-filter-blocks",
+            "1000",
+            "--rpc.max-trace-filter-transactions",
+            "0",
+        ])
+        .args;
+        assert_eq!(args.rpc_max_trace_filter_blocks.unwrap_or_max(), 1000);
+        assert_eq!(args.rpc_max_trace_filter_transactions.unwrap_or_max(), u64::MAX);
+    }
+
+    #[test]
+    fn test_rpc_server_filter_ttl_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.rpc_filter_ttl, constants::DEFAULT_STALE_FILTER_TTL_SECS);
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.filter-ttl", "30"]).args;
+        assert_eq!(args.rpc_filter_ttl, 30);
+    }
+
+    #[test]
+    fn test_rpc_server_ipc_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--ipc.permissions",
+            "660",
+            "--ipc.owner",
+            ":1000",
+            "--ipc.endpoint",
+            "/tmp/admin.ipc=admin,debug@600",
+            "--ipc.endpoint",
+            "/tmp/eth.ipc=eth",
+        ])
+        .args;
+        assert_eq!(args.ipc_permissions, Some(0o660));
+        assert_eq!(args.ipc_owner, Some(IpcSocketOwner { uid: None, gid: Some(1000) }));
+        assert_eq!(
+            args.ipc_endpoints,
+            vec![
+                IpcEndpointArgs {
+                    path: "/tmp/admin.ipc".to_string(),
+                    modules: RpcModuleSelection::try_from_selection(["admin", "debug"]).unwrap(),
+                    permissions: Some(0o600),
+                },
+                IpcEndpointArgs {
+                    path: "/tmp/eth.ipc".to_string(),
+                    modules: RpcModuleSelection::try_from_selection(["eth"]).unwrap(),
+                    permissions: None,
+                },
+            ]
+        );
+
+        assert!("/tmp/eth.ipc".parse::<IpcEndpointArgs>().is_err());
+        assert!("/tmp/eth.ipc=eth@999".parse::<IpcEndpointArgs>().is_err());
+        assert!(":".parse::<IpcSocketOwner>().is_err());
+    }
+
+    #[test]
+    fn test_rpc_server_method_response_size_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.max-method-response-size",
+            "eth_getLogs=20",
+            "--rpc.max-method-response-size",
+            "trace_block=50",
+        ])
+        .args;
+        assert_eq!(
+            args.rpc_max_method_response_sizes,
+            vec![
+                MethodResponseSizeArgs { method: "eth_getLogs".to_string(), size_mb: 20 },
+                MethodResponseSizeArgs { method: "trace_block".to_string(), size_mb: 50 },
+            ]
+        );
+
+        assert!("eth_getLogs".parse::<MethodResponseSizeArgs>().is_err());
+        assert!("=20".parse::<MethodResponseSizeArgs>().is_err());
+        assert!("eth_getLogs=-1".parse::<MethodResponseSizeArgs>().is_err());
+    }
+
     #[test]
     fn rpc_server_args_default_sanity_test() {
         let default_args = RpcServerArgs::default();
@@ -42,6 +42,10 @@ pub enum StageEnum {
     ///
     /// Deals with the retrieval and processing of transactions.
     TxLookup,
+    /// The transaction selectors stage within the pipeline.
+    ///
+    /// Indexes transactions by the function selector they call, if enabled.
+    TxSelectors,
     /// The account history stage within the pipeline.
     ///
     /// Manages historical data related to accounts.
@@ -50,4 +54,16 @@ pub enum StageEnum {
     ///
     /// Manages historical data related to storage.
     StorageHistory,
+    /// The log index stage within the pipeline.
+    ///
+    /// Indexes the blocks containing logs by address and topic, if enabled.
+    LogIndex,
+    /// The withdrawal history stage within the pipeline.
+    ///
+    /// Indexes withdrawals by recipient address and validator index, if enabled.
+    WithdrawalHistory,
+    /// The address transactions stage within the pipeline.
+    ///
+    /// Indexes transactions by their sender and recipient address, if enabled.
+    AddressTransactions,
 }
@@ -4,15 +4,29 @@ use crate::cli::config::RethTransactionPoolConfig;
 use alloy_eips::eip1559::{ETHEREUM_BLOCK_GAS_LIMIT, MIN_PROTOCOL_BASE_FEE};
 use alloy_primitives::Address;
 use clap::Args;
+use reth_cli_util::parse_duration_from_secs;
 use reth_transaction_pool::{
-    blobstore::disk::DEFAULT_MAX_CACHED_BLOBS,
+    analytics::{PoolAnalyticsConfig, DEFAULT_POOL_ANALYTICS_SAMPLE_RATE},
+    blobstore::disk::{
+        DiskFileBlobStoreConfig, DEFAULT_MAX_BLOB_STORE_SIZE, DEFAULT_MAX_CACHED_BLOBS,
+    },
+    event_log::{
+        PoolEventLogConfig, DEFAULT_POOL_EVENT_LOG_MAX_FILES, DEFAULT_POOL_EVENT_LOG_MAX_FILE_SIZE,
+    },
     pool::{NEW_TX_LISTENER_BUFFER_SIZE, PENDING_TX_LISTENER_BUFFER_SIZE},
-    validate::DEFAULT_MAX_TX_INPUT_BYTES,
-    LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
-    DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS, MAX_NEW_PENDING_TXS_NOTIFICATIONS,
-    REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
-    TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
+    validate::{
+        DEFAULT_MAX_TX_INPUT_BYTES, DEFAULT_SIMULATION_CACHED_SENDERS, DEFAULT_SIMULATION_MAX_GAS,
+    },
+    AdmissionSimulatorConfig, DynamicPriceFloorConfig, LocalTransactionConfig, PoolConfig,
+    PriceBumpConfig, PriceFloorConfig, PrioritySenderConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
+    DEFAULT_PRICE_FLOOR_HIGH_WATERMARK, DEFAULT_PRICE_FLOOR_LOW_WATERMARK, DEFAULT_PRICE_FLOOR_MAX,
+    DEFAULT_QUEUED_TRANSACTION_LIFETIME, DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS,
+    MAX_NEW_PENDING_TXS_NOTIFICATIONS, REPLACE_BLOB_PRICE_BUMP,
+    TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER, TXPOOL_MAX_QUEUED_PER_SENDER_DEFAULT,
+    TXPOOL_PRIORITY_RESERVED_SLOTS_DEFAULT, TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
+    TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
 };
+use std::{path::PathBuf, time::Duration};
 /// Parameters for debugging purposes
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
 #[command(next_help_heading = "TxPool")]
@@ -42,6 +56,16 @@ pub struct TxPoolArgs {
     #[arg(long = "txpool.max-account-slots", alias = "txpool.max_account_slots", default_value_t = TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER)]
     pub max_account_slots: usize,
 
+    /// Max number of transactions of a single sender in the queued sub-pool.
+    ///
+    /// The queued transactions of the sender with the highest nonces are evicted if exceeded.
+    #[arg(long = "txpool.max-queued-per-sender", default_value_t = TXPOOL_MAX_QUEUED_PER_SENDER_DEFAULT)]
+    pub max_queued_per_sender: usize,
+
+    /// Max time in seconds a transaction is kept in the queued sub-pool before it is evicted.
+    #[arg(long = "txpool.queued-lifetime", value_parser = parse_duration_from_secs, default_value = "10800", value_name = "SECONDS")]
+    pub queued_lifetime: Duration,
+
     /// Price bump (in %) for the transaction pool underpriced check.
     #[arg(long = "txpool.pricebump", default_value_t = DEFAULT_PRICE_BUMP)]
     pub price_bump: u128,
@@ -50,6 +74,29 @@ pub struct TxPoolArgs {
     #[arg(long = "txpool.minimal-protocol-fee", default_value_t = MIN_PROTOCOL_BASE_FEE)]
     pub minimal_protocol_basefee: u64,
 
+    /// Minimum priority fee in wei non-local transactions have to pay to enter the pool.
+    ///
+    /// This can be changed at runtime with `miner_setGasPrice`.
+    #[arg(long = "txpool.minimum-priority-fee", default_value_t = 0)]
+    pub minimum_priority_fee: u128,
+
+    /// Raise the minimum priority fee while the pool is congested and lower it back once the pool
+    /// drains.
+    #[arg(long = "txpool.dynamic-price-floor")]
+    pub dynamic_price_floor: bool,
+
+    /// Pool utilization in percent at or above which the dynamic price floor is raised.
+    #[arg(long = "txpool.price-floor-high-watermark", requires = "dynamic_price_floor", default_value_t = DEFAULT_PRICE_FLOOR_HIGH_WATERMARK, value_parser = clap::value_parser!(u8).range(1..=100))]
+    pub price_floor_high_watermark: u8,
+
+    /// Pool utilization in percent below which the dynamic price floor is lowered.
+    #[arg(long = "txpool.price-floor-low-watermark", requires = "dynamic_price_floor", default_value_t = DEFAULT_PRICE_FLOOR_LOW_WATERMARK, value_parser = clap::value_parser!(u8).range(0..=100))]
+    pub price_floor_low_watermark: u8,
+
+    /// Upper bound of the dynamic price floor in wei.
+    #[arg(long = "txpool.price-floor-max", requires = "dynamic_price_floor", default_value_t = DEFAULT_PRICE_FLOOR_MAX)]
+    pub price_floor_max: u128,
+
     /// The default enforced gas limit for transactions entering the pool
     #[arg(long = "txpool.gas-limit", default_value_t = ETHEREUM_BLOCK_GAS_LIMIT)]
     pub gas_limit: u64,
@@ -66,6 +113,13 @@ pub struct TxPoolArgs {
     #[arg(long = "txpool.max-cached-entries", alias = "txpool.max_cached_entries", default_value_t = DEFAULT_MAX_CACHED_BLOBS)]
     pub max_cached_entries: u32,
 
+    /// Max size of the blobs stored on disk in megabytes.
+    ///
+    /// The least recently used blobs are evicted if exceeded, and their transactions are removed
+    /// from the pool.
+    #[arg(long = "txpool.blobstore-max-size", default_value_t = DEFAULT_MAX_BLOB_STORE_SIZE / (1024 * 1024))]
+    pub blobstore_max_size: usize,
+
     /// Flag to disable local transaction exemptions.
     #[arg(long = "txpool.nolocals")]
     pub no_locals: bool,
@@ -75,6 +129,13 @@ pub struct TxPoolArgs {
     /// Flag to toggle local transaction propagation.
     #[arg(long = "txpool.no-local-transactions-propagation")]
     pub no_local_transactions_propagation: bool,
+    /// Senders whose transactions are never evicted when the pool is full, e.g. the sequencer of a
+    /// rollup.
+    #[arg(long = "txpool.priority-senders", value_name = "ADDRESS")]
+    pub priority_senders: Vec<Address>,
+    /// Max number of transactions of priority senders that are exempt from eviction.
+    #[arg(long = "txpool.priority-reserved-slots", default_value_t = TXPOOL_PRIORITY_RESERVED_SLOTS_DEFAULT)]
+    pub priority_reserved_slots: usize,
     /// Number of additional transaction validation tasks to spawn.
     #[arg(long = "txpool.additional-validation-tasks", alias = "txpool.additional_validation_tasks", default_value_t = DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS)]
     pub additional_validation_tasks: usize,
@@ -91,6 +152,54 @@ pub struct TxPoolArgs {
     /// iterators.
     #[arg(long = "txpool.max-new-pending-txs-notifications", alias = "txpool.max-new-pending-txs-notifications", default_value_t = MAX_NEW_PENDING_TXS_NOTIFICATIONS)]
     pub max_new_pending_txs_notifications: usize,
+
+    /// Reject transactions the sender can't afford together with its pooled transactions of lower
+    /// nonce, instead of parking them until the sender's balance is sufficient.
+    #[arg(long = "txpool.reject-overdraft")]
+    pub reject_overdraft: bool,
+
+    /// Simulate non-local transactions on top of the latest state before admitting them into the
+    /// pool, and reject transactions that revert or halt.
+    #[arg(long = "txpool.simulate")]
+    pub simulate: bool,
+
+    /// Reject transactions that use more gas than this in the simulation.
+    #[arg(long = "txpool.simulation-max-gas", value_name = "GAS", requires = "simulate", default_value_t = DEFAULT_SIMULATION_MAX_GAS)]
+    pub simulation_max_gas: u64,
+
+    /// Number of senders whose state reads are cached between simulations until the next block.
+    #[arg(long = "txpool.simulation-cached-senders", requires = "simulate", default_value_t = DEFAULT_SIMULATION_CACHED_SENDERS)]
+    pub simulation_cached_senders: u32,
+
+    /// Path of the journal local transactions are persisted to, so they survive restarts.
+    #[arg(long = "txpool.journal", value_name = "PATH")]
+    pub journal: Option<PathBuf>,
+
+    /// Path of a pool snapshot that is restored on startup and written on shutdown.
+    #[arg(long = "txpool.snapshot", value_name = "PATH")]
+    pub snapshot: Option<PathBuf>,
+
+    /// Directory to write a log of all pool events to, i.e. added, replaced, mined, evicted and
+    /// invalid transactions, as rotating JSONL files.
+    #[arg(long = "txpool.event-log", value_name = "DIR")]
+    pub event_log: Option<PathBuf>,
+
+    /// Max size of a single pool event log file in megabytes before it is rotated.
+    #[arg(long = "txpool.event-log-max-size", default_value_t = DEFAULT_POOL_EVENT_LOG_MAX_FILE_SIZE / (1024 * 1024))]
+    pub event_log_max_size: u64,
+
+    /// Max number of pool event log files to keep.
+    #[arg(long = "txpool.event-log-max-files", default_value_t = DEFAULT_POOL_EVENT_LOG_MAX_FILES)]
+    pub event_log_max_files: usize,
+
+    /// Record how long transactions stay in the pool and how they leave it, i.e. mined, replaced,
+    /// discarded or invalid, as metrics by tip.
+    #[arg(long = "txpool.analytics")]
+    pub analytics: bool,
+
+    /// Log one in this many transactions that left the pool, 0 to disable.
+    #[arg(long = "txpool.analytics-sample-rate", value_name = "RATE", default_value_t = DEFAULT_POOL_ANALYTICS_SAMPLE_RATE, requires = "analytics")]
+    pub analytics_sample_rate: u64,
 }
 
 impl Default for TxPoolArgs {
@@ -103,23 +212,75 @@ impl Default for TxPoolArgs {
             queued_max_count: TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
             queued_max_size: TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
+            max_queued_per_sender: TXPOOL_MAX_QUEUED_PER_SENDER_DEFAULT,
+            queued_lifetime: DEFAULT_QUEUED_TRANSACTION_LIFETIME,
             price_bump: DEFAULT_PRICE_BUMP,
             minimal_protocol_basefee: MIN_PROTOCOL_BASE_FEE,
+            minimum_priority_fee: 0,
+            dynamic_price_floor: false,
+            price_floor_high_watermark: DEFAULT_PRICE_FLOOR_HIGH_WATERMARK,
+            price_floor_low_watermark: DEFAULT_PRICE_FLOOR_LOW_WATERMARK,
+            price_floor_max: DEFAULT_PRICE_FLOOR_MAX,
             gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
             blob_transaction_price_bump: REPLACE_BLOB_PRICE_BUMP,
             max_tx_input_bytes: DEFAULT_MAX_TX_INPUT_BYTES,
             max_cached_entries: DEFAULT_MAX_CACHED_BLOBS,
+            blobstore_max_size: DEFAULT_MAX_BLOB_STORE_SIZE / (1024 * 1024),
             no_locals: false,
             locals: Default::default(),
             no_local_transactions_propagation: false,
+            priority_senders: Default::default(),
+            priority_reserved_slots: TXPOOL_PRIORITY_RESERVED_SLOTS_DEFAULT,
             additional_validation_tasks: DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS,
             pending_tx_listener_buffer_size: PENDING_TX_LISTENER_BUFFER_SIZE,
             new_tx_listener_buffer_size: NEW_TX_LISTENER_BUFFER_SIZE,
             max_new_pending_txs_notifications: MAX_NEW_PENDING_TXS_NOTIFICATIONS,
+            reject_overdraft: false,
+            simulate: false,
+            simulation_max_gas: DEFAULT_SIMULATION_MAX_GAS,
+            simulation_cached_senders: DEFAULT_SIMULATION_CACHED_SENDERS,
+            journal: None,
+            snapshot: None,
+            event_log: None,
+            event_log_max_size: DEFAULT_POOL_EVENT_LOG_MAX_FILE_SIZE / (1024 * 1024),
+            event_log_max_files: DEFAULT_POOL_EVENT_LOG_MAX_FILES,
+            analytics: false,
+            analytics_sample_rate: DEFAULT_POOL_ANALYTICS_SAMPLE_RATE,
         }
     }
 }
 
+impl TxPoolArgs {
+    /// Returns the configuration of the disk file blob store.
+    pub fn blob_store_config(&self) -> DiskFileBlobStoreConfig {
+        DiskFileBlobStoreConfig::default()
+            .with_max_cached_entries(self.max_cached_entries)
+            .with_max_size(Some(self.blobstore_max_size.saturating_mul(1024 * 1024)))
+    }
+
+    /// Returns the configuration of the pool event log, if enabled.
+    pub fn event_log_config(&self) -> Option<PoolEventLogConfig> {
+        self.event_log.as_ref().map(|dir| {
+            PoolEventLogConfig::new(dir)
+                .with_max_file_size(self.event_log_max_size.saturating_mul(1024 * 1024))
+                .with_max_files(self.event_log_max_files)
+        })
+    }
+
+    /// Returns the configuration of the pool analytics, if enabled.
+    pub fn analytics_config(&self) -> Option<PoolAnalyticsConfig> {
+        self.analytics.then(|| PoolAnalyticsConfig { sample_rate: self.analytics_sample_rate })
+    }
+
+    /// Returns the configuration of the admission simulation, if enabled.
+    pub fn admission_simulator_config(&self) -> Option<AdmissionSimulatorConfig> {
+        self.simulate.then(|| AdmissionSimulatorConfig {
+            max_gas_used: self.simulation_max_gas,
+            max_cached_senders: self.simulation_cached_senders,
+        })
+    }
+}
+
 impl RethTransactionPoolConfig for TxPoolArgs {
     /// Returns transaction pool configuration.
     fn pool_config(&self) -> PoolConfig {
@@ -129,6 +290,10 @@ impl RethTransactionPoolConfig for TxPoolArgs {
                 local_addresses: self.locals.clone().into_iter().collect(),
                 propagate_local_transactions: !self.no_local_transactions_propagation,
             },
+            priority_senders: PrioritySenderConfig {
+                senders: self.priority_senders.iter().copied().collect(),
+                reserved_slots: self.priority_reserved_slots,
+            },
             pending_limit: SubPoolLimit {
                 max_txs: self.pending_max_count,
                 max_size: self.pending_max_size.saturating_mul(1024 * 1024),
@@ -146,15 +311,29 @@ impl RethTransactionPoolConfig for TxPoolArgs {
                 max_size: self.queued_max_size.saturating_mul(1024 * 1024),
             },
             max_account_slots: self.max_account_slots,
+            max_queued_per_sender: self.max_queued_per_sender,
+            reject_overdraft: self.reject_overdraft,
+            queued_lifetime: self.queued_lifetime,
             price_bumps: PriceBumpConfig {
                 default_price_bump: self.price_bump,
                 replace_blob_tx_price_bump: self.blob_transaction_price_bump,
             },
+            price_floor: PriceFloorConfig {
+                minimum_priority_fee: self.minimum_priority_fee,
+                dynamic: self.dynamic_price_floor.then(|| DynamicPriceFloorConfig {
+                    high_watermark: self.price_floor_high_watermark,
+                    low_watermark: self.price_floor_low_watermark,
+                    max_priority_fee: self.price_floor_max,
+                    ..Default::default()
+                }),
+            },
             minimal_protocol_basefee: self.minimal_protocol_basefee,
             gas_limit: self.gas_limit,
             pending_tx_listener_buffer_size: self.pending_tx_listener_buffer_size,
             new_tx_listener_buffer_size: self.new_tx_listener_buffer_size,
             max_new_pending_txs_notifications: self.max_new_pending_txs_notifications,
+            local_journal_path: self.journal.clone(),
+            memory_budget: Default::default(),
         }
     }
 }
@@ -178,6 +357,70 @@ mod tests {
         assert_eq!(args, default_args);
     }
 
+    #[test]
+    fn txpool_parse_event_log() {
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.event-log",
+            "/tmp/pool-events",
+            "--txpool.event-log-max-size",
+            "10",
+        ])
+        .args;
+        let config = args.event_log_config().unwrap();
+        assert_eq!(config.dir, PathBuf::from("/tmp/pool-events"));
+        assert_eq!(config.max_file_size, 10 * 1024 * 1024);
+        assert_eq!(config.max_files, DEFAULT_POOL_EVENT_LOG_MAX_FILES);
+        assert!(TxPoolArgs::default().event_log_config().is_none());
+    }
+
+    #[test]
+    fn txpool_parse_analytics() {
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.analytics",
+            "--txpool.analytics-sample-rate",
+            "10",
+        ])
+        .args;
+        assert_eq!(args.analytics_config(), Some(PoolAnalyticsConfig { sample_rate: 10 }));
+        assert!(TxPoolArgs::default().analytics_config().is_none());
+    }
+
+    #[test]
+    fn txpool_parse_price_floor() {
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.minimum-priority-fee",
+            "1000",
+            "--txpool.dynamic-price-floor",
+            "--txpool.price-floor-high-watermark",
+            "80",
+        ])
+        .args;
+        let config = args.pool_config().price_floor;
+        assert_eq!(config.minimum_priority_fee, 1000);
+        let dynamic = config.dynamic.unwrap();
+        assert_eq!(dynamic.high_watermark, 80);
+        assert_eq!(dynamic.low_watermark, DEFAULT_PRICE_FLOOR_LOW_WATERMARK);
+        assert!(TxPoolArgs::default().pool_config().price_floor.dynamic.is_none());
+    }
+
+    #[test]
+    fn txpool_parse_simulation() {
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.simulate",
+            "--txpool.simulation-max-gas",
+            "2000000",
+        ])
+        .args;
+        let config = args.admission_simulator_config().unwrap();
+        assert_eq!(config.max_gas_used, 2_000_000);
+        assert_eq!(config.max_cached_senders, DEFAULT_SIMULATION_CACHED_SENDERS);
+        assert!(TxPoolArgs::default().admission_simulator_config().is_none());
+    }
+
     #[test]
     fn txpool_parse_locals() {
         let args = CommandParser::<TxPoolArgs>::parse_from([
@@ -188,4 +431,29 @@ mod tests {
         .args;
         assert_eq!(args.locals, vec![Address::ZERO]);
     }
+
+    #[test]
+    fn txpool_parse_priority_senders() {
+        let args = CommandParser::<TxPoolArgs>::parse_from([
+            "reth",
+            "--txpool.priority-senders",
+            "0x0000000000000000000000000000000000000000",
+            "--txpool.priority-reserved-slots",
+            "100",
+        ])
+        .args;
+        assert_eq!(args.priority_senders, vec![Address::ZERO]);
+        let config = args.pool_config();
+        assert!(config.priority_senders.is_priority_sender(&Address::ZERO));
+        assert_eq!(config.priority_senders.reserved_slots, 100);
+    }
+
+    #[test]
+    fn txpool_parse_blobstore_max_size() {
+        let args =
+            CommandParser::<TxPoolArgs>::parse_from(["reth", "--txpool.blobstore-max-size", "512"])
+                .args;
+        assert_eq!(args.blobstore_max_size, 512);
+        assert_eq!(args.blob_store_config().max_size, Some(512 * 1024 * 1024));
+    }
 }
@@ -3,7 +3,7 @@
 use alloy_primitives::Bytes;
 use reth_network::{protocol::IntoRlpxSubProtocol, NetworkPrimitives};
 use reth_transaction_pool::PoolConfig;
-use std::{borrow::Cow, time::Duration};
+use std::{borrow::Cow, path::Path, time::Duration};
 
 /// A trait that provides payload builder settings.
 ///
@@ -29,6 +29,12 @@ pub trait PayloadBuilderConfig {
 
     /// Maximum number of tasks to spawn for building a payload.
     fn max_payload_tasks(&self) -> usize;
+
+    /// Number of best pool transactions whose accessed state is prewarmed after each block.
+    fn prewarm_transactions(&self) -> usize;
+
+    /// Path to the file with the transactions that are excluded from built payloads, if any.
+    fn exclusion_list(&self) -> Option<&Path>;
 }
 
 /// A trait that represents the configured network and can be used to apply additional configuration
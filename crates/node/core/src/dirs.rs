@@ -330,6 +330,20 @@ impl<D> ChainPath<D> {
         self.data_dir().join("txpool-transactions-backup.rlp")
     }
 
+    /// Returns the path to the warm state keys file
+    ///
+    /// `<DIR>/<CHAIN_ID>/warm-state-keys.json`
+    pub fn warm_state_keys(&self) -> PathBuf {
+        self.data_dir().join("warm-state-keys.json")
+    }
+
+    /// Returns the path to the metrics snapshot file for this chain.
+    ///
+    /// `<DIR>/<CHAIN_ID>/metrics-snapshot.json`
+    pub fn metrics_snapshot(&self) -> PathBuf {
+        self.data_dir().join("metrics-snapshot.json")
+    }
+
     /// Returns the path to the config file for this chain.
     ///
     /// `<DIR>/<CHAIN_ID>/reth.toml`
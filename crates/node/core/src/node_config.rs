@@ -2,7 +2,7 @@
 
 use crate::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, NetworkArgs, PayloadBuilderArgs,
+        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EventsArgs, NetworkArgs, PayloadBuilderArgs,
         PruningArgs, RpcServerArgs, TxPoolArgs,
     },
     dirs::{ChainPath, DataDirPath},
@@ -31,6 +31,9 @@ use std::{
 };
 use tracing::*;
 
+/// The default interval in seconds at which a snapshot of key metrics is persisted.
+pub const DEFAULT_METRICS_SNAPSHOT_INTERVAL_SECS: u64 = 60;
+
 /// This includes all necessary configuration to launch the node.
 /// The individual configuration options can be overwritten before launching the node.
 ///
@@ -94,6 +97,14 @@ pub struct NodeConfig<ChainSpec> {
     /// The metrics will be served at the given interface and port.
     pub metrics: Option<SocketAddr>,
 
+    /// The interval in seconds at which a snapshot of key metrics is persisted to the datadir for
+    /// post-crash analysis, `0` disables the snapshots.
+    pub metrics_snapshot_interval: u64,
+
+    /// The memory budget in MiB that is shared by the major caches of the node, `None` if the
+    /// caches are only bounded by their own limits.
+    pub memory_budget: Option<u64>,
+
     /// Add a new instance of a node.
     ///
     /// Configures the ports of the node to avoid conflicts with the defaults.
@@ -133,6 +144,9 @@ pub struct NodeConfig<ChainSpec> {
 
     /// All pruning related arguments
     pub pruning: PruningArgs,
+
+    /// All canonical chain events publisher related arguments with --events prefix
+    pub events: EventsArgs,
 }
 
 impl NodeConfig<ChainSpec> {
@@ -151,6 +165,8 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             config: None,
             chain,
             metrics: None,
+            metrics_snapshot_interval: DEFAULT_METRICS_SNAPSHOT_INTERVAL_SECS,
+            memory_budget: None,
             instance: 1,
             network: NetworkArgs::default(),
             rpc: RpcServerArgs::default(),
@@ -160,6 +176,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             db: DatabaseArgs::default(),
             dev: DevArgs::default(),
             pruning: PruningArgs::default(),
+            events: EventsArgs::default(),
             datadir: DatadirArgs::default(),
         }
     }
@@ -207,6 +224,19 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         self
     }
 
+    /// Set the interval in seconds at which a snapshot of key metrics is persisted, `0` disables
+    /// the snapshots.
+    pub const fn with_metrics_snapshot_interval(mut self, interval: u64) -> Self {
+        self.metrics_snapshot_interval = interval;
+        self
+    }
+
+    /// Set the memory budget in MiB that is shared by the major caches of the node.
+    pub const fn with_memory_budget(mut self, memory_budget: u64) -> Self {
+        self.memory_budget = Some(memory_budget);
+        self
+    }
+
     /// Set the instance for the node
     pub const fn with_instance(mut self, instance: u16) -> Self {
         self.instance = instance;
@@ -261,6 +291,12 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         self
     }
 
+    /// Set the canonical chain events publisher args for the node
+    pub fn with_events(mut self, events: EventsArgs) -> Self {
+        self.events = events;
+        self
+    }
+
     /// Returns pruning configuration.
     pub fn prune_config(&self) -> Option<PruneConfig>
     where
@@ -440,6 +476,8 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             datadir: self.datadir,
             config: self.config,
             metrics: self.metrics,
+            metrics_snapshot_interval: self.metrics_snapshot_interval,
+            memory_budget: self.memory_budget,
             instance: self.instance,
             network: self.network,
             rpc: self.rpc,
@@ -449,6 +487,7 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             db: self.db,
             dev: self.dev,
             pruning: self.pruning,
+            events: self.events,
         }
     }
 }
@@ -465,6 +504,8 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
             chain: self.chain.clone(),
             config: self.config.clone(),
             metrics: self.metrics,
+            metrics_snapshot_interval: self.metrics_snapshot_interval,
+            memory_budget: self.memory_budget,
             instance: self.instance,
             network: self.network.clone(),
             rpc: self.rpc.clone(),
@@ -474,6 +515,7 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
             db: self.db,
             dev: self.dev,
             pruning: self.pruning.clone(),
+            events: self.events.clone(),
             datadir: self.datadir.clone(),
         }
     }
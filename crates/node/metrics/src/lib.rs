@@ -13,6 +13,7 @@ pub mod hooks;
 pub mod recorder;
 /// The metric server serving the metrics.
 pub mod server;
+pub mod snapshot;
 pub mod version;
 
 pub use metrics_exporter_prometheus::*;
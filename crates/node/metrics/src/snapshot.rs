@@ -0,0 +1,186 @@
+//! Periodic snapshots of key metrics that are persisted for post-crash analysis.
+
+use crate::{
+    hooks::Hooks,
+    recorder::{install_prometheus_recorder, PrometheusRecorder},
+};
+use eyre::WrapErr;
+use reth_tasks::TaskExecutor;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tracing::{debug, warn};
+
+/// Prefixes of the metrics that are included in a snapshot.
+///
+/// These cover the sync position, the connected peers, the database and static file sizes, the
+/// engine latencies and the memory usage.
+pub const SNAPSHOT_METRICS: &[&str] = &[
+    "reth_sync_checkpoint",
+    "reth_network_connected_peers",
+    "reth_network_tracked_peers",
+    "reth_db_table_size",
+    "reth_db_freelist",
+    "reth_static_files_segment_size",
+    "reth_engine_rpc_",
+    "reth_consensus_engine_beacon_executed_blocks",
+    "reth_consensus_engine_beacon_persistence_duration",
+    "reth_sync_block_validation_state_root_duration",
+    "reth_sync_execution_gas_per_second",
+    "reth_jemalloc_",
+    "process_resident_memory_bytes",
+];
+
+/// A snapshot of key metrics at a point in time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    /// The UNIX timestamp of the snapshot in seconds.
+    pub timestamp: u64,
+    /// The version of the node that took the snapshot.
+    pub version: String,
+    /// The values of the metrics by series, e.g. `reth_sync_checkpoint{stage="Headers"}`.
+    pub metrics: BTreeMap<String, f64>,
+}
+
+impl MetricsSnapshot {
+    /// Creates a snapshot of the [`SNAPSHOT_METRICS`] from the rendered Prometheus metrics.
+    pub fn from_rendered(rendered: &str, timestamp: u64, version: &str) -> Self {
+        let metrics = rendered
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| {
+                let (series, value) = line.rsplit_once(' ')?;
+                let name = series.split('{').next()?;
+                if !SNAPSHOT_METRICS.iter().any(|prefix| name.starts_with(prefix)) {
+                    return None
+                }
+                let value = value.parse::<f64>().ok().filter(|value| value.is_finite())?;
+                Some((series.to_string(), value))
+            })
+            .collect();
+        Self { timestamp, version: version.to_string(), metrics }
+    }
+
+    /// Reads the snapshot from the given file, returns `None` if it doesn't exist.
+    pub fn load(path: &Path) -> eyre::Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None)
+        }
+        let contents = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Could not read metrics snapshot {}", path.display()))?;
+        let snapshot = serde_json::from_str(&contents)
+            .wrap_err_with(|| format!("Invalid metrics snapshot {}", path.display()))?;
+        Ok(Some(snapshot))
+    }
+
+    /// Writes the snapshot to the given file.
+    ///
+    /// The snapshot is written to a temporary file first, so that a crash while writing doesn't
+    /// corrupt the previous snapshot.
+    pub fn save(&self, path: &Path) -> eyre::Result<()> {
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec_pretty(self)?)
+            .wrap_err_with(|| format!("Could not write metrics snapshot {}", tmp_path.display()))?;
+        std::fs::rename(&tmp_path, path)
+            .wrap_err_with(|| format!("Could not write metrics snapshot {}", path.display()))
+    }
+}
+
+/// Periodically persists a [`MetricsSnapshot`] to a file, and a final one on shutdown.
+///
+/// This works independently of the metrics endpoint, so that a snapshot is available for post-crash
+/// analysis even if the metrics weren't scraped.
+#[derive(Debug)]
+pub struct MetricsSnapshotter {
+    path: PathBuf,
+    interval: Duration,
+    version: &'static str,
+    hooks: Hooks,
+}
+
+impl MetricsSnapshotter {
+    /// Creates a new snapshotter that writes to the given file at the given interval.
+    ///
+    /// The hooks are called before each snapshot to collect the metrics that are only updated on
+    /// demand, e.g. the database sizes.
+    pub const fn new(
+        path: PathBuf,
+        interval: Duration,
+        version: &'static str,
+        hooks: Hooks,
+    ) -> Self {
+        Self { path, interval, version, hooks }
+    }
+
+    /// Takes a snapshot of the metrics of the given recorder and writes it to the file.
+    fn snapshot(&self, recorder: &PrometheusRecorder) -> eyre::Result<()> {
+        self.hooks.iter().for_each(|hook| hook());
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let snapshot =
+            MetricsSnapshot::from_rendered(&recorder.handle().render(), timestamp, self.version);
+        snapshot.save(&self.path)?;
+        debug!(target: "reth::cli", path = %self.path.display(), metrics = snapshot.metrics.len(), "Persisted metrics snapshot");
+        Ok(())
+    }
+
+    /// Spawns the task that persists the snapshots until shutdown.
+    pub fn spawn(self, task_executor: &TaskExecutor) {
+        task_executor.spawn_with_graceful_shutdown_signal(|mut signal| async move {
+            let recorder = install_prometheus_recorder();
+            let mut interval = tokio::time::interval(self.interval);
+            loop {
+                tokio::select! {
+                    guard = &mut signal => {
+                        if let Err(err) = self.snapshot(recorder) {
+                            warn!(target: "reth::cli", %err, "Failed to persist metrics snapshot");
+                        }
+                        drop(guard);
+                        break
+                    }
+                    _ = interval.tick() => {
+                        if let Err(err) = self.snapshot(recorder) {
+                            warn!(target: "reth::cli", %err, "Failed to persist metrics snapshot");
+                        }
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_roundtrip() {
+        let rendered = r#"# TYPE reth_sync_checkpoint gauge
+reth_sync_checkpoint{stage="Headers"} 100
+reth_sync_checkpoint{stage="Execution"} 90
+# TYPE reth_network_connected_peers gauge
+reth_network_connected_peers 25
+reth_engine_rpc_new_payload_v3{quantile="0.5"} 0.012
+reth_engine_rpc_new_payload_v3{quantile="0.99"} NaN
+reth_transaction_pool_pending_pool_transactions 10
+"#;
+        let snapshot = MetricsSnapshot::from_rendered(rendered, 1, "1.0.0");
+        assert_eq!(
+            snapshot.metrics,
+            BTreeMap::from([
+                ("reth_engine_rpc_new_payload_v3{quantile=\"0.5\"}".to_string(), 0.012),
+                ("reth_network_connected_peers".to_string(), 25.0),
+                ("reth_sync_checkpoint{stage=\"Execution\"}".to_string(), 90.0),
+                ("reth_sync_checkpoint{stage=\"Headers\"}".to_string(), 100.0),
+            ])
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metrics-snapshot.json");
+        assert_eq!(MetricsSnapshot::load(&path).unwrap(), None);
+        snapshot.save(&path).unwrap();
+        assert_eq!(MetricsSnapshot::load(&path).unwrap(), Some(snapshot));
+    }
+}
@@ -31,7 +31,8 @@ fn main() {
                 false => {
                     let engine_tree_config = TreeConfig::default()
                         .with_persistence_threshold(rollup_args.persistence_threshold)
-                        .with_memory_block_buffer_target(rollup_args.memory_block_buffer_target);
+                        .with_memory_block_buffer_target(rollup_args.memory_block_buffer_target)
+                        .with_max_warm_state_keys(rollup_args.max_warm_state_keys);
                     let handle = builder
                         .with_types_and_provider::<OpNode, BlockchainProvider2<_>>()
                         .with_components(OpNode::components(rollup_args.clone()))
@@ -0,0 +1,291 @@
+use crate::{parse_batcher_data, ChannelId, Frame};
+use alloy_primitives::{BlockNumber, Bytes};
+use reth_da::DataAvailabilityClient;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Default maximum total size of the channels buffered by the [`ChannelBank`].
+pub const DEFAULT_MAX_CHANNEL_BANK_SIZE: usize = 100_000_000;
+
+/// Size that is accounted for each frame in addition to its data.
+const FRAME_SIZE_OVERHEAD: usize = 200;
+
+/// A channel that is assembled from frames.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Channel {
+    id: ChannelId,
+    /// The L1 block that included the first frame of the channel.
+    open_block_number: BlockNumber,
+    frames: BTreeMap<u16, Bytes>,
+    /// Number of the frame that closes the channel, if it was received.
+    last_frame_number: Option<u16>,
+    size: usize,
+}
+
+impl Channel {
+    const fn new(id: ChannelId, open_block_number: BlockNumber) -> Self {
+        Self { id, open_block_number, frames: BTreeMap::new(), last_frame_number: None, size: 0 }
+    }
+
+    /// Returns the identifier of the channel.
+    pub const fn id(&self) -> ChannelId {
+        self.id
+    }
+
+    /// Returns the L1 block that included the first frame of the channel.
+    pub const fn open_block_number(&self) -> BlockNumber {
+        self.open_block_number
+    }
+
+    /// Returns the accounted size of the buffered frames.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns true if the channel is closed and all of its frames were received.
+    pub fn is_ready(&self) -> bool {
+        self.last_frame_number.is_some_and(|last| self.frames.len() == last as usize + 1)
+    }
+
+    /// Returns the concatenated data of all frames.
+    pub fn data(&self) -> Bytes {
+        self.frames.values().flat_map(|data| data.iter().copied()).collect()
+    }
+
+    fn add_frame(&mut self, frame: Frame) {
+        if self.frames.contains_key(&frame.number) ||
+            self.last_frame_number.is_some_and(|last| frame.is_last || frame.number > last)
+        {
+            // duplicate frame, or the channel is already closed
+            return
+        }
+
+        if frame.is_last {
+            self.last_frame_number = Some(frame.number);
+            // drop the frames that were received past the end of the channel
+            for (_, data) in self.frames.split_off(&frame.number) {
+                self.size -= data.len() + FRAME_SIZE_OVERHEAD;
+            }
+        }
+
+        self.size += frame.data.len() + FRAME_SIZE_OVERHEAD;
+        self.frames.insert(frame.number, frame.data);
+    }
+}
+
+/// Buffers the frames of channels until the channels are complete.
+///
+/// Channels are read in the order they were opened. A channel that is not complete within the
+/// channel timeout, counted in L1 blocks from the block that opened it, is dropped.
+#[derive(Debug)]
+pub struct ChannelBank {
+    channels: HashMap<ChannelId, Channel>,
+    /// Channel ids in the order they were opened.
+    queue: VecDeque<ChannelId>,
+    channel_timeout: u64,
+    max_size: usize,
+    size: usize,
+}
+
+impl ChannelBank {
+    /// Creates a new channel bank with the channel timeout of the chain.
+    pub fn new(channel_timeout: u64) -> Self {
+        Self {
+            channels: HashMap::new(),
+            queue: VecDeque::new(),
+            channel_timeout,
+            max_size: DEFAULT_MAX_CHANNEL_BANK_SIZE,
+            size: 0,
+        }
+    }
+
+    /// Sets the maximum total size of the buffered channels.
+    pub const fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Returns the number of buffered channels.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns true if no channels are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Adds a frame that was included in the given L1 block.
+    ///
+    /// If the buffered channels exceed the maximum size, the oldest channels are dropped.
+    pub fn ingest_frame(&mut self, frame: Frame, l1_block_number: BlockNumber) {
+        let channel = self.channels.entry(frame.id).or_insert_with(|| {
+            self.queue.push_back(frame.id);
+            Channel::new(frame.id, l1_block_number)
+        });
+
+        if l1_block_number > channel.open_block_number + self.channel_timeout {
+            // frames of timed out channels are ignored
+            return
+        }
+
+        let size_before = channel.size;
+        channel.add_frame(frame);
+        self.size = self.size - size_before + channel.size;
+
+        while self.size > self.max_size {
+            let Some(id) = self.queue.pop_front() else { break };
+            if let Some(channel) = self.channels.remove(&id) {
+                self.size -= channel.size;
+            }
+        }
+    }
+
+    /// Fetches the batcher data addressed by the commitment from the DA layer and adds its frames,
+    /// which were included in the given L1 block.
+    ///
+    /// Returns `false` if the data is not available. Per the spec, data that can't be parsed is
+    /// ignored as a whole.
+    pub async fn ingest_batcher_data<C: DataAvailabilityClient>(
+        &mut self,
+        client: &C,
+        commitment: &C::Commitment,
+        l1_block_number: BlockNumber,
+    ) -> Result<bool, C::Error> {
+        let Some(data) = client.fetch_batch(commitment).await? else { return Ok(false) };
+        for frame in parse_batcher_data(&data).unwrap_or_default() {
+            self.ingest_frame(frame, l1_block_number);
+        }
+        Ok(true)
+    }
+
+    /// Returns the oldest channel if it is complete, given the current L1 block.
+    ///
+    /// Only the oldest channel can be read. If it timed out, it's dropped and the next channel is
+    /// considered.
+    pub fn read(&mut self, l1_block_number: BlockNumber) -> Option<Channel> {
+        loop {
+            let channel = self.channels.get(self.queue.front()?)?;
+            let timed_out = l1_block_number > channel.open_block_number + self.channel_timeout;
+            if !timed_out && !channel.is_ready() {
+                return None
+            }
+
+            let id = self.queue.pop_front()?;
+            let channel = self.channels.remove(&id)?;
+            self.size -= channel.size;
+            if !timed_out {
+                return Some(channel)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DERIVATION_VERSION_0;
+    use std::{collections::HashMap, convert::Infallible};
+
+    /// DA layer that serves batcher data by an index.
+    struct TestDataAvailability(HashMap<u64, Bytes>);
+
+    impl DataAvailabilityClient for TestDataAvailability {
+        type Commitment = u64;
+        type Error = Infallible;
+
+        async fn fetch_batch(&self, commitment: &u64) -> Result<Option<Bytes>, Infallible> {
+            Ok(self.0.get(commitment).cloned())
+        }
+    }
+
+    fn frame(id: u8, number: u16, data: &'static [u8], is_last: bool) -> Frame {
+        Frame { id: ChannelId::repeat_byte(id), number, data: Bytes::from_static(data), is_last }
+    }
+
+    #[test]
+    fn assemble_channel() {
+        let mut bank = ChannelBank::new(10);
+
+        bank.ingest_frame(frame(1, 1, b"world", true), 1);
+        assert_eq!(bank.read(1), None);
+
+        // frames past the closing frame and duplicates are ignored
+        bank.ingest_frame(frame(1, 2, b"!", false), 2);
+        bank.ingest_frame(frame(1, 1, b"earth", true), 2);
+        bank.ingest_frame(frame(1, 0, b"hello ", false), 3);
+
+        let channel = bank.read(3).unwrap();
+        assert_eq!(channel.open_block_number(), 1);
+        assert_eq!(channel.data(), Bytes::from_static(b"hello world"));
+        assert!(bank.is_empty());
+    }
+
+    #[tokio::test]
+    async fn ingest_from_data_availability_layer() {
+        let mut first = vec![DERIVATION_VERSION_0];
+        frame(1, 0, b"hello ", false).encode(&mut first);
+        let mut second = vec![DERIVATION_VERSION_0];
+        frame(1, 1, b"world", true).encode(&mut second);
+        let da = TestDataAvailability(HashMap::from([
+            (0, first.into()),
+            (1, second.into()),
+            (2, Bytes::from_static(&[DERIVATION_VERSION_0, 1])),
+        ]));
+
+        let mut bank = ChannelBank::new(10);
+        assert_eq!(bank.ingest_batcher_data(&da, &0, 1).await, Ok(true));
+        assert_eq!(bank.ingest_batcher_data(&da, &3, 1).await, Ok(false));
+        // invalid data is ignored
+        assert_eq!(bank.ingest_batcher_data(&da, &2, 2).await, Ok(true));
+        assert_eq!(bank.read(2), None);
+
+        assert_eq!(bank.ingest_batcher_data(&da, &1, 2).await, Ok(true));
+        assert_eq!(bank.read(2).unwrap().data(), Bytes::from_static(b"hello world"));
+    }
+
+    #[test]
+    fn read_in_opening_order() {
+        let mut bank = ChannelBank::new(10);
+
+        bank.ingest_frame(frame(1, 0, b"first", false), 1);
+        bank.ingest_frame(frame(2, 0, b"second", true), 2);
+
+        // the second channel is ready, but the first one is not
+        assert_eq!(bank.read(2), None);
+
+        bank.ingest_frame(frame(1, 1, b"", true), 3);
+        assert_eq!(bank.read(3).unwrap().id(), ChannelId::repeat_byte(1));
+        assert_eq!(bank.read(3).unwrap().id(), ChannelId::repeat_byte(2));
+    }
+
+    #[test]
+    fn drop_timed_out_channels() {
+        let mut bank = ChannelBank::new(2);
+
+        bank.ingest_frame(frame(1, 0, b"stale", false), 1);
+        bank.ingest_frame(frame(2, 0, b"fresh", true), 2);
+
+        // frames of timed out channels are ignored
+        bank.ingest_frame(frame(1, 1, b"", true), 4);
+
+        let channel = bank.read(4).unwrap();
+        assert_eq!(channel.id(), ChannelId::repeat_byte(2));
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn prune_oldest_channels() {
+        let mut bank = ChannelBank::new(10).with_max_size(3 * FRAME_SIZE_OVERHEAD + 10);
+
+        bank.ingest_frame(frame(1, 0, b"12345", false), 1);
+        bank.ingest_frame(frame(2, 0, b"12345", false), 1);
+        assert_eq!(bank.len(), 2);
+
+        bank.ingest_frame(frame(3, 0, b"1", true), 1);
+        assert_eq!(bank.len(), 2);
+
+        bank.ingest_frame(frame(2, 1, b"", true), 1);
+        assert_eq!(bank.read(1).unwrap().id(), ChannelId::repeat_byte(2));
+        assert_eq!(bank.read(1).unwrap().id(), ChannelId::repeat_byte(3));
+    }
+}
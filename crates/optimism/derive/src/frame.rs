@@ -0,0 +1,149 @@
+use alloy_primitives::{Bytes, FixedBytes};
+
+/// The only supported derivation version of batcher transaction data.
+pub const DERIVATION_VERSION_0: u8 = 0;
+
+/// Maximum length of the data of a single frame.
+pub const MAX_FRAME_LEN: usize = 1_000_000;
+
+/// Size of the fields of a frame, excluding its data.
+const FRAME_OVERHEAD: usize = 16 + 2 + 4 + 1;
+
+/// Identifier of a channel.
+pub type ChannelId = FixedBytes<16>;
+
+/// Errors that can occur when parsing batcher transaction data.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FrameError {
+    /// The batcher transaction data is empty.
+    #[error("empty batcher data")]
+    Empty,
+    /// The derivation version is not supported.
+    #[error("unsupported derivation version {0}")]
+    UnsupportedVersion(u8),
+    /// The data ended in the middle of a frame.
+    #[error("truncated frame")]
+    Truncated,
+    /// The frame data exceeds [`MAX_FRAME_LEN`].
+    #[error("frame data length {0} exceeds the maximum frame length")]
+    TooLarge(usize),
+    /// The `is_last` flag is neither 0 nor 1.
+    #[error("invalid is_last flag {0}")]
+    InvalidIsLast(u8),
+}
+
+/// A chunk of the data of a channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// The channel the frame belongs to.
+    pub id: ChannelId,
+    /// Index of the frame within the channel.
+    pub number: u16,
+    /// The chunk of the channel data.
+    pub data: Bytes,
+    /// Whether this is the last frame of the channel.
+    pub is_last: bool,
+}
+
+impl Frame {
+    /// Decodes a frame from the start of the buffer and advances it past the frame.
+    pub fn decode(buf: &mut &[u8]) -> Result<Self, FrameError> {
+        if buf.len() < FRAME_OVERHEAD {
+            return Err(FrameError::Truncated)
+        }
+
+        let id = ChannelId::from_slice(&buf[..16]);
+        let number = u16::from_be_bytes([buf[16], buf[17]]);
+        let data_len = u32::from_be_bytes([buf[18], buf[19], buf[20], buf[21]]) as usize;
+        if data_len > MAX_FRAME_LEN {
+            return Err(FrameError::TooLarge(data_len))
+        }
+
+        let rest = &buf[22..];
+        if rest.len() < data_len + 1 {
+            return Err(FrameError::Truncated)
+        }
+        let data = Bytes::copy_from_slice(&rest[..data_len]);
+        let is_last = match rest[data_len] {
+            0 => false,
+            1 => true,
+            flag => return Err(FrameError::InvalidIsLast(flag)),
+        };
+
+        *buf = &rest[data_len + 1..];
+        Ok(Self { id, number, data, is_last })
+    }
+
+    /// Encodes the frame and appends it to the buffer.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        out.reserve(FRAME_OVERHEAD + self.data.len());
+        out.extend_from_slice(self.id.as_slice());
+        out.extend_from_slice(&self.number.to_be_bytes());
+        out.extend_from_slice(&(self.data.len() as u32).to_be_bytes());
+        out.extend_from_slice(&self.data);
+        out.push(self.is_last as u8);
+    }
+}
+
+/// Parses the frames of the data of a batcher transaction, which is either its calldata or one of
+/// its blobs.
+///
+/// Per the spec, the transaction is invalid as a whole if any of its frames can't be parsed, so no
+/// frames are returned in that case.
+pub fn parse_batcher_data(data: &[u8]) -> Result<Vec<Frame>, FrameError> {
+    let (&version, mut buf) = data.split_first().ok_or(FrameError::Empty)?;
+    if version != DERIVATION_VERSION_0 {
+        return Err(FrameError::UnsupportedVersion(version))
+    }
+
+    let mut frames = Vec::new();
+    while !buf.is_empty() {
+        frames.push(Frame::decode(&mut buf)?);
+    }
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::bytes;
+
+    fn frame(number: u16, is_last: bool) -> Frame {
+        Frame { id: ChannelId::repeat_byte(7), number, data: bytes!("c0ffee"), is_last }
+    }
+
+    #[test]
+    fn parse_frames() {
+        let frames = vec![frame(0, false), frame(1, true)];
+        let mut data = vec![DERIVATION_VERSION_0];
+        for frame in &frames {
+            frame.encode(&mut data);
+        }
+
+        assert_eq!(parse_batcher_data(&data), Ok(frames));
+    }
+
+    #[test]
+    fn reject_invalid_data() {
+        assert_eq!(parse_batcher_data(&[]), Err(FrameError::Empty));
+        assert_eq!(parse_batcher_data(&[1]), Err(FrameError::UnsupportedVersion(1)));
+
+        let mut data = vec![DERIVATION_VERSION_0];
+        frame(0, true).encode(&mut data);
+        let valid_len = data.len();
+
+        // trailing partial frame invalidates the whole transaction
+        data.push(0);
+        assert_eq!(parse_batcher_data(&data), Err(FrameError::Truncated));
+
+        data.truncate(valid_len);
+        *data.last_mut().unwrap() = 2;
+        assert_eq!(parse_batcher_data(&data), Err(FrameError::InvalidIsLast(2)));
+
+        let mut data = vec![DERIVATION_VERSION_0];
+        data.extend_from_slice(&[0; 18]);
+        data.extend_from_slice(&(MAX_FRAME_LEN as u32 + 1).to_be_bytes());
+        data.push(0);
+        assert_eq!(parse_batcher_data(&data), Err(FrameError::TooLarge(MAX_FRAME_LEN + 1)));
+    }
+}
@@ -0,0 +1,28 @@
+//! Batcher frame parsing and channel assembly of the OP [derivation pipeline].
+//!
+//! Batcher transaction data is fetched through a
+//! [`DataAvailabilityClient`](reth_da::DataAvailabilityClient) and parsed into [`Frame`]s, which
+//! the [`ChannelBank`] assembles into complete channels. The data of a ready channel is the
+//! compressed stream of batches of the L2 chain.
+//!
+//! This crate only covers these two stages. Channel decompression, batch decoding and the
+//! construction of payload attributes are not implemented, so it can't derive an L2 chain on its
+//! own and a separate rollup node is still required.
+//!
+//! [derivation pipeline]: https://specs.optimism.io/protocol/derivation.html
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod channel;
+mod frame;
+
+pub use channel::{Channel, ChannelBank, DEFAULT_MAX_CHANNEL_BANK_SIZE};
+pub use frame::{
+    parse_batcher_data, ChannelId, Frame, FrameError, DERIVATION_VERSION_0, MAX_FRAME_LEN,
+};
@@ -3,7 +3,7 @@
 //! clap [Args](clap::Args) for optimism rollup configuration
 
 use reth_node_builder::engine_tree_config::{
-    DEFAULT_MEMORY_BLOCK_BUFFER_TARGET, DEFAULT_PERSISTENCE_THRESHOLD,
+    DEFAULT_MAX_WARM_STATE_KEYS, DEFAULT_MEMORY_BLOCK_BUFFER_TARGET, DEFAULT_PERSISTENCE_THRESHOLD,
 };
 
 /// Parameters for rollup configuration
@@ -56,6 +56,11 @@ pub struct RollupArgs {
     /// Configure the target number of blocks to keep in memory.
     #[arg(long = "engine.memory-block-buffer-target", conflicts_with = "legacy", default_value_t = DEFAULT_MEMORY_BLOCK_BUFFER_TARGET)]
     pub memory_block_buffer_target: u64,
+
+    /// Configure the maximum number of recently changed account and storage keys that are
+    /// persisted on shutdown and pre-warmed on startup. Set to 0 to disable.
+    #[arg(long = "engine.warm-state-keys", conflicts_with = "legacy", default_value_t = DEFAULT_MAX_WARM_STATE_KEYS)]
+    pub max_warm_state_keys: u32,
 }
 
 impl Default for RollupArgs {
@@ -70,6 +75,7 @@ impl Default for RollupArgs {
             legacy: false,
             persistence_threshold: DEFAULT_PERSISTENCE_THRESHOLD,
             memory_block_buffer_target: DEFAULT_MEMORY_BLOCK_BUFFER_TARGET,
+            max_warm_state_keys: DEFAULT_MAX_WARM_STATE_KEYS,
         }
     }
 }
@@ -45,6 +45,8 @@ where
     type ExecutionPayloadEnvelopeV2 = ExecutionPayloadEnvelopeV2;
     type ExecutionPayloadEnvelopeV3 = OpExecutionPayloadEnvelopeV3;
     type ExecutionPayloadEnvelopeV4 = OpExecutionPayloadEnvelopeV4;
+    // Amsterdam is not scheduled on OP chains, so `engine_getPayloadV5` is never served
+    type ExecutionPayloadEnvelopeV5 = OpExecutionPayloadEnvelopeV4;
 }
 
 /// A default payload type for [`OpEngineTypes`]
@@ -169,18 +171,24 @@ pub fn validate_withdrawals_presence(
                     .to_error(VersionSpecificValidationError::WithdrawalsNotSupportedInV1))
             }
             if is_shanghai {
-                return Err(message_validation_kind
-                    .to_error(VersionSpecificValidationError::NoWithdrawalsPostShanghai))
+                return Err(message_validation_kind.to_error(
+                    VersionSpecificValidationError::NoWithdrawalsPostShanghai { timestamp },
+                ))
             }
         }
-        EngineApiMessageVersion::V2 | EngineApiMessageVersion::V3 | EngineApiMessageVersion::V4 => {
+        EngineApiMessageVersion::V2 |
+        EngineApiMessageVersion::V3 |
+        EngineApiMessageVersion::V4 |
+        EngineApiMessageVersion::V5 => {
             if is_shanghai && !has_withdrawals {
-                return Err(message_validation_kind
-                    .to_error(VersionSpecificValidationError::NoWithdrawalsPostShanghai))
+                return Err(message_validation_kind.to_error(
+                    VersionSpecificValidationError::NoWithdrawalsPostShanghai { timestamp },
+                ))
             }
             if !is_shanghai && has_withdrawals {
-                return Err(message_validation_kind
-                    .to_error(VersionSpecificValidationError::HasWithdrawalsPreShanghai))
+                return Err(message_validation_kind.to_error(
+                    VersionSpecificValidationError::HasWithdrawalsPreShanghai { timestamp },
+                ))
             }
         }
     };
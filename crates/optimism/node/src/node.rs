@@ -7,7 +7,9 @@ use crate::{
     OpEngineTypes,
 };
 use alloy_consensus::Header;
-use reth_basic_payload_builder::{BasicPayloadJobGenerator, BasicPayloadJobGeneratorConfig};
+use reth_basic_payload_builder::{
+    BasicPayloadJobGenerator, BasicPayloadJobGeneratorConfig, TransactionExclusions,
+};
 use reth_chainspec::{EthChainSpec, EthereumHardforks, Hardforks};
 use reth_db::transaction::{DbTx, DbTxMut};
 use reth_evm::{execute::BasicBlockExecutorProvider, ConfigureEvm};
@@ -39,7 +41,7 @@ use reth_provider::{
     ChainSpecProvider, DBProvider, EthStorage, ProviderResult, ReadBodyInput,
 };
 use reth_rpc_server_types::RethRpcModule;
-use reth_tracing::tracing::{debug, info};
+use reth_tracing::tracing::{debug, info, warn};
 use reth_transaction_pool::{
     blobstore::DiskFileBlobStore, CoinbaseTipOrdering, PoolTransaction, TransactionPool,
     TransactionValidationTaskExecutor,
@@ -393,7 +395,8 @@ where
     async fn build_pool(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Pool> {
         let Self { pool_config_overrides } = self;
         let data_dir = ctx.config().datadir();
-        let blob_store = DiskFileBlobStore::open(data_dir.blobstore(), Default::default())?;
+        let blob_store =
+            DiskFileBlobStore::open(data_dir.blobstore(), ctx.config().txpool.blob_store_config())?;
 
         let validator = TransactionValidationTaskExecutor::eth_builder(Arc::new(
             ctx.chain_spec().inner.clone(),
@@ -421,6 +424,10 @@ where
             pool_config_overrides.apply(ctx.pool_config()),
         );
         info!(target: "reth::cli", "Transaction pool initialized");
+
+        if let Err(err) = transaction_pool.replay_local_journal().await {
+            warn!(target: "reth::cli", %err, "Failed to replay local transaction journal");
+        }
         let transactions_path = data_dir.txpool_transactions();
 
         // spawn txpool maintenance task
@@ -442,6 +449,36 @@ where
                 },
             );
 
+            if let Some(snapshot_path) = ctx.config().txpool.snapshot.clone() {
+                ctx.task_executor().spawn_critical_with_graceful_shutdown_signal(
+                    "txpool snapshot task",
+                    |shutdown| {
+                        reth_transaction_pool::snapshot::pool_snapshot_task(
+                            shutdown,
+                            pool.clone(),
+                            snapshot_path,
+                        )
+                    },
+                );
+                debug!(target: "reth::cli", "Spawned txpool snapshot task");
+            }
+
+            if let Some(event_log_config) = ctx.config().txpool.event_log_config() {
+                ctx.task_executor().spawn(reth_transaction_pool::event_log::pool_event_log_task(
+                    pool.clone(),
+                    event_log_config,
+                ));
+                debug!(target: "reth::cli", "Spawned txpool event log task");
+            }
+
+            if let Some(analytics_config) = ctx.config().txpool.analytics_config() {
+                ctx.task_executor().spawn(reth_transaction_pool::analytics::pool_analytics_task(
+                    pool.clone(),
+                    analytics_config,
+                ));
+                debug!(target: "reth::cli", "Spawned txpool analytics task");
+            }
+
             // spawn the maintenance task
             ctx.task_executor().spawn_critical(
                 "txpool maintenance task",
@@ -518,15 +555,21 @@ where
             + 'static,
         Evm: ConfigureEvm<Header = Header, Transaction = TransactionSigned>,
     {
+        let conf = ctx.payload_builder_config();
+        let exclusions = match conf.exclusion_list() {
+            Some(path) => TransactionExclusions::load_and_watch(path, ctx.task_executor())?,
+            None => TransactionExclusions::default(),
+        };
         let payload_builder = reth_optimism_payload_builder::OpPayloadBuilder::new(evm_config)
             .with_transactions(self.best_transactions)
+            .with_exclusions(exclusions)
             .set_compute_pending_block(self.compute_pending_block);
-        let conf = ctx.payload_builder_config();
 
         let payload_job_config = BasicPayloadJobGeneratorConfig::default()
             .interval(conf.interval())
             .deadline(conf.deadline())
             .max_payload_tasks(conf.max_payload_tasks())
+            .prewarm_transactions(conf.prewarm_transactions())
             // no extradata for OP
             .extradata(Default::default());
 
@@ -137,6 +137,7 @@ where
             state_nonce,
             transaction: valid_tx,
             propagate,
+            authorities,
         } = outcome
         {
             let l1_block_info = self.block_info.l1_block_info.read().clone();
@@ -174,6 +175,7 @@ where
                 state_nonce,
                 transaction: valid_tx,
                 propagate,
+                authorities,
             }
         }
 
@@ -25,16 +25,18 @@ use reth_payload_primitives::PayloadBuilderAttributes;
 use reth_payload_util::PayloadTransactions;
 use reth_primitives::{
     proofs, transaction::SignedTransactionIntoRecoveredExt, Block, BlockBody, BlockExt, Receipt,
-    SealedHeader, TransactionSigned, TxType,
+    RecoveredTx, SealedHeader, TransactionSigned, TxType,
 };
 use reth_provider::{
     HashedPostStateProvider, ProviderError, StateProofProvider, StateProviderFactory,
     StateRootProvider,
 };
-use reth_revm::{database::StateProviderDatabase, witness::ExecutionWitnessRecord};
+use reth_revm::{
+    checkpoint::StateCheckpoint, database::StateProviderDatabase, witness::ExecutionWitnessRecord,
+};
 use reth_transaction_pool::{
     noop::NoopTransactionPool, pool::BestPayloadTransactions, BestTransactionsAttributes,
-    PoolTransaction, TransactionPool,
+    PoolTransaction, TransactionPool, ValidBundle,
 };
 use revm::{
     db::{states::bundle_state::BundleRetention, State},
@@ -49,7 +51,7 @@ use tracing::{debug, trace, warn};
 
 /// Optimism's payload builder
 #[derive(Debug, Clone)]
-pub struct OpPayloadBuilder<EvmConfig, Txs = ()> {
+pub struct OpPayloadBuilder<EvmConfig, Txs = (), Exclusions = ()> {
     /// The rollup's compute pending block configuration option.
     // TODO(clabby): Implement this feature.
     pub compute_pending_block: bool,
@@ -60,6 +62,8 @@ pub struct OpPayloadBuilder<EvmConfig, Txs = ()> {
     /// The type responsible for yielding the best transactions for the payload if mempool
     /// transactions are allowed.
     pub best_transactions: Txs,
+    /// The mempool transactions that are excluded from the payload.
+    pub exclusions: Exclusions,
 }
 
 impl<EvmConfig> OpPayloadBuilder<EvmConfig> {
@@ -72,11 +76,17 @@ impl<EvmConfig> OpPayloadBuilder<EvmConfig> {
 
     /// Configures the builder with the given [`OpBuilderConfig`].
     pub const fn with_builder_config(evm_config: EvmConfig, config: OpBuilderConfig) -> Self {
-        Self { compute_pending_block: true, evm_config, config, best_transactions: () }
+        Self {
+            compute_pending_block: true,
+            evm_config,
+            config,
+            best_transactions: (),
+            exclusions: (),
+        }
     }
 }
 
-impl<EvmConfig, Txs> OpPayloadBuilder<EvmConfig, Txs> {
+impl<EvmConfig, Txs, Exclusions> OpPayloadBuilder<EvmConfig, Txs, Exclusions> {
     /// Sets the rollup's compute pending block configuration option.
     pub const fn set_compute_pending_block(mut self, compute_pending_block: bool) -> Self {
         self.compute_pending_block = compute_pending_block;
@@ -88,9 +98,30 @@ impl<EvmConfig, Txs> OpPayloadBuilder<EvmConfig, Txs> {
     pub fn with_transactions<T: OpPayloadTransactions>(
         self,
         best_transactions: T,
-    ) -> OpPayloadBuilder<EvmConfig, T> {
-        let Self { compute_pending_block, evm_config, config, .. } = self;
-        OpPayloadBuilder { compute_pending_block, evm_config, best_transactions, config }
+    ) -> OpPayloadBuilder<EvmConfig, T, Exclusions> {
+        let Self { compute_pending_block, evm_config, config, exclusions, .. } = self;
+        OpPayloadBuilder {
+            compute_pending_block,
+            evm_config,
+            best_transactions,
+            config,
+            exclusions,
+        }
+    }
+
+    /// Configures the mempool transactions that are excluded from the payload.
+    pub fn with_exclusions<E: PayloadExclusions>(
+        self,
+        exclusions: E,
+    ) -> OpPayloadBuilder<EvmConfig, Txs, E> {
+        let Self { compute_pending_block, evm_config, config, best_transactions, .. } = self;
+        OpPayloadBuilder {
+            compute_pending_block,
+            evm_config,
+            best_transactions,
+            config,
+            exclusions,
+        }
     }
 
     /// Enables the rollup's compute pending block configuration option.
@@ -103,10 +134,11 @@ impl<EvmConfig, Txs> OpPayloadBuilder<EvmConfig, Txs> {
         self.compute_pending_block
     }
 }
-impl<EvmConfig, Txs> OpPayloadBuilder<EvmConfig, Txs>
+impl<EvmConfig, Txs, Exclusions> OpPayloadBuilder<EvmConfig, Txs, Exclusions>
 where
     EvmConfig: ConfigureEvm<Header = Header, Transaction = TransactionSigned>,
     Txs: OpPayloadTransactions,
+    Exclusions: PayloadExclusions,
 {
     /// Constructs an Optimism payload from the transactions sent via the
     /// Payload attributes by the sequencer. If the `no_tx_pool` argument is passed in
@@ -140,7 +172,8 @@ where
             best_payload,
         };
 
-        let builder = OpBuilder { pool, best: self.best_transactions.clone() };
+        let exclusions = self.exclusions.exclusion_filter(&client, ctx.parent().hash())?;
+        let builder = OpBuilder { pool, best: self.best_transactions.clone(), exclusions };
 
         let state_provider = client.state_by_block_hash(ctx.parent().hash())?;
         let state = StateProviderDatabase::new(state_provider);
@@ -160,7 +193,7 @@ where
     }
 }
 
-impl<EvmConfig, Txs> OpPayloadBuilder<EvmConfig, Txs>
+impl<EvmConfig, Txs, Exclusions> OpPayloadBuilder<EvmConfig, Txs, Exclusions>
 where
     EvmConfig: ConfigureEvm<Header = Header, Transaction = TransactionSigned>,
 {
@@ -214,18 +247,21 @@ where
         let state = StateProviderDatabase::new(state_provider);
         let mut state = State::builder().with_database(state).with_bundle_update().build();
 
-        let builder = OpBuilder { pool: NoopTransactionPool::default(), best: () };
+        let builder =
+            OpBuilder { pool: NoopTransactionPool::default(), best: (), exclusions: None };
         builder.witness(&mut state, &ctx)
     }
 }
 
 /// Implementation of the [`PayloadBuilder`] trait for [`OpPayloadBuilder`].
-impl<Pool, Client, EvmConfig, Txs> PayloadBuilder<Pool, Client> for OpPayloadBuilder<EvmConfig, Txs>
+impl<Pool, Client, EvmConfig, Txs, Exclusions> PayloadBuilder<Pool, Client>
+    for OpPayloadBuilder<EvmConfig, Txs, Exclusions>
 where
     Client: StateProviderFactory + ChainSpecProvider<ChainSpec = OpChainSpec>,
     Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>>,
     EvmConfig: ConfigureEvm<Header = Header, Transaction = TransactionSigned>,
     Txs: OpPayloadTransactions,
+    Exclusions: PayloadExclusions,
 {
     type Attributes = OpPayloadBuilderAttributes;
     type BuiltPayload = OpBuiltPayload;
@@ -277,7 +313,7 @@ where
 ///
 /// Depending on whether the node acts as a sequencer and is allowed to include additional
 /// transactions (`no_tx_pool == false`):
-/// 4. include additional transactions
+/// 4. include the bundles targeting the block and additional transactions
 ///
 /// And finally
 /// 5. build the block: compute all roots (txs, state)
@@ -287,6 +323,8 @@ pub struct OpBuilder<Pool, Txs> {
     pool: Pool,
     /// Yields the best transaction to include if transactions from the mempool are allowed.
     best: Txs,
+    /// Filters the mempool transactions that are excluded from the payload, if any.
+    exclusions: Option<TransactionExclusionFilter>,
 }
 
 impl<Pool, Txs> OpBuilder<Pool, Txs>
@@ -304,7 +342,7 @@ where
         EvmConfig: ConfigureEvm<Header = Header, Transaction = TransactionSigned>,
         DB: Database<Error = ProviderError>,
     {
-        let Self { pool, best } = self;
+        let Self { pool, best, exclusions } = self;
         debug!(target: "payload_builder", id=%ctx.payload_id(), parent_header = ?ctx.parent().hash(), parent_number = ctx.parent().number, "building new payload");
 
         // 1. apply eip-4788 pre block contract call
@@ -318,7 +356,15 @@ where
 
         // 4. if mem pool transactions are requested we execute them
         if !ctx.attributes().no_tx_pool {
-            let best_txs = best.best_transactions(pool, ctx.best_transaction_attributes());
+            let bundles = pool.bundles(ctx.block_number(), ctx.attributes().timestamp());
+            if ctx.execute_bundles(&mut info, state, bundles)?.is_some() {
+                return Ok(BuildOutcomeKind::Cancelled)
+            }
+
+            let best_txs = ExcludedPayloadTransactions {
+                inner: best.best_transactions(pool, ctx.best_transaction_attributes()),
+                exclusions,
+            };
             if ctx.execute_best_transactions::<_, Pool>(&mut info, state, best_txs)?.is_some() {
                 return Ok(BuildOutcomeKind::Cancelled)
             }
@@ -509,6 +555,37 @@ impl OpPayloadTransactions for () {
     }
 }
 
+/// Skips the transactions that are excluded from the payload, see [`TransactionExclusionFilter`].
+#[derive(Debug)]
+struct ExcludedPayloadTransactions<Txs> {
+    inner: Txs,
+    exclusions: Option<TransactionExclusionFilter>,
+}
+
+impl<Txs> PayloadTransactions for ExcludedPayloadTransactions<Txs>
+where
+    Txs: PayloadTransactions<Transaction = TransactionSigned>,
+{
+    type Transaction = TransactionSigned;
+
+    fn next(&mut self, ctx: ()) -> Option<RecoveredTx<TransactionSigned>> {
+        loop {
+            let tx = self.inner.next(ctx)?;
+            let Some(exclusions) = &self.exclusions else { return Some(tx) };
+            if !exclusions.is_excluded_call(tx.signer(), tx.to()) {
+                return Some(tx)
+            }
+            trace!(target: "payload_builder", tx=?tx.hash(), "skipping excluded transaction");
+            // descendants of the transaction can't be included either
+            self.inner.mark_invalid(tx.signer(), tx.nonce());
+        }
+    }
+
+    fn mark_invalid(&mut self, sender: Address, nonce: u64) {
+        self.inner.mark_invalid(sender, nonce)
+    }
+}
+
 /// Holds the state after execution
 #[derive(Debug)]
 pub struct ExecutedPayload {
@@ -836,6 +913,124 @@ where
         Ok(info)
     }
 
+    /// Executes the given bundles and updates the execution info.
+    ///
+    /// Each bundle is executed atomically: either all of its transactions are included in order or
+    /// none of them.
+    ///
+    /// Returns `Ok(Some(())` if the job was cancelled.
+    pub fn execute_bundles<DB, T>(
+        &self,
+        info: &mut ExecutionInfo,
+        db: &mut State<DB>,
+        bundles: Vec<Arc<ValidBundle<T>>>,
+    ) -> Result<Option<()>, PayloadBuilderError>
+    where
+        DB: Database<Error = ProviderError>,
+        T: PoolTransaction<Consensus = TransactionSigned>,
+    {
+        let block_gas_limit = self.block_gas_limit();
+        let base_fee = self.base_fee();
+
+        let env = EnvWithHandlerCfg::new_with_cfg_env(
+            self.initialized_cfg.clone(),
+            self.initialized_block_env.clone(),
+            TxEnv::default(),
+        );
+        let mut evm = self.evm_config.evm_with_env(&mut *db, env);
+
+        for bundle in bundles {
+            // check if the job was cancelled, if so we can exit early
+            if self.cancel.is_cancelled() {
+                return Ok(Some(()))
+            }
+
+            let bundle_gas_limit =
+                bundle.bundle.transactions.iter().map(|tx| tx.gas_limit()).sum::<u64>();
+            if info.cumulative_gas_used + bundle_gas_limit > block_gas_limit {
+                trace!(target: "payload_builder", bundle=%bundle.hash, ?bundle_gas_limit, "skipping bundle because it would exceed the block gas limit");
+                continue
+            }
+
+            // A sequencer's block should never contain deposit transactions from the pool.
+            if bundle.bundle.transactions.iter().any(|tx| tx.tx_type() == TxType::Deposit as u8) {
+                trace!(target: "payload_builder", bundle=%bundle.hash, "skipping bundle with deposit transaction");
+                continue
+            }
+
+            // snapshot the state so that the bundle can be rolled back if one of its transactions
+            // fails
+            let checkpoint = StateCheckpoint::new(evm.db());
+
+            let mut bundle_gas_used = info.cumulative_gas_used;
+            let mut bundle_fees = U256::ZERO;
+            let mut bundle_txs = Vec::with_capacity(bundle.bundle.transactions.len());
+            let mut bundle_receipts = Vec::with_capacity(bundle.bundle.transactions.len());
+            let mut failed = false;
+            for pool_tx in &bundle.bundle.transactions {
+                let tx = pool_tx.to_consensus();
+                *evm.tx_mut() = self.evm_config.tx_env(tx.as_signed(), tx.signer());
+
+                let ResultAndState { result, state } = match evm.transact() {
+                    Ok(res) => res,
+                    Err(EVMError::Transaction(err)) => {
+                        trace!(target: "payload_builder", bundle=%bundle.hash, %err, ?tx, "skipping bundle with invalid transaction");
+                        failed = true;
+                        break
+                    }
+                    Err(err) => {
+                        // this is an error that we should treat as fatal for this attempt
+                        return Err(PayloadBuilderError::EvmExecutionError(err))
+                    }
+                };
+
+                if !result.is_success() && !bundle.bundle.can_revert(pool_tx.hash()) {
+                    trace!(target: "payload_builder", bundle=%bundle.hash, ?tx, "skipping bundle with reverted transaction");
+                    failed = true;
+                    break
+                }
+
+                // commit changes
+                evm.db_mut().commit(state);
+
+                let gas_used = result.gas_used();
+                bundle_gas_used += gas_used;
+
+                bundle_receipts.push(Some(Receipt {
+                    tx_type: tx.tx_type(),
+                    success: result.is_success(),
+                    cumulative_gas_used: bundle_gas_used,
+                    logs: result.into_logs().into_iter().map(Into::into).collect(),
+                    deposit_nonce: None,
+                    deposit_receipt_version: None,
+                }));
+
+                let miner_fee = tx
+                    .effective_tip_per_gas(base_fee)
+                    .expect("fee is always valid; execution succeeded");
+                bundle_fees += U256::from(miner_fee) * U256::from(gas_used);
+
+                bundle_txs.push(tx);
+            }
+
+            if failed {
+                // roll back the transactions of the bundle that were already executed
+                checkpoint.revert(evm.db_mut());
+                continue
+            }
+
+            for tx in bundle_txs {
+                info.executed_senders.push(tx.signer());
+                info.executed_transactions.push(tx.into_signed());
+            }
+            info.receipts.extend(bundle_receipts);
+            info.cumulative_gas_used = bundle_gas_used;
+            info.total_fees += bundle_fees;
+        }
+
+        Ok(None)
+    }
+
     /// Executes the given best transactions and updates the execution info.
     ///
     /// Returns `Ok(Some(())` if the job was cancelled.
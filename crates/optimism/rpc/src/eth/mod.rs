@@ -21,8 +21,8 @@ use reth_network_api::NetworkInfo;
 use reth_node_builder::EthApiBuilderCtx;
 use reth_provider::{
     BlockNumReader, BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-    EvmEnvProvider, NodePrimitivesProvider, ProviderBlock, ProviderHeader, ProviderReceipt,
-    ProviderTx, StageCheckpointReader, StateProviderFactory,
+    EvmEnvProvider, LogIndexReader, NodePrimitivesProvider, ProviderBlock, ProviderHeader,
+    ProviderReceipt, ProviderTx, StageCheckpointReader, StateProviderFactory,
 };
 use reth_rpc::eth::{core::EthApiInner, DevSigner};
 use reth_rpc_eth_api::{
@@ -150,7 +150,8 @@ where
     N: OpNodeCore<
         Provider: ChainSpecProvider<ChainSpec: EthereumHardforks>
                       + BlockNumReader
-                      + StageCheckpointReader,
+                      + StageCheckpointReader
+                      + LogIndexReader,
         Network: NetworkInfo,
     >,
 {
@@ -1,6 +1,6 @@
 //! Loads and formats OP transaction RPC response.
 
-use alloy_consensus::{Signed, Transaction as _};
+use alloy_consensus::{Signed, Transaction as _, TxEnvelope};
 use alloy_primitives::{Bytes, PrimitiveSignature as Signature, Sealable, Sealed, B256};
 use alloy_rpc_types_eth::TransactionInfo;
 use op_alloy_consensus::OpTxEnvelope;
@@ -185,4 +185,33 @@ where
         };
         *input = input.slice(..4);
     }
+
+    fn otterscan_api_search_transaction(
+        tx: Self::Transaction,
+    ) -> Option<alloy_rpc_types_eth::Transaction> {
+        let alloy_rpc_types_eth::Transaction {
+            inner,
+            block_hash,
+            block_number,
+            transaction_index,
+            from,
+            effective_gas_price,
+        } = tx.inner;
+        // deposit transactions can't be represented as Ethereum transactions
+        let inner = match inner {
+            OpTxEnvelope::Legacy(tx) => TxEnvelope::Legacy(tx),
+            OpTxEnvelope::Eip2930(tx) => TxEnvelope::Eip2930(tx),
+            OpTxEnvelope::Eip1559(tx) => TxEnvelope::Eip1559(tx),
+            OpTxEnvelope::Eip7702(tx) => TxEnvelope::Eip7702(tx),
+            _ => return None,
+        };
+        Some(alloy_rpc_types_eth::Transaction {
+            inner,
+            block_hash,
+            block_number,
+            transaction_index,
+            from,
+            effective_gas_price,
+        })
+    }
 }
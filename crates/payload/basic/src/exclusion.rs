@@ -0,0 +1,228 @@
+//! Exclusion of transactions from locally built payloads.
+
+use alloy_primitives::{map::HashSet, Address, B256};
+use reth_provider::{AccountReader, ProviderResult, StateProviderBox, StateProviderFactory};
+use reth_tasks::TaskExecutor;
+use reth_transaction_pool::PoolTransaction;
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime},
+};
+use tracing::{debug, info, warn};
+
+/// The interval at which an exclusion list file is checked for modifications, see
+/// [`TransactionExclusions::reload_on_change`].
+pub const EXCLUSION_LIST_RELOAD_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Addresses and contract code hashes whose transactions are excluded from locally built
+/// payloads.
+///
+/// This only affects payload building: excluded transactions are still accepted by the pool and
+/// propagated to peers.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExclusionList {
+    /// Excluded senders and recipients.
+    #[serde(default)]
+    pub addresses: HashSet<Address>,
+    /// Code hashes of excluded contracts, which applies to transactions calling a contract with
+    /// any of these code hashes directly.
+    #[serde(default)]
+    pub code_hashes: HashSet<B256>,
+}
+
+impl ExclusionList {
+    /// Reads the list from the JSON file at the given path.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(io::Error::other)
+    }
+
+    /// Returns true if nothing is excluded.
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty() && self.code_hashes.is_empty()
+    }
+}
+
+/// A shared [`ExclusionList`] that can be updated while the node is running.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionExclusions {
+    list: Arc<RwLock<Arc<ExclusionList>>>,
+}
+
+impl TransactionExclusions {
+    /// Creates a new instance with the given list.
+    pub fn new(list: ExclusionList) -> Self {
+        Self { list: Arc::new(RwLock::new(Arc::new(list))) }
+    }
+
+    /// Loads the list from the given file and spawns a task that reloads it whenever the file is
+    /// modified, see [`Self::reload_on_change`].
+    pub fn load_and_watch(path: &Path, executor: &TaskExecutor) -> io::Result<Self> {
+        let exclusions = Self::new(ExclusionList::load(path)?);
+        executor.spawn(Box::pin(
+            exclusions.clone().reload_on_change(path.to_path_buf(), EXCLUSION_LIST_RELOAD_INTERVAL),
+        ));
+        Ok(exclusions)
+    }
+
+    /// Returns the current list.
+    pub fn get(&self) -> Arc<ExclusionList> {
+        self.list.read().unwrap().clone()
+    }
+
+    /// Replaces the current list, which applies to all payloads built afterwards.
+    pub fn set(&self, list: ExclusionList) {
+        *self.list.write().unwrap() = Arc::new(list);
+    }
+
+    /// Returns a filter for transactions of a payload built on top of the given parent block.
+    pub fn filter<Client: StateProviderFactory>(
+        &self,
+        client: &Client,
+        parent: B256,
+    ) -> ProviderResult<TransactionExclusionFilter> {
+        let list = self.get();
+        // code hashes are looked up in the state of the parent block, if there are any
+        let state = if list.code_hashes.is_empty() {
+            None
+        } else {
+            Some(client.state_by_block_hash(parent)?)
+        };
+        Ok(TransactionExclusionFilter { list, state })
+    }
+
+    /// Reloads the list from the given file whenever it is modified, checking every `interval`.
+    ///
+    /// If the file can't be read, the current list is kept.
+    pub async fn reload_on_change(self, path: PathBuf, interval: Duration) {
+        let mut last_modified = modified(&path);
+        let mut interval = tokio::time::interval(interval);
+        loop {
+            interval.tick().await;
+            let current = modified(&path);
+            if current == last_modified {
+                continue
+            }
+            last_modified = current;
+
+            match ExclusionList::load(&path) {
+                Ok(list) => {
+                    info!(target: "payload_builder", path=%path.display(), addresses=list.addresses.len(), code_hashes=list.code_hashes.len(), "reloaded exclusion list");
+                    self.set(list);
+                }
+                Err(err) => {
+                    warn!(target: "payload_builder", %err, path=%path.display(), "failed to reload exclusion list");
+                }
+            }
+        }
+    }
+}
+
+/// Provides the transactions that are excluded from a payload.
+///
+/// This is implemented by [`TransactionExclusions`] and by `()`, which doesn't exclude anything.
+pub trait PayloadExclusions: Clone + Send + Sync + 'static {
+    /// Returns the filter for a payload built on top of the given parent block, or `None` if
+    /// nothing is excluded.
+    fn exclusion_filter<Client: StateProviderFactory>(
+        &self,
+        client: &Client,
+        parent: B256,
+    ) -> ProviderResult<Option<TransactionExclusionFilter>>;
+}
+
+impl PayloadExclusions for () {
+    fn exclusion_filter<Client: StateProviderFactory>(
+        &self,
+        _client: &Client,
+        _parent: B256,
+    ) -> ProviderResult<Option<TransactionExclusionFilter>> {
+        Ok(None)
+    }
+}
+
+impl PayloadExclusions for TransactionExclusions {
+    fn exclusion_filter<Client: StateProviderFactory>(
+        &self,
+        client: &Client,
+        parent: B256,
+    ) -> ProviderResult<Option<TransactionExclusionFilter>> {
+        let filter = self.filter(client, parent)?;
+        Ok((!filter.is_empty()).then_some(filter))
+    }
+}
+
+/// Returns the modification time of the file at the given path.
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Decides which transactions are excluded from a payload, see [`TransactionExclusions::filter`].
+pub struct TransactionExclusionFilter {
+    list: Arc<ExclusionList>,
+    state: Option<StateProviderBox>,
+}
+
+impl TransactionExclusionFilter {
+    /// Returns true if nothing is excluded.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns true if the transaction must not be included in the payload.
+    ///
+    /// If the code hash of the recipient can't be looked up, the transaction is excluded.
+    pub fn is_excluded<T: PoolTransaction>(&self, tx: &T) -> bool {
+        self.is_excluded_call(tx.sender(), tx.to())
+    }
+
+    /// Returns true if a transaction of the sender to the given recipient must not be included in
+    /// the payload, see [`Self::is_excluded`].
+    pub fn is_excluded_call(&self, sender: Address, to: Option<Address>) -> bool {
+        if self.list.addresses.contains(&sender) {
+            return true
+        }
+        let Some(to) = to else { return false };
+        if self.list.addresses.contains(&to) {
+            return true
+        }
+
+        let Some(state) = &self.state else { return false };
+        match state.basic_account(to) {
+            Ok(account) => account
+                .and_then(|account| account.bytecode_hash)
+                .is_some_and(|hash| self.list.code_hashes.contains(&hash)),
+            Err(err) => {
+                debug!(target: "payload_builder", %err, %to, "failed to look up code hash for exclusion");
+                true
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for TransactionExclusionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TransactionExclusionFilter")
+            .field("list", &self.list)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_exclusion_list() {
+        let list: ExclusionList =
+            serde_json::from_str(r#"{"addresses":["0x0000000000000000000000000000000000000001"]}"#)
+                .unwrap();
+        assert!(list.addresses.contains(&Address::with_last_byte(1)));
+        assert!(list.code_hashes.is_empty());
+        assert!(!list.is_empty());
+    }
+}
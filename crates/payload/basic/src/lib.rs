@@ -8,7 +8,7 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
-use crate::metrics::PayloadBuilderMetrics;
+use crate::{metrics::PayloadBuilderMetrics, prewarm::PrewarmedState};
 use alloy_consensus::constants::EMPTY_WITHDRAWALS;
 use alloy_eips::{eip4895::Withdrawals, merge::SLOT_DURATION};
 use alloy_primitives::{Bytes, B256, U256};
@@ -18,7 +18,9 @@ use reth_chainspec::EthereumHardforks;
 use reth_evm::state_change::post_block_withdrawals_balance_increments;
 use reth_payload_builder::{KeepPayloadJobAlive, PayloadId, PayloadJob, PayloadJobGenerator};
 use reth_payload_builder_primitives::PayloadBuilderError;
-use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes, PayloadKind};
+use reth_payload_primitives::{
+    BuiltPayload, PayloadBuilderAttributes, PayloadKind, ProposerLookahead,
+};
 use reth_primitives::{proofs, SealedHeader};
 use reth_primitives_traits::constants::RETH_CLIENT_VERSION;
 use reth_provider::{BlockReaderIdExt, CanonStateNotification, StateProviderFactory};
@@ -41,9 +43,15 @@ use tokio::{
 };
 use tracing::{debug, trace, warn};
 
+mod exclusion;
 mod metrics;
+mod prewarm;
 mod stack;
 
+pub use exclusion::{
+    ExclusionList, PayloadExclusions, TransactionExclusionFilter, TransactionExclusions,
+    EXCLUSION_LIST_RELOAD_INTERVAL,
+};
 pub use stack::PayloadBuilderStack;
 
 /// The [`PayloadJobGenerator`] that creates [`BasicPayloadJob`]s.
@@ -65,6 +73,10 @@ pub struct BasicPayloadJobGenerator<Client, Pool, Tasks, Builder> {
     builder: Builder,
     /// Stored `cached_reads` for new payload jobs.
     pre_cached: Option<PrecachedState>,
+    /// The state read in the background for the transactions expected in the next payload.
+    prewarmed: PrewarmedState,
+    /// The upcoming slots the node is going to propose, if provided by the consensus layer.
+    proposer_lookahead: Option<ProposerLookahead>,
 }
 
 // === impl BasicPayloadJobGenerator ===
@@ -87,6 +99,8 @@ impl<Client, Pool, Tasks, Builder> BasicPayloadJobGenerator<Client, Pool, Tasks,
             config,
             builder,
             pre_cached: None,
+            prewarmed: Default::default(),
+            proposer_lookahead: None,
         }
     }
 
@@ -122,8 +136,20 @@ impl<Client, Pool, Tasks, Builder> BasicPayloadJobGenerator<Client, Pool, Tasks,
 
     /// Returns the pre-cached reads for the given parent header if it matches the cached state's
     /// block.
+    ///
+    /// This includes the prewarmed state if it was read on top of the same block.
     fn maybe_pre_cached(&self, parent: B256) -> Option<CachedReads> {
-        self.pre_cached.as_ref().filter(|pc| pc.block == parent).map(|pc| pc.cached.clone())
+        let mut cached =
+            self.pre_cached.as_ref().filter(|pc| pc.block == parent).map(|pc| pc.cached.clone())?;
+        if let Some(prewarmed) =
+            self.prewarmed.lock().unwrap().as_ref().filter(|pc| pc.block == parent)
+        {
+            // the changed state of the block takes precedence, both are based on the same state
+            let mut prewarmed = prewarmed.cached.clone();
+            prewarmed.extend(cached);
+            cached = prewarmed;
+        }
+        Some(cached)
     }
 }
 
@@ -212,7 +238,32 @@ where
             }
         }
 
-        self.pre_cached = Some(PrecachedState { block: committed.tip().hash(), cached });
+        let block = committed.tip().hash();
+        self.pre_cached = Some(PrecachedState { block, cached });
+
+        // without a lookahead we can't know whether the node is proposing the next slot
+        let timestamp = committed.tip().timestamp;
+        let proposing = self.proposer_lookahead.as_mut().is_none_or(|lookahead| {
+            lookahead.prune(timestamp);
+            lookahead.has_proposal_in(timestamp, timestamp + SLOT_DURATION.as_secs())
+        });
+
+        if proposing && self.config.prewarm_transactions > 0 {
+            // read the state the best transactions of the pool access in the background, so that
+            // it is cached when they are included in the next block
+            let accessed =
+                prewarm::best_transactions_access(&self.pool, self.config.prewarm_transactions);
+            let client = self.client.clone();
+            let prewarmed = Arc::clone(&self.prewarmed);
+            self.executor.spawn_blocking(Box::pin(async move {
+                prewarm::prewarm_state(&client, block, accessed, &prewarmed);
+            }));
+        }
+    }
+
+    fn on_proposer_lookahead(&mut self, lookahead: ProposerLookahead) {
+        trace!(target: "payload_builder", ?lookahead, "updated proposer lookahead");
+        self.proposer_lookahead = Some(lookahead);
     }
 }
 
@@ -261,6 +312,10 @@ pub struct BasicPayloadJobGeneratorConfig {
     deadline: Duration,
     /// Maximum number of tasks to spawn for building a payload.
     max_payload_tasks: usize,
+    /// Number of best pool transactions whose state is read after each new block.
+    ///
+    /// Disabled if 0.
+    prewarm_transactions: usize,
 }
 
 // === impl BasicPayloadJobGeneratorConfig ===
@@ -289,6 +344,16 @@ impl BasicPayloadJobGeneratorConfig {
         self
     }
 
+    /// Sets the number of best pool transactions whose accessed state is read in the background
+    /// after each new block, so that it is cached for the next payload.
+    ///
+    /// The state consists of the transactions' senders, recipients and access lists. Disabled if
+    /// 0.
+    pub const fn prewarm_transactions(mut self, prewarm_transactions: usize) -> Self {
+        self.prewarm_transactions = prewarm_transactions;
+        self
+    }
+
     /// Sets the data to include in the block's extra data field.
     ///
     /// Defaults to the current client version: `rlp(RETH_CLIENT_VERSION)`.
@@ -306,6 +371,7 @@ impl Default for BasicPayloadJobGeneratorConfig {
             // 12s slot time
             deadline: SLOT_DURATION,
             max_payload_tasks: 3,
+            prewarm_transactions: 0,
         }
     }
 }
@@ -0,0 +1,93 @@
+//! Warming of the state that the best pool transactions are going to access.
+
+use crate::PrecachedState;
+use alloy_primitives::{map::HashMap, Address, B256, U256};
+use reth_provider::StateProviderFactory;
+use reth_revm::{cached::CachedReads, database::StateProviderDatabase};
+use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use revm::Database;
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+};
+use tracing::{debug, trace};
+
+/// The shared slot the latest prewarmed state is stored in.
+pub(crate) type PrewarmedState = Arc<Mutex<Option<PrecachedState>>>;
+
+/// The state that transactions are expected to access, by account.
+pub(crate) type AccessedState = HashMap<Address, BTreeSet<B256>>;
+
+/// Collects the state the given number of best transactions of the pool are going to access: their
+/// senders, recipients and access lists.
+pub(crate) fn best_transactions_access<Pool: TransactionPool>(
+    pool: &Pool,
+    max_transactions: usize,
+) -> AccessedState {
+    let mut accessed = AccessedState::default();
+    for tx in pool.best_transactions().take(max_transactions) {
+        let tx = &tx.transaction;
+        accessed.entry(tx.sender()).or_default();
+        if let Some(to) = tx.to() {
+            accessed.entry(to).or_default();
+        }
+        for item in tx.access_list().into_iter().flat_map(|list| list.iter()) {
+            accessed.entry(item.address).or_default().extend(item.storage_keys.iter().copied());
+        }
+    }
+    accessed
+}
+
+/// Reads the accessed state on top of the given block into a [`CachedReads`] and stores it in the
+/// given slot.
+///
+/// Besides filling the cache of the next payload build, this loads the database pages of the state
+/// into the page cache, which also speeds up the execution of the next block if it includes these
+/// transactions.
+pub(crate) fn prewarm_state<Client: StateProviderFactory>(
+    client: &Client,
+    block: B256,
+    accessed: AccessedState,
+    slot: &Mutex<Option<PrecachedState>>,
+) {
+    let state = match client.state_by_block_hash(block) {
+        Ok(state) => state,
+        Err(err) => {
+            debug!(target: "payload_builder", %err, %block, "failed to get state for prewarming");
+            return
+        }
+    };
+
+    let mut cached = CachedReads::default();
+    let mut slots = 0;
+    {
+        let mut db = cached.as_db_mut(StateProviderDatabase::new(state));
+        for (address, keys) in &accessed {
+            if let Err(err) = warm_account(&mut db, *address, keys) {
+                debug!(target: "payload_builder", %err, %block, "failed to prewarm state");
+                return
+            }
+            slots += keys.len();
+        }
+    }
+    trace!(target: "payload_builder", %block, accounts = accessed.len(), slots, "prewarmed state");
+
+    *slot.lock().unwrap() = Some(PrecachedState { block, cached });
+}
+
+/// Reads the account, its code and the given storage slots from the database.
+fn warm_account<DB: Database>(
+    db: &mut DB,
+    address: Address,
+    keys: &BTreeSet<B256>,
+) -> Result<(), DB::Error> {
+    if let Some(account) = db.basic(address)? {
+        if !account.is_empty_code_hash() {
+            db.code_by_hash(account.code_hash)?;
+        }
+    }
+    for key in keys {
+        db.storage(address, U256::from_be_bytes(key.0))?;
+    }
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use reth_payload_primitives::PayloadTypes;
+use reth_payload_primitives::{PayloadFeeBreakdown, PayloadTypes};
 use std::{
     pin::Pin,
     task::{ready, Context, Poll},
@@ -20,6 +20,9 @@ pub enum Events<T: PayloadTypes> {
     /// Triggered by the CL whenever it asks for an execution payload.
     /// This event is only thrown if the CL is a validator.
     BuiltPayload(T::BuiltPayload),
+    /// The fee breakdown of the built payload, sent right after the
+    /// [`BuiltPayload`](Events::BuiltPayload) event.
+    BuiltPayloadFees(PayloadFeeBreakdown),
 }
 
 /// Represents a receiver for various payload events.
@@ -67,8 +70,8 @@ impl<T: PayloadTypes> Stream for BuiltPayloadStream<T> {
         loop {
             return match ready!(self.as_mut().project().st.poll_next(cx)) {
                 Some(Ok(Events::BuiltPayload(payload))) => Poll::Ready(Some(payload)),
-                Some(Ok(Events::Attributes(_))) => {
-                    // ignoring attributes
+                Some(Ok(Events::Attributes(_) | Events::BuiltPayloadFees(_))) => {
+                    // ignoring attributes and fee breakdowns
                     continue
                 }
                 Some(Err(err)) => {
@@ -97,7 +100,7 @@ impl<T: PayloadTypes> Stream for PayloadAttributeStream<T> {
         loop {
             return match ready!(self.as_mut().project().st.poll_next(cx)) {
                 Some(Ok(Events::Attributes(attr))) => Poll::Ready(Some(attr)),
-                Some(Ok(Events::BuiltPayload(_))) => {
+                Some(Ok(Events::BuiltPayload(_) | Events::BuiltPayloadFees(_))) => {
                     // ignoring payloads
                     continue
                 }
@@ -114,7 +114,7 @@ pub mod test_utils;
 
 pub use alloy_rpc_types::engine::PayloadId;
 pub use reth_payload_builder_primitives::PayloadBuilderError;
-pub use reth_payload_primitives::PayloadKind;
+pub use reth_payload_primitives::{PayloadKind, ProposerLookahead};
 pub use service::{
     PayloadBuilderHandle, PayloadBuilderService, PayloadServiceCommand, PayloadStore,
 };
@@ -122,4 +122,6 @@ pub use traits::{KeepPayloadJobAlive, PayloadJob, PayloadJobGenerator};
 
 // re-export the Ethereum engine primitives for convenience
 #[doc(inline)]
-pub use reth_ethereum_engine_primitives::{EthBuiltPayload, EthPayloadBuilderAttributes};
+pub use reth_ethereum_engine_primitives::{
+    EthBuiltPayload, EthPayloadBuilderAttributes, ExecutionPayloadEnvelopeV5,
+};
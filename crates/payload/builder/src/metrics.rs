@@ -4,6 +4,7 @@ use reth_metrics::{
     metrics::{Counter, Gauge},
     Metrics,
 };
+use reth_payload_primitives::PayloadFeeBreakdown;
 
 /// Payload builder service metrics
 #[derive(Metrics, Clone)]
@@ -23,6 +24,21 @@ pub(crate) struct PayloadBuilderServiceMetrics {
     pub(crate) resolved_revenue: Gauge,
     /// Current block returned as the resolved payload
     pub(crate) resolved_block: Gauge,
+    /// Base fees burned by the resolved payloads
+    pub(crate) resolved_base_fees_burned: Gauge,
+    /// Blob fees burned by the resolved payloads
+    pub(crate) resolved_blob_fees: Gauge,
+    /// Number of transactions in the resolved payloads
+    pub(crate) resolved_transactions: Gauge,
+    /// Number of blob transactions in the resolved payloads
+    pub(crate) resolved_blob_transactions: Gauge,
+    /// Lowest effective priority fee per gas in the resolved payloads
+    pub(crate) resolved_min_priority_fee_per_gas: Gauge,
+    /// Highest effective priority fee per gas in the resolved payloads
+    pub(crate) resolved_max_priority_fee_per_gas: Gauge,
+    /// Number of transactions in the resolved payloads paying a higher priority fee than the
+    /// transaction before them
+    pub(crate) resolved_tip_inversions: Gauge,
 }
 
 impl PayloadBuilderServiceMetrics {
@@ -47,4 +63,14 @@ impl PayloadBuilderServiceMetrics {
         self.resolved_block.set(block as f64);
         self.resolved_revenue.set(value)
     }
+
+    pub(crate) fn set_resolved_fees(&self, fees: &PayloadFeeBreakdown) {
+        self.resolved_base_fees_burned.set(f64::from(fees.base_fees_burned));
+        self.resolved_blob_fees.set(f64::from(fees.blob_fees));
+        self.resolved_transactions.set(fees.transactions as f64);
+        self.resolved_blob_transactions.set(fees.blob_transactions as f64);
+        self.resolved_min_priority_fee_per_gas.set(fees.min_priority_fee_per_gas as f64);
+        self.resolved_max_priority_fee_per_gas.set(fees.max_priority_fee_per_gas as f64);
+        self.resolved_tip_inversions.set(fees.tip_inversions as f64)
+    }
 }
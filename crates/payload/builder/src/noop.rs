@@ -53,6 +53,7 @@ where
                 PayloadServiceCommand::PayloadAttributes(_, tx) => tx.send(None).ok(),
                 PayloadServiceCommand::Resolve(_, _, tx) => tx.send(None).ok(),
                 PayloadServiceCommand::Subscribe(_) => None,
+                PayloadServiceCommand::ProposerLookahead(_) => None,
             };
         }
     }
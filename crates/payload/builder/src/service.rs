@@ -13,7 +13,9 @@ use reth_chain_state::CanonStateNotification;
 use reth_payload_builder_primitives::{
     Events, PayloadBuilder, PayloadBuilderError, PayloadEvents, PayloadStoreExt,
 };
-use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes, PayloadKind, PayloadTypes};
+use reth_payload_primitives::{
+    BuiltPayload, PayloadBuilderAttributes, PayloadKind, PayloadTypes, ProposerLookahead,
+};
 use std::{
     fmt,
     future::Future,
@@ -180,6 +182,13 @@ impl<T> PayloadBuilderHandle<T>
 where
     T: PayloadTypes,
 {
+    /// Sends the upcoming slots the node is going to propose to the service.
+    ///
+    /// See also [`PayloadJobGenerator::on_proposer_lookahead`].
+    pub fn set_proposer_lookahead(&self, lookahead: ProposerLookahead) {
+        let _ = self.to_service.send(PayloadServiceCommand::ProposerLookahead(lookahead));
+    }
+
     /// Creates a new payload builder handle for the given channel.
     ///
     /// Note: this is only used internally by the [`PayloadBuilderService`] to manage the payload
@@ -318,6 +327,10 @@ where
 
                 resolved_metrics
                     .set_resolved_revenue(payload.block().number, f64::from(payload.fees()));
+
+                let fees = payload.fee_breakdown();
+                resolved_metrics.set_resolved_fees(&fees);
+                payload_events.send(Events::BuiltPayloadFees(fees)).ok();
             }
             res.map(|p| p.into())
         };
@@ -443,6 +456,9 @@ where
                         let new_rx = this.payload_events.subscribe();
                         let _ = tx.send(new_rx);
                     }
+                    PayloadServiceCommand::ProposerLookahead(lookahead) => {
+                        this.generator.on_proposer_lookahead(lookahead);
+                    }
                 }
             }
 
@@ -475,6 +491,8 @@ pub enum PayloadServiceCommand<T: PayloadTypes> {
     ),
     /// Payload service events
     Subscribe(oneshot::Sender<broadcast::Receiver<Events<T>>>),
+    /// Update the upcoming slots the node is going to propose
+    ProposerLookahead(ProposerLookahead),
 }
 
 impl<T> fmt::Debug for PayloadServiceCommand<T>
@@ -494,6 +512,7 @@ where
             }
             Self::Resolve(f0, f1, _f2) => f.debug_tuple("Resolve").field(&f0).field(&f1).finish(),
             Self::Subscribe(f0) => f.debug_tuple("Subscribe").field(&f0).finish(),
+            Self::ProposerLookahead(f0) => f.debug_tuple("ProposerLookahead").field(&f0).finish(),
         }
     }
 }
@@ -2,7 +2,9 @@
 
 use reth_chain_state::CanonStateNotification;
 use reth_payload_builder_primitives::PayloadBuilderError;
-use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes, PayloadKind};
+use reth_payload_primitives::{
+    BuiltPayload, PayloadBuilderAttributes, PayloadKind, ProposerLookahead,
+};
 use std::future::Future;
 
 /// A type that can build a payload.
@@ -108,4 +110,12 @@ pub trait PayloadJobGenerator: Send + Sync {
     fn on_new_state(&mut self, new_state: CanonStateNotification) {
         let _ = new_state;
     }
+
+    /// Handles an updated proposer lookahead of the consensus layer.
+    ///
+    /// This is intended to restrict work done in anticipation of a payload job, like pre-building
+    /// or pre-warming, to the slots the node is actually going to propose.
+    fn on_proposer_lookahead(&mut self, lookahead: ProposerLookahead) {
+        let _ = lookahead;
+    }
 }
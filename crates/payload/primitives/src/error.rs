@@ -1,5 +1,6 @@
 //! Error types emitted by types or implementations of this crate.
 
+use crate::EngineApiMessageVersion;
 use alloy_primitives::B256;
 use alloy_rpc_types_engine::ForkchoiceUpdateError;
 use reth_errors::{ProviderError, RethError};
@@ -84,27 +85,68 @@ pub enum EngineObjectValidationError {
 /// Thrown when validating an execution payload OR payload attributes fails due to:
 /// * The existence of a new field that is not supported in the given engine method version, or
 /// * The absence of a field that is required in the given engine method version
+///
+/// Each variant carries the values that violated the rule, so that mismatches between the
+/// consensus and execution layer can be debugged from the error response.
 #[derive(thiserror::Error, Debug)]
 pub enum VersionSpecificValidationError {
     /// Thrown if the pre-V3 `PayloadAttributes` or `ExecutionPayload` contains a parent beacon
     /// block root
-    #[error("parent beacon block root not supported before V3")]
-    ParentBeaconBlockRootNotSupportedBeforeV3,
+    #[error("parent beacon block root not supported before V3, got {version:?}")]
+    ParentBeaconBlockRootNotSupportedBeforeV3 {
+        /// The version of the engine method.
+        version: EngineApiMessageVersion,
+    },
     /// Thrown if `engine_forkchoiceUpdatedV1` or `engine_newPayloadV1` contains withdrawals
     #[error("withdrawals not supported in V1")]
     WithdrawalsNotSupportedInV1,
     /// Thrown if `engine_forkchoiceUpdated` or `engine_newPayload` contains no withdrawals after
     /// Shanghai
-    #[error("no withdrawals post-Shanghai")]
-    NoWithdrawalsPostShanghai,
+    #[error("no withdrawals post-Shanghai, timestamp {timestamp}")]
+    NoWithdrawalsPostShanghai {
+        /// The timestamp of the payload or attributes.
+        timestamp: u64,
+    },
     /// Thrown if `engine_forkchoiceUpdated` or `engine_newPayload` contains withdrawals before
     /// Shanghai
-    #[error("withdrawals pre-Shanghai")]
-    HasWithdrawalsPreShanghai,
+    #[error("withdrawals pre-Shanghai, timestamp {timestamp}")]
+    HasWithdrawalsPreShanghai {
+        /// The timestamp of the payload or attributes.
+        timestamp: u64,
+    },
     /// Thrown if the `PayloadAttributes` or `ExecutionPayload` contains no parent beacon block
     /// root after Cancun
-    #[error("no parent beacon block root post-cancun")]
-    NoParentBeaconBlockRootPostCancun,
+    #[error("no parent beacon block root post-cancun, got {version:?}")]
+    NoParentBeaconBlockRootPostCancun {
+        /// The version of the engine method.
+        version: EngineApiMessageVersion,
+    },
+}
+
+impl VersionSpecificValidationError {
+    /// Returns the name of the validation rule that failed.
+    pub const fn rule(&self) -> &'static str {
+        match self {
+            Self::ParentBeaconBlockRootNotSupportedBeforeV3 { .. } => {
+                "parentBeaconBlockRootNotSupportedBeforeV3"
+            }
+            Self::WithdrawalsNotSupportedInV1 => "withdrawalsNotSupportedInV1",
+            Self::NoWithdrawalsPostShanghai { .. } => "noWithdrawalsPostShanghai",
+            Self::HasWithdrawalsPreShanghai { .. } => "hasWithdrawalsPreShanghai",
+            Self::NoParentBeaconBlockRootPostCancun { .. } => "noParentBeaconBlockRootPostCancun",
+        }
+    }
+
+    /// Returns the name of the field that violated the rule.
+    pub const fn field(&self) -> &'static str {
+        match self {
+            Self::ParentBeaconBlockRootNotSupportedBeforeV3 { .. } |
+            Self::NoParentBeaconBlockRootPostCancun { .. } => "parentBeaconBlockRoot",
+            Self::WithdrawalsNotSupportedInV1 |
+            Self::NoWithdrawalsPostShanghai { .. } |
+            Self::HasWithdrawalsPreShanghai { .. } => "withdrawals",
+        }
+    }
 }
 
 impl EngineObjectValidationError {
@@ -121,8 +163,13 @@ impl EngineObjectValidationError {
 #[derive(thiserror::Error, Debug)]
 pub enum InvalidPayloadAttributesError {
     /// Thrown if the timestamp of the payload attributes is invalid according to the engine specs.
-    #[error("parent beacon block root not supported before V3")]
-    InvalidTimestamp,
+    #[error("timestamp {timestamp} is not greater than head timestamp {head_timestamp}")]
+    InvalidTimestamp {
+        /// The timestamp of the payload attributes.
+        timestamp: u64,
+        /// The timestamp of the head block.
+        head_timestamp: u64,
+    },
     /// Another type of error that is not covered by the above variants.
     #[error("Invalid params: {0}")]
     InvalidParams(#[from] Box<dyn core::error::Error + Send + Sync>),
@@ -0,0 +1,123 @@
+use alloy_primitives::{B256, U256};
+use reth_primitives::SealedBlock;
+use serde::{Deserialize, Serialize};
+
+/// A breakdown of the value of a built payload, see [`BuiltPayload::fee_breakdown`].
+///
+/// [`BuiltPayload::fee_breakdown`]: crate::BuiltPayload::fee_breakdown
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayloadFeeBreakdown {
+    /// The hash of the built block.
+    pub block_hash: B256,
+    /// The number of the built block.
+    pub block_number: u64,
+    /// The number of transactions in the block.
+    pub transactions: u64,
+    /// The number of blob transactions in the block.
+    pub blob_transactions: u64,
+    /// The priority fees paid to the fee recipient, i.e. the value of the payload.
+    pub priority_fees: U256,
+    /// The base fees burned by the transactions of the block.
+    pub base_fees_burned: U256,
+    /// The blob fees burned by the blob transactions of the block.
+    pub blob_fees: U256,
+    /// The lowest effective priority fee per gas of a transaction in the block.
+    pub min_priority_fee_per_gas: u128,
+    /// The highest effective priority fee per gas of a transaction in the block.
+    pub max_priority_fee_per_gas: u128,
+    /// The number of transactions that pay a higher effective priority fee per gas than the
+    /// transaction before them.
+    ///
+    /// This is zero for blocks that are strictly ordered by priority fee. Transactions of the same
+    /// sender must be included in nonce order, so this may be non-zero regardless.
+    pub tip_inversions: u64,
+}
+
+impl PayloadFeeBreakdown {
+    /// Computes the breakdown of the given block with the given priority fees paid to the fee
+    /// recipient.
+    pub fn new(block: &SealedBlock, priority_fees: U256) -> Self {
+        let base_fee = block.base_fee_per_gas;
+        let mut breakdown = Self {
+            block_hash: block.hash(),
+            block_number: block.number,
+            transactions: block.body.transactions.len() as u64,
+            priority_fees,
+            base_fees_burned: U256::from(base_fee.unwrap_or_default()) * U256::from(block.gas_used),
+            blob_fees: U256::from(block.blob_fee().unwrap_or_default()) *
+                U256::from(block.blob_gas_used.unwrap_or_default()),
+            ..Default::default()
+        };
+
+        let mut previous_tip = None;
+        for tx in &block.body.transactions {
+            if tx.is_eip4844() {
+                breakdown.blob_transactions += 1;
+            }
+
+            let tip = tx.effective_tip_per_gas(base_fee).unwrap_or_default();
+            match previous_tip {
+                None => {
+                    breakdown.min_priority_fee_per_gas = tip;
+                    breakdown.max_priority_fee_per_gas = tip;
+                }
+                Some(previous_tip) => {
+                    breakdown.min_priority_fee_per_gas =
+                        breakdown.min_priority_fee_per_gas.min(tip);
+                    breakdown.max_priority_fee_per_gas =
+                        breakdown.max_priority_fee_per_gas.max(tip);
+                    if tip > previous_tip {
+                        breakdown.tip_inversions += 1;
+                    }
+                }
+            }
+            previous_tip = Some(tip);
+        }
+
+        breakdown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxEip1559;
+    use alloy_primitives::PrimitiveSignature as Signature;
+    use reth_primitives::{BlockBody, Header, SealedHeader, Transaction, TransactionSigned};
+
+    fn tx(max_priority_fee_per_gas: u128) -> TransactionSigned {
+        TransactionSigned::new_unhashed(
+            Transaction::Eip1559(TxEip1559 {
+                max_fee_per_gas: 100,
+                max_priority_fee_per_gas,
+                ..Default::default()
+            }),
+            Signature::test_signature(),
+        )
+    }
+
+    #[test]
+    fn fee_breakdown() {
+        let header = Header {
+            number: 1,
+            base_fee_per_gas: Some(10),
+            gas_used: 100_000,
+            ..Default::default()
+        };
+        let block = SealedBlock::new(
+            SealedHeader::seal(header),
+            BlockBody { transactions: vec![tx(5), tx(3), tx(4), tx(200)], ..Default::default() },
+        );
+
+        let breakdown = PayloadFeeBreakdown::new(&block, U256::from(1_000));
+        assert_eq!(breakdown.transactions, 4);
+        assert_eq!(breakdown.blob_transactions, 0);
+        assert_eq!(breakdown.priority_fees, U256::from(1_000));
+        assert_eq!(breakdown.base_fees_burned, U256::from(1_000_000));
+        assert_eq!(breakdown.blob_fees, U256::ZERO);
+        assert_eq!(breakdown.min_priority_fee_per_gas, 3);
+        assert_eq!(breakdown.max_priority_fee_per_gas, 90);
+        assert_eq!(breakdown.tip_inversions, 2);
+    }
+}
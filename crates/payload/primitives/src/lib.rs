@@ -24,6 +24,12 @@ pub use traits::{
 mod payload;
 pub use payload::PayloadOrAttributes;
 
+mod lookahead;
+pub use lookahead::ProposerLookahead;
+
+mod fees;
+pub use fees::PayloadFeeBreakdown;
+
 use reth_chainspec::EthereumHardforks;
 /// The types that are used by the engine API.
 pub trait PayloadTypes: Send + Sync + Unpin + core::fmt::Debug + Clone + 'static {
@@ -44,6 +50,7 @@ pub trait PayloadTypes: Send + Sync + Unpin + core::fmt::Debug + Clone + 'static
 /// * If V2, this ensures that the payload timestamp is pre-Cancun.
 /// * If V3, this ensures that the payload timestamp is within the Cancun timestamp.
 /// * If V4, this ensures that the payload timestamp is within the Prague timestamp.
+/// * If V5, this ensures that the payload timestamp is within the Amsterdam timestamp.
 ///
 /// Otherwise, this will return [`EngineObjectValidationError::UnsupportedFork`].
 pub fn validate_payload_timestamp(
@@ -117,6 +124,37 @@ pub fn validate_payload_timestamp(
         //    the payload does not fall within the time frame of the Prague fork.
         return Err(EngineObjectValidationError::UnsupportedFork)
     }
+
+    if version == EngineApiMessageVersion::V5 &&
+        !chain_spec.is_amsterdam_active_at_timestamp(timestamp)
+    {
+        // For `engine_newPayloadV5`, which carries the block access list of EIP-7928, client
+        // software **MUST** return `-38005: Unsupported fork` error if the `timestamp` of the
+        // payload does not fall within the time frame of the Amsterdam fork.
+        return Err(EngineObjectValidationError::UnsupportedFork)
+    }
+    Ok(())
+}
+
+/// Validates that a payload with the given timestamp is exchanged with a message version that
+/// carries the [EIP-7928](https://eips.ethereum.org/EIPS/eip-7928) block access list.
+///
+/// After Amsterdam, `engine_newPayloadV3`, `engine_newPayloadV4`, `engine_getPayloadV3` and
+/// `engine_getPayloadV4` return [`EngineObjectValidationError::UnsupportedFork`], since only
+/// `engine_newPayloadV5` and `engine_getPayloadV5` carry the block access list of the payload.
+///
+/// Payload attributes are not affected, payloads for Amsterdam are still requested with
+/// `engine_forkchoiceUpdatedV3`.
+pub fn validate_block_access_list_version(
+    chain_spec: impl EthereumHardforks,
+    version: EngineApiMessageVersion,
+    timestamp: u64,
+) -> Result<(), EngineObjectValidationError> {
+    if version < EngineApiMessageVersion::V5 &&
+        chain_spec.is_amsterdam_active_at_timestamp(timestamp)
+    {
+        return Err(EngineObjectValidationError::UnsupportedFork)
+    }
     Ok(())
 }
 
@@ -139,14 +177,19 @@ pub fn validate_withdrawals_presence<T: EthereumHardforks>(
                     .to_error(VersionSpecificValidationError::WithdrawalsNotSupportedInV1))
             }
         }
-        EngineApiMessageVersion::V2 | EngineApiMessageVersion::V3 | EngineApiMessageVersion::V4 => {
+        EngineApiMessageVersion::V2 |
+        EngineApiMessageVersion::V3 |
+        EngineApiMessageVersion::V4 |
+        EngineApiMessageVersion::V5 => {
             if is_shanghai_active && !has_withdrawals {
-                return Err(message_validation_kind
-                    .to_error(VersionSpecificValidationError::NoWithdrawalsPostShanghai))
+                return Err(message_validation_kind.to_error(
+                    VersionSpecificValidationError::NoWithdrawalsPostShanghai { timestamp },
+                ))
             }
             if !is_shanghai_active && has_withdrawals {
-                return Err(message_validation_kind
-                    .to_error(VersionSpecificValidationError::HasWithdrawalsPreShanghai))
+                return Err(message_validation_kind.to_error(
+                    VersionSpecificValidationError::HasWithdrawalsPreShanghai { timestamp },
+                ))
             }
         }
     };
@@ -236,14 +279,17 @@ pub fn validate_parent_beacon_block_root_presence<T: EthereumHardforks>(
         EngineApiMessageVersion::V1 | EngineApiMessageVersion::V2 => {
             if has_parent_beacon_block_root {
                 return Err(validation_kind.to_error(
-                    VersionSpecificValidationError::ParentBeaconBlockRootNotSupportedBeforeV3,
+                    VersionSpecificValidationError::ParentBeaconBlockRootNotSupportedBeforeV3 {
+                        version,
+                    },
                 ))
             }
         }
-        EngineApiMessageVersion::V3 | EngineApiMessageVersion::V4 => {
+        EngineApiMessageVersion::V3 | EngineApiMessageVersion::V4 | EngineApiMessageVersion::V5 => {
             if !has_parent_beacon_block_root {
-                return Err(validation_kind
-                    .to_error(VersionSpecificValidationError::NoParentBeaconBlockRootPostCancun))
+                return Err(validation_kind.to_error(
+                    VersionSpecificValidationError::NoParentBeaconBlockRootPostCancun { version },
+                ))
             }
         }
     };
@@ -318,7 +364,11 @@ where
         payload_or_attrs.message_validation_kind(),
         payload_or_attrs.timestamp(),
         payload_or_attrs.parent_beacon_block_root().is_some(),
-    )
+    )?;
+    if payload_or_attrs.message_validation_kind() == MessageValidationKind::Payload {
+        validate_block_access_list_version(chain_spec, version, payload_or_attrs.timestamp())?;
+    }
+    Ok(())
 }
 
 /// The version of Engine API message.
@@ -339,6 +389,10 @@ pub enum EngineApiMessageVersion {
     ///
     /// Added in the Prague hardfork.
     V4 = 4,
+    /// Version 5
+    ///
+    /// Added in the Amsterdam hardfork.
+    V5 = 5,
 }
 
 /// Determines how we should choose the payload to return.
@@ -366,9 +420,27 @@ pub enum PayloadKind {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reth_chainspec::{ChainSpecBuilder, EthereumHardfork, ForkCondition};
 
     #[test]
     fn version_ord() {
         assert!(EngineApiMessageVersion::V4 > EngineApiMessageVersion::V3);
     }
+
+    #[test]
+    fn block_access_list_version() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .with_fork(EthereumHardfork::Amsterdam, ForkCondition::Timestamp(100))
+            .build();
+
+        for version in [EngineApiMessageVersion::V3, EngineApiMessageVersion::V4] {
+            assert!(validate_block_access_list_version(&chain_spec, version, 99).is_ok());
+            assert!(matches!(
+                validate_block_access_list_version(&chain_spec, version, 100),
+                Err(EngineObjectValidationError::UnsupportedFork)
+            ));
+        }
+        assert!(validate_block_access_list_version(&chain_spec, EngineApiMessageVersion::V5, 100)
+            .is_ok());
+    }
 }
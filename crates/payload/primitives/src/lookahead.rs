@@ -0,0 +1,53 @@
+use std::collections::BTreeSet;
+
+/// The upcoming slots the node is going to propose blocks for, as announced by the consensus
+/// layer.
+///
+/// Slots are identified by their timestamp, which is the timestamp of the block proposed in them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProposerLookahead {
+    timestamps: BTreeSet<u64>,
+}
+
+impl ProposerLookahead {
+    /// Creates a new lookahead with the given slot timestamps.
+    pub fn new(timestamps: impl IntoIterator<Item = u64>) -> Self {
+        Self { timestamps: timestamps.into_iter().collect() }
+    }
+
+    /// Returns true if there is no upcoming proposal.
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+
+    /// Returns true if a block is going to be proposed in a slot after the given timestamp, up to
+    /// and including `until`.
+    pub fn has_proposal_in(&self, after: u64, until: u64) -> bool {
+        after < until && self.timestamps.range(after + 1..=until).next().is_some()
+    }
+
+    /// Removes all slots up to and including the given timestamp.
+    pub fn prune(&mut self, until: u64) {
+        self.timestamps = self.timestamps.split_off(&(until + 1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proposals_in_range() {
+        let mut lookahead = ProposerLookahead::new([24, 48]);
+        assert!(!lookahead.has_proposal_in(0, 12));
+        assert!(lookahead.has_proposal_in(12, 24));
+        assert!(!lookahead.has_proposal_in(24, 36));
+        assert!(lookahead.has_proposal_in(36, 48));
+
+        lookahead.prune(24);
+        assert!(!lookahead.has_proposal_in(12, 24));
+        assert!(!lookahead.is_empty());
+        lookahead.prune(48);
+        assert!(lookahead.is_empty());
+    }
+}
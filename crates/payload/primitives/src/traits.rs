@@ -1,3 +1,4 @@
+use crate::PayloadFeeBreakdown;
 use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
     eip7685::Requests,
@@ -16,6 +17,11 @@ pub trait BuiltPayload: Send + Sync + std::fmt::Debug {
     /// Returns the fees collected for the built block
     fn fees(&self) -> U256;
 
+    /// Returns the breakdown of the fees paid by the transactions of the built block.
+    fn fee_breakdown(&self) -> PayloadFeeBreakdown {
+        PayloadFeeBreakdown::new(self.block(), self.fees())
+    }
+
     /// Returns the entire execution data for the built block, if available.
     fn executed_block(&self) -> Option<ExecutedBlock> {
         None
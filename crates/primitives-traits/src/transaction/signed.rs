@@ -3,7 +3,7 @@
 use crate::{FillTxEnv, InMemorySize, MaybeArbitrary, MaybeCompact, MaybeSerde, TxType};
 use alloc::{fmt, vec::Vec};
 use alloy_eips::eip2718::{Decodable2718, Encodable2718};
-use alloy_primitives::{keccak256, Address, PrimitiveSignature, TxHash, B256};
+use alloy_primitives::{keccak256, Address, PrimitiveSignature, Selector, TxHash, B256};
 use core::hash::Hash;
 
 /// Helper trait that unifies all behaviour required by block to support full node operations.
@@ -74,4 +74,14 @@ pub trait SignedTransaction:
     fn recalculate_hash(&self) -> B256 {
         keccak256(self.encoded_2718())
     }
+
+    /// Returns the 4-byte function selector of the transaction's calldata.
+    ///
+    /// Returns `None` for contract creations and for calls with less than 4 bytes of input.
+    fn function_selector(&self) -> Option<Selector> {
+        if self.is_create() {
+            return None
+        }
+        self.input().get(..4).map(Selector::from_slice)
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{segments::SegmentSet, Pruner};
 use alloy_eips::eip2718::Encodable2718;
 use reth_chainspec::MAINNET;
-use reth_config::PruneConfig;
+use reth_config::{PruneConfig, PruneThrottleConfig};
 use reth_db::{table::Value, transaction::DbTxMut};
 use reth_exex_types::FinishedExExHeight;
 use reth_primitives_traits::NodePrimitives;
@@ -9,7 +9,7 @@ use reth_provider::{
     providers::StaticFileProvider, BlockReader, DBProvider, DatabaseProviderFactory,
     NodePrimitivesProvider, PruneCheckpointWriter, StaticFileProviderFactory,
 };
-use reth_prune_types::PruneModes;
+use reth_prune_types::{BlockPins, NodeLoad, PruneModes};
 use std::time::Duration;
 use tokio::sync::watch;
 
@@ -26,6 +26,12 @@ pub struct PrunerBuilder {
     timeout: Option<Duration>,
     /// The finished height of all `ExEx`'s.
     finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    /// Blocks that must not be pruned.
+    block_pins: BlockPins,
+    /// Load of the node.
+    node_load: NodeLoad,
+    /// Adaptive scheduling configuration.
+    throttle: Option<PruneThrottleConfig>,
 }
 
 impl PrunerBuilder {
@@ -37,6 +43,7 @@ impl PrunerBuilder {
         Self::default()
             .block_interval(pruner_config.block_interval)
             .segments(pruner_config.segments)
+            .throttle(pruner_config.throttle)
     }
 
     /// Sets the minimum pruning interval measured in blocks.
@@ -75,6 +82,24 @@ impl PrunerBuilder {
         self
     }
 
+    /// Sets the pinned blocks that must not be pruned.
+    pub fn block_pins(mut self, block_pins: BlockPins) -> Self {
+        self.block_pins = block_pins;
+        self
+    }
+
+    /// Sets the load of the node, used to defer pruner runs if the throttle is configured.
+    pub fn node_load(mut self, node_load: NodeLoad) -> Self {
+        self.node_load = node_load;
+        self
+    }
+
+    /// Sets the adaptive scheduling configuration.
+    pub const fn throttle(mut self, throttle: Option<PruneThrottleConfig>) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
     /// Builds a [Pruner] from the current configuration with the given provider factory.
     pub fn build_with_provider_factory<PF>(self, provider_factory: PF) -> Pruner<PF::ProviderRW, PF>
     where
@@ -99,6 +124,8 @@ impl PrunerBuilder {
             self.timeout,
             self.finished_exex_height,
         )
+        .with_block_pins(self.block_pins)
+        .with_throttle(self.node_load, self.throttle)
     }
 
     /// Builds a [Pruner] from the current configuration with the given static file provider.
@@ -121,6 +148,8 @@ impl PrunerBuilder {
             self.timeout,
             self.finished_exex_height,
         )
+        .with_block_pins(self.block_pins)
+        .with_throttle(self.node_load, self.throttle)
     }
 }
 
@@ -132,6 +161,9 @@ impl Default for PrunerBuilder {
             delete_limit: MAINNET.prune_delete_limit,
             timeout: None,
             finished_exex_height: watch::channel(FinishedExExHeight::NoExExs).1,
+            block_pins: BlockPins::default(),
+            node_load: NodeLoad::default(),
+            throttle: None,
         }
     }
 }
@@ -1,5 +1,5 @@
 use reth_metrics::{
-    metrics::{Gauge, Histogram},
+    metrics::{Counter, Gauge, Histogram},
     Metrics,
 };
 use reth_prune_types::PruneSegment;
@@ -10,6 +10,8 @@ use std::collections::HashMap;
 pub(crate) struct Metrics {
     /// Pruning duration
     pub(crate) duration_seconds: Histogram,
+    /// Number of pruner runs deferred because the node was under load
+    pub(crate) deferred_runs: Counter,
     #[metric(skip)]
     prune_segments: HashMap<PruneSegment, PrunerSegmentMetrics>,
 }
@@ -5,11 +5,12 @@ use crate::{
     Metrics, PruneLimiter, PrunerError, PrunerEvent,
 };
 use alloy_primitives::BlockNumber;
+use reth_config::PruneThrottleConfig;
 use reth_exex_types::FinishedExExHeight;
 use reth_provider::{
     DBProvider, DatabaseProviderFactory, PruneCheckpointReader, PruneCheckpointWriter,
 };
-use reth_prune_types::{PruneProgress, PrunedSegmentInfo, PrunerOutput};
+use reth_prune_types::{BlockPins, NodeLoad, PruneProgress, PrunedSegmentInfo, PrunerOutput};
 use reth_tokio_util::{EventSender, EventStream};
 use std::time::{Duration, Instant};
 use tokio::sync::watch;
@@ -43,6 +44,16 @@ pub struct Pruner<Provider, PF> {
     timeout: Option<Duration>,
     /// The finished height of all `ExEx`'s.
     finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    /// Blocks that must not be pruned.
+    block_pins: BlockPins,
+    /// Load of the node, used to defer pruner runs if `throttle` is set.
+    node_load: NodeLoad,
+    /// Adaptive scheduling configuration. If set, pruner runs are deferred while the node is
+    /// under load, and the pruning is resumed as soon as it's idle if the previous run was
+    /// interrupted.
+    throttle: Option<PruneThrottleConfig>,
+    /// Whether the previous run was interrupted and there's more data to prune.
+    has_more_data: bool,
     #[doc(hidden)]
     metrics: Metrics,
     event_sender: EventSender<PrunerEvent>,
@@ -65,6 +76,10 @@ impl<Provider> Pruner<Provider, ()> {
             delete_limit,
             timeout,
             finished_exex_height,
+            block_pins: BlockPins::default(),
+            node_load: NodeLoad::default(),
+            throttle: None,
+            has_more_data: false,
             metrics: Metrics::default(),
             event_sender: Default::default(),
         }
@@ -92,6 +107,10 @@ where
             delete_limit,
             timeout,
             finished_exex_height,
+            block_pins: BlockPins::default(),
+            node_load: NodeLoad::default(),
+            throttle: None,
+            has_more_data: false,
             metrics: Metrics::default(),
             event_sender: Default::default(),
         }
@@ -102,6 +121,23 @@ impl<Provider, S> Pruner<Provider, S>
 where
     Provider: PruneCheckpointReader + PruneCheckpointWriter,
 {
+    /// Sets the pinned blocks that must not be pruned.
+    pub fn with_block_pins(mut self, block_pins: BlockPins) -> Self {
+        self.block_pins = block_pins;
+        self
+    }
+
+    /// Sets the load of the node and the adaptive scheduling configuration.
+    pub fn with_throttle(
+        mut self,
+        node_load: NodeLoad,
+        throttle: Option<PruneThrottleConfig>,
+    ) -> Self {
+        self.node_load = node_load;
+        self.throttle = throttle;
+        self
+    }
+
     /// Listen for events on the pruner.
     pub fn events(&self) -> EventStream<PrunerEvent> {
         self.event_sender.new_listener()
@@ -117,13 +153,12 @@ where
         provider: &Provider,
         tip_block_number: BlockNumber,
     ) -> PrunerResult {
-        let Some(tip_block_number) =
-            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
-        else {
+        let Some(tip_block_number) = self.adjust_tip_block_number(tip_block_number) else {
             return Ok(PruneProgress::Finished.into())
         };
         if tip_block_number == 0 {
             self.previous_tip_block_number = Some(tip_block_number);
+            self.has_more_data = false;
 
             debug!(target: "pruner", %tip_block_number, "Nothing to prune yet");
             return Ok(PruneProgress::Finished.into())
@@ -143,6 +178,7 @@ where
             self.prune_segments(provider, tip_block_number, &mut limiter)?;
 
         self.previous_tip_block_number = Some(tip_block_number);
+        self.has_more_data = !output.progress.is_finished();
 
         let elapsed = start.elapsed();
         self.metrics.duration_seconds.record(elapsed);
@@ -265,19 +301,60 @@ where
 
     /// Returns `true` if the pruning is needed at the provided tip block number.
     /// This determined by the check against minimum pruning interval and last pruned block number.
+    ///
+    /// If the throttle is configured, the pruning is deferred while the node is under load for at
+    /// most [`PruneThrottleConfig::max_deferred_blocks`] blocks, and is needed regardless of the
+    /// interval if the node is idle and the previous run was interrupted.
     pub fn is_pruning_needed(&self, tip_block_number: BlockNumber) -> bool {
-        let Some(tip_block_number) =
-            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
-        else {
+        let Some(tip_block_number) = self.adjust_tip_block_number(tip_block_number) else {
             return false
         };
 
         // Saturating subtraction is needed for the case when the chain was reverted, meaning
         // current block number might be less than the previous tip block number.
         // If that's the case, no pruning is needed as outdated data is also reverted.
-        if tip_block_number.saturating_sub(self.previous_tip_block_number.unwrap_or_default()) >=
-            self.min_block_interval as u64
-        {
+        let blocks_since_previous_run =
+            tip_block_number.saturating_sub(self.previous_tip_block_number.unwrap_or_default());
+
+        if let Some(throttle) = &self.throttle {
+            if self
+                .node_load
+                .exceeds(throttle.max_block_processing_latency, throttle.max_rpc_queue_depth)
+            {
+                if blocks_since_previous_run < throttle.max_deferred_blocks {
+                    debug!(
+                        target: "pruner",
+                        previous_tip_block_number = ?self.previous_tip_block_number,
+                        %tip_block_number,
+                        block_processing_latency = ?self.node_load.block_processing_latency(),
+                        rpc_queue_depth = %self.node_load.rpc_queue_depth(),
+                        "Node is under load, deferring pruning"
+                    );
+                    self.metrics.deferred_runs.increment(1);
+                    return false
+                }
+
+                debug!(
+                    target: "pruner",
+                    previous_tip_block_number = ?self.previous_tip_block_number,
+                    %tip_block_number,
+                    "Maximum number of deferred blocks reached"
+                );
+                return true
+            }
+
+            if self.has_more_data && blocks_since_previous_run < self.min_block_interval as u64 {
+                debug!(
+                    target: "pruner",
+                    previous_tip_block_number = ?self.previous_tip_block_number,
+                    %tip_block_number,
+                    "Node is idle, resuming interrupted pruning"
+                );
+                return true
+            }
+        }
+
+        if blocks_since_previous_run >= self.min_block_interval as u64 {
             debug!(
                 target: "pruner",
                 previous_tip_block_number = ?self.previous_tip_block_number,
@@ -290,6 +367,20 @@ where
         }
     }
 
+    /// Adjusts the tip block number to the finished `ExEx` height and the lowest pinned block, so
+    /// that neither the data required by `ExExs` nor the history of pinned blocks is pruned.
+    fn adjust_tip_block_number(&self, tip_block_number: BlockNumber) -> Option<BlockNumber> {
+        let tip_block_number =
+            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)?;
+        match self.block_pins.lowest_pinned_block() {
+            Some(pinned_block) if pinned_block < tip_block_number => {
+                debug!(target: "pruner", %tip_block_number, %pinned_block, "Adjusting tip block number to the lowest pinned block");
+                Some(pinned_block)
+            }
+            _ => Some(tip_block_number),
+        }
+    }
+
     /// Adjusts the tip block number to the finished `ExEx` height. This is needed to not prune more
     /// data than `ExExs` have processed. Depending on the height:
     /// - [`FinishedExExHeight::NoExExs`] returns the tip block number as no adjustment for `ExExs`
@@ -335,8 +426,12 @@ where
 #[cfg(test)]
 mod tests {
     use crate::Pruner;
+    use alloy_primitives::B256;
+    use reth_config::PruneThrottleConfig;
     use reth_exex_types::FinishedExExHeight;
     use reth_provider::test_utils::create_test_provider_factory;
+    use reth_prune_types::{BlockPins, NodeLoad};
+    use std::time::Duration;
 
     #[test]
     fn is_pruning_needed() {
@@ -376,5 +471,56 @@ mod tests {
         // Adjust tip block number to the finished ExEx height that reaches the threshold
         finished_exex_height_tx.send(FinishedExExHeight::Height(third_block_number)).unwrap();
         assert!(pruner.is_pruning_needed(third_block_number));
+
+        // Adjust tip block number to the pinned block that doesn't reach the threshold
+        let block_pins = BlockPins::default();
+        let pin = block_pins.pin(second_block_number, B256::ZERO, Duration::from_secs(60)).unwrap();
+        let pruner = pruner.with_block_pins(block_pins.clone());
+        assert!(!pruner.is_pruning_needed(third_block_number));
+
+        // Pruning is needed again after the block is unpinned
+        assert!(block_pins.unpin(pin.id));
+        assert!(pruner.is_pruning_needed(third_block_number));
+    }
+
+    #[test]
+    fn is_pruning_needed_throttled() {
+        let provider_factory = create_test_provider_factory();
+        let finished_exex_height_rx = tokio::sync::watch::channel(FinishedExExHeight::NoExExs).1;
+
+        let node_load = NodeLoad::default();
+        let throttle = PruneThrottleConfig {
+            max_block_processing_latency: Duration::from_millis(100),
+            max_rpc_queue_depth: 1,
+            max_deferred_blocks: 20,
+        };
+        let mut pruner =
+            Pruner::new_with_factory(provider_factory, vec![], 5, 0, None, finished_exex_height_rx)
+                .with_throttle(node_load.clone(), Some(throttle));
+        pruner.previous_tip_block_number = Some(10);
+
+        // Interval is reached and the node is idle
+        assert!(pruner.is_pruning_needed(15));
+
+        // Node is under load, pruning is deferred
+        let first = node_load.rpc_request_started();
+        let second = node_load.rpc_request_started();
+        assert!(!pruner.is_pruning_needed(15));
+
+        // Pruning was deferred for too long
+        assert!(pruner.is_pruning_needed(10 + throttle.max_deferred_blocks));
+
+        // Node is idle again, but the interval is not reached
+        drop(first);
+        drop(second);
+        assert!(!pruner.is_pruning_needed(11));
+
+        // Previous run was interrupted, pruning is resumed while the node is idle
+        pruner.has_more_data = true;
+        assert!(pruner.is_pruning_needed(11));
+
+        // Slow block processing defers the pruning
+        node_load.record_block_processing(Duration::from_secs(1));
+        assert!(!pruner.is_pruning_needed(11));
     }
 }
@@ -6,6 +6,8 @@
 //!   node after static file producer has finished
 
 use crate::{db_ext::DbTxPruneExt, segments::PruneInput, PrunerError};
+use alloy_consensus::TxReceipt;
+use alloy_primitives::Address;
 use reth_db::{table::Value, tables, transaction::DbTxMut};
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
@@ -13,11 +15,16 @@ use reth_provider::{
     PruneCheckpointWriter, TransactionsProvider,
 };
 use reth_prune_types::{PruneCheckpoint, PruneSegment, SegmentOutput, SegmentOutputCheckpoint};
+use std::collections::BTreeSet;
 use tracing::trace;
 
+/// Prunes receipts in the next transaction range of the input.
+///
+/// Receipts containing a log emitted by one of the `retained_addresses` are skipped.
 pub(crate) fn prune<Provider>(
     provider: &Provider,
     input: PruneInput,
+    retained_addresses: &BTreeSet<Address>,
 ) -> Result<SegmentOutput, PrunerError>
 where
     Provider: DBProvider<Tx: DbTxMut>
@@ -37,16 +44,30 @@ where
     let mut limiter = input.limiter;
 
     let mut last_pruned_transaction = tx_range_end;
+    let mut last_skipped_transaction = None;
     let (pruned, done) = provider.tx_ref().prune_table_with_range::<tables::Receipts<
         <Provider::Primitives as NodePrimitives>::Receipt,
     >>(
         tx_range,
         &mut limiter,
-        |_| false,
+        |(tx_num, receipt)| {
+            let skip = !retained_addresses.is_empty() &&
+                receipt.logs().iter().any(|log| retained_addresses.contains(&log.address));
+            if skip {
+                last_skipped_transaction = Some(*tx_num);
+            }
+            skip
+        },
         |row| last_pruned_transaction = row.0,
     )?;
     trace!(target: "pruner", %pruned, %done, "Pruned receipts");
 
+    // Retained receipts at the end of the range are checked as well, so the checkpoint can move
+    // past them.
+    if let Some(last_skipped_transaction) = last_skipped_transaction {
+        last_pruned_transaction = last_pruned_transaction.max(last_skipped_transaction);
+    }
+
     let last_pruned_block = provider
         .transaction_block(last_pruned_transaction)?
         .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
@@ -167,7 +188,7 @@ mod tests {
                 .sub(1);
 
             let provider = db.factory.database_provider_rw().unwrap();
-            let result = super::prune(&provider, input).unwrap();
+            let result = super::prune(&provider, input, &Default::default()).unwrap();
             limiter.increment_deleted_entries_count_by(result.pruned);
 
             assert_matches!(
@@ -1,6 +1,6 @@
 use crate::segments::{
-    AccountHistory, ReceiptsByLogs, Segment, SenderRecovery, StorageHistory, TransactionLookup,
-    UserReceipts,
+    AccountHistory, AddressTransactions, ReceiptsByLogs, Segment, SenderRecovery, StorageHistory,
+    TransactionLookup, TransactionSelectors, UserReceipts, WithdrawalHistory,
 };
 use alloy_eips::eip2718::Encodable2718;
 use reth_db::{table::Value, transaction::DbTxMut};
@@ -61,10 +61,15 @@ where
         let PruneModes {
             sender_recovery,
             transaction_lookup,
+            transaction_hash_index,
+            transaction_selectors,
             receipts,
             account_history,
             storage_history,
             receipts_log_filter,
+            withdrawal_history,
+            address_transactions,
+            retained_addresses,
         } = prune_modes;
 
         Self::default()
@@ -75,18 +80,40 @@ where
             // Static file receipts
             .segment(StaticFileReceipts::new(static_file_provider))
             // Account history
-            .segment_opt(account_history.map(AccountHistory::new))
+            .segment_opt(account_history.map(|mode| {
+                AccountHistory::new(mode).with_retained_addresses(retained_addresses.clone())
+            }))
             // Storage history
-            .segment_opt(storage_history.map(StorageHistory::new))
+            .segment_opt(storage_history.map(|mode| {
+                StorageHistory::new(mode).with_retained_addresses(retained_addresses.clone())
+            }))
             // User receipts
-            .segment_opt(receipts.map(UserReceipts::new))
+            .segment_opt(receipts.map(|mode| {
+                UserReceipts::new(mode).with_retained_addresses(retained_addresses.clone())
+            }))
             // Receipts by logs
+            .segment_opt((!receipts_log_filter.is_empty()).then(|| {
+                ReceiptsByLogs::new(receipts_log_filter.clone())
+                    .with_retained_addresses(retained_addresses.clone())
+            }))
+            // Transaction lookup
+            .segment_opt(transaction_lookup.map(|mode| {
+                TransactionLookup::new(mode)
+                    .with_retained_addresses(retained_addresses.clone())
+                    .with_static_file_hash_index(transaction_hash_index)
+            }))
+            // Transaction selectors, `PruneMode::Full` means the index is disabled altogether
             .segment_opt(
-                (!receipts_log_filter.is_empty())
-                    .then(|| ReceiptsByLogs::new(receipts_log_filter.clone())),
+                transaction_selectors.filter(|mode| !mode.is_full()).map(TransactionSelectors::new),
+            )
+            // Withdrawal history, `PruneMode::Full` means the index is disabled altogether
+            .segment_opt(
+                withdrawal_history.filter(|mode| !mode.is_full()).map(WithdrawalHistory::new),
+            )
+            // Address transactions, `PruneMode::Full` means the index is disabled altogether
+            .segment_opt(
+                address_transactions.filter(|mode| !mode.is_full()).map(AddressTransactions::new),
             )
-            // Transaction lookup
-            .segment_opt(transaction_lookup.map(TransactionLookup::new))
             // Sender recovery
             .segment_opt(sender_recovery.map(SenderRecovery::new))
     }
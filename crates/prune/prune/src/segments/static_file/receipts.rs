@@ -45,7 +45,7 @@ where
     }
 
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
-        crate::segments::receipts::prune(provider, input)
+        crate::segments::receipts::prune(provider, input, &Default::default())
     }
 
     fn save_checkpoint(
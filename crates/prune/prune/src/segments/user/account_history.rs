@@ -3,6 +3,7 @@ use crate::{
     segments::{user::history::prune_history_indices, PruneInput, Segment},
     PrunerError,
 };
+use alloy_primitives::Address;
 use itertools::Itertools;
 use reth_db::{tables, transaction::DbTxMut};
 use reth_db_api::models::ShardedKey;
@@ -11,6 +12,7 @@ use reth_prune_types::{
     PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
 };
 use rustc_hash::FxHashMap;
+use std::collections::BTreeSet;
 use tracing::{instrument, trace};
 
 /// Number of account history tables to prune in one step.
@@ -22,11 +24,19 @@ const ACCOUNT_HISTORY_TABLES_TO_PRUNE: usize = 2;
 #[derive(Debug)]
 pub struct AccountHistory {
     mode: PruneMode,
+    /// Addresses whose changesets and history indices are never pruned.
+    retained_addresses: BTreeSet<Address>,
 }
 
 impl AccountHistory {
     pub const fn new(mode: PruneMode) -> Self {
-        Self { mode }
+        Self { mode, retained_addresses: BTreeSet::new() }
+    }
+
+    /// Sets the addresses whose changesets and history indices are never pruned.
+    pub fn with_retained_addresses(mut self, retained_addresses: BTreeSet<Address>) -> Self {
+        self.retained_addresses = retained_addresses;
+        self
     }
 }
 
@@ -83,7 +93,7 @@ where
             provider.tx_ref().prune_table_with_range::<tables::AccountChangeSets>(
                 range,
                 &mut limiter,
-                |_| false,
+                |(_, account)| self.retained_addresses.contains(&account.address),
                 |(block_number, account)| {
                     highest_deleted_accounts.insert(account.address, block_number);
                     last_changeset_pruned_block = Some(block_number);
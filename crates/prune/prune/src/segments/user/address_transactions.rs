@@ -0,0 +1,204 @@
+use crate::{
+    db_ext::DbTxPruneExt,
+    segments::{PruneInput, Segment, SegmentOutput},
+    PrunerError,
+};
+use alloy_consensus::Transaction;
+use reth_db::{table::Value, tables, transaction::DbTxMut};
+use reth_db_api::models::AddressTxNumber;
+use reth_primitives_traits::{NodePrimitives, SignedTransaction};
+use reth_provider::{BlockReader, DBProvider, StaticFileProviderFactory, TransactionsProvider};
+use reth_prune_types::{PruneMode, PrunePurpose, PruneSegment, SegmentOutputCheckpoint};
+use tracing::{instrument, trace};
+
+#[derive(Debug)]
+pub struct AddressTransactions {
+    mode: PruneMode,
+}
+
+impl AddressTransactions {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for AddressTransactions
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + BlockReader
+        + StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value>>,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::AddressTransactions
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let (start, end) = match input.get_next_tx_num_range(provider)? {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No address transaction entries to prune");
+                return Ok(SegmentOutput::done())
+            }
+        }
+        .into_inner();
+        let tx_range = start..=
+            Some(end)
+                .min(input.limiter.deleted_entries_limit_left().map(|left| start + left as u64 - 1))
+                .unwrap();
+        let tx_range_end = *tx_range.end();
+
+        // Retrieve transactions in the range and derive the index keys, keeping them ordered by
+        // transaction number so that a partial run still leaves a contiguous pruned range behind.
+        let transactions =
+            provider.static_file_provider().transactions_by_tx_range(tx_range.clone())?;
+
+        // Number of transactions retrieved from the static files should match the tx range count
+        if transactions.len() != tx_range.clone().count() {
+            return Err(PrunerError::InconsistentData(
+                "Unexpected number of transactions retrieved by transaction number range",
+            ))
+        }
+
+        let mut keys = Vec::<AddressTxNumber>::with_capacity(transactions.len());
+        for (tx_num, transaction) in tx_range.zip(transactions) {
+            let sender = transaction
+                .recover_signer_unchecked()
+                .ok_or(PrunerError::InconsistentData("Failed to recover transaction sender"))?;
+            keys.extend(
+                std::iter::once(sender)
+                    .chain(transaction.to().filter(|to| *to != sender))
+                    .map(|address| (address, tx_num).into()),
+            );
+        }
+
+        let mut limiter = input.limiter;
+
+        let mut last_pruned_transaction = None;
+        let (pruned, done) =
+            provider.tx_ref().prune_table_with_iterator::<tables::AddressTransactions>(
+                keys,
+                &mut limiter,
+                |(key, _)| last_pruned_transaction = Some(key.tx_number()),
+            )?;
+
+        let done = done && tx_range_end == end;
+        trace!(target: "pruner", %pruned, %done, "Pruned address transactions");
+
+        let last_pruned_transaction = if done {
+            tx_range_end
+        } else {
+            last_pruned_transaction.unwrap_or_else(|| start.saturating_sub(1))
+        };
+
+        let last_pruned_block = provider
+            .transaction_block(last_pruned_transaction)?
+            .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
+            // If there's more address transaction entries to prune, set the checkpoint block
+            // number to previous, so we could finish pruning its entries on the next run.
+            .checked_sub(if done { 0 } else { 1 });
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: last_pruned_block,
+                tx_number: Some(last_pruned_transaction),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{AddressTransactions, PruneInput, PruneLimiter, Segment};
+    use alloy_consensus::Transaction;
+    use alloy_primitives::B256;
+    use reth_db::tables;
+    use reth_db_api::models::AddressTxNumber;
+    use reth_primitives_traits::SignedTransaction;
+    use reth_provider::{DatabaseProviderFactory, PruneCheckpointReader};
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneProgress, PruneSegment};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            0..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+        let mut entries = Vec::new();
+        let mut tx_num = 0;
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                let sender = transaction.recover_signer().unwrap();
+                entries.push((AddressTxNumber((sender, tx_num)), block.number));
+                if let Some(to) = transaction.to().filter(|to| *to != sender) {
+                    entries.push((AddressTxNumber((to, tx_num)), block.number));
+                }
+                tx_num += 1;
+            }
+        }
+        db.insert_address_transactions(entries.clone()).expect("insert address transactions");
+        assert_eq!(db.table::<tables::AddressTransactions>().unwrap().len(), entries.len());
+
+        let to_block = 5;
+        let segment = AddressTransactions::new(PruneMode::Before(to_block));
+        let input =
+            PruneInput { previous_checkpoint: None, to_block, limiter: PruneLimiter::default() };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert_eq!(result.progress, PruneProgress::Finished);
+        segment
+            .save_checkpoint(
+                &provider,
+                result.checkpoint.unwrap().as_prune_checkpoint(PruneMode::Before(to_block)),
+            )
+            .unwrap();
+        provider.commit().expect("commit");
+
+        let remaining = db.table::<tables::AddressTransactions>().unwrap();
+        assert_eq!(
+            remaining.len(),
+            entries.iter().filter(|(_, block_number)| *block_number > to_block).count()
+        );
+        assert!(remaining.iter().all(|(_, block_number)| *block_number > to_block));
+
+        let last_pruned_tx_number = blocks
+            .iter()
+            .take(to_block as usize + 1)
+            .map(|block| block.body.transactions.len() as u64)
+            .sum::<u64>() -
+            1;
+        assert_eq!(
+            db.factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::AddressTransactions)
+                .unwrap(),
+            Some(PruneCheckpoint {
+                block_number: Some(to_block),
+                tx_number: Some(last_pruned_tx_number),
+                prune_mode: PruneMode::Before(to_block),
+            })
+        );
+    }
+}
@@ -1,14 +1,20 @@
 mod account_history;
+mod address_transactions;
 mod history;
 mod receipts;
 mod receipts_by_logs;
 mod sender_recovery;
 mod storage_history;
 mod transaction_lookup;
+mod transaction_selectors;
+mod withdrawal_history;
 
 pub use account_history::AccountHistory;
+pub use address_transactions::AddressTransactions;
 pub use receipts::Receipts;
 pub use receipts_by_logs::ReceiptsByLogs;
 pub use sender_recovery::SenderRecovery;
 pub use storage_history::StorageHistory;
 pub use transaction_lookup::TransactionLookup;
+pub use transaction_selectors::TransactionSelectors;
+pub use withdrawal_history::WithdrawalHistory;
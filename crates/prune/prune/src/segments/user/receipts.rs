@@ -2,6 +2,7 @@ use crate::{
     segments::{PruneInput, Segment},
     PrunerError,
 };
+use alloy_primitives::Address;
 use reth_db::{table::Value, transaction::DbTxMut};
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
@@ -9,16 +10,25 @@ use reth_provider::{
     PruneCheckpointWriter, TransactionsProvider,
 };
 use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment, SegmentOutput};
+use std::collections::BTreeSet;
 use tracing::instrument;
 
 #[derive(Debug)]
 pub struct Receipts {
     mode: PruneMode,
+    /// Addresses whose receipts are never pruned.
+    retained_addresses: BTreeSet<Address>,
 }
 
 impl Receipts {
     pub const fn new(mode: PruneMode) -> Self {
-        Self { mode }
+        Self { mode, retained_addresses: BTreeSet::new() }
+    }
+
+    /// Sets the addresses whose receipts are never pruned.
+    pub fn with_retained_addresses(mut self, retained_addresses: BTreeSet<Address>) -> Self {
+        self.retained_addresses = retained_addresses;
+        self
     }
 }
 
@@ -44,7 +54,7 @@ where
 
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
     fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
-        crate::segments::receipts::prune(provider, input)
+        crate::segments::receipts::prune(provider, input, &self.retained_addresses)
     }
 
     fn save_checkpoint(
@@ -4,6 +4,7 @@ use crate::{
     PrunerError,
 };
 use alloy_consensus::TxReceipt;
+use alloy_primitives::Address;
 use reth_db::{table::Value, tables, transaction::DbTxMut};
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
@@ -13,15 +14,24 @@ use reth_prune_types::{
     PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment, ReceiptsLogPruneConfig, SegmentOutput,
     MINIMUM_PRUNING_DISTANCE,
 };
+use std::collections::BTreeSet;
 use tracing::{instrument, trace};
 #[derive(Debug)]
 pub struct ReceiptsByLogs {
     config: ReceiptsLogPruneConfig,
+    /// Addresses whose receipts are never pruned.
+    retained_addresses: BTreeSet<Address>,
 }
 
 impl ReceiptsByLogs {
     pub const fn new(config: ReceiptsLogPruneConfig) -> Self {
-        Self { config }
+        Self { config, retained_addresses: BTreeSet::new() }
+    }
+
+    /// Sets the addresses whose receipts are never pruned.
+    pub fn with_retained_addresses(mut self, retained_addresses: BTreeSet<Address>) -> Self {
+        self.retained_addresses = retained_addresses;
+        self
     }
 }
 
@@ -155,9 +165,10 @@ where
                 tx_range,
                 &mut limiter,
                 |(tx_num, receipt)| {
-                    let skip = num_addresses > 0 &&
+                    let skip = (num_addresses > 0 || !self.retained_addresses.is_empty()) &&
                         receipt.logs().iter().any(|log| {
-                            filtered_addresses[..num_addresses].contains(&&log.address)
+                            filtered_addresses[..num_addresses].contains(&&log.address) ||
+                                self.retained_addresses.contains(&log.address)
                         });
 
                     if skip {
@@ -3,12 +3,14 @@ use crate::{
     segments::{user::history::prune_history_indices, PruneInput, Segment, SegmentOutput},
     PrunerError,
 };
+use alloy_primitives::Address;
 use itertools::Itertools;
 use reth_db::{tables, transaction::DbTxMut};
 use reth_db_api::models::{storage_sharded_key::StorageShardedKey, BlockNumberAddress};
 use reth_provider::DBProvider;
 use reth_prune_types::{PruneMode, PrunePurpose, PruneSegment, SegmentOutputCheckpoint};
 use rustc_hash::FxHashMap;
+use std::collections::BTreeSet;
 use tracing::{instrument, trace};
 
 /// Number of storage history tables to prune in one step
@@ -20,11 +22,19 @@ const STORAGE_HISTORY_TABLES_TO_PRUNE: usize = 2;
 #[derive(Debug)]
 pub struct StorageHistory {
     mode: PruneMode,
+    /// Addresses whose changesets and history indices are never pruned.
+    retained_addresses: BTreeSet<Address>,
 }
 
 impl StorageHistory {
     pub const fn new(mode: PruneMode) -> Self {
-        Self { mode }
+        Self { mode, retained_addresses: BTreeSet::new() }
+    }
+
+    /// Sets the addresses whose changesets and history indices are never pruned.
+    pub fn with_retained_addresses(mut self, retained_addresses: BTreeSet<Address>) -> Self {
+        self.retained_addresses = retained_addresses;
+        self
     }
 }
 
@@ -81,7 +91,7 @@ where
             provider.tx_ref().prune_table_with_range::<tables::StorageChangeSets>(
                 BlockNumberAddress::range(range),
                 &mut limiter,
-                |_| false,
+                |(BlockNumberAddress((_, address)), _)| self.retained_addresses.contains(address),
                 |(BlockNumberAddress((block_number, address)), entry)| {
                     highest_deleted_storages.insert((address, entry.key), block_number);
                     last_changeset_pruned_block = Some(block_number);
@@ -3,27 +3,61 @@ use crate::{
     segments::{PruneInput, Segment, SegmentOutput},
     PrunerError,
 };
+use alloy_consensus::Transaction;
 use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::Address;
 use rayon::prelude::*;
-use reth_db::{tables, transaction::DbTxMut};
-use reth_provider::{BlockReader, DBProvider};
+use reth_db::{table::Value, tables, transaction::DbTxMut};
+use reth_primitives_traits::{NodePrimitives, SignedTransaction};
+use reth_provider::{BlockReader, DBProvider, StaticFileProviderFactory};
 use reth_prune_types::{PruneMode, PrunePurpose, PruneSegment, SegmentOutputCheckpoint};
+use std::collections::BTreeSet;
 use tracing::{instrument, trace};
 
 #[derive(Debug)]
 pub struct TransactionLookup {
     mode: PruneMode,
+    /// Addresses whose sent and received transactions are never pruned from the lookup table.
+    retained_addresses: BTreeSet<Address>,
+    /// Whether lookups are only pruned for blocks whose transactions can be looked up by hash in
+    /// static files instead.
+    static_file_hash_index: bool,
 }
 
 impl TransactionLookup {
     pub const fn new(mode: PruneMode) -> Self {
-        Self { mode }
+        Self { mode, retained_addresses: BTreeSet::new(), static_file_hash_index: false }
+    }
+
+    /// Sets the addresses whose sent and received transactions are never pruned from the lookup
+    /// table.
+    pub fn with_retained_addresses(mut self, retained_addresses: BTreeSet<Address>) -> Self {
+        self.retained_addresses = retained_addresses;
+        self
+    }
+
+    /// Sets whether the transaction static files are indexed by hash, in which case lookups are
+    /// only pruned for blocks whose transactions are in indexed static files.
+    pub const fn with_static_file_hash_index(mut self, static_file_hash_index: bool) -> Self {
+        self.static_file_hash_index = static_file_hash_index;
+        self
+    }
+
+    /// Returns `true` if the transaction was sent by or to one of the retained addresses.
+    fn is_retained<T: SignedTransaction>(&self, transaction: &T) -> bool {
+        !self.retained_addresses.is_empty() &&
+            (transaction.to().is_some_and(|to| self.retained_addresses.contains(&to)) ||
+                transaction
+                    .recover_signer_unchecked()
+                    .is_some_and(|sender| self.retained_addresses.contains(&sender)))
     }
 }
 
 impl<Provider> Segment<Provider> for TransactionLookup
 where
-    Provider: DBProvider<Tx: DbTxMut> + BlockReader<Transaction: Encodable2718>,
+    Provider: DBProvider<Tx: DbTxMut>
+        + BlockReader<Transaction: Encodable2718>
+        + StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value>>,
 {
     fn segment(&self) -> PruneSegment {
         PruneSegment::TransactionLookup
@@ -38,7 +72,24 @@ where
     }
 
     #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
-    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+    fn prune(
+        &self,
+        provider: &Provider,
+        mut input: PruneInput,
+    ) -> Result<SegmentOutput, PrunerError> {
+        if self.static_file_hash_index {
+            // Lookups can only be pruned once the transactions can be found by hash elsewhere
+            let indexed_block =
+                provider.static_file_provider().ensure_transaction_hash_indexes(input.to_block)?;
+            match indexed_block {
+                Some(indexed_block) => input.to_block = indexed_block,
+                None => {
+                    trace!(target: "pruner", "No indexed transaction static files");
+                    return Ok(SegmentOutput::done())
+                }
+            }
+        }
+
         let (start, end) = match input.get_next_tx_num_range(provider)? {
             Some(range) => range,
             None => {
@@ -53,11 +104,12 @@ where
                 .unwrap();
         let tx_range_end = *tx_range.end();
 
-        // Retrieve transactions in the range and calculate their hashes in parallel
+        // Retrieve transactions in the range and calculate their hashes in parallel, marking the
+        // ones that are retained
         let hashes = provider
             .transactions_by_tx_range(tx_range.clone())?
             .into_par_iter()
-            .map(|transaction| transaction.trie_hash())
+            .map(|transaction| (transaction.trie_hash(), self.is_retained(&transaction)))
             .collect::<Vec<_>>();
 
         // Number of transactions retrieved from the database should match the tx range count
@@ -73,7 +125,7 @@ where
         let mut last_pruned_transaction = None;
         let (pruned, done) =
             provider.tx_ref().prune_table_with_iterator::<tables::TransactionHashNumbers>(
-                hashes,
+                hashes.into_iter().filter_map(|(hash, retained)| (!retained).then_some(hash)),
                 &mut limiter,
                 |row| {
                     last_pruned_transaction =
@@ -81,11 +133,14 @@ where
                 },
             )?;
 
+        // Retained entries are skipped, so if all of the range was checked, it was pruned up to
+        // its end.
+        let last_pruned_transaction =
+            if done { tx_range_end } else { last_pruned_transaction.unwrap_or(tx_range_end) };
+
         let done = done && tx_range_end == end;
         trace!(target: "pruner", %pruned, %done, "Pruned transaction lookup");
 
-        let last_pruned_transaction = last_pruned_transaction.unwrap_or(tx_range_end);
-
         let last_pruned_block = provider
             .transaction_block(last_pruned_transaction)?
             .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
@@ -117,13 +172,14 @@ mod tests {
         Itertools,
     };
     use reth_db::tables;
+    use reth_primitives_traits::SignedTransaction;
     use reth_provider::{DatabaseProviderFactory, PruneCheckpointReader};
     use reth_prune_types::{
         PruneCheckpoint, PruneInterruptReason, PruneMode, PruneProgress, PruneSegment,
     };
     use reth_stages::test_utils::{StorageKind, TestStageDB};
     use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
-    use std::ops::Sub;
+    use std::{collections::BTreeSet, ops::Sub};
 
     #[test]
     fn prune() {
@@ -252,4 +308,63 @@ mod tests {
         test_prune(6, (PruneProgress::Finished, 2));
         test_prune(10, (PruneProgress::Finished, 8));
     }
+
+    #[test]
+    fn prune_retained() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let blocks = random_block_range(
+            &mut rng,
+            1..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Database(None)).expect("insert blocks");
+
+        let transactions =
+            blocks.iter().flat_map(|block| &block.body.transactions).collect::<Vec<_>>();
+        db.insert_tx_hash_numbers(
+            transactions.iter().enumerate().map(|(tx_num, tx)| (tx.hash(), tx_num as TxNumber)),
+        )
+        .expect("insert tx hash numbers");
+
+        // Retain the transactions of the first sender
+        let retained_sender = transactions[0].recover_signer().unwrap();
+        let retained = transactions
+            .iter()
+            .filter(|tx| tx.recover_signer() == Some(retained_sender))
+            .map(|tx| tx.hash())
+            .collect::<Vec<_>>();
+
+        let prune_mode = PruneMode::Full;
+        let segment = TransactionLookup::new(prune_mode)
+            .with_retained_addresses(BTreeSet::from([retained_sender]));
+        let input = PruneInput {
+            previous_checkpoint: None,
+            to_block: 10,
+            limiter: PruneLimiter::default(),
+        };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert_matches!(
+            result,
+            SegmentOutput {
+                progress: PruneProgress::Finished,
+                pruned,
+                checkpoint: Some(checkpoint)
+            } if pruned == transactions.len() - retained.len() &&
+                    checkpoint.tx_number == Some(transactions.len() as TxNumber - 1)
+        );
+        provider.commit().expect("commit");
+
+        let remaining = db
+            .table::<tables::TransactionHashNumbers>()
+            .unwrap()
+            .into_iter()
+            .map(|(hash, _)| hash)
+            .collect::<Vec<_>>();
+        assert_eq!(remaining.len(), retained.len());
+        assert!(retained.iter().all(|hash| remaining.contains(hash)));
+    }
 }
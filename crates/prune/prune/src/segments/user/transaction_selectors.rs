@@ -0,0 +1,214 @@
+use crate::{
+    db_ext::DbTxPruneExt,
+    segments::{PruneInput, Segment, SegmentOutput},
+    PrunerError,
+};
+use reth_db::{table::Value, tables, transaction::DbTxMut};
+use reth_db_api::models::SelectorTxNumber;
+use reth_primitives_traits::{NodePrimitives, SignedTransaction};
+use reth_provider::{BlockReader, DBProvider, StaticFileProviderFactory, TransactionsProvider};
+use reth_prune_types::{PruneMode, PrunePurpose, PruneSegment, SegmentOutputCheckpoint};
+use tracing::{instrument, trace};
+
+#[derive(Debug)]
+pub struct TransactionSelectors {
+    mode: PruneMode,
+}
+
+impl TransactionSelectors {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for TransactionSelectors
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + BlockReader
+        + StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value>>,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::TransactionSelectors
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let (start, end) = match input.get_next_tx_num_range(provider)? {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No transaction selector entries to prune");
+                return Ok(SegmentOutput::done())
+            }
+        }
+        .into_inner();
+        let tx_range = start..=
+            Some(end)
+                .min(input.limiter.deleted_entries_limit_left().map(|left| start + left as u64 - 1))
+                .unwrap();
+        let tx_range_end = *tx_range.end();
+
+        // Retrieve transactions in the range and derive the index keys, keeping them ordered by
+        // transaction number so that a partial run still leaves a contiguous pruned range behind.
+        let transactions =
+            provider.static_file_provider().transactions_by_tx_range(tx_range.clone())?;
+
+        // Number of transactions retrieved from the static files should match the tx range count
+        if transactions.len() != tx_range.clone().count() {
+            return Err(PrunerError::InconsistentData(
+                "Unexpected number of transactions retrieved by transaction number range",
+            ))
+        }
+
+        let keys = tx_range
+            .zip(transactions)
+            .filter_map(|(tx_num, transaction)| {
+                transaction.function_selector().map(|selector| (selector, tx_num).into())
+            })
+            .collect::<Vec<SelectorTxNumber>>();
+
+        let mut limiter = input.limiter;
+
+        let mut last_pruned_transaction = None;
+        let (pruned, done) =
+            provider.tx_ref().prune_table_with_iterator::<tables::TransactionSelectors>(
+                keys,
+                &mut limiter,
+                |(key, _)| last_pruned_transaction = Some(key.tx_number()),
+            )?;
+
+        // Transactions without a selector have no entries, so if all of the range was checked, it
+        // was pruned up to its end.
+        let last_pruned_transaction =
+            if done { Some(tx_range_end) } else { last_pruned_transaction };
+
+        let done = done && tx_range_end == end;
+        trace!(target: "pruner", %pruned, %done, "Pruned transaction selectors");
+
+        let progress = limiter.progress(done);
+        let Some(last_pruned_transaction) = last_pruned_transaction else {
+            // Nothing was pruned, so there's no new checkpoint.
+            return Ok(SegmentOutput { progress, pruned, checkpoint: None })
+        };
+
+        let last_pruned_block = provider
+            .transaction_block(last_pruned_transaction)?
+            .ok_or(PrunerError::InconsistentData("Block for transaction is not found"))?
+            // If there's more transaction selector entries to prune, set the checkpoint block
+            // number to previous, so we could finish pruning its entries on the next run.
+            .checked_sub(if done { 0 } else { 1 });
+
+        Ok(SegmentOutput {
+            progress,
+            pruned,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: last_pruned_block,
+                tx_number: Some(last_pruned_transaction),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{PruneInput, PruneLimiter, Segment, TransactionSelectors};
+    use alloy_consensus::TxLegacy;
+    use alloy_primitives::{TxKind, B256};
+    use reth_db::tables;
+    use reth_db_api::models::SelectorTxNumber;
+    use reth_primitives::Transaction;
+    use reth_primitives_traits::SignedTransaction;
+    use reth_provider::{DatabaseProviderFactory, PruneCheckpointReader};
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneProgress, PruneSegment};
+    use reth_stages::test_utils::{StorageKind, TestStageDB};
+    use reth_testing_utils::generators::{
+        self, random_block_range, sign_tx_with_random_key_pair, BlockRangeParams, Rng,
+    };
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+
+        let mut blocks = random_block_range(
+            &mut rng,
+            0..=10,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 2..3, ..Default::default() },
+        );
+        // Replace every other transaction with a contract call, so that only some of them are
+        // indexed.
+        for block in &mut blocks {
+            for transaction in block.body.transactions.iter_mut().step_by(2) {
+                let call = TxLegacy {
+                    to: TxKind::Call(rng.gen()),
+                    input: rng.gen::<[u8; 4]>().into(),
+                    ..Default::default()
+                };
+                *transaction = sign_tx_with_random_key_pair(&mut rng, Transaction::Legacy(call));
+            }
+        }
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("insert blocks");
+
+        let mut selectors = Vec::new();
+        let mut tx_num = 0;
+        for block in &blocks {
+            for transaction in &block.body.transactions {
+                if let Some(selector) = transaction.function_selector() {
+                    selectors.push((SelectorTxNumber((selector, tx_num)), block.number));
+                }
+                tx_num += 1;
+            }
+        }
+        db.insert_transaction_selectors(selectors.clone()).expect("insert transaction selectors");
+        assert_eq!(db.table::<tables::TransactionSelectors>().unwrap().len(), selectors.len());
+
+        let to_block = 5;
+        let segment = TransactionSelectors::new(PruneMode::Before(to_block));
+        let input =
+            PruneInput { previous_checkpoint: None, to_block, limiter: PruneLimiter::default() };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert_eq!(result.progress, PruneProgress::Finished);
+        segment
+            .save_checkpoint(
+                &provider,
+                result.checkpoint.unwrap().as_prune_checkpoint(PruneMode::Before(to_block)),
+            )
+            .unwrap();
+        provider.commit().expect("commit");
+
+        let remaining = db.table::<tables::TransactionSelectors>().unwrap();
+        assert_eq!(
+            remaining.len(),
+            selectors.iter().filter(|(_, block_number)| *block_number > to_block).count()
+        );
+        assert!(remaining.iter().all(|(_, block_number)| *block_number > to_block));
+
+        let last_pruned_tx_number = blocks
+            .iter()
+            .take(to_block as usize + 1)
+            .map(|block| block.body.transactions.len() as u64)
+            .sum::<u64>() -
+            1;
+        assert_eq!(
+            db.factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::TransactionSelectors)
+                .unwrap(),
+            Some(PruneCheckpoint {
+                block_number: Some(to_block),
+                tx_number: Some(last_pruned_tx_number),
+                prune_mode: PruneMode::Before(to_block),
+            })
+        );
+    }
+}
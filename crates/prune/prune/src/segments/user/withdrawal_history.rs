@@ -0,0 +1,175 @@
+use crate::{
+    segments::{PruneInput, Segment, SegmentOutput},
+    PrunerError,
+};
+use reth_db::tables;
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW},
+    transaction::{DbTx, DbTxMut},
+};
+use reth_provider::DBProvider;
+use reth_prune_types::{PruneMode, PrunePurpose, PruneSegment, SegmentOutputCheckpoint};
+use tracing::{instrument, trace};
+
+#[derive(Debug)]
+pub struct WithdrawalHistory {
+    mode: PruneMode,
+}
+
+impl WithdrawalHistory {
+    pub const fn new(mode: PruneMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl<Provider> Segment<Provider> for WithdrawalHistory
+where
+    Provider: DBProvider<Tx: DbTxMut>,
+{
+    fn segment(&self) -> PruneSegment {
+        PruneSegment::WithdrawalHistory
+    }
+
+    fn mode(&self) -> Option<PruneMode> {
+        Some(self.mode)
+    }
+
+    fn purpose(&self) -> PrunePurpose {
+        PrunePurpose::User
+    }
+
+    #[instrument(level = "trace", target = "pruner", skip(self, provider), ret)]
+    fn prune(&self, provider: &Provider, input: PruneInput) -> Result<SegmentOutput, PrunerError> {
+        let range = match input.get_next_block_range() {
+            Some(range) => range,
+            None => {
+                trace!(target: "pruner", "No withdrawal history to prune");
+                return Ok(SegmentOutput::done())
+            }
+        };
+        let range_end = *range.end();
+
+        let tx = provider.tx_ref();
+        let mut withdrawals_cursor = tx.cursor_read::<tables::BlockWithdrawals>()?;
+        let mut address_cursor = tx.cursor_write::<tables::AddressWithdrawals>()?;
+        let mut validator_cursor = tx.cursor_write::<tables::ValidatorWithdrawals>()?;
+
+        let mut limiter = input.limiter;
+
+        // Entries of a block are always pruned together from both tables, so that the checkpoint
+        // can point to the last fully pruned block.
+        let mut pruned = 0;
+        let mut done = true;
+        let mut last_pruned_block = range.start().checked_sub(1);
+        for entry in withdrawals_cursor.walk_range(range)? {
+            if limiter.is_limit_reached() {
+                done = false;
+                break
+            }
+
+            let (block_number, stored) = entry?;
+            for withdrawal in stored.withdrawals.iter() {
+                if address_cursor.seek_exact((withdrawal.address, block_number).into())?.is_some() {
+                    address_cursor.delete_current()?;
+                    limiter.increment_deleted_entries_count();
+                    pruned += 1;
+                }
+                if validator_cursor
+                    .seek_exact((withdrawal.validator_index, block_number).into())?
+                    .is_some()
+                {
+                    validator_cursor.delete_current()?;
+                    limiter.increment_deleted_entries_count();
+                    pruned += 1;
+                }
+            }
+            last_pruned_block = Some(block_number);
+        }
+        trace!(target: "pruner", %pruned, %done, "Pruned withdrawal history");
+
+        let last_pruned_block = if done { Some(range_end) } else { last_pruned_block };
+
+        let progress = limiter.progress(done);
+
+        Ok(SegmentOutput {
+            progress,
+            pruned,
+            checkpoint: Some(SegmentOutputCheckpoint {
+                block_number: last_pruned_block,
+                tx_number: None,
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::segments::{PruneInput, PruneLimiter, Segment, WithdrawalHistory};
+    use alloy_eips::eip4895::Withdrawal;
+    use alloy_primitives::Address;
+    use reth_db::tables;
+    use reth_provider::{DatabaseProviderFactory, PruneCheckpointReader};
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneProgress, PruneSegment};
+    use reth_stages::test_utils::TestStageDB;
+
+    #[test]
+    fn prune() {
+        let db = TestStageDB::default();
+
+        // two withdrawals per block, to the same address from different validators
+        let address = Address::with_last_byte(1);
+        let withdrawals = (0..=10)
+            .map(|block_number| {
+                let withdrawals = (0..2)
+                    .map(|i| Withdrawal {
+                        index: block_number * 2 + i,
+                        validator_index: i,
+                        address,
+                        amount: 1,
+                    })
+                    .collect::<Vec<_>>();
+                (block_number, withdrawals)
+            })
+            .collect::<Vec<_>>();
+        db.insert_withdrawal_history(withdrawals).expect("insert withdrawal history");
+        assert_eq!(db.table::<tables::AddressWithdrawals>().unwrap().len(), 11);
+        assert_eq!(db.table::<tables::ValidatorWithdrawals>().unwrap().len(), 22);
+
+        let to_block = 5;
+        let segment = WithdrawalHistory::new(PruneMode::Before(to_block));
+        let input =
+            PruneInput { previous_checkpoint: None, to_block, limiter: PruneLimiter::default() };
+
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = segment.prune(&provider, input).unwrap();
+        assert_eq!(result.progress, PruneProgress::Finished);
+        assert_eq!(result.pruned, 18);
+        segment
+            .save_checkpoint(
+                &provider,
+                result.checkpoint.unwrap().as_prune_checkpoint(PruneMode::Before(to_block)),
+            )
+            .unwrap();
+        provider.commit().expect("commit");
+
+        let remaining = db.table::<tables::AddressWithdrawals>().unwrap();
+        assert_eq!(remaining.len(), 5);
+        assert!(remaining.iter().all(|(key, _)| key.block_number() > to_block));
+        let remaining = db.table::<tables::ValidatorWithdrawals>().unwrap();
+        assert_eq!(remaining.len(), 10);
+        assert!(remaining.iter().all(|(key, _)| key.block_number() > to_block));
+
+        assert_eq!(
+            db.factory
+                .provider()
+                .unwrap()
+                .get_prune_checkpoint(PruneSegment::WithdrawalHistory)
+                .unwrap(),
+            Some(PruneCheckpoint {
+                block_number: Some(to_block),
+                tx_number: None,
+                prune_mode: PruneMode::Before(to_block),
+            })
+        );
+    }
+}
@@ -10,14 +10,20 @@
 
 mod checkpoint;
 mod event;
+mod load;
 mod mode;
+mod pin;
 mod pruner;
 mod segment;
 mod target;
 
 pub use checkpoint::PruneCheckpoint;
 pub use event::PrunerEvent;
+pub use load::{NodeLoad, RpcRequestGuard};
 pub use mode::PruneMode;
+pub use pin::{
+    BlockPin, BlockPinError, BlockPins, DEFAULT_MAX_BLOCK_PINS, DEFAULT_MAX_BLOCK_PIN_LIFETIME,
+};
 pub use pruner::{
     PruneInterruptReason, PruneProgress, PrunedSegmentInfo, PrunerOutput, SegmentOutput,
     SegmentOutputCheckpoint,
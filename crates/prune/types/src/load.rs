@@ -0,0 +1,108 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Load of the node, shared between the pruner and the components processing blocks and serving
+/// RPC requests.
+///
+/// The pruner defers its runs while the node is under load, see
+/// [`NodeLoad::exceeds`].
+#[derive(Debug, Clone, Default)]
+pub struct NodeLoad {
+    inner: Arc<NodeLoadInner>,
+}
+
+#[derive(Debug, Default)]
+struct NodeLoadInner {
+    /// Exponential moving average of the block processing latency, in microseconds.
+    block_processing_latency_micros: AtomicU64,
+    /// Number of RPC requests that are currently being served.
+    rpc_queue_depth: AtomicUsize,
+}
+
+impl NodeLoad {
+    /// Records the time it took to process a block.
+    ///
+    /// The latency is smoothed with an exponential moving average, so that a single slow block
+    /// doesn't defer pruning on its own.
+    pub fn record_block_processing(&self, latency: Duration) {
+        let sample = latency.as_micros().min(u64::MAX as u128) as u64;
+        let _ = self.inner.block_processing_latency_micros.fetch_update(
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+            |average| Some(if average == 0 { sample } else { average * 3 / 4 + sample / 4 }),
+        );
+    }
+
+    /// Returns the smoothed block processing latency.
+    pub fn block_processing_latency(&self) -> Duration {
+        Duration::from_micros(self.inner.block_processing_latency_micros.load(Ordering::Relaxed))
+    }
+
+    /// Marks the start of an RPC request. The request is considered finished once the returned
+    /// guard is dropped.
+    pub fn rpc_request_started(&self) -> RpcRequestGuard {
+        self.inner.rpc_queue_depth.fetch_add(1, Ordering::Relaxed);
+        RpcRequestGuard { inner: self.inner.clone() }
+    }
+
+    /// Returns the number of RPC requests that are currently being served.
+    pub fn rpc_queue_depth(&self) -> usize {
+        self.inner.rpc_queue_depth.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the block processing latency or the RPC queue depth exceeds the given
+    /// thresholds.
+    pub fn exceeds(
+        &self,
+        max_block_processing_latency: Duration,
+        max_rpc_queue_depth: usize,
+    ) -> bool {
+        self.block_processing_latency() > max_block_processing_latency ||
+            self.rpc_queue_depth() > max_rpc_queue_depth
+    }
+}
+
+/// Guard of an RPC request that is being served, see [`NodeLoad::rpc_request_started`].
+#[derive(Debug)]
+pub struct RpcRequestGuard {
+    inner: Arc<NodeLoadInner>,
+}
+
+impl Drop for RpcRequestGuard {
+    fn drop(&mut self) {
+        self.inner.rpc_queue_depth.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_load() {
+        let load = NodeLoad::default();
+        assert!(!load.exceeds(Duration::from_millis(100), 1));
+
+        // the first sample is taken as is, later ones are smoothed
+        load.record_block_processing(Duration::from_millis(400));
+        assert_eq!(load.block_processing_latency(), Duration::from_millis(400));
+        load.record_block_processing(Duration::ZERO);
+        assert_eq!(load.block_processing_latency(), Duration::from_millis(300));
+        assert!(load.exceeds(Duration::from_millis(100), 1));
+        assert!(!load.exceeds(Duration::from_millis(300), 1));
+
+        let first = load.rpc_request_started();
+        let second = load.rpc_request_started();
+        assert_eq!(load.rpc_queue_depth(), 2);
+        assert!(load.exceeds(Duration::from_millis(300), 1));
+
+        drop(first);
+        drop(second);
+        assert_eq!(load.rpc_queue_depth(), 0);
+    }
+}
@@ -0,0 +1,194 @@
+use alloy_primitives::{BlockNumber, B256};
+use parking_lot::Mutex;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+
+/// The default maximum number of active pins.
+pub const DEFAULT_MAX_BLOCK_PINS: usize = 1024;
+
+/// The default maximum time a block can be pinned for, including all pins of the block.
+pub const DEFAULT_MAX_BLOCK_PIN_LIFETIME: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A block that is protected from pruning until the pin expires or is removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockPin {
+    /// The unique id of the pin.
+    pub id: u64,
+    /// The number of the pinned block.
+    pub number: BlockNumber,
+    /// The hash of the pinned block.
+    pub hash: B256,
+    /// When the pin expires.
+    pub expires_at: Instant,
+}
+
+/// Errors of [`BlockPins::pin`].
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+pub enum BlockPinError {
+    /// The maximum number of active pins is reached.
+    #[error("too many pinned blocks, at most {0} blocks can be pinned")]
+    TooManyPins(usize),
+    /// The block was pinned for the maximum lifetime already.
+    #[error("block {0} was pinned for the maximum lifetime already")]
+    LifetimeExceeded(BlockNumber),
+}
+
+/// Registry of [`BlockPin`]s, shared between the pruner and the users of the pins.
+///
+/// The pruner doesn't prune past the lowest pinned block, so that the history required to serve
+/// the state of all pinned blocks is retained. Expired pins are removed lazily.
+///
+/// The number of active pins is limited, and so is the total time a block stays pinned, so that
+/// pruning can't be held back indefinitely by pinning the same block over and over again.
+#[derive(Debug, Clone)]
+pub struct BlockPins {
+    inner: Arc<Mutex<BlockPinsInner>>,
+    /// The maximum number of active pins.
+    max_pins: usize,
+    /// The maximum time a block can be pinned for, starting with its first active pin.
+    max_lifetime: Duration,
+}
+
+impl Default for BlockPins {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BLOCK_PINS, DEFAULT_MAX_BLOCK_PIN_LIFETIME)
+    }
+}
+
+#[derive(Debug, Default)]
+struct BlockPinsInner {
+    /// The id of the next pin.
+    next_id: u64,
+    /// All pins by id.
+    pins: BTreeMap<u64, BlockPin>,
+    /// When the pinned blocks were first pinned, while they have active pins.
+    pinned_since: HashMap<BlockNumber, Instant>,
+}
+
+impl BlockPinsInner {
+    fn remove_expired(&mut self) {
+        let now = Instant::now();
+        self.pins.retain(|_, pin| pin.expires_at > now);
+        let pinned = self.pins.values().map(|pin| pin.number).collect::<HashSet<_>>();
+        self.pinned_since.retain(|number, _| pinned.contains(number));
+    }
+}
+
+impl BlockPins {
+    /// Creates a new registry with the given maximum number of active pins and the maximum time
+    /// a block can be pinned for.
+    pub fn new(max_pins: usize, max_lifetime: Duration) -> Self {
+        Self { inner: Default::default(), max_pins, max_lifetime }
+    }
+
+    /// Pins the given block for the given time to live and returns the new pin.
+    ///
+    /// The pin expires earlier if the block would otherwise be pinned for longer than the
+    /// maximum lifetime.
+    pub fn pin(
+        &self,
+        number: BlockNumber,
+        hash: B256,
+        ttl: Duration,
+    ) -> Result<BlockPin, BlockPinError> {
+        let mut inner = self.inner.lock();
+        inner.remove_expired();
+        if inner.pins.len() >= self.max_pins {
+            return Err(BlockPinError::TooManyPins(self.max_pins))
+        }
+
+        let now = Instant::now();
+        let pinned_since = *inner.pinned_since.entry(number).or_insert(now);
+        let end_of_lifetime = pinned_since + self.max_lifetime;
+        if end_of_lifetime <= now {
+            return Err(BlockPinError::LifetimeExceeded(number))
+        }
+
+        let id = inner.next_id;
+        inner.next_id += 1;
+        let pin = BlockPin { id, number, hash, expires_at: (now + ttl).min(end_of_lifetime) };
+        inner.pins.insert(id, pin);
+        Ok(pin)
+    }
+
+    /// Removes the pin with the given id.
+    ///
+    /// Returns `false` if there is no such pin or it expired already.
+    pub fn unpin(&self, id: u64) -> bool {
+        let mut inner = self.inner.lock();
+        inner.remove_expired();
+        inner.pins.remove(&id).is_some()
+    }
+
+    /// Returns the pin with the given id, if it hasn't expired.
+    pub fn get(&self, id: u64) -> Option<BlockPin> {
+        let mut inner = self.inner.lock();
+        inner.remove_expired();
+        inner.pins.get(&id).copied()
+    }
+
+    /// Returns the most recently created pin that hasn't expired.
+    pub fn latest(&self) -> Option<BlockPin> {
+        let mut inner = self.inner.lock();
+        inner.remove_expired();
+        inner.pins.values().next_back().copied()
+    }
+
+    /// Returns the lowest block number of all pins that haven't expired.
+    pub fn lowest_pinned_block(&self) -> Option<BlockNumber> {
+        let mut inner = self.inner.lock();
+        inner.remove_expired();
+        inner.pins.values().map(|pin| pin.number).min()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pin_unpin_expire() {
+        let pins = BlockPins::default();
+        assert_eq!(pins.lowest_pinned_block(), None);
+
+        let first = pins.pin(10, B256::with_last_byte(10), Duration::from_secs(60)).unwrap();
+        let second = pins.pin(5, B256::with_last_byte(5), Duration::from_secs(60)).unwrap();
+        let expired = pins.pin(1, B256::with_last_byte(1), Duration::ZERO).unwrap();
+        assert_ne!(first.id, second.id);
+
+        assert_eq!(pins.get(expired.id), None);
+        assert_eq!(pins.latest(), Some(second));
+        assert_eq!(pins.lowest_pinned_block(), Some(5));
+
+        assert!(pins.unpin(second.id));
+        assert!(!pins.unpin(second.id));
+        assert_eq!(pins.latest(), Some(first));
+        assert_eq!(pins.lowest_pinned_block(), Some(10));
+    }
+
+    #[test]
+    fn limit_pins() {
+        let pins = BlockPins::new(1, Duration::from_secs(60));
+        let pin = pins.pin(10, B256::ZERO, Duration::from_secs(120)).unwrap();
+        // the pin expires at the end of the lifetime of the block
+        assert!(pin.expires_at <= Instant::now() + Duration::from_secs(60));
+
+        assert_eq!(
+            pins.pin(11, B256::ZERO, Duration::from_secs(60)),
+            Err(BlockPinError::TooManyPins(1))
+        );
+    }
+
+    #[test]
+    fn limit_lifetime() {
+        let pins = BlockPins::new(10, Duration::ZERO);
+        assert_eq!(
+            pins.pin(10, B256::ZERO, Duration::from_secs(60)),
+            Err(BlockPinError::LifetimeExceeded(10))
+        );
+    }
+}
@@ -39,15 +39,25 @@ pub enum PruneSegment {
     Headers,
     /// Prune segment responsible for the `Transactions` table.
     Transactions,
+    /// Prune segment responsible for the `TransactionSelectors` table.
+    TransactionSelectors,
+    /// Prune segment responsible for the `AddressWithdrawals` and `ValidatorWithdrawals` tables.
+    WithdrawalHistory,
+    /// Prune segment responsible for the `AddressTransactions` table.
+    AddressTransactions,
 }
 
 impl PruneSegment {
     /// Returns minimum number of blocks to left in the database for this segment.
     pub const fn min_blocks(&self, purpose: PrunePurpose) -> u64 {
         match self {
-            Self::SenderRecovery | Self::TransactionLookup | Self::Headers | Self::Transactions => {
-                0
-            }
+            Self::SenderRecovery |
+            Self::TransactionLookup |
+            Self::TransactionSelectors |
+            Self::WithdrawalHistory |
+            Self::AddressTransactions |
+            Self::Headers |
+            Self::Transactions => 0,
             Self::Receipts if purpose.is_static_file() => 0,
             Self::ContractLogs | Self::AccountHistory | Self::StorageHistory => {
                 MINIMUM_PRUNING_DISTANCE
@@ -1,5 +1,7 @@
 use crate::{PruneMode, ReceiptsLogPruneConfig};
+use alloy_primitives::Address;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeSet;
 
 /// Minimum distance from the tip necessary for the node to work correctly:
 /// 1. Minimum 2 epochs (32 blocks per epoch) required to handle any reorg according to the
@@ -18,6 +20,32 @@ pub struct PruneModes {
     /// Transaction Lookup pruning configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_lookup: Option<PruneMode>,
+    /// Whether to index the transactions in static files by their hash.
+    ///
+    /// If enabled, transaction lookups are only pruned for blocks whose transactions are in
+    /// static files with a hash index, and transactions are looked up by hash in these indices
+    /// instead.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub transaction_hash_index: bool,
+    /// Transaction selector index configuration.
+    ///
+    /// Unlike the other segments, the selector index is opt-in: it is only maintained if this is
+    /// set to a mode other than [`PruneMode::Full`], e.g. `PruneMode::Before(0)` to index the
+    /// whole chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_selectors: Option<PruneMode>,
+    /// Withdrawal history index configuration.
+    ///
+    /// Like the selector index, the withdrawal history index is opt-in: it is only maintained if
+    /// this is set to a mode other than [`PruneMode::Full`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub withdrawal_history: Option<PruneMode>,
+    /// Transaction-by-address index configuration.
+    ///
+    /// Like the selector index, the transaction-by-address index is opt-in: it is only maintained
+    /// if this is set to a mode other than [`PruneMode::Full`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_transactions: Option<PruneMode>,
     /// Receipts pruning configuration. This setting overrides `receipts_log_filter`
     /// and offers improved performance.
     #[serde(
@@ -37,6 +65,15 @@ pub struct PruneModes {
         deserialize_with = "deserialize_opt_prune_mode_with_min_blocks::<MINIMUM_PRUNING_DISTANCE, _>"
     )]
     pub storage_history: Option<PruneMode>,
+    /// Addresses whose receipts, transaction lookups and changesets are never pruned, regardless
+    /// of the configuration of these segments.
+    ///
+    /// Receipts are retained if they contain a log emitted by one of the addresses, transaction
+    /// lookups if the transaction was sent by or to one of the addresses, and account and storage
+    /// changesets together with their history indices if they belong to one of the addresses.
+    /// Data that was pruned before an address was added is not restored.
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub retained_addresses: BTreeSet<Address>,
     /// Receipts pruning configuration by retaining only those receipts that contain logs emitted
     /// by the specified addresses, discarding others. This setting is overridden by `receipts`.
     ///
@@ -56,9 +93,14 @@ impl PruneModes {
         Self {
             sender_recovery: Some(PruneMode::Full),
             transaction_lookup: Some(PruneMode::Full),
+            transaction_hash_index: false,
+            transaction_selectors: Some(PruneMode::Full),
+            withdrawal_history: Some(PruneMode::Full),
+            address_transactions: Some(PruneMode::Full),
             receipts: Some(PruneMode::Full),
             account_history: Some(PruneMode::Full),
             storage_history: Some(PruneMode::Full),
+            retained_addresses: Default::default(),
             receipts_log_filter: Default::default(),
         }
     }
@@ -68,6 +110,27 @@ impl PruneModes {
         self.receipts.is_some() || !self.receipts_log_filter.is_empty()
     }
 
+    /// Returns whether the transaction selector index should be maintained.
+    pub fn has_transaction_selectors_index(&self) -> bool {
+        self.transaction_selectors.is_some_and(|mode| !mode.is_full())
+    }
+
+    /// Returns whether the withdrawal history index should be maintained.
+    pub fn has_withdrawal_history_index(&self) -> bool {
+        self.withdrawal_history.is_some_and(|mode| !mode.is_full())
+    }
+
+    /// Returns whether the transaction-by-address index should be maintained.
+    pub fn has_address_transactions_index(&self) -> bool {
+        self.address_transactions.is_some_and(|mode| !mode.is_full())
+    }
+
+    /// Returns whether the data of the address is never pruned, see
+    /// [`PruneModes::retained_addresses`].
+    pub fn is_retained(&self, address: &Address) -> bool {
+        self.retained_addresses.contains(address)
+    }
+
     /// Returns true if all prune modes are set to [`None`].
     pub fn is_empty(&self) -> bool {
         self == &Self::none()
@@ -112,6 +112,12 @@ impl<T> BlockBatchRecord<T> {
 
     /// Returns the [`BundleRetention`] for the given block based on the configured prune modes.
     pub fn bundle_retention(&self, block_number: BlockNumber) -> BundleRetention {
+        // Changesets of retained addresses are never pruned, so reverts are always kept and the
+        // pruner takes care of the rest.
+        if !self.prune_modes.retained_addresses.is_empty() {
+            return BundleRetention::Reverts
+        }
+
         if self.tip.is_none_or(|tip| {
             !self
                 .prune_modes
@@ -155,7 +161,21 @@ impl<T> BlockBatchRecord<T> {
             // [`PruneSegment::Receipts`] takes priority over [`PruneSegment::ContractLogs`]
             self.prune_modes.receipts.is_some_and(|mode| mode.should_prune(block_number, tip))
         {
-            receipts.clear();
+            if self.prune_modes.retained_addresses.is_empty() {
+                receipts.clear();
+            } else {
+                // Receipts with logs emitted by retained addresses are never pruned
+                for receipt in receipts.iter_mut() {
+                    let inner_receipt = receipt.as_ref().expect("receipts have not been pruned");
+                    if !inner_receipt
+                        .logs()
+                        .iter()
+                        .any(|log| self.prune_modes.is_retained(&log.address))
+                    {
+                        receipt.take();
+                    }
+                }
+            }
             return Ok(())
         }
 
@@ -182,7 +202,9 @@ impl<T> BlockBatchRecord<T> {
                 // If there is an address_filter, it does not contain any of the
                 // contract addresses, then remove this receipt.
                 let inner_receipt = receipt.as_ref().expect("receipts have not been pruned");
-                if !inner_receipt.logs().iter().any(|log| filter.contains(&log.address)) {
+                if !inner_receipt.logs().iter().any(|log| {
+                    filter.contains(&log.address) || self.prune_modes.is_retained(&log.address)
+                }) {
                     receipt.take();
                 }
             }
@@ -382,4 +404,30 @@ mod tests {
         assert_eq!(recorder.receipts().len(), 4);
         assert_eq!(recorder.receipts()[3][0], Some(receipt3));
     }
+
+    // Test saving receipts with retained addresses under full receipts pruning
+    #[test]
+    fn test_save_receipts_with_retained_addresses() {
+        let prune_modes = PruneModes {
+            receipts: Some(PruneMode::Full),
+            retained_addresses: [Address::with_last_byte(1)].into(),
+            ..Default::default()
+        };
+
+        let mut recorder = BlockBatchRecord::new(prune_modes);
+        recorder.set_first_block(1);
+        recorder.set_tip(130);
+
+        // Receipt with a log from a retained address is kept, the other one is pruned
+        let mut retained = Receipt::default();
+        retained.logs.push(Log { address: Address::with_last_byte(1), ..Default::default() });
+        let mut pruned = Receipt::default();
+        pruned.logs.push(Log { address: Address::with_last_byte(2), ..Default::default() });
+
+        assert!(recorder.save_receipts(vec![retained.clone(), pruned]).is_ok());
+        assert_eq!(recorder.receipts()[0], vec![Some(retained), None]);
+
+        // Changesets are always kept for the pruner to filter them
+        assert!(matches!(recorder.bundle_retention(1), BundleRetention::Reverts));
+    }
 }
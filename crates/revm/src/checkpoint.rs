@@ -0,0 +1,65 @@
+//! Helper for reverting state changes that were committed to a [`State`].
+
+use revm::db::{
+    states::{CacheState, TransitionState},
+    State,
+};
+
+/// A snapshot of the cached accounts and pending transitions of a [`State`].
+///
+/// This is used to roll back a group of transactions that were committed to the state one by one,
+/// e.g. when a transaction of a bundle fails after the earlier ones were already applied. Changes
+/// that were already merged into the bundle state are not reverted, so the checkpoint must not be
+/// kept across [`State::merge_transitions`].
+#[derive(Debug, Clone)]
+pub struct StateCheckpoint {
+    cache: CacheState,
+    transition_state: Option<TransitionState>,
+}
+
+impl StateCheckpoint {
+    /// Takes a checkpoint of the given state.
+    pub fn new<DB>(state: &State<DB>) -> Self {
+        Self { cache: state.cache.clone(), transition_state: state.transition_state.clone() }
+    }
+
+    /// Reverts all changes that were committed to the state since the checkpoint was taken.
+    pub fn revert<DB>(self, state: &mut State<DB>) {
+        state.cache = self.cache;
+        state.transition_state = self.transition_state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{map::HashMap, Address, U256};
+    use revm::{
+        db::{states::bundle_state::BundleRetention, CacheDB, EmptyDB},
+        primitives::{Account, AccountInfo},
+        Database, DatabaseCommit,
+    };
+
+    #[test]
+    fn revert_committed_changes() {
+        let address = Address::with_last_byte(1);
+        let mut db = CacheDB::new(EmptyDB::default());
+        db.insert_account_info(
+            address,
+            AccountInfo { balance: U256::from(1), ..Default::default() },
+        );
+        let mut state = State::builder().with_database(db).with_bundle_update().build();
+
+        let checkpoint = StateCheckpoint::new(&state);
+        let info = state.basic(address).unwrap().unwrap();
+        let mut account = Account::from(AccountInfo { balance: U256::from(2), ..info });
+        account.mark_touch();
+        state.commit(HashMap::from_iter([(address, account)]));
+        assert_eq!(state.basic(address).unwrap().unwrap().balance, U256::from(2));
+
+        checkpoint.revert(&mut state);
+        assert_eq!(state.basic(address).unwrap().unwrap().balance, U256::from(1));
+        state.merge_transitions(BundleRetention::Reverts);
+        assert!(state.bundle_state.state.is_empty());
+    }
+}
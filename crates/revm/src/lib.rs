@@ -13,6 +13,9 @@ extern crate alloc;
 
 pub mod batch;
 
+/// Helper for reverting committed state changes.
+pub mod checkpoint;
+
 /// Cache database that reads from an underlying [`DatabaseRef`].
 /// Database adapters for payload building.
 pub mod cached;
@@ -149,6 +149,13 @@ where
             }
         };
 
+        if let Err(err) = set_socket_options(&self.endpoint, &self.cfg) {
+            on_ready
+                .send(Err(IpcServerStartError { endpoint: self.endpoint.clone(), source: err }))
+                .ok();
+            return;
+        }
+
         // signal that we're ready to accept connections
         on_ready.send(Ok(())).ok();
 
@@ -246,6 +253,26 @@ impl std::fmt::Debug for IpcServer {
     }
 }
 
+/// Applies the configured permissions and ownership to the socket file of the endpoint.
+#[cfg(unix)]
+fn set_socket_options(endpoint: &str, cfg: &Settings) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    if let Some(mode) = cfg.socket_permissions {
+        std::fs::set_permissions(endpoint, std::fs::Permissions::from_mode(mode))?;
+    }
+    if cfg.socket_uid.is_some() || cfg.socket_gid.is_some() {
+        std::os::unix::fs::chown(endpoint, cfg.socket_uid, cfg.socket_gid)?;
+    }
+    Ok(())
+}
+
+/// Named pipes have no file permissions, so this is a no-op.
+#[cfg(not(unix))]
+fn set_socket_options(_endpoint: &str, _cfg: &Settings) -> io::Result<()> {
+    Ok(())
+}
+
 /// Error thrown when server couldn't be started.
 #[derive(Debug, thiserror::Error)]
 #[error("failed to listen on ipc endpoint `{endpoint}`: {source}")]
@@ -550,6 +577,12 @@ pub struct Settings {
     message_buffer_capacity: u32,
     /// Custom tokio runtime to run the server on.
     tokio_runtime: Option<tokio::runtime::Handle>,
+    /// Permissions of the socket file, unix only.
+    socket_permissions: Option<u32>,
+    /// Owner of the socket file, unix only.
+    socket_uid: Option<u32>,
+    /// Group of the socket file, unix only.
+    socket_gid: Option<u32>,
 }
 
 impl Default for Settings {
@@ -562,6 +595,9 @@ impl Default for Settings {
             max_subscriptions_per_connection: 1024,
             message_buffer_capacity: 1024,
             tokio_runtime: None,
+            socket_permissions: None,
+            socket_uid: None,
+            socket_gid: None,
         }
     }
 }
@@ -640,6 +676,23 @@ impl<HttpMiddleware, RpcMiddleware> Builder<HttpMiddleware, RpcMiddleware> {
         self
     }
 
+    /// Set the permissions of the socket file, e.g. `0o600` to only allow the owner to connect.
+    ///
+    /// This only has an effect on unix, where the endpoint is a socket file.
+    pub const fn set_socket_permissions(mut self, mode: u32) -> Self {
+        self.settings.socket_permissions = Some(mode);
+        self
+    }
+
+    /// Set the owner and group of the socket file, `None` keeps the current owner or group.
+    ///
+    /// This only has an effect on unix, where the endpoint is a socket file.
+    pub const fn set_socket_owner(mut self, uid: Option<u32>, gid: Option<u32>) -> Self {
+        self.settings.socket_uid = uid;
+        self.settings.socket_gid = gid;
+        self
+    }
+
     /// Configure a custom [`tokio::runtime::Handle`] to run the server on.
     ///
     /// Default: [`tokio::spawn`]
@@ -905,6 +958,20 @@ mod tests {
         assert!(response.is_err());
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn can_set_the_socket_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let endpoint = &dummy_name();
+        let server = Builder::default().set_socket_permissions(0o600).build(endpoint.clone());
+        let handle = server.start(RpcModule::new(())).await.unwrap();
+        tokio::spawn(handle.stopped());
+
+        let mode = std::fs::metadata(endpoint).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
     #[tokio::test]
     async fn can_set_max_connections() {
         init_test_tracing();
@@ -17,11 +17,14 @@ pub trait AdminApi {
     fn remove_peer(&self, record: AnyNode) -> RpcResult<bool>;
 
     /// Adds the given node record to the trusted peerset.
+    ///
+    /// If the node only accepts and dials trusted peers, see `--trusted-only`, this allows the
+    /// given node to connect without a restart.
     #[method(name = "addTrustedPeer")]
     fn add_trusted_peer(&self, record: AnyNode) -> RpcResult<bool>;
 
     /// Removes a remote node from the trusted peer set, but it does not disconnect it
-    /// automatically.
+    /// automatically, unless the node only accepts trusted peers, see `--trusted-only`.
     ///
     /// Returns true if the peer was successfully removed.
     #[method(name = "removeTrustedPeer")]
@@ -60,6 +60,21 @@ pub trait EngineApi<Engine: EngineTypes> {
         execution_requests: Requests,
     ) -> RpcResult<PayloadStatus>;
 
+    /// Post Amsterdam payload handler
+    ///
+    /// This is the same as `newPayloadV4`, but additionally expects the RLP encoded
+    /// [EIP-7928](https://eips.ethereum.org/EIPS/eip-7928) block access list of the payload, which
+    /// is verified against the block access list of its execution.
+    #[method(name = "newPayloadV5")]
+    async fn new_payload_v5(
+        &self,
+        payload: ExecutionPayloadV3,
+        versioned_hashes: Vec<B256>,
+        parent_beacon_block_root: B256,
+        execution_requests: Requests,
+        block_access_list: Bytes,
+    ) -> RpcResult<PayloadStatus>;
+
     /// See also <https://github.com/ethereum/execution-apis/blob/6709c2a795b707202e93c4f2867fa0bf2640a84f/src/engine/paris.md#engine_forkchoiceupdatedv1>
     ///
     /// Caution: This should not accept the `withdrawals` field in the payload attributes.
@@ -152,6 +167,20 @@ pub trait EngineApi<Engine: EngineTypes> {
         payload_id: PayloadId,
     ) -> RpcResult<Engine::ExecutionPayloadEnvelopeV4>;
 
+    /// Post Amsterdam payload handler.
+    ///
+    /// This is the same as `getPayloadV4`, but additionally returns the RLP encoded
+    /// [EIP-7928](https://eips.ethereum.org/EIPS/eip-7928) block access list of the payload.
+    ///
+    /// Returns the most recent version of the payload that is available in the corresponding
+    /// payload build process at the time of receiving this call. Note:
+    /// > Provider software MAY stop the corresponding build process after serving this call.
+    #[method(name = "getPayloadV5")]
+    async fn get_payload_v5(
+        &self,
+        payload_id: PayloadId,
+    ) -> RpcResult<Engine::ExecutionPayloadEnvelopeV5>;
+
     /// See also <https://github.com/ethereum/execution-apis/blob/6452a6b194d7db269bf1dbd087a267251d3cc7f8/src/engine/shanghai.md#engine_getpayloadbodiesbyhashv1>
     #[method(name = "getPayloadBodiesByHashV1")]
     async fn get_payload_bodies_by_hash_v1(
@@ -0,0 +1,52 @@
+use alloy_eips::BlockNumHash;
+use alloy_primitives::BlockNumber;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// Reth API namespace for introspecting the execution extensions (`ExEx`'s) installed on the node,
+/// e.g. to monitor their health.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait ExExApi {
+    /// Returns the status of all installed `ExEx`'s and their Write-Ahead Log.
+    #[method(name = "getExExStatus")]
+    async fn get_exex_status(&self) -> RpcResult<ExExManagerInfo>;
+}
+
+/// The status of all installed `ExEx`'s, see `reth_getExExStatus`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExExManagerInfo {
+    /// The status of each installed `ExEx`.
+    pub exexes: Vec<ExExInfo>,
+    /// The head of the last notification the node sent to the `ExEx`'s, if any.
+    pub head: Option<BlockNumHash>,
+    /// The number of notifications that are buffered until all `ExEx`'s received them.
+    pub buffered_notifications: usize,
+    /// The number of blocks in the Write-Ahead Log.
+    pub wal_blocks: usize,
+    /// The size of the Write-Ahead Log in bytes.
+    pub wal_size_bytes: u64,
+    /// The lowest finished height among all `ExEx`'s.
+    ///
+    /// `None` if no `ExEx`'s are installed or not all of them finished a block yet.
+    pub lowest_finished_height: Option<BlockNumber>,
+}
+
+/// The status of a single `ExEx`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExExInfo {
+    /// The ID of the `ExEx`.
+    pub id: String,
+    /// The head of the last notification that was delivered to the `ExEx`, if any.
+    pub head: Option<BlockNumHash>,
+    /// The last block the `ExEx` finished, if any.
+    pub finished_height: Option<BlockNumHash>,
+    /// The number of notifications that were not delivered to the `ExEx` yet.
+    pub pending_notifications: usize,
+    /// The number of blocks between the head of the node and the finished height of the `ExEx`.
+    ///
+    /// `None` if the `ExEx` did not finish a block yet.
+    pub lag: Option<u64>,
+}
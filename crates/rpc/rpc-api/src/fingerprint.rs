@@ -0,0 +1,46 @@
+use alloy_primitives::{BlockHash, B256};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// Fingerprint namespace rpc interface that serves the recorded execution fingerprints of
+/// canonical blocks.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "fingerprint"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "fingerprint"))]
+pub trait FingerprintApi {
+    /// Returns the recorded fingerprints of the canonical blocks in the given range, ordered by
+    /// block number.
+    ///
+    /// Only blocks that were executed while the recorder was enabled and that are still in the
+    /// recorder files are available, other blocks are omitted.
+    #[method(name = "getBlockFingerprints")]
+    async fn fingerprint_get_block_fingerprints(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> RpcResult<Vec<BlockFingerprint>>;
+}
+
+/// A compact summary of the execution of a block that can be compared across nodes, see
+/// `fingerprint_getBlockFingerprints`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockFingerprint {
+    /// The number of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub number: u64,
+    /// The hash of the block.
+    pub hash: BlockHash,
+    /// The state root of the block.
+    pub state_root: B256,
+    /// The receipts root of the block.
+    pub receipts_root: B256,
+    /// The number of transactions in the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub transaction_count: u64,
+    /// The gas used by the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// The hash of the accounts and storage slots that were changed by the block, with their
+    /// values after the block.
+    pub bundle_state_hash: B256,
+}
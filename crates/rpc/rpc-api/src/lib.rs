@@ -18,19 +18,37 @@ mod admin;
 mod anvil;
 mod debug;
 mod engine;
+mod exex;
+mod fingerprint;
 mod ganache;
 mod hardhat;
 mod mev;
 mod miner;
 mod net;
+mod nonce;
 mod otterscan;
+mod pin;
+mod proposer;
 mod reth;
 mod rpc;
+mod stats;
 mod trace;
 mod txpool;
+mod usage;
 mod validation;
 mod web3;
 
+pub use exex::{ExExInfo, ExExManagerInfo};
+pub use fingerprint::BlockFingerprint;
+pub use nonce::{NextNonce, NonceGap};
+pub use pin::PinnedBlock;
+pub use reth::{
+    BalanceProofNotification, DecodedEvent, DecodedLog, DecodedParam, PeerEventKind,
+    PeerEventNotification, ReceiptProof, WithdrawalHistoryEntry,
+};
+pub use stats::{ChainStatsBucket, StatsInterval};
+pub use usage::{ApiKeyUsage, MethodClass};
+
 /// re-export of all server traits
 pub use servers::*;
 
@@ -40,20 +58,27 @@ pub mod servers {
         admin::AdminApiServer,
         debug::{DebugApiServer, DebugExecutionWitnessApiServer},
         engine::{EngineApiServer, EngineEthApiServer},
-        mev::{MevFullApiServer, MevSimApiServer},
+        exex::ExExApiServer,
+        fingerprint::FingerprintApiServer,
+        mev::{MevCallBundleApiServer, MevFullApiServer, MevSimApiServer},
         miner::MinerApiServer,
         net::NetApiServer,
+        nonce::NonceApiServer,
         otterscan::OtterscanServer,
+        pin::BlockPinApiServer,
+        proposer::ProposerApiServer,
         reth::RethApiServer,
         rpc::RpcApiServer,
+        stats::StatsApiServer,
         trace::TraceApiServer,
         txpool::TxPoolApiServer,
+        usage::RpcUsageApiServer,
         validation::BlockSubmissionValidationApiServer,
         web3::Web3ApiServer,
     };
     pub use reth_rpc_eth_api::{
         self as eth, EthApiServer, EthBundleApiServer, EthCallBundleApiServer, EthFilterApiServer,
-        EthPubSubApiServer,
+        EthPubSubApiServer, EthSendBundleApiServer, EthSendRawTransactionSyncApiServer,
     };
 }
 
@@ -69,20 +94,28 @@ pub mod clients {
         anvil::AnvilApiClient,
         debug::{DebugApiClient, DebugExecutionWitnessApiClient},
         engine::{EngineApiClient, EngineEthApiClient},
+        exex::ExExApiClient,
+        fingerprint::FingerprintApiClient,
         ganache::GanacheApiClient,
         hardhat::HardhatApiClient,
-        mev::{MevFullApiClient, MevSimApiClient},
+        mev::{MevCallBundleApiClient, MevFullApiClient, MevSimApiClient},
         miner::MinerApiClient,
         net::NetApiClient,
+        nonce::NonceApiClient,
         otterscan::OtterscanClient,
+        pin::BlockPinApiClient,
+        proposer::ProposerApiClient,
         reth::RethApiClient,
         rpc::RpcApiServer,
+        stats::StatsApiClient,
         trace::TraceApiClient,
         txpool::TxPoolApiClient,
+        usage::RpcUsageApiClient,
         validation::BlockSubmissionValidationApiClient,
         web3::Web3ApiClient,
     };
     pub use reth_rpc_eth_api::{
         EthApiClient, EthBundleApiClient, EthCallBundleApiClient, EthFilterApiClient,
+        EthSendBundleApiClient, EthSendRawTransactionSyncApiClient,
     };
 }
@@ -1,5 +1,6 @@
 use alloy_rpc_types_mev::{
-    SendBundleRequest, SendBundleResponse, SimBundleOverrides, SimBundleResponse,
+    EthCallBundle, EthCallBundleResponse, SendBundleRequest, SendBundleResponse,
+    SimBundleOverrides, SimBundleResponse,
 };
 use jsonrpsee::proc_macros::rpc;
 
@@ -38,3 +39,20 @@ pub trait MevFullApi {
         sim_overrides: SimBundleOverrides,
     ) -> jsonrpsee::core::RpcResult<SimBundleResponse>;
 }
+
+/// Mev rpc interface for simulating bundles on top of the pending block.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "mev"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "mev"))]
+pub trait MevCallBundleApi {
+    /// Same as `eth_callBundle`, but simulates the bundle at the top of the next block on the
+    /// state of the pending block built by the payload builder, instead of a given block.
+    ///
+    /// The `blockNumber` and `stateBlockNumber` of the request are ignored, the block overrides
+    /// (`coinbase`, `timestamp`, `gasLimit`, `difficulty`, `baseFee`) are applied to the pending
+    /// block env.
+    #[method(name = "callBundle")]
+    async fn call_bundle(
+        &self,
+        request: EthCallBundle,
+    ) -> jsonrpsee::core::RpcResult<EthCallBundleResponse>;
+}
@@ -12,6 +12,8 @@ pub trait MinerApi {
     fn set_extra(&self, record: Bytes) -> RpcResult<bool>;
 
     /// Sets the minimum accepted gas price for the miner.
+    ///
+    /// This sets the minimum priority fee transactions have to pay to enter the transaction pool.
     #[method(name = "setGasPrice")]
     fn set_gas_price(&self, gas_price: U128) -> RpcResult<bool>;
 
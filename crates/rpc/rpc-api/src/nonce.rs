@@ -0,0 +1,44 @@
+use alloy_primitives::Address;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// Reth API namespace for looking up the nonce to use for the next transaction of an account.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait NonceApi {
+    /// Returns the nonce of the account in the latest block and the next nonce that can be used
+    /// with the transactions of the account in the pool.
+    ///
+    /// Pooled transactions only count towards the next nonce if their nonces are consecutive to
+    /// the nonce of the account, so the gaps that keep the remaining transactions queued are
+    /// reported as well.
+    #[method(name = "getNextNonce")]
+    async fn get_next_nonce(&self, address: Address) -> RpcResult<NextNonce>;
+}
+
+/// The next usable nonce of an account, see `reth_getNextNonce`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NextNonce {
+    /// The nonce of the account in the latest block.
+    pub chain_nonce: u64,
+    /// The nonce to use for the next transaction of the account.
+    ///
+    /// This is the chain nonce plus the number of pooled transactions with consecutive nonces.
+    pub next_nonce: u64,
+    /// The highest nonce of the account's transactions in the pool, if any.
+    pub highest_pool_nonce: Option<u64>,
+    /// The number of pooled transactions that can't be executed because of a nonce gap.
+    pub queued: u64,
+    /// The missing nonces between the next nonce and the highest pooled nonce.
+    pub gaps: Vec<NonceGap>,
+}
+
+/// A range of missing nonces, see [`NextNonce`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonceGap {
+    /// The first missing nonce.
+    pub start: u64,
+    /// The last missing nonce, inclusive.
+    pub end: u64,
+}
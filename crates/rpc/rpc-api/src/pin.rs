@@ -0,0 +1,44 @@
+use alloy_eips::BlockId;
+use alloy_primitives::{BlockNumber, B256};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// Reth API namespace for pinning a block, e.g. for analytics jobs that need a consistent view of
+/// the chain across multiple calls.
+///
+/// While a block is pinned, the `"pinned"` block tag resolves to the most recently pinned block
+/// and `"pinned:<id>"` to the block of the pin with the given id, in all methods that accept a
+/// block number or tag. The history of pinned blocks is not pruned until the pin expires or is
+/// removed.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait BlockPinApi {
+    /// Pins the given block for the given time to live in seconds, or a default time to live if
+    /// not set.
+    #[method(name = "pinBlock")]
+    async fn pin_block(&self, block_id: BlockId, ttl: Option<u64>) -> RpcResult<PinnedBlock>;
+
+    /// Removes the pin with the given id.
+    ///
+    /// Returns `false` if there is no such pin or it expired already.
+    #[method(name = "unpinBlock")]
+    async fn unpin_block(&self, id: u64) -> RpcResult<bool>;
+
+    /// Returns the pin with the given id, if it hasn't expired.
+    #[method(name = "getPinnedBlock")]
+    async fn get_pinned_block(&self, id: u64) -> RpcResult<Option<PinnedBlock>>;
+}
+
+/// A pinned block, see `reth_pinBlock`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PinnedBlock {
+    /// The id of the pin.
+    pub id: u64,
+    /// The number of the pinned block.
+    pub number: BlockNumber,
+    /// The hash of the pinned block.
+    pub hash: B256,
+    /// The number of seconds until the pin expires.
+    pub expires_in: u64,
+}
@@ -0,0 +1,16 @@
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+/// Reth API namespace for the consensus layer to inform the node about its upcoming proposals.
+///
+/// This is served on the authenticated engine API endpoint only.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait ProposerApi {
+    /// Sets the timestamps of the upcoming slots the node is going to propose blocks for,
+    /// replacing the previously set lookahead.
+    ///
+    /// Once set, the payload builder only prepares for the next block ahead of time, e.g. by
+    /// prewarming state, if the node is proposing it.
+    #[method(name = "setProposerLookahead")]
+    async fn set_proposer_lookahead(&self, timestamps: Vec<u64>) -> RpcResult<()>;
+}
@@ -1,6 +1,9 @@
-use alloy_eips::BlockId;
-use alloy_primitives::{Address, U256};
+use alloy_eips::{eip4895::Withdrawal, BlockId, BlockNumberOrTag};
+use alloy_primitives::{Address, BlockHash, BlockNumber, Bytes, Selector, TxHash, U256, U64};
+use alloy_rpc_types_eth::{EIP1186AccountProofResponse, Filter, Log};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_network_peers::PeerId;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Reth API namespace for reth-specific methods
@@ -13,4 +16,237 @@ pub trait RethApi {
         &self,
         block_id: BlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
+
+    /// Returns the hashes of the transactions in the given block range that call the function
+    /// with the given 4-byte selector, ordered by their position in the chain.
+    ///
+    /// Requires the transaction selector index to be enabled on the node, otherwise no
+    /// transactions are returned.
+    #[method(name = "getTransactionsBySelector")]
+    async fn reth_get_transactions_by_selector(
+        &self,
+        selector: Selector,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<TxHash>>;
+
+    /// Returns the withdrawals to the given address in the given block range, ordered by their
+    /// position in the chain.
+    ///
+    /// Requires the withdrawal history index to be enabled on the node, otherwise only
+    /// withdrawals of blocks that are not yet persisted are returned.
+    #[method(name = "getWithdrawalsByAddress")]
+    async fn reth_get_withdrawals_by_address(
+        &self,
+        address: Address,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<WithdrawalHistoryEntry>>;
+
+    /// Returns the withdrawals of the validator with the given index in the given block range,
+    /// ordered by their position in the chain.
+    ///
+    /// Requires the withdrawal history index to be enabled on the node, otherwise only
+    /// withdrawals of blocks that are not yet persisted are returned.
+    #[method(name = "getWithdrawalsByValidator")]
+    async fn reth_get_withdrawals_by_validator(
+        &self,
+        validator_index: U64,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<WithdrawalHistoryEntry>>;
+
+    /// Returns the logs matching the given filter, like `eth_getLogs`, together with their
+    /// decoded events.
+    ///
+    /// Events are decoded with the ABIs registered on the node and, if enabled, signatures and
+    /// contract ABIs that are looked up remotely. The event of a log is `null` if no matching ABI
+    /// is known.
+    #[method(name = "getDecodedLogs")]
+    async fn reth_get_decoded_logs(&self, filter: Filter) -> RpcResult<Vec<DecodedLog>>;
+
+    /// Returns the receipt of the transaction with the given hash together with its Merkle proof
+    /// against the receipts root of its block, e.g. for cross-chain message verification.
+    ///
+    /// Returns `null` if the transaction is not found.
+    #[method(name = "getReceiptProof")]
+    async fn reth_get_receipt_proof(&self, hash: TxHash) -> RpcResult<Option<ReceiptProof>>;
+
+    /// Creates a subscription that emits an event whenever a peer connects or disconnects, the
+    /// reputation of a peer changes or a peer reconnects with a newer eth protocol version.
+    #[subscription(
+        name = "subscribePeerEvents",
+        unsubscribe = "unsubscribePeerEvents",
+        item = PeerEventNotification
+    )]
+    async fn reth_subscribe_peer_events(&self) -> jsonrpsee::core::SubscriptionResult;
+
+    /// Creates a subscription that streams the account proofs of the given addresses at the given
+    /// block, e.g. for proof-of-reserve audits.
+    ///
+    /// The first notification contains the headers from the proof block up to the anchor block,
+    /// which defaults to the proof block, so that the proofs can be verified against a trusted
+    /// block hash. The proofs follow in batches, the subscription is complete once a batch with
+    /// no remaining proofs was sent. If a proof can't be computed, an error notification is sent
+    /// instead and the subscription is complete.
+    ///
+    /// Like `eth_getProof`, the proof block must be within the proof window of the node and the
+    /// proofs are computed with the permits that are shared with `eth_getProof`.
+    #[subscription(
+        name = "subscribeBalanceProofs",
+        unsubscribe = "unsubscribeBalanceProofs",
+        item = BalanceProofNotification
+    )]
+    async fn reth_subscribe_balance_proofs(
+        &self,
+        addresses: Vec<Address>,
+        block_id: BlockId,
+        anchor: Option<BlockId>,
+    ) -> jsonrpsee::core::SubscriptionResult;
+}
+
+/// A withdrawal with the number of the block it was included in, see
+/// `reth_getWithdrawalsByAddress` and `reth_getWithdrawalsByValidator`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalHistoryEntry {
+    /// The number of the block that contains the withdrawal.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: BlockNumber,
+    /// The withdrawal.
+    #[serde(flatten)]
+    pub withdrawal: Withdrawal,
+}
+
+/// A receipt with its inclusion proof, see `reth_getReceiptProof`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptProof {
+    /// The hash of the block that contains the transaction.
+    pub block_hash: BlockHash,
+    /// The number of the block that contains the transaction.
+    pub block_number: BlockNumber,
+    /// The RLP encoded header of the block, its hash is the block hash.
+    pub header: Bytes,
+    /// The index of the transaction in the block, the key of the receipt in the receipts trie is
+    /// the RLP encoding of the index.
+    pub transaction_index: u64,
+    /// The EIP-2718 encoded receipt, the value of the receipt in the receipts trie.
+    pub receipt: Bytes,
+    /// The RLP encoded trie nodes from the root of the receipts trie to the receipt.
+    pub proof: Vec<Bytes>,
+}
+
+/// A notification of `reth_subscribeBalanceProofs`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BalanceProofNotification {
+    /// The headers anchoring the proofs.
+    Headers {
+        /// The RLP encoded headers from the proof block up to the anchor block, in ascending
+        /// order.
+        headers: Vec<Bytes>,
+    },
+    /// A batch of account proofs.
+    Proofs {
+        /// The account proofs, in the order of the requested addresses.
+        proofs: Vec<EIP1186AccountProofResponse>,
+        /// The number of proofs that are still to be sent.
+        remaining: usize,
+    },
+    /// The proofs couldn't be computed, no further notifications are sent.
+    Error {
+        /// The error message.
+        message: String,
+    },
+}
+
+/// An event of a peer, see `reth_subscribePeerEvents`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerEventNotification {
+    /// The id of the peer.
+    pub peer_id: PeerId,
+    /// The enode URL of the peer, if known.
+    pub enode: Option<String>,
+    /// The ENR of the peer, if known.
+    pub enr: Option<String>,
+    /// The client version the peer announced, if known.
+    pub client_version: Option<String>,
+    /// The event.
+    #[serde(flatten)]
+    pub event: PeerEventKind,
+}
+
+/// The kind of a [`PeerEventNotification`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PeerEventKind {
+    /// A session with the peer was established.
+    #[serde(rename_all = "camelCase")]
+    Connected {
+        /// The negotiated eth protocol version.
+        eth_version: u8,
+        /// The capabilities the peer announced, e.g. `eth/68`.
+        capabilities: Vec<String>,
+    },
+    /// The session with the peer was closed.
+    Disconnected {
+        /// Why the session was closed, if known.
+        reason: Option<String>,
+    },
+    /// The reputation of the peer changed.
+    ReputationChanged {
+        /// The new reputation of the peer.
+        reputation: i32,
+    },
+    /// The peer reconnected with a newer eth protocol version than in its previous session.
+    #[serde(rename_all = "camelCase")]
+    ProtocolUpgraded {
+        /// The eth protocol version of the previous session.
+        previous_eth_version: u8,
+        /// The eth protocol version of the new session.
+        eth_version: u8,
+    },
+}
+
+/// A log together with its decoded event, see `reth_getDecodedLogs`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DecodedLog {
+    /// The log.
+    #[serde(flatten)]
+    pub log: Log,
+    /// The decoded event, `None` if no matching ABI is known.
+    pub event: Option<DecodedEvent>,
+}
+
+/// An event decoded from a log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedEvent {
+    /// The name of the event, e.g. `Transfer`.
+    pub name: String,
+    /// The canonical signature of the event, e.g. `Transfer(address,address,uint256)`.
+    pub signature: String,
+    /// The decoded parameters, in the order of the signature.
+    pub params: Vec<DecodedParam>,
+}
+
+/// A decoded event parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecodedParam {
+    /// The name of the parameter, empty if unknown.
+    pub name: String,
+    /// The solidity type of the parameter, e.g. `uint256`.
+    #[serde(rename = "type")]
+    pub ty: String,
+    /// Whether the parameter is indexed, i.e. decoded from a topic.
+    pub indexed: bool,
+    /// The decoded value.
+    ///
+    /// Integers are encoded as decimal strings and bytes as hex strings, arrays and tuples as
+    /// arrays.
+    pub value: serde_json::Value,
 }
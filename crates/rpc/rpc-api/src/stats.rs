@@ -0,0 +1,69 @@
+use alloy_primitives::U256;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// Stats namespace rpc interface that serves rollups of canonical chain statistics.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "stats"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "stats"))]
+pub trait StatsApi {
+    /// Returns the chain statistics of the given interval for the buckets that start in the given
+    /// timestamp range, ordered by their start timestamp.
+    ///
+    /// Only buckets within the retention window of the node are available, buckets without any
+    /// blocks are omitted.
+    #[method(name = "getChainStats")]
+    async fn stats_get_chain_stats(
+        &self,
+        interval: StatsInterval,
+        from_timestamp: Option<u64>,
+        to_timestamp: Option<u64>,
+    ) -> RpcResult<Vec<ChainStatsBucket>>;
+}
+
+/// The interval chain statistics are aggregated over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsInterval {
+    /// One hour.
+    Hour,
+    /// One day, in UTC.
+    Day,
+}
+
+impl StatsInterval {
+    /// Returns the length of the interval in seconds.
+    pub const fn seconds(&self) -> u64 {
+        match self {
+            Self::Hour => 60 * 60,
+            Self::Day => 24 * 60 * 60,
+        }
+    }
+
+    /// Returns the start timestamp of the interval that contains the given timestamp.
+    pub const fn start(&self, timestamp: u64) -> u64 {
+        timestamp - timestamp % self.seconds()
+    }
+}
+
+/// The statistics of the canonical blocks in an interval, see `stats_getChainStats`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainStatsBucket {
+    /// The start timestamp of the interval.
+    #[serde(with = "alloy_serde::quantity")]
+    pub timestamp: u64,
+    /// The number of blocks.
+    #[serde(with = "alloy_serde::quantity")]
+    pub blocks: u64,
+    /// The number of transactions.
+    #[serde(with = "alloy_serde::quantity")]
+    pub transactions: u64,
+    /// The total gas used.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// The total fees burned, i.e. the base fee and blob fee of all transactions, in wei.
+    pub fees_burned: U256,
+    /// The number of distinct transaction senders and recipients.
+    #[serde(with = "alloy_serde::quantity")]
+    pub active_addresses: u64,
+}
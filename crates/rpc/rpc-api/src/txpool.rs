@@ -1,5 +1,5 @@
 use alloy_json_rpc::RpcObject;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, U128};
 use alloy_rpc_types_txpool::{TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolStatus};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 
@@ -34,4 +34,12 @@ pub trait TxPoolApi<T: RpcObject> {
     /// See [here](https://geth.ethereum.org/docs/rpc/ns-txpool#txpool_content) for more details
     #[method(name = "content")]
     async fn txpool_content(&self) -> RpcResult<TxpoolContent<T>>;
+
+    /// Returns the minimum priority fee in wei transactions currently have to pay to enter the
+    /// pool.
+    ///
+    /// This is the configured minimum, which is set with `miner_setGasPrice`, or higher if the
+    /// dynamic price floor was raised because the pool is congested.
+    #[method(name = "priceFloor")]
+    async fn txpool_price_floor(&self) -> RpcResult<U128>;
 }
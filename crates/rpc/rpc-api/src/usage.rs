@@ -0,0 +1,92 @@
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Admin API namespace for exporting the usage of the RPC server per API key.
+///
+/// Each call is charged with the compute units of its [`MethodClass`] to the API key of the
+/// request, and rejected once the quota of the API key is used up for the current window.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait RpcUsageApi {
+    /// Returns the usage of all API keys.
+    #[method(name = "rpcUsage")]
+    async fn rpc_usage(&self) -> RpcResult<Vec<ApiKeyUsage>>;
+}
+
+/// The class of an RPC method, which determines how many compute units a call costs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MethodClass {
+    /// Cheap lookups, e.g. `eth_blockNumber` or `eth_getBalance`.
+    Read,
+    /// Transaction submissions, e.g. `eth_sendRawTransaction`.
+    Send,
+    /// EVM executions and proofs, e.g. `eth_call` or `eth_getProof`.
+    Call,
+    /// Log queries, e.g. `eth_getLogs`.
+    Logs,
+    /// Tracing and debugging, i.e. the `trace` and `debug` namespaces.
+    Trace,
+}
+
+impl MethodClass {
+    /// Returns the class of the given method.
+    pub fn of(method: &str) -> Self {
+        if method.starts_with("trace_") || method.starts_with("debug_") {
+            return Self::Trace
+        }
+        match method {
+            "eth_call" |
+            "eth_estimateGas" |
+            "eth_createAccessList" |
+            "eth_simulateV1" |
+            "eth_callMany" |
+            "eth_callBundle" |
+            "mev_callBundle" |
+            "mev_simBundle" |
+            "eth_getProof" => Self::Call,
+            "eth_getLogs" |
+            "eth_getFilterLogs" |
+            "eth_getFilterChanges" |
+            "reth_getDecodedLogs" => Self::Logs,
+            "eth_sendRawTransaction" |
+            "eth_sendRawTransactionSync" |
+            "eth_sendTransaction" |
+            "eth_sendBundle" => Self::Send,
+            _ => Self::Read,
+        }
+    }
+
+    /// Returns the compute units a call of this class costs.
+    pub const fn compute_units(&self) -> u64 {
+        match self {
+            Self::Read => 1,
+            Self::Send => 10,
+            Self::Call => 20,
+            Self::Logs => 50,
+            Self::Trace => 100,
+        }
+    }
+}
+
+/// The usage of an API key, see `admin_rpcUsage`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyUsage {
+    /// The first 8 bytes of the keccak256 hash of the API key, which identifies the API key
+    /// without revealing it.
+    pub key_hash: String,
+    /// The compute units the API key may use per window.
+    pub quota: u64,
+    /// The compute units used in the current window.
+    pub window_compute_units: u64,
+    /// The number of seconds until the current window ends.
+    pub window_resets_in: u64,
+    /// The compute units used in total.
+    pub total_compute_units: u64,
+    /// The number of calls in total, by method class.
+    pub calls: BTreeMap<MethodClass, u64>,
+    /// The number of calls that were rejected because the quota was used up.
+    pub rejected: u64,
+}
@@ -0,0 +1,331 @@
+//! Per API key accounting of the compute units used by RPC calls.
+
+use alloy_primitives::{hex, keccak256};
+use futures::future::Either;
+use http::{HeaderName, Request as HttpRequest};
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    server::middleware::rpc::{RpcService, RpcServiceT},
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use parking_lot::Mutex;
+use reth_rpc_api::{ApiKeyUsage, MethodClass, RpcUsageApiServer};
+use reth_rpc_layer::{ApiKeyAuthValidator, AuthLayer};
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::{ready, Ready},
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tower::{Layer, Service};
+
+/// Error code for calls without a known API key.
+pub const INVALID_API_KEY_CODE: i32 = -32001;
+
+/// Error code for calls that exceed the quota of the API key, see EIP-1474.
+pub const QUOTA_EXCEEDED_CODE: i32 = -32005;
+
+/// The [`RpcService`] with the optional accounting applied, see `RpcServerConfig::start`.
+pub type AccountedRpcService = tower::util::Either<RpcAccountingService<RpcService>, RpcService>;
+
+/// The API key of a request, inserted into the request extensions by [`ApiKeyLayer`].
+///
+/// Requests without a known API key are rejected by the [`AuthLayer`] of the accounting before
+/// they reach the [`ApiKeyLayer`], so the API key of a request is always a known one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ApiKey(pub String);
+
+/// Accounting of the compute units used by the RPC calls of each API key.
+///
+/// Requests without a known API key are rejected by the HTTP middleware, see
+/// [`Self::auth_layer`], before any RPC middleware runs. Calls are charged the compute units of
+/// their [`MethodClass`] and rejected if the quota of the API key is used up for the current
+/// window.
+///
+/// This is a [`Layer`] for the RPC middleware and requires the [`AuthLayer`] and [`ApiKeyLayer`]
+/// in the HTTP middleware, see [`Self::auth_layer`] and [`Self::api_key_layer`].
+#[derive(Debug, Clone)]
+pub struct RpcAccounting {
+    inner: Arc<RpcAccountingInner>,
+}
+
+impl RpcAccounting {
+    /// Creates a new instance for the given quotas in compute units per window by API key.
+    pub fn new(header: HeaderName, quotas: HashMap<String, u64>, window: Duration) -> Self {
+        let now = Instant::now();
+        let tenants = quotas
+            .into_iter()
+            .map(|(api_key, quota)| {
+                let usage = TenantUsage::new(key_hash(&api_key), quota, now);
+                (api_key, usage)
+            })
+            .collect();
+        Self {
+            inner: Arc::new(RpcAccountingInner { header, window, tenants: Mutex::new(tenants) }),
+        }
+    }
+
+    /// Returns the HTTP middleware layer that rejects requests without a known API key.
+    ///
+    /// This must be applied before the [`ApiKeyLayer`], so that only known API keys are inserted
+    /// into the request extensions.
+    pub fn auth_layer(&self) -> AuthLayer<ApiKeyAuthValidator> {
+        let api_keys = self.inner.tenants.lock().keys().cloned().collect::<Vec<_>>();
+        AuthLayer::new(ApiKeyAuthValidator::new(self.inner.header.clone(), api_keys))
+    }
+
+    /// Returns the HTTP middleware layer that extracts the API key from the request headers.
+    pub fn api_key_layer(&self) -> ApiKeyLayer {
+        ApiKeyLayer::new(self.inner.header.clone())
+    }
+
+    /// Charges the call of the given method to the API key.
+    ///
+    /// Returns the error to respond with if the call is rejected.
+    pub fn charge(
+        &self,
+        api_key: Option<&ApiKey>,
+        method: &str,
+    ) -> Result<(), ErrorObject<'static>> {
+        let mut tenants = self.inner.tenants.lock();
+        let Some(tenant) = api_key.and_then(|ApiKey(api_key)| tenants.get_mut(api_key)) else {
+            return Err(ErrorObject::owned(
+                INVALID_API_KEY_CODE,
+                "missing or unknown API key",
+                None::<()>,
+            ))
+        };
+
+        let now = Instant::now();
+        if now.duration_since(tenant.window_start) >= self.inner.window {
+            tenant.window_start = now;
+            tenant.window_compute_units = 0;
+        }
+
+        let class = MethodClass::of(method);
+        let compute_units = class.compute_units();
+        if tenant.window_compute_units + compute_units > tenant.quota {
+            tenant.rejected += 1;
+            return Err(ErrorObject::owned(
+                QUOTA_EXCEEDED_CODE,
+                format!("quota of {} compute units exceeded", tenant.quota),
+                None::<()>,
+            ))
+        }
+
+        tenant.window_compute_units += compute_units;
+        tenant.total_compute_units += compute_units;
+        *tenant.calls.entry(class).or_default() += 1;
+        Ok(())
+    }
+
+    /// Returns the usage of all API keys, sorted by the hash of the API key.
+    ///
+    /// API keys are identified by their hash, so the usage doesn't reveal the API keys.
+    pub fn usage(&self) -> Vec<ApiKeyUsage> {
+        let now = Instant::now();
+        let window = self.inner.window;
+        let mut usage = self
+            .inner
+            .tenants
+            .lock()
+            .iter()
+            .map(|(_, tenant)| {
+                let elapsed = now.duration_since(tenant.window_start);
+                let expired = elapsed >= window;
+                ApiKeyUsage {
+                    key_hash: tenant.key_hash.clone(),
+                    quota: tenant.quota,
+                    window_compute_units: if expired { 0 } else { tenant.window_compute_units },
+                    window_resets_in: if expired {
+                        window.as_secs()
+                    } else {
+                        (window - elapsed).as_secs()
+                    },
+                    total_compute_units: tenant.total_compute_units,
+                    calls: tenant.calls.clone(),
+                    rejected: tenant.rejected,
+                }
+            })
+            .collect::<Vec<_>>();
+        usage.sort_unstable_by(|a, b| a.key_hash.cmp(&b.key_hash));
+        usage
+    }
+}
+
+impl<S> Layer<S> for RpcAccounting {
+    type Service = RpcAccountingService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcAccountingService { inner, accounting: self.clone() }
+    }
+}
+
+#[async_trait]
+impl RpcUsageApiServer for RpcAccounting {
+    /// Handler for `admin_rpcUsage`
+    async fn rpc_usage(&self) -> RpcResult<Vec<ApiKeyUsage>> {
+        Ok(self.usage())
+    }
+}
+
+#[derive(Debug)]
+struct RpcAccountingInner {
+    /// The header that carries the API key.
+    header: HeaderName,
+    /// The window in which the quota can be used.
+    window: Duration,
+    /// The usage by API key.
+    tenants: Mutex<HashMap<String, TenantUsage>>,
+}
+
+/// The usage of a single API key.
+#[derive(Debug)]
+struct TenantUsage {
+    /// The hash that identifies the API key in the usage, see [`key_hash`].
+    key_hash: String,
+    quota: u64,
+    window_start: Instant,
+    window_compute_units: u64,
+    total_compute_units: u64,
+    calls: BTreeMap<MethodClass, u64>,
+    rejected: u64,
+}
+
+impl TenantUsage {
+    fn new(key_hash: String, quota: u64, now: Instant) -> Self {
+        Self {
+            key_hash,
+            quota,
+            window_start: now,
+            window_compute_units: 0,
+            total_compute_units: 0,
+            calls: BTreeMap::new(),
+            rejected: 0,
+        }
+    }
+}
+
+/// Returns the first 8 bytes of the keccak256 hash of the API key, hex encoded.
+fn key_hash(api_key: &str) -> String {
+    hex::encode_prefixed(&keccak256(api_key)[..8])
+}
+
+/// A [`RpcServiceT`] middleware that charges all calls to the API key of the request.
+#[derive(Debug, Clone)]
+pub struct RpcAccountingService<S> {
+    /// The inner service being wrapped
+    inner: S,
+    /// The accounting of all API keys.
+    accounting: RpcAccounting,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcAccountingService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = Either<S::Future, Ready<MethodResponse>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        match self.accounting.charge(req.extensions().get::<ApiKey>(), req.method_name()) {
+            Ok(()) => Either::Left(self.inner.call(req)),
+            Err(err) => Either::Right(ready(MethodResponse::error(req.id, err))),
+        }
+    }
+}
+
+/// HTTP middleware layer that inserts the [`ApiKey`] from the configured header into the request
+/// extensions, which are passed on to the RPC middleware.
+#[derive(Debug, Clone)]
+pub struct ApiKeyLayer {
+    header: HeaderName,
+}
+
+impl ApiKeyLayer {
+    /// Creates a new layer that reads the API key from the given header.
+    pub const fn new(header: HeaderName) -> Self {
+        Self { header }
+    }
+}
+
+impl<S> Layer<S> for ApiKeyLayer {
+    type Service = ApiKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyService { inner, header: self.header.clone() }
+    }
+}
+
+/// The [`Service`] of [`ApiKeyLayer`].
+#[derive(Debug, Clone)]
+pub struct ApiKeyService<S> {
+    inner: S,
+    header: HeaderName,
+}
+
+impl<S, B> Service<HttpRequest<B>> for ApiKeyService<S>
+where
+    S: Service<HttpRequest<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: HttpRequest<B>) -> Self::Future {
+        if let Some(api_key) = req.headers().get(&self.header).and_then(|v| v.to_str().ok()) {
+            let api_key = ApiKey(api_key.to_string());
+            req.extensions_mut().insert(api_key);
+        }
+        self.inner.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_rpc_server_types::constants::DEFAULT_API_KEY_HEADER;
+
+    #[test]
+    fn charge_compute_units() {
+        let accounting = RpcAccounting::new(
+            HeaderName::from_static(DEFAULT_API_KEY_HEADER),
+            HashMap::from([("tenant".to_string(), 120)]),
+            Duration::from_secs(60),
+        );
+        let api_key = ApiKey("tenant".to_string());
+
+        let err = accounting.charge(None, "eth_blockNumber").unwrap_err();
+        assert_eq!(err.code(), INVALID_API_KEY_CODE);
+        let err = accounting.charge(Some(&ApiKey("other".to_string())), "eth_call").unwrap_err();
+        assert_eq!(err.code(), INVALID_API_KEY_CODE);
+
+        accounting.charge(Some(&api_key), "eth_blockNumber").unwrap();
+        accounting.charge(Some(&api_key), "trace_block").unwrap();
+        // 101 + 20 exceeds the quota
+        let err = accounting.charge(Some(&api_key), "eth_call").unwrap_err();
+        assert_eq!(err.code(), QUOTA_EXCEEDED_CODE);
+        accounting.charge(Some(&api_key), "eth_sendRawTransaction").unwrap();
+
+        let usage = accounting.usage();
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage[0].key_hash, key_hash("tenant"));
+        assert!(!usage[0].key_hash.contains("tenant"));
+        assert_eq!(usage[0].window_compute_units, 111);
+        assert_eq!(usage[0].total_compute_units, 111);
+        assert_eq!(usage[0].rejected, 1);
+        assert_eq!(
+            usage[0].calls,
+            BTreeMap::from([
+                (MethodClass::Read, 1),
+                (MethodClass::Send, 1),
+                (MethodClass::Trace, 1)
+            ])
+        );
+    }
+}
@@ -1,16 +1,25 @@
-use std::{net::SocketAddr, path::PathBuf};
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
 
+use http::HeaderName;
 use jsonrpsee::server::ServerBuilder;
 use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_path};
-use reth_rpc::ValidationApiConfig;
-use reth_rpc_eth_types::{EthConfig, EthStateCacheConfig, GasPriceOracleConfig};
+use reth_rpc::{FingerprintApiConfig, RethApiConfig, StatsApiConfig, ValidationApiConfig};
+use reth_rpc_eth_types::{
+    EthConfig, EthStateCacheConfig, GasPriceOracleConfig, SubscriptionResumeConfig,
+};
 use reth_rpc_layer::{JwtError, JwtSecret};
 use reth_rpc_server_types::RpcModuleSelection;
 use tower::layer::util::Identity;
 use tracing::{debug, warn};
 
 use crate::{
-    auth::AuthServerConfig, error::RpcError, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
+    accounting::RpcAccounting,
+    auth::AuthServerConfig,
+    error::RpcError,
+    rate_limits::{RateLimitsConfig, RpcRateLimits},
+    response_limits::ResponseSizeLimits,
+    trace_scheduler::TraceSchedulerConfig,
+    IpcEndpointConfig, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
     TransportRpcModuleConfig,
 };
 
@@ -31,6 +40,15 @@ pub trait RethRpcServerConfig {
     /// The configured ethereum RPC settings.
     fn flashbots_config(&self) -> ValidationApiConfig;
 
+    /// The configured reth RPC settings.
+    fn reth_config(&self) -> RethApiConfig;
+
+    /// The configured stats RPC settings.
+    fn stats_config(&self) -> StatsApiConfig;
+
+    /// The configured fingerprint RPC settings.
+    fn fingerprint_config(&self) -> FingerprintApiConfig;
+
     /// Returns state cache configuration.
     fn state_cache_config(&self) -> EthStateCacheConfig;
 
@@ -58,6 +76,18 @@ pub trait RethRpcServerConfig {
     /// Creates the [`RpcServerConfig`] from cli args.
     fn rpc_server_config(&self) -> RpcServerConfig;
 
+    /// The configured scheduling of tracing calls, if enabled.
+    fn trace_scheduler_config(&self) -> Option<TraceSchedulerConfig>;
+
+    /// Creates the [`RpcAccounting`] if API keys are configured.
+    fn rpc_accounting(&self) -> Result<Option<RpcAccounting>, RpcError>;
+
+    /// Creates the [`RpcRateLimits`] if a rate limits file is configured.
+    fn rpc_rate_limits(&self) -> Result<Option<RpcRateLimits>, RpcError>;
+
+    /// Returns the configured response size limits per method.
+    fn response_size_limits(&self) -> ResponseSizeLimits;
+
     /// Creates the [`AuthServerConfig`] from cli args.
     fn auth_server_config(&self, jwt_secret: JwtSecret) -> Result<AuthServerConfig, RpcError>;
 
@@ -97,18 +127,43 @@ impl RethRpcServerConfig for RpcServerArgs {
             .max_tracing_requests(self.rpc_max_tracing_requests)
             .max_blocks_per_filter(self.rpc_max_blocks_per_filter.unwrap_or_max())
             .max_logs_per_response(self.rpc_max_logs_per_response.unwrap_or_max() as usize)
+            .stale_filter_ttl(Duration::from_secs(self.rpc_filter_ttl))
+            .max_trace_filter_blocks(self.rpc_max_trace_filter_blocks.unwrap_or_max())
+            .max_trace_filter_transactions(self.rpc_max_trace_filter_transactions.unwrap_or_max())
             .eth_proof_window(self.rpc_eth_proof_window)
             .rpc_gas_cap(self.rpc_gas_cap)
             .rpc_max_simulate_blocks(self.rpc_max_simulate_blocks)
             .state_cache(self.state_cache_config())
             .gpo_config(self.gas_price_oracle_config())
             .proof_permits(self.rpc_proof_permits)
+            .send_raw_transaction_sync_timeout(self.rpc_send_raw_transaction_sync_timeout)
+            .subscription_resume(SubscriptionResumeConfig {
+                window: Duration::from_secs(self.rpc_subscription_resume_window),
+                new_heads: self.rpc_subscription_resume_new_heads,
+                logs: self.rpc_subscription_resume_logs,
+                pending_transactions: self.rpc_subscription_resume_pending_transactions,
+            })
     }
 
     fn flashbots_config(&self) -> ValidationApiConfig {
         ValidationApiConfig { disallow: self.builder_disallow.clone().unwrap_or_default() }
     }
 
+    fn reth_config(&self) -> RethApiConfig {
+        RethApiConfig { abi_dir: self.rpc_abi_dir.clone(), abi_lookup: self.rpc_abi_lookup }
+    }
+
+    fn stats_config(&self) -> StatsApiConfig {
+        StatsApiConfig { enabled: self.rpc_stats, retention_days: self.rpc_stats_retention_days }
+    }
+
+    fn fingerprint_config(&self) -> FingerprintApiConfig {
+        FingerprintApiConfig {
+            path: self.rpc_fingerprints.clone(),
+            max_file_size: self.rpc_fingerprints_max_file_size.saturating_mul(1024 * 1024),
+        }
+    }
+
     fn state_cache_config(&self) -> EthStateCacheConfig {
         EthStateCacheConfig {
             max_blocks: self.rpc_state_cache.max_blocks,
@@ -131,8 +186,12 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn transport_rpc_module_config(&self) -> TransportRpcModuleConfig {
-        let mut config = TransportRpcModuleConfig::default()
-            .with_config(RpcModuleConfig::new(self.eth_config(), self.flashbots_config()));
+        let mut config = TransportRpcModuleConfig::default().with_config(
+            RpcModuleConfig::new(self.eth_config(), self.flashbots_config())
+                .with_reth(self.reth_config())
+                .with_stats(self.stats_config())
+                .with_fingerprint(self.fingerprint_config()),
+        );
 
         if self.http {
             config = config.with_http(
@@ -150,7 +209,8 @@ impl RethRpcServerConfig for RpcServerArgs {
             );
         }
 
-        if self.is_ipc_enabled() {
+        // additional ipc endpoints serve a subset of the ipc modules
+        if self.is_ipc_enabled() || !self.ipc_endpoints.is_empty() {
             config = config.with_ipc(RpcModuleSelection::default_ipc_modules());
         }
 
@@ -166,15 +226,25 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn ipc_server_builder(&self) -> IpcServerBuilder<Identity, Identity> {
-        IpcServerBuilder::default()
+        let mut builder = IpcServerBuilder::default()
             .max_subscriptions_per_connection(self.rpc_max_subscriptions_per_connection.get())
             .max_request_body_size(self.rpc_max_request_size_bytes())
             .max_response_body_size(self.rpc_max_response_size_bytes())
-            .max_connections(self.rpc_max_connections.get())
+            .max_connections(self.rpc_max_connections.get());
+        if let Some(mode) = self.ipc_permissions {
+            builder = builder.set_socket_permissions(mode);
+        }
+        if let Some(owner) = self.ipc_owner {
+            builder = builder.set_socket_owner(owner.uid, owner.gid);
+        }
+        builder
     }
 
     fn rpc_server_config(&self) -> RpcServerConfig {
-        let mut config = RpcServerConfig::default().with_jwt_secret(self.rpc_secret_key());
+        let mut config = RpcServerConfig::default()
+            .with_jwt_secret(self.rpc_secret_key())
+            .with_response_size_limits(Some(self.response_size_limits()))
+            .with_batch_snapshots(self.rpc_batch_snapshot);
 
         if self.http_api.is_some() && !self.http {
             warn!(
@@ -202,9 +272,65 @@ impl RethRpcServerConfig for RpcServerArgs {
                 config.with_ipc(self.ipc_server_builder()).with_ipc_endpoint(self.ipcpath.clone());
         }
 
+        for endpoint in &self.ipc_endpoints {
+            let mut builder = self.ipc_server_builder();
+            if let Some(mode) = endpoint.permissions {
+                builder = builder.set_socket_permissions(mode);
+            }
+            config = config.with_additional_ipc_endpoint(IpcEndpointConfig::new(
+                endpoint.path.clone(),
+                endpoint.modules.clone(),
+                builder,
+            ));
+        }
+
         config
     }
 
+    fn trace_scheduler_config(&self) -> Option<TraceSchedulerConfig> {
+        self.rpc_trace_scheduler.then(|| {
+            TraceSchedulerConfig::new(self.rpc_max_tracing_requests)
+                .with_heavy_concurrency(self.rpc_trace_heavy_concurrency)
+                .with_max_queued(self.rpc_trace_max_queued)
+        })
+    }
+
+    fn rpc_accounting(&self) -> Result<Option<RpcAccounting>, RpcError> {
+        let Some(quotas) = self.rpc_api_keys.clone() else { return Ok(None) };
+        let header = HeaderName::try_from(self.rpc_api_key_header.as_str()).map_err(|err| {
+            RpcError::Custom(format!("invalid API key header {}: {err}", self.rpc_api_key_header))
+        })?;
+        Ok(Some(RpcAccounting::new(
+            header,
+            quotas,
+            Duration::from_secs(self.rpc_api_key_quota_window),
+        )))
+    }
+
+    fn rpc_rate_limits(&self) -> Result<Option<RpcRateLimits>, RpcError> {
+        let Some(path) = &self.rpc_rate_limits else { return Ok(None) };
+        let client_ip_header = self
+            .rpc_client_ip_header
+            .as_deref()
+            .map(|name| {
+                HeaderName::try_from(name)
+                    .map_err(|err| RpcError::Custom(format!("invalid header {name}: {err}")))
+            })
+            .transpose()?;
+        Ok(Some(RpcRateLimits::new(client_ip_header, RateLimitsConfig::load(path)?)))
+    }
+
+    fn response_size_limits(&self) -> ResponseSizeLimits {
+        ResponseSizeLimits::new(
+            self.rpc_max_method_response_sizes
+                .iter()
+                .map(|limit| {
+                    (limit.method.clone(), limit.size_mb.saturating_mul(1024 * 1024) as usize)
+                })
+                .collect(),
+        )
+    }
+
     fn auth_server_config(&self, jwt_secret: JwtSecret) -> Result<AuthServerConfig, RpcError> {
         let address = SocketAddr::new(self.auth_addr, self.auth_port);
 
@@ -1,5 +1,6 @@
 use alloy_consensus::Header;
 use reth_evm::ConfigureEvm;
+use reth_memory_budget::MemoryBudget;
 use reth_primitives::NodePrimitives;
 use reth_provider::{BlockReader, CanonStateSubscriptions, EvmEnvProvider, StateProviderFactory};
 use reth_rpc::{EthFilter, EthPubSub};
@@ -60,12 +61,18 @@ where
             Events,
             EthApi,
         >,
+        memory_budget: &MemoryBudget,
     ) -> Self
     where
         EvmConfig: ConfigureEvm<Header = Header>,
         Tasks: TaskSpawner + Clone + 'static,
     {
-        let cache = EthStateCache::spawn_with(provider.clone(), config.cache, executor.clone());
+        let cache = EthStateCache::spawn_with_memory_budget(
+            provider.clone(),
+            config.cache,
+            executor.clone(),
+            memory_budget,
+        );
 
         let new_canonical_blocks = events.canonical_state_stream();
         let c = cache.clone();
@@ -96,7 +103,8 @@ where
             api.clone(),
             ctx.events.clone(),
             Box::new(ctx.executor.clone()),
-        );
+        )
+        .with_resume_config(ctx.config.subscription_resume);
 
         Self { api, cache: ctx.cache, filter, pubsub }
     }
@@ -0,0 +1,237 @@
+//! [`jsonrpsee`] helper layer for serving trivial `eth` calls without the full call stack.
+
+use crate::snapshot::BatchSnapshot;
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{U256, U64};
+use futures::future::{ready, BoxFuture, Either, Ready};
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{Id, Request, ResponsePayload},
+    MethodResponse,
+};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tower::Layer;
+
+/// The values served by the [`FastPathLayer`], shared by all connections.
+#[derive(Debug)]
+pub struct FastPathCache {
+    /// The chain id of the node.
+    chain_id: u64,
+    /// The canonical head.
+    best_block: RwLock<BlockNumHash>,
+    /// The last gas price returned by `eth_gasPrice`, and the canonical head it was computed for.
+    gas_price: RwLock<Option<(u64, U256)>>,
+}
+
+impl FastPathCache {
+    /// Creates a new cache for the given chain id and canonical head.
+    pub const fn new(chain_id: u64, best_block: BlockNumHash) -> Self {
+        Self { chain_id, best_block: RwLock::new(best_block), gas_price: RwLock::new(None) }
+    }
+
+    /// Sets the canonical head, this must be called whenever the canonical head changes.
+    ///
+    /// The cached gas price is invalidated by a new head.
+    pub fn set_best_block(&self, block: BlockNumHash) {
+        *self.best_block.write() = block;
+    }
+
+    /// Returns the canonical head.
+    pub fn best_block(&self) -> BlockNumHash {
+        *self.best_block.read()
+    }
+
+    /// Returns the number of the canonical head.
+    pub fn best_number(&self) -> u64 {
+        self.best_block.read().number
+    }
+
+    /// Returns the block number served by `eth_blockNumber`, which is the block of the given batch
+    /// snapshot if any, resolving it to the canonical head if it's not resolved yet.
+    fn block_number(&self, snapshot: Option<&BatchSnapshot>) -> u64 {
+        snapshot
+            .and_then(|snapshot| snapshot.get_or_resolve(|| Some(self.best_block())))
+            .map_or_else(|| self.best_number(), |block| block.number)
+    }
+
+    /// Returns the cached gas price, if it was computed for the current canonical head.
+    fn gas_price(&self) -> Option<U256> {
+        let (number, price) = (*self.gas_price.read())?;
+        (number == self.best_number()).then_some(price)
+    }
+
+    /// Caches the gas price that was computed for the given canonical head.
+    fn set_gas_price(&self, number: u64, price: U256) {
+        if number == self.best_number() {
+            *self.gas_price.write() = Some((number, price));
+        }
+    }
+}
+
+/// Layer that answers `eth_chainId` and `eth_blockNumber` from the [`FastPathCache`], and
+/// `eth_gasPrice` once it was computed for the current canonical head, without calling the
+/// method handlers.
+///
+/// These calls make up the majority of the calls on public endpoints, so they bypass the provider
+/// and the inner middleware. This must only be applied to servers that serve the `eth` namespace.
+///
+/// `eth_blockNumber` returns the block of the request's [`BatchSnapshot`] if batch snapshots are
+/// enabled, so that it's consistent with the other calls of the batch.
+///
+/// Responses are limited to the maximum response body size of the server, like the responses of
+/// the method handlers.
+#[derive(Debug, Clone)]
+pub struct FastPathLayer {
+    cache: Arc<FastPathCache>,
+    max_response_body_size: usize,
+}
+
+impl FastPathLayer {
+    /// Creates a new layer that serves the calls from the given cache, with the maximum response
+    /// body size of the server in bytes.
+    pub const fn new(cache: Arc<FastPathCache>, max_response_body_size: usize) -> Self {
+        Self { cache, max_response_body_size }
+    }
+}
+
+impl<S> Layer<S> for FastPathLayer {
+    type Service = FastPathService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FastPathService {
+            inner,
+            cache: self.cache.clone(),
+            max_response_body_size: self.max_response_body_size,
+        }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that serves trivial `eth` calls from the [`FastPathCache`].
+#[derive(Debug, Clone)]
+pub struct FastPathService<S> {
+    /// The inner service being wrapped
+    inner: S,
+    /// The cached values.
+    cache: Arc<FastPathCache>,
+    /// The maximum response body size of the server in bytes.
+    max_response_body_size: usize,
+}
+
+/// The result of a successful response.
+#[derive(Deserialize)]
+struct ResponseResult {
+    result: U256,
+}
+
+/// Returns a successful response with the given value, or an error if it exceeds the maximum
+/// response body size.
+fn success<T: Serialize + Clone>(
+    id: Id<'_>,
+    value: T,
+    max_response_body_size: usize,
+) -> Ready<MethodResponse> {
+    ready(MethodResponse::response(id, ResponsePayload::success(value), max_response_body_size))
+}
+
+impl<'a, S> RpcServiceT<'a> for FastPathService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = Either<Either<S::Future, Ready<MethodResponse>>, BoxFuture<'a, MethodResponse>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        match req.method_name() {
+            "eth_chainId" => Either::Left(Either::Right(success(
+                req.id,
+                U64::from(self.cache.chain_id),
+                self.max_response_body_size,
+            ))),
+            "eth_blockNumber" => {
+                let number = self.cache.block_number(req.extensions().get::<BatchSnapshot>());
+                Either::Left(Either::Right(success(
+                    req.id,
+                    U64::from(number),
+                    self.max_response_body_size,
+                )))
+            }
+            "eth_gasPrice" => {
+                if let Some(price) = self.cache.gas_price() {
+                    return Either::Left(Either::Right(success(
+                        req.id,
+                        price,
+                        self.max_response_body_size,
+                    )))
+                }
+
+                // compute the gas price and cache it for the head it was computed for
+                let cache = self.cache.clone();
+                let best_number = cache.best_number();
+                let fut = self.inner.call(req);
+                Either::Right(Box::pin(async move {
+                    let response = fut.await;
+                    if response.is_success() {
+                        if let Ok(ResponseResult { result }) =
+                            serde_json::from_str(response.as_result())
+                        {
+                            cache.set_gas_price(best_number, result);
+                        }
+                    }
+                    response
+                }))
+            }
+            _ => Either::Left(Either::Left(self.inner.call(req))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_gas_price_per_head() {
+        let cache = FastPathCache::new(1, BlockNumHash::new(10, Default::default()));
+        assert_eq!(cache.gas_price(), None);
+
+        cache.set_gas_price(10, U256::from(7));
+        assert_eq!(cache.gas_price(), Some(U256::from(7)));
+
+        // a gas price computed for an outdated head is not cached
+        cache.set_best_block(BlockNumHash::new(11, Default::default()));
+        assert_eq!(cache.gas_price(), None);
+        cache.set_gas_price(10, U256::from(8));
+        assert_eq!(cache.gas_price(), None);
+
+        cache.set_gas_price(11, U256::from(9));
+        assert_eq!(cache.gas_price(), Some(U256::from(9)));
+    }
+
+    #[test]
+    fn block_number_of_batch_snapshot() {
+        let cache = FastPathCache::new(1, BlockNumHash::new(10, Default::default()));
+        assert_eq!(cache.block_number(None), 10);
+
+        let snapshot = BatchSnapshot::default();
+        assert_eq!(cache.block_number(Some(&snapshot)), 10);
+
+        // the snapshot is kept for the whole batch
+        cache.set_best_block(BlockNumHash::new(11, Default::default()));
+        assert_eq!(cache.block_number(Some(&snapshot)), 10);
+        assert_eq!(cache.block_number(None), 11);
+    }
+
+    #[test]
+    fn parse_response_result() {
+        let response = success(Id::Number(1), U256::from(0x3b9aca00u64), usize::MAX).into_inner();
+        let ResponseResult { result } = serde_json::from_str(response.as_result()).unwrap();
+        assert_eq!(result, U256::from(0x3b9aca00u64));
+    }
+
+    #[test]
+    fn limit_response_size() {
+        let response = success(Id::Number(1), U256::from(0x3b9aca00u64), 10).into_inner();
+        assert!(!response.is_success());
+    }
+}
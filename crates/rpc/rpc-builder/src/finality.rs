@@ -0,0 +1,182 @@
+//! [`jsonrpsee`] helper layer for rejecting calls with stale `safe` and `finalized` block tags.
+
+use alloy_eips::BlockNumberOrTag;
+use futures::future::{ready, Either, Ready};
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use reth_provider::CanonChainTracker;
+use serde::Serialize;
+use serde_json::Value;
+use std::time::{Duration, Instant};
+use tower::Layer;
+
+/// Error code for calls that are rejected because the finality information is stale, see
+/// EIP-1474.
+pub const STALE_FINALITY_CODE: i32 = -32002;
+
+/// The message of the error for calls that are rejected because the finality information is
+/// stale.
+pub const STALE_FINALITY_MSG: &str = "finality information is stale, consensus client unavailable";
+
+/// Layer that rejects calls that use the `"safe"` or `"finalized"` block tag if no fork choice
+/// update was received from the consensus layer within the maximum staleness.
+///
+/// Without fork choice updates the safe and finalized blocks aren't advanced, so these calls
+/// would silently be served from outdated blocks.
+#[derive(Debug, Clone)]
+pub struct FinalityGuardLayer<Provider> {
+    provider: Provider,
+    max_staleness: Duration,
+}
+
+impl<Provider> FinalityGuardLayer<Provider> {
+    /// Creates a new layer that rejects the tags if the last fork choice update tracked by the
+    /// provider is older than `max_staleness`.
+    pub const fn new(provider: Provider, max_staleness: Duration) -> Self {
+        Self { provider, max_staleness }
+    }
+}
+
+impl<S, Provider: Clone> Layer<S> for FinalityGuardLayer<Provider> {
+    type Service = FinalityGuardService<S, Provider>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FinalityGuardService {
+            inner,
+            provider: self.provider.clone(),
+            max_staleness: self.max_staleness,
+        }
+    }
+}
+
+/// The data of the error for calls that are rejected because the finality information is stale.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StaleFinality {
+    tag: &'static str,
+    /// Seconds since the last fork choice update, if any was received.
+    last_update: Option<u64>,
+    max_staleness: u64,
+}
+
+/// Returns the first `safe` or `finalized` block tag in the given value.
+fn finality_tag(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::String(tag) => match tag.parse() {
+            Ok(BlockNumberOrTag::Safe) => Some("safe"),
+            Ok(BlockNumberOrTag::Finalized) => Some("finalized"),
+            _ => None,
+        },
+        Value::Array(values) => values.iter().find_map(finality_tag),
+        Value::Object(values) => values.values().find_map(finality_tag),
+        _ => None,
+    }
+}
+
+/// Returns the first `safe` or `finalized` block tag in the given parameters.
+fn params_finality_tag(params: &str) -> Option<&'static str> {
+    // fast path for the vast majority of calls that don't use the tags
+    if !params.contains("safe") && !params.contains("finalized") {
+        return None
+    }
+    finality_tag(&serde_json::from_str::<Value>(params).ok()?)
+}
+
+/// Returns the error for a call with the given tag if the last fork choice update is older than
+/// `max_staleness`.
+fn check_staleness(
+    tag: &'static str,
+    last_update: Option<Instant>,
+    max_staleness: Duration,
+) -> Option<ErrorObject<'static>> {
+    let since_last_update = last_update.map(|last_update| last_update.elapsed());
+    if since_last_update.is_some_and(|since| since <= max_staleness) {
+        return None
+    }
+    Some(ErrorObject::owned(
+        STALE_FINALITY_CODE,
+        STALE_FINALITY_MSG,
+        Some(StaleFinality {
+            tag,
+            last_update: since_last_update.map(|since| since.as_secs()),
+            max_staleness: max_staleness.as_secs(),
+        }),
+    ))
+}
+
+/// A [`RpcServiceT`] middleware that rejects calls with stale `safe` and `finalized` block tags.
+#[derive(Debug, Clone)]
+pub struct FinalityGuardService<S, Provider> {
+    /// The inner service being wrapped
+    inner: S,
+    /// Tracks the last fork choice update.
+    provider: Provider,
+    /// The maximum age of the last fork choice update.
+    max_staleness: Duration,
+}
+
+impl<'a, S, Provider> RpcServiceT<'a> for FinalityGuardService<S, Provider>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+    Provider: CanonChainTracker + Clone + 'static,
+{
+    type Future = Either<S::Future, Ready<MethodResponse>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let Some(tag) = req.params.as_deref().and_then(|params| params_finality_tag(params.get()))
+        else {
+            return Either::Left(self.inner.call(req))
+        };
+
+        match check_staleness(
+            tag,
+            self.provider.last_received_update_timestamp(),
+            self.max_staleness,
+        ) {
+            Some(err) => Either::Right(ready(MethodResponse::error(req.id, err))),
+            None => Either::Left(self.inner.call(req)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_finality_tags() {
+        assert_eq!(params_finality_tag(r#"["0x10",true]"#), None);
+        assert_eq!(params_finality_tag(r#"["latest"]"#), None);
+        assert_eq!(params_finality_tag(r#"[{"data":"unsafe"},"pending"]"#), None);
+        assert_eq!(params_finality_tag(r#"["safe",false]"#), Some("safe"));
+        assert_eq!(
+            params_finality_tag(r#"[{"fromBlock":"0x1","toBlock":"finalized"}]"#),
+            Some("finalized")
+        );
+    }
+
+    #[test]
+    fn reject_stale_finality() {
+        let max_staleness = Duration::from_secs(60);
+        assert!(check_staleness("safe", Some(Instant::now()), max_staleness).is_none());
+
+        let err = check_staleness("safe", None, max_staleness).unwrap();
+        assert_eq!(err.code(), STALE_FINALITY_CODE);
+        assert_eq!(
+            err.data().unwrap().get(),
+            r#"{"tag":"safe","lastUpdate":null,"maxStaleness":60}"#
+        );
+
+        let Some(last_update) = Instant::now().checked_sub(Duration::from_secs(120)) else {
+            return
+        };
+        let err = check_staleness("finalized", Some(last_update), max_staleness).unwrap();
+        assert_eq!(
+            err.data().unwrap().get(),
+            r#"{"tag":"finalized","lastUpdate":120,"maxStaleness":60}"#
+        );
+    }
+}
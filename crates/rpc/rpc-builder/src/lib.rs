@@ -18,7 +18,7 @@
 //! ```
 //! use alloy_consensus::Header;
 //! use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
-//! use reth_network_api::{NetworkInfo, Peers};
+//! use reth_network_api::{NetworkInfo, NetworkPeersEvents, Peers};
 //! use reth_primitives::TransactionSigned;
 //! use reth_provider::{AccountReader, CanonStateSubscriptions, ChangeSetReader, FullRpcProvider};
 //! use reth_rpc::EthApi;
@@ -47,7 +47,7 @@
 //!     Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>>
 //!         + Unpin
 //!         + 'static,
-//!     Network: NetworkInfo + Peers + Clone + 'static,
+//!     Network: NetworkInfo + Peers + NetworkPeersEvents + Clone + 'static,
 //!     Events:
 //!         CanonStateSubscriptions<Primitives = reth_primitives::EthPrimitives> + Clone + 'static,
 //!     EvmConfig: ConfigureEvm<Header = Header, Transaction = TransactionSigned>,
@@ -86,7 +86,7 @@
 //! use alloy_consensus::Header;
 //! use reth_engine_primitives::EngineTypes;
 //! use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
-//! use reth_network_api::{NetworkInfo, Peers};
+//! use reth_network_api::{NetworkInfo, NetworkPeersEvents, Peers};
 //! use reth_primitives::TransactionSigned;
 //! use reth_provider::{AccountReader, CanonStateSubscriptions, ChangeSetReader, FullRpcProvider};
 //! use reth_rpc::EthApi;
@@ -130,7 +130,7 @@
 //!     Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>>
 //!         + Unpin
 //!         + 'static,
-//!     Network: NetworkInfo + Peers + Clone + 'static,
+//!     Network: NetworkInfo + Peers + NetworkPeersEvents + Clone + 'static,
 //!     Events:
 //!         CanonStateSubscriptions<Primitives = reth_primitives::EthPrimitives> + Clone + 'static,
 //!     EngineApi: EngineApiServer<EngineT>,
@@ -182,11 +182,20 @@ use std::{
     collections::HashMap,
     fmt::Debug,
     net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    str::FromStr,
     sync::Arc,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use crate::{auth::AuthRpcModule, error::WsHttpSamePortError, metrics::RpcRequestMetrics};
+use crate::{
+    accounting::RpcAccounting,
+    auth::AuthRpcModule,
+    error::WsHttpSamePortError,
+    metrics::RpcRequestMetrics,
+    rate_limits::RpcRateLimits,
+    response_limits::{ResponseLimitedRpcService, ResponseSizeLimits},
+    snapshot::BatchSnapshotHttpLayer,
+};
 use alloy_consensus::Header;
 use error::{ConflictingModules, RpcError, ServerKind};
 use eth::DynEthApiBuilder;
@@ -203,7 +212,8 @@ use reth_chainspec::EthereumHardforks;
 use reth_consensus::FullConsensus;
 use reth_engine_primitives::EngineTypes;
 use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
-use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
+use reth_memory_budget::MemoryBudget;
+use reth_network_api::{noop::NoopNetwork, NetworkInfo, NetworkPeersEvents, Peers};
 use reth_primitives::NodePrimitives;
 use reth_provider::{
     AccountReader, BlockReader, CanonStateSubscriptions, ChainSpecProvider, ChangeSetReader,
@@ -211,16 +221,21 @@ use reth_provider::{
     StateProviderFactory,
 };
 use reth_rpc::{
-    AdminApi, DebugApi, EngineEthApi, EthBundle, MinerApi, NetApi, OtterscanApi, RPCApi, RethApi,
+    AdminApi, ConfigurableTracer, DebugApi, EngineEthApi, EthBundle, EthSendRawTransactionSync,
+    EthSimBundle, FingerprintApi, FingerprintApiConfig, MinerApi, NativeTracers, NetApi, NonceApi,
+    OtterscanApi, OtterscanApiConfig, RPCApi, RethApi, RethApiConfig, StatsApi, StatsApiConfig,
     TraceApi, TxPoolApi, ValidationApi, ValidationApiConfig, Web3Api,
 };
 use reth_rpc_api::servers::*;
 use reth_rpc_eth_api::{
-    helpers::{Call, EthApiSpec, EthTransactions, LoadPendingBlock, TraceExt},
+    helpers::{Call, EthApiSpec, EthState, EthTransactions, LoadPendingBlock, TraceExt},
     EthApiServer, EthApiTypes, FullEthApiServer, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction,
 };
 use reth_rpc_eth_types::{EthConfig, EthStateCache, EthSubscriptionIdProvider};
 use reth_rpc_layer::{AuthLayer, Claims, CompressionLayer, JwtAuthValidator, JwtSecret};
+use reth_rpc_server_types::constants::{
+    DEFAULT_FINGERPRINTS_MAX_FILE_SIZE_MB, DEFAULT_STATS_RETENTION_DAYS,
+};
 use reth_tasks::{pool::BlockingTaskGuard, TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{noop::NoopTransactionPool, TransactionPool};
 use serde::{Deserialize, Serialize};
@@ -257,9 +272,36 @@ pub use eth::EthHandlers;
 mod metrics;
 pub use metrics::{MeteredRequestFuture, RpcRequestMetricsService};
 
+// Rpc accounting per API key
+pub mod accounting;
+
 // Rpc rate limiter
 pub mod rate_limiter;
 
+// Rpc token bucket rate limits per client and method
+pub mod rate_limits;
+
+// Rpc response size limits per method
+pub mod response_limits;
+
+// Rpc pinned block tag
+pub mod pinned;
+
+// Rpc in-flight requests tracking for the pruner throttle
+pub mod load;
+
+// Rpc scheduling of tracing calls by cost
+pub mod trace_scheduler;
+
+// Rpc staleness guard of the safe and finalized block tags
+pub mod finality;
+
+// Rpc consistent latest block across the calls of a batch
+pub mod snapshot;
+
+// Rpc fast path for trivial eth calls
+pub mod fast_path;
+
 /// Convenience function for starting a server in one step.
 #[allow(clippy::too_many_arguments)]
 pub async fn launch<Provider, Pool, Network, Tasks, Events, EvmConfig, EthApi, BlockExecutor>(
@@ -283,7 +325,7 @@ where
         > + AccountReader
         + ChangeSetReader,
     Pool: TransactionPool<Transaction = <EthApi::Pool as TransactionPool>::Transaction> + 'static,
-    Network: NetworkInfo + Peers + Clone + 'static,
+    Network: NetworkInfo + Peers + NetworkPeersEvents + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
     Events: CanonStateSubscriptions<Primitives = BlockExecutor::Primitives> + Clone + 'static,
     EvmConfig: ConfigureEvm<
@@ -353,6 +395,10 @@ pub struct RpcModuleBuilder<
     block_executor: BlockExecutor,
     /// The consensus implementation.
     consensus: Consensus,
+    /// The native tracers that can be selected in the `debug` tracing methods.
+    tracers: NativeTracers,
+    /// The memory budget the `eth` state cache registers with.
+    memory_budget: MemoryBudget,
 }
 
 // === impl RpcBuilder ===
@@ -362,7 +408,7 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
 {
     /// Create a new instance of the builder
     #[allow(clippy::too_many_arguments)]
-    pub const fn new(
+    pub fn new(
         provider: Provider,
         pool: Pool,
         network: Network,
@@ -372,7 +418,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         block_executor: BlockExecutor,
         consensus: Consensus,
     ) -> Self {
-        Self { provider, pool, network, executor, events, evm_config, block_executor, consensus }
+        Self {
+            provider,
+            pool,
+            network,
+            executor,
+            events,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers: NativeTracers::default(),
+            memory_budget: MemoryBudget::unlimited(),
+        }
     }
 
     /// Configure the provider instance.
@@ -383,8 +440,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
     where
         P: BlockReader + StateProviderFactory + EvmEnvProvider + 'static,
     {
-        let Self { pool, network, executor, events, evm_config, block_executor, consensus, .. } =
-            self;
+        let Self {
+            pool,
+            network,
+            executor,
+            events,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             network,
@@ -394,6 +461,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -406,7 +475,16 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         P: TransactionPool + 'static,
     {
         let Self {
-            provider, network, executor, events, evm_config, block_executor, consensus, ..
+            provider,
+            network,
+            executor,
+            events,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
         } = self;
         RpcModuleBuilder {
             provider,
@@ -417,6 +495,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -438,7 +518,16 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         Consensus,
     > {
         let Self {
-            provider, executor, events, network, evm_config, block_executor, consensus, ..
+            provider,
+            executor,
+            events,
+            network,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
         } = self;
         RpcModuleBuilder {
             provider,
@@ -449,6 +538,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             block_executor,
             pool: NoopTransactionPool::default(),
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -461,7 +552,16 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         N: NetworkInfo + Peers + 'static,
     {
         let Self {
-            provider, pool, executor, events, evm_config, block_executor, consensus, ..
+            provider,
+            pool,
+            executor,
+            events,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
         } = self;
         RpcModuleBuilder {
             provider,
@@ -472,6 +572,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -493,7 +595,16 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         Consensus,
     > {
         let Self {
-            provider, pool, executor, events, evm_config, block_executor, consensus, ..
+            provider,
+            pool,
+            executor,
+            events,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
         } = self;
         RpcModuleBuilder {
             provider,
@@ -504,6 +615,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -515,8 +628,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
     where
         T: TaskSpawner + 'static,
     {
-        let Self { pool, network, provider, events, evm_config, block_executor, consensus, .. } =
-            self;
+        let Self {
+            pool,
+            network,
+            provider,
+            events,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             network,
@@ -526,6 +649,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -545,8 +670,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         BlockExecutor,
         Consensus,
     > {
-        let Self { pool, network, provider, events, evm_config, block_executor, consensus, .. } =
-            self;
+        let Self {
+            pool,
+            network,
+            provider,
+            events,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             network,
@@ -556,6 +691,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -568,7 +705,16 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         E: CanonStateSubscriptions + 'static,
     {
         let Self {
-            provider, pool, executor, network, evm_config, block_executor, consensus, ..
+            provider,
+            pool,
+            executor,
+            network,
+            evm_config,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
         } = self;
         RpcModuleBuilder {
             provider,
@@ -579,6 +725,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -590,8 +738,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
     where
         E: ConfigureEvm + 'static,
     {
-        let Self { provider, pool, executor, network, events, block_executor, consensus, .. } =
-            self;
+        let Self {
+            provider,
+            pool,
+            executor,
+            network,
+            events,
+            block_executor,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             network,
@@ -601,6 +759,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -612,7 +772,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
     where
         BE: BlockExecutorProvider,
     {
-        let Self { provider, network, pool, executor, events, evm_config, consensus, .. } = self;
+        let Self {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            consensus,
+            tracers,
+            memory_budget,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             network,
@@ -622,6 +793,8 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
 
@@ -630,8 +803,18 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
         self,
         consensus: C,
     ) -> RpcModuleBuilder<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, C> {
-        let Self { provider, network, pool, executor, events, evm_config, block_executor, .. } =
-            self;
+        let Self {
+            provider,
+            network,
+            pool,
+            executor,
+            events,
+            evm_config,
+            block_executor,
+            tracers,
+            memory_budget,
+            ..
+        } = self;
         RpcModuleBuilder {
             provider,
             network,
@@ -641,8 +824,34 @@ impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         }
     }
+
+    /// Registers a native tracer under the given name.
+    ///
+    /// The tracer is selected in `debug_traceTransaction`, `debug_traceCall` and the block tracing
+    /// methods if the `tracer` field of the tracing options is set to the name. The names of the
+    /// built-in tracers, e.g. `callTracer`, always select the built-in tracer.
+    pub fn with_tracer(mut self, name: impl Into<String>, tracer: impl ConfigurableTracer) -> Self {
+        self.tracers.register(name, tracer);
+        self
+    }
+
+    /// Configures the native tracers, replacing all registered tracers.
+    ///
+    /// See also [`Self::with_tracer`].
+    pub fn with_native_tracers(mut self, tracers: NativeTracers) -> Self {
+        self.tracers = tracers;
+        self
+    }
+
+    /// Configures the memory budget the `eth` state cache registers with.
+    pub fn with_memory_budget(mut self, memory_budget: MemoryBudget) -> Self {
+        self.memory_budget = memory_budget;
+        self
+    }
 }
 
 impl<Provider, Pool, Network, Tasks, Events, EvmConfig, BlockExecutor, Consensus>
@@ -654,7 +863,7 @@ where
         > + AccountReader
         + ChangeSetReader,
     Pool: TransactionPool + 'static,
-    Network: NetworkInfo + Peers + Clone + 'static,
+    Network: NetworkInfo + Peers + NetworkPeersEvents + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
     Events: CanonStateSubscriptions<Primitives = BlockExecutor::Primitives> + Clone + 'static,
     EvmConfig: ConfigureEvm<
@@ -706,6 +915,8 @@ where
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         } = self;
 
         let config = module_config.config.clone().unwrap_or_default();
@@ -721,7 +932,9 @@ where
             evm_config,
             eth,
             block_executor,
-        );
+            &memory_budget,
+        )
+        .with_native_tracers(tracers);
 
         let modules = registry.create_transport_rpc_modules(module_config);
 
@@ -784,6 +997,8 @@ where
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         } = self;
         RpcRegistryInner::new(
             provider,
@@ -796,7 +1011,9 @@ where
             evm_config,
             eth,
             block_executor,
+            &memory_budget,
         )
+        .with_native_tracers(tracers)
     }
 
     /// Configures all [`RpcModule`]s specific to the given [`TransportRpcModuleConfig`] which can
@@ -827,6 +1044,8 @@ where
             evm_config,
             block_executor,
             consensus,
+            tracers,
+            memory_budget,
         } = self;
 
         if !module_config.is_empty() {
@@ -843,7 +1062,9 @@ where
                 evm_config,
                 eth,
                 block_executor,
-            );
+                &memory_budget,
+            )
+            .with_native_tracers(tracers);
 
             modules.config = module_config;
             modules.http = registry.maybe_module(http.as_ref());
@@ -868,6 +1089,14 @@ pub struct RpcModuleConfig {
     eth: EthConfig,
     /// `flashbots` namespace settings
     flashbots: ValidationApiConfig,
+    /// `reth` namespace settings
+    reth: RethApiConfig,
+    /// `stats` namespace settings
+    stats: StatsApiConfig,
+    /// `fingerprint` namespace settings
+    fingerprint: FingerprintApiConfig,
+    /// `ots` namespace settings
+    ots: OtterscanApiConfig,
 }
 
 // === impl RpcModuleConfig ===
@@ -880,7 +1109,41 @@ impl RpcModuleConfig {
 
     /// Returns a new RPC module config given the eth namespace config
     pub const fn new(eth: EthConfig, flashbots: ValidationApiConfig) -> Self {
-        Self { eth, flashbots }
+        Self {
+            eth,
+            flashbots,
+            reth: RethApiConfig { abi_dir: None, abi_lookup: false },
+            stats: StatsApiConfig { enabled: false, retention_days: DEFAULT_STATS_RETENTION_DAYS },
+            fingerprint: FingerprintApiConfig {
+                path: None,
+                max_file_size: DEFAULT_FINGERPRINTS_MAX_FILE_SIZE_MB * 1024 * 1024,
+            },
+            ots: OtterscanApiConfig { address_index: false },
+        }
+    }
+
+    /// Configures the reth namespace
+    pub fn with_reth(mut self, reth: RethApiConfig) -> Self {
+        self.reth = reth;
+        self
+    }
+
+    /// Configures the stats namespace
+    pub const fn with_stats(mut self, stats: StatsApiConfig) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Configures the fingerprint namespace
+    pub fn with_fingerprint(mut self, fingerprint: FingerprintApiConfig) -> Self {
+        self.fingerprint = fingerprint;
+        self
+    }
+
+    /// Configures the ots namespace
+    pub const fn with_ots(mut self, ots: OtterscanApiConfig) -> Self {
+        self.ots = ots;
+        self
     }
 
     /// Get a reference to the eth namespace config
@@ -892,6 +1155,11 @@ impl RpcModuleConfig {
     pub fn eth_mut(&mut self) -> &mut EthConfig {
         &mut self.eth
     }
+
+    /// Get a mutable reference to the ots namespace config
+    pub fn ots_mut(&mut self) -> &mut OtterscanApiConfig {
+        &mut self.ots
+    }
 }
 
 /// Configures [`RpcModuleConfig`]
@@ -899,6 +1167,10 @@ impl RpcModuleConfig {
 pub struct RpcModuleConfigBuilder {
     eth: Option<EthConfig>,
     flashbots: Option<ValidationApiConfig>,
+    reth: Option<RethApiConfig>,
+    stats: Option<StatsApiConfig>,
+    fingerprint: Option<FingerprintApiConfig>,
+    ots: Option<OtterscanApiConfig>,
 }
 
 // === impl RpcModuleConfigBuilder ===
@@ -916,10 +1188,41 @@ impl RpcModuleConfigBuilder {
         self
     }
 
+    /// Configures a custom reth namespace config
+    pub fn reth(mut self, reth: RethApiConfig) -> Self {
+        self.reth = Some(reth);
+        self
+    }
+
+    /// Configures a custom stats namespace config
+    pub const fn stats(mut self, stats: StatsApiConfig) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// Configures a custom fingerprint namespace config
+    pub fn fingerprint(mut self, fingerprint: FingerprintApiConfig) -> Self {
+        self.fingerprint = Some(fingerprint);
+        self
+    }
+
+    /// Configures a custom ots namespace config
+    pub const fn ots(mut self, ots: OtterscanApiConfig) -> Self {
+        self.ots = Some(ots);
+        self
+    }
+
     /// Consumes the type and creates the [`RpcModuleConfig`]
     pub fn build(self) -> RpcModuleConfig {
-        let Self { eth, flashbots } = self;
-        RpcModuleConfig { eth: eth.unwrap_or_default(), flashbots: flashbots.unwrap_or_default() }
+        let Self { eth, flashbots, reth, stats, fingerprint, ots } = self;
+        RpcModuleConfig {
+            eth: eth.unwrap_or_default(),
+            flashbots: flashbots.unwrap_or_default(),
+            reth: reth.unwrap_or_default(),
+            stats: stats.unwrap_or_default(),
+            fingerprint: fingerprint.unwrap_or_default(),
+            ots: ots.unwrap_or_default(),
+        }
     }
 
     /// Get a reference to the eth namespace config, if any
@@ -963,6 +1266,8 @@ pub struct RpcRegistryInner<
     eth: EthHandlers<Provider, Events, EthApi>,
     /// to put trace calls behind semaphore
     blocking_pool_guard: BlockingTaskGuard,
+    /// native tracers that can be selected in the `debug` tracing methods
+    native_tracers: NativeTracers,
     /// Contains the [Methods] of a module
     modules: HashMap<RethRpcModule, Methods>,
 }
@@ -1008,6 +1313,7 @@ where
             EthApi,
         >,
         block_executor: BlockExecutor,
+        memory_budget: &MemoryBudget,
     ) -> Self
     where
         EvmConfig: ConfigureEvm<Header = Header>,
@@ -1023,6 +1329,7 @@ where
             executor.clone(),
             events.clone(),
             eth_api_builder,
+            memory_budget,
         );
 
         Self {
@@ -1037,8 +1344,15 @@ where
             blocking_pool_guard,
             events,
             block_executor,
+            native_tracers: NativeTracers::default(),
         }
     }
+
+    /// Configures the native tracers that can be selected in the `debug` tracing methods.
+    pub fn with_native_tracers(mut self, native_tracers: NativeTracers) -> Self {
+        self.native_tracers = native_tracers;
+        self
+    }
 }
 
 impl<Provider, Pool, Network, Tasks, Events, EthApi, BlockExecutor, Consensus>
@@ -1143,7 +1457,8 @@ impl<Provider, Pool, Network, Tasks, Events, EthApi, BlockExecutor, Consensus>
     RpcRegistryInner<Provider, Pool, Network, Tasks, Events, EthApi, BlockExecutor, Consensus>
 where
     Provider: FullRpcProvider + AccountReader + ChangeSetReader,
-    Network: NetworkInfo + Peers + Clone + 'static,
+    Pool: TransactionPool + 'static,
+    Network: NetworkInfo + Peers + NetworkPeersEvents + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
     EthApi: EthApiServer<
             RpcTransaction<EthApi::NetworkTypes>,
@@ -1230,9 +1545,15 @@ where
     /// # Panics
     ///
     /// If called outside of the tokio runtime.
-    pub fn register_reth(&mut self) -> &mut Self {
-        let rethapi = self.reth_api();
-        self.modules.insert(RethRpcModule::Reth, rethapi.into_rpc().into());
+    pub fn register_reth(&mut self) -> &mut Self
+    where
+        EthApi: EthState,
+    {
+        let mut module = self.reth_api().into_rpc();
+        module
+            .merge(NonceApi::new(self.provider.clone(), self.pool.clone()).into_rpc())
+            .expect("No conflicts");
+        self.modules.insert(RethRpcModule::Reth, module.into());
         self
     }
 
@@ -1241,9 +1562,9 @@ where
     /// # Panics
     ///
     /// If called outside of the tokio runtime. See also [`Self::eth_api`]
-    pub fn otterscan_api(&self) -> OtterscanApi<EthApi> {
+    pub fn otterscan_api(&self) -> OtterscanApi<Provider, EthApi> {
         let eth_api = self.eth_api().clone();
-        OtterscanApi::new(eth_api)
+        OtterscanApi::new(self.provider.clone(), eth_api, self.config.ots)
     }
 }
 
@@ -1251,7 +1572,7 @@ impl<Provider, Pool, Network, Tasks, Events, EthApi, BlockExecutor, Consensus>
     RpcRegistryInner<Provider, Pool, Network, Tasks, Events, EthApi, BlockExecutor, Consensus>
 where
     Provider: FullRpcProvider + AccountReader + ChangeSetReader,
-    Network: NetworkInfo + Peers + Clone + 'static,
+    Network: NetworkInfo + Peers + NetworkPeersEvents + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
     EthApi: EthApiTypes,
     BlockExecutor: BlockExecutorProvider,
@@ -1265,7 +1586,11 @@ where
     where
         EthApi: TraceExt,
     {
-        TraceApi::new(self.eth_api().clone(), self.blocking_pool_guard.clone())
+        TraceApi::new(
+            self.eth_api().clone(),
+            self.blocking_pool_guard.clone(),
+            self.config.eth.trace_filter_config(),
+        )
     }
 
     /// Instantiates [`EthBundle`] Api
@@ -1291,10 +1616,11 @@ where
         EthApi: EthApiSpec + EthTransactions + TraceExt,
         BlockExecutor::Primitives: NodePrimitives<Block = ProviderBlock<EthApi::Provider>>,
     {
-        DebugApi::new(
+        DebugApi::new_with_tracers(
             self.eth_api().clone(),
             self.blocking_pool_guard.clone(),
             self.block_executor.clone(),
+            self.native_tracers.clone(),
         )
     }
 
@@ -1312,8 +1638,19 @@ where
     }
 
     /// Instantiates `RethApi`
-    pub fn reth_api(&self) -> RethApi<Provider> {
-        RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+    pub fn reth_api(&self) -> RethApi<Provider, Network>
+    where
+        EthApi: EthState,
+    {
+        let eth_api = self.eth_api();
+        RethApi::new(
+            self.provider.clone(),
+            self.network.clone(),
+            Box::new(self.executor.clone()),
+            eth_api.max_proof_window(),
+            eth_api.tracing_task_guard().clone(),
+            self.config.reth.clone(),
+        )
     }
 
     /// Instantiates `ValidationApi`
@@ -1336,7 +1673,7 @@ impl<Provider, Pool, Network, Tasks, Events, EthApi, BlockExecutor, Consensus>
 where
     Provider: FullRpcProvider + AccountReader + ChangeSetReader,
     Pool: TransactionPool + 'static,
-    Network: NetworkInfo + Peers + Clone + 'static,
+    Network: NetworkInfo + Peers + NetworkPeersEvents + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
     Events: CanonStateSubscriptions<Primitives = BlockExecutor::Primitives> + Clone + 'static,
     EthApi: FullEthApiServer<
@@ -1441,10 +1778,11 @@ where
                                 .into_rpc()
                                 .into()
                         }
-                        RethRpcModule::Debug => DebugApi::new(
+                        RethRpcModule::Debug => DebugApi::new_with_tracers(
                             eth_api.clone(),
                             self.blocking_pool_guard.clone(),
                             self.block_executor.clone(),
+                            self.native_tracers.clone(),
                         )
                         .into_rpc()
                         .into(),
@@ -1453,26 +1791,36 @@ where
                             let mut module = eth_api.clone().into_rpc();
                             module.merge(eth_filter.clone().into_rpc()).expect("No conflicts");
                             module.merge(eth_pubsub.clone().into_rpc()).expect("No conflicts");
+                            let bundle =
+                                EthBundle::new(eth_api.clone(), self.blocking_pool_guard.clone());
                             module
-                                .merge(
-                                    EthBundle::new(
-                                        eth_api.clone(),
-                                        self.blocking_pool_guard.clone(),
-                                    )
-                                    .into_rpc(),
-                                )
+                                .merge(EthCallBundleApiServer::into_rpc(bundle.clone()))
                                 .expect("No conflicts");
+                            module
+                                .merge(EthSendBundleApiServer::into_rpc(bundle))
+                                .expect("No conflicts");
+                            if let Some(timeout) = self.config.eth.send_raw_transaction_sync_timeout
+                            {
+                                let send_sync = EthSendRawTransactionSync::new(
+                                    eth_api.clone(),
+                                    self.events.clone(),
+                                    timeout,
+                                );
+                                module.merge(send_sync.into_rpc()).expect("No conflicts");
+                            }
 
                             module.into()
                         }
                         RethRpcModule::Net => {
                             NetApi::new(self.network.clone(), eth_api.clone()).into_rpc().into()
                         }
-                        RethRpcModule::Trace => {
-                            TraceApi::new(eth_api.clone(), self.blocking_pool_guard.clone())
-                                .into_rpc()
-                                .into()
-                        }
+                        RethRpcModule::Trace => TraceApi::new(
+                            eth_api.clone(),
+                            self.blocking_pool_guard.clone(),
+                            self.config.eth.trace_filter_config(),
+                        )
+                        .into_rpc()
+                        .into(),
                         RethRpcModule::Web3 => Web3Api::new(self.network.clone()).into_rpc().into(),
                         RethRpcModule::Txpool => TxPoolApi::new(
                             self.eth.api.pool().clone(),
@@ -1488,11 +1836,30 @@ where
                         )
                         .into_rpc()
                         .into(),
-                        RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
+                        RethRpcModule::Ots => OtterscanApi::new(
+                            self.provider.clone(),
+                            eth_api.clone(),
+                            self.config.ots,
+                        )
+                        .into_rpc()
+                        .into(),
                         RethRpcModule::Reth => {
-                            RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
-                                .into_rpc()
-                                .into()
+                            let mut module = RethApi::new(
+                                self.provider.clone(),
+                                self.network.clone(),
+                                Box::new(self.executor.clone()),
+                                eth_api.max_proof_window(),
+                                eth_api.tracing_task_guard().clone(),
+                                self.config.reth.clone(),
+                            )
+                            .into_rpc();
+                            module
+                                .merge(
+                                    NonceApi::new(self.provider.clone(), self.pool.clone())
+                                        .into_rpc(),
+                                )
+                                .expect("No conflicts");
+                            module.into()
                         }
                         RethRpcModule::Flashbots => ValidationApi::new(
                             eth_api.provider().clone(),
@@ -1503,7 +1870,37 @@ where
                         )
                         .into_rpc()
                         .into(),
-                        RethRpcModule::Miner => MinerApi::default().into_rpc().into(),
+                        RethRpcModule::Miner => {
+                            MinerApi::new(self.eth.api.pool().clone()).into_rpc().into()
+                        }
+                        RethRpcModule::Stats => StatsApi::new(
+                            eth_api.provider().clone(),
+                            &self.events,
+                            Box::new(self.executor.clone()),
+                            self.config.stats.clone(),
+                        )
+                        .into_rpc()
+                        .into(),
+                        RethRpcModule::Fingerprint => FingerprintApi::new(
+                            &self.events,
+                            Box::new(self.executor.clone()),
+                            self.config.fingerprint.clone(),
+                        )
+                        .into_rpc()
+                        .into(),
+                        RethRpcModule::Mev => {
+                            let mut module = MevSimApiServer::into_rpc(EthSimBundle::new(
+                                eth_api.clone(),
+                                self.blocking_pool_guard.clone(),
+                            ));
+                            module
+                                .merge(MevCallBundleApiServer::into_rpc(EthBundle::new(
+                                    eth_api.clone(),
+                                    self.blocking_pool_guard.clone(),
+                                )))
+                                .expect("No conflicts");
+                            module.into()
+                        }
                     })
                     .clone()
             })
@@ -1540,10 +1937,20 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     ipc_server_config: Option<IpcServerBuilder<Identity, Identity>>,
     /// The Endpoint where to launch the ipc server
     ipc_endpoint: Option<String>,
+    /// Additional ipc endpoints that serve a subset of the ipc modules.
+    additional_ipc_endpoints: Vec<IpcEndpointConfig>,
     /// JWT secret for authentication
     jwt_secret: Option<JwtSecret>,
     /// Configurable RPC middleware
     rpc_middleware: RpcServiceBuilder<RpcMiddleware>,
+    /// Accounting of the compute units used per API key for http and ws.
+    accounting: Option<RpcAccounting>,
+    /// Rate limits of the calls per client for http and ws.
+    rate_limits: Option<RpcRateLimits>,
+    /// Response size limits per method for http and ws.
+    response_limits: Option<ResponseSizeLimits>,
+    /// Whether a [`BatchSnapshot`](snapshot::BatchSnapshot) is inserted into each http request.
+    batch_snapshots: bool,
 }
 
 // === impl RpcServerConfig ===
@@ -1560,8 +1967,13 @@ impl Default for RpcServerConfig<Identity> {
             ws_addr: None,
             ipc_server_config: None,
             ipc_endpoint: None,
+            additional_ipc_endpoints: Vec::new(),
             jwt_secret: None,
             rpc_middleware: RpcServiceBuilder::new(),
+            accounting: None,
+            rate_limits: None,
+            response_limits: None,
+            batch_snapshots: false,
         }
     }
 }
@@ -1623,8 +2035,13 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ws_addr: self.ws_addr,
             ipc_server_config: self.ipc_server_config,
             ipc_endpoint: self.ipc_endpoint,
+            additional_ipc_endpoints: self.additional_ipc_endpoints,
             jwt_secret: self.jwt_secret,
             rpc_middleware,
+            accounting: self.accounting,
+            rate_limits: self.rate_limits,
+            response_limits: self.response_limits,
+            batch_snapshots: self.batch_snapshots,
         }
     }
 
@@ -1677,8 +2094,17 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             self.ws_server_config = Some(ws.set_id_provider(id_provider.clone()));
         }
         if let Some(ipc) = self.ipc_server_config {
-            self.ipc_server_config = Some(ipc.set_id_provider(id_provider));
+            self.ipc_server_config = Some(ipc.set_id_provider(id_provider.clone()));
         }
+        self.additional_ipc_endpoints = self
+            .additional_ipc_endpoints
+            .into_iter()
+            .map(|mut endpoint| {
+                endpoint.server_config =
+                    endpoint.server_config.set_id_provider(id_provider.clone());
+                endpoint
+            })
+            .collect();
 
         self
     }
@@ -1691,19 +2117,69 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Adds an ipc endpoint that only serves the selected modules of the ipc transport.
+    ///
+    /// The ipc modules are configured with [`TransportRpcModuleConfig::with_ipc`], so the
+    /// additional endpoints can only serve a subset of them.
+    pub fn with_additional_ipc_endpoint(mut self, endpoint: IpcEndpointConfig) -> Self {
+        self.additional_ipc_endpoints.push(endpoint);
+        self
+    }
+
     /// Configures the JWT secret for authentication.
     pub const fn with_jwt_secret(mut self, secret: Option<JwtSecret>) -> Self {
         self.jwt_secret = secret;
         self
     }
 
+    /// Configures the accounting of the compute units used per API key.
+    ///
+    /// If set, all http and ws requests without a known API key are rejected before they reach
+    /// the rpc middleware, and calls that exceed the quota of their API key are rejected.
+    pub fn with_accounting(mut self, accounting: Option<RpcAccounting>) -> Self {
+        self.accounting = accounting;
+        self
+    }
+
+    /// Configures the rate limits of the calls per client.
+    ///
+    /// If set, all http and ws calls that exceed the rate limits of their client are rejected
+    /// before they are charged to the accounting.
+    pub fn with_rate_limits(mut self, rate_limits: Option<RpcRateLimits>) -> Self {
+        self.rate_limits = rate_limits;
+        self
+    }
+
+    /// Configures the response size limits per method.
+    ///
+    /// If set, http and ws responses that exceed the limit of their method or the maximum response
+    /// size of the server are replaced with an error that asks to narrow the query.
+    pub fn with_response_size_limits(
+        mut self,
+        response_limits: Option<ResponseSizeLimits>,
+    ) -> Self {
+        self.response_limits = response_limits;
+        self
+    }
+
+    /// Configures whether a [`BatchSnapshot`](snapshot::BatchSnapshot) is inserted into each http
+    /// request, so that all calls of a batch read the same `latest` block.
+    ///
+    /// This requires the [`BatchSnapshotLayer`](snapshot::BatchSnapshotLayer) in the rpc
+    /// middleware, see [`Self::set_rpc_middleware`].
+    pub const fn with_batch_snapshots(mut self, batch_snapshots: bool) -> Self {
+        self.batch_snapshots = batch_snapshots;
+        self
+    }
+
     /// Returns true if any server is configured.
     ///
     /// If no server is configured, no server will be launched on [`RpcServerConfig::start`].
-    pub const fn has_server(&self) -> bool {
+    pub fn has_server(&self) -> bool {
         self.http_server_config.is_some() ||
             self.ws_server_config.is_some() ||
-            self.ipc_server_config.is_some()
+            self.ipc_server_config.is_some() ||
+            !self.additional_ipc_endpoints.is_empty()
     }
 
     /// Returns the [`SocketAddr`] of the http server
@@ -1731,6 +2207,26 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         jwt_secret.map(|secret| AuthLayer::new(JwtAuthValidator::new(secret)))
     }
 
+    /// Creates the [`BatchSnapshotHttpLayer`] if enabled
+    const fn maybe_batch_snapshot_layer(&self) -> Option<BatchSnapshotHttpLayer> {
+        if self.batch_snapshots {
+            Some(BatchSnapshotHttpLayer)
+        } else {
+            None
+        }
+    }
+
+    /// Applies the response size limits to the methods of the module, if any.
+    fn limit_methods(
+        response_limits: Option<&ResponseSizeLimits>,
+        module: RpcModule<()>,
+    ) -> RpcModule<()> {
+        match response_limits {
+            Some(response_limits) => response_limits.limit_methods(module),
+            None => module,
+        }
+    }
+
     /// Returns a [`CompressionLayer`] that adds compression support (gzip, deflate, brotli, zstd)
     /// based on the client's `Accept-Encoding` header
     fn maybe_compression_layer() -> Option<CompressionLayer> {
@@ -1744,8 +2240,9 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
     /// Returns the [`RpcServerHandle`] with the handle to the started servers.
     pub async fn start(self, modules: &TransportRpcModules) -> Result<RpcServerHandle, RpcError>
     where
-        RpcMiddleware: Layer<RpcRequestMetricsService<RpcService>> + Clone + Send + 'static,
-        for<'a> <RpcMiddleware as Layer<RpcRequestMetricsService<RpcService>>>::Service:
+        RpcMiddleware:
+            Layer<RpcRequestMetricsService<ResponseLimitedRpcService>> + Clone + Send + 'static,
+        for<'a> <RpcMiddleware as Layer<RpcRequestMetricsService<ResponseLimitedRpcService>>>::Service:
             Send + Sync + 'static + RpcServiceT<'a>,
     {
         let mut http_handle = None;
@@ -1773,6 +2270,24 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ipc_handle = Some(ipc.start(modules.ipc.clone().expect("ipc server error")).await?);
         }
 
+        let mut additional_ipc_handles = Vec::with_capacity(self.additional_ipc_endpoints.len());
+        for endpoint in self.additional_ipc_endpoints {
+            let Some(module) = modules.ipc.as_ref() else {
+                return Err(RpcError::Custom(format!(
+                    "ipc endpoint {} requires the ipc transport",
+                    endpoint.endpoint
+                )))
+            };
+            let module = endpoint.filter_module(module);
+            let ipc = endpoint
+                .server_config
+                .set_rpc_middleware(
+                    IpcRpcServiceBuilder::new().layer(RpcRequestMetrics::ipc(&module)),
+                )
+                .build(endpoint.endpoint.clone());
+            additional_ipc_handles.push((endpoint.endpoint, ipc.start(module).await?));
+        }
+
         // If both are configured on the same port, we combine them into one server.
         if self.http_addr == self.ws_addr &&
             self.http_server_config.is_some() &&
@@ -1802,17 +2317,30 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                         tower::ServiceBuilder::new()
                             .option_layer(Self::maybe_cors_layer(cors)?)
                             .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                            .option_layer(self.accounting.as_ref().map(RpcAccounting::auth_layer))
+                            .option_layer(
+                                self.accounting.as_ref().map(RpcAccounting::api_key_layer),
+                            )
+                            .option_layer(
+                                self.rate_limits.as_ref().map(RpcRateLimits::client_layer),
+                            )
+                            .option_layer(self.maybe_batch_snapshot_layer())
                             .option_layer(Self::maybe_compression_layer()),
                     )
                     .set_rpc_middleware(
-                        self.rpc_middleware.clone().layer(
-                            modules
-                                .http
-                                .as_ref()
-                                .or(modules.ws.as_ref())
-                                .map(RpcRequestMetrics::same_port)
-                                .unwrap_or_default(),
-                        ),
+                        self.rpc_middleware
+                            .clone()
+                            .layer(
+                                modules
+                                    .http
+                                    .as_ref()
+                                    .or(modules.ws.as_ref())
+                                    .map(RpcRequestMetrics::same_port)
+                                    .unwrap_or_default(),
+                            )
+                            .option_layer(self.response_limits.clone())
+                            .option_layer(self.rate_limits.clone())
+                            .option_layer(self.accounting.clone()),
                     )
                     .build(http_socket_addr)
                     .await
@@ -1823,7 +2351,8 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     RpcError::server_error(err, ServerKind::WsHttp(http_socket_addr))
                 })?;
                 if let Some(module) = modules.http.as_ref().or(modules.ws.as_ref()) {
-                    let handle = server.start(module.clone());
+                    let handle = server
+                        .start(Self::limit_methods(self.response_limits.as_ref(), module.clone()));
                     http_handle = Some(handle.clone());
                     ws_handle = Some(handle);
                 }
@@ -1834,6 +2363,7 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     ws: ws_handle,
                     ipc_endpoint: self.ipc_endpoint.clone(),
                     ipc: ipc_handle,
+                    additional_ipc: additional_ipc_handles,
                     jwt_secret: self.jwt_secret,
                 });
             }
@@ -1850,12 +2380,18 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
-                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
+                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                        .option_layer(self.accounting.as_ref().map(RpcAccounting::auth_layer))
+                        .option_layer(self.accounting.as_ref().map(RpcAccounting::api_key_layer))
+                        .option_layer(self.rate_limits.as_ref().map(RpcRateLimits::client_layer)),
                 )
                 .set_rpc_middleware(
                     self.rpc_middleware
                         .clone()
-                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default()),
+                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default())
+                        .option_layer(self.response_limits.clone())
+                        .option_layer(self.rate_limits.clone())
+                        .option_layer(self.accounting.clone()),
                 )
                 .build(ws_socket_addr)
                 .await
@@ -1876,12 +2412,21 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                        .option_layer(self.accounting.as_ref().map(RpcAccounting::auth_layer))
+                        .option_layer(self.accounting.as_ref().map(RpcAccounting::api_key_layer))
+                        .option_layer(self.rate_limits.as_ref().map(RpcRateLimits::client_layer))
+                        .option_layer(self.maybe_batch_snapshot_layer())
                         .option_layer(Self::maybe_compression_layer()),
                 )
                 .set_rpc_middleware(
-                    self.rpc_middleware.clone().layer(
-                        modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
-                    ),
+                    self.rpc_middleware
+                        .clone()
+                        .layer(
+                            modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
+                        )
+                        .option_layer(self.response_limits.clone())
+                        .option_layer(self.rate_limits.clone())
+                        .option_layer(self.accounting.clone()),
                 )
                 .build(http_socket_addr)
                 .await
@@ -1893,10 +2438,15 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             http_server = Some(server);
         }
 
-        http_handle = http_server
-            .map(|http_server| http_server.start(modules.http.clone().expect("http server error")));
-        ws_handle = ws_server
-            .map(|ws_server| ws_server.start(modules.ws.clone().expect("ws server error")));
+        let response_limits = self.response_limits.as_ref();
+        http_handle = http_server.map(|http_server| {
+            let module = modules.http.clone().expect("http server error");
+            http_server.start(Self::limit_methods(response_limits, module))
+        });
+        ws_handle = ws_server.map(|ws_server| {
+            let module = modules.ws.clone().expect("ws server error");
+            ws_server.start(Self::limit_methods(response_limits, module))
+        });
         Ok(RpcServerHandle {
             http_local_addr,
             ws_local_addr,
@@ -1904,11 +2454,72 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ws: ws_handle,
             ipc_endpoint: self.ipc_endpoint.clone(),
             ipc: ipc_handle,
+            additional_ipc: additional_ipc_handles,
             jwt_secret: self.jwt_secret,
         })
     }
 }
 
+/// An additional ipc endpoint that only serves the methods of the selected modules.
+///
+/// This allows serving modules with different socket permissions, e.g. `admin` on a socket that
+/// only the owner can connect to and `eth` on a world-readable socket.
+#[derive(Debug)]
+pub struct IpcEndpointConfig {
+    /// The endpoint where to launch the ipc server.
+    endpoint: String,
+    /// The modules served by the endpoint.
+    modules: RpcModuleSelection,
+    /// Configs for the ipc server.
+    server_config: IpcServerBuilder<Identity, Identity>,
+}
+
+impl IpcEndpointConfig {
+    /// Creates a new config for an ipc endpoint that serves the given modules.
+    ///
+    /// Note: this always configures an [`EthSubscriptionIdProvider`] [`IdProvider`] for
+    /// convenience.
+    pub fn new(
+        endpoint: impl Into<String>,
+        modules: impl Into<RpcModuleSelection>,
+        server_config: IpcServerBuilder<Identity, Identity>,
+    ) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            modules: modules.into(),
+            server_config: server_config.set_id_provider(EthSubscriptionIdProvider::default()),
+        }
+    }
+
+    /// Returns the endpoint of the ipc server.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Returns the modules served by the endpoint.
+    pub const fn modules(&self) -> &RpcModuleSelection {
+        &self.modules
+    }
+
+    /// Returns a copy of the given module without the methods of unselected modules.
+    ///
+    /// Methods are assigned to modules by their namespace prefix, methods of namespaces that are
+    /// not a [`RethRpcModule`] are removed.
+    fn filter_module(&self, module: &RpcModule<()>) -> RpcModule<()> {
+        let mut module = module.clone();
+        let method_names = module.method_names().collect::<Vec<_>>();
+        for name in method_names {
+            let namespace = name.split_once('_').map_or(name, |(namespace, _)| namespace);
+            let selected = RethRpcModule::from_str(namespace)
+                .is_ok_and(|namespace| self.modules.contains(&namespace));
+            if !selected {
+                module.remove_method(name);
+            }
+        }
+        module
+    }
+}
+
 /// Holds modules to be installed per transport type
 ///
 /// # Example
@@ -2314,6 +2925,7 @@ pub struct RpcServerHandle {
     ws: Option<ServerHandle>,
     ipc_endpoint: Option<String>,
     ipc: Option<jsonrpsee::server::ServerHandle>,
+    additional_ipc: Vec<(String, jsonrpsee::server::ServerHandle)>,
     jwt_secret: Option<JwtSecret>,
 }
 
@@ -2360,6 +2972,10 @@ impl RpcServerHandle {
             handle.stop()?
         }
 
+        for (_, handle) in self.additional_ipc {
+            handle.stop()?
+        }
+
         Ok(())
     }
 
@@ -2368,6 +2984,11 @@ impl RpcServerHandle {
         self.ipc_endpoint.clone()
     }
 
+    /// Returns the endpoints of the launched additional IPC servers.
+    pub fn additional_ipc_endpoints(&self) -> impl Iterator<Item = &str> {
+        self.additional_ipc.iter().map(|(endpoint, _)| endpoint.as_str())
+    }
+
     /// Returns the url to the http server
     pub fn http_url(&self) -> Option<String> {
         self.http_local_addr.map(|addr| format!("http://{addr}"))
@@ -2497,6 +3118,7 @@ mod tests {
                 "rpc" => RethRpcModule::Rpc,
                 "ots" => RethRpcModule::Ots,
                 "reth" => RethRpcModule::Reth,
+                "mev" => RethRpcModule::Mev,
             );
     }
 
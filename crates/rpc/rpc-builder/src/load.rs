@@ -0,0 +1,122 @@
+//! [`jsonrpsee`] helper layer for tracking the number of in-flight RPC requests.
+
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request, MethodResponse};
+use reth_prune_types::{NodeLoad, RpcRequestGuard};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower::Layer;
+
+/// Layer that records every RPC call as in-flight in the [`NodeLoad`] until its response is ready.
+///
+/// The pruner uses the number of in-flight requests to defer its runs while the RPC server is
+/// busy.
+#[derive(Debug, Clone)]
+pub struct NodeLoadLayer {
+    node_load: NodeLoad,
+}
+
+impl NodeLoadLayer {
+    /// Creates a new layer that records the requests in the given [`NodeLoad`].
+    pub const fn new(node_load: NodeLoad) -> Self {
+        Self { node_load }
+    }
+}
+
+impl<S> Layer<S> for NodeLoadLayer {
+    type Service = NodeLoadService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        NodeLoadService { inner, node_load: self.node_load.clone() }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that records the in-flight requests.
+#[derive(Debug, Clone)]
+pub struct NodeLoadService<S> {
+    /// The inner service being wrapped
+    inner: S,
+    /// Load of the node.
+    node_load: NodeLoad,
+}
+
+impl<'a, S> RpcServiceT<'a> for NodeLoadService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = TrackedRequestFuture<S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        TrackedRequestFuture {
+            guard: Some(self.node_load.rpc_request_started()),
+            fut: self.inner.call(req),
+        }
+    }
+}
+
+/// Response future that keeps the request in-flight until the response is ready.
+#[pin_project::pin_project]
+pub struct TrackedRequestFuture<F> {
+    #[pin]
+    fut: F,
+    /// Released once the response is ready, or the future is dropped.
+    guard: Option<RpcRequestGuard>,
+}
+
+impl<F> std::fmt::Debug for TrackedRequestFuture<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TrackedRequestFuture")
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for TrackedRequestFuture<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let res = this.fut.poll(cx);
+        if res.is_ready() {
+            this.guard.take();
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{types::Id, ResponsePayload};
+    use std::future::{ready, Ready};
+
+    #[derive(Clone)]
+    struct Respond;
+
+    impl<'a> RpcServiceT<'a> for Respond {
+        type Future = Ready<MethodResponse>;
+
+        fn call(&self, req: Request<'a>) -> Self::Future {
+            ready(MethodResponse::response(req.id, ResponsePayload::success(()), usize::MAX))
+        }
+    }
+
+    #[tokio::test]
+    async fn track_in_flight_requests() {
+        let node_load = NodeLoad::default();
+        let service = NodeLoadLayer::new(node_load.clone()).layer(Respond);
+
+        // request is released once the response is ready
+        let fut = service.call(Request::new("eth_blockNumber".into(), None, Id::Number(1)));
+        assert_eq!(node_load.rpc_queue_depth(), 1);
+        fut.await;
+        assert_eq!(node_load.rpc_queue_depth(), 0);
+
+        // or once the request is cancelled
+        let fut = service.call(Request::new("eth_blockNumber".into(), None, Id::Number(2)));
+        assert_eq!(node_load.rpc_queue_depth(), 1);
+        drop(fut);
+        assert_eq!(node_load.rpc_queue_depth(), 0);
+    }
+}
@@ -0,0 +1,156 @@
+//! [`jsonrpsee`] helper layer for resolving the `pinned` block tag.
+
+use crate::snapshot::DEFAULT_BLOCK_PARAMS;
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request};
+use reth_prune_types::{BlockPin, BlockPins};
+use serde_json::{json, value::RawValue, Value};
+use std::borrow::Cow;
+use tower::Layer;
+
+/// The block tag that resolves to the most recently pinned block.
+pub const PINNED_BLOCK_TAG: &str = "pinned";
+
+/// Layer that resolves the `"pinned"` and `"pinned:<id>"` block tags in the parameters of all RPC
+/// calls to the pinned block, see `reth_pinBlock`.
+///
+/// Block parameters that accept a block hash are resolved to the hash of the pinned block, so that
+/// the call reads the pinned block even if it was reorged out. All other tags are resolved to the
+/// number of the pinned block.
+///
+/// Tags that don't refer to an active pin are left as is and rejected by the called method.
+#[derive(Debug, Clone)]
+pub struct PinnedBlockLayer {
+    block_pins: BlockPins,
+}
+
+impl PinnedBlockLayer {
+    /// Creates a new layer that resolves the tags against the given pins.
+    pub const fn new(block_pins: BlockPins) -> Self {
+        Self { block_pins }
+    }
+}
+
+impl<S> Layer<S> for PinnedBlockLayer {
+    type Service = PinnedBlockService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PinnedBlockService { inner, block_pins: self.block_pins.clone() }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that resolves the `pinned` block tag.
+#[derive(Debug, Clone)]
+pub struct PinnedBlockService<S> {
+    /// The inner service being wrapped
+    inner: S,
+    /// All pinned blocks.
+    block_pins: BlockPins,
+}
+
+impl<S> PinnedBlockService<S> {
+    /// Returns the pin the given tag refers to, if it's active.
+    fn resolve(&self, tag: &str) -> Option<BlockPin> {
+        match tag.strip_prefix(PINNED_BLOCK_TAG)? {
+            "" => self.block_pins.latest(),
+            id => self.block_pins.get(id.strip_prefix(':')?.parse().ok()?),
+        }
+    }
+
+    /// Replaces all resolvable tags in the given value with the number of the pinned block,
+    /// returns `true` if any was replaced.
+    fn resolve_value(&self, value: &mut Value) -> bool {
+        match value {
+            Value::String(tag) => {
+                let Some(pin) = self.resolve(tag) else { return false };
+                *value = Value::String(format!("{:#x}", pin.number));
+                true
+            }
+            Value::Array(values) => values
+                .iter_mut()
+                .fold(false, |resolved, value| self.resolve_value(value) || resolved),
+            Value::Object(values) => values
+                .values_mut()
+                .fold(false, |resolved, value| self.resolve_value(value) || resolved),
+            _ => false,
+        }
+    }
+
+    /// Returns the parameters of the method with all resolvable tags replaced, if any.
+    fn resolve_params(&self, method: &str, params: &RawValue) -> Option<Box<RawValue>> {
+        // fast path for the vast majority of calls that don't use the tag
+        if !params.get().contains(PINNED_BLOCK_TAG) {
+            return None
+        }
+        let mut value = serde_json::from_str::<Value>(params.get()).ok()?;
+
+        // resolve the block parameter to the hash of the pinned block if it accepts one
+        let mut resolved = false;
+        let block_param = DEFAULT_BLOCK_PARAMS
+            .iter()
+            .find_map(|(name, position)| (*name == method).then_some(*position));
+        if let (Some(position), Value::Array(values)) = (block_param, &mut value) {
+            if let Some(param) = values.get_mut(position) {
+                if let Some(pin) = param.as_str().and_then(|tag| self.resolve(tag)) {
+                    *param = json!({ "blockHash": pin.hash });
+                    resolved = true;
+                }
+            }
+        }
+
+        if !self.resolve_value(&mut value) && !resolved {
+            return None
+        }
+        serde_json::value::to_raw_value(&value).ok()
+    }
+}
+
+impl<'a, S> RpcServiceT<'a> for PinnedBlockService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = S::Future;
+
+    fn call(&self, mut req: Request<'a>) -> Self::Future {
+        if let Some(params) =
+            req.params.as_deref().and_then(|params| self.resolve_params(req.method_name(), params))
+        {
+            req.params = Some(Cow::Owned(params));
+        }
+        self.inner.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use std::time::Duration;
+
+    #[test]
+    fn resolve_pinned_tag() {
+        let block_pins = BlockPins::default();
+        let service = PinnedBlockLayer::new(block_pins.clone()).layer(());
+        let params = RawValue::from_string(
+            r#"[{"fromBlock":"pinned","toBlock":"pinned:0"},"pinned:1","latest"]"#.to_string(),
+        )
+        .unwrap();
+
+        // no active pins
+        assert!(service.resolve_params("eth_getLogs", &params).is_none());
+
+        block_pins.pin(16, B256::ZERO, Duration::from_secs(60)).unwrap();
+        block_pins.pin(32, B256::with_last_byte(32), Duration::from_secs(60)).unwrap();
+        assert_eq!(
+            service.resolve_params("eth_getLogs", &params).unwrap().get(),
+            r#"[{"fromBlock":"0x20","toBlock":"0x10"},"0x20","latest"]"#
+        );
+
+        // block parameters that accept a hash are resolved to the hash of the pinned block
+        let address = r#""0x0000000000000000000000000000000000000001""#;
+        let params = RawValue::from_string(format!("[{address},\"pinned\"]")).unwrap();
+        assert_eq!(
+            service.resolve_params("eth_getBalance", &params).unwrap().get(),
+            format!("[{address},{{\"blockHash\":\"{}\"}}]", B256::with_last_byte(32))
+        );
+    }
+}
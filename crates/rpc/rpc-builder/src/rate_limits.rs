@@ -0,0 +1,443 @@
+//! Token bucket rate limiting of RPC calls per client and method.
+
+use crate::{
+    accounting::{AccountedRpcService, ApiKey, QUOTA_EXCEEDED_CODE},
+    error::RpcError,
+};
+use futures::future::Either;
+use http::{HeaderName, Request as HttpRequest};
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use parking_lot::{Mutex, RwLock};
+use schnellru::{ByLength, LruMap};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    future::{ready, Ready},
+    net::IpAddr,
+    path::{Path, PathBuf},
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, Instant, SystemTime},
+};
+use tower::{Layer, Service};
+use tracing::{info, warn};
+
+/// The [`AccountedRpcService`] with the optional rate limits applied, see
+/// `RpcServerConfig::start`.
+pub type RateLimitedRpcService =
+    tower::util::Either<RpcRateLimitService<AccountedRpcService>, AccountedRpcService>;
+
+/// The maximum number of buckets, the buckets of the least recently seen clients are dropped.
+const MAX_BUCKETS: u32 = 10_000;
+
+/// Configuration of the rate limits, read from a TOML file.
+///
+/// ```toml
+/// # applies to all calls of each client
+/// [default]
+/// rate = 50.0
+/// burst = 100
+///
+/// # applies to the calls of the method of each client, in addition to the default bucket
+/// [methods.eth_call]
+/// rate = 5.0
+/// burst = 10
+///
+/// # replaces the default bucket for the API key
+/// [api_keys.my-key]
+/// rate = 500.0
+/// burst = 1000
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitsConfig {
+    /// The bucket of each client that applies to all calls.
+    pub default: Option<TokenBucketConfig>,
+    /// The buckets of each client that apply to the calls of a method.
+    pub methods: HashMap<String, TokenBucketConfig>,
+    /// The buckets of API keys that replace the default bucket.
+    ///
+    /// API keys are only known if the accounting of API keys is enabled, otherwise these buckets
+    /// don't apply.
+    pub api_keys: HashMap<String, TokenBucketConfig>,
+}
+
+impl RateLimitsConfig {
+    /// Returns the bucket of the client for the given limited method, or its default bucket.
+    fn bucket(&self, client: &Client, method: Option<&str>) -> Option<&TokenBucketConfig> {
+        match (client, method) {
+            (_, Some(method)) => self.methods.get(method),
+            (Client::ApiKey(api_key), None) => self.api_keys.get(api_key).or(self.default.as_ref()),
+            (_, None) => self.default.as_ref(),
+        }
+    }
+
+    /// Reads the configuration from a TOML file.
+    pub fn load(path: &Path) -> Result<Self, RpcError> {
+        let invalid = |err: &dyn std::fmt::Display| {
+            RpcError::Custom(format!("invalid rate limits file {}: {err}", path.display()))
+        };
+        let contents = std::fs::read_to_string(path).map_err(|err| invalid(&err))?;
+        toml::from_str(&contents).map_err(|err| invalid(&err))
+    }
+}
+
+/// Configuration of a token bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TokenBucketConfig {
+    /// The number of calls per second the bucket is refilled with.
+    pub rate: f64,
+    /// The maximum number of calls that can be made at once.
+    pub burst: u32,
+}
+
+/// The client a call is rate limited for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Client {
+    /// The API key of the request, which was validated by the accounting.
+    ApiKey(String),
+    /// The IP address of the client, from the header of the trusted reverse proxy.
+    Ip(IpAddr),
+    /// All requests without a known API key or IP address share a bucket.
+    Unknown,
+}
+
+/// The IP address of the client of a request, inserted into the request extensions by
+/// [`RateLimitClientLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClientIp(pub IpAddr);
+
+#[derive(Debug, Clone, Copy)]
+struct TokenBucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(config: &TokenBucketConfig, now: Instant) -> Self {
+        Self { tokens: config.burst as f64, updated_at: now }
+    }
+
+    /// Refills the bucket and returns the time until a call can be made, if the bucket is empty.
+    fn refill(&mut self, config: &TokenBucketConfig, now: Instant) -> Option<Duration> {
+        let elapsed = now.saturating_duration_since(self.updated_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * config.rate).min(config.burst as f64);
+        self.updated_at = now;
+        (self.tokens < 1.0).then(|| {
+            if config.rate > 0.0 {
+                Duration::try_from_secs_f64((1.0 - self.tokens) / config.rate)
+                    .unwrap_or(Duration::MAX)
+            } else {
+                Duration::MAX
+            }
+        })
+    }
+}
+
+/// The error data of a rate limited call.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RateLimitRejection {
+    /// The number of seconds after which the call should be retried.
+    retry_after: u64,
+}
+
+/// Token bucket rate limiting of the RPC calls of each client.
+///
+/// Clients are identified by their API key if it was validated by the
+/// [`RpcAccounting`](crate::accounting::RpcAccounting), or by their IP address from the header of a
+/// trusted reverse proxy, if configured. All other calls share one bucket, so that clients can't
+/// get a fresh bucket by making up API keys or forwarding headers. Every call takes a token from
+/// the bucket of the client and from the bucket of the client for the method, if the method is
+/// limited. Calls are rejected with a retry-after if any of the buckets is empty.
+///
+/// This is a [`Layer`] for the RPC middleware and requires the [`RateLimitClientLayer`] in the
+/// HTTP middleware, see [`Self::client_layer`]. The buckets of the least recently seen clients
+/// are dropped once there are more than [`MAX_BUCKETS`].
+#[derive(Debug, Clone)]
+pub struct RpcRateLimits {
+    inner: Arc<RpcRateLimitsInner>,
+}
+
+#[derive(Debug)]
+struct RpcRateLimitsInner {
+    /// The header that carries the IP address of the client, set by a trusted reverse proxy.
+    client_ip_header: Option<HeaderName>,
+    config: RwLock<RateLimitsConfig>,
+    /// The buckets by client and limited method, `None` for the default bucket of the client.
+    buckets: Mutex<LruMap<(Client, Option<String>), TokenBucket, ByLength>>,
+}
+
+impl RpcRateLimits {
+    /// Creates new rate limits with the given configuration.
+    ///
+    /// The IP address of clients is only read from the given header, which must be set by a
+    /// trusted reverse proxy.
+    pub fn new(client_ip_header: Option<HeaderName>, config: RateLimitsConfig) -> Self {
+        Self {
+            inner: Arc::new(RpcRateLimitsInner {
+                client_ip_header,
+                config: RwLock::new(config),
+                buckets: Mutex::new(LruMap::new(ByLength::new(MAX_BUCKETS))),
+            }),
+        }
+    }
+
+    /// Returns the HTTP middleware layer that extracts the IP address of the client from the
+    /// request headers.
+    pub fn client_layer(&self) -> RateLimitClientLayer {
+        RateLimitClientLayer { client_ip_header: self.inner.client_ip_header.clone() }
+    }
+
+    /// Replaces the configuration, all buckets are refilled.
+    pub fn set_config(&self, config: RateLimitsConfig) {
+        *self.inner.config.write() = config;
+        self.inner.buckets.lock().clear();
+    }
+
+    /// Takes a token for the call of the given method from the buckets of the client.
+    ///
+    /// Returns the error to respond with if the call is rate limited.
+    pub fn check(
+        &self,
+        api_key: Option<&ApiKey>,
+        client_ip: Option<&ClientIp>,
+        method: &str,
+    ) -> Result<(), ErrorObject<'static>> {
+        let config = self.inner.config.read();
+        let client = match (api_key, client_ip) {
+            (Some(ApiKey(api_key)), _) => Client::ApiKey(api_key.clone()),
+            (None, Some(ClientIp(ip))) => Client::Ip(*ip),
+            (None, None) => Client::Unknown,
+        };
+        let limits = [
+            config.bucket(&client, None).map(|bucket| (None, bucket)),
+            config.bucket(&client, Some(method)).map(|bucket| (Some(method.to_string()), bucket)),
+        ];
+
+        let now = Instant::now();
+        let mut buckets = self.inner.buckets.lock();
+
+        let mut retry_after = None;
+        for (method, config) in limits.iter().flatten() {
+            let Some(bucket) = buckets
+                .get_or_insert((client.clone(), method.clone()), || TokenBucket::new(config, now))
+            else {
+                continue
+            };
+            if let Some(wait) = bucket.refill(config, now) {
+                retry_after = retry_after.max(Some(wait));
+            }
+        }
+
+        if let Some(retry_after) = retry_after {
+            let retry_after = retry_after.as_secs_f64().ceil().min(u64::MAX as f64) as u64;
+            return Err(ErrorObject::owned(
+                QUOTA_EXCEEDED_CODE,
+                "rate limit exceeded",
+                Some(RateLimitRejection { retry_after }),
+            ))
+        }
+
+        for (method, _) in limits.iter().flatten() {
+            if let Some(bucket) = buckets.get_mut(&(client.clone(), method.clone())) {
+                bucket.tokens -= 1.0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reloads the configuration from the file whenever it's modified, checking every interval.
+    ///
+    /// If the file can't be read, the current configuration is kept.
+    pub async fn reload_on_change(self, path: PathBuf, interval: Duration) {
+        let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+        let mut last_modified: Option<SystemTime> = modified(&path);
+        loop {
+            tokio::time::sleep(interval).await;
+
+            let current = modified(&path);
+            if current == last_modified {
+                continue
+            }
+            last_modified = current;
+
+            match RateLimitsConfig::load(&path) {
+                Ok(config) => {
+                    info!(target: "rpc::rate_limits", path = %path.display(), "Reloaded RPC rate limits");
+                    self.set_config(config);
+                }
+                Err(err) => {
+                    warn!(target: "rpc::rate_limits", path = %path.display(), %err, "Failed to reload RPC rate limits");
+                }
+            }
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcRateLimits {
+    type Service = RpcRateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcRateLimitService { inner, rate_limits: self.clone() }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that rejects calls of clients that exceed their rate limits.
+#[derive(Debug, Clone)]
+pub struct RpcRateLimitService<S> {
+    /// The inner service being wrapped
+    inner: S,
+    /// The rate limits of all clients.
+    rate_limits: RpcRateLimits,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcRateLimitService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = Either<S::Future, Ready<MethodResponse>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let extensions = req.extensions();
+        match self.rate_limits.check(
+            extensions.get::<ApiKey>(),
+            extensions.get::<ClientIp>(),
+            req.method_name(),
+        ) {
+            Ok(()) => Either::Left(self.inner.call(req)),
+            Err(err) => Either::Right(ready(MethodResponse::error(req.id, err))),
+        }
+    }
+}
+
+/// HTTP middleware layer that inserts the [`ClientIp`] from the header of the trusted reverse
+/// proxy into the request extensions, which are passed on to the RPC middleware.
+///
+/// The [`ApiKey`] is inserted by the [`ApiKeyLayer`](crate::accounting::ApiKeyLayer) of the
+/// accounting, once it was validated.
+#[derive(Debug, Clone)]
+pub struct RateLimitClientLayer {
+    client_ip_header: Option<HeaderName>,
+}
+
+impl<S> Layer<S> for RateLimitClientLayer {
+    type Service = RateLimitClientService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitClientService { inner, client_ip_header: self.client_ip_header.clone() }
+    }
+}
+
+/// The [`Service`] of [`RateLimitClientLayer`].
+#[derive(Debug, Clone)]
+pub struct RateLimitClientService<S> {
+    inner: S,
+    client_ip_header: Option<HeaderName>,
+}
+
+impl<S, B> Service<HttpRequest<B>> for RateLimitClientService<S>
+where
+    S: Service<HttpRequest<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: HttpRequest<B>) -> Self::Future {
+        // the left-most address of a forwarding header is the original client
+        let client_ip = self
+            .client_ip_header
+            .as_ref()
+            .and_then(|header| req.headers().get(header))
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.split(',').next())
+            .and_then(|ip| ip.trim().parse().ok())
+            .map(ClientIp);
+
+        if let Some(client_ip) = client_ip {
+            req.extensions_mut().insert(client_ip);
+        }
+        self.inner.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rate_limits(config: &str) -> RpcRateLimits {
+        RpcRateLimits::new(
+            Some(HeaderName::from_static("x-forwarded-for")),
+            toml::from_str(config).unwrap(),
+        )
+    }
+
+    #[test]
+    fn limit_clients_and_methods() {
+        let rate_limits = rate_limits(
+            r#"
+            [default]
+            rate = 0.5
+            burst = 3
+
+            [methods.eth_call]
+            rate = 0.5
+            burst = 1
+
+            [api_keys.tenant]
+            rate = 0.5
+            burst = 5
+            "#,
+        );
+        let ip = ClientIp("10.0.0.1".parse().unwrap());
+        let api_key = ApiKey("tenant".to_string());
+
+        rate_limits.check(None, Some(&ip), "eth_call").unwrap();
+        let err = rate_limits.check(None, Some(&ip), "eth_call").unwrap_err();
+        assert_eq!(err.code(), QUOTA_EXCEEDED_CODE);
+        assert_eq!(err.data().unwrap().get(), r#"{"retryAfter":2}"#);
+
+        // the rejected call didn't take a token of the default bucket
+        rate_limits.check(None, Some(&ip), "eth_blockNumber").unwrap();
+        rate_limits.check(None, Some(&ip), "eth_blockNumber").unwrap();
+        rate_limits.check(None, Some(&ip), "eth_blockNumber").unwrap_err();
+
+        // other clients have their own buckets
+        rate_limits.check(None, Some(&ClientIp("10.0.0.2".parse().unwrap())), "eth_call").unwrap();
+        rate_limits.check(None, None, "eth_call").unwrap();
+        for _ in 0..5 {
+            rate_limits.check(Some(&api_key), Some(&ip), "eth_blockNumber").unwrap();
+        }
+        rate_limits.check(Some(&api_key), Some(&ip), "eth_blockNumber").unwrap_err();
+
+        rate_limits.set_config(RateLimitsConfig::default());
+        rate_limits.check(None, Some(&ip), "eth_call").unwrap();
+    }
+
+    #[test]
+    fn bounded_buckets() {
+        let rate_limits = rate_limits(
+            r#"
+            [default]
+            rate = 1.0
+            burst = 1
+            "#,
+        );
+
+        for i in 0..=MAX_BUCKETS {
+            let ip = ClientIp(IpAddr::from(i.to_be_bytes()));
+            rate_limits.check(None, Some(&ip), "eth_blockNumber").unwrap();
+        }
+        assert_eq!(rate_limits.inner.buckets.lock().len(), MAX_BUCKETS as usize);
+    }
+}
@@ -0,0 +1,211 @@
+//! Per method limits of the size of RPC responses.
+
+use crate::rate_limits::RateLimitedRpcService;
+use futures::future::{BoxFuture, FutureExt};
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{error::OVERSIZED_RESPONSE_CODE, ErrorObject, Request},
+    MethodCallback, MethodResponse, RpcModule,
+};
+use serde::Serialize;
+use std::{collections::HashMap, sync::Arc};
+use tower::Layer;
+
+/// The message of the error that replaces responses that exceed their size limit.
+pub const RESPONSE_TOO_LARGE_MSG: &str = "result too large, narrow your query";
+
+/// The [`RateLimitedRpcService`] with the optional response size limits applied, see
+/// `RpcServerConfig::start`.
+pub type ResponseLimitedRpcService =
+    tower::util::Either<ResponseSizeLimitService<RateLimitedRpcService>, RateLimitedRpcService>;
+
+/// Limits of the size of the responses of individual methods, e.g. `eth_getLogs` or
+/// `trace_block`.
+///
+/// The limits are enforced while the results are serialized, see [`Self::limit_methods`], so an
+/// oversized result is never serialized in full. Responses that exceed the limit of their method
+/// or the maximum response size of the server are replaced with an error that asks the client to
+/// narrow the query, instead of the generic oversized response error.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseSizeLimits {
+    /// The maximum size of the serialized result in bytes, by method.
+    limits: Arc<HashMap<String, usize>>,
+}
+
+impl ResponseSizeLimits {
+    /// Creates new limits from the maximum response size in bytes by method.
+    pub fn new(limits: HashMap<String, usize>) -> Self {
+        Self { limits: Arc::new(limits) }
+    }
+
+    /// Returns the limit of the given method in bytes, if any.
+    pub fn limit(&self, method: &str) -> Option<usize> {
+        self.limits.get(method).copied()
+    }
+
+    /// Lowers the maximum response size of the limited methods of the module to their limit.
+    ///
+    /// The method handlers stop serializing the result once it exceeds the maximum response size,
+    /// and respond with an oversized response error instead.
+    pub fn limit_methods(&self, mut module: RpcModule<()>) -> RpcModule<()> {
+        for (method, &limit) in self.limits.iter() {
+            let Some(name) = module.method_with_name(method).map(|(name, _)| name) else {
+                continue
+            };
+            let callback = match module.remove_method(name) {
+                Some(MethodCallback::Sync(callback)) => MethodCallback::Sync(Arc::new(
+                    move |id, params, max_response_size, extensions| {
+                        callback(id, params, max_response_size.min(limit), extensions)
+                    },
+                )),
+                Some(MethodCallback::Async(callback)) => MethodCallback::Async(Arc::new(
+                    move |id, params, connection_id, max_response_size, extensions| {
+                        callback(
+                            id,
+                            params,
+                            connection_id,
+                            max_response_size.min(limit),
+                            extensions,
+                        )
+                    },
+                )),
+                // subscriptions are not limited
+                Some(callback) => callback,
+                None => continue,
+            };
+            module.verify_and_insert(name, callback).expect("method was removed");
+        }
+        module
+    }
+
+    /// Returns the error that replaces the response if it exceeds the limit of the method.
+    fn check(&self, method: &str, response: &MethodResponse) -> Option<ErrorObject<'static>> {
+        if response.is_subscription() {
+            return None
+        }
+        let limit = self.limit(method);
+        if response.as_error_code() == Some(OVERSIZED_RESPONSE_CODE) {
+            return Some(response_too_large(method, limit, None))
+        }
+        // a handler that serializes its response itself may exceed the limit
+        let limit = limit?;
+        let size = response.as_result().len();
+        (response.is_success() && size > limit)
+            .then(|| response_too_large(method, Some(limit), Some(size)))
+    }
+}
+
+impl<S> Layer<S> for ResponseSizeLimits {
+    type Service = ResponseSizeLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ResponseSizeLimitService { inner, limits: self.clone() }
+    }
+}
+
+/// The data of the error that replaces responses that exceed their size limit.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResponseTooLarge<'a> {
+    method: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<usize>,
+}
+
+/// Returns the error for a response of the method that exceeds its size limit.
+fn response_too_large(
+    method: &str,
+    limit: Option<usize>,
+    size: Option<usize>,
+) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        OVERSIZED_RESPONSE_CODE,
+        RESPONSE_TOO_LARGE_MSG,
+        Some(ResponseTooLarge { method, limit, size }),
+    )
+}
+
+/// A [`RpcServiceT`] middleware that replaces responses that exceed the size limit of their
+/// method.
+#[derive(Debug, Clone)]
+pub struct ResponseSizeLimitService<S> {
+    /// The inner service being wrapped
+    inner: S,
+    /// The response size limits by method.
+    limits: ResponseSizeLimits,
+}
+
+impl<'a, S> RpcServiceT<'a> for ResponseSizeLimitService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = BoxFuture<'a, MethodResponse>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let id = req.id.clone();
+        let method = req.method_name().to_string();
+        let limits = self.limits.clone();
+        self.inner
+            .call(req)
+            .map(move |response| match limits.check(&method, &response) {
+                Some(err) => MethodResponse::error(id, err),
+                None => response,
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::types::{Id, Params, ResponsePayload};
+
+    fn response(value: &str) -> MethodResponse {
+        MethodResponse::response(Id::Number(1), ResponsePayload::success(value), usize::MAX)
+    }
+
+    #[test]
+    fn replace_oversized_responses() {
+        let limits = ResponseSizeLimits::new(HashMap::from([("eth_getLogs".to_string(), 10)]));
+
+        assert!(limits.check("eth_getLogs", &response("small")).is_none());
+        assert!(limits.check("eth_call", &response("not limited by method")).is_none());
+
+        let err = limits.check("eth_getLogs", &response("exceeds the limit")).unwrap();
+        assert_eq!(err.code(), OVERSIZED_RESPONSE_CODE);
+        assert_eq!(err.message(), RESPONSE_TOO_LARGE_MSG);
+        assert_eq!(err.data().unwrap().get(), r#"{"method":"eth_getLogs","limit":10,"size":19}"#);
+
+        // responses that exceed the maximum response size of the server
+        let oversized =
+            MethodResponse::response(Id::Number(1), ResponsePayload::success("result"), 1);
+        let err = limits.check("trace_block", &oversized).unwrap();
+        assert_eq!(err.data().unwrap().get(), r#"{"method":"trace_block"}"#);
+    }
+
+    #[test]
+    fn limit_serialized_results() {
+        let limits = ResponseSizeLimits::new(HashMap::from([("eth_getLogs".to_string(), 10)]));
+        let mut module = RpcModule::new(());
+        module.register_method("eth_getLogs", |_, _, _| "exceeds the limit").unwrap();
+        module.register_method("eth_call", |_, _, _| "not limited by method").unwrap();
+        let module = limits.limit_methods(module);
+
+        let call = |method| {
+            let Some(MethodCallback::Sync(callback)) = module.method(method) else {
+                panic!("expected a sync method")
+            };
+            callback(Id::Number(1), Params::new(None), usize::MAX, Default::default())
+        };
+
+        // the result is not serialized beyond the limit
+        let response = call("eth_getLogs");
+        assert_eq!(response.as_error_code(), Some(OVERSIZED_RESPONSE_CODE));
+        let err = limits.check("eth_getLogs", &response).unwrap();
+        assert_eq!(err.data().unwrap().get(), r#"{"method":"eth_getLogs","limit":10}"#);
+
+        assert!(call("eth_call").is_success());
+    }
+}
@@ -0,0 +1,317 @@
+//! [`jsonrpsee`] helper layers for reading all calls of a batch from the same `latest` block.
+
+use alloy_eips::BlockNumHash;
+use http::{header::UPGRADE, Request as HttpRequest};
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request};
+use reth_provider::BlockNumReader;
+use serde_json::{value::RawValue, Value};
+use std::{
+    borrow::Cow,
+    sync::{Arc, OnceLock},
+    task::{Context, Poll},
+};
+use tower::{Layer, Service};
+
+/// The block tag that is resolved to the snapshot of the request.
+const LATEST_BLOCK_TAG: &str = "latest";
+
+/// The position of the optional block parameter of methods that default to the `latest` block.
+///
+/// These parameters accept a block hash as well, see EIP-1898, so they are pinned to the hash of
+/// the snapshot block.
+pub(crate) const DEFAULT_BLOCK_PARAMS: &[(&str, usize)] = &[
+    ("eth_getBalance", 1),
+    ("eth_getCode", 1),
+    ("eth_getTransactionCount", 1),
+    ("eth_getStorageAt", 2),
+    ("eth_getProof", 2),
+    ("eth_call", 1),
+    ("eth_estimateGas", 1),
+    ("eth_createAccessList", 1),
+];
+
+/// The block parameters of methods that are pinned to the number of the snapshot block if they're
+/// `"latest"`.
+///
+/// Methods that resolve the `latest` block again later, e.g. `eth_newFilter`, must not be listed.
+const BLOCK_TAG_PARAMS: &[(&str, BlockParam)] = &[
+    ("eth_getBlockByNumber", BlockParam::Position(0)),
+    ("eth_getBlockTransactionCountByNumber", BlockParam::Position(0)),
+    ("eth_getUncleCountByBlockNumber", BlockParam::Position(0)),
+    ("eth_getUncleByBlockNumberAndIndex", BlockParam::Position(0)),
+    ("eth_getTransactionByBlockNumberAndIndex", BlockParam::Position(0)),
+    ("eth_getRawTransactionByBlockNumberAndIndex", BlockParam::Position(0)),
+    ("eth_getBlockReceipts", BlockParam::Position(0)),
+    ("eth_getHeaderByNumber", BlockParam::Position(0)),
+    ("eth_feeHistory", BlockParam::Position(1)),
+    ("eth_simulateV1", BlockParam::Position(1)),
+    ("eth_getLogs", BlockParam::Field(0, "fromBlock")),
+    ("eth_getLogs", BlockParam::Field(0, "toBlock")),
+    ("debug_traceBlockByNumber", BlockParam::Position(0)),
+    ("debug_traceCall", BlockParam::Position(1)),
+    ("trace_block", BlockParam::Position(0)),
+    ("trace_call", BlockParam::Position(2)),
+    ("trace_replayBlockTransactions", BlockParam::Position(0)),
+];
+
+/// A block parameter of a method.
+#[derive(Debug, Clone, Copy)]
+enum BlockParam {
+    /// The parameter at the given position.
+    Position(usize),
+    /// The field of the object parameter at the given position.
+    Field(usize, &'static str),
+}
+
+impl BlockParam {
+    /// Returns the parameter in the given parameters, if present.
+    fn get_mut(self, params: &mut [Value]) -> Option<&mut Value> {
+        match self {
+            Self::Position(position) => params.get_mut(position),
+            Self::Field(position, field) => params.get_mut(position)?.get_mut(field),
+        }
+    }
+}
+
+/// The `latest` block of an HTTP request, shared by all calls of the request.
+///
+/// It's resolved by the first call that reads the `latest` block and inserted into the request
+/// extensions by [`BatchSnapshotHttpLayer`].
+#[derive(Debug, Clone, Default)]
+pub struct BatchSnapshot(Arc<OnceLock<Option<BlockNumHash>>>);
+
+impl BatchSnapshot {
+    /// Returns the snapshot block, resolving it with the given function if this is the first call.
+    pub(crate) fn get_or_resolve(
+        &self,
+        resolve: impl FnOnce() -> Option<BlockNumHash>,
+    ) -> Option<BlockNumHash> {
+        *self.0.get_or_init(resolve)
+    }
+}
+
+/// Layer that pins the `latest` block of all calls of an HTTP request, including all calls of a
+/// batch, to the same block.
+///
+/// The block parameter of common state methods is set to the hash of the snapshot block if it's
+/// omitted or `"latest"`, the `"latest"` block tags of other known block parameters are replaced
+/// with the number of the snapshot block. This way all calls read the same state even if a new
+/// block is committed while the batch is served, and the state methods even if the snapshot block
+/// is reorged.
+///
+/// This is a [`Layer`] for the RPC middleware and requires the [`BatchSnapshotHttpLayer`] in the
+/// HTTP middleware, see `RpcServerConfig::with_batch_snapshots`.
+#[derive(Debug, Clone)]
+pub struct BatchSnapshotLayer<Provider> {
+    provider: Provider,
+}
+
+impl<Provider> BatchSnapshotLayer<Provider> {
+    /// Creates a new layer that resolves the `latest` block with the given provider.
+    pub const fn new(provider: Provider) -> Self {
+        Self { provider }
+    }
+}
+
+impl<S, Provider: Clone> Layer<S> for BatchSnapshotLayer<Provider> {
+    type Service = BatchSnapshotService<S, Provider>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BatchSnapshotService { inner, provider: self.provider.clone() }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that pins the `latest` block of a call to the snapshot of its
+/// request.
+#[derive(Debug, Clone)]
+pub struct BatchSnapshotService<S, Provider> {
+    /// The inner service being wrapped
+    inner: S,
+    /// Resolves the `latest` block.
+    provider: Provider,
+}
+
+/// Returns the parameters of the method with the `latest` block pinned to the resolved block, if
+/// the call reads the `latest` block.
+fn pin_params(
+    method: &str,
+    params: Option<&RawValue>,
+    resolve: impl FnOnce() -> Option<BlockNumHash>,
+) -> Option<Box<RawValue>> {
+    let default_block = DEFAULT_BLOCK_PARAMS
+        .iter()
+        .find_map(|(name, position)| (*name == method).then_some(*position));
+    let has_block_tags = BLOCK_TAG_PARAMS.iter().any(|(name, _)| *name == method);
+    let params = params.map(|params| params.get()).unwrap_or("[]");
+
+    // fast path for the vast majority of calls that don't read the latest block
+    if default_block.is_none() && !(has_block_tags && params.contains(LATEST_BLOCK_TAG)) {
+        return None
+    }
+    let mut value = serde_json::from_str::<Value>(params).ok()?;
+    let Value::Array(values) = &mut value else { return None };
+
+    if let Some(position) = default_block {
+        let latest_block = values.get(position).is_none_or(|value| {
+            value.is_null() || value.as_str().is_some_and(|tag| tag == LATEST_BLOCK_TAG)
+        });
+        if !latest_block {
+            return None
+        }
+        let block = resolve()?;
+        values.resize(values.len().max(position + 1), Value::Null);
+        values[position] = serde_json::json!({ "blockHash": block.hash });
+    } else {
+        let latest_tags = BLOCK_TAG_PARAMS
+            .iter()
+            .filter(|(name, _)| *name == method)
+            .map(|(_, param)| *param)
+            .filter(|param| {
+                param.get_mut(values).is_some_and(|tag| tag.as_str() == Some(LATEST_BLOCK_TAG))
+            })
+            .collect::<Vec<_>>();
+        if latest_tags.is_empty() {
+            return None
+        }
+        let number = format!("{:#x}", resolve()?.number);
+        for param in latest_tags {
+            if let Some(tag) = param.get_mut(values) {
+                *tag = Value::String(number.clone());
+            }
+        }
+    }
+    serde_json::value::to_raw_value(&value).ok()
+}
+
+impl<'a, S, Provider> RpcServiceT<'a> for BatchSnapshotService<S, Provider>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+    Provider: BlockNumReader + Clone + 'static,
+{
+    type Future = S::Future;
+
+    fn call(&self, mut req: Request<'a>) -> Self::Future {
+        if let Some(snapshot) = req.extensions().get::<BatchSnapshot>().cloned() {
+            let resolve = || {
+                snapshot.get_or_resolve(|| {
+                    let info = self.provider.chain_info().ok()?;
+                    Some(BlockNumHash::new(info.best_number, info.best_hash))
+                })
+            };
+            if let Some(params) = pin_params(req.method_name(), req.params.as_deref(), resolve) {
+                req.params = Some(Cow::Owned(params));
+            }
+        }
+        self.inner.call(req)
+    }
+}
+
+/// HTTP middleware layer that inserts a new [`BatchSnapshot`] into the extensions of each HTTP
+/// request.
+///
+/// Websocket upgrade requests are skipped, so that the calls of a websocket connection aren't
+/// pinned to the same block.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BatchSnapshotHttpLayer;
+
+impl<S> Layer<S> for BatchSnapshotHttpLayer {
+    type Service = BatchSnapshotHttpService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        BatchSnapshotHttpService { inner }
+    }
+}
+
+/// The [`Service`] of [`BatchSnapshotHttpLayer`].
+#[derive(Debug, Clone)]
+pub struct BatchSnapshotHttpService<S> {
+    inner: S,
+}
+
+impl<S, B> Service<HttpRequest<B>> for BatchSnapshotHttpService<S>
+where
+    S: Service<HttpRequest<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: HttpRequest<B>) -> Self::Future {
+        if !req.headers().contains_key(UPGRADE) {
+            req.extensions_mut().insert(BatchSnapshot::default());
+        }
+        self.inner.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "0x0000000000000000000000000000000000000000000000000000000000000001";
+
+    fn pin(method: &str, params: &str) -> Option<String> {
+        let params = RawValue::from_string(params.to_string()).unwrap();
+        let block = BlockNumHash::new(0x10, HASH.parse().unwrap());
+        pin_params(method, Some(&params), || Some(block)).map(|params| params.get().to_string())
+    }
+
+    #[test]
+    fn pin_latest_block() {
+        assert_eq!(pin("eth_getBlockByNumber", r#"["0x1",false]"#), None);
+        assert_eq!(
+            pin("eth_getBlockByNumber", r#"["latest",false]"#).unwrap(),
+            r#"["0x10",false]"#
+        );
+        assert_eq!(
+            pin("eth_getLogs", r#"[{"fromBlock":"0x1","toBlock":"latest"}]"#).unwrap(),
+            r#"[{"fromBlock":"0x1","toBlock":"0x10"}]"#
+        );
+        assert_eq!(pin("eth_newFilter", r#"[{"toBlock":"latest"}]"#), None);
+
+        // only known block parameters are pinned
+        assert_eq!(
+            pin("eth_getLogs", r#"[{"toBlock":"latest","topics":["latest"]}]"#).unwrap(),
+            r#"[{"toBlock":"0x10","topics":["latest"]}]"#
+        );
+        assert_eq!(pin("eth_sendRawTransaction", r#"["latest"]"#), None);
+        assert_eq!(
+            pin("eth_feeHistory", r#"["latest","latest",[]]"#).unwrap(),
+            r#"["latest","0x10",[]]"#
+        );
+
+        // omitted and latest block parameters are pinned to the block hash
+        let address = r#""0x0000000000000000000000000000000000000001""#;
+        let block_hash = format!("{{\"blockHash\":\"{HASH}\"}}");
+        assert_eq!(pin("eth_getBalance", &format!("[{address},\"0x1\"]")), None);
+        assert_eq!(pin("eth_getBalance", &format!("[{address},\"safe\"]")), None);
+        assert_eq!(
+            pin("eth_getBalance", &format!("[{address}]")).unwrap(),
+            format!("[{address},{block_hash}]")
+        );
+        assert_eq!(
+            pin("eth_getBalance", &format!("[{address},\"latest\"]")).unwrap(),
+            format!("[{address},{block_hash}]")
+        );
+        assert_eq!(
+            pin("eth_call", &format!("[{{\"to\":{address}}},null,{{}}]")).unwrap(),
+            format!("[{{\"to\":{address}}},{block_hash},{{}}]")
+        );
+    }
+
+    #[test]
+    fn resolve_snapshot_once() {
+        let snapshot = BatchSnapshot::default();
+        let shared = snapshot.clone();
+        let block = BlockNumHash::new(1, Default::default());
+        assert_eq!(snapshot.get_or_resolve(|| Some(block)), Some(block));
+        assert_eq!(
+            shared.get_or_resolve(|| Some(BlockNumHash::new(2, Default::default()))),
+            Some(block)
+        );
+    }
+}
@@ -0,0 +1,480 @@
+//! [`jsonrpsee`] helper layer for scheduling `trace` and `debug` calls by their estimated cost.
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_primitives::B256;
+use futures::future::{BoxFuture, Either};
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use reth_provider::{BlockReaderIdExt, TransactionsProvider};
+use reth_rpc_server_types::constants::{DEFAULT_TRACE_HEAVY_CONCURRENCY, DEFAULT_TRACE_MAX_QUEUED};
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+use tokio::sync::Semaphore;
+use tower::Layer;
+
+/// Error code for calls that are rejected because the queue of their cost class is full, see
+/// EIP-1474.
+pub const TRACE_QUEUE_FULL_CODE: i32 = -32005;
+
+/// The cost class of a tracing call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CostClass {
+    /// Calls that execute little gas, e.g. tracing a transaction early in a block.
+    Light,
+    /// Calls of unknown or moderate cost, e.g. `trace_call`.
+    Medium,
+    /// Calls that execute a lot of gas, e.g. `trace_block` of a full block or `trace_filter`.
+    Heavy,
+}
+
+/// The estimated cost of a tracing call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCost {
+    /// The cost class the call is scheduled in.
+    pub class: CostClass,
+    /// The estimated gas that is executed, if known.
+    pub gas: Option<u64>,
+    /// The estimated number of transactions that are executed, if known.
+    pub transactions: Option<u64>,
+}
+
+/// The error data of a rejected call.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceRejection {
+    /// The estimated cost of the call.
+    cost: TraceCost,
+    /// The number of seconds after which the call should be retried.
+    retry_after: u64,
+}
+
+/// Configuration for the [`TraceSchedulerLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceSchedulerConfig {
+    /// Calls that execute at most this much gas are light.
+    pub light_max_gas: u64,
+    /// Calls that execute more than this much gas are heavy.
+    pub medium_max_gas: u64,
+    /// The maximum number of concurrent light calls.
+    pub light_concurrency: usize,
+    /// The maximum number of concurrent medium calls.
+    pub medium_concurrency: usize,
+    /// The maximum number of concurrent heavy calls.
+    pub heavy_concurrency: usize,
+    /// The maximum number of calls per cost class that wait for execution, further calls are
+    /// rejected.
+    pub max_queued: usize,
+}
+
+impl TraceSchedulerConfig {
+    /// Creates a new config that runs at most `max_tracing_requests` light calls concurrently.
+    ///
+    /// Half of them can run medium calls and [`DEFAULT_TRACE_HEAVY_CONCURRENCY`] heavy calls.
+    pub fn new(max_tracing_requests: usize) -> Self {
+        Self {
+            light_max_gas: 5_000_000,
+            medium_max_gas: 20_000_000,
+            light_concurrency: max_tracing_requests.max(1),
+            medium_concurrency: (max_tracing_requests / 2).max(1),
+            heavy_concurrency: DEFAULT_TRACE_HEAVY_CONCURRENCY,
+            max_queued: DEFAULT_TRACE_MAX_QUEUED,
+        }
+    }
+
+    /// Sets the maximum number of concurrent heavy calls.
+    pub const fn with_heavy_concurrency(mut self, heavy_concurrency: usize) -> Self {
+        self.heavy_concurrency = heavy_concurrency;
+        self
+    }
+
+    /// Sets the maximum number of waiting calls per cost class.
+    pub const fn with_max_queued(mut self, max_queued: usize) -> Self {
+        self.max_queued = max_queued;
+        self
+    }
+
+    /// Returns the cost class for the given amount of gas.
+    const fn class(&self, gas: u64) -> CostClass {
+        if gas <= self.light_max_gas {
+            CostClass::Light
+        } else if gas <= self.medium_max_gas {
+            CostClass::Medium
+        } else {
+            CostClass::Heavy
+        }
+    }
+}
+
+/// Layer that queues `trace` and `debug` calls by their estimated cost, so that a few expensive
+/// calls, e.g. `trace_block` of full blocks, can't starve all other tracing calls.
+///
+/// The cost is estimated with the gas used and the number of transactions of the traced block.
+/// Each cost class has its own concurrency limit and queue, calls are rejected with their cost
+/// estimate and a retry-after if the queue of their class is full.
+#[derive(Debug, Clone)]
+pub struct TraceSchedulerLayer<Provider> {
+    inner: Arc<TraceScheduler<Provider>>,
+}
+
+impl<Provider> TraceSchedulerLayer<Provider> {
+    /// Creates a new layer that estimates the costs with the given provider.
+    pub fn new(provider: Provider, config: TraceSchedulerConfig) -> Self {
+        let inner = TraceScheduler {
+            provider,
+            config,
+            estimations: Arc::new(Semaphore::new(config.max_queued.max(1))),
+            light: CostQueue::new(config.light_concurrency),
+            medium: CostQueue::new(config.medium_concurrency),
+            heavy: CostQueue::new(config.heavy_concurrency),
+        };
+        Self { inner: Arc::new(inner) }
+    }
+}
+
+impl<S, Provider> Layer<S> for TraceSchedulerLayer<Provider> {
+    type Service = TraceSchedulerService<S, Provider>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceSchedulerService { inner, scheduler: self.inner.clone() }
+    }
+}
+
+#[derive(Debug)]
+struct TraceScheduler<Provider> {
+    provider: Provider,
+    config: TraceSchedulerConfig,
+    /// Permits for concurrent cost estimations, calls that can't be estimated are medium.
+    estimations: Arc<Semaphore>,
+    light: CostQueue,
+    medium: CostQueue,
+    heavy: CostQueue,
+}
+
+impl<Provider> TraceScheduler<Provider>
+where
+    Provider: BlockReaderIdExt + TransactionsProvider,
+{
+    /// Estimates the cost of the given call.
+    ///
+    /// Calls that can't be estimated, e.g. because the block is pending or doesn't exist, are
+    /// medium.
+    fn estimate(&self, call: &TraceCall) -> TraceCost {
+        let (gas, transactions) = match call {
+            TraceCall::Block(block_id) => self.block_cost(*block_id).unzip(),
+            TraceCall::Transaction(hash) => self.transaction_cost(*hash).unzip(),
+            TraceCall::Calls { gas, calls } => {
+                let class = gas.map_or(CostClass::Medium, |gas| self.config.class(gas));
+                return TraceCost { class, gas: *gas, transactions: Some(*calls) }
+            }
+            TraceCall::Range { from, to } => {
+                let transactions = self.range_transactions(*from, *to);
+                return TraceCost { class: CostClass::Heavy, gas: None, transactions }
+            }
+        };
+        let class = gas.map_or(CostClass::Medium, |gas| self.config.class(gas));
+        TraceCost { class, gas, transactions }
+    }
+
+    /// Returns the gas used and the number of transactions of the block.
+    ///
+    /// The pending block isn't estimated, since its transactions aren't known yet.
+    fn block_cost(&self, block_id: BlockId) -> Option<(u64, u64)> {
+        if block_id.is_pending() {
+            return None
+        }
+        let header = self.provider.header_by_id(block_id).ok()??;
+        let indices = self.provider.block_body_indices(header.number()).ok()??;
+        Some((header.gas_used(), indices.tx_count))
+    }
+
+    /// Returns the estimated gas and number of transactions that are executed to trace the
+    /// transaction, which includes all transactions before it in the block.
+    fn transaction_cost(&self, hash: B256) -> Option<(u64, u64)> {
+        let (_, meta) = self.provider.transaction_by_hash_with_meta(hash).ok()??;
+        let (gas, transactions) = self.block_cost(meta.block_number.into())?;
+        let executed = meta.index + 1;
+        Some((gas.saturating_mul(executed) / transactions.max(1), executed))
+    }
+
+    /// Returns the number of transactions in the given range of blocks.
+    fn range_transactions(&self, from: Option<u64>, to: Option<u64>) -> Option<u64> {
+        let to = match to {
+            Some(to) => to,
+            None => self.provider.header_by_id(BlockId::latest()).ok()??.number(),
+        };
+        let first = self.provider.block_body_indices(from.unwrap_or(to)).ok()??;
+        let last = self.provider.block_body_indices(to).ok()??;
+        Some(last.next_tx_num().saturating_sub(first.first_tx_num))
+    }
+
+    /// Returns the queue of the cost class.
+    const fn queue(&self, class: CostClass) -> &CostQueue {
+        match class {
+            CostClass::Light => &self.light,
+            CostClass::Medium => &self.medium,
+            CostClass::Heavy => &self.heavy,
+        }
+    }
+}
+
+/// The queue of a cost class.
+#[derive(Debug)]
+struct CostQueue {
+    /// Permits for concurrent calls, waiting calls are served in order.
+    permits: Arc<Semaphore>,
+    /// The maximum number of concurrent calls.
+    concurrency: usize,
+    /// The number of calls that wait for a permit.
+    queued: AtomicUsize,
+    /// The moving average of the call durations in milliseconds.
+    avg_duration_ms: AtomicU64,
+}
+
+impl CostQueue {
+    fn new(concurrency: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(concurrency)),
+            concurrency,
+            queued: AtomicUsize::new(0),
+            avg_duration_ms: AtomicU64::new(0),
+        }
+    }
+
+    /// Reserves a place in the queue, returns `None` if `max_queued` calls are already waiting.
+    fn try_enqueue(&self, max_queued: usize) -> Option<QueuedGuard<'_>> {
+        self.queued
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |queued| {
+                (queued < max_queued).then_some(queued + 1)
+            })
+            .ok()?;
+        Some(QueuedGuard(&self.queued))
+    }
+
+    /// Returns the estimated number of seconds until a newly queued call is executed.
+    fn retry_after(&self) -> u64 {
+        let queued = self.queued.load(Ordering::Relaxed) as u64 + 1;
+        let wait_ms =
+            self.avg_duration_ms.load(Ordering::Relaxed) * queued / self.concurrency.max(1) as u64;
+        wait_ms.div_ceil(1000).max(1)
+    }
+
+    /// Records the duration of a finished call.
+    fn record(&self, duration: Duration) {
+        let sample = duration.as_millis() as u64;
+        let avg = self.avg_duration_ms.load(Ordering::Relaxed);
+        let avg = if avg == 0 { sample } else { (avg * 7 + sample) / 8 };
+        self.avg_duration_ms.store(avg, Ordering::Relaxed);
+    }
+}
+
+/// Decrements the number of queued calls when dropped.
+struct QueuedGuard<'a>(&'a AtomicUsize);
+
+impl Drop for QueuedGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// What a scheduled call executes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TraceCall {
+    /// All transactions of a block.
+    Block(BlockId),
+    /// A transaction and all transactions before it in its block.
+    Transaction(B256),
+    /// Calls with the given total gas limit, if set.
+    Calls { gas: Option<u64>, calls: u64 },
+    /// All transactions of a range of blocks.
+    Range { from: Option<u64>, to: Option<u64> },
+}
+
+impl TraceCall {
+    /// Returns what the given call executes, or `None` if the method isn't scheduled.
+    fn new(method: &str, params: Option<&str>) -> Option<Self> {
+        let params = params
+            .and_then(|params| serde_json::from_str::<Vec<Value>>(params).ok())
+            .unwrap_or_default();
+        let first = params.into_iter().next().unwrap_or_default();
+        let call = match method {
+            "trace_block" |
+            "trace_replayBlockTransactions" |
+            "trace_blockOpcodeGas" |
+            "debug_traceBlockByNumber" |
+            "debug_traceBlockByHash" |
+            "debug_executionWitness" => Self::Block(serde_json::from_value(first).ok()?),
+            "trace_transaction" |
+            "trace_replayTransaction" |
+            "trace_get" |
+            "trace_transactionOpcodeGas" |
+            "debug_traceTransaction" => Self::Transaction(serde_json::from_value(first).ok()?),
+            "trace_call" | "debug_traceCall" => Self::Calls { gas: call_gas(&first), calls: 1 },
+            "trace_callMany" => {
+                let calls = first.as_array().map(Vec::as_slice).unwrap_or_default();
+                let gas =
+                    calls.iter().map(|call| call.get(0).and_then(call_gas)).sum::<Option<u64>>();
+                Self::Calls { gas, calls: calls.len() as u64 }
+            }
+            "trace_rawTransaction" | "debug_traceCallMany" => Self::Calls { gas: None, calls: 1 },
+            "trace_filter" => Self::Range {
+                from: first.get("fromBlock").and_then(quantity),
+                to: first.get("toBlock").and_then(quantity),
+            },
+            _ => return None,
+        };
+        Some(call)
+    }
+}
+
+/// Returns the gas limit of a call request.
+fn call_gas(request: &Value) -> Option<u64> {
+    request.get("gas").and_then(quantity)
+}
+
+/// Parses a hex quantity.
+fn quantity(value: &Value) -> Option<u64> {
+    u64::from_str_radix(value.as_str()?.strip_prefix("0x")?, 16).ok()
+}
+
+/// A [`RpcServiceT`] middleware that schedules tracing calls by their estimated cost.
+#[derive(Debug, Clone)]
+pub struct TraceSchedulerService<S, Provider> {
+    /// The inner service being wrapped
+    inner: S,
+    /// The scheduler shared by all connections.
+    scheduler: Arc<TraceScheduler<Provider>>,
+}
+
+impl<'a, S, Provider> RpcServiceT<'a> for TraceSchedulerService<S, Provider>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+    Provider: BlockReaderIdExt + TransactionsProvider + Clone + 'static,
+{
+    type Future = Either<S::Future, BoxFuture<'a, MethodResponse>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let Some(call) = TraceCall::new(req.method_name(), req.params.as_deref().map(|p| p.get()))
+        else {
+            return Either::Left(self.inner.call(req))
+        };
+
+        let inner = self.inner.clone();
+        let scheduler = self.scheduler.clone();
+        Either::Right(Box::pin(async move {
+            let unknown = TraceCost { class: CostClass::Medium, gas: None, transactions: None };
+            // the estimation reads from the database, so only a bounded number of calls is
+            // estimated concurrently and all others are scheduled as calls of unknown cost
+            let cost = match scheduler.estimations.clone().try_acquire_owned() {
+                Ok(permit) => {
+                    let estimate_scheduler = scheduler.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let _permit = permit;
+                        estimate_scheduler.estimate(&call)
+                    })
+                    .await
+                    .unwrap_or(unknown)
+                }
+                Err(_) => unknown,
+            };
+            let queue = scheduler.queue(cost.class);
+
+            let permit = match queue.permits.clone().try_acquire_owned() {
+                Ok(permit) => permit,
+                Err(_) => {
+                    let Some(_queued) = queue.try_enqueue(scheduler.config.max_queued) else {
+                        let rejection = TraceRejection { cost, retry_after: queue.retry_after() };
+                        return MethodResponse::error(
+                            req.id,
+                            ErrorObject::owned(
+                                TRACE_QUEUE_FULL_CODE,
+                                "too many queued tracing calls",
+                                Some(rejection),
+                            ),
+                        )
+                    };
+                    queue.permits.clone().acquire_owned().await.expect("semaphore is not closed")
+                }
+            };
+
+            let started = Instant::now();
+            let response = inner.call(req).await;
+            queue.record(started.elapsed());
+            drop(permit);
+            response
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_trace_calls() {
+        assert_eq!(
+            TraceCall::new("trace_block", Some(r#"["0x10"]"#)),
+            Some(TraceCall::Block(16u64.into()))
+        );
+        assert_eq!(
+            TraceCall::new(
+                "trace_callMany",
+                Some(r#"[[[{"gas":"0x10"},["trace"]],[{"gas":"0x20"},["trace"]]],"latest"]"#)
+            ),
+            Some(TraceCall::Calls { gas: Some(0x30), calls: 2 })
+        );
+        assert_eq!(
+            TraceCall::new(
+                "trace_call",
+                Some(r#"[{"to":"0x0000000000000000000000000000000000000000"}]"#)
+            ),
+            Some(TraceCall::Calls { gas: None, calls: 1 })
+        );
+        assert_eq!(
+            TraceCall::new("trace_filter", Some(r#"[{"fromBlock":"0x1","toBlock":"0x3"}]"#)),
+            Some(TraceCall::Range { from: Some(1), to: Some(3) })
+        );
+        assert_eq!(TraceCall::new("eth_call", Some(r#"[{}]"#)), None);
+        assert_eq!(TraceCall::new("debug_getRawBlock", Some(r#"["latest"]"#)), None);
+    }
+
+    #[test]
+    fn cost_classes_and_retry_after() {
+        let config = TraceSchedulerConfig::new(8);
+        assert_eq!(config.class(1_000_000), CostClass::Light);
+        assert_eq!(config.class(10_000_000), CostClass::Medium);
+        assert_eq!(config.class(30_000_000), CostClass::Heavy);
+
+        let queue = CostQueue::new(2);
+        assert_eq!(queue.retry_after(), 1);
+        queue.record(Duration::from_secs(4));
+        queue.queued.store(3, Ordering::Relaxed);
+        // 4 calls of 4s each with 2 concurrent calls
+        assert_eq!(queue.retry_after(), 8);
+    }
+
+    #[test]
+    fn bound_queued_calls() {
+        let queue = CostQueue::new(1);
+        let first = queue.try_enqueue(2).unwrap();
+        let _second = queue.try_enqueue(2).unwrap();
+        assert!(queue.try_enqueue(2).is_none());
+        assert_eq!(queue.queued.load(Ordering::Relaxed), 2);
+
+        drop(first);
+        assert!(queue.try_enqueue(2).is_some());
+        assert_eq!(queue.queued.load(Ordering::Relaxed), 1);
+    }
+}
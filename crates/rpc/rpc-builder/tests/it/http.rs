@@ -1,7 +1,7 @@
 #![allow(unreachable_pub)]
 //! Standalone http tests
 
-use crate::utils::{launch_http, launch_http_ws, launch_ws};
+use crate::utils::{launch_http, launch_http_with_config, launch_http_ws, launch_ws};
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_primitives::{hex_literal::hex, Address, Bytes, TxHash, B256, B64, U256, U64};
 use alloy_rpc_types_eth::{
@@ -20,11 +20,14 @@ use jsonrpsee::{
 };
 use reth_network_peers::NodeRecord;
 use reth_primitives::Receipt;
+use reth_rpc::OtterscanApiConfig;
 use reth_rpc_api::{
     clients::{AdminApiClient, EthApiClient},
     DebugApiClient, EthFilterApiClient, NetApiClient, OtterscanClient, TraceApiClient,
     Web3ApiClient,
 };
+use reth_rpc_builder::RpcModuleConfig;
+use reth_rpc_eth_types::AccountProofRequest;
 use reth_rpc_server_types::RethRpcModule;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
@@ -40,6 +43,16 @@ fn is_unimplemented(err: jsonrpsee::core::client::Error) -> bool {
     }
 }
 
+fn is_disabled(err: jsonrpsee::core::client::Error) -> bool {
+    match err {
+        jsonrpsee::core::client::Error::Call(error_obj) => {
+            error_obj.code() == ErrorCode::InternalError.code() &&
+                error_obj.message().ends_with("disabled")
+        }
+        _ => false,
+    }
+}
+
 async fn test_rpc_call_ok<R>(client: &HttpClient, method_name: &str, params: ArrayParams)
 where
     R: DeserializeOwned,
@@ -324,12 +337,19 @@ where
     .await
     .unwrap();
     EthApiClient::<Transaction, Block, Receipt, Header>::gas_price(client).await.unwrap_err();
-    EthApiClient::<Transaction, Block, Receipt, Header>::max_priority_fee_per_gas(client)
+    EthApiClient::<Transaction, Block, Receipt, Header>::max_priority_fee_per_gas(client, None)
         .await
         .unwrap_err();
     EthApiClient::<Transaction, Block, Receipt, Header>::get_proof(client, address, vec![], None)
         .await
         .unwrap();
+    EthApiClient::<Transaction, Block, Receipt, Header>::get_proofs(
+        client,
+        vec![AccountProofRequest { address, storage_keys: vec![] }],
+        None,
+    )
+    .await
+    .unwrap();
 
     // Unimplemented
     assert!(is_unimplemented(
@@ -465,7 +485,7 @@ where
     .err()
     .unwrap();
 
-    assert!(is_unimplemented(
+    assert!(is_disabled(
         OtterscanClient::<Transaction, Header>::search_transactions_before(
             client,
             address,
@@ -476,7 +496,7 @@ where
         .err()
         .unwrap()
     ));
-    assert!(is_unimplemented(
+    assert!(is_disabled(
         OtterscanClient::<Transaction, Header>::search_transactions_after(
             client,
             address,
@@ -697,6 +717,30 @@ async fn test_call_otterscan_functions_http_and_ws() {
     test_basic_otterscan_calls(&client).await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_otterscan_search_functions_with_address_index() {
+    reth_tracing::init_test_tracing();
+
+    let config = RpcModuleConfig::default().with_ots(OtterscanApiConfig { address_index: true });
+    let handle = launch_http_with_config(vec![RethRpcModule::Ots], config).await;
+    let client = handle.http_client().unwrap();
+    let address = Address::default();
+
+    let before =
+        OtterscanClient::<Transaction, Header>::search_transactions_before(&client, address, 0, 10)
+            .await
+            .unwrap();
+    assert!(before.txs.is_empty());
+    assert!(before.first_page && before.last_page);
+
+    let after =
+        OtterscanClient::<Transaction, Header>::search_transactions_after(&client, address, 0, 10)
+            .await
+            .unwrap();
+    assert!(after.txs.is_empty());
+    assert!(after.first_page && after.last_page);
+}
+
 // <https://github.com/paradigmxyz/reth/issues/5830>
 #[tokio::test(flavor = "multi_thread")]
 async fn test_eth_logs_args() {
@@ -6,23 +6,25 @@ use alloy_eips::{
     eip4844::BlobAndProofV1,
     eip7685::{Requests, RequestsOrHash},
 };
-use alloy_primitives::{BlockHash, BlockNumber, B256, U64};
+use alloy_primitives::{BlockHash, BlockNumber, Bytes, B256, U64};
+use alloy_rlp::Decodable;
 use alloy_rpc_types_engine::{
     CancunPayloadFields, ClientVersionV1, ExecutionPayload, ExecutionPayloadBodiesV1,
     ExecutionPayloadInputV2, ExecutionPayloadSidecar, ExecutionPayloadV1, ExecutionPayloadV3,
-    ForkchoiceState, ForkchoiceUpdated, PayloadId, PayloadStatus, PraguePayloadFields,
-    TransitionConfiguration,
+    ForkchoiceState, ForkchoiceUpdateError, ForkchoiceUpdated, PayloadId, PayloadStatus,
+    PraguePayloadFields, TransitionConfiguration,
 };
 use async_trait::async_trait;
 use jsonrpsee_core::RpcResult;
 use parking_lot::Mutex;
-use reth_beacon_consensus::BeaconConsensusEngineHandle;
+use reth_beacon_consensus::{BeaconConsensusEngineHandle, BeaconForkChoiceUpdateError};
 use reth_chainspec::{EthereumHardforks, Hardforks};
-use reth_engine_primitives::{EngineTypes, EngineValidator};
+use reth_engine_primitives::{BlockAccessList, EngineTypes, EngineValidator};
 use reth_evm::provider::EvmEnvProvider;
 use reth_payload_builder::PayloadStore;
 use reth_payload_primitives::{
-    validate_payload_timestamp, EngineApiMessageVersion, PayloadBuilderAttributes,
+    validate_block_access_list_version, validate_payload_timestamp, EngineApiMessageVersion,
+    InvalidPayloadAttributesError, PayloadAttributes, PayloadBuilderAttributes,
     PayloadOrAttributes,
 };
 use reth_primitives::{Block, EthereumHardfork};
@@ -319,6 +321,74 @@ where
         Ok(res?)
     }
 
+    /// Handles a post Amsterdam payload, which carries the RLP encoded
+    /// [EIP-7928](https://eips.ethereum.org/EIPS/eip-7928) block access list of the payload.
+    pub async fn new_payload_v5(
+        &self,
+        payload: ExecutionPayloadV3,
+        versioned_hashes: Vec<B256>,
+        parent_beacon_block_root: B256,
+        execution_requests: Requests,
+        block_access_list: Bytes,
+    ) -> EngineApiResult<PayloadStatus> {
+        let payload = ExecutionPayload::from(payload);
+        let payload_or_attrs =
+            PayloadOrAttributes::<'_, EngineT::PayloadAttributes>::from_execution_payload(
+                &payload,
+                Some(parent_beacon_block_root),
+            );
+        self.inner
+            .validator
+            .validate_version_specific_fields(EngineApiMessageVersion::V5, payload_or_attrs)?;
+
+        let block_access_list = BlockAccessList::decode(&mut block_access_list.as_ref())
+            .map_err(EngineApiError::InvalidBlockAccessList)?;
+
+        Ok(self
+            .inner
+            .beacon_consensus
+            .new_payload_with_block_access_list(
+                payload,
+                ExecutionPayloadSidecar::v4(
+                    CancunPayloadFields { versioned_hashes, parent_beacon_block_root },
+                    PraguePayloadFields {
+                        requests: RequestsOrHash::Requests(execution_requests),
+                        // TODO: add as an argument and handle in `try_into_block`
+                        target_blobs_per_block: 0,
+                    },
+                ),
+                block_access_list,
+            )
+            .await
+            .inspect(|_| self.inner.on_new_payload_response())?)
+    }
+
+    /// Metrics version of `new_payload_v5`
+    async fn new_payload_v5_metered(
+        &self,
+        payload: ExecutionPayloadV3,
+        versioned_hashes: Vec<B256>,
+        parent_beacon_block_root: B256,
+        execution_requests: Requests,
+        block_access_list: Bytes,
+    ) -> RpcResult<PayloadStatus> {
+        let start = Instant::now();
+        let gas_used = payload.payload_inner.payload_inner.gas_used;
+        let res = Self::new_payload_v5(
+            self,
+            payload,
+            versioned_hashes,
+            parent_beacon_block_root,
+            execution_requests,
+            block_access_list,
+        )
+        .await;
+        let elapsed = start.elapsed();
+        self.inner.metrics.latency.new_payload_v5.record(elapsed);
+        self.inner.metrics.new_payload_response.update_response_metrics(&res, gas_used, elapsed);
+        Ok(res?)
+    }
+
     /// Sends a message to the beacon consensus engine to update the fork choice _without_
     /// withdrawals.
     ///
@@ -441,6 +511,11 @@ where
             EngineApiMessageVersion::V3,
             attributes.timestamp(),
         )?;
+        validate_block_access_list_version(
+            &self.inner.chain_spec,
+            EngineApiMessageVersion::V3,
+            attributes.timestamp(),
+        )?;
 
         // Now resolve the payload
         self.inner
@@ -476,6 +551,11 @@ where
             EngineApiMessageVersion::V4,
             attributes.timestamp(),
         )?;
+        validate_block_access_list_version(
+            &self.inner.chain_spec,
+            EngineApiMessageVersion::V4,
+            attributes.timestamp(),
+        )?;
 
         // Now resolve the payload
         self.inner
@@ -491,6 +571,40 @@ where
             })
     }
 
+    /// Returns the most recent version of the payload that is available in the corresponding
+    /// payload build process at the time of receiving this call, together with its
+    /// [EIP-7928](https://eips.ethereum.org/EIPS/eip-7928) block access list.
+    ///
+    /// Note:
+    /// > Provider software MAY stop the corresponding build process after serving this call.
+    pub async fn get_payload_v5(
+        &self,
+        payload_id: PayloadId,
+    ) -> EngineApiResult<EngineT::ExecutionPayloadEnvelopeV5> {
+        // First we fetch the payload attributes to check the timestamp
+        let attributes = self.get_payload_attributes(payload_id).await?;
+
+        // validate timestamp according to engine rules
+        validate_payload_timestamp(
+            &self.inner.chain_spec,
+            EngineApiMessageVersion::V5,
+            attributes.timestamp(),
+        )?;
+
+        // Now resolve the payload
+        self.inner
+            .payload_store
+            .resolve(payload_id)
+            .await
+            .ok_or(EngineApiError::UnknownPayload)?
+            .map_err(|_| EngineApiError::UnknownPayload)?
+            .try_into()
+            .map_err(|_| {
+                warn!("could not transform built payload into ExecutionPayloadV5");
+                EngineApiError::UnknownPayload
+            })
+    }
+
     /// Fetches all the blocks for the provided range starting at `start`, containing `count`
     /// blocks and returns the mapped payload bodies.
     async fn get_payload_bodies_by_range_with<F, R>(
@@ -720,7 +834,42 @@ where
             }
         }
 
-        Ok(self.inner.beacon_consensus.fork_choice_updated(state, payload_attrs, version).await?)
+        let timestamp = payload_attrs.as_ref().map(|attrs| attrs.timestamp());
+        match self.inner.beacon_consensus.fork_choice_updated(state, payload_attrs, version).await {
+            Err(BeaconForkChoiceUpdateError::ForkchoiceUpdateError(
+                ForkchoiceUpdateError::UpdatedInvalidPayloadAttributes,
+            )) => Err(self.invalid_payload_attributes(state.head_block_hash, timestamp)),
+            res => Ok(res?),
+        }
+    }
+
+    /// Returns the error for payload attributes that were rejected by the consensus engine.
+    ///
+    /// The consensus engine doesn't report which rule failed, so this recovers the timestamp
+    /// violation from the head block to surface it with structured details.
+    fn invalid_payload_attributes(
+        &self,
+        head_block_hash: BlockHash,
+        timestamp: Option<u64>,
+    ) -> EngineApiError {
+        let head_timestamp = self
+            .inner
+            .provider
+            .header(&head_block_hash)
+            .ok()
+            .flatten()
+            .map(|header| header.timestamp);
+        match (timestamp, head_timestamp) {
+            (Some(timestamp), Some(head_timestamp)) if timestamp <= head_timestamp => {
+                EngineApiError::InvalidPayloadAttributes(
+                    InvalidPayloadAttributesError::InvalidTimestamp { timestamp, head_timestamp },
+                )
+            }
+            _ => BeaconForkChoiceUpdateError::ForkchoiceUpdateError(
+                ForkchoiceUpdateError::UpdatedInvalidPayloadAttributes,
+            )
+            .into(),
+        }
     }
 }
 
@@ -805,6 +954,27 @@ where
             .await?)
     }
 
+    /// Handler for `engine_newPayloadV5`
+    async fn new_payload_v5(
+        &self,
+        payload: ExecutionPayloadV3,
+        versioned_hashes: Vec<B256>,
+        parent_beacon_block_root: B256,
+        execution_requests: Requests,
+        block_access_list: Bytes,
+    ) -> RpcResult<PayloadStatus> {
+        trace!(target: "rpc::engine", "Serving engine_newPayloadV5");
+        Ok(self
+            .new_payload_v5_metered(
+                payload,
+                versioned_hashes,
+                parent_beacon_block_root,
+                execution_requests,
+                block_access_list,
+            )
+            .await?)
+    }
+
     /// Handler for `engine_forkchoiceUpdatedV1`
     /// See also <https://github.com/ethereum/execution-apis/blob/3d627c95a4d3510a8187dd02e0250ecb4331d27e/src/engine/paris.md#engine_forkchoiceupdatedv1>
     ///
@@ -935,6 +1105,25 @@ where
         Ok(res?)
     }
 
+    /// Handler for `engine_getPayloadV5`
+    ///
+    /// Returns the most recent version of the payload that is available in the corresponding
+    /// payload build process at the time of receiving this call, together with its block access
+    /// list.
+    ///
+    /// Note:
+    /// > Provider software MAY stop the corresponding build process after serving this call.
+    async fn get_payload_v5(
+        &self,
+        payload_id: PayloadId,
+    ) -> RpcResult<EngineT::ExecutionPayloadEnvelopeV5> {
+        trace!(target: "rpc::engine", "Serving engine_getPayloadV5");
+        let start = Instant::now();
+        let res = Self::get_payload_v5(self, payload_id).await;
+        self.inner.metrics.latency.get_payload_v5.record(start.elapsed());
+        Ok(res?)
+    }
+
     /// Handler for `engine_getPayloadBodiesByHashV1`
     /// See also <https://github.com/ethereum/execution-apis/blob/6452a6b194d7db269bf1dbd087a267251d3cc7f8/src/engine/shanghai.md#engine_getpayloadbodiesbyhashv1>
     async fn get_payload_bodies_by_hash_v1(
@@ -1,11 +1,13 @@
-use alloy_primitives::{B256, U256};
+use alloy_primitives::{B256, U256, U64};
 use jsonrpsee_types::error::{
     INTERNAL_ERROR_CODE, INVALID_PARAMS_CODE, INVALID_PARAMS_MSG, SERVER_ERROR_MSG,
 };
 use reth_beacon_consensus::BeaconForkChoiceUpdateError;
 use reth_engine_primitives::BeaconOnNewPayloadError;
 use reth_payload_builder_primitives::PayloadBuilderError;
-use reth_payload_primitives::EngineObjectValidationError;
+use reth_payload_primitives::{
+    EngineObjectValidationError, InvalidPayloadAttributesError, VersionSpecificValidationError,
+};
 use thiserror::Error;
 
 /// The Engine API result type
@@ -56,6 +58,9 @@ pub enum EngineApiError {
         /// Requested number of items
         count: u64,
     },
+    /// Thrown if the block access list of `engine_newPayloadV5` can't be decoded
+    #[error("invalid block access list: {0}")]
+    InvalidBlockAccessList(alloy_rlp::Error),
     /// Terminal total difficulty mismatch during transition configuration exchange.
     #[error(
         "invalid transition terminal total difficulty: \
@@ -93,6 +98,9 @@ pub enum EngineApiError {
     /// The payload or attributes are known to be malformed before processing.
     #[error(transparent)]
     EngineObjectValidationError(#[from] EngineObjectValidationError),
+    /// The payload attributes of a forkchoice update are invalid with respect to the head block.
+    #[error("Payload attributes validation error: {0}")]
+    InvalidPayloadAttributes(InvalidPayloadAttributesError),
     /// Any other rpc error
     #[error("{0}")]
     Other(jsonrpsee_types::ErrorObject<'static>),
@@ -108,41 +116,136 @@ impl EngineApiError {
 /// Helper type to represent the `error` field in the error response:
 /// <https://github.com/ethereum/execution-apis/blob/main/src/engine/common.md#errors>
 #[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 struct ErrorData {
     err: String,
+    /// Details of the validation rule that failed, if the payload or attributes are invalid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    validation_error: Option<ValidationErrorData>,
 }
 
 impl ErrorData {
     #[inline]
     fn new(err: impl std::fmt::Display) -> Self {
-        Self { err: err.to_string() }
+        Self { err: err.to_string(), validation_error: None }
+    }
+
+    /// Creates the data of a validation error with the details of the failed rule.
+    fn validation(err: impl std::fmt::Display, validation_error: ValidationErrorData) -> Self {
+        Self { err: err.to_string(), validation_error: Some(validation_error) }
+    }
+}
+
+/// The details of a failed validation rule, so that mismatches between the consensus and
+/// execution layer can be debugged without parsing the error message.
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidationErrorData {
+    /// The validated object, `payload` or `payloadAttributes`.
+    object: &'static str,
+    /// The name of the rule that failed.
+    rule: &'static str,
+    /// The name of the field that violated the rule.
+    field: &'static str,
+    /// The version of the engine method.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u8>,
+    /// The timestamp of the payload or attributes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    timestamp: Option<U64>,
+    /// The timestamp of the head block.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    head_timestamp: Option<U64>,
+}
+
+impl ValidationErrorData {
+    /// Creates the details of a version specific validation error of the given object.
+    fn version_specific(object: &'static str, err: &VersionSpecificValidationError) -> Self {
+        let (version, timestamp) = match err {
+            VersionSpecificValidationError::ParentBeaconBlockRootNotSupportedBeforeV3 {
+                version,
+            } |
+            VersionSpecificValidationError::NoParentBeaconBlockRootPostCancun { version } => {
+                (Some(*version as u8), None)
+            }
+            VersionSpecificValidationError::WithdrawalsNotSupportedInV1 => (Some(1), None),
+            VersionSpecificValidationError::NoWithdrawalsPostShanghai { timestamp } |
+            VersionSpecificValidationError::HasWithdrawalsPreShanghai { timestamp } => {
+                (None, Some(U64::from(*timestamp)))
+            }
+        };
+        Self {
+            object,
+            rule: err.rule(),
+            field: err.field(),
+            version,
+            timestamp,
+            head_timestamp: None,
+        }
+    }
+
+    /// Creates the details of a payload attributes error, if the rule is known.
+    fn payload_attributes(err: &InvalidPayloadAttributesError) -> Option<Self> {
+        match err {
+            InvalidPayloadAttributesError::InvalidTimestamp { timestamp, head_timestamp } => {
+                Some(Self {
+                    object: "payloadAttributes",
+                    rule: "timestampNotGreaterThanHead",
+                    field: "timestamp",
+                    version: None,
+                    timestamp: Some(U64::from(*timestamp)),
+                    head_timestamp: Some(U64::from(*head_timestamp)),
+                })
+            }
+            InvalidPayloadAttributesError::InvalidParams(_) => None,
+        }
     }
 }
 
 impl From<EngineApiError> for jsonrpsee_types::error::ErrorObject<'static> {
     fn from(error: EngineApiError) -> Self {
         match error {
-            EngineApiError::InvalidBodiesRange { .. } |
+            EngineApiError::EngineObjectValidationError(EngineObjectValidationError::Payload(
+                ref err,
+            )) => jsonrpsee_types::error::ErrorObject::owned(
+                INVALID_PARAMS_CODE,
+                INVALID_PARAMS_MSG,
+                Some(ErrorData::validation(
+                    &error,
+                    ValidationErrorData::version_specific("payload", err),
+                )),
+            ),
             EngineApiError::EngineObjectValidationError(
-                EngineObjectValidationError::Payload(_) |
-                EngineObjectValidationError::InvalidParams(_),
-            ) => {
-                // Note: the data field is not required by the spec, but is also included by other
-                // clients
+                EngineObjectValidationError::PayloadAttributes(ref err),
+            ) => jsonrpsee_types::error::ErrorObject::owned(
+                INVALID_PAYLOAD_ATTRIBUTES,
+                INVALID_PAYLOAD_ATTRIBUTES_MSG,
+                Some(ErrorData::validation(
+                    &error,
+                    ValidationErrorData::version_specific("payloadAttributes", err),
+                )),
+            ),
+            EngineApiError::InvalidPayloadAttributes(ref err) => {
+                let data = match ValidationErrorData::payload_attributes(err) {
+                    Some(validation_error) => ErrorData::validation(&error, validation_error),
+                    None => ErrorData::new(&error),
+                };
                 jsonrpsee_types::error::ErrorObject::owned(
-                    INVALID_PARAMS_CODE,
-                    INVALID_PARAMS_MSG,
-                    Some(ErrorData::new(error)),
+                    INVALID_PAYLOAD_ATTRIBUTES,
+                    INVALID_PAYLOAD_ATTRIBUTES_MSG,
+                    Some(data),
                 )
             }
+            EngineApiError::InvalidBodiesRange { .. } |
+            EngineApiError::InvalidBlockAccessList(_) |
             EngineApiError::EngineObjectValidationError(
-                EngineObjectValidationError::PayloadAttributes(_),
+                EngineObjectValidationError::InvalidParams(_),
             ) => {
                 // Note: the data field is not required by the spec, but is also included by other
                 // clients
                 jsonrpsee_types::error::ErrorObject::owned(
-                    INVALID_PAYLOAD_ATTRIBUTES,
-                    INVALID_PAYLOAD_ATTRIBUTES_MSG,
+                    INVALID_PARAMS_CODE,
+                    INVALID_PARAMS_MSG,
                     Some(ErrorData::new(error)),
                 )
             }
@@ -249,4 +352,34 @@ mod tests {
             EngineApiError::UnknownPayload,
         );
     }
+
+    #[test]
+    fn validation_error_data() {
+        let err: jsonrpsee_types::error::ErrorObject<'static> =
+            EngineApiError::EngineObjectValidationError(
+                EngineObjectValidationError::PayloadAttributes(
+                    VersionSpecificValidationError::NoWithdrawalsPostShanghai { timestamp: 16 },
+                ),
+            )
+            .into();
+        assert_eq!(err.code(), INVALID_PAYLOAD_ATTRIBUTES);
+        assert_eq!(
+            err.data().unwrap().get(),
+            r#"{"err":"Payload attributes validation error: no withdrawals post-Shanghai, timestamp 16","validationError":{"object":"payloadAttributes","rule":"noWithdrawalsPostShanghai","field":"withdrawals","timestamp":"0x10"}}"#
+        );
+
+        let err: jsonrpsee_types::error::ErrorObject<'static> =
+            EngineApiError::InvalidPayloadAttributes(
+                InvalidPayloadAttributesError::InvalidTimestamp {
+                    timestamp: 12,
+                    head_timestamp: 12,
+                },
+            )
+            .into();
+        assert_eq!(err.code(), INVALID_PAYLOAD_ATTRIBUTES);
+        assert_eq!(
+            err.data().unwrap().get(),
+            r#"{"err":"Payload attributes validation error: timestamp 12 is not greater than head timestamp 12","validationError":{"object":"payloadAttributes","rule":"timestampNotGreaterThanHead","field":"timestamp","timestamp":"0xc","headTimestamp":"0xc"}}"#
+        );
+    }
 }
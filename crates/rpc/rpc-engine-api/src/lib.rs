@@ -21,11 +21,15 @@ mod error;
 /// Engine API metrics.
 mod metrics;
 
+/// Proposer lookahead API.
+mod proposer;
+
 pub use engine_api::{EngineApi, EngineApiSender};
 pub use error::*;
+pub use proposer::ProposerApi;
 
 // re-export server trait for convenience
-pub use reth_rpc_api::EngineApiServer;
+pub use reth_rpc_api::{EngineApiServer, ProposerApiServer};
 
 #[cfg(test)]
 #[allow(unused_imports)]
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use jsonrpsee_core::RpcResult;
+use reth_payload_builder::PayloadBuilderHandle;
+use reth_payload_primitives::{PayloadTypes, ProposerLookahead};
+use reth_rpc_api::ProposerApiServer;
+use tracing::trace;
+
+/// Forwards the proposer lookahead of the consensus layer to the payload builder.
+#[derive(Debug)]
+pub struct ProposerApi<T: PayloadTypes> {
+    payload_builder: PayloadBuilderHandle<T>,
+}
+
+impl<T: PayloadTypes> ProposerApi<T> {
+    /// Creates a new instance that forwards the lookahead to the given payload builder.
+    pub const fn new(payload_builder: PayloadBuilderHandle<T>) -> Self {
+        Self { payload_builder }
+    }
+}
+
+#[async_trait]
+impl<T: PayloadTypes> ProposerApiServer for ProposerApi<T> {
+    async fn set_proposer_lookahead(&self, timestamps: Vec<u64>) -> RpcResult<()> {
+        trace!(target: "rpc::engine", ?timestamps, "Serving reth_setProposerLookahead");
+        self.payload_builder.set_proposer_lookahead(ProposerLookahead::new(timestamps));
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@ use alloy_rpc_types_eth::{
 };
 use alloy_serde::JsonStorageKey;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::{AccountProofRequest, PriorityFeeKind};
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use tracing::trace;
 
@@ -227,15 +228,18 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
         block_overrides: Option<Box<BlockOverrides>>,
     ) -> RpcResult<Bytes>;
 
-    /// Simulate arbitrary number of transactions at an arbitrary blockchain index, with the
-    /// optionality of state overrides
+    /// Simulate arbitrary number of bundles of transactions at an arbitrary blockchain index, with
+    /// the optionality of state overrides and block overrides per bundle.
+    ///
+    /// The state changes of each transaction are applied before the next one, also across
+    /// bundles.
     #[method(name = "callMany")]
     async fn call_many(
         &self,
-        bundle: Bundle,
+        bundles: Vec<Bundle>,
         state_context: Option<StateContext>,
         state_override: Option<StateOverride>,
-    ) -> RpcResult<Vec<EthCallResponse>>;
+    ) -> RpcResult<Vec<Vec<EthCallResponse>>>;
 
     /// Generates an access list for a transaction.
     ///
@@ -281,8 +285,11 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
     ) -> RpcResult<Option<alloy_rpc_types_eth::Account>>;
 
     /// Introduced in EIP-1559, returns suggestion for the priority for dynamic fee transactions.
+    ///
+    /// The optional kind requests a suggestion for blob transactions or large transactions, which
+    /// is sampled from recent transactions of that kind only.
     #[method(name = "maxPriorityFeePerGas")]
-    async fn max_priority_fee_per_gas(&self) -> RpcResult<U256>;
+    async fn max_priority_fee_per_gas(&self, kind: Option<PriorityFeeKind>) -> RpcResult<U256>;
 
     /// Introduced in EIP-4844, returns the current blob base fee in wei.
     #[method(name = "blobBaseFee")]
@@ -360,6 +367,18 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
         keys: Vec<JsonStorageKey>,
         block_number: Option<BlockId>,
     ) -> RpcResult<EIP1186AccountProofResponse>;
+
+    /// Returns the account and storage values of the specified accounts including the
+    /// Merkle-proofs, in the order of the requested accounts.
+    ///
+    /// This is equivalent to an `eth_getProof` call for each account, but generates all proofs
+    /// in a single pass over the state trie.
+    #[method(name = "getProofs")]
+    async fn get_proofs(
+        &self,
+        accounts: Vec<AccountProofRequest>,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<Vec<EIP1186AccountProofResponse>>;
 }
 
 #[async_trait::async_trait]
@@ -656,12 +675,12 @@ where
     /// Handler for: `eth_callMany`
     async fn call_many(
         &self,
-        bundle: Bundle,
+        bundles: Vec<Bundle>,
         state_context: Option<StateContext>,
         state_override: Option<StateOverride>,
-    ) -> RpcResult<Vec<EthCallResponse>> {
-        trace!(target: "rpc::eth", ?bundle, ?state_context, ?state_override, "Serving eth_callMany");
-        Ok(EthCall::call_many(self, bundle, state_context, state_override).await?)
+    ) -> RpcResult<Vec<Vec<EthCallResponse>>> {
+        trace!(target: "rpc::eth", ?bundles, ?state_context, ?state_override, "Serving eth_callMany");
+        Ok(EthCall::call_many(self, bundles, state_context, state_override).await?)
     }
 
     /// Handler for: `eth_createAccessList`
@@ -708,9 +727,9 @@ where
     }
 
     /// Handler for: `eth_maxPriorityFeePerGas`
-    async fn max_priority_fee_per_gas(&self) -> RpcResult<U256> {
-        trace!(target: "rpc::eth", "Serving eth_maxPriorityFeePerGas");
-        Ok(EthFees::suggested_priority_fee(self).await?)
+    async fn max_priority_fee_per_gas(&self, kind: Option<PriorityFeeKind>) -> RpcResult<U256> {
+        trace!(target: "rpc::eth", ?kind, "Serving eth_maxPriorityFeePerGas");
+        Ok(EthFees::suggested_priority_fee_for(self, kind.unwrap_or_default()).await?)
     }
 
     /// Handler for: `eth_blobBaseFee`
@@ -808,4 +827,14 @@ where
         trace!(target: "rpc::eth", ?address, ?keys, ?block_number, "Serving eth_getProof");
         Ok(EthState::get_proof(self, address, keys, block_number)?.await?)
     }
+
+    /// Handler for: `eth_getProofs`
+    async fn get_proofs(
+        &self,
+        accounts: Vec<AccountProofRequest>,
+        block_number: Option<BlockId>,
+    ) -> RpcResult<Vec<EIP1186AccountProofResponse>> {
+        trace!(target: "rpc::eth", ?accounts, ?block_number, "Serving eth_getProofs");
+        Ok(EthState::get_proofs(self, accounts, block_number)?.await?)
+    }
 }
@@ -239,17 +239,20 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
         }
     }
 
-    /// Simulate arbitrary number of transactions at an arbitrary blockchain index, with the
-    /// optionality of state overrides
+    /// Simulate arbitrary number of bundles of transactions at an arbitrary blockchain index, with
+    /// the optionality of state overrides and block overrides per bundle.
+    ///
+    /// The state changes of each transaction are applied before the next one, also across
+    /// bundles. Each following bundle increments the block number by 1 and the block timestamp by
+    /// 12 seconds.
     fn call_many(
         &self,
-        bundle: Bundle,
+        bundles: Vec<Bundle>,
         state_context: Option<StateContext>,
         mut state_override: Option<StateOverride>,
-    ) -> impl Future<Output = Result<Vec<EthCallResponse>, Self::Error>> + Send {
+    ) -> impl Future<Output = Result<Vec<Vec<EthCallResponse>>, Self::Error>> + Send {
         async move {
-            let Bundle { transactions, block_override } = bundle;
-            if transactions.is_empty() {
+            if bundles.iter().all(|bundle| bundle.transactions.is_empty()) {
                 return Err(
                     EthApiError::InvalidParams(String::from("transactions are empty.")).into()
                 )
@@ -273,7 +276,7 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
                     .into();
             }
 
-            let ((cfg, block_env, _), block) = futures::try_join!(
+            let ((cfg, mut block_env, _), block) = futures::try_join!(
                 self.evm_env_at(target_block),
                 self.block_with_senders(target_block)
             )?;
@@ -298,7 +301,7 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
 
             let this = self.clone();
             self.spawn_with_state_at_block(at.into(), move |state| {
-                let mut results = Vec::with_capacity(transactions.len());
+                let mut all_bundles = Vec::with_capacity(bundles.len());
                 let mut db = CacheDB::new(StateProviderDatabase::new(state));
 
                 if replay_block_txs {
@@ -316,39 +319,57 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
                     }
                 }
 
-                let block_overrides = block_override.map(Box::new);
-
-                let mut transactions = transactions.into_iter().peekable();
-                while let Some(tx) = transactions.next() {
-                    // apply state overrides only once, before the first transaction
-                    let state_overrides = state_override.take();
-                    let overrides = EvmOverrides::new(state_overrides, block_overrides.clone());
-
-                    let env = this
-                        .prepare_call_env(cfg.clone(), block_env.clone(), tx, &mut db, overrides)
-                        .map(Into::into)?;
-                    let (res, _) = this.transact(&mut db, env)?;
+                let mut bundles = bundles.into_iter().peekable();
+                while let Some(bundle) = bundles.next() {
+                    let Bundle { transactions, block_override } = bundle;
+                    let mut results = Vec::with_capacity(transactions.len());
+
+                    let block_overrides = block_override.map(Box::new);
+
+                    let mut transactions = transactions.into_iter().peekable();
+                    while let Some(tx) = transactions.next() {
+                        // apply state overrides only once, before the first transaction
+                        let state_overrides = state_override.take();
+                        let overrides = EvmOverrides::new(state_overrides, block_overrides.clone());
+
+                        let env = this
+                            .prepare_call_env(
+                                cfg.clone(),
+                                block_env.clone(),
+                                tx,
+                                &mut db,
+                                overrides,
+                            )
+                            .map(Into::into)?;
+                        let (res, _) = this.transact(&mut db, env)?;
 
-                    match ensure_success(res.result) {
-                        Ok(output) => {
-                            results.push(EthCallResponse { value: Some(output), error: None });
+                        match ensure_success(res.result) {
+                            Ok(output) => {
+                                results.push(EthCallResponse { value: Some(output), error: None });
+                            }
+                            Err(err) => {
+                                results.push(EthCallResponse {
+                                    value: None,
+                                    error: Some(err.to_string()),
+                                });
+                            }
                         }
-                        Err(err) => {
-                            results.push(EthCallResponse {
-                                value: None,
-                                error: Some(err.to_string()),
-                            });
+
+                        if transactions.peek().is_some() || bundles.peek().is_some() {
+                            // need to apply the state changes of this call before executing the
+                            // next call
+                            db.commit(res.state);
                         }
                     }
 
-                    if transactions.peek().is_some() {
-                        // need to apply the state changes of this call before executing the next
-                        // call
-                        db.commit(res.state);
-                    }
+                    // Increment block_env number and timestamp for the next bundle
+                    block_env.number += U256::from(1);
+                    block_env.timestamp += U256::from(12);
+
+                    all_bundles.push(results);
                 }
 
-                Ok(results)
+                Ok(all_bundles)
             })
             .await
         }
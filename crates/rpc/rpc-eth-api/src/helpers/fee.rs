@@ -9,7 +9,7 @@ use reth_primitives_traits::BlockBody;
 use reth_provider::{BlockIdReader, ChainSpecProvider, HeaderProvider};
 use reth_rpc_eth_types::{
     fee_history::calculate_reward_percentiles_for_block, EthApiError, FeeHistoryCache,
-    FeeHistoryEntry, GasPriceOracle, RpcInvalidTransactionError,
+    FeeHistoryEntry, GasPriceOracle, PriorityFeeKind, RpcInvalidTransactionError,
 };
 use tracing::debug;
 
@@ -46,6 +46,17 @@ pub trait EthFees: LoadFee {
         LoadFee::suggested_priority_fee(self)
     }
 
+    /// Returns a suggestion for the priority fee (the tip) of the given kind of transaction
+    fn suggested_priority_fee_for(
+        &self,
+        kind: PriorityFeeKind,
+    ) -> impl Future<Output = Result<U256, Self::Error>> + Send
+    where
+        Self: 'static,
+    {
+        LoadFee::suggested_priority_fee_for(self, kind)
+    }
+
     /// Reports the fee history, for the given amount of blocks, up until the given newest block.
     ///
     /// If `reward_percentiles` are provided the [`FeeHistory`] will include the _approximated_
@@ -123,14 +134,14 @@ pub trait EthFees: LoadFee {
 
             let mut rewards: Vec<Vec<u128>> = Vec::new();
 
-            // Check if the requested range is within the cache bounds
-            let fee_entries = self.fee_history_cache().get_history(start_block, end_block).await;
+            // Check if the entire requested range is cached
+            let fee_entries = self
+                .fee_history_cache()
+                .get_history(start_block, end_block)
+                .await
+                .filter(|fee_entries| fee_entries.len() == block_count as usize);
 
             if let Some(fee_entries) = fee_entries {
-                if fee_entries.len() != block_count as usize {
-                    return Err(EthApiError::InvalidBlockRange.into())
-                }
-
                 for entry in &fee_entries {
                     base_fee_per_gas.push(entry.base_fee_per_gas as u128);
                     gas_used_ratio.push(entry.gas_used_ratio);
@@ -162,6 +173,13 @@ pub trait EthFees: LoadFee {
                     return Err(EthApiError::InvalidBlockRange.into())
                 }
 
+                // The range may be partially cached, so that only the rewards of the blocks that
+                // aren't cached need to be calculated from their receipts
+                let cached_entries = if reward_percentiles.is_some() {
+                    self.fee_history_cache().get_entries(start_block, end_block).await
+                } else {
+                    Default::default()
+                };
 
                 for header in &headers {
                     base_fee_per_gas.push(header.base_fee_per_gas().unwrap_or_default() as u128);
@@ -173,7 +191,18 @@ pub trait EthFees: LoadFee {
                     );
 
                     // Percentiles were specified, so we need to collect reward percentile ino
-                    if let Some(percentiles) = &reward_percentiles {
+                    let Some(percentiles) = &reward_percentiles else { continue };
+                    let cached_entry = cached_entries
+                        .get(&header.number())
+                        .filter(|entry| entry.header_hash == header.hash());
+                    if let Some(entry) = cached_entry {
+                        rewards.push(
+                            percentiles
+                                .iter()
+                                .map(|&percentile| self.approximate_percentile(entry, percentile))
+                                .collect(),
+                        );
+                    } else {
                         let (block, receipts) = self.cache()
                             .get_block_and_receipts(header.hash())
                             .await
@@ -189,6 +218,11 @@ pub trait EthFees: LoadFee {
                             )
                             .unwrap_or_default(),
                         );
+
+                        // cache the rewards of the block for subsequent requests
+                        self.fee_history_cache()
+                            .insert_blocks(std::iter::once((&block.block, receipts)))
+                            .await;
                     }
                 }
 
@@ -343,6 +377,19 @@ pub trait LoadFee: LoadBlock {
     where
         Self: 'static,
     {
-        async move { self.gas_oracle().suggest_tip_cap().await.map_err(Self::Error::from_eth_err) }
+        self.suggested_priority_fee_for(PriorityFeeKind::Regular)
+    }
+
+    /// Returns a suggestion for the priority fee (the tip) of the given kind of transaction
+    fn suggested_priority_fee_for(
+        &self,
+        kind: PriorityFeeKind,
+    ) -> impl Future<Output = Result<U256, Self::Error>> + Send
+    where
+        Self: 'static,
+    {
+        async move {
+            self.gas_oracle().suggest_tip_cap_for(kind).await.map_err(Self::Error::from_eth_err)
+        }
     }
 }
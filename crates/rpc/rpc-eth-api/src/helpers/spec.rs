@@ -6,7 +6,7 @@ use futures::Future;
 use reth_chainspec::{ChainInfo, EthereumHardforks};
 use reth_errors::{RethError, RethResult};
 use reth_network_api::NetworkInfo;
-use reth_provider::{BlockNumReader, ChainSpecProvider, StageCheckpointReader};
+use reth_provider::{BlockNumReader, ChainSpecProvider, LogIndexReader, StageCheckpointReader};
 
 use crate::{helpers::EthSigner, RpcNodeCore};
 
@@ -18,7 +18,8 @@ pub trait EthApiSpec:
     RpcNodeCore<
     Provider: ChainSpecProvider<ChainSpec: EthereumHardforks>
                   + BlockNumReader
-                  + StageCheckpointReader,
+                  + StageCheckpointReader
+                  + LogIndexReader,
     Network: NetworkInfo,
 >
 {
@@ -4,7 +4,11 @@ use super::{EthApiSpec, LoadPendingBlock, SpawnBlocking};
 use crate::{EthApiTypes, FromEthApiError, RpcNodeCore, RpcNodeCoreExt};
 use alloy_consensus::{constants::KECCAK_EMPTY, BlockHeader};
 use alloy_eips::BlockId;
-use alloy_primitives::{Address, Bytes, B256, U256};
+use alloy_primitives::{
+    keccak256,
+    map::{HashMap, HashSet},
+    Address, Bytes, B256, U256,
+};
 use alloy_rpc_types_eth::{Account, EIP1186AccountProofResponse};
 use alloy_serde::JsonStorageKey;
 use futures::Future;
@@ -15,7 +19,10 @@ use reth_provider::{
     BlockIdReader, BlockNumReader, ChainSpecProvider, EvmEnvProvider as _, ProviderHeader,
     StateProvider, StateProviderBox, StateProviderFactory,
 };
-use reth_rpc_eth_types::{EthApiError, PendingBlockEnv, RpcInvalidTransactionError};
+use reth_rpc_eth_types::{
+    AccountProofRequest, EthApiError, PendingBlockEnv, RpcInvalidTransactionError,
+};
+use reth_rpc_server_types::constants::MAX_PROOFS_ACCOUNTS;
 use reth_transaction_pool::TransactionPool;
 use revm_primitives::{BlockEnv, CfgEnvWithHandlerCfg, SpecId};
 
@@ -27,7 +34,8 @@ pub trait EthState: LoadState + SpawnBlocking {
     /// Returns the number of transactions sent from an address at the given block identifier.
     ///
     /// If this is [`BlockNumberOrTag::Pending`](alloy_eips::BlockNumberOrTag) then this will
-    /// look up the highest transaction in pool and return the next nonce (highest + 1).
+    /// look up the highest transaction in pool without a nonce gap and return the next nonce
+    /// (highest + 1).
     fn transaction_count(
         &self,
         address: Address,
@@ -124,6 +132,81 @@ pub trait EthState: LoadState + SpawnBlocking {
         })
     }
 
+    /// Returns the values stored of the given accounts, with Merkle-proofs, at the given block
+    /// number.
+    ///
+    /// All proofs are generated from a single multiproof, so that the trie nodes that are shared
+    /// by the proofs are only read once.
+    fn get_proofs(
+        &self,
+        requests: Vec<AccountProofRequest>,
+        block_id: Option<BlockId>,
+    ) -> Result<
+        impl Future<Output = Result<Vec<EIP1186AccountProofResponse>, Self::Error>> + Send,
+        Self::Error,
+    >
+    where
+        Self: EthApiSpec,
+    {
+        if requests.len() > MAX_PROOFS_ACCOUNTS {
+            return Err(EthApiError::InvalidParams(format!(
+                "too many accounts, max is {MAX_PROOFS_ACCOUNTS}"
+            ))
+            .into())
+        }
+
+        Ok(async move {
+            let _permit = self
+                .acquire_owned()
+                .await
+                .map_err(RethError::other)
+                .map_err(EthApiError::Internal)?;
+
+            let chain_info = self.chain_info().map_err(Self::Error::from_eth_err)?;
+            let block_id = block_id.unwrap_or_default();
+
+            // Check whether the distance to the block exceeds the maximum configured window.
+            let block_number = self
+                .provider()
+                .block_number_for_id(block_id)
+                .map_err(Self::Error::from_eth_err)?
+                .ok_or(EthApiError::HeaderNotFound(block_id))?;
+            let max_window = self.max_proof_window();
+            if chain_info.best_number.saturating_sub(block_number) > max_window {
+                return Err(EthApiError::ExceedsMaxProofWindow.into())
+            }
+
+            self.spawn_blocking_io(move |this| {
+                let state = this.state_at_block_id(block_id)?;
+
+                let mut targets = HashMap::<B256, HashSet<B256>>::default();
+                for request in &requests {
+                    targets
+                        .entry(keccak256(request.address))
+                        .or_default()
+                        .extend(request.storage_keys.iter().map(|key| keccak256(key.as_b256())));
+                }
+                let multiproof = state
+                    .multiproof(Default::default(), targets)
+                    .map_err(Self::Error::from_eth_err)?;
+
+                requests
+                    .into_iter()
+                    .map(|AccountProofRequest { address, storage_keys }| {
+                        let slots =
+                            storage_keys.iter().map(|key| key.as_b256()).collect::<Vec<_>>();
+                        let proof = multiproof
+                            .account_proof(address, &slots)
+                            .map_err(RethError::other)
+                            .map_err(EthApiError::Internal)?;
+                        Ok(proof.into_eip1186_response(storage_keys))
+                    })
+                    .collect()
+            })
+            .await
+        })
+    }
+
     /// Returns the account at the given address for the provided block identifier.
     fn get_account(
         &self,
@@ -302,7 +385,8 @@ pub trait LoadState:
     /// Returns the number of transactions sent from an address at the given block identifier.
     ///
     /// If this is [`BlockNumberOrTag::Pending`](alloy_eips::BlockNumberOrTag) then this will
-    /// look up the highest transaction in pool and return the next nonce (highest + 1).
+    /// look up the highest transaction in pool without a nonce gap and return the next nonce
+    /// (highest + 1).
     fn transaction_count(
         &self,
         address: Address,
@@ -320,26 +404,24 @@ pub trait LoadState:
                 .unwrap_or_default();
 
             if block_id == Some(BlockId::pending()) {
-                // for pending tag we need to find the highest nonce in the pool
-                if let Some(highest_pool_tx) =
-                    this.pool().get_highest_transaction_by_sender(address)
+                // for pending tag we need to find the highest executable nonce in the pool, nonce
+                // gapped transactions are skipped, because the gap must be filled first
+                if let Some(highest_pool_tx) = this
+                    .pool()
+                    .get_highest_consecutive_transaction_by_sender(address, on_chain_account_nonce)
                 {
-                    {
-                        // and the corresponding txcount is nonce + 1 of the highest tx in the pool
-                        // (on chain nonce is increased after tx)
-                        let next_tx_nonce =
-                            highest_pool_tx.nonce().checked_add(1).ok_or_else(|| {
-                                Self::Error::from(EthApiError::InvalidTransaction(
-                                    RpcInvalidTransactionError::NonceMaxValue,
-                                ))
-                            })?;
-
-                        // guard against drifts in the pool
-                        let next_tx_nonce = on_chain_account_nonce.max(next_tx_nonce);
-
-                        let tx_count = on_chain_account_nonce.max(next_tx_nonce);
-                        return Ok(U256::from(tx_count));
-                    }
+                    // and the corresponding txcount is nonce + 1 of the highest tx in the pool
+                    // (on chain nonce is increased after tx)
+                    let next_tx_nonce =
+                        highest_pool_tx.nonce().checked_add(1).ok_or_else(|| {
+                            Self::Error::from(EthApiError::InvalidTransaction(
+                                RpcInvalidTransactionError::NonceMaxValue,
+                            ))
+                        })?;
+
+                    // guard against drifts in the pool
+                    let tx_count = on_chain_account_nonce.max(next_tx_nonce);
+                    return Ok(U256::from(tx_count));
                 }
             }
             Ok(U256::from(on_chain_account_nonce))
@@ -18,9 +18,10 @@ pub mod filter;
 pub mod helpers;
 pub mod node;
 pub mod pubsub;
+pub mod send_sync;
 pub mod types;
 
-pub use bundle::{EthBundleApiServer, EthCallBundleApiServer};
+pub use bundle::{EthBundleApiServer, EthCallBundleApiServer, EthSendBundleApiServer};
 pub use core::{EthApiServer, FullEthApiServer};
 pub use filter::EthFilterApiServer;
 pub use node::{RpcNodeCore, RpcNodeCoreExt};
@@ -29,13 +30,16 @@ pub use reth_rpc_eth_types::error::{
     AsEthApiError, FromEthApiError, FromEvmError, IntoEthApiError,
 };
 pub use reth_rpc_types_compat::TransactionCompat;
+pub use send_sync::EthSendRawTransactionSyncApiServer;
 pub use types::{EthApiTypes, FullEthApiTypes, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction};
 
 #[cfg(feature = "client")]
-pub use bundle::{EthBundleApiClient, EthCallBundleApiClient};
+pub use bundle::{EthBundleApiClient, EthCallBundleApiClient, EthSendBundleApiClient};
 #[cfg(feature = "client")]
 pub use core::EthApiClient;
 #[cfg(feature = "client")]
 pub use filter::EthFilterApiClient;
+#[cfg(feature = "client")]
+pub use send_sync::EthSendRawTransactionSyncApiClient;
 
 use reth_trie_common as _;
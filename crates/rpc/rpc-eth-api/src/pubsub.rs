@@ -1,21 +1,32 @@
 //! `eth_` RPC API for pubsub subscription.
 
 use alloy_json_rpc::RpcObject;
-use alloy_rpc_types_eth::pubsub::{Params, SubscriptionKind};
 use jsonrpsee::proc_macros::rpc;
+use reth_rpc_eth_types::{PubSubParams, SubscriptionOptions, SubscriptionTarget};
 
 /// Ethereum pub-sub rpc interface.
 #[rpc(server, namespace = "eth")]
 pub trait EthPubSubApi<T: RpcObject> {
     /// Create an ethereum subscription for the given params
+    ///
+    /// `newPendingTransactions` also accepts a
+    /// [`PendingTransactionFilter`](reth_rpc_eth_types::PendingTransactionFilter) that is
+    /// evaluated by the server.
+    ///
+    /// A subscription that is created with the `resumable` option sends its events as
+    /// [`ResumableSubscriptionItem`](reth_rpc_eth_types::ResumableSubscriptionItem)s. After a
+    /// disconnect, `eth_resubscribe` with the cursor of the last received event resumes the
+    /// subscription with the events that were missed, if they are still buffered.
     #[subscription(
         name = "subscribe" => "subscription",
+        aliases = ["eth_resubscribe"],
         unsubscribe = "unsubscribe",
         item = alloy_rpc_types::pubsub::SubscriptionResult
     )]
     async fn subscribe(
         &self,
-        kind: SubscriptionKind,
-        params: Option<Params>,
+        target: SubscriptionTarget,
+        params: Option<PubSubParams>,
+        options: Option<SubscriptionOptions>,
     ) -> jsonrpsee::core::SubscriptionResult;
 }
@@ -0,0 +1,18 @@
+//! `eth_` RPC API for submitting transactions and waiting for their inclusion.
+
+use alloy_json_rpc::RpcObject;
+use alloy_primitives::Bytes;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+/// Opt-in interface for submitting a raw transaction and waiting until it is included in a block.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "eth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "eth"))]
+pub trait EthSendRawTransactionSyncApi<R: RpcObject> {
+    /// Submits a signed transaction and waits until it is included in a canonical block.
+    ///
+    /// Returns the receipt of the transaction, or an error if the transaction is dropped from the
+    /// pool or not included within the timeout in milliseconds. The timeout is capped by the
+    /// configured maximum, which is also the default.
+    #[method(name = "sendRawTransactionSync")]
+    async fn send_raw_transaction_sync(&self, bytes: Bytes, timeout: Option<u64>) -> RpcResult<R>;
+}
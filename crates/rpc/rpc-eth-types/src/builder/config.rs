@@ -5,14 +5,18 @@ use std::time::Duration;
 use crate::{
     EthStateCacheConfig, FeeHistoryCacheConfig, GasPriceOracleConfig, RPC_DEFAULT_GAS_CAP,
 };
+use alloy_rpc_types_eth::pubsub::SubscriptionKind;
 use reth_rpc_server_types::constants::{
     default_max_tracing_requests, DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_BLOCKS_PER_FILTER,
-    DEFAULT_MAX_LOGS_PER_RESPONSE, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS,
+    DEFAULT_MAX_LOGS_PER_RESPONSE, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_MAX_TRACE_FILTER_BLOCKS,
+    DEFAULT_MAX_TRACE_FILTER_TRANSACTIONS, DEFAULT_PROOF_PERMITS, DEFAULT_STALE_FILTER_TTL_SECS,
+    DEFAULT_SUBSCRIPTION_RESUME_LOGS, DEFAULT_SUBSCRIPTION_RESUME_NEW_HEADS,
+    DEFAULT_SUBSCRIPTION_RESUME_PENDING_TRANSACTIONS, DEFAULT_SUBSCRIPTION_RESUME_WINDOW_SECS,
 };
 use serde::{Deserialize, Serialize};
 
 /// Default value for stale filter ttl
-pub const DEFAULT_STALE_FILTER_TTL: Duration = Duration::from_secs(5 * 60);
+pub const DEFAULT_STALE_FILTER_TTL: Duration = Duration::from_secs(DEFAULT_STALE_FILTER_TTL_SECS);
 
 /// Additional config values for the eth namespace.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
@@ -29,6 +33,10 @@ pub struct EthConfig {
     pub max_blocks_per_filter: u64,
     /// Maximum number of logs that can be returned in a single response in `eth_getLogs` calls.
     pub max_logs_per_response: usize,
+    /// Maximum block range of a `trace_filter` request.
+    pub max_trace_filter_blocks: u64,
+    /// Maximum number of transactions a `trace_filter` request may execute.
+    pub max_trace_filter_transactions: u64,
     /// Gas limit for `eth_call` and call tracing RPC methods.
     ///
     /// Defaults to [`RPC_DEFAULT_GAS_CAP`]
@@ -42,6 +50,12 @@ pub struct EthConfig {
     pub fee_history_cache: FeeHistoryCacheConfig,
     /// The maximum number of getproof calls that can be executed concurrently.
     pub proof_permits: usize,
+    /// The maximum time `eth_sendRawTransactionSync` waits for the inclusion of a transaction.
+    ///
+    /// The endpoint is disabled if `None`.
+    pub send_raw_transaction_sync_timeout: Option<Duration>,
+    /// Settings for resumable `eth_subscribe` subscriptions.
+    pub subscription_resume: SubscriptionResumeConfig,
 }
 
 impl EthConfig {
@@ -52,6 +66,14 @@ impl EthConfig {
             .max_logs_per_response(self.max_logs_per_response)
             .stale_filter_ttl(self.stale_filter_ttl)
     }
+
+    /// Returns the config for `trace_filter` requests of the `trace` handler.
+    pub const fn trace_filter_config(&self) -> TraceFilterConfig {
+        TraceFilterConfig {
+            max_blocks: self.max_trace_filter_blocks,
+            max_transactions: self.max_trace_filter_transactions,
+        }
+    }
 }
 
 impl Default for EthConfig {
@@ -63,11 +85,15 @@ impl Default for EthConfig {
             max_tracing_requests: default_max_tracing_requests(),
             max_blocks_per_filter: DEFAULT_MAX_BLOCKS_PER_FILTER,
             max_logs_per_response: DEFAULT_MAX_LOGS_PER_RESPONSE,
+            max_trace_filter_blocks: DEFAULT_MAX_TRACE_FILTER_BLOCKS,
+            max_trace_filter_transactions: DEFAULT_MAX_TRACE_FILTER_TRANSACTIONS,
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
             rpc_max_simulate_blocks: DEFAULT_MAX_SIMULATE_BLOCKS,
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
             fee_history_cache: FeeHistoryCacheConfig::default(),
             proof_permits: DEFAULT_PROOF_PERMITS,
+            send_raw_transaction_sync_timeout: None,
+            subscription_resume: SubscriptionResumeConfig::default(),
         }
     }
 }
@@ -103,6 +129,24 @@ impl EthConfig {
         self
     }
 
+    /// Configures the duration after which a filter that has not been polled is uninstalled
+    pub const fn stale_filter_ttl(mut self, ttl: Duration) -> Self {
+        self.stale_filter_ttl = ttl;
+        self
+    }
+
+    /// Configures the maximum block range of a `trace_filter` request
+    pub const fn max_trace_filter_blocks(mut self, max_blocks: u64) -> Self {
+        self.max_trace_filter_blocks = max_blocks;
+        self
+    }
+
+    /// Configures the maximum number of transactions a `trace_filter` request may execute
+    pub const fn max_trace_filter_transactions(mut self, max_transactions: u64) -> Self {
+        self.max_trace_filter_transactions = max_transactions;
+        self
+    }
+
     /// Configures the maximum gas limit for `eth_call` and call tracing RPC methods
     pub const fn rpc_gas_cap(mut self, rpc_gas_cap: u64) -> Self {
         self.rpc_gas_cap = rpc_gas_cap;
@@ -126,6 +170,69 @@ impl EthConfig {
         self.proof_permits = permits;
         self
     }
+
+    /// Enables `eth_sendRawTransactionSync` with the given maximum time to wait for the inclusion
+    /// of a transaction.
+    pub const fn send_raw_transaction_sync_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.send_raw_transaction_sync_timeout = timeout;
+        self
+    }
+
+    /// Configures resumable `eth_subscribe` subscriptions
+    pub const fn subscription_resume(mut self, config: SubscriptionResumeConfig) -> Self {
+        self.subscription_resume = config;
+        self
+    }
+}
+
+/// Config for resumable `eth_subscribe` subscriptions.
+///
+/// The events of a resumable subscription are buffered, so that a client can resume the
+/// subscription with `eth_resubscribe` after a disconnect and receive the events it missed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriptionResumeConfig {
+    /// How long a subscription is kept after its client disconnected.
+    ///
+    /// Subscriptions are not resumable if this is zero.
+    pub window: Duration,
+    /// The number of buffered events of `newHeads` subscriptions.
+    pub new_heads: usize,
+    /// The number of buffered events of `logs` subscriptions.
+    pub logs: usize,
+    /// The number of buffered events of `newPendingTransactions` subscriptions.
+    pub pending_transactions: usize,
+}
+
+impl SubscriptionResumeConfig {
+    /// Returns the number of buffered events of the subscription kind, `None` if subscriptions of
+    /// the kind are not resumable.
+    pub const fn capacity(&self, kind: SubscriptionKind) -> Option<usize> {
+        if self.window.is_zero() {
+            return None
+        }
+        let capacity = match kind {
+            SubscriptionKind::NewHeads => self.new_heads,
+            SubscriptionKind::Logs => self.logs,
+            SubscriptionKind::NewPendingTransactions => self.pending_transactions,
+            SubscriptionKind::Syncing => 0,
+        };
+        if capacity == 0 {
+            None
+        } else {
+            Some(capacity)
+        }
+    }
+}
+
+impl Default for SubscriptionResumeConfig {
+    fn default() -> Self {
+        Self {
+            window: Duration::from_secs(DEFAULT_SUBSCRIPTION_RESUME_WINDOW_SECS),
+            new_heads: DEFAULT_SUBSCRIPTION_RESUME_NEW_HEADS,
+            logs: DEFAULT_SUBSCRIPTION_RESUME_LOGS,
+            pending_transactions: DEFAULT_SUBSCRIPTION_RESUME_PENDING_TRANSACTIONS,
+        }
+    }
 }
 
 /// Config for the filter
@@ -173,7 +280,28 @@ impl Default for EthFilterConfig {
             max_blocks_per_filter: None,
             max_logs_per_response: None,
             // 5min
-            stale_filter_ttl: Duration::from_secs(5 * 60),
+            stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
+        }
+    }
+}
+
+/// Config for the `trace_filter` handler of the `trace` namespace.
+///
+/// Bounds the work of a single request, the transactions of the blocks in the range are executed
+/// one block after another until the requested traces are collected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceFilterConfig {
+    /// Maximum block range of a request.
+    pub max_blocks: u64,
+    /// Maximum number of transactions a request may execute.
+    pub max_transactions: u64,
+}
+
+impl Default for TraceFilterConfig {
+    fn default() -> Self {
+        Self {
+            max_blocks: DEFAULT_MAX_TRACE_FILTER_BLOCKS,
+            max_transactions: DEFAULT_MAX_TRACE_FILTER_TRANSACTIONS,
         }
     }
 }
@@ -7,6 +7,7 @@ use futures::{future::Either, Stream, StreamExt};
 use reth_chain_state::CanonStateNotification;
 use reth_errors::{ProviderError, ProviderResult};
 use reth_execution_types::Chain;
+use reth_memory_budget::MemoryBudget;
 use reth_primitives::{NodePrimitives, SealedBlockWithSenders};
 use reth_primitives_traits::{Block, BlockBody};
 use reth_storage_api::{BlockReader, StateProviderFactory, TransactionVariant};
@@ -57,6 +58,15 @@ type ReceiptsLruCache<R, L> =
 
 type HeaderLruCache<H, L> = MultiConsumerLruCache<B256, H, L, HeaderResponseSender<H>>;
 
+/// The estimated size of a cached block in bytes, used for the memory budget.
+const ESTIMATED_BLOCK_SIZE: usize = 256 * 1024;
+
+/// The estimated size of the cached receipts of a block in bytes, used for the memory budget.
+const ESTIMATED_RECEIPTS_SIZE: usize = 128 * 1024;
+
+/// The estimated size of a cached header in bytes, used for the memory budget.
+const ESTIMATED_HEADER_SIZE: usize = 1024;
+
 /// Provides async access to cached eth data
 ///
 /// This is the frontend for the async caching service which manages cached data on a different
@@ -81,6 +91,7 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
         max_receipts: u32,
         max_headers: u32,
         max_concurrent_db_operations: usize,
+        memory_budget: &MemoryBudget,
     ) -> (Self, EthStateCacheService<Provider, Tasks>)
     where
         Provider: BlockReader<Block = B, Receipt = R>,
@@ -88,9 +99,21 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
         let (to_service, rx) = unbounded_channel();
         let service = EthStateCacheService {
             provider,
-            full_block_cache: BlockLruCache::new(max_blocks, "blocks"),
-            receipts_cache: ReceiptsLruCache::new(max_receipts, "receipts"),
-            headers_cache: HeaderLruCache::new(max_headers, "headers"),
+            full_block_cache: BlockLruCache::new(max_blocks, "blocks").with_memory_budget(
+                memory_budget,
+                "rpc_blocks_cache",
+                ESTIMATED_BLOCK_SIZE,
+            ),
+            receipts_cache: ReceiptsLruCache::new(max_receipts, "receipts").with_memory_budget(
+                memory_budget,
+                "rpc_receipts_cache",
+                ESTIMATED_RECEIPTS_SIZE,
+            ),
+            headers_cache: HeaderLruCache::new(max_headers, "headers").with_memory_budget(
+                memory_budget,
+                "rpc_headers_cache",
+                ESTIMATED_HEADER_SIZE,
+            ),
             action_tx: to_service.clone(),
             action_rx: UnboundedReceiverStream::new(rx),
             action_task_spawner,
@@ -121,6 +144,25 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
         config: EthStateCacheConfig,
         executor: Tasks,
     ) -> Self
+    where
+        Provider:
+            StateProviderFactory + BlockReader<Block = B, Receipt = R> + Clone + Unpin + 'static,
+        Tasks: TaskSpawner + Clone + 'static,
+    {
+        Self::spawn_with_memory_budget(provider, config, executor, &MemoryBudget::unlimited())
+    }
+
+    /// Creates a new async LRU backed cache service task and spawns it to a new task via the given
+    /// spawner.
+    ///
+    /// The caches are registered with the given memory budget, which shrinks their limits if the
+    /// node is under memory pressure.
+    pub fn spawn_with_memory_budget<Provider, Tasks>(
+        provider: Provider,
+        config: EthStateCacheConfig,
+        executor: Tasks,
+        memory_budget: &MemoryBudget,
+    ) -> Self
     where
         Provider:
             StateProviderFactory + BlockReader<Block = B, Receipt = R> + Clone + Unpin + 'static,
@@ -139,6 +181,7 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
             max_receipts,
             max_headers,
             max_concurrent_db_requests,
+            memory_budget,
         );
         executor.spawn_critical("eth state cache", Box::pin(service));
         this
@@ -7,6 +7,7 @@ use std::{
     hash::Hash,
 };
 
+use reth_memory_budget::{MemoryBudget, MemoryConsumer};
 use schnellru::{ByLength, Limiter, LruMap};
 
 use super::metrics::CacheMetrics;
@@ -23,6 +24,18 @@ where
     queued: HashMap<K, Vec<S>>,
     /// Cache metrics
     metrics: CacheMetrics,
+    /// The share of the node's memory budget, if the cache is bounded by it.
+    memory: Option<CacheMemory>,
+}
+
+/// Bounds the length of a [`MultiConsumerLruCache`] by a share of the node's memory budget.
+#[derive(Debug)]
+struct CacheMemory {
+    consumer: MemoryConsumer,
+    /// The configured maximum number of entries.
+    max_len: usize,
+    /// The estimated size of an entry in bytes.
+    entry_size: usize,
 }
 
 impl<K, V, L, S> Debug for MultiConsumerLruCache<K, V, L, S>
@@ -90,7 +103,18 @@ where
     where
         L::KeyToInsert<'a>: Hash + PartialEq<K>,
     {
-        self.cache.insert(key, value)
+        let inserted = self.cache.insert(key, value);
+        if let Some(memory) = &self.memory {
+            // evict the oldest entries if the cache exceeds its share of the memory budget
+            let max_len = memory.consumer.scale(memory.max_len);
+            while self.cache.len() > max_len {
+                if self.cache.pop_oldest().is_none() {
+                    break
+                }
+            }
+            memory.consumer.set_usage(self.cache.len().saturating_mul(memory.entry_size));
+        }
+        inserted
     }
 
     /// Update metrics for the inner cache.
@@ -110,6 +134,24 @@ where
             cache: LruMap::new(ByLength::new(max_len)),
             queued: Default::default(),
             metrics: CacheMetrics::new_with_labels(&[("cache", cache_id.to_string())]),
+            memory: None,
         }
     }
+
+    /// Bounds the cache by the given memory budget, registered as the given consumer with the
+    /// estimated size of an entry in bytes.
+    pub fn with_memory_budget(
+        mut self,
+        memory_budget: &MemoryBudget,
+        name: &'static str,
+        entry_size: usize,
+    ) -> Self {
+        let max_len = self.cache.limiter().max_length() as usize;
+        self.memory = Some(CacheMemory {
+            consumer: memory_budget.register(name, max_len.saturating_mul(entry_size)),
+            max_len,
+            entry_size,
+        });
+        self
+    }
 }
@@ -6,7 +6,7 @@ pub use api::{AsEthApiError, FromEthApiError, FromEvmError, IntoEthApiError};
 use core::time::Duration;
 
 use alloy_eips::BlockId;
-use alloy_primitives::{Address, Bytes, U256};
+use alloy_primitives::{Address, Bytes, B256, U256};
 use alloy_rpc_types_eth::{error::EthRpcErrorCode, request::TransactionInputError, BlockError};
 use alloy_sol_types::decode_revert_reason;
 use reth_errors::RethError;
@@ -15,7 +15,7 @@ use reth_rpc_server_types::result::{
 };
 use reth_transaction_pool::error::{
     Eip4844PoolTransactionError, Eip7702PoolTransactionError, InvalidPoolTransactionError,
-    PoolError, PoolErrorKind, PoolTransactionError,
+    PoolError, PoolErrorKind, PoolTransactionError, SimulationPoolTransactionError,
 };
 use revm::primitives::{EVMError, ExecutionResult, HaltReason, InvalidTransaction, OutOfGasError};
 use revm_inspectors::tracing::MuxError;
@@ -34,6 +34,9 @@ impl ToRpcError for jsonrpsee_types::ErrorObject<'static> {
     }
 }
 
+/// The error code of [`EthApiError::TransactionConfirmationTimeout`].
+pub const TRANSACTION_CONFIRMATION_TIMEOUT_CODE: i32 = 4;
+
 /// Result alias
 pub type EthResult<T> = Result<T, EthApiError>;
 
@@ -140,6 +143,23 @@ pub enum EthApiError {
     /// Error thrown when tracing with a muxTracer fails
     #[error(transparent)]
     MuxTracerError(#[from] MuxError),
+    /// Thrown when a submitted transaction wasn't included in a block within the timeout
+    #[error("transaction {hash} was added to the pool but wasn't included within {duration:?}")]
+    TransactionConfirmationTimeout {
+        /// Hash of the transaction
+        hash: B256,
+        /// How long the inclusion was awaited
+        duration: Duration,
+    },
+    /// Thrown when a submitted transaction was removed from the pool before it was included in a
+    /// block
+    #[error("transaction {hash} was dropped from the pool: {reason}")]
+    TransactionDropped {
+        /// Hash of the transaction
+        hash: B256,
+        /// Why the transaction was dropped
+        reason: &'static str,
+    },
     /// Any other error
     #[error("{0}")]
     Other(Box<dyn ToRpcError>),
@@ -221,6 +241,16 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
             err @ EthApiError::TransactionInputError(_) => invalid_params_rpc_err(err.to_string()),
             EthApiError::Other(err) => err.to_rpc_error(),
             EthApiError::MuxTracerError(msg) => internal_rpc_err(msg.to_string()),
+            EthApiError::TransactionConfirmationTimeout { hash, .. } => rpc_err(
+                TRANSACTION_CONFIRMATION_TIMEOUT_CODE,
+                error.to_string(),
+                Some(hash.as_slice()),
+            ),
+            EthApiError::TransactionDropped { hash, .. } => rpc_err(
+                EthRpcErrorCode::TransactionRejected.code(),
+                error.to_string(),
+                Some(hash.as_slice()),
+            ),
         }
     }
 }
@@ -661,6 +691,9 @@ pub enum RpcPoolError {
     /// EIP-7702 related error
     #[error(transparent)]
     Eip7702(#[from] Eip7702PoolTransactionError),
+    /// Admission simulation related error
+    #[error(transparent)]
+    Simulation(#[from] SimulationPoolTransactionError),
     /// Thrown if a conflicting transaction type is already in the pool
     ///
     /// In other words, thrown if a transaction with the same sender that violates the exclusivity
@@ -713,6 +746,7 @@ impl From<InvalidPoolTransactionError> for RpcPoolError {
             InvalidPoolTransactionError::Other(err) => Self::PoolTransactionError(err),
             InvalidPoolTransactionError::Eip4844(err) => Self::Eip4844(err),
             InvalidPoolTransactionError::Eip7702(err) => Self::Eip7702(err),
+            InvalidPoolTransactionError::Simulation(err) => Self::Simulation(err),
             InvalidPoolTransactionError::Overdraft { cost, balance } => {
                 Self::Invalid(RpcInvalidTransactionError::InsufficientFunds { cost, balance })
             }
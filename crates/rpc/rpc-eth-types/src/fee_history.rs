@@ -72,8 +72,11 @@ impl FeeHistoryCache {
             .collect()
     }
 
-    /// Insert block data into the cache.
-    async fn insert_blocks<'a, I, H, B, R>(&self, blocks: I)
+    /// Insert block data into the cache and calculate the rewards for the predefined percentiles.
+    ///
+    /// Blocks that are older than the configured max number of blocks behind the newest cached
+    /// block are not kept.
+    pub async fn insert_blocks<'a, I, H, B, R>(&self, blocks: I)
     where
         H: BlockHeader + 'a,
         B: BlockBody,
@@ -102,21 +105,30 @@ impl FeeHistoryCache {
             entries.pop_first();
         }
 
-        if entries.len() == 0 {
-            self.inner.upper_bound.store(0, SeqCst);
-            self.inner.lower_bound.store(0, SeqCst);
-            return
+        if let Some((&upper_bound, _)) = entries.last_key_value() {
+            // also enforce proper lower bound in case we have gaps
+            let target_lower = upper_bound.saturating_sub(self.inner.config.max_blocks);
+            while entries.len() > 1 && *entries.first_key_value().unwrap().0 < target_lower {
+                entries.pop_first();
+            }
         }
 
-        let upper_bound = *entries.last_entry().expect("Contains at least one entry").key();
+        self.update_bounds(&entries);
+    }
 
-        // also enforce proper lower bound in case we have gaps
-        let target_lower = upper_bound.saturating_sub(self.inner.config.max_blocks);
-        while entries.len() > 1 && *entries.first_key_value().unwrap().0 < target_lower {
-            entries.pop_first();
-        }
+    /// Removes the entries of all blocks starting at the given block number.
+    ///
+    /// This is used to drop the entries of blocks that were reorged out.
+    async fn remove_blocks_from(&self, block_number: u64) {
+        let mut entries = self.inner.entries.write().await;
+        entries.split_off(&block_number);
+        self.update_bounds(&entries);
+    }
 
-        let lower_bound = *entries.first_entry().expect("Contains at least one entry").key();
+    /// Sets the bounds to the first and last block of the entries.
+    fn update_bounds(&self, entries: &BTreeMap<u64, FeeHistoryEntry>) {
+        let lower_bound = entries.first_key_value().map(|(&number, _)| number).unwrap_or_default();
+        let upper_bound = entries.last_key_value().map(|(&number, _)| number).unwrap_or_default();
         self.inner.upper_bound.store(upper_bound, SeqCst);
         self.inner.lower_bound.store(lower_bound, SeqCst);
     }
@@ -161,6 +173,25 @@ impl FeeHistoryCache {
         }
     }
 
+    /// Returns the cached entries of the blocks in the given range, keyed by block number.
+    ///
+    /// Unlike [`Self::get_history`], this also returns the cached entries if only a part of the
+    /// range is cached.
+    pub async fn get_entries(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> BTreeMap<u64, FeeHistoryEntry> {
+        if start_block > end_block {
+            return BTreeMap::new()
+        }
+        let entries = self.inner.entries.read().await;
+        entries
+            .range(start_block..=end_block)
+            .map(|(&number, entry)| (number, entry.clone()))
+            .collect()
+    }
+
     /// Generates predefined set of percentiles
     ///
     /// This returns 100 * resolution points
@@ -249,6 +280,10 @@ pub async fn fee_history_cache_new_blocks_task<St, Provider, N>(
                 };
 
                 let committed = event.committed();
+                if event.reverted().is_some() {
+                    // drop the entries of reorged out blocks that are not replaced by the new chain
+                    fee_history_cache.remove_blocks_from(committed.first().number()).await;
+                }
                 let (blocks, receipts): (Vec<_>, Vec<_>) = committed
                     .blocks_and_receipts()
                     .map(|(block, receipts)| {
@@ -407,3 +442,60 @@ impl FeeHistoryEntry {
         Some(calc_excess_blob_gas(self.excess_blob_gas?, self.blob_gas_used?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use reth_primitives::{BlockBody, Receipt, SealedHeader};
+
+    fn block(number: u64) -> SealedBlock {
+        let header = Header { number, gas_limit: 30_000_000, ..Default::default() };
+        SealedBlock::new(SealedHeader::seal(header), BlockBody::default())
+    }
+
+    async fn insert_range(cache: &FeeHistoryCache, range: std::ops::RangeInclusive<u64>) {
+        let blocks = range.map(block).collect::<Vec<_>>();
+        cache
+            .insert_blocks(blocks.iter().map(|block| (block, Arc::new(Vec::<Receipt>::new()))))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn get_partially_cached_entries() {
+        let cache = FeeHistoryCache::new(FeeHistoryCacheConfig::default());
+        insert_range(&cache, 10..=20).await;
+        assert_eq!((cache.lower_bound(), cache.upper_bound()), (10, 20));
+
+        assert!(cache.get_history(5, 15).await.is_none());
+        let entries = cache.get_entries(5, 15).await;
+        assert_eq!(entries.keys().copied().collect::<Vec<_>>(), (10..=15).collect::<Vec<_>>());
+        assert_eq!(entries[&10].rewards.len(), cache.predefined_percentiles().len());
+        assert!(cache.get_entries(15, 5).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn remove_reorged_blocks() {
+        let cache = FeeHistoryCache::new(FeeHistoryCacheConfig::default());
+        insert_range(&cache, 10..=20).await;
+
+        cache.remove_blocks_from(15).await;
+        assert_eq!((cache.lower_bound(), cache.upper_bound()), (10, 14));
+        assert!(cache.get_entries(15, 20).await.is_empty());
+
+        cache.remove_blocks_from(0).await;
+        assert_eq!((cache.lower_bound(), cache.upper_bound()), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn evict_blocks_outside_of_window() {
+        let config = FeeHistoryCacheConfig { max_blocks: 5, ..Default::default() };
+        let cache = FeeHistoryCache::new(config);
+        insert_range(&cache, 10..=20).await;
+        assert_eq!((cache.lower_bound(), cache.upper_bound()), (16, 20));
+
+        // a block that is too old is not kept
+        insert_range(&cache, 3..=3).await;
+        assert_eq!((cache.lower_bound(), cache.upper_bound()), (16, 20));
+    }
+}
@@ -12,7 +12,8 @@ use reth_rpc_server_types::{
     constants,
     constants::gas_oracle::{
         DEFAULT_GAS_PRICE_BLOCKS, DEFAULT_GAS_PRICE_PERCENTILE, DEFAULT_IGNORE_GAS_PRICE,
-        DEFAULT_MAX_GAS_PRICE, MAX_HEADER_HISTORY, SAMPLE_NUMBER,
+        DEFAULT_MAX_GAS_PRICE, LARGE_TRANSACTION_GAS_LIMIT_PERCENT, MAX_HEADER_HISTORY,
+        SAMPLE_NUMBER,
     },
 };
 use reth_storage_api::{BlockReader, BlockReaderIdExt};
@@ -68,6 +69,36 @@ impl Default for GasPriceOracleConfig {
     }
 }
 
+/// The kind of transaction a priority fee is suggested for.
+///
+/// Blob transactions and transactions that use a large share of the block gas limit compete for
+/// inclusion differently than regular transactions, so their tips are sampled separately.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PriorityFeeKind {
+    /// Any transaction, sampled from all transactions of a block.
+    #[default]
+    Regular,
+    /// Blob-carrying EIP-4844 transactions.
+    Blob,
+    /// Transactions with a gas limit of at least
+    /// [`LARGE_TRANSACTION_GAS_LIMIT_PERCENT`] of the block gas limit.
+    Large,
+}
+
+impl PriorityFeeKind {
+    /// All kinds, in the order of their index.
+    const ALL: [Self; 3] = [Self::Regular, Self::Blob, Self::Large];
+
+    const fn index(&self) -> usize {
+        match self {
+            Self::Regular => 0,
+            Self::Blob => 1,
+            Self::Large => 2,
+        }
+    }
+}
+
 /// Calculates a gas price depending on recent blocks.
 #[derive(Debug)]
 pub struct GasPriceOracle<Provider>
@@ -107,7 +138,7 @@ where
         // this is the number of blocks that we will cache the values for
         let cached_values = (oracle_config.blocks * 5).max(oracle_config.max_block_history as u32);
         let inner = Mutex::new(GasPriceOracleInner {
-            last_price: Default::default(),
+            last_prices: Default::default(),
             lowest_effective_tip_cache: EffectiveTipLruCache(LruMap::new(ByLength::new(
                 cached_values,
             ))),
@@ -123,6 +154,15 @@ where
 
     /// Suggests a gas price estimate based on recent blocks, using the configured percentile.
     pub async fn suggest_tip_cap(&self) -> EthResult<U256> {
+        self.suggest_tip_cap_for(PriorityFeeKind::Regular).await
+    }
+
+    /// Suggests a gas price estimate for the given kind of transaction based on recent blocks,
+    /// using the configured percentile.
+    ///
+    /// Only the transactions of the given kind are sampled, blocks without such transactions are
+    /// treated like empty blocks.
+    pub async fn suggest_tip_cap_for(&self, kind: PriorityFeeKind) -> EthResult<U256> {
         let header = self
             .provider
             .sealed_header_by_number_or_tag(BlockNumberOrTag::Latest)?
@@ -131,8 +171,9 @@ where
         let mut inner = self.inner.lock().await;
 
         // if we have stored a last price, then we check whether or not it was for the same head
-        if inner.last_price.block_hash == header.hash() {
-            return Ok(inner.last_price.price)
+        let last_price = inner.last_prices[kind.index()].clone();
+        if last_price.block_hash == header.hash() {
+            return Ok(last_price.price)
         }
 
         // if all responses are empty, then we can return a maximum of 2*check_block blocks' worth
@@ -153,23 +194,23 @@ where
 
         for _ in 0..max_blocks {
             // Check if current hash is in cache
-            let (parent_hash, block_values) =
-                if let Some(vals) = inner.lowest_effective_tip_cache.get(&current_hash) {
-                    vals.to_owned()
-                } else {
-                    // Otherwise we fetch it using get_block_values
-                    let (parent_hash, block_values) = self
-                        .get_block_values(current_hash, SAMPLE_NUMBER)
-                        .await?
-                        .ok_or(EthApiError::HeaderNotFound(current_hash.into()))?;
-                    inner
-                        .lowest_effective_tip_cache
-                        .insert(current_hash, (parent_hash, block_values.clone()));
-                    (parent_hash, block_values)
-                };
+            let (parent_hash, block_values) = if let Some(vals) =
+                inner.lowest_effective_tip_cache.get(&current_hash)
+            {
+                (vals.0, vals.1.get(kind).to_vec())
+            } else {
+                // Otherwise we fetch it using get_block_values
+                let (parent_hash, block_values) = self
+                    .get_block_values(current_hash, SAMPLE_NUMBER)
+                    .await?
+                    .ok_or(EthApiError::HeaderNotFound(current_hash.into()))?;
+                let values = block_values.get(kind).to_vec();
+                inner.lowest_effective_tip_cache.insert(current_hash, (parent_hash, block_values));
+                (parent_hash, values)
+            };
 
             if block_values.is_empty() {
-                results.push(U256::from(inner.last_price.price));
+                results.push(last_price.price);
             } else {
                 results.extend(block_values);
                 populated_blocks += 1;
@@ -185,7 +226,7 @@ where
 
         // sort results then take the configured percentile result
         let mut price = if results.is_empty() {
-            inner.last_price.price
+            last_price.price
         } else {
             results.sort_unstable();
             *results.get((results.len() - 1) * self.oracle_config.percentile as usize / 100).expect(
@@ -200,14 +241,14 @@ where
             }
         }
 
-        inner.last_price = GasPriceOracleResult { block_hash: header.hash(), price };
+        inner.last_prices[kind.index()] = GasPriceOracleResult { block_hash: header.hash(), price };
 
         Ok(price)
     }
 
-    /// Get the `limit` lowest effective tip values for the given block. If the oracle has a
-    /// configured `ignore_price` threshold, then tip values under that threshold will be ignored
-    /// before returning a result.
+    /// Get the `limit` lowest effective tip values of each [`PriorityFeeKind`] for the given block.
+    /// If the oracle has a configured `ignore_price` threshold, then tip values under that
+    /// threshold will be ignored before returning a result.
     ///
     /// If the block cannot be found, then this will return `None`.
     ///
@@ -216,7 +257,7 @@ where
         &self,
         block_hash: B256,
         limit: usize,
-    ) -> EthResult<Option<(B256, Vec<U256>)>> {
+    ) -> EthResult<Option<(B256, BlockTipValues)>> {
         // check the cache (this will hit the disk if the block is not cached)
         let block = match self.cache.get_sealed_block_with_senders(block_hash).await? {
             Some(block) => block,
@@ -225,6 +266,7 @@ where
 
         let base_fee_per_gas = block.base_fee_per_gas();
         let parent_hash = block.parent_hash();
+        let large_gas_limit = block.gas_limit() * LARGE_TRANSACTION_GAS_LIMIT_PERCENT / 100;
 
         // sort the functions by ascending effective tip first
         let sorted_transactions = block.body.transactions().iter().sorted_by_cached_key(|tx| {
@@ -235,7 +277,7 @@ where
             }
         });
 
-        let mut prices = Vec::with_capacity(limit);
+        let mut prices = BlockTipValues::default();
 
         for tx in sorted_transactions {
             let effective_tip = if let Some(base_fee) = base_fee_per_gas {
@@ -260,10 +302,25 @@ where
 
             // a `None` effective_gas_tip represents a transaction where the max_fee_per_gas is
             // less than the base fee which would be invalid
-            prices.push(U256::from(effective_tip.ok_or(RpcInvalidTransactionError::FeeCapTooLow)?));
+            let effective_tip =
+                U256::from(effective_tip.ok_or(RpcInvalidTransactionError::FeeCapTooLow)?);
+
+            let is_blob = tx.blob_versioned_hashes().is_some();
+            let is_large = tx.gas_limit() >= large_gas_limit;
+            for kind in PriorityFeeKind::ALL {
+                let matches = match kind {
+                    PriorityFeeKind::Regular => true,
+                    PriorityFeeKind::Blob => is_blob,
+                    PriorityFeeKind::Large => is_large,
+                };
+                let values = &mut prices.0[kind.index()];
+                if matches && values.len() < limit {
+                    values.push(effective_tip);
+                }
+            }
 
             // we have enough entries
-            if prices.len() >= limit {
+            if prices.0.iter().all(|values| values.len() >= limit) {
                 break
             }
         }
@@ -275,13 +332,25 @@ where
 /// Container type for mutable inner state of the [`GasPriceOracle`]
 #[derive(Debug)]
 struct GasPriceOracleInner {
-    last_price: GasPriceOracleResult,
+    /// The last price of each [`PriorityFeeKind`]
+    last_prices: [GasPriceOracleResult; 3],
     lowest_effective_tip_cache: EffectiveTipLruCache,
 }
 
+/// The lowest effective tip values of a block for each [`PriorityFeeKind`].
+#[derive(Debug, Clone, Default)]
+pub struct BlockTipValues([Vec<U256>; 3]);
+
+impl BlockTipValues {
+    /// Returns the tip values of the given kind of transactions.
+    pub fn get(&self, kind: PriorityFeeKind) -> &[U256] {
+        &self.0[kind.index()]
+    }
+}
+
 /// Wrapper struct for `LruMap`
 #[derive(Deref, DerefMut)]
-pub struct EffectiveTipLruCache(LruMap<B256, (B256, Vec<U256>), ByLength>);
+pub struct EffectiveTipLruCache(LruMap<B256, (B256, BlockTipValues), ByLength>);
 
 impl Debug for EffectiveTipLruCache {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
@@ -331,4 +400,12 @@ mod tests {
     fn ignore_price_sanity() {
         assert_eq!(DEFAULT_IGNORE_GAS_PRICE, U256::from(2u64));
     }
+
+    #[test]
+    fn priority_fee_kind_serde() {
+        let kind: PriorityFeeKind = serde_json::from_str(r#""blob""#).unwrap();
+        assert_eq!(kind, PriorityFeeKind::Blob);
+        assert_eq!(serde_json::to_string(&PriorityFeeKind::Large).unwrap(), r#""large""#);
+        assert_eq!(PriorityFeeKind::default(), PriorityFeeKind::Regular);
+    }
 }
@@ -16,6 +16,8 @@ pub mod gas_oracle;
 pub mod id_provider;
 pub mod logs_utils;
 pub mod pending_block;
+pub mod proof;
+pub mod pubsub;
 pub mod receipt;
 pub mod revm_utils;
 pub mod simulate;
@@ -23,7 +25,7 @@ pub mod transaction;
 pub mod utils;
 
 pub use builder::{
-    config::{EthConfig, EthFilterConfig},
+    config::{EthConfig, EthFilterConfig, SubscriptionResumeConfig, TraceFilterConfig},
     ctx::EthApiBuilderCtx,
 };
 pub use cache::{
@@ -33,9 +35,15 @@ pub use cache::{
 pub use error::{EthApiError, EthResult, RevertError, RpcInvalidTransactionError, SignError};
 pub use fee_history::{FeeHistoryCache, FeeHistoryCacheConfig, FeeHistoryEntry};
 pub use gas_oracle::{
-    GasCap, GasPriceOracle, GasPriceOracleConfig, GasPriceOracleResult, RPC_DEFAULT_GAS_CAP,
+    GasCap, GasPriceOracle, GasPriceOracleConfig, GasPriceOracleResult, PriorityFeeKind,
+    RPC_DEFAULT_GAS_CAP,
 };
 pub use id_provider::EthSubscriptionIdProvider;
 pub use pending_block::{PendingBlock, PendingBlockEnv, PendingBlockEnvOrigin};
+pub use proof::AccountProofRequest;
+pub use pubsub::{
+    PendingTransactionFilter, PubSubParams, ResumableSubscriptionItem, SubscriptionCursor,
+    SubscriptionOptions, SubscriptionTarget,
+};
 pub use receipt::EthReceiptBuilder;
 pub use transaction::TransactionSource;
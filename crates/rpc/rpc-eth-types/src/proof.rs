@@ -0,0 +1,37 @@
+//! Types for batched state proofs.
+
+use alloy_primitives::Address;
+use alloy_serde::JsonStorageKey;
+use serde::{Deserialize, Serialize};
+
+/// An account and its storage keys to prove with `eth_getProofs`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProofRequest {
+    /// The address of the account.
+    pub address: Address,
+    /// The storage keys of the account to prove.
+    #[serde(default)]
+    pub storage_keys: Vec<JsonStorageKey>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    #[test]
+    fn deserialize_account_proof_request() {
+        let request: AccountProofRequest = serde_json::from_str(
+            r#"{"address":"0x0000000000000000000000000000000000000001","storageKeys":["0x01"]}"#,
+        )
+        .unwrap();
+        assert_eq!(request.address, Address::with_last_byte(1));
+        assert_eq!(request.storage_keys[0].as_b256(), B256::with_last_byte(1));
+
+        let request: AccountProofRequest =
+            serde_json::from_str(r#"{"address":"0x0000000000000000000000000000000000000001"}"#)
+                .unwrap();
+        assert!(request.storage_keys.is_empty());
+    }
+}
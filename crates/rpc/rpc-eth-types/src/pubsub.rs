@@ -0,0 +1,264 @@
+//! Parameters of `eth_subscribe`.
+
+use alloy_primitives::{Address, FixedBytes, B128, U256};
+use alloy_rpc_types_eth::{
+    pubsub::{Params, SubscriptionKind},
+    Filter,
+};
+use reth_transaction_pool::PoolTransaction;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The parameters of `eth_subscribe`.
+///
+/// This extends [`Params`] with a [`PendingTransactionFilter`] for `newPendingTransactions`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PubSubParams {
+    /// No parameters passed.
+    #[default]
+    None,
+    /// Log parameters.
+    Logs(Box<Filter>),
+    /// Boolean parameter for new pending transactions.
+    Bool(bool),
+    /// Filter criteria for new pending transactions.
+    PendingTransactions(PendingTransactionFilter),
+}
+
+impl From<Params> for PubSubParams {
+    fn from(params: Params) -> Self {
+        match params {
+            Params::None => Self::None,
+            Params::Logs(filter) => Self::Logs(filter),
+            Params::Bool(full) => Self::Bool(full),
+        }
+    }
+}
+
+impl From<PendingTransactionFilter> for PubSubParams {
+    fn from(filter: PendingTransactionFilter) -> Self {
+        Self::PendingTransactions(filter)
+    }
+}
+
+impl Serialize for PubSubParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::None => (&[] as &[serde_json::Value]).serialize(serializer),
+            Self::Logs(logs) => logs.serialize(serializer),
+            Self::Bool(full) => full.serialize(serializer),
+            Self::PendingTransactions(filter) => filter.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for PubSubParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let v = serde_json::Value::deserialize(deserializer)?;
+
+        // the pending transaction filter rejects unknown fields, so log filters are not mistaken
+        // for it, an empty object is handled by the subscription kind
+        if v.is_object() {
+            if let Ok(filter) = serde_json::from_value::<PendingTransactionFilter>(v.clone()) {
+                return Ok(filter.into())
+            }
+        }
+
+        serde_json::from_value::<Params>(v)
+            .map(Into::into)
+            .map_err(|e| D::Error::custom(format!("Invalid Pub-Sub parameters: {e}")))
+    }
+}
+
+/// Filter criteria for `newPendingTransactions` subscriptions that are evaluated by the server.
+///
+/// All set criteria must match.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PendingTransactionFilter {
+    /// Only transactions sent by one of these addresses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<HashSet<Address>>,
+    /// Only transactions sent to one of these addresses, this excludes contract creations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<HashSet<Address>>,
+    /// Only transactions with at least this max fee per gas, or gas price for legacy
+    /// transactions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_gas_price: Option<U256>,
+    /// Only EIP-4844 blob transactions.
+    #[serde(default)]
+    pub blob_only: bool,
+    /// Whether to return full transaction objects instead of hashes.
+    #[serde(default)]
+    pub full_transactions: bool,
+}
+
+impl PendingTransactionFilter {
+    /// Returns `true` if the filter has no criteria, i.e. matches all transactions.
+    pub fn is_empty(&self) -> bool {
+        self.from.is_none() && self.to.is_none() && self.min_gas_price.is_none() && !self.blob_only
+    }
+
+    /// Returns `true` if the transaction matches all criteria.
+    pub fn matches<T: PoolTransaction>(&self, tx: &T) -> bool {
+        if self.from.as_ref().is_some_and(|from| !from.contains(&tx.sender())) {
+            return false
+        }
+        if let Some(to) = &self.to {
+            if !tx.to().is_some_and(|tx_to| to.contains(&tx_to)) {
+                return false
+            }
+        }
+        if self.min_gas_price.is_some_and(|min| U256::from(tx.max_fee_per_gas()) < min) {
+            return false
+        }
+        !self.blob_only || tx.is_eip4844()
+    }
+}
+
+/// The first parameter of `eth_subscribe`, or the cursor of `eth_resubscribe`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SubscriptionTarget {
+    /// Creates a new subscription of this kind.
+    Kind(SubscriptionKind),
+    /// Resumes the resumable subscription after the event of the cursor.
+    Resume(SubscriptionCursor),
+}
+
+/// Options of `eth_subscribe`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SubscriptionOptions {
+    /// Whether the subscription can be resumed with `eth_resubscribe` after a disconnect.
+    ///
+    /// Events of resumable subscriptions are sent as [`ResumableSubscriptionItem`]s.
+    #[serde(default)]
+    pub resumable: bool,
+}
+
+/// Identifies an event of a resumable subscription.
+///
+/// This is serialized as an opaque hex string of the subscription id followed by the sequence
+/// number of the event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionCursor {
+    /// The id of the resumable subscription, which is independent of the connection.
+    pub id: B128,
+    /// The sequence number of the event within the subscription, starting at 0.
+    pub sequence: u64,
+}
+
+impl SubscriptionCursor {
+    fn to_bytes(self) -> FixedBytes<24> {
+        let mut bytes = FixedBytes::ZERO;
+        bytes[..16].copy_from_slice(self.id.as_slice());
+        bytes[16..].copy_from_slice(&self.sequence.to_be_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: FixedBytes<24>) -> Self {
+        let mut sequence = [0; 8];
+        sequence.copy_from_slice(&bytes[16..]);
+        Self { id: B128::from_slice(&bytes[..16]), sequence: u64::from_be_bytes(sequence) }
+    }
+}
+
+impl Serialize for SubscriptionCursor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_bytes().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SubscriptionCursor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        FixedBytes::<24>::deserialize(deserializer).map(Self::from_bytes)
+    }
+}
+
+/// An event of a resumable subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResumableSubscriptionItem<T> {
+    /// The cursor to resume the subscription after this event.
+    pub cursor: SubscriptionCursor,
+    /// The event.
+    pub item: T,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_transaction_pool::test_utils::MockTransaction;
+
+    #[test]
+    fn deserialize_params() {
+        let params: PubSubParams = serde_json::from_str("true").unwrap();
+        assert_eq!(params, PubSubParams::Bool(true));
+
+        let params: PubSubParams =
+            serde_json::from_str(r#"{"address":"0x0000000000000000000000000000000000000001"}"#)
+                .unwrap();
+        assert!(matches!(params, PubSubParams::Logs(_)));
+
+        let params: PubSubParams = serde_json::from_str(
+            r#"{"from":["0x0000000000000000000000000000000000000001"],"minGasPrice":"0x10","blobOnly":true}"#,
+        )
+        .unwrap();
+        let PubSubParams::PendingTransactions(filter) = params else { panic!("{params:?}") };
+        assert_eq!(filter.min_gas_price, Some(U256::from(16)));
+        assert!(filter.blob_only);
+        assert!(!filter.full_transactions);
+    }
+
+    #[test]
+    fn deserialize_subscription_target() {
+        let target: SubscriptionTarget = serde_json::from_str(r#""newHeads""#).unwrap();
+        assert_eq!(target, SubscriptionTarget::Kind(SubscriptionKind::NewHeads));
+
+        let cursor = SubscriptionCursor { id: B128::repeat_byte(1), sequence: 42 };
+        let json = serde_json::to_string(&cursor).unwrap();
+        assert_eq!(json, r#""0x01010101010101010101010101010101000000000000002a""#);
+        let target: SubscriptionTarget = serde_json::from_str(&json).unwrap();
+        assert_eq!(target, SubscriptionTarget::Resume(cursor));
+    }
+
+    #[test]
+    fn match_pending_transactions() {
+        let tx = MockTransaction::eip1559().with_max_fee(100);
+        assert!(PendingTransactionFilter::default().matches(&tx));
+
+        let filter = PendingTransactionFilter {
+            from: Some(HashSet::from([tx.sender()])),
+            ..Default::default()
+        };
+        assert!(filter.matches(&tx));
+        let filter = PendingTransactionFilter {
+            to: Some(HashSet::from([Address::ZERO])),
+            ..Default::default()
+        };
+        assert_eq!(filter.matches(&tx), tx.to() == Some(Address::ZERO));
+
+        let filter =
+            PendingTransactionFilter { min_gas_price: Some(U256::from(101)), ..Default::default() };
+        assert!(!filter.matches(&tx));
+
+        let filter = PendingTransactionFilter { blob_only: true, ..Default::default() };
+        assert!(!filter.matches(&tx));
+        assert!(filter.matches(&MockTransaction::eip4844()));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::AuthValidator;
+use http::{HeaderMap, HeaderName, Response, StatusCode};
+use jsonrpsee_http_client::{HttpBody, HttpResponse};
+use std::{collections::HashSet, sync::Arc};
+
+/// Implements API key validation and integrates to an Http [`AuthLayer`][crate::AuthLayer] by
+/// implementing the [`AuthValidator`] trait.
+///
+/// Requests are rejected if the configured header is missing or doesn't carry one of the known
+/// API keys.
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuthValidator {
+    header: HeaderName,
+    api_keys: Arc<HashSet<String>>,
+}
+
+impl ApiKeyAuthValidator {
+    /// Creates a new instance of [`ApiKeyAuthValidator`] that accepts the given API keys in the
+    /// given header.
+    pub fn new(header: HeaderName, api_keys: impl IntoIterator<Item = String>) -> Self {
+        Self { header, api_keys: Arc::new(api_keys.into_iter().collect()) }
+    }
+}
+
+impl AuthValidator for ApiKeyAuthValidator {
+    fn validate(&self, headers: &HeaderMap) -> Result<(), HttpResponse> {
+        let api_key = headers.get(&self.header).and_then(|value| value.to_str().ok());
+        if api_key.is_some_and(|api_key| self.api_keys.contains(api_key)) {
+            Ok(())
+        } else {
+            Err(Response::builder()
+                .status(StatusCode::UNAUTHORIZED)
+                .body(HttpBody::new("missing or unknown API key".to_string()))
+                .expect("This should never happen"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_api_key() {
+        let header = HeaderName::from_static("x-api-key");
+        let validator = ApiKeyAuthValidator::new(header.clone(), ["tenant".to_string()]);
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(validator.validate(&headers).unwrap_err().status(), StatusCode::UNAUTHORIZED);
+
+        headers.insert(header.clone(), "other".parse().unwrap());
+        assert!(validator.validate(&headers).is_err());
+
+        headers.insert(header, "tenant".parse().unwrap());
+        assert!(validator.validate(&headers).is_ok());
+    }
+}
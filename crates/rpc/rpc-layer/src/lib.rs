@@ -11,6 +11,7 @@
 use http::HeaderMap;
 use jsonrpsee_http_client::HttpResponse;
 
+mod api_key_validator;
 mod auth_client_layer;
 mod auth_layer;
 mod compression_layer;
@@ -22,6 +23,7 @@ pub use compression_layer::CompressionLayer;
 // Export alloy JWT types
 pub use alloy_rpc_types_engine::{Claims, JwtError, JwtSecret};
 
+pub use api_key_validator::ApiKeyAuthValidator;
 pub use auth_client_layer::{secret_to_bearer_header, AuthClientLayer, AuthClientService};
 pub use auth_layer::AuthLayer;
 pub use jwt_validator::JwtAuthValidator;
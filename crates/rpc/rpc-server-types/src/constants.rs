@@ -15,6 +15,12 @@ pub const DEFAULT_MAX_BLOCKS_PER_FILTER: u64 = 100_000;
 /// The default maximum of logs in a single response.
 pub const DEFAULT_MAX_LOGS_PER_RESPONSE: usize = 20_000;
 
+/// The default maximum block range of a `trace_filter` request.
+pub const DEFAULT_MAX_TRACE_FILTER_BLOCKS: u64 = 100;
+
+/// The default maximum number of transactions a `trace_filter` request may execute.
+pub const DEFAULT_MAX_TRACE_FILTER_TRANSACTIONS: u64 = 25_000;
+
 /// The default maximum number tracing requests we're allowing concurrently.
 /// Tracing is mostly CPU bound so we're limiting the number of concurrent requests to something
 /// lower that the number of cores, in order to minimize the impact on the rest of the system.
@@ -29,6 +35,45 @@ pub fn default_max_tracing_requests() -> usize {
 /// The default number of getproof calls we are allowing to run concurrently.
 pub const DEFAULT_PROOF_PERMITS: usize = 25;
 
+/// The default number of days chain statistics of the `stats` namespace are retained.
+pub const DEFAULT_STATS_RETENTION_DAYS: u64 = 7;
+
+/// The default maximum size in megabytes of a block fingerprints file of the `fingerprint`
+/// namespace before it's rotated.
+pub const DEFAULT_FINGERPRINTS_MAX_FILE_SIZE_MB: u64 = 100;
+
+/// The default number of seconds after which a filter that has not been polled is uninstalled.
+pub const DEFAULT_STALE_FILTER_TTL_SECS: u64 = 5 * 60;
+
+/// The default number of seconds a resumable subscription is kept after its client disconnected.
+pub const DEFAULT_SUBSCRIPTION_RESUME_WINDOW_SECS: u64 = 60;
+
+/// The default number of buffered events of resumable `newHeads` subscriptions.
+pub const DEFAULT_SUBSCRIPTION_RESUME_NEW_HEADS: usize = 128;
+
+/// The default number of buffered events of resumable `logs` subscriptions.
+pub const DEFAULT_SUBSCRIPTION_RESUME_LOGS: usize = 10_000;
+
+/// The default number of buffered events of resumable `newPendingTransactions` subscriptions.
+///
+/// Pending transaction subscriptions are not resumable by default, because of their volume.
+pub const DEFAULT_SUBSCRIPTION_RESUME_PENDING_TRANSACTIONS: usize = 0;
+
+/// The default header that carries the API key for the accounting of RPC calls.
+pub const DEFAULT_API_KEY_HEADER: &str = "x-api-key";
+
+/// The default number of seconds in which the quota of an API key can be used.
+pub const DEFAULT_API_KEY_QUOTA_WINDOW_SECS: u64 = 24 * 60 * 60;
+
+/// The default number of seconds after which the rate limits file is checked for changes.
+pub const DEFAULT_RATE_LIMITS_RELOAD_INTERVAL_SECS: u64 = 5;
+
+/// The default number of concurrent heavy tracing calls, e.g. `trace_block` of a full block.
+pub const DEFAULT_TRACE_HEAVY_CONCURRENCY: usize = 1;
+
+/// The default number of tracing calls per cost class that can wait for execution.
+pub const DEFAULT_TRACE_MAX_QUEUED: usize = 32;
+
 /// The default IPC endpoint
 #[cfg(windows)]
 pub const DEFAULT_IPC_ENDPOINT: &str = r"\\.\pipe\reth.ipc";
@@ -55,6 +100,9 @@ pub const DEFAULT_ETH_PROOF_WINDOW: u64 = 0;
 /// second block time, and a month on a 2 second block time.
 pub const MAX_ETH_PROOF_WINDOW: u64 = 28 * 24 * 60 * 60 / 2;
 
+/// The maximum number of accounts that can be proven with a single `eth_getProofs` request.
+pub const MAX_PROOFS_ACCOUNTS: usize = 1_000;
+
 /// GPO specific constants
 pub mod gas_oracle {
     use alloy_primitives::U256;
@@ -71,6 +119,10 @@ pub mod gas_oracle {
     /// The percentile of gas prices to use for the estimate
     pub const DEFAULT_GAS_PRICE_PERCENTILE: u32 = 60;
 
+    /// The share of the block gas limit, in percent, from which on a transaction is considered
+    /// large by the gas price oracle
+    pub const LARGE_TRANSACTION_GAS_LIMIT_PERCENT: u64 = 50;
+
     /// Maximum transaction priority fee (or gas price before London Fork) to be recommended by the
     /// gas price oracle
     pub const DEFAULT_MAX_GAS_PRICE: U256 = U256::from_limbs([500_000_000_000u64, 0, 0, 0]);
@@ -271,6 +271,12 @@ pub enum RethRpcModule {
     Flashbots,
     /// `miner_` module
     Miner,
+    /// `stats_` module
+    Stats,
+    /// `fingerprint_` module
+    Fingerprint,
+    /// `mev_` module
+    Mev,
 }
 
 // === impl RethRpcModule ===
@@ -320,6 +326,9 @@ impl FromStr for RethRpcModule {
             "reth" => Self::Reth,
             "ots" => Self::Ots,
             "flashbots" => Self::Flashbots,
+            "stats" => Self::Stats,
+            "fingerprint" => Self::Fingerprint,
+            "mev" => Self::Mev,
             _ => return Err(ParseError::VariantNotFound),
         })
     }
@@ -65,6 +65,14 @@ pub trait TransactionCompat<T = TransactionSigned>:
     // todo: remove in favour of using constructor on `TransactionResponse` or similar
     // <https://github.com/alloy-rs/alloy/issues/1315>.
     fn otterscan_api_truncate_input(tx: &mut Self::Transaction);
+
+    /// Converts the transaction into the Ethereum transaction type returned by
+    /// `ots_searchTransactionsBefore` and `ots_searchTransactionsAfter`.
+    ///
+    /// Returns `None` if the transaction has no Ethereum representation.
+    fn otterscan_api_search_transaction(
+        tx: Self::Transaction,
+    ) -> Option<alloy_rpc_types_eth::Transaction>;
 }
 
 /// Convert [`RecoveredTx`] to [`TransactionRequest`]
@@ -0,0 +1,511 @@
+//! Registry of event ABIs that is used to decode logs, see `reth_getDecodedLogs`.
+
+use alloy_dyn_abi::{DynSolValue, EventExt};
+use alloy_json_abi::{Event, EventParam, JsonAbi};
+use alloy_primitives::{hex, Address, Log, B256};
+use futures::{stream, StreamExt};
+use parking_lot::Mutex;
+use reth_rpc_api::{DecodedEvent, DecodedParam};
+use schnellru::{ByLength, LruMap};
+use serde::Deserialize;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    hash::Hash,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tracing::{debug, trace};
+
+/// The default URL of the event signature database, see <https://www.4byte.directory>.
+pub const DEFAULT_FOUR_BYTE_URL: &str = "https://www.4byte.directory";
+
+/// The default URL of the Sourcify server, see <https://sourcify.dev>.
+pub const DEFAULT_SOURCIFY_URL: &str = "https://sourcify.dev/server";
+
+/// The default number of event signatures and contract ABIs that are cached by an [`AbiLookup`].
+pub const DEFAULT_ABI_LOOKUP_CACHE_SIZE: u32 = 10_000;
+
+/// How long a failed remote lookup is cached before it is retried.
+const FAILED_LOOKUP_RETRY_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// The maximum number of remote lookups that are in flight at once while decoding logs.
+const MAX_CONCURRENT_LOOKUPS: usize = 16;
+
+/// Errors when loading ABIs into an [`AbiRegistry`].
+#[derive(Debug, thiserror::Error)]
+pub enum AbiRegistryError {
+    /// Failed to read an ABI file or directory.
+    #[error("failed to read {path}: {err}")]
+    Io {
+        /// The path that could not be read.
+        path: PathBuf,
+        /// The underlying error.
+        err: std::io::Error,
+    },
+    /// An ABI file is neither an ABI nor a build artifact that contains one.
+    #[error("invalid ABI in {path}: {err}")]
+    InvalidAbi {
+        /// The path of the file.
+        path: PathBuf,
+        /// The underlying error.
+        err: serde_json::Error,
+    },
+}
+
+/// Registry of event ABIs that is used to decode logs.
+///
+/// Events are resolved by the first topic of a log, the event selector. User-provided ABIs take
+/// precedence, if none matches and an [`AbiLookup`] is configured, the verified ABI of the
+/// emitting contract is looked up on Sourcify and then the event signature on 4byte.
+#[derive(Debug, Default)]
+pub struct AbiRegistry {
+    /// User-provided events by their selector.
+    events: HashMap<B256, Vec<Event>>,
+    /// Remote lookup of unknown events, if enabled.
+    lookup: Option<AbiLookup>,
+}
+
+impl AbiRegistry {
+    /// Creates an empty registry without remote lookups.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables remote lookups of unknown events.
+    pub fn with_lookup(mut self, lookup: AbiLookup) -> Self {
+        self.lookup = Some(lookup);
+        self
+    }
+
+    /// Returns `true` if there are no user-provided events and remote lookups are disabled.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty() && self.lookup.is_none()
+    }
+
+    /// Adds all events of the ABI.
+    pub fn add_abi(&mut self, abi: &JsonAbi) {
+        for event in abi.events() {
+            let events = self.events.entry(event.selector()).or_default();
+            if !events.contains(event) {
+                events.push(event.clone());
+            }
+        }
+    }
+
+    /// Adds the events of an ABI file.
+    ///
+    /// The file is either a JSON ABI or a build artifact with an `abi` field, as produced by
+    /// Foundry or Hardhat.
+    pub fn add_abi_file(&mut self, path: &Path) -> Result<(), AbiRegistryError> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum AbiFile {
+            Abi(JsonAbi),
+            Artifact { abi: JsonAbi },
+        }
+
+        let contents = std::fs::read(path)
+            .map_err(|err| AbiRegistryError::Io { path: path.to_path_buf(), err })?;
+        let abi = match serde_json::from_slice(&contents)
+            .map_err(|err| AbiRegistryError::InvalidAbi { path: path.to_path_buf(), err })?
+        {
+            AbiFile::Abi(abi) | AbiFile::Artifact { abi } => abi,
+        };
+        self.add_abi(&abi);
+        Ok(())
+    }
+
+    /// Adds the events of all `.json` files in the directory, see [`Self::add_abi_file`].
+    ///
+    /// Returns the number of loaded files.
+    pub fn add_abi_dir(&mut self, dir: &Path) -> Result<usize, AbiRegistryError> {
+        let io_err = |err| AbiRegistryError::Io { path: dir.to_path_buf(), err };
+        let mut loaded = 0;
+        for entry in std::fs::read_dir(dir).map_err(io_err)? {
+            let path = entry.map_err(io_err)?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+                self.add_abi_file(&path)?;
+                loaded += 1;
+            }
+        }
+        Ok(loaded)
+    }
+
+    /// Decodes the events of logs that were emitted on the given chain.
+    ///
+    /// The decoded event of a log is `None` if the log has no topics or no known event matches it.
+    /// Remote lookups are done concurrently, once per contract and once per event selector of the
+    /// logs that the user-provided ABIs don't match.
+    pub async fn decode_all(&self, chain_id: u64, logs: &[&Log]) -> Vec<Option<DecodedEvent>> {
+        let mut decoded = logs
+            .iter()
+            .map(|log| {
+                let events = self.events.get(log.topics().first()?)?;
+                decode_with_any(events, log)
+            })
+            .collect::<Vec<_>>();
+        let Some(lookup) = self.lookup.as_ref() else { return decoded };
+
+        // the logs that are not decoded yet, with their index and event selector
+        let undecoded = |decoded: &[Option<DecodedEvent>]| {
+            logs.iter()
+                .zip(decoded)
+                .enumerate()
+                .filter(|(_, (_, decoded))| decoded.is_none())
+                .filter_map(|(index, (log, _))| Some((index, *log, *log.topics().first()?)))
+                .collect::<Vec<_>>()
+        };
+
+        let pending = undecoded(&decoded);
+        let contracts = lookup_all(pending.iter().map(|(_, log, _)| log.address), |address| {
+            lookup.contract_events(chain_id, address)
+        })
+        .await;
+        for (index, log, selector) in pending {
+            decoded[index] = contracts[&log.address]
+                .get(&selector)
+                .and_then(|events| decode_with_any(events, log));
+        }
+
+        let pending = undecoded(&decoded);
+        let signatures = lookup_all(pending.iter().map(|(_, _, selector)| *selector), |selector| {
+            lookup.signature_events(selector)
+        })
+        .await;
+        for (index, log, selector) in pending {
+            decoded[index] = decode_with_any(&signatures[&selector], log);
+        }
+
+        decoded
+    }
+}
+
+/// Remote lookup of event ABIs, with caching.
+///
+/// Verified contract ABIs are looked up on Sourcify, event signatures on 4byte. Signatures don't
+/// specify which parameters are indexed, so the leading parameters are assumed to be indexed,
+/// according to the number of topics of the decoded log.
+///
+/// Results are cached, including negative ones. Failed requests are cached as negative results
+/// for five minutes and retried afterwards.
+#[derive(Debug)]
+pub struct AbiLookup {
+    client: reqwest::Client,
+    four_byte_url: String,
+    sourcify_url: String,
+    /// Events by selector, looked up on 4byte.
+    signatures: Mutex<LruMap<B256, CachedLookup<Vec<Event>>, ByLength>>,
+    /// Events of verified contracts by chain id and address, looked up on Sourcify.
+    contracts: Mutex<LruMap<(u64, Address), CachedLookup<ContractEvents>, ByLength>>,
+}
+
+impl AbiLookup {
+    /// Creates a lookup that uses the given URLs and caches at most `cache_size` signatures and
+    /// contract ABIs each.
+    pub fn new(
+        four_byte_url: impl Into<String>,
+        sourcify_url: impl Into<String>,
+        cache_size: u32,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            four_byte_url: four_byte_url.into(),
+            sourcify_url: sourcify_url.into(),
+            signatures: Mutex::new(LruMap::new(ByLength::new(cache_size))),
+            contracts: Mutex::new(LruMap::new(ByLength::new(cache_size))),
+        }
+    }
+
+    /// Returns the events of the verified contract at the address by their selector.
+    async fn contract_events(&self, chain_id: u64, address: Address) -> Arc<ContractEvents> {
+        let key = (chain_id, address);
+        if let Some(events) = CachedLookup::get(&self.contracts, &key) {
+            return events
+        }
+
+        let url = format!("{}/v2/contract/{chain_id}/{address}?fields=abi", self.sourcify_url);
+        let cached = match self.get::<SourcifyContract>(&url).await {
+            Ok(contract) => {
+                let mut events = ContractEvents::new();
+                for event in
+                    contract.and_then(|contract| contract.abi).iter().flat_map(JsonAbi::events)
+                {
+                    events.entry(event.selector()).or_default().push(event.clone());
+                }
+                CachedLookup::new(events)
+            }
+            Err(err) => {
+                debug!(target: "rpc::reth", %err, %address, "Failed to look up contract ABI");
+                CachedLookup::failed()
+            }
+        };
+        let events = cached.value.clone();
+        self.contracts.lock().insert(key, cached);
+        events
+    }
+
+    /// Returns the events with the given selector, parsed from their signatures.
+    async fn signature_events(&self, selector: B256) -> Arc<Vec<Event>> {
+        if let Some(events) = CachedLookup::get(&self.signatures, &selector) {
+            return events
+        }
+
+        let url =
+            format!("{}/api/v1/event-signatures/?hex_signature={selector}", self.four_byte_url);
+        let cached = match self.get::<FourByteSignatures>(&url).await {
+            Ok(signatures) => CachedLookup::new(
+                signatures
+                    .into_iter()
+                    .flat_map(|signatures| signatures.results)
+                    .filter_map(|signature| {
+                        Event::parse(&format!("event {}", signature.text_signature))
+                            .inspect_err(|err| {
+                                trace!(target: "rpc::reth", %err, signature = signature.text_signature, "Invalid event signature")
+                            })
+                            .ok()
+                    })
+                    .filter(|event| event.selector() == selector)
+                    .collect(),
+            ),
+            Err(err) => {
+                debug!(target: "rpc::reth", %err, %selector, "Failed to look up event signature");
+                CachedLookup::failed()
+            }
+        };
+        let events = cached.value.clone();
+        self.signatures.lock().insert(selector, cached);
+        events
+    }
+
+    /// Sends a GET request, returns `None` if the resource does not exist.
+    async fn get<T: serde::de::DeserializeOwned>(&self, url: &str) -> reqwest::Result<Option<T>> {
+        let response = self.client.get(url).send().await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None)
+        }
+        response.error_for_status()?.json().await.map(Some)
+    }
+}
+
+impl Default for AbiLookup {
+    fn default() -> Self {
+        Self::new(DEFAULT_FOUR_BYTE_URL, DEFAULT_SOURCIFY_URL, DEFAULT_ABI_LOOKUP_CACHE_SIZE)
+    }
+}
+
+/// Events of a contract by their selector.
+type ContractEvents = HashMap<B256, Vec<Event>>;
+
+/// A cached result of a remote lookup.
+#[derive(Debug)]
+struct CachedLookup<T> {
+    value: Arc<T>,
+    /// When the entry expires, only set for failed lookups so they are retried eventually.
+    expires_at: Option<Instant>,
+}
+
+impl<T: Default> CachedLookup<T> {
+    fn new(value: T) -> Self {
+        Self { value: Arc::new(value), expires_at: None }
+    }
+
+    /// Returns an empty entry for a failed lookup, see [`FAILED_LOOKUP_RETRY_INTERVAL`].
+    fn failed() -> Self {
+        Self {
+            value: Default::default(),
+            expires_at: Some(Instant::now() + FAILED_LOOKUP_RETRY_INTERVAL),
+        }
+    }
+
+    /// Returns the cached value of the key, unless it expired.
+    fn get<K: Hash + PartialEq>(
+        cache: &Mutex<LruMap<K, Self, ByLength>>,
+        key: &K,
+    ) -> Option<Arc<T>> {
+        let mut cache = cache.lock();
+        let entry = cache.get(key)?;
+        if entry.expires_at.is_some_and(|expires_at| expires_at <= Instant::now()) {
+            cache.remove(key);
+            return None
+        }
+        Some(entry.value.clone())
+    }
+}
+
+/// Runs the lookup of each distinct key, at most [`MAX_CONCURRENT_LOOKUPS`] at once.
+async fn lookup_all<K, V, F, Fut>(keys: impl IntoIterator<Item = K>, lookup: F) -> HashMap<K, V>
+where
+    K: Copy + Eq + Hash,
+    F: Fn(K) -> Fut,
+    Fut: Future<Output = V>,
+{
+    stream::iter(keys.into_iter().collect::<HashSet<_>>())
+        .map(|key| {
+            let value = lookup(key);
+            async move { (key, value.await) }
+        })
+        .buffer_unordered(MAX_CONCURRENT_LOOKUPS)
+        .collect()
+        .await
+}
+
+/// Response of the Sourcify contract endpoint.
+#[derive(Deserialize)]
+struct SourcifyContract {
+    abi: Option<JsonAbi>,
+}
+
+/// Response of the 4byte event signature endpoint.
+#[derive(Deserialize)]
+struct FourByteSignatures {
+    results: Vec<FourByteSignature>,
+}
+
+#[derive(Deserialize)]
+struct FourByteSignature {
+    text_signature: String,
+}
+
+/// Decodes the log with the first of the events that matches it.
+fn decode_with_any(events: &[Event], log: &Log) -> Option<DecodedEvent> {
+    events.iter().find_map(|event| decode(event, log))
+}
+
+/// Decodes the log with the event.
+///
+/// If the event does not specify indexed parameters, e.g. because it was parsed from a signature,
+/// the leading parameters are assumed to be indexed, according to the number of topics.
+fn decode(event: &Event, log: &Log) -> Option<DecodedEvent> {
+    let num_indexed = log.topics().len().saturating_sub(1);
+    let reindexed;
+    let event = if num_indexed > 0 && !event.inputs.iter().any(|input| input.indexed) {
+        let mut event = event.clone();
+        for input in event.inputs.iter_mut().take(num_indexed) {
+            input.indexed = true;
+        }
+        reindexed = event;
+        &reindexed
+    } else {
+        event
+    };
+
+    let decoded = event.decode_log(&log.data, true).ok()?;
+    let mut indexed = decoded.indexed.into_iter();
+    let mut body = decoded.body.into_iter();
+    let params = event
+        .inputs
+        .iter()
+        .map(|input| {
+            let value = if input.indexed { indexed.next() } else { body.next() };
+            Some(decoded_param(input, &value?))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(DecodedEvent { name: event.name.clone(), signature: event.signature(), params })
+}
+
+fn decoded_param(input: &EventParam, value: &DynSolValue) -> DecodedParam {
+    DecodedParam {
+        name: input.name.clone(),
+        ty: input.selector_type().into_owned(),
+        indexed: input.indexed,
+        value: to_json(value),
+    }
+}
+
+/// Converts a decoded value to JSON, integers are encoded as decimal strings and bytes as hex
+/// strings.
+fn to_json(value: &DynSolValue) -> serde_json::Value {
+    match value {
+        DynSolValue::Bool(value) => (*value).into(),
+        DynSolValue::Int(value, _) => value.to_string().into(),
+        DynSolValue::Uint(value, _) => value.to_string().into(),
+        DynSolValue::FixedBytes(word, size) => hex::encode_prefixed(&word[..*size]).into(),
+        DynSolValue::Address(address) => address.to_checksum(None).into(),
+        DynSolValue::Function(function) => hex::encode_prefixed(function).into(),
+        DynSolValue::Bytes(bytes) => hex::encode_prefixed(bytes).into(),
+        DynSolValue::String(value) => value.clone().into(),
+        DynSolValue::Array(values) |
+        DynSolValue::FixedArray(values) |
+        DynSolValue::Tuple(values) |
+        DynSolValue::CustomStruct { tuple: values, .. } => {
+            values.iter().map(to_json).collect::<Vec<_>>().into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, LogData, U256};
+
+    fn transfer_log(indexed: bool) -> Log {
+        let from = Address::with_last_byte(1);
+        let to = Address::with_last_byte(2);
+        let mut topics =
+            vec![Event::parse("event Transfer(address,address,uint256)").unwrap().selector()];
+        let mut data = Vec::new();
+        if indexed {
+            topics.push(from.into_word());
+            topics.push(to.into_word());
+        } else {
+            data.extend_from_slice(from.into_word().as_slice());
+            data.extend_from_slice(to.into_word().as_slice());
+        }
+        data.extend_from_slice(&U256::from(100).to_be_bytes::<32>());
+        Log {
+            address: Address::with_last_byte(3),
+            data: LogData::new_unchecked(topics, Bytes::from(data)),
+        }
+    }
+
+    #[tokio::test]
+    async fn decode_with_registered_abi() {
+        let abi: JsonAbi = serde_json::from_str(
+            r#"[{"type":"event","name":"Transfer","anonymous":false,"inputs":[
+                {"name":"from","type":"address","indexed":true},
+                {"name":"to","type":"address","indexed":true},
+                {"name":"value","type":"uint256","indexed":false}]}]"#,
+        )
+        .unwrap();
+        let mut registry = AbiRegistry::new();
+        assert!(registry.is_empty());
+        registry.add_abi(&abi);
+
+        let log = transfer_log(true);
+        let decoded = registry.decode_all(1, &[&log]).await.pop().flatten().unwrap();
+        assert_eq!(decoded.name, "Transfer");
+        assert_eq!(decoded.signature, "Transfer(address,address,uint256)");
+        let params = decoded
+            .params
+            .iter()
+            .map(|param| (param.name.as_str(), param.indexed, param.value.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params,
+            vec![
+                ("from", true, Address::with_last_byte(1).to_checksum(None).into()),
+                ("to", true, Address::with_last_byte(2).to_checksum(None).into()),
+                ("value", false, "100".into()),
+            ]
+        );
+
+        // the registered event has indexed parameters, so a log without topics doesn't match
+        let log = transfer_log(false);
+        assert_eq!(registry.decode_all(1, &[&log]).await, vec![None]);
+    }
+
+    #[test]
+    fn decode_with_signature() {
+        let event = Event::parse("event Transfer(address,address,uint256)").unwrap();
+
+        let decoded = decode(&event, &transfer_log(true)).unwrap();
+        assert_eq!(decoded.params.iter().filter(|param| param.indexed).count(), 2);
+
+        let decoded = decode(&event, &transfer_log(false)).unwrap();
+        assert!(decoded.params.iter().all(|param| !param.indexed));
+        assert_eq!(decoded.params[2].value, serde_json::Value::from("100"));
+    }
+}
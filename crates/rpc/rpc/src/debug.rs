@@ -44,6 +44,8 @@ use revm_inspectors::tracing::{
 use std::sync::Arc;
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
+use crate::native_tracer::NativeTracers;
+
 /// `debug` API implementation.
 ///
 /// This type provides the functionality for handling `debug` related requests.
@@ -60,7 +62,22 @@ impl<Eth, BlockExecutor> DebugApi<Eth, BlockExecutor> {
         blocking_task_guard: BlockingTaskGuard,
         block_executor: BlockExecutor,
     ) -> Self {
-        let inner = Arc::new(DebugApiInner { eth_api: eth, blocking_task_guard, block_executor });
+        Self::new_with_tracers(eth, blocking_task_guard, block_executor, NativeTracers::default())
+    }
+
+    /// Create a new instance of the [`DebugApi`] that can trace with the given native tracers.
+    pub fn new_with_tracers(
+        eth: Eth,
+        blocking_task_guard: BlockingTaskGuard,
+        block_executor: BlockExecutor,
+        native_tracers: NativeTracers,
+    ) -> Self {
+        let inner = Arc::new(DebugApiInner {
+            eth_api: eth,
+            blocking_task_guard,
+            block_executor,
+            native_tracers,
+        });
         Self { inner }
     }
 
@@ -303,6 +320,24 @@ where
         let GethDebugTracingOptions { config, tracer, tracer_config, .. } = tracing_options;
 
         let this = self.clone();
+        if let Some(native_tracer) =
+            tracer.as_ref().and_then(|tracer| self.inner.native_tracers.get(tracer)).cloned()
+        {
+            let mut inspector = TracingInspector::new(
+                native_tracer.inspector_config(&tracer_config).map_err(Eth::Error::from_eth_err)?,
+            );
+            let trace = self
+                .eth_api()
+                .spawn_with_call_at(call, at, overrides, move |db, env| {
+                    let (res, _) = this.eth_api().inspect(db, env, &mut inspector)?;
+                    native_tracer
+                        .trace(&tracer_config, &inspector, &res, &TransactionInfo::default())
+                        .map_err(Eth::Error::from_eth_err)
+                })
+                .await?;
+            return Ok(GethTrace::JS(trace))
+        }
+
         if let Some(tracer) = tracer {
             return match tracer {
                 GethDebugTracerType::BuiltInTracer(tracer) => match tracer {
@@ -680,6 +715,19 @@ where
             base_fee: Some(env.block.basefee.try_into().unwrap_or_default()),
         };
 
+        if let Some(native_tracer) =
+            tracer.as_ref().and_then(|tracer| self.inner.native_tracers.get(tracer))
+        {
+            let mut inspector = TracingInspector::new(
+                native_tracer.inspector_config(tracer_config).map_err(Eth::Error::from_eth_err)?,
+            );
+            let (res, _) = self.eth_api().inspect(db, env, &mut inspector)?;
+            let trace = native_tracer
+                .trace(tracer_config, &inspector, &res, &tx_info)
+                .map_err(Eth::Error::from_eth_err)?;
+            return Ok((GethTrace::JS(trace), res.state))
+        }
+
         if let Some(tracer) = tracer {
             return match tracer {
                 GethDebugTracerType::BuiltInTracer(tracer) => match tracer {
@@ -822,10 +870,8 @@ where
                 let number = self
                     .provider()
                     .convert_block_number(number_or_tag)
-                    .to_rpc_result()?
-                    .ok_or_else(|| {
-                    internal_rpc_err("Pending block not supported".to_string())
-                })?;
+                    .map_err(EthApiError::from)?
+                    .ok_or_else(|| internal_rpc_err("Pending block not supported".to_string()))?;
                 self.provider().header_by_number(number).to_rpc_result()?
             }
         };
@@ -843,7 +889,7 @@ where
         let block = self
             .provider()
             .block_by_id(block_id)
-            .to_rpc_result()?
+            .map_err(EthApiError::from)?
             .ok_or(EthApiError::HeaderNotFound(block_id))?;
         let mut res = Vec::new();
         block.encode(&mut res);
@@ -865,7 +911,7 @@ where
         let block = self
             .provider()
             .block_with_senders_by_id(block_id, TransactionVariant::NoHash)
-            .to_rpc_result()?
+            .map_err(EthApiError::from)?
             .unwrap_or_default();
         Ok(block.into_transactions_ecrecovered().map(|tx| tx.encoded_2718().into()).collect())
     }
@@ -875,7 +921,7 @@ where
         Ok(self
             .provider()
             .receipts_by_block_id(block_id)
-            .to_rpc_result()?
+            .map_err(EthApiError::from)?
             .unwrap_or_default()
             .into_iter()
             .map(|receipt| ReceiptWithBloom::from(receipt).encoded_2718().into())
@@ -1207,4 +1253,6 @@ struct DebugApiInner<Eth, BlockExecutor> {
     blocking_task_guard: BlockingTaskGuard,
     /// block executor for debug & trace apis
     block_executor: BlockExecutor,
+    /// native tracers that can be selected by name
+    native_tracers: NativeTracers,
 }
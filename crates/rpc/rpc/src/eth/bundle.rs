@@ -1,8 +1,12 @@
 //! `Eth` bundle implementation and helpers.
 
 use alloy_consensus::{BlockHeader, Transaction as _};
-use alloy_primitives::{Keccak256, U256};
-use alloy_rpc_types_mev::{EthCallBundle, EthCallBundleResponse, EthCallBundleTransactionResult};
+use alloy_primitives::{Address, Bytes, Keccak256, B256, U256};
+use alloy_rpc_types_eth::BlockId;
+use alloy_rpc_types_mev::{
+    CancelBundleRequest, EthBundleHash, EthCallBundle, EthCallBundleResponse,
+    EthCallBundleTransactionResult, EthSendBundle,
+};
 use jsonrpsee::core::RpcResult;
 use reth_chainspec::EthChainSpec;
 use reth_evm::{ConfigureEvm, ConfigureEvmEnv};
@@ -10,18 +14,24 @@ use reth_primitives::PooledTransactionsElement;
 use reth_primitives_traits::SignedTransaction;
 use reth_provider::{ChainSpecProvider, HeaderProvider};
 use reth_revm::database::StateProviderDatabase;
+use reth_rpc_api::MevCallBundleApiServer;
 use reth_rpc_eth_api::{
     helpers::{Call, EthTransactions, LoadPendingBlock},
-    EthCallBundleApiServer, FromEthApiError, FromEvmError, RpcNodeCore,
+    EthCallBundleApiServer, EthSendBundleApiServer, FromEthApiError, FromEvmError, RpcNodeCore,
 };
 use reth_rpc_eth_types::{utils::recover_raw_transaction, EthApiError, RpcInvalidTransactionError};
 use reth_tasks::pool::BlockingTaskGuard;
-use reth_transaction_pool::{PoolConsensusTx, PoolPooledTx, PoolTransaction, TransactionPool};
+use reth_transaction_pool::{
+    error::BundleError, Bundle, PoolConsensusTx, PoolPooledTx, PoolTransaction, TransactionPool,
+};
 use revm::{
     db::{CacheDB, DatabaseCommit, DatabaseRef},
     primitives::{ResultAndState, TxEnv},
 };
-use revm_primitives::{EnvKzgSettings, EnvWithHandlerCfg, SpecId, MAX_BLOB_GAS_PER_BLOCK};
+use revm_primitives::{
+    BlockEnv, CfgEnvWithHandlerCfg, EnvKzgSettings, EnvWithHandlerCfg, SpecId,
+    MAX_BLOB_GAS_PER_BLOCK,
+};
 use std::sync::Arc;
 
 /// `Eth` bundle implementation.
@@ -88,6 +98,95 @@ where
             .into())
         }
 
+        let transactions = Self::recover_bundle_transactions(txs)?;
+
+        let block_id: BlockId = state_block_number.into();
+        // Note: the block number is considered the `parent` block: <https://github.com/flashbots/mev-geth/blob/fddf97beec5877483f879a77b7dea2e58a58d653/internal/ethapi/api.go#L2104>
+        let (cfg, mut block_env, at) = self.eth_api().evm_env_at(block_id).await?;
+
+        self.apply_block_overrides(&mut block_env, coinbase, difficulty, gas_limit)?;
+
+        // need to adjust the timestamp for the next block
+        if let Some(timestamp) = timestamp {
+            block_env.timestamp = U256::from(timestamp);
+        } else {
+            block_env.timestamp += U256::from(12);
+        }
+
+        if let Some(base_fee) = base_fee {
+            block_env.basefee = U256::from(base_fee);
+        } else if cfg.handler_cfg.spec_id.is_enabled_in(SpecId::LONDON) {
+            let parent_block = block_env.number.saturating_to::<u64>();
+            // here we need to fetch the _next_ block's basefee based on the parent block <https://github.com/flashbots/mev-geth/blob/fddf97beec5877483f879a77b7dea2e58a58d653/internal/ethapi/api.go#L2130>
+            let parent = RpcNodeCore::provider(self.eth_api())
+                .header_by_number(parent_block)
+                .map_err(Eth::Error::from_eth_err)?
+                .ok_or(EthApiError::HeaderNotFound(parent_block.into()))?;
+            if let Some(base_fee) = parent.next_block_base_fee(
+                RpcNodeCore::provider(self.eth_api())
+                    .chain_spec()
+                    .base_fee_params_at_block(parent_block),
+            ) {
+                block_env.basefee = U256::from(base_fee);
+            }
+        }
+
+        let state_block_number = block_env.number.to();
+        // use the block number of the request
+        block_env.number = U256::from(block_number);
+
+        self.simulate_bundle(transactions, cfg, block_env, at, state_block_number).await
+    }
+
+    /// Simulates a bundle of transactions at the top of the next block on the state of the pending
+    /// block, as built by the payload builder.
+    ///
+    /// If there's no pending block, this is the next block on top of the latest block. Unlike
+    /// [`Self::call_bundle`], the block number of the bundle is ignored.
+    pub async fn call_pending_bundle(
+        &self,
+        bundle: EthCallBundle,
+    ) -> Result<EthCallBundleResponse, Eth::Error> {
+        let EthCallBundle {
+            txs,
+            coinbase,
+            timeout: _,
+            timestamp,
+            gas_limit,
+            difficulty,
+            base_fee,
+            ..
+        } = bundle;
+        if txs.is_empty() {
+            return Err(EthApiError::InvalidParams(
+                EthBundleError::EmptyBundleTransactions.to_string(),
+            )
+            .into())
+        }
+
+        let transactions = Self::recover_bundle_transactions(txs)?;
+
+        // the pending block env is already configured for the next block
+        let (cfg, mut block_env, at) = self.eth_api().evm_env_at(BlockId::pending()).await?;
+
+        self.apply_block_overrides(&mut block_env, coinbase, difficulty, gas_limit)?;
+        if let Some(timestamp) = timestamp {
+            block_env.timestamp = U256::from(timestamp);
+        }
+        if let Some(base_fee) = base_fee {
+            block_env.basefee = U256::from(base_fee);
+        }
+
+        let state_block_number = block_env.number.saturating_to::<u64>().saturating_sub(1);
+
+        self.simulate_bundle(transactions, cfg, block_env, at, state_block_number).await
+    }
+
+    /// Recovers the signers of the raw transactions of a bundle and validates that the bundle
+    /// doesn't exceed the blob gas limit of a block.
+    fn recover_bundle_transactions(
+        txs: Vec<Bytes>,
+    ) -> Result<Vec<(PooledTransactionsElement, Address)>, Eth::Error> {
         let transactions = txs
             .into_iter()
             .map(|tx| recover_raw_transaction::<PoolPooledTx<Eth::Pool>>(&tx))
@@ -116,23 +215,25 @@ where
             .into())
         }
 
-        let block_id: alloy_rpc_types_eth::BlockId = state_block_number.into();
-        // Note: the block number is considered the `parent` block: <https://github.com/flashbots/mev-geth/blob/fddf97beec5877483f879a77b7dea2e58a58d653/internal/ethapi/api.go#L2104>
-        let (cfg, mut block_env, at) = self.eth_api().evm_env_at(block_id).await?;
+        Ok(transactions)
+    }
 
+    /// Applies the `coinbase`, `difficulty` and `gas_limit` overrides of a bundle to the block env.
+    ///
+    /// The gas limit defaults to the call gas limit, a larger gas limit is rejected.
+    fn apply_block_overrides(
+        &self,
+        block_env: &mut BlockEnv,
+        coinbase: Option<Address>,
+        difficulty: Option<U256>,
+        gas_limit: Option<u64>,
+    ) -> Result<(), Eth::Error> {
         if let Some(coinbase) = coinbase {
             block_env.coinbase = coinbase;
         }
 
-        // need to adjust the timestamp for the next block
-        if let Some(timestamp) = timestamp {
-            block_env.timestamp = U256::from(timestamp);
-        } else {
-            block_env.timestamp += U256::from(12);
-        }
-
         if let Some(difficulty) = difficulty {
-            block_env.difficulty = U256::from(difficulty);
+            block_env.difficulty = difficulty;
         }
 
         // default to call gas limit unless user requests a smaller limit
@@ -147,28 +248,19 @@ where
             block_env.gas_limit = gas_limit;
         }
 
-        if let Some(base_fee) = base_fee {
-            block_env.basefee = U256::from(base_fee);
-        } else if cfg.handler_cfg.spec_id.is_enabled_in(SpecId::LONDON) {
-            let parent_block = block_env.number.saturating_to::<u64>();
-            // here we need to fetch the _next_ block's basefee based on the parent block <https://github.com/flashbots/mev-geth/blob/fddf97beec5877483f879a77b7dea2e58a58d653/internal/ethapi/api.go#L2130>
-            let parent = RpcNodeCore::provider(self.eth_api())
-                .header_by_number(parent_block)
-                .map_err(Eth::Error::from_eth_err)?
-                .ok_or(EthApiError::HeaderNotFound(parent_block.into()))?;
-            if let Some(base_fee) = parent.next_block_base_fee(
-                RpcNodeCore::provider(self.eth_api())
-                    .chain_spec()
-                    .base_fee_params_at_block(parent_block),
-            ) {
-                block_env.basefee = U256::from(base_fee);
-            }
-        }
-
-        let state_block_number = block_env.number;
-        // use the block number of the request
-        block_env.number = U256::from(block_number);
+        Ok(())
+    }
 
+    /// Executes the transactions of a bundle one after another on the state of the given block,
+    /// tracking the payments to the coinbase.
+    async fn simulate_bundle(
+        &self,
+        transactions: Vec<(PooledTransactionsElement, Address)>,
+        cfg: CfgEnvWithHandlerCfg,
+        block_env: BlockEnv,
+        at: BlockId,
+        state_block_number: u64,
+    ) -> Result<EthCallBundleResponse, Eth::Error> {
         let eth_api = self.eth_api().clone();
 
         self.eth_api()
@@ -272,7 +364,7 @@ where
                     eth_sent_to_coinbase,
                     gas_fees: total_gas_fess,
                     results,
-                    state_block_number: state_block_number.to(),
+                    state_block_number,
                     total_gas_used,
                 };
 
@@ -292,6 +384,90 @@ where
     }
 }
 
+#[async_trait::async_trait]
+impl<Eth> MevCallBundleApiServer for EthBundle<Eth>
+where
+    Eth: EthTransactions + LoadPendingBlock + Call + 'static,
+{
+    async fn call_bundle(&self, request: EthCallBundle) -> RpcResult<EthCallBundleResponse> {
+        Self::call_pending_bundle(self, request).await.map_err(Into::into)
+    }
+}
+
+impl<Eth> EthBundle<Eth>
+where
+    Eth: RpcNodeCore<Pool: TransactionPool> + 'static,
+{
+    /// Decodes the transactions of the bundle and submits it to the transaction pool.
+    ///
+    /// Returns the hash of the bundle.
+    pub async fn send_bundle(&self, bundle: EthSendBundle) -> Result<B256, EthApiError> {
+        let EthSendBundle {
+            txs,
+            block_number,
+            min_timestamp,
+            max_timestamp,
+            reverting_tx_hashes,
+            ..
+        } = bundle;
+        if txs.is_empty() {
+            return Err(EthApiError::InvalidParams(
+                EthBundleError::EmptyBundleTransactions.to_string(),
+            ))
+        }
+        if block_number == 0 {
+            return Err(EthApiError::InvalidParams(
+                EthBundleError::BundleMissingBlockNumber.to_string(),
+            ))
+        }
+
+        let transactions = txs
+            .iter()
+            .map(|tx| {
+                recover_raw_transaction::<PoolPooledTx<Eth::Pool>>(tx)
+                    .map(<Eth::Pool as TransactionPool>::Transaction::from_pooled)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let bundle = Bundle {
+            transactions,
+            block_number,
+            min_timestamp,
+            max_timestamp,
+            reverting_tx_hashes,
+        };
+        self.eth_api().pool().add_bundle(bundle).await.map_err(|err| match err {
+            BundleError::InvalidTransaction(err) => err.into(),
+            err => EthApiError::InvalidParams(err.to_string()),
+        })
+    }
+
+    /// Removes a previously submitted bundle from the transaction pool.
+    pub fn cancel_bundle(&self, request: CancelBundleRequest) -> Result<(), EthApiError> {
+        let hash = request
+            .bundle_hash
+            .parse::<B256>()
+            .map_err(|err| EthApiError::InvalidParams(err.to_string()))?;
+        self.eth_api().pool().cancel_bundle(&hash);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl<Eth> EthSendBundleApiServer for EthBundle<Eth>
+where
+    Eth: RpcNodeCore<Pool: TransactionPool> + 'static,
+{
+    async fn send_bundle(&self, bundle: EthSendBundle) -> RpcResult<EthBundleHash> {
+        let bundle_hash = Self::send_bundle(self, bundle).await?;
+        Ok(EthBundleHash { bundle_hash })
+    }
+
+    async fn cancel_bundle(&self, request: CancelBundleRequest) -> RpcResult<()> {
+        Ok(Self::cancel_bundle(self, request)?)
+    }
+}
+
 /// Container type for  `EthBundle` internals
 #[derive(Debug)]
 struct EthBundleInner<Eth> {
@@ -1,33 +1,36 @@
 //! `eth_` `Filter` RPC handler implementation
 
 use alloy_consensus::BlockHeader;
-use alloy_primitives::TxHash;
+use alloy_primitives::{Bloom, TxHash, B256};
 use alloy_rpc_types_eth::{
-    BlockNumHash, Filter, FilterBlockOption, FilterChanges, FilterId, FilteredParams, Log,
-    PendingTransactionFilterKind,
+    BlockNumHash, BloomFilter, Filter, FilterBlockOption, FilterChanges, FilterId, FilteredParams,
+    Log, PendingTransactionFilterKind,
 };
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, server::IdProvider};
+use metrics::Counter;
 use reth_chainspec::ChainInfo;
+use reth_metrics::{metrics::Gauge, Metrics};
 use reth_primitives::SealedBlockWithSenders;
 use reth_provider::{
-    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, ProviderBlock,
-    ProviderError, ProviderReceipt,
+    BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, HeaderProvider, LogIndexReader,
+    ProviderBlock, ProviderError, ProviderReceipt,
 };
 use reth_rpc_eth_api::{
     EthApiTypes, EthFilterApiServer, FullEthApiTypes, RpcNodeCoreExt, RpcTransaction,
     TransactionCompat,
 };
 use reth_rpc_eth_types::{
-    logs_utils::{self, append_matching_block_logs, ProviderOrBlock},
+    logs_utils::{self, append_matching_block_logs, log_matches_filter, ProviderOrBlock},
     EthApiError, EthFilterConfig, EthStateCache, EthSubscriptionIdProvider,
 };
 use reth_rpc_server_types::{result::rpc_error_with_code, ToRpcResult};
 use reth_rpc_types_compat::transaction::from_recovered;
 use reth_tasks::TaskSpawner;
 use reth_transaction_pool::{NewSubpoolTransactionStream, PoolTransaction, TransactionPool};
+use schnellru::{ByLength, LruMap};
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
     fmt,
     iter::StepBy,
     ops::RangeInclusive,
@@ -43,6 +46,9 @@ use tracing::{error, trace};
 /// The maximum number of headers we read at once when handling a range filter.
 const MAX_HEADERS_RANGE: u64 = 1_000; // with ~530bytes per header this is ~500kb
 
+/// The number of most recent blocks whose logs are materialized once and shared by all filters.
+const MAX_SHARED_BLOCK_LOGS: u32 = 256;
+
 /// `Eth` filter RPC implementation.
 pub struct EthFilter<Eth: EthApiTypes> {
     /// All nested fields bundled together
@@ -80,6 +86,8 @@ where
             max_headers_range: MAX_HEADERS_RANGE,
             task_spawner,
             stale_filter_ttl,
+            block_logs: parking_lot::Mutex::new(LruMap::new(ByLength::new(MAX_SHARED_BLOCK_LOGS))),
+            metrics: EthFilterMetrics::default(),
             // if not set, use the max value, which is effectively no limit
             max_blocks_per_filter: max_blocks_per_filter.unwrap_or(u64::MAX),
             max_logs_per_response: max_logs_per_response.unwrap_or(usize::MAX),
@@ -121,7 +129,8 @@ where
     /// `stale_filter_ttl` at the given instant.
     pub async fn clear_stale_filters(&self, now: Instant) {
         trace!(target: "rpc::eth", "clear stale filters");
-        self.active_filters().inner.lock().await.retain(|id, filter| {
+        let mut filters = self.active_filters().inner.lock().await;
+        let evicted = filters.retain(|id, filter| {
             let is_valid = (now - filter.last_poll_timestamp) < self.inner.stale_filter_ttl;
 
             if !is_valid {
@@ -129,13 +138,15 @@ where
             }
 
             is_valid
-        })
+        });
+        self.inner.metrics.stale_filters_evicted_total.increment(evicted as u64);
+        self.inner.metrics.active_filters.set(filters.len() as f64);
     }
 }
 
 impl<Eth> EthFilter<Eth>
 where
-    Eth: FullEthApiTypes<Provider: BlockReader + BlockIdReader> + RpcNodeCoreExt,
+    Eth: FullEthApiTypes<Provider: BlockReader + BlockIdReader + LogIndexReader> + RpcNodeCoreExt,
 {
     /// Access the underlying provider.
     fn provider(&self) -> &Eth::Provider {
@@ -189,7 +200,7 @@ where
                 Ok(FilterChanges::Hashes(block_hashes))
             }
             FilterKind::Log(filter) => {
-                let (from_block_number, to_block_number) = match filter.block_option {
+                let (from_block_number, to_block_number) = match filter.filter.block_option {
                     FilterBlockOption::Range { from_block, to_block } => {
                         let from = from_block
                             .map(|num| self.provider().convert_block_number(num))
@@ -228,7 +239,7 @@ where
             if let FilterKind::Log(ref filter) =
                 filters.get(&id).ok_or_else(|| EthFilterError::FilterNotFound(id.clone()))?.kind
             {
-                *filter.clone()
+                filter.filter.clone()
             } else {
                 // Not a log filter
                 return Err(EthFilterError::FilterNotFound(id))
@@ -242,13 +253,15 @@ where
 #[async_trait]
 impl<Eth> EthFilterApiServer<RpcTransaction<Eth::NetworkTypes>> for EthFilter<Eth>
 where
-    Eth: FullEthApiTypes + RpcNodeCoreExt<Provider: BlockIdReader> + 'static,
+    Eth: FullEthApiTypes + RpcNodeCoreExt<Provider: BlockIdReader + LogIndexReader> + 'static,
 {
     /// Handler for `eth_newFilter`
     async fn new_filter(&self, filter: Filter) -> RpcResult<FilterId> {
         trace!(target: "rpc::eth", "Serving eth_newFilter");
         self.inner
-            .install_filter(FilterKind::<RpcTransaction<Eth::NetworkTypes>>::Log(Box::new(filter)))
+            .install_filter(FilterKind::<RpcTransaction<Eth::NetworkTypes>>::Log(Arc::new(
+                LogFilter::new(filter),
+            )))
             .await
     }
 
@@ -314,6 +327,8 @@ where
         let mut filters = self.inner.active_filters.inner.lock().await;
         if filters.remove(&id).is_some() {
             trace!(target: "rpc::eth::filter", ?id, "uninstalled filter");
+            self.inner.metrics.filters_uninstalled_total.increment(1);
+            self.inner.metrics.active_filters.set(filters.len() as f64);
             Ok(true)
         } else {
             Ok(false)
@@ -357,11 +372,16 @@ struct EthFilterInner<Eth: EthApiTypes> {
     task_spawner: Box<dyn TaskSpawner>,
     /// Duration since the last filter poll, after which the filter is considered stale
     stale_filter_ttl: Duration,
+    /// All logs of recently matched blocks, shared by all filters.
+    block_logs: parking_lot::Mutex<LruMap<B256, Arc<Vec<Log>>, ByLength>>,
+    /// Filter metrics.
+    metrics: EthFilterMetrics,
 }
 
 impl<Eth> EthFilterInner<Eth>
 where
-    Eth: RpcNodeCoreExt<Provider: BlockIdReader, Pool: TransactionPool> + EthApiTypes,
+    Eth: RpcNodeCoreExt<Provider: BlockIdReader + LogIndexReader, Pool: TransactionPool>
+        + EthApiTypes,
 {
     /// Access the underlying provider.
     fn provider(&self) -> &Eth::Provider {
@@ -429,8 +449,13 @@ where
                     .flatten();
                 let (from_block_number, to_block_number) =
                     logs_utils::get_filter_block_range(from, to, start_block, info);
-                self.get_logs_in_block_range(&filter, from_block_number, to_block_number, info)
-                    .await
+                self.get_logs_in_block_range(
+                    &LogFilter::new(filter),
+                    from_block_number,
+                    to_block_number,
+                    info,
+                )
+                .await
             }
         }
     }
@@ -451,6 +476,7 @@ where
                 kind,
             },
         );
+        self.metrics.active_filters.set(filters.len() as f64);
         Ok(id)
     }
 
@@ -461,12 +487,12 @@ where
     ///  - amount of matches exceeds configured limit
     async fn get_logs_in_block_range(
         &self,
-        filter: &Filter,
+        filter: &LogFilter,
         from_block: u64,
         to_block: u64,
         chain_info: ChainInfo,
     ) -> Result<Vec<Log>, EthFilterError> {
-        trace!(target: "rpc::eth::filter", from=from_block, to=to_block, ?filter, "finding logs in range");
+        trace!(target: "rpc::eth::filter", from=from_block, to=to_block, filter=?filter.filter, "finding logs in range");
 
         if to_block < from_block {
             return Err(EthFilterError::InvalidBlockRangeParams)
@@ -477,24 +503,131 @@ where
         }
 
         let mut all_logs = Vec::new();
-        let filter_params = FilteredParams::new(Some(filter.clone()));
 
-        // derive bloom filters from filter input, so we can check headers for matching logs
-        let address_filter = FilteredParams::address_filter(&filter.address);
-        let topics_filter = FilteredParams::topics_filter(&filter.topics);
+        // blocks covered by the log index are only checked if the index has matching entries, the
+        // remaining blocks are checked against the bloom filter of their header
+        let (before, indexed_blocks, after) = match self
+            .indexed_log_blocks(&filter.filter, from_block..=to_block)?
+        {
+            Some((indexed_range, blocks)) => {
+                trace!(target: "rpc::eth::filter", ?indexed_range, candidates=blocks.len(), "using log index");
+                (
+                    (from_block < *indexed_range.start())
+                        .then(|| from_block..=indexed_range.start() - 1),
+                    blocks,
+                    (*indexed_range.end() < to_block).then(|| indexed_range.end() + 1..=to_block),
+                )
+            }
+            None => (Some(from_block..=to_block), Vec::new(), None),
+        };
+
+        if let Some(range) = before {
+            self.append_bloom_matching_logs(
+                &mut all_logs,
+                filter,
+                range,
+                from_block..=to_block,
+                chain_info,
+            )
+            .await?;
+        }
+
+        for block_number in indexed_blocks {
+            let header = self
+                .provider()
+                .sealed_header(block_number)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+            let num_hash = BlockNumHash::new(block_number, header.hash());
+            self.append_block_logs(
+                &mut all_logs,
+                filter,
+                num_hash,
+                header.timestamp(),
+                from_block..=to_block,
+                chain_info,
+            )
+            .await?;
+        }
+
+        if let Some(range) = after {
+            self.append_bloom_matching_logs(
+                &mut all_logs,
+                filter,
+                range,
+                from_block..=to_block,
+                chain_info,
+            )
+            .await?;
+        }
+
+        Ok(all_logs)
+    }
+
+    /// Returns the part of the range that is covered by the log index together with the blocks in
+    /// it that may contain logs matching the filter, in ascending order.
+    ///
+    /// Returns `None` if the log index is not maintained, does not cover the range or the filter
+    /// has no address or topic criteria.
+    fn indexed_log_blocks(
+        &self,
+        filter: &Filter,
+        range: RangeInclusive<u64>,
+    ) -> Result<Option<(RangeInclusive<u64>, Vec<u64>)>, EthFilterError> {
+        if filter.address.is_empty() && filter.topics.iter().all(|topics| topics.is_empty()) {
+            return Ok(None)
+        }
+        let Some(log_index_range) = self.provider().log_index_range()? else { return Ok(None) };
+        let indexed_range = (*range.start()).max(*log_index_range.start())..=
+            (*range.end()).min(*log_index_range.end());
+        if indexed_range.is_empty() {
+            return Ok(None)
+        }
+
+        // a block can only match if it contains a log of one of the addresses and, for each
+        // topic position, a log with one of the topics
+        let mut candidates: Option<BTreeSet<u64>> = None;
+        if !filter.address.is_empty() {
+            let mut blocks = BTreeSet::new();
+            for address in filter.address.iter() {
+                blocks.extend(
+                    self.provider().blocks_with_address_logs(*address, indexed_range.clone())?,
+                );
+            }
+            candidates = Some(blocks);
+        }
+        for topics in filter.topics.iter().filter(|topics| !topics.is_empty()) {
+            let mut blocks = BTreeSet::new();
+            for topic in topics.iter() {
+                blocks
+                    .extend(self.provider().blocks_with_topic_logs(*topic, indexed_range.clone())?);
+            }
+            candidates = Some(match candidates {
+                Some(candidates) => candidates.intersection(&blocks).copied().collect(),
+                None => blocks,
+            });
+        }
 
+        Ok(Some((indexed_range, candidates.unwrap_or_default().into_iter().collect())))
+    }
+
+    /// Appends the logs matching the filter of all blocks in the range whose bloom filter
+    /// matches.
+    async fn append_bloom_matching_logs(
+        &self,
+        all_logs: &mut Vec<Log>,
+        filter: &LogFilter,
+        range: RangeInclusive<u64>,
+        query_range: RangeInclusive<u64>,
+        chain_info: ChainInfo,
+    ) -> Result<(), EthFilterError> {
         // loop over the range of new blocks and check logs if the filter matches the log's bloom
         // filter
-        for (from, to) in
-            BlockRangeInclusiveIter::new(from_block..=to_block, self.max_headers_range)
-        {
+        for (from, to) in BlockRangeInclusiveIter::new(range, self.max_headers_range) {
             let headers = self.provider().headers_range(from..=to)?;
 
             for (idx, header) in headers.iter().enumerate() {
                 // only if filter matches
-                if FilteredParams::matches_address(header.logs_bloom(), &address_filter) &&
-                    FilteredParams::matches_topics(header.logs_bloom(), &topics_filter)
-                {
+                if filter.matches_bloom(header.logs_bloom()) {
                     // these are consecutive headers, so we can use the parent hash of the next
                     // block to get the current header's hash
                     let block_hash = match headers.get(idx + 1) {
@@ -506,37 +639,123 @@ where
                     };
 
                     let num_hash = BlockNumHash::new(header.number(), block_hash);
-                    if let Some((receipts, maybe_block)) =
-                        self.receipts_and_maybe_block(&num_hash, chain_info.best_number).await?
-                    {
-                        append_matching_block_logs(
-                            &mut all_logs,
-                            maybe_block
-                                .map(ProviderOrBlock::Block)
-                                .unwrap_or_else(|| ProviderOrBlock::Provider(self.provider())),
-                            &filter_params,
-                            num_hash,
-                            &receipts,
-                            false,
-                            header.timestamp(),
-                        )?;
-
-                        // size check but only if range is multiple blocks, so we always return all
-                        // logs of a single block
-                        let is_multi_block_range = from_block != to_block;
-                        if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
-                            return Err(EthFilterError::QueryExceedsMaxResults {
-                                max_logs: self.max_logs_per_response,
-                                from_block,
-                                to_block: num_hash.number.saturating_sub(1),
-                            });
-                        }
-                    }
+                    self.append_block_logs(
+                        all_logs,
+                        filter,
+                        num_hash,
+                        header.timestamp(),
+                        query_range.clone(),
+                        chain_info,
+                    )
+                    .await?;
                 }
             }
         }
 
-        Ok(all_logs)
+        Ok(())
+    }
+
+    /// Appends the logs of the block that match the filter.
+    ///
+    /// Logs of recent blocks are taken from the logs shared by all filters, see
+    /// [`Self::shared_block_logs`].
+    ///
+    /// Returns an error if the amount of matches for the queried range exceeds the configured
+    /// limit.
+    async fn append_block_logs(
+        &self,
+        all_logs: &mut Vec<Log>,
+        filter: &LogFilter,
+        num_hash: BlockNumHash,
+        timestamp: u64,
+        query_range: RangeInclusive<u64>,
+        chain_info: ChainInfo,
+    ) -> Result<(), EthFilterError> {
+        let shared_range = chain_info.best_number.saturating_sub(MAX_SHARED_BLOCK_LOGS as u64)..=
+            chain_info.best_number;
+        if shared_range.contains(&num_hash.number) {
+            if let Some(block_logs) =
+                self.shared_block_logs(num_hash, timestamp, chain_info.best_number).await?
+            {
+                all_logs.extend(
+                    block_logs
+                        .iter()
+                        .filter(|log| log_matches_filter(num_hash, &log.inner, &filter.params))
+                        .cloned(),
+                );
+            }
+        } else if let Some((receipts, maybe_block)) =
+            self.receipts_and_maybe_block(&num_hash, chain_info.best_number).await?
+        {
+            append_matching_block_logs(
+                all_logs,
+                maybe_block
+                    .map(ProviderOrBlock::Block)
+                    .unwrap_or_else(|| ProviderOrBlock::Provider(self.provider())),
+                &filter.params,
+                num_hash,
+                &receipts,
+                false,
+                timestamp,
+            )?;
+        }
+
+        // size check but only if range is multiple blocks, so we always return all
+        // logs of a single block
+        let (from_block, to_block) = query_range.into_inner();
+        let is_multi_block_range = from_block != to_block;
+        if is_multi_block_range && all_logs.len() > self.max_logs_per_response {
+            return Err(EthFilterError::QueryExceedsMaxResults {
+                max_logs: self.max_logs_per_response,
+                from_block,
+                to_block: num_hash.number.saturating_sub(1),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns all logs of the block.
+    ///
+    /// The logs are only materialized from the block's receipts the first time a filter matches
+    /// the block and then reused by all other filters, so that many filters polling the same new
+    /// blocks don't each re-scan the receipts.
+    ///
+    /// Returns `None` if the receipts of the block are not available.
+    async fn shared_block_logs(
+        &self,
+        num_hash: BlockNumHash,
+        timestamp: u64,
+        best_number: u64,
+    ) -> Result<Option<Arc<Vec<Log>>>, EthFilterError> {
+        if let Some(block_logs) = self.block_logs.lock().get(&num_hash.hash).cloned() {
+            self.metrics.block_logs_hits_total.increment(1);
+            return Ok(Some(block_logs))
+        }
+        self.metrics.block_logs_misses_total.increment(1);
+
+        let Some((receipts, maybe_block)) =
+            self.receipts_and_maybe_block(&num_hash, best_number).await?
+        else {
+            return Ok(None)
+        };
+
+        let mut block_logs = Vec::new();
+        append_matching_block_logs(
+            &mut block_logs,
+            maybe_block
+                .map(ProviderOrBlock::Block)
+                .unwrap_or_else(|| ProviderOrBlock::Provider(self.provider())),
+            &FilteredParams::default(),
+            num_hash,
+            &receipts,
+            false,
+            timestamp,
+        )?;
+
+        let block_logs = Arc::new(block_logs);
+        self.block_logs.lock().insert(num_hash.hash, block_logs.clone());
+        Ok(Some(block_logs))
     }
 
     /// Retrieves receipts and block from cache if near the tip (4 blocks), otherwise only receipts.
@@ -568,13 +787,107 @@ where
 /// All active filters
 #[derive(Debug, Clone, Default)]
 pub struct ActiveFilters<T> {
-    inner: Arc<Mutex<HashMap<FilterId, ActiveFilter<T>>>>,
+    inner: Arc<Mutex<FilterSlab<T>>>,
 }
 
 impl<T> ActiveFilters<T> {
     /// Returns an empty instance.
     pub fn new() -> Self {
-        Self { inner: Arc::new(Mutex::new(HashMap::default())) }
+        Self { inner: Arc::new(Mutex::new(FilterSlab::default())) }
+    }
+
+    /// Returns the number of installed filters.
+    pub async fn len(&self) -> usize {
+        self.inner.lock().await.len()
+    }
+
+    /// Returns `true` if no filters are installed.
+    pub async fn is_empty(&self) -> bool {
+        self.len().await == 0
+    }
+}
+
+/// Slab of installed filters.
+///
+/// Filters are stored in a single vector and looked up by their slot, slots of uninstalled
+/// filters are reused by the next installed filter.
+#[derive(Debug)]
+struct FilterSlab<T> {
+    /// The installed filters, `None` for vacant slots.
+    entries: Vec<Option<(FilterId, ActiveFilter<T>)>>,
+    /// Vacant slots in `entries`.
+    vacant: Vec<usize>,
+    /// The slot of each installed filter.
+    slots: HashMap<FilterId, usize>,
+}
+
+impl<T> FilterSlab<T> {
+    /// Returns the number of installed filters.
+    fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Inserts the filter, replacing an existing filter with the same id.
+    fn insert(&mut self, id: FilterId, filter: ActiveFilter<T>) {
+        if let Some(slot) = self.slots.get(&id) {
+            self.entries[*slot] = Some((id, filter));
+            return
+        }
+
+        let entry = Some((id.clone(), filter));
+        let slot = match self.vacant.pop() {
+            Some(slot) => {
+                self.entries[slot] = entry;
+                slot
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        };
+        self.slots.insert(id, slot);
+    }
+
+    /// Returns the filter with the given id.
+    fn get(&self, id: &FilterId) -> Option<&ActiveFilter<T>> {
+        let slot = *self.slots.get(id)?;
+        self.entries[slot].as_ref().map(|(_, filter)| filter)
+    }
+
+    /// Returns the filter with the given id mutably.
+    fn get_mut(&mut self, id: &FilterId) -> Option<&mut ActiveFilter<T>> {
+        let slot = *self.slots.get(id)?;
+        self.entries[slot].as_mut().map(|(_, filter)| filter)
+    }
+
+    /// Removes the filter with the given id and returns it.
+    fn remove(&mut self, id: &FilterId) -> Option<ActiveFilter<T>> {
+        let slot = self.slots.remove(id)?;
+        self.vacant.push(slot);
+        self.entries[slot].take().map(|(_, filter)| filter)
+    }
+
+    /// Retains only the filters for which the predicate returns `true`.
+    ///
+    /// Returns the number of removed filters.
+    fn retain(&mut self, mut f: impl FnMut(&FilterId, &ActiveFilter<T>) -> bool) -> usize {
+        let mut removed = 0;
+        for (slot, entry) in self.entries.iter_mut().enumerate() {
+            if entry.as_ref().is_some_and(|(id, filter)| !f(id, filter)) {
+                if let Some((id, _)) = entry.take() {
+                    self.slots.remove(&id);
+                }
+                self.vacant.push(slot);
+                removed += 1;
+            }
+        }
+        removed
+    }
+}
+
+impl<T> Default for FilterSlab<T> {
+    fn default() -> Self {
+        Self { entries: Vec::new(), vacant: Vec::new(), slots: HashMap::default() }
     }
 }
 
@@ -691,11 +1004,43 @@ impl<T: 'static> PendingTransactionKind<T> {
 
 #[derive(Clone, Debug)]
 enum FilterKind<T> {
-    Log(Box<Filter>),
+    Log(Arc<LogFilter>),
     Block,
     PendingTransaction(PendingTransactionKind<T>),
 }
 
+/// A log filter with the state that is needed to match logs, derived once when it's created.
+#[derive(Debug)]
+struct LogFilter {
+    /// The filter as requested.
+    filter: Filter,
+    /// Matches individual logs.
+    params: FilteredParams,
+    /// Bloom prefilter for the addresses of the filter.
+    address_filter: BloomFilter,
+    /// Bloom prefilters for the topics of the filter.
+    topics_filter: Vec<BloomFilter>,
+}
+
+impl LogFilter {
+    fn new(filter: Filter) -> Self {
+        let address_filter = FilteredParams::address_filter(&filter.address);
+        let topics_filter = FilteredParams::topics_filter(&filter.topics);
+        Self {
+            params: FilteredParams::new(Some(filter.clone())),
+            filter,
+            address_filter,
+            topics_filter,
+        }
+    }
+
+    /// Returns `true` if a block with the given logs bloom may contain matching logs.
+    fn matches_bloom(&self, bloom: Bloom) -> bool {
+        FilteredParams::matches_address(bloom, &self.address_filter) &&
+            FilteredParams::matches_topics(bloom, &self.topics_filter)
+    }
+}
+
 /// An iterator that yields _inclusive_ block ranges of a given step size
 #[derive(Debug)]
 struct BlockRangeInclusiveIter {
@@ -723,6 +1068,22 @@ impl Iterator for BlockRangeInclusiveIter {
     }
 }
 
+/// Metrics for installed filters.
+#[derive(Metrics)]
+#[metrics(scope = "rpc.eth_filter")]
+struct EthFilterMetrics {
+    /// The number of installed filters.
+    active_filters: Gauge,
+    /// The number of filters evicted because they were not polled within the filter TTL.
+    stale_filters_evicted_total: Counter,
+    /// The number of filters removed with `eth_uninstallFilter`.
+    filters_uninstalled_total: Counter,
+    /// The number of blocks whose shared logs were already materialized.
+    block_logs_hits_total: Counter,
+    /// The number of blocks whose shared logs had to be materialized from receipts.
+    block_logs_misses_total: Counter,
+}
+
 /// Errors that can occur in the handler implementation
 #[derive(Debug, thiserror::Error)]
 pub enum EthFilterError {
@@ -806,4 +1167,30 @@ mod tests {
 
         assert_eq!(end, *range.end());
     }
+
+    fn active_filter(block: u64) -> ActiveFilter<()> {
+        ActiveFilter { block, last_poll_timestamp: Instant::now(), kind: FilterKind::Block }
+    }
+
+    #[test]
+    fn test_filter_slab_reuses_slots() {
+        let mut slab = FilterSlab::default();
+        let (a, b, c) = (FilterId::from(1u64), FilterId::from(2u64), FilterId::from(3u64));
+        slab.insert(a.clone(), active_filter(1));
+        slab.insert(b.clone(), active_filter(2));
+        assert_eq!(slab.len(), 2);
+
+        assert_eq!(slab.remove(&a).map(|filter| filter.block), Some(1));
+        assert!(slab.get(&a).is_none());
+
+        slab.insert(c.clone(), active_filter(3));
+        assert_eq!(slab.entries.len(), 2);
+        assert_eq!(slab.get(&c).map(|filter| filter.block), Some(3));
+
+        slab.get_mut(&b).unwrap().block = 4;
+        assert_eq!(slab.retain(|_, filter| filter.block > 3), 1);
+        assert_eq!(slab.len(), 1);
+        assert!(slab.get(&c).is_none());
+        assert_eq!(slab.get(&b).map(|filter| filter.block), Some(4));
+    }
 }
@@ -2,7 +2,8 @@ use alloy_primitives::U256;
 use reth_chainspec::EthereumHardforks;
 use reth_network_api::NetworkInfo;
 use reth_provider::{
-    BlockNumReader, BlockReader, ChainSpecProvider, ProviderTx, StageCheckpointReader,
+    BlockNumReader, BlockReader, ChainSpecProvider, LogIndexReader, ProviderTx,
+    StageCheckpointReader,
 };
 use reth_rpc_eth_api::{helpers::EthApiSpec, RpcNodeCore};
 
@@ -13,7 +14,8 @@ where
     Self: RpcNodeCore<
         Provider: ChainSpecProvider<ChainSpec: EthereumHardforks>
                       + BlockNumReader
-                      + StageCheckpointReader,
+                      + StageCheckpointReader
+                      + LogIndexReader,
         Network: NetworkInfo,
     >,
     Provider: BlockReader,
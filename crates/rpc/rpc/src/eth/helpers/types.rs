@@ -113,4 +113,8 @@ where
         };
         *input = input.slice(..4);
     }
+
+    fn otterscan_api_search_transaction(tx: Self::Transaction) -> Option<Transaction> {
+        Some(tx)
+    }
 }
@@ -5,6 +5,8 @@ pub mod core;
 pub mod filter;
 pub mod helpers;
 pub mod pubsub;
+pub mod resumable;
+pub mod send_sync;
 pub mod sim_bundle;
 
 /// Implementation of `eth` namespace API.
@@ -12,6 +14,8 @@ pub use bundle::EthBundle;
 pub use core::EthApi;
 pub use filter::EthFilter;
 pub use pubsub::EthPubSub;
+pub use send_sync::EthSendRawTransactionSync;
+pub use sim_bundle::EthSimBundle;
 
 pub use helpers::{
     signer::DevSigner,
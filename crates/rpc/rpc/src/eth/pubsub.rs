@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use alloy_primitives::TxHash;
 use alloy_rpc_types_eth::{
-    pubsub::{Params, PubSubSyncStatus, SubscriptionKind, SyncStatusMetadata},
+    pubsub::{PubSubSyncStatus, SubscriptionKind, SyncStatusMetadata},
     FilteredParams, Header, Log,
 };
 use futures::StreamExt;
@@ -17,7 +17,10 @@ use reth_provider::{BlockNumReader, CanonStateSubscriptions};
 use reth_rpc_eth_api::{
     pubsub::EthPubSubApiServer, EthApiTypes, RpcNodeCore, RpcTransaction, TransactionCompat,
 };
-use reth_rpc_eth_types::logs_utils;
+use reth_rpc_eth_types::{
+    logs_utils, PendingTransactionFilter, PubSubParams, SubscriptionOptions,
+    SubscriptionResumeConfig, SubscriptionTarget,
+};
 use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
 use reth_rpc_types_compat::transaction::from_recovered;
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
@@ -29,6 +32,8 @@ use tokio_stream::{
 };
 use tracing::error;
 
+use crate::eth::resumable::{pipe_resumable, ResumableSubscriptions};
+
 /// `Eth` pubsub RPC implementation.
 ///
 /// This handles `eth_subscribe` RPC calls.
@@ -38,6 +43,8 @@ pub struct EthPubSub<Eth, Events> {
     inner: Arc<EthPubSubInner<Eth, Events>>,
     /// The type that's used to spawn subscription tasks.
     subscription_task_spawner: Box<dyn TaskSpawner>,
+    /// The subscriptions that can be resumed with `eth_resubscribe`.
+    resumable: ResumableSubscriptions,
 }
 
 // === impl EthPubSub ===
@@ -57,7 +64,14 @@ impl<Eth, Events> EthPubSub<Eth, Events> {
         subscription_task_spawner: Box<dyn TaskSpawner>,
     ) -> Self {
         let inner = EthPubSubInner { eth_api, chain_events };
-        Self { inner: Arc::new(inner), subscription_task_spawner }
+        let resumable = ResumableSubscriptions::new(SubscriptionResumeConfig::default());
+        Self { inner: Arc::new(inner), subscription_task_spawner, resumable }
+    }
+
+    /// Configures the buffering of resumable subscriptions.
+    pub fn with_resume_config(mut self, config: SubscriptionResumeConfig) -> Self {
+        self.resumable = ResumableSubscriptions::new(config);
+        self
     }
 }
 
@@ -73,13 +87,43 @@ where
     async fn subscribe(
         &self,
         pending: PendingSubscriptionSink,
-        kind: SubscriptionKind,
-        params: Option<Params>,
+        target: SubscriptionTarget,
+        params: Option<PubSubParams>,
+        options: Option<SubscriptionOptions>,
     ) -> jsonrpsee::core::SubscriptionResult {
+        let kind = match target {
+            SubscriptionTarget::Kind(kind) => kind,
+            SubscriptionTarget::Resume(cursor) => {
+                let subscription = match self.resumable.get(&cursor) {
+                    Ok(subscription) => subscription,
+                    Err(err) => {
+                        pending.reject(err).await;
+                        return Ok(())
+                    }
+                };
+                let sink = pending.accept().await?;
+                self.subscription_task_spawner.spawn(Box::pin(async move {
+                    let _ =
+                        pipe_resumable(sink, subscription, cursor.sequence.saturating_add(1)).await;
+                }));
+                return Ok(())
+            }
+        };
+
+        let resumable = options.is_some_and(|options| options.resumable);
+        if resumable && self.resumable.config().capacity(kind).is_none() {
+            pending
+                .reject(invalid_params_rpc_err(format!("{kind:?} subscriptions are not resumable")))
+                .await;
+            return Ok(())
+        }
+
         let sink = pending.accept().await?;
         let pubsub = self.inner.clone();
+        let resumable =
+            resumable.then(|| (self.resumable.clone(), self.subscription_task_spawner.clone()));
         self.subscription_task_spawner.spawn(Box::pin(async move {
-            let _ = handle_accepted(pubsub, sink, kind, params).await;
+            let _ = handle_accepted(pubsub, sink, kind, params, resumable).await;
         }));
 
         Ok(())
@@ -91,63 +135,84 @@ async fn handle_accepted<Eth, Events>(
     pubsub: Arc<EthPubSubInner<Eth, Events>>,
     accepted_sink: SubscriptionSink,
     kind: SubscriptionKind,
-    params: Option<Params>,
+    params: Option<PubSubParams>,
+    resumable: Option<(ResumableSubscriptions, Box<dyn TaskSpawner>)>,
 ) -> Result<(), ErrorObject<'static>>
 where
     Events: CanonStateSubscriptions + 'static,
     Eth: RpcNodeCore<Provider: BlockNumReader, Pool: TransactionPool, Network: NetworkInfo>
-        + EthApiTypes<TransactionCompat: TransactionCompat<PoolConsensusTx<Eth::Pool>>>,
+        + EthApiTypes<TransactionCompat: TransactionCompat<PoolConsensusTx<Eth::Pool>>>
+        + 'static,
 {
     match kind {
         SubscriptionKind::NewHeads => {
-            pipe_from_stream(accepted_sink, pubsub.new_headers_stream()).await
+            pipe_subscription(accepted_sink, pubsub.new_headers_stream(), kind, resumable).await
         }
         SubscriptionKind::Logs => {
             // if no params are provided, used default filter params
             let filter = match params {
-                Some(Params::Logs(filter)) => FilteredParams::new(Some(*filter)),
-                Some(Params::Bool(_)) => {
+                Some(PubSubParams::Logs(filter)) => FilteredParams::new(Some(*filter)),
+                Some(PubSubParams::Bool(_)) => {
+                    return Err(invalid_params_rpc_err("Invalid params for logs"))
+                }
+                Some(PubSubParams::PendingTransactions(filter))
+                    if !filter.is_empty() || filter.full_transactions =>
+                {
                     return Err(invalid_params_rpc_err("Invalid params for logs"))
                 }
                 _ => FilteredParams::default(),
             };
-            pipe_from_stream(accepted_sink, pubsub.log_stream(filter)).await
+            pipe_subscription(accepted_sink, pubsub.log_stream(filter), kind, resumable).await
         }
         SubscriptionKind::NewPendingTransactions => {
-            if let Some(params) = params {
-                match params {
-                    Params::Bool(true) => {
-                        // full transaction objects requested
-                        let stream = pubsub.full_pending_transaction_stream().filter_map(|tx| {
-                            let tx_value = match from_recovered(
-                                tx.transaction.to_consensus(),
-                                pubsub.eth_api.tx_resp_builder(),
-                            ) {
-                                Ok(tx) => Some(tx),
-                                Err(err) => {
-                                    error!(target = "rpc",
-                                        %err,
-                                        "Failed to fill transaction with block context"
-                                    );
-                                    None
-                                }
-                            };
-                            std::future::ready(tx_value)
-                        });
-                        return pipe_from_stream(accepted_sink, stream).await
-                    }
-                    Params::Bool(false) | Params::None => {
-                        // only hashes requested
-                    }
-                    Params::Logs(_) => {
-                        return Err(invalid_params_rpc_err(
-                            "Invalid params for newPendingTransactions",
-                        ))
-                    }
+            let filter = match params {
+                Some(PubSubParams::Bool(true)) => {
+                    // full transaction objects requested
+                    PendingTransactionFilter { full_transactions: true, ..Default::default() }
+                }
+                Some(PubSubParams::PendingTransactions(filter)) => filter,
+                Some(PubSubParams::Logs(_)) => {
+                    return Err(invalid_params_rpc_err("Invalid params for newPendingTransactions"))
                 }
+                Some(PubSubParams::Bool(false) | PubSubParams::None) | None => {
+                    // only hashes requested
+                    return pipe_subscription(
+                        accepted_sink,
+                        pubsub.pending_transaction_hashes_stream(),
+                        kind,
+                        resumable,
+                    )
+                    .await
+                }
+            };
+
+            // the filter is evaluated here, so that only matching transactions are sent
+            let full_transactions = filter.full_transactions;
+            let stream = pubsub
+                .full_pending_transaction_stream()
+                .filter(move |tx| std::future::ready(filter.matches(&tx.transaction.transaction)));
+            if !full_transactions {
+                let stream = stream.map(|tx| *tx.transaction.hash());
+                return pipe_subscription(accepted_sink, stream, kind, resumable).await
             }
 
-            pipe_from_stream(accepted_sink, pubsub.pending_transaction_hashes_stream()).await
+            let stream = stream.filter_map(move |tx| {
+                let tx_value = match from_recovered(
+                    tx.transaction.to_consensus(),
+                    pubsub.eth_api.tx_resp_builder(),
+                ) {
+                    Ok(tx) => Some(tx),
+                    Err(err) => {
+                        error!(target = "rpc",
+                            %err,
+                            "Failed to fill transaction with block context"
+                        );
+                        None
+                    }
+                };
+                std::future::ready(tx_value)
+            });
+            pipe_subscription(accepted_sink, stream, kind, resumable).await
         }
         SubscriptionKind::Syncing => {
             // get new block subscription
@@ -192,7 +257,7 @@ where
 pub struct SubscriptionSerializeError(#[from] serde_json::Error);
 
 impl SubscriptionSerializeError {
-    const fn new(err: serde_json::Error) -> Self {
+    pub(crate) const fn new(err: serde_json::Error) -> Self {
         Self(err)
     }
 }
@@ -203,6 +268,25 @@ impl From<SubscriptionSerializeError> for ErrorObject<'static> {
     }
 }
 
+/// Pipes all stream items to the subscription sink, or through a resumable subscription that
+/// buffers the items if requested.
+async fn pipe_subscription<T, St>(
+    sink: SubscriptionSink,
+    stream: St,
+    kind: SubscriptionKind,
+    resumable: Option<(ResumableSubscriptions, Box<dyn TaskSpawner>)>,
+) -> Result<(), ErrorObject<'static>>
+where
+    St: Stream<Item = T> + Send + Unpin + 'static,
+    T: Serialize + Send,
+{
+    let Some((resumable, task_spawner)) = resumable else {
+        return pipe_from_stream(sink, stream).await
+    };
+    let subscription = resumable.create(kind, stream, &*task_spawner)?;
+    pipe_resumable(sink, subscription, 0).await
+}
+
 /// Pipes all stream items to the subscription sink.
 async fn pipe_from_stream<T, St>(
     sink: SubscriptionSink,
@@ -0,0 +1,298 @@
+//! Buffers of resumable `eth_subscribe` subscriptions.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::B128;
+use alloy_rpc_types_eth::pubsub::SubscriptionKind;
+use futures::{Stream, StreamExt};
+use jsonrpsee::{server::SubscriptionMessage, types::ErrorObject, SubscriptionSink};
+use parking_lot::Mutex;
+use reth_rpc_eth_types::{ResumableSubscriptionItem, SubscriptionCursor, SubscriptionResumeConfig};
+use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
+use reth_tasks::TaskSpawner;
+use serde::Serialize;
+use tokio::sync::watch;
+use tracing::error;
+
+use crate::eth::pubsub::SubscriptionSerializeError;
+
+/// The maximum number of resumable subscriptions that are kept at once.
+const MAX_RESUMABLE_SUBSCRIPTIONS: usize = 10_000;
+
+/// The resumable subscriptions of the `eth` pubsub handler.
+///
+/// The events of a resumable subscription are buffered by a task that is independent of the
+/// connection of the subscriber, so that the subscription can be resumed on another connection.
+#[derive(Debug, Clone)]
+pub struct ResumableSubscriptions {
+    subscriptions: Arc<Mutex<HashMap<B128, Arc<ResumableSubscription>>>>,
+    config: SubscriptionResumeConfig,
+}
+
+impl ResumableSubscriptions {
+    /// Creates a new instance with the given config.
+    pub fn new(config: SubscriptionResumeConfig) -> Self {
+        Self { subscriptions: Default::default(), config }
+    }
+
+    /// Returns the config of the resumable subscriptions.
+    pub const fn config(&self) -> &SubscriptionResumeConfig {
+        &self.config
+    }
+
+    /// Creates a resumable subscription that buffers the events of the stream.
+    ///
+    /// This spawns a task that consumes the stream until it ends, or until the subscription isn't
+    /// resumed within the window after its subscriber disconnected.
+    pub(crate) fn create<St, T>(
+        &self,
+        kind: SubscriptionKind,
+        stream: St,
+        task_spawner: &dyn TaskSpawner,
+    ) -> Result<Arc<ResumableSubscription>, ErrorObject<'static>>
+    where
+        St: Stream<Item = T> + Send + Unpin + 'static,
+        T: Serialize + Send,
+    {
+        let Some(capacity) = self.config.capacity(kind) else {
+            return Err(invalid_params_rpc_err(format!("{kind:?} subscriptions are not resumable")))
+        };
+
+        let mut subscriptions = self.subscriptions.lock();
+        if subscriptions.len() >= MAX_RESUMABLE_SUBSCRIPTIONS {
+            return Err(internal_rpc_err("too many resumable subscriptions"))
+        }
+        let id = B128::from(rand::random::<[u8; 16]>());
+        let subscription = Arc::new(ResumableSubscription::new(id, capacity));
+        subscriptions.insert(id, subscription.clone());
+
+        task_spawner.spawn(Box::pin(buffer_events(self.clone(), subscription.clone(), stream)));
+        Ok(subscription)
+    }
+
+    /// Returns the subscription of the cursor, if the events after the cursor are still buffered.
+    pub(crate) fn get(
+        &self,
+        cursor: &SubscriptionCursor,
+    ) -> Result<Arc<ResumableSubscription>, ErrorObject<'static>> {
+        let subscription = self
+            .subscriptions
+            .lock()
+            .get(&cursor.id)
+            .cloned()
+            .ok_or_else(|| invalid_params_rpc_err("unknown or expired subscription cursor"))?;
+        if subscription.events_from(cursor.sequence.saturating_add(1)).is_none() {
+            return Err(invalid_params_rpc_err("events after the cursor are no longer buffered"))
+        }
+        Ok(subscription)
+    }
+}
+
+/// Buffers the events of the stream until the stream ends or the subscription expires.
+async fn buffer_events<St, T>(
+    subscriptions: ResumableSubscriptions,
+    subscription: Arc<ResumableSubscription>,
+    mut stream: St,
+) where
+    St: Stream<Item = T> + Unpin,
+    T: Serialize,
+{
+    let window = subscriptions.config.window;
+    let mut expiry = tokio::time::interval(window);
+    loop {
+        tokio::select! {
+            maybe_item = stream.next() => {
+                let Some(item) = maybe_item else { break };
+                match serde_json::to_value(item) {
+                    Ok(item) => subscription.push(item),
+                    Err(err) => {
+                        error!(target: "rpc", %err, "Failed to serialize subscription item");
+                        break
+                    }
+                }
+            }
+            _ = expiry.tick() => {
+                if subscription.is_expired(window) {
+                    break
+                }
+            }
+        }
+    }
+
+    subscription.close();
+    subscriptions.subscriptions.lock().remove(&subscription.id);
+}
+
+/// A resumable subscription with its buffered events.
+#[derive(Debug)]
+pub(crate) struct ResumableSubscription {
+    id: B128,
+    buffer: Mutex<EventBuffer>,
+    /// Notifies the subscribers about new events.
+    notify: watch::Sender<()>,
+}
+
+impl ResumableSubscription {
+    fn new(id: B128, capacity: usize) -> Self {
+        let buffer = EventBuffer {
+            events: VecDeque::new(),
+            capacity,
+            next_sequence: 0,
+            subscribers: 0,
+            detached_at: Some(Instant::now()),
+            closed: false,
+        };
+        Self { id, buffer: Mutex::new(buffer), notify: watch::Sender::new(()) }
+    }
+
+    fn push(&self, event: serde_json::Value) {
+        let mut buffer = self.buffer.lock();
+        buffer.events.push_back(event);
+        if buffer.events.len() > buffer.capacity {
+            buffer.events.pop_front();
+        }
+        buffer.next_sequence += 1;
+        drop(buffer);
+        self.notify.send_replace(());
+    }
+
+    fn close(&self) {
+        self.buffer.lock().closed = true;
+        self.notify.send_replace(());
+    }
+
+    fn is_expired(&self, window: Duration) -> bool {
+        let buffer = self.buffer.lock();
+        buffer.subscribers == 0 && buffer.detached_at.is_some_and(|at| at.elapsed() >= window)
+    }
+
+    /// Returns the buffered events starting with the given sequence number and whether the
+    /// subscription is closed, `None` if events after the sequence number were dropped.
+    fn events_from(&self, sequence: u64) -> Option<(Vec<(u64, serde_json::Value)>, bool)> {
+        let buffer = self.buffer.lock();
+        let first = buffer.next_sequence - buffer.events.len() as u64;
+        if sequence < first {
+            return None
+        }
+        let events = buffer
+            .events
+            .iter()
+            .skip((sequence - first) as usize)
+            .cloned()
+            .zip(sequence..)
+            .map(|(event, sequence)| (sequence, event))
+            .collect();
+        Some((events, buffer.closed))
+    }
+
+    fn attach(&self) {
+        let mut buffer = self.buffer.lock();
+        buffer.subscribers += 1;
+        buffer.detached_at = None;
+    }
+
+    fn detach(&self) {
+        let mut buffer = self.buffer.lock();
+        buffer.subscribers -= 1;
+        if buffer.subscribers == 0 {
+            buffer.detached_at = Some(Instant::now());
+        }
+    }
+}
+
+#[derive(Debug)]
+struct EventBuffer {
+    events: VecDeque<serde_json::Value>,
+    capacity: usize,
+    /// The sequence number of the next event.
+    next_sequence: u64,
+    /// The number of attached subscribers.
+    subscribers: usize,
+    /// When the last subscriber disconnected.
+    detached_at: Option<Instant>,
+    /// Whether the stream of the subscription ended.
+    closed: bool,
+}
+
+/// Sends the events of the resumable subscription to the sink, starting with the given sequence
+/// number.
+///
+/// If the subscriber falls behind by more than the buffered events, the sink is closed. The
+/// subscriber can't resume the subscription in that case.
+pub(crate) async fn pipe_resumable(
+    sink: SubscriptionSink,
+    subscription: Arc<ResumableSubscription>,
+    mut sequence: u64,
+) -> Result<(), ErrorObject<'static>> {
+    let mut notify = subscription.notify.subscribe();
+    subscription.attach();
+
+    let res = 'pipe: loop {
+        let Some((events, closed)) = subscription.events_from(sequence) else { break Ok(()) };
+        for (event_sequence, item) in events {
+            let cursor = SubscriptionCursor { id: subscription.id, sequence: event_sequence };
+            let msg =
+                match SubscriptionMessage::from_json(&ResumableSubscriptionItem { cursor, item }) {
+                    Ok(msg) => msg,
+                    Err(err) => break 'pipe Err(SubscriptionSerializeError::new(err).into()),
+                };
+            if sink.send(msg).await.is_err() {
+                break 'pipe Ok(())
+            }
+            sequence = event_sequence + 1;
+        }
+        if closed {
+            break Ok(())
+        }
+
+        tokio::select! {
+            _ = sink.closed() => break Ok(()),
+            res = notify.changed() => {
+                if res.is_err() {
+                    break Ok(())
+                }
+            }
+        }
+    };
+
+    subscription.detach();
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_events() {
+        let subscription = ResumableSubscription::new(B128::ZERO, 2);
+        for event in 0..3 {
+            subscription.push(event.into());
+        }
+
+        // the first event was dropped
+        assert!(subscription.events_from(0).is_none());
+        let (events, closed) = subscription.events_from(1).unwrap();
+        assert_eq!(events, [(1, serde_json::Value::from(1)), (2, serde_json::Value::from(2))]);
+        assert!(!closed);
+        assert!(subscription.events_from(3).unwrap().0.is_empty());
+
+        subscription.close();
+        assert!(subscription.events_from(3).unwrap().1);
+    }
+
+    #[test]
+    fn expire_detached_subscriptions() {
+        let subscription = ResumableSubscription::new(B128::ZERO, 1);
+        subscription.attach();
+        assert!(!subscription.is_expired(Duration::ZERO));
+
+        subscription.detach();
+        assert!(subscription.is_expired(Duration::ZERO));
+        assert!(!subscription.is_expired(Duration::from_secs(60)));
+    }
+}
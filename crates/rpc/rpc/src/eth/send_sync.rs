@@ -0,0 +1,239 @@
+//! `eth_sendRawTransactionSync` implementation.
+
+use alloy_primitives::Bytes;
+use futures::StreamExt;
+use jsonrpsee::core::RpcResult;
+use reth_provider::CanonStateSubscriptions;
+use reth_rpc_eth_api::{
+    helpers::{EthTransactions, LoadReceipt},
+    EthSendRawTransactionSyncApiServer, FromEthApiError, RpcNodeCore, RpcReceipt,
+};
+use reth_rpc_eth_types::EthApiError;
+use reth_transaction_pool::{TransactionEvent, TransactionPool};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::broadcast::error::RecvError;
+
+/// Submits transactions and waits until they are included in a canonical block.
+///
+/// Transactions are submitted with [`EthTransactions::send_raw_transaction`], so they are also
+/// forwarded if the node is configured to. Drops are detected with the pool's event stream of the
+/// transaction, and the inclusion with the canonical state notifications.
+pub struct EthSendRawTransactionSync<Eth, Events> {
+    inner: Arc<EthSendRawTransactionSyncInner<Eth, Events>>,
+}
+
+impl<Eth, Events> EthSendRawTransactionSync<Eth, Events> {
+    /// Creates a new instance that waits at most `max_timeout` for the inclusion.
+    pub fn new(eth_api: Eth, events: Events, max_timeout: Duration) -> Self {
+        Self { inner: Arc::new(EthSendRawTransactionSyncInner { eth_api, events, max_timeout }) }
+    }
+}
+
+impl<Eth, Events> EthSendRawTransactionSync<Eth, Events>
+where
+    Eth: EthTransactions + LoadReceipt + 'static,
+    Events: CanonStateSubscriptions,
+{
+    /// Submits the raw transaction and waits until it is included.
+    ///
+    /// The timeout is capped by the configured maximum, which is also the default.
+    pub async fn send_raw_transaction_sync(
+        &self,
+        tx: Bytes,
+        timeout: Option<Duration>,
+    ) -> Result<RpcReceipt<Eth::NetworkTypes>, Eth::Error> {
+        let max_timeout = self.inner.max_timeout;
+        let timeout = timeout.map_or(max_timeout, |timeout| timeout.min(max_timeout));
+
+        // subscribe before submitting, so that the inclusion can't be missed
+        let mut canonical = self.inner.events.subscribe_to_canonical_state();
+        let hash = EthTransactions::send_raw_transaction(&self.inner.eth_api, tx).await?;
+        // the transaction may already have left the pool, in which case the inclusion is awaited
+        // with the canonical notifications only
+        let mut events =
+            futures::stream::iter(self.inner.eth_api.pool().transaction_event_listener(hash))
+                .flatten();
+
+        let wait_for_inclusion = async {
+            let mut pool_events_done = false;
+            loop {
+                tokio::select! {
+                    event = events.next(), if !pool_events_done => {
+                        let reason = match event {
+                            Some(TransactionEvent::Replaced(_)) => "replaced",
+                            Some(TransactionEvent::Discarded) => "discarded",
+                            Some(TransactionEvent::Invalid) => "invalid",
+                            Some(_) => continue,
+                            None => {
+                                // the inclusion is awaited with the canonical notifications
+                                pool_events_done = true;
+                                continue
+                            }
+                        };
+                        return Err(Eth::Error::from_eth_err(EthApiError::TransactionDropped {
+                            hash,
+                            reason,
+                        }))
+                    }
+                    notification = canonical.recv() => {
+                        let included = match notification {
+                            Ok(notification) => notification
+                                .committed()
+                                .inner()
+                                .0
+                                .transaction_hashes()
+                                .any(|included| included == hash),
+                            // notifications were missed, so the transaction may have been included
+                            // in one of them
+                            Err(RecvError::Lagged(_)) => true,
+                            Err(RecvError::Closed) => {
+                                return Err(Eth::Error::from_eth_err(EthApiError::InternalEthError))
+                            }
+                        };
+                        if included {
+                            if let Some(receipt) =
+                                self.inner.eth_api.transaction_receipt(hash).await?
+                            {
+                                return Ok(receipt)
+                            }
+                        }
+                    }
+                }
+            }
+        };
+
+        tokio::time::timeout(timeout, wait_for_inclusion).await.map_err(|_| {
+            Eth::Error::from_eth_err(EthApiError::TransactionConfirmationTimeout {
+                hash,
+                duration: timeout,
+            })
+        })?
+    }
+}
+
+#[async_trait::async_trait]
+impl<Eth, Events> EthSendRawTransactionSyncApiServer<RpcReceipt<Eth::NetworkTypes>>
+    for EthSendRawTransactionSync<Eth, Events>
+where
+    Eth: EthTransactions + LoadReceipt + 'static,
+    Events: CanonStateSubscriptions + 'static,
+{
+    /// Handler for: `eth_sendRawTransactionSync`
+    async fn send_raw_transaction_sync(
+        &self,
+        bytes: Bytes,
+        timeout: Option<u64>,
+    ) -> RpcResult<RpcReceipt<Eth::NetworkTypes>> {
+        Self::send_raw_transaction_sync(self, bytes, timeout.map(Duration::from_millis))
+            .await
+            .map_err(Into::into)
+    }
+}
+
+impl<Eth, Events> std::fmt::Debug for EthSendRawTransactionSync<Eth, Events> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EthSendRawTransactionSync")
+            .field("max_timeout", &self.inner.max_timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Eth, Events> Clone for EthSendRawTransactionSync<Eth, Events> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+/// Container type for [`EthSendRawTransactionSync`].
+#[derive(Debug)]
+struct EthSendRawTransactionSyncInner<Eth, Events> {
+    /// Access to commonly used code of the `eth` namespace
+    eth_api: Eth,
+    /// Canonical state notifications
+    events: Events,
+    /// The maximum time to wait for the inclusion
+    max_timeout: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EthApi;
+    use alloy_eips::eip1559::ETHEREUM_BLOCK_GAS_LIMIT;
+    use alloy_primitives::hex_literal::hex;
+    use reth_chainspec::ChainSpecProvider;
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_provider::test_utils::{NoopProvider, TestCanonStateSubscriptions};
+    use reth_rpc_eth_types::{
+        EthStateCache, FeeHistoryCache, FeeHistoryCacheConfig, GasPriceOracle,
+    };
+    use reth_rpc_server_types::constants::{
+        DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS,
+    };
+    use reth_tasks::pool::BlockingTaskPool;
+    use reth_transaction_pool::test_utils::{testing_pool, TestPool};
+
+    // https://etherscan.io/tx/0xa694b71e6c128a2ed8e2e0f6770bddbe52e3bb8f10e8472f9a79ab81497a8b5d
+    const RAW_TX: [u8; 116] = hex!("02f871018303579880850555633d1b82520894eee27662c2b8eba3cd936a23f039f3189633e4c887ad591c62bdaeb180c080a07ea72c68abfb8fca1bd964f0f99132ed9280261bdca3e549546c0205e800f7d0a05b4ef3039e9c9b9babc179a1878fb825b5aaf5aed2fa8744854150157b08d6f3");
+
+    fn send_sync(
+        pool: TestPool,
+    ) -> EthSendRawTransactionSync<
+        EthApi<NoopProvider, TestPool, NoopNetwork, EthEvmConfig>,
+        TestCanonStateSubscriptions,
+    > {
+        let provider = NoopProvider::default();
+        let evm_config = EthEvmConfig::new(provider.chain_spec());
+        let cache = EthStateCache::spawn(provider, Default::default());
+        let eth_api = EthApi::new(
+            provider,
+            pool,
+            NoopNetwork::default(),
+            cache.clone(),
+            GasPriceOracle::new(provider, Default::default(), cache),
+            ETHEREUM_BLOCK_GAS_LIMIT,
+            DEFAULT_MAX_SIMULATE_BLOCKS,
+            DEFAULT_ETH_PROOF_WINDOW,
+            BlockingTaskPool::build().expect("failed to build tracing pool"),
+            FeeHistoryCache::new(FeeHistoryCacheConfig::default()),
+            evm_config,
+            DEFAULT_PROOF_PERMITS,
+        );
+        EthSendRawTransactionSync::new(
+            eth_api,
+            TestCanonStateSubscriptions::default(),
+            Duration::from_secs(10),
+        )
+    }
+
+    #[tokio::test]
+    async fn submit_and_time_out() {
+        let pool = testing_pool();
+        let send_sync = send_sync(pool.clone());
+
+        let err = send_sync
+            .send_raw_transaction_sync(Bytes::from(RAW_TX), Some(Duration::from_millis(10)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, EthApiError::TransactionConfirmationTimeout { .. }));
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn report_dropped_transaction() {
+        let pool = testing_pool();
+        let send_sync = send_sync(pool.clone());
+
+        let handle = tokio::spawn(async move {
+            send_sync.send_raw_transaction_sync(Bytes::from(RAW_TX), None).await
+        });
+        while pool.is_empty() {
+            tokio::task::yield_now().await;
+        }
+        pool.remove_transactions(pool.pooled_transaction_hashes());
+
+        let err = handle.await.unwrap().unwrap_err();
+        assert!(matches!(err, EthApiError::TransactionDropped { reason: "discarded", .. }));
+    }
+}
@@ -284,13 +284,13 @@ where
         }
 
         let eth_api = self.inner.eth_api.clone();
+        let current_block_number = block_env.number.saturating_to::<u64>();
 
         let sim_response = self
             .inner
             .eth_api
             .spawn_with_state_at_block(current_block, move |state| {
                 // Setup environment
-                let current_block_number = current_block.as_u64().unwrap();
                 let coinbase = block_env.coinbase;
                 let basefee = block_env.basefee;
                 let env = EnvWithHandlerCfg::new_with_cfg_env(cfg, block_env, TxEnv::default());
@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_exex_types::{FinishedExExHeight, SharedExExStatus};
+use reth_rpc_api::{ExExApiServer, ExExInfo, ExExManagerInfo};
+use tracing::trace;
+
+/// `reth` API implementation for introspecting the installed `ExEx`'s.
+///
+/// The status is shared with the `ExEx` manager, which updates it whenever it is polled.
+#[derive(Clone, Debug)]
+pub struct ExExApi {
+    /// The status of the `ExEx` manager.
+    status: SharedExExStatus,
+}
+
+impl ExExApi {
+    /// Creates a new instance of `ExExApi`.
+    pub const fn new(status: SharedExExStatus) -> Self {
+        Self { status }
+    }
+}
+
+#[async_trait]
+impl ExExApiServer for ExExApi {
+    /// Handler for `reth_getExExStatus`
+    async fn get_exex_status(&self) -> RpcResult<ExExManagerInfo> {
+        trace!(target: "rpc::reth", "Serving reth_getExExStatus");
+        let status = self.status.get();
+
+        let exexes = status
+            .exexes
+            .iter()
+            .map(|exex| ExExInfo {
+                id: exex.id.clone(),
+                head: exex.head,
+                finished_height: exex.finished_height,
+                pending_notifications: exex.pending_notifications,
+                lag: status.lag(exex),
+            })
+            .collect();
+        let lowest_finished_height = match status.finished_height {
+            FinishedExExHeight::Height(height) => Some(height),
+            FinishedExExHeight::NoExExs | FinishedExExHeight::NotReady => None,
+        };
+
+        Ok(ExExManagerInfo {
+            exexes,
+            head: status.head,
+            buffered_notifications: status.buffered_notifications,
+            wal_blocks: status.wal_blocks,
+            wal_size_bytes: status.wal_size_bytes,
+            lowest_finished_height,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B256;
+    use reth_exex_types::{ExExManagerStatus, ExExStatus};
+
+    #[tokio::test]
+    async fn exex_status() {
+        let status = SharedExExStatus::default();
+        let api = ExExApi::new(status.clone());
+        assert_eq!(
+            api.get_exex_status().await.unwrap(),
+            ExExManagerInfo {
+                exexes: Vec::new(),
+                head: None,
+                buffered_notifications: 0,
+                wal_blocks: 0,
+                wal_size_bytes: 0,
+                lowest_finished_height: None,
+            }
+        );
+
+        let head = BlockNumHash::new(10, B256::with_last_byte(10));
+        let finished_height = BlockNumHash::new(7, B256::with_last_byte(7));
+        status.set(ExExManagerStatus {
+            exexes: vec![ExExStatus {
+                id: "exex".to_string(),
+                head: Some(head),
+                finished_height: Some(finished_height),
+                pending_notifications: 1,
+            }],
+            head: Some(head),
+            buffered_notifications: 1,
+            wal_blocks: 4,
+            wal_size_bytes: 1024,
+            finished_height: FinishedExExHeight::Height(7),
+        });
+
+        let info = api.get_exex_status().await.unwrap();
+        assert_eq!(info.lowest_finished_height, Some(7));
+        assert_eq!(info.wal_blocks, 4);
+        assert_eq!(info.exexes[0].lag, Some(3));
+        assert_eq!(info.exexes[0].finished_height, Some(finished_height));
+    }
+}
@@ -0,0 +1,437 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::OsString,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{Address, BlockNumber, Keccak256, B256, U256};
+use async_trait::async_trait;
+use futures::StreamExt;
+use jsonrpsee::core::RpcResult;
+use parking_lot::Mutex;
+use reth_primitives::NodePrimitives;
+use reth_primitives_traits::BlockBody;
+use reth_provider::{CanonStateNotification, CanonStateSubscriptions, Chain};
+use reth_rpc_api::{BlockFingerprint, FingerprintApiServer};
+use reth_rpc_eth_types::EthApiError;
+use reth_rpc_server_types::constants::DEFAULT_FINGERPRINTS_MAX_FILE_SIZE_MB;
+use reth_tasks::TaskSpawner;
+use revm::db::BundleState;
+use revm_primitives::AccountInfo;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+/// The maximum number of blocks that are returned by `fingerprint_getBlockFingerprints`.
+const MAX_FINGERPRINTS_BLOCK_RANGE: u64 = 10_000;
+
+/// Configuration for the `fingerprint` API.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FingerprintApiConfig {
+    /// The file block fingerprints are recorded to, `None` if disabled.
+    pub path: Option<PathBuf>,
+    /// The maximum size of the file in bytes before it's rotated.
+    pub max_file_size: u64,
+}
+
+impl Default for FingerprintApiConfig {
+    fn default() -> Self {
+        Self { path: None, max_file_size: DEFAULT_FINGERPRINTS_MAX_FILE_SIZE_MB * 1024 * 1024 }
+    }
+}
+
+/// `fingerprint` API implementation.
+///
+/// This type provides the functionality for handling `fingerprint` related requests.
+#[derive(Debug, Clone)]
+pub struct FingerprintApi {
+    /// The recorder of the block fingerprints, `None` if disabled.
+    recorder: Option<FingerprintRecorder>,
+}
+
+impl FingerprintApi {
+    /// Creates a new instance of the [`FingerprintApi`].
+    ///
+    /// If enabled, this spawns a task that records the fingerprints of the blocks of the canonical
+    /// chain.
+    pub fn new<Events>(
+        events: &Events,
+        task_spawner: Box<dyn TaskSpawner>,
+        config: FingerprintApiConfig,
+    ) -> Self
+    where
+        Events: CanonStateSubscriptions,
+    {
+        let Some(path) = config.path else { return Self { recorder: None } };
+
+        let recorder = match FingerprintRecorder::open(path, config.max_file_size) {
+            Ok(recorder) => recorder,
+            Err(err) => {
+                warn!(target: "rpc::fingerprint", %err, "Failed to open block fingerprints file");
+                return Self { recorder: None }
+            }
+        };
+        info!(target: "rpc::fingerprint", head = ?recorder.head(), "Recording block fingerprints");
+
+        let mut events = events.canonical_state_stream();
+        let this = recorder.clone();
+        task_spawner.spawn(Box::pin(async move {
+            while let Some(notification) = events.next().await {
+                if let Err(err) = this.on_canon_state(&notification) {
+                    warn!(target: "rpc::fingerprint", %err, "Failed to record block fingerprints");
+                }
+            }
+        }));
+        Self { recorder: Some(recorder) }
+    }
+}
+
+#[async_trait]
+impl FingerprintApiServer for FingerprintApi {
+    /// Handler for `fingerprint_getBlockFingerprints`
+    async fn fingerprint_get_block_fingerprints(
+        &self,
+        from_block: u64,
+        to_block: u64,
+    ) -> RpcResult<Vec<BlockFingerprint>> {
+        let Some(recorder) = &self.recorder else {
+            return Err(EthApiError::Unsupported("block fingerprints are disabled").into())
+        };
+        if from_block > to_block {
+            return Err(EthApiError::InvalidBlockRange.into())
+        }
+        if to_block - from_block >= MAX_FINGERPRINTS_BLOCK_RANGE {
+            return Err(EthApiError::InvalidParams(format!(
+                "block range exceeds the maximum of {MAX_FINGERPRINTS_BLOCK_RANGE} blocks"
+            ))
+            .into())
+        }
+        Ok(recorder.fingerprints(from_block, to_block))
+    }
+}
+
+/// Records the fingerprints of canonical blocks to a rolling file.
+///
+/// Fingerprints are appended to the file as JSON lines. If the file exceeds its maximum size, it's
+/// moved to a backup file with the `.1` suffix, replacing the previous backup. The fingerprints of
+/// both files are kept in memory to serve them.
+///
+/// A fingerprint replaces the fingerprints of the block and all later blocks, so the last recorded
+/// fingerprints reflect the canonical chain after a reorg.
+#[derive(Debug, Clone)]
+pub struct FingerprintRecorder {
+    inner: Arc<Mutex<RecorderInner>>,
+}
+
+impl FingerprintRecorder {
+    /// Opens the file at the given path and loads the fingerprints of the file and its backup.
+    pub fn open(path: PathBuf, max_file_size: u64) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut fingerprints = BTreeMap::new();
+        load_fingerprints(&backup_path(&path), &mut fingerprints)?;
+        let file_first_block = load_fingerprints(&path, &mut fingerprints)?;
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let file_size = file.metadata()?.len();
+        let inner = RecorderInner {
+            path,
+            file: BufWriter::new(file),
+            file_size,
+            max_file_size,
+            file_first_block,
+            fingerprints,
+        };
+        Ok(Self { inner: Arc::new(Mutex::new(inner)) })
+    }
+
+    /// Returns the number of the last recorded block.
+    pub fn head(&self) -> Option<BlockNumber> {
+        self.inner.lock().fingerprints.last_key_value().map(|(number, _)| *number)
+    }
+
+    /// Returns the fingerprints of the blocks in the given range.
+    pub fn fingerprints(&self, from_block: u64, to_block: u64) -> Vec<BlockFingerprint> {
+        self.inner
+            .lock()
+            .fingerprints
+            .range(from_block..=to_block)
+            .map(|(_, f)| f.clone())
+            .collect()
+    }
+
+    /// Records the fingerprints of the blocks of the new chain.
+    pub fn on_canon_state<N: NodePrimitives>(
+        &self,
+        notification: &CanonStateNotification<N>,
+    ) -> io::Result<()> {
+        let committed = notification.committed();
+        let mut inner = self.inner.lock();
+        for fingerprint in chain_fingerprints(&committed) {
+            inner.record(fingerprint)?;
+        }
+        inner.file.flush()
+    }
+}
+
+#[derive(Debug)]
+struct RecorderInner {
+    path: PathBuf,
+    file: BufWriter<File>,
+    file_size: u64,
+    max_file_size: u64,
+    /// The first block that was recorded to the file.
+    file_first_block: Option<BlockNumber>,
+    /// The fingerprints of the file and its backup.
+    fingerprints: BTreeMap<BlockNumber, BlockFingerprint>,
+}
+
+impl RecorderInner {
+    fn record(&mut self, fingerprint: BlockFingerprint) -> io::Result<()> {
+        let mut line = serde_json::to_vec(&fingerprint)?;
+        line.push(b'\n');
+
+        if self.file_size > 0 && self.file_size + line.len() as u64 > self.max_file_size {
+            self.rotate()?;
+        }
+
+        self.file.write_all(&line)?;
+        self.file_size += line.len() as u64;
+        self.file_first_block.get_or_insert(fingerprint.number);
+        insert_fingerprint(&mut self.fingerprints, fingerprint);
+        Ok(())
+    }
+
+    /// Moves the file to the backup file and drops the fingerprints of the previous backup.
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        fs::rename(&self.path, backup_path(&self.path))?;
+        let file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.file = BufWriter::new(file);
+        self.file_size = 0;
+
+        if let Some(first) = self.file_first_block.take() {
+            self.fingerprints = self.fingerprints.split_off(&first);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the path of the backup file of the given file.
+fn backup_path(path: &Path) -> PathBuf {
+    let mut path = OsString::from(path);
+    path.push(".1");
+    path.into()
+}
+
+/// Loads the fingerprints of the file, if it exists, and returns the first block of the file.
+///
+/// Lines that can't be parsed, e.g. a partially written last line, are skipped.
+fn load_fingerprints(
+    path: &Path,
+    fingerprints: &mut BTreeMap<BlockNumber, BlockFingerprint>,
+) -> io::Result<Option<BlockNumber>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let mut first = None;
+    for line in BufReader::new(file).lines() {
+        let Ok(fingerprint) = serde_json::from_str::<BlockFingerprint>(&line?) else { continue };
+        first.get_or_insert(fingerprint.number);
+        insert_fingerprint(fingerprints, fingerprint);
+    }
+    Ok(first)
+}
+
+/// Inserts the fingerprint, replacing the fingerprints of the block and all later blocks.
+fn insert_fingerprint(
+    fingerprints: &mut BTreeMap<BlockNumber, BlockFingerprint>,
+    fingerprint: BlockFingerprint,
+) {
+    fingerprints.split_off(&fingerprint.number);
+    fingerprints.insert(fingerprint.number, fingerprint);
+}
+
+/// Returns the fingerprints of the blocks of the chain.
+fn chain_fingerprints<N: NodePrimitives>(chain: &Chain<N>) -> Vec<BlockFingerprint> {
+    let outcome = chain.execution_outcome();
+    let bundle_state_hashes = bundle_state_hashes(outcome.state());
+
+    chain
+        .blocks()
+        .iter()
+        .map(|(number, block)| {
+            let bundle_state_hash = number
+                .checked_sub(outcome.first_block())
+                .and_then(|index| bundle_state_hashes.get(index as usize))
+                .copied()
+                .unwrap_or_default();
+            BlockFingerprint {
+                number: *number,
+                hash: block.hash(),
+                state_root: block.header.state_root(),
+                receipts_root: block.header.receipts_root(),
+                transaction_count: block.body.transactions().len() as u64,
+                gas_used: block.header.gas_used(),
+                bundle_state_hash,
+            }
+        })
+        .collect()
+}
+
+/// Returns the hash of the changes of each block of the bundle state, in block order.
+///
+/// The bundle state only contains the values after the last block, so the values after each block
+/// are restored by walking the reverts of the blocks backwards.
+fn bundle_state_hashes(bundle: &BundleState) -> Vec<B256> {
+    let reverts = bundle.reverts.to_plain_state_reverts();
+
+    // values after the current block that differ from the values of the bundle state
+    let mut accounts = HashMap::<Address, Option<AccountInfo>>::new();
+    let mut storage = HashMap::<(Address, U256), U256>::new();
+
+    let mut hashes = Vec::with_capacity(reverts.accounts.len());
+    for (account_reverts, storage_reverts) in reverts.accounts.iter().zip(&reverts.storage).rev() {
+        let mut changes = BlockChanges::default();
+        for (address, previous) in account_reverts {
+            let info = accounts
+                .remove(address)
+                .unwrap_or_else(|| bundle.account(address).and_then(|acc| acc.info.clone()));
+            changes.accounts.insert(*address, info);
+            accounts.insert(*address, previous.clone());
+        }
+        for revert in storage_reverts {
+            for (slot, previous) in &revert.storage_revert {
+                let key = (revert.address, *slot);
+                let value = storage.remove(&key).unwrap_or_else(|| {
+                    bundle
+                        .account(&revert.address)
+                        .and_then(|acc| acc.storage_slot(*slot))
+                        .unwrap_or_default()
+                });
+                changes.storage.insert(key, value);
+                storage.insert(key, previous.to_previous_value());
+            }
+        }
+        hashes.push(changes.hash());
+    }
+
+    hashes.reverse();
+    hashes
+}
+
+/// The accounts and storage slots that were changed by a block, with their values after the block.
+#[derive(Debug, Default)]
+struct BlockChanges {
+    accounts: BTreeMap<Address, Option<AccountInfo>>,
+    storage: BTreeMap<(Address, U256), U256>,
+}
+
+impl BlockChanges {
+    fn hash(&self) -> B256 {
+        let mut hasher = Keccak256::new();
+
+        hasher.update((self.accounts.len() as u64).to_be_bytes());
+        for (address, info) in &self.accounts {
+            hasher.update(address);
+            match info {
+                Some(info) => {
+                    hasher.update([1]);
+                    hasher.update(info.nonce.to_be_bytes());
+                    hasher.update(info.balance.to_be_bytes::<32>());
+                    hasher.update(info.code_hash);
+                }
+                None => hasher.update([0]),
+            }
+        }
+
+        hasher.update((self.storage.len() as u64).to_be_bytes());
+        for ((address, slot), value) in &self.storage {
+            hasher.update(address);
+            hasher.update(slot.to_be_bytes::<32>());
+            hasher.update(value.to_be_bytes::<32>());
+        }
+
+        hasher.finalize()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(nonce: u64) -> AccountInfo {
+        AccountInfo { nonce, ..Default::default() }
+    }
+
+    fn fingerprint(number: u64) -> BlockFingerprint {
+        BlockFingerprint { number, hash: B256::with_last_byte(number as u8), ..Default::default() }
+    }
+
+    #[test]
+    fn bundle_state_hashes_of_blocks() {
+        let address = Address::with_last_byte(1);
+        let slot = U256::from(1);
+
+        // the nonce is increased in both blocks, the slot is only set in the first block
+        let bundle = BundleState::new(
+            [(
+                address,
+                None,
+                Some(account(2)),
+                [(slot, (U256::ZERO, U256::from(5)))].into_iter().collect(),
+            )],
+            [
+                vec![(address, Some(None), vec![(slot, U256::ZERO)])],
+                vec![(address, Some(Some(account(1))), vec![])],
+            ],
+            [],
+        );
+
+        let first = BlockChanges {
+            accounts: [(address, Some(account(1)))].into(),
+            storage: [((address, slot), U256::from(5))].into(),
+        };
+        let second =
+            BlockChanges { accounts: [(address, Some(account(2)))].into(), ..Default::default() };
+        assert_eq!(bundle_state_hashes(&bundle), [first.hash(), second.hash()]);
+    }
+
+    #[test]
+    fn rotate_and_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fingerprints.jsonl");
+        let line_len = serde_json::to_vec(&fingerprint(1)).unwrap().len() as u64 + 1;
+
+        // two fingerprints fit into a file
+        let recorder = FingerprintRecorder::open(path.clone(), 2 * line_len).unwrap();
+        {
+            let mut inner = recorder.inner.lock();
+            for number in 1..=5 {
+                inner.record(fingerprint(number)).unwrap();
+            }
+            // reorg of the last block
+            inner.record(fingerprint(4)).unwrap();
+            inner.file.flush().unwrap();
+        }
+
+        // the first file was dropped with the second rotation
+        let numbers = |recorder: &FingerprintRecorder| {
+            recorder.fingerprints(0, 10).iter().map(|f| f.number).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(&recorder), [3, 4]);
+        assert!(backup_path(&path).exists());
+
+        let reloaded = FingerprintRecorder::open(path, 2 * line_len).unwrap();
+        assert_eq!(numbers(&reloaded), [3, 4]);
+        assert_eq!(reloaded.head(), Some(4));
+    }
+}
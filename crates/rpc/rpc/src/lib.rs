@@ -32,29 +32,46 @@ use jsonwebtoken as _;
 use pin_project as _;
 use tower as _;
 
+mod abi;
 mod admin;
 mod debug;
 mod engine;
 pub mod eth;
+mod exex;
+mod fingerprint;
 mod miner;
+mod native_tracer;
 mod net;
+mod nonce;
 mod otterscan;
+mod pin;
 mod reth;
 mod rpc;
+mod stats;
 mod trace;
 mod txpool;
 mod validation;
 mod web3;
 
+pub use abi::{
+    AbiLookup, AbiRegistry, AbiRegistryError, DEFAULT_ABI_LOOKUP_CACHE_SIZE, DEFAULT_FOUR_BYTE_URL,
+    DEFAULT_SOURCIFY_URL,
+};
 pub use admin::AdminApi;
 pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
-pub use eth::{EthApi, EthBundle, EthFilter, EthPubSub};
+pub use eth::{EthApi, EthBundle, EthFilter, EthPubSub, EthSendRawTransactionSync, EthSimBundle};
+pub use exex::ExExApi;
+pub use fingerprint::{FingerprintApi, FingerprintApiConfig, FingerprintRecorder};
 pub use miner::MinerApi;
+pub use native_tracer::{ConfigurableTracer, NativeTracerError, NativeTracers};
 pub use net::NetApi;
-pub use otterscan::OtterscanApi;
-pub use reth::RethApi;
+pub use nonce::NonceApi;
+pub use otterscan::{OtterscanApi, OtterscanApiConfig};
+pub use pin::{BlockPinApi, DEFAULT_BLOCK_PIN_TTL, MAX_BLOCK_PIN_TTL};
+pub use reth::{RethApi, RethApiConfig};
 pub use rpc::RPCApi;
+pub use stats::{ChainStats, StatsApi, StatsApiConfig};
 pub use trace::TraceApi;
 pub use txpool::TxPoolApi;
 pub use validation::{ValidationApi, ValidationApiConfig};
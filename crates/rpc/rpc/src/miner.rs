@@ -2,21 +2,39 @@ use alloy_primitives::{Bytes, U128};
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_rpc_api::MinerApiServer;
+use reth_transaction_pool::TransactionPool;
+use tracing::trace;
 
 /// `miner` API implementation.
 ///
 /// This type provides the functionality for handling `miner` related requests.
-#[derive(Clone, Debug, Default)]
-pub struct MinerApi {}
+#[derive(Clone, Debug)]
+pub struct MinerApi<Pool> {
+    /// An interface to interact with the pool
+    pool: Pool,
+}
+
+impl<Pool> MinerApi<Pool> {
+    /// Creates a new instance of `MinerApi`.
+    pub const fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
 
 #[async_trait]
-impl MinerApiServer for MinerApi {
+impl<Pool> MinerApiServer for MinerApi<Pool>
+where
+    Pool: TransactionPool + 'static,
+{
     fn set_extra(&self, _record: Bytes) -> RpcResult<bool> {
         Ok(false)
     }
 
-    fn set_gas_price(&self, _gas_price: U128) -> RpcResult<bool> {
-        Ok(false)
+    /// Handler for `miner_setGasPrice`
+    fn set_gas_price(&self, gas_price: U128) -> RpcResult<bool> {
+        trace!(target: "rpc::miner", %gas_price, "Serving miner_setGasPrice");
+        self.pool.set_minimum_priority_fee(gas_price.to());
+        Ok(true)
     }
 
     fn set_gas_limit(&self, _gas_price: U128) -> RpcResult<bool> {
@@ -0,0 +1,132 @@
+//! Registry of native tracers that can be selected by name in the `debug` tracing methods.
+
+use alloy_rpc_types_eth::TransactionInfo;
+use alloy_rpc_types_trace::geth::{GethDebugTracerConfig, GethDebugTracerType};
+use reth_errors::RethError;
+use reth_rpc_eth_types::EthApiError;
+use revm_inspectors::tracing::{TracingInspector, TracingInspectorConfig};
+use revm_primitives::ResultAndState;
+use std::{collections::HashMap, fmt, sync::Arc};
+
+/// A native tracer that can be selected by name with the `tracer` field of the tracing options,
+/// e.g. in `debug_traceTransaction`, `debug_traceCall` and the block tracing methods.
+///
+/// The transaction is executed with a [`TracingInspector`] that is configured by the tracer, the
+/// tracer then builds its result from the recorded traces. This allows to ship bespoke tracers
+/// without a JS runtime.
+pub trait ConfigurableTracer: fmt::Debug + Send + Sync + 'static {
+    /// Returns the configuration of the inspector for the given `tracerConfig`.
+    fn inspector_config(
+        &self,
+        config: &GethDebugTracerConfig,
+    ) -> Result<TracingInspectorConfig, NativeTracerError>;
+
+    /// Builds the result of the tracer from the inspector that executed the transaction.
+    fn trace(
+        &self,
+        config: &GethDebugTracerConfig,
+        inspector: &TracingInspector,
+        result: &ResultAndState,
+        tx_info: &TransactionInfo,
+    ) -> Result<serde_json::Value, NativeTracerError>;
+}
+
+/// Errors of a [`ConfigurableTracer`].
+#[derive(Debug, thiserror::Error)]
+pub enum NativeTracerError {
+    /// The `tracerConfig` is invalid for the tracer.
+    #[error("invalid tracer config: {0}")]
+    InvalidConfig(String),
+    /// The trace could not be built.
+    #[error("{0}")]
+    Trace(String),
+}
+
+impl From<NativeTracerError> for EthApiError {
+    fn from(err: NativeTracerError) -> Self {
+        match err {
+            err @ NativeTracerError::InvalidConfig(_) => Self::InvalidParams(err.to_string()),
+            NativeTracerError::Trace(msg) => Self::Internal(RethError::msg(msg)),
+        }
+    }
+}
+
+/// The registered [`ConfigurableTracer`]s by name.
+///
+/// The names of the built-in tracers, e.g. `callTracer`, always select the built-in tracer.
+#[derive(Debug, Clone, Default)]
+pub struct NativeTracers {
+    tracers: HashMap<String, Arc<dyn ConfigurableTracer>>,
+}
+
+impl NativeTracers {
+    /// Registers the tracer under the given name, replacing a tracer with the same name.
+    pub fn register(&mut self, name: impl Into<String>, tracer: impl ConfigurableTracer) {
+        self.tracers.insert(name.into(), Arc::new(tracer));
+    }
+
+    /// Returns the tracer that is selected by the given `tracer` option, if any.
+    ///
+    /// Tracers that aren't built in are parsed as JS tracers, so a registered tracer takes
+    /// precedence over the JS tracer with the same code.
+    pub fn get(&self, tracer: &GethDebugTracerType) -> Option<&Arc<dyn ConfigurableTracer>> {
+        match tracer {
+            GethDebugTracerType::JsTracer(name) => self.tracers.get(name),
+            GethDebugTracerType::BuiltInTracer(_) => None,
+        }
+    }
+
+    /// Returns the names of all registered tracers.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tracers.keys().map(String::as_str)
+    }
+
+    /// Returns `true` if no tracers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.tracers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_trace::geth::GethDebugBuiltInTracerType;
+
+    #[derive(Debug)]
+    struct CallCountTracer;
+
+    impl ConfigurableTracer for CallCountTracer {
+        fn inspector_config(
+            &self,
+            _config: &GethDebugTracerConfig,
+        ) -> Result<TracingInspectorConfig, NativeTracerError> {
+            Ok(TracingInspectorConfig::default_geth())
+        }
+
+        fn trace(
+            &self,
+            _config: &GethDebugTracerConfig,
+            inspector: &TracingInspector,
+            _result: &ResultAndState,
+            _tx_info: &TransactionInfo,
+        ) -> Result<serde_json::Value, NativeTracerError> {
+            Ok(inspector.traces().nodes().len().into())
+        }
+    }
+
+    #[test]
+    fn select_registered_tracer() {
+        let mut tracers = NativeTracers::default();
+        assert!(tracers.is_empty());
+        tracers.register("callCountTracer", CallCountTracer);
+
+        let tracer: GethDebugTracerType =
+            serde_json::from_value(serde_json::json!("callCountTracer")).unwrap();
+        assert!(tracers.get(&tracer).is_some());
+        assert!(tracers.get(&GethDebugTracerType::JsTracer("{}".to_string())).is_none());
+        assert!(tracers
+            .get(&GethDebugTracerType::BuiltInTracer(GethDebugBuiltInTracerType::CallTracer))
+            .is_none());
+        assert_eq!(tracers.names().collect::<Vec<_>>(), ["callCountTracer"]);
+    }
+}
@@ -0,0 +1,113 @@
+use alloy_primitives::Address;
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_provider::StateProviderFactory;
+use reth_rpc_api::{NextNonce, NonceApiServer, NonceGap};
+use reth_rpc_eth_types::EthApiError;
+use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use tracing::trace;
+
+/// `reth` API implementation for looking up the next usable nonce of an account.
+#[derive(Clone, Debug)]
+pub struct NonceApi<Provider, Pool> {
+    /// The provider to look up the on-chain nonce with.
+    provider: Provider,
+    /// The transaction pool.
+    pool: Pool,
+}
+
+impl<Provider, Pool> NonceApi<Provider, Pool> {
+    /// Creates a new instance of `NonceApi`.
+    pub const fn new(provider: Provider, pool: Pool) -> Self {
+        Self { provider, pool }
+    }
+}
+
+impl<Provider, Pool> NonceApi<Provider, Pool>
+where
+    Provider: StateProviderFactory,
+    Pool: TransactionPool,
+{
+    /// Returns the next usable nonce of the account, taking the pooled transactions into account.
+    pub fn next_nonce(&self, address: Address) -> Result<NextNonce, EthApiError> {
+        let chain_nonce = self.provider.latest()?.account_nonce(address)?.unwrap_or_default();
+        let pool_nonces = self
+            .pool
+            .get_transactions_by_sender(address)
+            .into_iter()
+            .map(|tx| tx.transaction.nonce())
+            .collect();
+        Ok(next_nonce(chain_nonce, pool_nonces))
+    }
+}
+
+/// Computes the next usable nonce and the nonce gaps from the nonce of the account and the nonces
+/// of its pooled transactions.
+///
+/// Pooled nonces below the chain nonce are stale and ignored.
+fn next_nonce(chain_nonce: u64, mut pool_nonces: Vec<u64>) -> NextNonce {
+    pool_nonces.retain(|nonce| *nonce >= chain_nonce);
+    pool_nonces.sort_unstable();
+    pool_nonces.dedup();
+
+    let mut next = NextNonce {
+        chain_nonce,
+        next_nonce: chain_nonce,
+        highest_pool_nonce: pool_nonces.last().copied(),
+        ..Default::default()
+    };
+
+    let mut expected = chain_nonce;
+    let mut gapped = false;
+    for nonce in pool_nonces {
+        if nonce > expected {
+            next.gaps.push(NonceGap { start: expected, end: nonce - 1 });
+            gapped = true;
+        }
+        if gapped {
+            next.queued += 1;
+        } else {
+            next.next_nonce = nonce.saturating_add(1);
+        }
+        expected = nonce.saturating_add(1);
+    }
+    next
+}
+
+#[async_trait]
+impl<Provider, Pool> NonceApiServer for NonceApi<Provider, Pool>
+where
+    Provider: StateProviderFactory + 'static,
+    Pool: TransactionPool + 'static,
+{
+    /// Handler for `reth_getNextNonce`
+    async fn get_next_nonce(&self, address: Address) -> RpcResult<NextNonce> {
+        trace!(target: "rpc::reth", ?address, "Serving reth_getNextNonce");
+        Ok(self.next_nonce(address)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_nonce_with_gaps() {
+        let next = next_nonce(5, vec![3, 5, 6, 9, 8, 12]);
+        assert_eq!(next.chain_nonce, 5);
+        assert_eq!(next.next_nonce, 7);
+        assert_eq!(next.highest_pool_nonce, Some(12));
+        assert_eq!(next.queued, 3);
+        assert_eq!(next.gaps, vec![NonceGap { start: 7, end: 7 }, NonceGap { start: 10, end: 11 }]);
+
+        // a replacement has the same nonce as the replaced transaction
+        let next = next_nonce(5, vec![5, 5]);
+        assert_eq!(next.next_nonce, 6);
+        assert!(next.gaps.is_empty());
+
+        let next = next_nonce(5, vec![6]);
+        assert_eq!(next.next_nonce, 5);
+        assert_eq!(next.gaps, vec![NonceGap { start: 5, end: 5 }]);
+        assert_eq!(next.queued, 1);
+    }
+}
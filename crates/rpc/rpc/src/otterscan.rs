@@ -1,7 +1,7 @@
 use alloy_consensus::{BlockHeader, Transaction};
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_network::{ReceiptResponse, TransactionResponse};
-use alloy_primitives::{Address, Bytes, TxHash, B256, U256};
+use alloy_primitives::{Address, BlockNumber, Bytes, TxHash, B256, U256};
 use alloy_rpc_types_eth::{BlockTransactions, TransactionReceipt};
 use alloy_rpc_types_trace::{
     otterscan::{
@@ -12,10 +12,15 @@ use alloy_rpc_types_trace::{
 };
 use async_trait::async_trait;
 use jsonrpsee::{core::RpcResult, types::ErrorObjectOwned};
+use reth_primitives_traits::SignedTransaction;
+use reth_provider::{
+    AddressTransactionsReader, BlockNumReader, HeaderProvider, TransactionsProvider,
+};
 use reth_rpc_api::{EthApiServer, OtterscanServer};
 use reth_rpc_eth_api::{
-    helpers::{EthTransactions, TraceExt},
-    FullEthApiTypes, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction, TransactionCompat,
+    helpers::{EthTransactions, SpawnBlocking, TraceExt},
+    FromEthApiError, FullEthApiTypes, RpcBlock, RpcHeader, RpcReceipt, RpcTransaction,
+    TransactionCompat,
 };
 use reth_rpc_eth_types::{utils::binary_search, EthApiError};
 use reth_rpc_server_types::result::internal_rpc_err;
@@ -24,26 +29,52 @@ use revm_inspectors::{
     transfer::{TransferInspector, TransferKind},
 };
 use revm_primitives::{ExecutionResult, SignedAuthorization};
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
 
 const API_LEVEL: u64 = 8;
 
+/// Configuration for the `ots` API.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OtterscanApiConfig {
+    /// Whether the transaction-by-address index is maintained, which is required to search the
+    /// transactions of an address.
+    pub address_index: bool,
+}
+
 /// Otterscan API.
 #[derive(Debug)]
-pub struct OtterscanApi<Eth> {
+pub struct OtterscanApi<Provider, Eth> {
+    provider: Provider,
     eth: Eth,
+    config: OtterscanApiConfig,
 }
 
-impl<Eth> OtterscanApi<Eth> {
+impl<Provider, Eth> OtterscanApi<Provider, Eth> {
     /// Creates a new instance of `Otterscan`.
-    pub const fn new(eth: Eth) -> Self {
-        Self { eth }
+    pub const fn new(provider: Provider, eth: Eth, config: OtterscanApiConfig) -> Self {
+        Self { provider, eth, config }
     }
 }
 
-impl<Eth> OtterscanApi<Eth>
+impl<Provider, Eth> OtterscanApi<Provider, Eth>
 where
-    Eth: FullEthApiTypes,
+    Provider: BlockNumReader
+        + HeaderProvider
+        + TransactionsProvider
+        + AddressTransactionsReader
+        + Clone
+        + 'static,
+    Eth: FullEthApiTypes + SpawnBlocking,
 {
+    /// Returns an error if the transaction-by-address index is not maintained.
+    fn ensure_address_index(&self) -> RpcResult<()> {
+        if !self.config.address_index {
+            return Err(EthApiError::Unsupported("transaction-by-address index is disabled").into())
+        }
+        Ok(())
+    }
+
     /// Constructs a `BlockDetails` from a block and its receipts.
     fn block_details(
         &self,
@@ -58,12 +89,147 @@ where
 
         Ok(BlockDetails::new(block, Default::default(), U256::from(total_fees)))
     }
+
+    /// Searches the transaction-by-address index for the transactions of `address` within the
+    /// block range, walking backwards from the end of the range if `reverse` is set.
+    ///
+    /// A page never ends in the middle of a block: once `page_size` transactions are found, the
+    /// remaining transactions of the last block are included as well.
+    ///
+    /// Returns the hash and block timestamp of each transaction, and whether there are more
+    /// transactions beyond the last block.
+    async fn search_transactions(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        page_size: usize,
+        reverse: bool,
+    ) -> RpcResult<(Vec<(TxHash, u64)>, bool)> {
+        let provider = self.provider.clone();
+        self.eth
+            .spawn_blocking_io(move |_| {
+                Self::try_search_transactions(&provider, address, block_range, page_size, reverse)
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+            .map_err(Into::into)
+    }
+
+    fn try_search_transactions(
+        provider: &Provider,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        page_size: usize,
+        reverse: bool,
+    ) -> Result<(Vec<(TxHash, u64)>, bool), EthApiError> {
+        let (start, end) = block_range.clone().into_inner();
+        let mut entries =
+            provider.transactions_by_address(address, block_range, page_size, reverse)?;
+
+        let mut has_more = false;
+        let last_block = entries
+            .last()
+            .filter(|_| entries.len() >= page_size)
+            .map(|(_, block_number)| *block_number);
+        if let Some(last_block) = last_block {
+            entries.retain(|(_, block_number)| *block_number != last_block);
+            entries.extend(provider.transactions_by_address(
+                address,
+                last_block..=last_block,
+                usize::MAX,
+                reverse,
+            )?);
+
+            let remaining = if reverse {
+                (last_block > start).then(|| start..=last_block - 1)
+            } else {
+                (last_block < end).then(|| last_block + 1..=end)
+            };
+            if let Some(remaining) = remaining {
+                has_more =
+                    !provider.transactions_by_address(address, remaining, 1, reverse)?.is_empty();
+            }
+        }
+
+        let mut last_header = None;
+        let transactions = entries
+            .into_iter()
+            .map(|(tx_number, block_number)| {
+                let hash = provider
+                    .transaction_by_id(tx_number)?
+                    .map(|tx| *tx.tx_hash())
+                    .ok_or(EthApiError::TransactionNotFound)?;
+                let timestamp = match last_header {
+                    Some((number, timestamp)) if number == block_number => timestamp,
+                    _ => {
+                        let timestamp = provider
+                            .header_by_number(block_number)?
+                            .ok_or(EthApiError::HeaderNotFound(block_number.into()))?
+                            .timestamp();
+                        last_header = Some((block_number, timestamp));
+                        timestamp
+                    }
+                };
+                Ok((hash, timestamp))
+            })
+            .collect::<Result<Vec<_>, EthApiError>>()?;
+
+        Ok((transactions, has_more))
+    }
+
+    /// Fetches the transactions and receipts of a transaction search result.
+    ///
+    /// Transactions without an Ethereum representation are skipped.
+    async fn transactions_with_receipts(
+        &self,
+        transactions: Vec<(TxHash, u64)>,
+        first_page: bool,
+        last_page: bool,
+    ) -> RpcResult<TransactionsWithReceipts>
+    where
+        Eth: EthApiServer<
+            RpcTransaction<Eth::NetworkTypes>,
+            RpcBlock<Eth::NetworkTypes>,
+            RpcReceipt<Eth::NetworkTypes>,
+            RpcHeader<Eth::NetworkTypes>,
+        >,
+    {
+        let entries = futures::future::try_join_all(transactions.into_iter().map(
+            |(hash, timestamp)| async move {
+                let tx = EthApiServer::transaction_by_hash(&self.eth, hash);
+                let receipt = EthApiServer::transaction_receipt(&self.eth, hash);
+                let (tx, receipt) = futures::try_join!(tx, receipt)?;
+                let tx = tx.ok_or(EthApiError::TransactionNotFound)?;
+                let receipt = receipt.ok_or(EthApiError::TransactionNotFound)?;
+                Ok::<_, ErrorObjectOwned>((tx, receipt, timestamp))
+            },
+        ))
+        .await?;
+
+        let mut txs = Vec::with_capacity(entries.len());
+        let mut receipts = Vec::with_capacity(entries.len());
+        for (tx, receipt, timestamp) in entries {
+            let Some(tx) = Eth::TransactionCompat::otterscan_api_search_transaction(tx) else {
+                continue
+            };
+            receipts.push(ots_receipt(&receipt, tx.ty(), Some(timestamp)));
+            txs.push(tx);
+        }
+
+        Ok(TransactionsWithReceipts { txs, receipts, first_page, last_page })
+    }
 }
 
 #[async_trait]
-impl<Eth> OtterscanServer<RpcTransaction<Eth::NetworkTypes>, RpcHeader<Eth::NetworkTypes>>
-    for OtterscanApi<Eth>
+impl<Provider, Eth> OtterscanServer<RpcTransaction<Eth::NetworkTypes>, RpcHeader<Eth::NetworkTypes>>
+    for OtterscanApi<Provider, Eth>
 where
+    Provider: BlockNumReader
+        + HeaderProvider
+        + TransactionsProvider
+        + AddressTransactionsReader
+        + Clone
+        + 'static,
     Eth: EthApiServer<
             RpcTransaction<Eth::NetworkTypes>,
             RpcBlock<Eth::NetworkTypes>,
@@ -253,35 +419,7 @@ where
         let receipts = receipts
             .drain(page_start..page_end)
             .zip(transactions.iter().map(Transaction::ty))
-            .map(|(receipt, tx_ty)| {
-                let inner = OtsReceipt {
-                    status: receipt.status(),
-                    cumulative_gas_used: receipt.cumulative_gas_used() as u64,
-                    logs: None,
-                    logs_bloom: None,
-                    r#type: tx_ty,
-                };
-
-                let receipt = TransactionReceipt {
-                    inner,
-                    transaction_hash: receipt.transaction_hash(),
-                    transaction_index: receipt.transaction_index(),
-                    block_hash: receipt.block_hash(),
-                    block_number: receipt.block_number(),
-                    gas_used: receipt.gas_used(),
-                    effective_gas_price: receipt.effective_gas_price(),
-                    blob_gas_used: receipt.blob_gas_used(),
-                    blob_gas_price: receipt.blob_gas_price(),
-                    from: receipt.from(),
-                    to: receipt.to(),
-                    contract_address: receipt.contract_address(),
-                    authorization_list: receipt
-                        .authorization_list()
-                        .map(<[SignedAuthorization]>::to_vec),
-                };
-
-                OtsTransactionReceipt { receipt, timestamp }
-            })
+            .map(|(receipt, tx_ty)| ots_receipt(&receipt, tx_ty, timestamp))
             .collect();
 
         // use `transaction_count` to indicate the paginate information
@@ -291,23 +429,50 @@ where
     }
 
     /// Handler for `searchTransactionsBefore`
+    ///
+    /// Returns the transactions of `address` in the blocks before `block_number`, newest first.
+    /// A `block_number` of 0 searches from the latest block on.
     async fn search_transactions_before(
         &self,
-        _address: Address,
-        _block_number: u64,
-        _page_size: usize,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
     ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        self.ensure_address_index()?;
+
+        let first_page = block_number == 0;
+        let end = if first_page {
+            self.provider.best_block_number().map_err(EthApiError::from)?
+        } else {
+            block_number - 1
+        };
+
+        let (transactions, has_more) =
+            self.search_transactions(address, 0..=end, page_size, true).await?;
+        self.transactions_with_receipts(transactions, first_page, !has_more).await
     }
 
     /// Handler for `searchTransactionsAfter`
+    ///
+    /// Returns the transactions of `address` in the blocks after `block_number`, newest first.
     async fn search_transactions_after(
         &self,
-        _address: Address,
-        _block_number: u64,
-        _page_size: usize,
+        address: Address,
+        block_number: u64,
+        page_size: usize,
     ) -> RpcResult<TransactionsWithReceipts> {
-        Err(internal_rpc_err("unimplemented"))
+        self.ensure_address_index()?;
+
+        let last_page = block_number == 0;
+        let latest = self.provider.best_block_number().map_err(EthApiError::from)?;
+        if block_number >= latest {
+            return self.transactions_with_receipts(Vec::new(), true, last_page).await
+        }
+
+        let (mut transactions, has_more) =
+            self.search_transactions(address, block_number + 1..=latest, page_size, false).await?;
+        transactions.reverse();
+        self.transactions_with_receipts(transactions, !has_more, last_page).await
     }
 
     /// Handler for `getTransactionBySenderAndNonce`
@@ -388,3 +553,36 @@ where
         Ok(found)
     }
 }
+
+/// Converts a receipt into the trimmed down receipt returned by the `ots_` transaction listings.
+fn ots_receipt<R: ReceiptResponse>(
+    receipt: &R,
+    tx_ty: u8,
+    timestamp: Option<u64>,
+) -> OtsTransactionReceipt {
+    let inner = OtsReceipt {
+        status: receipt.status(),
+        cumulative_gas_used: receipt.cumulative_gas_used() as u64,
+        logs: None,
+        logs_bloom: None,
+        r#type: tx_ty,
+    };
+
+    let receipt = TransactionReceipt {
+        inner,
+        transaction_hash: receipt.transaction_hash(),
+        transaction_index: receipt.transaction_index(),
+        block_hash: receipt.block_hash(),
+        block_number: receipt.block_number(),
+        gas_used: receipt.gas_used(),
+        effective_gas_price: receipt.effective_gas_price(),
+        blob_gas_used: receipt.blob_gas_used(),
+        blob_gas_price: receipt.blob_gas_price(),
+        from: receipt.from(),
+        to: receipt.to(),
+        contract_address: receipt.contract_address(),
+        authorization_list: receipt.authorization_list().map(<[SignedAuthorization]>::to_vec),
+    };
+
+    OtsTransactionReceipt { receipt, timestamp }
+}
@@ -0,0 +1,93 @@
+use alloy_eips::BlockId;
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_provider::{BlockIdReader, StateProviderFactory};
+use reth_prune_types::{BlockPin, BlockPins};
+use reth_rpc_api::{BlockPinApiServer, PinnedBlock};
+use reth_rpc_eth_types::EthApiError;
+use std::time::{Duration, Instant};
+use tracing::trace;
+
+/// The default time to live of a pin.
+pub const DEFAULT_BLOCK_PIN_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// The maximum time to live of a pin.
+pub const MAX_BLOCK_PIN_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// `reth` API implementation for pinning blocks.
+///
+/// The pins are shared with the pruner, which doesn't prune the history of pinned blocks.
+#[derive(Clone, Debug)]
+pub struct BlockPinApi<Provider> {
+    /// The provider to resolve blocks with.
+    provider: Provider,
+    /// All pinned blocks.
+    block_pins: BlockPins,
+}
+
+impl<Provider> BlockPinApi<Provider> {
+    /// Creates a new instance of `BlockPinApi`.
+    pub const fn new(provider: Provider, block_pins: BlockPins) -> Self {
+        Self { provider, block_pins }
+    }
+}
+
+/// Converts a [`BlockPin`] into the RPC representation.
+fn pinned_block(pin: BlockPin) -> PinnedBlock {
+    PinnedBlock {
+        id: pin.id,
+        number: pin.number,
+        hash: pin.hash,
+        expires_in: pin.expires_at.saturating_duration_since(Instant::now()).as_secs(),
+    }
+}
+
+#[async_trait]
+impl<Provider> BlockPinApiServer for BlockPinApi<Provider>
+where
+    Provider: BlockIdReader + StateProviderFactory + 'static,
+{
+    /// Handler for `reth_pinBlock`
+    async fn pin_block(&self, block_id: BlockId, ttl: Option<u64>) -> RpcResult<PinnedBlock> {
+        trace!(target: "rpc::reth", ?block_id, ?ttl, "Serving reth_pinBlock");
+        let ttl = ttl.map(Duration::from_secs).unwrap_or(DEFAULT_BLOCK_PIN_TTL);
+        if ttl.is_zero() || ttl > MAX_BLOCK_PIN_TTL {
+            return Err(EthApiError::InvalidParams(format!(
+                "ttl must be between 1 and {} seconds",
+                MAX_BLOCK_PIN_TTL.as_secs()
+            ))
+            .into())
+        }
+
+        // resolve the hash by number, so that both refer to the same block if a tag is given
+        let Some(number) =
+            self.provider.block_number_for_id(block_id).map_err(EthApiError::from)?
+        else {
+            return Err(EthApiError::HeaderNotFound(block_id).into())
+        };
+        let Some(hash) = self.provider.block_hash(number).map_err(EthApiError::from)? else {
+            return Err(EthApiError::HeaderNotFound(block_id).into())
+        };
+
+        // ensure that the state of the block is still available, i.e. not pruned already
+        self.provider.history_by_block_hash(hash).map_err(EthApiError::from)?;
+
+        let pin = self
+            .block_pins
+            .pin(number, hash, ttl)
+            .map_err(|err| EthApiError::InvalidParams(err.to_string()))?;
+        Ok(pinned_block(pin))
+    }
+
+    /// Handler for `reth_unpinBlock`
+    async fn unpin_block(&self, id: u64) -> RpcResult<bool> {
+        trace!(target: "rpc::reth", id, "Serving reth_unpinBlock");
+        Ok(self.block_pins.unpin(id))
+    }
+
+    /// Handler for `reth_getPinnedBlock`
+    async fn get_pinned_block(&self, id: u64) -> RpcResult<Option<PinnedBlock>> {
+        trace!(target: "rpc::reth", id, "Serving reth_getPinnedBlock");
+        Ok(self.block_pins.get(id).map(pinned_block))
+    }
+}
@@ -1,41 +1,151 @@
-use std::{collections::HashMap, future::Future, sync::Arc};
+use std::{collections::HashMap, future::Future, ops::RangeInclusive, path::PathBuf, sync::Arc};
 
-use alloy_eips::BlockId;
-use alloy_primitives::{Address, U256};
+use alloy_consensus::BlockHeader;
+use alloy_eips::{eip4895::Withdrawal, BlockId, BlockNumHash, BlockNumberOrTag};
+use alloy_primitives::{
+    keccak256, map::HashSet, Address, BlockNumber, Bytes, Selector, TxHash, B256, U256, U64,
+};
+use alloy_rpc_types_eth::{Filter, FilterBlockOption, FilteredParams, Log};
+use alloy_trie::{proof::ProofRetainer, root::adjust_index_for_rlp, HashBuilder, Nibbles};
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult;
-use reth_errors::RethResult;
-use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
-use reth_rpc_api::RethApiServer;
-use reth_rpc_eth_types::{EthApiError, EthResult};
-use reth_tasks::TaskSpawner;
+use futures::StreamExt;
+use jsonrpsee::{
+    core::RpcResult, types::ErrorObject, PendingSubscriptionSink, SubscriptionMessage,
+    SubscriptionSink,
+};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec};
+use reth_errors::{RethError, RethResult};
+use reth_network_api::{
+    events::{PeerEvent, PeerEventStream},
+    NetworkPeersEvents, PeerId, PeerInfo, Peers,
+};
+use reth_primitives_traits::{Receipt, SignedTransaction};
+use reth_provider::{
+    BlockNumReader, BlockReaderIdExt, ChangeSetReader, HeaderProvider, ReceiptProvider,
+    StateProviderFactory, TransactionSelectorReader, WithdrawalHistoryReader,
+};
+use reth_rpc_api::{
+    BalanceProofNotification, DecodedLog, PeerEventKind, PeerEventNotification, ReceiptProof,
+    RethApiServer, WithdrawalHistoryEntry,
+};
+use reth_rpc_eth_types::{
+    logs_utils::{append_matching_block_logs, ProviderOrBlock},
+    EthApiError, EthResult,
+};
+use reth_tasks::{pool::BlockingTaskGuard, TaskSpawner};
+use serde::{Deserialize, Serialize};
 use tokio::sync::oneshot;
+use tracing::{info, warn};
+
+use crate::{
+    abi::{AbiLookup, AbiRegistry},
+    eth::pubsub::SubscriptionSerializeError,
+};
+
+/// The maximum number of transactions returned by `reth_getTransactionsBySelector`.
+const MAX_TRANSACTIONS_BY_SELECTOR: usize = 10_000;
+
+/// The maximum number of withdrawals returned by `reth_getWithdrawalsByAddress` and
+/// `reth_getWithdrawalsByValidator`.
+const MAX_WITHDRAWAL_HISTORY: usize = 10_000;
+
+/// The maximum number of blocks that are searched by `reth_getDecodedLogs`.
+const MAX_DECODED_LOGS_BLOCK_RANGE: u64 = 10_000;
+
+/// The maximum number of logs returned by `reth_getDecodedLogs`.
+const MAX_DECODED_LOGS: usize = 10_000;
+
+/// The maximum number of addresses that are proven by `reth_subscribeBalanceProofs`.
+const MAX_BALANCE_PROOF_ADDRESSES: usize = 100_000;
+
+/// The maximum number of headers between the proof block and the anchor block of
+/// `reth_subscribeBalanceProofs`.
+const MAX_BALANCE_PROOF_ANCHOR_HEADERS: u64 = 10_000;
+
+/// The number of account proofs that are computed together and sent in one notification by
+/// `reth_subscribeBalanceProofs`.
+const BALANCE_PROOF_BATCH_SIZE: usize = 256;
+
+/// Configuration for the `reth` API.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RethApiConfig {
+    /// Directory with JSON ABIs or build artifacts that are used to decode logs.
+    pub abi_dir: Option<PathBuf>,
+    /// Whether events that are not in the ABI directory are looked up on Sourcify and 4byte.
+    pub abi_lookup: bool,
+}
+
+impl RethApiConfig {
+    /// Creates the [`AbiRegistry`] for the configuration.
+    ///
+    /// ABIs that fail to load are skipped with a warning.
+    fn abi_registry(&self) -> AbiRegistry {
+        let mut registry = AbiRegistry::new();
+        if let Some(dir) = &self.abi_dir {
+            match registry.add_abi_dir(dir) {
+                Ok(loaded) => info!(target: "rpc::reth", ?dir, loaded, "Loaded ABIs"),
+                Err(err) => warn!(target: "rpc::reth", %err, "Failed to load ABIs"),
+            }
+        }
+        if self.abi_lookup {
+            registry = registry.with_lookup(AbiLookup::default());
+        }
+        registry
+    }
+}
 
 /// `reth` API implementation.
 ///
 /// This type provides the functionality for handling `reth` prototype RPC requests.
-pub struct RethApi<Provider> {
-    inner: Arc<RethApiInner<Provider>>,
+pub struct RethApi<Provider, Network> {
+    inner: Arc<RethApiInner<Provider, Network>>,
 }
 
 // === impl RethApi ===
 
-impl<Provider> RethApi<Provider> {
+impl<Provider, Network> RethApi<Provider, Network> {
     /// The provider that can interact with the chain.
     pub fn provider(&self) -> &Provider {
         &self.inner.provider
     }
 
+    /// The network the node is connected to.
+    pub fn network(&self) -> &Network {
+        &self.inner.network
+    }
+
     /// Create a new instance of the [`RethApi`]
-    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
-        let inner = Arc::new(RethApiInner { provider, task_spawner });
+    pub fn new(
+        provider: Provider,
+        network: Network,
+        task_spawner: Box<dyn TaskSpawner>,
+        max_proof_window: u64,
+        proof_guard: BlockingTaskGuard,
+        config: RethApiConfig,
+    ) -> Self {
+        let abi_registry = config.abi_registry();
+        let inner = Arc::new(RethApiInner {
+            provider,
+            network,
+            task_spawner,
+            max_proof_window,
+            proof_guard,
+            abi_registry,
+        });
         Self { inner }
     }
 }
 
-impl<Provider> RethApi<Provider>
+impl<Provider, Network> RethApi<Provider, Network>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt
+        + ChainSpecProvider
+        + ChangeSetReader
+        + StateProviderFactory
+        + TransactionSelectorReader
+        + WithdrawalHistoryReader
+        + 'static,
+    Network: Peers + NetworkPeersEvents + 'static,
 {
     /// Executes the future on a new blocking task.
     async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
@@ -83,12 +193,340 @@ where
         )?;
         Ok(hash_map)
     }
+
+    /// Returns the hashes of the transactions calling the function with the given selector.
+    pub async fn transactions_by_selector(
+        &self,
+        selector: Selector,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> EthResult<Vec<TxHash>> {
+        self.on_blocking_task(|this| async move {
+            this.try_transactions_by_selector(selector, from_block, to_block, limit)
+        })
+        .await
+    }
+
+    fn try_transactions_by_selector(
+        &self,
+        selector: Selector,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> EthResult<Vec<TxHash>> {
+        let from_block = self.block_number(from_block.unwrap_or(BlockNumberOrTag::Earliest))?;
+        let to_block = self.block_number(to_block.unwrap_or(BlockNumberOrTag::Latest))?;
+        if from_block > to_block {
+            return Err(EthApiError::InvalidBlockRange)
+        }
+
+        let limit = limit.unwrap_or(MAX_TRANSACTIONS_BY_SELECTOR).min(MAX_TRANSACTIONS_BY_SELECTOR);
+        self.provider()
+            .transactions_by_selector(selector, from_block..=to_block, limit)?
+            .into_iter()
+            .map(|(tx_number, _)| {
+                self.provider()
+                    .transaction_by_id(tx_number)?
+                    .map(|transaction| *transaction.tx_hash())
+                    .ok_or(EthApiError::TransactionNotFound)
+            })
+            .collect()
+    }
+
+    /// Returns the withdrawals to the given address.
+    pub async fn withdrawals_by_address(
+        &self,
+        address: Address,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> EthResult<Vec<WithdrawalHistoryEntry>> {
+        self.on_blocking_task(|this| async move {
+            let (block_range, limit) =
+                this.withdrawal_history_range(from_block, to_block, limit)?;
+            Ok(withdrawal_history_entries(this.provider().withdrawals_by_address(
+                address,
+                block_range,
+                limit,
+            )?))
+        })
+        .await
+    }
+
+    /// Returns the withdrawals of the validator with the given index.
+    pub async fn withdrawals_by_validator(
+        &self,
+        validator_index: u64,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> EthResult<Vec<WithdrawalHistoryEntry>> {
+        self.on_blocking_task(|this| async move {
+            let (block_range, limit) =
+                this.withdrawal_history_range(from_block, to_block, limit)?;
+            Ok(withdrawal_history_entries(this.provider().withdrawals_by_validator(
+                validator_index,
+                block_range,
+                limit,
+            )?))
+        })
+        .await
+    }
+
+    /// Resolves the block range and the limit of a withdrawal history query.
+    fn withdrawal_history_range(
+        &self,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> EthResult<(RangeInclusive<BlockNumber>, usize)> {
+        let from_block = self.block_number(from_block.unwrap_or(BlockNumberOrTag::Earliest))?;
+        let to_block = self.block_number(to_block.unwrap_or(BlockNumberOrTag::Latest))?;
+        if from_block > to_block {
+            return Err(EthApiError::InvalidBlockRange)
+        }
+
+        let limit = limit.unwrap_or(MAX_WITHDRAWAL_HISTORY).min(MAX_WITHDRAWAL_HISTORY);
+        Ok((from_block..=to_block, limit))
+    }
+
+    /// Returns the logs matching the filter, with their events decoded using the ABI registry.
+    pub async fn decoded_logs(&self, filter: Filter) -> EthResult<Vec<DecodedLog>> {
+        let logs = self.on_blocking_task(|this| async move { this.try_logs(&filter) }).await?;
+        let chain_id = self.provider().chain_spec().chain_id();
+
+        let inner = logs.iter().map(|log| &log.inner).collect::<Vec<_>>();
+        let events = self.inner.abi_registry.decode_all(chain_id, &inner).await;
+        Ok(logs.into_iter().zip(events).map(|(log, event)| DecodedLog { log, event }).collect())
+    }
+
+    fn try_logs(&self, filter: &Filter) -> EthResult<Vec<Log>> {
+        let (from_block, to_block) = match filter.block_option {
+            FilterBlockOption::AtBlockHash(block_hash) => {
+                let number = self
+                    .provider()
+                    .block_number(block_hash)?
+                    .ok_or_else(|| EthApiError::HeaderNotFound(block_hash.into()))?;
+                (number, number)
+            }
+            FilterBlockOption::Range { from_block, to_block } => (
+                self.block_number(from_block.unwrap_or(BlockNumberOrTag::Latest))?,
+                self.block_number(to_block.unwrap_or(BlockNumberOrTag::Latest))?,
+            ),
+        };
+        if from_block > to_block {
+            return Err(EthApiError::InvalidBlockRange)
+        }
+        if to_block - from_block >= MAX_DECODED_LOGS_BLOCK_RANGE {
+            return Err(EthApiError::InvalidParams(format!(
+                "block range exceeds the maximum of {MAX_DECODED_LOGS_BLOCK_RANGE} blocks"
+            )))
+        }
+
+        let filter_params = FilteredParams::new(Some(filter.clone()));
+        let address_filter = FilteredParams::address_filter(&filter.address);
+        let topics_filter = FilteredParams::topics_filter(&filter.topics);
+
+        let mut logs = Vec::new();
+        for header in self.provider().sealed_headers_range(from_block..=to_block)? {
+            if !FilteredParams::matches_address(header.logs_bloom(), &address_filter) ||
+                !FilteredParams::matches_topics(header.logs_bloom(), &topics_filter)
+            {
+                continue
+            }
+
+            let num_hash = BlockNumHash::new(header.number(), header.hash());
+            let receipts = self
+                .provider()
+                .receipts_by_block(num_hash.hash.into())?
+                .ok_or_else(|| EthApiError::ReceiptsNotFound(num_hash.hash.into()))?;
+            append_matching_block_logs(
+                &mut logs,
+                ProviderOrBlock::Provider(self.provider()),
+                &filter_params,
+                num_hash,
+                &receipts,
+                false,
+                header.timestamp(),
+            )?;
+
+            if logs.len() > MAX_DECODED_LOGS {
+                return Err(EthApiError::InvalidParams(format!(
+                    "query returned more than {MAX_DECODED_LOGS} logs"
+                )))
+            }
+        }
+        Ok(logs)
+    }
+
+    /// Returns the receipt of the transaction together with its proof against the receipts root
+    /// of its block.
+    pub async fn receipt_proof(&self, hash: TxHash) -> EthResult<Option<ReceiptProof>> {
+        self.on_blocking_task(|this| async move { this.try_receipt_proof(hash) }).await
+    }
+
+    fn try_receipt_proof(&self, hash: TxHash) -> EthResult<Option<ReceiptProof>> {
+        let Some((_, meta)) = self.provider().transaction_by_hash_with_meta(hash)? else {
+            return Ok(None)
+        };
+
+        let header = self
+            .provider()
+            .sealed_header(meta.block_number)?
+            .ok_or_else(|| EthApiError::HeaderNotFound(meta.block_hash.into()))?;
+        let receipts = self
+            .provider()
+            .receipts_by_block(meta.block_hash.into())?
+            .ok_or_else(|| EthApiError::ReceiptsNotFound(meta.block_hash.into()))?;
+        let index = meta.index as usize;
+        let receipt = receipts
+            .get(index)
+            .ok_or_else(|| EthApiError::ReceiptsNotFound(meta.block_hash.into()))?;
+
+        // the receipts are rebuilt from storage, which e.g. doesn't retain the state root of
+        // pre-byzantium receipts, so the proof is only returned if it matches the header
+        let (receipts_root, proof) = receipt_proof(&receipts, index);
+        if receipts_root != header.receipts_root() {
+            return Err(EthApiError::Internal(RethError::msg(format!(
+                "receipts root mismatch for block {}: computed {receipts_root}, expected {}",
+                meta.block_number,
+                header.receipts_root()
+            ))))
+        }
+
+        let mut encoded_receipt = Vec::new();
+        receipt.eip2718_encode_with_bloom(&receipt.bloom(), &mut encoded_receipt);
+        Ok(Some(ReceiptProof {
+            block_hash: header.hash(),
+            block_number: header.number(),
+            header: alloy_rlp::encode(header.header()).into(),
+            transaction_index: meta.index,
+            receipt: encoded_receipt.into(),
+            proof,
+        }))
+    }
+
+    /// Returns the range of blocks from the proof block up to the anchor block of
+    /// `reth_subscribeBalanceProofs`.
+    fn balance_proof_range(
+        &self,
+        num_addresses: usize,
+        block_id: BlockId,
+        anchor: Option<BlockId>,
+    ) -> EthResult<RangeInclusive<u64>> {
+        if num_addresses == 0 || num_addresses > MAX_BALANCE_PROOF_ADDRESSES {
+            return Err(EthApiError::InvalidParams(format!(
+                "number of addresses must be between 1 and {MAX_BALANCE_PROOF_ADDRESSES}"
+            )))
+        }
+
+        let block_number = self
+            .provider()
+            .block_number_for_id(block_id)?
+            .ok_or(EthApiError::HeaderNotFound(block_id))?;
+        let best_number = self.provider().best_block_number()?;
+        if best_number.saturating_sub(block_number) > self.inner.max_proof_window {
+            return Err(EthApiError::ExceedsMaxProofWindow)
+        }
+        let anchor_number = match anchor {
+            Some(anchor) => self
+                .provider()
+                .block_number_for_id(anchor)?
+                .ok_or(EthApiError::HeaderNotFound(anchor))?,
+            None => block_number,
+        };
+        if anchor_number < block_number {
+            return Err(EthApiError::InvalidParams(
+                "anchor block must not be before the proof block".to_string(),
+            ));
+        }
+        if anchor_number - block_number >= MAX_BALANCE_PROOF_ANCHOR_HEADERS {
+            return Err(EthApiError::InvalidParams(format!(
+                "anchor block exceeds the maximum distance of {MAX_BALANCE_PROOF_ANCHOR_HEADERS} blocks"
+            )))
+        }
+        Ok(block_number..=anchor_number)
+    }
+
+    /// Sends the anchoring headers and the account proofs of the addresses at the first block of
+    /// the range to the subscription.
+    async fn pipe_balance_proofs(
+        &self,
+        sink: &SubscriptionSink,
+        addresses: Vec<Address>,
+        range: RangeInclusive<u64>,
+    ) -> Result<(), ErrorObject<'static>> {
+        let block_number = *range.start();
+        let headers =
+            self.provider().sealed_headers_range(range.clone()).map_err(EthApiError::from)?;
+        if headers.len() as u64 != range.end() - block_number + 1 {
+            return Err(
+                EthApiError::HeaderRangeNotFound(block_number.into(), (*range.end()).into()).into()
+            )
+        }
+        let headers =
+            headers.iter().map(|header| alloy_rlp::encode(header.header()).into()).collect();
+        if !send_balance_proof_notification(sink, &BalanceProofNotification::Headers { headers })
+            .await?
+        {
+            return Ok(())
+        }
+
+        // all proofs are computed on the same historical state, one multiproof per batch
+        let state =
+            self.provider().history_by_block_number(block_number).map_err(EthApiError::from)?;
+        let mut remaining = addresses.len();
+        for batch in addresses.chunks(BALANCE_PROOF_BATCH_SIZE) {
+            // each batch takes a permit that is shared with `eth_getProof`
+            let _permit = self
+                .inner
+                .proof_guard
+                .clone()
+                .acquire_owned()
+                .await
+                .map_err(|err| EthApiError::Internal(RethError::other(err)))?;
+            let targets =
+                batch.iter().map(|address| (keccak256(address), HashSet::default())).collect();
+            let multiproof =
+                state.multiproof(Default::default(), targets).map_err(EthApiError::from)?;
+            let proofs = batch
+                .iter()
+                .map(|address| {
+                    multiproof
+                        .account_proof(*address, &[])
+                        .map(|proof| proof.into_eip1186_response(Vec::new()))
+                        .map_err(|err| EthApiError::Internal(RethError::other(err)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            remaining -= batch.len();
+            let notification = BalanceProofNotification::Proofs { proofs, remaining };
+            if !send_balance_proof_notification(sink, &notification).await? {
+                return Ok(())
+            }
+        }
+        Ok(())
+    }
+
+    fn block_number(&self, block: BlockNumberOrTag) -> EthResult<u64> {
+        self.provider()
+            .convert_block_number(block)?
+            .ok_or_else(|| EthApiError::HeaderNotFound(block.into()))
+    }
 }
 
 #[async_trait]
-impl<Provider> RethApiServer for RethApi<Provider>
+impl<Provider, Network> RethApiServer for RethApi<Provider, Network>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt
+        + ChainSpecProvider
+        + ChangeSetReader
+        + StateProviderFactory
+        + TransactionSelectorReader
+        + WithdrawalHistoryReader
+        + 'static,
+    Network: Peers + NetworkPeersEvents + 'static,
 {
     /// Handler for `reth_getBalanceChangesInBlock`
     async fn reth_get_balance_changes_in_block(
@@ -97,23 +535,335 @@ where
     ) -> RpcResult<HashMap<Address, U256>> {
         Ok(Self::balance_changes_in_block(self, block_id).await?)
     }
+
+    /// Handler for `reth_getTransactionsBySelector`
+    async fn reth_get_transactions_by_selector(
+        &self,
+        selector: Selector,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<TxHash>> {
+        Ok(Self::transactions_by_selector(self, selector, from_block, to_block, limit).await?)
+    }
+
+    /// Handler for `reth_getWithdrawalsByAddress`
+    async fn reth_get_withdrawals_by_address(
+        &self,
+        address: Address,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<WithdrawalHistoryEntry>> {
+        Ok(Self::withdrawals_by_address(self, address, from_block, to_block, limit).await?)
+    }
+
+    /// Handler for `reth_getWithdrawalsByValidator`
+    async fn reth_get_withdrawals_by_validator(
+        &self,
+        validator_index: U64,
+        from_block: Option<BlockNumberOrTag>,
+        to_block: Option<BlockNumberOrTag>,
+        limit: Option<usize>,
+    ) -> RpcResult<Vec<WithdrawalHistoryEntry>> {
+        Ok(Self::withdrawals_by_validator(self, validator_index.to(), from_block, to_block, limit)
+            .await?)
+    }
+
+    /// Handler for `reth_getDecodedLogs`
+    async fn reth_get_decoded_logs(&self, filter: Filter) -> RpcResult<Vec<DecodedLog>> {
+        Ok(Self::decoded_logs(self, filter).await?)
+    }
+
+    /// Handler for `reth_getReceiptProof`
+    async fn reth_get_receipt_proof(&self, hash: TxHash) -> RpcResult<Option<ReceiptProof>> {
+        Ok(Self::receipt_proof(self, hash).await?)
+    }
+
+    /// Handler for `reth_subscribePeerEvents`
+    async fn reth_subscribe_peer_events(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        let sink = pending.accept().await?;
+        let events = self.network().peer_events();
+        let this = self.clone();
+        self.inner.task_spawner.spawn(Box::pin(async move {
+            let _ = pipe_peer_events(this.network(), sink, events).await;
+        }));
+        Ok(())
+    }
+
+    /// Handler for `reth_subscribeBalanceProofs`
+    async fn reth_subscribe_balance_proofs(
+        &self,
+        pending: PendingSubscriptionSink,
+        addresses: Vec<Address>,
+        block_id: BlockId,
+        anchor: Option<BlockId>,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        let range = match self.balance_proof_range(addresses.len(), block_id, anchor) {
+            Ok(range) => range,
+            Err(err) => {
+                pending.reject(ErrorObject::from(err)).await;
+                return Ok(())
+            }
+        };
+
+        let sink = pending.accept().await?;
+        let this = self.clone();
+        self.inner.task_spawner.spawn_blocking(Box::pin(async move {
+            if let Err(err) = this.pipe_balance_proofs(&sink, addresses, range).await {
+                warn!(target: "rpc::reth", %err, "Failed to stream balance proofs");
+                let notification =
+                    BalanceProofNotification::Error { message: err.message().to_string() };
+                let _ = send_balance_proof_notification(&sink, &notification).await;
+            }
+        }));
+        Ok(())
+    }
+}
+
+/// Converts the withdrawals with their block numbers to the entries returned by
+/// `reth_getWithdrawalsByAddress` and `reth_getWithdrawalsByValidator`.
+fn withdrawal_history_entries(
+    withdrawals: Vec<(BlockNumber, Withdrawal)>,
+) -> Vec<WithdrawalHistoryEntry> {
+    withdrawals
+        .into_iter()
+        .map(|(block_number, withdrawal)| WithdrawalHistoryEntry { block_number, withdrawal })
+        .collect()
+}
+
+/// Computes the receipts root of the given receipts and the proof of the receipt at the given
+/// index, i.e. the trie nodes from the root to the receipt.
+fn receipt_proof<R: Receipt>(receipts: &[R], index: usize) -> (B256, Vec<Bytes>) {
+    let target = Nibbles::unpack(alloy_rlp::encode_fixed_size(&index));
+    let mut hash_builder =
+        HashBuilder::default().with_proof_retainer(ProofRetainer::from_iter([target.clone()]));
+
+    // the leaves must be added in the order of their keys, the RLP encoded indices
+    let mut value = Vec::new();
+    for i in 0..receipts.len() {
+        let index = adjust_index_for_rlp(i, receipts.len());
+        let receipt = &receipts[index];
+        value.clear();
+        receipt.eip2718_encode_with_bloom(&receipt.bloom(), &mut value);
+        hash_builder.add_leaf(Nibbles::unpack(alloy_rlp::encode_fixed_size(&index)), &value);
+    }
+
+    let root = hash_builder.root();
+    let proof = hash_builder
+        .take_proof_nodes()
+        .matching_nodes_sorted(&target)
+        .into_iter()
+        .map(|(_, node)| node)
+        .collect();
+    (root, proof)
+}
+
+/// Sends the notification to the subscription.
+///
+/// Returns `false` if the subscription was closed.
+async fn send_balance_proof_notification(
+    sink: &SubscriptionSink,
+    notification: &BalanceProofNotification,
+) -> Result<bool, ErrorObject<'static>> {
+    let msg =
+        SubscriptionMessage::from_json(notification).map_err(SubscriptionSerializeError::new)?;
+    Ok(sink.send(msg).await.is_ok())
+}
+
+/// Forwards the peer events of the network to the subscription until either is closed.
+async fn pipe_peer_events<Network: Peers>(
+    network: &Network,
+    sink: SubscriptionSink,
+    mut events: PeerEventStream,
+) -> Result<(), ErrorObject<'static>> {
+    // known details of peers, which are kept after a peer disconnected to detect protocol upgrades
+    // when it reconnects
+    let mut peers = HashMap::new();
+    loop {
+        let event = tokio::select! {
+            _ = sink.closed() => break Ok(()),
+            event = events.next() => match event {
+                Some(event) => event,
+                None => break Ok(()),
+            },
+        };
+
+        // look up the ENR of newly connected peers
+        let peer_info = match &event {
+            PeerEvent::SessionEstablished(info) => {
+                network.get_peer_by_id(info.peer_id).await.ok().flatten()
+            }
+            _ => None,
+        };
+
+        for notification in peer_event_notifications(&mut peers, event, peer_info) {
+            let msg = SubscriptionMessage::from_json(&notification)
+                .map_err(SubscriptionSerializeError::new)?;
+            if sink.send(msg).await.is_err() {
+                return Ok(())
+            }
+        }
+    }
 }
 
-impl<Provider> std::fmt::Debug for RethApi<Provider> {
+/// Known details of a peer, see [`pipe_peer_events`].
+#[derive(Debug, Default)]
+struct PeerDetails {
+    enode: Option<String>,
+    enr: Option<String>,
+    client_version: Option<String>,
+    eth_version: Option<u8>,
+}
+
+/// Converts the peer event to notifications for `reth_subscribePeerEvents`, updating the known
+/// details of the peer.
+fn peer_event_notifications(
+    peers: &mut HashMap<PeerId, PeerDetails>,
+    event: PeerEvent,
+    peer_info: Option<PeerInfo>,
+) -> Vec<PeerEventNotification> {
+    let (peer_id, kinds) = match event {
+        PeerEvent::SessionEstablished(info) => {
+            let eth_version = info.version as u8;
+            let details = peers.entry(info.peer_id).or_default();
+            details.client_version = Some(info.client_version.to_string());
+            if let Some(peer_info) = peer_info {
+                details.enode = Some(peer_info.enode);
+                details.enr = peer_info.enr;
+            }
+
+            let mut kinds = vec![PeerEventKind::Connected {
+                eth_version,
+                capabilities: info
+                    .capabilities
+                    .capabilities()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            }];
+            match details.eth_version.replace(eth_version) {
+                Some(previous_eth_version) if previous_eth_version < eth_version => {
+                    kinds.push(PeerEventKind::ProtocolUpgraded {
+                        previous_eth_version,
+                        eth_version,
+                    });
+                }
+                _ => {}
+            }
+            (info.peer_id, kinds)
+        }
+        PeerEvent::SessionClosed { peer_id, reason } => (
+            peer_id,
+            vec![PeerEventKind::Disconnected { reason: reason.map(|reason| reason.to_string()) }],
+        ),
+        PeerEvent::ReputationChanged { peer_id, reputation } => {
+            (peer_id, vec![PeerEventKind::ReputationChanged { reputation }])
+        }
+        PeerEvent::PeerRemoved(peer_id) => {
+            peers.remove(&peer_id);
+            return Vec::new()
+        }
+        PeerEvent::PeerAdded(_) => return Vec::new(),
+    };
+
+    let details = peers.get(&peer_id);
+    kinds
+        .into_iter()
+        .map(|event| PeerEventNotification {
+            peer_id,
+            enode: details.and_then(|details| details.enode.clone()),
+            enr: details.and_then(|details| details.enr.clone()),
+            client_version: details.and_then(|details| details.client_version.clone()),
+            event,
+        })
+        .collect()
+}
+
+impl<Provider, Network> std::fmt::Debug for RethApi<Provider, Network> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RethApi").finish_non_exhaustive()
     }
 }
 
-impl<Provider> Clone for RethApi<Provider> {
+impl<Provider, Network> Clone for RethApi<Provider, Network> {
     fn clone(&self) -> Self {
         Self { inner: Arc::clone(&self.inner) }
     }
 }
 
-struct RethApiInner<Provider> {
+struct RethApiInner<Provider, Network> {
     /// The provider that can interact with the chain.
     provider: Provider,
+    /// The network the node is connected to.
+    network: Network,
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
+    /// The maximum number of blocks into the past for generating balance proofs.
+    max_proof_window: u64,
+    /// The permits for computing proofs, shared with `eth_getProof`.
+    proof_guard: BlockingTaskGuard,
+    /// The registry that is used to decode logs.
+    abi_registry: AbiRegistry,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::Header;
+    use alloy_trie::proof::verify_proof;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_primitives::{proofs::calculate_receipt_root_no_memo, Receipt, TxType};
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_tasks::TokioTaskExecutor;
+
+    #[test]
+    fn limit_balance_proofs_to_proof_window() {
+        let provider = MockEthProvider::default();
+        provider.add_header(B256::with_last_byte(1), Header { number: 100, ..Default::default() });
+        let api = RethApi::new(
+            provider,
+            NoopNetwork::default(),
+            Box::new(TokioTaskExecutor::default()),
+            10,
+            BlockingTaskGuard::new(1),
+            RethApiConfig::default(),
+        );
+
+        let err = api.balance_proof_range(1, 89.into(), None).unwrap_err();
+        assert!(matches!(err, EthApiError::ExceedsMaxProofWindow));
+        assert_eq!(api.balance_proof_range(1, 90.into(), None).unwrap(), 90..=90);
+        assert_eq!(api.balance_proof_range(1, 90.into(), Some(100.into())).unwrap(), 90..=100);
+    }
+
+    #[test]
+    fn verify_receipt_proofs() {
+        // more than 0x7f receipts so that the indices are reordered
+        for len in [1, 2, 3, 130] {
+            let receipts = (0..len)
+                .map(|i| Receipt {
+                    tx_type: TxType::Eip1559,
+                    success: i % 2 == 0,
+                    cumulative_gas_used: 21_000 * (i + 1),
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>();
+            let expected_root =
+                calculate_receipt_root_no_memo(&receipts.iter().collect::<Vec<_>>());
+
+            for index in [0, len as usize / 2, len as usize - 1] {
+                let (root, proof) = receipt_proof(&receipts, index);
+                assert_eq!(root, expected_root);
+
+                let receipt = &receipts[index];
+                let mut value = Vec::new();
+                receipt.eip2718_encode_with_bloom(&receipt.bloom(), &mut value);
+                let key = Nibbles::unpack(alloy_rlp::encode_fixed_size(&index));
+                verify_proof(root, key, Some(value), &proof).unwrap();
+            }
+        }
+    }
 }
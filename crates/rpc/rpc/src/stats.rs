@@ -0,0 +1,488 @@
+use std::{
+    collections::{btree_map, hash_map, BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_primitives::{Address, BlockNumber, U256};
+use async_trait::async_trait;
+use futures::StreamExt;
+use jsonrpsee::core::RpcResult;
+use parking_lot::RwLock;
+use reth_primitives::{NodePrimitives, SealedBlockWithSenders};
+use reth_primitives_traits::{Block, BlockBody};
+use reth_provider::{
+    BlockReader, CanonStateNotification, CanonStateNotificationStream, CanonStateSubscriptions,
+    ProviderResult,
+};
+use reth_rpc_api::{ChainStatsBucket, StatsApiServer, StatsInterval};
+use reth_rpc_eth_types::EthApiError;
+use reth_rpc_server_types::constants::DEFAULT_STATS_RETENTION_DAYS;
+use reth_tasks::TaskSpawner;
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+use tracing::{debug, info, warn};
+
+/// The number of blocks that are read at once when catching up with the chain.
+const CATCH_UP_BATCH_SIZE: u64 = 1_000;
+
+/// Configuration for the `stats` API.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StatsApiConfig {
+    /// Whether chain statistics are maintained.
+    pub enabled: bool,
+    /// The number of days chain statistics are retained.
+    pub retention_days: u64,
+}
+
+impl Default for StatsApiConfig {
+    fn default() -> Self {
+        Self { enabled: false, retention_days: DEFAULT_STATS_RETENTION_DAYS }
+    }
+}
+
+/// `stats` API implementation.
+///
+/// This type provides the functionality for handling `stats` related requests.
+#[derive(Debug, Clone)]
+pub struct StatsApi {
+    /// The maintained chain statistics, `None` if disabled.
+    stats: Option<ChainStats>,
+}
+
+impl StatsApi {
+    /// Creates a new instance of the [`StatsApi`].
+    ///
+    /// If enabled, this spawns a task that catches up with the chain within the retention window
+    /// and then keeps the statistics up to date with the canonical chain.
+    pub fn new<Provider, Events>(
+        provider: Provider,
+        events: &Events,
+        task_spawner: Box<dyn TaskSpawner>,
+        config: StatsApiConfig,
+    ) -> Self
+    where
+        Provider:
+            BlockReader<Block = <Events::Primitives as NodePrimitives>::Block> + Clone + 'static,
+        Events: CanonStateSubscriptions,
+    {
+        if !config.enabled {
+            return Self { stats: None }
+        }
+
+        let stats = ChainStats::new(config.retention_days);
+        let task = chain_stats_task(
+            stats.clone(),
+            events.canonical_state_stream(),
+            provider,
+            task_spawner.clone(),
+        );
+        task_spawner.spawn(Box::pin(task));
+        Self { stats: Some(stats) }
+    }
+}
+
+#[async_trait]
+impl StatsApiServer for StatsApi {
+    /// Handler for `stats_getChainStats`
+    async fn stats_get_chain_stats(
+        &self,
+        interval: StatsInterval,
+        from_timestamp: Option<u64>,
+        to_timestamp: Option<u64>,
+    ) -> RpcResult<Vec<ChainStatsBucket>> {
+        let Some(stats) = &self.stats else {
+            return Err(EthApiError::Unsupported("chain statistics are disabled").into())
+        };
+        Ok(stats.buckets(interval, from_timestamp, to_timestamp))
+    }
+}
+
+/// Catches up with the chain and then applies the canonical state notifications to the chain
+/// statistics.
+async fn chain_stats_task<Provider, N>(
+    stats: ChainStats,
+    mut events: CanonStateNotificationStream<N>,
+    provider: Provider,
+    task_spawner: Box<dyn TaskSpawner>,
+) where
+    Provider: BlockReader<Block = N::Block> + Clone + 'static,
+    N: NodePrimitives,
+{
+    // notifications are buffered while the blocks of the retention window are read
+    let (tx, rx) = oneshot::channel();
+    let (this, this_provider) = (stats.clone(), provider.clone());
+    task_spawner.spawn_blocking(Box::pin(async move {
+        let res =
+            this_provider.best_block_number().and_then(|tip| this.catch_up(&this_provider, tip));
+        let _ = tx.send(res);
+    }));
+    match rx.await {
+        Ok(Ok(())) => info!(target: "rpc::stats", head = ?stats.head(), "Caught up with the chain"),
+        Ok(Err(err)) => warn!(target: "rpc::stats", %err, "Failed to catch up with the chain"),
+        Err(_) => return,
+    }
+
+    while let Some(notification) = events.next().await {
+        if let Err(err) = stats.on_canon_state(&provider, &notification) {
+            warn!(target: "rpc::stats", %err, "Failed to update chain statistics");
+        }
+    }
+}
+
+/// Hourly and daily rollups of the canonical chain, maintained incrementally.
+#[derive(Debug, Clone)]
+pub struct ChainStats {
+    inner: Arc<RwLock<ChainStatsRollups>>,
+    /// The number of seconds buckets are retained.
+    retention: u64,
+}
+
+impl ChainStats {
+    /// Creates new empty rollups that retain the given number of days.
+    pub fn new(retention_days: u64) -> Self {
+        Self {
+            inner: Default::default(),
+            retention: retention_days.saturating_mul(StatsInterval::Day.seconds()),
+        }
+    }
+
+    /// Returns the number of the last applied block.
+    pub fn head(&self) -> Option<BlockNumber> {
+        self.inner.read().head
+    }
+
+    /// Returns the buckets of the interval that contain timestamps in the given range.
+    pub fn buckets(
+        &self,
+        interval: StatsInterval,
+        from_timestamp: Option<u64>,
+        to_timestamp: Option<u64>,
+    ) -> Vec<ChainStatsBucket> {
+        let from = interval.start(from_timestamp.unwrap_or_default());
+        let to = to_timestamp.unwrap_or(u64::MAX);
+        if from > to {
+            return Vec::new()
+        }
+        self.inner
+            .read()
+            .buckets(interval)
+            .range(from..=to)
+            .map(|(timestamp, bucket)| bucket.to_rpc(*timestamp))
+            .collect()
+    }
+
+    /// Applies a canonical state notification, reverting the blocks of the old chain and applying
+    /// the blocks of the new chain.
+    ///
+    /// Blocks that were missed, e.g. because the notification stream lagged behind, are read from
+    /// the provider.
+    pub fn on_canon_state<Provider, N>(
+        &self,
+        provider: &Provider,
+        notification: &CanonStateNotification<N>,
+    ) -> ProviderResult<()>
+    where
+        Provider: BlockReader<Block = N::Block>,
+        N: NodePrimitives,
+    {
+        if let Some(reverted) = notification.reverted() {
+            let mut rollups = self.inner.write();
+            for (number, block) in reverted.blocks().iter().rev() {
+                rollups.revert(*number, &BlockStats::new(block));
+            }
+        }
+
+        let committed = notification.committed();
+        let Some(first) = committed.blocks().keys().next().copied() else { return Ok(()) };
+        if let Some(head) = self.head() {
+            if first > head + 1 {
+                debug!(target: "rpc::stats", head, first, "Reading missed blocks");
+                self.catch_up(provider, first - 1)?;
+            }
+        }
+
+        let mut rollups = self.inner.write();
+        for (number, block) in committed.blocks() {
+            rollups.apply(*number, &BlockStats::new(block));
+        }
+        rollups.prune(self.retention);
+        Ok(())
+    }
+
+    /// Applies the canonical blocks up to the given block that have not been applied yet, starting
+    /// at the retention window if no block has been applied.
+    fn catch_up<Provider: BlockReader>(
+        &self,
+        provider: &Provider,
+        to: BlockNumber,
+    ) -> ProviderResult<()> {
+        let mut start = match self.head() {
+            Some(head) => head + 1,
+            None => self.retention_start(provider, to)?,
+        };
+
+        while start <= to {
+            let end = start.saturating_add(CATCH_UP_BATCH_SIZE - 1).min(to);
+            let blocks = provider.sealed_block_with_senders_range(start..=end)?;
+
+            let mut rollups = self.inner.write();
+            for block in &blocks {
+                rollups.apply(block.header.number(), &BlockStats::new(block));
+            }
+            rollups.prune(self.retention);
+
+            start = end + 1;
+        }
+        Ok(())
+    }
+
+    /// Returns the first block of the retention window, relative to the given block.
+    fn retention_start<Provider: BlockReader>(
+        &self,
+        provider: &Provider,
+        tip: BlockNumber,
+    ) -> ProviderResult<BlockNumber> {
+        let timestamp = |number| {
+            provider.header_by_number(number).map(|header| header.map(|header| header.timestamp()))
+        };
+        let Some(tip_timestamp) = timestamp(tip)? else { return Ok(tip) };
+        let cutoff = retention_cutoff(tip_timestamp, self.retention);
+
+        // block timestamps are strictly increasing, search for the first block after the cutoff
+        let (mut low, mut high) = (0, tip);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if timestamp(mid)?.unwrap_or_default() < cutoff {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+}
+
+/// Returns the start of the oldest day that is retained, relative to the given timestamp.
+const fn retention_cutoff(timestamp: u64, retention: u64) -> u64 {
+    StatsInterval::Day.start(timestamp.saturating_sub(retention))
+}
+
+/// The buckets of all intervals.
+#[derive(Debug, Default)]
+struct ChainStatsRollups {
+    hourly: BTreeMap<u64, Bucket>,
+    daily: BTreeMap<u64, Bucket>,
+    /// The number of the last applied block.
+    head: Option<BlockNumber>,
+}
+
+impl ChainStatsRollups {
+    const fn buckets(&self, interval: StatsInterval) -> &BTreeMap<u64, Bucket> {
+        match interval {
+            StatsInterval::Hour => &self.hourly,
+            StatsInterval::Day => &self.daily,
+        }
+    }
+
+    fn buckets_mut(&mut self, interval: StatsInterval) -> &mut BTreeMap<u64, Bucket> {
+        match interval {
+            StatsInterval::Hour => &mut self.hourly,
+            StatsInterval::Day => &mut self.daily,
+        }
+    }
+
+    /// Adds a block to the buckets, unless it has already been applied.
+    fn apply(&mut self, number: BlockNumber, block: &BlockStats) {
+        if self.head.is_some_and(|head| number <= head) {
+            return
+        }
+        for interval in [StatsInterval::Hour, StatsInterval::Day] {
+            self.buckets_mut(interval)
+                .entry(interval.start(block.timestamp))
+                .or_default()
+                .apply(block);
+        }
+        self.head = Some(number);
+    }
+
+    /// Removes a block from the buckets, if it has been applied.
+    fn revert(&mut self, number: BlockNumber, block: &BlockStats) {
+        if !self.head.is_some_and(|head| number <= head) {
+            return
+        }
+        for interval in [StatsInterval::Hour, StatsInterval::Day] {
+            if let btree_map::Entry::Occupied(mut entry) =
+                self.buckets_mut(interval).entry(interval.start(block.timestamp))
+            {
+                entry.get_mut().revert(block);
+                if entry.get().blocks == 0 {
+                    entry.remove();
+                }
+            }
+        }
+        self.head = number.checked_sub(1);
+    }
+
+    /// Removes the buckets that are outside of the retention window of the latest bucket.
+    fn prune(&mut self, retention: u64) {
+        let Some(latest) = self.hourly.last_key_value().map(|(timestamp, _)| *timestamp) else {
+            return
+        };
+        let cutoff = retention_cutoff(latest, retention);
+        self.hourly = self.hourly.split_off(&cutoff);
+        self.daily = self.daily.split_off(&cutoff);
+    }
+}
+
+/// The aggregated statistics of the blocks in an interval.
+#[derive(Debug, Default)]
+struct Bucket {
+    blocks: u64,
+    transactions: u64,
+    gas_used: u64,
+    fees_burned: U256,
+    /// The number of times each active address occurs in the blocks, so that reverted blocks can
+    /// be removed.
+    addresses: HashMap<Address, u32>,
+}
+
+impl Bucket {
+    fn apply(&mut self, block: &BlockStats) {
+        self.blocks += 1;
+        self.transactions += block.transactions;
+        self.gas_used += block.gas_used;
+        self.fees_burned += block.fees_burned;
+        for address in &block.addresses {
+            *self.addresses.entry(*address).or_default() += 1;
+        }
+    }
+
+    fn revert(&mut self, block: &BlockStats) {
+        self.blocks = self.blocks.saturating_sub(1);
+        self.transactions = self.transactions.saturating_sub(block.transactions);
+        self.gas_used = self.gas_used.saturating_sub(block.gas_used);
+        self.fees_burned = self.fees_burned.saturating_sub(block.fees_burned);
+        for address in &block.addresses {
+            if let hash_map::Entry::Occupied(mut entry) = self.addresses.entry(*address) {
+                *entry.get_mut() -= 1;
+                if *entry.get() == 0 {
+                    entry.remove();
+                }
+            }
+        }
+    }
+
+    fn to_rpc(&self, timestamp: u64) -> ChainStatsBucket {
+        ChainStatsBucket {
+            timestamp,
+            blocks: self.blocks,
+            transactions: self.transactions,
+            gas_used: self.gas_used,
+            fees_burned: self.fees_burned,
+            active_addresses: self.addresses.len() as u64,
+        }
+    }
+}
+
+/// The statistics of a single block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct BlockStats {
+    timestamp: u64,
+    transactions: u64,
+    gas_used: u64,
+    /// The base fee and blob fee paid by all transactions.
+    fees_burned: U256,
+    /// The senders and recipients of the transactions, including duplicates.
+    addresses: Vec<Address>,
+}
+
+impl BlockStats {
+    fn new<B: Block>(block: &SealedBlockWithSenders<B>) -> Self {
+        let header = &block.header;
+        let transactions = block.body.transactions();
+
+        let base_fee = U256::from(header.base_fee_per_gas().unwrap_or_default()) *
+            U256::from(header.gas_used());
+        let blob_fee = U256::from(header.blob_fee().unwrap_or_default()) *
+            U256::from(header.blob_gas_used().unwrap_or_default());
+
+        let recipients = transactions.iter().filter_map(|tx| tx.to());
+        Self {
+            timestamp: header.timestamp(),
+            transactions: transactions.len() as u64,
+            gas_used: header.gas_used(),
+            fees_burned: base_fee + blob_fee,
+            addresses: block.senders.iter().copied().chain(recipients).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 60 * 60;
+    const DAY: u64 = 24 * HOUR;
+
+    fn block(timestamp: u64, addresses: &[u8]) -> BlockStats {
+        BlockStats {
+            timestamp,
+            transactions: addresses.len() as u64,
+            gas_used: 21_000 * addresses.len() as u64,
+            fees_burned: U256::from(addresses.len()),
+            addresses: addresses.iter().map(|byte| Address::with_last_byte(*byte)).collect(),
+        }
+    }
+
+    #[test]
+    fn apply_and_revert_blocks() {
+        let stats = ChainStats::new(DEFAULT_STATS_RETENTION_DAYS);
+        {
+            let mut rollups = stats.inner.write();
+            rollups.apply(1, &block(DAY, &[1, 2]));
+            rollups.apply(2, &block(DAY + HOUR, &[2, 3]));
+            // already applied
+            rollups.apply(2, &block(DAY + HOUR, &[2, 3]));
+        }
+
+        let daily = stats.buckets(StatsInterval::Day, None, None);
+        assert_eq!(daily.len(), 1);
+        assert_eq!(daily[0].timestamp, DAY);
+        assert_eq!(daily[0].blocks, 2);
+        assert_eq!(daily[0].transactions, 4);
+        assert_eq!(daily[0].fees_burned, U256::from(4));
+        assert_eq!(daily[0].active_addresses, 3);
+
+        let hourly = stats.buckets(StatsInterval::Hour, Some(DAY + HOUR + 1), None);
+        assert_eq!(hourly.len(), 1);
+        assert_eq!(hourly[0].timestamp, DAY + HOUR);
+        assert_eq!(hourly[0].active_addresses, 2);
+
+        stats.inner.write().revert(2, &block(DAY + HOUR, &[2, 3]));
+        assert_eq!(stats.head(), Some(1));
+        let daily = stats.buckets(StatsInterval::Day, None, None);
+        assert_eq!(daily[0].blocks, 1);
+        assert_eq!(daily[0].active_addresses, 2);
+        assert_eq!(stats.buckets(StatsInterval::Hour, None, None).len(), 1);
+    }
+
+    #[test]
+    fn prune_buckets() {
+        let stats = ChainStats::new(1);
+        {
+            let mut rollups = stats.inner.write();
+            rollups.apply(1, &block(DAY + HOUR, &[1]));
+            rollups.apply(2, &block(2 * DAY + HOUR, &[1]));
+            rollups.apply(3, &block(3 * DAY + 2 * HOUR, &[1]));
+            rollups.prune(stats.retention);
+        }
+
+        let daily = stats.buckets(StatsInterval::Day, None, None);
+        assert_eq!(
+            daily.iter().map(|bucket| bucket.timestamp).collect::<Vec<_>>(),
+            [2 * DAY, 3 * DAY]
+        );
+        assert_eq!(stats.buckets(StatsInterval::Hour, None, None).len(), 2);
+    }
+}
@@ -7,24 +7,26 @@ use alloy_rpc_types_eth::{
     BlockOverrides, Index,
 };
 use alloy_rpc_types_trace::{
-    filter::TraceFilter,
+    filter::{TraceFilter, TraceFilterMatcher},
     opcode::{BlockOpcodeGas, TransactionOpcodeGas},
     parity::*,
     tracerequest::TraceCallRequest,
 };
 use async_trait::async_trait;
+use futures::{stream, Stream, StreamExt, TryStreamExt};
 use jsonrpsee::core::RpcResult;
 use reth_chainspec::EthereumHardforks;
 use reth_consensus_common::calc::{
     base_block_reward, base_block_reward_pre_merge, block_reward, ommer_reward,
 };
 use reth_evm::ConfigureEvmEnv;
+use reth_primitives::SealedBlockWithSenders;
 use reth_primitives_traits::{BlockBody, BlockHeader};
-use reth_provider::{BlockNumReader, BlockReader, ChainSpecProvider, HeaderProvider};
+use reth_provider::{BlockNumReader, ChainSpecProvider, HeaderProvider, ProviderBlock};
 use reth_revm::database::StateProviderDatabase;
 use reth_rpc_api::TraceApiServer;
 use reth_rpc_eth_api::{helpers::TraceExt, FromEthApiError, RpcNodeCore};
-use reth_rpc_eth_types::{error::EthApiError, utils::recover_raw_transaction};
+use reth_rpc_eth_types::{error::EthApiError, utils::recover_raw_transaction, TraceFilterConfig};
 use reth_tasks::pool::BlockingTaskGuard;
 use reth_transaction_pool::{PoolPooledTx, PoolTransaction, TransactionPool};
 use revm::{
@@ -35,9 +37,12 @@ use revm_inspectors::{
     opcode::OpcodeGasInspector,
     tracing::{parity::populate_state_diff, TracingInspector, TracingInspectorConfig},
 };
-use std::sync::Arc;
+use std::{future, sync::Arc};
 use tokio::sync::{AcquireError, OwnedSemaphorePermit};
 
+/// The maximum number of blocks a `trace_filter` request traces concurrently.
+pub const TRACE_FILTER_BLOCK_CONCURRENCY: usize = 4;
+
 /// `trace` API implementation.
 ///
 /// This type provides the functionality for handling `trace` related requests.
@@ -49,8 +54,12 @@ pub struct TraceApi<Eth> {
 
 impl<Eth> TraceApi<Eth> {
     /// Create a new instance of the [`TraceApi`]
-    pub fn new(eth_api: Eth, blocking_task_guard: BlockingTaskGuard) -> Self {
-        let inner = Arc::new(TraceApiInner { eth_api, blocking_task_guard });
+    pub fn new(
+        eth_api: Eth,
+        blocking_task_guard: BlockingTaskGuard,
+        filter_config: TraceFilterConfig,
+    ) -> Self {
+        let inner = Arc::new(TraceApiInner { eth_api, blocking_task_guard, filter_config });
         Self { inner }
     }
 
@@ -244,10 +253,31 @@ where
     ///
     /// This is similar to [`Self::trace_block`] but only returns traces for transactions that match
     /// the filter.
+    ///
+    /// See also [`Self::trace_filter_stream`].
     pub async fn trace_filter(
         &self,
         filter: TraceFilter,
     ) -> Result<Vec<LocalizedTransactionTrace>, Eth::Error> {
+        self.trace_filter_stream(filter)?.try_collect().await
+    }
+
+    /// Returns a stream of the traces that match the given filter, ordered by block and
+    /// transaction.
+    ///
+    /// Blocks are traced on demand, at most [`TRACE_FILTER_BLOCK_CONCURRENCY`] at once, so only the
+    /// traces of the blocks in flight are held in memory and no further blocks are traced once
+    /// `count` traces were produced.
+    ///
+    /// The block range and the number of executed transactions are bounded by the
+    /// [`TraceFilterConfig`], the stream yields an error once the transaction budget is exceeded.
+    pub fn trace_filter_stream(
+        &self,
+        filter: TraceFilter,
+    ) -> Result<
+        impl Stream<Item = Result<LocalizedTransactionTrace, Eth::Error>> + Send + '_,
+        Eth::Error,
+    > {
         // We'll reuse the matcher across multiple blocks that are traced in parallel
         let matcher = Arc::new(filter.matcher());
         let TraceFilter { from_block, to_block, after, count, .. } = filter;
@@ -265,29 +295,62 @@ where
             .into())
         }
 
-        // ensure that the range is not too large, since we need to fetch all blocks in the range
-        let distance = end.saturating_sub(start);
-        if distance > 100 {
-            return Err(EthApiError::InvalidParams(
-                "Block range too large; currently limited to 100 blocks".to_string(),
-            )
+        // ensure that the range is not too large, since we need to trace all blocks in the range
+        let TraceFilterConfig { max_blocks, max_transactions } = self.inner.filter_config;
+        if end - start > max_blocks {
+            return Err(EthApiError::InvalidParams(format!(
+                "Block range too large; currently limited to {max_blocks} blocks"
+            ))
             .into())
         }
 
-        // fetch all blocks in that range
-        let blocks = self
-            .provider()
-            .sealed_block_with_senders_range(start..=end)
-            .map_err(Eth::Error::from_eth_err)?
-            .into_iter()
-            .map(Arc::new)
-            .collect::<Vec<_>>();
+        let mut transactions = 0u64;
+        let mut skip = after.unwrap_or_default();
+        let traces = stream::iter(start..=end)
+            .then(move |number| async move {
+                self.eth_api().block_with_senders(number.into()).await?.ok_or_else(|| {
+                    Eth::Error::from_eth_err(EthApiError::HeaderNotFound(number.into()))
+                })
+            })
+            // enforce the budget before the block is executed
+            .map(move |block| {
+                let block = block?;
+                transactions += block.body.transactions().len() as u64;
+                if transactions > max_transactions {
+                    return Err(Eth::Error::from_eth_err(EthApiError::InvalidParams(format!(
+                        "Too many transactions to trace; currently limited to {max_transactions} transactions"
+                    ))))
+                }
+                Ok(block)
+            })
+            .map(move |block| {
+                let matcher = matcher.clone();
+                async move { self.trace_filter_block(block?, matcher).await }
+            })
+            .buffered(TRACE_FILTER_BLOCK_CONCURRENCY)
+            .map_ok(|traces| stream::iter(traces.into_iter().map(Ok)))
+            .try_flatten()
+            // skips the first `after` matching traces
+            .try_filter(move |_| {
+                let keep = skip == 0;
+                skip = skip.saturating_sub(1);
+                future::ready(keep)
+            })
+            .take(count.map_or(usize::MAX, |count| count as usize));
+
+        Ok(traces)
+    }
 
-        // trace all blocks
-        let mut block_traces = Vec::with_capacity(blocks.len());
-        for block in &blocks {
-            let matcher = matcher.clone();
-            let traces = self.eth_api().trace_block_until(
+    /// Returns the traces of the block that match the filter, including the block rewards.
+    async fn trace_filter_block(
+        &self,
+        block: Arc<SealedBlockWithSenders<ProviderBlock<Eth::Provider>>>,
+        matcher: Arc<TraceFilterMatcher>,
+    ) -> Result<Vec<LocalizedTransactionTrace>, Eth::Error> {
+        let tx_matcher = matcher.clone();
+        let mut traces = self
+            .eth_api()
+            .trace_block_until(
                 block.hash().into(),
                 Some(block.clone()),
                 None,
@@ -295,61 +358,30 @@ where
                 move |tx_info, inspector, _, _, _| {
                     let mut traces =
                         inspector.into_parity_builder().into_localized_transaction_traces(tx_info);
-                    traces.retain(|trace| matcher.matches(&trace.trace));
-                    Ok(Some(traces))
+                    traces.retain(|trace| tx_matcher.matches(&trace.trace));
+                    Ok(traces)
                 },
-            );
-            block_traces.push(traces);
-        }
-
-        let block_traces = futures::future::try_join_all(block_traces).await?;
-        let mut all_traces = block_traces
+            )
+            .await?
             .into_iter()
             .flatten()
-            .flat_map(|traces| traces.into_iter().flatten().flat_map(|traces| traces.into_iter()))
+            .flatten()
             .collect::<Vec<_>>();
 
-        // add reward traces for all blocks
-        for block in &blocks {
-            if let Some(base_block_reward) =
-                self.calculate_base_block_reward(block.header.header())?
-            {
-                all_traces.extend(
-                    self.extract_reward_traces(
-                        block.header.header(),
-                        block.body.ommers(),
-                        base_block_reward,
-                    )
-                    .into_iter()
-                    .filter(|trace| matcher.matches(&trace.trace)),
-                );
-            } else {
-                // no block reward, means we're past the Paris hardfork and don't expect any rewards
-                // because the blocks in ascending order
-                break
-            }
-        }
-
-        // Skips the first `after` number of matching traces.
-        // If `after` is greater than or equal to the number of matched traces, it returns an empty
-        // array.
-        if let Some(after) = after.map(|a| a as usize) {
-            if after < all_traces.len() {
-                all_traces.drain(..after);
-            } else {
-                return Ok(vec![])
-            }
+        // no block reward means we're past the Paris hardfork
+        if let Some(base_block_reward) = self.calculate_base_block_reward(block.header.header())? {
+            traces.extend(
+                self.extract_reward_traces(
+                    block.header.header(),
+                    block.body.ommers(),
+                    base_block_reward,
+                )
+                .into_iter()
+                .filter(|trace| matcher.matches(&trace.trace)),
+            );
         }
 
-        // Return at most `count` of traces
-        if let Some(count) = count {
-            let count = count as usize;
-            if count < all_traces.len() {
-                all_traces.truncate(count);
-            }
-        };
-
-        Ok(all_traces)
+        Ok(traces)
     }
 
     /// Returns all traces for the given transaction hash
@@ -712,6 +744,8 @@ struct TraceApiInner<Eth> {
     eth_api: Eth,
     // restrict the number of concurrent calls to `trace_*`
     blocking_task_guard: BlockingTaskGuard,
+    /// Bounds the work of `trace_filter` requests
+    filter_config: TraceFilterConfig,
 }
 
 /// Helper to construct a [`LocalizedTransactionTrace`] that describes a reward to the block
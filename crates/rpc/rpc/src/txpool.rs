@@ -2,7 +2,7 @@ use core::fmt;
 use std::collections::BTreeMap;
 
 use alloy_consensus::Transaction;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, U128};
 use alloy_rpc_types_txpool::{
     TxpoolContent, TxpoolContentFrom, TxpoolInspect, TxpoolInspectSummary, TxpoolStatus,
 };
@@ -150,6 +150,14 @@ where
         trace!(target: "rpc::eth", "Serving txpool_content");
         Ok(self.content().map_err(Into::into)?)
     }
+
+    /// Returns the minimum priority fee transactions currently have to pay to enter the pool.
+    ///
+    /// Handler for `txpool_priceFloor`
+    async fn txpool_price_floor(&self) -> RpcResult<U128> {
+        trace!(target: "rpc::eth", "Serving txpool_priceFloor");
+        Ok(U128::from(self.pool.price_floor()))
+    }
 }
 
 impl<Pool, Eth> fmt::Debug for TxPoolApi<Pool, Eth> {
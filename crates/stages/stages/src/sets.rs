@@ -37,8 +37,10 @@
 use crate::{
     stages::{
         AccountHashingStage, BodyStage, ExecutionStage, FinishStage, HeaderStage,
-        IndexAccountHistoryStage, IndexStorageHistoryStage, MerkleStage, PruneSenderRecoveryStage,
-        PruneStage, SenderRecoveryStage, StorageHashingStage, TransactionLookupStage,
+        IndexAccountHistoryStage, IndexAddressTransactionsStage, IndexLogsStage,
+        IndexStorageHistoryStage, IndexTransactionSelectorsStage, IndexWithdrawalHistoryStage,
+        MerkleStage, PruneSenderRecoveryStage, PruneStage, SenderRecoveryStage,
+        StorageHashingStage, TransactionLookupStage,
     },
     StageSet, StageSetBuilder,
 };
@@ -48,7 +50,7 @@ use reth_consensus::Consensus;
 use reth_evm::execute::BlockExecutorProvider;
 use reth_network_p2p::{bodies::downloader::BodyDownloader, headers::downloader::HeaderDownloader};
 use reth_provider::HeaderSyncGapProvider;
-use reth_prune_types::PruneModes;
+use reth_prune_types::{PruneMode, PruneModes};
 use reth_stages_api::Stage;
 use std::{ops::Not, sync::Arc};
 use tokio::sync::watch;
@@ -72,8 +74,12 @@ use tokio::sync::watch;
 /// - [`StorageHashingStage`]
 /// - [`MerkleStage`] (execute)
 /// - [`TransactionLookupStage`]
+/// - [`IndexTransactionSelectorsStage`]
+/// - [`IndexAddressTransactionsStage`]
 /// - [`IndexStorageHistoryStage`]
 /// - [`IndexAccountHistoryStage`]
+/// - [`IndexLogsStage`]
+/// - [`IndexWithdrawalHistoryStage`]
 /// - [`PruneStage`] (execute)
 /// - [`FinishStage`]
 #[derive(Debug)]
@@ -424,25 +430,56 @@ pub struct HistoryIndexingStages {
 impl<Provider> StageSet<Provider> for HistoryIndexingStages
 where
     TransactionLookupStage: Stage<Provider>,
+    IndexTransactionSelectorsStage: Stage<Provider>,
+    IndexAddressTransactionsStage: Stage<Provider>,
     IndexStorageHistoryStage: Stage<Provider>,
     IndexAccountHistoryStage: Stage<Provider>,
+    IndexLogsStage: Stage<Provider>,
+    IndexWithdrawalHistoryStage: Stage<Provider>,
 {
     fn builder(self) -> StageSetBuilder<Provider> {
+        // Stages skip writing the data that is going to be pruned anyway. If there are retained
+        // addresses, the data has to be written in full, so the pruner can filter it afterwards.
+        let retained_aware = |mode: Option<PruneMode>| {
+            mode.filter(|_| self.prune_modes.retained_addresses.is_empty())
+        };
+
         StageSetBuilder::default()
             .add_stage(TransactionLookupStage::new(
                 self.stages_config.transaction_lookup,
                 self.stages_config.etl.clone(),
-                self.prune_modes.transaction_lookup,
+                // Lookups can only be pruned once the transactions are in indexed static files
+                retained_aware(self.prune_modes.transaction_lookup)
+                    .filter(|_| !self.prune_modes.transaction_hash_index),
+            ))
+            .add_stage(IndexTransactionSelectorsStage::new(
+                self.stages_config.index_transaction_selectors,
+                self.stages_config.etl.clone(),
+                self.prune_modes.transaction_selectors,
+            ))
+            .add_stage(IndexAddressTransactionsStage::new(
+                self.stages_config.index_address_transactions,
+                self.stages_config.etl.clone(),
+                self.prune_modes.address_transactions,
             ))
             .add_stage(IndexStorageHistoryStage::new(
                 self.stages_config.index_storage_history,
                 self.stages_config.etl.clone(),
-                self.prune_modes.account_history,
+                retained_aware(self.prune_modes.account_history),
             ))
             .add_stage(IndexAccountHistoryStage::new(
                 self.stages_config.index_account_history,
                 self.stages_config.etl.clone(),
-                self.prune_modes.storage_history,
+                retained_aware(self.prune_modes.storage_history),
+            ))
+            .add_stage(IndexLogsStage::new(
+                self.stages_config.index_logs,
+                self.stages_config.etl.clone(),
+            ))
+            .add_stage(IndexWithdrawalHistoryStage::new(
+                self.stages_config.index_withdrawal_history,
+                self.stages_config.etl.clone(),
+                self.prune_modes.withdrawal_history,
             ))
     }
 }
@@ -11,6 +11,10 @@ use reth_evm::{
 };
 use reth_execution_types::Chain;
 use reth_exex::{ExExManagerHandle, ExExNotification, ExExNotificationSource};
+use reth_metrics::{
+    metrics::{Gauge, Histogram},
+    Metrics,
+};
 use reth_primitives::{SealedHeader, StaticFileSegment};
 use reth_primitives_traits::{format_gas_throughput, Block, BlockBody, NodePrimitives};
 use reth_provider::{
@@ -94,6 +98,8 @@ where
     exex_manager_handle: ExExManagerHandle<E::Primitives>,
     /// Executor metrics.
     metrics: ExecutorMetrics,
+    /// Metrics of the execution batches.
+    batch_metrics: ExecutionBatchMetrics,
 }
 
 impl<E> ExecutionStage<E>
@@ -117,6 +123,7 @@ where
             post_unwind_commit_input: None,
             exex_manager_handle,
             metrics: ExecutorMetrics::default(),
+            batch_metrics: ExecutionBatchMetrics::default(),
         }
     }
 
@@ -393,6 +400,11 @@ where
 
             // Check if we should commit now
             let bundle_size_hint = executor.size_hint().unwrap_or_default() as u64;
+            self.batch_metrics.record_progress(
+                block_number - start_block + 1,
+                cumulative_gas,
+                bundle_size_hint,
+            );
             if self.thresholds.is_end_of_batch(
                 block_number - start_block,
                 bundle_size_hint,
@@ -445,6 +457,8 @@ where
         provider.write_state(state, OriginalValuesKnown::Yes, StorageLocation::StaticFiles)?;
 
         let db_write_duration = time.elapsed();
+        self.batch_metrics.gas_per_batch.record(cumulative_gas as f64);
+        self.batch_metrics.duration_seconds.record(batch_start.elapsed());
         debug!(
             target: "sync::stages::execution",
             block_fetch = ?fetch_block_duration,
@@ -550,6 +564,31 @@ where
     }
 }
 
+/// Metrics of the batches of blocks executed by the execution stage before committing.
+#[derive(Metrics)]
+#[metrics(scope = "sync.execution.batch")]
+struct ExecutionBatchMetrics {
+    /// The number of blocks executed in the current batch.
+    blocks: Gauge,
+    /// The cumulative gas used by the blocks of the current batch.
+    cumulative_gas: Gauge,
+    /// The number of state changes of the current batch.
+    state_changes: Gauge,
+    /// The cumulative gas used by the blocks of committed batches.
+    gas_per_batch: Histogram,
+    /// The time it took to execute and write a batch.
+    duration_seconds: Histogram,
+}
+
+impl ExecutionBatchMetrics {
+    /// Records the progress of the current batch.
+    fn record_progress(&self, blocks: u64, cumulative_gas: u64, state_changes: u64) {
+        self.blocks.set(blocks as f64);
+        self.cumulative_gas.set(cumulative_gas as f64);
+        self.state_changes.set(state_changes as f64);
+    }
+}
+
 fn execution_checkpoint<N: NodePrimitives>(
     provider: &StaticFileProvider<N>,
     start_block: BlockNumber,
@@ -0,0 +1,328 @@
+use super::{load_history_indices, DEFAULT_CACHE_THRESHOLD};
+use alloy_consensus::TxReceipt;
+use alloy_primitives::{
+    map::{Entry, HashMap},
+    Address, BlockNumber, Log, B256,
+};
+use reth_config::config::{EtlConfig, IndexLogsConfig};
+use reth_db::{tables, BlockNumberList};
+use reth_db_api::{
+    models::ShardedKey,
+    table::{Decode, Key},
+    transaction::DbTxMut,
+};
+use reth_etl::Collector;
+use reth_provider::{DBProvider, HistoryWriter, PruneCheckpointReader, ReceiptProvider};
+use reth_prune_types::PruneSegment;
+use reth_stages_api::{
+    CheckpointBlockRange, EntitiesCheckpoint, ExecInput, ExecOutput, IndexHistoryCheckpoint, Stage,
+    StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use std::hash::Hash;
+use tracing::info;
+
+/// The log indexing stage.
+///
+/// This stage walks over the receipts and maps the emitting address and each topic of every log to
+/// the blocks they appear in. This is written to [`tables::LogAddressIndex`] and
+/// [`tables::LogTopicIndex`] and is used by `eth_getLogs` to skip blocks without matching logs.
+///
+/// The index is optional: unless enabled through [`IndexLogsConfig::enabled`], the stage clears
+/// any existing index and is a no-op. The indexed block range is stored in the
+/// [`IndexHistoryCheckpoint`] of the stage, the index is rebuilt from scratch if it is missing.
+#[derive(Debug, Clone)]
+pub struct IndexLogsStage {
+    /// Whether the log index is maintained.
+    pub enabled: bool,
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    pub commit_threshold: u64,
+    /// ETL configuration
+    pub etl_config: EtlConfig,
+}
+
+impl IndexLogsStage {
+    /// Create new instance of [`IndexLogsStage`].
+    pub const fn new(config: IndexLogsConfig, etl_config: EtlConfig) -> Self {
+        Self { enabled: config.enabled, commit_threshold: config.commit_threshold, etl_config }
+    }
+}
+
+impl Default for IndexLogsStage {
+    fn default() -> Self {
+        Self { enabled: false, commit_threshold: 100_000, etl_config: EtlConfig::default() }
+    }
+}
+
+impl<Provider> Stage<Provider> for IndexLogsStage
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + HistoryWriter
+        + PruneCheckpointReader
+        + ReceiptProvider<Receipt: TxReceipt<Log = Log>>,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexLogs
+    }
+
+    /// Execute the stage.
+    fn execute(&mut self, provider: &Provider, input: ExecInput) -> Result<ExecOutput, StageError> {
+        let indexed_range = input.checkpoint().index_history_stage_checkpoint();
+
+        if !self.enabled {
+            if indexed_range.is_some() {
+                info!(target: "sync::stages::index_logs", "Log index is disabled, clearing it");
+                clear_log_index(provider)?;
+            }
+            return Ok(ExecOutput::done(StageCheckpoint::new(input.target())))
+        }
+
+        let (first_block, next_block) = match indexed_range {
+            Some(IndexHistoryCheckpoint { block_range, .. }) => {
+                (block_range.from, block_range.to + 1)
+            }
+            None => {
+                // There is no index yet, build it from the first block with receipts.
+                clear_log_index(provider)?;
+                let first_block = provider
+                    .get_prune_checkpoint(PruneSegment::Receipts)?
+                    .and_then(|checkpoint| checkpoint.block_number)
+                    .map_or(0, |block_number| block_number + 1);
+                (first_block, first_block)
+            }
+        };
+
+        let target = input.target();
+        if next_block > target {
+            return Ok(ExecOutput::done(input.checkpoint().with_block_number(target)))
+        }
+
+        let range = next_block..=
+            target.min(next_block.saturating_add(self.commit_threshold.saturating_sub(1)));
+        let first_build = next_block == first_block;
+
+        info!(target: "sync::stages::index_logs::exec", ?range, ?first_build, "Collecting indices");
+        let mut address_cache = HashMap::<Address, Vec<BlockNumber>>::default();
+        let mut topic_cache = HashMap::<B256, Vec<BlockNumber>>::default();
+        let mut address_collector =
+            Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+        let mut topic_collector =
+            Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+
+        for block_number in range.clone() {
+            let Some(receipts) = provider.receipts_by_block(block_number.into())? else { continue };
+            for log in receipts.iter().flat_map(|receipt| receipt.logs()) {
+                push_block(&mut address_cache, log.address, block_number);
+                for topic in log.topics() {
+                    push_block(&mut topic_cache, *topic, block_number);
+                }
+            }
+
+            if (block_number - range.start() + 1) % DEFAULT_CACHE_THRESHOLD == 0 {
+                collect_cache(&mut address_collector, &mut address_cache)?;
+                collect_cache(&mut topic_collector, &mut topic_cache)?;
+            }
+        }
+        collect_cache(&mut address_collector, &mut address_cache)?;
+        collect_cache(&mut topic_collector, &mut topic_cache)?;
+
+        info!(target: "sync::stages::index_logs::exec", "Loading indices into database");
+        load_history_indices::<_, tables::LogAddressIndex, _>(
+            provider,
+            address_collector,
+            first_build,
+            ShardedKey::new,
+            ShardedKey::<Address>::decode_owned,
+            |key| key.key,
+        )?;
+        load_history_indices::<_, tables::LogTopicIndex, _>(
+            provider,
+            topic_collector,
+            first_build,
+            ShardedKey::new,
+            ShardedKey::<B256>::decode_owned,
+            |key| key.key,
+        )?;
+
+        let end = *range.end();
+        Ok(ExecOutput {
+            checkpoint: StageCheckpoint::new(end).with_index_history_stage_checkpoint(
+                IndexHistoryCheckpoint {
+                    block_range: CheckpointBlockRange { from: first_block, to: end },
+                    progress: EntitiesCheckpoint {
+                        processed: end - first_block + 1,
+                        total: target - first_block + 1,
+                    },
+                },
+            ),
+            done: end == target,
+        })
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_to, _) = input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        let Some(IndexHistoryCheckpoint { block_range: indexed_range, .. }) =
+            input.checkpoint.index_history_stage_checkpoint()
+        else {
+            return Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) })
+        };
+
+        let mut receipts = Vec::new();
+        for block_number in
+            (*range.start()).max(indexed_range.from)..=(*range.end()).min(indexed_range.to)
+        {
+            receipts.extend(provider.receipts_by_block(block_number.into())?.unwrap_or_default());
+        }
+        provider.unwind_log_index(receipts.iter().flat_map(|receipt| receipt.logs()), unwind_to)?;
+
+        let mut checkpoint = StageCheckpoint::new(unwind_to);
+        // If nothing is indexed anymore, the index is rebuilt on the next execution.
+        if unwind_to >= indexed_range.from {
+            let to = unwind_to.min(indexed_range.to);
+            let processed = to - indexed_range.from + 1;
+            checkpoint = checkpoint.with_index_history_stage_checkpoint(IndexHistoryCheckpoint {
+                block_range: CheckpointBlockRange { from: indexed_range.from, to },
+                progress: EntitiesCheckpoint { processed, total: processed },
+            });
+        }
+
+        Ok(UnwindOutput { checkpoint })
+    }
+}
+
+/// Clears both log index tables.
+fn clear_log_index<Provider: DBProvider<Tx: DbTxMut>>(
+    provider: &Provider,
+) -> Result<(), StageError> {
+    provider.tx_ref().clear::<tables::LogAddressIndex>()?;
+    provider.tx_ref().clear::<tables::LogTopicIndex>()?;
+    Ok(())
+}
+
+/// Adds the block to the cached indices of the key, blocks are pushed in ascending order.
+fn push_block<K: Hash + Eq>(
+    cache: &mut HashMap<K, Vec<BlockNumber>>,
+    key: K,
+    block_number: BlockNumber,
+) {
+    match cache.entry(key) {
+        Entry::Occupied(mut entry) => {
+            if entry.get().last() != Some(&block_number) {
+                entry.get_mut().push(block_number);
+            }
+        }
+        Entry::Vacant(entry) => {
+            entry.insert(vec![block_number]);
+        }
+    }
+}
+
+/// Moves the cached indices into the [`Collector`], keyed by their highest block number.
+fn collect_cache<K>(
+    collector: &mut Collector<ShardedKey<K>, BlockNumberList>,
+    cache: &mut HashMap<K, Vec<BlockNumber>>,
+) -> Result<(), StageError>
+where
+    ShardedKey<K>: Key,
+    K: Copy + Hash + Eq,
+{
+    for (key, indices) in cache.drain() {
+        let last = *indices.last().expect("cached indices are never empty");
+        collector.insert(ShardedKey::new(key, last), BlockNumberList::new_pre_sorted(indices))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{StorageKind, TestStageDB};
+    use alloy_primitives::address;
+    use reth_db_api::transaction::DbTx;
+    use reth_provider::{DatabaseProviderFactory, LogIndexReader};
+    use reth_testing_utils::generators::{
+        self, random_block_range, random_log, random_receipt, BlockRangeParams,
+    };
+
+    const ADDRESS: Address = address!("0000000000000000000000000000000000000001");
+
+    #[test]
+    fn execute_and_unwind() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+        let tip = 20;
+
+        let blocks = random_block_range(
+            &mut rng,
+            0..=tip,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 1..2, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).unwrap();
+
+        // every third block has a log emitted by `ADDRESS`
+        let mut tx_num = 0;
+        let mut receipts = Vec::new();
+        for block in &blocks {
+            let mut block_receipts = Vec::new();
+            for transaction in &block.body.transactions {
+                let mut receipt = random_receipt(&mut rng, transaction, Some(0));
+                if block.number % 3 == 0 {
+                    receipt.logs.push(random_log(&mut rng, Some(ADDRESS), Some(1)));
+                }
+                block_receipts.push((tx_num, receipt));
+                tx_num += 1;
+            }
+            receipts.push((block.number, block_receipts));
+        }
+        db.insert_receipts_by_block(receipts, StorageKind::Static).unwrap();
+        let expected = (0..=tip).filter(|n| n % 3 == 0).collect::<Vec<_>>();
+
+        let mut stage = IndexLogsStage::new(
+            IndexLogsConfig { enabled: true, commit_threshold: 8 },
+            EtlConfig::default(),
+        );
+        let provider = db.factory.database_provider_rw().unwrap();
+        let mut input = ExecInput { target: Some(tip), checkpoint: None };
+        loop {
+            let output = stage.execute(&provider, input).unwrap();
+            input.checkpoint = Some(output.checkpoint);
+            if output.done {
+                break
+            }
+        }
+        assert_eq!(
+            input.checkpoint().index_history_stage_checkpoint().map(|c| c.block_range),
+            Some(CheckpointBlockRange { from: 0, to: tip })
+        );
+        assert_eq!(provider.blocks_with_address_logs(ADDRESS, 0..=tip).unwrap(), expected);
+        assert_eq!(provider.blocks_with_address_logs(ADDRESS, 4..=9).unwrap(), vec![6, 9]);
+
+        let mut checkpoint = input.checkpoint();
+        while checkpoint.block_number > 10 {
+            let input = UnwindInput { checkpoint, unwind_to: 10, bad_block: None };
+            checkpoint = stage.unwind(&provider, input).unwrap().checkpoint;
+        }
+        assert_eq!(
+            checkpoint.index_history_stage_checkpoint().map(|c| c.block_range),
+            Some(CheckpointBlockRange { from: 0, to: 10 })
+        );
+        assert_eq!(
+            provider.blocks_with_address_logs(ADDRESS, 0..=tip).unwrap(),
+            expected.into_iter().filter(|n| *n <= 10).collect::<Vec<_>>()
+        );
+
+        // disabling the index clears it
+        stage.enabled = false;
+        stage
+            .execute(&provider, ExecInput { target: Some(tip), checkpoint: Some(checkpoint) })
+            .unwrap();
+        assert_eq!(provider.tx_ref().entries::<tables::LogAddressIndex>().unwrap(), 0);
+    }
+}
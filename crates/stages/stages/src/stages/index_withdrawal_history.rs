@@ -0,0 +1,326 @@
+use alloy_eips::eip4895::{Withdrawal, Withdrawals};
+use alloy_primitives::{Address, BlockNumber};
+use num_traits::Zero;
+use reth_config::config::{EtlConfig, IndexHistoryConfig};
+use reth_db::{tables, RawKey, RawValue};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW},
+    models::{AddressBlockNumber, StoredBlockWithdrawals, ValidatorBlockNumber},
+    table::Table,
+    transaction::{DbTx, DbTxMut},
+};
+use reth_etl::Collector;
+use reth_provider::{DBProvider, PruneCheckpointReader, PruneCheckpointWriter, StatsReader};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment};
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use std::collections::BTreeMap;
+use tracing::*;
+
+/// The withdrawal history indexing stage.
+///
+/// This stage walks over the withdrawals of existing blocks, and maps both the recipient address
+/// and the validator index of each withdrawal to the withdrawals of the block. This is written to
+/// [`tables::AddressWithdrawals`] and [`tables::ValidatorWithdrawals`] and is used for looking up
+/// the withdrawal history of an address or a validator.
+///
+/// The index is optional: the stage only records its blocks as pruned unless the withdrawal
+/// history index is enabled through
+/// [`PruneModes::withdrawal_history`](reth_prune_types::PruneModes::withdrawal_history).
+///
+/// It uses [`reth_etl::Collector`] to collect all entries before finally writing them to disk.
+#[derive(Debug, Clone)]
+pub struct IndexWithdrawalHistoryStage {
+    /// Number of blocks after which the control
+    /// flow will be returned to the pipeline for commit.
+    commit_threshold: u64,
+    etl_config: EtlConfig,
+    prune_mode: Option<PruneMode>,
+}
+
+impl Default for IndexWithdrawalHistoryStage {
+    fn default() -> Self {
+        Self { commit_threshold: 100_000, etl_config: EtlConfig::default(), prune_mode: None }
+    }
+}
+
+impl IndexWithdrawalHistoryStage {
+    /// Create new instance of [`IndexWithdrawalHistoryStage`].
+    pub const fn new(
+        config: IndexHistoryConfig,
+        etl_config: EtlConfig,
+        prune_mode: Option<PruneMode>,
+    ) -> Self {
+        Self { commit_threshold: config.commit_threshold, etl_config, prune_mode }
+    }
+
+    /// Returns `true` if the withdrawal history index is enabled.
+    fn is_enabled(&self) -> bool {
+        self.prune_mode.is_some_and(|mode| !mode.is_full())
+    }
+}
+
+impl<Provider> Stage<Provider> for IndexWithdrawalHistoryStage
+where
+    Provider: DBProvider<Tx: DbTxMut> + PruneCheckpointWriter + PruneCheckpointReader + StatsReader,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexWithdrawalHistory
+    }
+
+    /// Write address + block number and validator index + block number -> withdrawals entries
+    fn execute(
+        &mut self,
+        provider: &Provider,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if !self.is_enabled() {
+            // Recording the skipped blocks as pruned makes lookups over them fail instead of
+            // coming back empty, should the index be enabled later on.
+            provider.save_prune_checkpoint(
+                PruneSegment::WithdrawalHistory,
+                PruneCheckpoint {
+                    block_number: Some(input.target()),
+                    tx_number: None,
+                    prune_mode: PruneMode::Full,
+                },
+            )?;
+            return Ok(ExecOutput::done(StageCheckpoint::new(input.target())))
+        }
+
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| {
+                mode.prune_target_block(
+                    input.target(),
+                    PruneSegment::WithdrawalHistory,
+                    PrunePurpose::User,
+                )
+            })
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::WithdrawalHistory)?.is_none() {
+                    provider.save_prune_checkpoint(
+                        PruneSegment::WithdrawalHistory,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: None,
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()));
+        }
+
+        let (range, is_final_range) = input.next_block_range_with_threshold(self.commit_threshold);
+
+        info!(
+            target: "sync::stages::index_withdrawal_history",
+            ?range,
+            "Collecting withdrawal history"
+        );
+
+        let mut address_collector: Collector<AddressBlockNumber, StoredBlockWithdrawals> =
+            Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+        let mut validator_collector: Collector<ValidatorBlockNumber, StoredBlockWithdrawals> =
+            Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+
+        for entry in provider
+            .tx_ref()
+            .cursor_read::<tables::BlockWithdrawals>()?
+            .walk_range(range.clone())?
+        {
+            let (block_number, stored) = entry?;
+            for (address, withdrawals) in group_withdrawals(&stored, |w| w.address) {
+                address_collector.insert((address, block_number).into(), withdrawals)?;
+            }
+            for (validator_index, withdrawals) in group_withdrawals(&stored, |w| w.validator_index)
+            {
+                validator_collector.insert((validator_index, block_number).into(), withdrawals)?;
+            }
+        }
+
+        load_withdrawal_history::<_, tables::AddressWithdrawals>(provider, address_collector)?;
+        load_withdrawal_history::<_, tables::ValidatorWithdrawals>(provider, validator_collector)?;
+
+        Ok(ExecOutput { checkpoint: StageCheckpoint::new(*range.end()), done: is_final_range })
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_to, _) = input.unwind_block_range_with_threshold(self.commit_threshold);
+
+        if !self.is_enabled() {
+            return Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) })
+        }
+
+        let tx = provider.tx_ref();
+        let mut withdrawals_cursor = tx.cursor_read::<tables::BlockWithdrawals>()?;
+        let mut address_cursor = tx.cursor_write::<tables::AddressWithdrawals>()?;
+        let mut validator_cursor = tx.cursor_write::<tables::ValidatorWithdrawals>()?;
+        for entry in withdrawals_cursor.walk_range(range)? {
+            let (block_number, stored) = entry?;
+            for withdrawal in stored.withdrawals.iter() {
+                if address_cursor.seek_exact((withdrawal.address, block_number).into())?.is_some() {
+                    address_cursor.delete_current()?;
+                }
+                if validator_cursor
+                    .seek_exact((withdrawal.validator_index, block_number).into())?
+                    .is_some()
+                {
+                    validator_cursor.delete_current()?;
+                }
+            }
+        }
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) })
+    }
+}
+
+/// Groups the withdrawals of a block by the given key, e.g. the recipient [`Address`].
+fn group_withdrawals<K: Ord>(
+    stored: &StoredBlockWithdrawals,
+    key: impl Fn(&Withdrawal) -> K,
+) -> BTreeMap<K, StoredBlockWithdrawals> {
+    let mut grouped = BTreeMap::<K, Vec<Withdrawal>>::new();
+    for withdrawal in stored.withdrawals.iter() {
+        grouped.entry(key(withdrawal)).or_default().push(*withdrawal);
+    }
+    grouped
+        .into_iter()
+        .map(|(key, withdrawals)| {
+            (key, StoredBlockWithdrawals { withdrawals: Withdrawals::new(withdrawals) })
+        })
+        .collect()
+}
+
+/// Writes the collected withdrawal history entries into the table, appending them if the table is
+/// empty.
+fn load_withdrawal_history<Provider, T>(
+    provider: &Provider,
+    collector: Collector<T::Key, T::Value>,
+) -> Result<(), StageError>
+where
+    Provider: DBProvider<Tx: DbTxMut> + StatsReader,
+    T: Table<Value = StoredBlockWithdrawals>,
+{
+    let append_only = provider.count_entries::<T>()?.is_zero();
+    let mut cursor = provider.tx_ref().cursor_write::<tables::RawTable<T>>()?;
+
+    let total = collector.len();
+    for entry in collector.iter()? {
+        let (key, value) = entry?;
+        let key = RawKey::<T::Key>::from_vec(key);
+        let value = RawValue::<T::Value>::from_vec(value);
+        if append_only {
+            cursor.append(key, value)?
+        } else {
+            cursor.insert(key, value)?
+        }
+    }
+
+    trace!(target: "sync::stages::index_withdrawal_history",
+        table = T::NAME,
+        total,
+        "Withdrawal history inserted"
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TestStageDB;
+    use reth_provider::{DatabaseProviderFactory, WithdrawalHistoryReader};
+    use reth_prune_types::PruneModes;
+
+    fn withdrawal(index: u64, validator_index: u64, address: Address) -> Withdrawal {
+        Withdrawal { index, validator_index, address, amount: 1 }
+    }
+
+    #[test]
+    fn execute_and_unwind() {
+        let db = TestStageDB::default();
+        let tip = 20;
+        let address = Address::with_last_byte(1);
+
+        // every block has a withdrawal to `address` from validator 7, every other block a second
+        // one to `address` from validator 8
+        db.commit(|tx| {
+            for block_number in 0..=tip {
+                let mut withdrawals = vec![withdrawal(block_number * 2, 7, address)];
+                if block_number % 2 == 0 {
+                    withdrawals.push(withdrawal(block_number * 2 + 1, 8, address));
+                }
+                tx.put::<tables::BlockWithdrawals>(
+                    block_number,
+                    StoredBlockWithdrawals { withdrawals: Withdrawals::new(withdrawals) },
+                )?;
+            }
+            Ok(())
+        })
+        .unwrap();
+
+        let prune_mode = PruneMode::Distance(100);
+        let mut stage = IndexWithdrawalHistoryStage::new(
+            IndexHistoryConfig { commit_threshold: 8 },
+            EtlConfig::default(),
+            Some(prune_mode),
+        );
+        let factory = db.factory.clone().with_prune_modes(PruneModes {
+            withdrawal_history: Some(prune_mode),
+            ..Default::default()
+        });
+        let provider = factory.database_provider_rw().unwrap();
+        let mut input = ExecInput { target: Some(tip), checkpoint: None };
+        loop {
+            let output = stage.execute(&provider, input).unwrap();
+            input.checkpoint = Some(output.checkpoint);
+            if output.done {
+                break
+            }
+        }
+
+        // block 0 is the checkpoint of the first execution, it's not indexed
+        assert_eq!(
+            provider.withdrawals_by_address(address, 0..=tip, usize::MAX).unwrap().len(),
+            30
+        );
+        assert_eq!(provider.withdrawals_by_validator(7, 0..=tip, usize::MAX).unwrap().len(), 20);
+        assert_eq!(provider.withdrawals_by_validator(8, 0..=tip, usize::MAX).unwrap().len(), 10);
+        assert_eq!(
+            provider.withdrawals_by_validator(8, 4..=9, usize::MAX).unwrap(),
+            vec![
+                (4, withdrawal(9, 8, address)),
+                (6, withdrawal(13, 8, address)),
+                (8, withdrawal(17, 8, address))
+            ]
+        );
+        assert_eq!(provider.withdrawals_by_address(address, 0..=tip, 3).unwrap().len(), 3);
+
+        let mut checkpoint = input.checkpoint();
+        while checkpoint.block_number > 10 {
+            let input = UnwindInput { checkpoint, unwind_to: 10, bad_block: None };
+            checkpoint = stage.unwind(&provider, input).unwrap().checkpoint;
+        }
+        assert_eq!(checkpoint.block_number, 10);
+        assert_eq!(provider.withdrawals_by_validator(7, 0..=tip, usize::MAX).unwrap().len(), 10);
+        assert_eq!(provider.withdrawals_by_validator(8, 0..=tip, usize::MAX).unwrap().len(), 5);
+    }
+}
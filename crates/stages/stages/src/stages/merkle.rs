@@ -7,7 +7,7 @@ use reth_db_api::transaction::{DbTx, DbTxMut};
 use reth_primitives::{GotExpected, SealedHeader};
 use reth_provider::{
     DBProvider, HeaderProvider, ProviderError, StageCheckpointReader, StageCheckpointWriter,
-    StatsReader, TrieWriter,
+    StateCommitmentProvider, StateRootOf, StatsReader, TrieWriter,
 };
 use reth_stages_api::{
     BlockErrorKind, EntitiesCheckpoint, ExecInput, ExecOutput, MerkleCheckpoint, Stage,
@@ -138,7 +138,8 @@ where
         + StatsReader
         + HeaderProvider<Header = alloy_consensus::Header>
         + StageCheckpointReader
-        + StageCheckpointWriter,
+        + StageCheckpointWriter
+        + StateCommitmentProvider,
 {
     /// Return the id of the stage
     fn id(&self) -> StageId {
@@ -251,7 +252,7 @@ where
         } else {
             debug!(target: "sync::stages::merkle::exec", current = ?current_block_number, target = ?to_block, "Updating trie");
             let (root, updates) =
-                StateRoot::incremental_root_with_updates(provider.tx_ref(), range)
+                StateRootOf::<'_, Provider>::incremental_root_with_updates(provider.tx_ref(), range)
                     .map_err(|e| {
                         error!(target: "sync::stages::merkle", %e, ?current_block_number, ?to_block, "Incremental state root failed! {INVALID_STATE_ROOT_ERROR_MESSAGE}");
                         StageError::Fatal(Box::new(e))
@@ -322,8 +323,9 @@ where
         if range.is_empty() {
             info!(target: "sync::stages::merkle::unwind", "Nothing to unwind");
         } else {
-            let (block_root, updates) = StateRoot::incremental_root_with_updates(tx, range)
-                .map_err(|e| StageError::Fatal(Box::new(e)))?;
+            let (block_root, updates) =
+                StateRootOf::<'_, Provider>::incremental_root_with_updates(tx, range)
+                    .map_err(|e| StageError::Fatal(Box::new(e)))?;
 
             // Validate the calculated state root
             let target = provider
@@ -12,8 +12,14 @@ mod hashing_storage;
 mod headers;
 /// Index history of account changes
 mod index_account_history;
+/// Index of the transactions of each address
+mod index_address_transactions;
+/// Index of the blocks containing logs
+mod index_logs;
 /// Index history of storage changes
 mod index_storage_history;
+/// Index history of withdrawals
+mod index_withdrawal_history;
 /// Stage for computing state root.
 mod merkle;
 mod prune;
@@ -21,6 +27,8 @@ mod prune;
 mod sender_recovery;
 /// The transaction lookup stage
 mod tx_lookup;
+/// The transaction selectors indexing stage
+mod tx_selectors;
 
 pub use bodies::*;
 pub use execution::*;
@@ -29,11 +37,15 @@ pub use hashing_account::*;
 pub use hashing_storage::*;
 pub use headers::*;
 pub use index_account_history::*;
+pub use index_address_transactions::*;
+pub use index_logs::*;
 pub use index_storage_history::*;
+pub use index_withdrawal_history::*;
 pub use merkle::*;
 pub use prune::*;
 pub use sender_recovery::*;
 pub use tx_lookup::*;
+pub use tx_selectors::*;
 
 mod utils;
 use utils::*;
@@ -368,8 +368,8 @@ struct FailedSenderRecoveryError {
 mod tests {
     use super::*;
     use crate::test_utils::{
-        stage_test_suite_ext, ExecuteStageTestRunner, StageTestRunner, StorageKind,
-        TestRunnerError, TestStageDB, UnwindStageTestRunner,
+        stage_test_suite_ext, ExecuteStageTestRunner, StageInvariants, StageTestRunner,
+        StorageKind, TestRunnerError, TestStageDB, UnwindStageTestRunner,
     };
     use alloy_primitives::{BlockNumber, B256};
     use assert_matches::assert_matches;
@@ -383,7 +383,7 @@ mod tests {
     use reth_prune_types::{PruneCheckpoint, PruneMode};
     use reth_stages_api::StageUnitCheckpoint;
     use reth_testing_utils::generators::{
-        self, random_block, random_block_range, BlockParams, BlockRangeParams,
+        self, random_block, random_block_range, BlockParams, BlockRangeParams, Rng,
     };
 
     stage_test_suite_ext!(SenderRecoveryTestRunner, sender_recovery);
@@ -578,6 +578,24 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn stage_invariants() {
+        let mut rng = generators::rng();
+        let db = TestStageDB::default();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=100,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..3, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).expect("failed to insert blocks");
+
+        let mut invariants =
+            StageInvariants::new(&db, SenderRecoveryStage { commit_threshold: 10 }, |db| {
+                db.table::<tables::TransactionSenders>().unwrap()
+            });
+        invariants.fuzz(rng.gen(), 10, 0..=100).await.unwrap();
+    }
+
     struct SenderRecoveryTestRunner {
         db: TestStageDB,
         threshold: u64,
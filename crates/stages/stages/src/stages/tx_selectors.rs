@@ -0,0 +1,252 @@
+use alloy_primitives::BlockNumber;
+use num_traits::Zero;
+use reth_config::config::{EtlConfig, IndexTransactionSelectorsConfig};
+use reth_db::{table::Value, tables, RawKey, RawValue};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW},
+    models::SelectorTxNumber,
+    transaction::{DbTx, DbTxMut},
+};
+use reth_etl::Collector;
+use reth_primitives::NodePrimitives;
+use reth_primitives_traits::SignedTransaction;
+use reth_provider::{
+    BlockReader, DBProvider, PruneCheckpointReader, PruneCheckpointWriter,
+    StaticFileProviderFactory, StatsReader, TransactionsProvider,
+};
+use reth_prune_types::{PruneCheckpoint, PruneMode, PrunePurpose, PruneSegment};
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageId, UnwindInput, UnwindOutput,
+};
+use reth_storage_errors::provider::ProviderError;
+use tracing::*;
+
+/// The transaction selectors indexing stage.
+///
+/// This stage walks over existing transactions, and maps the 4-byte function selector of each
+/// contract call to the corresponding `BlockNumber`. This is written to the
+/// [`tables::TransactionSelectors`] and is used for looking up transactions calling a specific
+/// function.
+///
+/// The index is optional: the stage is a no-op unless the selector index is enabled through
+/// [`PruneModes::transaction_selectors`](reth_prune_types::PruneModes::transaction_selectors).
+///
+/// It uses [`reth_etl::Collector`] to collect all entries before finally writing them to disk.
+#[derive(Debug, Clone)]
+pub struct IndexTransactionSelectorsStage {
+    /// The maximum number of transactions to process before pushing the entries to
+    /// [`reth_etl::Collector`].
+    chunk_size: u64,
+    etl_config: EtlConfig,
+    prune_mode: Option<PruneMode>,
+}
+
+impl Default for IndexTransactionSelectorsStage {
+    fn default() -> Self {
+        Self { chunk_size: 5_000_000, etl_config: EtlConfig::default(), prune_mode: None }
+    }
+}
+
+impl IndexTransactionSelectorsStage {
+    /// Create new instance of [`IndexTransactionSelectorsStage`].
+    pub const fn new(
+        config: IndexTransactionSelectorsConfig,
+        etl_config: EtlConfig,
+        prune_mode: Option<PruneMode>,
+    ) -> Self {
+        Self { chunk_size: config.chunk_size, etl_config, prune_mode }
+    }
+
+    /// Returns `true` if the selector index is enabled.
+    fn is_enabled(&self) -> bool {
+        self.prune_mode.is_some_and(|mode| !mode.is_full())
+    }
+}
+
+impl<Provider> Stage<Provider> for IndexTransactionSelectorsStage
+where
+    Provider: DBProvider<Tx: DbTxMut>
+        + PruneCheckpointWriter
+        + BlockReader
+        + PruneCheckpointReader
+        + StatsReader
+        + StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value + SignedTransaction>>,
+{
+    /// Return the id of the stage
+    fn id(&self) -> StageId {
+        StageId::IndexTransactionSelectors
+    }
+
+    /// Write selector + tx number -> block number entries
+    fn execute(
+        &mut self,
+        provider: &Provider,
+        mut input: ExecInput,
+    ) -> Result<ExecOutput, StageError> {
+        if !self.is_enabled() {
+            // The blocks are not indexed, so they are recorded as pruned. This way they are not
+            // served as having no matches if the index is enabled later on.
+            if let Some(body) = provider.block_body_indices(input.target())? {
+                provider.save_prune_checkpoint(
+                    PruneSegment::TransactionSelectors,
+                    PruneCheckpoint {
+                        block_number: Some(input.target()),
+                        tx_number: Some(body.last_tx_num()),
+                        prune_mode: PruneMode::Full,
+                    },
+                )?;
+            }
+            return Ok(ExecOutput::done(StageCheckpoint::new(input.target())))
+        }
+
+        if let Some((target_prunable_block, prune_mode)) = self
+            .prune_mode
+            .map(|mode| {
+                mode.prune_target_block(
+                    input.target(),
+                    PruneSegment::TransactionSelectors,
+                    PrunePurpose::User,
+                )
+            })
+            .transpose()?
+            .flatten()
+        {
+            if target_prunable_block > input.checkpoint().block_number {
+                input.checkpoint = Some(StageCheckpoint::new(target_prunable_block));
+
+                // Save prune checkpoint only if we don't have one already.
+                // Otherwise, pruner may skip the unpruned range of blocks.
+                if provider.get_prune_checkpoint(PruneSegment::TransactionSelectors)?.is_none() {
+                    let target_prunable_tx_number = provider
+                        .block_body_indices(target_prunable_block)?
+                        .ok_or(ProviderError::BlockBodyIndicesNotFound(target_prunable_block))?
+                        .last_tx_num();
+
+                    provider.save_prune_checkpoint(
+                        PruneSegment::TransactionSelectors,
+                        PruneCheckpoint {
+                            block_number: Some(target_prunable_block),
+                            tx_number: Some(target_prunable_tx_number),
+                            prune_mode,
+                        },
+                    )?;
+                }
+            }
+        }
+        if input.target_reached() {
+            return Ok(ExecOutput::done(input.checkpoint()));
+        }
+
+        let mut selector_collector: Collector<SelectorTxNumber, BlockNumber> =
+            Collector::new(self.etl_config.file_size, self.etl_config.dir.clone());
+
+        info!(
+            target: "sync::stages::index_transaction_selectors",
+            block_range = ?input.checkpoint().block_number..=input.target(),
+            "Updating transaction selectors index"
+        );
+
+        let static_file_provider = provider.static_file_provider();
+        let mut body_cursor = provider.tx_ref().cursor_read::<tables::BlockBodyIndices>()?;
+        loop {
+            let (tx_range, block_range, is_final_range) =
+                input.next_block_range_with_transaction_threshold(provider, self.chunk_size)?;
+
+            let end_block = *block_range.end();
+
+            info!(target: "sync::stages::index_transaction_selectors", ?tx_range, "Collecting transaction selectors");
+
+            for entry in body_cursor.walk_range(block_range)? {
+                let (block_number, body) = entry?;
+                let transactions =
+                    static_file_provider.transactions_by_tx_range(body.tx_num_range())?;
+                for (tx_number, transaction) in body.tx_num_range().zip(transactions) {
+                    if let Some(selector) = transaction.function_selector() {
+                        selector_collector.insert((selector, tx_number).into(), block_number)?;
+                    }
+                }
+            }
+
+            input.checkpoint = Some(StageCheckpoint::new(end_block));
+
+            if is_final_range {
+                let append_only =
+                    provider.count_entries::<tables::TransactionSelectors>()?.is_zero();
+                let mut selector_cursor = provider
+                    .tx_ref()
+                    .cursor_write::<tables::RawTable<tables::TransactionSelectors>>()?;
+
+                let total_selectors = selector_collector.len();
+                let interval = (total_selectors / 10).max(1);
+                for (index, entry) in selector_collector.iter()?.enumerate() {
+                    let (key, block_number) = entry?;
+                    if index > 0 && index % interval == 0 {
+                        info!(
+                            target: "sync::stages::index_transaction_selectors",
+                            ?append_only,
+                            progress = %format!("{:.2}%", (index as f64 / total_selectors as f64) * 100.0),
+                            "Inserting selectors"
+                        );
+                    }
+
+                    let key = RawKey::<SelectorTxNumber>::from_vec(key);
+                    let value = RawValue::<BlockNumber>::from_vec(block_number);
+                    if append_only {
+                        selector_cursor.append(key, value)?
+                    } else {
+                        selector_cursor.insert(key, value)?
+                    }
+                }
+
+                trace!(target: "sync::stages::index_transaction_selectors",
+                    total_selectors,
+                    "Transaction selectors inserted"
+                );
+
+                break;
+            }
+        }
+
+        Ok(ExecOutput::done(StageCheckpoint::new(input.target())))
+    }
+
+    /// Unwind the stage.
+    fn unwind(
+        &mut self,
+        provider: &Provider,
+        input: UnwindInput,
+    ) -> Result<UnwindOutput, StageError> {
+        let (range, unwind_to, _) = input.unwind_block_range_with_threshold(self.chunk_size);
+
+        if !self.is_enabled() {
+            return Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) })
+        }
+
+        let tx = provider.tx_ref();
+
+        // Cursors to unwind selector + tx number to block number
+        let mut body_cursor = tx.cursor_read::<tables::BlockBodyIndices>()?;
+        let mut selector_cursor = tx.cursor_write::<tables::TransactionSelectors>()?;
+        let static_file_provider = provider.static_file_provider();
+        let mut rev_walker = body_cursor.walk_back(Some(*range.end()))?;
+        while let Some((number, body)) = rev_walker.next().transpose()? {
+            if number <= unwind_to {
+                break;
+            }
+
+            // Delete all selector entries of the transactions that belong to this block
+            for tx_id in body.tx_num_range() {
+                if let Some(selector) = static_file_provider
+                    .transaction_by_id(tx_id)?
+                    .and_then(|transaction| transaction.function_selector())
+                {
+                    if selector_cursor.seek_exact((selector, tx_id).into())?.is_some() {
+                        selector_cursor.delete_current()?;
+                    }
+                }
+            }
+        }
+
+        Ok(UnwindOutput { checkpoint: StageCheckpoint::new(unwind_to) })
+    }
+}
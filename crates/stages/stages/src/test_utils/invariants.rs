@@ -0,0 +1,240 @@
+use super::TestStageDB;
+use alloy_primitives::BlockNumber;
+use reth_db::{test_utils::TempDatabase, Database, DatabaseEnv};
+use reth_provider::{test_utils::MockNodeTypesWithDB, DatabaseProvider, ProviderError};
+use reth_stages_api::{
+    ExecInput, ExecOutput, Stage, StageCheckpoint, StageError, StageExt, UnwindInput, UnwindOutput,
+};
+use reth_testing_utils::generators::{rng_with_seed, Rng};
+use std::{fmt::Debug, ops::RangeInclusive};
+use tracing::debug;
+
+/// The provider that stages are run with by [`StageInvariants`].
+pub type TestStageProvider =
+    DatabaseProvider<<TempDatabase<DatabaseEnv> as Database>::TXMut, MockNodeTypesWithDB>;
+
+/// A stage invariant that was violated, see [`StageInvariants`].
+#[derive(Debug, thiserror::Error)]
+pub enum InvariantViolation {
+    /// The stage returned an error.
+    #[error(transparent)]
+    Stage(#[from] StageError),
+    /// The database returned an error.
+    #[error(transparent)]
+    Provider(#[from] ProviderError),
+    /// The checkpoint decreased during execution.
+    #[error("execution checkpoint decreased from {previous} to {current}")]
+    ExecuteCheckpointDecreased {
+        /// The checkpoint before the execution.
+        previous: BlockNumber,
+        /// The checkpoint returned by the execution.
+        current: BlockNumber,
+    },
+    /// The checkpoint returned by an execution is beyond the target.
+    #[error("execution checkpoint {checkpoint} is beyond the target {target}")]
+    ExecuteCheckpointBeyondTarget {
+        /// The checkpoint returned by the execution.
+        checkpoint: BlockNumber,
+        /// The target of the execution.
+        target: BlockNumber,
+    },
+    /// The checkpoint increased during an unwind.
+    #[error("unwind checkpoint increased from {previous} to {current}")]
+    UnwindCheckpointIncreased {
+        /// The checkpoint before the unwind.
+        previous: BlockNumber,
+        /// The checkpoint returned by the unwind.
+        current: BlockNumber,
+    },
+    /// The checkpoint returned by an unwind is not the block that was unwound to.
+    #[error("unwind checkpoint {checkpoint} does not match the unwind target {unwind_to}")]
+    UnwindCheckpointMismatch {
+        /// The checkpoint returned by the unwind.
+        checkpoint: BlockNumber,
+        /// The block that was unwound to.
+        unwind_to: BlockNumber,
+    },
+    /// Executing to the target again after an unwind resulted in a different state.
+    #[error("state after re-executing {case:?} differs from the state after the first execution")]
+    NotIdempotent {
+        /// The case that was checked.
+        case: InvariantCase,
+    },
+    /// An invariant of the given case was violated.
+    #[error("{case:?}: {violation}")]
+    Case {
+        /// The case that was checked.
+        case: InvariantCase,
+        /// The violated invariant.
+        #[source]
+        violation: Box<InvariantViolation>,
+    },
+}
+
+/// The block numbers of an execute, unwind and re-execute cycle, see
+/// [`StageInvariants::check_case`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvariantCase {
+    /// The block the stage is executed to.
+    pub target: BlockNumber,
+    /// The block the stage is unwound to after the execution.
+    pub unwind_to: BlockNumber,
+}
+
+/// Checks invariants that every stage is expected to uphold.
+///
+/// The stage is run against the given [`TestStageDB`], which must be seeded with the data the
+/// stage requires for all checked blocks. The invariants are:
+/// - the checkpoint never decreases during execution and never exceeds the target
+/// - the checkpoint never increases during an unwind and ends at the block that was unwound to
+/// - executing to a target, unwinding, and executing to the same target again results in the same
+///   state, as returned by the `snapshot` function
+///
+/// Stages that are not part of this crate can reuse this to test their own implementation.
+#[derive(Debug)]
+pub struct StageInvariants<'a, S, F> {
+    db: &'a TestStageDB,
+    stage: S,
+    snapshot: F,
+    checkpoint: StageCheckpoint,
+}
+
+impl<'a, S, F, T> StageInvariants<'a, S, F>
+where
+    S: Stage<TestStageProvider>,
+    F: Fn(&TestStageDB) -> T,
+    T: PartialEq + Debug,
+{
+    /// Creates a new instance for the stage, starting at the genesis checkpoint.
+    ///
+    /// The `snapshot` function returns the state written by the stage, e.g. the content of the
+    /// tables it populates.
+    pub fn new(db: &'a TestStageDB, stage: S, snapshot: F) -> Self {
+        Self { db, stage, snapshot, checkpoint: StageCheckpoint::new(0) }
+    }
+
+    /// Sets the checkpoint the stage starts at.
+    pub const fn with_checkpoint(mut self, checkpoint: StageCheckpoint) -> Self {
+        self.checkpoint = checkpoint;
+        self
+    }
+
+    /// Returns the current checkpoint of the stage.
+    pub const fn checkpoint(&self) -> StageCheckpoint {
+        self.checkpoint
+    }
+
+    /// Executes the stage until it's done with the given target, checking the checkpoint of
+    /// every execution.
+    pub async fn execute(&mut self, target: BlockNumber) -> Result<(), InvariantViolation> {
+        loop {
+            let input = ExecInput { target: Some(target), checkpoint: Some(self.checkpoint) };
+            self.stage.execute_ready(input).await?;
+
+            let provider = self.db.factory.provider_rw()?;
+            let ExecOutput { checkpoint, done } = self.stage.execute(&provider, input)?;
+            provider.commit()?;
+            self.stage.post_execute_commit()?;
+
+            if checkpoint.block_number < self.checkpoint.block_number {
+                return Err(InvariantViolation::ExecuteCheckpointDecreased {
+                    previous: self.checkpoint.block_number,
+                    current: checkpoint.block_number,
+                })
+            }
+            if checkpoint.block_number > target {
+                return Err(InvariantViolation::ExecuteCheckpointBeyondTarget {
+                    checkpoint: checkpoint.block_number,
+                    target,
+                })
+            }
+            self.checkpoint = checkpoint;
+
+            if done {
+                return Ok(())
+            }
+        }
+    }
+
+    /// Unwinds the stage to the given block, checking the checkpoint of the unwind.
+    pub fn unwind(&mut self, unwind_to: BlockNumber) -> Result<(), InvariantViolation> {
+        let input = UnwindInput { checkpoint: self.checkpoint, unwind_to, bad_block: None };
+
+        let provider = self.db.factory.provider_rw()?;
+        let UnwindOutput { checkpoint } = self.stage.unwind(&provider, input)?;
+        provider.commit()?;
+        self.stage.post_unwind_commit()?;
+
+        if checkpoint.block_number > self.checkpoint.block_number {
+            return Err(InvariantViolation::UnwindCheckpointIncreased {
+                previous: self.checkpoint.block_number,
+                current: checkpoint.block_number,
+            })
+        }
+        if checkpoint.block_number != unwind_to {
+            return Err(InvariantViolation::UnwindCheckpointMismatch {
+                checkpoint: checkpoint.block_number,
+                unwind_to,
+            })
+        }
+        self.checkpoint = checkpoint;
+
+        Ok(())
+    }
+
+    /// Executes the stage to the target of the case, unwinds it and executes it to the same
+    /// target again, checking that both executions result in the same state.
+    ///
+    /// The stage is left at the target of the case.
+    pub async fn check_case(&mut self, case: InvariantCase) -> Result<(), InvariantViolation> {
+        self.execute(case.target).await?;
+        let expected = (self.snapshot)(self.db);
+
+        self.unwind(case.unwind_to)?;
+        self.execute(case.target).await?;
+        let state = (self.snapshot)(self.db);
+
+        if state != expected {
+            debug!(target: "sync::stages::test", ?case, ?expected, ?state, "stage is not idempotent");
+            return Err(InvariantViolation::NotIdempotent { case })
+        }
+        Ok(())
+    }
+
+    /// Checks `iterations` random cases within the given block range, which are derived from the
+    /// seed.
+    ///
+    /// Each case executes the stage to a random target beyond the current checkpoint and unwinds
+    /// it to a random block in the range, see [`Self::check_case`]. Between cases the stage is
+    /// occasionally unwound further, so that targets throughout the range are covered.
+    ///
+    /// Running this again with the same seed on the same database checks the same cases.
+    pub async fn fuzz(
+        &mut self,
+        seed: u64,
+        iterations: usize,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Result<(), InvariantViolation> {
+        let mut rng = rng_with_seed(&seed.to_be_bytes());
+        let (start, end) = (*range.start(), *range.end());
+        self.checkpoint =
+            self.checkpoint.with_block_number(self.checkpoint.block_number.max(start));
+
+        for _ in 0..iterations {
+            let target = rng.gen_range(self.checkpoint.block_number.min(end)..=end);
+            let case = InvariantCase { target, unwind_to: rng.gen_range(start..=target) };
+            debug!(target: "sync::stages::test", seed, ?case, "checking stage invariants");
+            self.check_case(case).await.map_err(|violation| InvariantViolation::Case {
+                case,
+                violation: Box::new(violation),
+            })?;
+
+            if rng.gen_bool(0.5) {
+                self.unwind(rng.gen_range(start..=target)).map_err(|violation| {
+                    InvariantViolation::Case { case, violation: Box::new(violation) }
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use alloy_eips::eip4895::{Withdrawal, Withdrawals};
 use alloy_primitives::{keccak256, Address, BlockNumber, TxHash, TxNumber, B256, U256};
 use reth_chainspec::MAINNET;
 use reth_db::{
@@ -9,7 +10,10 @@ use reth_db_api::{
     common::KeyValue,
     cursor::{DbCursorRO, DbCursorRW, DbDupCursorRO},
     database::Database,
-    models::{AccountBeforeTx, StoredBlockBodyIndices},
+    models::{
+        AccountBeforeTx, AddressTxNumber, SelectorTxNumber, StoredBlockBodyIndices,
+        StoredBlockWithdrawals,
+    },
     table::Table,
     transaction::{DbTx, DbTxMut},
     DatabaseError as DbError,
@@ -311,6 +315,64 @@ impl TestStageDB {
         })
     }
 
+    /// Insert collection of ([`SelectorTxNumber`], [`BlockNumber`]) into the corresponding table.
+    pub fn insert_transaction_selectors<I>(&self, transaction_selectors: I) -> ProviderResult<()>
+    where
+        I: IntoIterator<Item = (SelectorTxNumber, BlockNumber)>,
+    {
+        self.commit(|tx| {
+            transaction_selectors.into_iter().try_for_each(|(key, block_number)| {
+                // Insert into transaction selectors table.
+                Ok(tx.put::<tables::TransactionSelectors>(key, block_number)?)
+            })
+        })
+    }
+
+    /// Insert collection of ([`AddressTxNumber`], [`BlockNumber`]) into the corresponding table.
+    pub fn insert_address_transactions<I>(&self, address_transactions: I) -> ProviderResult<()>
+    where
+        I: IntoIterator<Item = (AddressTxNumber, BlockNumber)>,
+    {
+        self.commit(|tx| {
+            address_transactions.into_iter().try_for_each(|(key, block_number)| {
+                // Insert into address transactions table.
+                Ok(tx.put::<tables::AddressTransactions>(key, block_number)?)
+            })
+        })
+    }
+
+    /// Insert collection of ([`BlockNumber`], [`Vec<Withdrawal>`]) into the block withdrawals
+    /// table and the withdrawal history index tables.
+    pub fn insert_withdrawal_history<I>(&self, block_withdrawals: I) -> ProviderResult<()>
+    where
+        I: IntoIterator<Item = (BlockNumber, Vec<Withdrawal>)>,
+    {
+        self.commit(|tx| {
+            block_withdrawals.into_iter().try_for_each(|(block_number, withdrawals)| {
+                for withdrawal in &withdrawals {
+                    let stored = |filter: &dyn Fn(&Withdrawal) -> bool| StoredBlockWithdrawals {
+                        withdrawals: Withdrawals::new(
+                            withdrawals.iter().filter(|w| filter(w)).copied().collect(),
+                        ),
+                    };
+                    tx.put::<tables::AddressWithdrawals>(
+                        (withdrawal.address, block_number).into(),
+                        stored(&|w| w.address == withdrawal.address),
+                    )?;
+                    tx.put::<tables::ValidatorWithdrawals>(
+                        (withdrawal.validator_index, block_number).into(),
+                        stored(&|w| w.validator_index == withdrawal.validator_index),
+                    )?;
+                }
+                // Insert into block withdrawals table.
+                Ok(tx.put::<tables::BlockWithdrawals>(
+                    block_number,
+                    StoredBlockWithdrawals { withdrawals: Withdrawals::new(withdrawals) },
+                )?)
+            })
+        })
+    }
+
     /// Insert collection of ([`TxNumber`], [Receipt]) into the corresponding table.
     pub fn insert_receipts<I>(&self, receipts: I) -> ProviderResult<()>
     where
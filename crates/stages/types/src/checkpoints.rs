@@ -228,7 +228,7 @@ impl StageCheckpoint {
             StageId::StorageHashing => {
                 StageUnitCheckpoint::Storage(StorageHashingCheckpoint::default())
             }
-            StageId::IndexStorageHistory | StageId::IndexAccountHistory => {
+            StageId::IndexStorageHistory | StageId::IndexAccountHistory | StageId::IndexLogs => {
                 StageUnitCheckpoint::IndexHistory(IndexHistoryCheckpoint::default())
             }
             _ => return self,
@@ -2,11 +2,8 @@ use core::time::Duration;
 
 /// The thresholds at which the execution stage writes state changes to the database.
 ///
-/// If either of the thresholds (`max_blocks` and `max_changes`) are hit, then the execution stage
-/// commits all pending changes to the database.
-///
-/// A third threshold, `max_changesets`, can be set to periodically write changesets to the
-/// current database transaction, which frees up memory.
+/// If any of the thresholds is hit, then the execution stage commits all pending changes to the
+/// database. The number of blocks also bounds the blocks that are unwound at once.
 #[derive(Debug, Clone)]
 pub struct ExecutionStageThresholds {
     /// The maximum number of blocks to execute before the execution stage commits.
@@ -18,8 +18,12 @@ pub enum StageId {
     StorageHashing,
     MerkleExecute,
     TransactionLookup,
+    IndexTransactionSelectors,
+    IndexAddressTransactions,
     IndexStorageHistory,
     IndexAccountHistory,
+    IndexLogs,
+    IndexWithdrawalHistory,
     Prune,
     Finish,
     /// Other custom stage with a provided string identifier.
@@ -28,7 +32,7 @@ pub enum StageId {
 
 impl StageId {
     /// All supported Stages
-    pub const ALL: [Self; 14] = [
+    pub const ALL: [Self; 18] = [
         Self::Headers,
         Self::Bodies,
         Self::SenderRecovery,
@@ -39,8 +43,12 @@ impl StageId {
         Self::StorageHashing,
         Self::MerkleExecute,
         Self::TransactionLookup,
+        Self::IndexTransactionSelectors,
+        Self::IndexAddressTransactions,
         Self::IndexStorageHistory,
         Self::IndexAccountHistory,
+        Self::IndexLogs,
+        Self::IndexWithdrawalHistory,
         Self::Prune,
         Self::Finish,
     ];
@@ -73,8 +81,12 @@ impl StageId {
             Self::StorageHashing => "StorageHashing",
             Self::MerkleExecute => "MerkleExecute",
             Self::TransactionLookup => "TransactionLookup",
+            Self::IndexTransactionSelectors => "IndexTransactionSelectors",
+            Self::IndexAddressTransactions => "IndexAddressTransactions",
             Self::IndexAccountHistory => "IndexAccountHistory",
             Self::IndexStorageHistory => "IndexStorageHistory",
+            Self::IndexLogs => "IndexLogs",
+            Self::IndexWithdrawalHistory => "IndexWithdrawalHistory",
             Self::Prune => "Prune",
             Self::Finish => "Finish",
             Self::Other(s) => s,
@@ -120,6 +132,10 @@ mod tests {
         assert_eq!(StageId::IndexAccountHistory.to_string(), "IndexAccountHistory");
         assert_eq!(StageId::IndexStorageHistory.to_string(), "IndexStorageHistory");
         assert_eq!(StageId::TransactionLookup.to_string(), "TransactionLookup");
+        assert_eq!(StageId::IndexTransactionSelectors.to_string(), "IndexTransactionSelectors");
+        assert_eq!(StageId::IndexAddressTransactions.to_string(), "IndexAddressTransactions");
+        assert_eq!(StageId::IndexLogs.to_string(), "IndexLogs");
+        assert_eq!(StageId::IndexWithdrawalHistory.to_string(), "IndexWithdrawalHistory");
         assert_eq!(StageId::Finish.to_string(), "Finish");
 
         assert_eq!(StageId::Other("Foo").to_string(), "Foo");
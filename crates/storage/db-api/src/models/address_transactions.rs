@@ -0,0 +1,100 @@
+//! Transaction-by-address index related models and types.
+
+use std::ops::Range;
+
+use crate::{
+    impl_fixed_arbitrary,
+    table::{Decode, Encode},
+    DatabaseError,
+};
+use alloy_primitives::{Address, TxNumber};
+use serde::{Deserialize, Serialize};
+
+/// [`Address`] concatenated with the [`TxNumber`] of a transaction the address took part in.
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct AddressTxNumber(pub (Address, TxNumber));
+
+impl AddressTxNumber {
+    /// Create a new Range covering all transactions of the given address whose transaction number
+    /// is within `range`.
+    pub fn range(address: Address, range: Range<TxNumber>) -> Range<Self> {
+        (address, range.start).into()..(address, range.end).into()
+    }
+
+    /// Return the address
+    pub const fn address(&self) -> Address {
+        self.0 .0
+    }
+
+    /// Return the transaction number
+    pub const fn tx_number(&self) -> TxNumber {
+        self.0 .1
+    }
+}
+
+impl From<(Address, TxNumber)> for AddressTxNumber {
+    fn from(tpl: (Address, TxNumber)) -> Self {
+        Self(tpl)
+    }
+}
+
+impl Encode for AddressTxNumber {
+    type Encoded = [u8; 28];
+
+    fn encode(self) -> Self::Encoded {
+        let address = self.0 .0;
+        let tx_number = self.0 .1;
+
+        let mut buf = [0u8; 28];
+
+        buf[..20].copy_from_slice(address.as_slice());
+        buf[20..].copy_from_slice(&tx_number.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for AddressTxNumber {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        let address = Address::from_slice(value.get(..20).ok_or(DatabaseError::Decode)?);
+        let tx_number =
+            u64::from_be_bytes(value[20..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self((address, tx_number)))
+    }
+}
+
+impl_fixed_arbitrary!((AddressTxNumber, 28));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_address_tx_number() {
+        let address = Address::with_last_byte(1);
+        let num = 42u64;
+        let key = AddressTxNumber((address, num));
+
+        let mut bytes = [0u8; 28];
+        bytes[..20].copy_from_slice(address.as_slice());
+        bytes[20..].copy_from_slice(&num.to_be_bytes());
+
+        let encoded = Encode::encode(key);
+        assert_eq!(encoded, bytes);
+
+        let decoded: AddressTxNumber = Decode::decode(&encoded).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn test_address_tx_number_rand() {
+        let mut bytes = [0u8; 28];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = AddressTxNumber::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+    }
+}
@@ -16,18 +16,26 @@ use reth_trie_common::{StoredNibbles, StoredNibblesSubKey, *};
 use serde::{Deserialize, Serialize};
 
 pub mod accounts;
+pub mod address_transactions;
 pub mod blocks;
 pub mod integer_list;
+pub mod selector;
 pub mod sharded_key;
 pub mod storage_sharded_key;
+pub mod touch_epoch;
+pub mod withdrawal;
 
 pub use accounts::*;
+pub use address_transactions::AddressTxNumber;
 pub use blocks::*;
 pub use integer_list::IntegerList;
 pub use reth_db_models::{
     AccountBeforeTx, ClientVersion, StoredBlockBodyIndices, StoredBlockWithdrawals,
 };
+pub use selector::SelectorTxNumber;
 pub use sharded_key::ShardedKey;
+pub use touch_epoch::{AddressEpoch, AddressStorageKeyEpoch};
+pub use withdrawal::{AddressBlockNumber, ValidatorBlockNumber};
 
 /// Macro that implements [`Encode`] and [`Decode`] for uint types.
 macro_rules! impl_uints {
@@ -0,0 +1,101 @@
+//! Transaction selector related models and types.
+
+use std::ops::Range;
+
+use crate::{
+    impl_fixed_arbitrary,
+    table::{Decode, Encode},
+    DatabaseError,
+};
+use alloy_primitives::{Selector, TxNumber};
+use serde::{Deserialize, Serialize};
+
+/// 4-byte function [`Selector`] concatenated with [`TxNumber`].
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct SelectorTxNumber(pub (Selector, TxNumber));
+
+impl SelectorTxNumber {
+    /// Create a new Range covering all transactions with the given selector whose transaction
+    /// number is within `range`.
+    pub fn range(selector: Selector, range: Range<TxNumber>) -> Range<Self> {
+        (selector, range.start).into()..(selector, range.end).into()
+    }
+
+    /// Return the selector
+    pub const fn selector(&self) -> Selector {
+        self.0 .0
+    }
+
+    /// Return the transaction number
+    pub const fn tx_number(&self) -> TxNumber {
+        self.0 .1
+    }
+}
+
+impl From<(Selector, TxNumber)> for SelectorTxNumber {
+    fn from(tpl: (Selector, TxNumber)) -> Self {
+        Self(tpl)
+    }
+}
+
+impl Encode for SelectorTxNumber {
+    type Encoded = [u8; 12];
+
+    fn encode(self) -> Self::Encoded {
+        let selector = self.0 .0;
+        let tx_number = self.0 .1;
+
+        let mut buf = [0u8; 12];
+
+        buf[..4].copy_from_slice(selector.as_slice());
+        buf[4..].copy_from_slice(&tx_number.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for SelectorTxNumber {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        let selector = Selector::try_from(value.get(..4).ok_or(DatabaseError::Decode)?)
+            .map_err(|_| DatabaseError::Decode)?;
+        let tx_number =
+            u64::from_be_bytes(value[4..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self((selector, tx_number)))
+    }
+}
+
+impl_fixed_arbitrary!((SelectorTxNumber, 12));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_selector_tx_number() {
+        let selector = Selector::from([0xa9, 0x05, 0x9c, 0xbb]);
+        let num = 42u64;
+        let key = SelectorTxNumber((selector, num));
+
+        let mut bytes = [0u8; 12];
+        bytes[..4].copy_from_slice(selector.as_slice());
+        bytes[4..].copy_from_slice(&num.to_be_bytes());
+
+        let encoded = Encode::encode(key);
+        assert_eq!(encoded, bytes);
+
+        let decoded: SelectorTxNumber = Decode::decode(&encoded).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn test_selector_tx_number_rand() {
+        let mut bytes = [0u8; 12];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = SelectorTxNumber::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+    }
+}
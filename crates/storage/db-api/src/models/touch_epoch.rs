@@ -0,0 +1,182 @@
+//! Touch epoch related models and types.
+
+use std::ops::Range;
+
+use crate::{
+    impl_fixed_arbitrary,
+    table::{Decode, Encode},
+    DatabaseError,
+};
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+
+/// [`Address`] concatenated with the epoch the account was touched in.
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct AddressEpoch(pub (Address, u64));
+
+impl AddressEpoch {
+    /// Create a new Range covering all epochs of the given address within `range`.
+    pub fn range(address: Address, range: Range<u64>) -> Range<Self> {
+        (address, range.start).into()..(address, range.end).into()
+    }
+
+    /// Return the address
+    pub const fn address(&self) -> Address {
+        self.0 .0
+    }
+
+    /// Return the epoch
+    pub const fn epoch(&self) -> u64 {
+        self.0 .1
+    }
+}
+
+impl From<(Address, u64)> for AddressEpoch {
+    fn from(tpl: (Address, u64)) -> Self {
+        Self(tpl)
+    }
+}
+
+impl Encode for AddressEpoch {
+    type Encoded = [u8; 28];
+
+    fn encode(self) -> Self::Encoded {
+        let address = self.0 .0;
+        let epoch = self.0 .1;
+
+        let mut buf = [0u8; 28];
+
+        buf[..20].copy_from_slice(address.as_slice());
+        buf[20..].copy_from_slice(&epoch.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for AddressEpoch {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        let address = Address::from_slice(value.get(..20).ok_or(DatabaseError::Decode)?);
+        let epoch = u64::from_be_bytes(value[20..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self((address, epoch)))
+    }
+}
+
+/// [`Address`] and storage key concatenated with the epoch the storage slot was touched in.
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct AddressStorageKeyEpoch(pub (Address, B256, u64));
+
+impl AddressStorageKeyEpoch {
+    /// Create a new Range covering all epochs of the given storage slot within `range`.
+    pub fn range(address: Address, storage_key: B256, range: Range<u64>) -> Range<Self> {
+        (address, storage_key, range.start).into()..(address, storage_key, range.end).into()
+    }
+
+    /// Return the address
+    pub const fn address(&self) -> Address {
+        self.0 .0
+    }
+
+    /// Return the storage key
+    pub const fn storage_key(&self) -> B256 {
+        self.0 .1
+    }
+
+    /// Return the epoch
+    pub const fn epoch(&self) -> u64 {
+        self.0 .2
+    }
+}
+
+impl From<(Address, B256, u64)> for AddressStorageKeyEpoch {
+    fn from(tpl: (Address, B256, u64)) -> Self {
+        Self(tpl)
+    }
+}
+
+impl Encode for AddressStorageKeyEpoch {
+    type Encoded = [u8; 60];
+
+    fn encode(self) -> Self::Encoded {
+        let (address, storage_key, epoch) = self.0;
+
+        let mut buf = [0u8; 60];
+
+        buf[..20].copy_from_slice(address.as_slice());
+        buf[20..52].copy_from_slice(storage_key.as_slice());
+        buf[52..].copy_from_slice(&epoch.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for AddressStorageKeyEpoch {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        let address = Address::from_slice(value.get(..20).ok_or(DatabaseError::Decode)?);
+        let storage_key = B256::from_slice(value.get(20..52).ok_or(DatabaseError::Decode)?);
+        let epoch = u64::from_be_bytes(value[52..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self((address, storage_key, epoch)))
+    }
+}
+
+impl_fixed_arbitrary!((AddressEpoch, 28), (AddressStorageKeyEpoch, 60));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_address_epoch() {
+        let address = Address::repeat_byte(0xaa);
+        let epoch = 42u64;
+        let key = AddressEpoch((address, epoch));
+
+        let mut bytes = [0u8; 28];
+        bytes[..20].copy_from_slice(address.as_slice());
+        bytes[20..].copy_from_slice(&epoch.to_be_bytes());
+
+        let encoded = Encode::encode(key);
+        assert_eq!(encoded, bytes);
+
+        let decoded: AddressEpoch = Decode::decode(&encoded).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn test_address_storage_key_epoch() {
+        let address = Address::repeat_byte(0xaa);
+        let storage_key = B256::repeat_byte(0xbb);
+        let epoch = 42u64;
+        let key = AddressStorageKeyEpoch((address, storage_key, epoch));
+
+        let mut bytes = [0u8; 60];
+        bytes[..20].copy_from_slice(address.as_slice());
+        bytes[20..52].copy_from_slice(storage_key.as_slice());
+        bytes[52..].copy_from_slice(&epoch.to_be_bytes());
+
+        let encoded = Encode::encode(key);
+        assert_eq!(encoded, bytes);
+
+        let decoded: AddressStorageKeyEpoch = Decode::decode(&encoded).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn test_touch_epoch_keys_rand() {
+        let mut bytes = [0u8; 28];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = AddressEpoch::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+
+        let mut bytes = [0u8; 60];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = AddressStorageKeyEpoch::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+    }
+}
@@ -0,0 +1,180 @@
+//! Withdrawal history related models and types.
+
+use std::ops::Range;
+
+use crate::{
+    impl_fixed_arbitrary,
+    table::{Decode, Encode},
+    DatabaseError,
+};
+use alloy_primitives::{Address, BlockNumber};
+use serde::{Deserialize, Serialize};
+
+/// Withdrawal recipient [`Address`] concatenated with the [`BlockNumber`] of the withdrawals.
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct AddressBlockNumber(pub (Address, BlockNumber));
+
+impl AddressBlockNumber {
+    /// Create a new Range covering all blocks of the given address within `range`.
+    pub fn range(address: Address, range: Range<BlockNumber>) -> Range<Self> {
+        (address, range.start).into()..(address, range.end).into()
+    }
+
+    /// Return the address
+    pub const fn address(&self) -> Address {
+        self.0 .0
+    }
+
+    /// Return the block number
+    pub const fn block_number(&self) -> BlockNumber {
+        self.0 .1
+    }
+}
+
+impl From<(Address, BlockNumber)> for AddressBlockNumber {
+    fn from(tpl: (Address, BlockNumber)) -> Self {
+        Self(tpl)
+    }
+}
+
+impl Encode for AddressBlockNumber {
+    type Encoded = [u8; 28];
+
+    fn encode(self) -> Self::Encoded {
+        let address = self.0 .0;
+        let block_number = self.0 .1;
+
+        let mut buf = [0u8; 28];
+
+        buf[..20].copy_from_slice(address.as_slice());
+        buf[20..].copy_from_slice(&block_number.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for AddressBlockNumber {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        let address = Address::from_slice(value.get(..20).ok_or(DatabaseError::Decode)?);
+        let block_number =
+            u64::from_be_bytes(value[20..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self((address, block_number)))
+    }
+}
+
+/// Validator index concatenated with the [`BlockNumber`] of the withdrawals.
+///
+/// Since it's used as a key, it isn't compressed when encoding it.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Ord, PartialOrd, Hash,
+)]
+pub struct ValidatorBlockNumber(pub (u64, BlockNumber));
+
+impl ValidatorBlockNumber {
+    /// Create a new Range covering all blocks of the given validator within `range`.
+    pub fn range(validator_index: u64, range: Range<BlockNumber>) -> Range<Self> {
+        (validator_index, range.start).into()..(validator_index, range.end).into()
+    }
+
+    /// Return the validator index
+    pub const fn validator_index(&self) -> u64 {
+        self.0 .0
+    }
+
+    /// Return the block number
+    pub const fn block_number(&self) -> BlockNumber {
+        self.0 .1
+    }
+}
+
+impl From<(u64, BlockNumber)> for ValidatorBlockNumber {
+    fn from(tpl: (u64, BlockNumber)) -> Self {
+        Self(tpl)
+    }
+}
+
+impl Encode for ValidatorBlockNumber {
+    type Encoded = [u8; 16];
+
+    fn encode(self) -> Self::Encoded {
+        let validator_index = self.0 .0;
+        let block_number = self.0 .1;
+
+        let mut buf = [0u8; 16];
+
+        buf[..8].copy_from_slice(&validator_index.to_be_bytes());
+        buf[8..].copy_from_slice(&block_number.to_be_bytes());
+        buf
+    }
+}
+
+impl Decode for ValidatorBlockNumber {
+    fn decode(value: &[u8]) -> Result<Self, DatabaseError> {
+        let validator_index = u64::from_be_bytes(
+            value
+                .get(..8)
+                .ok_or(DatabaseError::Decode)?
+                .try_into()
+                .map_err(|_| DatabaseError::Decode)?,
+        );
+        let block_number =
+            u64::from_be_bytes(value[8..].try_into().map_err(|_| DatabaseError::Decode)?);
+        Ok(Self((validator_index, block_number)))
+    }
+}
+
+impl_fixed_arbitrary!((AddressBlockNumber, 28), (ValidatorBlockNumber, 16));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    #[test]
+    fn test_address_block_number() {
+        let address = Address::with_last_byte(1);
+        let num = 42u64;
+        let key = AddressBlockNumber((address, num));
+
+        let mut bytes = [0u8; 28];
+        bytes[..20].copy_from_slice(address.as_slice());
+        bytes[20..].copy_from_slice(&num.to_be_bytes());
+
+        let encoded = Encode::encode(key);
+        assert_eq!(encoded, bytes);
+
+        let decoded: AddressBlockNumber = Decode::decode(&encoded).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn test_validator_block_number() {
+        let key = ValidatorBlockNumber((7, 42));
+
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&7u64.to_be_bytes());
+        bytes[8..].copy_from_slice(&42u64.to_be_bytes());
+
+        let encoded = Encode::encode(key);
+        assert_eq!(encoded, bytes);
+
+        let decoded: ValidatorBlockNumber = Decode::decode(&encoded).unwrap();
+        assert_eq!(decoded, key);
+    }
+
+    #[test]
+    fn test_withdrawal_keys_rand() {
+        let mut bytes = [0u8; 28];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = AddressBlockNumber::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+
+        let mut bytes = [0u8; 16];
+        thread_rng().fill(bytes.as_mut_slice());
+        let key = ValidatorBlockNumber::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(bytes, Encode::encode(key));
+    }
+}
@@ -131,6 +131,19 @@ impl<N: ProviderNodeTypes> DbTool<N> {
             .map_err(|e| eyre::eyre!(e))
     }
 
+    /// Grabs all duplicates of the `DupSort` table for the given key
+    pub fn get_dups<T: DupSort>(&self, key: T::Key) -> Result<Vec<T::Value>> {
+        self.provider_factory
+            .db_ref()
+            .view(|tx| {
+                tx.cursor_dup_read::<T>()?
+                    .walk_dup(Some(key), None)?
+                    .map(|entry| entry.map(|(_, value)| value))
+                    .collect::<Result<Vec<_>, DatabaseError>>()
+            })?
+            .map_err(|e| eyre::eyre!(e))
+    }
+
     /// Drops the database and the static files at the given path.
     pub fn drop(
         &self,
@@ -88,4 +88,10 @@ macro_rules! impl_fuzzer_value_with_input {
 }
 
 impl_fuzzer_key!(BlockNumberAddress);
+impl_fuzzer_key!(SelectorTxNumber);
+impl_fuzzer_key!(AddressEpoch);
+impl_fuzzer_key!(AddressStorageKeyEpoch);
+impl_fuzzer_key!(AddressBlockNumber);
+impl_fuzzer_key!(ValidatorBlockNumber);
+impl_fuzzer_key!(AddressTxNumber);
 impl_fuzzer_value_with_input!((IntegerList, IntegerListInput));
@@ -26,8 +26,9 @@ use reth_db_api::{
         accounts::BlockNumberAddress,
         blocks::{HeaderHash, StoredBlockOmmers},
         storage_sharded_key::StorageShardedKey,
-        AccountBeforeTx, ClientVersion, CompactU256, IntegerList, ShardedKey,
-        StoredBlockBodyIndices, StoredBlockWithdrawals,
+        AccountBeforeTx, AddressBlockNumber, AddressEpoch, AddressStorageKeyEpoch, AddressTxNumber,
+        ClientVersion, CompactU256, IntegerList, SelectorTxNumber, ShardedKey,
+        StoredBlockBodyIndices, StoredBlockWithdrawals, ValidatorBlockNumber,
     },
     table::{Decode, DupSort, Encode, Table, TableInfo},
 };
@@ -361,6 +362,44 @@ tables! {
         type Value = TxNumber;
     }
 
+    /// Stores the mapping of the 4-byte function selector of a transaction's calldata to the
+    /// transaction number and the block number it was included in.
+    ///
+    /// This index is optional and only maintained if enabled through
+    /// [`PruneModes::transaction_selectors`](reth_prune_types::PruneModes::transaction_selectors).
+    table TransactionSelectors {
+        type Key = SelectorTxNumber;
+        type Value = BlockNumber;
+    }
+
+    /// Stores the mapping of an address to the transaction numbers of the transactions it sent or
+    /// directly received, and the block number they were included in.
+    ///
+    /// This index is optional and only maintained if enabled through
+    /// [`PruneModes::address_transactions`](reth_prune_types::PruneModes::address_transactions).
+    table AddressTransactions {
+        type Key = AddressTxNumber;
+        type Value = BlockNumber;
+    }
+
+    /// Stores the withdrawals of a block to the recipient address.
+    ///
+    /// This index is optional and only maintained if enabled through
+    /// [`PruneModes::withdrawal_history`](reth_prune_types::PruneModes::withdrawal_history).
+    table AddressWithdrawals {
+        type Key = AddressBlockNumber;
+        type Value = StoredBlockWithdrawals;
+    }
+
+    /// Stores the withdrawals of a block from the validator.
+    ///
+    /// This index is optional and only maintained if enabled through
+    /// [`PruneModes::withdrawal_history`](reth_prune_types::PruneModes::withdrawal_history).
+    table ValidatorWithdrawals {
+        type Key = ValidatorBlockNumber;
+        type Value = StoredBlockWithdrawals;
+    }
+
     /// Stores the mapping of transaction number to the blocks number.
     ///
     /// The key is the highest transaction ID in the block.
@@ -443,6 +482,50 @@ tables! {
         type Value = BlockNumberList;
     }
 
+    /// Stores the epochs in which an account was touched, with the first block of the epoch that
+    /// touched it.
+    ///
+    /// An epoch is a fixed number of consecutive blocks, and an account is touched by a block if
+    /// the block changed it. The last entry of an address is the last epoch the account was
+    /// touched in.
+    ///
+    /// This table is optional and only maintained if a touch epoch length is configured.
+    table AccountTouchEpochs {
+        type Key = AddressEpoch;
+        type Value = BlockNumber;
+    }
+
+    /// Stores the epochs in which a storage slot was touched, with the first block of the epoch
+    /// that touched it.
+    ///
+    /// See [`AccountTouchEpochs`] for the definition of epochs.
+    ///
+    /// This table is optional and only maintained if a touch epoch length is configured.
+    table StorageTouchEpochs {
+        type Key = AddressStorageKeyEpoch;
+        type Value = BlockNumber;
+    }
+
+    /// Stores pointers to the blocks that contain logs emitted by an address.
+    ///
+    /// Sharded the same way as [`AccountsHistory`].
+    ///
+    /// This index is optional and only maintained if the `IndexLogs` stage is enabled.
+    table LogAddressIndex {
+        type Key = ShardedKey<Address>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores pointers to the blocks that contain logs with a topic, at any position.
+    ///
+    /// Sharded the same way as [`AccountsHistory`].
+    ///
+    /// This index is optional and only maintained if the `IndexLogs` stage is enabled.
+    table LogTopicIndex {
+        type Key = ShardedKey<B256>;
+        type Value = BlockNumberList;
+    }
+
     /// Stores the state of an account before a certain transaction changed it.
     /// Change on state can be: account is created, selfdestructed, touched while empty
     /// or changed balance,nonce.
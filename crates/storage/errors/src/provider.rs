@@ -102,9 +102,19 @@ pub enum ProviderError {
     /// State is not available for the given block number because it is pruned.
     #[display("state at block #{_0} is pruned")]
     StateAtBlockPruned(BlockNumber),
+    /// State is not available for the given block number because too many changesets are not
+    /// indexed yet.
+    #[display("state at block #{_0} is not indexed")]
+    StateAtBlockNotIndexed(BlockNumber),
     /// Provider does not support this particular request.
     #[display("this provider does not support this request")]
     UnsupportedProvider,
+    /// The optional index required for the request is not maintained by the node.
+    #[display("{_0} index is disabled")]
+    IndexDisabled(&'static str),
+    /// The optional index required for the request is pruned up to and including the given block.
+    #[display("{_0} index is pruned up to block #{_1}")]
+    IndexPruned(&'static str, BlockNumber),
     /// Static File is not found at specified path.
     #[cfg(feature = "std")]
     #[display("not able to find {_0} static file at {_1:?}")]
@@ -0,0 +1,183 @@
+use crate::NippyJarError;
+use memmap2::Mmap;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Size of the header, which holds the number of rows of the indexed jar.
+const HEADER_SIZE: usize = 8;
+/// Number of leading bytes of a hash stored in an entry.
+const PREFIX_SIZE: usize = 8;
+/// Size of an entry: hash prefix followed by the row index.
+const ENTRY_SIZE: usize = PREFIX_SIZE + 4;
+
+/// Index of the rows of a [`NippyJar`](crate::NippyJar) by the hash of their content, such as
+/// transaction hashes.
+///
+/// Only a prefix of every hash is stored, sorted, alongside the index of its row. Lookups return
+/// all rows with a matching prefix, and it's up to the caller to compare the full hash against the
+/// row content.
+///
+/// The index stores the number of rows of the jar it was built for, so an index of a jar that was
+/// modified afterwards can be detected and discarded, see [`Self::rows`].
+#[derive(Debug)]
+pub struct HashIndex {
+    /// File descriptor. Needs to be kept alive as long as `mmap` handle.
+    #[allow(dead_code)]
+    file: File,
+    /// Mmap handle for the index.
+    mmap: Mmap,
+}
+
+impl HashIndex {
+    /// Opens the index at the given path.
+    pub fn open(path: &Path) -> Result<Self, NippyJarError> {
+        let file = File::open(path)?;
+        // SAFETY: File is read-only and its descriptor is kept alive as long as the mmap handle.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        if mmap.len() < HEADER_SIZE || (mmap.len() - HEADER_SIZE) % ENTRY_SIZE != 0 {
+            return Err(NippyJarError::InconsistentState)
+        }
+
+        let index = Self { file, mmap };
+        if index.entries() != index.rows() {
+            return Err(NippyJarError::InconsistentState)
+        }
+        Ok(index)
+    }
+
+    /// Returns the number of rows of the jar the index was built for.
+    pub fn rows(&self) -> usize {
+        u64::from_le_bytes(self.mmap[..HEADER_SIZE].try_into().expect("header size")) as usize
+    }
+
+    /// Returns the rows whose hash shares the prefix of the given hash, in ascending order.
+    pub fn find(&self, hash: &[u8]) -> Vec<usize> {
+        let prefix = prefix(hash);
+
+        // First entry with a prefix that is not lower than the requested one
+        let (mut low, mut high) = (0, self.entries());
+        while low < high {
+            let mid = (low + high) / 2;
+            if self.entry(mid).0 < prefix {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        // Entries with the same prefix are sorted by row
+        (low..self.entries())
+            .map(|index| self.entry(index))
+            .take_while(|(entry_prefix, _)| *entry_prefix == prefix)
+            .map(|(_, row)| row as usize)
+            .collect()
+    }
+
+    /// Returns the number of entries in the index.
+    fn entries(&self) -> usize {
+        (self.mmap.len() - HEADER_SIZE) / ENTRY_SIZE
+    }
+
+    /// Returns the hash prefix and the row of the entry at the given index.
+    fn entry(&self, index: usize) -> (u64, u32) {
+        let start = HEADER_SIZE + index * ENTRY_SIZE;
+        let entry = &self.mmap[start..start + ENTRY_SIZE];
+        (
+            u64::from_be_bytes(entry[..PREFIX_SIZE].try_into().expect("prefix size")),
+            u32::from_le_bytes(entry[PREFIX_SIZE..].try_into().expect("row size")),
+        )
+    }
+}
+
+/// Builder of a [`HashIndex`], fed with the hashes of all rows of a jar, in order.
+#[derive(Debug, Default)]
+pub struct HashIndexBuilder {
+    /// Hash prefixes and their rows.
+    entries: Vec<(u64, u32)>,
+}
+
+impl HashIndexBuilder {
+    /// Adds the hash of the next row.
+    pub fn push(&mut self, hash: &[u8]) -> Result<(), NippyJarError> {
+        let row = u32::try_from(self.entries.len()).map_err(|_| {
+            NippyJarError::Custom(format!("too many rows to index: {}", self.entries.len() + 1))
+        })?;
+        self.entries.push((prefix(hash), row));
+        Ok(())
+    }
+
+    /// Returns the number of hashes added.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no hashes were added.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Sorts the entries and writes the index to the given path.
+    pub fn write(mut self, path: &Path) -> Result<(), NippyJarError> {
+        self.entries.sort_unstable();
+
+        Ok(reth_fs_util::atomic_write_file(path, |file| {
+            let mut writer = BufWriter::new(file);
+            writer.write_all(&(self.entries.len() as u64).to_le_bytes())?;
+            for (prefix, row) in self.entries {
+                writer.write_all(&prefix.to_be_bytes())?;
+                writer.write_all(&row.to_le_bytes())?;
+            }
+            writer.flush()
+        })?)
+    }
+}
+
+/// Returns the big-endian prefix of the hash, so that the prefixes sort as the hashes do.
+fn prefix(hash: &[u8]) -> u64 {
+    let mut prefix = [0; PREFIX_SIZE];
+    let len = hash.len().min(PREFIX_SIZE);
+    prefix[..len].copy_from_slice(&hash[..len]);
+    u64::from_be_bytes(prefix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::SmallRng, RngCore, SeedableRng};
+
+    #[test]
+    fn find_rows() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut hashes = (0..100)
+            .map(|_| {
+                let mut hash = [0; 32];
+                rng.fill_bytes(&mut hash);
+                hash
+            })
+            .collect::<Vec<_>>();
+        // hashes sharing the prefix of the first one
+        let first = hashes[0];
+        for row in [10, 20] {
+            hashes[row][..PREFIX_SIZE].copy_from_slice(&first[..PREFIX_SIZE]);
+        }
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let mut builder = HashIndexBuilder::default();
+        for hash in &hashes {
+            builder.push(hash).unwrap();
+        }
+        builder.write(file.path()).unwrap();
+
+        let index = HashIndex::open(file.path()).unwrap();
+        assert_eq!(index.rows(), hashes.len());
+        for (row, hash) in hashes.iter().enumerate().skip(1).filter(|(row, _)| row % 10 != 0) {
+            assert_eq!(index.find(hash), vec![row]);
+        }
+        assert_eq!(index.find(&hashes[0]), vec![0, 10, 20]);
+        assert!(index.find(&[0xff; 32]).is_empty());
+    }
+}
@@ -50,6 +50,9 @@ pub use writer::NippyJarWriter;
 mod consistency;
 pub use consistency::NippyJarChecker;
 
+mod hash_index;
+pub use hash_index::{HashIndex, HashIndexBuilder};
+
 /// The version number of the Nippy Jar format.
 const NIPPY_JAR_VERSION: usize = 1;
 /// The file extension used for index files.
@@ -58,6 +61,8 @@ const INDEX_FILE_EXTENSION: &str = "idx";
 const OFFSETS_FILE_EXTENSION: &str = "off";
 /// The file extension used for configuration files.
 pub const CONFIG_FILE_EXTENSION: &str = "conf";
+/// The file extension used for hash index files.
+const HASH_INDEX_FILE_EXTENSION: &str = "hidx";
 
 /// A [`RefRow`] is a list of column value slices pointing to either an internal buffer or a
 /// memory-mapped file.
@@ -226,13 +231,22 @@ impl<H: NippyJarHeader> NippyJar<H> {
         self.path.with_extension(CONFIG_FILE_EXTENSION)
     }
 
+    /// Returns the path for the hash index file
+    pub fn hash_index_path(&self) -> PathBuf {
+        self.path.with_extension(HASH_INDEX_FILE_EXTENSION)
+    }
+
     /// Deletes from disk this [`NippyJar`] alongside every satellite file.
     pub fn delete(self) -> Result<(), NippyJarError> {
         // TODO(joshie): ensure consistency on unexpected shutdown
 
-        for path in
-            [self.data_path().into(), self.index_path(), self.offsets_path(), self.config_path()]
-        {
+        for path in [
+            self.data_path().into(),
+            self.index_path(),
+            self.offsets_path(),
+            self.config_path(),
+            self.hash_index_path(),
+        ] {
             if path.exists() {
                 reth_fs_util::remove_file(path)?;
             }
@@ -246,6 +260,37 @@ impl<H: NippyJarHeader> NippyJar<H> {
         DataReader::new(self.data_path())
     }
 
+    /// Returns the [`HashIndex`] of the jar, if one was written for its current rows.
+    pub fn open_hash_index(&self) -> Result<Option<HashIndex>, NippyJarError> {
+        let path = self.hash_index_path();
+        if !path.exists() {
+            return Ok(None)
+        }
+        let index = HashIndex::open(&path)?;
+        Ok((index.rows() == self.rows).then_some(index))
+    }
+
+    /// Writes the [`HashIndex`] of the jar, built from the hashes of all its rows.
+    pub fn write_hash_index(&self, builder: HashIndexBuilder) -> Result<(), NippyJarError> {
+        if builder.len() != self.rows {
+            return Err(NippyJarError::Custom(format!(
+                "hash index has {} entries, but the jar has {} rows",
+                builder.len(),
+                self.rows
+            )))
+        }
+        builder.write(&self.hash_index_path())
+    }
+
+    /// Deletes the [`HashIndex`] of the jar, if any.
+    pub fn delete_hash_index(&self) -> Result<(), NippyJarError> {
+        let path = self.hash_index_path();
+        if path.exists() {
+            reth_fs_util::remove_file(path)?;
+        }
+        Ok(())
+    }
+
     /// Writes all necessary configuration to file.
     fn freeze_config(&self) -> Result<(), NippyJarError> {
         Ok(reth_fs_util::atomic_write_file(&self.config_path(), |file| {
@@ -247,8 +247,11 @@ impl<H: NippyJarHeader> NippyJarWriter<H> {
     }
 
     /// Prunes rows from data and offsets file and updates its configuration on disk
+    ///
+    /// The hash index of the jar, if any, no longer matches its rows and is deleted.
     pub fn prune_rows(&mut self, num_rows: usize) -> Result<(), NippyJarError> {
         self.dirty = true;
+        self.jar.delete_hash_index()?;
 
         self.offsets_file.flush()?;
         self.data_file.flush()?;
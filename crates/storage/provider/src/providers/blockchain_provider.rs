@@ -1,20 +1,24 @@
 #![allow(unused)]
 use crate::{
     providers::{ConsistentProvider, StaticFileProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BlockSource, CanonChainTracker, CanonStateNotifications, CanonStateSubscriptions,
-    ChainSpecProvider, ChainStateBlockReader, ChangeSetReader, DatabaseProvider,
-    DatabaseProviderFactory, EvmEnvProvider, FullProvider, HashedPostStateProvider, HeaderProvider,
-    ProviderError, ProviderFactory, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
-    StageCheckpointReader, StateProviderBox, StateProviderFactory, StateReader,
-    StaticFileProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AccountReader, AddressTransactionsReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, BlockSource, CanonChainTracker, CanonStateNotifications,
+    CanonStateSubscriptions, ChainSpecProvider, ChainStateBlockReader, ChangeSetReader,
+    ColdStateStats, DatabaseProvider, DatabaseProviderFactory, EvmEnvProvider, FullProvider,
+    HashedPostStateProvider, HeaderProvider, LogIndexReader, ProviderError, ProviderFactory,
+    PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader,
+    StateProviderBox, StateProviderFactory, StateReader, StaticFileProviderFactory,
+    TouchEpochReader, TransactionSelectorReader, TransactionVariant, TransactionsProvider,
+    WithdrawalHistoryReader, WithdrawalsProvider,
 };
 use alloy_consensus::Header;
 use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
     BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag,
 };
-use alloy_primitives::{Address, BlockHash, BlockNumber, Sealable, TxHash, TxNumber, B256, U256};
+use alloy_primitives::{
+    Address, BlockHash, BlockNumber, Sealable, Selector, TxHash, TxNumber, B256, U256,
+};
 use alloy_rpc_types_engine::ForkchoiceState;
 use reth_chain_state::{
     BlockState, CanonicalInMemoryState, ForkChoiceNotifications, ForkChoiceSubscriptions,
@@ -771,6 +775,93 @@ impl<N: ProviderNodeTypes> StorageChangeSetReader for BlockchainProvider2<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> TransactionSelectorReader for BlockchainProvider2<N> {
+    fn transactions_by_selector(
+        &self,
+        selector: Selector,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        self.consistent_provider()?.transactions_by_selector(selector, block_range, limit)
+    }
+}
+
+impl<N: ProviderNodeTypes> AddressTransactionsReader for BlockchainProvider2<N> {
+    fn transactions_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+        reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        self.consistent_provider()?.transactions_by_address(address, block_range, limit, reverse)
+    }
+}
+
+impl<N: ProviderNodeTypes> WithdrawalHistoryReader for BlockchainProvider2<N> {
+    fn withdrawals_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.consistent_provider()?.withdrawals_by_address(address, block_range, limit)
+    }
+
+    fn withdrawals_by_validator(
+        &self,
+        validator_index: u64,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.consistent_provider()?.withdrawals_by_validator(validator_index, block_range, limit)
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexReader for BlockchainProvider2<N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.consistent_provider()?.log_index_range()
+    }
+
+    fn blocks_with_address_logs(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.consistent_provider()?.blocks_with_address_logs(address, block_range)
+    }
+
+    fn blocks_with_topic_logs(
+        &self,
+        topic: B256,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.consistent_provider()?.blocks_with_topic_logs(topic, block_range)
+    }
+}
+
+impl<N: ProviderNodeTypes> TouchEpochReader for BlockchainProvider2<N> {
+    fn touch_epoch_length(&self) -> Option<u64> {
+        self.database.touch_epoch_length()
+    }
+
+    fn account_last_touch_epoch(&self, address: Address) -> ProviderResult<Option<u64>> {
+        self.database.account_last_touch_epoch(address)
+    }
+
+    fn storage_last_touch_epoch(
+        &self,
+        address: Address,
+        storage_key: B256,
+    ) -> ProviderResult<Option<u64>> {
+        self.database.storage_last_touch_epoch(address, storage_key)
+    }
+
+    fn cold_state_stats(&self, epoch: u64) -> ProviderResult<ColdStateStats> {
+        self.database.cold_state_stats(epoch)
+    }
+}
+
 impl<N: ProviderNodeTypes> ChangeSetReader for BlockchainProvider2<N> {
     fn account_block_changeset(
         &self,
@@ -840,10 +931,10 @@ mod tests {
     use reth_primitives::{BlockExt, Receipt, SealedBlock, StaticFileSegment};
     use reth_primitives_traits::{BlockBody as _, SignedTransaction};
     use reth_storage_api::{
-        BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, BlockSource,
-        ChangeSetReader, DatabaseProviderFactory, HeaderProvider, ReceiptProvider,
-        ReceiptProviderIdExt, StateProviderFactory, TransactionVariant, TransactionsProvider,
-        WithdrawalsProvider,
+        BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
+        BlockReaderRevExt, BlockSource, ChangeSetReader, DatabaseProviderFactory, HeaderProvider,
+        ReceiptProvider, ReceiptProviderIdExt, StateProviderFactory, TransactionVariant,
+        TransactionsProvider, WithdrawalsProvider,
     };
     use reth_testing_utils::generators::{
         self, random_block, random_block_range, random_changeset_range, random_eoa_accounts,
@@ -1822,6 +1913,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_block_reader_rev_ext() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, receipts) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams { tx_count: 0..3, ..Default::default() },
+        )?;
+
+        let blocks = database_blocks.iter().chain(in_memory_blocks.iter()).collect::<Vec<_>>();
+        let range = 0..=blocks.last().unwrap().number;
+
+        // chunks span the database and the in-memory blocks
+        let rev_blocks = provider
+            .blocks_rev(range.clone())
+            .with_chunk_size(3)
+            .map(|block| block.map(|block| block.block))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(rev_blocks, blocks.iter().rev().copied().cloned().collect::<Vec<_>>());
+
+        let rev_receipts = provider
+            .receipts_by_block_rev(range)
+            .with_chunk_size(4)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            rev_receipts,
+            blocks
+                .iter()
+                .rev()
+                .map(|block| (block.number, receipts[block.number as usize].clone()))
+                .collect::<Vec<_>>()
+        );
+
+        // only the most recent blocks are fetched
+        let latest = provider.blocks_rev(0..=3).take(2).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(latest.iter().map(|block| block.number).collect::<Vec<_>>(), vec![3, 2]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_changeset_reader() -> eyre::Result<()> {
         let mut rng = generators::rng();
@@ -1,18 +1,19 @@
 use super::{DatabaseProviderRO, ProviderFactory, ProviderNodeTypes};
 use crate::{
-    providers::StaticFileProvider, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader,
-    BlockReader, BlockReaderIdExt, BlockSource, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
-    HeaderProvider, ProviderError, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
-    StageCheckpointReader, StateReader, StaticFileProviderFactory, TransactionVariant,
-    TransactionsProvider, WithdrawalsProvider,
+    providers::StaticFileProvider, AccountReader, AddressTransactionsReader, BlockHashReader,
+    BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt, BlockSource, ChainSpecProvider,
+    ChangeSetReader, EvmEnvProvider, HeaderProvider, LogIndexReader, ProviderError,
+    PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader,
+    StateReader, StaticFileProviderFactory, TransactionSelectorReader, TransactionVariant,
+    TransactionsProvider, WithdrawalHistoryReader, WithdrawalsProvider,
 };
-use alloy_consensus::BlockHeader;
+use alloy_consensus::{BlockHeader, Transaction as _};
 use alloy_eips::{
     eip2718::Encodable2718,
     eip4895::{Withdrawal, Withdrawals},
     BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag, HashOrNumber,
 };
-use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256};
+use alloy_primitives::{Address, BlockHash, BlockNumber, Selector, TxHash, TxNumber, B256, U256};
 use reth_chain_state::{BlockState, CanonicalInMemoryState, MemoryOverlayStateProviderRef};
 use reth_chainspec::{ChainInfo, EthereumHardforks};
 use reth_db::models::BlockNumberAddress;
@@ -24,7 +25,7 @@ use reth_primitives::{
     Account, BlockWithSenders, SealedBlockFor, SealedBlockWithSenders, SealedHeader, StorageEntry,
     TransactionMeta,
 };
-use reth_primitives_traits::BlockBody;
+use reth_primitives_traits::{BlockBody, SignedTransaction};
 use reth_prune_types::{PruneCheckpoint, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
@@ -1441,6 +1442,255 @@ impl<N: ProviderNodeTypes> StorageChangeSetReader for ConsistentProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> TransactionSelectorReader for ConsistentProvider<N> {
+    fn transactions_by_selector(
+        &self,
+        selector: Selector,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        let in_mem_chain = self.head_block.iter().flat_map(|b| b.chain()).collect::<Vec<_>>();
+        let provider = &self.storage_provider;
+
+        // The in-memory blocks are only scanned if the database index could answer the query
+        provider.ensure_index_available(
+            PruneSegment::TransactionSelectors,
+            "transaction selector",
+            provider.prune_modes_ref().has_transaction_selectors_index(),
+            &block_range,
+        )?;
+
+        // Get the last block number stored in the storage which does NOT overlap with in-memory
+        // chain.
+        let last_database_block_number = in_mem_chain
+            .last()
+            .map(|b| Ok(b.anchor().number))
+            .unwrap_or_else(|| provider.last_block_number())?;
+
+        let mut items = if *block_range.start() <= last_database_block_number {
+            provider.transactions_by_selector(
+                selector,
+                *block_range.start()..=last_database_block_number.min(*block_range.end()),
+                limit,
+            )?
+        } else {
+            Vec::new()
+        };
+
+        // In-memory blocks are not indexed yet, so their transactions are scanned directly.
+        let mut in_memory_tx_num = provider
+            .block_body_indices(last_database_block_number)?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(last_database_block_number))?
+            .next_tx_num();
+        for block_state in in_mem_chain.iter().rev() {
+            let block_number = block_state.number();
+            for transaction in block_state.block_ref().block().body.transactions() {
+                if items.len() >= limit {
+                    return Ok(items)
+                }
+                if block_range.contains(&block_number) &&
+                    transaction.function_selector() == Some(selector)
+                {
+                    items.push((in_memory_tx_num, block_number));
+                }
+                in_memory_tx_num += 1;
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+impl<N: ProviderNodeTypes> AddressTransactionsReader for ConsistentProvider<N> {
+    fn transactions_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+        reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        let in_mem_chain = self.head_block.iter().flat_map(|b| b.chain()).collect::<Vec<_>>();
+        let provider = &self.storage_provider;
+
+        // The in-memory blocks are only scanned if the database index could answer the query
+        provider.ensure_index_available(
+            PruneSegment::AddressTransactions,
+            "transaction-by-address",
+            provider.prune_modes_ref().has_address_transactions_index(),
+            &block_range,
+        )?;
+
+        // Get the last block number stored in the storage which does NOT overlap with in-memory
+        // chain.
+        let last_database_block_number = in_mem_chain
+            .last()
+            .map(|b| Ok(b.anchor().number))
+            .unwrap_or_else(|| provider.last_block_number())?;
+        let database_range = (*block_range.start() <= last_database_block_number)
+            .then(|| *block_range.start()..=last_database_block_number.min(*block_range.end()));
+
+        // In-memory blocks are not indexed yet, so their transactions are scanned directly.
+        let mut in_memory_items = Vec::new();
+        let mut in_memory_tx_num = provider
+            .block_body_indices(last_database_block_number)?
+            .ok_or(ProviderError::BlockBodyIndicesNotFound(last_database_block_number))?
+            .next_tx_num();
+        for block_state in in_mem_chain.iter().rev() {
+            let block_number = block_state.number();
+            let executed_block = block_state.block_ref();
+            for (transaction, sender) in
+                executed_block.block().body.transactions().iter().zip(executed_block.senders())
+            {
+                if block_range.contains(&block_number) &&
+                    (*sender == address || transaction.to() == Some(address))
+                {
+                    in_memory_items.push((in_memory_tx_num, block_number));
+                }
+                in_memory_tx_num += 1;
+            }
+        }
+
+        let mut items = Vec::new();
+        if reverse {
+            items.extend(in_memory_items.into_iter().rev().take(limit));
+            if let Some(range) = database_range.filter(|_| items.len() < limit) {
+                items.extend(provider.transactions_by_address(
+                    address,
+                    range,
+                    limit - items.len(),
+                    true,
+                )?);
+            }
+        } else {
+            if let Some(range) = database_range {
+                items = provider.transactions_by_address(address, range, limit, false)?;
+            }
+            items.extend(in_memory_items.into_iter().take(limit - items.len()));
+        }
+
+        Ok(items)
+    }
+}
+
+impl<N: ProviderNodeTypes> ConsistentProvider<N> {
+    /// Returns the withdrawals within the block range that match the predicate, reading the
+    /// blocks persisted to the database from the withdrawal history index with `fetch_from_db`.
+    ///
+    /// In-memory blocks are not indexed yet, so their withdrawals are scanned directly.
+    fn withdrawal_history<F, P>(
+        &self,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+        fetch_from_db: F,
+        mut predicate: P,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>>
+    where
+        F: FnOnce(
+            &DatabaseProviderRO<N::DB, N>,
+            RangeInclusive<BlockNumber>,
+        ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>>,
+        P: FnMut(&Withdrawal) -> bool,
+    {
+        let in_mem_chain = self.head_block.iter().flat_map(|b| b.chain()).collect::<Vec<_>>();
+        let provider = &self.storage_provider;
+
+        provider.ensure_index_available(
+            PruneSegment::WithdrawalHistory,
+            "withdrawal history",
+            provider.prune_modes_ref().has_withdrawal_history_index(),
+            &block_range,
+        )?;
+
+        // Get the last block number stored in the storage which does NOT overlap with in-memory
+        // chain.
+        let last_database_block_number = in_mem_chain
+            .last()
+            .map(|b| Ok(b.anchor().number))
+            .unwrap_or_else(|| provider.last_block_number())?;
+
+        let mut items = if *block_range.start() <= last_database_block_number {
+            fetch_from_db(
+                provider,
+                *block_range.start()..=last_database_block_number.min(*block_range.end()),
+            )?
+        } else {
+            Vec::new()
+        };
+
+        for block_state in in_mem_chain.iter().rev() {
+            let block_number = block_state.number();
+            if !block_range.contains(&block_number) {
+                continue
+            }
+            let Some(withdrawals) = block_state.block_ref().block().body.withdrawals() else {
+                continue
+            };
+            for withdrawal in withdrawals.iter() {
+                if items.len() >= limit {
+                    return Ok(items)
+                }
+                if predicate(withdrawal) {
+                    items.push((block_number, *withdrawal));
+                }
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+impl<N: ProviderNodeTypes> WithdrawalHistoryReader for ConsistentProvider<N> {
+    fn withdrawals_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.withdrawal_history(
+            block_range,
+            limit,
+            |provider, range| provider.withdrawals_by_address(address, range, limit),
+            |withdrawal| withdrawal.address == address,
+        )
+    }
+
+    fn withdrawals_by_validator(
+        &self,
+        validator_index: u64,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.withdrawal_history(
+            block_range,
+            limit,
+            |provider, range| provider.withdrawals_by_validator(validator_index, range, limit),
+            |withdrawal| withdrawal.validator_index == validator_index,
+        )
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexReader for ConsistentProvider<N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.storage_provider.log_index_range()
+    }
+
+    fn blocks_with_address_logs(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.storage_provider.blocks_with_address_logs(address, block_range)
+    }
+
+    fn blocks_with_topic_logs(
+        &self,
+        topic: B256,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.storage_provider.blocks_with_topic_logs(topic, block_range)
+    }
+}
+
 impl<N: ProviderNodeTypes> ChangeSetReader for ConsistentProvider<N> {
     fn account_block_changeset(
         &self,
@@ -1,11 +1,14 @@
 use crate::{
-    providers::{state::latest::LatestStateProvider, StaticFileProvider},
+    providers::{
+        state::latest::LatestStateProvider, StaticFileProvider, DEFAULT_MAX_UNINDEXED_BLOCKS,
+    },
     to_range,
     traits::{BlockSource, ReceiptProvider},
-    BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, DatabaseProviderFactory,
-    EvmEnvProvider, HashedPostStateProvider, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider,
-    ProviderError, PruneCheckpointReader, StageCheckpointReader, StateProviderBox,
-    StaticFileProviderFactory, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    BlockHashReader, BlockNumReader, BlockReader, ChainSpecProvider, ColdStateStats,
+    DatabaseProviderFactory, EvmEnvProvider, HashedPostStateProvider, HeaderProvider,
+    HeaderSyncGap, HeaderSyncGapProvider, ProviderError, PruneCheckpointReader,
+    StageCheckpointReader, StateProviderBox, StaticFileProviderFactory, TouchEpochReader,
+    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
@@ -65,6 +68,10 @@ pub struct ProviderFactory<N: NodeTypesWithDB> {
     static_file_provider: StaticFileProvider<N::Primitives>,
     /// Optional pruning configuration
     prune_modes: PruneModes,
+    /// Optional number of blocks per epoch of the touch epoch tables
+    touch_epoch_length: Option<u64>,
+    /// Maximum number of blocks with changesets that are not indexed
+    max_unindexed_history_blocks: u64,
     /// The node storage handler.
     storage: Arc<N::Storage>,
 }
@@ -74,12 +81,22 @@ where
     N: NodeTypesWithDB<DB: fmt::Debug, ChainSpec: fmt::Debug, Storage: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self { db, chain_spec, static_file_provider, prune_modes, storage } = self;
+        let Self {
+            db,
+            chain_spec,
+            static_file_provider,
+            prune_modes,
+            touch_epoch_length,
+            max_unindexed_history_blocks,
+            storage,
+        } = self;
         f.debug_struct("ProviderFactory")
             .field("db", &db)
             .field("chain_spec", &chain_spec)
             .field("static_file_provider", &static_file_provider)
             .field("prune_modes", &prune_modes)
+            .field("touch_epoch_length", &touch_epoch_length)
+            .field("max_unindexed_history_blocks", &max_unindexed_history_blocks)
             .field("storage", &storage)
             .finish()
     }
@@ -97,6 +114,8 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
             chain_spec,
             static_file_provider,
             prune_modes: PruneModes::none(),
+            touch_epoch_length: None,
+            max_unindexed_history_blocks: DEFAULT_MAX_UNINDEXED_BLOCKS,
             storage: Default::default(),
         }
     }
@@ -113,6 +132,25 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
         self
     }
 
+    /// Sets the number of blocks per epoch of the touch epoch tables for an existing
+    /// [`ProviderFactory`].
+    ///
+    /// The tables are only maintained if this is set, a length of zero disables them.
+    pub fn with_touch_epoch_length(mut self, touch_epoch_length: Option<u64>) -> Self {
+        self.touch_epoch_length = touch_epoch_length.filter(|length| *length > 0);
+        self
+    }
+
+    /// Sets the maximum number of blocks whose changesets are not indexed in the history tables,
+    /// for which historical state is still served, for an existing [`ProviderFactory`].
+    pub const fn with_max_unindexed_history_blocks(
+        mut self,
+        max_unindexed_history_blocks: u64,
+    ) -> Self {
+        self.max_unindexed_history_blocks = max_unindexed_history_blocks;
+        self
+    }
+
     /// Returns reference to the underlying database.
     pub const fn db_ref(&self) -> &N::DB {
         &self.db
@@ -139,6 +177,8 @@ impl<N: NodeTypesWithDB<DB = Arc<DatabaseEnv>>> ProviderFactory<N> {
             chain_spec,
             static_file_provider,
             prune_modes: PruneModes::none(),
+            touch_epoch_length: None,
+            max_unindexed_history_blocks: DEFAULT_MAX_UNINDEXED_BLOCKS,
             storage: Default::default(),
         })
     }
@@ -159,7 +199,9 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
             self.static_file_provider.clone(),
             self.prune_modes.clone(),
             self.storage.clone(),
-        ))
+        )
+        .with_touch_epoch_length(self.touch_epoch_length)
+        .with_max_unindexed_history_blocks(self.max_unindexed_history_blocks))
     }
 
     /// Returns a provider with a created `DbTxMut` inside, which allows fetching and updating
@@ -168,13 +210,17 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
     /// open.
     #[track_caller]
     pub fn provider_rw(&self) -> ProviderResult<DatabaseProviderRW<N::DB, N>> {
-        Ok(DatabaseProviderRW(DatabaseProvider::new_rw(
-            self.db.tx_mut()?,
-            self.chain_spec.clone(),
-            self.static_file_provider.clone(),
-            self.prune_modes.clone(),
-            self.storage.clone(),
-        )))
+        Ok(DatabaseProviderRW(
+            DatabaseProvider::new_rw(
+                self.db.tx_mut()?,
+                self.chain_spec.clone(),
+                self.static_file_provider.clone(),
+                self.prune_modes.clone(),
+                self.storage.clone(),
+            )
+            .with_touch_epoch_length(self.touch_epoch_length)
+            .with_max_unindexed_history_blocks(self.max_unindexed_history_blocks),
+        ))
     }
 
     /// State provider for latest block
@@ -589,6 +635,28 @@ impl<N: ProviderNodeTypes> StageCheckpointReader for ProviderFactory<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> TouchEpochReader for ProviderFactory<N> {
+    fn touch_epoch_length(&self) -> Option<u64> {
+        self.touch_epoch_length
+    }
+
+    fn account_last_touch_epoch(&self, address: Address) -> ProviderResult<Option<u64>> {
+        self.provider()?.account_last_touch_epoch(address)
+    }
+
+    fn storage_last_touch_epoch(
+        &self,
+        address: Address,
+        storage_key: B256,
+    ) -> ProviderResult<Option<u64>> {
+        self.provider()?.storage_last_touch_epoch(address, storage_key)
+    }
+
+    fn cold_state_stats(&self, epoch: u64) -> ProviderResult<ColdStateStats> {
+        self.provider()?.cold_state_stats(epoch)
+    }
+}
+
 impl<N: ProviderNodeTypes> EvmEnvProvider<HeaderTy<N>> for ProviderFactory<N> {
     fn fill_env_with_header<EvmConfig>(
         &self,
@@ -664,6 +732,8 @@ impl<N: NodeTypesWithDB> Clone for ProviderFactory<N> {
             chain_spec: self.chain_spec.clone(),
             static_file_provider: self.static_file_provider.clone(),
             prune_modes: self.prune_modes.clone(),
+            touch_epoch_length: self.touch_epoch_length,
+            max_unindexed_history_blocks: self.max_unindexed_history_blocks,
             storage: self.storage.clone(),
         }
     }
@@ -675,10 +745,12 @@ mod tests {
     use crate::{
         providers::{StaticFileProvider, StaticFileWriter},
         test_utils::{blocks::TEST_BLOCK, create_test_provider_factory, MockNodeTypesWithDB},
-        BlockHashReader, BlockNumReader, BlockWriter, DBProvider, HeaderSyncGapProvider,
-        StorageLocation, TransactionsProvider,
+        AccountReader, AddressTransactionsReader, BlockHashReader, BlockNumReader, BlockWriter,
+        ColdStateStats, DBProvider, HeaderSyncGapProvider, OriginalValuesKnown, StateProvider,
+        StateWriter, StorageLocation, TouchEpochReader, TransactionSelectorReader,
+        TransactionsProvider,
     };
-    use alloy_primitives::{TxNumber, B256, U256};
+    use alloy_primitives::{map::HashMap, Address, Selector, TxNumber, B256, U256};
     use assert_matches::assert_matches;
     use rand::Rng;
     use reth_chainspec::ChainSpecBuilder;
@@ -686,10 +758,16 @@ mod tests {
         mdbx::DatabaseArguments,
         tables,
         test_utils::{create_test_static_files_dir, ERROR_TEMPDIR},
+        BlockNumberList,
+    };
+    use reth_db_api::{
+        models::{storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey},
+        transaction::{DbTx, DbTxMut},
     };
-    use reth_primitives::StaticFileSegment;
+    use reth_execution_types::ExecutionOutcome;
+    use reth_primitives::{Account, Receipts, StaticFileSegment, StorageEntry};
     use reth_primitives_traits::SignedTransaction;
-    use reth_prune_types::{PruneMode, PruneModes};
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneModes, PruneSegment};
     use reth_storage_errors::provider::ProviderError;
     use reth_testing_utils::generators::{self, random_block, random_header, BlockParams};
     use std::{ops::RangeInclusive, sync::Arc};
@@ -852,4 +930,250 @@ mod tests {
         assert_eq!(gap.local_head, head);
         assert_eq!(gap.target.tip(), consensus_tip.into());
     }
+
+    #[test]
+    fn touch_epochs() {
+        let factory = create_test_provider_factory().with_touch_epoch_length(Some(2));
+        let provider = factory.provider_rw().unwrap();
+
+        let (alice, bob) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let slot = B256::with_last_byte(1);
+        let account = |nonce| Account { nonce, ..Default::default() };
+        let storage = |value| StorageEntry { key: slot, value: U256::from(value) };
+
+        // alice is changed in blocks 1, 2 and 5, her slot in blocks 2 and 3 and bob in block 4,
+        // which results in the epochs 0, 1 and 2 for alice, 1 for the slot and 2 for bob
+        let outcome = ExecutionOutcome::new_init(
+            HashMap::from_iter([
+                (
+                    alice,
+                    (
+                        None,
+                        Some(account(3)),
+                        HashMap::from_iter([(slot, (U256::ZERO, U256::from(2)))]),
+                    ),
+                ),
+                (bob, (None, Some(account(1)), HashMap::default())),
+            ]),
+            HashMap::from_iter([
+                (1, HashMap::from_iter([(alice, (Some(None), Vec::new()))])),
+                (2, HashMap::from_iter([(alice, (Some(Some(account(1))), vec![storage(0)]))])),
+                (3, HashMap::from_iter([(alice, (None, vec![storage(1)]))])),
+                (4, HashMap::from_iter([(bob, (Some(None), Vec::new()))])),
+                (5, HashMap::from_iter([(alice, (Some(Some(account(2))), Vec::new()))])),
+            ]),
+            Vec::new(),
+            Receipts::default(),
+            1,
+            Vec::new(),
+        );
+        for block_number in 1..=5 {
+            provider.tx_ref().put::<tables::CanonicalHeaders>(block_number, B256::ZERO).unwrap();
+            provider
+                .tx_ref()
+                .put::<tables::BlockBodyIndices>(block_number, Default::default())
+                .unwrap();
+        }
+        provider.write_state(outcome, OriginalValuesKnown::Yes, StorageLocation::Database).unwrap();
+
+        assert_eq!(provider.touch_epoch_length(), Some(2));
+        assert_eq!(
+            provider.tx_ref().get::<tables::AccountTouchEpochs>((alice, 1).into()).unwrap(),
+            Some(2)
+        );
+        assert_eq!(provider.account_last_touch_epoch(alice).unwrap(), Some(2));
+        assert_eq!(provider.account_last_touch_epoch(bob).unwrap(), Some(2));
+        assert_eq!(provider.account_last_touch_epoch(Address::ZERO).unwrap(), None);
+        assert_eq!(provider.storage_last_touch_epoch(alice, slot).unwrap(), Some(1));
+        assert_eq!(provider.storage_last_touch_epoch(bob, slot).unwrap(), None);
+        assert_eq!(
+            provider.cold_state_stats(2).unwrap(),
+            ColdStateStats {
+                accounts: 2,
+                cold_accounts: 0,
+                storage_slots: 1,
+                cold_storage_slots: 1
+            }
+        );
+
+        // unwinding block 5 removes the last epoch of alice, but not of bob
+        provider.remove_state_above(4, StorageLocation::Database).unwrap();
+        assert_eq!(provider.account_last_touch_epoch(alice).unwrap(), Some(1));
+        assert_eq!(provider.account_last_touch_epoch(bob).unwrap(), Some(2));
+        assert_eq!(
+            provider.cold_state_stats(2).unwrap(),
+            ColdStateStats {
+                accounts: 2,
+                cold_accounts: 1,
+                storage_slots: 1,
+                cold_storage_slots: 1
+            }
+        );
+
+        // unwinding block 4 removes bob entirely
+        provider.remove_state_above(3, StorageLocation::Database).unwrap();
+        assert_eq!(provider.account_last_touch_epoch(bob).unwrap(), None);
+        assert_eq!(provider.storage_last_touch_epoch(alice, slot).unwrap(), Some(1));
+    }
+
+    #[test]
+    fn history_of_retained_addresses() {
+        let (alice, bob) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let factory = create_test_provider_factory().with_prune_modes(PruneModes {
+            retained_addresses: [alice].into(),
+            ..PruneModes::none()
+        });
+        let slot = B256::with_last_byte(1);
+        let account = |nonce| Account { nonce, ..Default::default() };
+
+        // the history below block 6 was pruned, except for the changesets of alice
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+        for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+            tx.put::<tables::PruneCheckpoints>(
+                segment,
+                PruneCheckpoint {
+                    block_number: Some(5),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(6),
+                },
+            )
+            .unwrap();
+        }
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey::new(alice, u64::MAX),
+            BlockNumberList::new([1, 3, 8]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey::new(bob, u64::MAX),
+            BlockNumberList::new([8]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::StoragesHistory>(
+            StorageShardedKey::new(alice, slot, u64::MAX),
+            BlockNumberList::new([1, 3]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(1, AccountBeforeTx { address: alice, info: None })
+            .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            3,
+            AccountBeforeTx { address: alice, info: Some(account(1)) },
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            8,
+            AccountBeforeTx { address: alice, info: Some(account(2)) },
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            8,
+            AccountBeforeTx { address: bob, info: Some(account(1)) },
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSets>(
+            (1, alice).into(),
+            StorageEntry { key: slot, value: U256::ZERO },
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSets>(
+            (3, alice).into(),
+            StorageEntry { key: slot, value: U256::from(1) },
+        )
+        .unwrap();
+        tx.put::<tables::PlainAccountState>(alice, account(3)).unwrap();
+        tx.put::<tables::PlainAccountState>(bob, account(2)).unwrap();
+        tx.put::<tables::PlainStorageState>(
+            alice,
+            StorageEntry { key: slot, value: U256::from(2) },
+        )
+        .unwrap();
+        provider.commit().unwrap();
+
+        // the state of alice is served at a pruned height, the state of bob is not
+        let state = factory.history_by_block_number(1).unwrap();
+        assert_eq!(state.basic_account(alice), Ok(Some(account(1))));
+        assert_eq!(state.storage(alice, slot), Ok(Some(U256::from(1))));
+        assert_eq!(state.basic_account(bob), Err(ProviderError::StateAtBlockPruned(2)));
+
+        // both are served above the prune checkpoint
+        let state = factory.history_by_block_number(6).unwrap();
+        assert_eq!(state.basic_account(alice), Ok(Some(account(2))));
+        assert_eq!(state.basic_account(bob), Ok(Some(account(1))));
+    }
+
+    #[test]
+    fn transaction_selectors_index_availability() {
+        let selector = Selector::ZERO;
+
+        // the index is not maintained
+        let factory = create_test_provider_factory();
+        assert_eq!(
+            factory.provider().unwrap().transactions_by_selector(selector, 0..=10, 10),
+            Err(ProviderError::IndexDisabled("transaction selector"))
+        );
+
+        // the index is pruned up to block 5
+        let factory = factory.with_prune_modes(PruneModes {
+            transaction_selectors: Some(PruneMode::Before(6)),
+            ..PruneModes::none()
+        });
+        let provider = factory.provider_rw().unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::PruneCheckpoints>(
+                PruneSegment::TransactionSelectors,
+                PruneCheckpoint {
+                    block_number: Some(5),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(6),
+                },
+            )
+            .unwrap();
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.transactions_by_selector(selector, 0..=10, 10),
+            Err(ProviderError::IndexPruned("transaction selector", 5))
+        );
+        assert_eq!(provider.transactions_by_selector(selector, 6..=10, 10), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn address_transactions_index_availability() {
+        let address = Address::random();
+
+        let factory = create_test_provider_factory();
+        assert_eq!(
+            factory.provider().unwrap().transactions_by_address(address, 0..=10, 10, false),
+            Err(ProviderError::IndexDisabled("transaction-by-address"))
+        );
+
+        let factory = factory.with_prune_modes(PruneModes {
+            address_transactions: Some(PruneMode::Before(6)),
+            ..PruneModes::none()
+        });
+        let provider = factory.provider_rw().unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::PruneCheckpoints>(
+                PruneSegment::AddressTransactions,
+                PruneCheckpoint {
+                    block_number: Some(5),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(6),
+                },
+            )
+            .unwrap();
+        provider.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.transactions_by_address(address, 3..=10, 10, true),
+            Err(ProviderError::IndexPruned("transaction-by-address", 5))
+        );
+        assert_eq!(provider.transactions_by_address(address, 6..=10, 10, true), Ok(Vec::new()));
+    }
 }
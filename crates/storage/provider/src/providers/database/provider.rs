@@ -3,23 +3,25 @@ use crate::{
     providers::{
         database::{chain::ChainStorage, metrics},
         static_file::StaticFileWriter,
-        NodeTypesForProvider, StaticFileProvider,
+        NodeTypesForProvider, StaticFileProvider, DEFAULT_MAX_UNINDEXED_BLOCKS,
     },
     to_range,
     traits::{
         AccountExtReader, BlockSource, ChangeSetReader, ReceiptProvider, StageCheckpointWriter,
     },
-    AccountReader, BlockBodyWriter, BlockExecutionWriter, BlockHashReader, BlockNumReader,
-    BlockReader, BlockWriter, BundleStateInit, ChainStateBlockReader, ChainStateBlockWriter,
-    DBProvider, EvmEnvProvider, HashingWriter, HeaderProvider, HeaderSyncGap,
-    HeaderSyncGapProvider, HistoricalStateProvider, HistoricalStateProviderRef, HistoryWriter,
-    LatestStateProvider, LatestStateProviderRef, OriginalValuesKnown, ProviderError,
-    PruneCheckpointReader, PruneCheckpointWriter, RevertsInit, StageCheckpointReader,
-    StateCommitmentProvider, StateProviderBox, StateWriter, StaticFileProviderFactory, StatsReader,
-    StorageLocation, StorageReader, StorageTrieWriter, TransactionVariant, TransactionsProvider,
-    TransactionsProviderExt, TrieWriter, WithdrawalsProvider,
+    AccountReader, AddressTransactionsReader, BlockBodyWriter, BlockExecutionWriter,
+    BlockHashReader, BlockNumReader, BlockReader, BlockWriter, BundleStateInit,
+    ChainStateBlockReader, ChainStateBlockWriter, ColdStateStats, DBProvider, EvmEnvProvider,
+    HashingWriter, HeaderProvider, HeaderSyncGap, HeaderSyncGapProvider, HistoricalStateProvider,
+    HistoricalStateProviderRef, HistoryWriter, LatestStateProvider, LatestStateProviderRef,
+    LogIndexReader, OriginalValuesKnown, ProviderError, PruneCheckpointReader,
+    PruneCheckpointWriter, RevertsInit, StageCheckpointReader, StateCommitmentProvider,
+    StateProviderBox, StateWriter, StaticFileProviderFactory, StatsReader, StorageLocation,
+    StorageReader, StorageTrieWriter, TouchEpochReader, TransactionSelectorReader,
+    TransactionVariant, TransactionsProvider, TransactionsProviderExt, TrieWriter,
+    WithdrawalHistoryReader, WithdrawalsProvider,
 };
-use alloy_consensus::{BlockHeader, Header};
+use alloy_consensus::{BlockHeader, Header, Transaction as _, TxReceipt};
 use alloy_eips::{
     eip2718::Encodable2718,
     eip4895::{Withdrawal, Withdrawals},
@@ -28,7 +30,7 @@ use alloy_eips::{
 use alloy_primitives::{
     keccak256,
     map::{hash_map, HashMap, HashSet},
-    Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256,
+    Address, BlockHash, BlockNumber, Log, Selector, TxHash, TxNumber, B256, U256,
 };
 use itertools::Itertools;
 use rayon::slice::ParallelSliceMut;
@@ -40,8 +42,10 @@ use reth_db_api::{
     cursor::{DbCursorRO, DbCursorRW, DbDupCursorRO},
     database::Database,
     models::{
-        sharded_key, storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress,
-        ShardedKey, StoredBlockBodyIndices,
+        sharded_key, storage_sharded_key::StorageShardedKey, AccountBeforeTx, AddressBlockNumber,
+        AddressEpoch, AddressStorageKeyEpoch, AddressTxNumber, BlockNumberAddress,
+        SelectorTxNumber, ShardedKey, StoredBlockBodyIndices, StoredBlockWithdrawals,
+        ValidatorBlockNumber,
     },
     table::Table,
     transaction::{DbTx, DbTxMut},
@@ -58,16 +62,16 @@ use reth_primitives::{
 };
 use reth_primitives_traits::{Block as _, BlockBody as _, SignedTransaction};
 use reth_prune_types::{PruneCheckpoint, PruneModes, PruneSegment};
-use reth_stages_types::{StageCheckpoint, StageId};
+use reth_stages_types::{CheckpointBlockRange, IndexHistoryCheckpoint, StageCheckpoint, StageId};
 use reth_storage_api::{
-    BlockBodyReader, NodePrimitivesProvider, StateProvider, StorageChangeSetReader,
+    BlockBodyReader, NodePrimitivesProvider, StateProvider, StateRootOf, StorageChangeSetReader,
     TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::{ProviderResult, RootMismatch};
 use reth_trie::{
     prefix_set::{PrefixSet, PrefixSetMut, TriePrefixSets},
     updates::{StorageTrieUpdates, TrieUpdates},
-    HashedPostStateSorted, Nibbles, StateRoot, StoredNibbles,
+    HashedPostStateSorted, Nibbles, StoredNibbles,
 };
 use reth_trie_db::{DatabaseStateRoot, DatabaseStorageTrieCursor};
 use revm::{
@@ -150,6 +154,11 @@ pub struct DatabaseProvider<TX, N: NodeTypes> {
     static_file_provider: StaticFileProvider<N::Primitives>,
     /// Pruning configuration
     prune_modes: PruneModes,
+    /// Number of blocks per epoch of the touch epoch tables, `None` if they are not maintained.
+    touch_epoch_length: Option<u64>,
+    /// Maximum number of blocks with changesets that are not indexed, for which the historical
+    /// state is still served.
+    max_unindexed_history_blocks: u64,
     /// Node storage handler.
     storage: Arc<N::Storage>,
 }
@@ -189,7 +198,11 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
         let storage_history_prune_checkpoint =
             self.get_prune_checkpoint(PruneSegment::StorageHistory)?;
 
-        let mut state_provider = HistoricalStateProviderRef::new(self, block_number);
+        let (account_history_index_block_number, storage_history_index_block_number) =
+            self.highest_indexed_history_blocks()?;
+
+        let mut state_provider = HistoricalStateProviderRef::new(self, block_number)
+            .with_max_unindexed_blocks(self.max_unindexed_history_blocks);
 
         // If we pruned account or storage history, we can't return state on every historical block.
         // Instead, we should cap it at the latest prune checkpoint for corresponding prune segment.
@@ -208,14 +221,92 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
             );
         }
 
+        if let Some(block_number) = account_history_index_block_number {
+            state_provider =
+                state_provider.with_highest_indexed_account_history_block_number(block_number);
+        }
+        if let Some(block_number) = storage_history_index_block_number {
+            state_provider =
+                state_provider.with_highest_indexed_storage_history_block_number(block_number);
+        }
+
         Ok(Box::new(state_provider))
     }
 
+    /// Returns the highest blocks whose account and storage changesets are indexed, if the history
+    /// indices lag behind the executed blocks.
+    ///
+    /// The changesets of the blocks that are not indexed yet have to be looked up directly by the
+    /// historical state providers.
+    fn highest_indexed_history_blocks(
+        &self,
+    ) -> ProviderResult<(Option<BlockNumber>, Option<BlockNumber>)> {
+        let execution_block_number =
+            self.get_stage_checkpoint(StageId::Execution)?.unwrap_or_default().block_number;
+        let index_block_number = |stage_id| -> ProviderResult<_> {
+            let block_number =
+                self.get_stage_checkpoint(stage_id)?.unwrap_or_default().block_number;
+            Ok(Some(block_number).filter(|block_number| *block_number < execution_block_number))
+        };
+
+        Ok((
+            index_block_number(StageId::IndexAccountHistory)?,
+            index_block_number(StageId::IndexStorageHistory)?,
+        ))
+    }
+
+    /// Returns an error if the optional index that is pruned by `segment` can't answer a query
+    /// over the block range, because it's not maintained or pruned within the range.
+    ///
+    /// Blocks that were executed while the index was disabled count as pruned.
+    pub(crate) fn ensure_index_available(
+        &self,
+        segment: PruneSegment,
+        name: &'static str,
+        enabled: bool,
+        block_range: &RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<()> {
+        if !enabled {
+            return Err(ProviderError::IndexDisabled(name))
+        }
+        if let Some(pruned_block) =
+            self.get_prune_checkpoint(segment)?.and_then(|checkpoint| checkpoint.block_number)
+        {
+            if *block_range.start() <= pruned_block {
+                return Err(ProviderError::IndexPruned(name, pruned_block))
+            }
+        }
+        Ok(())
+    }
+
     #[cfg(feature = "test-utils")]
     /// Sets the prune modes for provider.
     pub fn set_prune_modes(&mut self, prune_modes: PruneModes) {
         self.prune_modes = prune_modes;
     }
+
+    /// Sets the number of blocks per epoch of the touch epoch tables, which are only maintained if
+    /// this is set.
+    ///
+    /// See [`AccountTouchEpochs`](tables::AccountTouchEpochs) and
+    /// [`StorageTouchEpochs`](tables::StorageTouchEpochs).
+    pub fn with_touch_epoch_length(mut self, touch_epoch_length: Option<u64>) -> Self {
+        self.touch_epoch_length = touch_epoch_length;
+        self
+    }
+
+    /// Sets the maximum number of blocks whose changesets are not indexed in the history tables,
+    /// for which the historical state providers still serve state.
+    ///
+    /// Every historical lookup walks the changesets of these blocks, so the historical state is
+    /// not served if there are more.
+    pub const fn with_max_unindexed_history_blocks(
+        mut self,
+        max_unindexed_history_blocks: u64,
+    ) -> Self {
+        self.max_unindexed_history_blocks = max_unindexed_history_blocks;
+        self
+    }
 }
 
 impl<TX, N: NodeTypes> NodePrimitivesProvider for DatabaseProvider<TX, N> {
@@ -248,7 +339,15 @@ impl<TX: DbTxMut, N: NodeTypes> DatabaseProvider<TX, N> {
         prune_modes: PruneModes,
         storage: Arc<N::Storage>,
     ) -> Self {
-        Self { tx, chain_spec, static_file_provider, prune_modes, storage }
+        Self {
+            tx,
+            chain_spec,
+            static_file_provider,
+            prune_modes,
+            touch_epoch_length: None,
+            max_unindexed_history_blocks: DEFAULT_MAX_UNINDEXED_BLOCKS,
+            storage,
+        }
     }
 }
 
@@ -259,6 +358,29 @@ impl<TX, N: NodeTypes> AsRef<Self> for DatabaseProvider<TX, N> {
 }
 
 impl<TX: DbTx + DbTxMut + 'static, N: NodeTypesForProvider> DatabaseProvider<TX, N> {
+    /// Unwinds the log index above the given block, if it is maintained.
+    ///
+    /// This reads the receipts of the unwound blocks and must be called before they are removed.
+    /// Returns the remaining range of blocks covered by the log index.
+    fn unwind_log_index_above(
+        &self,
+        block: BlockNumber,
+    ) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        let Some(indexed_range) = self.log_index_range()? else { return Ok(None) };
+        if *indexed_range.end() <= block {
+            return Ok(Some(indexed_range))
+        }
+
+        let mut receipts = Vec::new();
+        for block_number in block + 1..=*indexed_range.end() {
+            receipts.extend(self.receipts_by_block(block_number.into())?.unwrap_or_default());
+        }
+        self.unwind_log_index(receipts.iter().flat_map(|receipt| receipt.logs()), block)?;
+
+        // If all indexed blocks are unwound, the index needs to be rebuilt by the stage.
+        Ok((*indexed_range.start() <= block).then(|| *indexed_range.start()..=block))
+    }
+
     /// Unwinds trie state for the given range.
     ///
     /// This includes calculating the resulted state root and comparing it with the parent block
@@ -311,17 +433,16 @@ impl<TX: DbTx + DbTxMut + 'static, N: NodeTypesForProvider> DatabaseProvider<TX,
         self.unwind_storage_history_indices(changed_storages.iter().copied())?;
 
         // Calculate the reverted merkle root.
-        // This is the same as `StateRoot::incremental_root_with_updates`, only the prefix sets
-        // are pre-loaded.
+        // This is the same as `DatabaseStateRoot::incremental_root_with_updates`, only the prefix
+        // sets are pre-loaded.
         let prefix_sets = TriePrefixSets {
             account_prefix_set: account_prefix_set.freeze(),
             storage_prefix_sets,
             destroyed_accounts,
         };
-        let (new_state_root, trie_updates) = StateRoot::from_tx(&self.tx)
-            .with_prefix_sets(prefix_sets)
-            .root_with_updates()
-            .map_err(Into::<reth_db::DatabaseError>::into)?;
+        let (new_state_root, trie_updates) =
+            StateRootOf::<'_, Self>::prefix_sets_root_with_updates(&self.tx, prefix_sets)
+                .map_err(Into::<reth_db::DatabaseError>::into)?;
 
         let parent_number = range.start().saturating_sub(1);
         let parent_state_root = self
@@ -373,6 +494,100 @@ impl<TX: DbTx + DbTxMut + 'static, N: NodeTypesForProvider> DatabaseProvider<TX,
 
         Ok(())
     }
+
+    /// Records the epochs in which the accounts and storage slots changed in the given block range
+    /// were touched, if touch epochs are tracked.
+    ///
+    /// Existing entries are kept, so that every entry holds the first block of its epoch that
+    /// touched the state. The changesets of the range must already be written.
+    fn write_touch_epochs(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<()> {
+        let Some(epoch_length) = self.touch_epoch_length else { return Ok(()) };
+
+        let mut accounts_cursor = self.tx.cursor_write::<tables::AccountTouchEpochs>()?;
+        for (address, blocks) in self.changed_accounts_and_blocks_with_range(range.clone())? {
+            for block_number in
+                blocks.into_iter().dedup_by(|a, b| a / epoch_length == b / epoch_length)
+            {
+                let key = AddressEpoch::from((address, block_number / epoch_length));
+                if accounts_cursor.seek_exact(key)?.is_none() {
+                    accounts_cursor.upsert(key, block_number)?;
+                }
+            }
+        }
+
+        let mut storages_cursor = self.tx.cursor_write::<tables::StorageTouchEpochs>()?;
+        for ((address, storage_key), blocks) in
+            self.changed_storages_and_blocks_with_range(range)?
+        {
+            for block_number in
+                blocks.into_iter().dedup_by(|a, b| a / epoch_length == b / epoch_length)
+            {
+                let key = AddressStorageKeyEpoch::from((
+                    address,
+                    storage_key,
+                    block_number / epoch_length,
+                ));
+                if storages_cursor.seek_exact(key)?.is_none() {
+                    storages_cursor.upsert(key, block_number)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the touch epochs of the given state that were recorded by blocks above `block`, if
+    /// touch epochs are tracked.
+    ///
+    /// The state must contain all accounts and storage slots changed above `block`.
+    fn unwind_touch_epochs(
+        &self,
+        block: BlockNumber,
+        state: &BundleStateInit,
+    ) -> ProviderResult<()> {
+        let Some(epoch_length) = self.touch_epoch_length else { return Ok(()) };
+
+        // Only the entries of the epoch of the first unwound block and later epochs can have been
+        // recorded by unwound blocks.
+        let first_epoch = (block + 1) / epoch_length;
+
+        let mut accounts_cursor = self.tx.cursor_read::<tables::AccountTouchEpochs>()?;
+        let mut storages_cursor = self.tx.cursor_read::<tables::StorageTouchEpochs>()?;
+        let mut unwound_accounts = Vec::new();
+        let mut unwound_storages = Vec::new();
+        for (address, (_, _, storage)) in state {
+            for entry in
+                accounts_cursor.walk_range(AddressEpoch::range(*address, first_epoch..u64::MAX))?
+            {
+                let (key, first_block) = entry?;
+                if first_block > block {
+                    unwound_accounts.push(key);
+                }
+            }
+
+            for storage_key in storage.keys() {
+                for entry in storages_cursor.walk_range(AddressStorageKeyEpoch::range(
+                    *address,
+                    *storage_key,
+                    first_epoch..u64::MAX,
+                ))? {
+                    let (key, first_block) = entry?;
+                    if first_block > block {
+                        unwound_storages.push(key);
+                    }
+                }
+            }
+        }
+
+        for key in unwound_accounts {
+            self.tx.delete::<tables::AccountTouchEpochs>(key, None)?;
+        }
+        for key in unwound_storages {
+            self.tx.delete::<tables::StorageTouchEpochs>(key, None)?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<TX: DbTx + 'static, N: NodeTypes> TryIntoHistoricalStateProvider for DatabaseProvider<TX, N> {
@@ -394,7 +609,12 @@ impl<TX: DbTx + 'static, N: NodeTypes> TryIntoHistoricalStateProvider for Databa
         let storage_history_prune_checkpoint =
             self.get_prune_checkpoint(PruneSegment::StorageHistory)?;
 
-        let mut state_provider = HistoricalStateProvider::new(self, block_number);
+        let (account_history_index_block_number, storage_history_index_block_number) =
+            self.highest_indexed_history_blocks()?;
+
+        let max_unindexed_history_blocks = self.max_unindexed_history_blocks;
+        let mut state_provider = HistoricalStateProvider::new(self, block_number)
+            .with_max_unindexed_blocks(max_unindexed_history_blocks);
 
         // If we pruned account or storage history, we can't return state on every historical block.
         // Instead, we should cap it at the latest prune checkpoint for corresponding prune segment.
@@ -413,6 +633,15 @@ impl<TX: DbTx + 'static, N: NodeTypes> TryIntoHistoricalStateProvider for Databa
             );
         }
 
+        if let Some(block_number) = account_history_index_block_number {
+            state_provider =
+                state_provider.with_highest_indexed_account_history_block_number(block_number);
+        }
+        if let Some(block_number) = storage_history_index_block_number {
+            state_provider =
+                state_provider.with_highest_indexed_storage_history_block_number(block_number);
+        }
+
         Ok(Box::new(state_provider))
     }
 }
@@ -506,6 +735,36 @@ where
     Ok(Vec::new())
 }
 
+/// Returns the block numbers in the given range from the shards of the key, in ascending order.
+fn sharded_index_blocks<K, T, C>(
+    cursor: &mut C,
+    key: K,
+    block_range: RangeInclusive<BlockNumber>,
+) -> ProviderResult<Vec<BlockNumber>>
+where
+    K: PartialEq + Copy,
+    T: Table<Key = ShardedKey<K>, Value = BlockNumberList>,
+    C: DbCursorRO<T>,
+{
+    let (start, end) = block_range.into_inner();
+    let mut blocks = Vec::new();
+    // The first shard that may contain blocks of the range is the first one with a highest block
+    // number greater or equal to the start of the range.
+    for entry in cursor.walk(Some(ShardedKey::new(key, start)))? {
+        let (sharded_key, list) = entry?;
+        if sharded_key.key != key {
+            break
+        }
+        blocks.extend(
+            list.iter().skip_while(|block| *block < start).take_while(|block| *block <= end),
+        );
+        if sharded_key.highest_block_number >= end {
+            break
+        }
+    }
+    Ok(blocks)
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> DatabaseProvider<TX, N> {
     /// Creates a provider with an inner read-only transaction.
     pub const fn new(
@@ -515,7 +774,15 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> DatabaseProvider<TX, N> {
         prune_modes: PruneModes,
         storage: Arc<N::Storage>,
     ) -> Self {
-        Self { tx, chain_spec, static_file_provider, prune_modes, storage }
+        Self {
+            tx,
+            chain_spec,
+            static_file_provider,
+            prune_modes,
+            touch_epoch_length: None,
+            max_unindexed_history_blocks: DEFAULT_MAX_UNINDEXED_BLOCKS,
+            storage,
+        }
     }
 
     /// Consume `DbTx` or `DbTxMut`.
@@ -861,6 +1128,19 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
         }
         Ok(())
     }
+
+    /// Saves the range of blocks covered by the log index to the
+    /// [`IndexLogs`](StageId::IndexLogs) stage checkpoint, keeping its block number.
+    pub fn save_log_index_range(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<()> {
+        let checkpoint = self.get_stage_checkpoint(StageId::IndexLogs)?.unwrap_or_default();
+        self.save_stage_checkpoint(
+            StageId::IndexLogs,
+            checkpoint.with_index_history_stage_checkpoint(IndexHistoryCheckpoint {
+                block_range: CheckpointBlockRange::from(range),
+                ..Default::default()
+            }),
+        )
+    }
 }
 
 impl<TX: DbTx, N: NodeTypes> AccountReader for DatabaseProvider<TX, N> {
@@ -928,6 +1208,279 @@ impl<TX: DbTx, N: NodeTypes> StorageChangeSetReader for DatabaseProvider<TX, N>
     }
 }
 
+impl<TX: DbTx + 'static, N: NodeTypes> TransactionSelectorReader for DatabaseProvider<TX, N> {
+    fn transactions_by_selector(
+        &self,
+        selector: Selector,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        self.ensure_index_available(
+            PruneSegment::TransactionSelectors,
+            "transaction selector",
+            self.prune_modes.has_transaction_selectors_index(),
+            &block_range,
+        )?;
+
+        let Some(first_tx_num) = self
+            .tx
+            .get::<tables::BlockBodyIndices>(*block_range.start())?
+            .map(|indices| indices.first_tx_num())
+        else {
+            return Ok(Vec::new())
+        };
+        let end_tx_num = self
+            .tx
+            .get::<tables::BlockBodyIndices>(*block_range.end())?
+            .map_or(TxNumber::MAX, |indices| indices.next_tx_num());
+
+        self.tx
+            .cursor_read::<tables::TransactionSelectors>()?
+            .walk_range(SelectorTxNumber::range(selector, first_tx_num..end_tx_num))?
+            .take(limit)
+            .map(|entry| entry.map(|(key, block_number)| (key.tx_number(), block_number)))
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> AddressTransactionsReader for DatabaseProvider<TX, N> {
+    fn transactions_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+        reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        self.ensure_index_available(
+            PruneSegment::AddressTransactions,
+            "transaction-by-address",
+            self.prune_modes.has_address_transactions_index(),
+            &block_range,
+        )?;
+
+        let Some(first_tx_num) = self
+            .tx
+            .get::<tables::BlockBodyIndices>(*block_range.start())?
+            .map(|indices| indices.first_tx_num())
+        else {
+            return Ok(Vec::new())
+        };
+        let end_tx_num = self
+            .tx
+            .get::<tables::BlockBodyIndices>(*block_range.end())?
+            .map_or(TxNumber::MAX, |indices| indices.next_tx_num());
+
+        let range = AddressTxNumber::range(address, first_tx_num..end_tx_num);
+        let mut cursor = self.tx.cursor_read::<tables::AddressTransactions>()?;
+        if !reverse {
+            return cursor
+                .walk_range(range)?
+                .take(limit)
+                .map(|entry| entry.map(|(key, block_number)| (key.tx_number(), block_number)))
+                .collect::<Result<_, _>>()
+                .map_err(Into::into)
+        }
+
+        // Position the cursor on the last entry of the range and walk backwards from there
+        let mut items = Vec::new();
+        let mut entry = match cursor.seek(range.end)? {
+            Some(_) => cursor.prev()?,
+            None => cursor.last()?,
+        };
+        while let Some((key, block_number)) = entry {
+            if key < range.start || items.len() >= limit {
+                break
+            }
+            items.push((key.tx_number(), block_number));
+            entry = cursor.prev()?;
+        }
+        Ok(items)
+    }
+}
+
+impl<TX: DbTx + 'static, N: NodeTypes> WithdrawalHistoryReader for DatabaseProvider<TX, N> {
+    fn withdrawals_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.ensure_index_available(
+            PruneSegment::WithdrawalHistory,
+            "withdrawal history",
+            self.prune_modes.has_withdrawal_history_index(),
+            &block_range,
+        )?;
+
+        let range = *block_range.start()..block_range.end().saturating_add(1);
+        let mut withdrawals = Vec::new();
+        for entry in self
+            .tx
+            .cursor_read::<tables::AddressWithdrawals>()?
+            .walk_range(AddressBlockNumber::range(address, range))?
+        {
+            let (key, stored) = entry?;
+            withdrawals.extend(
+                stored
+                    .withdrawals
+                    .into_inner()
+                    .into_iter()
+                    .map(|withdrawal| (key.block_number(), withdrawal)),
+            );
+            if withdrawals.len() >= limit {
+                break
+            }
+        }
+        withdrawals.truncate(limit);
+        Ok(withdrawals)
+    }
+
+    fn withdrawals_by_validator(
+        &self,
+        validator_index: u64,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.ensure_index_available(
+            PruneSegment::WithdrawalHistory,
+            "withdrawal history",
+            self.prune_modes.has_withdrawal_history_index(),
+            &block_range,
+        )?;
+
+        let range = *block_range.start()..block_range.end().saturating_add(1);
+        let mut withdrawals = Vec::new();
+        for entry in self
+            .tx
+            .cursor_read::<tables::ValidatorWithdrawals>()?
+            .walk_range(ValidatorBlockNumber::range(validator_index, range))?
+        {
+            let (key, stored) = entry?;
+            withdrawals.extend(
+                stored
+                    .withdrawals
+                    .into_inner()
+                    .into_iter()
+                    .map(|withdrawal| (key.block_number(), withdrawal)),
+            );
+            if withdrawals.len() >= limit {
+                break
+            }
+        }
+        withdrawals.truncate(limit);
+        Ok(withdrawals)
+    }
+}
+
+impl<TX: DbTx, N: NodeTypes> LogIndexReader for DatabaseProvider<TX, N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(self
+            .get_stage_checkpoint(StageId::IndexLogs)?
+            .and_then(|checkpoint| checkpoint.index_history_stage_checkpoint())
+            .map(|checkpoint| checkpoint.block_range.from..=checkpoint.block_range.to))
+    }
+
+    fn blocks_with_address_logs(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        let mut cursor = self.tx.cursor_read::<tables::LogAddressIndex>()?;
+        sharded_index_blocks(&mut cursor, address, block_range)
+    }
+
+    fn blocks_with_topic_logs(
+        &self,
+        topic: B256,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        let mut cursor = self.tx.cursor_read::<tables::LogTopicIndex>()?;
+        sharded_index_blocks(&mut cursor, topic, block_range)
+    }
+}
+
+impl<TX: DbTx, N: NodeTypes> TouchEpochReader for DatabaseProvider<TX, N> {
+    fn touch_epoch_length(&self) -> Option<u64> {
+        self.touch_epoch_length
+    }
+
+    fn account_last_touch_epoch(&self, address: Address) -> ProviderResult<Option<u64>> {
+        let mut cursor = self.tx.cursor_read::<tables::AccountTouchEpochs>()?;
+        let entry = match cursor.seek(AddressEpoch::from((address, u64::MAX)))? {
+            Some((key, _)) if key.address() == address => Some(key),
+            Some(_) => cursor.prev()?.map(|(key, _)| key),
+            None => cursor.last()?.map(|(key, _)| key),
+        };
+        Ok(entry.filter(|key| key.address() == address).map(|key| key.epoch()))
+    }
+
+    fn storage_last_touch_epoch(
+        &self,
+        address: Address,
+        storage_key: B256,
+    ) -> ProviderResult<Option<u64>> {
+        let mut cursor = self.tx.cursor_read::<tables::StorageTouchEpochs>()?;
+        let entry =
+            match cursor.seek(AddressStorageKeyEpoch::from((address, storage_key, u64::MAX)))? {
+                Some((key, _)) if key.address() == address && key.storage_key() == storage_key => {
+                    Some(key)
+                }
+                Some(_) => cursor.prev()?.map(|(key, _)| key),
+                None => cursor.last()?.map(|(key, _)| key),
+            };
+        Ok(entry
+            .filter(|key| key.address() == address && key.storage_key() == storage_key)
+            .map(|key| key.epoch()))
+    }
+
+    fn cold_state_stats(&self, epoch: u64) -> ProviderResult<ColdStateStats> {
+        let (accounts, cold_accounts) = self
+            .count_cold_keys::<tables::AccountTouchEpochs, _>(epoch, |key| {
+                (key.address(), key.epoch())
+            })?;
+        let (storage_slots, cold_storage_slots) = self
+            .count_cold_keys::<tables::StorageTouchEpochs, _>(epoch, |key| {
+                ((key.address(), key.storage_key()), key.epoch())
+            })?;
+        Ok(ColdStateStats { accounts, cold_accounts, storage_slots, cold_storage_slots })
+    }
+}
+
+impl<TX: DbTx, N: NodeTypes> DatabaseProvider<TX, N> {
+    /// Walks a touch epoch table and returns the number of distinct keys and the number of keys
+    /// whose last epoch is before `epoch`.
+    ///
+    /// `split` returns the key without the epoch and the epoch of a table key.
+    fn count_cold_keys<T, K>(
+        &self,
+        epoch: u64,
+        split: impl Fn(T::Key) -> (K, u64),
+    ) -> ProviderResult<(u64, u64)>
+    where
+        T: Table<Value = BlockNumber>,
+        K: PartialEq,
+    {
+        let (mut keys, mut cold_keys) = (0, 0);
+        let mut last: Option<(K, u64)> = None;
+        for entry in self.tx.cursor_read::<T>()?.walk(None)? {
+            let (key, key_epoch) = split(entry?.0);
+            // entries of a key are ordered by epoch, so the previous entry holds the last epoch of
+            // its key if the key changed
+            if let Some((_, last_epoch)) = last.take().filter(|(last_key, _)| *last_key != key) {
+                keys += 1;
+                cold_keys += u64::from(last_epoch < epoch);
+            }
+            last = Some((key, key_epoch));
+        }
+        if let Some((_, last_epoch)) = last {
+            keys += 1;
+            cold_keys += u64::from(last_epoch < epoch);
+        }
+        Ok((keys, cold_keys))
+    }
+}
+
 impl<TX: DbTx, N: NodeTypes> ChangeSetReader for DatabaseProvider<TX, N> {
     fn account_block_changeset(
         &self,
@@ -1411,7 +1964,16 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> TransactionsProvider for Datab
     type Transaction = TxTy<N>;
 
     fn transaction_id(&self, tx_hash: TxHash) -> ProviderResult<Option<TxNumber>> {
-        Ok(self.tx.get::<tables::TransactionHashNumbers>(tx_hash)?)
+        if let Some(id) = self.tx.get::<tables::TransactionHashNumbers>(tx_hash)? {
+            return Ok(Some(id))
+        }
+
+        // Lookups of transactions in indexed static files may have been pruned
+        if self.prune_modes.transaction_hash_index {
+            return self.static_file_provider.transaction_id(tx_hash)
+        }
+
+        Ok(None)
     }
 
     fn transaction_by_id(&self, id: TxNumber) -> ProviderResult<Option<Self::Transaction>> {
@@ -1822,9 +2384,15 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
     ) -> ProviderResult<()> {
         let (plain_state, reverts) =
             execution_outcome.bundle.to_plain_state_and_reverts(is_value_known);
+        // The reverts hold an entry for every block, even if it didn't change any state.
+        let block_count = reverts.accounts.len() as u64;
 
         self.write_state_reverts(reverts, execution_outcome.first_block)?;
         self.write_state_changes(plain_state)?;
+        if block_count > 0 {
+            let first_block = execution_outcome.first_block;
+            self.write_touch_epochs(first_block..=first_block + block_count - 1)?;
+        }
 
         let mut bodies_cursor = self.tx.cursor_read::<tables::BlockBodyIndices>()?;
 
@@ -2106,6 +2674,8 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
             &mut plain_storage_cursor,
         )?;
 
+        self.unwind_touch_epochs(block, &state)?;
+
         // iterate over local plain state remove all account and all storages.
         for (address, (old_account, new_account, storage)) in &state {
             // revert account if needed.
@@ -2206,6 +2776,8 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
             &mut plain_storage_cursor,
         )?;
 
+        self.unwind_touch_epochs(block, &state)?;
+
         // iterate over local plain state remove all account and all storages.
         for (address, (old_account, new_account, storage)) in &state {
             // revert account if needed.
@@ -2569,8 +3141,8 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HashingWriter for DatabaseProvi
 
         // merkle tree
         {
-            // This is the same as `StateRoot::incremental_root_with_updates`, only the prefix sets
-            // are pre-loaded.
+            // This is the same as `DatabaseStateRoot::incremental_root_with_updates`, only the
+            // prefix sets are pre-loaded.
             let prefix_sets = TriePrefixSets {
                 account_prefix_set: account_prefix_set.freeze(),
                 storage_prefix_sets: storage_prefix_sets
@@ -2579,10 +3151,9 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HashingWriter for DatabaseProvi
                     .collect(),
                 destroyed_accounts,
             };
-            let (state_root, trie_updates) = StateRoot::from_tx(&self.tx)
-                .with_prefix_sets(prefix_sets)
-                .root_with_updates()
-                .map_err(Into::<reth_db::DatabaseError>::into)?;
+            let (state_root, trie_updates) =
+                StateRootOf::<'_, Self>::prefix_sets_root_with_updates(&self.tx, prefix_sets)
+                    .map_err(Into::<reth_db::DatabaseError>::into)?;
             if state_root != expected_state_root {
                 return Err(ProviderError::StateRootMismatch(Box::new(RootMismatch {
                     root: GotExpected { got: state_root, expected: expected_state_root },
@@ -2732,6 +3303,91 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> HistoryWriter for DatabaseProvi
 
         Ok(())
     }
+
+    fn append_log_index<'a>(
+        &self,
+        block_logs: impl IntoIterator<Item = (BlockNumber, impl IntoIterator<Item = &'a Log>)>,
+    ) -> ProviderResult<()> {
+        let Some(indexed_range) = self.log_index_range()? else { return Ok(()) };
+
+        let mut last_block = *indexed_range.end();
+        let mut addresses = BTreeMap::<Address, Vec<BlockNumber>>::new();
+        let mut topics = BTreeMap::<B256, Vec<BlockNumber>>::new();
+        let push_block = |blocks: &mut Vec<BlockNumber>, block_number: BlockNumber| {
+            if blocks.last() != Some(&block_number) {
+                blocks.push(block_number);
+            }
+        };
+        for (block_number, logs) in block_logs {
+            // The index can't have gaps, blocks that don't follow the indexed range need to be
+            // indexed by the stage.
+            if block_number != last_block + 1 {
+                return Ok(())
+            }
+            last_block = block_number;
+
+            for log in logs {
+                push_block(addresses.entry(log.address).or_default(), block_number);
+                for topic in log.topics() {
+                    push_block(topics.entry(*topic).or_default(), block_number);
+                }
+            }
+        }
+        if last_block == *indexed_range.end() {
+            return Ok(())
+        }
+
+        self.append_history_index::<_, tables::LogAddressIndex>(addresses, ShardedKey::new)?;
+        self.append_history_index::<_, tables::LogTopicIndex>(topics, ShardedKey::new)?;
+        self.save_log_index_range(*indexed_range.start()..=last_block)
+    }
+
+    fn unwind_log_index<'a>(
+        &self,
+        logs: impl IntoIterator<Item = &'a Log>,
+        unwind_to: BlockNumber,
+    ) -> ProviderResult<usize> {
+        let mut addresses = BTreeSet::new();
+        let mut topics = BTreeSet::new();
+        for log in logs {
+            addresses.insert(log.address);
+            topics.extend(log.topics().iter().copied());
+        }
+
+        let mut cursor = self.tx.cursor_write::<tables::LogAddressIndex>()?;
+        for &address in &addresses {
+            let partial_shard = unwind_history_shards::<_, tables::LogAddressIndex, _>(
+                &mut cursor,
+                ShardedKey::last(address),
+                unwind_to + 1,
+                |sharded_key| sharded_key.key == address,
+            )?;
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(address),
+                    BlockNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+        }
+
+        let mut cursor = self.tx.cursor_write::<tables::LogTopicIndex>()?;
+        for &topic in &topics {
+            let partial_shard = unwind_history_shards::<_, tables::LogTopicIndex, _>(
+                &mut cursor,
+                ShardedKey::last(topic),
+                unwind_to + 1,
+                |sharded_key| sharded_key.key == topic,
+            )?;
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(topic),
+                    BlockNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+        }
+
+        Ok(addresses.len() + topics.len())
+    }
 }
 
 impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockExecutionWriter
@@ -2744,6 +3400,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockExecu
     ) -> ProviderResult<Chain<Self::Primitives>> {
         let range = block + 1..=self.last_block_number()?;
 
+        let log_index_range = self.unwind_log_index_above(block)?;
         self.unwind_trie_state_range(range.clone())?;
 
         // get execution res
@@ -2757,6 +3414,9 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockExecu
 
         // Update pipeline progress
         self.update_pipeline_stages(block, true)?;
+        if let Some(log_index_range) = log_index_range {
+            self.save_log_index_range(log_index_range)?;
+        }
 
         Ok(Chain::new(blocks, execution_state, None))
     }
@@ -2768,6 +3428,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockExecu
     ) -> ProviderResult<()> {
         let range = block + 1..=self.last_block_number()?;
 
+        let log_index_range = self.unwind_log_index_above(block)?;
         self.unwind_trie_state_range(range)?;
 
         // remove execution res
@@ -2779,6 +3440,9 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockExecu
 
         // Update pipeline progress
         self.update_pipeline_stages(block, true)?;
+        if let Some(log_index_range) = log_index_range {
+            self.save_log_index_range(log_index_range)?;
+        }
 
         Ok(())
     }
@@ -2810,6 +3474,16 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockWrite
     ///
     /// If the provider has __not__ configured full transaction lookup pruning, this will modify
     /// [`TransactionHashNumbers`](tables::TransactionHashNumbers).
+    ///
+    /// If the provider has configured the transaction selector index, this will modify
+    /// [`TransactionSelectors`](tables::TransactionSelectors).
+    ///
+    /// If the provider has configured the transaction-by-address index, this will modify
+    /// [`AddressTransactions`](tables::AddressTransactions).
+    ///
+    /// If the provider has configured the withdrawal history index and withdrawals are not empty,
+    /// this will modify [`AddressWithdrawals`](tables::AddressWithdrawals) and
+    /// [`ValidatorWithdrawals`](tables::ValidatorWithdrawals).
     fn insert_block(
         &self,
         block: SealedBlockWithSenders<Self::Block>,
@@ -2875,9 +3549,52 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockWrite
             if self.prune_modes.transaction_lookup.is_none_or(|m| !m.is_full()) {
                 self.tx.put::<tables::TransactionHashNumbers>(*hash, next_tx_num)?;
             }
+
+            if self.prune_modes.has_transaction_selectors_index() {
+                if let Some(selector) = transaction.function_selector() {
+                    self.tx.put::<tables::TransactionSelectors>(
+                        (selector, next_tx_num).into(),
+                        block_number,
+                    )?;
+                }
+            }
+
+            if self.prune_modes.has_address_transactions_index() {
+                for address in
+                    std::iter::once(*sender).chain(transaction.to().filter(|to| to != sender))
+                {
+                    self.tx.put::<tables::AddressTransactions>(
+                        (address, next_tx_num).into(),
+                        block_number,
+                    )?;
+                }
+            }
             next_tx_num += 1;
         }
 
+        if self.prune_modes.has_withdrawal_history_index() {
+            if let Some(withdrawals) = block.block.body.withdrawals() {
+                let mut by_address = BTreeMap::<_, Vec<_>>::new();
+                let mut by_validator = BTreeMap::<_, Vec<_>>::new();
+                for withdrawal in withdrawals.iter() {
+                    by_address.entry(withdrawal.address).or_default().push(*withdrawal);
+                    by_validator.entry(withdrawal.validator_index).or_default().push(*withdrawal);
+                }
+                for (address, withdrawals) in by_address {
+                    self.tx.put::<tables::AddressWithdrawals>(
+                        (address, block_number).into(),
+                        StoredBlockWithdrawals { withdrawals: Withdrawals::new(withdrawals) },
+                    )?;
+                }
+                for (validator_index, withdrawals) in by_validator {
+                    self.tx.put::<tables::ValidatorWithdrawals>(
+                        (validator_index, block_number).into(),
+                        StoredBlockWithdrawals { withdrawals: Withdrawals::new(withdrawals) },
+                    )?;
+                }
+            }
+        }
+
         self.append_block_bodies(vec![(block_number, Some(block.block.body))], write_to)?;
 
         debug!(
@@ -3006,10 +3723,55 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider + 'static> BlockWrite
             for (hash, _) in self.transaction_hashes_by_range(unwind_tx_from..(unwind_tx_to + 1))? {
                 self.tx.delete::<tables::TransactionHashNumbers>(hash, None)?;
             }
+
+            if self.prune_modes.has_transaction_selectors_index() {
+                let transactions = self.transactions_by_tx_range(unwind_tx_from..=unwind_tx_to)?;
+                for (tx_num, transaction) in (unwind_tx_from..).zip(transactions) {
+                    if let Some(selector) = transaction.function_selector() {
+                        self.tx.delete::<tables::TransactionSelectors>(
+                            (selector, tx_num).into(),
+                            None,
+                        )?;
+                    }
+                }
+            }
+
+            if self.prune_modes.has_address_transactions_index() {
+                let transactions = self.transactions_by_tx_range(unwind_tx_from..=unwind_tx_to)?;
+                for (tx_num, transaction) in (unwind_tx_from..).zip(transactions) {
+                    // Senders may already be pruned, so they are recovered from the signature
+                    let Some(sender) = transaction.recover_signer_unchecked() else { continue };
+                    for address in
+                        std::iter::once(sender).chain(transaction.to().filter(|to| *to != sender))
+                    {
+                        self.tx.delete::<tables::AddressTransactions>(
+                            (address, tx_num).into(),
+                            None,
+                        )?;
+                    }
+                }
+            }
         }
 
         self.remove::<tables::TransactionSenders>(unwind_tx_from..)?;
 
+        if self.prune_modes.has_withdrawal_history_index() {
+            let mut withdrawals_cursor = self.tx.cursor_read::<tables::BlockWithdrawals>()?;
+            for entry in withdrawals_cursor.walk_range(block + 1..)? {
+                let (block_number, stored) = entry?;
+                for withdrawal in stored.withdrawals.iter() {
+                    self.tx.delete::<tables::AddressWithdrawals>(
+                        (withdrawal.address, block_number).into(),
+                        None,
+                    )?;
+                    self.tx.delete::<tables::ValidatorWithdrawals>(
+                        (withdrawal.validator_index, block_number).into(),
+                        None,
+                    )?;
+                }
+            }
+        }
+
         self.remove_bodies_above(block, remove_transactions_from)?;
 
         Ok(())
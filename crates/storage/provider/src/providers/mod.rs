@@ -1,19 +1,20 @@
 use crate::{
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BlockSource, BlockchainTreePendingStateProvider, CanonStateNotifications,
-    CanonStateSubscriptions, ChainSpecProvider, ChainStateBlockReader, ChangeSetReader,
-    DatabaseProviderFactory, EvmEnvProvider, FullExecutionDataProvider, HeaderProvider,
-    NodePrimitivesProvider, ProviderError, PruneCheckpointReader, ReceiptProvider,
-    ReceiptProviderIdExt, StageCheckpointReader, StateProviderBox, StateProviderFactory,
-    StaticFileProviderFactory, TransactionVariant, TransactionsProvider, TreeViewer,
-    WithdrawalsProvider,
+    AccountReader, AddressTransactionsReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, BlockSource, BlockchainTreePendingStateProvider,
+    CanonStateNotifications, CanonStateSubscriptions, ChainSpecProvider, ChainStateBlockReader,
+    ChangeSetReader, ColdStateStats, DatabaseProviderFactory, EvmEnvProvider,
+    FullExecutionDataProvider, HeaderProvider, LogIndexReader, NodePrimitivesProvider,
+    ProviderError, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
+    StageCheckpointReader, StateProviderBox, StateProviderFactory, StaticFileProviderFactory,
+    TouchEpochReader, TransactionSelectorReader, TransactionVariant, TransactionsProvider,
+    TreeViewer, WithdrawalHistoryReader, WithdrawalsProvider,
 };
 use alloy_consensus::Header;
 use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
     BlockHashOrNumber, BlockId, BlockNumHash, BlockNumberOrTag,
 };
-use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256};
+use alloy_primitives::{Address, BlockHash, BlockNumber, Selector, TxHash, TxNumber, B256, U256};
 use alloy_rpc_types_engine::ForkchoiceState;
 use reth_blockchain_tree_api::{
     error::{CanonicalError, InsertBlockError},
@@ -57,7 +58,9 @@ pub use static_file::{
 
 mod state;
 pub use state::{
-    historical::{HistoricalStateProvider, HistoricalStateProviderRef},
+    historical::{
+        HistoricalStateProvider, HistoricalStateProviderRef, DEFAULT_MAX_UNINDEXED_BLOCKS,
+    },
     latest::{LatestStateProvider, LatestStateProviderRef},
 };
 
@@ -968,6 +971,93 @@ impl<N: TreeNodeTypes> ForkChoiceSubscriptions for BlockchainProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> TransactionSelectorReader for BlockchainProvider<N> {
+    fn transactions_by_selector(
+        &self,
+        selector: Selector,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        self.database.provider()?.transactions_by_selector(selector, block_range, limit)
+    }
+}
+
+impl<N: ProviderNodeTypes> AddressTransactionsReader for BlockchainProvider<N> {
+    fn transactions_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+        reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        self.database.provider()?.transactions_by_address(address, block_range, limit, reverse)
+    }
+}
+
+impl<N: ProviderNodeTypes> WithdrawalHistoryReader for BlockchainProvider<N> {
+    fn withdrawals_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.database.provider()?.withdrawals_by_address(address, block_range, limit)
+    }
+
+    fn withdrawals_by_validator(
+        &self,
+        validator_index: u64,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        self.database.provider()?.withdrawals_by_validator(validator_index, block_range, limit)
+    }
+}
+
+impl<N: ProviderNodeTypes> LogIndexReader for BlockchainProvider<N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        self.database.provider()?.log_index_range()
+    }
+
+    fn blocks_with_address_logs(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.database.provider()?.blocks_with_address_logs(address, block_range)
+    }
+
+    fn blocks_with_topic_logs(
+        &self,
+        topic: B256,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        self.database.provider()?.blocks_with_topic_logs(topic, block_range)
+    }
+}
+
+impl<N: ProviderNodeTypes> TouchEpochReader for BlockchainProvider<N> {
+    fn touch_epoch_length(&self) -> Option<u64> {
+        self.database.touch_epoch_length()
+    }
+
+    fn account_last_touch_epoch(&self, address: Address) -> ProviderResult<Option<u64>> {
+        self.database.account_last_touch_epoch(address)
+    }
+
+    fn storage_last_touch_epoch(
+        &self,
+        address: Address,
+        storage_key: B256,
+    ) -> ProviderResult<Option<u64>> {
+        self.database.storage_last_touch_epoch(address, storage_key)
+    }
+
+    fn cold_state_stats(&self, epoch: u64) -> ProviderResult<ColdStateStats> {
+        self.database.cold_state_stats(epoch)
+    }
+}
+
 impl<N: ProviderNodeTypes> ChangeSetReader for BlockchainProvider<N> {
     fn account_block_changeset(
         &self,
@@ -10,27 +10,27 @@ use alloy_primitives::{
 use reth_db::{tables, BlockNumberList};
 use reth_db_api::{
     cursor::{DbCursorRO, DbDupCursorRO},
-    models::{storage_sharded_key::StorageShardedKey, ShardedKey},
+    models::{
+        storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress, ShardedKey,
+    },
     table::Table,
     transaction::DbTx,
 };
-use reth_primitives::{Account, Bytecode};
+use reth_primitives::{Account, Bytecode, StorageEntry};
 use reth_storage_api::{
-    BlockNumReader, DBProvider, StateCommitmentProvider, StateProofProvider, StorageRootProvider,
+    BlockNumReader, DBProvider, StateCommitmentProvider, StateProofOf, StateProofProvider,
+    StateRootOf, StateWitnessOf, StorageProofOf, StorageRootOf, StorageRootProvider,
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{
-    proof::{Proof, StorageProof},
-    updates::TrieUpdates,
-    witness::TrieWitness,
-    AccountProof, HashedPostState, HashedStorage, MultiProof, StateRoot, StorageMultiProof,
-    StorageRoot, TrieInput,
+    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof,
+    StorageMultiProof, TrieInput,
 };
 use reth_trie_db::{
     DatabaseHashedPostState, DatabaseHashedStorage, DatabaseProof, DatabaseStateRoot,
     DatabaseStorageProof, DatabaseStorageRoot, DatabaseTrieWitness, StateCommitment,
 };
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::RangeInclusive};
 
 /// State provider for a given block number which takes a tx reference.
 ///
@@ -43,6 +43,15 @@ use std::fmt::Debug;
 /// - [`tables::StoragesHistory`]
 /// - [`tables::AccountChangeSets`]
 /// - [`tables::StorageChangeSets`]
+///
+/// If the history indices lag behind the changesets, the changesets of the blocks that are not
+/// indexed yet are applied lazily, only for the accounts and storage slots that are accessed.
+///
+/// Below the lowest available blocks, e.g. on full nodes, the changesets are pruned and the state
+/// can't be reconstructed, so [`ProviderError::StateAtBlockPruned`] is returned.
+///
+/// The history of [`PruneModes::retained_addresses`](reth_prune_types::PruneModes) is never
+/// pruned, so their accounts and storage slots are served below the lowest available blocks.
 #[derive(Debug)]
 pub struct HistoricalStateProviderRef<'b, Provider> {
     /// Database provider
@@ -51,6 +60,8 @@ pub struct HistoricalStateProviderRef<'b, Provider> {
     block_number: BlockNumber,
     /// Lowest blocks at which different parts of the state are available.
     lowest_available_blocks: LowestAvailableBlocks,
+    /// Highest blocks whose changesets are indexed.
+    highest_indexed_blocks: HighestIndexedBlocks,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -66,7 +77,12 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
 {
     /// Create new `StateProvider` for historical block number
     pub fn new(provider: &'b Provider, block_number: BlockNumber) -> Self {
-        Self { provider, block_number, lowest_available_blocks: Default::default() }
+        Self {
+            provider,
+            block_number,
+            lowest_available_blocks: Default::default(),
+            highest_indexed_blocks: Default::default(),
+        }
     }
 
     /// Create new `StateProvider` for historical block number and lowest block numbers at which
@@ -76,21 +92,37 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
         block_number: BlockNumber,
         lowest_available_blocks: LowestAvailableBlocks,
     ) -> Self {
-        Self { provider, block_number, lowest_available_blocks }
+        Self {
+            provider,
+            block_number,
+            lowest_available_blocks,
+            highest_indexed_blocks: HighestIndexedBlocks::new(),
+        }
+    }
+
+    /// Returns `true` if the changesets and history of the address are never pruned.
+    fn is_retained(&self, address: &Address) -> bool {
+        self.provider.prune_modes_ref().is_retained(address)
     }
 
     /// Lookup an account in the `AccountsHistory` table
     pub fn account_history_lookup(&self, address: Address) -> ProviderResult<HistoryInfo> {
-        if !self.lowest_available_blocks.is_account_history_available(self.block_number) {
+        // the history of retained addresses is complete, regardless of the prune checkpoint
+        let lowest_available_block_number = if self.is_retained(&address) {
+            None
+        } else if !self.lowest_available_blocks.is_account_history_available(self.block_number) {
             return Err(ProviderError::StateAtBlockPruned(self.block_number))
-        }
+        } else {
+            self.lowest_available_blocks.account_history_block_number
+        };
 
         // history key to search IntegerList of block number changesets.
         let history_key = ShardedKey::new(address, self.block_number);
         self.history_info::<tables::AccountsHistory, _>(
             history_key,
             |key| key.key == address,
-            self.lowest_available_blocks.account_history_block_number,
+            lowest_available_block_number,
+            self.highest_indexed_blocks.account_history_block_number,
         )
     }
 
@@ -100,19 +132,92 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
         address: Address,
         storage_key: StorageKey,
     ) -> ProviderResult<HistoryInfo> {
-        if !self.lowest_available_blocks.is_storage_history_available(self.block_number) {
+        // the history of retained addresses is complete, regardless of the prune checkpoint
+        let lowest_available_block_number = if self.is_retained(&address) {
+            None
+        } else if !self.lowest_available_blocks.is_storage_history_available(self.block_number) {
             return Err(ProviderError::StateAtBlockPruned(self.block_number))
-        }
+        } else {
+            self.lowest_available_blocks.storage_history_block_number
+        };
 
         // history key to search IntegerList of block number changesets.
         let history_key = StorageShardedKey::new(address, storage_key, self.block_number);
         self.history_info::<tables::StoragesHistory, _>(
             history_key,
             |key| key.address == address && key.sharded_key.key == storage_key,
-            self.lowest_available_blocks.storage_history_block_number,
+            lowest_available_block_number,
+            self.highest_indexed_blocks.storage_history_block_number,
         )
     }
 
+    /// Returns the first change of the account in the given blocks, that are not indexed in the
+    /// [`tables::AccountsHistory`] table.
+    ///
+    /// The changesets of all blocks are read in a single walk, instead of a seek per block.
+    fn unindexed_account_change(
+        &self,
+        address: Address,
+        blocks: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Option<AccountBeforeTx>> {
+        let mut cursor = self.tx().cursor_dup_read::<tables::AccountChangeSets>()?;
+        for entry in cursor.walk_range(blocks)? {
+            let (_, entry) = entry?;
+            if entry.address == address {
+                return Ok(Some(entry))
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the first change of the storage slot in the given blocks, that are not indexed in
+    /// the [`tables::StoragesHistory`] table.
+    ///
+    /// The changesets of all blocks are read in a single walk, instead of a seek per block.
+    fn unindexed_storage_change(
+        &self,
+        address: Address,
+        storage_key: StorageKey,
+        blocks: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Option<StorageEntry>> {
+        let mut cursor = self.tx().cursor_dup_read::<tables::StorageChangeSets>()?;
+        for entry in cursor.walk_range(BlockNumberAddress::range(blocks))? {
+            let (key, entry) = entry?;
+            if key.address() == address && entry.key == storage_key {
+                return Ok(Some(entry))
+            }
+        }
+        Ok(None)
+    }
+
+    /// Returns the blocks starting at the provider block whose changesets in table `T` are not
+    /// indexed, given the highest indexed block.
+    ///
+    /// Returns `None` if all changesets are indexed.
+    fn unindexed_blocks<T: Table>(
+        &self,
+        highest_indexed_block: Option<BlockNumber>,
+        key_block_number: impl Fn(T::Key) -> BlockNumber,
+    ) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        let Some(highest_indexed_block) = highest_indexed_block else { return Ok(None) };
+        let Some(highest_changeset_block) =
+            self.tx().cursor_read::<T>()?.last()?.map(|(key, _)| key_block_number(key))
+        else {
+            return Ok(None)
+        };
+        if highest_changeset_block <= highest_indexed_block {
+            return Ok(None)
+        }
+
+        if highest_changeset_block - highest_indexed_block >
+            self.highest_indexed_blocks.max_unindexed_blocks
+        {
+            return Err(ProviderError::StateAtBlockNotIndexed(self.block_number))
+        }
+
+        Ok(Some(self.block_number.max(highest_indexed_block + 1)..=highest_changeset_block))
+    }
+
     /// Checks and returns `true` if distance to historical block exceeds the provided limit.
     fn check_distance_against_limit(&self, limit: u64) -> ProviderResult<bool> {
         let tip = self.provider.last_block_number()?;
@@ -143,7 +248,9 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
 
     /// Retrieve revert hashed storage for this history provider and target address.
     fn revert_storage(&self, address: Address) -> ProviderResult<HashedStorage> {
-        if !self.lowest_available_blocks.is_storage_history_available(self.block_number) {
+        if !self.is_retained(&address) &&
+            !self.lowest_available_blocks.is_storage_history_available(self.block_number)
+        {
             return Err(ProviderError::StateAtBlockPruned(self.block_number))
         }
 
@@ -163,6 +270,7 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
         key: K,
         key_filter: impl Fn(&K) -> bool,
         lowest_available_block_number: Option<BlockNumber>,
+        highest_indexed_block_number: Option<BlockNumber>,
     ) -> ProviderResult<HistoryInfo>
     where
         T: Table<Key = K, Value = BlockNumberList>,
@@ -194,10 +302,12 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
                 block_number != Some(self.block_number) &&
                 !cursor.prev()?.is_some_and(|(key, _)| key_filter(&key))
             {
-                if let (Some(_), Some(block_number)) = (lowest_available_block_number, block_number)
-                {
+                let may_be_written = lowest_available_block_number.is_some() ||
+                    highest_indexed_block_number.is_some();
+                if let (true, Some(block_number)) = (may_be_written, block_number) {
                     // The key may have been written, but due to pruning we may not have changesets
-                    // and history, so we need to make a changeset lookup.
+                    // and history, or the write may not be indexed yet, so we need to make a
+                    // changeset lookup.
                     Ok(HistoryInfo::InChangeset(block_number))
                 } else {
                     // The key is written to, but only after our block.
@@ -238,12 +348,52 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
         self.lowest_available_blocks.storage_history_block_number = Some(block_number);
         self
     }
+
+    /// Set the highest block number whose account changesets are indexed.
+    pub const fn with_highest_indexed_account_history_block_number(
+        mut self,
+        block_number: BlockNumber,
+    ) -> Self {
+        self.highest_indexed_blocks.account_history_block_number = Some(block_number);
+        self
+    }
+
+    /// Set the highest block number whose storage changesets are indexed.
+    pub const fn with_highest_indexed_storage_history_block_number(
+        mut self,
+        block_number: BlockNumber,
+    ) -> Self {
+        self.highest_indexed_blocks.storage_history_block_number = Some(block_number);
+        self
+    }
+
+    /// Set the maximum number of blocks with changesets that are not indexed, above which the
+    /// historical state is not served.
+    pub const fn with_max_unindexed_blocks(mut self, max_unindexed_blocks: u64) -> Self {
+        self.highest_indexed_blocks.max_unindexed_blocks = max_unindexed_blocks;
+        self
+    }
 }
 
 impl<Provider: DBProvider + BlockNumReader> HistoricalStateProviderRef<'_, Provider> {
     fn tx(&self) -> &Provider::Tx {
         self.provider.tx_ref()
     }
+
+    /// Returns the value of the storage slot in the plain state.
+    fn plain_storage(
+        &self,
+        address: Address,
+        storage_key: StorageKey,
+    ) -> ProviderResult<Option<StorageValue>> {
+        Ok(self
+            .tx()
+            .cursor_dup_read::<tables::PlainStorageState>()?
+            .seek_by_key_subkey(address, storage_key)?
+            .filter(|entry| entry.key == storage_key)
+            .map(|entry| entry.value)
+            .or(Some(StorageValue::ZERO)))
+    }
 }
 
 impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> AccountReader
@@ -251,7 +401,21 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> AccountRea
 {
     /// Get basic account information.
     fn basic_account(&self, address: Address) -> ProviderResult<Option<Account>> {
-        match self.account_history_lookup(address)? {
+        let history_info = self.account_history_lookup(address)?;
+        if !matches!(history_info, HistoryInfo::InChangeset(_)) {
+            // the account may have been changed by a block that is not indexed yet
+            if let Some(blocks) = self.unindexed_blocks::<tables::AccountChangeSets>(
+                self.highest_indexed_blocks.account_history_block_number,
+                |block_number| block_number,
+            )? {
+                return match self.unindexed_account_change(address, blocks)? {
+                    Some(entry) => Ok(entry.info),
+                    None => Ok(self.tx().get::<tables::PlainAccountState>(address)?),
+                }
+            }
+        }
+
+        match history_info {
             HistoryInfo::NotYetWritten => Ok(None),
             HistoryInfo::InChangeset(changeset_block_number) => Ok(self
                 .tx()
@@ -293,13 +457,13 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateRootP
     fn state_root(&self, hashed_state: HashedPostState) -> ProviderResult<B256> {
         let mut revert_state = self.revert_state()?;
         revert_state.extend(hashed_state);
-        StateRoot::overlay_root(self.tx(), revert_state)
+        StateRootOf::<'_, Provider>::overlay_root(self.tx(), revert_state)
             .map_err(|err| ProviderError::Database(err.into()))
     }
 
     fn state_root_from_nodes(&self, mut input: TrieInput) -> ProviderResult<B256> {
         input.prepend(self.revert_state()?);
-        StateRoot::overlay_root_from_nodes(self.tx(), input)
+        StateRootOf::<'_, Provider>::overlay_root_from_nodes(self.tx(), input)
             .map_err(|err| ProviderError::Database(err.into()))
     }
 
@@ -309,7 +473,7 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateRootP
     ) -> ProviderResult<(B256, TrieUpdates)> {
         let mut revert_state = self.revert_state()?;
         revert_state.extend(hashed_state);
-        StateRoot::overlay_root_with_updates(self.tx(), revert_state)
+        StateRootOf::<'_, Provider>::overlay_root_with_updates(self.tx(), revert_state)
             .map_err(|err| ProviderError::Database(err.into()))
     }
 
@@ -318,7 +482,7 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateRootP
         mut input: TrieInput,
     ) -> ProviderResult<(B256, TrieUpdates)> {
         input.prepend(self.revert_state()?);
-        StateRoot::overlay_root_from_nodes_with_updates(self.tx(), input)
+        StateRootOf::<'_, Provider>::overlay_root_from_nodes_with_updates(self.tx(), input)
             .map_err(|err| ProviderError::Database(err.into()))
     }
 }
@@ -333,7 +497,7 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StorageRoo
     ) -> ProviderResult<B256> {
         let mut revert_storage = self.revert_storage(address)?;
         revert_storage.extend(&hashed_storage);
-        StorageRoot::overlay_root(self.tx(), address, revert_storage)
+        StorageRootOf::<'_, Provider>::overlay_root(self.tx(), address, revert_storage)
             .map_err(|err| ProviderError::Database(err.into()))
     }
 
@@ -345,8 +509,13 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StorageRoo
     ) -> ProviderResult<reth_trie::StorageProof> {
         let mut revert_storage = self.revert_storage(address)?;
         revert_storage.extend(&hashed_storage);
-        StorageProof::overlay_storage_proof(self.tx(), address, slot, revert_storage)
-            .map_err(Into::<ProviderError>::into)
+        StorageProofOf::<'_, Provider>::overlay_storage_proof(
+            self.tx(),
+            address,
+            slot,
+            revert_storage,
+        )
+        .map_err(Into::<ProviderError>::into)
     }
 
     fn storage_multiproof(
@@ -357,8 +526,13 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StorageRoo
     ) -> ProviderResult<StorageMultiProof> {
         let mut revert_storage = self.revert_storage(address)?;
         revert_storage.extend(&hashed_storage);
-        StorageProof::overlay_storage_multiproof(self.tx(), address, slots, revert_storage)
-            .map_err(Into::<ProviderError>::into)
+        StorageProofOf::<'_, Provider>::overlay_storage_multiproof(
+            self.tx(),
+            address,
+            slots,
+            revert_storage,
+        )
+        .map_err(Into::<ProviderError>::into)
     }
 }
 
@@ -373,7 +547,7 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateProof
         slots: &[B256],
     ) -> ProviderResult<AccountProof> {
         input.prepend(self.revert_state()?);
-        Proof::overlay_account_proof(self.tx(), input, address, slots)
+        StateProofOf::<'_, Provider>::overlay_account_proof(self.tx(), input, address, slots)
             .map_err(Into::<ProviderError>::into)
     }
 
@@ -383,7 +557,8 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateProof
         targets: HashMap<B256, HashSet<B256>>,
     ) -> ProviderResult<MultiProof> {
         input.prepend(self.revert_state()?);
-        Proof::overlay_multiproof(self.tx(), input, targets).map_err(Into::<ProviderError>::into)
+        StateProofOf::<'_, Provider>::overlay_multiproof(self.tx(), input, targets)
+            .map_err(Into::<ProviderError>::into)
     }
 
     fn witness(
@@ -392,7 +567,8 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider> StateProof
         target: HashedPostState,
     ) -> ProviderResult<HashMap<B256, Bytes>> {
         input.prepend(self.revert_state()?);
-        TrieWitness::overlay_witness(self.tx(), input, target).map_err(Into::<ProviderError>::into)
+        StateWitnessOf::<'_, Provider>::overlay_witness(self.tx(), input, target)
+            .map_err(Into::<ProviderError>::into)
     }
 }
 
@@ -415,7 +591,21 @@ impl<Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentPr
         address: Address,
         storage_key: StorageKey,
     ) -> ProviderResult<Option<StorageValue>> {
-        match self.storage_history_lookup(address, storage_key)? {
+        let history_info = self.storage_history_lookup(address, storage_key)?;
+        if !matches!(history_info, HistoryInfo::InChangeset(_)) {
+            // the storage slot may have been changed by a block that is not indexed yet
+            if let Some(blocks) = self.unindexed_blocks::<tables::StorageChangeSets>(
+                self.highest_indexed_blocks.storage_history_block_number,
+                |key| key.block_number(),
+            )? {
+                if let Some(entry) = self.unindexed_storage_change(address, storage_key, blocks)? {
+                    return Ok(Some(entry.value))
+                }
+                return self.plain_storage(address, storage_key)
+            }
+        }
+
+        match history_info {
             HistoryInfo::NotYetWritten => Ok(None),
             HistoryInfo::InChangeset(changeset_block_number) => Ok(Some(
                 self.tx()
@@ -429,13 +619,9 @@ impl<Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentPr
                     })?
                     .value,
             )),
-            HistoryInfo::InPlainState | HistoryInfo::MaybeInPlainState => Ok(self
-                .tx()
-                .cursor_dup_read::<tables::PlainStorageState>()?
-                .seek_by_key_subkey(address, storage_key)?
-                .filter(|entry| entry.key == storage_key)
-                .map(|entry| entry.value)
-                .or(Some(StorageValue::ZERO))),
+            HistoryInfo::InPlainState | HistoryInfo::MaybeInPlainState => {
+                self.plain_storage(address, storage_key)
+            }
         }
     }
 
@@ -461,6 +647,8 @@ pub struct HistoricalStateProvider<Provider> {
     block_number: BlockNumber,
     /// Lowest blocks at which different parts of the state are available.
     lowest_available_blocks: LowestAvailableBlocks,
+    /// Highest blocks whose changesets are indexed.
+    highest_indexed_blocks: HighestIndexedBlocks,
 }
 
 impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
@@ -468,7 +656,12 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
 {
     /// Create new `StateProvider` for historical block number
     pub fn new(provider: Provider, block_number: BlockNumber) -> Self {
-        Self { provider, block_number, lowest_available_blocks: Default::default() }
+        Self {
+            provider,
+            block_number,
+            lowest_available_blocks: Default::default(),
+            highest_indexed_blocks: Default::default(),
+        }
     }
 
     /// Set the lowest block number at which the account history is available.
@@ -489,14 +682,44 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
         self
     }
 
+    /// Set the highest block number whose account changesets are indexed.
+    ///
+    /// The changesets of later blocks are applied lazily for the accessed accounts.
+    pub const fn with_highest_indexed_account_history_block_number(
+        mut self,
+        block_number: BlockNumber,
+    ) -> Self {
+        self.highest_indexed_blocks.account_history_block_number = Some(block_number);
+        self
+    }
+
+    /// Set the highest block number whose storage changesets are indexed.
+    ///
+    /// The changesets of later blocks are applied lazily for the accessed storage slots.
+    pub const fn with_highest_indexed_storage_history_block_number(
+        mut self,
+        block_number: BlockNumber,
+    ) -> Self {
+        self.highest_indexed_blocks.storage_history_block_number = Some(block_number);
+        self
+    }
+
+    /// Set the maximum number of blocks with changesets that are not indexed, above which the
+    /// historical state is not served.
+    pub const fn with_max_unindexed_blocks(mut self, max_unindexed_blocks: u64) -> Self {
+        self.highest_indexed_blocks.max_unindexed_blocks = max_unindexed_blocks;
+        self
+    }
+
     /// Returns a new provider that takes the `TX` as reference
     #[inline(always)]
     const fn as_ref(&self) -> HistoricalStateProviderRef<'_, Provider> {
-        HistoricalStateProviderRef::new_with_lowest_available_blocks(
-            &self.provider,
-            self.block_number,
-            self.lowest_available_blocks,
-        )
+        HistoricalStateProviderRef {
+            provider: &self.provider,
+            block_number: self.block_number,
+            lowest_available_blocks: self.lowest_available_blocks,
+            highest_indexed_blocks: self.highest_indexed_blocks,
+        }
     }
 }
 
@@ -511,6 +734,9 @@ delegate_provider_impls!(HistoricalStateProvider<Provider> where [Provider: DBPr
 
 /// Lowest blocks at which different parts of the state are available.
 /// They may be [Some] if pruning is enabled.
+///
+/// They don't apply to [`PruneModes::retained_addresses`](reth_prune_types::PruneModes), whose
+/// history is always available.
 #[derive(Clone, Copy, Debug, Default)]
 pub struct LowestAvailableBlocks {
     /// Lowest block number at which the account history is available. It may not be available if
@@ -537,6 +763,45 @@ impl LowestAvailableBlocks {
     }
 }
 
+/// The default maximum number of blocks with changesets that are not indexed, see
+/// [`HighestIndexedBlocks::max_unindexed_blocks`].
+pub const DEFAULT_MAX_UNINDEXED_BLOCKS: u64 = 10_000;
+
+/// Highest blocks whose changesets are indexed in the history tables.
+/// They may be [Some] if the history indices lag behind the changesets, e.g. for blocks that were
+/// executed by the pipeline but not indexed yet.
+#[derive(Clone, Copy, Debug)]
+pub struct HighestIndexedBlocks {
+    /// Highest block number whose account changesets are indexed in
+    /// [`tables::AccountsHistory`]. [`Option::None`] means all account changesets are indexed.
+    pub account_history_block_number: Option<BlockNumber>,
+    /// Highest block number whose storage changesets are indexed in
+    /// [`tables::StoragesHistory`]. [`Option::None`] means all storage changesets are indexed.
+    pub storage_history_block_number: Option<BlockNumber>,
+    /// Maximum number of blocks with changesets that are not indexed.
+    ///
+    /// Every lookup walks the changesets of these blocks, so the historical state is not served
+    /// if there are more.
+    pub max_unindexed_blocks: u64,
+}
+
+impl HighestIndexedBlocks {
+    /// Creates a new instance, with all changesets indexed.
+    pub const fn new() -> Self {
+        Self {
+            account_history_block_number: None,
+            storage_history_block_number: None,
+            max_unindexed_blocks: DEFAULT_MAX_UNINDEXED_BLOCKS,
+        }
+    }
+}
+
+impl Default for HighestIndexedBlocks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -676,6 +941,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn history_provider_unindexed_changesets() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let acc_plain = Account { nonce: 100, balance: U256::ZERO, bytecode_hash: None };
+        let acc_at8 = Account { nonce: 8, balance: U256::ZERO, bytecode_hash: None };
+        let acc_at3 = Account { nonce: 3, balance: U256::ZERO, bytecode_hash: None };
+        let entry_plain = StorageEntry { key: STORAGE, value: U256::from(100) };
+        let entry_at8 = StorageEntry { key: STORAGE, value: U256::from(8) };
+
+        // only the changesets up to block 5 are indexed
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey { key: ADDRESS, highest_block_number: u64::MAX },
+            BlockNumberList::new([3]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            3,
+            AccountBeforeTx { address: ADDRESS, info: Some(acc_at3) },
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            8,
+            AccountBeforeTx { address: ADDRESS, info: Some(acc_at8) },
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            8,
+            AccountBeforeTx { address: HIGHER_ADDRESS, info: None },
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSets>((8, ADDRESS).into(), entry_at8).unwrap();
+
+        tx.put::<tables::PlainAccountState>(ADDRESS, acc_plain).unwrap();
+        tx.put::<tables::PlainAccountState>(HIGHER_ADDRESS, acc_plain).unwrap();
+        tx.put::<tables::PlainStorageState>(ADDRESS, entry_plain).unwrap();
+        tx.commit().unwrap();
+
+        let db = factory.provider().unwrap();
+        let provider = |block_number| {
+            HistoricalStateProviderRef::new(&db, block_number)
+                .with_highest_indexed_account_history_block_number(5)
+                .with_highest_indexed_storage_history_block_number(5)
+        };
+
+        // indexed change
+        assert_eq!(provider(2).basic_account(ADDRESS), Ok(Some(acc_at3)));
+        // changes that are not indexed
+        assert_eq!(provider(4).basic_account(ADDRESS), Ok(Some(acc_at8)));
+        assert_eq!(provider(7).basic_account(HIGHER_ADDRESS), Ok(None));
+        assert_eq!(provider(4).storage(ADDRESS, STORAGE), Ok(Some(entry_at8.value)));
+        // no changes after the block
+        assert_eq!(provider(9).basic_account(ADDRESS), Ok(Some(acc_plain)));
+        assert_eq!(provider(9).basic_account(HIGHER_ADDRESS), Ok(Some(acc_plain)));
+        assert_eq!(provider(9).storage(ADDRESS, STORAGE), Ok(Some(entry_plain.value)));
+
+        // too many changesets that are not indexed
+        assert_eq!(
+            provider(4).with_max_unindexed_blocks(2).basic_account(ADDRESS),
+            Err(ProviderError::StateAtBlockNotIndexed(4))
+        );
+    }
+
     #[test]
     fn history_provider_get_storage() {
         let factory = create_test_provider_factory();
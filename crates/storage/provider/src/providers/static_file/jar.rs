@@ -216,17 +216,43 @@ impl<N: NodePrimitives> BlockNumReader for StaticFileJarProvider<'_, N> {
     }
 }
 
+impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction>> StaticFileJarProvider<'_, N> {
+    /// Returns the transaction with the given hash and its number, looked up in the transaction
+    /// hash index of the static file.
+    ///
+    /// Returns `None` if the static file has no transaction hash index.
+    pub fn transaction_by_hash_with_number(
+        &self,
+        hash: TxHash,
+    ) -> ProviderResult<Option<(TxNumber, N::SignedTx)>> {
+        let (Some(hash_index), Some(tx_start)) = (self.hash_index(), self.user_header().tx_start())
+        else {
+            return Ok(None)
+        };
+
+        let mut cursor = self.cursor()?;
+        for row in hash_index.find(hash.as_slice()) {
+            let number = tx_start + row as u64;
+            if let Some(transaction) =
+                cursor.get_one::<TransactionMask<N::SignedTx>>(number.into())?
+            {
+                // Only a prefix of the hash is indexed
+                if transaction.trie_hash() == hash {
+                    return Ok(Some((number, transaction)))
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
 impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction>> TransactionsProvider
     for StaticFileJarProvider<'_, N>
 {
     type Transaction = N::SignedTx;
 
     fn transaction_id(&self, hash: TxHash) -> ProviderResult<Option<TxNumber>> {
-        let mut cursor = self.cursor()?;
-
-        Ok(cursor
-            .get_one::<TransactionMask<Self::Transaction>>((&hash).into())?
-            .and_then(|res| (res.trie_hash() == hash).then(|| cursor.number()).flatten()))
+        Ok(self.transaction_by_hash_with_number(hash)?.map(|(number, _)| number))
     }
 
     fn transaction_by_id(&self, num: TxNumber) -> ProviderResult<Option<Self::Transaction>> {
@@ -241,7 +267,7 @@ impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction>> TransactionsPr
     }
 
     fn transaction_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Transaction>> {
-        self.cursor()?.get_one::<TransactionMask<Self::Transaction>>((&hash).into())
+        Ok(self.transaction_by_hash_with_number(hash)?.map(|(_, transaction)| transaction))
     }
 
     fn transaction_by_hash_with_meta(
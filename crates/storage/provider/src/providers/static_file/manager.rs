@@ -30,7 +30,7 @@ use reth_db::{
 use reth_db_api::{
     cursor::DbCursorRO, models::StoredBlockBodyIndices, table::Table, transaction::DbTx,
 };
-use reth_nippy_jar::{NippyJar, NippyJarChecker, CONFIG_FILE_EXTENSION};
+use reth_nippy_jar::{HashIndexBuilder, NippyJar, NippyJarChecker, CONFIG_FILE_EXTENSION};
 use reth_node_types::{FullNodePrimitives, NodePrimitives};
 use reth_primitives::{
     static_file::{
@@ -52,6 +52,7 @@ use std::{
     ops::{Deref, Range, RangeBounds, RangeInclusive},
     path::{Path, PathBuf},
     sync::{mpsc, Arc},
+    time::Instant,
 };
 use strum::IntoEnumIterator;
 use tracing::{info, trace, warn};
@@ -1182,6 +1183,85 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
     }
 }
 
+impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction>> StaticFileProvider<N> {
+    /// Builds the transaction hash index of every finalized transaction static file up to the one
+    /// containing `to_block`, if it doesn't have one yet.
+    ///
+    /// Returns the highest block, capped at `to_block`, up to which all transactions can be looked
+    /// up by hash in static files, or `None` if there's no finalized transaction static file.
+    pub fn ensure_transaction_hash_indexes(
+        &self,
+        to_block: BlockNumber,
+    ) -> ProviderResult<Option<BlockNumber>> {
+        let segment = StaticFileSegment::Transactions;
+        let Some(highest_block) = self.get_highest_static_file_block(segment) else {
+            return Ok(None)
+        };
+
+        let mut indexed_block = None;
+        let mut range = self.find_fixed_range(0);
+        // The highest static file is still being appended to, so it can't be indexed yet
+        while range.end() < highest_block && range.start() <= to_block {
+            if self.get_or_create_jar_provider(segment, &range)?.hash_index().is_none() {
+                self.build_transaction_hash_index(&range)?;
+            }
+
+            indexed_block = Some(range.end().min(to_block));
+            range = SegmentRangeInclusive::new(
+                range.start() + self.blocks_per_file,
+                range.end() + self.blocks_per_file,
+            );
+        }
+
+        Ok(indexed_block)
+    }
+
+    /// Builds the transaction hash index of the transaction static file with the given block
+    /// range, and reloads its cached provider.
+    fn build_transaction_hash_index(
+        &self,
+        fixed_block_range: &SegmentRangeInclusive,
+    ) -> ProviderResult<()> {
+        let segment = StaticFileSegment::Transactions;
+        let start = Instant::now();
+
+        let jar = {
+            let jar_provider = self.get_or_create_jar_provider(segment, fixed_block_range)?;
+
+            let mut builder = HashIndexBuilder::default();
+            if let Some(tx_range) = jar_provider.user_header().tx_range().copied() {
+                let mut cursor = jar_provider.cursor()?;
+                for number in tx_range.start()..=tx_range.end() {
+                    let transaction = cursor
+                        .get_one::<TransactionMask<N::SignedTx>>(number.into())?
+                        .ok_or(ProviderError::MissingStaticFileTx(segment, number))?;
+                    builder
+                        .push(transaction.trie_hash().as_slice())
+                        .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+                }
+            }
+            jar_provider
+                .write_hash_index(builder)
+                .map_err(|e| ProviderError::NippyJar(e.to_string()))?;
+
+            NippyJar::<SegmentHeader>::load(jar_provider.data_path())
+                .map_err(|e| ProviderError::NippyJar(e.to_string()))?
+        };
+
+        // The cached provider has to be dropped before, or it will deadlock.
+        self.map.insert((fixed_block_range.end(), segment), LoadedJar::new(jar)?);
+
+        info!(
+            target: "provider::static_file",
+            ?fixed_block_range,
+            elapsed = ?start.elapsed(),
+            "Built transaction hash index"
+        );
+
+        Ok(())
+    }
+}
+
 /// Helper trait to manage different [`StaticFileProviderRW`] of an `Arc<StaticFileProvider`
 pub trait StaticFileWriter {
     /// The primitives type used by the static file provider.
@@ -1463,16 +1543,7 @@ impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction>> TransactionsPr
 
     fn transaction_id(&self, tx_hash: TxHash) -> ProviderResult<Option<TxNumber>> {
         self.find_static_file(StaticFileSegment::Transactions, |jar_provider| {
-            let mut cursor = jar_provider.cursor()?;
-            if cursor
-                .get_one::<TransactionMask<Self::Transaction>>((&tx_hash).into())?
-                .and_then(|tx| (tx.trie_hash() == tx_hash).then_some(tx))
-                .is_some()
-            {
-                Ok(cursor.number())
-            } else {
-                Ok(None)
-            }
+            Ok(jar_provider.transaction_by_hash_with_number(tx_hash)?.map(|(number, _)| number))
         })
     }
 
@@ -1506,9 +1577,8 @@ impl<N: NodePrimitives<SignedTx: Decompress + SignedTransaction>> TransactionsPr
     fn transaction_by_hash(&self, hash: TxHash) -> ProviderResult<Option<Self::Transaction>> {
         self.find_static_file(StaticFileSegment::Transactions, |jar_provider| {
             Ok(jar_provider
-                .cursor()?
-                .get_one::<TransactionMask<Self::Transaction>>((&hash).into())?
-                .and_then(|tx| (tx.trie_hash() == hash).then_some(tx)))
+                .transaction_by_hash_with_number(hash)?
+                .map(|(_, transaction)| transaction))
         })
     }
 
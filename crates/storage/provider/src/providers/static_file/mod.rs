@@ -9,7 +9,7 @@ pub use writer::{StaticFileProviderRW, StaticFileProviderRWRefMut};
 
 mod metrics;
 
-use reth_nippy_jar::NippyJar;
+use reth_nippy_jar::{HashIndex, NippyJar};
 use reth_primitives::{static_file::SegmentHeader, StaticFileSegment};
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
 use std::{ops::Deref, sync::Arc};
@@ -22,17 +22,20 @@ type LoadedJarRef<'a> = dashmap::mapref::one::Ref<'a, (u64, StaticFileSegment),
 pub struct LoadedJar {
     jar: NippyJar<SegmentHeader>,
     mmap_handle: Arc<reth_nippy_jar::DataReader>,
+    /// Transaction hash index, only loaded for transaction static files that have one.
+    hash_index: Option<HashIndex>,
 }
 
 impl LoadedJar {
     fn new(jar: NippyJar<SegmentHeader>) -> ProviderResult<Self> {
-        match jar.open_data_reader() {
-            Ok(data_reader) => {
-                let mmap_handle = Arc::new(data_reader);
-                Ok(Self { jar, mmap_handle })
-            }
-            Err(e) => Err(ProviderError::NippyJar(e.to_string())),
-        }
+        let mmap_handle =
+            Arc::new(jar.open_data_reader().map_err(|e| ProviderError::NippyJar(e.to_string()))?);
+        let hash_index = if jar.user_header().segment() == StaticFileSegment::Transactions {
+            jar.open_hash_index().map_err(|e| ProviderError::NippyJar(e.to_string()))?
+        } else {
+            None
+        };
+        Ok(Self { jar, mmap_handle, hash_index })
     }
 
     /// Returns a clone of the mmap handle that can be used to instantiate a cursor.
@@ -40,6 +43,11 @@ impl LoadedJar {
         self.mmap_handle.clone()
     }
 
+    /// Returns the transaction hash index of the jar, if any.
+    const fn hash_index(&self) -> Option<&HashIndex> {
+        self.hash_index.as_ref()
+    }
+
     const fn segment(&self) -> StaticFileSegment {
         self.jar.user_header().segment()
     }
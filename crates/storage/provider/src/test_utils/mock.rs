@@ -1,9 +1,11 @@
 use crate::{
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, DatabaseProvider, EthStorage, EvmEnvProvider,
-    HeaderProvider, ReceiptProviderIdExt, StateProvider, StateProviderBox, StateProviderFactory,
-    StateReader, StateRootProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    AccountReader, AddressTransactionsReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, DatabaseProvider,
+    EthStorage, EvmEnvProvider, HeaderProvider, LogIndexReader, ReceiptProviderIdExt,
+    StateProvider, StateProviderBox, StateProviderFactory, StateReader, StateRootProvider,
+    TransactionSelectorReader, TransactionVariant, TransactionsProvider, WithdrawalHistoryReader,
+    WithdrawalsProvider,
 };
 use alloy_consensus::{constants::EMPTY_ROOT_HASH, Header};
 use alloy_eips::{
@@ -13,7 +15,8 @@ use alloy_eips::{
 use alloy_primitives::{
     keccak256,
     map::{HashMap, HashSet},
-    Address, BlockHash, BlockNumber, Bytes, StorageKey, StorageValue, TxHash, TxNumber, B256, U256,
+    Address, BlockHash, BlockNumber, Bytes, Selector, StorageKey, StorageValue, TxHash, TxNumber,
+    B256, U256,
 };
 use parking_lot::Mutex;
 use reth_chainspec::{ChainInfo, ChainSpec};
@@ -829,6 +832,71 @@ impl ChangeSetReader for MockEthProvider {
     }
 }
 
+impl TransactionSelectorReader for MockEthProvider {
+    fn transactions_by_selector(
+        &self,
+        _selector: Selector,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl AddressTransactionsReader for MockEthProvider {
+    fn transactions_by_address(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+        _reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl WithdrawalHistoryReader for MockEthProvider {
+    fn withdrawals_by_address(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        Ok(Vec::default())
+    }
+
+    fn withdrawals_by_validator(
+        &self,
+        _validator_index: u64,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl LogIndexReader for MockEthProvider {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn blocks_with_address_logs(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+
+    fn blocks_with_topic_logs(
+        &self,
+        _topic: B256,
+        _block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+}
+
 impl StateReader for MockEthProvider {
     type Receipt = Receipt;
 
@@ -11,7 +11,8 @@ use alloy_eips::{
 };
 use alloy_primitives::{
     map::{HashMap, HashSet},
-    Address, BlockHash, BlockNumber, Bytes, StorageKey, StorageValue, TxHash, TxNumber, B256, U256,
+    Address, BlockHash, BlockNumber, Bytes, Selector, StorageKey, StorageValue, TxHash, TxNumber,
+    B256, U256,
 };
 use reth_chain_state::{
     CanonStateNotifications, CanonStateSubscriptions, ForkChoiceNotifications,
@@ -40,11 +41,12 @@ use tokio::sync::{broadcast, watch};
 use crate::{
     providers::StaticFileProvider,
     traits::{BlockSource, ReceiptProvider},
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, EvmEnvProvider, HeaderProvider, PruneCheckpointReader,
-    ReceiptProviderIdExt, StageCheckpointReader, StateProvider, StateProviderBox,
-    StateProviderFactory, StateRootProvider, StaticFileProviderFactory, TransactionVariant,
-    TransactionsProvider, WithdrawalsProvider,
+    AccountReader, AddressTransactionsReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, EvmEnvProvider,
+    HeaderProvider, LogIndexReader, PruneCheckpointReader, ReceiptProviderIdExt,
+    StageCheckpointReader, StateProvider, StateProviderBox, StateProviderFactory,
+    StateRootProvider, StaticFileProviderFactory, TransactionSelectorReader, TransactionVariant,
+    TransactionsProvider, WithdrawalHistoryReader, WithdrawalsProvider,
 };
 
 /// Supports various api interfaces for testing purposes.
@@ -336,6 +338,71 @@ impl ChangeSetReader for NoopProvider {
     }
 }
 
+impl TransactionSelectorReader for NoopProvider {
+    fn transactions_by_selector(
+        &self,
+        _selector: Selector,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl AddressTransactionsReader for NoopProvider {
+    fn transactions_by_address(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+        _reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl WithdrawalHistoryReader for NoopProvider {
+    fn withdrawals_by_address(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        Ok(Vec::default())
+    }
+
+    fn withdrawals_by_validator(
+        &self,
+        _validator_index: u64,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl LogIndexReader for NoopProvider {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn blocks_with_address_logs(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+
+    fn blocks_with_topic_logs(
+        &self,
+        _topic: B256,
+        _block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+}
+
 impl StateRootProvider for NoopProvider {
     fn state_root(&self, _state: HashedPostState) -> ProviderResult<B256> {
         Ok(B256::default())
@@ -1,9 +1,10 @@
 //! Helper provider traits to encapsulate all provider traits for simplicity.
 
 use crate::{
-    AccountReader, BlockReaderIdExt, ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory,
-    EvmEnvProvider, HeaderProvider, StageCheckpointReader, StateProviderFactory,
-    StaticFileProviderFactory, TransactionsProvider,
+    AccountReader, AddressTransactionsReader, BlockReaderIdExt, CanonChainTracker,
+    ChainSpecProvider, ChangeSetReader, DatabaseProviderFactory, EvmEnvProvider, HeaderProvider,
+    LogIndexReader, StageCheckpointReader, StateProviderFactory, StaticFileProviderFactory,
+    TransactionSelectorReader, TransactionsProvider, WithdrawalHistoryReader,
 };
 use reth_chain_state::{CanonStateSubscriptions, ForkChoiceSubscriptions};
 use reth_chainspec::EthereumHardforks;
@@ -25,8 +26,13 @@ pub trait FullProvider<N: NodeTypesWithDB>:
     + EvmEnvProvider
     + ChainSpecProvider<ChainSpec = N::ChainSpec>
     + ChangeSetReader
+    + TransactionSelectorReader
+    + AddressTransactionsReader
+    + WithdrawalHistoryReader
+    + LogIndexReader
     + CanonStateSubscriptions
     + ForkChoiceSubscriptions<Header = HeaderTy<N>>
+    + CanonChainTracker<Header = HeaderTy<N>>
     + StageCheckpointReader
     + Clone
     + Unpin
@@ -48,8 +54,13 @@ impl<T, N: NodeTypesWithDB> FullProvider<N> for T where
         + EvmEnvProvider
         + ChainSpecProvider<ChainSpec = N::ChainSpec>
         + ChangeSetReader
+        + TransactionSelectorReader
+        + AddressTransactionsReader
+        + WithdrawalHistoryReader
+        + LogIndexReader
         + CanonStateSubscriptions
         + ForkChoiceSubscriptions<Header = HeaderTy<N>>
+        + CanonChainTracker<Header = HeaderTy<N>>
         + StageCheckpointReader
         + Clone
         + Unpin
@@ -66,6 +77,10 @@ pub trait FullRpcProvider:
     + BlockReaderIdExt
     + HeaderProvider
     + TransactionsProvider
+    + TransactionSelectorReader
+    + AddressTransactionsReader
+    + WithdrawalHistoryReader
+    + LogIndexReader
     + StageCheckpointReader
     + Clone
     + Unpin
@@ -80,6 +95,10 @@ impl<T> FullRpcProvider for T where
         + BlockReaderIdExt
         + HeaderProvider
         + TransactionsProvider
+        + TransactionSelectorReader
+        + AddressTransactionsReader
+        + WithdrawalHistoryReader
+        + LogIndexReader
         + StageCheckpointReader
         + Clone
         + Unpin
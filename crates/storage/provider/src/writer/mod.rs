@@ -166,6 +166,13 @@ where
                 .unwrap();
             self.database().insert_block(sealed_block, StorageLocation::Both)?;
 
+            // Index the logs before the execution output is consumed, the receipts are not
+            // readable from the static files until they are committed.
+            let first_block = execution_output.first_block;
+            self.database().append_log_index(
+                execution_output.logs(first_block).map(|logs| (first_block, logs)),
+            )?;
+
             // Write state and changesets to the database.
             // Must be written after blocks because of the receipt lookup.
             self.database().write_state(
@@ -0,0 +1,148 @@
+use crate::BlockReader;
+use alloy_primitives::BlockNumber;
+use reth_primitives::SealedBlockWithSenders;
+use reth_storage_errors::provider::ProviderResult;
+use std::{fmt, ops::RangeInclusive};
+
+/// Default number of blocks fetched at once by the reverse-chronological iterators.
+pub const DEFAULT_REV_CHUNK_SIZE: u64 = 100;
+
+/// Extension trait for [`BlockReader`] to stream blocks and receipts newest-to-oldest.
+///
+/// The iterators fetch the requested range in chunks of consecutive blocks, starting at the
+/// highest block. Every chunk is read with a single range query of the provider, so it's served
+/// sequentially from static files and the database instead of one point lookup per block.
+///
+/// Blocks that are not available are skipped.
+pub trait BlockReaderRevExt: BlockReader + Sized {
+    /// Returns an iterator over the sealed blocks with senders in the given range, ordered from
+    /// the highest to the lowest block number.
+    fn blocks_rev(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> RevChunkedIter<'_, Self, SealedBlockWithSenders<Self::Block>> {
+        RevChunkedIter::new(self, range, |provider, range| {
+            provider.sealed_block_with_senders_range(range)
+        })
+    }
+
+    /// Returns an iterator over the receipts of each block in the given range, ordered from the
+    /// highest to the lowest block number.
+    fn receipts_by_block_rev(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> RevChunkedIter<'_, Self, (BlockNumber, Vec<Self::Receipt>)> {
+        RevChunkedIter::new(self, range, receipts_by_block_range)
+    }
+}
+
+impl<T: BlockReader> BlockReaderRevExt for T {}
+
+/// Returns the receipts of each available block in the range, reading all receipts of the range
+/// at once.
+fn receipts_by_block_range<P: BlockReader>(
+    provider: &P,
+    range: RangeInclusive<BlockNumber>,
+) -> ProviderResult<Vec<(BlockNumber, Vec<P::Receipt>)>> {
+    let mut indices = Vec::new();
+    for number in range {
+        if let Some(body) = provider.block_body_indices(number)? {
+            indices.push((number, body));
+        }
+    }
+
+    let (Some((_, first)), Some((_, last))) = (indices.first(), indices.last()) else {
+        return Ok(Vec::new())
+    };
+    let first_tx_num = first.first_tx_num;
+    let mut receipts = provider.receipts_by_tx_range(first_tx_num..last.next_tx_num())?;
+
+    // split from the back, so the receipts can be moved out without shifting
+    let mut result = Vec::with_capacity(indices.len());
+    for (number, body) in indices.into_iter().rev() {
+        let start = ((body.first_tx_num - first_tx_num) as usize).min(receipts.len());
+        let mut block_receipts = receipts.split_off(start);
+        block_receipts.truncate(body.tx_count as usize);
+        result.push((number, block_receipts));
+    }
+    result.reverse();
+
+    Ok(result)
+}
+
+/// Iterator over a block range from the highest to the lowest block, that fetches the items in
+/// chunks of consecutive blocks.
+///
+/// Returned by the methods of [`BlockReaderRevExt`].
+pub struct RevChunkedIter<'a, P, T> {
+    provider: &'a P,
+    /// Lowest block of the range.
+    start: BlockNumber,
+    /// Highest block that was not fetched yet, `None` if the range is exhausted.
+    end: Option<BlockNumber>,
+    chunk_size: u64,
+    fetch: fn(&P, RangeInclusive<BlockNumber>) -> ProviderResult<Vec<T>>,
+    /// Fetched items of the current chunk, in ascending order.
+    buffer: Vec<T>,
+}
+
+impl<'a, P, T> RevChunkedIter<'a, P, T> {
+    fn new(
+        provider: &'a P,
+        range: RangeInclusive<BlockNumber>,
+        fetch: fn(&P, RangeInclusive<BlockNumber>) -> ProviderResult<Vec<T>>,
+    ) -> Self {
+        let end = (!range.is_empty()).then_some(*range.end());
+        Self {
+            provider,
+            start: *range.start(),
+            end,
+            chunk_size: DEFAULT_REV_CHUNK_SIZE,
+            fetch,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Sets the number of blocks fetched at once.
+    ///
+    /// Smaller chunks are preferable if only a few of the most recent items are consumed.
+    pub fn with_chunk_size(mut self, chunk_size: u64) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+}
+
+impl<P, T> Iterator for RevChunkedIter<'_, P, T> {
+    type Item = ProviderResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop() {
+                return Some(Ok(item))
+            }
+
+            let end = self.end?;
+            let start = end.saturating_sub(self.chunk_size - 1).max(self.start);
+            self.end = start.checked_sub(1).filter(|next| *next >= self.start);
+
+            match (self.fetch)(self.provider, start..=end) {
+                Ok(items) => self.buffer = items,
+                Err(err) => {
+                    self.end = None;
+                    return Some(Err(err))
+                }
+            }
+        }
+    }
+}
+
+impl<P, T> fmt::Debug for RevChunkedIter<'_, P, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RevChunkedIter")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("chunk_size", &self.chunk_size)
+            .field("buffered", &self.buffer.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_primitives::{Address, BlockNumber, B256};
+use alloy_primitives::{Address, BlockNumber, Log, B256};
 use auto_impl::auto_impl;
 use reth_db::models::{AccountBeforeTx, BlockNumberAddress};
 use reth_primitives::StorageEntry;
@@ -54,4 +54,23 @@ pub trait HistoryWriter: Send + Sync {
 
     /// Read account/storage changesets and update account/storage history indices.
     fn update_history_indices(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<()>;
+
+    /// Inserts the logs of the given blocks into the log index.
+    ///
+    /// This is a no-op unless the log index is maintained and the blocks directly follow the
+    /// indexed range, in which case the indexed range is extended to the last given block.
+    fn append_log_index<'a>(
+        &self,
+        block_logs: impl IntoIterator<Item = (BlockNumber, impl IntoIterator<Item = &'a Log>)>,
+    ) -> ProviderResult<()>;
+
+    /// Unwind and clear the log index above the given block.
+    ///
+    /// The given logs must include all logs of the unwound blocks. Returns the number of unique
+    /// addresses and topics unwound.
+    fn unwind_log_index<'a>(
+        &self,
+        logs: impl IntoIterator<Item = &'a Log>,
+        unwind_to: BlockNumber,
+    ) -> ProviderResult<usize>;
 }
@@ -16,6 +16,9 @@ pub use account::*;
 mod block;
 pub use block::*;
 
+mod block_rev;
+pub use block_rev::*;
+
 mod block_id;
 pub use block_id::*;
 
@@ -46,6 +49,12 @@ pub use storage::*;
 mod transactions;
 pub use transactions::*;
 
+mod touch_epochs;
+pub use touch_epochs::*;
+
+mod log_index;
+pub use log_index::*;
+
 mod trie;
 pub use trie::*;
 
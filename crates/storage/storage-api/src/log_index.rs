@@ -0,0 +1,30 @@
+use alloy_primitives::{Address, BlockNumber, B256};
+use reth_storage_errors::provider::ProviderResult;
+use std::ops::RangeInclusive;
+
+/// Client trait for looking up the blocks that contain logs emitted by an address or with a topic.
+///
+/// This is backed by an optional index which is only maintained if the `IndexLogs` stage is
+/// enabled. Blocks outside of [`LogIndexReader::log_index_range`] are not indexed.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait LogIndexReader: Send + Sync {
+    /// Returns the range of blocks covered by the log index, or `None` if the log index is not
+    /// maintained.
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>>;
+
+    /// Returns the numbers of the blocks in the given range that contain logs emitted by the
+    /// address, in ascending order.
+    fn blocks_with_address_logs(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>;
+
+    /// Returns the numbers of the blocks in the given range that contain logs with the topic at
+    /// any position, in ascending order.
+    fn blocks_with_topic_logs(
+        &self,
+        topic: B256,
+        block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>>;
+}
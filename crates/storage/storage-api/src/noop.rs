@@ -1,11 +1,13 @@
 //! Various noop implementations for traits.
 
 use crate::{
-    AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    BlockSource, ChangeSetReader, HashedPostStateProvider, HeaderProvider, NodePrimitivesProvider,
+    AccountReader, AddressTransactionsReader, BlockHashReader, BlockIdReader, BlockNumReader,
+    BlockReader, BlockReaderIdExt, BlockSource, ChangeSetReader, ColdStateStats,
+    HashedPostStateProvider, HeaderProvider, LogIndexReader, NodePrimitivesProvider,
     PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt, StageCheckpointReader,
     StateProofProvider, StateProvider, StateProviderBox, StateProviderFactory, StateRootProvider,
-    StorageRootProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    StorageRootProvider, TouchEpochReader, TransactionSelectorReader, TransactionVariant,
+    TransactionsProvider, WithdrawalHistoryReader, WithdrawalsProvider,
 };
 use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
@@ -13,7 +15,8 @@ use alloy_eips::{
 };
 use alloy_primitives::{
     map::{HashMap, HashSet},
-    Address, BlockHash, BlockNumber, Bytes, StorageKey, StorageValue, TxHash, TxNumber, B256, U256,
+    Address, BlockHash, BlockNumber, Bytes, Selector, StorageKey, StorageValue, TxHash, TxNumber,
+    B256, U256,
 };
 use reth_chainspec::{ChainInfo, ChainSpecProvider, EthChainSpec, MAINNET};
 use reth_db_models::{AccountBeforeTx, StoredBlockBodyIndices};
@@ -377,6 +380,93 @@ impl<C: Send + Sync, N: NodePrimitives> ChangeSetReader for NoopProvider<C, N> {
     }
 }
 
+impl<C: Send + Sync, N: NodePrimitives> TransactionSelectorReader for NoopProvider<C, N> {
+    fn transactions_by_selector(
+        &self,
+        _selector: Selector,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> AddressTransactionsReader for NoopProvider<C, N> {
+    fn transactions_by_address(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+        _reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> WithdrawalHistoryReader for NoopProvider<C, N> {
+    fn withdrawals_by_address(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        Ok(Vec::default())
+    }
+
+    fn withdrawals_by_validator(
+        &self,
+        _validator_index: u64,
+        _block_range: RangeInclusive<BlockNumber>,
+        _limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> LogIndexReader for NoopProvider<C, N> {
+    fn log_index_range(&self) -> ProviderResult<Option<RangeInclusive<BlockNumber>>> {
+        Ok(None)
+    }
+
+    fn blocks_with_address_logs(
+        &self,
+        _address: Address,
+        _block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+
+    fn blocks_with_topic_logs(
+        &self,
+        _topic: B256,
+        _block_range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<BlockNumber>> {
+        Ok(Vec::default())
+    }
+}
+
+impl<C: Send + Sync, N: NodePrimitives> TouchEpochReader for NoopProvider<C, N> {
+    fn touch_epoch_length(&self) -> Option<u64> {
+        None
+    }
+
+    fn account_last_touch_epoch(&self, _address: Address) -> ProviderResult<Option<u64>> {
+        Ok(None)
+    }
+
+    fn storage_last_touch_epoch(
+        &self,
+        _address: Address,
+        _storage_key: B256,
+    ) -> ProviderResult<Option<u64>> {
+        Ok(None)
+    }
+
+    fn cold_state_stats(&self, _epoch: u64) -> ProviderResult<ColdStateStats> {
+        Ok(ColdStateStats::default())
+    }
+}
+
 impl<C: Send + Sync, N: NodePrimitives> StateRootProvider for NoopProvider<C, N> {
     fn state_root(&self, _state: HashedPostState) -> ProviderResult<B256> {
         Ok(B256::default())
@@ -1,6 +1,6 @@
 use super::{
-    AccountReader, BlockHashReader, BlockIdReader, StateProofProvider, StateRootProvider,
-    StorageRootProvider,
+    AccountReader, BlockHashReader, BlockIdReader, DBProvider, StateProofProvider,
+    StateRootProvider, StorageRootProvider,
 };
 use alloy_consensus::constants::KECCAK_EMPTY;
 use alloy_eips::{BlockId, BlockNumberOrTag};
@@ -91,6 +91,41 @@ pub trait StateCommitmentProvider: Send + Sync {
     type StateCommitment: StateCommitment;
 }
 
+/// The [`StateCommitment::StateRoot`] type of the provider `P` over its database transaction.
+pub type StateRootOf<'a, P> =
+    <<P as StateCommitmentProvider>::StateCommitment as StateCommitment>::StateRoot<
+        'a,
+        <P as DBProvider>::Tx,
+    >;
+
+/// The [`StateCommitment::StorageRoot`] type of the provider `P` over its database transaction.
+pub type StorageRootOf<'a, P> =
+    <<P as StateCommitmentProvider>::StateCommitment as StateCommitment>::StorageRoot<
+        'a,
+        <P as DBProvider>::Tx,
+    >;
+
+/// The [`StateCommitment::StateProof`] type of the provider `P` over its database transaction.
+pub type StateProofOf<'a, P> =
+    <<P as StateCommitmentProvider>::StateCommitment as StateCommitment>::StateProof<
+        'a,
+        <P as DBProvider>::Tx,
+    >;
+
+/// The [`StateCommitment::StorageProof`] type of the provider `P` over its database transaction.
+pub type StorageProofOf<'a, P> =
+    <<P as StateCommitmentProvider>::StateCommitment as StateCommitment>::StorageProof<
+        'a,
+        <P as DBProvider>::Tx,
+    >;
+
+/// The [`StateCommitment::StateWitness`] type of the provider `P` over its database transaction.
+pub type StateWitnessOf<'a, P> =
+    <<P as StateCommitmentProvider>::StateCommitment as StateCommitment>::StateWitness<
+        'a,
+        <P as DBProvider>::Tx,
+    >;
+
 /// Trait that provides the hashed state from various sources.
 #[auto_impl(&, Arc, Box)]
 pub trait HashedPostStateProvider: Send + Sync {
@@ -0,0 +1,42 @@
+use alloy_primitives::{Address, B256};
+use reth_storage_errors::provider::ProviderResult;
+
+/// The volume of state that was last touched before a given epoch, see
+/// [`TouchEpochReader::cold_state_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColdStateStats {
+    /// The number of accounts that were touched in any epoch.
+    pub accounts: u64,
+    /// The number of accounts that were last touched before the epoch.
+    pub cold_accounts: u64,
+    /// The number of storage slots that were touched in any epoch.
+    pub storage_slots: u64,
+    /// The number of storage slots that were last touched before the epoch.
+    pub cold_storage_slots: u64,
+}
+
+/// Client trait for looking up the epochs in which accounts and storage slots were last touched.
+///
+/// An epoch is a fixed number of consecutive blocks, and state is touched by a block if the block
+/// changed it. This is backed by optional tables which are only maintained if a touch epoch length
+/// is configured, state that was last changed before tracking was enabled is unknown.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait TouchEpochReader: Send + Sync {
+    /// Returns the number of blocks per epoch, or `None` if touch epochs are not tracked.
+    fn touch_epoch_length(&self) -> Option<u64>;
+
+    /// Returns the last epoch the account was touched in.
+    fn account_last_touch_epoch(&self, address: Address) -> ProviderResult<Option<u64>>;
+
+    /// Returns the last epoch the storage slot was touched in.
+    fn storage_last_touch_epoch(
+        &self,
+        address: Address,
+        storage_key: B256,
+    ) -> ProviderResult<Option<u64>>;
+
+    /// Returns the number of accounts and storage slots that were last touched before `epoch`.
+    ///
+    /// This walks both touch epoch tables entirely.
+    fn cold_state_stats(&self, epoch: u64) -> ProviderResult<ColdStateStats>;
+}
@@ -1,6 +1,6 @@
 use crate::{BlockNumReader, BlockReader};
 use alloy_eips::BlockHashOrNumber;
-use alloy_primitives::{Address, BlockNumber, TxHash, TxNumber};
+use alloy_primitives::{Address, BlockNumber, Selector, TxHash, TxNumber};
 use reth_primitives::TransactionMeta;
 use reth_primitives_traits::SignedTransaction;
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
@@ -111,3 +111,48 @@ pub trait TransactionsProviderExt: BlockReader + Send + Sync {
         tx_range: Range<TxNumber>,
     ) -> ProviderResult<Vec<(TxHash, TxNumber)>>;
 }
+
+/// Client trait for looking up transactions by the 4-byte function selector of their calldata.
+///
+/// This is backed by an optional index which is only maintained if enabled through the
+/// `transaction_selectors` prune configuration.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait TransactionSelectorReader: Send + Sync {
+    /// Returns the transaction numbers of all transactions in the given block range whose calldata
+    /// starts with `selector`, each paired with the number of the block it was included in.
+    ///
+    /// Entries are ordered by transaction number and at most `limit` entries are returned.
+    ///
+    /// Returns [`ProviderError::IndexDisabled`] if the index is not maintained and
+    /// [`ProviderError::IndexPruned`] if it's pruned within the block range.
+    fn transactions_by_selector(
+        &self,
+        selector: Selector,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>>;
+}
+
+/// Client trait for looking up the transactions an address took part in, either as the sender or
+/// as the direct recipient.
+///
+/// This is backed by an optional index which is only maintained if enabled through the
+/// `address_transactions` prune configuration.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait AddressTransactionsReader: Send + Sync {
+    /// Returns the transaction numbers of all transactions in the given block range that were sent
+    /// to or by `address`, each paired with the number of the block it was included in.
+    ///
+    /// Entries are ordered by transaction number, descending if `reverse` is set, and at most
+    /// `limit` entries are returned.
+    ///
+    /// Returns [`ProviderError::IndexDisabled`] if the index is not maintained and
+    /// [`ProviderError::IndexPruned`] if it's pruned within the block range.
+    fn transactions_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+        reverse: bool,
+    ) -> ProviderResult<Vec<(TxNumber, BlockNumber)>>;
+}
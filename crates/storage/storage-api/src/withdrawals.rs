@@ -2,7 +2,9 @@ use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
     BlockHashOrNumber,
 };
-use reth_storage_errors::provider::ProviderResult;
+use alloy_primitives::{Address, BlockNumber};
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
+use std::ops::RangeInclusive;
 
 ///  Client trait for fetching [Withdrawal] related data.
 #[auto_impl::auto_impl(&, Arc)]
@@ -17,3 +19,40 @@ pub trait WithdrawalsProvider: Send + Sync {
     /// Get latest withdrawal from this block or earlier .
     fn latest_withdrawal(&self) -> ProviderResult<Option<Withdrawal>>;
 }
+
+/// Client trait for looking up the withdrawals of a recipient address or a validator.
+///
+/// This is backed by an optional index which is only maintained if enabled through the
+/// `withdrawal_history` prune configuration or `--index.withdrawals`.
+#[auto_impl::auto_impl(&, Arc)]
+pub trait WithdrawalHistoryReader: Send + Sync {
+    /// Returns the withdrawals to the address in the given block range, each paired with the
+    /// number of the block it was included in.
+    ///
+    /// Withdrawals are ordered by their position in the chain and at most `limit` withdrawals are
+    /// returned.
+    ///
+    /// Returns [`ProviderError::IndexDisabled`] if the index is not maintained and
+    /// [`ProviderError::IndexPruned`] if it's pruned within the block range.
+    fn withdrawals_by_address(
+        &self,
+        address: Address,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>>;
+
+    /// Returns the withdrawals from the validator in the given block range, each paired with the
+    /// number of the block it was included in.
+    ///
+    /// Withdrawals are ordered by their position in the chain and at most `limit` withdrawals are
+    /// returned.
+    ///
+    /// Returns [`ProviderError::IndexDisabled`] if the index is not maintained and
+    /// [`ProviderError::IndexPruned`] if it's pruned within the block range.
+    fn withdrawals_by_validator(
+        &self,
+        validator_index: u64,
+        block_range: RangeInclusive<BlockNumber>,
+        limit: usize,
+    ) -> ProviderResult<Vec<(BlockNumber, Withdrawal)>>;
+}
@@ -160,7 +160,7 @@ fn truncate_pending(
     // for now we just use the default SubPoolLimit
     group.bench_function(group_id, |b| {
         b.iter_with_setup(setup, |mut txpool| {
-            txpool.truncate_pool(SubPoolLimit::default());
+            txpool.truncate_pool(SubPoolLimit::default(), &Default::default());
             std::hint::black_box(());
         });
     });
@@ -194,7 +194,7 @@ fn truncate_queued(
     // for now we just use the default SubPoolLimit
     group.bench_function(group_id, |b| {
         b.iter_with_setup(setup, |mut txpool| {
-            txpool.truncate_pool(SubPoolLimit::default());
+            txpool.truncate_pool(SubPoolLimit::default(), &Default::default());
             std::hint::black_box(());
         });
     });
@@ -228,7 +228,7 @@ fn truncate_basefee(
     // for now we just use the default SubPoolLimit
     group.bench_function(group_id, |b| {
         b.iter_with_setup(setup, |mut txpool| {
-            txpool.truncate_pool(SubPoolLimit::default());
+            txpool.truncate_pool(SubPoolLimit::default(), &Default::default());
             std::hint::black_box(());
         });
     });
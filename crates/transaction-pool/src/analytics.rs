@@ -0,0 +1,318 @@
+//! Analytics of how long transactions stay in the pool and how they leave it.
+//!
+//! [`PoolAnalytics`] follows every transaction from the moment it is added to the pool until it is
+//! mined, replaced, discarded or found to be invalid. The outcome is recorded as
+//! [`PoolAnalyticsMetrics`], labeled by outcome and tip, together with the time the transaction
+//! spent in the pool and the length of its replacement chain, so that questions like how long
+//! transactions with a 2 gwei tip wait on average can be answered from the node's own metrics.
+//! One in [`PoolAnalyticsConfig::sample_rate`] outcomes is also logged.
+
+use crate::{
+    metrics::PoolAnalyticsMetrics, pool::FullTransactionEvent, PoolTransaction, TransactionPool,
+    ValidPoolTransaction,
+};
+use alloy_primitives::TxHash;
+use futures_util::StreamExt;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+use tracing::{debug, info};
+
+/// Default rate at which transaction outcomes are logged: one in 100.
+pub const DEFAULT_POOL_ANALYTICS_SAMPLE_RATE: u64 = 100;
+
+/// Lower bounds in gwei of the tip buckets transactions are grouped by.
+const TIP_BUCKETS_GWEI: [u64; 8] = [0, 1, 2, 3, 5, 10, 20, 50];
+
+/// One gwei in wei.
+const GWEI: u128 = 1_000_000_000;
+
+/// Configuration of the [`PoolAnalytics`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolAnalyticsConfig {
+    /// One in `sample_rate` transaction outcomes is logged, `0` disables logging.
+    pub sample_rate: u64,
+}
+
+impl Default for PoolAnalyticsConfig {
+    fn default() -> Self {
+        Self { sample_rate: DEFAULT_POOL_ANALYTICS_SAMPLE_RATE }
+    }
+}
+
+/// How a transaction left the pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransactionOutcome {
+    /// The transaction was included in a block.
+    Mined,
+    /// The transaction was replaced by another transaction of the same sender and nonce.
+    Replaced,
+    /// The transaction was removed from the pool, e.g. because the pool limits were exceeded.
+    Discarded,
+    /// The transaction became invalid.
+    Invalid,
+}
+
+impl TransactionOutcome {
+    /// Returns the name of the outcome, as used in the metric labels.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mined => "mined",
+            Self::Replaced => "replaced",
+            Self::Discarded => "discarded",
+            Self::Invalid => "invalid",
+        }
+    }
+}
+
+/// The outcome of a transaction that left the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionOutcomeRecord {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// How the transaction left the pool.
+    pub outcome: TransactionOutcome,
+    /// The priority fee of the transaction, or the gas price of legacy transactions.
+    pub tip: u128,
+    /// How long the transaction was in the pool.
+    pub time_in_pool: Duration,
+    /// How long ago the first transaction of the replacement chain of the transaction was added
+    /// to the pool.
+    ///
+    /// This is the same as the `time_in_pool` if the transaction didn't replace another one.
+    pub chain_time_in_pool: Duration,
+    /// The number of transactions the transaction replaced, directly or through the transactions
+    /// it replaced.
+    pub replacements: u32,
+}
+
+/// A transaction that is currently in the pool.
+#[derive(Debug, Clone, Copy)]
+struct TrackedTransaction {
+    /// When the transaction was added to the pool.
+    added: Instant,
+    /// When the first transaction of the replacement chain was added to the pool.
+    chain_added: Instant,
+    /// The priority fee of the transaction, or the gas price of legacy transactions.
+    tip: u128,
+    /// The number of transactions the transaction replaced.
+    replacements: u32,
+}
+
+impl TrackedTransaction {
+    fn new<T: PoolTransaction>(transaction: &ValidPoolTransaction<T>) -> Self {
+        Self {
+            added: transaction.timestamp,
+            chain_added: transaction.timestamp,
+            tip: transaction.transaction.priority_fee_or_price(),
+            replacements: 0,
+        }
+    }
+
+    fn into_record(
+        self,
+        hash: TxHash,
+        outcome: TransactionOutcome,
+        now: Instant,
+    ) -> TransactionOutcomeRecord {
+        TransactionOutcomeRecord {
+            hash,
+            outcome,
+            tip: self.tip,
+            time_in_pool: now.saturating_duration_since(self.added),
+            chain_time_in_pool: now.saturating_duration_since(self.chain_added),
+            replacements: self.replacements,
+        }
+    }
+}
+
+/// Tracks the transactions of the pool and records how they leave it.
+#[derive(Debug)]
+pub struct PoolAnalytics {
+    config: PoolAnalyticsConfig,
+    /// All transactions currently in the pool.
+    tracked: HashMap<TxHash, TrackedTransaction>,
+    /// The metrics of each outcome and tip bucket.
+    metrics: HashMap<(TransactionOutcome, u64), PoolAnalyticsMetrics>,
+    /// The number of recorded outcomes.
+    outcomes: u64,
+}
+
+// === impl PoolAnalytics ===
+
+impl PoolAnalytics {
+    /// Creates new analytics with the given config.
+    pub fn new(config: PoolAnalyticsConfig) -> Self {
+        Self { config, tracked: HashMap::default(), metrics: HashMap::default(), outcomes: 0 }
+    }
+
+    /// Returns the number of tracked transactions.
+    pub fn tracked_len(&self) -> usize {
+        self.tracked.len()
+    }
+
+    /// Starts tracking the transaction, if it isn't tracked already.
+    pub fn on_added<T: PoolTransaction>(&mut self, transaction: &ValidPoolTransaction<T>) {
+        self.tracked
+            .entry(*transaction.hash())
+            .or_insert_with(|| TrackedTransaction::new(transaction));
+    }
+
+    /// Updates the analytics with the event of the pool.
+    ///
+    /// Returns the record of the transaction if the event completed it.
+    pub fn on_event<P: TransactionPool>(
+        &mut self,
+        pool: &P,
+        event: FullTransactionEvent<P::Transaction>,
+    ) -> Option<TransactionOutcomeRecord> {
+        let now = Instant::now();
+        let record = match event {
+            FullTransactionEvent::Pending(hash) | FullTransactionEvent::Queued(hash) => {
+                if !self.tracked.contains_key(&hash) {
+                    if let Some(transaction) = pool.get(&hash) {
+                        self.on_added(&transaction);
+                    }
+                }
+                return None
+            }
+            FullTransactionEvent::Replaced { transaction, replaced_by } => {
+                let replaced = self
+                    .tracked
+                    .remove(transaction.hash())
+                    .unwrap_or_else(|| TrackedTransaction::new(&transaction));
+                // the replacement continues the chain of the replaced transaction
+                if let Some(replacement) = self.tracked.get_mut(&replaced_by) {
+                    replacement.chain_added = replaced.chain_added;
+                    replacement.replacements = replaced.replacements + 1;
+                }
+                replaced.into_record(*transaction.hash(), TransactionOutcome::Replaced, now)
+            }
+            FullTransactionEvent::Mined { tx_hash, .. } => {
+                self.remove(tx_hash, TransactionOutcome::Mined, now)?
+            }
+            FullTransactionEvent::Discarded(hash) => {
+                self.remove(hash, TransactionOutcome::Discarded, now)?
+            }
+            FullTransactionEvent::Invalid(tx_hash) => {
+                self.remove(tx_hash, TransactionOutcome::Invalid, now)?
+            }
+            FullTransactionEvent::Propagated(_) => return None,
+        };
+
+        self.record(&record);
+        Some(record)
+    }
+
+    /// Stops tracking the transaction and returns its record, if it was tracked.
+    fn remove(
+        &mut self,
+        hash: TxHash,
+        outcome: TransactionOutcome,
+        now: Instant,
+    ) -> Option<TransactionOutcomeRecord> {
+        self.tracked.remove(&hash).map(|tracked| tracked.into_record(hash, outcome, now))
+    }
+
+    /// Records the outcome in the metrics and logs it, if it is sampled.
+    fn record(&mut self, record: &TransactionOutcomeRecord) {
+        let tip_bucket = tip_bucket(record.tip);
+        let metrics = self.metrics.entry((record.outcome, tip_bucket)).or_insert_with(|| {
+            PoolAnalyticsMetrics::new_with_labels(&[
+                ("outcome", record.outcome.as_str().to_string()),
+                ("tip_gwei", tip_bucket.to_string()),
+            ])
+        });
+        metrics.transactions.increment(1);
+        metrics.time_in_pool.record(record.time_in_pool);
+        metrics.chain_time_in_pool.record(record.chain_time_in_pool);
+        metrics.replacement_chain_length.record(record.replacements as f64);
+
+        self.outcomes += 1;
+        if self.config.sample_rate > 0 && self.outcomes % self.config.sample_rate == 0 {
+            info!(
+                target: "txpool::analytics",
+                hash=?record.hash,
+                outcome=record.outcome.as_str(),
+                tip=record.tip,
+                time_in_pool=?record.time_in_pool,
+                chain_time_in_pool=?record.chain_time_in_pool,
+                replacements=record.replacements,
+                "Transaction left the pool"
+            );
+        }
+    }
+}
+
+/// Returns the lower bound in gwei of the tip bucket the tip belongs to.
+fn tip_bucket(tip: u128) -> u64 {
+    let tip_gwei = tip / GWEI;
+    TIP_BUCKETS_GWEI
+        .into_iter()
+        .rev()
+        .find(|bucket| tip_gwei >= *bucket as u128)
+        .unwrap_or_default()
+}
+
+/// Task that records the [`PoolAnalytics`] of all transactions of the pool.
+pub async fn pool_analytics_task<P>(pool: P, config: PoolAnalyticsConfig)
+where
+    P: TransactionPool,
+{
+    let mut events = pool.all_transactions_event_listener();
+    let mut analytics = PoolAnalytics::new(config);
+    for transaction in pool.pooled_transactions() {
+        analytics.on_added(&transaction);
+    }
+    debug!(target: "txpool", ?config, tracked=analytics.tracked_len(), "Recording pool analytics");
+
+    while let Some(event) = events.next().await {
+        analytics.on_event(&pool, event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{testing_pool, MockTransactionFactory};
+
+    #[test]
+    fn tip_buckets() {
+        assert_eq!(tip_bucket(0), 0);
+        assert_eq!(tip_bucket(GWEI - 1), 0);
+        assert_eq!(tip_bucket(2 * GWEI), 2);
+        assert_eq!(tip_bucket(4 * GWEI), 3);
+        assert_eq!(tip_bucket(1_000 * GWEI), 50);
+    }
+
+    #[tokio::test]
+    async fn tracks_replacement_chain() {
+        let pool = testing_pool();
+        let mut events = pool.all_transactions_event_listener();
+        let mut analytics = PoolAnalytics::new(PoolAnalyticsConfig::default());
+
+        let mut factory = MockTransactionFactory::default();
+        let tx = factory.create_eip1559().transaction;
+        pool.add_external_transaction(tx.clone()).await.unwrap();
+        assert_eq!(analytics.on_event(&pool, events.next().await.unwrap()), None);
+        assert_eq!(analytics.tracked_len(), 1);
+
+        // the replacement is added before the replaced transaction is reported
+        let replacement = tx.clone().rng_hash().with_gas_price(tx.get_gas_price() * 2);
+        pool.add_external_transaction(replacement.clone()).await.unwrap();
+        assert_eq!(analytics.on_event(&pool, events.next().await.unwrap()), None);
+        let replaced = analytics.on_event(&pool, events.next().await.unwrap()).unwrap();
+        assert_eq!(replaced.hash, *tx.hash());
+        assert_eq!(replaced.outcome, TransactionOutcome::Replaced);
+        assert_eq!(replaced.replacements, 0);
+
+        pool.remove_transactions(vec![*replacement.hash()]);
+        let discarded = analytics.on_event(&pool, events.next().await.unwrap()).unwrap();
+        assert_eq!(discarded.hash, *replacement.hash());
+        assert_eq!(discarded.outcome, TransactionOutcome::Discarded);
+        assert_eq!(discarded.replacements, 1);
+        assert!(discarded.chain_time_in_pool >= discarded.time_in_pool);
+        assert_eq!(analytics.tracked_len(), 0);
+    }
+}
@@ -4,18 +4,25 @@ use crate::blobstore::{BlobStore, BlobStoreCleanupStat, BlobStoreError, BlobStor
 use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
 use alloy_primitives::{TxHash, B256};
 use parking_lot::{Mutex, RwLock};
-use schnellru::{ByLength, LruMap};
+use schnellru::{ByLength, LruMap, Unlimited};
 use std::{collections::HashSet, fmt, fs, io, path::PathBuf, sync::Arc};
 use tracing::{debug, trace};
 
 /// How many [`BlobTransactionSidecar`] to cache in memory.
 pub const DEFAULT_MAX_CACHED_BLOBS: u32 = 100;
 
+/// The default maximum size of the blobs stored on disk, 4 GiB.
+pub const DEFAULT_MAX_BLOB_STORE_SIZE: usize = 4 * 1024 * 1024 * 1024;
+
 /// A blob store that stores blob data on disk.
 ///
 /// The type uses deferred deletion, meaning that blobs are not immediately deleted from disk, but
 /// it's expected that the maintenance task will call [`BlobStore::cleanup`] to remove the deleted
 /// blobs from disk.
+///
+/// If the blobs on disk exceed the configured [`DiskFileBlobStoreConfig::max_size`], the least
+/// recently used blobs are evicted right away. The transactions of evicted blobs are returned by
+/// [`BlobStore::take_evicted`] and removed from the pool.
 #[derive(Clone, Debug)]
 pub struct DiskFileBlobStore {
     inner: Arc<DiskFileBlobStoreInner>,
@@ -28,8 +35,8 @@ impl DiskFileBlobStore {
         opts: DiskFileBlobStoreConfig,
     ) -> Result<Self, DiskFileBlobStoreError> {
         let blob_dir = blob_dir.into();
-        let DiskFileBlobStoreConfig { max_cached_entries, .. } = opts;
-        let inner = DiskFileBlobStoreInner::new(blob_dir, max_cached_entries);
+        let DiskFileBlobStoreConfig { max_cached_entries, max_size, .. } = opts;
+        let inner = DiskFileBlobStoreInner::new(blob_dir, max_cached_entries, max_size);
 
         // initialize the blob store
         inner.delete_all()?;
@@ -76,6 +83,12 @@ impl BlobStore for DiskFileBlobStore {
 
     fn cleanup(&self) -> BlobStoreCleanupStat {
         let txs_to_delete = std::mem::take(&mut *self.inner.txs_to_delete.write());
+        {
+            let mut on_disk = self.inner.on_disk.lock();
+            for tx in &txs_to_delete {
+                on_disk.remove(tx);
+            }
+        }
         let mut stat = BlobStoreCleanupStat::default();
         let mut subsize = 0;
         debug!(target:"txpool::blob", num_blobs=%txs_to_delete.len(), "Removing blobs from disk");
@@ -159,22 +172,36 @@ impl BlobStore for DiskFileBlobStore {
     fn blobs_len(&self) -> usize {
         self.inner.size_tracker.blobs_len()
     }
+
+    fn take_evicted(&self) -> Vec<B256> {
+        std::mem::take(&mut *self.inner.evicted.lock())
+    }
 }
 
 struct DiskFileBlobStoreInner {
     blob_dir: PathBuf,
     blob_cache: Mutex<LruMap<TxHash, Arc<BlobTransactionSidecar>, ByLength>>,
+    /// The size of all blobs on disk, in least recently used order.
+    on_disk: Mutex<LruMap<TxHash, usize, Unlimited>>,
+    /// The maximum size of the blobs on disk.
+    max_size: Option<usize>,
+    /// The transactions whose blobs were evicted, until they are taken by the pool.
+    evicted: Mutex<Vec<B256>>,
     size_tracker: BlobStoreSize,
     file_lock: RwLock<()>,
     txs_to_delete: RwLock<HashSet<B256>>,
 }
 
 impl DiskFileBlobStoreInner {
-    /// Creates a new empty disk file blob store with the given maximum length of the blob cache.
-    fn new(blob_dir: PathBuf, max_length: u32) -> Self {
+    /// Creates a new empty disk file blob store with the given maximum length of the blob cache
+    /// and maximum size of the blobs on disk.
+    fn new(blob_dir: PathBuf, max_length: u32, max_size: Option<usize>) -> Self {
         Self {
             blob_dir,
             blob_cache: Mutex::new(LruMap::new(ByLength::new(max_length))),
+            on_disk: Mutex::new(LruMap::new(Unlimited)),
+            max_size,
+            evicted: Default::default(),
             size_tracker: Default::default(),
             file_lock: Default::default(),
             txs_to_delete: Default::default(),
@@ -209,6 +236,8 @@ impl DiskFileBlobStoreInner {
 
         self.size_tracker.add_size(size);
         self.size_tracker.inc_len(1);
+        self.on_disk.lock().insert(tx, buf.len());
+        self.evict_to_max_size();
         Ok(())
     }
 
@@ -219,7 +248,7 @@ impl DiskFileBlobStoreInner {
             .map(|(tx, data)| {
                 let mut buf = Vec::with_capacity(data.rlp_encoded_fields_length());
                 data.rlp_encode_fields(&mut buf);
-                (self.blob_disk_file(*tx), buf)
+                (*tx, self.blob_disk_file(*tx), buf)
             })
             .collect::<Vec<_>>();
 
@@ -233,23 +262,80 @@ impl DiskFileBlobStoreInner {
         let mut num = 0;
         {
             let _lock = self.file_lock.write();
-            for (path, data) in raw {
+            let mut on_disk = self.on_disk.lock();
+            for (tx, path, data) in raw {
                 if path.exists() {
                     debug!(target:"txpool::blob", ?path, "Blob already exists");
+                    on_disk.get(&tx);
                 } else if let Err(err) = fs::write(&path, &data) {
                     debug!(target:"txpool::blob", %err, ?path, "Failed to write blob file");
                 } else {
                     add += data.len();
                     num += 1;
+                    on_disk.insert(tx, data.len());
                 }
             }
         }
         self.size_tracker.add_size(add);
         self.size_tracker.inc_len(num);
+        self.evict_to_max_size();
 
         Ok(())
     }
 
+    /// Evicts the least recently used blobs until the blobs on disk are within the configured
+    /// maximum size.
+    ///
+    /// The most recently used blob is never evicted.
+    fn evict_to_max_size(&self) {
+        let Some(max_size) = self.max_size else { return };
+        if self.size_tracker.data_size() <= max_size {
+            return
+        }
+
+        let mut evicted = Vec::new();
+        let mut subsize = 0;
+        {
+            let _lock = self.file_lock.write();
+            let mut on_disk = self.on_disk.lock();
+            while self.size_tracker.data_size().saturating_sub(subsize) > max_size &&
+                on_disk.len() > 1
+            {
+                let Some((tx, size)) = on_disk.pop_oldest() else { break };
+                let path = self.blob_disk_file(tx);
+                match fs::remove_file(&path) {
+                    Ok(_) => {
+                        subsize += size;
+                        evicted.push(tx);
+                    }
+                    Err(e) => {
+                        let err = DiskFileBlobStoreError::DeleteFile(tx, path, e);
+                        debug!(target:"txpool::blob", %err, "Failed to evict blob");
+                    }
+                }
+            }
+        }
+
+        {
+            let mut cache = self.blob_cache.lock();
+            let mut txs_to_delete = self.txs_to_delete.write();
+            for tx in &evicted {
+                cache.remove(tx);
+                txs_to_delete.remove(tx);
+            }
+        }
+        debug!(target:"txpool::blob", evicted=evicted.len(), size=subsize, max_size, "Evicted least recently used blobs");
+        self.size_tracker.sub_size(subsize);
+        self.size_tracker.sub_len(evicted.len());
+        self.evicted.lock().extend(evicted);
+    }
+
+    /// Marks the blob as recently used.
+    #[inline]
+    fn touch(&self, tx: &B256) {
+        self.on_disk.lock().get(tx);
+    }
+
     /// Returns true if the blob for the given transaction hash is in the blob cache or on disk.
     fn contains(&self, tx: B256) -> Result<bool, BlobStoreError> {
         if self.blob_cache.lock().get(&tx).is_some() {
@@ -278,6 +364,7 @@ impl DiskFileBlobStoreInner {
 
     /// Retrieves the blob for the given transaction hash from the blob cache or disk.
     fn get_one(&self, tx: B256) -> Result<Option<Arc<BlobTransactionSidecar>>, BlobStoreError> {
+        self.touch(&tx);
         if let Some(blob) = self.blob_cache.lock().get(&tx) {
             return Ok(Some(blob.clone()))
         }
@@ -380,6 +467,12 @@ impl DiskFileBlobStoreInner {
     ) -> Result<Vec<(B256, Arc<BlobTransactionSidecar>)>, BlobStoreError> {
         let mut res = Vec::with_capacity(txs.len());
         let mut cache_miss = Vec::new();
+        {
+            let mut on_disk = self.on_disk.lock();
+            for tx in &txs {
+                on_disk.get(tx);
+            }
+        }
         {
             let mut cache = self.blob_cache.lock();
             for tx in txs {
@@ -463,13 +556,20 @@ impl From<DiskFileBlobStoreError> for BlobStoreError {
 pub struct DiskFileBlobStoreConfig {
     /// The maximum number of blobs to keep in the in memory blob cache.
     pub max_cached_entries: u32,
+    /// The maximum size of the blobs on disk in bytes, least recently used blobs are evicted once
+    /// it's exceeded.
+    pub max_size: Option<usize>,
     /// How to open the blob store.
     pub open: OpenDiskFileBlobStore,
 }
 
 impl Default for DiskFileBlobStoreConfig {
     fn default() -> Self {
-        Self { max_cached_entries: DEFAULT_MAX_CACHED_BLOBS, open: Default::default() }
+        Self {
+            max_cached_entries: DEFAULT_MAX_CACHED_BLOBS,
+            max_size: Some(DEFAULT_MAX_BLOB_STORE_SIZE),
+            open: Default::default(),
+        }
     }
 }
 
@@ -479,6 +579,12 @@ impl DiskFileBlobStoreConfig {
         self.max_cached_entries = max_cached_entries;
         self
     }
+
+    /// Set the maximum size of the blobs on disk in bytes.
+    pub const fn with_max_size(mut self, max_size: Option<usize>) -> Self {
+        self.max_size = max_size;
+        self
+    }
 }
 
 /// How to open a disk file blob store.
@@ -683,4 +789,32 @@ mod tests {
         assert_eq!(stat.delete_succeed, 3);
         assert_eq!(stat.delete_failed, 0);
     }
+
+    #[test]
+    fn disk_evict_least_recently_used() {
+        let blobs = rng_blobs(3);
+        let blob_size = blobs[0].1.rlp_encoded_fields_length();
+        let dir = tempfile::tempdir().unwrap();
+        let store = DiskFileBlobStore::open(
+            dir.path(),
+            DiskFileBlobStoreConfig::default().with_max_size(Some(2 * blob_size)),
+        )
+        .unwrap();
+
+        let (first, second, third) = (blobs[0].0, blobs[1].0, blobs[2].0);
+        store.insert_all(blobs[..2].to_vec()).unwrap();
+        // mark the first blob as recently used
+        assert!(store.get(first).unwrap().is_some());
+
+        store.insert(third, blobs[2].1.clone()).unwrap();
+        assert!(store.contains(first).unwrap());
+        assert!(!store.contains(second).unwrap());
+        assert!(store.get(second).unwrap().is_none());
+        assert!(store.contains(third).unwrap());
+        assert_eq!(store.data_size_hint(), Some(2 * blob_size));
+        assert_eq!(store.blobs_len(), 2);
+
+        assert_eq!(store.take_evicted(), vec![second]);
+        assert!(store.take_evicted().is_empty());
+    }
 }
@@ -0,0 +1,100 @@
+//! Cache of the blob sidecars of recently mined transactions.
+
+use alloy_eips::eip4844::{Blob, BlobTransactionSidecar, Bytes48};
+use alloy_primitives::B256;
+use schnellru::{ByLength, LruMap};
+
+/// The default number of blobs kept by [`MinedBlobSidecars`].
+pub const DEFAULT_MAX_MINED_BLOBS: u32 = 128;
+
+/// A blob with its commitment and proof.
+#[derive(Debug, Clone)]
+struct MinedBlob {
+    blob: Blob,
+    commitment: Bytes48,
+    proof: Bytes48,
+}
+
+/// Bounded cache of the blobs of recently mined transactions, keyed by versioned hash.
+///
+/// Blocks don't carry the sidecars of their blob transactions, so a blob transaction that is
+/// removed from the chain by a reorg can only be re-added to the pool if its sidecar is still
+/// known. This complements the blob store, which is keyed by transaction hash and may no longer
+/// contain the sidecar.
+#[derive(Debug)]
+pub struct MinedBlobSidecars {
+    blobs: LruMap<B256, MinedBlob, ByLength>,
+}
+
+impl MinedBlobSidecars {
+    /// Creates a new cache that keeps at most `max_blobs` blobs.
+    pub fn new(max_blobs: u32) -> Self {
+        Self { blobs: LruMap::new(ByLength::new(max_blobs)) }
+    }
+
+    /// Adds all blobs of the sidecar.
+    pub fn insert(&mut self, sidecar: &BlobTransactionSidecar) {
+        for (versioned_hash, ((blob, commitment), proof)) in sidecar
+            .versioned_hashes()
+            .zip(sidecar.blobs.iter().zip(sidecar.commitments.iter()).zip(sidecar.proofs.iter()))
+        {
+            self.blobs.insert(
+                versioned_hash,
+                MinedBlob { blob: *blob, commitment: *commitment, proof: *proof },
+            );
+        }
+    }
+
+    /// Rebuilds the sidecar for the given versioned hashes.
+    ///
+    /// Returns `None` unless all blobs are cached.
+    pub fn get(&mut self, versioned_hashes: &[B256]) -> Option<BlobTransactionSidecar> {
+        let mut sidecar = BlobTransactionSidecar::default();
+        for versioned_hash in versioned_hashes {
+            let MinedBlob { blob, commitment, proof } = self.blobs.get(versioned_hash)?;
+            sidecar.blobs.push(*blob);
+            sidecar.commitments.push(*commitment);
+            sidecar.proofs.push(*proof);
+        }
+        Some(sidecar)
+    }
+}
+
+impl Default for MinedBlobSidecars {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_MINED_BLOBS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sidecar(blobs: u8) -> BlobTransactionSidecar {
+        let mut sidecar = BlobTransactionSidecar::default();
+        for i in 0..blobs {
+            let mut blob = Blob::default();
+            blob[0] = i;
+            sidecar.blobs.push(blob);
+            sidecar.commitments.push(Bytes48::with_last_byte(i));
+            sidecar.proofs.push(Bytes48::with_last_byte(i));
+        }
+        sidecar
+    }
+
+    #[test]
+    fn restore_sidecar_by_versioned_hashes() {
+        let mut cache = MinedBlobSidecars::new(2);
+        let full = sidecar(3);
+        let versioned_hashes = full.versioned_hashes().collect::<Vec<_>>();
+        cache.insert(&full);
+
+        // the first blob was evicted
+        assert!(cache.get(&versioned_hashes).is_none());
+
+        let restored = cache.get(&versioned_hashes[1..]).unwrap();
+        assert_eq!(restored.blobs, full.blobs[1..]);
+        assert_eq!(restored.commitments, full.commitments[1..]);
+        assert_eq!(restored.proofs, full.proofs[1..]);
+    }
+}
@@ -4,6 +4,7 @@ use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
 use alloy_primitives::B256;
 pub use disk::{DiskFileBlobStore, DiskFileBlobStoreConfig, OpenDiskFileBlobStore};
 pub use mem::InMemoryBlobStore;
+pub use mined::{MinedBlobSidecars, DEFAULT_MAX_MINED_BLOBS};
 pub use noop::NoopBlobStore;
 use std::{
     fmt,
@@ -16,6 +17,7 @@ pub use tracker::{BlobStoreCanonTracker, BlobStoreUpdates};
 
 pub mod disk;
 mod mem;
+mod mined;
 mod noop;
 mod tracker;
 
@@ -80,6 +82,14 @@ pub trait BlobStore: fmt::Debug + Send + Sync + 'static {
 
     /// How many blobs are in the blob store.
     fn blobs_len(&self) -> usize;
+
+    /// Returns the transactions whose blobs were evicted by the store since the last call, for
+    /// example because the store exceeded its size limit.
+    ///
+    /// These transactions can't be included without their blobs and are removed from the pool.
+    fn take_evicted(&self) -> Vec<B256> {
+        Vec::new()
+    }
 }
 
 /// Error variants that can occur when interacting with a blob store.
@@ -5,11 +5,18 @@ use crate::{
 use alloy_consensus::constants::EIP4844_TX_TYPE_ID;
 use alloy_eips::eip1559::{ETHEREUM_BLOCK_GAS_LIMIT, MIN_PROTOCOL_BASE_FEE};
 use alloy_primitives::Address;
-use std::{collections::HashSet, ops::Mul};
+use reth_memory_budget::MemoryBudget;
+use std::{collections::HashSet, ops::Mul, path::PathBuf, time::Duration};
 
 /// Guarantees max transactions for one sender, compatible with geth/erigon
 pub const TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER: usize = 16;
 
+/// The default maximum number of queued transactions per sender, compatible with geth.
+pub const TXPOOL_MAX_QUEUED_PER_SENDER_DEFAULT: usize = 64;
+
+/// The default maximum time a transaction is kept in the queued sub-pool, compatible with geth.
+pub const DEFAULT_QUEUED_TRANSACTION_LIFETIME: Duration = Duration::from_secs(3 * 60 * 60);
+
 /// The default maximum allowed number of transactions in the given subpool.
 pub const TXPOOL_SUBPOOL_MAX_TXS_DEFAULT: usize = 10_000;
 
@@ -27,6 +34,27 @@ pub const DEFAULT_PRICE_BUMP: u128 = 10;
 /// This enforces that a blob transaction requires a 100% price bump to be replaced
 pub const REPLACE_BLOB_PRICE_BUMP: u128 = 100;
 
+/// The default number of transactions of priority senders that are retained when the pool is
+/// full, see [`PrioritySenderConfig`].
+pub const TXPOOL_PRIORITY_RESERVED_SLOTS_DEFAULT: usize = 1_000;
+
+/// The default pool utilization (in %) at or above which the dynamic price floor is raised.
+pub const DEFAULT_PRICE_FLOOR_HIGH_WATERMARK: u8 = 90;
+
+/// The default pool utilization (in %) below which the dynamic price floor is lowered.
+pub const DEFAULT_PRICE_FLOOR_LOW_WATERMARK: u8 = 70;
+
+/// The default step (in %) by which the dynamic price floor is adjusted per canonical state change.
+pub const DEFAULT_PRICE_FLOOR_STEP: u8 = 10;
+
+/// The default upper bound of the dynamic price floor: 100 gwei.
+pub const DEFAULT_PRICE_FLOOR_MAX: u128 = 100_000_000_000;
+
+/// The smallest adjustment of the dynamic price floor: 0.01 gwei.
+///
+/// This ensures the floor can be raised from zero.
+pub const PRICE_FLOOR_MIN_STEP: u128 = 10_000_000;
+
 /// Default maximum new transactions for broadcasting.
 pub const MAX_NEW_PENDING_TXS_NOTIFICATIONS: usize = 200;
 
@@ -43,8 +71,27 @@ pub struct PoolConfig {
     pub blob_limit: SubPoolLimit,
     /// Max number of executable transaction slots guaranteed per account
     pub max_account_slots: usize,
+    /// Max number of transactions of a single sender in the queued sub-pool.
+    ///
+    /// If exceeded, the queued transactions of the sender with the highest nonces are evicted.
+    /// Local transactions are exempt, see [`LocalTransactionConfig`].
+    pub max_queued_per_sender: usize,
+    /// Whether to reject transactions whose cost, together with the cost of all pooled
+    /// transactions of the sender with a lower nonce, exceeds the sender's on-chain balance.
+    ///
+    /// By default such transactions are accepted and parked until the sender can afford them,
+    /// which allows filling the pool with chains of transactions that can never be executed.
+    /// Local transactions are exempt, see [`LocalTransactionConfig`].
+    pub reject_overdraft: bool,
+    /// Max time a transaction is kept in the queued sub-pool before it is evicted.
+    ///
+    /// This is enforced on every canonical state change. Local transactions are exempt, see
+    /// [`LocalTransactionConfig`].
+    pub queued_lifetime: Duration,
     /// Price bump (in %) for the transaction pool underpriced check.
     pub price_bumps: PriceBumpConfig,
+    /// Minimum priority fee non-local transactions must pay to enter the pool.
+    pub price_floor: PriceFloorConfig,
     /// Minimum base fee required by the protocol.
     pub minimal_protocol_basefee: u64,
     /// The max gas limit for transactions in the pool
@@ -52,12 +99,24 @@ pub struct PoolConfig {
     /// How to handle locally received transactions:
     /// [`TransactionOrigin::Local`](TransactionOrigin).
     pub local_transactions_config: LocalTransactionConfig,
+    /// Senders whose transactions are retained when a sub-pool exceeds its limits.
+    pub priority_senders: PrioritySenderConfig,
     /// Bound on number of pending transactions from `reth_network::TransactionsManager` to buffer.
     pub pending_tx_listener_buffer_size: usize,
     /// Bound on number of new transactions from `reth_network::TransactionsManager` to buffer.
     pub new_tx_listener_buffer_size: usize,
     /// How many new pending transactions to buffer and send iterators in progress.
     pub max_new_pending_txs_notifications: usize,
+    /// Path of the journal that local transactions are persisted to, if any.
+    ///
+    /// Local transactions are appended to the journal when they enter the pool and can be
+    /// replayed on startup, see
+    /// [`LocalTransactionJournal`](crate::journal::LocalTransactionJournal).
+    pub local_journal_path: Option<PathBuf>,
+    /// The memory budget of the node the pool registers with.
+    ///
+    /// The size limits of the sub-pools are scaled down if the budget is exceeded.
+    pub memory_budget: MemoryBudget,
 }
 
 impl PoolConfig {
@@ -79,13 +138,20 @@ impl Default for PoolConfig {
             queued_limit: Default::default(),
             blob_limit: Default::default(),
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
+            max_queued_per_sender: TXPOOL_MAX_QUEUED_PER_SENDER_DEFAULT,
+            reject_overdraft: false,
+            queued_lifetime: DEFAULT_QUEUED_TRANSACTION_LIFETIME,
             price_bumps: Default::default(),
+            price_floor: Default::default(),
             minimal_protocol_basefee: MIN_PROTOCOL_BASE_FEE,
             gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
             local_transactions_config: Default::default(),
+            priority_senders: Default::default(),
             pending_tx_listener_buffer_size: PENDING_TX_LISTENER_BUFFER_SIZE,
             new_tx_listener_buffer_size: NEW_TX_LISTENER_BUFFER_SIZE,
             max_new_pending_txs_notifications: MAX_NEW_PENDING_TXS_NOTIFICATIONS,
+            local_journal_path: None,
+            memory_budget: Default::default(),
         }
     }
 }
@@ -110,6 +176,14 @@ impl SubPoolLimit {
     pub const fn is_exceeded(&self, txs: usize, size: usize) -> bool {
         self.max_txs < txs || self.max_size < size
     }
+
+    /// Returns how much of the limit (in %) is used by the given amount and size, whichever is
+    /// higher.
+    #[inline]
+    pub fn utilization(&self, txs: usize, size: usize) -> usize {
+        let percent = |used: usize, max: usize| used.saturating_mul(100) / max.max(1);
+        percent(txs, self.max_txs).max(percent(size, self.max_size))
+    }
 }
 
 impl Mul<usize> for SubPoolLimit {
@@ -160,6 +234,62 @@ impl Default for PriceBumpConfig {
     }
 }
 
+/// Configuration of the minimum priority fee of non-local transactions.
+///
+/// For legacy transactions the gas price is compared against the floor.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct PriceFloorConfig {
+    /// Minimum priority fee (in wei) that is always required.
+    ///
+    /// This can be changed at runtime, see
+    /// [`TransactionPool::set_minimum_priority_fee`](crate::TransactionPool::set_minimum_priority_fee).
+    pub minimum_priority_fee: u128,
+    /// If set, the floor is raised above the minimum while the pool is congested.
+    pub dynamic: Option<DynamicPriceFloorConfig>,
+}
+
+/// Settings of the dynamic price floor.
+///
+/// On every canonical state change the floor is raised by `step` % if the pool utilization is at
+/// or above the `high_watermark` and lowered by `step` % towards the minimum if the utilization
+/// is below the `low_watermark`. In between, the floor is kept as is.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct DynamicPriceFloorConfig {
+    /// Pool utilization (in %) at or above which the floor is raised.
+    pub high_watermark: u8,
+    /// Pool utilization (in %) below which the floor is lowered.
+    pub low_watermark: u8,
+    /// Adjustment (in %) of the floor per canonical state change.
+    pub step: u8,
+    /// Upper bound of the floor (in wei).
+    pub max_priority_fee: u128,
+}
+
+impl DynamicPriceFloorConfig {
+    /// Returns the next floor given the current floor, the minimum and the pool utilization.
+    pub fn next_floor(&self, current: u128, minimum: u128, utilization: usize) -> u128 {
+        let step = (current * self.step as u128 / 100).max(PRICE_FLOOR_MIN_STEP);
+        if utilization >= self.high_watermark as usize {
+            current.saturating_add(step).min(self.max_priority_fee.max(minimum))
+        } else if utilization < self.low_watermark as usize {
+            current.saturating_sub(step).max(minimum)
+        } else {
+            current.max(minimum)
+        }
+    }
+}
+
+impl Default for DynamicPriceFloorConfig {
+    fn default() -> Self {
+        Self {
+            high_watermark: DEFAULT_PRICE_FLOOR_HIGH_WATERMARK,
+            low_watermark: DEFAULT_PRICE_FLOOR_LOW_WATERMARK,
+            step: DEFAULT_PRICE_FLOOR_STEP,
+            max_priority_fee: DEFAULT_PRICE_FLOOR_MAX,
+        }
+    }
+}
+
 /// Configuration options for the locally received transactions:
 /// [`TransactionOrigin::Local`](TransactionOrigin)
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -223,6 +353,40 @@ impl LocalTransactionConfig {
     }
 }
 
+/// Configuration of the priority senders, e.g. the sequencer or operator of a rollup.
+///
+/// Transactions of priority senders are never evicted when a sub-pool exceeds its limits, as long
+/// as they fit into the reserved slots. The reserved slots are shared by all priority senders and
+/// assigned to the transactions with the lowest nonces of each sender, so that evicting the
+/// remaining transactions of a sender creates no nonce gaps.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PrioritySenderConfig {
+    /// The priority senders.
+    pub senders: HashSet<Address>,
+    /// Max number of transactions of priority senders that are exempt from eviction.
+    pub reserved_slots: usize,
+}
+
+impl PrioritySenderConfig {
+    /// Returns whether there are no priority senders.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.senders.is_empty() || self.reserved_slots == 0
+    }
+
+    /// Returns whether the given address is a priority sender.
+    #[inline]
+    pub fn is_priority_sender(&self, address: &Address) -> bool {
+        self.senders.contains(address)
+    }
+}
+
+impl Default for PrioritySenderConfig {
+    fn default() -> Self {
+        Self { senders: HashSet::default(), reserved_slots: TXPOOL_PRIORITY_RESERVED_SLOTS_DEFAULT }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,6 +426,30 @@ mod tests {
         assert!(config.is_exceeded(pool_size));
     }
 
+    #[test]
+    fn test_dynamic_price_floor_hysteresis() {
+        let config = DynamicPriceFloorConfig { max_priority_fee: 30_000_000, ..Default::default() };
+        let minimum = 0;
+
+        // raised from zero while congested, up to the max
+        let floor = config.next_floor(minimum, minimum, 95);
+        assert_eq!(floor, PRICE_FLOOR_MIN_STEP);
+        let floor = config.next_floor(floor, minimum, 90);
+        assert_eq!(floor, 2 * PRICE_FLOOR_MIN_STEP);
+        let floor = config.next_floor(config.next_floor(floor, minimum, 100), minimum, 100);
+        assert_eq!(floor, 30_000_000);
+
+        // kept between the watermarks
+        assert_eq!(config.next_floor(floor, minimum, 80), floor);
+        assert_eq!(config.next_floor(floor, minimum, 70), floor);
+
+        // lowered to the minimum once the pool drains
+        let floor = config.next_floor(floor, minimum, 69);
+        assert_eq!(floor, 2 * PRICE_FLOOR_MIN_STEP);
+        let floor = config.next_floor(config.next_floor(floor, minimum, 0), minimum, 0);
+        assert_eq!(floor, minimum);
+    }
+
     #[test]
     fn test_default_config() {
         let config = LocalTransactionConfig::default();
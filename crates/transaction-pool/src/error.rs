@@ -3,6 +3,7 @@
 use alloy_eips::eip4844::BlobTransactionValidationError;
 use alloy_primitives::{Address, TxHash, U256};
 use reth_primitives::InvalidTransactionError;
+use revm::primitives::HaltReason;
 
 /// Transaction pool result type.
 pub type PoolResult<T> = Result<T, PoolError>;
@@ -67,6 +68,35 @@ pub enum PoolErrorKind {
     Other(#[from] Box<dyn core::error::Error + Send + Sync>),
 }
 
+/// Errors that can happen when adding a [`Bundle`](crate::pool::Bundle) to the pool.
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    /// The bundle contains no transactions.
+    #[error("bundle contains no transactions")]
+    Empty,
+    /// The bundle targets a block that is already part of the chain.
+    #[error("bundle targets block {0} which is not in the future")]
+    Expired(u64),
+    /// The bundle targets a block that is too far past the tip.
+    #[error("bundle targets block {0} which is too far in the future")]
+    TooFarInFuture(u64),
+    /// The pool already tracks the maximum number of bundles.
+    #[error("bundle pool is full")]
+    PoolFull,
+    /// The pool already tracks the maximum number of bundles of a sender of the bundle.
+    #[error("sender {0} exceeds the maximum number of bundles")]
+    SenderLimitExceeded(Address),
+    /// Blob transactions can not be part of a bundle.
+    #[error("blob transaction {0} is not supported in bundles")]
+    UnsupportedBlobTransaction(TxHash),
+    /// A transaction of the bundle is invalid.
+    #[error(transparent)]
+    InvalidTransaction(#[from] PoolError),
+    /// The pool does not support bundles.
+    #[error("bundles are not supported")]
+    Unsupported,
+}
+
 // === impl PoolError ===
 
 impl PoolError {
@@ -182,6 +212,28 @@ pub enum Eip7702PoolTransactionError {
     MissingEip7702AuthorizationList,
 }
 
+/// Represents all errors that can happen when simulating transactions before they are admitted
+/// into the pool.
+///
+/// See [`AdmissionSimulator`](crate::validate::AdmissionSimulator).
+#[derive(Debug, thiserror::Error)]
+pub enum SimulationPoolTransactionError {
+    /// Thrown if the transaction reverted in the simulation
+    #[error("transaction reverted in simulation")]
+    Reverted,
+    /// Thrown if the transaction halted in the simulation, e.g. ran out of gas
+    #[error("transaction halted in simulation: {0:?}")]
+    Halted(HaltReason),
+    /// Thrown if the transaction used more gas in the simulation than the configured threshold
+    #[error("transaction used {gas_used} gas in simulation, exceeds threshold {threshold}")]
+    ExceedsGasThreshold {
+        /// The gas used in the simulation.
+        gas_used: u64,
+        /// The configured threshold.
+        threshold: u64,
+    },
+}
+
 /// Represents errors that can happen when validating transactions for the pool
 ///
 /// See [`TransactionValidator`](crate::TransactionValidator).
@@ -220,6 +272,9 @@ pub enum InvalidPoolTransactionError {
     /// EIP-7702 related errors
     #[error(transparent)]
     Eip7702(#[from] Eip7702PoolTransactionError),
+    /// Admission simulation related errors
+    #[error(transparent)]
+    Simulation(#[from] SimulationPoolTransactionError),
     /// Any other error that occurred while inserting/validating that is transaction specific
     #[error(transparent)]
     Other(Box<dyn PoolTransactionError>),
@@ -284,6 +339,11 @@ impl InvalidPoolTransactionError {
             }
             Self::IntrinsicGasTooLow => true,
             Self::Overdraft { .. } => false,
+            Self::Simulation(_) => {
+                // the peer made us execute a transaction that can't be included on top of the
+                // latest state, or that exceeds the gas we simulate for any peer
+                true
+            }
             Self::Other(err) => err.is_bad_transaction(),
             Self::Eip4844(eip4844_err) => {
                 match eip4844_err {
@@ -0,0 +1,425 @@
+//! Persistent log of pool events for forensic analysis.
+//!
+//! Every status change of a transaction, i.e. when it is added, replaced, mined, evicted or
+//! rejected by the validator, is turned into a [`PoolEventRecord`] by [`pool_event_records`]. The
+//! records can be consumed as a stream, e.g. by an `ExEx`, or written to a rotating JSONL file with
+//! [`pool_event_log_task`], one record per line, so that it can be reconstructed why a transaction
+//! never made it into a block.
+
+use crate::{
+    pool::{EvictedTransaction, EvictionReason, FullTransactionEvent, RejectedTransaction},
+    PoolTransaction, TransactionPool,
+};
+use alloy_primitives::{TxHash, B256};
+use futures_util::{future, stream, Stream, StreamExt};
+use reth_fs_util::FsPathError;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tracing::{debug, warn};
+
+/// Default max size of a single pool event log file: 100 MiB.
+pub const DEFAULT_POOL_EVENT_LOG_MAX_FILE_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Default number of pool event log files to keep, including the active one.
+pub const DEFAULT_POOL_EVENT_LOG_MAX_FILES: usize = 5;
+
+/// The file name of the active pool event log file. Rotated files are suffixed with their index,
+/// e.g. `pool-events.1.jsonl`, where a higher index is an older file.
+const POOL_EVENT_LOG_FILE_NAME: &str = "pool-events";
+
+/// The extension of pool event log files.
+const POOL_EVENT_LOG_FILE_EXTENSION: &str = "jsonl";
+
+/// The number of records that are buffered for the writer of the [`PoolEventLog`].
+const POOL_EVENT_LOG_BUFFER_SIZE: usize = 1024;
+
+/// A single event of a transaction in the pool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolEventRecord {
+    /// When the event was observed, in milliseconds since the unix epoch.
+    pub timestamp: u64,
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// What happened to the transaction.
+    #[serde(flatten)]
+    pub event: PoolEventKind,
+}
+
+impl PoolEventRecord {
+    /// Creates a new record of the event, observed now.
+    pub fn new(hash: TxHash, event: PoolEventKind) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+        Self { timestamp, hash, event }
+    }
+
+    /// Converts the pool event into a record.
+    ///
+    /// Returns `None` for events that don't change the status of the transaction, i.e.
+    /// propagation.
+    pub fn from_pool_event<T: PoolTransaction>(event: FullTransactionEvent<T>) -> Option<Self> {
+        let (hash, event) = match event {
+            FullTransactionEvent::Pending(hash) => (hash, PoolEventKind::Pending),
+            FullTransactionEvent::Queued(hash) => (hash, PoolEventKind::Queued),
+            FullTransactionEvent::Mined { tx_hash, block_hash } => {
+                (tx_hash, PoolEventKind::Mined { block_hash })
+            }
+            FullTransactionEvent::Replaced { transaction, replaced_by } => {
+                (*transaction.hash(), PoolEventKind::Replaced { replaced_by })
+            }
+            FullTransactionEvent::Discarded(hash) => (hash, PoolEventKind::Discarded),
+            FullTransactionEvent::Invalid(hash) => (hash, PoolEventKind::Invalid { reason: None }),
+            FullTransactionEvent::Propagated(_) => return None,
+        };
+        Some(Self::new(hash, event))
+    }
+
+    /// Converts the evicted transaction into a record.
+    pub fn from_evicted<T: PoolTransaction>(evicted: &EvictedTransaction<T>) -> Self {
+        Self::new(*evicted.hash(), PoolEventKind::Evicted { reason: evicted.reason })
+    }
+
+    /// Converts the transaction rejected by the validator into a record.
+    pub fn from_rejected(rejected: &RejectedTransaction) -> Self {
+        Self::new(
+            rejected.tx_hash,
+            PoolEventKind::Invalid { reason: Some(rejected.reason.to_string()) },
+        )
+    }
+}
+
+/// What happened to a transaction, see [`PoolEventRecord`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "camelCase")]
+pub enum PoolEventKind {
+    /// The transaction was added to the pending sub-pool.
+    Pending,
+    /// The transaction was added to the queued sub-pool.
+    Queued,
+    /// The transaction was replaced by another transaction of the same sender and nonce.
+    #[serde(rename_all = "camelCase")]
+    Replaced {
+        /// The hash of the replacement transaction.
+        replaced_by: TxHash,
+    },
+    /// The transaction was included in a block.
+    #[serde(rename_all = "camelCase")]
+    Mined {
+        /// The hash of the block that contains the transaction.
+        block_hash: B256,
+    },
+    /// The transaction was removed from the pool.
+    ///
+    /// An eviction due to the pool limits is recorded as [`PoolEventKind::Evicted`] and a
+    /// rejection by the validator as [`PoolEventKind::Invalid`], followed by this event.
+    Discarded,
+    /// The transaction was evicted because the pool limits were exceeded.
+    Evicted {
+        /// Why the transaction was evicted.
+        reason: EvictionReason,
+    },
+    /// The transaction is invalid, e.g. it was rejected by the validator.
+    Invalid {
+        /// Why the transaction is invalid, if known.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
+    },
+}
+
+/// Returns a stream of [`PoolEventRecord`]s of all transactions of the pool.
+pub fn pool_event_records<P>(pool: &P) -> impl Stream<Item = PoolEventRecord> + Send + Unpin
+where
+    P: TransactionPool,
+{
+    let events = pool
+        .all_transactions_event_listener()
+        .filter_map(|event| future::ready(PoolEventRecord::from_pool_event(event)));
+    let evictions = ReceiverStream::new(pool.evicted_transactions_listener())
+        .map(|evicted| PoolEventRecord::from_evicted(&evicted));
+    let rejections = ReceiverStream::new(pool.rejected_transactions_listener())
+        .map(|rejected| PoolEventRecord::from_rejected(&rejected));
+    stream::select(events, stream::select(evictions, rejections))
+}
+
+/// Configuration of the [`PoolEventLog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolEventLogConfig {
+    /// The directory the log files are written to.
+    pub dir: PathBuf,
+    /// The size in bytes after which the active log file is rotated.
+    pub max_file_size: u64,
+    /// The max number of log files to keep, including the active one. The oldest file is
+    /// deleted on rotation.
+    pub max_files: usize,
+}
+
+impl PoolEventLogConfig {
+    /// Creates a new config that writes the log files to the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_file_size: DEFAULT_POOL_EVENT_LOG_MAX_FILE_SIZE,
+            max_files: DEFAULT_POOL_EVENT_LOG_MAX_FILES,
+        }
+    }
+
+    /// Sets the size in bytes after which the active log file is rotated.
+    pub const fn with_max_file_size(mut self, max_file_size: u64) -> Self {
+        self.max_file_size = max_file_size;
+        self
+    }
+
+    /// Sets the max number of log files to keep.
+    pub const fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+}
+
+/// A rotating log of [`PoolEventRecord`]s, written as JSONL.
+#[derive(Debug)]
+pub struct PoolEventLog {
+    config: PoolEventLogConfig,
+    /// The active log file, opened lazily on the first write.
+    writer: Option<BufWriter<File>>,
+    /// The size of the active log file.
+    size: u64,
+}
+
+// === impl PoolEventLog ===
+
+impl PoolEventLog {
+    /// Creates a new log with the given config.
+    pub const fn new(config: PoolEventLogConfig) -> Self {
+        Self { config, writer: None, size: 0 }
+    }
+
+    /// Returns the path of the active log file.
+    pub fn path(&self) -> PathBuf {
+        self.file_path(0)
+    }
+
+    /// Returns the path of the log file with the given index, `0` is the active file.
+    fn file_path(&self, index: usize) -> PathBuf {
+        let name = if index == 0 {
+            format!("{POOL_EVENT_LOG_FILE_NAME}.{POOL_EVENT_LOG_FILE_EXTENSION}")
+        } else {
+            format!("{POOL_EVENT_LOG_FILE_NAME}.{index}.{POOL_EVENT_LOG_FILE_EXTENSION}")
+        };
+        self.config.dir.join(name)
+    }
+
+    /// Appends the record to the active log file and rotates it if it exceeds the max size.
+    ///
+    /// The record is buffered, see [`Self::flush`].
+    pub fn write(&mut self, record: &PoolEventRecord) -> Result<(), FsPathError> {
+        let mut line = serde_json::to_vec(record).expect("record is serializable");
+        line.push(b'\n');
+
+        if self.writer.is_none() {
+            self.open_writer()?;
+        }
+        let path = self.path();
+        let file = self.writer.as_mut().expect("writer is opened");
+        file.write_all(&line).map_err(|err| FsPathError::write(err, &path))?;
+        self.size += line.len() as u64;
+
+        if self.size >= self.config.max_file_size {
+            self.rotate()?;
+        }
+        Ok(())
+    }
+
+    /// Flushes all buffered records to the active log file.
+    pub fn flush(&mut self) -> Result<(), FsPathError> {
+        let path = self.path();
+        if let Some(file) = &mut self.writer {
+            file.flush().map_err(|err| FsPathError::write(err, &path))?;
+        }
+        Ok(())
+    }
+
+    /// Moves the active log file to index `1`, shifts all older files and deletes the oldest one
+    /// if there are more than the configured max files.
+    fn rotate(&mut self) -> Result<(), FsPathError> {
+        self.flush()?;
+        self.writer = None;
+        self.size = 0;
+
+        let max_files = self.config.max_files.max(1);
+        let oldest = self.file_path(max_files - 1);
+        if oldest.exists() {
+            reth_fs_util::remove_file(&oldest)?;
+        }
+        for index in (0..max_files - 1).rev() {
+            let from = self.file_path(index);
+            if from.exists() {
+                reth_fs_util::rename(&from, self.file_path(index + 1))?;
+            }
+        }
+
+        debug!(target: "txpool", log=?self.config.dir, "Rotated pool event log");
+        Ok(())
+    }
+
+    /// Opens the active log file for appending, creating it if it does not exist.
+    fn open_writer(&mut self) -> Result<(), FsPathError> {
+        reth_fs_util::create_dir_all(&self.config.dir)?;
+        let path = self.path();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|err| FsPathError::open(err, &path))?;
+        self.size = file.metadata().map_err(|err| FsPathError::metadata(err, &path))?.len();
+        self.writer = Some(BufWriter::new(file));
+        Ok(())
+    }
+}
+
+/// Task that writes all events of the pool to the [`PoolEventLog`] in the configured directory.
+///
+/// The records are written by a dedicated thread, which flushes the log whenever all queued
+/// records are written.
+pub async fn pool_event_log_task<P>(pool: P, config: PoolEventLogConfig)
+where
+    P: TransactionPool,
+{
+    let mut records = pool_event_records(&pool);
+    let mut log = PoolEventLog::new(config);
+    debug!(target: "txpool", log=?log.path(), "Writing pool event log");
+
+    let (to_writer, mut from_pool) = mpsc::channel(POOL_EVENT_LOG_BUFFER_SIZE);
+    let writer = std::thread::Builder::new().name("pool-event-log".to_string()).spawn(move || {
+        let write = |log: &mut PoolEventLog, record: PoolEventRecord| {
+            if let Err(err) = log.write(&record) {
+                warn!(target: "txpool", %err, hash=?record.hash, "Failed to write pool event log");
+            }
+        };
+        while let Some(record) = from_pool.blocking_recv() {
+            write(&mut log, record);
+            while let Ok(record) = from_pool.try_recv() {
+                write(&mut log, record);
+            }
+            if let Err(err) = log.flush() {
+                warn!(target: "txpool", %err, "Failed to flush pool event log");
+            }
+        }
+    });
+    if let Err(err) = writer {
+        warn!(target: "txpool", %err, "Failed to spawn pool event log writer");
+        return
+    }
+
+    // the writer exits once all records are written and the channel is closed
+    while let Some(record) = records.next().await {
+        if to_writer.send(record).await.is_err() {
+            break
+        }
+    }
+}
+
+/// Reads all records of the given log file.
+///
+/// Lines that can't be decoded, e.g. a partially written record after a crash, are skipped.
+pub fn read_pool_event_log(path: &Path) -> Result<Vec<PoolEventRecord>, FsPathError> {
+    let data = reth_fs_util::read_to_string(path)?;
+    Ok(data.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blobstore::InMemoryBlobStore,
+        test_utils::{testing_pool, MockTransactionFactory},
+        validate::EthTransactionValidatorBuilder,
+        CoinbaseTipOrdering, EthPooledTransaction, Pool,
+    };
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::hex;
+    use reth_chainspec::MAINNET;
+    use reth_primitives::{
+        transaction::SignedTransactionIntoRecoveredExt, PooledTransactionsElement,
+    };
+    use reth_provider::test_utils::MockEthProvider;
+
+    #[test]
+    fn record_json() {
+        let hash = TxHash::random();
+        let record = PoolEventRecord {
+            timestamp: 1,
+            hash,
+            event: PoolEventKind::Evicted { reason: EvictionReason::SubPoolLimit },
+        };
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json["event"], "evicted");
+        assert_eq!(json["reason"], "SubPoolLimit");
+        assert_eq!(serde_json::from_value::<PoolEventRecord>(json).unwrap(), record);
+    }
+
+    #[test]
+    fn rotate_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let record = PoolEventRecord::new(TxHash::random(), PoolEventKind::Pending);
+        let line_len = serde_json::to_vec(&record).unwrap().len() as u64 + 1;
+        let config =
+            PoolEventLogConfig::new(dir.path()).with_max_file_size(line_len * 2).with_max_files(2);
+        let mut log = PoolEventLog::new(config);
+
+        // fills the active file twice, the first file is deleted on the second rotation
+        for _ in 0..5 {
+            log.write(&record).unwrap();
+        }
+        log.flush().unwrap();
+        assert_eq!(read_pool_event_log(&log.path()).unwrap(), vec![record.clone()]);
+        assert_eq!(read_pool_event_log(&log.file_path(1)).unwrap().len(), 2);
+        assert!(!log.file_path(2).exists());
+    }
+
+    #[tokio::test]
+    async fn records_pool_events() {
+        let pool = testing_pool();
+        let mut records = pool_event_records(&pool);
+
+        let mut factory = MockTransactionFactory::default();
+        let tx = factory.create_eip1559();
+        pool.add_external_transaction(tx.transaction.clone()).await.unwrap();
+
+        let record = records.next().await.unwrap();
+        assert_eq!(record.hash, *tx.hash());
+        assert_eq!(record.event, PoolEventKind::Pending);
+    }
+
+    #[tokio::test]
+    async fn records_rejected_transactions() {
+        let blob_store = InMemoryBlobStore::default();
+        let validator = EthTransactionValidatorBuilder::new(MAINNET.clone())
+            .build(MockEthProvider::default(), blob_store.clone());
+        let pool =
+            Pool::new(validator, CoinbaseTipOrdering::default(), blob_store, Default::default());
+        let mut records = pool_event_records(&pool);
+
+        // the sender has no funds, so the transaction is rejected by the validator
+        let tx_bytes = hex!("02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507");
+        let tx = PooledTransactionsElement::decode_2718(&mut &tx_bytes[..]).unwrap();
+        let transaction: EthPooledTransaction = tx.try_into_ecrecovered().unwrap().into();
+        let hash = *transaction.hash();
+        assert!(pool.add_external_transaction(transaction).await.is_err());
+
+        let mut events = vec![records.next().await.unwrap(), records.next().await.unwrap()];
+        events.sort_by_key(|record| matches!(record.event, PoolEventKind::Discarded));
+        assert!(events.iter().all(|record| record.hash == hash));
+        assert!(matches!(&events[0].event, PoolEventKind::Invalid { reason: Some(_) }));
+        assert_eq!(events[1].event, PoolEventKind::Discarded);
+    }
+}
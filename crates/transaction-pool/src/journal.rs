@@ -0,0 +1,345 @@
+//! Disk-backed journal of local transactions.
+//!
+//! The journal is an append-only file of RLP encoded transactions, similar to geth's
+//! `transactions.rlp`. Every local transaction that enters the pool is appended to it, so that
+//! local transactions survive a restart of the node even if it was not shut down gracefully. On
+//! startup the journal is replayed into the pool and rotated, so that it only contains the local
+//! transactions that are still in the pool, see
+//! [`Pool::replay_local_journal`](crate::Pool::replay_local_journal). The journal is also rotated
+//! whenever it has grown too much since the last rotation, see
+//! [`LocalTransactionJournal::needs_rotation`].
+//!
+//! The file is written by a dedicated thread, so adding local transactions never waits for the
+//! file system.
+
+use alloy_rlp::{Decodable, Encodable};
+use parking_lot::Mutex;
+use reth_fs_util::FsPathError;
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc,
+    },
+};
+use tracing::{debug, warn};
+
+/// The size a journal may grow to before it is rotated, regardless of its size after the last
+/// rotation: 1 MiB.
+pub const LOCAL_JOURNAL_MIN_ROTATION_SIZE: u64 = 1024 * 1024;
+
+/// An append-only journal of RLP encoded local transactions.
+#[derive(Debug)]
+pub struct LocalTransactionJournal {
+    /// The path of the journal file.
+    path: PathBuf,
+    /// Sends writes to the writer thread, which is spawned lazily on the first write.
+    to_writer: Mutex<Option<mpsc::Sender<JournalCommand>>>,
+    /// The size of the journal file, including queued writes.
+    size: AtomicU64,
+    /// The size of the journal file after the last rotation.
+    rotated_size: AtomicU64,
+}
+
+// === impl LocalTransactionJournal ===
+
+impl LocalTransactionJournal {
+    /// Creates a new journal at the given path.
+    ///
+    /// The file is created on the first insert.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let size = std::fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or_default();
+        Self {
+            path,
+            to_writer: Mutex::new(None),
+            size: AtomicU64::new(size),
+            rotated_size: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the path of the journal file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Loads all transactions from the journal.
+    ///
+    /// Returns an empty list if the journal does not exist. A corrupted entry, e.g. a partially
+    /// written transaction after a crash, ends the journal: all transactions before it are
+    /// returned.
+    ///
+    /// Note: Writes that are still queued are not included, see [`Self::sync`].
+    pub fn load<T: Decodable>(&self) -> Result<Vec<T>, FsPathError> {
+        if !self.path.exists() {
+            return Ok(Vec::new())
+        }
+
+        let data = reth_fs_util::read(&self.path)?;
+        let mut buf = data.as_slice();
+        let mut transactions = Vec::new();
+        while !buf.is_empty() {
+            match T::decode(&mut buf) {
+                Ok(tx) => transactions.push(tx),
+                Err(err) => {
+                    warn!(target: "txpool", journal=?self.path, %err, loaded=transactions.len(), "Failed to decode local transaction journal entry, ignoring the rest of the journal");
+                    break
+                }
+            }
+        }
+
+        debug!(target: "txpool", journal=?self.path, num_txs=transactions.len(), "Loaded local transaction journal");
+        Ok(transactions)
+    }
+
+    /// Queues the transaction to be appended to the journal.
+    pub fn insert<T: Encodable>(&self, transaction: &T) {
+        let mut buf = Vec::with_capacity(transaction.length());
+        transaction.encode(&mut buf);
+        self.size.fetch_add(buf.len() as u64, Ordering::Relaxed);
+        self.send(JournalCommand::Append(buf));
+    }
+
+    /// Queues an atomic replacement of the content of the journal with the given transactions.
+    ///
+    /// All transactions that are inserted afterwards are appended to the replaced journal.
+    pub fn rotate<'a, T: Encodable + 'a>(&self, transactions: impl IntoIterator<Item = &'a T>) {
+        let mut buf = Vec::new();
+        let mut num_txs = 0;
+        for tx in transactions {
+            tx.encode(&mut buf);
+            num_txs += 1;
+        }
+
+        let size = buf.len() as u64;
+        self.size.store(size, Ordering::Relaxed);
+        self.rotated_size.store(size, Ordering::Relaxed);
+        self.send(JournalCommand::Replace { buf, num_txs });
+    }
+
+    /// Returns `true` if the journal has grown to more than twice its size after the last
+    /// rotation, and at least to [`LOCAL_JOURNAL_MIN_ROTATION_SIZE`].
+    ///
+    /// Transactions are only ever appended to the journal, so rotating it once it has grown
+    /// bounds its size by the local transactions that are in the pool.
+    pub fn needs_rotation(&self) -> bool {
+        let rotated_size = self.rotated_size.load(Ordering::Relaxed);
+        self.size.load(Ordering::Relaxed) >
+            rotated_size.saturating_mul(2).max(LOCAL_JOURNAL_MIN_ROTATION_SIZE)
+    }
+
+    /// Blocks until all queued writes are written to the journal file.
+    pub fn sync(&self) {
+        let (tx, rx) = mpsc::sync_channel(1);
+        if self.send(JournalCommand::Sync(tx)) {
+            let _ = rx.recv();
+        }
+    }
+
+    /// Sends the command to the writer thread, spawning it if it is not running yet.
+    ///
+    /// Returns `false` if the writer thread could not be spawned.
+    fn send(&self, command: JournalCommand) -> bool {
+        let mut to_writer = self.to_writer.lock();
+        if to_writer.is_none() {
+            let (tx, rx) = mpsc::channel();
+            let writer = JournalWriter { path: self.path.clone(), file: None };
+            match std::thread::Builder::new()
+                .name("txpool-journal".to_string())
+                .spawn(move || writer.run(rx))
+            {
+                Ok(_) => *to_writer = Some(tx),
+                Err(err) => {
+                    warn!(target: "txpool", %err, journal=?self.path, "Failed to spawn local transaction journal writer");
+                    return false
+                }
+            }
+        }
+        to_writer.as_ref().expect("writer is spawned").send(command).is_ok()
+    }
+}
+
+/// Commands for the [`JournalWriter`].
+#[derive(Debug)]
+enum JournalCommand {
+    /// Appends the encoded transaction to the journal.
+    Append(Vec<u8>),
+    /// Replaces the content of the journal with the encoded transactions.
+    Replace { buf: Vec<u8>, num_txs: usize },
+    /// Notifies the sender once all previous commands are processed.
+    Sync(mpsc::SyncSender<()>),
+}
+
+/// Writes the journal file on a dedicated thread.
+#[derive(Debug)]
+struct JournalWriter {
+    /// The path of the journal file.
+    path: PathBuf,
+    /// The file new transactions are appended to, opened lazily on the first append.
+    file: Option<File>,
+}
+
+impl JournalWriter {
+    /// Processes all commands until the [`LocalTransactionJournal`] is dropped.
+    fn run(mut self, commands: mpsc::Receiver<JournalCommand>) {
+        while let Ok(command) = commands.recv() {
+            let res = match command {
+                JournalCommand::Append(buf) => self.append(&buf),
+                JournalCommand::Replace { buf, num_txs } => self.replace(&buf).map(|_| {
+                    debug!(target: "txpool", journal=?self.path, num_txs, "Rotated local transaction journal");
+                }),
+                JournalCommand::Sync(tx) => {
+                    let _ = tx.send(());
+                    Ok(())
+                }
+            };
+            if let Err(err) = res {
+                warn!(target: "txpool", %err, "Failed to write local transaction journal");
+            }
+        }
+    }
+
+    fn append(&mut self, buf: &[u8]) -> Result<(), FsPathError> {
+        if self.file.is_none() {
+            self.create_parent_dir()?;
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .map_err(|err| FsPathError::open(err, &self.path))?;
+            self.file = Some(file);
+        }
+        let file = self.file.as_mut().expect("file is opened");
+        file.write_all(buf).map_err(|err| FsPathError::write(err, &self.path))
+    }
+
+    fn replace(&mut self, buf: &[u8]) -> Result<(), FsPathError> {
+        // the appended file is replaced, so the next append reopens the journal
+        self.file = None;
+        self.create_parent_dir()?;
+        reth_fs_util::atomic_write_file(&self.path, |file| file.write_all(buf))
+    }
+
+    /// Creates the directory of the journal file if it does not exist.
+    fn create_parent_dir(&self) -> Result<(), FsPathError> {
+        match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => reth_fs_util::create_dir_all(parent),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blobstore::InMemoryBlobStore, validate::EthTransactionValidatorBuilder,
+        CoinbaseTipOrdering, EthPooledTransaction, Pool, PoolConfig, PoolTransaction,
+        TransactionOrigin, TransactionPool,
+    };
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::{hex, Bytes, U256};
+    use reth_chainspec::MAINNET;
+    use reth_primitives::{
+        transaction::SignedTransactionIntoRecoveredExt, PooledTransactionsElement,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+    #[test]
+    fn insert_load_rotate() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = LocalTransactionJournal::new(dir.path().join("txpool").join("journal.rlp"));
+        assert!(journal.load::<U256>().unwrap().is_empty());
+
+        let entries = (1..=3u64).map(U256::from).collect::<Vec<_>>();
+        for entry in &entries {
+            journal.insert(entry);
+        }
+        journal.sync();
+        assert_eq!(journal.load::<U256>().unwrap(), entries);
+
+        // a reopened journal appends to the existing entries
+        let journal = LocalTransactionJournal::new(journal.path());
+        journal.insert(&U256::from(4));
+        journal.sync();
+        assert_eq!(journal.load::<U256>().unwrap().len(), 4);
+
+        journal.rotate(&entries[..1]);
+        journal.insert(&U256::from(5));
+        journal.sync();
+        assert_eq!(journal.load::<U256>().unwrap(), vec![U256::from(1), U256::from(5)]);
+    }
+
+    #[test]
+    fn truncated_journal() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = LocalTransactionJournal::new(dir.path().join("journal.rlp"));
+        let entries = vec![Bytes::from(vec![1; 64]), Bytes::from(vec![2; 64])];
+        journal.rotate(&entries);
+        journal.sync();
+
+        // simulate a crash while the last entry was written
+        let data = reth_fs_util::read(journal.path()).unwrap();
+        reth_fs_util::write(journal.path(), &data[..data.len() - 10]).unwrap();
+
+        assert_eq!(journal.load::<Bytes>().unwrap(), entries[..1]);
+    }
+
+    #[test]
+    fn rotation_bound() {
+        let dir = tempfile::tempdir().unwrap();
+        let journal = LocalTransactionJournal::new(dir.path().join("journal.rlp"));
+        let entry = Bytes::from(vec![1; 64 * 1024]);
+
+        for _ in 0..16 {
+            journal.insert(&entry);
+        }
+        assert!(journal.needs_rotation());
+
+        journal.rotate([&entry]);
+        assert!(!journal.needs_rotation());
+        journal.sync();
+        assert_eq!(journal.load::<Bytes>().unwrap(), vec![entry]);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn replay_journal() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = PoolConfig {
+            local_journal_path: Some(dir.path().join("journal.rlp")),
+            ..Default::default()
+        };
+
+        let tx_bytes = hex!("02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507");
+        let tx = PooledTransactionsElement::decode_2718(&mut &tx_bytes[..]).unwrap();
+        let transaction: EthPooledTransaction = tx.try_into_ecrecovered().unwrap().into();
+        let provider = MockEthProvider::default();
+        provider.add_account(transaction.sender(), ExtendedAccount::new(42, U256::MAX));
+
+        let new_pool = || {
+            let blob_store = InMemoryBlobStore::default();
+            let validator = EthTransactionValidatorBuilder::new(MAINNET.clone())
+                .build(provider.clone(), blob_store.clone());
+            Pool::new(validator, CoinbaseTipOrdering::default(), blob_store, config.clone())
+        };
+
+        let pool = new_pool();
+        assert_eq!(pool.replay_local_journal().await.unwrap(), 0);
+        pool.add_transaction(TransactionOrigin::Local, transaction.clone()).await.unwrap();
+        pool.inner().local_journal().unwrap().sync();
+        // the local transaction was appended to the journal
+        let journal = LocalTransactionJournal::new(dir.path().join("journal.rlp"));
+        assert_eq!(journal.load::<PooledTransactionsElement>().unwrap().len(), 1);
+
+        // a restarted pool contains the journaled transaction
+        let pool = new_pool();
+        assert_eq!(pool.replay_local_journal().await.unwrap(), 1);
+        let local = pool.get_local_transactions();
+        assert_eq!(local.len(), 1);
+        assert_eq!(local[0].hash(), transaction.hash());
+        pool.inner().local_journal().unwrap().sync();
+        assert_eq!(journal.load::<PooledTransactionsElement>().unwrap().len(), 1);
+    }
+}
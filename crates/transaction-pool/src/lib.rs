@@ -150,36 +150,49 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
-use crate::{identifier::TransactionId, pool::PoolInner};
+use crate::{
+    error::{BundleError, PoolError},
+    identifier::TransactionId,
+    pool::PoolInner,
+    snapshot::{PoolSnapshot, SnapshotTransaction},
+};
 use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
 use alloy_primitives::{Address, TxHash, B256, U256};
 use aquamarine as _;
 use reth_eth_wire_types::HandleMempoolData;
 use reth_execution_types::ChangedAccount;
-use reth_primitives::RecoveredTx;
+use reth_fs_util::FsPathError;
+use reth_primitives::{transaction::SignedTransactionIntoRecoveredExt, RecoveredTx};
 use reth_storage_api::StateProviderFactory;
 use std::{collections::HashSet, sync::Arc};
 use tokio::sync::mpsc::Receiver;
-use tracing::{instrument, trace};
+use tracing::{info, instrument, trace};
 
 pub use crate::{
     blobstore::{BlobStore, BlobStoreError},
     config::{
-        LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
+        DynamicPriceFloorConfig, LocalTransactionConfig, PoolConfig, PriceBumpConfig,
+        PriceFloorConfig, PrioritySenderConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
+        DEFAULT_PRICE_FLOOR_HIGH_WATERMARK, DEFAULT_PRICE_FLOOR_LOW_WATERMARK,
+        DEFAULT_PRICE_FLOOR_MAX, DEFAULT_PRICE_FLOOR_STEP, DEFAULT_QUEUED_TRANSACTION_LIFETIME,
         DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS, MAX_NEW_PENDING_TXS_NOTIFICATIONS,
         REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
+        TXPOOL_MAX_QUEUED_PER_SENDER_DEFAULT, TXPOOL_PRIORITY_RESERVED_SLOTS_DEFAULT,
         TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
     },
     error::PoolResult,
     ordering::{CoinbaseTipOrdering, Priority, TransactionOrdering},
     pool::{
-        blob_tx_priority, fee_delta, state::SubPool, AllTransactionsEvents, FullTransactionEvent,
-        TransactionEvent, TransactionEvents,
+        blob_tx_priority, fee_delta, state::SubPool, AllTransactionsEvents, Bundle,
+        EvictedTransaction, EvictionReason, FullTransactionEvent, RejectedTransaction,
+        TransactionEvent, TransactionEvents, ValidBundle, MAX_BUNDLES, MAX_BUNDLES_PER_SENDER,
+        MAX_BUNDLE_BLOCK_DISTANCE,
     },
     traits::*,
     validate::{
-        EthTransactionValidator, TransactionValidationOutcome, TransactionValidationTaskExecutor,
-        TransactionValidator, ValidPoolTransaction,
+        AdmissionSimulator, AdmissionSimulatorConfig, BlobVerificationCache,
+        EthTransactionValidator, EvmAdmissionSimulator, TransactionValidationOutcome,
+        TransactionValidationTaskExecutor, TransactionValidator, ValidPoolTransaction,
     },
 };
 
@@ -190,10 +203,15 @@ pub mod noop;
 pub mod pool;
 pub mod validate;
 
+pub mod analytics;
 pub mod blobstore;
 mod config;
+#[cfg(feature = "serde")]
+pub mod event_log;
 pub mod identifier;
+pub mod journal;
 mod ordering;
+pub mod snapshot;
 mod traits;
 
 #[cfg(any(test, feature = "test-utils"))]
@@ -276,6 +294,140 @@ where
     pub fn is_exceeded(&self) -> bool {
         self.pool.is_exceeded()
     }
+
+    /// Replays the journal of local transactions into the pool, see
+    /// [`PoolConfig::local_journal_path`].
+    ///
+    /// All journaled transactions are validated and added as local transactions. Afterwards the
+    /// journal is rotated so that it only contains the local transactions that are in the pool.
+    /// Returns the number of transactions that were added to the pool.
+    ///
+    /// This should be called once on startup, before the pool receives local transactions.
+    pub async fn replay_local_journal(&self) -> Result<usize, FsPathError>
+    where
+        V::Transaction: EthPoolTransaction,
+    {
+        let Some(journal) = self.pool.local_journal() else { return Ok(0) };
+        let transactions = journal
+            .load::<<V::Transaction as PoolTransaction>::Pooled>()?
+            .into_iter()
+            .filter_map(|tx| tx.try_into_ecrecovered().ok())
+            .map(<V::Transaction as PoolTransaction>::from_pooled)
+            .collect::<Vec<_>>();
+
+        let mut num_added = 0;
+        if !transactions.is_empty() {
+            let validated = self.validate_all(TransactionOrigin::Local, transactions).await;
+            let added = self.pool.add_transactions(
+                TransactionOrigin::Local,
+                validated.into_iter().map(|(_, outcome)| outcome),
+            );
+            num_added = added.iter().filter(|res| res.is_ok()).count();
+            info!(target: "txpool", journal=?journal.path(), num_txs=added.len(), num_added, "Replayed local transaction journal");
+        }
+
+        self.pool.rotate_local_journal();
+        Ok(num_added)
+    }
+
+    /// Appends the local transactions that were added to the pool to the journal, if configured,
+    /// and rotates the journal if it has grown too much, see
+    /// [`LocalTransactionJournal::needs_rotation`](journal::LocalTransactionJournal::needs_rotation).
+    ///
+    /// This must be called after the pool lock is released.
+    fn journal_local_transactions(&self, origin: TransactionOrigin, results: &[PoolResult<TxHash>])
+    where
+        V::Transaction: EthPoolTransaction,
+    {
+        if !origin.is_local() {
+            return
+        }
+        let Some(journal) = self.pool.local_journal() else { return };
+        let hashes = results.iter().filter_map(|res| res.as_ref().ok().copied()).collect();
+        for tx in self.pool.get_all(hashes) {
+            if let Some(pooled) = self.pool.to_pooled_transaction(tx) {
+                journal.insert(&pooled.into_signed());
+            }
+        }
+
+        if journal.needs_rotation() {
+            self.pool.rotate_local_journal();
+        }
+    }
+
+    /// Returns a [`PoolSnapshot`] of all transactions in the pool, pending transactions first.
+    ///
+    /// Blob transactions include their sidecar; blob transactions whose sidecar is missing from
+    /// the blob store are skipped.
+    pub fn snapshot(&self) -> PoolSnapshot<<V::Transaction as PoolTransaction>::Pooled>
+    where
+        V::Transaction: EthPoolTransaction,
+    {
+        let AllPoolTransactions { pending, queued } = self.pool.all_transactions();
+        let transactions = pending
+            .into_iter()
+            .map(|tx| (tx, true))
+            .chain(queued.into_iter().map(|tx| (tx, false)))
+            .filter_map(|(tx, pending)| {
+                let origin = tx.origin;
+                let hash = *tx.hash();
+                let sender = tx.sender();
+                let nonce = tx.nonce();
+                let tx_type = tx.tx_type();
+                let transaction = self.pool.to_pooled_transaction(tx)?.into_signed();
+                Some(SnapshotTransaction {
+                    origin,
+                    pending,
+                    hash,
+                    sender,
+                    nonce,
+                    tx_type,
+                    transaction,
+                })
+            })
+            .collect();
+
+        PoolSnapshot { block_number: self.pool.block_info().last_seen_block_number, transactions }
+    }
+
+    /// Adds all transactions of the given [`PoolSnapshot`] to the pool.
+    ///
+    /// The transactions are validated against the current state and keep their origin. Returns
+    /// the number of transactions that were added to the pool.
+    pub async fn restore(
+        &self,
+        snapshot: PoolSnapshot<<V::Transaction as PoolTransaction>::Pooled>,
+    ) -> usize
+    where
+        V::Transaction: EthPoolTransaction,
+    {
+        let num_txs = snapshot.transactions.len();
+        let mut num_added = 0;
+        for origin in
+            [TransactionOrigin::Local, TransactionOrigin::External, TransactionOrigin::Private]
+        {
+            let transactions = snapshot
+                .transactions
+                .iter()
+                .filter(|tx| tx.origin == origin)
+                .filter_map(|tx| tx.transaction.clone().try_into_ecrecovered().ok())
+                .map(<V::Transaction as PoolTransaction>::from_pooled)
+                .collect::<Vec<_>>();
+            if transactions.is_empty() {
+                continue
+            }
+
+            let validated = self.validate_all(origin, transactions).await;
+            let added = self
+                .pool
+                .add_transactions(origin, validated.into_iter().map(|(_, outcome)| outcome));
+            self.journal_local_transactions(origin, &added);
+            num_added += added.iter().filter(|res| res.is_ok()).count();
+        }
+
+        info!(target: "txpool", block_number=snapshot.block_number, num_txs, num_added, "Restored transaction pool snapshot");
+        num_added
+    }
 }
 
 impl<Client, S> EthTransactionPool<Client, S>
@@ -338,13 +490,25 @@ where
         self.pool.block_info()
     }
 
+    fn price_floor(&self) -> u128 {
+        self.pool.price_floor()
+    }
+
+    fn set_minimum_priority_fee(&self, minimum_priority_fee: u128) {
+        self.pool.set_minimum_priority_fee(minimum_priority_fee)
+    }
+
     async fn add_transaction_and_subscribe(
         &self,
         origin: TransactionOrigin,
         transaction: Self::Transaction,
     ) -> PoolResult<TransactionEvents> {
         let (_, tx) = self.validate(origin, transaction).await;
-        self.pool.add_transaction_and_subscribe(origin, tx)
+        let listener = self.pool.add_transaction_and_subscribe(origin, tx);
+        if let Ok(listener) = &listener {
+            self.journal_local_transactions(origin, &[Ok(listener.hash())]);
+        }
+        listener
     }
 
     async fn add_transaction(
@@ -354,6 +518,7 @@ where
     ) -> PoolResult<TxHash> {
         let (_, tx) = self.validate(origin, transaction).await;
         let mut results = self.pool.add_transactions(origin, std::iter::once(tx));
+        self.journal_local_transactions(origin, &results);
         results.pop().expect("result length is the same as the input")
     }
 
@@ -367,7 +532,68 @@ where
         }
         let validated = self.validate_all(origin, transactions).await;
 
-        self.pool.add_transactions(origin, validated.into_iter().map(|(_, tx)| tx))
+        let results = self.pool.add_transactions(origin, validated.into_iter().map(|(_, tx)| tx));
+        self.journal_local_transactions(origin, &results);
+        results
+    }
+
+    async fn add_bundle(&self, bundle: Bundle<Self::Transaction>) -> Result<B256, BundleError> {
+        if bundle.transactions.is_empty() {
+            return Err(BundleError::Empty)
+        }
+        let tip = self.pool.block_info().last_seen_block_number;
+        if bundle.block_number <= tip {
+            return Err(BundleError::Expired(bundle.block_number))
+        }
+        if bundle.block_number > tip + MAX_BUNDLE_BLOCK_DISTANCE {
+            return Err(BundleError::TooFarInFuture(bundle.block_number))
+        }
+
+        let Bundle {
+            transactions,
+            block_number,
+            min_timestamp,
+            max_timestamp,
+            reverting_tx_hashes,
+        } = bundle;
+        let mut valid = Vec::with_capacity(transactions.len());
+        for (hash, outcome) in self.validate_all(TransactionOrigin::Private, transactions).await {
+            match outcome {
+                TransactionValidationOutcome::Valid { transaction, .. } => match transaction {
+                    validate::ValidTransaction::Valid(tx) => valid.push(tx),
+                    validate::ValidTransaction::ValidWithSidecar { .. } => {
+                        return Err(BundleError::UnsupportedBlobTransaction(hash))
+                    }
+                },
+                TransactionValidationOutcome::Invalid(_, err) => {
+                    return Err(PoolError::new(hash, err).into())
+                }
+                TransactionValidationOutcome::Error(hash, err) => {
+                    return Err(PoolError::other(hash, err).into())
+                }
+            }
+        }
+
+        let bundle = Bundle {
+            transactions: valid,
+            block_number,
+            min_timestamp,
+            max_timestamp,
+            reverting_tx_hashes,
+        };
+        self.pool.add_bundle(ValidBundle { hash: bundle.hash(), bundle })
+    }
+
+    fn cancel_bundle(&self, hash: &B256) -> bool {
+        self.pool.cancel_bundle(hash)
+    }
+
+    fn bundles(
+        &self,
+        block_number: u64,
+        timestamp: u64,
+    ) -> Vec<Arc<ValidBundle<Self::Transaction>>> {
+        self.pool.bundles(block_number, timestamp)
     }
 
     fn transaction_event_listener(&self, tx_hash: TxHash) -> Option<TransactionEvents> {
@@ -386,6 +612,14 @@ where
         self.pool.add_blob_sidecar_listener()
     }
 
+    fn evicted_transactions_listener(&self) -> Receiver<EvictedTransaction<Self::Transaction>> {
+        self.pool.add_eviction_listener()
+    }
+
+    fn rejected_transactions_listener(&self) -> Receiver<RejectedTransaction> {
+        self.pool.add_rejection_listener()
+    }
+
     fn new_transactions_listener_for(
         &self,
         kind: TransactionListenerKind,
@@ -599,6 +833,10 @@ where
     ) -> Result<Vec<Option<BlobAndProofV1>>, BlobStoreError> {
         self.pool.blob_store().get_by_versioned_hashes(versioned_hashes)
     }
+
+    fn blob_verification_cache(&self) -> Option<BlobVerificationCache> {
+        self.pool.validator().blob_verification_cache()
+    }
 }
 
 impl<V, T, S> TransactionPoolExt for Pool<V, T, S>
@@ -1,13 +1,15 @@
 //! Support for maintaining the state of the transaction pool
 
 use crate::{
-    blobstore::{BlobStoreCanonTracker, BlobStoreUpdates},
+    blobstore::{
+        BlobStoreCanonTracker, BlobStoreUpdates, MinedBlobSidecars, DEFAULT_MAX_MINED_BLOBS,
+    },
     error::PoolError,
     metrics::MaintainPoolMetrics,
     traits::{CanonicalStateUpdate, EthPoolTransaction, TransactionPool, TransactionPoolExt},
     BlockInfo, PoolTransaction, PoolUpdateKind,
 };
-use alloy_consensus::BlockHeader;
+use alloy_consensus::{BlockHeader, Transaction as _};
 use alloy_eips::BlockNumberOrTag;
 use alloy_primitives::{Address, BlockHash, BlockNumber};
 use alloy_rlp::Encodable;
@@ -17,7 +19,7 @@ use futures_util::{
 };
 use reth_chain_state::CanonStateNotification;
 use reth_chainspec::{ChainSpecProvider, EthChainSpec};
-use reth_execution_types::ChangedAccount;
+use reth_execution_types::{ChainBlocks, ChangedAccount};
 use reth_fs_util::FsPathError;
 use reth_primitives::{
     transaction::SignedTransactionIntoRecoveredExt, SealedHeader, TransactionSigned,
@@ -47,11 +49,20 @@ pub struct MaintainPoolConfig {
     ///
     /// Default: 100
     pub max_reload_accounts: usize,
+    /// Maximum number of blobs of mined transactions that are kept to restore reorged blob
+    /// transactions whose sidecar is no longer in the blob store.
+    ///
+    /// Default: [`DEFAULT_MAX_MINED_BLOBS`]
+    pub max_mined_blobs: u32,
 }
 
 impl Default for MaintainPoolConfig {
     fn default() -> Self {
-        Self { max_update_depth: 64, max_reload_accounts: 100 }
+        Self {
+            max_update_depth: 64,
+            max_reload_accounts: 100,
+            max_mined_blobs: DEFAULT_MAX_MINED_BLOBS,
+        }
     }
 }
 
@@ -105,7 +116,7 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
     Tasks: TaskSpawner + 'static,
 {
     let metrics = MaintainPoolMetrics::default();
-    let MaintainPoolConfig { max_update_depth, max_reload_accounts, .. } = config;
+    let MaintainPoolConfig { max_update_depth, max_reload_accounts, max_mined_blobs } = config;
     // ensure the pool points to latest state
     if let Ok(Some(latest)) = client.header_by_number_or_tag(BlockNumberOrTag::Latest) {
         let latest = SealedHeader::seal(latest);
@@ -127,6 +138,9 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
     // keeps track of mined blob transaction so we can clean finalized transactions
     let mut blob_store_tracker = BlobStoreCanonTracker::default();
 
+    // keeps the sidecars of mined blob transactions so they can be restored on reorg
+    let mut mined_blob_sidecars = MinedBlobSidecars::new(max_mined_blobs);
+
     // keeps track of the latest finalized block
     let mut last_finalized_block =
         FinalizedBlockTracker::new(client.finalized_block_number().ok().flatten());
@@ -329,15 +343,24 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
                             // been validated previously, we still need the blob in order to
                             // accurately set the transaction's
                             // encoded-length which is propagated over the network.
-                            pool.get_blob(TransactionSigned::hash(&tx))
-                                .ok()
-                                .flatten()
-                                .map(Arc::unwrap_or_clone)
-                                .and_then(|sidecar| {
-                                    <P as TransactionPool>::Transaction::try_from_eip4844(
-                                        tx, sidecar,
-                                    )
-                                })
+                            let sidecar = match pool.get_blob(TransactionSigned::hash(&tx)) {
+                                Ok(Some(sidecar)) => Some(Arc::unwrap_or_clone(sidecar)),
+                                _ => {
+                                    // fall back to the sidecars of mined transactions
+                                    let sidecar = tx
+                                        .blob_versioned_hashes()
+                                        .and_then(|hashes| mined_blob_sidecars.get(hashes));
+                                    if sidecar.is_some() {
+                                        metrics.inc_restored_blob_sidecars();
+                                    } else {
+                                        metrics.inc_missing_blob_sidecars();
+                                    }
+                                    sidecar
+                                }
+                            };
+                            sidecar.and_then(|sidecar| {
+                                <P as TransactionPool>::Transaction::try_from_eip4844(tx, sidecar)
+                            })
                         } else {
                             <P as TransactionPool>::Transaction::try_from_consensus(tx).ok()
                         }
@@ -367,6 +390,7 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
 
                 // keep track of new mined blob transactions
                 blob_store_tracker.add_new_chain_blocks(&new_blocks);
+                cache_mined_blob_sidecars(&pool, &new_blocks, &mut mined_blob_sidecars);
             }
             CanonStateNotification::Commit { new } => {
                 let (blocks, state) = new.inner();
@@ -441,11 +465,25 @@ pub async fn maintain_transaction_pool<Client, P, St, Tasks>(
 
                 // keep track of mined blob transactions
                 blob_store_tracker.add_new_chain_blocks(&blocks);
+                cache_mined_blob_sidecars(&pool, &blocks, &mut mined_blob_sidecars);
             }
         }
     }
 }
 
+/// Adds the sidecars of the blob transactions in the given blocks to the cache of mined sidecars.
+fn cache_mined_blob_sidecars<P: TransactionPool>(
+    pool: &P,
+    blocks: &ChainBlocks<'_, reth_primitives::Block>,
+    mined_blob_sidecars: &mut MinedBlobSidecars,
+) {
+    for tx in blocks.transactions().filter(|tx| tx.is_eip4844()) {
+        if let Ok(Some(sidecar)) = pool.get_blob(*tx.tx_hash()) {
+            mined_blob_sidecars.insert(&sidecar);
+        }
+    }
+}
+
 struct FinalizedBlockTracker {
     last_finalized_block: Option<BlockNumber>,
 }
@@ -1,7 +1,7 @@
 //! Transaction pool metrics.
 
 use reth_metrics::{
-    metrics::{Counter, Gauge},
+    metrics::{Counter, Gauge, Histogram},
     Metrics,
 };
 
@@ -51,6 +51,27 @@ pub struct TxPoolMetrics {
 
     /// How often the pool was updated after the canonical state changed
     pub(crate) performed_state_updates: Counter,
+    /// How long mined transactions were in the pool before they were included, in seconds
+    pub(crate) mined_transaction_time_in_pool: Histogram,
+
+    /// The minimum priority fee non-local transactions currently have to pay
+    pub(crate) price_floor: Gauge,
+}
+
+/// Metrics of transactions that left the pool, labeled by how they left it and their tip
+#[derive(Metrics)]
+#[metrics(scope = "transaction_pool.analytics")]
+pub struct PoolAnalyticsMetrics {
+    /// Number of transactions that left the pool
+    pub(crate) transactions: Counter,
+    /// How long transactions were in the pool, in seconds
+    pub(crate) time_in_pool: Histogram,
+    /// How long ago the first transaction of the replacement chain of transactions was added to
+    /// the pool, in seconds
+    pub(crate) chain_time_in_pool: Histogram,
+    /// Number of transactions a transaction replaced, directly or through the transactions it
+    /// replaced
+    pub(crate) replacement_chain_length: Histogram,
 }
 
 /// Transaction pool blobstore metrics
@@ -82,6 +103,12 @@ pub struct MaintainPoolMetrics {
     pub(crate) reinserted_transactions: Counter,
     /// Counter for the number of finalized blob transactions that have been removed from tracking.
     pub(crate) deleted_tracked_finalized_blobs: Counter,
+    /// Counter for the number of reorged blob transactions whose sidecar was restored from the
+    /// cache of mined blob sidecars.
+    pub(crate) restored_blob_sidecars: Counter,
+    /// Counter for the number of reorged blob transactions that couldn't be re-added to the pool
+    /// because their sidecar is unknown.
+    pub(crate) missing_blob_sidecars: Counter,
 }
 
 impl MaintainPoolMetrics {
@@ -104,6 +131,16 @@ impl MaintainPoolMetrics {
     pub(crate) fn inc_drift(&self) {
         self.drift_count.increment(1);
     }
+
+    #[inline]
+    pub(crate) fn inc_restored_blob_sidecars(&self) {
+        self.restored_blob_sidecars.increment(1);
+    }
+
+    #[inline]
+    pub(crate) fn inc_missing_blob_sidecars(&self) {
+        self.missing_blob_sidecars.increment(1);
+    }
 }
 
 /// All Transactions metrics
@@ -118,8 +155,32 @@ pub struct AllTransactionsMetrics {
     pub(crate) all_transactions_by_all_senders: Gauge,
     /// Number of blob transactions nonce gaps.
     pub(crate) blob_transactions_nonce_gaps: Counter,
+    /// Number of inserted transactions that can't be pending because of a nonce gap
+    pub(crate) nonce_gap_blocked_transactions: Counter,
+    /// Number of transactions that were replaced by a transaction with the same nonce
+    pub(crate) replaced_transactions: Counter,
+    /// Number of replacements of a sender while it had transactions in the pool, recorded once
+    /// the sender has no transactions left in the pool
+    pub(crate) sender_replacements: Histogram,
+    /// Number of transactions rejected because the sender can't afford them together with its
+    /// pooled transactions.
+    pub(crate) overdraft_rejections: Counter,
+    /// Number of non-local transactions rejected because their priority fee is below the price
+    /// floor.
+    pub(crate) price_floor_rejections: Counter,
     /// The current blob base fee
     pub(crate) blob_base_fee: Gauge,
     /// The current base fee
     pub(crate) base_fee: Gauge,
 }
+
+/// Blob verification cache metrics
+#[derive(Metrics, Clone)]
+#[metrics(scope = "transaction_pool.blob_verification_cache")]
+pub struct BlobVerificationCacheMetrics {
+    /// Number of blob sidecar verifications that were skipped because the sidecar was verified
+    /// before
+    pub(crate) hits: Counter,
+    /// Number of blob sidecars that were verified
+    pub(crate) misses: Counter,
+}
@@ -5,16 +5,17 @@
 
 use crate::{
     blobstore::BlobStoreError,
-    error::PoolError,
+    error::{BundleError, PoolError},
     traits::{
         BestTransactionsAttributes, GetPooledTransactionLimit, NewBlobSidecar,
         TransactionListenerKind,
     },
     validate::ValidTransaction,
-    AllPoolTransactions, AllTransactionsEvents, BestTransactions, BlockInfo, EthPoolTransaction,
-    EthPooledTransaction, NewTransactionEvent, PoolResult, PoolSize, PoolTransaction,
-    PropagatedTransactions, TransactionEvents, TransactionOrigin, TransactionPool,
-    TransactionValidationOutcome, TransactionValidator, ValidPoolTransaction,
+    AllPoolTransactions, AllTransactionsEvents, BestTransactions, BlockInfo, Bundle,
+    EthPoolTransaction, EthPooledTransaction, EvictedTransaction, NewTransactionEvent, PoolResult,
+    PoolSize, PoolTransaction, PropagatedTransactions, RejectedTransaction, TransactionEvents,
+    TransactionOrigin, TransactionPool, TransactionValidationOutcome, TransactionValidator,
+    ValidBundle, ValidPoolTransaction,
 };
 use alloy_eips::{
     eip1559::ETHEREUM_BLOCK_GAS_LIMIT,
@@ -51,6 +52,12 @@ impl TransactionPool for NoopTransactionPool {
         }
     }
 
+    fn price_floor(&self) -> u128 {
+        0
+    }
+
+    fn set_minimum_priority_fee(&self, _minimum_priority_fee: u128) {}
+
     async fn add_transaction_and_subscribe(
         &self,
         _origin: TransactionOrigin,
@@ -83,6 +90,22 @@ impl TransactionPool for NoopTransactionPool {
             .collect()
     }
 
+    async fn add_bundle(&self, _bundle: Bundle<Self::Transaction>) -> Result<B256, BundleError> {
+        Err(BundleError::Unsupported)
+    }
+
+    fn cancel_bundle(&self, _hash: &B256) -> bool {
+        false
+    }
+
+    fn bundles(
+        &self,
+        _block_number: u64,
+        _timestamp: u64,
+    ) -> Vec<Arc<ValidBundle<Self::Transaction>>> {
+        vec![]
+    }
+
     fn transaction_event_listener(&self, _tx_hash: TxHash) -> Option<TransactionEvents> {
         None
     }
@@ -106,6 +129,14 @@ impl TransactionPool for NoopTransactionPool {
         mpsc::channel(1).1
     }
 
+    fn evicted_transactions_listener(&self) -> Receiver<EvictedTransaction<Self::Transaction>> {
+        mpsc::channel(1).1
+    }
+
+    fn rejected_transactions_listener(&self) -> Receiver<RejectedTransaction> {
+        mpsc::channel(1).1
+    }
+
     fn new_transactions_listener_for(
         &self,
         _kind: TransactionListenerKind,
@@ -345,6 +376,7 @@ impl<T: EthPoolTransaction> TransactionValidator for MockTransactionValidator<T>
                 TransactionOrigin::Local => self.propagate_local,
                 TransactionOrigin::Private => false,
             },
+            authorities: None,
         }
     }
 }
@@ -216,6 +216,13 @@ impl<T: TransactionOrdering> Iterator for BestTransactions<T> {
                     ),
                 )
             } else {
+                // an authorization consumes the nonce of its authority, so a pending transaction
+                // of the authority with the same nonce can no longer be executed
+                for id in best.transaction.authorization_ids() {
+                    if id.sender != sender_id && self.all.contains_key(id) {
+                        self.invalid.insert(id.sender);
+                    }
+                }
                 return Some(best.transaction)
             }
         }
@@ -506,6 +513,29 @@ mod tests {
         assert!(Iterator::next(&mut best).is_none());
     }
 
+    #[test]
+    fn test_best_iter_skips_consumed_authority_nonce() {
+        let mut pool = PendingPool::new(MockOrdering::default());
+        let mut f = MockTransactionFactory::default();
+
+        // the authority's own transaction has a lower priority than the transaction carrying its
+        // authorization
+        let authority_tx = f.validated(MockTransaction::eip1559().with_priority_fee(1));
+        let authority = authority_tx.transaction_id;
+        pool.add_transaction(Arc::new(authority_tx), 0);
+
+        let mut sponsored =
+            f.validated(MockTransaction::eip1559().with_max_fee(10).with_priority_fee(10));
+        sponsored.authorization_ids = Some(vec![authority]);
+        let sponsored = Arc::new(sponsored);
+        pool.add_transaction(sponsored.clone(), 0);
+
+        let mut best = pool.best();
+        assert_eq!(best.next().unwrap().hash(), sponsored.hash());
+        // the authorization consumed the nonce of the authority's transaction
+        assert!(best.next().is_none());
+    }
+
     #[test]
     fn test_best_with_fees_iter_base_fee_satisfied() {
         let mut pool = PendingPool::new(MockOrdering::default());
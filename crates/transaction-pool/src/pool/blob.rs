@@ -1,4 +1,4 @@
-use super::txpool::PendingFees;
+use super::txpool::{PendingFees, ProtectedTransactions};
 use crate::{
     identifier::TransactionId, pool::size::SizeTracker, traits::BestTransactionsAttributes,
     PoolTransaction, SubPoolLimit, ValidPoolTransaction,
@@ -216,20 +216,37 @@ impl<T: PoolTransaction> BlobTransactions<T> {
     /// This is done by removing transactions according to their ordering in the pool, defined by
     /// the [`BlobOrd`] struct.
     ///
+    /// The `protected` transactions are never removed.
+    ///
     /// Removed transactions are returned in the order they were removed.
     pub(crate) fn truncate_pool(
         &mut self,
         limit: SubPoolLimit,
+        protected: &ProtectedTransactions,
     ) -> Vec<Arc<ValidPoolTransaction<T>>> {
-        let mut removed = Vec::new();
-
-        while self.exceeds(&limit) {
-            let tx = self.all.last().expect("pool is not empty");
-            let id = *tx.transaction.id();
-            removed.push(self.remove_transaction(&id).expect("transaction exists"));
-        }
+        // select the worst transactions that are not protected in a single pass
+        let mut len = self.len();
+        let mut size = self.size();
+        let to_remove = self
+            .all
+            .iter()
+            .rev()
+            .filter(|tx| !protected.contains(tx.transaction.id()))
+            .take_while(|tx| {
+                if !limit.is_exceeded(len, size) {
+                    return false
+                }
+                len -= 1;
+                size -= tx.transaction.size();
+                true
+            })
+            .map(|tx| *tx.transaction.id())
+            .collect::<Vec<_>>();
 
-        removed
+        to_remove
+            .into_iter()
+            .map(|id| self.remove_transaction(&id).expect("transaction exists"))
+            .collect()
     }
 
     /// Returns `true` if the transaction with the given id is already included in this pool.
@@ -774,7 +791,7 @@ mod tests {
 
         // Set a size limit that requires truncation
         let limit = SubPoolLimit { max_txs: 2, max_size: 300 };
-        let removed = pool.truncate_pool(limit);
+        let removed = pool.truncate_pool(limit, &Default::default());
 
         // Check that only one transaction was removed to satisfy the limit
         assert_eq!(removed.len(), 1);
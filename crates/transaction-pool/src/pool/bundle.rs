@@ -0,0 +1,259 @@
+//! Bundles of transactions that must be included together and in order.
+//!
+//! Bundles are submitted via `eth_sendBundle` and kept separate from the regular sub-pools: the
+//! transactions of a bundle are not gossiped and are only considered by the payload builder as a
+//! whole, see [`TransactionPool::bundles`](crate::TransactionPool::bundles).
+
+use crate::{error::BundleError, PoolTransaction};
+use alloy_primitives::{keccak256, Address, TxHash, B256};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+/// The maximum number of bundles tracked by the pool.
+pub const MAX_BUNDLES: usize = 1024;
+
+/// The maximum number of tracked bundles that contain a transaction of the same sender.
+pub const MAX_BUNDLES_PER_SENDER: usize = 16;
+
+/// The maximum number of blocks past the tip a bundle can target.
+pub const MAX_BUNDLE_BLOCK_DISTANCE: u64 = 25;
+
+/// A group of transactions that must be included together and in the given order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bundle<T> {
+    /// The transactions of the bundle, in execution order.
+    pub transactions: Vec<T>,
+    /// The block number the bundle is valid for.
+    pub block_number: u64,
+    /// The minimum timestamp of the block the bundle is valid for.
+    pub min_timestamp: Option<u64>,
+    /// The maximum timestamp of the block the bundle is valid for.
+    pub max_timestamp: Option<u64>,
+    /// Hashes of the transactions that are allowed to revert without invalidating the bundle.
+    pub reverting_tx_hashes: Vec<TxHash>,
+}
+
+impl<T: PoolTransaction> Bundle<T> {
+    /// Returns the hash of the bundle: the keccak256 hash of the concatenated transaction hashes.
+    pub fn hash(&self) -> B256 {
+        let mut buf = Vec::with_capacity(self.transactions.len() * 32);
+        for tx in &self.transactions {
+            buf.extend_from_slice(tx.hash().as_slice());
+        }
+        keccak256(buf)
+    }
+}
+
+impl<T: PoolTransaction> Bundle<T> {
+    /// Returns the distinct senders of the transactions of the bundle.
+    pub fn senders(&self) -> Vec<Address> {
+        let mut senders = self.transactions.iter().map(|tx| tx.sender()).collect::<Vec<_>>();
+        senders.sort_unstable();
+        senders.dedup();
+        senders
+    }
+}
+
+impl<T> Bundle<T> {
+    /// Returns `true` if the bundle can be included in a block with the given number and
+    /// timestamp.
+    pub fn is_valid_at(&self, block_number: u64, timestamp: u64) -> bool {
+        self.block_number == block_number &&
+            self.min_timestamp.is_none_or(|min| timestamp >= min) &&
+            self.max_timestamp.is_none_or(|max| timestamp <= max)
+    }
+
+    /// Returns `true` if the transaction with the given hash is allowed to revert.
+    pub fn can_revert(&self, hash: &TxHash) -> bool {
+        self.reverting_tx_hashes.contains(hash)
+    }
+}
+
+/// A bundle whose transactions were validated by the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidBundle<T> {
+    /// The hash of the bundle.
+    pub hash: B256,
+    /// The validated bundle.
+    pub bundle: Bundle<T>,
+}
+
+/// Tracks the bundles of the pool in insertion order.
+#[derive(Debug)]
+pub(crate) struct BundlePool<T> {
+    /// Sequence number assigned to the next inserted bundle.
+    next_id: u64,
+    /// All bundles by insertion order.
+    by_id: BTreeMap<u64, Arc<ValidBundle<T>>>,
+    /// Maps the hash of a bundle to its sequence number.
+    by_hash: HashMap<B256, u64>,
+    /// The number of tracked bundles that contain a transaction of the sender.
+    by_sender: HashMap<Address, usize>,
+}
+
+// === impl BundlePool ===
+
+impl<T: PoolTransaction> BundlePool<T> {
+    /// Returns the number of tracked bundles.
+    pub(crate) fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    /// Adds the bundle to the pool.
+    ///
+    /// Re-submitting a tracked bundle is a no-op.
+    pub(crate) fn insert(&mut self, bundle: ValidBundle<T>) -> Result<B256, BundleError> {
+        let hash = bundle.hash;
+        if self.by_hash.contains_key(&hash) {
+            return Ok(hash)
+        }
+        if self.len() >= MAX_BUNDLES {
+            return Err(BundleError::PoolFull)
+        }
+        let senders = bundle.bundle.senders();
+        if let Some(sender) = senders.iter().find(|sender| {
+            self.by_sender.get(*sender).is_some_and(|count| *count >= MAX_BUNDLES_PER_SENDER)
+        }) {
+            return Err(BundleError::SenderLimitExceeded(*sender))
+        }
+
+        for sender in senders {
+            *self.by_sender.entry(sender).or_default() += 1;
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.by_id.insert(id, Arc::new(bundle));
+        self.by_hash.insert(hash, id);
+        Ok(hash)
+    }
+
+    /// Removes the bundle with the given hash.
+    pub(crate) fn remove(&mut self, hash: &B256) -> Option<Arc<ValidBundle<T>>> {
+        let id = self.by_hash.remove(hash)?;
+        let bundle = self.by_id.remove(&id)?;
+        for sender in bundle.bundle.senders() {
+            if let Some(count) = self.by_sender.get_mut(&sender) {
+                *count -= 1;
+                if *count == 0 {
+                    self.by_sender.remove(&sender);
+                }
+            }
+        }
+        Some(bundle)
+    }
+
+    /// Returns all bundles that can be included in a block with the given number and timestamp,
+    /// in insertion order.
+    pub(crate) fn bundles_at(&self, block_number: u64, timestamp: u64) -> Vec<Arc<ValidBundle<T>>> {
+        self.by_id
+            .values()
+            .filter(|bundle| bundle.bundle.is_valid_at(block_number, timestamp))
+            .cloned()
+            .collect()
+    }
+
+    /// Removes all bundles targeting the given block or an earlier one.
+    ///
+    /// Returns the number of removed bundles.
+    pub(crate) fn prune(&mut self, block_number: u64) -> usize {
+        let outdated = self
+            .by_id
+            .values()
+            .filter(|bundle| bundle.bundle.block_number <= block_number)
+            .map(|bundle| bundle.hash)
+            .collect::<Vec<_>>();
+        for hash in &outdated {
+            self.remove(hash);
+        }
+        outdated.len()
+    }
+}
+
+impl<T> Default for BundlePool<T> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            by_id: Default::default(),
+            by_hash: Default::default(),
+            by_sender: Default::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockTransaction;
+
+    fn bundle(block_number: u64, nonce: u64) -> ValidBundle<MockTransaction> {
+        let tx = MockTransaction::eip1559().with_nonce(nonce).rng_hash();
+        bundle_of(block_number, tx)
+    }
+
+    fn bundle_of(block_number: u64, tx: MockTransaction) -> ValidBundle<MockTransaction> {
+        let bundle = Bundle {
+            transactions: vec![tx],
+            block_number,
+            min_timestamp: Some(10),
+            max_timestamp: Some(20),
+            reverting_tx_hashes: Vec::new(),
+        };
+        ValidBundle { hash: bundle.hash(), bundle }
+    }
+
+    #[test]
+    fn validity_window() {
+        let bundle = bundle(5, 0).bundle;
+        assert!(bundle.is_valid_at(5, 10));
+        assert!(bundle.is_valid_at(5, 20));
+        assert!(!bundle.is_valid_at(5, 9));
+        assert!(!bundle.is_valid_at(5, 21));
+        assert!(!bundle.is_valid_at(6, 15));
+    }
+
+    #[test]
+    fn insert_remove_prune() {
+        let mut pool = BundlePool::default();
+        let first = bundle(5, 0);
+        let second = bundle(6, 1);
+        let first_hash = pool.insert(first.clone()).unwrap();
+        // re-submission is a no-op
+        assert_eq!(pool.insert(first).unwrap(), first_hash);
+        let second_hash = pool.insert(second).unwrap();
+        assert_eq!(pool.len(), 2);
+
+        assert_eq!(pool.bundles_at(5, 15).len(), 1);
+        assert!(pool.bundles_at(5, 25).is_empty());
+
+        assert_eq!(pool.prune(5), 1);
+        assert!(pool.remove(&first_hash).is_none());
+        assert_eq!(pool.remove(&second_hash).unwrap().hash, second_hash);
+        assert_eq!(pool.len(), 0);
+    }
+
+    #[test]
+    fn limit_bundles_per_sender() {
+        let mut pool = BundlePool::default();
+        let sender = Address::random();
+        let mut hashes = Vec::new();
+        for nonce in 0..MAX_BUNDLES_PER_SENDER as u64 {
+            let tx = MockTransaction::eip1559().with_sender(sender).with_nonce(nonce).rng_hash();
+            hashes.push(pool.insert(bundle_of(5, tx)).unwrap());
+        }
+
+        let tx = MockTransaction::eip1559().with_sender(sender).rng_hash();
+        let err = pool.insert(bundle_of(5, tx.clone())).unwrap_err();
+        assert!(matches!(err, BundleError::SenderLimitExceeded(s) if s == sender));
+        // other senders are not affected
+        pool.insert(bundle(5, 0)).unwrap();
+
+        // removing a bundle of the sender frees a slot
+        pool.remove(&hashes[0]).unwrap();
+        pool.insert(bundle_of(5, tx)).unwrap();
+
+        assert_eq!(pool.prune(5), MAX_BUNDLES_PER_SENDER + 1);
+        assert!(pool.by_sender.is_empty());
+    }
+}
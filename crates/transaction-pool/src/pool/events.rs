@@ -54,6 +54,57 @@ impl<T: PoolTransaction> Clone for FullTransactionEvent<T> {
     }
 }
 
+/// A transaction that was evicted from the pool, see [`EvictionReason`].
+#[derive(Debug)]
+pub struct EvictedTransaction<T: PoolTransaction> {
+    /// The evicted transaction.
+    pub transaction: Arc<ValidPoolTransaction<T>>,
+    /// Why the transaction was evicted.
+    pub reason: EvictionReason,
+}
+
+impl<T: PoolTransaction> EvictedTransaction<T> {
+    /// Creates a new instance for the evicted transaction.
+    pub const fn new(transaction: Arc<ValidPoolTransaction<T>>, reason: EvictionReason) -> Self {
+        Self { transaction, reason }
+    }
+
+    /// Returns the hash of the evicted transaction.
+    pub fn hash(&self) -> &TxHash {
+        self.transaction.hash()
+    }
+}
+
+impl<T: PoolTransaction> Clone for EvictedTransaction<T> {
+    fn clone(&self) -> Self {
+        Self { transaction: Arc::clone(&self.transaction), reason: self.reason }
+    }
+}
+
+/// A transaction that was rejected by the validator when it was added to the pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedTransaction {
+    /// The hash of the rejected transaction.
+    pub tx_hash: TxHash,
+    /// Why the transaction was rejected.
+    pub reason: Arc<str>,
+}
+
+/// The reason a transaction was evicted from the pool due to configured limits.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EvictionReason {
+    /// The sub-pool of the transaction, or of one of its ancestors, exceeded its size limits.
+    SubPoolLimit,
+    /// The sender exceeded the max number of queued transactions.
+    QueuedPerSenderLimit,
+    /// The transaction, or one of its ancestors, was queued for longer than the max lifetime.
+    QueuedLifetimeExceeded,
+    /// The blob store exceeded its size limit and evicted the blobs of the transaction, or of one
+    /// of its ancestors.
+    BlobStoreLimit,
+}
+
 /// Various events that describe status changes of a transaction.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -66,9 +66,11 @@
 //!    category (2.) and become pending.
 
 use crate::{
-    error::{PoolError, PoolErrorKind, PoolResult},
+    error::{BundleError, PoolError, PoolErrorKind, PoolResult},
     identifier::{SenderId, SenderIdentifiers, TransactionId},
+    journal::LocalTransactionJournal,
     pool::{
+        bundle::BundlePool,
         listener::PoolEventBroadcast,
         state::SubPool,
         txpool::{SenderInfo, TxPool},
@@ -87,7 +89,8 @@ use parking_lot::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
 use reth_eth_wire_types::HandleMempoolData;
 use reth_execution_types::ChangedAccount;
 
-use alloy_eips::eip4844::BlobTransactionSidecar;
+use alloy_eips::eip4844::{BlobTransactionSidecar, BYTES_PER_BLOB};
+use alloy_rlp::Encodable;
 use reth_primitives::RecoveredTx;
 use rustc_hash::FxHashMap;
 use std::{collections::HashSet, fmt, sync::Arc, time::Instant};
@@ -105,13 +108,19 @@ pub use best::{
     BestPayloadTransactions, BestTransactionFilter, BestTransactionsWithPrioritizedSenders,
 };
 pub use blob::{blob_tx_priority, fee_delta};
-pub use events::{FullTransactionEvent, TransactionEvent};
+pub use bundle::{
+    Bundle, ValidBundle, MAX_BUNDLES, MAX_BUNDLES_PER_SENDER, MAX_BUNDLE_BLOCK_DISTANCE,
+};
+pub use events::{
+    EvictedTransaction, EvictionReason, FullTransactionEvent, RejectedTransaction, TransactionEvent,
+};
 pub use listener::{AllTransactionsEvents, TransactionEvents};
 pub use parked::{BasefeeOrd, ParkedOrd, ParkedPool, QueuedOrd};
 pub use pending::PendingPool;
 
 mod best;
 mod blob;
+mod bundle;
 mod listener;
 mod parked;
 pub(crate) mod pending;
@@ -127,6 +136,10 @@ pub const NEW_TX_LISTENER_BUFFER_SIZE: usize = 1024;
 
 const BLOB_SIDECAR_LISTENER_BUFFER_SIZE: usize = 512;
 
+const EVICTION_LISTENER_BUFFER_SIZE: usize = 1024;
+
+const REJECTION_LISTENER_BUFFER_SIZE: usize = 1024;
+
 /// Transaction pool internals.
 pub struct PoolInner<V, T, S>
 where
@@ -150,8 +163,16 @@ where
     transaction_listener: Mutex<Vec<TransactionListener<T::Transaction>>>,
     /// Listener for new blob transaction sidecars added to the pool.
     blob_transaction_sidecar_listener: Mutex<Vec<BlobTransactionSidecarListener>>,
+    /// Listeners for transactions evicted from the pool.
+    eviction_listener: Mutex<Vec<mpsc::Sender<EvictedTransaction<T::Transaction>>>>,
+    /// Listeners for transactions rejected by the validator.
+    rejection_listener: Mutex<Vec<mpsc::Sender<RejectedTransaction>>>,
     /// Metrics for the blob store
     blob_store_metrics: BlobStoreMetrics,
+    /// Journal of local transactions, if configured.
+    local_journal: Option<LocalTransactionJournal>,
+    /// Bundles submitted to the pool.
+    bundles: RwLock<BundlePool<T::Transaction>>,
 }
 
 // === impl PoolInner ===
@@ -172,6 +193,10 @@ where
             pending_transaction_listener: Default::default(),
             transaction_listener: Default::default(),
             blob_transaction_sidecar_listener: Default::default(),
+            eviction_listener: Default::default(),
+            rejection_listener: Default::default(),
+            local_journal: config.local_journal_path.clone().map(LocalTransactionJournal::new),
+            bundles: Default::default(),
             config,
             blob_store,
             blob_store_metrics: Default::default(),
@@ -192,6 +217,16 @@ where
     pub fn block_info(&self) -> BlockInfo {
         self.get_pool_data().block_info()
     }
+
+    /// Returns the minimum priority fee non-local transactions currently have to pay.
+    pub fn price_floor(&self) -> u128 {
+        self.get_pool_data().price_floor()
+    }
+
+    /// Sets the minimum priority fee required for non-local transactions.
+    pub fn set_minimum_priority_fee(&self, minimum_priority_fee: u128) {
+        self.pool.write().set_minimum_priority_fee(minimum_priority_fee)
+    }
     /// Sets the currently tracked block
     pub fn set_block_info(&self, info: BlockInfo) {
         self.pool.write().set_block_info(info)
@@ -261,6 +296,22 @@ where
         rx
     }
 
+    /// Adds a new eviction listener to the pool that gets notified about every transaction that is
+    /// evicted from the pool due to the configured limits.
+    pub fn add_eviction_listener(&self) -> mpsc::Receiver<EvictedTransaction<T::Transaction>> {
+        let (sender, rx) = mpsc::channel(EVICTION_LISTENER_BUFFER_SIZE);
+        self.eviction_listener.lock().push(sender);
+        rx
+    }
+
+    /// Adds a new rejection listener to the pool that gets notified about every transaction that
+    /// is rejected by the validator, together with the reason.
+    pub fn add_rejection_listener(&self) -> mpsc::Receiver<RejectedTransaction> {
+        let (sender, rx) = mpsc::channel(REJECTION_LISTENER_BUFFER_SIZE);
+        self.rejection_listener.lock().push(sender);
+        rx
+    }
+
     /// If the pool contains the transaction, this adds a new listener that gets notified about
     /// transaction events.
     pub fn add_transaction_event_listener(&self, tx_hash: TxHash) -> Option<TransactionEvents> {
@@ -312,7 +363,7 @@ where
     ///
     /// If the transaction is an EIP-4844 transaction, the blob sidecar is fetched from the blob
     /// store and attached to the transaction.
-    fn to_pooled_transaction(
+    pub(crate) fn to_pooled_transaction(
         &self,
         transaction: Arc<ValidPoolTransaction<T::Transaction>>,
     ) -> Option<RecoveredTx<<<V as TransactionValidator>::Transaction as PoolTransaction>::Pooled>>
@@ -338,6 +389,11 @@ where
     }
 
     /// Returns pooled transactions for the given transaction hashes.
+    ///
+    /// The transactions are returned in request order. The response is packed up to the size limit
+    /// by the encoded size of the pooled transactions: transactions that would exceed the limit,
+    /// typically blob transactions with large sidecars, are skipped in favor of subsequent
+    /// transactions that still fit.
     pub fn get_pooled_transaction_elements(
         &self,
         tx_hashes: Vec<TxHash>,
@@ -350,17 +406,30 @@ where
         let mut elements = Vec::with_capacity(transactions.len());
         let mut size = 0;
         for transaction in transactions {
-            let encoded_len = transaction.encoded_length();
+            // the first transaction is always included, so that oversized transactions can still
+            // be served one at a time
+            if !elements.is_empty() {
+                if limit.exceeds(size) {
+                    break
+                }
+                // skip blob transactions whose blobs alone don't fit before loading the sidecar
+                let blobs_len = transaction.transaction.blob_count() * BYTES_PER_BLOB;
+                if limit.exceeds(size + blobs_len) {
+                    continue
+                }
+            }
+
             let Some(pooled) = self.to_pooled_transaction(transaction) else {
                 continue;
             };
+            let pooled = pooled.into_signed();
+            let encoded_len = pooled.length();
+            if !elements.is_empty() && limit.exceeds(size + encoded_len) {
+                continue
+            }
 
             size += encoded_len;
-            elements.push(pooled.into_signed());
-
-            if limit.exceeds(size) {
-                break
-            }
+            elements.push(pooled);
         }
 
         elements
@@ -389,19 +458,60 @@ where
 
         let changed_senders = self.changed_senders(changed_accounts.into_iter());
 
-        // update the pool
-        let outcome = self.pool.write().on_canonical_state_change(
-            block_info,
-            mined_transactions,
-            changed_senders,
-            update_kind,
-        );
+        // update the pool and evict expired queued transactions
+        let (outcome, expired) = {
+            let mut pool = self.pool.write();
+            let outcome = pool.on_canonical_state_change(
+                block_info,
+                mined_transactions,
+                changed_senders,
+                update_kind,
+            );
+            let expired = pool.remove_expired_queued(self.config.queued_lifetime);
+            (outcome, expired)
+        };
+
+        // bundles can only be included in future blocks
+        let pruned = self.bundles.write().prune(block_info.last_seen_block_number);
+        if pruned > 0 {
+            trace!(target: "txpool", pruned, "pruned outdated bundles");
+        }
 
         // This will discard outdated transactions based on the account's nonce
         self.delete_discarded_blobs(outcome.discarded.iter());
 
         // notify listeners about updates
         self.notify_on_new_state(outcome);
+
+        self.on_evicted_transactions(
+            expired
+                .into_iter()
+                .map(|tx| EvictedTransaction::new(tx, EvictionReason::QueuedLifetimeExceeded))
+                .collect(),
+        );
+    }
+
+    /// Adds a validated bundle to the pool.
+    pub fn add_bundle(&self, bundle: ValidBundle<T::Transaction>) -> Result<B256, BundleError> {
+        let hash = self.bundles.write().insert(bundle)?;
+        debug!(target: "txpool", %hash, "added bundle");
+        Ok(hash)
+    }
+
+    /// Removes the bundle with the given hash.
+    ///
+    /// Returns `true` if the bundle was tracked by the pool.
+    pub fn cancel_bundle(&self, hash: &B256) -> bool {
+        self.bundles.write().remove(hash).is_some()
+    }
+
+    /// Returns all bundles that can be included in a block with the given number and timestamp.
+    pub fn bundles(
+        &self,
+        block_number: u64,
+        timestamp: u64,
+    ) -> Vec<Arc<ValidBundle<T::Transaction>>> {
+        self.bundles.read().bundles_at(block_number, timestamp)
     }
 
     /// Performs account updates on the pool.
@@ -437,9 +547,19 @@ where
                 state_nonce,
                 transaction,
                 propagate,
+                authorities,
             } => {
                 let sender_id = self.get_sender_id(transaction.sender());
                 let transaction_id = TransactionId::new(sender_id, transaction.nonce());
+                let authorization_ids = authorities.map(|authorities| {
+                    let mut identifiers = self.identifiers.write();
+                    authorities
+                        .into_iter()
+                        .map(|(authority, nonce)| {
+                            TransactionId::new(identifiers.sender_id_or_create(authority), nonce)
+                        })
+                        .collect()
+                });
 
                 // split the valid transaction and the blob sidecar if it has any
                 let (transaction, maybe_sidecar) = match transaction {
@@ -459,6 +579,7 @@ where
                     propagate,
                     timestamp: Instant::now(),
                     origin,
+                    authorization_ids,
                 };
 
                 let added = pool.add_transaction(tx, balance, state_nonce)?;
@@ -496,18 +617,44 @@ where
                 Ok(hash)
             }
             TransactionValidationOutcome::Invalid(tx, err) => {
-                let mut listener = self.event_listener.write();
-                listener.discarded(tx.hash());
+                self.event_listener.write().discarded(tx.hash());
+                self.on_rejected_transaction(*tx.hash(), err.to_string());
                 Err(PoolError::new(*tx.hash(), err))
             }
             TransactionValidationOutcome::Error(tx_hash, err) => {
-                let mut listener = self.event_listener.write();
-                listener.discarded(&tx_hash);
+                self.event_listener.write().discarded(&tx_hash);
+                self.on_rejected_transaction(tx_hash, err.to_string());
                 Err(PoolError::other(tx_hash, err))
             }
         }
     }
 
+    /// Returns the journal of local transactions, if configured.
+    pub const fn local_journal(&self) -> Option<&LocalTransactionJournal> {
+        self.local_journal.as_ref()
+    }
+
+    /// Replaces the content of the journal with all local transactions currently in the pool and
+    /// returns their number.
+    ///
+    /// This keeps the journal from growing with transactions that have been mined or dropped.
+    /// Blob transactions are journaled with their sidecar; blob transactions whose sidecar is
+    /// missing from the blob store are skipped.
+    pub fn rotate_local_journal(&self) -> usize
+    where
+        <V as TransactionValidator>::Transaction: EthPoolTransaction,
+    {
+        let Some(journal) = &self.local_journal else { return 0 };
+        let transactions = self
+            .get_transactions_by_origin(TransactionOrigin::Local)
+            .into_iter()
+            .filter_map(|tx| self.to_pooled_transaction(tx))
+            .map(|tx| tx.into_signed())
+            .collect::<Vec<_>>();
+        journal.rotate(&transactions);
+        transactions.len()
+    }
+
     /// Adds a transaction and returns the event stream.
     pub fn add_transaction_and_subscribe(
         &self,
@@ -534,7 +681,7 @@ where
         transactions: impl IntoIterator<Item = TransactionValidationOutcome<T::Transaction>>,
     ) -> Vec<PoolResult<TxHash>> {
         // Add the transactions and enforce the pool size limits in one write lock
-        let (mut added, discarded) = {
+        let (mut added, evicted) = {
             let mut pool = self.pool.write();
             let added = transactions
                 .into_iter()
@@ -542,26 +689,42 @@ where
                 .collect::<Vec<_>>();
 
             // Enforce the pool size limits if at least one transaction was added successfully
-            let discarded = if added.iter().any(Result::is_ok) {
-                pool.discard_worst()
-            } else {
-                Default::default()
-            };
+            let mut evicted = Vec::new();
+            if added.iter().any(Result::is_ok) {
+                let senders = added
+                    .iter()
+                    .filter_map(|res| pool.get(res.as_ref().ok()?))
+                    .map(|tx| tx.sender_id())
+                    .collect::<HashSet<_>>();
+                for sender in senders {
+                    evicted.extend(pool.truncate_queued_by_sender(sender).into_iter().map(|tx| {
+                        EvictedTransaction::new(tx, EvictionReason::QueuedPerSenderLimit)
+                    }));
+                }
+                evicted.extend(
+                    pool.discard_worst()
+                        .into_iter()
+                        .map(|tx| EvictedTransaction::new(tx, EvictionReason::SubPoolLimit)),
+                );
+            }
 
-            (added, discarded)
-        };
+            // blob transactions can't be included without their blobs, so they are removed if the
+            // blob store evicted their blobs
+            let blobs_evicted = self.blob_store.take_evicted();
+            if !blobs_evicted.is_empty() {
+                evicted.extend(
+                    pool.remove_transactions_and_descendants(blobs_evicted)
+                        .into_iter()
+                        .map(|tx| EvictedTransaction::new(tx, EvictionReason::BlobStoreLimit)),
+                );
+            }
 
-        if !discarded.is_empty() {
-            // Delete any blobs associated with discarded blob transactions
-            self.delete_discarded_blobs(discarded.iter());
+            (added, evicted)
+        };
 
-            let discarded_hashes =
-                discarded.into_iter().map(|tx| *tx.hash()).collect::<HashSet<_>>();
-
-            {
-                let mut listener = self.event_listener.write();
-                discarded_hashes.iter().for_each(|hash| listener.discarded(hash));
-            }
+        if !evicted.is_empty() {
+            let discarded_hashes = evicted.iter().map(|tx| *tx.hash()).collect::<HashSet<_>>();
+            self.on_evicted_transactions(evicted);
 
             // A newly added transaction may be immediately discarded, so we need to
             // adjust the result here
@@ -577,6 +740,61 @@ where
         added
     }
 
+    /// Deletes the blobs of the evicted transactions and notifies all listeners about them.
+    fn on_evicted_transactions(&self, evicted: Vec<EvictedTransaction<T::Transaction>>) {
+        if evicted.is_empty() {
+            return
+        }
+
+        // Delete any blobs associated with evicted blob transactions
+        self.delete_discarded_blobs(evicted.iter().map(|tx| &tx.transaction));
+
+        {
+            let mut listener = self.event_listener.write();
+            evicted.iter().for_each(|tx| listener.discarded(tx.hash()));
+        }
+
+        let mut eviction_listeners = self.eviction_listener.lock();
+        eviction_listeners.retain_mut(|listener| {
+            for event in evicted.iter().cloned() {
+                match listener.try_send(event) {
+                    Ok(()) => {}
+                    Err(mpsc::error::TrySendError::Full(event)) => {
+                        debug!(
+                            target: "txpool",
+                            "[{:?}] failed to send evicted tx; channel full",
+                            event.hash(),
+                        );
+                    }
+                    Err(mpsc::error::TrySendError::Closed(_)) => return false,
+                }
+            }
+            true
+        });
+    }
+
+    /// Notifies all rejection listeners about the transaction rejected by the validator.
+    fn on_rejected_transaction(&self, tx_hash: TxHash, reason: String) {
+        let mut rejection_listeners = self.rejection_listener.lock();
+        if rejection_listeners.is_empty() {
+            return
+        }
+
+        let event = RejectedTransaction { tx_hash, reason: reason.into() };
+        rejection_listeners.retain_mut(|listener| match listener.try_send(event.clone()) {
+            Ok(()) => true,
+            Err(mpsc::error::TrySendError::Full(event)) => {
+                debug!(
+                    target: "txpool",
+                    "[{:?}] failed to send rejected tx; channel full",
+                    event.tx_hash,
+                );
+                true
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => false,
+        });
+    }
+
     /// Notify all listeners about a new pending transaction.
     fn on_new_pending_transaction(&self, pending: &AddedPendingTransaction<T::Transaction>) {
         let propagate_allowed = pending.is_propagate_allowed();
@@ -666,7 +884,7 @@ where
 
         match tx {
             AddedTransaction::Pending(tx) => {
-                let AddedPendingTransaction { transaction, promoted, discarded, replaced } = tx;
+                let AddedPendingTransaction { transaction, promoted, discarded, replaced, .. } = tx;
 
                 listener.pending(transaction.hash(), replaced.clone());
                 promoted.iter().for_each(|tx| listener.pending(tx.hash(), None));
@@ -679,6 +897,17 @@ where
                 }
             }
         }
+
+        // transactions with conflicting authorizations are replaced by the added transaction,
+        // their descendants are discarded
+        let authorization_ids = tx.transaction().authorization_ids();
+        for displaced in tx.displaced_transactions() {
+            if displaced.authorization_ids().iter().any(|id| authorization_ids.contains(id)) {
+                listener.replaced(displaced.clone(), *tx.hash());
+            } else {
+                listener.discarded(displaced.hash());
+            }
+        }
     }
 
     /// Returns an iterator that yields transactions that are ready to be included in the block.
@@ -1056,6 +1285,9 @@ pub struct AddedPendingTransaction<T: PoolTransaction> {
     promoted: Vec<Arc<ValidPoolTransaction<T>>>,
     /// transactions that failed and became discarded
     discarded: Vec<Arc<ValidPoolTransaction<T>>>,
+    /// transactions of other senders with conflicting EIP-7702 authorizations that were replaced,
+    /// and their descendants
+    displaced: Vec<Arc<ValidPoolTransaction<T>>>,
 }
 
 impl<T: PoolTransaction> AddedPendingTransaction<T> {
@@ -1140,6 +1372,9 @@ pub enum AddedTransaction<T: PoolTransaction> {
         transaction: Arc<ValidPoolTransaction<T>>,
         /// Replaced transaction.
         replaced: Option<Arc<ValidPoolTransaction<T>>>,
+        /// Transactions of other senders with conflicting EIP-7702 authorizations that were
+        /// replaced, and their descendants.
+        displaced: Vec<Arc<ValidPoolTransaction<T>>>,
         /// The subpool it was moved to.
         subpool: SubPool,
     },
@@ -1170,19 +1405,32 @@ impl<T: PoolTransaction> AddedTransaction<T> {
         }
     }
 
+    /// Returns the transactions that were removed because of conflicting EIP-7702 authorizations
+    pub(crate) fn displaced_transactions(&self) -> &[Arc<ValidPoolTransaction<T>>] {
+        match self {
+            Self::Pending(tx) => &tx.displaced,
+            Self::Parked { displaced, .. } => displaced,
+        }
+    }
+
     /// Returns the hash of the replaced transaction if it is a blob transaction.
     pub(crate) fn replaced_blob_transaction(&self) -> Option<B256> {
         self.replaced().filter(|tx| tx.transaction.is_eip4844()).map(|tx| *tx.transaction.hash())
     }
 
-    /// Returns the hash of the transaction
-    pub(crate) fn hash(&self) -> &TxHash {
+    /// Returns the added transaction
+    pub(crate) const fn transaction(&self) -> &Arc<ValidPoolTransaction<T>> {
         match self {
-            Self::Pending(tx) => tx.transaction.hash(),
-            Self::Parked { transaction, .. } => transaction.hash(),
+            Self::Pending(tx) => &tx.transaction,
+            Self::Parked { transaction, .. } => transaction,
         }
     }
 
+    /// Returns the hash of the transaction
+    pub(crate) fn hash(&self) -> &TxHash {
+        self.transaction().hash()
+    }
+
     /// Converts this type into the event type for listeners
     pub(crate) fn into_new_transaction_event(self) -> NewTransactionEvent<T> {
         match self {
@@ -1261,9 +1509,11 @@ mod tests {
         blobstore::{BlobStore, InMemoryBlobStore},
         test_utils::{MockTransaction, TestPoolBuilder},
         validate::ValidTransaction,
-        BlockInfo, PoolConfig, SubPoolLimit, TransactionOrigin, TransactionValidationOutcome, U256,
+        BlockInfo, GetPooledTransactionLimit, PoolConfig, SubPoolLimit, TransactionOrigin,
+        TransactionValidationOutcome, U256,
     };
     use alloy_eips::eip4844::BlobTransactionSidecar;
+    use alloy_rlp::Encodable;
     use reth_primitives::kzg::Blob;
     use std::{fs, path::PathBuf};
 
@@ -1328,6 +1578,7 @@ mod tests {
                         sidecar: sidecar.clone(),
                     },
                     propagate: true,
+                    authorities: None,
                 }],
             );
         }
@@ -1341,4 +1592,60 @@ mod tests {
         // Assert that the pool's blob store matches the expected blob store.
         assert_eq!(*test_pool.blob_store(), blob_store);
     }
+
+    #[test]
+    fn test_get_pooled_transaction_elements_packs_by_size() {
+        let test_pool = &TestPoolBuilder::default().pool;
+        test_pool
+            .set_block_info(BlockInfo { pending_blob_fee: Some(10_000_000), ..Default::default() });
+
+        let small = MockTransaction::eip1559();
+        let mut big = MockTransaction::eip1559();
+        big.set_input(vec![1u8; 1000].into());
+        let other_small = MockTransaction::eip1559();
+        let blob = MockTransaction::eip4844_with_sidecar(BlobTransactionSidecar::default());
+
+        let outcome = |tx: MockTransaction| {
+            let transaction = if tx.is_eip4844() {
+                ValidTransaction::ValidWithSidecar { transaction: tx, sidecar: Default::default() }
+            } else {
+                ValidTransaction::Valid(tx)
+            };
+            TransactionValidationOutcome::Valid {
+                balance: U256::from(1_000),
+                state_nonce: 0,
+                transaction,
+                propagate: true,
+                authorities: None,
+            }
+        };
+        let hashes =
+            vec![*blob.get_hash(), *small.get_hash(), *big.get_hash(), *other_small.get_hash()];
+        test_pool.add_transactions(
+            TransactionOrigin::External,
+            [blob, small, big, other_small].into_iter().map(outcome),
+        );
+
+        // transactions are served in request order
+        let all = test_pool
+            .get_pooled_transaction_elements(hashes.clone(), GetPooledTransactionLimit::None);
+        assert_eq!(all.len(), 4);
+        assert!(all[0].is_eip4844());
+        assert!(all[2].length() > 1000);
+
+        // the big transaction doesn't fit, but the transactions after it do
+        let limit = all[0].length() + all[1].length() + all[3].length();
+        let packed = test_pool.get_pooled_transaction_elements(
+            hashes.clone(),
+            GetPooledTransactionLimit::ResponseSizeSoftLimit(limit),
+        );
+        assert_eq!(packed, vec![all[0].clone(), all[1].clone(), all[3].clone()]);
+
+        // the first transaction is always served
+        let packed = test_pool.get_pooled_transaction_elements(
+            hashes,
+            GetPooledTransactionLimit::ResponseSizeSoftLimit(0),
+        );
+        assert_eq!(packed, vec![all[0].clone()]);
+    }
 }
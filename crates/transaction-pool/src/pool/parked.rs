@@ -1,6 +1,6 @@
 use crate::{
     identifier::{SenderId, TransactionId},
-    pool::size::SizeTracker,
+    pool::{size::SizeTracker, txpool::ProtectedTransactions},
     PoolTransaction, SubPoolLimit, ValidPoolTransaction, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
 };
 use rustc_hash::FxHashMap;
@@ -181,12 +181,14 @@ impl<T: ParkedOrd> ParkedPool<T> {
     /// descending order. Senders that have least recently submitted a transaction are first.
     ///
     /// Then, for each sender, all transactions for that sender are removed, until the pool limits
-    /// have been met.
+    /// have been met. The `protected` transactions are never removed, they are always the ones
+    /// with the lowest nonces of their sender.
     ///
     /// Any removed transactions are returned.
     pub fn truncate_pool(
         &mut self,
         limit: SubPoolLimit,
+        protected: &ProtectedTransactions,
     ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         if !self.exceeds(&limit) {
             // if we are below the limits, we don't need to drop anything
@@ -195,14 +197,24 @@ impl<T: ParkedOrd> ParkedPool<T> {
 
         let mut removed = Vec::new();
 
-        while limit.is_exceeded(self.len(), self.size()) && !self.last_sender_submission.is_empty()
-        {
-            // NOTE: This will not panic due to `!last_sender_transaction.is_empty()`
-            let sender_id = self.last_sender_submission.last().expect("not empty").sender_id;
+        let senders = self
+            .last_sender_submission
+            .iter()
+            .rev()
+            .map(|submission| submission.sender_id)
+            .collect::<Vec<_>>();
+        for sender_id in senders {
+            if !self.exceeds(&limit) {
+                break
+            }
             let list = self.get_txs_by_sender(sender_id);
 
             // Drop transactions from this sender until the pool is under limits
             for txid in list.into_iter().rev() {
+                if protected.contains(&txid) {
+                    // the remaining transactions of the sender have lower nonces
+                    break
+                }
                 if let Some(tx) = self.remove_transaction(&txid) {
                     removed.push(tx);
                 }
@@ -637,7 +649,7 @@ mod tests {
         let pool_limit = SubPoolLimit { max_txs: 4, max_size: usize::MAX };
 
         // truncate the pool
-        let removed = pool.truncate_pool(pool_limit);
+        let removed = pool.truncate_pool(pool_limit, &Default::default());
         assert_eq!(removed.len(), expected_removed.len());
 
         // get the inner txs from the removed txs
@@ -679,7 +691,7 @@ mod tests {
         }
 
         // truncate the pool, it should remove at least one transaction
-        let removed = pool.truncate_pool(default_limits);
+        let removed = pool.truncate_pool(default_limits, &Default::default());
         assert_eq!(removed.len(), 1);
     }
 
@@ -1009,14 +1021,14 @@ mod tests {
 
         // Set a limit that matches the current number of transactions
         let limit = SubPoolLimit { max_txs: 2, max_size: usize::MAX };
-        let removed = pool.truncate_pool(limit);
+        let removed = pool.truncate_pool(limit, &Default::default());
 
         // No transactions should be removed
         assert!(removed.is_empty());
 
         // Set a stricter limit that requires truncating one transaction
         let limit = SubPoolLimit { max_txs: 1, max_size: usize::MAX };
-        let removed = pool.truncate_pool(limit);
+        let removed = pool.truncate_pool(limit, &Default::default());
 
         // One transaction should be removed, and the pool should have one left
         assert_eq!(removed.len(), 1);
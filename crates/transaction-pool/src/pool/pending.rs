@@ -3,6 +3,7 @@ use crate::{
     pool::{
         best::{BestTransactions, BestTransactionsWithFees},
         size::SizeTracker,
+        txpool::ProtectedTransactions,
     },
     Priority, SubPoolLimit, TransactionOrdering, ValidPoolTransaction,
 };
@@ -362,11 +363,14 @@ impl<T: TransactionOrdering> PendingPool<T> {
     /// Otherwise, if the `remove_locals` flag is set, transactions will be removed per-sender
     /// until the pool is under the given limits.
     ///
+    /// The `protected` transactions are never removed.
+    ///
     /// Any removed transactions will be added to the `end_removed` vector.
     pub fn remove_to_limit(
         &mut self,
         limit: &SubPoolLimit,
         remove_locals: bool,
+        protected: &ProtectedTransactions,
         end_removed: &mut Vec<Arc<ValidPoolTransaction<T::Transaction>>>,
     ) {
         // This serves as a termination condition for the loop - it represents the number of
@@ -423,7 +427,10 @@ impl<T: TransactionOrdering> PendingPool<T> {
                     return
                 }
 
-                if !remove_locals && tx.transaction.is_local() {
+                // the lower nonces of a sender whose highest nonce is protected are protected too
+                if (!remove_locals && tx.transaction.is_local()) ||
+                    protected.contains(tx.transaction.id())
+                {
                     non_local_senders -= 1;
                     continue
                 }
@@ -456,11 +463,12 @@ impl<T: TransactionOrdering> PendingPool<T> {
     /// [`remove_to_limit`](PendingPool::remove_to_limit).
     ///
     /// This first truncates all of the non-local transactions in the pool. If the subpool is still
-    /// not under the limit, this truncates the entire pool, including non-local transactions. The
-    /// removed transactions are returned.
+    /// not under the limit, this truncates the entire pool, including non-local transactions.
+    /// The `protected` transactions are never removed. The removed transactions are returned.
     pub fn truncate_pool(
         &mut self,
         limit: SubPoolLimit,
+        protected: &ProtectedTransactions,
     ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let mut removed = Vec::new();
         // return early if the pool is already under the limits
@@ -469,14 +477,14 @@ impl<T: TransactionOrdering> PendingPool<T> {
         }
 
         // first truncate only non-local transactions, returning if the pool end up under the limit
-        self.remove_to_limit(&limit, false, &mut removed);
+        self.remove_to_limit(&limit, false, protected, &mut removed);
         if !self.exceeds(&limit) {
             return removed
         }
 
         // now repeat for local transactions, since local transactions must be removed now for the
         // pool to be under the limit
-        self.remove_to_limit(&limit, true, &mut removed);
+        self.remove_to_limit(&limit, true, protected, &mut removed);
 
         removed
     }
@@ -687,7 +695,8 @@ mod tests {
         );
 
         // truncate pool with max size = 1, ensure it's the same transaction
-        let removed = pool.truncate_pool(SubPoolLimit { max_txs: 1, max_size: usize::MAX });
+        let removed = pool
+            .truncate_pool(SubPoolLimit { max_txs: 1, max_size: usize::MAX }, &Default::default());
         assert_eq!(removed.len(), 1);
         assert_eq!(removed[0].hash(), t.hash());
     }
@@ -811,7 +820,7 @@ mod tests {
         let pool_limit = SubPoolLimit { max_txs: 4, max_size: usize::MAX };
 
         // Truncate the pool based on the defined limit.
-        let removed = pool.truncate_pool(pool_limit);
+        let removed = pool.truncate_pool(pool_limit, &Default::default());
         pool.assert_invariants();
         assert_eq!(removed.len(), expected_removed.len());
 
@@ -883,7 +892,8 @@ mod tests {
         assert_eq!(pool.size(), 0);
 
         // Verify that attempting to truncate an empty pool does not panic and returns an empty vec
-        let removed = pool.truncate_pool(SubPoolLimit { max_txs: 10, max_size: 1000 });
+        let removed =
+            pool.truncate_pool(SubPoolLimit { max_txs: 10, max_size: 1000 }, &Default::default());
         assert!(removed.is_empty());
 
         // Verify that retrieving transactions from an empty pool yields nothing
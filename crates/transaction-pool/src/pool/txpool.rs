@@ -1,7 +1,9 @@
 //! The internal transaction pool implementation.
 
 use crate::{
-    config::{LocalTransactionConfig, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER},
+    config::{
+        DynamicPriceFloorConfig, LocalTransactionConfig, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
+    },
     error::{Eip4844PoolTransactionError, InvalidPoolTransactionError, PoolError, PoolErrorKind},
     identifier::{SenderId, TransactionId},
     metrics::{AllTransactionsMetrics, TxPoolMetrics},
@@ -15,8 +17,8 @@ use crate::{
         AddedPendingTransaction, AddedTransaction, OnNewCanonicalStateOutcome,
     },
     traits::{BestTransactionsAttributes, BlockInfo, PoolSize},
-    PoolConfig, PoolResult, PoolTransaction, PoolUpdateKind, PriceBumpConfig, TransactionOrdering,
-    ValidPoolTransaction, U256,
+    PoolConfig, PoolResult, PoolTransaction, PoolUpdateKind, PriceBumpConfig, SubPoolLimit,
+    TransactionOrdering, ValidPoolTransaction, U256,
 };
 use alloy_consensus::constants::{
     EIP1559_TX_TYPE_ID, EIP2930_TX_TYPE_ID, EIP4844_TX_TYPE_ID, EIP7702_TX_TYPE_ID,
@@ -27,7 +29,8 @@ use alloy_eips::{
     eip4844::BLOB_TX_MIN_BLOB_GASPRICE,
 };
 use alloy_primitives::{Address, TxHash, B256};
-use rustc_hash::FxHashMap;
+use reth_memory_budget::MemoryConsumer;
+use rustc_hash::{FxHashMap, FxHashSet};
 use smallvec::SmallVec;
 use std::{
     cmp::Ordering,
@@ -35,6 +38,7 @@ use std::{
     fmt,
     ops::Bound::{Excluded, Unbounded},
     sync::Arc,
+    time::Duration,
 };
 use tracing::trace;
 
@@ -74,8 +78,13 @@ pub struct TxPool<T: TransactionOrdering> {
     blob_pool: BlobTransactions<T::Transaction>,
     /// All transactions in the pool.
     all_transactions: AllTransactions<T::Transaction>,
+    /// The ids of the priority senders that have submitted transactions, see
+    /// [`PrioritySenderConfig`](crate::PrioritySenderConfig).
+    priority_sender_ids: FxHashSet<SenderId>,
     /// Transaction pool metrics
     metrics: TxPoolMetrics,
+    /// The share of the node's memory budget, which shrinks the subpool limits under pressure.
+    memory: MemoryConsumer,
     /// The last update kind that was applied to the pool.
     latest_update_kind: Option<PoolUpdateKind>,
 }
@@ -85,6 +94,14 @@ pub struct TxPool<T: TransactionOrdering> {
 impl<T: TransactionOrdering> TxPool<T> {
     /// Create a new graph pool instance.
     pub fn new(ordering: T, config: PoolConfig) -> Self {
+        let capacity = [
+            &config.pending_limit,
+            &config.basefee_limit,
+            &config.queued_limit,
+            &config.blob_limit,
+        ]
+        .iter()
+        .fold(0usize, |capacity, limit| capacity.saturating_add(limit.max_size));
         Self {
             sender_info: Default::default(),
             pending_pool: PendingPool::new(ordering),
@@ -92,6 +109,8 @@ impl<T: TransactionOrdering> TxPool<T> {
             basefee_pool: Default::default(),
             blob_pool: Default::default(),
             all_transactions: AllTransactions::new(&config),
+            priority_sender_ids: Default::default(),
+            memory: config.memory_budget.register("txpool", capacity),
             config,
             metrics: Default::default(),
             latest_update_kind: None,
@@ -166,6 +185,42 @@ impl<T: TransactionOrdering> TxPool<T> {
         }
     }
 
+    /// Returns the minimum priority fee non-local transactions currently have to pay.
+    pub const fn price_floor(&self) -> u128 {
+        self.all_transactions.price_floor
+    }
+
+    /// Sets the minimum priority fee required for non-local transactions.
+    ///
+    /// This resets the floor to the given value, if the dynamic price floor is enabled it is
+    /// adjusted again on the next canonical state change.
+    pub fn set_minimum_priority_fee(&mut self, minimum_priority_fee: u128) {
+        self.all_transactions.minimum_priority_fee = minimum_priority_fee;
+        self.all_transactions.price_floor = minimum_priority_fee;
+        self.metrics.price_floor.set(minimum_priority_fee as f64);
+    }
+
+    /// Adjusts the dynamic price floor, if enabled, to the current utilization of the pool.
+    fn update_price_floor(&mut self) {
+        let Some(dynamic) = self.all_transactions.dynamic_price_floor else { return };
+        let size = self.size();
+        let utilization = self
+            .config
+            .pending_limit
+            .utilization(size.pending, size.pending_size)
+            .max(self.config.basefee_limit.utilization(size.basefee, size.basefee_size))
+            .max(self.config.queued_limit.utilization(size.queued, size.queued_size));
+
+        let current = self.all_transactions.price_floor;
+        let next =
+            dynamic.next_floor(current, self.all_transactions.minimum_priority_fee, utilization);
+        if next != current {
+            trace!(target: "txpool", current, next, utilization, "Adjusting price floor");
+            self.all_transactions.price_floor = next;
+            self.metrics.price_floor.set(next as f64);
+        }
+    }
+
     /// Returns the currently tracked block values
     pub const fn block_info(&self) -> BlockInfo {
         BlockInfo {
@@ -427,10 +482,23 @@ impl<T: TransactionOrdering> TxPool<T> {
         }
     }
 
-    /// Returns `true` if the pool is over its configured limits.
+    /// Returns `true` if the pool is over its configured limits, as scaled by the memory budget.
     #[inline]
     pub(crate) fn is_exceeded(&self) -> bool {
-        self.config.is_exceeded(self.size())
+        let size = self.size();
+        self.budgeted_limit(&self.config.blob_limit).is_exceeded(size.blob, size.blob_size) ||
+            self.budgeted_limit(&self.config.pending_limit)
+                .is_exceeded(size.pending, size.pending_size) ||
+            self.budgeted_limit(&self.config.basefee_limit)
+                .is_exceeded(size.basefee, size.basefee_size) ||
+            self.budgeted_limit(&self.config.queued_limit)
+                .is_exceeded(size.queued, size.queued_size)
+    }
+
+    /// Returns the given subpool limit scaled down to the share of the node's memory budget that
+    /// the pool may currently use.
+    fn budgeted_limit(&self, limit: &SubPoolLimit) -> SubPoolLimit {
+        SubPoolLimit::new(self.memory.scale(limit.max_txs), self.memory.scale(limit.max_size))
     }
 
     /// Returns the transaction for the given hash.
@@ -493,8 +561,9 @@ impl<T: TransactionOrdering> TxPool<T> {
         // Remove all transaction that were included in the block
         let mut removed_txs_count = 0;
         for tx_hash in &mined_transactions {
-            if self.prune_transaction_by_hash(tx_hash).is_some() {
+            if let Some(tx) = self.prune_transaction_by_hash(tx_hash) {
                 removed_txs_count += 1;
+                self.metrics.mined_transaction_time_in_pool.record(tx.timestamp.elapsed());
             }
         }
 
@@ -503,6 +572,7 @@ impl<T: TransactionOrdering> TxPool<T> {
 
         let UpdateOutcome { promoted, discarded } = self.update_accounts(changed_senders);
 
+        self.update_price_floor();
         self.update_transaction_type_metrics();
         self.metrics.performed_state_updates.increment(1);
 
@@ -515,6 +585,9 @@ impl<T: TransactionOrdering> TxPool<T> {
     /// Update sub-pools size metrics.
     pub(crate) fn update_size_metrics(&self) {
         let stats = self.size();
+        self.memory.set_usage(
+            stats.pending_size + stats.basefee_size + stats.queued_size + stats.blob_size,
+        );
         self.metrics.pending_pool_transactions.set(stats.pending as f64);
         self.metrics.pending_pool_size_bytes.set(stats.pending_size as f64);
         self.metrics.basefee_pool_transactions.set(stats.basefee as f64);
@@ -593,8 +666,12 @@ impl<T: TransactionOrdering> TxPool<T> {
             .or_default()
             .update(on_chain_nonce, on_chain_balance);
 
+        if self.config.priority_senders.is_priority_sender(tx.transaction.sender_ref()) {
+            self.priority_sender_ids.insert(tx.sender_id());
+        }
+
         match self.all_transactions.insert_tx(tx, on_chain_balance, on_chain_nonce) {
-            Ok(InsertOk { transaction, move_to, replaced_tx, updates, .. }) => {
+            Ok(InsertOk { transaction, move_to, replaced_tx, displaced, updates, .. }) => {
                 // replace the new tx and remove the replaced in the subpool(s)
                 self.add_new_transaction(transaction.clone(), replaced_tx.clone(), move_to);
                 // Update inserted transactions metric
@@ -602,6 +679,9 @@ impl<T: TransactionOrdering> TxPool<T> {
                 let UpdateOutcome { promoted, discarded } = self.process_updates(updates);
 
                 let replaced = replaced_tx.map(|(tx, _)| tx);
+                // remove the transactions with conflicting authorizations, their descendants are
+                // removed as well because they now have a nonce gap
+                let displaced = self.remove_transactions_and_descendants(displaced);
 
                 // This transaction was moved to the pending pool.
                 let res = if move_to.is_pending() {
@@ -610,9 +690,10 @@ impl<T: TransactionOrdering> TxPool<T> {
                         promoted,
                         discarded,
                         replaced,
+                        displaced,
                     })
                 } else {
-                    AddedTransaction::Parked { transaction, subpool: move_to, replaced }
+                    AddedTransaction::Parked { transaction, subpool: move_to, replaced, displaced }
                 };
 
                 // Update size metrics after adding and potentially moving transactions.
@@ -659,6 +740,10 @@ impl<T: TransactionOrdering> TxPool<T> {
                             Eip4844PoolTransactionError::Eip4844NonceGap.into(),
                         ),
                     )),
+                    InsertErr::BelowPriceFloor { transaction } => Err(PoolError::new(
+                        *transaction.hash(),
+                        PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Underpriced),
+                    )),
                     InsertErr::Overdraft { transaction } => Err(PoolError::new(
                         *transaction.hash(),
                         PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Overdraft {
@@ -919,33 +1004,50 @@ impl<T: TransactionOrdering> TxPool<T> {
     /// pool and returned.
     ///
     /// This returns all transactions that were removed from the entire pool.
+    ///
+    /// Transactions of priority senders that fit into the reserved slots are never evicted, see
+    /// [`PrioritySenderConfig`](crate::PrioritySenderConfig).
     pub(crate) fn discard_worst(&mut self) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let mut removed = Vec::new();
+        if !self.is_exceeded() {
+            return removed
+        }
+        let protected = self.protected_transactions();
+
+        // the configured limits, shrunk if the node is under memory pressure
+        let pending_limit = self.budgeted_limit(&self.config.pending_limit);
+        let basefee_limit = self.budgeted_limit(&self.config.basefee_limit);
+        let blob_limit = self.budgeted_limit(&self.config.blob_limit);
+        let queued_limit = self.budgeted_limit(&self.config.queued_limit);
 
         // Helper macro that discards the worst transactions for the pools
         macro_rules! discard_worst {
             ($this:ident, $removed:ident, [$($limit:ident => $pool:ident),* $(,)*]) => {
                 $ (
-                while $this.$pool.exceeds(&$this.config.$limit)
+                while $this.$pool.exceeds(&$limit)
                     {
                         trace!(
                             target: "txpool",
                             "discarding transactions from {}, limit: {:?}, curr size: {}, curr len: {}",
                             stringify!($pool),
-                            $this.config.$limit,
+                            $limit,
                             $this.$pool.size(),
                             $this.$pool.len(),
                         );
 
                         // 1. first remove the worst transaction from the subpool
-                        let removed_from_subpool = $this.$pool.truncate_pool($this.config.$limit.clone());
+                        let removed_from_subpool = $this.$pool.truncate_pool($limit.clone(), &protected);
+                        if removed_from_subpool.is_empty() {
+                            // only protected transactions are left
+                            break
+                        }
 
                         trace!(
                             target: "txpool",
                             "removed {} transactions from {}, limit: {:?}, curr size: {}, curr len: {}",
                             removed_from_subpool.len(),
                             stringify!($pool),
-                            $this.config.$limit,
+                            $limit,
                             $this.$pool.size(),
                             $this.$pool.len()
                         );
@@ -980,6 +1082,101 @@ impl<T: TransactionOrdering> TxPool<T> {
         removed
     }
 
+    /// Returns the transactions of priority senders that are exempt from eviction.
+    ///
+    /// The reserved slots are assigned to the priority senders in the pool in the order of their
+    /// ids. Each sender gets the slots for the transactions with its lowest nonces, so evicting
+    /// the remaining transactions of the sender doesn't create a nonce gap.
+    fn protected_transactions(&self) -> ProtectedTransactions {
+        let mut protected = ProtectedTransactions::default();
+        let mut remaining_slots = self.config.priority_senders.reserved_slots;
+        if self.config.priority_senders.is_empty() {
+            return protected
+        }
+
+        let mut senders = self.priority_sender_ids.iter().copied().collect::<Vec<_>>();
+        senders.sort_unstable();
+        for sender in senders {
+            if remaining_slots == 0 {
+                break
+            }
+            let txs = self.all_transactions.txs_iter(sender).take(remaining_slots);
+            if let Some((idx, (id, _))) = txs.enumerate().last() {
+                remaining_slots -= idx + 1;
+                protected.nonces.insert(sender, id.nonce + 1);
+            }
+        }
+        protected
+    }
+
+    /// Ensures that the sender has at most [`PoolConfig::max_queued_per_sender`] transactions in
+    /// the queued sub-pool.
+    ///
+    /// If exceeded, the queued transactions of the sender with the highest nonces are evicted.
+    /// Local transactions are exempt and don't count towards the limit. This returns all evicted
+    /// transactions.
+    pub(crate) fn truncate_queued_by_sender(
+        &mut self,
+        sender: SenderId,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        let local_config = &self.config.local_transactions_config;
+        let queued = self
+            .queued_pool
+            .get_txs_by_sender(sender)
+            .into_iter()
+            .filter(|id| {
+                self.all_transactions.get(id).is_some_and(|tx| {
+                    !local_config.is_local(tx.transaction.origin, tx.transaction.sender_ref())
+                })
+            })
+            .collect::<Vec<_>>();
+        let excess = queued.len().saturating_sub(self.config.max_queued_per_sender);
+        if excess == 0 {
+            return Vec::new()
+        }
+
+        // descendants of queued transactions are queued as well, so evicting the transactions with
+        // the highest nonces leaves the state of all remaining transactions unchanged
+        let evicted = queued
+            .iter()
+            .rev()
+            .take(excess)
+            .filter_map(|id| self.remove_transaction(id))
+            .collect::<Vec<_>>();
+        trace!(target: "txpool", ?sender, evicted=evicted.len(), "evicted queued transactions exceeding the sender limit");
+        self.metrics.removed_transactions.increment(evicted.len() as u64);
+        self.update_size_metrics();
+        evicted
+    }
+
+    /// Evicts all queued transactions that were added to the pool at least `lifetime` ago,
+    /// including their descendants.
+    ///
+    /// Local transactions are exempt. This returns all evicted transactions.
+    pub(crate) fn remove_expired_queued(
+        &mut self,
+        lifetime: Duration,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        let local_config = &self.config.local_transactions_config;
+        let expired = self
+            .queued_pool
+            .all()
+            .filter(|tx| {
+                tx.timestamp.elapsed() >= lifetime &&
+                    !local_config.is_local(tx.origin, tx.sender_ref())
+            })
+            .map(|tx| *tx.hash())
+            .collect::<Vec<_>>();
+        if expired.is_empty() {
+            return Vec::new()
+        }
+
+        let evicted = self.remove_transactions_and_descendants(expired);
+        trace!(target: "txpool", evicted=evicted.len(), "evicted expired queued transactions");
+        self.metrics.removed_transactions.increment(evicted.len() as u64);
+        evicted
+    }
+
     /// Number of transactions in the entire pool
     pub(crate) fn len(&self) -> usize {
         self.all_transactions.len()
@@ -1061,12 +1258,27 @@ pub(crate) struct AllTransactions<T: PoolTransaction> {
     block_gas_limit: u64,
     /// Max number of executable transaction slots guaranteed per account
     max_account_slots: usize,
+    /// Whether transactions that would overdraft the sender's balance are rejected, see
+    /// [`PoolConfig::reject_overdraft`].
+    reject_overdraft: bool,
+    /// The configured minimum priority fee of non-local transactions.
+    minimum_priority_fee: u128,
+    /// The minimum priority fee non-local transactions currently have to pay, this is at least
+    /// `minimum_priority_fee`.
+    price_floor: u128,
+    /// How the price floor is adjusted to the pool utilization, if at all.
+    dynamic_price_floor: Option<DynamicPriceFloorConfig>,
     /// _All_ transactions identified by their hash.
     by_hash: HashMap<TxHash, Arc<ValidPoolTransaction<T>>>,
     /// _All_ transaction in the pool sorted by their sender and nonce pair.
     txs: BTreeMap<TransactionId, PoolInternalTransaction<T>>,
     /// Tracks the number of transactions by sender that are currently in the pool.
     tx_counter: FxHashMap<SenderId, usize>,
+    /// Tracks the number of replaced transactions by sender while the sender has transactions in
+    /// the pool.
+    replacements_by_sender: FxHashMap<SenderId, u64>,
+    /// Transactions with EIP-7702 authorizations, by the authority nonces they consume.
+    by_authorization: HashMap<TransactionId, HashSet<TxHash>>,
     /// The current block number the pool keeps track of.
     last_seen_block_number: u64,
     /// The current block hash the pool keeps track of.
@@ -1086,6 +1298,10 @@ impl<T: PoolTransaction> AllTransactions<T> {
     fn new(config: &PoolConfig) -> Self {
         Self {
             max_account_slots: config.max_account_slots,
+            reject_overdraft: config.reject_overdraft,
+            minimum_priority_fee: config.price_floor.minimum_priority_fee,
+            price_floor: config.price_floor.minimum_priority_fee,
+            dynamic_price_floor: config.price_floor.dynamic,
             price_bumps: config.price_bumps,
             local_transactions_config: config.local_transactions_config.clone(),
             minimal_protocol_basefee: config.minimal_protocol_basefee,
@@ -1131,6 +1347,8 @@ impl<T: PoolTransaction> AllTransactions<T> {
             if *count == 1 {
                 entry.remove();
                 self.metrics.all_transactions_by_all_senders.decrement(1.0);
+                let replacements = self.replacements_by_sender.remove(&sender).unwrap_or_default();
+                self.metrics.sender_replacements.record(replacements as f64);
                 return
             }
             *count -= 1;
@@ -1414,6 +1632,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
     ) -> Option<(Arc<ValidPoolTransaction<T>>, SubPool)> {
         let tx = self.by_hash.remove(tx_hash)?;
         let internal = self.txs.remove(&tx.transaction_id)?;
+        self.untrack_authorizations(&tx);
         // decrement the counter for the sender.
         self.tx_decr(tx.sender_id());
         self.update_size_metrics();
@@ -1430,6 +1649,7 @@ impl<T: PoolTransaction> AllTransactions<T> {
         id: &TransactionId,
     ) -> Option<(Arc<ValidPoolTransaction<T>>, SubPool)> {
         let internal = self.txs.remove(id)?;
+        self.untrack_authorizations(&internal.transaction);
 
         // decrement the counter for the sender.
         self.tx_decr(internal.transaction.sender_id());
@@ -1442,6 +1662,48 @@ impl<T: PoolTransaction> AllTransactions<T> {
         result
     }
 
+    /// Tracks the authority nonces consumed by the authorizations of the transaction.
+    fn track_authorizations(&mut self, tx: &ValidPoolTransaction<T>) {
+        for id in tx.authorization_ids() {
+            self.by_authorization.entry(*id).or_default().insert(*tx.hash());
+        }
+    }
+
+    /// Removes the authorizations of the transaction from the tracked authority nonces.
+    fn untrack_authorizations(&mut self, tx: &ValidPoolTransaction<T>) {
+        for id in tx.authorization_ids() {
+            if let hash_map::Entry::Occupied(mut entry) = self.by_authorization.entry(*id) {
+                entry.get_mut().remove(tx.hash());
+                if entry.get().is_empty() {
+                    entry.remove();
+                }
+            }
+        }
+    }
+
+    /// Returns all transactions of other senders with an authorization that consumes the same
+    /// authority nonce as an authorization of the given transaction.
+    ///
+    /// Only one of these authorizations can be valid, so these transactions are replacement
+    /// candidates for the given transaction.
+    fn conflicting_authorizations(
+        &self,
+        tx: &ValidPoolTransaction<T>,
+    ) -> Vec<Arc<ValidPoolTransaction<T>>> {
+        let mut conflicting = Vec::<Arc<ValidPoolTransaction<T>>>::new();
+        for id in tx.authorization_ids() {
+            for hash in self.by_authorization.get(id).into_iter().flatten() {
+                let Some(existing) = self.by_hash.get(hash) else { continue };
+                if existing.sender_id() != tx.sender_id() &&
+                    !conflicting.iter().any(|other| other.hash() == hash)
+                {
+                    conflicting.push(Arc::clone(existing));
+                }
+            }
+        }
+        conflicting
+    }
+
     /// Checks if the given transaction's type conflicts with an existing transaction.
     ///
     /// See also [`ValidPoolTransaction::tx_type_conflicts_with`].
@@ -1459,6 +1721,8 @@ impl<T: PoolTransaction> AllTransactions<T> {
     /// This will enforce all additional rules in the context of this pool, such as:
     ///   - Spam protection: reject new non-local transaction from a sender that exhausted its slot
     ///     capacity.
+    ///   - Price floor: reject new non-local transactions that pay less than the current minimum
+    ///     priority fee.
     ///   - Gas limit: reject transactions if they exceed a block's maximum gas.
     ///   - Ensures transaction types are not conflicting for the sender: blob vs normal
     ///     transactions are mutually exclusive for the same sender.
@@ -1478,6 +1742,11 @@ impl<T: PoolTransaction> AllTransactions<T> {
                     transaction: Arc::new(transaction),
                 })
             }
+
+            if transaction.priority_fee_or_price() < self.price_floor {
+                self.metrics.price_floor_rejections.increment(1);
+                return Err(InsertErr::BelowPriceFloor { transaction: Arc::new(transaction) })
+            }
         }
         if transaction.gas_limit() > self.block_gas_limit {
             return Err(InsertErr::TxGasLimitMoreThanAvailableBlockGas {
@@ -1555,6 +1824,38 @@ impl<T: PoolTransaction> AllTransactions<T> {
         Ok(new_blob_tx)
     }
 
+    /// Rejects the transaction if its cost, together with the cost of all transactions of the
+    /// sender with a lower nonce, exceeds the sender's on-chain balance.
+    ///
+    /// This is only enforced if [`PoolConfig::reject_overdraft`] is set, blob transactions are
+    /// always checked by [`Self::ensure_valid_blob_transaction`]. Local transactions are exempt.
+    fn ensure_no_overdraft(
+        &self,
+        transaction: ValidPoolTransaction<T>,
+        on_chain_balance: U256,
+        on_chain_nonce: u64,
+    ) -> Result<ValidPoolTransaction<T>, InsertErr<T>> {
+        if !self.reject_overdraft ||
+            self.local_transactions_config.is_local(transaction.origin, transaction.sender_ref())
+        {
+            return Ok(transaction)
+        }
+
+        // a replaced transaction is not included, since its nonce is not lower
+        let id = *transaction.id();
+        let cumulative_cost = self
+            .txs
+            .range(TransactionId::new(id.sender, on_chain_nonce)..id)
+            .fold(*transaction.cost(), |cost, (_, tx)| cost.saturating_add(*tx.transaction.cost()));
+
+        if cumulative_cost > on_chain_balance {
+            self.metrics.overdraft_rejections.increment(1);
+            return Err(InsertErr::Overdraft { transaction: Arc::new(transaction) })
+        }
+
+        Ok(transaction)
+    }
+
     /// Inserts a new _valid_ transaction into the pool.
     ///
     /// If the transaction already exists, it will be replaced if not underpriced.
@@ -1586,6 +1887,11 @@ impl<T: PoolTransaction> AllTransactions<T> {
     ///
     /// The replacement candidate must satisfy given price bump constraints: replacement candidate
     /// must not be underpriced
+    ///
+    /// An EIP-7702 transaction is also a replacement candidate for all transactions of other
+    /// senders with an authorization for the same authority nonce, see
+    /// [`Self::conflicting_authorizations`]. These transactions and their descendants must be
+    /// removed from the pool by the caller.
     pub(crate) fn insert_tx(
         &mut self,
         transaction: ValidPoolTransaction<T>,
@@ -1626,6 +1932,9 @@ impl<T: PoolTransaction> AllTransactions<T> {
         } else {
             // Non-EIP4844 transaction always satisfy the blob fee cap condition
             state.insert(TxState::ENOUGH_BLOB_FEE_CAP_BLOCK);
+
+            transaction =
+                self.ensure_no_overdraft(transaction, on_chain_balance, on_chain_nonce)?;
         }
 
         let transaction = Arc::new(transaction);
@@ -1646,6 +1955,16 @@ impl<T: PoolTransaction> AllTransactions<T> {
             state.insert(TxState::ENOUGH_FEE_CAP_BLOCK);
         }
 
+        // transactions of other senders with conflicting authorizations are replaced by this
+        // transaction, so it must satisfy the price bump for all of them
+        let displaced = self.conflicting_authorizations(&transaction);
+        if let Some(existing) = displaced
+            .iter()
+            .find(|existing| existing.is_underpriced(&transaction, &self.price_bumps))
+        {
+            return Err(InsertErr::Underpriced { transaction, existing: *existing.hash() })
+        }
+
         // placeholder for the replaced transaction, if any
         let mut replaced_tx = None;
 
@@ -1680,10 +1999,14 @@ impl<T: PoolTransaction> AllTransactions<T> {
                 let replaced = entry.insert(pool_tx);
                 self.by_hash.remove(replaced.transaction.hash());
                 self.by_hash.insert(new_hash, new_transaction);
+                self.untrack_authorizations(&replaced.transaction);
+                *self.replacements_by_sender.entry(inserted_tx_id.sender).or_default() += 1;
+                self.metrics.replaced_transactions.increment(1);
                 // also remove the hash
                 replaced_tx = Some((replaced.transaction, replaced.subpool));
             }
         }
+        self.track_authorizations(&transaction);
 
         // The next transaction of this sender
         let on_chain_id = TransactionId::new(transaction.sender_id(), on_chain_nonce);
@@ -1758,9 +2081,15 @@ impl<T: PoolTransaction> AllTransactions<T> {
             self.tx_inc(inserted_tx_id.sender);
         }
 
+        if !state.contains(TxState::NO_NONCE_GAPS) {
+            self.metrics.nonce_gap_blocked_transactions.increment(1);
+        }
+
         self.update_size_metrics();
 
-        Ok(InsertOk { transaction, move_to: state.into(), state, replaced_tx, updates })
+        let displaced = displaced.iter().map(|tx| *tx.hash()).collect();
+
+        Ok(InsertOk { transaction, move_to: state.into(), state, replaced_tx, displaced, updates })
     }
 
     /// Number of transactions in the entire pool
@@ -1794,11 +2123,17 @@ impl<T: PoolTransaction> Default for AllTransactions<T> {
     fn default() -> Self {
         Self {
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
+            reject_overdraft: false,
+            minimum_priority_fee: 0,
+            price_floor: 0,
+            dynamic_price_floor: None,
             minimal_protocol_basefee: MIN_PROTOCOL_BASE_FEE,
             block_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT,
             by_hash: Default::default(),
             txs: Default::default(),
             tx_counter: Default::default(),
+            replacements_by_sender: Default::default(),
+            by_authorization: Default::default(),
             last_seen_block_number: Default::default(),
             last_seen_block_hash: Default::default(),
             pending_fees: Default::default(),
@@ -1824,6 +2159,21 @@ impl Default for PendingFees {
     }
 }
 
+/// The transactions of priority senders that are exempt from eviction, see
+/// [`PrioritySenderConfig`](crate::PrioritySenderConfig).
+#[derive(Debug, Clone, Default)]
+pub struct ProtectedTransactions {
+    /// The nonce of each protected sender below which its transactions are protected.
+    nonces: FxHashMap<SenderId, u64>,
+}
+
+impl ProtectedTransactions {
+    /// Returns `true` if the transaction with the given id must not be evicted.
+    pub fn contains(&self, id: &TransactionId) -> bool {
+        self.nonces.get(&id.sender).is_some_and(|nonce| id.nonce < *nonce)
+    }
+}
+
 /// Result type for inserting a transaction
 pub(crate) type InsertResult<T> = Result<InsertOk<T>, InsertErr<T>>;
 
@@ -1838,6 +2188,8 @@ pub(crate) enum InsertErr<T: PoolTransaction> {
     },
     /// Attempted to insert a blob transaction with a nonce gap
     BlobTxHasNonceGap { transaction: Arc<ValidPoolTransaction<T>> },
+    /// The priority fee of a non-local transaction is below the current price floor.
+    BelowPriceFloor { transaction: Arc<ValidPoolTransaction<T>> },
     /// Attempted to insert a transaction that would overdraft the sender's balance at the time of
     /// insertion.
     Overdraft { transaction: Arc<ValidPoolTransaction<T>> },
@@ -1871,6 +2223,9 @@ pub(crate) struct InsertOk<T: PoolTransaction> {
     state: TxState,
     /// The transaction that was replaced by this.
     replaced_tx: Option<(Arc<ValidPoolTransaction<T>>, SubPool)>,
+    /// The transactions of other senders with conflicting authorizations that are replaced by
+    /// this, see [`AllTransactions::conflicting_authorizations`].
+    displaced: Vec<TxHash>,
     /// Additional updates to transactions affected by this change.
     updates: Vec<PoolUpdate>,
 }
@@ -1938,11 +2293,13 @@ impl SenderInfo {
 mod tests {
     use super::*;
     use crate::{
+        config::PRICE_FLOOR_MIN_STEP,
         test_utils::{MockOrdering, MockTransaction, MockTransactionFactory, MockTransactionSet},
         traits::TransactionOrigin,
-        SubPoolLimit,
+        PriceFloorConfig, PrioritySenderConfig, SubPoolLimit,
     };
     use alloy_primitives::address;
+    use reth_memory_budget::MemoryBudget;
     use reth_primitives::TxType;
 
     #[test]
@@ -2403,6 +2760,27 @@ mod tests {
         assert_eq!(pool.len(), 1);
     }
 
+    #[test]
+    fn track_replacements_by_sender() {
+        let on_chain_balance = U256::ZERO;
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = AllTransactions::default();
+        let tx = MockTransaction::eip1559().inc_price().inc_limit();
+        let first = f.validated(tx.clone());
+        let sender = first.sender_id();
+        let _ = pool.insert_tx(first, on_chain_balance, on_chain_nonce).unwrap();
+        assert!(pool.replacements_by_sender.is_empty());
+
+        let replacement = f.validated(tx.rng_hash().inc_price());
+        let _ = pool.insert_tx(replacement.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(pool.replacements_by_sender.get(&sender), Some(&1));
+
+        // the count is dropped once the sender has no transactions left
+        let _ = pool.remove_transaction(replacement.id()).unwrap();
+        assert!(pool.replacements_by_sender.is_empty());
+    }
+
     #[test]
     fn insert_replace_txpool() {
         let on_chain_balance = U256::ZERO;
@@ -2611,6 +2989,94 @@ mod tests {
         assert!(first_in_pool.state.contains(TxState::NO_NONCE_GAPS));
     }
 
+    #[test]
+    fn insert_replace_conflicting_authorization() {
+        let on_chain_balance = U256::MAX;
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::mock();
+
+        let authority = f.tx_id(&MockTransaction::eip1559()).sender;
+        let with_authorization = |f: &mut MockTransactionFactory, tx: MockTransaction| {
+            let mut tx = f.validated(tx);
+            tx.authorization_ids = Some(vec![TransactionId::new(authority, 0)]);
+            tx
+        };
+
+        let tx = MockTransaction::eip1559().with_priority_fee(100).with_max_fee(100);
+        let first = with_authorization(&mut f, tx.clone());
+        let descendant = f.validated(tx.next());
+        pool.add_transaction(first.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        pool.add_transaction(descendant.clone(), on_chain_balance, on_chain_nonce).unwrap();
+
+        // an authorization of another sender for the same authority nonce must be bumped
+        let other = MockTransaction::eip1559().with_priority_fee(100).with_max_fee(100);
+        let err = pool
+            .add_transaction(
+                with_authorization(&mut f, other.clone()),
+                on_chain_balance,
+                on_chain_nonce,
+            )
+            .unwrap_err();
+        assert!(matches!(err.kind, PoolErrorKind::ReplacementUnderpriced));
+        assert!(pool.contains(first.hash()));
+
+        // the replacement displaces the conflicting transaction and its descendants
+        let replacement =
+            with_authorization(&mut f, other.rng_hash().with_priority_fee(110).with_max_fee(110));
+        let added =
+            pool.add_transaction(replacement.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        let displaced =
+            added.displaced_transactions().iter().map(|tx| *tx.hash()).collect::<Vec<_>>();
+        assert_eq!(displaced, vec![*first.hash(), *descendant.hash()]);
+        assert!(!pool.contains(first.hash()));
+        assert!(!pool.contains(descendant.hash()));
+        assert!(pool.contains(replacement.hash()));
+        assert_eq!(pool.len(), 1);
+        pool.assert_invariants();
+    }
+
+    #[test]
+    fn authorizations_of_mined_transactions_are_untracked() {
+        let on_chain_balance = U256::MAX;
+        let on_chain_nonce = 0;
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::mock();
+
+        let authority = f.tx_id(&MockTransaction::eip1559()).sender;
+        let with_authorization = |f: &mut MockTransactionFactory, nonce: u64| {
+            let mut tx = f.validated(MockTransaction::eip1559());
+            tx.authorization_ids = Some(vec![TransactionId::new(authority, nonce)]);
+            tx
+        };
+
+        // authorizations for different nonces of the same authority don't conflict
+        let mined = with_authorization(&mut f, 0);
+        let next = with_authorization(&mut f, 1);
+        pool.add_transaction(mined.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        pool.add_transaction(next.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        assert_eq!(pool.len(), 2);
+
+        pool.on_canonical_state_change(
+            BlockInfo::default(),
+            vec![*mined.hash()],
+            Default::default(),
+            PoolUpdateKind::Commit,
+        );
+        assert!(!pool.all().by_authorization.contains_key(&TransactionId::new(authority, 0)));
+
+        // the mined transaction is reinjected after a reorg, its authorization is tracked again
+        // without displacing the transaction of the next authority nonce
+        let added = pool.add_transaction(mined.clone(), on_chain_balance, on_chain_nonce).unwrap();
+        assert!(added.displaced_transactions().is_empty());
+        assert!(pool.contains(next.hash()));
+        let err = pool
+            .add_transaction(with_authorization(&mut f, 0), on_chain_balance, on_chain_nonce)
+            .unwrap_err();
+        assert!(matches!(err.kind, PoolErrorKind::ReplacementUnderpriced));
+        pool.assert_invariants();
+    }
+
     #[test]
     fn rejects_spammer() {
         let on_chain_balance = U256::from(1_000);
@@ -2921,6 +3387,124 @@ mod tests {
         assert_eq!(removed.len(), 1);
     }
 
+    #[test]
+    fn reject_cumulative_overdraft() {
+        let sender = address!("000000000000000000000000000000000000000c");
+        let txs = MockTransactionSet::dependent(sender, 0, 3, TxType::Eip1559).into_vec();
+        // the sender can afford the first two transactions only
+        let balance = *txs[0].cost() + *txs[1].cost();
+
+        for reject_overdraft in [true, false] {
+            let mut f = MockTransactionFactory::default();
+            let config = PoolConfig { reject_overdraft, ..Default::default() };
+            let mut pool = TxPool::new(MockOrdering::default(), config);
+            pool.add_transaction(f.validated(txs[0].clone()), balance, 0).unwrap();
+            pool.add_transaction(f.validated(txs[1].clone()), balance, 0).unwrap();
+
+            let res = pool.add_transaction(f.validated(txs[2].clone()), balance, 0);
+            if reject_overdraft {
+                let err = res.unwrap_err();
+                assert!(matches!(
+                    err.kind,
+                    PoolErrorKind::InvalidTransaction(
+                        InvalidPoolTransactionError::Overdraft { .. }
+                    )
+                ));
+
+                // local transactions are exempt
+                let local = f.validated_with_origin(TransactionOrigin::Local, txs[2].clone());
+                assert!(pool.add_transaction(local, balance, 0).is_ok());
+            } else {
+                // the transaction is parked until the sender can afford it
+                assert_eq!(res.unwrap().subpool(), SubPool::Queued);
+            }
+        }
+    }
+
+    #[test]
+    fn dynamic_price_floor() {
+        let mut f = MockTransactionFactory::default();
+        let config = PoolConfig {
+            queued_limit: SubPoolLimit::new(2, usize::MAX),
+            price_floor: PriceFloorConfig {
+                minimum_priority_fee: 1,
+                dynamic: Some(Default::default()),
+            },
+            ..Default::default()
+        };
+        let mut pool = TxPool::new(MockOrdering::default(), config);
+        let below_floor = || MockTransaction::eip1559().with_priority_fee(0).rng_hash();
+
+        let err = pool.add_transaction(f.validated(below_floor()), U256::MAX, 0).unwrap_err();
+        assert!(matches!(
+            err.kind,
+            PoolErrorKind::InvalidTransaction(InvalidPoolTransactionError::Underpriced)
+        ));
+
+        // fill the queued pool, which raises the floor on the next update
+        for _ in 0..2 {
+            let tx = MockTransaction::eip1559().with_priority_fee(1).inc_nonce();
+            pool.add_transaction(f.validated(tx), U256::MAX, 0).unwrap();
+        }
+        pool.on_canonical_state_change(
+            BlockInfo::default(),
+            vec![],
+            Default::default(),
+            PoolUpdateKind::Commit,
+        );
+        assert_eq!(pool.price_floor(), 1 + PRICE_FLOOR_MIN_STEP);
+        let tx = MockTransaction::eip1559().with_priority_fee(PRICE_FLOOR_MIN_STEP);
+        assert!(pool.add_transaction(f.validated(tx.clone()), U256::MAX, 0).is_err());
+
+        // local transactions are exempt
+        let local = f.validated_with_origin(TransactionOrigin::Local, tx);
+        assert!(pool.add_transaction(local, U256::MAX, 0).is_ok());
+
+        // setting the minimum resets the floor
+        pool.set_minimum_priority_fee(0);
+        assert_eq!(pool.price_floor(), 0);
+        assert!(pool.add_transaction(f.validated(below_floor()), U256::MAX, 0).is_ok());
+    }
+
+    #[test]
+    fn discard_retains_priority_senders() {
+        let priority_sender = address!("000000000000000000000000000000000000000a");
+        let other_sender = address!("000000000000000000000000000000000000000b");
+        let pool_base_fee = 100;
+
+        for reserved_slots in [2, 1] {
+            let mut f = MockTransactionFactory::default();
+            let config = PoolConfig {
+                basefee_limit: SubPoolLimit::new(2, usize::MAX),
+                priority_senders: PrioritySenderConfig {
+                    senders: [priority_sender].into_iter().collect(),
+                    reserved_slots,
+                },
+                ..Default::default()
+            };
+            let mut pool = TxPool::new(MockOrdering::default(), config);
+            pool.update_basefee(pool_base_fee);
+
+            // the priority sender submitted first and is evicted first if it is not protected
+            for (sender, max_fee) in [(priority_sender, 1), (other_sender, pool_base_fee - 1)] {
+                for mut tx in MockTransactionSet::dependent(sender, 0, 2, TxType::Eip1559) {
+                    tx.set_max_fee(max_fee.into());
+                    pool.add_transaction(f.validated(tx), U256::from(1_000), 0).unwrap();
+                }
+            }
+
+            // only the transactions with the lowest nonces of the priority sender are retained
+            let removed = pool.discard_worst();
+            assert_eq!(removed.len(), 2);
+            assert_eq!(
+                removed.iter().filter(|tx| tx.sender() == priority_sender).count(),
+                2 - reserved_slots,
+                "reserved slots: {reserved_slots}"
+            );
+            assert!(removed.iter().all(|tx| tx.sender() != priority_sender || tx.nonce() == 1));
+        }
+    }
+
     #[test]
     fn discard_at_capacity() {
         let mut f = MockTransactionFactory::default();
@@ -2951,6 +3535,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn scale_limits_with_memory_budget() {
+        let config = PoolConfig::default();
+        let capacity = [
+            &config.pending_limit,
+            &config.basefee_limit,
+            &config.queued_limit,
+            &config.blob_limit,
+        ]
+        .iter()
+        .map(|limit| limit.max_size)
+        .sum::<usize>();
+        let memory_budget = MemoryBudget::new(capacity / 2);
+        let pool =
+            TxPool::new(MockOrdering::default(), PoolConfig { memory_budget, ..config.clone() });
+
+        let limit = pool.budgeted_limit(&config.pending_limit);
+        assert_eq!(limit.max_txs, config.pending_limit.max_txs / 2);
+
+        // pools without a budget may use their full capacity
+        let pool = TxPool::new(MockOrdering::default(), config.clone());
+        assert_eq!(pool.budgeted_limit(&config.pending_limit), config.pending_limit);
+    }
+
     #[test]
     fn discard_blobs_at_capacity() {
         let mut f = MockTransactionFactory::default();
@@ -2981,6 +3589,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn truncate_queued_by_sender() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(
+            MockOrdering::default(),
+            PoolConfig { max_queued_per_sender: 2, ..Default::default() },
+        );
+
+        // transactions with a nonce gap are queued
+        let tx_1 = MockTransaction::eip1559().inc_nonce();
+        let tx_2 = tx_1.next();
+        let tx_3 = tx_2.next();
+        for tx in [&tx_1, &tx_2, &tx_3] {
+            pool.add_transaction(f.validated(tx.clone()), U256::from(1_000), 0).unwrap();
+        }
+        assert_eq!(pool.size().queued, 3);
+
+        // the transaction with the highest nonce is evicted
+        let sender = f.ids.sender_id(tx_1.get_sender()).unwrap();
+        let evicted = pool.truncate_queued_by_sender(sender);
+        assert_eq!(evicted.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(), vec![*tx_3.get_hash()]);
+        assert_eq!(pool.size().queued, 2);
+        pool.assert_invariants();
+
+        // local transactions don't count towards the limit
+        let local = tx_3.next();
+        pool.add_transaction(
+            f.validated_with_origin(TransactionOrigin::Local, local),
+            U256::from(1_000),
+            0,
+        )
+        .unwrap();
+        assert!(pool.truncate_queued_by_sender(sender).is_empty());
+        assert_eq!(pool.size().queued, 3);
+    }
+
+    #[test]
+    fn remove_expired_queued() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = TxPool::new(MockOrdering::default(), Default::default());
+
+        let pending = MockTransaction::eip1559();
+        let queued = MockTransaction::eip1559().inc_nonce();
+        let local = MockTransaction::eip1559().inc_nonce();
+        pool.add_transaction(f.validated(pending), U256::from(1_000), 0).unwrap();
+        pool.add_transaction(f.validated(queued.clone()), U256::from(1_000), 0).unwrap();
+        pool.add_transaction(
+            f.validated_with_origin(TransactionOrigin::Local, local),
+            U256::from(1_000),
+            0,
+        )
+        .unwrap();
+
+        assert!(pool.remove_expired_queued(Duration::from_secs(60)).is_empty());
+
+        // only the remote queued transaction is evicted
+        let evicted = pool.remove_expired_queued(Duration::ZERO);
+        assert_eq!(
+            evicted.iter().map(|tx| *tx.hash()).collect::<Vec<_>>(),
+            vec![*queued.get_hash()]
+        );
+        assert_eq!(pool.size().pending, 1);
+        assert_eq!(pool.size().queued, 1);
+        pool.assert_invariants();
+    }
+
     #[test]
     fn account_updates_nonce_gap() {
         let on_chain_balance = U256::from(10_000);
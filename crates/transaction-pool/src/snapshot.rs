@@ -0,0 +1,384 @@
+//! Snapshots of the entire content of the pool.
+//!
+//! A [`PoolSnapshot`] contains all transactions of the pool in their pooled format, i.e. blob
+//! transactions include their sidecar, together with their origin and whether they were pending.
+//! Snapshots are taken with [`Pool::snapshot`](crate::Pool::snapshot) and added to a pool with
+//! [`Pool::restore`](crate::Pool::restore), e.g. to migrate the pool to another node or to inspect
+//! the pool of a production node offline with `reth db pool-snapshot`. The node writes a snapshot
+//! on shutdown and restores it on startup with [`pool_snapshot_task`].
+//!
+//! The encoding starts with [`SNAPSHOT_MAGIC`], the format version and the block number the
+//! snapshot was taken at, followed by one entry per transaction: a flags byte, the hash, sender,
+//! nonce and type of the transaction, and the length prefixed RLP encoded pooled transaction. The
+//! metadata allows inspecting a snapshot without knowing the transaction type of the pool.
+
+use crate::{
+    blobstore::BlobStore, traits::EthPoolTransaction, Pool, TransactionOrdering, TransactionOrigin,
+    TransactionValidator,
+};
+use alloy_primitives::{Address, Bytes, TxHash};
+use alloy_rlp::{Decodable, Encodable};
+use reth_fs_util::FsPathError;
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+use tracing::{debug, info, warn};
+
+/// The magic bytes every pool snapshot starts with.
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"RTXP";
+
+/// The current version of the pool snapshot encoding.
+pub const SNAPSHOT_VERSION: u8 = 2;
+
+/// The length of the metadata of an entry: hash, sender, nonce, type and the length of the
+/// encoded transaction.
+const ENTRY_METADATA_LEN: usize = 32 + 20 + 8 + 1 + 4;
+
+/// Flag of an entry that was pending when the snapshot was taken.
+const PENDING_FLAG: u8 = 0b100;
+
+/// Mask of the origin bits of an entry's flags.
+const ORIGIN_MASK: u8 = 0b11;
+
+/// All transactions of a pool at a given block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolSnapshot<T> {
+    /// The block number the pool was at when the snapshot was taken.
+    pub block_number: u64,
+    /// The transactions of the pool, pending transactions first.
+    pub transactions: Vec<SnapshotTransaction<T>>,
+}
+
+/// A transaction of a [`PoolSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotTransaction<T> {
+    /// The origin of the transaction.
+    pub origin: TransactionOrigin,
+    /// Whether the transaction was pending when the snapshot was taken.
+    pub pending: bool,
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// The sender of the transaction.
+    pub sender: Address,
+    /// The nonce of the transaction.
+    pub nonce: u64,
+    /// The type of the transaction.
+    pub tx_type: u8,
+    /// The pooled transaction.
+    pub transaction: T,
+}
+
+impl<T> SnapshotTransaction<T> {
+    const fn flags(&self) -> u8 {
+        let origin = match self.origin {
+            TransactionOrigin::Local => 0,
+            TransactionOrigin::External => 1,
+            TransactionOrigin::Private => 2,
+        };
+        if self.pending {
+            origin | PENDING_FLAG
+        } else {
+            origin
+        }
+    }
+}
+
+// === impl PoolSnapshot ===
+
+impl<T> PoolSnapshot<T> {
+    /// Returns the number of transactions that were pending when the snapshot was taken.
+    pub fn num_pending(&self) -> usize {
+        self.transactions.iter().filter(|tx| tx.pending).count()
+    }
+
+    /// Returns the number of transactions that were queued when the snapshot was taken.
+    pub fn num_queued(&self) -> usize {
+        self.transactions.len() - self.num_pending()
+    }
+}
+
+impl<T: Encodable> PoolSnapshot<T> {
+    /// Encodes the snapshot.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&SNAPSHOT_MAGIC);
+        out.push(SNAPSHOT_VERSION);
+        out.extend_from_slice(&self.block_number.to_be_bytes());
+        for tx in &self.transactions {
+            out.push(tx.flags());
+            out.extend_from_slice(tx.hash.as_slice());
+            out.extend_from_slice(tx.sender.as_slice());
+            out.extend_from_slice(&tx.nonce.to_be_bytes());
+            out.push(tx.tx_type);
+            out.extend_from_slice(&(tx.transaction.length() as u32).to_be_bytes());
+            tx.transaction.encode(&mut out);
+        }
+        out
+    }
+
+    /// Writes the encoded snapshot to the given file, replacing it if it exists.
+    pub fn save(&self, path: &Path) -> Result<(), FsPathError> {
+        let data = self.encode();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            reth_fs_util::create_dir_all(parent)?;
+        }
+        reth_fs_util::atomic_write_file(path, |file| file.write_all(&data))
+    }
+}
+
+impl<T: Decodable> PoolSnapshot<T> {
+    /// Decodes a snapshot.
+    pub fn decode(buf: &[u8]) -> Result<Self, PoolSnapshotError> {
+        decode_with(buf, |mut encoded| {
+            let transaction = T::decode(&mut encoded)?;
+            if !encoded.is_empty() {
+                return Err(alloy_rlp::Error::UnexpectedLength.into())
+            }
+            Ok(transaction)
+        })
+    }
+
+    /// Loads a snapshot from the given file.
+    pub fn load(path: &Path) -> Result<Self, PoolSnapshotError> {
+        let data = reth_fs_util::read(path)?;
+        Self::decode(&data)
+    }
+}
+
+impl PoolSnapshot<Bytes> {
+    /// Decodes a snapshot without decoding the transactions, which are returned RLP encoded.
+    ///
+    /// This can be used to inspect the metadata of a snapshot without knowing the transaction
+    /// type of the pool it was taken from.
+    pub fn decode_raw(buf: &[u8]) -> Result<Self, PoolSnapshotError> {
+        decode_with(buf, |encoded| Ok(Bytes::copy_from_slice(encoded)))
+    }
+
+    /// Loads a snapshot from the given file without decoding the transactions, see
+    /// [`Self::decode_raw`].
+    pub fn load_raw(path: &Path) -> Result<Self, PoolSnapshotError> {
+        let data = reth_fs_util::read(path)?;
+        Self::decode_raw(&data)
+    }
+}
+
+/// Decodes a snapshot, the transactions are decoded from their RLP encoding with the given
+/// function.
+fn decode_with<T>(
+    mut buf: &[u8],
+    mut decode_transaction: impl FnMut(&[u8]) -> Result<T, PoolSnapshotError>,
+) -> Result<PoolSnapshot<T>, PoolSnapshotError> {
+    if buf.len() < SNAPSHOT_MAGIC.len() + 9 || buf[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC {
+        return Err(PoolSnapshotError::InvalidMagic)
+    }
+    buf = &buf[SNAPSHOT_MAGIC.len()..];
+
+    let version = buf[0];
+    if version != SNAPSHOT_VERSION {
+        return Err(PoolSnapshotError::UnsupportedVersion(version))
+    }
+    let block_number = u64::from_be_bytes(buf[1..9].try_into().expect("length checked"));
+    buf = &buf[9..];
+
+    let mut transactions = Vec::new();
+    while let Some((&flags, rest)) = buf.split_first() {
+        buf = rest;
+        let origin = match flags & ORIGIN_MASK {
+            0 => TransactionOrigin::Local,
+            1 => TransactionOrigin::External,
+            2 => TransactionOrigin::Private,
+            _ => return Err(PoolSnapshotError::InvalidFlags(flags)),
+        };
+        if flags & !(ORIGIN_MASK | PENDING_FLAG) != 0 {
+            return Err(PoolSnapshotError::InvalidFlags(flags))
+        }
+
+        if buf.len() < ENTRY_METADATA_LEN {
+            return Err(alloy_rlp::Error::InputTooShort.into())
+        }
+        let (metadata, rest) = buf.split_at(ENTRY_METADATA_LEN);
+        let hash = TxHash::from_slice(&metadata[..32]);
+        let sender = Address::from_slice(&metadata[32..52]);
+        let nonce = u64::from_be_bytes(metadata[52..60].try_into().expect("length checked"));
+        let tx_type = metadata[60];
+        let len = u32::from_be_bytes(metadata[61..65].try_into().expect("length checked")) as usize;
+        if rest.len() < len {
+            return Err(alloy_rlp::Error::InputTooShort.into())
+        }
+        let (encoded, rest) = rest.split_at(len);
+        buf = rest;
+
+        transactions.push(SnapshotTransaction {
+            origin,
+            pending: flags & PENDING_FLAG != 0,
+            hash,
+            sender,
+            nonce,
+            tx_type,
+            transaction: decode_transaction(encoded)?,
+        });
+    }
+
+    Ok(PoolSnapshot { block_number, transactions })
+}
+
+/// Task that restores the pool from the snapshot at the given path on startup and writes a
+/// snapshot of the pool to it on shutdown.
+pub async fn pool_snapshot_task<V, T, S>(
+    shutdown: reth_tasks::shutdown::GracefulShutdown,
+    pool: Pool<V, T, S>,
+    path: PathBuf,
+) where
+    V: TransactionValidator<Transaction: EthPoolTransaction>,
+    T: TransactionOrdering<Transaction = V::Transaction>,
+    S: BlobStore,
+{
+    if path.exists() {
+        match PoolSnapshot::load(&path) {
+            Ok(snapshot) => {
+                pool.restore(snapshot).await;
+            }
+            Err(err) => {
+                warn!(target: "txpool", %err, ?path, "Failed to load transaction pool snapshot");
+            }
+        }
+    } else {
+        debug!(target: "txpool", ?path, "No transaction pool snapshot to restore");
+    }
+
+    let graceful_guard = shutdown.await;
+
+    let snapshot = pool.snapshot();
+    match snapshot.save(&path) {
+        Ok(()) => {
+            info!(target: "txpool", ?path, num_txs = snapshot.transactions.len(), "Wrote transaction pool snapshot");
+        }
+        Err(err) => {
+            warn!(target: "txpool", %err, ?path, "Failed to write transaction pool snapshot");
+        }
+    }
+
+    drop(graceful_guard)
+}
+
+/// Errors that can happen when loading a [`PoolSnapshot`].
+#[derive(Debug, thiserror::Error)]
+pub enum PoolSnapshotError {
+    /// The data doesn't start with [`SNAPSHOT_MAGIC`].
+    #[error("not a transaction pool snapshot")]
+    InvalidMagic,
+    /// The snapshot was encoded with an unsupported version.
+    #[error("unsupported transaction pool snapshot version {0}")]
+    UnsupportedVersion(u8),
+    /// The flags of an entry are invalid.
+    #[error("invalid transaction pool snapshot entry flags {0:#04x}")]
+    InvalidFlags(u8),
+    /// A transaction could not be decoded.
+    #[error("failed to decode transaction pool snapshot entry: {0}")]
+    Decode(#[from] alloy_rlp::Error),
+    /// The snapshot file could not be read.
+    #[error(transparent)]
+    FsPath(#[from] FsPathError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blobstore::InMemoryBlobStore, validate::EthTransactionValidatorBuilder,
+        CoinbaseTipOrdering, EthPooledTransaction, Pool, PoolConfig, PoolTransaction,
+        TransactionPool,
+    };
+    use alloy_eips::eip2718::Decodable2718;
+    use alloy_primitives::{hex, Bytes, U256};
+    use reth_chainspec::MAINNET;
+    use reth_primitives::{
+        transaction::SignedTransactionIntoRecoveredExt, PooledTransactionsElement,
+    };
+    use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
+
+    #[test]
+    fn encode_decode() {
+        let snapshot = PoolSnapshot {
+            block_number: 42,
+            transactions: vec![
+                SnapshotTransaction {
+                    origin: TransactionOrigin::Local,
+                    pending: true,
+                    hash: TxHash::with_last_byte(1),
+                    sender: Address::with_last_byte(1),
+                    nonce: 1,
+                    tx_type: 2,
+                    transaction: Bytes::from(vec![1; 64]),
+                },
+                SnapshotTransaction {
+                    origin: TransactionOrigin::Private,
+                    pending: false,
+                    hash: TxHash::with_last_byte(2),
+                    sender: Address::with_last_byte(2),
+                    nonce: 7,
+                    tx_type: 0,
+                    transaction: Bytes::from(vec![2; 3]),
+                },
+            ],
+        };
+        let encoded = snapshot.encode();
+        assert_eq!(PoolSnapshot::<Bytes>::decode(&encoded).unwrap(), snapshot);
+        assert_eq!(snapshot.num_pending(), 1);
+        assert_eq!(snapshot.num_queued(), 1);
+
+        // raw decoding keeps the RLP encoded transactions
+        let raw = PoolSnapshot::decode_raw(&encoded).unwrap();
+        assert_eq!(raw.transactions[1].nonce, 7);
+        assert_eq!(
+            raw.transactions[1].transaction,
+            alloy_rlp::encode(&snapshot.transactions[1].transaction)
+        );
+
+        assert!(matches!(
+            PoolSnapshot::<Bytes>::decode(&encoded[1..]),
+            Err(PoolSnapshotError::InvalidMagic)
+        ));
+        let mut invalid_flags = encoded;
+        invalid_flags[13] = 0b11;
+        assert!(matches!(
+            PoolSnapshot::<Bytes>::decode(&invalid_flags),
+            Err(PoolSnapshotError::InvalidFlags(0b11))
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn snapshot_restore() {
+        let tx_bytes = hex!("02f87201830655c2808505ef61f08482565f94388c818ca8b9251b393131c08a736a67ccb192978801049e39c4b5b1f580c001a01764ace353514e8abdfb92446de356b260e3c1225b73fc4c8876a6258d12a129a04f02294aa61ca7676061cd99f29275491218b4754b46a0248e5e42bc5091f507");
+        let tx = PooledTransactionsElement::decode_2718(&mut &tx_bytes[..]).unwrap();
+        let transaction: EthPooledTransaction = tx.try_into_ecrecovered().unwrap().into();
+        let provider = MockEthProvider::default();
+        provider.add_account(transaction.sender(), ExtendedAccount::new(42, U256::MAX));
+
+        let new_pool = || {
+            let blob_store = InMemoryBlobStore::default();
+            let validator = EthTransactionValidatorBuilder::new(MAINNET.clone())
+                .build(provider.clone(), blob_store.clone());
+            Pool::new(validator, CoinbaseTipOrdering::default(), blob_store, PoolConfig::default())
+        };
+
+        let pool = new_pool();
+        pool.add_transaction(TransactionOrigin::External, transaction.clone()).await.unwrap();
+        let snapshot = pool.snapshot();
+        assert_eq!(snapshot.transactions.len(), 1);
+        assert_eq!(snapshot.transactions[0].origin, TransactionOrigin::External);
+        assert_eq!(snapshot.transactions[0].hash, *transaction.hash());
+        assert_eq!(snapshot.transactions[0].sender, transaction.sender());
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("txpool.snapshot");
+        snapshot.save(&path).unwrap();
+        let snapshot = PoolSnapshot::load(&path).unwrap();
+
+        let pool = new_pool();
+        assert_eq!(pool.restore(snapshot).await, 1);
+        let restored = pool.get(transaction.hash()).unwrap();
+        assert_eq!(restored.origin, TransactionOrigin::External);
+    }
+}
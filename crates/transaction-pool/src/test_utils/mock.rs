@@ -15,6 +15,7 @@ use alloy_eips::{
     eip1559::MIN_PROTOCOL_BASE_FEE,
     eip2930::AccessList,
     eip4844::{BlobTransactionSidecar, BlobTransactionValidationError, DATA_GAS_PER_BLOB},
+    eip7702::SignedAuthorization,
 };
 use alloy_primitives::{
     Address, Bytes, ChainId, PrimitiveSignature as Signature, TxHash, TxKind, B256, U256,
@@ -825,6 +826,10 @@ impl EthPoolTransaction for MockTransaction {
     fn authorization_count(&self) -> usize {
         0
     }
+
+    fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
+        None
+    }
 }
 
 impl TryFrom<RecoveredTx> for MockTransaction {
@@ -1099,6 +1104,7 @@ impl MockTransactionFactory {
             transaction,
             timestamp: Instant::now(),
             origin,
+            authorization_ids: None,
         }
     }
 
@@ -1,8 +1,11 @@
 use crate::{
     blobstore::BlobStoreError,
-    error::{InvalidPoolTransactionError, PoolResult},
-    pool::{state::SubPool, BestTransactionFilter, TransactionEvents},
-    validate::ValidPoolTransaction,
+    error::{BundleError, InvalidPoolTransactionError, PoolResult},
+    pool::{
+        state::SubPool, BestTransactionFilter, Bundle, EvictedTransaction, RejectedTransaction,
+        TransactionEvents, ValidBundle,
+    },
+    validate::{BlobVerificationCache, ValidPoolTransaction},
     AllTransactionsEvents,
 };
 use alloy_consensus::{
@@ -13,6 +16,7 @@ use alloy_eips::{
     eip2718::Encodable2718,
     eip2930::AccessList,
     eip4844::{BlobAndProofV1, BlobTransactionSidecar, BlobTransactionValidationError},
+    eip7702::SignedAuthorization,
 };
 use alloy_primitives::{Address, TxHash, TxKind, B256, U256};
 use futures_util::{ready, Stream};
@@ -67,6 +71,19 @@ pub trait TransactionPool: Send + Sync + Clone {
     /// This tracks the block that the pool has last seen.
     fn block_info(&self) -> BlockInfo;
 
+    /// Returns the minimum priority fee (in wei) non-local transactions currently have to pay to
+    /// enter the pool.
+    ///
+    /// If the dynamic price floor is enabled this can be higher than the configured minimum while
+    /// the pool is congested, see [`PriceFloorConfig`](crate::PriceFloorConfig).
+    fn price_floor(&self) -> u128;
+
+    /// Sets the minimum priority fee (in wei) non-local transactions have to pay to enter the
+    /// pool.
+    ///
+    /// This only applies to new transactions, transactions already in the pool are kept.
+    fn set_minimum_priority_fee(&self, minimum_priority_fee: u128);
+
     /// Imports an _external_ transaction.
     ///
     /// This is intended to be used by the network to insert incoming transactions received over the
@@ -122,6 +139,36 @@ pub trait TransactionPool: Send + Sync + Clone {
         transactions: Vec<Self::Transaction>,
     ) -> impl Future<Output = Vec<PoolResult<TxHash>>> + Send;
 
+    /// Adds the given _unvalidated_ bundle to the pool.
+    ///
+    /// The transactions of the bundle are validated but not added to the regular sub-pools, the
+    /// bundle is only considered as a whole when building a block it is valid for.
+    ///
+    /// Returns the hash of the bundle.
+    ///
+    /// Consumer: RPC
+    fn add_bundle(
+        &self,
+        bundle: Bundle<Self::Transaction>,
+    ) -> impl Future<Output = Result<B256, BundleError>> + Send;
+
+    /// Removes the bundle with the given hash.
+    ///
+    /// Returns `true` if the bundle was tracked by the pool.
+    ///
+    /// Consumer: RPC
+    fn cancel_bundle(&self, hash: &B256) -> bool;
+
+    /// Returns all bundles that can be included in a block with the given number and timestamp,
+    /// in the order they were submitted.
+    ///
+    /// Consumer: Block production
+    fn bundles(
+        &self,
+        block_number: u64,
+        timestamp: u64,
+    ) -> Vec<Arc<ValidBundle<Self::Transaction>>>;
+
     /// Returns a new transaction change event stream for the given transaction.
     ///
     /// Returns `None` if the transaction is not in the pool.
@@ -154,6 +201,19 @@ pub trait TransactionPool: Send + Sync + Clone {
     /// commitments/proofs) for eip-4844 transactions inserted into the pool
     fn blob_transaction_sidecars_listener(&self) -> Receiver<NewBlobSidecar>;
 
+    /// Returns a new [Receiver] that yields every transaction that is evicted from the pool due to
+    /// the configured limits, together with the reason of the eviction, e.g. to notify users that
+    /// their transaction was dropped.
+    ///
+    /// Consumer: RPC/ExEx
+    fn evicted_transactions_listener(&self) -> Receiver<EvictedTransaction<Self::Transaction>>;
+
+    /// Returns a new [Receiver] that yields every transaction that is rejected by the validator
+    /// when it is added to the pool, together with the reason of the rejection.
+    ///
+    /// Consumer: RPC/ExEx
+    fn rejected_transactions_listener(&self) -> Receiver<RejectedTransaction>;
+
     /// Returns a new stream that yields new valid transactions added to the pool
     /// depending on the given [TransactionListenerKind] argument.
     fn new_transactions_listener_for(
@@ -494,6 +554,13 @@ pub trait TransactionPool: Send + Sync + Clone {
         &self,
         versioned_hashes: &[B256],
     ) -> Result<Vec<Option<BlobAndProofV1>>, BlobStoreError>;
+
+    /// Returns the cache of blob sidecar verification results of the pool's validator, if any.
+    ///
+    /// This can be used to share the verification results with other validators.
+    fn blob_verification_cache(&self) -> Option<BlobVerificationCache> {
+        None
+    }
 }
 
 /// Extension for [TransactionPool] trait that allows to set the current block info.
@@ -1172,6 +1239,11 @@ pub trait EthPoolTransaction: PoolTransaction {
 
     /// Returns the number of authorizations this transaction has.
     fn authorization_count(&self) -> usize;
+
+    /// Returns the EIP-7702 authorization list of the transaction.
+    ///
+    /// Returns `None` if this transaction is not an EIP-7702 transaction.
+    fn authorization_list(&self) -> Option<&[SignedAuthorization]>;
 }
 
 /// The default [`PoolTransaction`] for the [Pool](crate::Pool) for Ethereum.
@@ -1437,6 +1509,13 @@ impl EthPoolTransaction for EthPooledTransaction {
             _ => 0,
         }
     }
+
+    fn authorization_list(&self) -> Option<&[SignedAuthorization]> {
+        match &self.transaction.transaction {
+            Transaction::Eip7702(tx) => Some(&tx.authorization_list),
+            _ => None,
+        }
+    }
 }
 
 impl TryFrom<RecoveredTx> for EthPooledTransaction {
@@ -1558,6 +1637,9 @@ pub enum GetPooledTransactionLimit {
     /// No limit, return all transactions.
     None,
     /// Enforce a size limit on the returned transactions, for example 2MB
+    ///
+    /// The size is measured as the network encoding of the transactions, including blob sidecars.
+    /// The first transaction is always returned, even if it exceeds the limit on its own.
     ResponseSizeSoftLimit(usize),
 }
 
@@ -0,0 +1,149 @@
+//! Cache of blob sidecar verification results.
+
+use crate::metrics::BlobVerificationCacheMetrics;
+use alloy_eips::eip4844::{BlobTransactionSidecar, BlobTransactionValidationError};
+use alloy_primitives::{Keccak256, TxHash, B256};
+use parking_lot::Mutex;
+use schnellru::{ByLength, LruMap};
+use std::{fmt, sync::Arc};
+
+/// The default number of transactions whose blob verification result is cached.
+pub const DEFAULT_BLOB_VERIFICATION_CACHE_SIZE: u32 = 4096;
+
+/// Caches the results of the KZG proof verification of blob transaction sidecars.
+///
+/// Blob transactions are usually announced by multiple peers, and verifying the proofs of a
+/// sidecar is expensive. The cache can be shared between validators, so a sidecar is only verified
+/// once.
+///
+/// The results are keyed by the transaction hash and the hash of the sidecar, since the
+/// transaction hash does not commit to the blobs and proofs: a peer can send a valid transaction
+/// with an invalid sidecar, which must not affect the same transaction with a valid sidecar.
+#[derive(Clone)]
+pub struct BlobVerificationCache {
+    results: Arc<Mutex<LruMap<(TxHash, B256), BlobVerification, ByLength>>>,
+    metrics: BlobVerificationCacheMetrics,
+}
+
+impl BlobVerificationCache {
+    /// Creates a new cache that holds the results of up to `max_length` sidecars.
+    pub fn new(max_length: u32) -> Self {
+        Self {
+            results: Arc::new(Mutex::new(LruMap::new(ByLength::new(max_length)))),
+            metrics: Default::default(),
+        }
+    }
+
+    /// Verifies the sidecar of the transaction with the given hash using the given function,
+    /// unless the same sidecar was verified before.
+    ///
+    /// A sidecar that failed verification before is rejected with
+    /// [`BlobTransactionValidationError::InvalidProof`].
+    pub fn verify(
+        &self,
+        tx_hash: TxHash,
+        sidecar: &BlobTransactionSidecar,
+        verify: impl FnOnce() -> Result<(), BlobTransactionValidationError>,
+    ) -> Result<(), BlobTransactionValidationError> {
+        let key = (tx_hash, sidecar_hash(sidecar));
+        let cached = self.results.lock().get(&key).copied();
+        match cached {
+            Some(BlobVerification::Valid) => {
+                self.metrics.hits.increment(1);
+                return Ok(())
+            }
+            Some(BlobVerification::Invalid) => {
+                self.metrics.hits.increment(1);
+                return Err(BlobTransactionValidationError::InvalidProof)
+            }
+            None => self.metrics.misses.increment(1),
+        }
+
+        let result = verify();
+        let verification =
+            if result.is_ok() { BlobVerification::Valid } else { BlobVerification::Invalid };
+        self.results.lock().insert(key, verification);
+        result
+    }
+
+    /// Returns true if the given sidecar of the transaction with the given hash failed
+    /// verification before.
+    pub fn is_invalid(&self, tx_hash: TxHash, sidecar: &BlobTransactionSidecar) -> bool {
+        matches!(
+            self.results.lock().peek(&(tx_hash, sidecar_hash(sidecar))),
+            Some(BlobVerification::Invalid)
+        )
+    }
+}
+
+impl Default for BlobVerificationCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_BLOB_VERIFICATION_CACHE_SIZE)
+    }
+}
+
+impl fmt::Debug for BlobVerificationCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlobVerificationCache")
+            .field("len", &self.results.lock().len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The cached result of a sidecar verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlobVerification {
+    /// The sidecar is valid.
+    Valid,
+    /// The sidecar is invalid.
+    Invalid,
+}
+
+/// Returns the hash of all blobs, commitments and proofs of the sidecar.
+fn sidecar_hash(sidecar: &BlobTransactionSidecar) -> B256 {
+    let mut hasher = Keccak256::new();
+    for blob in &sidecar.blobs {
+        hasher.update(blob.as_slice());
+    }
+    for commitment in &sidecar.commitments {
+        hasher.update(commitment.as_slice());
+    }
+    for proof in &sidecar.proofs {
+        hasher.update(proof.as_slice());
+    }
+    hasher.finalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip4844::{Blob, Bytes48};
+
+    fn sidecar(byte: u8) -> BlobTransactionSidecar {
+        BlobTransactionSidecar::new(
+            vec![Blob::repeat_byte(byte)],
+            vec![Bytes48::repeat_byte(byte)],
+            vec![Bytes48::repeat_byte(byte)],
+        )
+    }
+
+    #[test]
+    fn cache_results_per_sidecar() {
+        let cache = BlobVerificationCache::default();
+        let tx_hash = TxHash::random();
+        let invalid = || Err(BlobTransactionValidationError::InvalidProof);
+
+        assert!(cache.verify(tx_hash, &sidecar(1), || Ok(())).is_ok());
+        // the same sidecar is not verified again
+        assert!(cache.verify(tx_hash, &sidecar(1), invalid).is_ok());
+
+        // an invalid sidecar for the same transaction doesn't affect the valid one
+        assert!(cache.verify(tx_hash, &sidecar(2), invalid).is_err());
+        assert!(cache.is_invalid(tx_hash, &sidecar(2)));
+        assert!(!cache.is_invalid(tx_hash, &sidecar(1)));
+        assert!(cache.verify(tx_hash, &sidecar(1), invalid).is_ok());
+
+        // the invalid sidecar is rejected without verifying it again
+        assert!(cache.verify(tx_hash, &sidecar(2), || Ok(())).is_err());
+    }
+}
@@ -7,7 +7,10 @@ use crate::{
         Eip4844PoolTransactionError, Eip7702PoolTransactionError, InvalidPoolTransactionError,
     },
     traits::TransactionOrigin,
-    validate::{ValidTransaction, ValidationTask, MAX_INIT_CODE_BYTE_SIZE},
+    validate::{
+        AdmissionSimulator, BlobVerificationCache, ValidTransaction, ValidationTask,
+        DEFAULT_BLOB_VERIFICATION_CACHE_SIZE, MAX_INIT_CODE_BYTE_SIZE,
+    },
     EthBlobTransactionSidecar, EthPoolTransaction, LocalTransactionConfig,
     TransactionValidationOutcome, TransactionValidationTaskExecutor, TransactionValidator,
 };
@@ -20,13 +23,13 @@ use alloy_consensus::{
 };
 use alloy_eips::eip4844::MAX_BLOBS_PER_BLOCK;
 use reth_chainspec::{ChainSpec, EthereumHardforks};
-use reth_primitives::{InvalidTransactionError, SealedBlock};
-use reth_primitives_traits::GotExpected;
+use reth_primitives::{Header, InvalidTransactionError, SealedBlock};
+use reth_primitives_traits::{FillTxEnv, GotExpected};
 use reth_storage_api::{AccountReader, StateProviderFactory};
 use reth_tasks::TaskSpawner;
 use revm::{
     interpreter::gas::validate_initial_tx_gas,
-    primitives::{EnvKzgSettings, SpecId},
+    primitives::{EnvKzgSettings, SpecId, TxEnv},
 };
 use std::{
     marker::PhantomData,
@@ -56,7 +59,7 @@ impl<Client, Tx> EthTransactionValidator<Client, Tx> {
 impl<Client, Tx> EthTransactionValidator<Client, Tx>
 where
     Client: StateProviderFactory,
-    Tx: EthPoolTransaction,
+    Tx: EthPoolTransaction<Consensus: FillTxEnv>,
 {
     /// Validates a single transaction.
     ///
@@ -85,7 +88,7 @@ where
 impl<Client, Tx> TransactionValidator for EthTransactionValidator<Client, Tx>
 where
     Client: StateProviderFactory,
-    Tx: EthPoolTransaction,
+    Tx: EthPoolTransaction<Consensus: FillTxEnv>,
 {
     type Transaction = Tx;
 
@@ -105,8 +108,15 @@ where
     }
 
     fn on_new_head_block(&self, new_tip_block: &SealedBlock) {
+        if let Some(simulator) = &self.inner.admission_simulator {
+            simulator.on_new_head_block(&new_tip_block.header);
+        }
         self.inner.on_new_head_block(new_tip_block.header())
     }
+
+    fn blob_verification_cache(&self) -> Option<BlobVerificationCache> {
+        Some(self.inner.blob_cache.clone())
+    }
 }
 
 /// A [`TransactionValidator`] implementation that validates ethereum transaction.
@@ -147,10 +157,14 @@ pub(crate) struct EthTransactionValidatorInner<Client, T> {
     minimum_priority_fee: Option<u128>,
     /// Stores the setup and parameters needed for validating KZG proofs.
     kzg_settings: EnvKzgSettings,
+    /// Results of previous blob sidecar verifications.
+    blob_cache: BlobVerificationCache,
     /// How to handle [`TransactionOrigin::Local`](TransactionOrigin) transactions.
     local_transactions_config: LocalTransactionConfig,
     /// Maximum size in bytes a single transaction can have in order to be accepted into the pool.
     max_tx_input_bytes: usize,
+    /// Simulates non-local transactions before they are accepted into the pool.
+    admission_simulator: Option<Arc<dyn AdmissionSimulator>>,
     /// Marker for the transaction type
     _marker: PhantomData<T>,
 }
@@ -167,7 +181,7 @@ impl<Client, Tx> EthTransactionValidatorInner<Client, Tx> {
 impl<Client, Tx> EthTransactionValidatorInner<Client, Tx>
 where
     Client: StateProviderFactory,
-    Tx: EthPoolTransaction,
+    Tx: EthPoolTransaction<Consensus: FillTxEnv>,
 {
     /// Validates a single transaction.
     fn validate_one(
@@ -411,6 +425,35 @@ where
             )
         }
 
+        // Simulate non-local transactions that are executable on top of the latest state
+        if let Some(simulator) = &self.admission_simulator {
+            if tx_nonce == account.nonce &&
+                !self.local_transactions_config.is_local(origin, transaction.sender_ref())
+            {
+                let consensus = transaction.clone_into_consensus();
+                let mut tx_env = TxEnv::default();
+                consensus.as_signed().fill_tx_env(&mut tx_env, consensus.signer());
+                // simulate on the state of the block the simulation environment is built from
+                let result = simulator.state_block_hash().map(|block_hash| {
+                    self.client
+                        .state_by_block_hash(block_hash)
+                        .and_then(|state| simulator.simulate(state, block_hash, tx_env))
+                });
+                match result.unwrap_or(Ok(Ok(()))) {
+                    Ok(Ok(())) => {}
+                    Ok(Err(err)) => {
+                        return TransactionValidationOutcome::Invalid(transaction, err.into())
+                    }
+                    Err(err) => {
+                        return TransactionValidationOutcome::Error(
+                            *transaction.hash(),
+                            Box::new(err),
+                        )
+                    }
+                }
+            }
+        }
+
         let mut maybe_blob_sidecar = None;
 
         // heavy blob tx validation
@@ -442,7 +485,9 @@ where
                 }
                 EthBlobTransactionSidecar::Present(blob) => {
                     // validate the blob
-                    if let Err(err) = transaction.validate_blob(&blob, self.kzg_settings.get()) {
+                    if let Err(err) = self.blob_cache.verify(*transaction.hash(), &blob, || {
+                        transaction.validate_blob(&blob, self.kzg_settings.get())
+                    }) {
                         return TransactionValidationOutcome::Invalid(
                             transaction,
                             InvalidPoolTransactionError::Eip4844(
@@ -456,6 +501,20 @@ where
             }
         }
 
+        // recover the authorities of the authorization list, which the pool tracks to detect
+        // conflicting authorizations
+        let authorities = transaction.authorization_list().map(|authorizations| {
+            let mut authorities = authorizations
+                .iter()
+                .filter_map(|authorization| {
+                    Some((authorization.recover_authority().ok()?, authorization.nonce()))
+                })
+                .collect::<Vec<_>>();
+            authorities.sort_unstable();
+            authorities.dedup();
+            authorities
+        });
+
         // Return the valid transaction
         TransactionValidationOutcome::Valid {
             balance: account.balance,
@@ -469,10 +528,11 @@ where
                 }
                 TransactionOrigin::Private => false,
             },
+            authorities,
         }
     }
 
-    fn on_new_head_block<T: BlockHeader>(&self, new_tip_block: &T) {
+    fn on_new_head_block(&self, new_tip_block: &Header) {
         // update all forks
         if self.chain_spec.is_cancun_active_at_timestamp(new_tip_block.timestamp()) {
             self.fork_tracker.cancun.store(true, std::sync::atomic::Ordering::Relaxed);
@@ -517,10 +577,14 @@ pub struct EthTransactionValidatorBuilder {
 
     /// Stores the setup and parameters needed for validating KZG proofs.
     kzg_settings: EnvKzgSettings,
+    /// Cache of blob sidecar verification results.
+    blob_cache: BlobVerificationCache,
     /// How to handle [`TransactionOrigin::Local`](TransactionOrigin) transactions.
     local_transactions_config: LocalTransactionConfig,
     /// Max size in bytes of a single transaction allowed
     max_tx_input_bytes: usize,
+    /// Simulates non-local transactions before they are accepted into the pool.
+    admission_simulator: Option<Arc<dyn AdmissionSimulator>>,
 }
 
 impl EthTransactionValidatorBuilder {
@@ -539,8 +603,10 @@ impl EthTransactionValidatorBuilder {
             minimum_priority_fee: None,
             additional_tasks: 1,
             kzg_settings: EnvKzgSettings::Default,
+            blob_cache: BlobVerificationCache::new(DEFAULT_BLOB_VERIFICATION_CACHE_SIZE),
             local_transactions_config: Default::default(),
             max_tx_input_bytes: DEFAULT_MAX_TX_INPUT_BYTES,
+            admission_simulator: None,
 
             // by default all transaction types are allowed
             eip2718: true,
@@ -640,6 +706,14 @@ impl EthTransactionValidatorBuilder {
         self
     }
 
+    /// Sets the [`BlobVerificationCache`] that stores the results of blob sidecar verifications.
+    ///
+    /// This can be used to share verification results with other validators.
+    pub fn with_blob_verification_cache(mut self, blob_cache: BlobVerificationCache) -> Self {
+        self.blob_cache = blob_cache;
+        self
+    }
+
     /// Sets a minimum priority fee that's enforced for acceptance into the pool.
     pub const fn with_minimum_priority_fee(mut self, minimum_priority_fee: u128) -> Self {
         self.minimum_priority_fee = Some(minimum_priority_fee);
@@ -676,6 +750,18 @@ impl EthTransactionValidatorBuilder {
         self
     }
 
+    /// Sets the simulator for non-local transactions.
+    ///
+    /// Transactions that can be executed on top of the latest state are rejected if the simulator
+    /// rejects them, e.g. because they revert.
+    pub fn with_admission_simulator(
+        mut self,
+        admission_simulator: Arc<dyn AdmissionSimulator>,
+    ) -> Self {
+        self.admission_simulator = Some(admission_simulator);
+        self
+    }
+
     /// Builds a the [`EthTransactionValidator`] without spawning validator tasks.
     pub fn build<Client, Tx, S>(
         self,
@@ -697,8 +783,10 @@ impl EthTransactionValidatorBuilder {
             block_gas_limit,
             minimum_priority_fee,
             kzg_settings,
+            blob_cache,
             local_transactions_config,
             max_tx_input_bytes,
+            admission_simulator,
             ..
         } = self;
 
@@ -720,8 +808,10 @@ impl EthTransactionValidatorBuilder {
             minimum_priority_fee,
             blob_store: Box::new(blob_store),
             kzg_settings,
+            blob_cache,
             local_transactions_config,
             max_tx_input_bytes,
+            admission_simulator,
             _marker: Default::default(),
         };
 
@@ -834,13 +924,17 @@ pub fn ensure_intrinsic_gas<T: EthPoolTransaction>(
 mod tests {
     use super::*;
     use crate::{
-        blobstore::InMemoryBlobStore, error::PoolErrorKind, traits::PoolTransaction,
+        blobstore::InMemoryBlobStore,
+        error::{PoolErrorKind, SimulationPoolTransactionError},
+        traits::PoolTransaction,
+        validate::{AdmissionSimulatorConfig, EvmAdmissionSimulator},
         CoinbaseTipOrdering, EthPooledTransaction, Pool, TransactionPool,
     };
     use alloy_eips::eip2718::Decodable2718;
     use alloy_primitives::{hex, U256};
     use reth_chainspec::MAINNET;
-    use reth_primitives::PooledTransactionsElement;
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_primitives::{PooledTransactionsElement, SealedHeader};
     use reth_provider::test_utils::{ExtendedAccount, MockEthProvider};
 
     fn get_transaction() -> EthPooledTransaction {
@@ -922,4 +1016,49 @@ mod tests {
         let tx = pool.get(transaction.hash());
         assert!(tx.is_none());
     }
+
+    #[test]
+    fn invalid_on_simulation_gas_threshold() {
+        let transaction = get_transaction();
+
+        let provider = MockEthProvider::default();
+        provider.add_account(
+            transaction.sender(),
+            ExtendedAccount::new(transaction.nonce(), U256::MAX),
+        );
+
+        let head = Header {
+            number: 20_000_000,
+            timestamp: 1_720_000_000,
+            gas_limit: 30_000_000,
+            base_fee_per_gas: Some(1),
+            excess_blob_gas: Some(0),
+            ..Default::default()
+        };
+        let simulator = EvmAdmissionSimulator::new(
+            EthEvmConfig::new(MAINNET.clone()),
+            AdmissionSimulatorConfig { max_gas_used: 300_000, ..Default::default() },
+        )
+        .with_head(SealedHeader::seal(head), U256::MAX);
+
+        let validator = EthTransactionValidatorBuilder::new(MAINNET.clone())
+            .with_admission_simulator(Arc::new(simulator))
+            .build(provider, InMemoryBlobStore::default());
+
+        // the contract deployment uses more gas than the threshold
+        let outcome = validator.validate_one(TransactionOrigin::External, transaction.clone());
+        assert!(matches!(
+            outcome,
+            TransactionValidationOutcome::Invalid(
+                _,
+                InvalidPoolTransactionError::Simulation(
+                    SimulationPoolTransactionError::ExceedsGasThreshold { threshold: 300_000, .. }
+                )
+            )
+        ));
+
+        // local transactions are not simulated
+        let outcome = validator.validate_one(TransactionOrigin::Local, transaction);
+        assert!(outcome.is_valid());
+    }
 }
@@ -12,13 +12,24 @@ use futures_util::future::Either;
 use reth_primitives::{RecoveredTx, SealedBlock};
 use std::{fmt, future::Future, time::Instant};
 
+mod blob_cache;
 mod constants;
 mod eth;
+mod simulator;
 mod task;
 
+/// A cache of blob sidecar verification results.
+pub use blob_cache::{BlobVerificationCache, DEFAULT_BLOB_VERIFICATION_CACHE_SIZE};
+
 /// A `TransactionValidator` implementation that validates ethereum transaction.
 pub use eth::*;
 
+/// Simulation of transactions before they are admitted into the pool.
+pub use simulator::{
+    AdmissionSimulator, AdmissionSimulatorConfig, EvmAdmissionSimulator,
+    DEFAULT_SIMULATION_CACHED_SENDERS, DEFAULT_SIMULATION_MAX_GAS,
+};
+
 /// A spawnable task that performs transaction validation.
 pub use task::{TransactionValidationTaskExecutor, ValidationTask};
 
@@ -45,6 +56,12 @@ pub enum TransactionValidationOutcome<T: PoolTransaction> {
         transaction: ValidTransaction<T>,
         /// Whether to propagate the transaction to the network.
         propagate: bool,
+        /// The authority and nonce of every authorization in the EIP-7702 authorization list of
+        /// the transaction.
+        ///
+        /// This is `None` for all other transaction types. Authorizations with an invalid
+        /// signature are omitted.
+        authorities: Option<Vec<(Address, u64)>>,
     },
     /// The transaction is considered invalid indefinitely: It violates constraints that prevent
     /// this transaction from ever becoming valid.
@@ -207,6 +224,13 @@ pub trait TransactionValidator: Send + Sync {
     ///
     /// This can be used to update fork specific values (timestamp).
     fn on_new_head_block(&self, _new_tip_block: &SealedBlock) {}
+
+    /// Returns the cache of blob sidecar verification results used by this validator, if any.
+    ///
+    /// The cache can be shared with other validators, so a sidecar is only verified once.
+    fn blob_verification_cache(&self) -> Option<BlobVerificationCache> {
+        None
+    }
 }
 
 impl<A, B> TransactionValidator for Either<A, B>
@@ -243,6 +267,13 @@ where
             Self::Right(v) => v.on_new_head_block(new_tip_block),
         }
     }
+
+    fn blob_verification_cache(&self) -> Option<BlobVerificationCache> {
+        match self {
+            Self::Left(v) => v.blob_verification_cache(),
+            Self::Right(v) => v.blob_verification_cache(),
+        }
+    }
 }
 
 /// A valid transaction in the pool.
@@ -262,6 +293,11 @@ pub struct ValidPoolTransaction<T: PoolTransaction> {
     pub timestamp: Instant,
     /// Where this transaction originated from.
     pub origin: TransactionOrigin,
+    /// The nonces of the authorities that the EIP-7702 authorization list of the transaction
+    /// consumes, identified like transactions of the authorities.
+    ///
+    /// This is `None` for all other transaction types.
+    pub authorization_ids: Option<Vec<TransactionId>>,
 }
 
 // === impl ValidPoolTransaction ===
@@ -302,6 +338,14 @@ impl<T: PoolTransaction> ValidPoolTransaction<T> {
         &self.transaction_id
     }
 
+    /// Returns the authority nonces consumed by the EIP-7702 authorization list.
+    ///
+    /// An authorization of an authority at nonce `n` conflicts with any other authorization or
+    /// transaction of the authority at the same nonce.
+    pub(crate) fn authorization_ids(&self) -> &[TransactionId] {
+        self.authorization_ids.as_deref().unwrap_or_default()
+    }
+
     /// Returns the length of the rlp encoded transaction
     #[inline]
     pub fn encoded_length(&self) -> usize {
@@ -448,6 +492,7 @@ impl<T: PoolTransaction> Clone for ValidPoolTransaction<T> {
             propagate: self.propagate,
             timestamp: self.timestamp,
             origin: self.origin,
+            authorization_ids: self.authorization_ids.clone(),
         }
     }
 }
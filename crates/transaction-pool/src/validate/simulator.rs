@@ -0,0 +1,263 @@
+//! Simulation of transactions before they are admitted into the pool.
+
+use crate::error::SimulationPoolTransactionError;
+use alloy_primitives::{Address, B256, U256};
+use parking_lot::{Mutex, RwLock};
+use reth_evm::{ConfigureEvm, ConfigureEvmEnv};
+use reth_primitives::{Header, SealedHeader};
+use reth_revm::{cached::CachedReads, database::StateProviderDatabase};
+use reth_storage_api::{errors::provider::ProviderResult, StateProviderBox};
+use revm::primitives::{
+    EVMError, EnvWithHandlerCfg, ExecutionResult, HaltReason, InvalidTransaction, TxEnv,
+};
+use schnellru::{ByLength, LruMap};
+use std::fmt;
+use tracing::trace;
+
+/// The default number of senders whose state reads are cached by the [`EvmAdmissionSimulator`].
+pub const DEFAULT_SIMULATION_CACHED_SENDERS: u32 = 1024;
+
+/// The default amount of gas a transaction may use in the admission simulation: 5M.
+///
+/// Any peer can make the node simulate its transactions, so the EVM work per transaction is
+/// bounded well below the block gas limit.
+pub const DEFAULT_SIMULATION_MAX_GAS: u64 = 5_000_000;
+
+/// Simulates transactions before they are admitted into the pool, e.g. to reject transactions
+/// that revert.
+///
+/// See [`EthTransactionValidatorBuilder::with_admission_simulator`](crate::validate::EthTransactionValidatorBuilder::with_admission_simulator).
+#[auto_impl::auto_impl(&, Arc, Box)]
+pub trait AdmissionSimulator: fmt::Debug + Send + Sync {
+    /// Returns the hash of the block transactions are simulated on, if known.
+    ///
+    /// Transactions are not simulated until the block is known.
+    fn state_block_hash(&self) -> Option<B256>;
+
+    /// Executes the transaction with the given environment on top of the given state of the
+    /// block with the given hash, see [`Self::state_block_hash`], without committing the changes.
+    ///
+    /// Returns the reason if the transaction must be rejected.
+    fn simulate(
+        &self,
+        state: StateProviderBox,
+        state_block_hash: B256,
+        tx_env: TxEnv,
+    ) -> ProviderResult<Result<(), SimulationPoolTransactionError>>;
+
+    /// Updates the simulator with the new tip of the chain.
+    fn on_new_head_block(&self, header: &SealedHeader);
+}
+
+/// Configuration of the [`EvmAdmissionSimulator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdmissionSimulatorConfig {
+    /// Rejects transactions that use more gas than this in the simulation.
+    ///
+    /// The simulation is aborted once a transaction used this much gas.
+    pub max_gas_used: u64,
+    /// The number of senders whose state reads are cached until the next block.
+    pub max_cached_senders: u32,
+}
+
+impl Default for AdmissionSimulatorConfig {
+    fn default() -> Self {
+        Self {
+            max_gas_used: DEFAULT_SIMULATION_MAX_GAS,
+            max_cached_senders: DEFAULT_SIMULATION_CACHED_SENDERS,
+        }
+    }
+}
+
+/// An [`AdmissionSimulator`] that executes transactions in a throwaway EVM.
+///
+/// Transactions are executed in the environment and on top of the state of the latest block. The
+/// state reads of a transaction are cached per sender for that block, so that subsequent
+/// transactions of the same sender, which usually touch the same accounts and contracts, are
+/// simulated without reading the database again.
+pub struct EvmAdmissionSimulator<Evm> {
+    /// Configures the EVM.
+    evm_config: Evm,
+    /// The simulation settings.
+    config: AdmissionSimulatorConfig,
+    /// The latest block and the total difficulty at it, transactions are not simulated until
+    /// the latest block is known.
+    head: RwLock<Option<(SealedHeader, U256)>>,
+    /// The cached state reads by sender.
+    cached_reads: Mutex<SimulationCache>,
+}
+
+impl<Evm> EvmAdmissionSimulator<Evm> {
+    /// Creates a new simulator with the given EVM configuration and settings.
+    pub fn new(evm_config: Evm, config: AdmissionSimulatorConfig) -> Self {
+        Self {
+            evm_config,
+            config,
+            head: RwLock::new(None),
+            cached_reads: Mutex::new(SimulationCache {
+                block_hash: B256::ZERO,
+                reads: LruMap::new(ByLength::new(config.max_cached_senders)),
+            }),
+        }
+    }
+
+    /// Sets the latest block and the total difficulty at it.
+    ///
+    /// The total difficulty is then tracked with the difficulty of new blocks.
+    pub fn with_head(self, header: SealedHeader, total_difficulty: U256) -> Self {
+        self.cached_reads.lock().clear(header.hash());
+        *self.head.write() = Some((header, total_difficulty));
+        self
+    }
+}
+
+impl<Evm> fmt::Debug for EvmAdmissionSimulator<Evm> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EvmAdmissionSimulator")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Evm> AdmissionSimulator for EvmAdmissionSimulator<Evm>
+where
+    Evm: ConfigureEvm<Header = Header>,
+{
+    fn state_block_hash(&self) -> Option<B256> {
+        self.head.read().as_ref().map(|(header, _)| header.hash())
+    }
+
+    fn simulate(
+        &self,
+        state: StateProviderBox,
+        state_block_hash: B256,
+        mut tx_env: TxEnv,
+    ) -> ProviderResult<Result<(), SimulationPoolTransactionError>> {
+        let Some((cfg, mut block_env)) =
+            self.head.read().as_ref().filter(|(header, _)| header.hash() == state_block_hash).map(
+                |(header, total_difficulty)| {
+                    self.evm_config.cfg_and_block_env(header.header(), *total_difficulty)
+                },
+            )
+        else {
+            // the head changed since the state was fetched
+            return Ok(Ok(()))
+        };
+        // transactions that don't cover the base fee are still admitted as pending
+        block_env.basefee = block_env.basefee.min(tx_env.gas_price);
+
+        // the simulation is aborted once the transaction used the maximum gas
+        let threshold = self.config.max_gas_used;
+        let gas_limited = tx_env.gas_limit > threshold;
+        tx_env.gas_limit = tx_env.gas_limit.min(threshold);
+
+        let sender = tx_env.caller;
+        let mut cached_reads =
+            self.cached_reads.lock().take(state_block_hash, &sender).unwrap_or_default();
+        let result = {
+            let db = cached_reads.as_db_mut(StateProviderDatabase::new(state));
+            let env = EnvWithHandlerCfg::new_with_cfg_env(cfg, block_env, tx_env);
+            self.evm_config.evm_with_env(db, env).transact()
+        };
+        self.cached_reads.lock().insert(state_block_hash, sender, cached_reads);
+
+        let result = match result {
+            Ok(result) => result.result,
+            Err(EVMError::Database(err)) => return Err(err),
+            Err(EVMError::Transaction(InvalidTransaction::CallGasCostMoreThanGasLimit))
+                if gas_limited =>
+            {
+                // the intrinsic gas alone exceeds the maximum
+                return Ok(Err(SimulationPoolTransactionError::ExceedsGasThreshold {
+                    gas_used: threshold,
+                    threshold,
+                }))
+            }
+            Err(err) => {
+                // invalid transactions are rejected by the validator itself, the remaining
+                // errors depend on the environment, e.g. the blob gas price
+                trace!(target: "txpool", %sender, %err, "Skipping admission simulation");
+                return Ok(Ok(()))
+            }
+        };
+
+        Ok(match result {
+            ExecutionResult::Success { .. } => Ok(()),
+            ExecutionResult::Revert { .. } => Err(SimulationPoolTransactionError::Reverted),
+            ExecutionResult::Halt { reason: HaltReason::OutOfGas(_), gas_used } if gas_limited => {
+                Err(SimulationPoolTransactionError::ExceedsGasThreshold { gas_used, threshold })
+            }
+            ExecutionResult::Halt { reason, .. } => {
+                Err(SimulationPoolTransactionError::Halted(reason))
+            }
+        })
+    }
+
+    fn on_new_head_block(&self, header: &SealedHeader) {
+        let mut head = self.head.write();
+        let total_difficulty =
+            head.as_ref().map(|(_, total_difficulty)| *total_difficulty).unwrap_or_default() +
+                header.difficulty;
+        *head = Some((header.clone(), total_difficulty));
+        // the cached reads are stale once the state changed
+        self.cached_reads.lock().clear(header.hash());
+    }
+}
+
+/// The state reads of the [`EvmAdmissionSimulator`] by sender, for the state of a single block.
+#[derive(Debug)]
+struct SimulationCache {
+    /// The hash of the block whose state the reads were made on.
+    block_hash: B256,
+    /// The cached state reads by sender.
+    reads: LruMap<Address, CachedReads, ByLength>,
+}
+
+impl SimulationCache {
+    /// Removes the cached reads of the sender made on the state of the given block.
+    fn take(&mut self, block_hash: B256, sender: &Address) -> Option<CachedReads> {
+        (self.block_hash == block_hash).then(|| self.reads.remove(sender)).flatten()
+    }
+
+    /// Caches the reads of the sender made on the state of the given block.
+    ///
+    /// Reads made on the state of any other block than the one the cache is keyed to are
+    /// discarded.
+    fn insert(&mut self, block_hash: B256, sender: Address, reads: CachedReads) {
+        if self.block_hash == block_hash {
+            self.reads.insert(sender, reads);
+        }
+    }
+
+    /// Discards all reads and keys the cache to the state of the given block.
+    fn clear(&mut self, block_hash: B256) {
+        self.block_hash = block_hash;
+        self.reads.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_is_keyed_by_state_block() {
+        let mut cache = SimulationCache {
+            block_hash: B256::with_last_byte(1),
+            reads: LruMap::new(ByLength::new(10)),
+        };
+        let sender = Address::with_last_byte(1);
+
+        cache.insert(B256::with_last_byte(1), sender, CachedReads::default());
+        // reads of another block are not returned
+        assert!(cache.take(B256::with_last_byte(2), &sender).is_none());
+        assert!(cache.take(B256::with_last_byte(1), &sender).is_some());
+
+        // reads made on a stale block are discarded
+        cache.insert(B256::with_last_byte(1), sender, CachedReads::default());
+        cache.clear(B256::with_last_byte(2));
+        cache.insert(B256::with_last_byte(1), sender, CachedReads::default());
+        assert!(cache.take(B256::with_last_byte(1), &sender).is_none());
+        assert!(cache.take(B256::with_last_byte(2), &sender).is_none());
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::{
     blobstore::BlobStore,
-    validate::{EthTransactionValidatorBuilder, TransactionValidatorError},
+    validate::{BlobVerificationCache, EthTransactionValidatorBuilder, TransactionValidatorError},
     EthTransactionValidator, PoolTransaction, TransactionOrigin, TransactionValidationOutcome,
     TransactionValidator,
 };
@@ -208,4 +208,8 @@ where
     fn on_new_head_block(&self, new_tip_block: &SealedBlock) {
         self.validator.on_new_head_block(new_tip_block)
     }
+
+    fn blob_verification_cache(&self) -> Option<BlobVerificationCache> {
+        self.validator.blob_verification_cache()
+    }
 }
@@ -0,0 +1,33 @@
+use assert_matches::assert_matches;
+use reth_transaction_pool::{
+    error::BundleError,
+    test_utils::{MockTransaction, TestPoolBuilder},
+    Bundle, TransactionPool, MAX_BUNDLE_BLOCK_DISTANCE,
+};
+
+fn bundle(block_number: u64) -> Bundle<MockTransaction> {
+    Bundle {
+        transactions: vec![MockTransaction::eip1559()],
+        block_number,
+        min_timestamp: None,
+        max_timestamp: None,
+        reverting_tx_hashes: Vec::new(),
+    }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_bundle_target_block() {
+    let txpool = TestPoolBuilder::default();
+    let tip = txpool.block_info().last_seen_block_number;
+
+    assert_matches!(txpool.add_bundle(bundle(tip)).await, Err(BundleError::Expired(n)) if n == tip);
+
+    let last = tip + MAX_BUNDLE_BLOCK_DISTANCE;
+    let hash = txpool.add_bundle(bundle(last)).await.unwrap();
+    assert_eq!(txpool.bundles(last, 0)[0].hash, hash);
+
+    assert_matches!(
+        txpool.add_bundle(bundle(last + 1)).await,
+        Err(BundleError::TooFarInFuture(n)) if n == last + 1
+    );
+}
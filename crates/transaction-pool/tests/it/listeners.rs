@@ -1,9 +1,10 @@
 use assert_matches::assert_matches;
 use reth_transaction_pool::{
+    error::PoolErrorKind,
     noop::MockTransactionValidator,
-    test_utils::{MockTransactionFactory, TestPoolBuilder},
-    FullTransactionEvent, TransactionEvent, TransactionListenerKind, TransactionOrigin,
-    TransactionPool,
+    test_utils::{MockTransaction, MockTransactionFactory, TestPoolBuilder},
+    EvictionReason, FullTransactionEvent, PoolConfig, TransactionEvent, TransactionListenerKind,
+    TransactionOrigin, TransactionPool,
 };
 use std::{future::poll_fn, task::Poll};
 use tokio_stream::StreamExt;
@@ -89,3 +90,22 @@ async fn txpool_listener_new_propagate_only() {
     })
     .await;
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn txpool_listener_evicted() {
+    let txpool = TestPoolBuilder::default()
+        .with_config(PoolConfig { max_queued_per_sender: 1, ..Default::default() });
+    let mut evicted = txpool.evicted_transactions_listener();
+
+    // both transactions have a nonce gap, so the second one exceeds the queued limit
+    let first = MockTransaction::eip1559().inc_nonce();
+    let second = first.next();
+    txpool.add_transaction(TransactionOrigin::External, first).await.unwrap();
+    let err =
+        txpool.add_transaction(TransactionOrigin::External, second.clone()).await.unwrap_err();
+    assert_matches!(err.kind, PoolErrorKind::DiscardedOnInsert);
+
+    let event = evicted.recv().await.unwrap();
+    assert_eq!(event.hash(), second.get_hash());
+    assert_eq!(event.reason, EvictionReason::QueuedPerSenderLimit);
+}
@@ -3,6 +3,8 @@
 #[cfg(feature = "test-utils")]
 mod blobs;
 #[cfg(feature = "test-utils")]
+mod bundle;
+#[cfg(feature = "test-utils")]
 mod evict;
 #[cfg(feature = "test-utils")]
 mod listeners;
@@ -41,6 +41,9 @@ pub use proofs::*;
 
 pub mod root;
 
+mod subtrie;
+pub use subtrie::{SubtrieExport, SubtrieVerificationError};
+
 /// Buffer for trie updates.
 pub mod updates;
 
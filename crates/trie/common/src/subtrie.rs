@@ -0,0 +1,78 @@
+use crate::{proof::ProofVerificationError, root::storage_root_unhashed, AccountProof};
+use alloy_primitives::{
+    keccak256,
+    map::{B256HashMap, B256HashSet},
+    Address, BlockNumber, Bytes, B256, U256,
+};
+
+/// A part of the state with its proofs against the state root of a block.
+///
+/// Every account is exported with all of its storage slots and its bytecode, so the part can be
+/// verified and imported into the state of another node at the same block, e.g. to heal corrupted
+/// accounts without a full resync.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(any(test, feature = "serde"), derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "serde"), serde(rename_all = "camelCase"))]
+pub struct SubtrieExport {
+    /// The block the state belongs to.
+    pub block_number: BlockNumber,
+    /// The state root of the block.
+    pub state_root: B256,
+    /// The accounts with the proofs of all of their storage slots.
+    pub accounts: Vec<AccountProof>,
+    /// The bytecodes of the accounts.
+    pub bytecodes: Vec<Bytes>,
+}
+
+impl SubtrieExport {
+    /// Verifies the exported state against the given state root.
+    ///
+    /// Besides the proofs of the accounts and storage slots, this verifies that the storage of
+    /// every account is complete and that the bytecodes of all accounts are included.
+    pub fn verify(&self, state_root: B256) -> Result<(), SubtrieVerificationError> {
+        let code_hashes: B256HashSet = self.bytecodes.iter().map(keccak256).collect();
+
+        for account in &self.accounts {
+            account.verify(state_root)?;
+
+            // proofs of empty slots only prove their absence, duplicate slots were proven to have
+            // the same value
+            let storage: B256HashMap<U256> = account
+                .storage_proofs
+                .iter()
+                .filter(|proof| !proof.value.is_zero())
+                .map(|proof| (proof.key, proof.value))
+                .collect();
+            let storage_root = storage_root_unhashed(storage);
+            if storage_root != account.storage_root {
+                return Err(SubtrieVerificationError::IncompleteStorage(account.address))
+            }
+
+            if let Some(code_hash) = account.info.and_then(|info| info.bytecode_hash) {
+                if !code_hashes.contains(&code_hash) {
+                    return Err(SubtrieVerificationError::MissingBytecode(account.address))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors that can occur when verifying a [`SubtrieExport`].
+#[derive(Debug, PartialEq, Eq, derive_more::Display, derive_more::From)]
+pub enum SubtrieVerificationError {
+    /// A proof of an account or storage slot is invalid.
+    #[display("invalid proof: {_0}")]
+    Proof(ProofVerificationError),
+    /// The storage slots of the account don't match its storage root.
+    #[display("storage of account {_0} is incomplete")]
+    #[from(ignore)]
+    IncompleteStorage(Address),
+    /// The bytecode of the account is not included.
+    #[display("bytecode of account {_0} is missing")]
+    #[from(ignore)]
+    MissingBytecode(Address),
+}
+
+impl core::error::Error for SubtrieVerificationError {}
@@ -1,13 +1,21 @@
 use crate::{
-    DatabaseHashedCursorFactory, DatabaseProof, DatabaseStateRoot, DatabaseStorageRoot,
-    DatabaseTrieCursorFactory, DatabaseTrieWitness,
+    DatabaseHashedCursorFactory, DatabaseProof, DatabaseStateRoot, DatabaseStorageProof,
+    DatabaseStorageRoot, DatabaseTrieCursorFactory, DatabaseTrieWitness,
 };
 use reth_db::transaction::DbTx;
 use reth_trie::{
-    proof::Proof, witness::TrieWitness, KeccakKeyHasher, KeyHasher, StateRoot, StorageRoot,
+    proof::{Proof, StorageProof},
+    witness::TrieWitness,
+    KeccakKeyHasher, KeyHasher, StateRoot, StorageRoot,
 };
 
 /// The `StateCommitment` trait provides associated types for state commitment operations.
+///
+/// This is the trie backend of a node: state roots, proofs and witnesses of the state providers
+/// are computed with the types of the node's state commitment. [`MerklePatriciaTrie`] is the
+/// backend of Ethereum, an experimental commitment scheme, e.g. a verkle or binary trie, can be
+/// plugged in for testnets by implementing this trait and setting it as
+/// `NodeTypes::StateCommitment`.
 pub trait StateCommitment: std::fmt::Debug + Send + Sync + Unpin + 'static {
     /// The state root type.
     type StateRoot<'a, TX: DbTx + 'a>: DatabaseStateRoot<'a, TX>;
@@ -15,6 +23,8 @@ pub trait StateCommitment: std::fmt::Debug + Send + Sync + Unpin + 'static {
     type StorageRoot<'a, TX: DbTx + 'a>: DatabaseStorageRoot<'a, TX>;
     /// The state proof type.
     type StateProof<'a, TX: DbTx + 'a>: DatabaseProof<'a, TX>;
+    /// The storage proof type.
+    type StorageProof<'a, TX: DbTx + 'a>: DatabaseStorageProof<'a, TX>;
     /// The state witness type.
     type StateWitness<'a, TX: DbTx + 'a>: DatabaseTrieWitness<'a, TX>;
     /// The key hasher type.
@@ -33,6 +43,8 @@ impl StateCommitment for MerklePatriciaTrie {
         StorageRoot<DatabaseTrieCursorFactory<'a, TX>, DatabaseHashedCursorFactory<'a, TX>>;
     type StateProof<'a, TX: DbTx + 'a> =
         Proof<DatabaseTrieCursorFactory<'a, TX>, DatabaseHashedCursorFactory<'a, TX>>;
+    type StorageProof<'a, TX: DbTx + 'a> =
+        StorageProof<DatabaseTrieCursorFactory<'a, TX>, DatabaseHashedCursorFactory<'a, TX>>;
     type StateWitness<'a, TX: DbTx + 'a> =
         TrieWitness<DatabaseTrieCursorFactory<'a, TX>, DatabaseHashedCursorFactory<'a, TX>>;
     type KeyHasher = KeccakKeyHasher;
@@ -9,9 +9,9 @@ use reth_db_api::{
 use reth_execution_errors::StateRootError;
 use reth_storage_errors::db::DatabaseError;
 use reth_trie::{
-    hashed_cursor::HashedPostStateCursorFactory, trie_cursor::InMemoryTrieCursorFactory,
-    updates::TrieUpdates, HashedPostState, HashedStorage, KeccakKeyHasher, KeyHasher, StateRoot,
-    StateRootProgress, TrieInput,
+    hashed_cursor::HashedPostStateCursorFactory, prefix_set::TriePrefixSets,
+    trie_cursor::InMemoryTrieCursorFactory, updates::TrieUpdates, HashedPostState, HashedStorage,
+    KeccakKeyHasher, KeyHasher, StateRoot, StateRootProgress, TrieInput,
 };
 use std::{collections::HashMap, ops::RangeInclusive};
 use tracing::debug;
@@ -67,6 +67,17 @@ pub trait DatabaseStateRoot<'a, TX>: Sized {
         range: RangeInclusive<BlockNumber>,
     ) -> Result<StateRootProgress, StateRootError>;
 
+    /// Computes the state root of the trie with the given changed account and storage prefixes
+    /// and existing trie nodes collecting updates in the process.
+    ///
+    /// # Returns
+    ///
+    /// The updated state root and the trie updates.
+    fn prefix_sets_root_with_updates(
+        tx: &'a TX,
+        prefix_sets: TriePrefixSets,
+    ) -> Result<(B256, TrieUpdates), StateRootError>;
+
     /// Calculate the state root for this [`HashedPostState`].
     /// Internally, this method retrieves prefixsets and uses them
     /// to calculate incremental state root.
@@ -165,6 +176,13 @@ impl<'a, TX: DbTx> DatabaseStateRoot<'a, TX>
         Self::incremental_root_calculator(tx, range)?.root_with_progress()
     }
 
+    fn prefix_sets_root_with_updates(
+        tx: &'a TX,
+        prefix_sets: TriePrefixSets,
+    ) -> Result<(B256, TrieUpdates), StateRootError> {
+        Self::from_tx(tx).with_prefix_sets(prefix_sets).root_with_updates()
+    }
+
     fn overlay_root(tx: &'a TX, post_state: HashedPostState) -> Result<B256, StateRootError> {
         let prefix_sets = post_state.construct_prefix_sets().freeze();
         let state_sorted = post_state.into_sorted();
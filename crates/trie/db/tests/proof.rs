@@ -6,7 +6,9 @@ use alloy_rlp::EMPTY_STRING_CODE;
 use reth_chainspec::{Chain, ChainSpec, HOLESKY, MAINNET};
 use reth_primitives::Account;
 use reth_provider::test_utils::{create_test_provider_factory, insert_genesis};
-use reth_trie::{proof::Proof, AccountProof, Nibbles, StorageProof};
+use reth_trie::{
+    proof::Proof, AccountProof, Nibbles, StorageProof, SubtrieExport, SubtrieVerificationError,
+};
 use reth_trie_db::DatabaseProof;
 use std::{
     str::FromStr,
@@ -254,3 +256,34 @@ fn holesky_deposit_contract_proof() {
     similar_asserts::assert_eq!(account_proof, expected);
     assert_eq!(account_proof.verify(root), Ok(()));
 }
+
+#[test]
+fn holesky_deposit_contract_subtrie_export() {
+    // Create test database and insert genesis accounts.
+    let factory = create_test_provider_factory();
+    let root = insert_genesis(&factory, HOLESKY.clone()).unwrap();
+
+    let target = Address::from_str("0x4242424242424242424242424242424242424242").unwrap();
+    let alloc = HOLESKY.genesis.alloc.get(&target).unwrap();
+    let slots = alloc.storage.as_ref().unwrap().keys().copied().collect::<Vec<_>>();
+
+    let provider = factory.provider().unwrap();
+    let account_proof = Proof::from_tx(provider.tx_ref()).account_proof(target, &slots).unwrap();
+    let mut export = SubtrieExport {
+        block_number: 0,
+        state_root: root,
+        accounts: vec![account_proof],
+        bytecodes: vec![alloc.code.clone().unwrap()],
+    };
+    assert_eq!(export.verify(root), Ok(()));
+
+    // the proofs are only valid for the exported state root
+    assert!(matches!(export.verify(B256::random()), Err(SubtrieVerificationError::Proof(_))));
+
+    let bytecode = export.bytecodes.pop().unwrap();
+    assert_eq!(export.verify(root), Err(SubtrieVerificationError::MissingBytecode(target)));
+    export.bytecodes.push(bytecode);
+
+    export.accounts[0].storage_proofs.pop();
+    assert_eq!(export.verify(root), Err(SubtrieVerificationError::IncompleteStorage(target)));
+}
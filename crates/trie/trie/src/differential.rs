@@ -0,0 +1,229 @@
+//! Differential testing of state roots and proofs against the reference implementation in
+//! [`test_utils`](crate::test_utils).
+//!
+//! A [`DifferentialTrie`] tracks a plain model of the state next to the hashed state and the trie
+//! nodes that the trie implementation works with. Every batch of [`StateTransition`]s is applied to
+//! both, and the incrementally computed state root is checked against the root computed from
+//! scratch by the reference. Proofs are checked by verifying them against the reference root.
+
+use crate::{
+    hashed_cursor::{noop::NoopHashedCursorFactory, HashedPostStateCursorFactory},
+    proof::Proof,
+    test_utils,
+    trie_cursor::{noop::NoopTrieCursorFactory, InMemoryTrieCursorFactory},
+    updates::TrieUpdates,
+    HashedPostState, HashedStorage, StateRoot, EMPTY_ROOT_HASH,
+};
+use alloy_primitives::{keccak256, Address, B256, U256};
+use reth_primitives::Account;
+use std::collections::BTreeMap;
+
+/// A change to the state, see [`DifferentialTrie::apply`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateTransition {
+    /// Creates or updates the account.
+    SetAccount(Address, Account),
+    /// Destroys the account, including its storage.
+    ///
+    /// This is ignored if the account doesn't exist.
+    DestroyAccount(Address),
+    /// Sets the storage slot of the account, a zero value deletes the slot.
+    ///
+    /// This is ignored if the account doesn't exist.
+    SetStorage(Address, B256, U256),
+}
+
+/// Checks the state root and proofs of a state against the reference implementation while the
+/// state changes.
+///
+/// The trie is kept in memory: the hashed state and the trie nodes that resulted from previous
+/// transitions play the role of the database, so that every root is computed incrementally from
+/// the nodes of the previous one.
+#[derive(Debug, Default)]
+pub struct DifferentialTrie {
+    /// The model of the state, the input of the reference implementation.
+    accounts: BTreeMap<Address, (Account, BTreeMap<B256, U256>)>,
+    /// The hashed state after all transitions.
+    state: HashedPostState,
+    /// The trie nodes after all transitions.
+    trie_updates: TrieUpdates,
+    /// The current state root.
+    root: B256,
+}
+
+impl DifferentialTrie {
+    /// Creates a new instance with an empty state.
+    pub fn new() -> Self {
+        Self { root: EMPTY_ROOT_HASH, ..Default::default() }
+    }
+
+    /// Returns the current state root.
+    pub const fn root(&self) -> B256 {
+        self.root
+    }
+
+    /// Returns the model of the current state.
+    pub const fn accounts(&self) -> &BTreeMap<Address, (Account, BTreeMap<B256, U256>)> {
+        &self.accounts
+    }
+
+    /// Applies the transitions as a single batch and returns the new state root.
+    ///
+    /// # Panics
+    ///
+    /// If the incrementally computed root differs from the root computed by the reference.
+    pub fn apply(&mut self, transitions: impl IntoIterator<Item = StateTransition>) -> B256 {
+        let mut changes = HashedPostState::default();
+        for transition in transitions {
+            match transition {
+                StateTransition::SetAccount(address, account) => {
+                    self.accounts.entry(address).or_default().0 = account;
+                    changes.accounts.insert(keccak256(address), Some(account));
+                }
+                StateTransition::DestroyAccount(address) => {
+                    if self.accounts.remove(&address).is_none() {
+                        continue
+                    }
+                    let hashed_address = keccak256(address);
+                    changes.accounts.insert(hashed_address, None);
+                    changes.storages.insert(hashed_address, HashedStorage::new(true));
+                }
+                StateTransition::SetStorage(address, slot, value) => {
+                    let Some((_, storage)) = self.accounts.get_mut(&address) else { continue };
+                    if value.is_zero() {
+                        storage.remove(&slot);
+                    } else {
+                        storage.insert(slot, value);
+                    }
+                    changes
+                        .storages
+                        .entry(keccak256(address))
+                        .or_insert_with(|| HashedStorage::new(false))
+                        .storage
+                        .insert(keccak256(slot), value);
+                }
+            }
+        }
+
+        let prefix_sets = changes.construct_prefix_sets().freeze();
+        self.state.extend(changes);
+        let state = self.state.clone().into_sorted();
+        let trie_nodes = self.trie_updates.clone().into_sorted();
+        let (root, updates) = StateRoot::new(
+            InMemoryTrieCursorFactory::new(NoopTrieCursorFactory, &trie_nodes),
+            HashedPostStateCursorFactory::new(NoopHashedCursorFactory, &state),
+        )
+        .with_prefix_sets(prefix_sets)
+        .root_with_updates()
+        .expect("in-memory state root computation can't fail");
+        self.trie_updates.extend(updates);
+
+        assert_eq!(root, self.reference_root(), "state root differs from the reference");
+        self.root = root;
+        root
+    }
+
+    /// Generates the proof of the account and the given storage slots and checks it against the
+    /// model and the reference root.
+    ///
+    /// # Panics
+    ///
+    /// If the proof doesn't verify or proves a different account or storage value.
+    pub fn check_proof(&self, address: Address, slots: &[B256]) {
+        let state = self.state.clone().into_sorted();
+        let trie_nodes = self.trie_updates.clone().into_sorted();
+        let proof = Proof::new(
+            InMemoryTrieCursorFactory::new(NoopTrieCursorFactory, &trie_nodes),
+            HashedPostStateCursorFactory::new(NoopHashedCursorFactory, &state),
+        )
+        .account_proof(address, slots)
+        .expect("in-memory proof generation can't fail");
+
+        if let Err(err) = proof.verify(self.reference_root()) {
+            panic!("proof of {address} does not verify against the reference root: {err}")
+        }
+
+        let entry = self.accounts.get(&address);
+        assert_eq!(proof.info, entry.map(|(account, _)| *account), "proven account of {address}");
+        let storage = entry.map(|(_, storage)| storage.clone()).unwrap_or_default();
+        assert_eq!(
+            proof.storage_root,
+            test_utils::storage_root(storage.clone()),
+            "proven storage root of {address}"
+        );
+        for storage_proof in &proof.storage_proofs {
+            assert_eq!(
+                storage_proof.value,
+                storage.get(&storage_proof.key).copied().unwrap_or_default(),
+                "proven value of slot {} of {address}",
+                storage_proof.key
+            );
+        }
+    }
+
+    /// Computes the state root of the model with the reference implementation.
+    fn reference_root(&self) -> B256 {
+        test_utils::state_root(
+            self.accounts
+                .iter()
+                .map(|(address, (account, storage))| (*address, (*account, storage.clone()))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest_arbitrary_interop::arb;
+
+    /// The number of cases of the differential test, can be increased with the
+    /// `TRIE_DIFFERENTIAL_CASES` environment variable for long-running fuzzing, e.g. nightly.
+    fn cases() -> u32 {
+        std::env::var("TRIE_DIFFERENTIAL_CASES")
+            .ok()
+            .and_then(|cases| cases.parse().ok())
+            .unwrap_or(10)
+    }
+
+    /// Transitions over a small set of accounts with many storage slots, so that accounts are
+    /// updated and destroyed repeatedly and storage tries get deep.
+    fn transition() -> impl Strategy<Value = StateTransition> {
+        let address = (0u8..16).prop_map(Address::with_last_byte);
+        let slot = (0u16..1024).prop_map(|slot| B256::left_padding_from(&slot.to_be_bytes()));
+        let value = prop_oneof![1 => Just(U256::ZERO), 3 => any::<u64>().prop_map(U256::from)];
+        prop_oneof![
+            2 => (address.clone(), arb::<Account>())
+                .prop_map(|(address, account)| StateTransition::SetAccount(address, account)),
+            1 => address.clone().prop_map(StateTransition::DestroyAccount),
+            8 => (address, slot, value).prop_map(|(address, slot, value)| {
+                StateTransition::SetStorage(address, slot, value)
+            }),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(cases()))]
+
+        #[test]
+        fn differential_state_root(
+            batches in prop::collection::vec(prop::collection::vec(transition(), 1..64), 1..16)
+        ) {
+            let mut trie = DifferentialTrie::new();
+            for batch in batches {
+                trie.apply(batch);
+
+                let addresses = trie.accounts().keys().copied().collect::<Vec<_>>();
+                for address in addresses.into_iter().chain([Address::with_last_byte(0xff)]) {
+                    let slots = trie
+                        .accounts()
+                        .get(&address)
+                        .map(|(_, storage)| storage.keys().take(4).copied().collect::<Vec<_>>())
+                        .unwrap_or_default();
+                    // also prove a slot that doesn't exist
+                    trie.check_proof(address, &[slots, vec![B256::repeat_byte(0xff)]].concat());
+                }
+            }
+        }
+    }
+}
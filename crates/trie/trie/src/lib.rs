@@ -63,3 +63,7 @@ pub mod metrics;
 /// Collection of trie-related test utilities.
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
+
+/// Differential testing of state roots and proofs against the reference implementation.
+#[cfg(any(test, feature = "test-utils"))]
+pub mod differential;
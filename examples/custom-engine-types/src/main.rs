@@ -66,8 +66,8 @@ use reth_node_ethereum::{
     EthEvmConfig,
 };
 use reth_payload_builder::{
-    EthBuiltPayload, EthPayloadBuilderAttributes, PayloadBuilderError, PayloadBuilderHandle,
-    PayloadBuilderService,
+    EthBuiltPayload, EthPayloadBuilderAttributes, ExecutionPayloadEnvelopeV5, PayloadBuilderError,
+    PayloadBuilderHandle, PayloadBuilderService,
 };
 use reth_tracing::{RethTracer, Tracer};
 use reth_trie_db::MerklePatriciaTrie;
@@ -168,6 +168,7 @@ impl EngineTypes for CustomEngineTypes {
     type ExecutionPayloadEnvelopeV2 = ExecutionPayloadEnvelopeV2;
     type ExecutionPayloadEnvelopeV3 = ExecutionPayloadEnvelopeV3;
     type ExecutionPayloadEnvelopeV4 = ExecutionPayloadEnvelopeV4;
+    type ExecutionPayloadEnvelopeV5 = ExecutionPayloadEnvelopeV5;
 }
 
 /// Custom engine validator
@@ -14,7 +14,8 @@ use reth_chainspec::{Chain, MAINNET};
 use reth_discv4::{DiscoveryUpdate, Discv4, Discv4ConfigBuilder, DEFAULT_DISCOVERY_ADDRESS};
 use reth_ecies::stream::ECIESStream;
 use reth_eth_wire::{
-    EthMessage, EthStream, HelloMessage, P2PStream, Status, UnauthedEthStream, UnauthedP2PStream,
+    BlockRangeUpdate, EthMessage, EthStream, HelloMessage, P2PStream, Status, UnauthedEthStream,
+    UnauthedP2PStream,
 };
 use reth_network::{config::rng_secret_key, EthNetworkPrimitives};
 use reth_network_peers::{mainnet_nodes, pk2id, NodeRecord};
@@ -108,7 +109,10 @@ async fn handshake_eth(p2p_stream: AuthedP2PStream) -> eyre::Result<(AuthedEthSt
 
     let status =
         Status { version: p2p_stream.shared_capabilities().eth()?.version().try_into()?, ..status };
-    let eth_unauthed = UnauthedEthStream::new(p2p_stream);
+    // we only have the genesis block
+    let block_range =
+        BlockRangeUpdate { earliest: 0, latest: 0, latest_hash: MAINNET_GENESIS_HASH };
+    let eth_unauthed = UnauthedEthStream::new(p2p_stream).with_block_range(block_range);
     Ok(eth_unauthed.handshake(status, fork_filter).await?)
 }
 
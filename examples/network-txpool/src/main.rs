@@ -88,6 +88,7 @@ impl TransactionValidator for OkValidator {
             state_nonce: transaction.nonce(),
             transaction: ValidTransaction::Valid(transaction),
             propagate: false,
+            authorities: None,
         }
     }
 }
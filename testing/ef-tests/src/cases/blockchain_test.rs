@@ -69,100 +69,125 @@ impl Case for BlockchainTestCase {
         // Iterate through test cases, filtering by the network type to exclude specific forks.
         self.tests
             .values()
-            .filter(|case| {
-                !matches!(
-                    case.network,
-                    ForkSpec::ByzantiumToConstantinopleAt5 |
-                        ForkSpec::Constantinople |
-                        ForkSpec::ConstantinopleFix |
-                        ForkSpec::MergeEOF |
-                        ForkSpec::MergeMeterInitCode |
-                        ForkSpec::MergePush0 |
-                        ForkSpec::Unknown
-                )
-            })
+            .filter(|case| is_supported_fork(case.network))
             .par_bridge()
-            .try_for_each(|case| {
-                // Create a new test database and initialize a provider for the test case.
-                let chain_spec: Arc<ChainSpec> = Arc::new(case.network.into());
-                let provider = create_test_provider_factory_with_chain_spec(chain_spec.clone())
-                    .database_provider_rw()
-                    .unwrap();
-
-                // Insert initial test state into the provider.
-                provider.insert_historical_block(
-                    SealedBlock::new(
-                        case.genesis_block_header.clone().into(),
-                        BlockBody::default(),
-                    )
-                    .try_seal_with_senders()
-                    .unwrap(),
-                )?;
-                case.pre.write_to_db(provider.tx_ref())?;
-
-                // Initialize receipts static file with genesis
-                {
-                    let static_file_provider = provider.static_file_provider();
-                    let mut receipts_writer =
-                        static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
-                    receipts_writer.increment_block(0).unwrap();
-                    receipts_writer.commit_without_sync_all().unwrap();
-                }
-
-                // Decode and insert blocks, creating a chain of blocks for the test case.
-                let last_block = case.blocks.iter().try_fold(None, |_, block| {
-                    let decoded = SealedBlock::decode(&mut block.rlp.as_ref())?;
-                    provider.insert_historical_block(
-                        decoded.clone().try_seal_with_senders().unwrap(),
-                    )?;
-                    Ok::<Option<SealedBlock>, Error>(Some(decoded))
-                })?;
-                provider
-                    .static_file_provider()
-                    .latest_writer(StaticFileSegment::Headers)
-                    .unwrap()
-                    .commit_without_sync_all()
-                    .unwrap();
-
-                // Execute the execution stage using the EVM processor factory for the test case
-                // network.
-                let _ = ExecutionStage::new_with_executor(
-                    reth_evm_ethereum::execute::EthExecutorProvider::ethereum(chain_spec),
-                )
-                .execute(
-                    &provider,
-                    ExecInput { target: last_block.as_ref().map(|b| b.number), checkpoint: None },
-                );
-
-                // Validate the post-state for the test case.
-                match (&case.post_state, &case.post_state_hash) {
-                    (Some(state), None) => {
-                        // Validate accounts in the state against the provider's database.
-                        for (&address, account) in state {
-                            account.assert_db(address, provider.tx_ref())?;
-                        }
-                    }
-                    (None, Some(expected_state_root)) => {
-                        // Insert state hashes into the provider based on the expected state root.
-                        let last_block = last_block.unwrap_or_default();
-                        provider.insert_hashes(
-                            0..=last_block.number,
-                            last_block.hash(),
-                            *expected_state_root,
-                        )?;
-                    }
-                    _ => return Err(Error::MissingPostState),
-                }
-
-                // Drop the provider without committing to the database.
-                drop(provider);
-                Ok(())
-            })?;
+            .try_for_each(run_test)?;
 
         Ok(())
     }
 }
 
+impl BlockchainTestCase {
+    /// Runs each test of the case and returns the result of each test by name and fork.
+    ///
+    /// Unlike [`Case::run`], this doesn't stop at the first failure, and tests for forks that are
+    /// not supported are reported as skipped.
+    ///
+    /// If `forks` is not empty, only the tests for these forks are run.
+    pub fn run_by_fork(&self, forks: &[ForkSpec]) -> Vec<(String, ForkSpec, Result<(), Error>)> {
+        self.tests
+            .iter()
+            .filter(|(_, case)| forks.is_empty() || forks.contains(&case.network))
+            .par_bridge()
+            .map(|(name, case)| {
+                let result = if self.skip || !is_supported_fork(case.network) {
+                    Err(Error::Skipped)
+                } else {
+                    run_test(case)
+                };
+                (name.clone(), case.network, result)
+            })
+            .collect()
+    }
+}
+
+/// Returns whether tests for the given fork are run.
+const fn is_supported_fork(fork: ForkSpec) -> bool {
+    !matches!(
+        fork,
+        ForkSpec::ByzantiumToConstantinopleAt5 |
+            ForkSpec::Constantinople |
+            ForkSpec::ConstantinopleFix |
+            ForkSpec::MergeEOF |
+            ForkSpec::MergeMeterInitCode |
+            ForkSpec::MergePush0 |
+            ForkSpec::Unknown
+    )
+}
+
+/// Executes the blocks of a single test with the execution stage and validates the post-state.
+fn run_test(case: &BlockchainTest) -> Result<(), Error> {
+    // Create a new test database and initialize a provider for the test case.
+    let chain_spec: Arc<ChainSpec> = Arc::new(case.network.into());
+    let provider = create_test_provider_factory_with_chain_spec(chain_spec.clone())
+        .database_provider_rw()
+        .unwrap();
+
+    // Insert initial test state into the provider.
+    provider.insert_historical_block(
+        SealedBlock::new(case.genesis_block_header.clone().into(), BlockBody::default())
+            .try_seal_with_senders()
+            .unwrap(),
+    )?;
+    case.pre.write_to_db(provider.tx_ref())?;
+
+    // Initialize receipts static file with genesis
+    {
+        let static_file_provider = provider.static_file_provider();
+        let mut receipts_writer =
+            static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
+        receipts_writer.increment_block(0).unwrap();
+        receipts_writer.commit_without_sync_all().unwrap();
+    }
+
+    // Decode and insert blocks, creating a chain of blocks for the test case.
+    let last_block = case.blocks.iter().try_fold(None, |_, block| {
+        let decoded = SealedBlock::decode(&mut block.rlp.as_ref())?;
+        provider.insert_historical_block(decoded.clone().try_seal_with_senders().unwrap())?;
+        Ok::<Option<SealedBlock>, Error>(Some(decoded))
+    })?;
+    provider
+        .static_file_provider()
+        .latest_writer(StaticFileSegment::Headers)
+        .unwrap()
+        .commit_without_sync_all()
+        .unwrap();
+
+    // Execute the execution stage using the EVM processor factory for the test case
+    // network.
+    let _ = ExecutionStage::new_with_executor(
+        reth_evm_ethereum::execute::EthExecutorProvider::ethereum(chain_spec),
+    )
+    .execute(
+        &provider,
+        ExecInput { target: last_block.as_ref().map(|b| b.number), checkpoint: None },
+    );
+
+    // Validate the post-state for the test case.
+    match (&case.post_state, &case.post_state_hash) {
+        (Some(state), None) => {
+            // Validate accounts in the state against the provider's database.
+            for (&address, account) in state {
+                account.assert_db(address, provider.tx_ref())?;
+            }
+        }
+        (None, Some(expected_state_root)) => {
+            // Insert state hashes into the provider based on the expected state root.
+            let last_block = last_block.unwrap_or_default();
+            provider.insert_hashes(
+                0..=last_block.number,
+                last_block.hash(),
+                *expected_state_root,
+            )?;
+        }
+        _ => return Err(Error::MissingPostState),
+    }
+
+    // Drop the provider without committing to the database.
+    drop(provider);
+    Ok(())
+}
+
 /// Returns whether the test at the given path should be skipped.
 ///
 /// Some tests are edge cases that cannot happen on mainnet, while others are skipped for
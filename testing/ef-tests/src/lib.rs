@@ -11,6 +11,7 @@ use reth_revm as _;
 use revm as _;
 
 pub mod case;
+pub mod report;
 pub mod result;
 pub mod suite;
 
@@ -0,0 +1,47 @@
+//! Runs blockchain tests from a directory against reth's executor and provider stack.
+//!
+//! ```sh
+//! cargo run --release -p ef-tests -- ethereum-tests/BlockchainTests --fork Cancun
+//! ```
+
+use clap::Parser;
+use ef_tests::{models::ForkSpec, report::ForkReport};
+use std::{path::PathBuf, process::ExitCode};
+
+/// Runs the official blockchain tests, or state tests filled as blockchain tests, and reports
+/// the results per fork.
+#[derive(Debug, Parser)]
+#[command(name = "ef-tests")]
+struct Args {
+    /// The directory or file with the tests, e.g. `BlockchainTests` of
+    /// <https://github.com/ethereum/tests> or `fixtures/blockchain_tests` of
+    /// <https://github.com/ethereum/execution-spec-tests>.
+    path: PathBuf,
+
+    /// Only report the tests for these forks, e.g. `Shanghai`.
+    #[arg(long = "fork", value_parser = parse_fork)]
+    forks: Vec<ForkSpec>,
+}
+
+fn parse_fork(s: &str) -> Result<ForkSpec, String> {
+    match serde_json::from_value(serde_json::Value::String(s.to_string())) {
+        Ok(ForkSpec::Unknown) | Err(_) => Err(format!("unknown fork: {s}")),
+        Ok(fork) => Ok(fork),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if !args.path.exists() {
+        eprintln!("Test path does not exist: {}", args.path.display());
+        return ExitCode::FAILURE
+    }
+
+    let report = ForkReport::run(&args.path, &args.forks);
+    print!("{report}");
+    if report.is_success() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
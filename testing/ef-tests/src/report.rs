@@ -0,0 +1,115 @@
+//! Per-fork reports of blockchain test runs.
+
+use crate::{
+    case::Case, cases::blockchain_test::BlockchainTestCase, models::ForkSpec, result::Error,
+    suite::find_all_files_with_extension,
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// A failed test in a [`ForkReport`].
+#[derive(Debug)]
+pub struct FailedTest {
+    /// The path to the file that contains the test.
+    pub path: PathBuf,
+    /// The name of the test in the file.
+    pub name: String,
+    /// Why the test failed, e.g. the mismatch between the expected and the actual post-state.
+    pub error: Error,
+}
+
+/// A test file that could not be loaded in a [`ForkReport`].
+#[derive(Debug)]
+pub struct FailedFile {
+    /// The path to the file.
+    pub path: PathBuf,
+    /// Why the file could not be loaded, e.g. invalid JSON.
+    pub error: Error,
+}
+
+/// The results of the tests for a single fork.
+#[derive(Debug, Default)]
+pub struct ForkResults {
+    /// The number of tests that passed.
+    pub passed: usize,
+    /// The number of tests that were skipped.
+    pub skipped: usize,
+    /// The tests that failed.
+    pub failed: Vec<FailedTest>,
+}
+
+/// The results of running all blockchain tests in a directory, grouped by fork.
+///
+/// The tests are executed with reth's executor and written to a test database through the
+/// provider, so this can be used to check custom EVM changes against the official blockchain
+/// tests, including the state tests filled as blockchain tests.
+#[derive(Debug, Default)]
+pub struct ForkReport {
+    /// The results by fork.
+    pub forks: BTreeMap<ForkSpec, ForkResults>,
+    /// The test files that could not be loaded.
+    pub failed_files: Vec<FailedFile>,
+}
+
+impl ForkReport {
+    /// Runs every blockchain test found recursively in the given path, which may also be a
+    /// single test file.
+    ///
+    /// If `forks` is not empty, only the tests for these forks are run and reported. Files that
+    /// can't be loaded are reported as failed.
+    pub fn run(path: &Path, forks: &[ForkSpec]) -> Self {
+        let mut report = Self::default();
+        for test_path in find_all_files_with_extension(path, ".json") {
+            let case = match BlockchainTestCase::load(&test_path) {
+                Ok(case) => case,
+                Err(error) => {
+                    report.failed_files.push(FailedFile { path: test_path, error });
+                    continue
+                }
+            };
+            for (name, fork, result) in case.run_by_fork(forks) {
+                let results = report.forks.entry(fork).or_default();
+                match result {
+                    Ok(()) => results.passed += 1,
+                    Err(Error::Skipped) => results.skipped += 1,
+                    Err(error) => {
+                        results.failed.push(FailedTest { path: test_path.clone(), name, error })
+                    }
+                }
+            }
+        }
+        report
+    }
+
+    /// Returns `true` if all test files were loaded and no test failed.
+    pub fn is_success(&self) -> bool {
+        self.failed_files.is_empty() && self.forks.values().all(|results| results.failed.is_empty())
+    }
+}
+
+impl fmt::Display for ForkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (fork, results) in &self.forks {
+            writeln!(
+                f,
+                "{fork:?}: {} passed, {} failed, {} skipped",
+                results.passed,
+                results.failed.len(),
+                results.skipped
+            )?;
+            for test in &results.failed {
+                writeln!(f, "  [!] {} ({}): {}", test.name, test.path.display(), test.error)?;
+            }
+        }
+        if !self.failed_files.is_empty() {
+            writeln!(f, "{} test files could not be loaded", self.failed_files.len())?;
+            for file in &self.failed_files {
+                writeln!(f, "  [!] {}: {}", file.path.display(), file.error)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -65,14 +65,19 @@ impl Default for BlockRangeParams {
 /// If `SEED` is not set, a random seed is used.
 pub fn rng() -> StdRng {
     if let Ok(seed) = std::env::var("SEED") {
-        let mut hasher = DefaultHasher::new();
-        hasher.write(seed.as_bytes());
-        StdRng::seed_from_u64(hasher.finish())
+        rng_with_seed(seed.as_bytes())
     } else {
         StdRng::from_rng(thread_rng()).expect("could not build rng")
     }
 }
 
+/// Returns a deterministic random number generator for the given seed.
+pub fn rng_with_seed(seed: &[u8]) -> StdRng {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(seed);
+    StdRng::seed_from_u64(hasher.finish())
+}
+
 /// Generates a range of random [`SealedHeader`]s.
 ///
 /// The parent hash of the first header